@@ -1,13 +1,19 @@
 use crate::{
-    checkpoint::strategy::{CheckpointStrategy, NoCheckpointing},
+    checkpoint::{
+        base::Checkpointer,
+        state::{OffloadedState, StateContent},
+        strategy::{CheckpointStrategy, NoCheckpointing},
+    },
     grads::Gradients,
+    ops::{unary, Backward, Ops},
     runtime::AutodiffClient,
     tensor::AutodiffTensor,
     AutodiffBridge,
 };
-use burn_common::sync_type::SyncType;
-use burn_tensor::backend::{AutodiffBackend, Backend};
+use burn_common::{memory_usage::MemoryUsage, sync_type::SyncType};
+use burn_tensor::backend::{AutodiffBackend, Backend, DeviceInfo};
 use core::marker::PhantomData;
+use std::sync::Arc;
 
 /// Enable auto-differentiation on a backend.
 ///
@@ -44,9 +50,21 @@ impl<B: Backend, C: CheckpointStrategy> Backend for Autodiff<B, C> {
         B::seed(seed)
     }
 
+    fn fork_seed() -> Option<u64> {
+        B::fork_seed()
+    }
+
     fn sync(device: &B::Device, sync_type: SyncType) {
         B::sync(device, sync_type)
     }
+
+    fn memory_usage(device: &B::Device) -> Option<MemoryUsage> {
+        B::memory_usage(device)
+    }
+
+    fn devices() -> Vec<DeviceInfo<B::Device>> {
+        B::devices()
+    }
 }
 
 impl<B: Backend, C: CheckpointStrategy> AutodiffBackend for Autodiff<B, C> {
@@ -112,4 +130,40 @@ impl<B: Backend, C: CheckpointStrategy> AutodiffBackend for Autodiff<B, C> {
     ) -> burn_tensor::ops::BoolTensor<Self, D> {
         tensor
     }
+
+    fn offload<const D: usize>(tensor: AutodiffTensor<B, D>) -> AutodiffTensor<B, D> {
+        #[derive(Debug)]
+        struct Offload;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Offload {
+            type State = ();
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| grad);
+            }
+        }
+
+        // Nothing is saved for the backward pass of an untracked tensor.
+        if tensor.node.requirement.is_none() {
+            return tensor;
+        }
+
+        let device = B::float_device(&tensor.primitive);
+        let data = B::float_into_data(tensor.primitive.clone())
+            .read_sync()
+            .expect("Offloading activations requires reading tensors synchronously.");
+        let state = OffloadedState::new(Arc::new(move || -> StateContent {
+            Box::new(B::float_from_data(data.clone(), &device))
+        }));
+
+        Offload
+            .prepare::<C>([tensor.node.clone()])
+            .offloaded(state)
+            .stateless(tensor.primitive)
+    }
 }
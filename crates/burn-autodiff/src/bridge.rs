@@ -152,4 +152,16 @@ where
         .parents([&tensor])
         .stateless(Bridge::from_target(tensor.primitive, None))
     }
+
+    fn target_device(
+        device: &burn_tensor::Device<Autodiff<B, C>>,
+    ) -> burn_tensor::Device<Self::Target> {
+        Bridge::target_device(device)
+    }
+
+    fn origin_device(
+        device: &burn_tensor::Device<Self::Target>,
+    ) -> burn_tensor::Device<Autodiff<B, C>> {
+        Bridge::origin_device(device)
+    }
 }
@@ -94,6 +94,12 @@ impl CheckpointerBuilder {
                     retro_forward: retro_forward.clone(),
                 })
             }
+            ComputingProperty::Offloaded { state } => {
+                action_list.push(CheckpointingAction::Computed {
+                    node_id: tensor.node.id,
+                    state_content: Box::new(state.clone()),
+                })
+            }
         }
     }
 
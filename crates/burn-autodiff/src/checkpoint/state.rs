@@ -1,10 +1,23 @@
-use std::{any::Any, collections::HashMap};
+use std::{any::Any, collections::HashMap, sync::Arc};
 
 use crate::graph::NodeID;
 
 /// In order to accept arbitrary node output in the same hashmap, we need to upcast them to any.
 pub(crate) type StateContent = Box<dyn Any + Send>;
 
+#[derive(new, Clone)]
+/// The output of a node kept in host memory, which is loaded back on its device each time it is
+/// retrieved instead of being stored as is.
+pub struct OffloadedState {
+    load: Arc<dyn Fn() -> StateContent + Send + Sync>,
+}
+
+impl core::fmt::Debug for OffloadedState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("OffloadedState")
+    }
+}
+
 #[derive(Debug)]
 /// The state contained at one node. Encapsulates the node output if precomputed,
 /// or clearly asks that it needs to be recomputed from the parents.
@@ -91,17 +104,20 @@ impl BackwardStates {
                 },
             };
 
-            let downcasted = new_stored_state
-                .to_state_content()
-                .downcast_ref::<T>()
-                .unwrap()
-                .clone();
+            let state_content = new_stored_state.to_state_content();
+            let downcasted = match state_content.downcast_ref::<OffloadedState>() {
+                Some(offloaded) => *(offloaded.load)().downcast::<T>().unwrap(),
+                None => state_content.downcast_ref::<T>().unwrap().clone(),
+            };
 
             self.insert_state(*node_id, new_stored_state);
 
             downcasted
         } else {
-            let downcasted = state.into_state_content().downcast::<T>().unwrap();
+            let downcasted = match state.into_state_content().downcast::<OffloadedState>() {
+                Ok(offloaded) => (offloaded.load)().downcast::<T>().unwrap(),
+                Err(state_content) => state_content.downcast::<T>().unwrap(),
+            };
             *downcasted
         }
     }
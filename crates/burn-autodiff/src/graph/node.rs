@@ -1,7 +1,7 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
-use crate::checkpoint::retro_forward::RetroForward;
+use crate::checkpoint::{retro_forward::RetroForward, state::OffloadedState};
 use crate::runtime::AutodiffClientImpl;
 
 use super::Requirement;
@@ -13,6 +13,9 @@ pub enum ComputingProperty {
         retro_forward: Arc<dyn RetroForward>,
     },
     Ambiguous, // Maybe autotune someday
+    Offloaded {
+        state: OffloadedState,
+    },
 }
 
 /// This is safe only because we only call RetroForward on the autodiff server.
@@ -12,7 +12,7 @@ use crate::{
 };
 use burn_tensor::{
     backend::Backend,
-    ops::{ActivationOps, FloatTensor},
+    ops::{ActivationOps, FloatTensor, IntTensor},
 };
 
 impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodiff<B, C> {
@@ -166,4 +166,42 @@ impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodi
             OpsKind::UnTracked(prep) => prep.finish(B::log_sigmoid(tensor.primitive)),
         }
     }
+
+    fn cross_entropy(
+        logits: FloatTensor<Self, 2>,
+        targets: IntTensor<B, 1>,
+    ) -> FloatTensor<Self, 1> {
+        #[derive(Debug)]
+        struct CrossEntropy;
+
+        impl<B: Backend> Backward<B, 1, 1> for CrossEntropy {
+            type State = (NodeID, IntTensor<B, 1>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (logits, targets) = ops.state;
+                let logits = checkpointer.retrieve_node_output(logits);
+
+                unary::<B, 1, 2, _>(ops.parents, ops.node, grads, |grad| {
+                    B::cross_entropy_backward(logits, targets, grad)
+                });
+            }
+        }
+
+        match CrossEntropy
+            .prepare::<C>([logits.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = (prep.checkpoint(&logits), targets.clone());
+                prep.finish(state, B::cross_entropy(logits.primitive, targets))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::cross_entropy(logits.primitive, targets)),
+        }
+    }
 }
@@ -4,6 +4,7 @@ use crate::{
         base::Checkpointer,
         builder::{ActionType, CheckpointerBuilder},
         retro_forward::RetroForward,
+        state::OffloadedState,
         strategy::CheckpointStrategy,
     },
     grads::Gradients,
@@ -70,6 +71,21 @@ where
             self.checkpointer_builder,
         )
     }
+
+    /// Indicates that the output of the operation is kept in host memory when checkpointed,
+    /// whatever the checkpoint strategy, and loaded back on the device when retrieved
+    pub(crate) fn offloaded(
+        self,
+        state: OffloadedState,
+    ) -> OpsPrep<BO, B, S, C, D, N, ComputePropertyDone> {
+        OpsPrep::new(
+            self.nodes,
+            self.requirement,
+            self.backward,
+            ComputingProperty::Offloaded { state },
+            self.checkpointer_builder,
+        )
+    }
 }
 
 impl<BO, B, S, C, const D: usize, const N: usize> OpsPrep<BO, B, S, C, D, N, MemoryBound>
@@ -16,8 +16,8 @@ use crate::{
 
 use burn_tensor::{
     backend::Backend,
-    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
-    Data, Device, ElementConversion, Reader, Shape, Tensor,
+    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor, ScatterReduction},
+    Bool, Data, Device, ElementConversion, Reader, Shape, Tensor,
 };
 
 use super::maxmin::MaxMinDim;
@@ -964,6 +964,130 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    #[cfg(not(target_family = "wasm"))]
+    fn float_scatter_reduce<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<B, D>,
+        value: FloatTensor<Self, D>,
+        reduction: ScatterReduction,
+        deterministic: bool,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct ScatterReduce;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for ScatterReduce {
+            type State = (
+                usize,
+                IntTensor<B, D>,
+                FloatTensor<B, D>,
+                FloatTensor<B, D>,
+                FloatTensor<B, D>,
+                ScatterReduction,
+            );
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (dim, indices, tensor, value, output, reduction) = ops.state;
+
+                // Each element of the output receives the gradient, split evenly between the
+                // reduced elements for the mean, or given to the selected ones for the extremums,
+                // where ties all receive the gradient.
+                let scale = match reduction {
+                    ScatterReduction::Mean => {
+                        let device = B::float_device(&tensor);
+                        Some(B::float_scatter(
+                            dim,
+                            B::float_ones(B::float_shape(&tensor), &device),
+                            indices.clone(),
+                            B::float_ones(B::float_shape(&value), &device),
+                        ))
+                    }
+                    _ => None,
+                };
+                let selected = match reduction {
+                    ScatterReduction::Max | ScatterReduction::Min => Some((
+                        B::float_equal(tensor, output.clone()),
+                        B::float_equal(value, B::float_gather(dim, output, indices.clone())),
+                    )),
+                    _ => None,
+                };
+                let scale_4rhs = scale.clone();
+                let (selected_4lhs, selected_4rhs) = selected.unzip();
+
+                binary::<B, D, D, D, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        let grad = match scale {
+                            Some(scale) => B::float_div(grad, scale),
+                            None => grad,
+                        };
+
+                        match selected_4lhs {
+                            Some(mask) => B::float_mask_fill(grad, B::bool_not(mask), 0.elem()),
+                            None => grad,
+                        }
+                    },
+                    |grad| {
+                        let grad = match scale_4rhs {
+                            Some(scale) => B::float_div(grad, scale),
+                            None => grad,
+                        };
+                        let grad = B::float_gather(dim, grad, indices);
+
+                        match selected_4rhs {
+                            Some(mask) => B::float_mask_fill(grad, B::bool_not(mask), 0.elem()),
+                            None => grad,
+                        }
+                    },
+                );
+            }
+        }
+
+        match ScatterReduce
+            .prepare::<C>([tensor.node, value.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_scatter_reduce(
+                    dim,
+                    tensor.primitive.clone(),
+                    indices.clone(),
+                    value.primitive.clone(),
+                    reduction,
+                    deterministic,
+                );
+
+                prep.finish(
+                    (
+                        dim,
+                        indices,
+                        tensor.primitive,
+                        value.primitive,
+                        output.clone(),
+                        reduction,
+                    ),
+                    output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_scatter_reduce(
+                dim,
+                tensor.primitive,
+                indices,
+                value.primitive,
+                reduction,
+                deterministic,
+            )),
+        }
+    }
+
     fn float_select<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -2430,6 +2554,480 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         B::float_argsort(tensor.primitive, dim, descending)
     }
 
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_bits<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<B, 2> {
+        B::float_bits(tensor.primitive)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Cholesky;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Cholesky {
+            type State = FloatTensor<B, D>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let lower = ops.state;
+
+                // The gradient of the symmetric input is sym(L^-T phi(L^T grad) L^-1), where phi
+                // keeps the lower triangle and halves the diagonal. The upper triangle of the
+                // factor is constant, so its gradient is ignored.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let shape = B::float_shape(&grad);
+                    let device = B::float_device(&grad);
+                    let tril = Tensor::<B, D, Bool>::tril_mask(shape.clone(), 0, &device);
+                    let diag = Tensor::<B, D, Bool>::diag_mask(shape, 0, &device);
+                    let tril = tril.into_primitive();
+
+                    let grad = B::float_mask_fill(grad, tril.clone(), 0.elem());
+                    let phi = B::float_matmul(B::float_transpose(lower.clone()), grad);
+                    let phi = B::float_mask_fill(phi, tril, 0.elem());
+                    let half_diagonal = B::float_mul_scalar(
+                        B::float_mask_fill(phi.clone(), diag.into_primitive(), 0.elem()),
+                        0.5.elem(),
+                    );
+                    let phi = B::float_sub(phi, half_diagonal);
+
+                    let inverse = B::float_inverse(lower);
+                    let grad = B::float_matmul(
+                        B::float_matmul(B::float_transpose(inverse.clone()), phi),
+                        inverse,
+                    );
+                    let grad = B::float_add(grad.clone(), B::float_transpose(grad));
+
+                    B::float_mul_scalar(grad, 0.5.elem())
+                });
+            }
+        }
+
+        match Cholesky
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_cholesky(tensor.primitive);
+                prep.finish(output.clone(), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cholesky(tensor.primitive)),
+        }
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_solve<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Solve;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Solve {
+            type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (lhs, output) = ops.state;
+                let [node_lhs, node_rhs] = ops.parents;
+                let grad = grads.consume::<B, D>(&ops.node);
+
+                // The gradient of the right-hand side solves the transposed system, and the one
+                // of the matrix is its outer product with the solution.
+                let grad_rhs = B::float_solve(B::float_transpose(lhs), grad);
+
+                if let Some(node) = node_lhs {
+                    let grad_lhs = B::float_neg(B::float_matmul(
+                        grad_rhs.clone(),
+                        B::float_transpose(output),
+                    ));
+                    grads.register::<B, D>(node.id, grad_lhs);
+                }
+                if let Some(node) = node_rhs {
+                    grads.register::<B, D>(node.id, grad_rhs);
+                }
+            }
+        }
+
+        match Solve
+            .prepare::<C>([lhs.node, rhs.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_solve(lhs.primitive.clone(), rhs.primitive);
+                prep.finish((lhs.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_solve(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_inverse<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Inverse;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Inverse {
+            type State = FloatTensor<B, D>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let inverse = B::float_transpose(ops.state);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let grad = B::float_matmul(B::float_matmul(inverse.clone(), grad), inverse);
+                    B::float_neg(grad)
+                });
+            }
+        }
+
+        match Inverse
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_inverse(tensor.primitive);
+                prep.finish(output.clone(), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_inverse(tensor.primitive)),
+        }
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_det<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Det;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Det {
+            type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (tensor, det) = ops.state;
+
+                // The gradient of the determinant is the determinant times the inverse transpose.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let inverse = B::float_transpose(B::float_inverse(tensor));
+                    B::float_mul(B::float_mul(grad, det), inverse)
+                });
+            }
+        }
+
+        match Det.prepare::<C>([tensor.node]).compute_bound().stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_det(tensor.primitive.clone());
+                prep.finish((tensor.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_det(tensor.primitive)),
+        }
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_slogdet<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        #[derive(Debug)]
+        struct Slogdet;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Slogdet {
+            type State = FloatTensor<B, D>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let tensor = ops.state;
+
+                // The gradient of the logarithm of the determinant is the inverse transpose.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let inverse = B::float_transpose(B::float_inverse(tensor));
+                    B::float_mul(grad, inverse)
+                });
+            }
+        }
+
+        match Slogdet
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let (sign, logabsdet) = B::float_slogdet(tensor.primitive.clone());
+                let logabsdet = prep.finish(tensor.primitive, logabsdet);
+
+                (AutodiffTensor::new(sign), logabsdet)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (sign, logabsdet) = B::float_slogdet(tensor.primitive);
+
+                (AutodiffTensor::new(sign), prep.finish(logabsdet))
+            }
+        }
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_pinverse<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        rcond: f64,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Pinverse;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Pinverse {
+            type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (tensor, pinverse) = ops.state;
+
+                // The gradient of the pseudo-inverse X of A with a constant rank is
+                // -X^T G X^T + (I - A X) G^T X X^T + X^T X G^T (I - X A).
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let pinverse_t = B::float_transpose(pinverse.clone());
+                    let grad_t = B::float_transpose(grad.clone());
+
+                    let inverse = B::float_neg(B::float_matmul(
+                        B::float_matmul(pinverse_t.clone(), grad),
+                        pinverse_t.clone(),
+                    ));
+
+                    let left = B::float_matmul(
+                        grad_t.clone(),
+                        B::float_matmul(pinverse.clone(), pinverse_t.clone()),
+                    );
+                    let left = B::float_sub(
+                        left.clone(),
+                        B::float_matmul(tensor.clone(), B::float_matmul(pinverse.clone(), left)),
+                    );
+
+                    let right =
+                        B::float_matmul(B::float_matmul(pinverse_t, pinverse.clone()), grad_t);
+                    let right = B::float_sub(
+                        right.clone(),
+                        B::float_matmul(B::float_matmul(right, pinverse), tensor),
+                    );
+
+                    B::float_add(B::float_add(inverse, left), right)
+                });
+            }
+        }
+
+        match Pinverse
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_pinverse(tensor.primitive.clone(), rcond);
+                prep.finish((tensor.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_pinverse(tensor.primitive, rcond)),
+        }
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        // The gradient is linear in the gradients of both factors, so each factor is the output
+        // of its own node, the gradients of the input being summed.
+        #[derive(Debug)]
+        struct QrQ;
+        #[derive(Debug)]
+        struct QrR;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for QrQ {
+            type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (q, r) = ops.state;
+
+                // (grad_q + Q tril(Q^T grad_q - grad_q^T Q) - Q Q^T grad_q) R^-T
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let qt_grad = B::float_matmul(B::float_transpose(q.clone()), grad.clone());
+                    let skew = B::float_sub(qt_grad.clone(), B::float_transpose(qt_grad.clone()));
+                    let grad = B::float_add(
+                        grad,
+                        B::float_matmul(q.clone(), B::float_sub(qr_tril::<B, D>(skew), qt_grad)),
+                    );
+
+                    qr_solve_r_transposed::<B, D>(r, grad)
+                });
+            }
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for QrR {
+            type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (q, r) = ops.state;
+
+                // Q (grad_r + tril(R grad_r^T - grad_r R^T) R^-T)
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let r_grad = B::float_matmul(r.clone(), B::float_transpose(grad.clone()));
+                    let skew = B::float_sub(r_grad.clone(), B::float_transpose(r_grad));
+                    let tril = qr_solve_r_transposed::<B, D>(r, qr_tril::<B, D>(skew));
+
+                    B::float_matmul(q, B::float_add(grad, tril))
+                });
+            }
+        }
+
+        let (q, r) = B::float_qr(tensor.primitive);
+        let state = (q.clone(), r.clone());
+
+        let q = match QrQ
+            .prepare::<C>([tensor.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(state.clone(), q),
+            OpsKind::UnTracked(prep) => prep.finish(q),
+        };
+        let r = match QrR.prepare::<C>([tensor.node]).compute_bound().stateful() {
+            OpsKind::Tracked(prep) => prep.finish(state, r),
+            OpsKind::UnTracked(prep) => prep.finish(r),
+        };
+
+        (q, r)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        let (u, s, vt) = B::float_svd(tensor.primitive);
+        (
+            AutodiffTensor::new(u),
+            AutodiffTensor::new(s),
+            AutodiffTensor::new(vt),
+        )
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        // The gradient is linear in the gradients of both outputs, so each output is the output
+        // of its own node, the gradients of the input being summed.
+        #[derive(Debug)]
+        struct EighValues;
+        #[derive(Debug)]
+        struct EighVectors;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for EighValues {
+            type State = FloatTensor<B, D>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let vectors = ops.state;
+
+                // V diag(grad) V^T
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let scaled = B::float_mul(vectors.clone(), grad);
+                    B::float_matmul(scaled, B::float_transpose(vectors))
+                });
+            }
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for EighVectors {
+            type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (values, vectors) = ops.state;
+
+                // sym(V (F * V^T grad) V^T), where F[i, j] = 1 / (values[j] - values[i]) outside
+                // of the diagonal, which is only defined for distinct eigenvalues.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let shape = B::float_shape(&vectors);
+                    let device = B::float_device(&vectors);
+                    let diag = Tensor::<B, D, Bool>::diag_mask(shape, 0, &device).into_primitive();
+
+                    let gaps = B::float_sub(values.clone(), B::float_transpose(values));
+                    let gaps = B::float_mask_fill(gaps, diag.clone(), 1.elem());
+                    let factors = B::float_mask_fill(B::float_recip(gaps), diag, 0.elem());
+
+                    let inner = B::float_matmul(B::float_transpose(vectors.clone()), grad);
+                    let inner = B::float_mul(factors, inner);
+                    let grad = B::float_matmul(
+                        B::float_matmul(vectors.clone(), inner),
+                        B::float_transpose(vectors),
+                    );
+                    let grad = B::float_add(grad.clone(), B::float_transpose(grad));
+
+                    B::float_mul_scalar(grad, 0.5.elem())
+                });
+            }
+        }
+
+        let (values, vectors) = B::float_eigh(tensor.primitive);
+
+        let values_output = match EighValues
+            .prepare::<C>([tensor.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(vectors.clone(), values.clone()),
+            OpsKind::UnTracked(prep) => prep.finish(values.clone()),
+        };
+        let vectors = match EighVectors
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((values, vectors.clone()), vectors),
+            OpsKind::UnTracked(prep) => prep.finish(vectors),
+        };
+
+        (values_output, vectors)
+    }
+
     fn float_repeat<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -2529,3 +3127,34 @@ impl<const D: usize> BinaryOpsBroadcast<D> {
         }
     }
 }
+
+/// Keeps the lower triangle of the matrices, diagonal included.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn qr_tril<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let shape = B::float_shape(&tensor);
+    let device = B::float_device(&tensor);
+    let upper = Tensor::<B, D, Bool>::triu_mask(shape, 1, &device);
+
+    B::float_mask_fill(tensor, upper.into_primitive(), 0.elem())
+}
+
+/// Computes `tensor R^-T` with the square factor `R` of a QR decomposition.
+///
+/// # Panics
+///
+/// If the factor isn't square, the gradient of the QR decomposition of matrices with more columns
+/// than rows not being supported.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn qr_solve_r_transposed<B: Backend, const D: usize>(
+    r: FloatTensor<B, D>,
+    tensor: FloatTensor<B, D>,
+) -> FloatTensor<B, D> {
+    let dims = B::float_shape(&r).dims;
+    assert_eq!(
+        dims[D - 2],
+        dims[D - 1],
+        "The gradient of the QR decomposition is only supported for matrices with at least as many rows as columns."
+    );
+
+    B::float_transpose(B::float_solve(r, B::float_transpose(tensor)))
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(ad_cross_entropy_loss)]
 mod tests {
     use super::*;
-    use burn_tensor::{loss, Data, Tensor};
+    use burn_tensor::{loss, Data, Int, Tensor};
 
     #[test]
     fn test_cross_entropy_loss_grad() {
@@ -29,4 +29,24 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[-1.3486, 1.3486], [-2.0637, 2.0637]]), 3);
     }
+
+    #[test]
+    fn test_cross_entropy_with_targets_grad() {
+        let data_logits = Data::from([[1.0, 2.0, 3.0], [0.5, -1.0, 2.0]]);
+        let data_targets = Data::from([0, 2]);
+
+        let device = Default::default();
+        let logits =
+            Tensor::<TestAutodiffBackend, 2>::from_data(data_logits, &device).require_grad();
+        let targets = Tensor::<TestAutodiffBackend, 1, Int>::from_data(data_targets, &device);
+
+        let loss = loss::cross_entropy(logits.clone(), targets);
+        let grads = loss.sum().backward();
+        let grad = logits.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq(
+            &Data::from([[-0.90997, 0.24473, 0.66524], [0.17529, 0.03911, -0.21440]]),
+            3,
+        );
+    }
 }
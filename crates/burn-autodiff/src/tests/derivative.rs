@@ -0,0 +1,76 @@
+#[burn_tensor_testgen::testgen(ad_derivative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{derivative, Data, Tensor};
+
+    type NestedAutodiffBackend = burn_autodiff::Autodiff<TestAutodiffBackend>;
+
+    #[test]
+    fn should_compute_batched_gradient() {
+        let device = Default::default();
+        let inputs = TestAutodiffTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device)
+            .require_grad();
+
+        let outputs = inputs.clone().mul(inputs.clone()).sum_dim(1);
+        let gradients = derivative::gradient(outputs, &inputs);
+
+        gradients
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 4.0], [6.0, 8.0]]), 4);
+    }
+
+    #[test]
+    fn should_compute_batched_jacobian() {
+        let device = Default::default();
+        let inputs = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device);
+
+        let jacobian = derivative::jacobian::<TestAutodiffBackend, _>(
+            |inputs| inputs.clone().mul(inputs).mul_scalar(0.5),
+            inputs,
+        );
+
+        jacobian.to_data().assert_approx_eq(
+            &Data::from([[[1.0, 0.0], [0.0, 2.0]], [[3.0, 0.0], [0.0, 4.0]]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn should_compute_batched_hessian() {
+        let device = Default::default();
+        let inputs = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device);
+
+        // f(x, y) = x^2 y
+        let hessian = derivative::hessian::<NestedAutodiffBackend, _>(
+            |inputs: Tensor<NestedAutodiffBackend, 2>| {
+                let x = inputs.clone().narrow(1, 0, 1);
+                let y = inputs.narrow(1, 1, 1);
+                x.clone().mul(x).mul(y)
+            },
+            inputs,
+        );
+
+        hessian.to_data().assert_approx_eq(
+            &Data::from([[[4.0, 2.0], [2.0, 0.0]], [[8.0, 6.0], [6.0, 0.0]]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn should_compute_batched_laplacian() {
+        let device = Default::default();
+        let inputs = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device);
+
+        // f(x, y) = x^3 + y^3
+        let laplacian = derivative::laplacian::<NestedAutodiffBackend, _>(
+            |inputs: Tensor<NestedAutodiffBackend, 2>| {
+                inputs.clone().mul(inputs.clone()).mul(inputs).sum_dim(1)
+            },
+            inputs,
+        );
+
+        laplacian
+            .to_data()
+            .assert_approx_eq(&Data::from([[18.0], [42.0]]), 4);
+    }
+}
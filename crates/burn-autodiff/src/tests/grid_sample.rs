@@ -0,0 +1,29 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample_2d;
+    use burn_tensor::ops::GridSampleOptions;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_grid_sample_2d() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_data(Data::from([[[[0.0, 1.0], [2.0, 3.0]]]]), &device)
+            .require_grad();
+        let grid =
+            TestAutodiffTensor::from_data(Data::from([[[[0.0, 0.0]]]]), &device).require_grad();
+
+        let output = grid_sample_2d(x.clone(), grid.clone(), GridSampleOptions::default());
+        let grads = output.backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        let grid_grad = grid.grad(&grads).unwrap();
+
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[0.25, 0.25], [0.25, 0.25]]]]), 3);
+        grid_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[1.0, 2.0]]]]), 3);
+    }
+}
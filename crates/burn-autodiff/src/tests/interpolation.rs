@@ -0,0 +1,43 @@
+#[burn_tensor_testgen::testgen(ad_interpolation)]
+mod tests {
+    use super::*;
+    use burn_tensor::{interpolation, Data};
+
+    #[test]
+    fn should_diff_interp1d() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_data(Data::from([0.25, 2.0]), &device).require_grad();
+        let xp = TestAutodiffTensor::from_data(Data::from([0.0, 1.0, 3.0]), &device);
+        let fp = TestAutodiffTensor::from_data(Data::from([0.0, 2.0, 6.0]), &device).require_grad();
+
+        let output = interpolation::interp1d(x.clone(), xp, fp.clone());
+        let grads = output.sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        let fp_grad = fp.grad(&grads).unwrap();
+        // Each point has the slope of its interval and splits its gradient between its knots.
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([2.0, 2.0]), 4);
+        fp_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([0.75, 0.75, 0.5]), 4);
+    }
+
+    #[test]
+    fn should_diff_cubic_spline() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_data(Data::from([0.5, 1.5]), &device);
+        let xp = TestAutodiffTensor::from_data(Data::from([0.0, 1.0, 2.0]), &device);
+        let fp = TestAutodiffTensor::from_data(Data::from([0.0, 1.0, 0.0]), &device).require_grad();
+
+        let output = interpolation::cubic_spline(x, xp, fp.clone());
+        let grads = output.sum().backward();
+
+        // The spline is linear in the values, 0.6875 at both points for the middle knot.
+        let fp_grad = fp.grad(&grads).unwrap();
+        fp_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([0.3125, 1.375, 0.3125]), 4);
+    }
+}
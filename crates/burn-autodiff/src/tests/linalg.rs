@@ -0,0 +1,155 @@
+#[burn_tensor_testgen::testgen(ad_linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_diff_cholesky() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(Data::from([[4.0, 2.0], [2.0, 5.0]]), &device)
+            .require_grad();
+
+        let lower = linalg::cholesky(tensor.clone());
+        let grads = lower.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        // The gradient of the symmetric input splits the off-diagonal gradient evenly.
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.1875, 0.125], [0.125, 0.25]]), 3);
+    }
+
+    #[test]
+    fn should_diff_solve() {
+        let device = Default::default();
+        let lhs = TestAutodiffTensor::from_data(Data::from([[3.0, 1.0], [1.0, 2.0]]), &device)
+            .require_grad();
+        let rhs = TestAutodiffTensor::from_data(Data::from([[9.0], [8.0]]), &device).require_grad();
+
+        let solution = linalg::solve(lhs.clone(), rhs.clone());
+        let grads = solution.sum().backward();
+
+        let grad_lhs = lhs.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+        grad_lhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.4, -0.6], [-0.8, -1.2]]), 3);
+        grad_rhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.2], [0.4]]), 3);
+    }
+
+    #[test]
+    fn should_diff_inverse() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(Data::from([[4.0, 7.0], [2.0, 6.0]]), &device)
+            .require_grad();
+
+        let inverse = linalg::inverse(tensor.clone());
+        let grads = inverse.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.04, -0.08], [-0.03, 0.06]]), 3);
+    }
+
+    #[test]
+    fn should_diff_det() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device)
+            .require_grad();
+
+        let det = linalg::det(tensor.clone());
+        let grads = det.sum().backward();
+
+        // The gradient is the cofactor matrix.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[4.0, -3.0], [-2.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_slogdet() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device)
+            .require_grad();
+
+        let (_sign, logabsdet) = linalg::slogdet(tensor.clone());
+        let grads = logabsdet.sum().backward();
+
+        // The gradient is the inverse transpose.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[-2.0, 1.5], [1.0, -0.5]]), 3);
+    }
+
+    #[test]
+    fn should_diff_pinverse() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(Data::from([[4.0, 7.0], [2.0, 6.0]]), &device)
+            .require_grad();
+
+        let pinverse = linalg::pinverse(tensor.clone(), 1e-6);
+        let grads = pinverse.sum().backward();
+
+        // The pseudo-inverse of an invertible matrix has the gradient of its inverse.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.04, -0.08], [-0.03, 0.06]]), 3);
+    }
+
+    #[test]
+    fn should_diff_matrix_exp() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(Data::from([[1.0, 0.0], [0.0, 2.0]]), &device)
+            .require_grad();
+
+        let exp = linalg::matrix_exp(tensor.clone());
+        let grads = exp.sum().backward();
+
+        // The off-diagonal gradients are the divided differences of the exponential.
+        let (e1, e2) = (1.0f32.exp(), 2.0f32.exp());
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[e1, e2 - e1], [e2 - e1, e2]]), 2);
+    }
+
+    #[test]
+    fn should_diff_qr() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(
+            Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]),
+            &device,
+        )
+        .require_grad();
+
+        let (q, r) = linalg::qr(tensor.clone());
+        let grads = (q.sum() + r.sum()).backward();
+
+        // The gradients of both factors are summed.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data().assert_approx_eq(
+            &Data::from([[-0.9383, 1.0661], [0.1664, 0.7831], [1.2710, 0.5001]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_eigh() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(Data::from([[2.0, 1.0], [1.0, 3.0]]), &device)
+            .require_grad();
+        let weights_values = TestAutodiffTensor::from_data(Data::from([[1.0, 2.0]]), &device);
+        let weights_vectors =
+            TestAutodiffTensor::from_data(Data::from([[1.0, 2.0], [4.0, 3.0]]), &device);
+
+        let (values, vectors) = linalg::eigh(tensor.clone());
+        // The squared eigenvectors don't depend on their signs.
+        let loss =
+            (values * weights_values).sum() + (vectors.powf_scalar(2.0) * weights_vectors).sum();
+        let grads = loss.backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[1.6342, 0.6261], [0.6261, 1.3658]]), 3);
+    }
+}
@@ -19,6 +19,7 @@ mod conv_transpose1d;
 mod conv_transpose2d;
 mod cos;
 mod cross_entropy;
+mod derivative;
 mod div;
 mod erf;
 mod exp;
@@ -27,6 +28,9 @@ mod flip;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod grid_sample;
+mod interpolation;
+mod linalg;
 mod log;
 mod log1p;
 mod log_sigmoid;
@@ -41,12 +45,15 @@ mod multithread;
 mod nearest_interpolate;
 mod neg;
 mod nonzero;
+mod offload;
 mod permute;
 mod pow;
 mod recip;
 mod relu;
 mod repeat;
 mod reshape;
+mod roi_align;
+mod scatter_reduce;
 mod select;
 mod sigmoid;
 mod sign;
@@ -71,6 +78,7 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_bridge!();
         burn_autodiff::testgen_checkpoint!();
         burn_autodiff::testgen_memory_management!();
+        burn_autodiff::testgen_offload!();
 
         // Activation
         burn_autodiff::testgen_ad_relu!();
@@ -89,6 +97,11 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
         burn_autodiff::testgen_module_backward!();
         burn_autodiff::testgen_ad_nearest_interpolate!();
+        burn_autodiff::testgen_ad_grid_sample!();
+        burn_autodiff::testgen_ad_roi_align!();
+        burn_autodiff::testgen_ad_linalg!();
+        burn_autodiff::testgen_ad_interpolation!();
+        burn_autodiff::testgen_ad_derivative!();
 
         // Tensor
         burn_autodiff::testgen_ad_complex!();
@@ -104,6 +117,7 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_exp!();
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
+        burn_autodiff::testgen_ad_scatter_reduce!();
         burn_autodiff::testgen_ad_select!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
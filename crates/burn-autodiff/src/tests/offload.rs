@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(offload)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_offloaded_activation() {
+        let (grad_1, grad_2) = grads_of_activation(false);
+        let (grad_1_offloaded, grad_2_offloaded) = grads_of_activation(true);
+
+        grad_1_offloaded.assert_approx_eq(&grad_1, 3);
+        grad_2_offloaded.assert_approx_eq(&grad_2, 3);
+    }
+
+    #[test]
+    fn should_not_track_offloaded_tensor_without_grad() {
+        let data = Data::<f32, 2>::from([[1.0, 7.0], [-2.0, -3.0]]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(data.clone(), &device);
+
+        tensor.offload().into_data().assert_approx_eq(&data, 3);
+    }
+
+    fn grads_of_activation(offload: bool) -> (Data<f32, 2>, Data<f32, 2>) {
+        let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [-2.0, -3.0]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, -7.0], [2.0, 3.0]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data_1, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let mut activation = tensor_1.clone().exp();
+        if offload {
+            activation = activation.offload();
+        }
+        let grads = activation.matmul(tensor_2.clone()).backward();
+
+        (
+            tensor_1.grad(&grads).unwrap().to_data().convert(),
+            tensor_2.grad(&grads).unwrap().to_data().convert(),
+        )
+    }
+}
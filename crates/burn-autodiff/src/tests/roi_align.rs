@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(ad_roi_align)]
+mod tests {
+    use super::*;
+    use burn_tensor::{vision, Data};
+
+    #[test]
+    fn should_diff_roi_align() {
+        let device = Default::default();
+        let features = TestAutodiffTensor::from_data(
+            Data::from([[[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]]]),
+            &device,
+        )
+        .require_grad();
+        let rois = TestAutodiffTensor::from_data(Data::from([[0.0, 0.0, 0.0, 2.0, 2.0]]), &device);
+
+        // A single sample at (0.5, 0.5) interpolates the top-left pixels equally.
+        let output = vision::roi_align(features.clone(), rois, [1, 1], 1.0, 1, true);
+        let grads = output.backward();
+
+        let features_grad = features.grad(&grads).unwrap();
+
+        features_grad.to_data().assert_approx_eq(
+            &Data::from([[[[0.25, 0.25, 0.0], [0.25, 0.25, 0.0], [0.0, 0.0, 0.0]]]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(ad_scatter_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ops::ScatterReduction, Data, Int, Tensor};
+
+    #[test]
+    fn test_scatter_reduce_sum_grad() {
+        let (grad_tensor, grad_values) = scatter_reduce_grads(ScatterReduction::Sum);
+
+        grad_tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0], [3.0, 4.0]]), 3);
+        grad_values
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 4.0], [1.0, 2.0], [3.0, 4.0]]), 3);
+    }
+
+    #[test]
+    fn test_scatter_reduce_mean_grad() {
+        let (grad_tensor, grad_values) = scatter_reduce_grads(ScatterReduction::Mean);
+
+        grad_tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.3333, 1.0], [1.5, 1.3333]]), 3);
+        grad_values.into_data().assert_approx_eq(
+            &Data::from([[0.3333, 1.3333], [0.3333, 1.0], [1.5, 1.3333]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_scatter_reduce_max_grad() {
+        let (grad_tensor, grad_values) = scatter_reduce_grads(ScatterReduction::Max);
+
+        grad_tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 2.0], [3.0, 0.0]]), 3);
+        grad_values
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [1.0, 0.0], [0.0, 4.0]]), 3);
+    }
+
+    fn scatter_reduce_grads(reduction: ScatterReduction) -> (TestTensor<2>, TestTensor<2>) {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(Data::from([[1.0, 5.0], [2.0, 0.0]]), &device)
+            .require_grad();
+        let values = TestAutodiffTensor::from_data(
+            Data::from([[3.0, 1.0], [4.0, 2.0], [0.0, 6.0]]),
+            &device,
+        )
+        .require_grad();
+        let indices = Tensor::<TestAutodiffBackend, 2, Int>::from_data(
+            Data::from([[0, 1], [0, 0], [1, 1]]),
+            &device,
+        );
+        let weights = TestAutodiffTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]), &device);
+
+        let output = tensor
+            .clone()
+            .scatter_reduce(0, indices, values.clone(), reduction);
+        let grads = output.mul(weights).sum().backward();
+
+        (tensor.grad(&grads).unwrap(), values.grad(&grads).unwrap())
+    }
+}
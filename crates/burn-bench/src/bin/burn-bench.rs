@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+
+use burn_bench::BenchmarkSuite;
+use burn_core::tensor::backend::Backend;
+use clap::Parser;
+
+/// Run the standard benchmarks on the backend selected with the crate features.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// The JSON file where the results are saved.
+    #[arg(short, long, default_value = "burn-bench.json")]
+    output: PathBuf,
+    /// Only run the benchmarks whose name or variant contains the filter.
+    #[arg(short, long)]
+    filter: Option<String>,
+}
+
+#[allow(dead_code)]
+fn run<B: Backend>(device: B::Device, args: &Args) {
+    let mut suite = BenchmarkSuite::<B>::new(device);
+    if let Some(filter) = &args.filter {
+        suite = suite.with_filter(filter.as_str());
+    }
+
+    suite.run_standard();
+    suite
+        .save(&args.output)
+        .expect("The benchmark results should be saved");
+
+    println!("Results saved in {}", args.output.display());
+}
+
+fn main() {
+    let args = Args::parse();
+
+    #[cfg(feature = "wgpu")]
+    {
+        use burn_core::backend::wgpu::{AutoGraphicsApi, Wgpu, WgpuDevice};
+
+        run::<Wgpu<AutoGraphicsApi, f32, i32>>(WgpuDevice::default(), &args);
+    }
+
+    #[cfg(all(feature = "ndarray", not(feature = "wgpu")))]
+    {
+        use burn_core::backend::{ndarray::NdArrayDevice, NdArray};
+
+        run::<NdArray>(NdArrayDevice::Cpu, &args);
+    }
+
+    #[cfg(not(any(feature = "ndarray", feature = "wgpu")))]
+    {
+        let _ = args;
+        panic!("A backend feature should be enabled, e.g. `ndarray` or `wgpu`");
+    }
+}
@@ -0,0 +1,81 @@
+use burn_common::{benchmark::Benchmark, sync_type::SyncType};
+use burn_core::tensor::{backend::Backend, module::conv2d, ops::ConvOptions, Distribution, Tensor};
+
+/// Benchmark of a 2D convolution with a bias.
+#[derive(new)]
+pub struct Conv2dBenchmark<B: Backend> {
+    name: &'static str,
+    shape_input: [usize; 4],
+    shape_weight: [usize; 4],
+    options: ConvOptions<2>,
+    device: B::Device,
+}
+
+impl<B: Backend> Conv2dBenchmark<B> {
+    /// The standard convolutions, taken from a ResNet: the stem, a 3x3 block, a pointwise
+    /// projection and a depthwise convolution.
+    pub fn standard(device: &B::Device) -> Vec<Self> {
+        vec![
+            Self::new(
+                "stem-7x7",
+                [16, 3, 224, 224],
+                [64, 3, 7, 7],
+                ConvOptions::new([2, 2], [3, 3], [1, 1], 1),
+                device.clone(),
+            ),
+            Self::new(
+                "block-3x3",
+                [16, 64, 56, 56],
+                [64, 64, 3, 3],
+                ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+                device.clone(),
+            ),
+            Self::new(
+                "pointwise-1x1",
+                [16, 256, 14, 14],
+                [1024, 256, 1, 1],
+                ConvOptions::new([1, 1], [0, 0], [1, 1], 1),
+                device.clone(),
+            ),
+            Self::new(
+                "depthwise-3x3",
+                [16, 128, 28, 28],
+                [128, 1, 3, 3],
+                ConvOptions::new([1, 1], [1, 1], [1, 1], 128),
+                device.clone(),
+            ),
+        ]
+    }
+}
+
+impl<B: Backend> Benchmark for Conv2dBenchmark<B> {
+    type Args = (Tensor<B, 4>, Tensor<B, 4>, Tensor<B, 1>);
+
+    fn name(&self) -> String {
+        "conv2d".into()
+    }
+
+    fn options(&self) -> Option<String> {
+        Some(self.name.into())
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![self.shape_input.into(), self.shape_weight.into()]
+    }
+
+    fn execute(&self, (x, weight, bias): Self::Args) {
+        conv2d(x, weight, Some(bias), self.options.clone());
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let x = Tensor::random(self.shape_input, Distribution::Default, &self.device);
+        let weight = Tensor::random(self.shape_weight, Distribution::Default, &self.device);
+        let bias = Tensor::random([self.shape_weight[0]], Distribution::Default, &self.device);
+
+        (x, weight, bias)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device, SyncType::Wait)
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use burn_common::{benchmark::Benchmark, sync_type::SyncType};
+use burn_core::{
+    data::{
+        dataloader::{batcher::Batcher, DataLoader, DataLoaderBuilder},
+        dataset::InMemDataset,
+    },
+    tensor::{backend::Backend, Data, Shape, Tensor},
+};
+
+/// Benchmark of a full iteration over a data loader batching in-memory items into tensors.
+#[derive(new)]
+pub struct DataLoaderBenchmark<B: Backend> {
+    name: &'static str,
+    num_items: usize,
+    item_size: usize,
+    batch_size: usize,
+    num_workers: Option<usize>,
+    device: B::Device,
+}
+
+impl<B: Backend> DataLoaderBenchmark<B> {
+    /// The standard data loaders: a single-threaded one, and one with four workers.
+    pub fn standard(device: &B::Device) -> Vec<Self> {
+        vec![
+            Self::new("single-thread", 4096, 3072, 64, None, device.clone()),
+            Self::new("workers-4", 4096, 3072, 64, Some(4), device.clone()),
+        ]
+    }
+}
+
+#[derive(new, Clone)]
+struct ItemBatcher<B: Backend> {
+    device: B::Device,
+}
+
+impl<B: Backend> Batcher<Vec<f32>, Tensor<B, 2>> for ItemBatcher<B> {
+    fn batch(&self, items: Vec<Vec<f32>>) -> Tensor<B, 2> {
+        let shape = Shape::new([items.len(), items[0].len()]);
+        let values = items.into_iter().flatten().collect();
+
+        Tensor::from_data(Data::new(values, shape).convert(), &self.device)
+    }
+}
+
+impl<B: Backend> Benchmark for DataLoaderBenchmark<B> {
+    type Args = Arc<dyn DataLoader<Tensor<B, 2>>>;
+
+    fn name(&self) -> String {
+        "dataloader".into()
+    }
+
+    fn options(&self) -> Option<String> {
+        Some(self.name.into())
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![vec![self.num_items, self.item_size]]
+    }
+
+    fn num_samples(&self) -> usize {
+        5
+    }
+
+    fn execute(&self, dataloader: Self::Args) {
+        for _batch in dataloader.iter() {}
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let items = (0..self.num_items)
+            .map(|i| vec![i as f32; self.item_size])
+            .collect();
+        let builder = DataLoaderBuilder::new(ItemBatcher::<B>::new(self.device.clone()))
+            .batch_size(self.batch_size);
+        let builder = match self.num_workers {
+            Some(num_workers) => builder.num_workers(num_workers),
+            None => builder,
+        };
+
+        builder.build(InMemDataset::new(items))
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device, SyncType::Wait)
+    }
+}
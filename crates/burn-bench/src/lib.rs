@@ -0,0 +1,19 @@
+#![warn(missing_docs)]
+
+//! Standardized microbenchmarks runnable against any Burn backend, producing JSON results for
+//! regression tracking and device comparisons.
+
+#[macro_use]
+extern crate derive_new;
+
+mod conv;
+mod dataloader;
+mod matmul;
+mod suite;
+mod transformer;
+
+pub use conv::*;
+pub use dataloader::*;
+pub use matmul::*;
+pub use suite::*;
+pub use transformer::*;
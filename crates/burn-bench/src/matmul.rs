@@ -0,0 +1,71 @@
+use burn_common::{benchmark::Benchmark, sync_type::SyncType};
+use burn_core::tensor::{backend::Backend, Distribution, Tensor};
+
+/// Benchmark of a batched matrix multiplication.
+#[derive(new)]
+pub struct MatmulBenchmark<B: Backend> {
+    name: &'static str,
+    shape_lhs: [usize; 3],
+    shape_rhs: [usize; 3],
+    device: B::Device,
+}
+
+impl<B: Backend> MatmulBenchmark<B> {
+    /// The standard matrix multiplications: square matrices of increasing sizes, many small
+    /// batched matrices and a matrix-vector product.
+    pub fn standard(device: &B::Device) -> Vec<Self> {
+        vec![
+            Self::new("square-256", [1, 256, 256], [1, 256, 256], device.clone()),
+            Self::new(
+                "square-1024",
+                [1, 1024, 1024],
+                [1, 1024, 1024],
+                device.clone(),
+            ),
+            Self::new(
+                "square-2048",
+                [1, 2048, 2048],
+                [1, 2048, 2048],
+                device.clone(),
+            ),
+            Self::new("batched", [64, 128, 64], [64, 64, 128], device.clone()),
+            Self::new(
+                "matrix-vector",
+                [1, 4096, 4096],
+                [1, 4096, 1],
+                device.clone(),
+            ),
+        ]
+    }
+}
+
+impl<B: Backend> Benchmark for MatmulBenchmark<B> {
+    type Args = (Tensor<B, 3>, Tensor<B, 3>);
+
+    fn name(&self) -> String {
+        "matmul".into()
+    }
+
+    fn options(&self) -> Option<String> {
+        Some(self.name.into())
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![self.shape_lhs.into(), self.shape_rhs.into()]
+    }
+
+    fn execute(&self, (lhs, rhs): Self::Args) {
+        lhs.matmul(rhs);
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let lhs = Tensor::random(self.shape_lhs, Distribution::Default, &self.device);
+        let rhs = Tensor::random(self.shape_rhs, Distribution::Default, &self.device);
+
+        (lhs, rhs)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device, SyncType::Wait)
+    }
+}
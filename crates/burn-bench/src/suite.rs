@@ -0,0 +1,193 @@
+use std::{fs, io, path::Path};
+
+use burn_common::benchmark::{run_benchmark, Benchmark, BenchmarkResult};
+use burn_core::tensor::backend::Backend;
+use serde::{Deserialize, Serialize};
+
+use crate::{Conv2dBenchmark, DataLoaderBenchmark, MatmulBenchmark, TransformerBlockBenchmark};
+
+/// The result of a benchmark, flattened so it can be easily queried once saved in JSON.
+///
+/// The durations are in microseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkRecord {
+    /// The name of the backend.
+    pub backend: String,
+    /// The device the benchmark was executed on.
+    pub device: String,
+    /// The git commit hash of the run.
+    pub git_hash: String,
+    /// The name of the benchmark.
+    pub name: String,
+    /// The variant of the benchmark.
+    pub options: Option<String>,
+    /// The shapes of the inputs.
+    pub shapes: Vec<Vec<usize>>,
+    /// The number of measured executions.
+    pub num_samples: usize,
+    /// The mean duration.
+    pub mean: u128,
+    /// The median duration.
+    pub median: u128,
+    /// The variance of the durations.
+    pub variance: u128,
+    /// The minimum duration.
+    pub min: u128,
+    /// The maximum duration.
+    pub max: u128,
+    /// The time just before the run, in milliseconds since the Unix epoch.
+    pub timestamp: u128,
+}
+
+/// Runs benchmarks on a device and collects their results.
+pub struct BenchmarkSuite<B: Backend> {
+    device: B::Device,
+    filter: Option<String>,
+    records: Vec<BenchmarkRecord>,
+}
+
+impl<B: Backend> BenchmarkSuite<B> {
+    /// Create a suite running the benchmarks on the given device.
+    pub fn new(device: B::Device) -> Self {
+        Self {
+            device,
+            filter: None,
+            records: Vec::new(),
+        }
+    }
+
+    /// Only run the benchmarks whose name or variant contains the filter.
+    pub fn with_filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Run the standard benchmarks: [matmul](MatmulBenchmark), [conv2d](Conv2dBenchmark),
+    /// [transformer](TransformerBlockBenchmark) and [dataloader](DataLoaderBenchmark).
+    pub fn run_standard(&mut self) {
+        let device = self.device.clone();
+
+        MatmulBenchmark::<B>::standard(&device)
+            .into_iter()
+            .for_each(|benchmark| self.run(benchmark));
+        Conv2dBenchmark::<B>::standard(&device)
+            .into_iter()
+            .for_each(|benchmark| self.run(benchmark));
+        TransformerBlockBenchmark::<B>::standard(&device)
+            .into_iter()
+            .for_each(|benchmark| self.run(benchmark));
+        DataLoaderBenchmark::<B>::standard(&device)
+            .into_iter()
+            .for_each(|benchmark| self.run(benchmark));
+    }
+
+    /// Run a benchmark, unless excluded by the filter.
+    pub fn run<BM: Benchmark>(&mut self, benchmark: BM) {
+        if !self.is_selected(&benchmark) {
+            return;
+        }
+
+        let result = run_benchmark(benchmark);
+        println!("{result}");
+
+        self.records.push(self.record(result));
+    }
+
+    /// The results of the benchmarks run so far.
+    pub fn records(&self) -> &[BenchmarkRecord] {
+        &self.records
+    }
+
+    /// The results of the benchmarks run so far, in JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.records)
+            .expect("Benchmark records should be serializable")
+    }
+
+    /// Save the results of the benchmarks run so far in a JSON file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+
+    fn is_selected<BM: Benchmark>(&self, benchmark: &BM) -> bool {
+        let filter = match &self.filter {
+            Some(filter) => filter,
+            None => return true,
+        };
+
+        benchmark.name().contains(filter.as_str())
+            || benchmark
+                .options()
+                .is_some_and(|options| options.contains(filter.as_str()))
+    }
+
+    fn record(&self, result: BenchmarkResult) -> BenchmarkRecord {
+        BenchmarkRecord {
+            backend: B::name(),
+            device: format!("{:?}", self.device),
+            git_hash: result.git_hash,
+            name: result.name,
+            options: result.options,
+            shapes: result.shapes,
+            num_samples: result.raw.durations.len(),
+            mean: result.computed.mean.as_micros(),
+            median: result.computed.median.as_micros(),
+            variance: result.computed.variance.as_micros(),
+            min: result.computed.min.as_micros(),
+            max: result.computed.max.as_micros(),
+            timestamp: result.timestamp,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ndarray"))]
+mod tests {
+    use super::*;
+    use burn_core::backend::NdArray;
+
+    #[test]
+    fn should_only_run_the_filtered_benchmarks() {
+        let device = Default::default();
+        let mut suite = BenchmarkSuite::<NdArray>::new(device).with_filter("tiny");
+
+        suite.run(MatmulBenchmark::<NdArray>::new(
+            "tiny",
+            [1, 2, 2],
+            [1, 2, 2],
+            device,
+        ));
+        suite.run(MatmulBenchmark::<NdArray>::new(
+            "small",
+            [1, 4, 4],
+            [1, 4, 4],
+            device,
+        ));
+
+        let records = suite.records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].name, "matmul");
+        assert_eq!(records[0].options.as_deref(), Some("tiny"));
+        assert_eq!(records[0].shapes, vec![vec![1, 2, 2], vec![1, 2, 2]]);
+        assert_eq!(records[0].num_samples, 10);
+    }
+
+    #[test]
+    fn should_serialize_the_records_in_json() {
+        let device = Default::default();
+        let mut suite = BenchmarkSuite::<NdArray>::new(device);
+
+        suite.run(MatmulBenchmark::<NdArray>::new(
+            "tiny",
+            [1, 2, 2],
+            [1, 2, 2],
+            device,
+        ));
+
+        let json = suite.to_json();
+        let records: Vec<BenchmarkRecord> = serde_json::from_str(&json).unwrap();
+
+        assert!(json.contains("\"numSamples\": 10"));
+        assert_eq!(records, suite.records());
+    }
+}
@@ -0,0 +1,63 @@
+use burn_common::{benchmark::Benchmark, sync_type::SyncType};
+use burn_core::{
+    nn::transformer::{TransformerEncoder, TransformerEncoderConfig, TransformerEncoderInput},
+    tensor::{backend::Backend, Distribution, Tensor},
+};
+
+/// Benchmark of the forward pass of a transformer encoder block, without dropout.
+#[derive(new)]
+pub struct TransformerBlockBenchmark<B: Backend> {
+    name: &'static str,
+    batch_size: usize,
+    seq_length: usize,
+    d_model: usize,
+    n_heads: usize,
+    device: B::Device,
+}
+
+impl<B: Backend> TransformerBlockBenchmark<B> {
+    /// The standard transformer blocks: the size of BERT base with short and long sequences.
+    pub fn standard(device: &B::Device) -> Vec<Self> {
+        vec![
+            Self::new("base-128", 32, 128, 768, 12, device.clone()),
+            Self::new("base-512", 8, 512, 768, 12, device.clone()),
+        ]
+    }
+}
+
+impl<B: Backend> Benchmark for TransformerBlockBenchmark<B> {
+    type Args = (TransformerEncoder<B>, Tensor<B, 3>);
+
+    fn name(&self) -> String {
+        "transformer".into()
+    }
+
+    fn options(&self) -> Option<String> {
+        Some(format!("{}-heads-{}", self.name, self.n_heads))
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![vec![self.batch_size, self.seq_length, self.d_model]]
+    }
+
+    fn execute(&self, (block, x): Self::Args) {
+        block.forward(TransformerEncoderInput::new(x));
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let block = TransformerEncoderConfig::new(self.d_model, 4 * self.d_model, self.n_heads, 1)
+            .with_dropout(0.0)
+            .init(&self.device);
+        let x = Tensor::random(
+            [self.batch_size, self.seq_length, self.d_model],
+            Distribution::Default,
+            &self.device,
+        );
+
+        (block, x)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device, SyncType::Wait)
+    }
+}
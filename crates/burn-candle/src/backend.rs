@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use burn_tensor::{
-    backend::{Backend, DeviceId, DeviceOps, SyncType},
+    backend::{Backend, DeviceCapabilities, DeviceId, DeviceInfo, DeviceOps, SyncType},
     Device,
 };
 use candle_core::DeviceLocation;
@@ -126,4 +126,41 @@ impl<F: FloatCandleElement, I: IntCandleElement> Backend for Candle<F, I> {
             SyncType::Flush => (), // Nothhing to flush.
         };
     }
+
+    fn devices() -> Vec<DeviceInfo<Self::Device>> {
+        // Candle doesn't report the properties of its devices, only the ones of the backend are
+        // known: every device supports half precision floats and brain floats.
+        let capabilities = |name: String, unified_memory: bool| DeviceCapabilities {
+            name,
+            f16: true,
+            bf16: true,
+            unified_memory,
+            ..Default::default()
+        };
+        let mut devices = vec![DeviceInfo::new(
+            CandleDevice::Cpu,
+            capabilities("CPU".to_string(), true),
+        )];
+
+        // Creating a device fails when the index isn't valid, or when candle isn't compiled with
+        // the feature of the device.
+        let cuda = (0..)
+            .take_while(|index| candle_core::Device::new_cuda(*index).is_ok())
+            .map(|index| {
+                let name = format!("CUDA {index}");
+                DeviceInfo::new(CandleDevice::Cuda(index), capabilities(name, false))
+            });
+        devices.extend(cuda);
+
+        // The GPUs of Apple silicon share their memory with the CPU.
+        let metal = (0..)
+            .take_while(|index| candle_core::Device::new_metal(*index).is_ok())
+            .map(|index| {
+                let name = format!("Metal {index}");
+                DeviceInfo::new(CandleDevice::Metal(index), capabilities(name, true))
+            });
+        devices.extend(metal);
+
+        devices
+    }
 }
@@ -32,4 +32,12 @@ where
     ) -> FloatTensor<Candle<OElem, IntElem>, D> {
         CandleTensor::new(tensor.tensor.to_dtype(OElem::DTYPE).unwrap())
     }
+
+    fn target_device(device: &Device<Candle<OElem, IntElem>>) -> Device<Self::Target> {
+        device.clone()
+    }
+
+    fn origin_device(device: &Device<Self::Target>) -> Device<Candle<OElem, IntElem>> {
+        device.clone()
+    }
 }
@@ -10,11 +10,15 @@ mod backend;
 mod bridge;
 mod element;
 mod ops;
+mod quantization;
 mod tensor;
+mod weights;
 
 pub use backend::*;
 pub use bridge::*;
+pub use quantization::*;
 pub use tensor::*;
+pub use weights::*;
 
 #[cfg(test)]
 mod tests {
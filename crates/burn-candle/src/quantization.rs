@@ -0,0 +1,275 @@
+use std::sync::Arc;
+
+use burn_tensor::{Shape, Tensor};
+use candle_core::quantized::{GgmlDType, QMatMul, QTensor};
+
+use crate::{
+    element::{FloatCandleElement, IntCandleElement},
+    Candle, CandleDevice, CandleTensor,
+};
+
+/// The block quantization formats of [candle](candle_core), the ones of the GGML library.
+///
+/// Values are quantized by blocks along the last dimension, so its size must be a multiple of
+/// the size of the blocks: 32 values for the legacy formats and 256 for the k-quants.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuantizationType {
+    /// 4 bits per value with a scale per block.
+    Q4_0,
+    /// 4 bits per value with a scale and a minimum per block.
+    Q4_1,
+    /// 5 bits per value with a scale per block.
+    Q5_0,
+    /// 5 bits per value with a scale and a minimum per block.
+    Q5_1,
+    /// 8 bits per value with a scale per block.
+    Q8_0,
+    /// 2 bits per value, k-quant.
+    Q2K,
+    /// 3 bits per value, k-quant.
+    Q3K,
+    /// 4 bits per value, k-quant.
+    Q4K,
+    /// 5 bits per value, k-quant.
+    Q5K,
+    /// 6 bits per value, k-quant.
+    Q6K,
+    /// 8 bits per value, k-quant.
+    Q8K,
+    /// Half precision floats, not quantized.
+    F16,
+    /// Single precision floats, not quantized.
+    F32,
+}
+
+impl From<QuantizationType> for GgmlDType {
+    fn from(value: QuantizationType) -> Self {
+        match value {
+            QuantizationType::Q4_0 => GgmlDType::Q4_0,
+            QuantizationType::Q4_1 => GgmlDType::Q4_1,
+            QuantizationType::Q5_0 => GgmlDType::Q5_0,
+            QuantizationType::Q5_1 => GgmlDType::Q5_1,
+            QuantizationType::Q8_0 => GgmlDType::Q8_0,
+            QuantizationType::Q2K => GgmlDType::Q2K,
+            QuantizationType::Q3K => GgmlDType::Q3K,
+            QuantizationType::Q4K => GgmlDType::Q4K,
+            QuantizationType::Q5K => GgmlDType::Q5K,
+            QuantizationType::Q6K => GgmlDType::Q6K,
+            QuantizationType::Q8K => GgmlDType::Q8K,
+            QuantizationType::F16 => GgmlDType::F16,
+            QuantizationType::F32 => GgmlDType::F32,
+        }
+    }
+}
+
+/// Fails for the `Q8_1` type, which is only used by candle to quantize the inputs of matmuls.
+impl TryFrom<GgmlDType> for QuantizationType {
+    type Error = candle_core::Error;
+
+    fn try_from(value: GgmlDType) -> Result<Self, Self::Error> {
+        match value {
+            GgmlDType::Q4_0 => Ok(QuantizationType::Q4_0),
+            GgmlDType::Q4_1 => Ok(QuantizationType::Q4_1),
+            GgmlDType::Q5_0 => Ok(QuantizationType::Q5_0),
+            GgmlDType::Q5_1 => Ok(QuantizationType::Q5_1),
+            GgmlDType::Q8_0 => Ok(QuantizationType::Q8_0),
+            GgmlDType::Q2K => Ok(QuantizationType::Q2K),
+            GgmlDType::Q3K => Ok(QuantizationType::Q3K),
+            GgmlDType::Q4K => Ok(QuantizationType::Q4K),
+            GgmlDType::Q5K => Ok(QuantizationType::Q5K),
+            GgmlDType::Q6K => Ok(QuantizationType::Q6K),
+            GgmlDType::Q8K => Ok(QuantizationType::Q8K),
+            GgmlDType::F16 => Ok(QuantizationType::F16),
+            GgmlDType::F32 => Ok(QuantizationType::F32),
+            GgmlDType::Q8_1 => Err(candle_core::Error::Msg(
+                "Q8_1 is only used to quantize the inputs of matmuls".into(),
+            )),
+        }
+    }
+}
+
+/// A tensor quantized with one of the [quantization types](QuantizationType) of candle.
+///
+/// Quantized tensors can't be used in the operations of the backend, they are either
+/// [dequantized](CandleQTensor::dequantize) or used as the weight of a
+/// [quantized matmul](CandleQMatMul), which multiplies float inputs with the quantized values
+/// directly.
+#[derive(Debug, Clone)]
+pub struct CandleQTensor<const D: usize> {
+    pub(crate) qtensor: Arc<QTensor>,
+}
+
+impl<const D: usize> CandleQTensor<D> {
+    /// Quantize a float tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the size of the last dimension isn't a multiple of the block size of the quantization
+    /// type.
+    pub fn quantize<F: FloatCandleElement, I: IntCandleElement>(
+        tensor: Tensor<Candle<F, I>, D>,
+        qtype: QuantizationType,
+    ) -> Self {
+        let tensor = tensor
+            .into_primitive()
+            .tensor
+            .to_dtype(candle_core::DType::F32)
+            .unwrap();
+        let qtensor = QTensor::quantize(&tensor, qtype.into()).unwrap();
+
+        Self::new(qtensor)
+    }
+
+    /// Create a quantized tensor from a candle quantized tensor, e.g. read from a GGUF file.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor doesn't have `D` dimensions.
+    pub fn new(qtensor: QTensor) -> Self {
+        assert_eq!(
+            qtensor.shape().rank(),
+            D,
+            "The quantized tensor should have {D} dimensions."
+        );
+
+        Self {
+            qtensor: Arc::new(qtensor),
+        }
+    }
+
+    /// Dequantize the tensor on the given device.
+    pub fn dequantize<F: FloatCandleElement, I: IntCandleElement>(
+        &self,
+        device: &CandleDevice,
+    ) -> Tensor<Candle<F, I>, D> {
+        let tensor = self
+            .qtensor
+            .dequantize(&(*device).into())
+            .unwrap()
+            .to_dtype(F::DTYPE)
+            .unwrap();
+
+        Tensor::from_primitive(CandleTensor::new(tensor))
+    }
+
+    /// The shape of the tensor.
+    pub fn shape(&self) -> Shape<D> {
+        let dims: [usize; D] = self.qtensor.shape().dims().try_into().unwrap();
+        Shape::from(dims)
+    }
+
+    /// The quantization type of the tensor, which fails for the types only used internally by
+    /// candle, e.g. `Q8_1`.
+    pub fn qtype(&self) -> candle_core::Result<QuantizationType> {
+        self.qtensor.dtype().try_into()
+    }
+
+    /// The number of bytes used to store the quantized values.
+    pub fn size_in_bytes(&self) -> usize {
+        self.qtensor.storage_size_in_bytes()
+    }
+}
+
+/// A matrix multiplication with a quantized weight, multiplying the inputs with the quantized
+/// values without dequantizing them, as done by the linear layers of quantized language models.
+///
+/// The weight has the shape `[d_output, d_input]`, the layout of the weights of PyTorch and GGUF
+/// files, so the output is `input @ weight^T`.
+#[derive(Debug, Clone)]
+pub struct CandleQMatMul {
+    matmul: QMatMul,
+    d_output: usize,
+}
+
+impl CandleQMatMul {
+    /// Create a quantized matmul with the given weight of shape `[d_output, d_input]`.
+    pub fn new(weight: CandleQTensor<2>) -> Self {
+        let [d_output, _d_input] = weight.shape().dims;
+
+        Self {
+            matmul: QMatMul::from_arc(weight.qtensor).unwrap(),
+            d_output,
+        }
+    }
+
+    /// Applies the matmul on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<F: FloatCandleElement, I: IntCandleElement, const D: usize>(
+        &self,
+        input: Tensor<Candle<F, I>, D>,
+    ) -> Tensor<Candle<F, I>, D> {
+        let input = input.into_primitive().tensor;
+        let dtype = input.dtype();
+        // The quantized kernels only accept single precision inputs.
+        let input = input.to_dtype(candle_core::DType::F32).unwrap();
+        let output = candle_core::Module::forward(&self.matmul, &input)
+            .unwrap()
+            .to_dtype(dtype)
+            .unwrap();
+
+        Tensor::from_primitive(CandleTensor::new(output))
+    }
+
+    /// The number of output features.
+    pub fn d_output(&self) -> usize {
+        self.d_output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Distribution;
+
+    type TestBackend = Candle<f32, i64>;
+
+    #[test]
+    fn should_dequantize_close_to_the_original_values() {
+        let device = CandleDevice::Cpu;
+        let tensor = Tensor::<TestBackend, 2>::random([4, 64], Distribution::Default, &device);
+
+        let qtensor = CandleQTensor::quantize(tensor.clone(), QuantizationType::Q8_0);
+        let output = qtensor.dequantize::<f32, i64>(&device);
+
+        assert_eq!(qtensor.shape(), Shape::new([4, 64]));
+        assert_eq!(qtensor.qtype().unwrap(), QuantizationType::Q8_0);
+        assert!(qtensor.size_in_bytes() < 4 * 64 * core::mem::size_of::<f32>());
+        output.into_data().assert_approx_eq(&tensor.into_data(), 2);
+    }
+
+    #[test]
+    fn should_fail_to_convert_the_matmul_input_type() {
+        assert!(QuantizationType::try_from(GgmlDType::Q8_1).is_err());
+        assert_eq!(
+            QuantizationType::try_from(GgmlDType::Q4K).unwrap(),
+            QuantizationType::Q4K
+        );
+    }
+
+    #[test]
+    fn quantized_matmul_should_match_matmul_with_dequantized_weight() {
+        let device = CandleDevice::Cpu;
+        let distribution = Distribution::Uniform(-1.0, 1.0);
+        let weight = Tensor::<TestBackend, 2>::random([8, 64], distribution, &device);
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 64], distribution, &device);
+
+        let weight = CandleQTensor::quantize(weight, QuantizationType::Q8_0);
+        let expected = input.clone().matmul(
+            weight
+                .dequantize::<f32, i64>(&device)
+                .transpose()
+                .unsqueeze(),
+        );
+        let output = CandleQMatMul::new(weight).forward(input);
+
+        assert_eq!(output.shape(), Shape::new([2, 3, 8]));
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 1);
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, Read, Seek},
+    path::Path,
+};
+
+use burn_tensor::Tensor;
+use candle_core::{quantized::gguf_file, safetensors::MmapedSafetensors};
+
+use crate::{
+    element::{FloatCandleElement, IntCandleElement},
+    Candle, CandleDevice, CandleQTensor, CandleTensor,
+};
+
+/// The weights of a [safetensors](https://huggingface.co/docs/safetensors) file, memory mapped so
+/// each tensor is only read from the disk when it is loaded.
+///
+/// Loading the tensors one at a time keeps the memory usage close to the size of the model
+/// instead of twice its size, which makes loading large language models on the CPU practical.
+pub struct SafetensorsWeights {
+    safetensors: MmapedSafetensors,
+}
+
+impl SafetensorsWeights {
+    /// Memory map the safetensors file at the given path.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified while it is mapped, which is undefined behavior.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> candle_core::Result<Self> {
+        Ok(Self {
+            safetensors: MmapedSafetensors::new(path)?,
+        })
+    }
+
+    /// Memory map the safetensors files of a model split in multiple files, e.g. the shards of
+    /// the weights of a large language model.
+    ///
+    /// # Safety
+    ///
+    /// The files must not be modified while they are mapped, which is undefined behavior.
+    pub unsafe fn open_multi<P: AsRef<Path>>(paths: &[P]) -> candle_core::Result<Self> {
+        Ok(Self {
+            safetensors: MmapedSafetensors::multi(paths)?,
+        })
+    }
+
+    /// The names of the tensors.
+    pub fn names(&self) -> Vec<String> {
+        self.safetensors
+            .tensors()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// The shape of a tensor, without loading it.
+    pub fn shape(&self, name: &str) -> candle_core::Result<Vec<usize>> {
+        Ok(self.safetensors.get(name)?.shape().to_vec())
+    }
+
+    /// Load a float tensor on the given device, converting its values to the float element of
+    /// the backend.
+    pub fn load<F: FloatCandleElement, I: IntCandleElement, const D: usize>(
+        &self,
+        name: &str,
+        device: &CandleDevice,
+    ) -> candle_core::Result<Tensor<Candle<F, I>, D>> {
+        let tensor = self.load_candle(name, device)?.to_dtype(F::DTYPE)?;
+
+        Ok(Tensor::from_primitive(CandleTensor::new(tensor)))
+    }
+
+    /// Load a tensor and quantize it, so only the tensor being quantized is stored in full
+    /// precision.
+    pub fn load_quantized<const D: usize>(
+        &self,
+        name: &str,
+        qtype: crate::QuantizationType,
+    ) -> candle_core::Result<CandleQTensor<D>> {
+        let tensor = self
+            .load_candle(name, &CandleDevice::Cpu)?
+            .to_dtype(candle_core::DType::F32)?;
+        let qtensor = candle_core::quantized::QTensor::quantize(&tensor, qtype.into())?;
+
+        Ok(CandleQTensor::new(qtensor))
+    }
+
+    fn load_candle(
+        &self,
+        name: &str,
+        device: &CandleDevice,
+    ) -> candle_core::Result<candle_core::Tensor> {
+        self.safetensors.load(name, &(*device).into())
+    }
+}
+
+/// The quantized weights of a [GGUF](https://github.com/ggerganov/ggml/blob/master/docs/gguf.md)
+/// file, the format of the quantized models of llama.cpp.
+///
+/// Only the header of the file is read when it is opened, each tensor is read from the file when
+/// it is loaded.
+pub struct GgufWeights<R: Read + Seek = BufReader<File>> {
+    content: gguf_file::Content,
+    reader: R,
+}
+
+impl GgufWeights {
+    /// Open the GGUF file at the given path, reading its header.
+    pub fn open<P: AsRef<Path>>(path: P) -> candle_core::Result<Self> {
+        let file = File::open(path)?;
+
+        Self::from_reader(BufReader::new(file))
+    }
+}
+
+impl<R: Read + Seek> GgufWeights<R> {
+    /// Read the header of the GGUF content of the given reader.
+    pub fn from_reader(mut reader: R) -> candle_core::Result<Self> {
+        let content = gguf_file::Content::read(&mut reader)?;
+
+        Ok(Self { content, reader })
+    }
+
+    /// The names of the tensors.
+    pub fn names(&self) -> Vec<String> {
+        self.content.tensor_infos.keys().cloned().collect()
+    }
+
+    /// The shape of a tensor, without loading it.
+    pub fn shape(&self, name: &str) -> Option<Vec<usize>> {
+        self.content
+            .tensor_infos
+            .get(name)
+            .map(|info| info.shape.dims().to_vec())
+    }
+
+    /// The metadata of the file, e.g. the hyper-parameters and the tokenizer of the model.
+    pub fn metadata(&self) -> &HashMap<String, gguf_file::Value> {
+        &self.content.metadata
+    }
+
+    /// Load a quantized tensor on the given device.
+    ///
+    /// Note that GGUF files store the dimensions of the tensors from the innermost to the
+    /// outermost, candle reverses them so the weights of linear layers have the shape
+    /// `[d_output, d_input]`, ready to be used with a [quantized matmul](crate::CandleQMatMul).
+    pub fn load<const D: usize>(
+        &mut self,
+        name: &str,
+        device: &CandleDevice,
+    ) -> candle_core::Result<CandleQTensor<D>> {
+        let qtensor = self
+            .content
+            .tensor(&mut self.reader, name, &(*device).into())?;
+
+        Ok(CandleQTensor::new(qtensor))
+    }
+
+    /// Load a tensor and dequantize it, e.g. for the embeddings and the normalization layers of a
+    /// model, which aren't used in matmuls.
+    pub fn load_dequantized<F: FloatCandleElement, I: IntCandleElement, const D: usize>(
+        &mut self,
+        name: &str,
+        device: &CandleDevice,
+    ) -> candle_core::Result<Tensor<Candle<F, I>, D>> {
+        Ok(self.load(name, device)?.dequantize(device))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QuantizationType;
+    use burn_tensor::{Distribution, Shape};
+    use std::io::Cursor;
+
+    type TestBackend = Candle<f32, i64>;
+
+    #[test]
+    fn should_load_safetensors_weights() {
+        let device = CandleDevice::Cpu;
+        let tensor = Tensor::<TestBackend, 2>::random([3, 4], Distribution::Default, &device);
+        let path = std::env::temp_dir().join("burn_candle_should_load_safetensors_weights");
+        candle_core::safetensors::save(
+            &HashMap::from([("weight".to_string(), tensor.clone().into_primitive().tensor)]),
+            &path,
+        )
+        .unwrap();
+
+        let weights = unsafe { SafetensorsWeights::open(&path) }.unwrap();
+        let loaded = weights.load::<f32, i64, 2>("weight", &device).unwrap();
+
+        assert_eq!(weights.names(), vec!["weight".to_string()]);
+        assert_eq!(weights.shape("weight").unwrap(), vec![3, 4]);
+        assert_eq!(loaded.into_data(), tensor.into_data());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn should_load_gguf_weights() {
+        let device = CandleDevice::Cpu;
+        let tensor = Tensor::<TestBackend, 2>::random([2, 32], Distribution::Default, &device);
+        let qtensor = CandleQTensor::quantize(tensor, QuantizationType::Q8_0);
+        let mut file = Cursor::new(Vec::new());
+        gguf_file::write(&mut file, &[], &[("weight", &*qtensor.qtensor)]).unwrap();
+        file.set_position(0);
+
+        let mut weights = GgufWeights::from_reader(file).unwrap();
+        let loaded = weights.load::<2>("weight", &device).unwrap();
+
+        assert_eq!(weights.shape("weight"), Some(vec![2, 32]));
+        assert_eq!(loaded.shape(), Shape::new([2, 32]));
+        assert_eq!(loaded.qtype().unwrap(), QuantizationType::Q8_0);
+        assert_eq!(
+            loaded.dequantize::<f32, i64>(&device).into_data(),
+            qtensor.dequantize::<f32, i64>(&device).into_data()
+        );
+    }
+}
@@ -28,6 +28,14 @@ pub mod reader;
 /// Synchronization type module, used both by ComputeServer and Backends.
 pub mod sync_type;
 
+/// Memory usage module, used both by ComputeServer and Backends.
+pub mod memory_usage;
+
+/// Timeline module, recording operation dispatches, memory events and sync points to be exported
+/// in the Chrome tracing format.
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+pub mod timeline;
+
 extern crate alloc;
 
 /// Network utilities.
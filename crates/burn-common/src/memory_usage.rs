@@ -0,0 +1,10 @@
+/// Memory usage of a device, as reported by its memory management strategy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The number of bytes currently used by live tensors.
+    pub bytes_in_use: usize,
+    /// The number of bytes reserved on the device, including memory that is kept for reuse.
+    pub bytes_reserved: usize,
+    /// The number of allocations done on the device that are still alive.
+    pub number_allocs: usize,
+}
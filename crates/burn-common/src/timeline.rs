@@ -0,0 +1,382 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::Write,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::Duration,
+};
+use spin::Mutex;
+use std::time::Instant;
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static STATE: Mutex<Option<TimelineState>> = Mutex::new(None);
+static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(0);
+
+std::thread_local! {
+    static THREAD_ID: u64 = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+}
+
+struct TimelineState {
+    start: Instant,
+    events: Vec<TimelineEvent>,
+    threads: Vec<(u64, String)>,
+}
+
+/// The kind of a [timeline event](TimelineEvent).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimelineEventKind {
+    /// An event with a duration, such as an operation dispatch or a sync.
+    Span {
+        /// The duration of the span.
+        duration: Duration,
+    },
+    /// An event without duration, such as an allocation.
+    Instant,
+    /// The value of a counter, such as the memory in use.
+    Counter {
+        /// The value of the counter.
+        value: u64,
+    },
+}
+
+/// An event recorded on the timeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineEvent {
+    /// The name of the event.
+    pub name: String,
+    /// The category of the event, e.g. `op`, `memory` or `sync`.
+    pub category: &'static str,
+    /// The kind of the event.
+    pub kind: TimelineEventKind,
+    /// The time of the event since the start of the recording.
+    pub timestamp: Duration,
+    /// The id of the thread that recorded the event.
+    pub thread: u64,
+    /// Additional values attached to the event.
+    pub args: Vec<(&'static str, u64)>,
+}
+
+/// The events recorded between [start](start) and [stop](stop), which can be exported in the
+/// Chrome tracing format to be opened with `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+#[derive(Debug, Clone, Default)]
+pub struct Timeline {
+    /// The recorded events, in the order they were recorded.
+    pub events: Vec<TimelineEvent>,
+    /// The name of each thread that recorded an event.
+    pub threads: Vec<(u64, String)>,
+}
+
+/// A span recording its duration on the timeline when dropped.
+///
+/// Nothing is recorded when the span was created while the timeline wasn't recording.
+#[must_use = "The span is recorded when dropped"]
+pub struct Span {
+    event: Option<(TimelineEvent, Instant)>,
+}
+
+/// Start recording the events, forgetting the events recorded so far.
+pub fn start() {
+    *STATE.lock() = Some(TimelineState {
+        start: Instant::now(),
+        events: Vec::new(),
+        threads: Vec::new(),
+    });
+    RECORDING.store(true, Ordering::Relaxed);
+}
+
+/// Stop recording the events, and return the timeline.
+pub fn stop() -> Timeline {
+    RECORDING.store(false, Ordering::Relaxed);
+
+    match STATE.lock().take() {
+        Some(state) => Timeline {
+            events: state.events,
+            threads: state.threads,
+        },
+        None => Timeline::default(),
+    }
+}
+
+/// Whether the events are recorded.
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+/// Start a span, recorded when the returned guard is dropped.
+pub fn span<N: Into<String>>(name: N, category: &'static str) -> Span {
+    if !is_recording() {
+        return Span { event: None };
+    }
+
+    let event = TimelineEvent {
+        name: name.into(),
+        category,
+        kind: TimelineEventKind::Span {
+            duration: Duration::ZERO,
+        },
+        timestamp: Duration::ZERO,
+        thread: 0,
+        args: Vec::new(),
+    };
+
+    Span {
+        event: Some((event, Instant::now())),
+    }
+}
+
+/// Record an event without duration.
+pub fn instant<N: Into<String>>(name: N, category: &'static str, args: &[(&'static str, u64)]) {
+    if !is_recording() {
+        return;
+    }
+
+    record(
+        name.into(),
+        category,
+        TimelineEventKind::Instant,
+        Instant::now(),
+        args.to_vec(),
+    );
+}
+
+/// Record the value of a counter.
+pub fn counter<N: Into<String>>(name: N, category: &'static str, value: u64) {
+    if !is_recording() {
+        return;
+    }
+
+    record(
+        name.into(),
+        category,
+        TimelineEventKind::Counter { value },
+        Instant::now(),
+        Vec::new(),
+    );
+}
+
+fn record(
+    name: String,
+    category: &'static str,
+    kind: TimelineEventKind,
+    time: Instant,
+    args: Vec<(&'static str, u64)>,
+) {
+    let thread = THREAD_ID.with(|id| *id);
+    let mut state = STATE.lock();
+
+    if let Some(state) = state.as_mut() {
+        if !state.threads.iter().any(|(id, _)| *id == thread) {
+            let name = std::thread::current()
+                .name()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| format!("thread-{thread}"));
+            state.threads.push((thread, name));
+        }
+
+        state.events.push(TimelineEvent {
+            name,
+            category,
+            kind,
+            timestamp: time.saturating_duration_since(state.start),
+            thread,
+            args,
+        });
+    }
+}
+
+impl Span {
+    /// Attach a value to the span.
+    pub fn with_arg(mut self, key: &'static str, value: u64) -> Self {
+        if let Some((event, _)) = self.event.as_mut() {
+            event.args.push((key, value));
+        }
+        self
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some((event, start)) = self.event.take() {
+            let duration = start.elapsed();
+
+            record(
+                event.name,
+                event.category,
+                TimelineEventKind::Span { duration },
+                start,
+                event.args,
+            );
+        }
+    }
+}
+
+impl Timeline {
+    /// Export the timeline in the Chrome tracing JSON format.
+    pub fn to_chrome_json(&self) -> String {
+        let mut json = String::from("{\"displayTimeUnit\":\"ms\",\"traceEvents\":[");
+        let mut first = true;
+        let mut separator = |json: &mut String| {
+            if !first {
+                json.push(',');
+            }
+            first = false;
+        };
+
+        for (thread, name) in self.threads.iter() {
+            separator(&mut json);
+            write!(
+                json,
+                "{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":0,\"tid\":{thread},\
+                 \"args\":{{\"name\":\"{}\"}}}}",
+                escape(name)
+            )
+            .unwrap();
+        }
+
+        for event in self.events.iter() {
+            separator(&mut json);
+            write!(
+                json,
+                "{{\"name\":\"{}\",\"cat\":\"{}\",\"pid\":0,\"tid\":{},\"ts\":{:.3}",
+                escape(&event.name),
+                escape(event.category),
+                event.thread,
+                micros(event.timestamp),
+            )
+            .unwrap();
+
+            let mut args = event.args.clone();
+            match event.kind {
+                TimelineEventKind::Span { duration } => {
+                    write!(json, ",\"ph\":\"X\",\"dur\":{:.3}", micros(duration)).unwrap()
+                }
+                TimelineEventKind::Instant => json.push_str(",\"ph\":\"i\",\"s\":\"t\""),
+                TimelineEventKind::Counter { value } => {
+                    json.push_str(",\"ph\":\"C\"");
+                    args.insert(0, ("value", value));
+                }
+            }
+
+            if !args.is_empty() {
+                json.push_str(",\"args\":{");
+                for (i, (key, value)) in args.iter().enumerate() {
+                    if i > 0 {
+                        json.push(',');
+                    }
+                    write!(json, "\"{}\":{value}", escape(key)).unwrap();
+                }
+                json.push('}');
+            }
+
+            json.push('}');
+        }
+
+        json.push_str("]}");
+        json
+    }
+
+    /// Save the timeline in the Chrome tracing JSON format.
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_chrome_json())
+    }
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1_000_000.0
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_export_events_in_chrome_format() {
+        let timeline = Timeline {
+            events: vec![
+                TimelineEvent {
+                    name: "float_add".into(),
+                    category: "op",
+                    kind: TimelineEventKind::Span {
+                        duration: Duration::from_micros(3),
+                    },
+                    timestamp: Duration::from_micros(10),
+                    thread: 0,
+                    args: Vec::new(),
+                },
+                TimelineEvent {
+                    name: "alloc".into(),
+                    category: "memory",
+                    kind: TimelineEventKind::Instant,
+                    timestamp: Duration::from_micros(12),
+                    thread: 1,
+                    args: vec![("bytes", 64)],
+                },
+                TimelineEvent {
+                    name: "memory \"in use\"".into(),
+                    category: "memory",
+                    kind: TimelineEventKind::Counter { value: 128 },
+                    timestamp: Duration::from_micros(14),
+                    thread: 1,
+                    args: Vec::new(),
+                },
+            ],
+            threads: vec![(0, "main".into())],
+        };
+
+        assert_eq!(
+            timeline.to_chrome_json(),
+            "{\"displayTimeUnit\":\"ms\",\"traceEvents\":[\
+             {\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":0,\"tid\":0,\"args\":{\"name\":\"main\"}},\
+             {\"name\":\"float_add\",\"cat\":\"op\",\"pid\":0,\"tid\":0,\"ts\":10.000,\"ph\":\"X\",\"dur\":3.000},\
+             {\"name\":\"alloc\",\"cat\":\"memory\",\"pid\":0,\"tid\":1,\"ts\":12.000,\"ph\":\"i\",\"s\":\"t\",\"args\":{\"bytes\":64}},\
+             {\"name\":\"memory \\\"in use\\\"\",\"cat\":\"memory\",\"pid\":0,\"tid\":1,\"ts\":14.000,\"ph\":\"C\",\"args\":{\"value\":128}}\
+             ]}"
+        );
+    }
+
+    #[test]
+    fn should_record_events_between_start_and_stop() {
+        instant("before", "memory", &[]);
+
+        start();
+        {
+            let _span = span("float_add", "op").with_arg("inputs", 2);
+            instant("alloc", "memory", &[("bytes", 64)]);
+        }
+        counter("memory", "memory", 128);
+        let timeline = stop();
+
+        instant("after", "memory", &[]);
+
+        let names = timeline
+            .events
+            .iter()
+            .map(|event| event.name.as_str())
+            .collect::<Vec<_>>();
+
+        // The span is recorded when it ends.
+        assert_eq!(names, vec!["alloc", "float_add", "memory"]);
+        assert_eq!(timeline.events[1].args, vec![("inputs", 2)]);
+        assert_eq!(timeline.threads.len(), 1);
+        assert!(timeline.events[1].timestamp <= timeline.events[0].timestamp);
+    }
+}
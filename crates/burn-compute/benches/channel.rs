@@ -0,0 +1,107 @@
+use std::{sync::Arc, thread};
+
+use burn_common::{
+    benchmark::{run_benchmark, Benchmark},
+    stub::RwLock,
+    sync_type::SyncType,
+};
+use burn_compute::{
+    channel::{ComputeChannel, MpscComputeChannel, MutexComputeChannel},
+    client::ComputeClient,
+    memory_management::simple::{DeallocStrategy, SimpleMemoryManagement, SliceStrategy},
+    server::Handle,
+    storage::BytesStorage,
+    tune::Tuner,
+};
+use derive_new::new;
+
+#[path = "../tests/dummy/mod.rs"]
+#[allow(dead_code)]
+mod dummy;
+
+use dummy::{DummyElementwiseAddition, DummyServer};
+
+/// Many threads issuing small kernels on the same device.
+#[derive(new)]
+struct ChannelBenchmark<C> {
+    channel: &'static str,
+    client: ComputeClient<DummyServer, C>,
+    num_threads: usize,
+    num_kernels: usize,
+}
+
+impl<C: ComputeChannel<DummyServer>> Benchmark for ChannelBenchmark<C> {
+    type Args = Vec<Handle<DummyServer>>;
+
+    fn name(&self) -> String {
+        format!("channel-{}-{}-threads", self.channel, self.num_threads)
+    }
+
+    fn execute(&self, handles: Self::Args) {
+        thread::scope(|scope| {
+            for _ in 0..self.num_threads {
+                scope.spawn(|| {
+                    for _ in 0..self.num_kernels {
+                        let bindings = handles
+                            .iter()
+                            .map(|handle| handle.clone().binding())
+                            .collect();
+
+                        self.client
+                            .execute(Arc::new(DummyElementwiseAddition), bindings);
+                    }
+                });
+            }
+        });
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let lhs = self.client.create(&[0, 1, 2, 3]);
+        let rhs = self.client.create(&[4, 5, 6, 7]);
+        let out = self.client.empty(4);
+
+        vec![lhs, rhs, out]
+    }
+
+    fn sync(&self) {
+        self.client.sync(SyncType::Wait)
+    }
+}
+
+fn client<C, F>(channel: F) -> ComputeClient<DummyServer, C>
+where
+    C: ComputeChannel<DummyServer>,
+    F: FnOnce(DummyServer) -> C,
+{
+    let memory_management = SimpleMemoryManagement::new(
+        BytesStorage::default(),
+        DeallocStrategy::Never,
+        SliceStrategy::Never,
+    );
+    let server = DummyServer::new(memory_management);
+    let tuner = Arc::new(RwLock::new(Tuner::new("dummy", "benches/dummy-device")));
+
+    ComputeClient::new(channel(server), tuner)
+}
+
+fn main() {
+    const NUM_KERNELS: usize = 1024;
+
+    for num_threads in [1, 4, 16] {
+        let mutex = ChannelBenchmark::new(
+            "mutex",
+            client(MutexComputeChannel::new),
+            num_threads,
+            NUM_KERNELS,
+        );
+        let mpsc = ChannelBenchmark::new(
+            "mpsc",
+            client(MpscComputeChannel::new),
+            num_threads,
+            NUM_KERNELS,
+        );
+
+        println!("{}", run_benchmark(mutex));
+        println!("{}", run_benchmark(mpsc));
+    }
+}
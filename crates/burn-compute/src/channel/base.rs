@@ -1,9 +1,9 @@
 use crate::{
-    server::{Binding, ComputeServer, Handle},
-    storage::ComputeStorage,
+    server::{Binding, ComputeServer, Handle, ServerError},
+    storage::{ComputeStorage, StorageHint},
 };
 use alloc::vec::Vec;
-use burn_common::{reader::Reader, sync_type::SyncType};
+use burn_common::{memory_usage::MemoryUsage, reader::Reader, sync_type::SyncType};
 
 /// The ComputeChannel trait links the ComputeClient to the ComputeServer
 /// while ensuring thread-safety
@@ -23,9 +23,65 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug + Send
     /// Reserves `size` bytes in the storage, and returns a handle over them
     fn empty(&self, size: usize) -> Handle<Server>;
 
+    /// Reserves `size` bytes of the kind given by the `hint` in the storage, and returns a handle
+    /// over them.
+    fn empty_with_hint(&self, size: usize, hint: StorageHint) -> Handle<Server>;
+
     /// Executes the `kernel` over the given `bindings`.
     fn execute(&self, kernel: Server::Kernel, bindings: Vec<Binding<Server>>);
 
+    /// Executes the `kernel` over the given `bindings`, with the cube count read from the
+    /// `cube_count` binding.
+    fn execute_indirect(
+        &self,
+        kernel: Server::Kernel,
+        cube_count: Binding<Server>,
+        bindings: Vec<Binding<Server>>,
+    );
+
     /// Perform some synchronization of commands on the server.
     fn sync(&self, sync_type: SyncType);
+
+    /// Get the current memory usage of the server.
+    ///
+    /// The default implementation reports no memory usage.
+    fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::default()
+    }
+
+    /// Run a custom command with mutable access to the server.
+    fn run_custom_command(&self, f: impl Fn(&mut Server) + Send);
+
+    /// Returns the error that made the server unusable, if any.
+    fn status(&self) -> Result<(), ServerError>;
+
+    /// Returns a channel to the same server whose tasks are scheduled with the given
+    /// [priority](Priority).
+    ///
+    /// Channels that don't schedule their tasks process them in order, whatever their priority.
+    fn with_priority(&self, _priority: Priority) -> Self {
+        self.clone()
+    }
+}
+
+/// The priority of the tasks sent through a [channel](ComputeChannel).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Background tasks, such as precomputations.
+    Low,
+    /// The priority of the tasks by default, such as training.
+    #[default]
+    Normal,
+    /// Interactive tasks, such as inference requests.
+    High,
+}
+
+impl Priority {
+    /// The number of priorities.
+    pub(crate) const COUNT: usize = 3;
+
+    /// The index of the priority, from the lowest one.
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
 }
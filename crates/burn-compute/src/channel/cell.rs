@@ -1,8 +1,9 @@
 use super::ComputeChannel;
-use crate::server::{Binding, ComputeServer, Handle};
-use crate::storage::ComputeStorage;
+use crate::server::{Binding, ComputeServer, Handle, ServerError};
+use crate::storage::{ComputeStorage, StorageHint};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
+use burn_common::memory_usage::MemoryUsage;
 use burn_common::reader::Reader;
 use burn_common::sync_type::SyncType;
 
@@ -63,19 +64,42 @@ where
         self.server.borrow_mut().empty(size)
     }
 
+    fn empty_with_hint(&self, size: usize, hint: StorageHint) -> Handle<Server> {
+        self.server.borrow_mut().empty_with_hint(size, hint)
+    }
+
     fn execute(&self, kernel_description: Server::Kernel, bindings: Vec<Binding<Server>>) {
         self.server
             .borrow_mut()
             .execute(kernel_description, bindings)
     }
 
+    fn execute_indirect(
+        &self,
+        kernel_description: Server::Kernel,
+        cube_count: Binding<Server>,
+        bindings: Vec<Binding<Server>>,
+    ) {
+        self.server
+            .borrow_mut()
+            .execute_indirect(kernel_description, cube_count, bindings)
+    }
+
     fn sync(&self, sync_type: SyncType) {
         self.server.borrow_mut().sync(sync_type)
     }
 
+    fn memory_usage(&self) -> MemoryUsage {
+        self.server.borrow_mut().memory_usage()
+    }
+
     fn run_custom_command(&self, f: impl Fn(&mut Server) + Send) {
         self.server.borrow_mut().run_custom_command(f)
     }
+
+    fn status(&self) -> Result<(), ServerError> {
+        self.server.borrow_mut().status()
+    }
 }
 
 /// This is unsafe, since no concurrency is supported by the `RefCell` channel.
@@ -11,6 +11,11 @@ mod mpsc;
 #[cfg(all(feature = "channel-mpsc", not(target_family = "wasm")))]
 pub use mpsc::*;
 
+#[cfg(all(feature = "channel-priority", not(target_family = "wasm")))]
+mod priority;
+#[cfg(all(feature = "channel-priority", not(target_family = "wasm")))]
+pub use priority::*;
+
 #[cfg(feature = "channel-cell")]
 mod cell;
 #[cfg(feature = "channel-cell")]
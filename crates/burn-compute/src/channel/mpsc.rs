@@ -1,18 +1,22 @@
 use std::{
-    sync::{mpsc, Arc},
+    sync::{mpsc, Arc, Mutex},
     thread,
 };
 
-use burn_common::{reader::Reader, sync_type::SyncType};
+use burn_common::{memory_usage::MemoryUsage, reader::Reader, sync_type::SyncType};
 
 use super::ComputeChannel;
 use crate::{
-    server::{Binding, ComputeServer, Handle},
-    storage::ComputeStorage,
+    server::{Binding, ComputeServer, Handle, ServerError},
+    storage::{ComputeStorage, StorageHint},
 };
 
 /// Create a channel using the [multi-producer, single-consumer channel](mpsc) to communicate with
 /// the compute server spawn on its own thread.
+///
+/// Unlike the [mutex channel](super::MutexComputeChannel), the threads issuing tasks don't wait
+/// for each other to access the server, since the tasks are only queued, which reduces the latency
+/// when many threads issue small tasks on the same device.
 #[derive(Debug)]
 pub struct MpscComputeChannel<Server>
 where
@@ -28,6 +32,7 @@ where
 {
     _handle: thread::JoinHandle<()>,
     sender: mpsc::Sender<Message<Server>>,
+    server: Arc<Mutex<Server>>,
 }
 
 type Callback<Response> = mpsc::Sender<Response>;
@@ -42,9 +47,13 @@ where
         Callback<<Server::Storage as ComputeStorage>::Resource>,
     ),
     Create(Vec<u8>, Callback<Handle<Server>>),
-    Empty(usize, Callback<Handle<Server>>),
+    Empty(usize, StorageHint, Callback<Handle<Server>>),
     ExecuteKernel(Server::Kernel, Vec<Binding<Server>>),
+    ExecuteKernelIndirect(Server::Kernel, Binding<Server>, Vec<Binding<Server>>),
     Sync(SyncType, Callback<()>),
+    MemoryUsage(Callback<MemoryUsage>),
+    Status(Callback<Result<(), ServerError>>),
+    Barrier(Callback<()>),
 }
 
 impl<Server> MpscComputeChannel<Server>
@@ -52,11 +61,16 @@ where
     Server: ComputeServer + 'static,
 {
     /// Create a new mpsc compute channel.
-    pub fn new(mut server: Server) -> Self {
+    pub fn new(server: Server) -> Self {
         let (sender, receiver) = mpsc::channel();
+        let server = Arc::new(Mutex::new(server));
+        let server_thread = server.clone();
 
         let _handle = thread::spawn(move || {
             while let Ok(message) = receiver.recv() {
+                // Only locked by the custom commands, run once the previous messages are processed.
+                let mut server = server_thread.lock().unwrap();
+
                 match message {
                     Message::Read(binding, callback) => {
                         let data = server.read(binding);
@@ -70,22 +84,38 @@ where
                         let handle = server.create(&data);
                         callback.send(handle).unwrap();
                     }
-                    Message::Empty(size, callback) => {
-                        let handle = server.empty(size);
+                    Message::Empty(size, hint, callback) => {
+                        let handle = server.empty_with_hint(size, hint);
                         callback.send(handle).unwrap();
                     }
                     Message::ExecuteKernel(kernel, bindings) => {
                         server.execute(kernel, bindings);
                     }
+                    Message::ExecuteKernelIndirect(kernel, cube_count, bindings) => {
+                        server.execute_indirect(kernel, cube_count, bindings);
+                    }
                     Message::Sync(sync_type, callback) => {
                         server.sync(sync_type);
                         callback.send(()).unwrap();
                     }
+                    Message::MemoryUsage(callback) => {
+                        callback.send(server.memory_usage()).unwrap();
+                    }
+                    Message::Status(callback) => {
+                        callback.send(server.status()).unwrap();
+                    }
+                    Message::Barrier(callback) => {
+                        callback.send(()).unwrap();
+                    }
                 };
             }
         });
 
-        let state = Arc::new(MpscComputeChannelState { sender, _handle });
+        let state = Arc::new(MpscComputeChannelState {
+            sender,
+            _handle,
+            server,
+        });
 
         Self { state }
     }
@@ -140,11 +170,15 @@ where
     }
 
     fn empty(&self, size: usize) -> Handle<Server> {
+        self.empty_with_hint(size, StorageHint::Device)
+    }
+
+    fn empty_with_hint(&self, size: usize, hint: StorageHint) -> Handle<Server> {
         let (callback, response) = mpsc::channel();
 
         self.state
             .sender
-            .send(Message::Empty(size, callback))
+            .send(Message::Empty(size, hint, callback))
             .unwrap();
 
         self.response(response)
@@ -157,6 +191,18 @@ where
             .unwrap()
     }
 
+    fn execute_indirect(
+        &self,
+        kernel: Server::Kernel,
+        cube_count: Binding<Server>,
+        bindings: Vec<Binding<Server>>,
+    ) {
+        self.state
+            .sender
+            .send(Message::ExecuteKernelIndirect(kernel, cube_count, bindings))
+            .unwrap()
+    }
+
     fn sync(&self, sync_type: SyncType) {
         let (callback, response) = mpsc::channel();
         self.state
@@ -166,11 +212,29 @@ where
         self.response(response)
     }
 
-    fn run_custom_command(&self, _f: impl Fn(&mut Server) + Send) {
-        // TODO: This doesn't work due to some lifetimes I don't quite understand :/ f has to be
-        // 'static somehow, but I'm not sure why. Making it 'static would largey defeat the point
-        // of this mechanism.
-        todo!();
+    fn memory_usage(&self) -> MemoryUsage {
+        let (callback, response) = mpsc::channel();
+        self.state
+            .sender
+            .send(Message::MemoryUsage(callback))
+            .unwrap();
+        self.response(response)
+    }
+
+    fn run_custom_command(&self, f: impl Fn(&mut Server) + Send) {
+        // The command can borrow from the caller, so it isn't sent to the server thread. It runs on
+        // the calling thread instead, once the previous messages are processed.
+        let (callback, response) = mpsc::channel();
+        self.state.sender.send(Message::Barrier(callback)).unwrap();
+        self.response(response);
+
+        self.state.server.lock().unwrap().run_custom_command(f)
+    }
+
+    fn status(&self) -> Result<(), ServerError> {
+        let (callback, response) = mpsc::channel();
+        self.state.sender.send(Message::Status(callback)).unwrap();
+        self.response(response)
     }
 }
 
@@ -1,7 +1,8 @@
 use super::ComputeChannel;
-use crate::server::{Binding, ComputeServer, Handle};
-use crate::storage::ComputeStorage;
+use crate::server::{Binding, ComputeServer, Handle, ServerError};
+use crate::storage::{ComputeStorage, StorageHint};
 use alloc::sync::Arc;
+use burn_common::memory_usage::MemoryUsage;
 use burn_common::reader::Reader;
 use burn_common::sync_type::SyncType;
 use spin::Mutex;
@@ -55,15 +56,38 @@ where
         self.server.lock().empty(size)
     }
 
+    fn empty_with_hint(&self, size: usize, hint: StorageHint) -> Handle<Server> {
+        self.server.lock().empty_with_hint(size, hint)
+    }
+
     fn execute(&self, kernel: Server::Kernel, handles: Vec<Binding<Server>>) {
         self.server.lock().execute(kernel, handles)
     }
 
+    fn execute_indirect(
+        &self,
+        kernel: Server::Kernel,
+        cube_count: Binding<Server>,
+        handles: Vec<Binding<Server>>,
+    ) {
+        self.server
+            .lock()
+            .execute_indirect(kernel, cube_count, handles)
+    }
+
     fn sync(&self, sync_type: SyncType) {
         self.server.lock().sync(sync_type)
     }
 
+    fn memory_usage(&self) -> MemoryUsage {
+        self.server.lock().memory_usage()
+    }
+
     fn run_custom_command(&self, f: impl Fn(&mut Server) + Send) {
         self.server.lock().run_custom_command(f)
     }
+
+    fn status(&self) -> Result<(), ServerError> {
+        self.server.lock().status()
+    }
 }
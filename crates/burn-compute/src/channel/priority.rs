@@ -0,0 +1,373 @@
+use std::{
+    collections::VecDeque,
+    sync::{mpsc, Arc, Condvar, Mutex},
+    thread,
+};
+
+use burn_common::{memory_usage::MemoryUsage, reader::Reader, sync_type::SyncType};
+
+use super::{ComputeChannel, Priority};
+use crate::{
+    server::{Binding, ComputeServer, Handle, ServerError},
+    storage::{ComputeStorage, StorageHint},
+};
+
+/// Create a channel communicating with the compute server spawn on its own thread, like the
+/// [mpsc channel](super::MpscComputeChannel), which processes the tasks of a higher
+/// [priority](Priority) first.
+///
+/// This lets interactive tasks, such as inference requests, be ordered ahead of the background
+/// tasks sharing the same device. Tasks of the same priority are processed in order. To avoid
+/// starving the lower priorities, a waiting task is processed once a number of tasks of higher
+/// priorities, the starvation limit, were processed ahead of it.
+pub struct PriorityComputeChannel<Server>
+where
+    Server: ComputeServer,
+{
+    state: Arc<PriorityComputeChannelState<Server>>,
+    priority: Priority,
+}
+
+struct PriorityComputeChannelState<Server>
+where
+    Server: ComputeServer,
+{
+    _handle: thread::JoinHandle<()>,
+    queue: Arc<MessageQueue<Server>>,
+    server: Arc<Mutex<Server>>,
+}
+
+struct MessageQueue<Server>
+where
+    Server: ComputeServer,
+{
+    scheduler: Mutex<Scheduler<Message<Server>>>,
+    available: Condvar,
+}
+
+type Callback<Response> = mpsc::Sender<Response>;
+
+enum Message<Server>
+where
+    Server: ComputeServer,
+{
+    Read(Binding<Server>, Callback<Reader<Vec<u8>>>),
+    GetResource(
+        Binding<Server>,
+        Callback<<Server::Storage as ComputeStorage>::Resource>,
+    ),
+    Create(Vec<u8>, Callback<Handle<Server>>),
+    Empty(usize, StorageHint, Callback<Handle<Server>>),
+    ExecuteKernel(Server::Kernel, Vec<Binding<Server>>),
+    ExecuteKernelIndirect(Server::Kernel, Binding<Server>, Vec<Binding<Server>>),
+    Sync(SyncType, Callback<()>),
+    MemoryUsage(Callback<MemoryUsage>),
+    Status(Callback<Result<(), ServerError>>),
+    Barrier(Callback<()>),
+}
+
+/// Queues of items for each priority, served from the highest priority while no lower priority
+/// starves.
+struct Scheduler<T> {
+    queues: [VecDeque<T>; Priority::COUNT],
+    skipped: [usize; Priority::COUNT],
+    starvation_limit: usize,
+    closed: bool,
+}
+
+impl<T> Scheduler<T> {
+    fn new(starvation_limit: usize) -> Self {
+        Self {
+            queues: [VecDeque::new(), VecDeque::new(), VecDeque::new()],
+            skipped: [0; Priority::COUNT],
+            starvation_limit,
+            closed: false,
+        }
+    }
+
+    fn push(&mut self, priority: Priority, item: T) {
+        self.queues[priority.index()].push_back(item);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let index = self.next_index()?;
+
+        // Only the lower priorities wait for the higher ones.
+        for lower in 0..index {
+            if !self.queues[lower].is_empty() {
+                self.skipped[lower] += 1;
+            }
+        }
+        self.skipped[index] = 0;
+
+        self.queues[index].pop_front()
+    }
+
+    /// The index of the queue to serve: the highest starving priority, or else the highest
+    /// waiting priority.
+    fn next_index(&self) -> Option<usize> {
+        let mut waiting = (0..Priority::COUNT)
+            .rev()
+            .filter(|index| !self.queues[*index].is_empty());
+
+        waiting
+            .clone()
+            .find(|index| self.skipped[*index] >= self.starvation_limit)
+            .or_else(|| waiting.next())
+    }
+}
+
+impl<Server> PriorityComputeChannel<Server>
+where
+    Server: ComputeServer + 'static,
+{
+    /// Create a new priority compute channel, with the [normal](Priority::Normal) priority.
+    ///
+    /// A waiting task is processed once `starvation_limit` tasks of higher priorities were
+    /// processed ahead of it.
+    pub fn new(server: Server, starvation_limit: usize) -> Self {
+        let queue = Arc::new(MessageQueue {
+            scheduler: Mutex::new(Scheduler::new(starvation_limit)),
+            available: Condvar::new(),
+        });
+        let queue_server = queue.clone();
+        let server = Arc::new(Mutex::new(server));
+        let server_thread = server.clone();
+
+        let _handle = thread::spawn(move || {
+            while let Some(message) = queue_server.next() {
+                // Only locked by the custom commands, run once the previous messages are processed.
+                let mut server = server_thread.lock().unwrap();
+
+                match message {
+                    Message::Read(binding, callback) => {
+                        let data = server.read(binding);
+                        callback.send(data).unwrap();
+                    }
+                    Message::GetResource(binding, callback) => {
+                        let data = server.get_resource(binding);
+                        callback.send(data).unwrap();
+                    }
+                    Message::Create(data, callback) => {
+                        let handle = server.create(&data);
+                        callback.send(handle).unwrap();
+                    }
+                    Message::Empty(size, hint, callback) => {
+                        let handle = server.empty_with_hint(size, hint);
+                        callback.send(handle).unwrap();
+                    }
+                    Message::ExecuteKernel(kernel, bindings) => {
+                        server.execute(kernel, bindings);
+                    }
+                    Message::ExecuteKernelIndirect(kernel, cube_count, bindings) => {
+                        server.execute_indirect(kernel, cube_count, bindings);
+                    }
+                    Message::Sync(sync_type, callback) => {
+                        server.sync(sync_type);
+                        callback.send(()).unwrap();
+                    }
+                    Message::MemoryUsage(callback) => {
+                        callback.send(server.memory_usage()).unwrap();
+                    }
+                    Message::Status(callback) => {
+                        callback.send(server.status()).unwrap();
+                    }
+                    Message::Barrier(callback) => {
+                        callback.send(()).unwrap();
+                    }
+                };
+            }
+        });
+
+        let state = Arc::new(PriorityComputeChannelState {
+            _handle,
+            queue,
+            server,
+        });
+
+        Self {
+            state,
+            priority: Priority::Normal,
+        }
+    }
+}
+
+impl<Server: ComputeServer> MessageQueue<Server> {
+    fn push(&self, priority: Priority, message: Message<Server>) {
+        self.scheduler.lock().unwrap().push(priority, message);
+        self.available.notify_one();
+    }
+
+    /// Wait for the next message, until the channel is dropped.
+    fn next(&self) -> Option<Message<Server>> {
+        let mut scheduler = self.scheduler.lock().unwrap();
+
+        loop {
+            if let Some(message) = scheduler.pop() {
+                return Some(message);
+            }
+
+            if scheduler.closed {
+                return None;
+            }
+
+            scheduler = self.available.wait(scheduler).unwrap();
+        }
+    }
+}
+
+impl<Server: ComputeServer> Drop for PriorityComputeChannelState<Server> {
+    fn drop(&mut self) {
+        // The server thread stops once the remaining messages are processed.
+        self.queue.scheduler.lock().unwrap().closed = true;
+        self.queue.available.notify_one();
+    }
+}
+
+impl<Server: ComputeServer> Clone for PriorityComputeChannel<Server> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            priority: self.priority,
+        }
+    }
+}
+
+impl<Server: ComputeServer> core::fmt::Debug for PriorityComputeChannel<Server> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PriorityComputeChannel")
+            .field("priority", &self.priority)
+            .finish()
+    }
+}
+
+impl<Server> ComputeChannel<Server> for PriorityComputeChannel<Server>
+where
+    Server: ComputeServer + 'static,
+{
+    fn read(&self, binding: Binding<Server>) -> Reader<Vec<u8>> {
+        let (callback, response) = mpsc::channel();
+        self.send(Message::Read(binding, callback));
+        self.response(response)
+    }
+
+    fn get_resource(
+        &self,
+        binding: Binding<Server>,
+    ) -> <Server::Storage as ComputeStorage>::Resource {
+        let (callback, response) = mpsc::channel();
+        self.send(Message::GetResource(binding, callback));
+        self.response(response)
+    }
+
+    fn create(&self, data: &[u8]) -> Handle<Server> {
+        let (callback, response) = mpsc::channel();
+        self.send(Message::Create(data.to_vec(), callback));
+        self.response(response)
+    }
+
+    fn empty(&self, size: usize) -> Handle<Server> {
+        self.empty_with_hint(size, StorageHint::Device)
+    }
+
+    fn empty_with_hint(&self, size: usize, hint: StorageHint) -> Handle<Server> {
+        let (callback, response) = mpsc::channel();
+        self.send(Message::Empty(size, hint, callback));
+        self.response(response)
+    }
+
+    fn execute(&self, kernel: Server::Kernel, bindings: Vec<Binding<Server>>) {
+        self.send(Message::ExecuteKernel(kernel, bindings))
+    }
+
+    fn execute_indirect(
+        &self,
+        kernel: Server::Kernel,
+        cube_count: Binding<Server>,
+        bindings: Vec<Binding<Server>>,
+    ) {
+        self.send(Message::ExecuteKernelIndirect(kernel, cube_count, bindings))
+    }
+
+    fn sync(&self, sync_type: SyncType) {
+        let (callback, response) = mpsc::channel();
+        self.send(Message::Sync(sync_type, callback));
+        self.response(response)
+    }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        let (callback, response) = mpsc::channel();
+        self.send(Message::MemoryUsage(callback));
+        self.response(response)
+    }
+
+    fn run_custom_command(&self, f: impl Fn(&mut Server) + Send) {
+        // The command can borrow from the caller, so it runs on the calling thread once the
+        // previous messages of its priority are processed.
+        let (callback, response) = mpsc::channel();
+        self.send(Message::Barrier(callback));
+        self.response(response);
+
+        self.state.server.lock().unwrap().run_custom_command(f)
+    }
+
+    fn status(&self) -> Result<(), ServerError> {
+        let (callback, response) = mpsc::channel();
+        self.send(Message::Status(callback));
+        self.response(response)
+    }
+
+    fn with_priority(&self, priority: Priority) -> Self {
+        Self {
+            state: self.state.clone(),
+            priority,
+        }
+    }
+}
+
+impl<Server: ComputeServer> PriorityComputeChannel<Server> {
+    fn send(&self, message: Message<Server>) {
+        self.state.queue.push(self.priority, message);
+    }
+
+    fn response<Response>(&self, response: mpsc::Receiver<Response>) -> Response {
+        match response.recv() {
+            Ok(val) => val,
+            Err(err) => panic!("Can't connect to the server correctly {err:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn higher_priorities_should_be_served_first() {
+        let mut scheduler = Scheduler::new(usize::MAX);
+
+        scheduler.push(Priority::Low, 0);
+        scheduler.push(Priority::Normal, 1);
+        scheduler.push(Priority::High, 2);
+        scheduler.push(Priority::Normal, 3);
+
+        assert_eq!(drain(&mut scheduler), vec![2, 1, 3, 0]);
+    }
+
+    #[test]
+    fn lower_priorities_should_not_starve() {
+        let mut scheduler = Scheduler::new(2);
+
+        scheduler.push(Priority::Low, 0);
+        scheduler.push(Priority::Low, 1);
+        for item in 2..7 {
+            scheduler.push(Priority::High, item);
+        }
+
+        assert_eq!(drain(&mut scheduler), vec![2, 3, 0, 4, 5, 1, 6]);
+    }
+
+    fn drain(scheduler: &mut Scheduler<usize>) -> Vec<usize> {
+        core::iter::from_fn(|| scheduler.pop()).collect()
+    }
+}
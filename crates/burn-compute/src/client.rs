@@ -1,13 +1,14 @@
 use crate::{
-    channel::ComputeChannel,
-    server::{Binding, ComputeServer, Handle},
-    storage::ComputeStorage,
+    channel::{ComputeChannel, Priority},
+    server::{Binding, ComputeServer, Handle, ServerError},
+    storage::{ComputeStorage, StorageHint},
     tune::{AutotuneOperationSet, Tuner},
+    uniform::{UniformPool, MAX_UNIFORM_SIZE},
 };
 use alloc::vec::Vec;
 use alloc::{boxed::Box, sync::Arc};
-use burn_common::stub::RwLock;
-use burn_common::{reader::Reader, sync_type::SyncType};
+use burn_common::stub::{Mutex, RwLock};
+use burn_common::{memory_usage::MemoryUsage, reader::Reader, sync_type::SyncType};
 
 /// The ComputeClient is the entry point to require tasks from the ComputeServer.
 /// It should be obtained for a specific device via the Compute struct.
@@ -15,6 +16,7 @@ use burn_common::{reader::Reader, sync_type::SyncType};
 pub struct ComputeClient<Server: ComputeServer, Channel> {
     channel: Channel,
     tuner: Arc<RwLock<Tuner<Server::AutotuneKey>>>,
+    uniforms: Arc<Mutex<UniformPool<Server>>>,
 }
 
 impl<S, C> Clone for ComputeClient<S, C>
@@ -26,6 +28,7 @@ where
         Self {
             channel: self.channel.clone(),
             tuner: self.tuner.clone(),
+            uniforms: self.uniforms.clone(),
         }
     }
 }
@@ -37,11 +40,31 @@ where
 {
     /// Create a new client.
     pub fn new(channel: Channel, tuner: Arc<RwLock<Tuner<Server::AutotuneKey>>>) -> Self {
-        Self { channel, tuner }
+        Self {
+            channel,
+            tuner,
+            uniforms: Arc::new(Mutex::new(UniformPool::new())),
+        }
+    }
+
+    /// Returns a client to the same server whose tasks are scheduled with the given
+    /// [priority](Priority), when the channel supports it.
+    ///
+    /// Tasks of different priorities can be reordered, so the tasks writing a handle must be
+    /// [synchronized](Self::sync) before the handle is used by a client of another priority.
+    pub fn with_priority(&self, priority: Priority) -> Self {
+        Self {
+            channel: self.channel.with_priority(priority),
+            tuner: self.tuner.clone(),
+            uniforms: self.uniforms.clone(),
+        }
     }
 
     /// Given a binding, returns owned resource as bytes.
     pub fn read(&self, binding: Binding<Server>) -> Reader<Vec<u8>> {
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        let _span = burn_common::timeline::span("read", "sync");
+
         self.channel.read(binding)
     }
 
@@ -55,22 +78,99 @@ where
 
     /// Given a resource, stores it and returns the resource handle.
     pub fn create(&self, data: &[u8]) -> Handle<Server> {
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        burn_common::timeline::instant("create", "memory", &[("bytes", data.len() as u64)]);
+
         self.channel.create(data)
     }
 
+    /// Returns a read-only handle with the given content, such as the info or the scalars of a
+    /// kernel launch.
+    ///
+    /// Small buffers are pooled, so launches with the same uniforms don't allocate and upload them
+    /// again. The returned handle must never be written to.
+    pub fn create_uniform(&self, data: &[u8]) -> Handle<Server> {
+        if data.len() > MAX_UNIFORM_SIZE {
+            return self.create(data);
+        }
+
+        self.uniforms
+            .lock()
+            .unwrap()
+            .get_or_create(data, |data| self.create(data))
+    }
+
     /// Reserves `size` bytes in the storage, and returns a handle over them.
     pub fn empty(&self, size: usize) -> Handle<Server> {
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        burn_common::timeline::instant("empty", "memory", &[("bytes", size as u64)]);
+
         self.channel.empty(size)
     }
 
+    /// Reserves `size` bytes of the kind given by the `hint` in the storage, and returns a handle
+    /// over them.
+    ///
+    /// Host-mapped memory can be read back faster, while sparse memory suits very large buffers of
+    /// which only parts are used. The kinds of memory not supported by the server fall back to
+    /// regular memory.
+    pub fn empty_with_hint(&self, size: usize, hint: StorageHint) -> Handle<Server> {
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        burn_common::timeline::instant("empty", "memory", &[("bytes", size as u64)]);
+
+        self.channel.empty_with_hint(size, hint)
+    }
+
     /// Executes the `kernel` over the given `bindings`.
     pub fn execute(&self, kernel: Server::Kernel, bindings: Vec<Binding<Server>>) {
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        let _span = burn_common::timeline::span("execute", "compute");
+
         self.channel.execute(kernel, bindings)
     }
 
+    /// Executes the `kernel` over the given `bindings`, with the number of cubes read from the
+    /// `cube_count` binding holding three `u32`, for the x, y and z axes.
+    pub fn execute_indirect(
+        &self,
+        kernel: Server::Kernel,
+        cube_count: Binding<Server>,
+        bindings: Vec<Binding<Server>>,
+    ) {
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        let _span = burn_common::timeline::span("execute_indirect", "compute");
+
+        self.channel.execute_indirect(kernel, cube_count, bindings)
+    }
+
     /// Wait for the completion of every task in the server.
     pub fn sync(&self, sync_type: SyncType) {
-        self.channel.sync(sync_type)
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        let _span = burn_common::timeline::span(
+            match sync_type {
+                SyncType::Flush => "sync_flush",
+                SyncType::Wait => "sync_wait",
+            },
+            "sync",
+        );
+
+        self.channel.sync(sync_type);
+
+        #[cfg(feature = "std")]
+        crate::handle_tracker::sync_point();
+    }
+
+    /// Get the current memory usage of the server.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.channel.memory_usage()
+    }
+
+    /// Returns the error that made the server unusable, e.g. when its device was lost.
+    ///
+    /// The tasks requested while the server is unusable are dropped, and reading data returns
+    /// garbage or panics, so this can be checked after a failure to find its cause.
+    pub fn status(&self) -> Result<(), ServerError> {
+        self.channel.status()
     }
 
     /// Executes the fastest kernel in the autotune operation, using (cached) runtime benchmarks
@@ -1,4 +1,5 @@
 use crate::{channel::ComputeChannel, client::ComputeClient, server::ComputeServer};
+use burn_common::sync_type::SyncType;
 use core::ops::DerefMut;
 use hashbrown::HashMap;
 
@@ -74,6 +75,43 @@ where
         Self::register_inner(device, client, &mut clients);
     }
 
+    /// Release the compute client of the given device, after waiting for the completion of its
+    /// tasks, and returns whether a client was registered.
+    ///
+    /// # Notes
+    ///
+    /// The server, along with its memory, is dropped once every clone of the client is dropped,
+    /// including the ones held by tensors still allocated on the device. A new client can then be
+    /// registered or lazily created for the device.
+    pub fn release(&self, device: &Device) -> bool {
+        let client = match self.clients.lock().as_mut() {
+            Some(clients) => clients.remove(device),
+            None => None,
+        };
+
+        match client {
+            Some(client) => {
+                client.sync(SyncType::Wait);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Release the compute clients of every device.
+    ///
+    /// See [release](ComputeRuntime::release).
+    pub fn release_all(&self) {
+        let clients = self.clients.lock().take();
+
+        for client in clients
+            .into_iter()
+            .flat_map(|clients| clients.into_values())
+        {
+            client.sync(SyncType::Wait);
+        }
+    }
+
     fn register_inner(
         device: &Device,
         client: ComputeClient<Server, Channel>,
@@ -0,0 +1,277 @@
+use alloc::{
+    string::{String, ToString},
+    sync::{Arc, Weak},
+    vec::Vec,
+};
+use core::sync::atomic::{AtomicBool, Ordering};
+use spin::Mutex;
+use std::backtrace::Backtrace;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static STATE: Mutex<TrackerState> = Mutex::new(TrackerState::new());
+
+/// Statistics on the memory handles created while the tracker is enabled.
+///
+/// A handle is in use when it is referenced outside of the memory management, e.g. by a tensor,
+/// and free when only the memory management holds it so it can be reused by a new allocation.
+/// Drops and reuses are observed at sync points and when a report is requested.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HandleStats {
+    /// The number of tracked handles created.
+    pub created: usize,
+    /// The number of tracked handles dropped.
+    pub dropped: usize,
+    /// The number of times a free handle was used again.
+    pub reused: usize,
+    /// The number of tracked handles currently in use.
+    pub in_use: usize,
+    /// The number of tracked handles currently free.
+    pub free: usize,
+}
+
+/// A handle that has been in use for more sync points than allowed by the tracker, which
+/// usually means it was leaked.
+#[derive(Clone, Debug)]
+pub struct LongLivedHandle {
+    /// The type of the handle id.
+    pub kind: &'static str,
+    /// The number of sync points since the handle was created.
+    pub age: usize,
+    /// The number of references to the handle, including the one of the memory management.
+    pub references: usize,
+    /// The backtrace of the handle creation.
+    pub backtrace: String,
+}
+
+/// The state of the tracked handles.
+#[derive(Clone, Debug)]
+pub struct HandleReport {
+    /// The handle statistics.
+    pub stats: HandleStats,
+    /// The handles in use for more sync points than allowed.
+    pub long_lived: Vec<LongLivedHandle>,
+}
+
+/// Start tracking the memory handles created from now on, logging a warning with the backtrace of
+/// their creation when they are still in use after `max_age` sync points.
+///
+/// Capturing the backtraces is slow, so the tracker should only be enabled to diagnose memory
+/// growth, e.g. for a few iterations of a training loop.
+pub fn enable(max_age: usize) {
+    let mut state = STATE.lock();
+    *state = TrackerState::new();
+    state.max_age = max_age;
+
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Stop tracking the memory handles, and forget the ones tracked so far.
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+
+    *STATE.lock() = TrackerState::new();
+}
+
+/// Returns the statistics and the long-lived handles observed so far.
+pub fn report() -> HandleReport {
+    let mut state = STATE.lock();
+    state.update();
+
+    HandleReport {
+        stats: state.stats(),
+        long_lived: state
+            .long_lived()
+            .map(|entry| entry.to_long_lived(state.num_syncs))
+            .collect(),
+    }
+}
+
+/// Track a new handle, if the tracker is enabled.
+pub(crate) fn register<Id>(all: &Arc<()>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let backtrace = Backtrace::force_capture();
+    let mut state = STATE.lock();
+
+    state.created += 1;
+    state.entries.push(Entry {
+        kind: core::any::type_name::<Id>(),
+        all: Arc::downgrade(all),
+        created_at: state.num_syncs,
+        backtrace,
+        was_free: false,
+        reported: false,
+    });
+}
+
+/// Called when the compute client is synced, reporting the handles that became long-lived.
+pub(crate) fn sync_point() {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let mut state = STATE.lock();
+    state.num_syncs += 1;
+    state.update();
+
+    let num_syncs = state.num_syncs;
+    let max_age = state.max_age;
+
+    for entry in state.entries.iter_mut() {
+        if !entry.reported && entry.is_in_use() && num_syncs - entry.created_at > max_age {
+            let handle = entry.to_long_lived(num_syncs);
+            entry.reported = true;
+
+            log::warn!(
+                "Memory handle {} still in use after {} sync points with {} references, \
+                 created at:\n{}",
+                handle.kind,
+                handle.age,
+                handle.references,
+                handle.backtrace
+            );
+        }
+    }
+}
+
+struct Entry {
+    kind: &'static str,
+    all: Weak<()>,
+    created_at: usize,
+    backtrace: Backtrace,
+    was_free: bool,
+    reported: bool,
+}
+
+impl Entry {
+    fn is_in_use(&self) -> bool {
+        // The memory management always holds one reference.
+        self.all.strong_count() > 1
+    }
+
+    fn to_long_lived(&self, num_syncs: usize) -> LongLivedHandle {
+        LongLivedHandle {
+            kind: self.kind,
+            age: num_syncs - self.created_at,
+            references: self.all.strong_count(),
+            backtrace: self.backtrace.to_string(),
+        }
+    }
+}
+
+struct TrackerState {
+    entries: Vec<Entry>,
+    num_syncs: usize,
+    max_age: usize,
+    created: usize,
+    dropped: usize,
+    reused: usize,
+}
+
+impl TrackerState {
+    const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            num_syncs: 0,
+            max_age: usize::MAX,
+            created: 0,
+            dropped: 0,
+            reused: 0,
+        }
+    }
+
+    /// Forget the dropped handles and count the free handles that are used again.
+    fn update(&mut self) {
+        let num_entries = self.entries.len();
+        let mut reused = 0;
+
+        self.entries.retain_mut(|entry| {
+            if entry.all.strong_count() == 0 {
+                return false;
+            }
+
+            let in_use = entry.is_in_use();
+            if in_use && entry.was_free {
+                reused += 1;
+            }
+            entry.was_free = !in_use;
+
+            true
+        });
+
+        self.dropped += num_entries - self.entries.len();
+        self.reused += reused;
+    }
+
+    fn stats(&self) -> HandleStats {
+        let in_use = self
+            .entries
+            .iter()
+            .filter(|entry| entry.is_in_use())
+            .count();
+
+        HandleStats {
+            created: self.created,
+            dropped: self.dropped,
+            reused: self.reused,
+            in_use,
+            free: self.entries.len() - in_use,
+        }
+    }
+
+    fn long_lived(&self) -> impl Iterator<Item = &Entry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.is_in_use() && self.num_syncs - entry.created_at > self.max_age)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory_management::{
+            simple::{DeallocStrategy, SimpleMemoryManagement, SliceStrategy},
+            MemoryManagement,
+        },
+        storage::BytesStorage,
+    };
+
+    // The tracker is global, so the handles created by the other tests are filtered out with the
+    // backtrace of their creation.
+    fn created_by(report: &HandleReport, test_name: &str) -> usize {
+        report
+            .long_lived
+            .iter()
+            .filter(|handle| handle.backtrace.contains(test_name))
+            .count()
+    }
+
+    #[test]
+    fn should_report_long_lived_handles() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Never,
+        );
+
+        enable(1);
+        let leaked = memory_management.reserve(16);
+        let dropped = memory_management.reserve(16);
+
+        sync_point();
+        assert_eq!(created_by(&report(), "should_report_long_lived_handles"), 0);
+
+        core::mem::drop(dropped);
+        sync_point();
+        let report = report();
+        disable();
+
+        assert_eq!(created_by(&report, "should_report_long_lived_handles"), 1);
+        assert!(report.stats.created >= 2);
+        assert!(report.stats.free >= 1);
+        core::mem::drop(leaked);
+    }
+}
@@ -63,9 +63,14 @@ where
 {
     /// Create a new handle.
     pub(crate) fn new(id: Id) -> Self {
+        let all = Arc::new(());
+
+        #[cfg(feature = "std")]
+        crate::handle_tracker::register::<Id>(&all);
+
         Self {
             id: Arc::new(id),
-            all: Arc::new(()),
+            all,
         }
     }
 
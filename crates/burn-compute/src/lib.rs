@@ -25,5 +25,15 @@ pub mod server;
 /// Compute Storage module.
 pub mod storage;
 
+mod uniform;
+
+/// Debug facility tracking the memory handles to find leaks.
+#[cfg(feature = "std")]
+pub mod handle_tracker;
+
+/// Remote compute server module, running the tasks on the device of another machine.
+#[cfg(feature = "remote")]
+pub mod remote;
+
 mod compute;
 pub use compute::*;
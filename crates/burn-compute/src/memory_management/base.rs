@@ -1,4 +1,5 @@
-use crate::storage::ComputeStorage;
+use crate::storage::{ComputeStorage, StorageHandle, StorageHint};
+use burn_common::memory_usage::MemoryUsage;
 
 /// The managed tensor buffer handle that points to some memory segment.
 /// It should not contain actual data.
@@ -29,6 +30,18 @@ pub trait MemoryManagement<Storage: ComputeStorage>: Send + core::fmt::Debug {
     /// Finds a spot in memory for a resource with the given size in bytes, and returns a handle to it
     fn reserve(&mut self, size: usize) -> Self::Handle;
 
+    /// Finds a spot in memory of the kind given by the `hint` for a resource with the given size
+    /// in bytes, and returns a handle to it.
+    ///
+    /// # Notes
+    ///
+    /// The hint is forwarded to the [storage](ComputeStorage::alloc_with_hint), and memory of a
+    /// kind is only reused for reservations of the same kind. Memory managements without
+    /// support for hints reserve regular memory.
+    fn reserve_with_hint(&mut self, size: usize, _hint: StorageHint) -> Self::Handle {
+        self.reserve(size)
+    }
+
     /// Bypass the memory allocation algorithm to allocate data directly.
     ///
     /// # Notes
@@ -43,6 +56,15 @@ pub trait MemoryManagement<Storage: ComputeStorage>: Send + core::fmt::Debug {
     /// Can be useful for servers that want specific control over memory.
     fn dealloc(&mut self, binding: Self::Binding);
 
+    /// Register memory allocated outside of the memory management, e.g. a buffer shared with
+    /// another library, and returns a handle to it.
+    ///
+    /// # Notes
+    ///
+    /// The registered memory is never reused for other allocations, and is deallocated from the
+    /// storage once its handle is no longer referenced.
+    fn register(&mut self, storage: StorageHandle) -> Self::Handle;
+
     /// Fetch the storage used by the memory manager.
     ///
     /// # Notes
@@ -54,4 +76,11 @@ pub trait MemoryManagement<Storage: ComputeStorage>: Send + core::fmt::Debug {
     /// This is useful if you need to time the deallocations based on async computation, or to
     /// change the mode of storage for different reasons.
     fn storage(&mut self) -> &mut Storage;
+
+    /// Returns the current memory usage of the storage.
+    ///
+    /// The default implementation reports no memory usage.
+    fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::default()
+    }
 }
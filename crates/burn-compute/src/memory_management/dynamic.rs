@@ -1,9 +1,10 @@
 use crate::{
     memory_id_type,
-    storage::{ComputeStorage, StorageHandle, StorageUtilization},
+    storage::{ComputeStorage, StorageHandle, StorageHint, StorageUtilization},
 };
 use alloc::vec::Vec;
-use hashbrown::HashMap;
+use burn_common::memory_usage::MemoryUsage;
+use hashbrown::{HashMap, HashSet};
 
 #[cfg(all(not(target_family = "wasm"), feature = "std"))]
 use std::time;
@@ -117,6 +118,7 @@ struct Chunk {
     storage: StorageHandle,
     handle: ChunkHandle,
     slices: Vec<SliceId>,
+    hint: StorageHint,
 }
 
 #[derive(new, Debug)]
@@ -149,6 +151,7 @@ const BUFFER_ALIGNMENT: usize = 32;
 pub struct DynamicMemoryManagement<Storage> {
     chunks: HashMap<ChunkId, Chunk>,
     slices: HashMap<SliceId, Slice>,
+    external: HashSet<ChunkId>,
     merging_strategy: MergingStrategy,
     slice_strategy: SliceStrategy,
     storage: Storage,
@@ -216,7 +219,15 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for DynamicMemoryManagem
     ///
     /// Also clean ups, merging free slices together if permitted by the merging strategy
     fn reserve(&mut self, size: usize) -> Self::Handle {
-        let handle = self.reserve_algorithm(size);
+        self.reserve_with_hint(size, StorageHint::Device)
+    }
+
+    /// Reserves memory of the kind given by the hint, only reusing the slices of chunks of the
+    /// same kind.
+    fn reserve_with_hint(&mut self, size: usize, hint: StorageHint) -> Self::Handle {
+        self.cleanup_external();
+
+        let handle = self.reserve_algorithm(size, hint);
 
         if self.merging_strategy.should_perform_defragmentation() {
             self.defragmentation();
@@ -226,24 +237,14 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for DynamicMemoryManagem
     }
 
     fn alloc(&mut self, size: usize) -> Self::Handle {
-        let handle_chunk = self.create_chunk(size);
-        let chunk_id = *handle_chunk.id();
-
-        let slice = self.create_slice(0, size, handle_chunk);
-        let handle_slice = slice.handle.clone();
-
-        let chunk = self.chunks.get_mut(&chunk_id).unwrap();
-        chunk.slices.push(*handle_slice.id());
-
-        self.slices.insert(*handle_slice.id(), slice);
-
-        DynamicHandle::Slice(handle_slice)
+        self.alloc_with_hint(size, StorageHint::Device)
     }
 
     fn dealloc(&mut self, binding: Self::Binding) {
         match binding {
             DynamicBinding::Chunk(chunk) => {
                 if let Some(chunk) = self.chunks.remove(chunk.id()) {
+                    self.external.remove(chunk.handle.id());
                     self.storage.dealloc(chunk.storage.id);
                 }
             }
@@ -251,9 +252,46 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for DynamicMemoryManagem
         }
     }
 
+    fn register(&mut self, storage: StorageHandle) -> Self::Handle {
+        let handle_chunk = ChunkHandle::new();
+        let handle_slice = SliceHandle::new();
+        let chunk_id = *handle_chunk.id();
+
+        // Every allocation is a slice of a chunk, so the registered memory is a single slice
+        // covering its whole chunk.
+        let storage_slice = StorageHandle::new(storage.id.clone(), storage.utilization.clone());
+        let slice = Slice::new(storage_slice, handle_slice.clone(), handle_chunk.clone(), 0);
+
+        let mut chunk = Chunk::new(storage, handle_chunk, Vec::new(), StorageHint::Device);
+        chunk.slices.push(*handle_slice.id());
+
+        self.chunks.insert(chunk_id, chunk);
+        self.slices.insert(*handle_slice.id(), slice);
+        self.external.insert(chunk_id);
+
+        DynamicHandle::Slice(handle_slice)
+    }
+
     fn storage(&mut self) -> &mut Storage {
         &mut self.storage
     }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        let mut usage = MemoryUsage {
+            bytes_reserved: self.chunks.values().map(|chunk| chunk.storage.size()).sum(),
+            ..Default::default()
+        };
+
+        // Every allocation is a slice of a chunk.
+        for slice in self.slices.values() {
+            if !slice.handle.is_free() {
+                usage.bytes_in_use += slice.storage.size();
+                usage.number_allocs += 1;
+            }
+        }
+
+        usage
+    }
 }
 
 impl<Storage: ComputeStorage> DynamicMemoryManagement<Storage> {
@@ -266,30 +304,51 @@ impl<Storage: ComputeStorage> DynamicMemoryManagement<Storage> {
         Self {
             chunks: HashMap::new(),
             slices: HashMap::new(),
+            external: HashSet::new(),
             merging_strategy,
             slice_strategy,
             storage,
         }
     }
 
-    fn reserve_algorithm(&mut self, size: usize) -> DynamicHandle {
+    fn reserve_algorithm(&mut self, size: usize, hint: StorageHint) -> DynamicHandle {
         // Looks for a large enough, existing but unused chunk of memory.
-        let slice = self.get_free_slice(size);
+        let slice = self.get_free_slice(size, hint);
 
         match slice {
             Some(slice) => DynamicHandle::Slice(slice.clone()),
-            None => self.alloc(size),
+            None => self.alloc_with_hint(size, hint),
         }
     }
 
+    /// Allocates a chunk of the given kind, used by a single slice.
+    fn alloc_with_hint(&mut self, size: usize, hint: StorageHint) -> DynamicHandle {
+        let handle_chunk = self.create_chunk(size, hint);
+        let chunk_id = *handle_chunk.id();
+
+        let slice = self.create_slice(0, size, handle_chunk);
+        let handle_slice = slice.handle.clone();
+
+        let chunk = self.chunks.get_mut(&chunk_id).unwrap();
+        chunk.slices.push(*handle_slice.id());
+
+        self.slices.insert(*handle_slice.id(), slice);
+
+        DynamicHandle::Slice(handle_slice)
+    }
+
     fn find_free_slice_best_fit(
         &self,
         size: usize,
         effective_size: usize,
+        hint: StorageHint,
     ) -> Option<(SliceId, usize)> {
         let mut size_diff_current = usize::MAX;
         let mut found = None;
-        for (__, chunk) in self.chunks.iter() {
+        for (chunk_id, chunk) in self.chunks.iter() {
+            if self.external.contains(chunk_id) || chunk.hint != hint {
+                continue;
+            }
             if size < MIN_SIZE_NEEDED_TO_OFFSET && chunk.slices.len() > 1 {
                 continue;
             }
@@ -382,11 +441,11 @@ impl<Storage: ComputeStorage> DynamicMemoryManagement<Storage> {
 
     /// Finds the smallest of the free and large enough chunks to fit `size`
     /// Returns the chunk's id and size.
-    fn get_free_slice(&mut self, size: usize) -> Option<SliceHandle> {
+    fn get_free_slice(&mut self, size: usize, hint: StorageHint) -> Option<SliceHandle> {
         let padding = Self::calculate_padding(size);
         let effective_size = size + padding;
 
-        let found = self.find_free_slice_best_fit(size, effective_size);
+        let found = self.find_free_slice_best_fit(size, effective_size, hint);
         let (slice_id, size_diff_current) = match found {
             Some(val) => val,
             None => {
@@ -447,17 +506,17 @@ impl<Storage: ComputeStorage> DynamicMemoryManagement<Storage> {
         chunk.slices.push(slice_id);
     }
 
-    /// Creates a chunk of given size by allocating on the storage.
-    fn create_chunk(&mut self, size: usize) -> ChunkHandle {
+    /// Creates a chunk of given size and kind by allocating on the storage.
+    fn create_chunk(&mut self, size: usize, hint: StorageHint) -> ChunkHandle {
         let padding = Self::calculate_padding(size);
         let effective_size = size + padding;
 
-        let storage = self.storage.alloc(effective_size);
+        let storage = self.storage.alloc_with_hint(effective_size, hint);
         let handle = ChunkHandle::new();
 
         self.chunks.insert(
             *handle.id(),
-            Chunk::new(storage, handle.clone(), Vec::new()),
+            Chunk::new(storage, handle.clone(), Vec::new(), hint),
         );
 
         handle
@@ -547,6 +606,34 @@ impl<Storage: ComputeStorage> DynamicMemoryManagement<Storage> {
         }
     }
 
+    /// Deallocates the externally registered chunks that are no longer referenced.
+    fn cleanup_external(&mut self) {
+        let mut ids_to_remove = Vec::new();
+
+        for chunk_id in self.external.iter() {
+            let chunk = self.chunks.get(chunk_id).unwrap();
+            let is_free = chunk
+                .slices
+                .iter()
+                .all(|slice_id| self.slices.get(slice_id).unwrap().handle.is_free());
+
+            if is_free {
+                ids_to_remove.push(*chunk_id);
+            }
+        }
+
+        for chunk_id in ids_to_remove {
+            let chunk = self.chunks.remove(&chunk_id).unwrap();
+
+            for slice_id in chunk.slices.iter() {
+                self.slices.remove(slice_id);
+            }
+
+            self.external.remove(&chunk_id);
+            self.storage.dealloc(chunk.storage.id);
+        }
+    }
+
     fn calculate_padding(size: usize) -> usize {
         let rem = size % BUFFER_ALIGNMENT;
         if rem != 0 {
@@ -574,7 +661,7 @@ mod tests {
         );
 
         let chunk_size = 4;
-        let simple_handle = memory_management.create_chunk(chunk_size);
+        let simple_handle = memory_management.create_chunk(chunk_size, StorageHint::Device);
 
         let x = simple_handle.clone();
         core::mem::drop(simple_handle);
@@ -591,7 +678,7 @@ mod tests {
         );
 
         let chunk_size = 4;
-        let simple_handle = memory_management.create_chunk(chunk_size);
+        let simple_handle = memory_management.create_chunk(chunk_size, StorageHint::Device);
 
         let x = simple_handle.clone();
 
@@ -613,6 +700,47 @@ mod tests {
         assert_eq!(memory_management.chunks.len(), 2);
     }
 
+    #[test]
+    fn registered_chunk_is_deallocated_when_free() {
+        let mut memory_management = DynamicMemoryManagement::new(
+            BytesStorage::default(),
+            MergingStrategy::Never,
+            SliceStrategy::Never,
+        );
+        let chunk_size = 32;
+        let storage = memory_management.storage().alloc(chunk_size);
+        let registered = memory_management.register(storage);
+        let _handle = memory_management.reserve(chunk_size);
+
+        assert_eq!(memory_management.chunks.len(), 2);
+
+        drop(registered);
+        let _handle = memory_management.reserve(chunk_size);
+
+        assert_eq!(memory_management.chunks.len(), 2);
+        assert!(memory_management.external.is_empty());
+    }
+
+    #[test]
+    fn reservations_only_reuse_chunks_of_the_same_hint() {
+        let mut memory_management = DynamicMemoryManagement::new(
+            BytesStorage::default(),
+            MergingStrategy::Never,
+            SliceStrategy::MinimumSize(0),
+        );
+
+        let device = memory_management.reserve(32);
+        drop(device);
+
+        let host_mapped = memory_management.reserve_with_hint(32, StorageHint::HostMapped);
+        assert_eq!(memory_management.chunks.len(), 2);
+        drop(host_mapped);
+
+        let _device = memory_management.reserve(32);
+        let _host_mapped = memory_management.reserve_with_hint(32, StorageHint::HostMapped);
+        assert_eq!(memory_management.chunks.len(), 2);
+    }
+
     #[test]
     fn when_big_chunk_is_freed_should_be_filled_with_smaller_slices() {
         let mut memory_management = DynamicMemoryManagement::new(
@@ -644,7 +772,7 @@ mod tests {
             SliceStrategy::Ratio(0.2),
         );
 
-        let chunk_handle = memory_management.create_chunk(32 + 32);
+        let chunk_handle = memory_management.create_chunk(32 + 32, StorageHint::Device);
         let slice = memory_management.create_slice(0, 32 + 32, chunk_handle.clone());
         memory_management.insert_slice(slice, *chunk_handle.id());
 
@@ -670,7 +798,8 @@ mod tests {
         let slice_size = 32;
         let num_of_slice = 7;
 
-        let chunk_handle = memory_management.create_chunk(slice_size * num_of_slice);
+        let chunk_handle =
+            memory_management.create_chunk(slice_size * num_of_slice, StorageHint::Device);
         let chunk_id = *chunk_handle.id();
         let slice = memory_management.create_slice(0, slice_size * num_of_slice, chunk_handle);
         memory_management.insert_slice(slice, chunk_id);
@@ -840,4 +969,22 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn memory_usage_should_only_count_used_handles() {
+        let mut memory_management = DynamicMemoryManagement::new(
+            BytesStorage::default(),
+            MergingStrategy::Never,
+            SliceStrategy::Never,
+        );
+        let _handle = memory_management.reserve(10);
+        let other = memory_management.reserve(20);
+        core::mem::drop(other);
+
+        let usage = memory_management.memory_usage();
+
+        assert_eq!(usage.bytes_in_use, 10);
+        assert_eq!(usage.number_allocs, 1);
+        assert!(usage.bytes_reserved >= 30);
+    }
 }
@@ -1,9 +1,10 @@
 use crate::{
     memory_id_type,
-    storage::{ComputeStorage, StorageHandle, StorageUtilization},
+    storage::{ComputeStorage, StorageHandle, StorageHint, StorageUtilization},
 };
 use alloc::vec::Vec;
-use hashbrown::HashMap;
+use burn_common::memory_usage::MemoryUsage;
+use hashbrown::{HashMap, HashSet};
 
 #[cfg(all(not(target_family = "wasm"), feature = "std"))]
 use std::time;
@@ -36,7 +37,7 @@ pub enum SimpleBinding {
 }
 
 /// The strategy defines the frequency at which deallocation of unused memory chunks should occur.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum DeallocStrategy {
     /// Once every n calls to reserve.
     PeriodTick {
@@ -58,7 +59,7 @@ pub enum DeallocStrategy {
 }
 
 /// The strategy defines when to reuse chunk with slices.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SliceStrategy {
     /// Never use slices.
     Never,
@@ -117,6 +118,7 @@ struct Chunk {
     storage: StorageHandle,
     handle: ChunkHandle,
     slices: Vec<SliceId>,
+    hint: StorageHint,
 }
 
 #[derive(new)]
@@ -134,6 +136,7 @@ struct Slice {
 pub struct SimpleMemoryManagement<Storage> {
     chunks: HashMap<ChunkId, Chunk>,
     slices: HashMap<SliceId, Slice>,
+    external: HashSet<ChunkId>,
     dealloc_strategy: DeallocStrategy,
     slice_strategy: SliceStrategy,
     storage: Storage,
@@ -201,9 +204,14 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for SimpleMemoryManageme
     ///
     /// Also clean ups, removing unused slices, and chunks if permitted by deallocation strategy.
     fn reserve(&mut self, size: usize) -> Self::Handle {
+        self.reserve_with_hint(size, StorageHint::Device)
+    }
+
+    /// Reserves memory of the kind given by the hint, only reusing the chunks of the same kind.
+    fn reserve_with_hint(&mut self, size: usize, hint: StorageHint) -> Self::Handle {
         self.cleanup_slices();
 
-        let handle = self.reserve_algorithm(size);
+        let handle = self.reserve_algorithm(size, hint);
 
         if self.dealloc_strategy.should_dealloc() {
             self.cleanup_chunks();
@@ -213,13 +221,14 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for SimpleMemoryManageme
     }
 
     fn alloc(&mut self, size: usize) -> Self::Handle {
-        self.create_chunk(size)
+        self.create_chunk(size, StorageHint::Device)
     }
 
     fn dealloc(&mut self, binding: Self::Binding) {
         match binding {
             SimpleBinding::Chunk(chunk) => {
                 if let Some(chunk) = self.chunks.remove(chunk.id()) {
+                    self.external.remove(chunk.handle.id());
                     self.storage.dealloc(chunk.storage.id);
                 }
             }
@@ -227,9 +236,44 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for SimpleMemoryManageme
         }
     }
 
+    fn register(&mut self, storage: StorageHandle) -> Self::Handle {
+        let handle = ChunkHandle::new();
+
+        self.external.insert(*handle.id());
+        self.chunks.insert(
+            *handle.id(),
+            Chunk::new(storage, handle.clone(), Vec::new(), StorageHint::Device),
+        );
+
+        SimpleHandle::Chunk(handle)
+    }
+
     fn storage(&mut self) -> &mut Storage {
         &mut self.storage
     }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        let mut usage = MemoryUsage::default();
+
+        for chunk in self.chunks.values() {
+            usage.bytes_reserved += chunk.storage.size();
+
+            // A chunk used through a slice is never free, so only the slice size is counted.
+            if chunk.slices.is_empty() && !chunk.handle.is_free() {
+                usage.bytes_in_use += chunk.storage.size();
+                usage.number_allocs += 1;
+            }
+        }
+
+        for slice in self.slices.values() {
+            if !slice.handle.is_free() {
+                usage.bytes_in_use += slice.storage.size();
+                usage.number_allocs += 1;
+            }
+        }
+
+        usage
+    }
 }
 
 impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
@@ -242,15 +286,16 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
         Self {
             chunks: HashMap::new(),
             slices: HashMap::new(),
+            external: HashSet::new(),
             dealloc_strategy,
             slice_strategy,
             storage,
         }
     }
 
-    fn reserve_algorithm(&mut self, size: usize) -> SimpleHandle {
+    fn reserve_algorithm(&mut self, size: usize, hint: StorageHint) -> SimpleHandle {
         // Looks for a large enough, existing but unused chunk of memory.
-        let chunk = self.find_free_chunk(size);
+        let chunk = self.find_free_chunk(size, hint);
 
         match chunk {
             Some(chunk) => {
@@ -263,19 +308,24 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
                 }
             }
             // If no chunk available, creates one of exactly the right size.
-            None => self.create_chunk(size),
+            None => self.create_chunk(size, hint),
         }
     }
 
-    /// Finds the smallest of the free and large enough chunks to fit `size`
+    /// Finds the smallest of the free and large enough chunks of the given kind to fit `size`
     /// Returns the chunk's id and size.
-    fn find_free_chunk(&self, size: usize) -> Option<&Chunk> {
+    fn find_free_chunk(&self, size: usize, hint: StorageHint) -> Option<&Chunk> {
         let mut size_diff_current = usize::MAX;
         let mut current = None;
 
         for chunk in self.chunks.values() {
-            // If chunk is already used, we do not choose it
-            if !chunk.handle.is_free() {
+            // If chunk is already used or registered externally, we do not choose it
+            if !chunk.handle.is_free() || self.external.contains(chunk.handle.id()) {
+                continue;
+            }
+
+            // Memory of another kind would behave differently than requested.
+            if chunk.hint != hint {
                 continue;
             }
 
@@ -328,14 +378,14 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
         SimpleHandle::Slice(handle_slice)
     }
 
-    /// Creates a chunk of given size by allocating on the storage.
-    fn create_chunk(&mut self, size: usize) -> SimpleHandle {
-        let storage = self.storage.alloc(size);
+    /// Creates a chunk of given size and kind by allocating on the storage.
+    fn create_chunk(&mut self, size: usize, hint: StorageHint) -> SimpleHandle {
+        let storage = self.storage.alloc_with_hint(size, hint);
         let handle = ChunkHandle::new();
 
         self.chunks.insert(
             *handle.id(),
-            Chunk::new(storage, handle.clone(), Vec::new()),
+            Chunk::new(storage, handle.clone(), Vec::new(), hint),
         );
 
         SimpleHandle::Chunk(handle)
@@ -355,6 +405,7 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
             .iter()
             .map(|chunk_id| self.chunks.remove(chunk_id).unwrap())
             .for_each(|chunk| {
+                self.external.remove(chunk.handle.id());
                 self.storage.dealloc(chunk.storage.id);
             });
     }
@@ -387,6 +438,26 @@ mod tests {
         storage::BytesStorage,
     };
 
+    #[test]
+    fn reservations_only_reuse_chunks_of_the_same_hint() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Never,
+        );
+
+        let device = memory_management.reserve(16);
+        core::mem::drop(device);
+
+        let sparse = memory_management.reserve_with_hint(16, StorageHint::Sparse);
+        assert_eq!(memory_management.chunks.len(), 2);
+        core::mem::drop(sparse);
+
+        let _device = memory_management.reserve(16);
+        let _sparse = memory_management.reserve_with_hint(16, StorageHint::Sparse);
+        assert_eq!(memory_management.chunks.len(), 2);
+    }
+
     #[test]
     fn can_mut_with_single_tensor_reference() {
         let mut memory_management = SimpleMemoryManagement::new(
@@ -396,7 +467,7 @@ mod tests {
         );
 
         let chunk_size = 4;
-        let simple_handle = memory_management.create_chunk(chunk_size);
+        let simple_handle = memory_management.create_chunk(chunk_size, StorageHint::Device);
 
         let x = simple_handle.clone();
         core::mem::drop(simple_handle);
@@ -413,7 +484,7 @@ mod tests {
         );
 
         let chunk_size = 4;
-        let simple_handle = memory_management.create_chunk(chunk_size);
+        let simple_handle = memory_management.create_chunk(chunk_size, StorageHint::Device);
 
         let x = simple_handle.clone();
 
@@ -450,6 +521,27 @@ mod tests {
         assert_eq!(memory_management.chunks.len(), 0);
     }
 
+    #[test]
+    fn registered_chunk_is_never_reused() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Never,
+        );
+        let chunk_size = 4;
+        let storage = memory_management.storage().alloc(chunk_size);
+        let registered = memory_management.register(storage);
+        drop(registered);
+        let _handle = memory_management.reserve(chunk_size);
+
+        assert_eq!(memory_management.chunks.len(), 2);
+
+        memory_management.cleanup_chunks();
+
+        assert_eq!(memory_management.chunks.len(), 1);
+        assert!(memory_management.external.is_empty());
+    }
+
     #[test]
     fn never_dealloc_strategy_never_deallocs() {
         let mut never_dealloc = DeallocStrategy::Never;
@@ -550,4 +642,22 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn memory_usage_should_only_count_used_handles() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Never,
+        );
+        let _handle = memory_management.reserve(10);
+        let other = memory_management.reserve(20);
+        core::mem::drop(other);
+
+        let usage = memory_management.memory_usage();
+
+        assert_eq!(usage.bytes_in_use, 10);
+        assert_eq!(usage.number_allocs, 1);
+        assert!(usage.bytes_reserved >= 30);
+    }
 }
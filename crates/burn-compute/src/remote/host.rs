@@ -0,0 +1,135 @@
+use std::io;
+
+use burn_common::sync_type::SyncType;
+use hashbrown::HashMap;
+use serde::de::DeserializeOwned;
+
+use super::{
+    protocol::{receive, send, Request, Response},
+    Transport,
+};
+use crate::{
+    channel::ComputeChannel,
+    client::ComputeClient,
+    server::{Binding, ComputeServer, Handle, ServerError},
+};
+
+/// Run the tasks received from a [remote server](super::RemoteServer) with the given client,
+/// until the client of the remote server disconnects.
+///
+/// The kernels are created from the [descriptions](super::RemoteKernel::Description) sent by the
+/// client with the `kernel` function.
+///
+/// # Errors
+///
+/// When a message can't be exchanged with the client, except for the disconnection of the client,
+/// which ends the session without an error, or when a request of the client is invalid, e.g. it
+/// uses a handle that wasn't created.
+pub fn serve<S, C, D, T, F>(
+    client: &ComputeClient<S, C>,
+    mut transport: T,
+    kernel: F,
+) -> io::Result<()>
+where
+    S: ComputeServer,
+    C: ComputeChannel<S>,
+    D: DeserializeOwned,
+    T: Transport,
+    F: Fn(D) -> S::Kernel,
+{
+    let mut handles = HashMap::<u64, Handle<S>>::new();
+
+    let binding = |handles: &HashMap<u64, Handle<S>>, id: u64| -> io::Result<Binding<S>> {
+        handles
+            .get(&id)
+            .map(|handle| handle.clone().binding())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("The remote handle {id} wasn't created by the client"),
+                )
+            })
+    };
+
+    loop {
+        let request = match receive::<_, Request<D>>(&mut transport) {
+            Ok(request) => request,
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(()),
+            Err(err) => return Err(err),
+        };
+
+        match request {
+            Request::Create { id, data } => {
+                handles.insert(id, client.create(&data));
+            }
+            Request::Empty { id, size, hint } => {
+                handles.insert(id, client.empty_with_hint(size, hint));
+            }
+            Request::Execute {
+                kernel: desc,
+                bindings,
+            } => {
+                let bindings = bindings
+                    .into_iter()
+                    .map(|id| binding(&handles, id))
+                    .collect::<io::Result<_>>()?;
+                client.execute(kernel(desc), bindings);
+            }
+            Request::ExecuteIndirect {
+                kernel: desc,
+                cube_count,
+                bindings,
+            } => {
+                let cube_count = binding(&handles, cube_count)?;
+                let bindings = bindings
+                    .into_iter()
+                    .map(|id| binding(&handles, id))
+                    .collect::<io::Result<_>>()?;
+                client.execute_indirect(kernel(desc), cube_count, bindings);
+            }
+            Request::Free { ids } => {
+                for id in ids {
+                    handles.remove(&id);
+                }
+            }
+            Request::Read { id } => {
+                let data = client
+                    .read(binding(&handles, id)?)
+                    .read_sync()
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::Unsupported,
+                            "The host can't read data synchronously",
+                        )
+                    })?;
+                send(&mut transport, &Response::Data(data))?;
+            }
+            Request::Sync { wait } => {
+                client.sync(match wait {
+                    true => SyncType::Wait,
+                    false => SyncType::Flush,
+                });
+                send(&mut transport, &Response::Synced)?;
+            }
+            Request::MemoryUsage => {
+                let usage = client.memory_usage();
+                send(
+                    &mut transport,
+                    &Response::MemoryUsage {
+                        bytes_in_use: usage.bytes_in_use,
+                        bytes_reserved: usage.bytes_reserved,
+                        number_allocs: usage.number_allocs,
+                    },
+                )?;
+            }
+            Request::Status => {
+                let status = match client.status() {
+                    Ok(()) => None,
+                    Err(ServerError::DeviceLost(reason)) => Some(reason),
+                    Err(err) => Some(err.to_string()),
+                };
+                send(&mut transport, &Response::Status(status))?;
+            }
+        }
+    }
+}
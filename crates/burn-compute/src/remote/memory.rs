@@ -0,0 +1,143 @@
+use crate::{
+    memory_id_type,
+    memory_management::{MemoryBinding, MemoryHandle, MemoryManagement},
+    storage::{ComputeStorage, StorageHandle, StorageId, StorageUtilization},
+};
+use burn_common::memory_usage::MemoryUsage;
+use hashbrown::HashMap;
+
+// The RemoteId identifies a handle on the host, and tracks the references to it on the client.
+memory_id_type!(RemoteId, RemoteHandle, RemoteBinding);
+
+impl RemoteId {
+    pub(crate) fn to_u64(self) -> u64 {
+        self.value as u64
+    }
+}
+
+impl MemoryHandle<RemoteBinding> for RemoteHandle {
+    fn can_mut(&self) -> bool {
+        self.value.can_mut()
+    }
+
+    fn binding(self) -> RemoteBinding {
+        self.binding()
+    }
+}
+
+impl MemoryBinding for RemoteBinding {}
+
+/// The resource of a [remote handle](RemoteHandle), whose memory lives on the host.
+#[derive(Debug, Clone)]
+pub struct RemoteResource {
+    /// The size of the resource in bytes.
+    pub size: usize,
+}
+
+/// The storage of a [remote server](super::RemoteServer), which only gives an id to the
+/// allocations of the client, their memory living on the host.
+#[derive(Debug, Default)]
+pub struct RemoteStorage;
+
+impl ComputeStorage for RemoteStorage {
+    type Resource = RemoteResource;
+
+    fn get(&mut self, handle: &StorageHandle) -> Self::Resource {
+        RemoteResource {
+            size: handle.size(),
+        }
+    }
+
+    fn alloc(&mut self, size: usize) -> StorageHandle {
+        StorageHandle::new(StorageId::new(), StorageUtilization::Full(size))
+    }
+
+    fn dealloc(&mut self, _id: StorageId) {
+        // The memory is freed on the host once its handle is no longer referenced.
+    }
+}
+
+/// Tracks the handles of a [remote server](super::RemoteServer), each one matching a handle of
+/// the host, which manages the actual memory.
+///
+/// The memory of the handles is only created on the host by the requests of the remote server,
+/// so a [registered](MemoryManagement::register) storage must be created on the host the same
+/// way before being used.
+#[derive(Debug, Default)]
+pub struct RemoteMemoryManagement {
+    storage: RemoteStorage,
+    handles: HashMap<RemoteId, (RemoteHandle, StorageHandle)>,
+}
+
+impl RemoteMemoryManagement {
+    /// Removes the handles no longer referenced, returning their ids to free them on the host.
+    pub(crate) fn cleanup(&mut self) -> Vec<u64> {
+        let mut freed = Vec::new();
+
+        self.handles.retain(|id, (handle, _)| {
+            let free = handle.is_free();
+            if free {
+                freed.push(id.to_u64());
+            }
+            !free
+        });
+
+        freed
+    }
+
+    fn track(&mut self, storage: StorageHandle) -> RemoteHandle {
+        let handle = RemoteHandle::new();
+        self.handles.insert(*handle.id(), (handle.clone(), storage));
+
+        handle
+    }
+}
+
+impl MemoryManagement<RemoteStorage> for RemoteMemoryManagement {
+    type Handle = RemoteHandle;
+    type Binding = RemoteBinding;
+
+    fn get(&mut self, binding: Self::Binding) -> RemoteResource {
+        let (_, storage) = self
+            .handles
+            .get(binding.id())
+            .expect("The handle should be tracked by the remote memory management");
+
+        self.storage.get(storage)
+    }
+
+    fn reserve(&mut self, size: usize) -> Self::Handle {
+        let storage = self.storage.alloc(size);
+        self.track(storage)
+    }
+
+    fn alloc(&mut self, size: usize) -> Self::Handle {
+        self.reserve(size)
+    }
+
+    fn dealloc(&mut self, binding: Self::Binding) {
+        self.handles.remove(binding.id());
+    }
+
+    fn register(&mut self, storage: StorageHandle) -> Self::Handle {
+        self.track(storage)
+    }
+
+    fn storage(&mut self) -> &mut RemoteStorage {
+        &mut self.storage
+    }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        let bytes = self
+            .handles
+            .values()
+            .map(|(_, storage)| storage.size())
+            .sum();
+
+        MemoryUsage {
+            bytes_in_use: bytes,
+            bytes_reserved: bytes,
+            number_allocs: self.handles.len(),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Run the tasks of a client on the compute server of another machine.
+//!
+//! The client uses a [remote server](RemoteServer), sending each task through a
+//! [transport](Transport), such as the length-prefixed [TCP transport](TcpTransport). The machine
+//! owning the device runs the tasks with the client of its own compute server with [serve].
+//!
+//! The handles of the client are numbered by the client, so creating a resource or executing a
+//! kernel doesn't wait for the host, only reading data and synchronizing do.
+
+mod host;
+mod memory;
+mod protocol;
+mod server;
+mod transport;
+
+pub use host::*;
+pub use memory::*;
+pub use server::*;
+pub use transport::*;
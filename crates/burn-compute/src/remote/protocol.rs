@@ -0,0 +1,70 @@
+use super::Transport;
+use crate::storage::StorageHint;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// A task sent by a [remote server](super::RemoteServer) to its host.
+///
+/// Handles are identified by the id given by the client.
+#[derive(Serialize, Deserialize, Debug)]
+pub(crate) enum Request<K> {
+    Create {
+        id: u64,
+        data: Vec<u8>,
+    },
+    Empty {
+        id: u64,
+        size: usize,
+        hint: StorageHint,
+    },
+    Execute {
+        kernel: K,
+        bindings: Vec<u64>,
+    },
+    ExecuteIndirect {
+        kernel: K,
+        cube_count: u64,
+        bindings: Vec<u64>,
+    },
+    Free {
+        ids: Vec<u64>,
+    },
+    Read {
+        id: u64,
+    },
+    Sync {
+        wait: bool,
+    },
+    MemoryUsage,
+    Status,
+}
+
+/// The response of the host to the requests waiting for one.
+#[derive(Serialize, Deserialize, Debug)]
+pub(crate) enum Response {
+    Data(Vec<u8>),
+    Synced,
+    MemoryUsage {
+        bytes_in_use: usize,
+        bytes_reserved: usize,
+        number_allocs: usize,
+    },
+    Status(Option<String>),
+}
+
+pub(crate) fn send<T: Transport, M: Serialize>(
+    transport: &mut T,
+    message: &M,
+) -> std::io::Result<()> {
+    let bytes = bincode::serde::encode_to_vec(message, bincode::config::standard())
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err.to_string()))?;
+
+    transport.send(&bytes)
+}
+
+pub(crate) fn receive<T: Transport, M: DeserializeOwned>(transport: &mut T) -> std::io::Result<M> {
+    let bytes = transport.receive()?;
+    let (message, _) = bincode::serde::decode_from_slice(&bytes, bincode::config::standard())
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err.to_string()))?;
+
+    Ok(message)
+}
@@ -0,0 +1,242 @@
+use core::marker::PhantomData;
+
+use burn_common::{memory_usage::MemoryUsage, reader::Reader, sync_type::SyncType};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{
+    protocol::{receive, send, Request, Response},
+    RemoteMemoryManagement, RemoteResource, RemoteStorage, Transport,
+};
+use crate::{
+    memory_management::MemoryManagement,
+    server::{Binding, ComputeServer, Handle, ServerError},
+    storage::StorageHint,
+    tune::AutotuneKey,
+};
+
+/// Number of handles reserved between two cleanups of the handles no longer referenced.
+const CLEANUP_PERIOD: usize = 128;
+
+/// A kernel that can be sent to the host of a [remote server](RemoteServer).
+///
+/// The host creates its own kernel from the description, see [serve](super::serve).
+pub trait RemoteKernel: Send {
+    /// The description of the kernel sent to the host.
+    type Description: Serialize + DeserializeOwned;
+
+    /// Describe the kernel to execute it on the host.
+    fn describe(self) -> Self::Description;
+}
+
+/// A [compute server](ComputeServer) running its tasks on the server of a host, reached through
+/// a [transport](Transport).
+///
+/// Only reading data, synchronizing and fetching the memory usage or the status wait for the
+/// host. When the connection is lost, the tasks that don't wait for the host are dropped and the
+/// [status](ComputeServer::status) returns [disconnected](ServerError::Disconnected), while the
+/// reads panic.
+pub struct RemoteServer<K, AK, T> {
+    transport: T,
+    memory_management: RemoteMemoryManagement,
+    num_reserved: usize,
+    disconnected: Option<String>,
+    _kernel: PhantomData<fn() -> (K, AK)>,
+}
+
+impl<K, AK, T> RemoteServer<K, AK, T>
+where
+    K: RemoteKernel,
+    AK: AutotuneKey,
+    T: Transport,
+{
+    /// Create a new remote server, connected to its host through the given transport.
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            memory_management: RemoteMemoryManagement::default(),
+            num_reserved: 0,
+            disconnected: None,
+            _kernel: PhantomData,
+        }
+    }
+
+    fn send(&mut self, request: Request<K::Description>) {
+        if self.disconnected.is_some() {
+            return;
+        }
+
+        if let Err(err) = send(&mut self.transport, &request) {
+            self.disconnected = Some(err.to_string());
+        }
+    }
+
+    fn request(&mut self, request: Request<K::Description>) -> Result<Response, String> {
+        if let Some(reason) = &self.disconnected {
+            return Err(reason.clone());
+        }
+
+        let response =
+            send(&mut self.transport, &request).and_then(|_| receive(&mut self.transport));
+
+        response.map_err(|err| {
+            let reason = err.to_string();
+            self.disconnected = Some(reason.clone());
+            reason
+        })
+    }
+
+    fn reserve(&mut self, size: usize) -> Handle<Self> {
+        self.num_reserved += 1;
+        if self.num_reserved % CLEANUP_PERIOD == 0 {
+            self.cleanup();
+        }
+
+        Handle::new(self.memory_management.reserve(size))
+    }
+
+    /// Free the memory of the handles no longer referenced on the host.
+    fn cleanup(&mut self) {
+        let ids = self.memory_management.cleanup();
+
+        if !ids.is_empty() {
+            self.send(Request::Free { ids });
+        }
+    }
+}
+
+fn binding_id<K, AK, T>(binding: &Binding<RemoteServer<K, AK, T>>) -> u64
+where
+    K: RemoteKernel,
+    AK: AutotuneKey,
+    T: Transport,
+{
+    binding.memory.id().to_u64()
+}
+
+impl<K, AK, T> ComputeServer for RemoteServer<K, AK, T>
+where
+    K: RemoteKernel,
+    AK: AutotuneKey,
+    T: Transport,
+{
+    type Kernel = K;
+    type Storage = RemoteStorage;
+    type MemoryManagement = RemoteMemoryManagement;
+    type AutotuneKey = AK;
+
+    fn read(&mut self, binding: Binding<Self>) -> Reader<Vec<u8>> {
+        let id = binding_id(&binding);
+
+        match self.request(Request::Read { id }) {
+            Ok(Response::Data(data)) => Reader::Concrete(data),
+            Ok(response) => panic!("Unexpected response of the remote server: {response:?}"),
+            Err(reason) => panic!("The remote server was disconnected: {reason}"),
+        }
+    }
+
+    fn get_resource(&mut self, binding: Binding<Self>) -> RemoteResource {
+        self.memory_management.get(binding.memory)
+    }
+
+    fn create(&mut self, data: &[u8]) -> Handle<Self> {
+        let handle = self.reserve(data.len());
+        let id = handle.memory.id().to_u64();
+
+        self.send(Request::Create {
+            id,
+            data: data.to_vec(),
+        });
+
+        handle
+    }
+
+    fn empty(&mut self, size: usize) -> Handle<Self> {
+        self.empty_with_hint(size, StorageHint::Device)
+    }
+
+    fn empty_with_hint(&mut self, size: usize, hint: StorageHint) -> Handle<Self> {
+        let handle = self.reserve(size);
+        let id = handle.memory.id().to_u64();
+
+        self.send(Request::Empty { id, size, hint });
+
+        handle
+    }
+
+    fn execute(&mut self, kernel: Self::Kernel, bindings: Vec<Binding<Self>>) {
+        let bindings = bindings.iter().map(binding_id).collect();
+
+        self.send(Request::Execute {
+            kernel: kernel.describe(),
+            bindings,
+        });
+    }
+
+    fn execute_indirect(
+        &mut self,
+        kernel: Self::Kernel,
+        cube_count: Binding<Self>,
+        bindings: Vec<Binding<Self>>,
+    ) {
+        let cube_count = binding_id(&cube_count);
+        let bindings = bindings.iter().map(binding_id).collect();
+
+        self.send(Request::ExecuteIndirect {
+            kernel: kernel.describe(),
+            cube_count,
+            bindings,
+        });
+    }
+
+    fn sync(&mut self, command: SyncType) {
+        self.cleanup();
+
+        let wait = match command {
+            SyncType::Flush => false,
+            SyncType::Wait => true,
+        };
+
+        // A lost connection is reported by the status, the tasks can't be completed anyway.
+        let _ = self.request(Request::Sync { wait });
+    }
+
+    fn memory_usage(&mut self) -> MemoryUsage {
+        match self.request(Request::MemoryUsage) {
+            Ok(Response::MemoryUsage {
+                bytes_in_use,
+                bytes_reserved,
+                number_allocs,
+            }) => MemoryUsage {
+                bytes_in_use,
+                bytes_reserved,
+                number_allocs,
+            },
+            // The memory of the host is unknown, only the handles of the client are.
+            _ => self.memory_management.memory_usage(),
+        }
+    }
+
+    fn run_custom_command(&mut self, f: impl Fn(&mut Self) + Send) {
+        f(self);
+    }
+
+    fn status(&mut self) -> Result<(), ServerError> {
+        match self.request(Request::Status) {
+            Ok(Response::Status(None)) => Ok(()),
+            Ok(Response::Status(Some(reason))) => Err(ServerError::DeviceLost(reason)),
+            Ok(response) => Err(ServerError::Disconnected(format!(
+                "Unexpected response of the remote server: {response:?}"
+            ))),
+            Err(reason) => Err(ServerError::Disconnected(reason)),
+        }
+    }
+}
+
+impl<K, AK, T> core::fmt::Debug for RemoteServer<K, AK, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RemoteServer")
+            .field("memory_management", &self.memory_management)
+            .field("disconnected", &self.disconnected)
+            .finish()
+    }
+}
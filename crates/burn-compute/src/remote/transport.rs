@@ -0,0 +1,88 @@
+use std::{
+    io::{self, BufReader, BufWriter, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+};
+
+/// Exchanges the messages between a [remote server](super::RemoteServer) and its host.
+pub trait Transport: Send {
+    /// Send a message.
+    ///
+    /// The message can be buffered until the next [receive](Transport::receive), since the
+    /// client only waits for the host when it receives a response.
+    fn send(&mut self, message: &[u8]) -> io::Result<()>;
+
+    /// Wait for the next message.
+    fn receive(&mut self) -> io::Result<Vec<u8>>;
+}
+
+/// The default maximum size of a message received by a [TCP transport](TcpTransport), in bytes.
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 1 << 30;
+
+/// A [transport](Transport) over a TCP stream, where each message is prefixed by its size in
+/// bytes as a little endian `u64`.
+///
+/// Messages bigger than the [maximum size](TcpTransport::with_max_message_size) are rejected
+/// before being allocated, so a corrupted or malicious peer can't exhaust the memory.
+#[derive(Debug)]
+pub struct TcpTransport {
+    reader: BufReader<TcpStream>,
+    writer: BufWriter<TcpStream>,
+    max_message_size: usize,
+}
+
+impl TcpTransport {
+    /// Connect to the host listening at the given address.
+    pub fn connect<A: ToSocketAddrs>(address: A) -> io::Result<Self> {
+        Self::new(TcpStream::connect(address)?)
+    }
+
+    /// Create a transport over a connected stream, e.g. accepted by a
+    /// [listener](std::net::TcpListener).
+    pub fn new(stream: TcpStream) -> io::Result<Self> {
+        // The messages are already buffered, so they are sent as soon as they are flushed.
+        stream.set_nodelay(true)?;
+
+        Ok(Self {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: BufWriter::new(stream),
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+        })
+    }
+
+    /// Set the maximum size of the messages received, in bytes.
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+}
+
+impl Transport for TcpTransport {
+    fn send(&mut self, message: &[u8]) -> io::Result<()> {
+        self.writer
+            .write_all(&(message.len() as u64).to_le_bytes())?;
+        self.writer.write_all(message)
+    }
+
+    fn receive(&mut self) -> io::Result<Vec<u8>> {
+        self.writer.flush()?;
+
+        let mut size = [0; 8];
+        self.reader.read_exact(&mut size)?;
+
+        let size = u64::from_le_bytes(size);
+        if size > self.max_message_size as u64 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "The message of {size} bytes exceeds the maximum size of {} bytes",
+                    self.max_message_size
+                ),
+            ));
+        }
+
+        let mut message = vec![0; size as usize];
+        self.reader.read_exact(&mut message)?;
+
+        Ok(message)
+    }
+}
@@ -1,10 +1,10 @@
 use crate::{
     memory_management::{MemoryHandle, MemoryManagement},
-    storage::ComputeStorage,
+    storage::{ComputeStorage, StorageHint},
     tune::AutotuneKey,
 };
 use alloc::vec::Vec;
-use burn_common::{reader::Reader, sync_type::SyncType};
+use burn_common::{memory_usage::MemoryUsage, reader::Reader, sync_type::SyncType};
 use core::fmt::Debug;
 
 /// The compute server is responsible for handling resources and computations over resources.
@@ -39,16 +39,76 @@ where
     /// Reserves `size` bytes in the storage, and returns a handle over them.
     fn empty(&mut self, size: usize) -> Handle<Self>;
 
+    /// Reserves `size` bytes of the kind given by the `hint` in the storage, and returns a handle
+    /// over them.
+    ///
+    /// Servers without support for the kind of memory reserve regular memory.
+    fn empty_with_hint(&mut self, size: usize, _hint: StorageHint) -> Handle<Self> {
+        self.empty(size)
+    }
+
     /// Executes the `kernel` over the given memory `handles`.
     ///
     /// Kernels have mutable access to every resource they are given
     /// and are responsible of determining which should be read or written.
     fn execute(&mut self, kernel: Self::Kernel, bindings: Vec<Binding<Self>>);
 
+    /// Executes the `kernel` over the given memory `handles`, with a cube count read from the
+    /// `cube_count` binding, holding the number of cubes on the x, y and z axes as `u32`.
+    ///
+    /// This lets kernels whose output size depends on values computed on the device launch their
+    /// follow-up work without reading the values back.
+    fn execute_indirect(
+        &mut self,
+        kernel: Self::Kernel,
+        cube_count: Binding<Self>,
+        bindings: Vec<Binding<Self>>,
+    );
+
     /// Wait for the completion of every task in the server.
     fn sync(&mut self, command: SyncType);
+
+    /// The current memory usage of the server.
+    ///
+    /// The default implementation reports no memory usage.
+    fn memory_usage(&mut self) -> MemoryUsage {
+        MemoryUsage::default()
+    }
+
+    /// Run a custom command with mutable access to the server.
+    fn run_custom_command(&mut self, f: impl Fn(&mut Self) + Send);
+
+    /// Returns the error that made the device of the server unusable, if any.
+    ///
+    /// Servers whose device can't be lost are always available.
+    fn status(&mut self) -> Result<(), ServerError> {
+        Ok(())
+    }
+}
+
+/// Error of a [compute server](ComputeServer) that can no longer execute tasks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServerError {
+    /// The device was lost, e.g. after a driver reset, along with the content of its memory.
+    DeviceLost(String),
+    /// The connection to a remote server was lost, along with the tasks that weren't received.
+    Disconnected(String),
 }
 
+impl core::fmt::Display for ServerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::DeviceLost(reason) => f.write_fmt(format_args!("The device was lost: {reason}")),
+            Self::Disconnected(reason) => {
+                f.write_fmt(format_args!("The remote server was disconnected: {reason}"))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ServerError {}
+
 /// Server handle containing the [memory handle](MemoryManagement::Handle).
 #[derive(new, Debug)]
 pub struct Handle<Server: ComputeServer> {
@@ -44,6 +44,22 @@ impl StorageHandle {
     }
 }
 
+/// The kind of memory requested for an allocation, letting a storage use a different kind of
+/// memory than its device memory.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StorageHint {
+    /// Memory of the device, used by kernels.
+    #[default]
+    Device,
+    /// Memory of the device that the host can map, so reading it back doesn't need a staging
+    /// copy, at the cost of slower accesses from the kernels.
+    HostMapped,
+    /// Memory only backed by physical memory once it is touched, for very large buffers of which
+    /// only parts are used.
+    Sparse,
+}
+
 /// Storage types are responsible for allocating and deallocating memory.
 pub trait ComputeStorage: Send {
     /// The resource associated type determines the way data is implemented and how
@@ -56,6 +72,15 @@ pub trait ComputeStorage: Send {
     /// Allocates `size` units of memory and returns a handle to it
     fn alloc(&mut self, size: usize) -> StorageHandle;
 
+    /// Allocates `size` units of memory of the kind given by the `hint`, and returns a handle to
+    /// it.
+    ///
+    /// Storages that don't support the kind of memory fall back to a regular
+    /// [allocation](ComputeStorage::alloc).
+    fn alloc_with_hint(&mut self, size: usize, _hint: StorageHint) -> StorageHandle {
+        self.alloc(size)
+    }
+
     /// Deallocates the memory pointed by the given storage id.
     fn dealloc(&mut self, id: StorageId);
 }
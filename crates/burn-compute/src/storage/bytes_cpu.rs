@@ -1,5 +1,5 @@
-use super::{ComputeStorage, StorageHandle, StorageId, StorageUtilization};
-use alloc::alloc::{alloc, dealloc, Layout};
+use super::{ComputeStorage, StorageHandle, StorageHint, StorageId, StorageUtilization};
+use alloc::alloc::{alloc, alloc_zeroed, dealloc, Layout};
 use hashbrown::HashMap;
 
 /// The bytes storage maps ids to pointers of bytes in a contiguous layout.
@@ -66,6 +66,13 @@ impl ComputeStorage for BytesStorage {
     }
 
     fn alloc(&mut self, size: usize) -> StorageHandle {
+        self.alloc_with_hint(size, StorageHint::Device)
+    }
+
+    /// The memory is always mapped by the host, while sparse memory is allocated zeroed, so that
+    /// the allocator can request pages from the operating system that are only backed once
+    /// touched.
+    fn alloc_with_hint(&mut self, size: usize, hint: StorageHint) -> StorageHandle {
         let id = StorageId::new();
         let handle = StorageHandle {
             id: id.clone(),
@@ -74,7 +81,10 @@ impl ComputeStorage for BytesStorage {
 
         unsafe {
             let layout = Layout::array::<u8>(size).unwrap();
-            let ptr = alloc(layout);
+            let ptr = match hint {
+                StorageHint::Device | StorageHint::HostMapped => alloc(layout),
+                StorageHint::Sparse => alloc_zeroed(layout),
+            };
             let memory = AllocatedBytes { ptr, layout };
 
             self.memory.insert(id, memory);
@@ -105,6 +115,15 @@ mod tests {
         storage.dealloc(handle_1.id);
     }
 
+    #[test]
+    fn test_sparse_alloc_is_zeroed() {
+        let mut storage = BytesStorage::default();
+        let handle = storage.alloc_with_hint(64, StorageHint::Sparse);
+
+        assert!(storage.get(&handle).read().iter().all(|b| *b == 0));
+        storage.dealloc(handle.id);
+    }
+
     #[test]
     fn test_slices() {
         let mut storage = BytesStorage::default();
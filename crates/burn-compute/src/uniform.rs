@@ -0,0 +1,54 @@
+use crate::server::{ComputeServer, Handle};
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+
+/// The largest buffer, in bytes, that is pooled as a uniform.
+pub(crate) const MAX_UNIFORM_SIZE: usize = 256;
+
+/// The number of uniforms kept alive by the pool before it is cleared.
+const MAX_UNIFORM_COUNT: usize = 1024;
+
+/// Pool of small read-only buffers, such as the info and scalar bindings of kernels, reused
+/// across launches with the same content.
+///
+/// Each pooled handle is kept alive by the pool, so it is never reused in place or freed while
+/// it can still be returned.
+pub(crate) struct UniformPool<Server: ComputeServer> {
+    handles: HashMap<Vec<u8>, Handle<Server>>,
+}
+
+impl<Server: ComputeServer> core::fmt::Debug for UniformPool<Server> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UniformPool")
+            .field("num_uniforms", &self.handles.len())
+            .finish()
+    }
+}
+
+impl<Server: ComputeServer> UniformPool<Server> {
+    pub(crate) fn new() -> Self {
+        Self {
+            handles: HashMap::new(),
+        }
+    }
+
+    /// Get the pooled handle with the given content, creating it when missing.
+    pub(crate) fn get_or_create<F>(&mut self, data: &[u8], create: F) -> Handle<Server>
+    where
+        F: FnOnce(&[u8]) -> Handle<Server>,
+    {
+        if let Some(handle) = self.handles.get(data) {
+            return handle.clone();
+        }
+
+        // The content of the uniforms depends on the shapes of the launches, so the pool is
+        // bounded to keep the memory of dynamic shapes in check.
+        if self.handles.len() >= MAX_UNIFORM_COUNT {
+            self.handles.clear();
+        }
+
+        let handle = create(data);
+        self.handles.insert(data.to_vec(), handle.clone());
+        handle
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use burn_common::{reader::Reader, sync_type::SyncType};
+use burn_common::{memory_usage::MemoryUsage, reader::Reader, sync_type::SyncType};
 use burn_compute::{
     memory_management::{simple::SimpleMemoryManagement, MemoryHandle, MemoryManagement},
     server::{Binding, ComputeServer, Handle},
@@ -62,10 +62,24 @@ where
         kernel.compute(&mut resources);
     }
 
+    fn execute_indirect(
+        &mut self,
+        kernel: Self::Kernel,
+        _cube_count: Binding<Self>,
+        bindings: Vec<Binding<Self>>,
+    ) {
+        // Dummy kernels don't have a cube count.
+        self.execute(kernel, bindings)
+    }
+
     fn sync(&mut self, _: SyncType) {
         // Nothing to do with dummy backend.
     }
 
+    fn memory_usage(&mut self) -> MemoryUsage {
+        self.memory_management.memory_usage()
+    }
+
     fn run_custom_command(&mut self, f: impl Fn(&mut Self) + Send) {
         f(self);
     }
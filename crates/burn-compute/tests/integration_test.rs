@@ -2,7 +2,7 @@ mod dummy;
 
 use std::sync::Arc;
 
-use crate::dummy::{client, DummyDevice, DummyElementwiseAddition};
+use crate::dummy::{client, init_client, DummyDevice, DummyElementwiseAddition};
 use burn_compute::ComputeRuntime;
 
 #[allow(unused)]
@@ -19,6 +19,46 @@ fn created_resource_is_the_same_when_read() {
     assert_eq!(resource, obtained_resource.read())
 }
 
+#[test]
+fn uniforms_with_the_same_content_are_reused() {
+    // A new client, so that the memory usage isn't shared with other tests.
+    let client = init_client();
+    let uniform = client.create_uniform(&[1, 2, 3, 4]);
+    let memory_before = client.memory_usage().bytes_in_use;
+
+    let uniform_same = client.create_uniform(&[1, 2, 3, 4]);
+    assert_eq!(client.memory_usage().bytes_in_use, memory_before);
+
+    let uniform_other = client.create_uniform(&[5, 6, 7, 8]);
+    assert!(client.memory_usage().bytes_in_use > memory_before);
+
+    assert_eq!(client.read(uniform.binding()).read(), vec![1, 2, 3, 4]);
+    assert_eq!(client.read(uniform_same.binding()).read(), vec![1, 2, 3, 4]);
+    assert_eq!(
+        client.read(uniform_other.binding()).read(),
+        vec![5, 6, 7, 8]
+    );
+}
+
+#[test]
+fn released_client_is_created_again() {
+    type Runtime = ComputeRuntime<DummyDevice, dummy::DummyServer, dummy::DummyChannel>;
+    let runtime = Runtime::new();
+
+    let client = runtime.client(&DummyDevice, init_client);
+    let _resource = client.create(&[0, 1, 2]);
+    core::mem::drop(client);
+
+    assert!(runtime.release(&DummyDevice));
+    assert!(!runtime.release(&DummyDevice));
+
+    let client = runtime.client(&DummyDevice, init_client);
+    assert_eq!(client.memory_usage().bytes_in_use, 0);
+
+    runtime.release_all();
+    assert!(!runtime.release(&DummyDevice));
+}
+
 #[test]
 fn empty_allocates_memory() {
     let client = client(&DummyDevice);
@@ -287,3 +327,132 @@ fn autotune_cache_different_checksums_return_a_cache_miss() {
     // so CacheTestSlowOn3 (but faster on 4) should be used, returning rhs
     assert_eq!(obtained_resource.read(), Vec::from([5, 6, 7, 8]));
 }
+
+#[test]
+#[cfg(feature = "remote")]
+fn remote_server_runs_tasks_on_the_host() {
+    use burn_common::stub::RwLock;
+    use burn_compute::{
+        channel::MutexComputeChannel,
+        client::ComputeClient,
+        memory_management::simple::{DeallocStrategy, SimpleMemoryManagement, SliceStrategy},
+        remote::{serve, RemoteKernel, RemoteServer, TcpTransport},
+        storage::BytesStorage,
+        tune::Tuner,
+    };
+    use dummy::{DummyKernel, DummyServer};
+    use std::net::TcpListener;
+
+    struct RemoteAddition;
+
+    impl RemoteKernel for RemoteAddition {
+        type Description = String;
+
+        fn describe(self) -> String {
+            "addition".to_string()
+        }
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let host = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Never,
+        );
+        let server = DummyServer::new(memory_management);
+        let tuner = Arc::new(RwLock::new(Tuner::new("remote", "tests/remote-host")));
+        let client = ComputeClient::new(MutexComputeChannel::new(server), tuner);
+
+        serve(
+            &client,
+            TcpTransport::new(stream).unwrap(),
+            |description: String| {
+                assert_eq!(description, "addition");
+                Arc::new(DummyElementwiseAddition) as Arc<dyn DummyKernel>
+            },
+        )
+    });
+
+    let server =
+        RemoteServer::<RemoteAddition, String, _>::new(TcpTransport::connect(address).unwrap());
+    let tuner = Arc::new(RwLock::new(Tuner::new("remote", "tests/remote-device")));
+    let client = ComputeClient::new(MutexComputeChannel::new(server), tuner);
+
+    let lhs = client.create(&[0, 1, 2, 3]);
+    let rhs = client.create(&[4, 5, 6, 7]);
+    let out = client.empty(4);
+
+    client.execute(
+        RemoteAddition,
+        vec![lhs.binding(), rhs.binding(), out.clone().binding()],
+    );
+
+    assert_eq!(client.read(out.binding()).read(), vec![4, 6, 8, 10]);
+    assert_eq!(client.status(), Ok(()));
+
+    // The host stops serving once the client disconnects.
+    core::mem::drop(client);
+    host.join().unwrap().unwrap();
+}
+
+#[test]
+#[cfg(feature = "remote")]
+fn remote_host_rejects_messages_over_the_maximum_size() {
+    use burn_common::stub::RwLock;
+    use burn_compute::{
+        channel::MutexComputeChannel,
+        client::ComputeClient,
+        memory_management::simple::{DeallocStrategy, SimpleMemoryManagement, SliceStrategy},
+        remote::{serve, RemoteKernel, RemoteServer, TcpTransport},
+        storage::BytesStorage,
+        tune::Tuner,
+    };
+    use dummy::{DummyKernel, DummyServer};
+    use std::net::TcpListener;
+
+    struct RemoteAddition;
+
+    impl RemoteKernel for RemoteAddition {
+        type Description = String;
+
+        fn describe(self) -> String {
+            "addition".to_string()
+        }
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let host = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Never,
+        );
+        let server = DummyServer::new(memory_management);
+        let tuner = Arc::new(RwLock::new(Tuner::new("remote", "tests/remote-host")));
+        let client = ComputeClient::new(MutexComputeChannel::new(server), tuner);
+        let transport = TcpTransport::new(stream).unwrap().with_max_message_size(64);
+
+        serve(&client, transport, |_description: String| {
+            Arc::new(DummyElementwiseAddition) as Arc<dyn DummyKernel>
+        })
+    });
+
+    let server =
+        RemoteServer::<RemoteAddition, String, _>::new(TcpTransport::connect(address).unwrap());
+    let tuner = Arc::new(RwLock::new(Tuner::new("remote", "tests/remote-device")));
+    let client = ComputeClient::new(MutexComputeChannel::new(server), tuner);
+
+    let _handle = client.create(&[0; 1024]);
+    let status = client.status();
+
+    let err = host.join().unwrap().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(status.is_err());
+}
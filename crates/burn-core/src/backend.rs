@@ -10,12 +10,36 @@ pub use burn_autodiff as autodiff;
 #[cfg(feature = "autodiff")]
 pub use burn_autodiff::Autodiff;
 
+#[cfg(feature = "tracer")]
+pub use burn_tracer as tracer;
+
+#[cfg(feature = "tracer")]
+pub use burn_tracer::Tracer;
+
+#[cfg(feature = "vmap")]
+pub use burn_vmap as vmap;
+
+#[cfg(feature = "vmap")]
+pub use burn_vmap::Batched;
+
+#[cfg(feature = "dynamic")]
+pub use burn_dyn as dynamic;
+
+#[cfg(feature = "dynamic")]
+pub use burn_dyn::{DynBackend, DynDevice};
+
 #[cfg(feature = "wgpu")]
 pub use burn_wgpu as wgpu;
 
 #[cfg(feature = "wgpu")]
 pub use burn_wgpu::Wgpu;
 
+#[cfg(feature = "opencl")]
+pub use burn_opencl as opencl;
+
+#[cfg(feature = "opencl")]
+pub use burn_opencl::OpenCl;
+
 #[cfg(feature = "candle")]
 pub use burn_candle as candle;
 
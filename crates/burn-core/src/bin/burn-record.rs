@@ -0,0 +1,44 @@
+use burn_core::record::RecordInspection;
+
+const USAGE: &str = "Usage:
+    burn-record <record>                      List the tensors of a record with their statistics
+    burn-record diff <first> <second> [tol]   Compare the tensors of two records";
+
+/// Inspects `.mpk` and `.json` record files, compressed or not.
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["diff", first, second, rest @ ..] => {
+            let tolerance = rest
+                .first()
+                .map(|tolerance| tolerance.parse().expect("Tolerance should be a number"))
+                .unwrap_or(0.0);
+            let first = inspect(first);
+            let second = inspect(second);
+            let diff = first.diff(&second, tolerance);
+
+            print!("{diff}");
+            if !diff.is_empty() {
+                std::process::exit(1);
+            }
+        }
+        [record] if *record != "diff" => print!("{}", inspect(record)),
+        _ => {
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        }
+    }
+}
+
+fn inspect(path: &str) -> RecordInspection {
+    RecordInspection::from_file(path).unwrap_or_else(|err| {
+        eprintln!("Failed to inspect {path}: {err}");
+        std::process::exit(2);
+    })
+}
@@ -0,0 +1,271 @@
+use burn_tensor::{backend::Backend, Tensor};
+use std::any::Any;
+use std::sync::{Arc, Condvar, Mutex};
+
+/// The reduction applied by an [all-reduce](CollectiveGroup::all_reduce).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReduceOperation {
+    /// The sum of the tensors of all replicas.
+    Sum,
+    /// The mean of the tensors of all replicas.
+    Mean,
+}
+
+/// A group of replicas of a model, each running on its own thread (usually one per device), that
+/// exchange tensors with collective operations.
+///
+/// Clones refer to the same group, so a module holding a group can be
+/// [forked](crate::module::Module::fork) on each device.
+///
+/// # Notes
+///
+/// Every collective operation blocks until all the replicas of the group called it, so each
+/// replica has to execute the same collective operations in the same order. Tensors are exchanged
+/// as is, so tensors tracked by autodiff should be detached first to keep the graph of each replica
+/// separated.
+#[derive(Clone)]
+pub struct CollectiveGroup {
+    num_replicas: usize,
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    condvar: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    generation: usize,
+    pending: Vec<Box<dyn Any + Send>>,
+    results: Vec<Box<dyn Any + Send>>,
+}
+
+impl CollectiveGroup {
+    /// Creates a new group with the given number of replicas.
+    pub fn new(num_replicas: usize) -> Self {
+        assert!(num_replicas > 0, "A group needs at least one replica.");
+
+        Self {
+            num_replicas,
+            shared: Arc::new(Shared {
+                state: Mutex::new(State::default()),
+                condvar: Condvar::new(),
+            }),
+        }
+    }
+
+    /// The number of replicas in the group.
+    pub fn num_replicas(&self) -> usize {
+        self.num_replicas
+    }
+
+    /// Collect the tensor of every replica, moved to the device of the given tensor.
+    ///
+    /// The tensors are in the same order for all replicas.
+    pub fn all_gather<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+    ) -> Vec<Tensor<B, D>> {
+        let device = tensor.device();
+
+        self.exchange(tensor)
+            .into_iter()
+            .map(|tensor| tensor.to_device(&device))
+            .collect()
+    }
+
+    /// Send the tensor of a single replica, the root, to every replica, moved to the given device.
+    ///
+    /// The root calls it with its tensor while every other replica calls it with `None`.
+    pub fn broadcast<B: Backend, const D: usize>(
+        &self,
+        tensor: Option<Tensor<B, D>>,
+        device: &B::Device,
+    ) -> Tensor<B, D> {
+        let mut tensors = self.exchange(tensor).into_iter().flatten();
+        let tensor = tensors
+            .next()
+            .expect("A replica should broadcast its tensor.");
+        assert!(
+            tensors.next().is_none(),
+            "Only one replica should broadcast its tensor."
+        );
+
+        tensor.to_device(device)
+    }
+
+    /// Reduce the tensor of every replica on a single replica, the root, which is the only one to
+    /// get the result, on the device of its tensor.
+    ///
+    /// Only the tensors of the other replicas are moved, to the device of the root.
+    pub fn reduce<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        operation: ReduceOperation,
+        is_root: bool,
+    ) -> Option<Tensor<B, D>> {
+        let device = tensor.device();
+        let tensors = self.exchange((is_root, tensor));
+
+        if !is_root {
+            return None;
+        }
+
+        let tensors = tensors
+            .into_iter()
+            .map(|(_, tensor)| tensor.to_device(&device))
+            .collect();
+
+        Some(reduce(tensors, operation))
+    }
+
+    /// Reduce the tensor of every replica, the result is on the device of the given tensor.
+    pub fn all_reduce<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<B, D>,
+        operation: ReduceOperation,
+    ) -> Tensor<B, D> {
+        reduce(self.all_gather(tensor), operation)
+    }
+
+    /// Exchange a value between all the replicas, in the same order for all replicas.
+    fn exchange<T: Clone + Send + 'static>(&self, value: T) -> Vec<T> {
+        if self.num_replicas == 1 {
+            return vec![value];
+        }
+
+        let mut state = self.shared.state.lock().unwrap();
+        let generation = state.generation;
+        state.pending.push(Box::new(value));
+
+        if state.pending.len() == self.num_replicas {
+            // The results can't be overwritten before every replica read them, since the next
+            // generation can't complete without all the replicas.
+            state.results = core::mem::take(&mut state.pending);
+            state.generation += 1;
+            self.shared.condvar.notify_all();
+        } else {
+            state = self
+                .shared
+                .condvar
+                .wait_while(state, |state| state.generation == generation)
+                .unwrap();
+        }
+
+        state
+            .results
+            .iter()
+            .map(|value| {
+                value
+                    .downcast_ref::<T>()
+                    .expect("All replicas should exchange values of the same kind.")
+                    .clone()
+            })
+            .collect()
+    }
+}
+
+fn reduce<B: Backend, const D: usize>(
+    tensors: Vec<Tensor<B, D>>,
+    operation: ReduceOperation,
+) -> Tensor<B, D> {
+    let num_tensors = tensors.len();
+    let sum = tensors
+        .into_iter()
+        .reduce(|acc, tensor| acc.add(tensor))
+        .unwrap();
+
+    match operation {
+        ReduceOperation::Sum => sum,
+        ReduceOperation::Mean => sum.div_scalar(num_tensors as f32),
+    }
+}
+
+impl core::fmt::Debug for CollectiveGroup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CollectiveGroup")
+            .field("num_replicas", &self.num_replicas)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_all_reduce_between_threads() {
+        let group = CollectiveGroup::new(3);
+
+        let handles = (0..3)
+            .map(|i| {
+                let group = group.clone();
+                std::thread::spawn(move || {
+                    let device = Default::default();
+                    let tensor = Tensor::<TestBackend, 1>::from_floats([i as f32, 1.0], &device);
+                    let sum = group.all_reduce(tensor.clone(), ReduceOperation::Sum);
+                    let mean = group.all_reduce(tensor, ReduceOperation::Mean);
+                    (sum.into_data(), mean.into_data())
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            let (sum, mean) = handle.join().unwrap();
+            sum.assert_approx_eq(&Data::from([3.0, 3.0]), 3);
+            mean.assert_approx_eq(&Data::from([1.0, 1.0]), 3);
+        }
+    }
+
+    #[test]
+    fn test_broadcast_and_reduce_to_the_root() {
+        let group = CollectiveGroup::new(3);
+
+        let handles = (0..3)
+            .map(|i| {
+                let group = group.clone();
+                std::thread::spawn(move || {
+                    let device = Default::default();
+                    let tensor = Tensor::<TestBackend, 1>::from_floats([i as f32, 1.0], &device);
+                    let is_root = i == 1;
+                    let broadcasted = group.broadcast(is_root.then(|| tensor.clone()), &device);
+                    let reduced = group.reduce(tensor, ReduceOperation::Sum, is_root);
+                    (
+                        is_root,
+                        broadcasted.into_data(),
+                        reduced.map(|t| t.into_data()),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            let (is_root, broadcasted, reduced) = handle.join().unwrap();
+            broadcasted.assert_approx_eq(&Data::from([1.0, 1.0]), 3);
+            match reduced {
+                Some(reduced) => {
+                    assert!(is_root);
+                    reduced.assert_approx_eq(&Data::from([3.0, 3.0]), 3);
+                }
+                None => assert!(!is_root),
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_replica_is_identity() {
+        let group = CollectiveGroup::new(1);
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([2.0, 4.0], &device);
+
+        let tensors = group.all_gather(tensor);
+
+        assert_eq!(tensors.len(), 1);
+        tensors[0]
+            .to_data()
+            .assert_approx_eq(&Data::from([2.0, 4.0]), 3);
+    }
+}
@@ -0,0 +1,132 @@
+/// Decode tokens into text, e.g. with the tokenizer of a language model.
+pub trait Detokenizer {
+    /// Decode the tokens into text.
+    ///
+    /// Invalid UTF-8, such as a character split across many tokens, should be decoded as the
+    /// replacement character `�`.
+    fn decode(&self, tokens: &[usize]) -> String;
+}
+
+/// The number of tokens of the prompt decoded with the first generated tokens.
+const PROMPT_CONTEXT: usize = 5;
+
+/// Decode the text of tokens as they are generated, one at a time.
+///
+/// Decoding each token separately isn't correct with most tokenizers: a character can be split
+/// across many tokens, and the text of a token can depend on the previous one, e.g. for the
+/// leading spaces of SentencePiece. The text of the new tokens is instead the difference between
+/// the decoding of the new tokens with a few previous ones and the decoding of the previous ones
+/// alone, and is held back while it ends with an incomplete character.
+#[derive(Debug, Clone)]
+pub struct IncrementalDetokenizer<D> {
+    detokenizer: D,
+    tokens: Vec<usize>,
+    prefix_offset: usize,
+    read_offset: usize,
+}
+
+impl<D: Detokenizer> IncrementalDetokenizer<D> {
+    /// Create an incremental detokenizer, with the tokens of the prompt whose text isn't decoded.
+    pub fn new(detokenizer: D, prompt: &[usize]) -> Self {
+        Self {
+            detokenizer,
+            tokens: prompt.to_vec(),
+            prefix_offset: prompt.len().saturating_sub(PROMPT_CONTEXT),
+            read_offset: prompt.len(),
+        }
+    }
+
+    /// Add the token, returning the new text if it's complete.
+    pub fn push(&mut self, token: usize) -> Option<String> {
+        self.tokens.push(token);
+
+        let text = self.pending()?;
+        if text.is_empty() || text.ends_with(char::REPLACEMENT_CHARACTER) {
+            return None;
+        }
+
+        self.prefix_offset = self.read_offset;
+        self.read_offset = self.tokens.len();
+
+        Some(text)
+    }
+
+    /// Returns the text held back, even if it ends with an incomplete character.
+    pub fn finish(&mut self) -> Option<String> {
+        let text = self.pending().filter(|text| !text.is_empty())?;
+
+        self.prefix_offset = self.read_offset;
+        self.read_offset = self.tokens.len();
+
+        Some(text)
+    }
+
+    /// All the tokens, including the ones of the prompt.
+    pub fn tokens(&self) -> &[usize] {
+        &self.tokens
+    }
+
+    fn pending(&self) -> Option<String> {
+        let prefix = self
+            .detokenizer
+            .decode(&self.tokens[self.prefix_offset..self.read_offset]);
+        let text = self.detokenizer.decode(&self.tokens[self.prefix_offset..]);
+
+        text.get(prefix.len()..).map(ToString::to_string)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Decode each token as a byte, with a leading space removed like SentencePiece.
+    #[derive(Clone)]
+    pub(crate) struct ByteDetokenizer;
+
+    impl Detokenizer for ByteDetokenizer {
+        fn decode(&self, tokens: &[usize]) -> String {
+            let bytes = tokens.iter().map(|token| *token as u8).collect::<Vec<_>>();
+            let text = String::from_utf8_lossy(&bytes);
+
+            text.strip_prefix(' ').unwrap_or(&text).to_string()
+        }
+    }
+
+    fn tokens(text: &str) -> Vec<usize> {
+        text.bytes().map(|byte| byte as usize).collect()
+    }
+
+    #[test]
+    fn should_keep_the_leading_spaces_of_the_tokens() {
+        let mut detokenizer = IncrementalDetokenizer::new(ByteDetokenizer, &tokens("Hi"));
+
+        let chunks = tokens(" there")
+            .into_iter()
+            .filter_map(|token| detokenizer.push(token))
+            .collect::<Vec<_>>();
+
+        assert_eq!(chunks.concat(), " there");
+    }
+
+    #[test]
+    fn should_hold_back_incomplete_characters() {
+        let mut detokenizer = IncrementalDetokenizer::new(ByteDetokenizer, &[]);
+        let [a, b, c] = tokens("é!")[..] else {
+            panic!("Three bytes expected")
+        };
+
+        assert_eq!(detokenizer.push(a), None);
+        assert_eq!(detokenizer.push(b), Some("é".to_string()));
+        assert_eq!(detokenizer.push(c), Some("!".to_string()));
+        assert_eq!(detokenizer.finish(), None);
+    }
+
+    #[test]
+    fn should_flush_the_text_held_back_when_finished() {
+        let mut detokenizer = IncrementalDetokenizer::new(ByteDetokenizer, &[]);
+
+        assert_eq!(detokenizer.push(0xC3), None);
+        assert_eq!(detokenizer.finish(), Some("�".to_string()));
+    }
+}
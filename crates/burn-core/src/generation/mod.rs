@@ -0,0 +1,11 @@
+mod detokenizer;
+mod sampler;
+mod scheduler;
+mod speculative;
+mod stream;
+
+pub use detokenizer::*;
+pub use sampler::*;
+pub use scheduler::*;
+pub use speculative::*;
+pub use stream::*;
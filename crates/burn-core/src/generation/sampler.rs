@@ -0,0 +1,194 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::tensor::backend::Backend;
+use crate::tensor::{ElementConversion, Tensor};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Configuration to create a [Sampler](Sampler) using the [init function](SamplerConfig::init).
+#[derive(Config, Debug)]
+pub struct SamplerConfig {
+    /// The temperature dividing the logits before sampling, the most likely token is always
+    /// selected when it's zero.
+    #[config(default = 1.0)]
+    pub temperature: f64,
+    /// Only sample among the `k` most likely tokens.
+    pub top_k: Option<usize>,
+    /// Only sample among the most likely tokens whose cumulative probability reaches `p`, also
+    /// known as nucleus sampling.
+    pub top_p: Option<f64>,
+    /// The seed of the random number generator, a random seed is used when not set.
+    pub seed: Option<u64>,
+}
+
+/// Select the next token from the logits produced by a language model.
+///
+/// Should be created with [SamplerConfig].
+#[derive(Debug, Clone)]
+pub struct Sampler {
+    temperature: f64,
+    top_k: Option<usize>,
+    top_p: Option<f64>,
+    rng: StdRng,
+}
+
+impl SamplerConfig {
+    /// Initialize a new [sampler](Sampler).
+    pub fn init(&self) -> Sampler {
+        let rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        Sampler {
+            temperature: self.temperature,
+            top_k: self.top_k,
+            top_p: self.top_p,
+            rng,
+        }
+    }
+}
+
+impl Sampler {
+    /// Select the next token from the logits of the vocabulary.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[vocab_size]`
+    pub fn sample<B: Backend>(&mut self, logits: Tensor<B, 1>) -> usize {
+        if self.is_greedy() {
+            return logits.argmax(0).into_scalar().elem::<i64>() as usize;
+        }
+
+        let probs = self.probs(logits);
+        self.sample_probs(&probs)
+    }
+
+    /// The probabilities of the tokens the sampler selects from, after the temperature, `top_k`
+    /// and `top_p` are applied.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[vocab_size]`
+    /// - output: `[vocab_size]`
+    pub(crate) fn probs<B: Backend>(&self, logits: Tensor<B, 1>) -> Vec<f64> {
+        if self.is_greedy() {
+            let [vocab_size] = logits.dims();
+            let token = logits.argmax(0).into_scalar().elem::<i64>() as usize;
+            let mut probs = vec![0.0; vocab_size];
+            probs[token] = 1.0;
+
+            return probs;
+        }
+
+        let probs = crate::tensor::activation::softmax(logits / self.temperature, 0);
+        let probs = probs.into_data().convert::<f64>().value;
+
+        self.filter_probs(probs)
+    }
+
+    /// Select a token with the given probabilities, which don't have to be normalized.
+    pub(crate) fn sample_probs(&mut self, probs: &[f64]) -> usize {
+        let total = probs.iter().sum::<f64>();
+        let mut threshold = self.rng.gen::<f64>() * total;
+
+        for (token, prob) in probs.iter().enumerate() {
+            if threshold < *prob {
+                return token;
+            }
+            threshold -= prob;
+        }
+
+        // Rounding errors might leave a tiny part of the threshold.
+        probs.iter().rposition(|prob| *prob > 0.0).unwrap_or(0)
+    }
+
+    /// Draw a number uniformly in `[0, 1)`.
+    pub(crate) fn uniform(&mut self) -> f64 {
+        self.rng.gen::<f64>()
+    }
+
+    fn is_greedy(&self) -> bool {
+        self.temperature <= 0.0 || self.top_k == Some(1)
+    }
+
+    fn filter_probs(&self, mut probs: Vec<f64>) -> Vec<f64> {
+        if self.top_k.is_none() && self.top_p.is_none() {
+            return probs;
+        }
+
+        let mut candidates = probs.iter().copied().enumerate().collect::<Vec<_>>();
+        candidates.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        if let Some(top_k) = self.top_k {
+            candidates.truncate(top_k.max(1));
+        }
+
+        if let Some(top_p) = self.top_p {
+            let mut cumulative = 0.0;
+            // Keep the tokens until the cumulative probability reaches `p`, including the one
+            // crossing the threshold.
+            let num_kept = candidates
+                .iter()
+                .position(|(_, prob)| {
+                    cumulative += prob;
+                    cumulative >= top_p
+                })
+                .map(|position| position + 1)
+                .unwrap_or(candidates.len());
+            candidates.truncate(num_kept);
+        }
+
+        let total = candidates.iter().map(|(_, prob)| prob).sum::<f64>();
+        probs.iter_mut().for_each(|prob| *prob = 0.0);
+        for (token, prob) in candidates {
+            probs[token] = prob / total;
+        }
+
+        probs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    fn logits() -> Tensor<TestBackend, 1> {
+        Tensor::from_floats([0.5, 3.0, 2.0, -1.0], &Default::default())
+    }
+
+    #[test]
+    fn should_select_the_most_likely_token_without_temperature() {
+        let mut sampler = SamplerConfig::new().with_temperature(0.0).init();
+
+        assert_eq!(sampler.sample(logits()), 1);
+    }
+
+    #[test]
+    fn should_only_sample_among_the_top_k_tokens() {
+        let mut sampler = SamplerConfig::new()
+            .with_top_k(Some(2))
+            .with_seed(Some(42))
+            .init();
+
+        for _ in 0..20 {
+            let token = sampler.sample(logits());
+            assert!(token == 1 || token == 2, "Unexpected token {token}");
+        }
+    }
+
+    #[test]
+    fn should_only_sample_among_the_nucleus() {
+        let mut sampler = SamplerConfig::new()
+            .with_top_p(Some(0.5))
+            .with_seed(Some(42))
+            .init();
+
+        // The most likely token alone has a probability above 0.5.
+        for _ in 0..20 {
+            assert_eq!(sampler.sample(logits()), 1);
+        }
+    }
+}
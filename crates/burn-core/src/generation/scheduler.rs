@@ -0,0 +1,477 @@
+use crate as burn;
+
+use super::stream::tokens_tensor;
+use super::{FinishReason, Sampler, SamplerConfig};
+use crate::config::Config;
+use crate::nn::attention::{PagedKvCache, SequenceId};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor};
+use std::collections::VecDeque;
+
+/// A language model computing the tokens of many sequences at once, with their keys and values
+/// stored in a [paged KV cache](PagedKvCache), see [Scheduler].
+pub trait BatchedGenerationModel<B: Backend> {
+    /// Compute the logits of the next token of each sequence from its new tokens, whose space is
+    /// already [reserved](PagedKvCache::reserve) in the cache, e.g. with the
+    /// [paged attention](crate::nn::attention::MultiHeadAttention::forward_paged) of each layer.
+    ///
+    /// # Shapes
+    ///
+    /// - tokens: `[batch_size, seq_length]`, the new tokens of each sequence.
+    /// - output: `[batch_size, vocab_size]`
+    fn forward_paged(
+        &self,
+        tokens: Tensor<B, 2, Int>,
+        cache: &mut PagedKvCache<B>,
+        sequences: &[SequenceId],
+    ) -> Tensor<B, 2>;
+}
+
+/// Configuration to create a [Scheduler](Scheduler) using the [init function](SchedulerConfig::init).
+#[derive(Config, Debug)]
+pub struct SchedulerConfig {
+    /// The configuration of the sampler selecting the generated tokens.
+    #[config(default = "SamplerConfig::new()")]
+    pub sampler: SamplerConfig,
+    /// The maximum number of sequences generated at once.
+    #[config(default = 64)]
+    pub max_batch_size: usize,
+    /// The maximum number of tokens computed at each step, including the prompts of the new
+    /// requests.
+    #[config(default = 2048)]
+    pub max_tokens_per_step: usize,
+    /// The tokens ending the generation, e.g. the end of sequence token, which aren't returned.
+    #[config(default = "Vec::new()")]
+    pub stop_tokens: Vec<usize>,
+}
+
+/// The identifier of a request submitted to a [scheduler](Scheduler).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestId(u64);
+
+/// An event of a request produced by a step of a [scheduler](Scheduler).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchedulerEvent {
+    /// A token was generated.
+    Token {
+        /// The request of the token.
+        request: RequestId,
+        /// The generated token.
+        token: usize,
+    },
+    /// The request is finished and removed from the scheduler.
+    Finished {
+        /// The finished request.
+        request: RequestId,
+        /// The reason the generation finished.
+        reason: FinishReason,
+    },
+}
+
+struct Request {
+    id: RequestId,
+    /// All the tokens of the request, the prompt followed by the generated ones.
+    tokens: Vec<usize>,
+    num_generated: usize,
+    max_new_tokens: usize,
+}
+
+struct Running {
+    request: Request,
+    sequence: SequenceId,
+}
+
+/// Generate the tokens of many requests with continuous batching.
+///
+/// At each [step](Scheduler::step), the running sequences generate one token in a single batch,
+/// the waiting requests are admitted while the token budget, the batch size and the free blocks of
+/// the [paged KV cache](PagedKvCache) allow it, and the finished sequences are evicted, freeing
+/// their blocks for the next requests. When the cache is full, the last admitted sequences are
+/// preempted and put back in front of the queue, to be computed again once blocks are freed.
+///
+/// Should be created with [SchedulerConfig].
+pub struct Scheduler<B: Backend, M> {
+    model: M,
+    cache: PagedKvCache<B>,
+    sampler: Sampler,
+    config: SchedulerConfig,
+    waiting: VecDeque<Request>,
+    running: Vec<Running>,
+    events: Vec<SchedulerEvent>,
+    next_id: u64,
+}
+
+impl SchedulerConfig {
+    /// Initialize a new [scheduler](Scheduler) for the model, storing the keys and values of the
+    /// sequences in the cache.
+    pub fn init<B, M>(&self, model: M, cache: PagedKvCache<B>) -> Scheduler<B, M>
+    where
+        B: Backend,
+        M: BatchedGenerationModel<B>,
+    {
+        Scheduler {
+            model,
+            cache,
+            sampler: self.sampler.init(),
+            config: self.clone(),
+            waiting: VecDeque::new(),
+            running: Vec::new(),
+            events: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+impl<B, M> Scheduler<B, M>
+where
+    B: Backend,
+    M: BatchedGenerationModel<B>,
+{
+    /// Add a request generating at most `max_new_tokens` tokens after the prompt, which is
+    /// admitted at the next steps.
+    pub fn submit(&mut self, prompt: Vec<usize>, max_new_tokens: usize) -> RequestId {
+        assert!(
+            !prompt.is_empty(),
+            "The prompt should have at least one token."
+        );
+
+        let id = RequestId(self.next_id);
+        self.next_id += 1;
+        self.waiting.push_back(Request {
+            id,
+            tokens: prompt,
+            num_generated: 0,
+            max_new_tokens,
+        });
+
+        id
+    }
+
+    /// Cancel the request, returning false if it isn't in the scheduler.
+    ///
+    /// Its [finished event](SchedulerEvent::Finished) is returned by the next step.
+    pub fn cancel(&mut self, id: RequestId) -> bool {
+        if let Some(position) = self.waiting.iter().position(|request| request.id == id) {
+            self.waiting.remove(position);
+        } else if let Some(position) = self
+            .running
+            .iter()
+            .position(|running| running.request.id == id)
+        {
+            let running = self.running.remove(position);
+            self.remove_sequence(running.sequence);
+        } else {
+            return false;
+        }
+
+        self.events.push(SchedulerEvent::Finished {
+            request: id,
+            reason: FinishReason::Cancelled,
+        });
+
+        true
+    }
+
+    /// Whether there are no requests left.
+    pub fn is_idle(&self) -> bool {
+        self.waiting.is_empty() && self.running.is_empty()
+    }
+
+    /// The number of requests waiting to be admitted.
+    pub fn num_waiting(&self) -> usize {
+        self.waiting.len()
+    }
+
+    /// The number of sequences being generated.
+    pub fn num_running(&self) -> usize {
+        self.running.len()
+    }
+
+    /// Generate the next token of the running sequences and of the admitted requests.
+    pub fn step(&mut self, device: &B::Device) -> Vec<SchedulerEvent> {
+        self.reserve_running();
+        let num_decoding = self.running.len();
+        self.admit();
+
+        let mut logits = Vec::with_capacity(self.running.len());
+
+        if num_decoding > 0 {
+            let running = &self.running[..num_decoding];
+            let last_tokens = running
+                .iter()
+                .map(|running| *running.request.tokens.last().unwrap())
+                .collect::<Vec<_>>();
+            let sequences = running
+                .iter()
+                .map(|running| running.sequence)
+                .collect::<Vec<_>>();
+            let tokens = tokens_tensor::<B>(&last_tokens, device).reshape([num_decoding, 1]);
+            let output = self
+                .model
+                .forward_paged(tokens, &mut self.cache, &sequences);
+
+            logits.extend(output.iter_dim(0));
+        }
+
+        // The prompts of the new requests have different lengths, so they are computed apart.
+        for running in self.running[num_decoding..].iter() {
+            let prompt = &running.request.tokens;
+            let tokens = tokens_tensor::<B>(prompt, device);
+
+            logits.push(
+                self.model
+                    .forward_paged(tokens, &mut self.cache, &[running.sequence]),
+            );
+        }
+
+        let mut events = core::mem::take(&mut self.events);
+        let mut finished = Vec::new();
+
+        for (index, logits) in logits.into_iter().enumerate() {
+            let [_, vocab_size] = logits.dims();
+            let token = self.sampler.sample(logits.reshape([vocab_size]));
+            let request = &mut self.running[index].request;
+
+            let reason = if self.config.stop_tokens.contains(&token) {
+                Some(FinishReason::StopToken)
+            } else {
+                events.push(SchedulerEvent::Token {
+                    request: request.id,
+                    token,
+                });
+                request.tokens.push(token);
+                request.num_generated += 1;
+
+                (request.num_generated >= request.max_new_tokens).then_some(FinishReason::MaxTokens)
+            };
+
+            if let Some(reason) = reason {
+                events.push(SchedulerEvent::Finished {
+                    request: request.id,
+                    reason,
+                });
+                finished.push(index);
+            }
+        }
+
+        for index in finished.into_iter().rev() {
+            let running = self.running.remove(index);
+            self.remove_sequence(running.sequence);
+        }
+
+        events
+    }
+
+    /// Reserve the space of the next token of the running sequences, preempting the last admitted
+    /// ones until there are enough free blocks.
+    fn reserve_running(&mut self) {
+        loop {
+            let required = self
+                .running
+                .iter()
+                .map(|running| self.cache.num_blocks_required(running.sequence, 1).unwrap())
+                .sum::<usize>();
+
+            if required <= self.cache.num_free_blocks() || self.running.len() <= 1 {
+                break;
+            }
+
+            let preempted = self.running.pop().unwrap();
+            self.remove_sequence(preempted.sequence);
+            self.waiting.push_front(preempted.request);
+        }
+
+        for running in self.running.iter() {
+            self.cache
+                .reserve(running.sequence, 1)
+                .expect("A single sequence should fit in the cache.");
+        }
+    }
+
+    /// Admit the waiting requests while the budget and the free blocks allow it.
+    fn admit(&mut self) {
+        let mut budget = self
+            .config
+            .max_tokens_per_step
+            .saturating_sub(self.running.len());
+
+        while let Some(request) = self.waiting.front() {
+            let num_tokens = request.tokens.len();
+
+            if self.running.len() >= self.config.max_batch_size {
+                break;
+            }
+            // A prompt longer than the budget is only computed alone, so it isn't blocked.
+            if num_tokens > budget && !self.running.is_empty() {
+                break;
+            }
+
+            let sequence = self.cache.add_sequence();
+            if self.cache.reserve(sequence, num_tokens).is_err() {
+                self.remove_sequence(sequence);
+                break;
+            }
+
+            budget = budget.saturating_sub(num_tokens);
+            self.running.push(Running {
+                request: self.waiting.pop_front().unwrap(),
+                sequence,
+            });
+        }
+    }
+
+    fn remove_sequence(&mut self, sequence: SequenceId) {
+        self.cache
+            .remove_sequence(sequence)
+            .expect("The sequences of the scheduler should be in the cache.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::attention::PagedKvCacheConfig;
+    use crate::tensor::{Data, Shape};
+    use crate::TestBackend;
+    use std::collections::HashMap;
+
+    /// Always predict the next byte, checking the space of the tokens is reserved in the cache.
+    struct NextByteModel;
+
+    impl<B: Backend> BatchedGenerationModel<B> for NextByteModel {
+        fn forward_paged(
+            &self,
+            tokens: Tensor<B, 2, Int>,
+            cache: &mut PagedKvCache<B>,
+            sequences: &[SequenceId],
+        ) -> Tensor<B, 2> {
+            let [batch_size, seq_length] = tokens.dims();
+            assert_eq!(batch_size, sequences.len());
+            for sequence in sequences {
+                assert!(cache.sequence_length(*sequence).unwrap() >= seq_length);
+            }
+
+            let device = tokens.device();
+            let tokens = tokens.into_data().convert::<i64>().value;
+            let mut logits = vec![0.0f32; batch_size * 256];
+            for (index, chunk) in tokens.chunks(seq_length).enumerate() {
+                let next = (*chunk.last().unwrap() as usize + 1) % 256;
+                logits[index * 256 + next] = 10.0;
+            }
+
+            Tensor::from_data(
+                Data::new(logits, Shape::new([batch_size, 256])).convert(),
+                &device,
+            )
+        }
+    }
+
+    fn scheduler(
+        num_blocks: usize,
+        config: SchedulerConfig,
+    ) -> Scheduler<TestBackend, NextByteModel> {
+        let cache = PagedKvCacheConfig::new(1, 1, 1, num_blocks)
+            .with_block_size(2)
+            .init(&Default::default());
+
+        config
+            .with_sampler(SamplerConfig::new().with_temperature(0.0))
+            .init(NextByteModel, cache)
+    }
+
+    /// Run the scheduler until it's idle, returning the tokens of each request and the number of
+    /// steps.
+    fn run(
+        scheduler: &mut Scheduler<TestBackend, NextByteModel>,
+    ) -> (HashMap<RequestId, Vec<usize>>, usize) {
+        let mut tokens = HashMap::<_, Vec<_>>::new();
+        let mut num_steps = 0;
+
+        while !scheduler.is_idle() {
+            for event in scheduler.step(&Default::default()) {
+                if let SchedulerEvent::Token { request, token } = event {
+                    tokens.entry(request).or_default().push(token);
+                }
+            }
+            num_steps += 1;
+        }
+
+        (tokens, num_steps)
+    }
+
+    #[test]
+    fn should_generate_the_requests_in_a_single_batch() {
+        let mut scheduler = scheduler(16, SchedulerConfig::new());
+        let first = scheduler.submit(vec![0, 1], 3);
+        let second = scheduler.submit(vec![10], 2);
+
+        let (tokens, num_steps) = run(&mut scheduler);
+
+        assert_eq!(tokens[&first], vec![2, 3, 4]);
+        assert_eq!(tokens[&second], vec![11, 12]);
+        assert_eq!(num_steps, 3);
+    }
+
+    #[test]
+    fn should_admit_the_requests_within_the_token_budget() {
+        let mut scheduler = scheduler(16, SchedulerConfig::new().with_max_tokens_per_step(3));
+        scheduler.submit(vec![0, 1], 4);
+        scheduler.submit(vec![0, 1], 4);
+
+        scheduler.step(&Default::default());
+        assert_eq!(scheduler.num_running(), 1);
+
+        // The running sequence uses one token of the budget.
+        scheduler.step(&Default::default());
+        assert_eq!(scheduler.num_running(), 2);
+    }
+
+    #[test]
+    fn should_stop_at_the_stop_token() {
+        let mut scheduler = scheduler(16, SchedulerConfig::new().with_stop_tokens(vec![3]));
+        let request = scheduler.submit(vec![0], 10);
+
+        let events = (0..3)
+            .flat_map(|_| scheduler.step(&Default::default()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            events.last(),
+            Some(&SchedulerEvent::Finished {
+                request,
+                reason: FinishReason::StopToken
+            })
+        );
+        assert!(scheduler.is_idle());
+    }
+
+    #[test]
+    fn should_preempt_the_sequences_when_the_cache_is_full() {
+        let mut scheduler = scheduler(4, SchedulerConfig::new());
+        let first = scheduler.submit(vec![0, 1, 2], 3);
+        let second = scheduler.submit(vec![10, 11, 12], 3);
+
+        let (tokens, _) = run(&mut scheduler);
+
+        assert_eq!(tokens[&first], vec![3, 4, 5]);
+        assert_eq!(tokens[&second], vec![13, 14, 15]);
+        assert_eq!(scheduler.cache.num_free_blocks(), 4);
+    }
+
+    #[test]
+    fn should_cancel_the_requests() {
+        let mut scheduler = scheduler(16, SchedulerConfig::new());
+        let request = scheduler.submit(vec![0], 10);
+        scheduler.step(&Default::default());
+
+        assert!(scheduler.cancel(request));
+        assert_eq!(
+            scheduler.step(&Default::default()),
+            vec![SchedulerEvent::Finished {
+                request,
+                reason: FinishReason::Cancelled
+            }]
+        );
+        assert!(scheduler.is_idle());
+    }
+}
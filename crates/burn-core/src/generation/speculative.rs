@@ -0,0 +1,427 @@
+use crate as burn;
+
+use super::stream::{spawn_stream, tokens_tensor, Chunker};
+use super::{
+    Detokenizer, FinishReason, GeneratedChunk, Sampler, SamplerConfig, TextGenerationModel,
+    TokenStream,
+};
+use crate::config::Config;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor};
+use core::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A language model computing the logits of many positions of a sequence at once, used to verify
+/// the tokens proposed by a draft model with a single forward pass, see [SpeculativeGenerator].
+///
+/// The sequence can be shorter than the one of the previous call after draft tokens are
+/// rejected, the cache should then be truncated, which the
+/// [autoregressive cache](crate::nn::transformer::TransformerEncoderAutoregressiveCache) of the
+/// transformer does.
+pub trait SpeculativeModel<B: Backend>: TextGenerationModel<B> {
+    /// Compute the logits of the tokens following each of the last `num_positions` tokens of the
+    /// sequence.
+    ///
+    /// # Shapes
+    ///
+    /// - tokens: `[1, seq_length]`, all the tokens of the sequence including the prompt.
+    /// - output: `[num_positions, vocab_size]`
+    fn positions_logits(
+        &self,
+        tokens: Tensor<B, 2, Int>,
+        num_positions: usize,
+        cache: &mut Self::Cache,
+    ) -> Tensor<B, 2>;
+}
+
+/// Configuration to create a [SpeculativeGenerator](SpeculativeGenerator) using the
+/// [init function](SpeculativeConfig::init).
+#[derive(Config, Debug)]
+pub struct SpeculativeConfig {
+    /// The configuration of the sampler selecting the generated tokens, used for both the draft
+    /// and the main model.
+    #[config(default = "SamplerConfig::new()")]
+    pub sampler: SamplerConfig,
+    /// The number of tokens proposed by the draft model before they are verified.
+    #[config(default = 4)]
+    pub num_draft_tokens: usize,
+    /// The maximum number of generated tokens.
+    #[config(default = 256)]
+    pub max_new_tokens: usize,
+    /// The tokens ending the generation, e.g. the end of sequence token, which aren't returned.
+    #[config(default = "Vec::new()")]
+    pub stop_tokens: Vec<usize>,
+}
+
+/// Statistics of a speculative generation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpeculativeStats {
+    /// The number of tokens proposed by the draft model.
+    pub num_drafted: usize,
+    /// The number of draft tokens accepted by the main model.
+    pub num_accepted: usize,
+    /// The number of forward passes of the main model.
+    pub num_verifications: usize,
+}
+
+impl SpeculativeStats {
+    /// The fraction of the draft tokens accepted by the main model.
+    pub fn acceptance_rate(&self) -> f64 {
+        if self.num_drafted == 0 {
+            return 0.0;
+        }
+
+        self.num_accepted as f64 / self.num_drafted as f64
+    }
+}
+
+/// Generate text with speculative decoding: a small draft model proposes a few tokens which are
+/// verified by the main model with a single forward pass.
+///
+/// The draft tokens are accepted with the probability of the main model over the one of the draft
+/// model, and the first rejected token is replaced by one sampled from the difference of their
+/// distributions, so the generated text follows the distribution of the main model. Each
+/// verification produces between one and `num_draft_tokens + 1` tokens.
+///
+/// Should be created with [SpeculativeConfig].
+#[derive(Debug, Clone)]
+pub struct SpeculativeGenerator<B, M, S, D> {
+    model: M,
+    draft: S,
+    detokenizer: D,
+    config: SpeculativeConfig,
+    _backend: PhantomData<B>,
+}
+
+impl SpeculativeConfig {
+    /// Initialize a new [speculative generator](SpeculativeGenerator) verifying the tokens of the
+    /// draft model with the main model.
+    ///
+    /// # Panics
+    ///
+    /// If the number of draft tokens is zero.
+    pub fn init<B, M, S, D>(
+        &self,
+        model: M,
+        draft: S,
+        detokenizer: D,
+    ) -> SpeculativeGenerator<B, M, S, D>
+    where
+        B: Backend,
+        M: SpeculativeModel<B>,
+        S: TextGenerationModel<B>,
+        D: Detokenizer,
+    {
+        assert!(
+            self.num_draft_tokens > 0,
+            "The draft model should propose at least one token."
+        );
+
+        SpeculativeGenerator {
+            model,
+            draft,
+            detokenizer,
+            config: self.clone(),
+            _backend: PhantomData,
+        }
+    }
+}
+
+impl<B, M, S, D> SpeculativeGenerator<B, M, S, D>
+where
+    B: Backend,
+    M: SpeculativeModel<B>,
+    S: TextGenerationModel<B>,
+    D: Detokenizer + Clone,
+{
+    /// Generate the text following the prompt, blocking until the generation is finished.
+    pub fn generate(
+        &self,
+        prompt: &[usize],
+        device: &B::Device,
+    ) -> (String, FinishReason, SpeculativeStats) {
+        let mut text = String::new();
+        let (reason, stats) = self.run(prompt, device, &AtomicBool::new(false), &mut |chunk| {
+            text.push_str(&chunk.text)
+        });
+
+        (text, reason, stats)
+    }
+
+    /// Generate the text following the prompt on another thread, returning a
+    /// [stream](TokenStream) of the chunks of text as they are produced.
+    pub fn stream(&self, prompt: Vec<usize>, device: &B::Device) -> TokenStream
+    where
+        M: Clone + Send + 'static,
+        S: Clone + Send + 'static,
+        D: Send + 'static,
+    {
+        let generator = self.clone();
+        let device = device.clone();
+
+        spawn_stream(move |cancelled, emit| generator.run(&prompt, &device, cancelled, emit).0)
+    }
+
+    fn run<F: FnMut(GeneratedChunk) + ?Sized>(
+        &self,
+        prompt: &[usize],
+        device: &B::Device,
+        cancelled: &AtomicBool,
+        emit: &mut F,
+    ) -> (FinishReason, SpeculativeStats) {
+        assert!(
+            !prompt.is_empty(),
+            "The prompt should have at least one token."
+        );
+
+        let mut sampler = self.config.sampler.init();
+        let mut cache = self.model.new_cache();
+        let mut draft_cache = self.draft.new_cache();
+        let mut chunker = Chunker::new(self.detokenizer.clone(), prompt);
+        let mut tokens = prompt.to_vec();
+        let mut stats = SpeculativeStats::default();
+        let mut num_generated = 0;
+
+        loop {
+            if cancelled.load(Ordering::Relaxed) {
+                chunker.finish(emit);
+                return (FinishReason::Cancelled, stats);
+            }
+
+            let remaining = self.config.max_new_tokens - num_generated;
+            if remaining == 0 {
+                chunker.finish(emit);
+                return (FinishReason::MaxTokens, stats);
+            }
+
+            let num_draft = self.config.num_draft_tokens.min(remaining);
+            let (drafted, draft_probs) =
+                self.draft(&tokens, num_draft, device, &mut draft_cache, &mut sampler);
+            let accepted = self.verify(
+                &tokens,
+                &drafted,
+                &draft_probs,
+                device,
+                &mut cache,
+                &mut sampler,
+            );
+
+            stats.num_drafted += drafted.len();
+            // The last token is always sampled by the main model.
+            stats.num_accepted += accepted.len() - 1;
+            stats.num_verifications += 1;
+
+            for token in accepted.into_iter().take(remaining) {
+                if self.config.stop_tokens.contains(&token) {
+                    chunker.finish(emit);
+                    return (FinishReason::StopToken, stats);
+                }
+
+                chunker.push(token, emit);
+                tokens.push(token);
+                num_generated += 1;
+            }
+        }
+    }
+
+    /// Sample the draft tokens, returning them with the probabilities of the draft model.
+    fn draft(
+        &self,
+        tokens: &[usize],
+        num_draft: usize,
+        device: &B::Device,
+        cache: &mut S::Cache,
+        sampler: &mut Sampler,
+    ) -> (Vec<usize>, Vec<Vec<f64>>) {
+        let mut sequence = tokens.to_vec();
+        let mut drafted = Vec::with_capacity(num_draft);
+        let mut probs = Vec::with_capacity(num_draft);
+
+        for _ in 0..num_draft {
+            let logits = self
+                .draft
+                .next_token_logits(tokens_tensor::<B>(&sequence, device), cache);
+            let token_probs = sampler.probs(logits);
+            let token = sampler.sample_probs(&token_probs);
+
+            sequence.push(token);
+            drafted.push(token);
+            probs.push(token_probs);
+        }
+
+        (drafted, probs)
+    }
+
+    /// Verify the draft tokens with the main model, returning the accepted ones followed by the
+    /// token sampled by the main model.
+    fn verify(
+        &self,
+        tokens: &[usize],
+        drafted: &[usize],
+        draft_probs: &[Vec<f64>],
+        device: &B::Device,
+        cache: &mut M::Cache,
+        sampler: &mut Sampler,
+    ) -> Vec<usize> {
+        let sequence = [tokens, drafted].concat();
+        let num_positions = drafted.len() + 1;
+        let logits = self.model.positions_logits(
+            tokens_tensor::<B>(&sequence, device),
+            num_positions,
+            cache,
+        );
+        let [_, vocab_size] = logits.dims();
+        let mut accepted = Vec::with_capacity(num_positions);
+
+        for (position, (token, draft_probs)) in drafted.iter().zip(draft_probs).enumerate() {
+            let probs = sampler.probs(position_logits(logits.clone(), position, vocab_size));
+            let ratio = probs[*token] / draft_probs[*token];
+
+            if sampler.uniform() < ratio {
+                accepted.push(*token);
+                continue;
+            }
+
+            // The rejected token is replaced by one sampled from the residual distribution,
+            // which keeps the distribution of the main model.
+            let residual = probs
+                .iter()
+                .zip(draft_probs)
+                .map(|(prob, draft_prob)| (prob - draft_prob).max(0.0))
+                .collect::<Vec<_>>();
+            let token = match residual.iter().any(|prob| *prob > 0.0) {
+                true => sampler.sample_probs(&residual),
+                false => sampler.sample_probs(&probs),
+            };
+            accepted.push(token);
+
+            return accepted;
+        }
+
+        // All the draft tokens are accepted, the main model already computed the next one.
+        let logits = position_logits(logits, drafted.len(), vocab_size);
+        accepted.push(sampler.sample(logits));
+
+        accepted
+    }
+}
+
+fn position_logits<B: Backend>(
+    logits: Tensor<B, 2>,
+    position: usize,
+    vocab_size: usize,
+) -> Tensor<B, 1> {
+    logits
+        .slice([position..position + 1, 0..vocab_size])
+        .reshape([vocab_size])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::detokenizer::tests::ByteDetokenizer;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+
+    /// Predict the next byte, or the same byte again when `repeat` is set.
+    #[derive(Clone)]
+    struct ByteModel {
+        repeat: bool,
+    }
+
+    impl ByteModel {
+        fn logits<B: Backend>(&self, last: usize, device: &B::Device) -> Tensor<B, 1> {
+            let next = match self.repeat {
+                true => last,
+                false => (last + 1) % 256,
+            };
+            let mut logits = vec![0.0f32; 256];
+            logits[next] = 10.0;
+
+            Tensor::from_data(Data::from(logits.as_slice()).convert(), device)
+        }
+    }
+
+    impl<B: Backend> TextGenerationModel<B> for ByteModel {
+        type Cache = ();
+
+        fn new_cache(&self) {}
+
+        fn next_token_logits(&self, tokens: Tensor<B, 2, Int>, cache: &mut ()) -> Tensor<B, 1> {
+            self.positions_logits(tokens, 1, cache).reshape([256])
+        }
+    }
+
+    impl<B: Backend> SpeculativeModel<B> for ByteModel {
+        fn positions_logits(
+            &self,
+            tokens: Tensor<B, 2, Int>,
+            num_positions: usize,
+            _cache: &mut (),
+        ) -> Tensor<B, 2> {
+            let [_, seq_length] = tokens.dims();
+            let device = tokens.device();
+            let tokens = tokens.into_data().convert::<i64>().value;
+
+            let logits = tokens[seq_length - num_positions..]
+                .iter()
+                .map(|token| self.logits::<B>(*token as usize, &device).unsqueeze())
+                .collect();
+
+            Tensor::cat(logits, 0)
+        }
+    }
+
+    fn generate(draft: ByteModel) -> (String, FinishReason, SpeculativeStats) {
+        let config = SpeculativeConfig::new()
+            .with_sampler(SamplerConfig::new().with_temperature(0.0))
+            .with_num_draft_tokens(3)
+            .with_max_new_tokens(8);
+        let generator = config.init::<TestBackend, _, _, _>(
+            ByteModel { repeat: false },
+            draft,
+            ByteDetokenizer,
+        );
+
+        generator.generate(&[b'a' as usize], &Default::default())
+    }
+
+    #[test]
+    fn should_accept_the_tokens_of_a_matching_draft_model() {
+        let (text, reason, stats) = generate(ByteModel { repeat: false });
+
+        assert_eq!(text, "bcdefghi");
+        assert_eq!(reason, FinishReason::MaxTokens);
+        assert_eq!(stats.acceptance_rate(), 1.0);
+        assert_eq!(stats.num_verifications, 2);
+    }
+
+    #[test]
+    fn should_generate_the_tokens_of_the_main_model_when_the_draft_is_rejected() {
+        let (text, reason, stats) = generate(ByteModel { repeat: true });
+
+        assert_eq!(text, "bcdefghi");
+        assert_eq!(reason, FinishReason::MaxTokens);
+        assert_eq!(stats.num_accepted, 0);
+        assert_eq!(stats.num_verifications, 8);
+    }
+
+    #[test]
+    fn should_stop_at_the_stop_token() {
+        let config = SpeculativeConfig::new()
+            .with_sampler(SamplerConfig::new().with_temperature(0.0))
+            .with_stop_tokens(vec![b'd' as usize]);
+        let generator = config.init::<TestBackend, _, _, _>(
+            ByteModel { repeat: false },
+            ByteModel { repeat: false },
+            ByteDetokenizer,
+        );
+
+        let chunks = generator
+            .stream(vec![b'a' as usize], &Default::default())
+            .map(|chunk| chunk.text)
+            .collect::<Vec<_>>();
+
+        assert_eq!(chunks, vec!["b", "c"]);
+    }
+}
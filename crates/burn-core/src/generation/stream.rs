@@ -0,0 +1,481 @@
+use crate as burn;
+
+use super::{Detokenizer, IncrementalDetokenizer, SamplerConfig};
+use crate::config::Config;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, ElementConversion, Int, Shape, Tensor};
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+/// A language model generating the tokens of a sequence one at a time, see [TextGenerator].
+pub trait TextGenerationModel<B: Backend> {
+    /// The cache of the model for a sequence, e.g. the
+    /// [autoregressive cache](crate::nn::transformer::TransformerEncoderAutoregressiveCache) of
+    /// its transformer, so only the last token is computed at each step.
+    type Cache;
+
+    /// Create an empty cache for a new sequence.
+    fn new_cache(&self) -> Self::Cache;
+
+    /// Compute the logits of the next token of the sequence.
+    ///
+    /// The sequence usually has one more token than at the previous call, but it can also have
+    /// many more or fewer tokens when it's used as the draft model of a
+    /// [speculative generator](super::SpeculativeGenerator).
+    ///
+    /// # Shapes
+    ///
+    /// - tokens: `[1, seq_length]`, all the tokens of the sequence including the prompt.
+    /// - output: `[vocab_size]`
+    fn next_token_logits(&self, tokens: Tensor<B, 2, Int>, cache: &mut Self::Cache)
+        -> Tensor<B, 1>;
+}
+
+/// Configuration to create a [TextGenerator](TextGenerator) using the
+/// [init function](GenerationConfig::init).
+#[derive(Config, Debug)]
+pub struct GenerationConfig {
+    /// The configuration of the sampler selecting the generated tokens.
+    #[config(default = "SamplerConfig::new()")]
+    pub sampler: SamplerConfig,
+    /// The maximum number of generated tokens.
+    #[config(default = 256)]
+    pub max_new_tokens: usize,
+    /// The tokens ending the generation, e.g. the end of sequence token, which aren't returned.
+    #[config(default = "Vec::new()")]
+    pub stop_tokens: Vec<usize>,
+}
+
+/// The reason a generation finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinishReason {
+    /// The maximum number of tokens was generated.
+    MaxTokens,
+    /// A stop token was generated.
+    StopToken,
+    /// The generation was cancelled.
+    Cancelled,
+}
+
+/// Generated tokens with their text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedChunk {
+    /// The generated tokens.
+    pub tokens: Vec<usize>,
+    /// The text of the tokens, which can be empty when it's held back because of an incomplete
+    /// character until the next chunk.
+    pub text: String,
+}
+
+/// Generate text with a [language model](TextGenerationModel), layered on the cache of the model
+/// and a [sampler](super::Sampler).
+///
+/// Should be created with [GenerationConfig].
+#[derive(Debug, Clone)]
+pub struct TextGenerator<B, M, D> {
+    model: M,
+    detokenizer: D,
+    config: GenerationConfig,
+    _backend: PhantomData<B>,
+}
+
+impl GenerationConfig {
+    /// Initialize a new [text generator](TextGenerator) for the model, decoding the tokens with
+    /// the detokenizer.
+    pub fn init<B, M, D>(&self, model: M, detokenizer: D) -> TextGenerator<B, M, D>
+    where
+        B: Backend,
+        M: TextGenerationModel<B>,
+        D: Detokenizer,
+    {
+        TextGenerator {
+            model,
+            detokenizer,
+            config: self.clone(),
+            _backend: PhantomData,
+        }
+    }
+}
+
+impl<B, M, D> TextGenerator<B, M, D>
+where
+    B: Backend,
+    M: TextGenerationModel<B>,
+    D: Detokenizer + Clone,
+{
+    /// Generate the text following the prompt, blocking until the generation is finished.
+    pub fn generate(&self, prompt: &[usize], device: &B::Device) -> (String, FinishReason) {
+        let mut text = String::new();
+        let reason = self.run(prompt, device, &AtomicBool::new(false), &mut |chunk| {
+            text.push_str(&chunk.text)
+        });
+
+        (text, reason)
+    }
+
+    /// Generate the text following the prompt on another thread, returning a
+    /// [stream](TokenStream) of the chunks of text as they are produced.
+    pub fn stream(&self, prompt: Vec<usize>, device: &B::Device) -> TokenStream
+    where
+        M: Clone + Send + 'static,
+        D: Send + 'static,
+    {
+        let generator = self.clone();
+        let device = device.clone();
+
+        spawn_stream(move |cancelled, emit| generator.run(&prompt, &device, cancelled, emit))
+    }
+
+    fn run<F: FnMut(GeneratedChunk) + ?Sized>(
+        &self,
+        prompt: &[usize],
+        device: &B::Device,
+        cancelled: &AtomicBool,
+        emit: &mut F,
+    ) -> FinishReason {
+        assert!(
+            !prompt.is_empty(),
+            "The prompt should have at least one token."
+        );
+
+        let mut sampler = self.config.sampler.init();
+        let mut cache = self.model.new_cache();
+        let mut chunker = Chunker::new(self.detokenizer.clone(), prompt);
+        let mut tokens = tokens_tensor::<B>(prompt, device);
+        let mut reason = FinishReason::MaxTokens;
+
+        for _ in 0..self.config.max_new_tokens {
+            if cancelled.load(Ordering::Relaxed) {
+                reason = FinishReason::Cancelled;
+                break;
+            }
+
+            let logits = self.model.next_token_logits(tokens.clone(), &mut cache);
+            let token = sampler.sample(logits);
+
+            if self.config.stop_tokens.contains(&token) {
+                reason = FinishReason::StopToken;
+                break;
+            }
+
+            chunker.push(token, emit);
+            tokens = Tensor::cat(vec![tokens, tokens_tensor::<B>(&[token], device)], 1);
+        }
+
+        chunker.finish(emit);
+
+        reason
+    }
+}
+
+/// Group the generated tokens into [chunks](GeneratedChunk) as soon as their text is complete.
+pub(crate) struct Chunker<D> {
+    detokenizer: IncrementalDetokenizer<D>,
+    pending: Vec<usize>,
+}
+
+impl<D: Detokenizer> Chunker<D> {
+    pub(crate) fn new(detokenizer: D, prompt: &[usize]) -> Self {
+        Self {
+            detokenizer: IncrementalDetokenizer::new(detokenizer, prompt),
+            pending: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push<F: FnMut(GeneratedChunk) + ?Sized>(&mut self, token: usize, emit: &mut F) {
+        self.pending.push(token);
+
+        if let Some(text) = self.detokenizer.push(token) {
+            emit(GeneratedChunk {
+                tokens: core::mem::take(&mut self.pending),
+                text,
+            });
+        }
+    }
+
+    pub(crate) fn finish<F: FnMut(GeneratedChunk) + ?Sized>(mut self, emit: &mut F) {
+        let text = self.detokenizer.finish().unwrap_or_default();
+
+        if !self.pending.is_empty() {
+            emit(GeneratedChunk {
+                tokens: self.pending,
+                text,
+            });
+        }
+    }
+}
+
+/// Run the generation on another thread, returning the [stream](TokenStream) of its chunks.
+pub(crate) fn spawn_stream<F>(run: F) -> TokenStream
+where
+    F: FnOnce(&AtomicBool, &mut dyn FnMut(GeneratedChunk)) -> FinishReason + Send + 'static,
+{
+    let shared = Arc::new(StreamShared::default());
+    let sender = StreamSender {
+        shared: shared.clone(),
+    };
+
+    std::thread::spawn(move || {
+        let reason = run(&sender.shared.cancelled, &mut |chunk| sender.send(chunk));
+        sender.finish(reason);
+    });
+
+    TokenStream { shared }
+}
+
+pub(crate) fn tokens_tensor<B: Backend>(tokens: &[usize], device: &B::Device) -> Tensor<B, 2, Int> {
+    Tensor::from_data(
+        Data::new(
+            tokens.iter().map(|token| (*token as i64).elem()).collect(),
+            Shape::new([1, tokens.len()]),
+        ),
+        device,
+    )
+}
+
+#[derive(Default)]
+struct StreamShared {
+    state: Mutex<StreamState>,
+    ready: Condvar,
+    cancelled: AtomicBool,
+}
+
+#[derive(Default)]
+struct StreamState {
+    chunks: VecDeque<GeneratedChunk>,
+    closed: bool,
+    reason: Option<FinishReason>,
+    waker: Option<Waker>,
+}
+
+impl StreamShared {
+    fn update<F: FnOnce(&mut StreamState)>(&self, func: F) {
+        let mut state = self.state.lock().unwrap();
+        func(&mut state);
+
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+        self.ready.notify_all();
+    }
+}
+
+/// The sending side of a [token stream](TokenStream), closing it when dropped, e.g. if the model
+/// panics during the generation.
+struct StreamSender {
+    shared: Arc<StreamShared>,
+}
+
+impl StreamSender {
+    fn send(&self, chunk: GeneratedChunk) {
+        self.shared.update(|state| state.chunks.push_back(chunk));
+    }
+
+    fn finish(self, reason: FinishReason) {
+        self.shared.update(|state| state.reason = Some(reason));
+    }
+}
+
+impl Drop for StreamSender {
+    fn drop(&mut self) {
+        self.shared.update(|state| state.closed = true);
+    }
+}
+
+/// The chunks of text of a generation, as they are produced by a [text generator](TextGenerator).
+///
+/// The stream can be consumed asynchronously as a [Stream](futures_core::Stream) or by blocking
+/// the current thread as an [Iterator]. The generation is [cancelled](TokenStream::cancel) when
+/// the stream is dropped.
+pub struct TokenStream {
+    shared: Arc<StreamShared>,
+}
+
+/// Cancel a generation from another thread or task, see [TokenStream::canceller].
+#[derive(Clone)]
+pub struct GenerationCanceller {
+    shared: Arc<StreamShared>,
+}
+
+impl GenerationCanceller {
+    /// Cancel the generation, the remaining chunks are still returned by the stream.
+    pub fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+impl TokenStream {
+    /// Cancel the generation, the chunks produced before the model notices it are still returned.
+    pub fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Get a handle to cancel the generation while the stream is consumed.
+    pub fn canceller(&self) -> GenerationCanceller {
+        GenerationCanceller {
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// The reason the generation finished, once the stream is exhausted.
+    ///
+    /// There is no reason if the generation panicked.
+    pub fn finish_reason(&self) -> Option<FinishReason> {
+        self.shared.state.lock().unwrap().reason
+    }
+}
+
+impl Iterator for TokenStream {
+    type Item = GeneratedChunk;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut state = self.shared.state.lock().unwrap();
+
+        loop {
+            if let Some(chunk) = state.chunks.pop_front() {
+                return Some(chunk);
+            }
+
+            if state.closed {
+                return None;
+            }
+
+            state = self.shared.ready.wait(state).unwrap();
+        }
+    }
+}
+
+impl futures_core::Stream for TokenStream {
+    type Item = GeneratedChunk;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.shared.state.lock().unwrap();
+
+        if let Some(chunk) = state.chunks.pop_front() {
+            return Poll::Ready(Some(chunk));
+        }
+
+        if state.closed {
+            return Poll::Ready(None);
+        }
+
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for TokenStream {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::detokenizer::tests::ByteDetokenizer;
+    use crate::TestBackend;
+    use std::task::Wake;
+
+    /// Always predict the next byte, counting the steps in its cache.
+    #[derive(Clone)]
+    struct NextByteModel;
+
+    impl<B: Backend> TextGenerationModel<B> for NextByteModel {
+        type Cache = usize;
+
+        fn new_cache(&self) -> usize {
+            0
+        }
+
+        fn next_token_logits(&self, tokens: Tensor<B, 2, Int>, steps: &mut usize) -> Tensor<B, 1> {
+            let [_, seq_length] = tokens.dims();
+            *steps += 1;
+            assert_eq!(
+                seq_length, *steps,
+                "One token should be added at each step."
+            );
+
+            let last = tokens
+                .slice([0..1, seq_length - 1..seq_length])
+                .into_scalar()
+                .elem::<i64>() as usize;
+            let mut logits = vec![0.0f32; 256];
+            logits[(last + 1) % 256] = 10.0;
+
+            Tensor::from_data(Data::from(logits.as_slice()).convert(), &tokens.device())
+        }
+    }
+
+    fn generator(
+        config: GenerationConfig,
+    ) -> TextGenerator<TestBackend, NextByteModel, ByteDetokenizer> {
+        let config = config.with_sampler(SamplerConfig::new().with_temperature(0.0));
+
+        config.init(NextByteModel, ByteDetokenizer)
+    }
+
+    #[test]
+    fn should_generate_until_the_max_tokens() {
+        let generator = generator(GenerationConfig::new().with_max_new_tokens(3));
+
+        let (text, reason) = generator.generate(&[b'a' as usize], &Default::default());
+
+        assert_eq!(text, "bcd");
+        assert_eq!(reason, FinishReason::MaxTokens);
+    }
+
+    #[test]
+    fn should_stream_the_chunks_until_the_stop_token() {
+        let generator = generator(GenerationConfig::new().with_stop_tokens(vec![b'e' as usize]));
+
+        let mut stream = generator.stream(vec![b'a' as usize], &Default::default());
+        let chunks = stream.by_ref().map(|chunk| chunk.text).collect::<Vec<_>>();
+
+        assert_eq!(chunks, vec!["b", "c", "d"]);
+        assert_eq!(stream.finish_reason(), Some(FinishReason::StopToken));
+    }
+
+    #[test]
+    fn should_cancel_the_generation() {
+        let generator = generator(GenerationConfig::new().with_max_new_tokens(usize::MAX));
+
+        let mut stream = generator.stream(vec![0], &Default::default());
+        let _ = stream.next();
+        stream.canceller().cancel();
+        let _ = stream.by_ref().count();
+
+        assert_eq!(stream.finish_reason(), Some(FinishReason::Cancelled));
+    }
+
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    #[test]
+    fn should_poll_the_chunks_asynchronously() {
+        let generator = generator(GenerationConfig::new().with_max_new_tokens(2));
+        let mut stream = generator.stream(vec![b'a' as usize], &Default::default());
+        let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        let mut chunks = Vec::new();
+
+        loop {
+            match futures_core::Stream::poll_next(Pin::new(&mut stream), &mut cx) {
+                Poll::Ready(Some(chunk)) => chunks.push(chunk.text),
+                Poll::Ready(None) => break,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+
+        assert_eq!(chunks, vec!["b", "c"]);
+    }
+}
@@ -27,6 +27,29 @@ pub mod lr_scheduler;
 /// Gradient clipping module.
 pub mod grad_clipping;
 
+/// Collective operations between the replicas of a model.
+#[cfg(feature = "std")]
+pub mod collective;
+
+/// Pipeline-parallel execution of modules split in stages across devices.
+#[cfg(feature = "std")]
+pub mod pipeline;
+
+/// Text generation with language models.
+#[cfg(feature = "std")]
+pub mod generation;
+
+/// Pruning of the weights of modules.
+#[cfg(feature = "std")]
+pub mod prune;
+
+/// Solvers of ordinary differential equations, e.g. for neural ODEs.
+#[cfg(feature = "std")]
+pub mod ode;
+
+/// Preprocessing of the inputs of models, bundled with their records.
+pub mod preprocessing;
+
 /// Module for the neural network module.
 pub mod module;
 
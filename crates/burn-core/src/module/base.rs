@@ -1,4 +1,4 @@
-use super::ParamId;
+use super::{ParamId, SubmoduleMapper};
 use crate::{
     record::Record,
     tensor::backend::{AutodiffBackend, Backend},
@@ -141,6 +141,82 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
     /// Map each tensor parameter in the module with a [mapper](ModuleMapper).
     fn map<Mapper: ModuleMapper<B>>(self, mapper: &mut Mapper) -> Self;
 
+    /// Map each direct submodule of the module with a [submodule mapper](SubmoduleMapper).
+    ///
+    /// Modules without submodules, e.g. parameters and constants, are returned unchanged.
+    fn map_submodules<Mapper: SubmoduleMapper<B>>(self, _mapper: &mut Mapper) -> Self
+    where
+        Self: 'static,
+    {
+        self
+    }
+
+    /// Replace the submodule at the given path, e.g. `head` or `blocks.0.attention`, with another
+    /// module of the same type, e.g. to replace the classifier of a pretrained model.
+    ///
+    /// The items of vectors, arrays and tuples are named by their index. The record of the
+    /// module follows its new structure, so load the pretrained weights before replacing the
+    /// submodules, and save the edited module as usual.
+    ///
+    /// # Panics
+    ///
+    /// If there is no submodule of the type of the replacement at the given path.
+    fn replace_submodule<M: Module<B> + 'static>(self, path: &str, module: M) -> Self
+    where
+        Self: 'static,
+    {
+        super::replace_submodule::<B, Self, M>(self, path, module)
+    }
+
+    /// Replace every submodule of the type `M` with the output of the function, called with the
+    /// path of the submodule and the submodule, e.g. to change the probability of every dropout.
+    ///
+    /// The replacements aren't searched for submodules of the same type, and submodules can only
+    /// be replaced by modules of the same type: to swap, say, activation functions, the type of
+    /// the fields must cover both, e.g. an enum of activations deriving [Module].
+    fn replace_submodules<M, F>(self, func: F) -> Self
+    where
+        Self: 'static,
+        M: Module<B> + 'static,
+        F: FnMut(&str, M) -> M,
+    {
+        super::replace_submodules::<B, Self, M, F>(self, func)
+    }
+
+    /// Insert the module in the vector of modules at the given path, e.g. `blocks.2` to insert
+    /// the module in the field `blocks` at index 2, shifting the following modules.
+    ///
+    /// # Panics
+    ///
+    /// If there is no vector of modules of the type of the inserted module at the given path, or
+    /// if the index is greater than the length of the vector.
+    fn insert_submodule<M: Module<B> + 'static>(self, path: &str, module: M) -> Self
+    where
+        Self: 'static,
+    {
+        super::insert_submodule::<B, Self, M>(self, path, module)
+    }
+
+    /// Replace the float parameters of the module by the [supplied ones](super::ModuleParams) of
+    /// the same id, keeping their graph. The parameters that aren't supplied are unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If a supplied parameter doesn't have the shape of the parameter it replaces.
+    fn load_params(self, params: &super::ModuleParams) -> Self {
+        super::load_params::<B, Self>(self, params)
+    }
+
+    /// Evaluate the function, e.g. the forward pass, with a copy of the module using the
+    /// [supplied parameters](super::ModuleParams) instead of its own, leaving the module
+    /// unchanged.
+    ///
+    /// This is useful for meta-learning, hypernetworks and weight perturbations, where the same
+    /// module is evaluated with parameters computed elsewhere.
+    fn forward_with<R, F: FnOnce(Self) -> R>(&self, params: &super::ModuleParams, func: F) -> R {
+        func(self.clone().load_params(params))
+    }
+
     /// Load the module state from a record.
     fn load_record(self, record: Self::Record) -> Self;
 
@@ -211,6 +287,14 @@ pub trait ModuleVisitor<B: Backend> {
     fn visit_int<const D: usize>(&mut self, _id: &ParamId, _tensor: &Tensor<B, D, Int>) {}
     /// Visit a bool tensor in the module.
     fn visit_bool<const D: usize>(&mut self, _id: &ParamId, _tensor: &Tensor<B, D, Bool>) {}
+    /// Called before visiting a field of a module, with the name of the field and the type of
+    /// the module containing it, e.g. `("weight", "Linear")`.
+    ///
+    /// The items of vectors, arrays and tuples are named by their index, with the `Vec`,
+    /// `Array` and `Tuple` container types.
+    fn enter_module(&mut self, _name: &str, _container_type: &str) {}
+    /// Called after visiting a field of a module, see [enter_module](ModuleVisitor::enter_module).
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {}
 }
 
 /// Module mapper trait.
@@ -0,0 +1,309 @@
+use crate::tensor::backend::Backend;
+use crate::tensor::{Tensor, TensorKind};
+use core::any::Any;
+use core::cell::RefCell;
+use std::collections::BTreeMap;
+
+thread_local! {
+    static EXTRACTION: RefCell<Option<Extraction>> = const { RefCell::new(None) };
+}
+
+struct Extraction {
+    paths: Vec<String>,
+    scope: Vec<String>,
+    features: BTreeMap<String, Box<dyn Any + Send>>,
+}
+
+impl Extraction {
+    fn path(&self, name: &str) -> String {
+        let mut path = self.scope.join(".");
+        if !path.is_empty() {
+            path.push('.');
+        }
+        path.push_str(name);
+
+        path
+    }
+}
+
+/// A model returning the outputs of some of its submodules alongside its own output, e.g. for
+/// perceptual losses, feature pyramid networks or probing experiments.
+///
+/// Modules don't have forward hooks, so the submodules expose their outputs by calling
+/// [feature] with their name, and name the scope of their own submodules with
+/// [feature_scope]. A feature is then selected by its path, e.g. `encoder.block1` for the output
+/// named `block1` in the scope `encoder`:
+///
+/// ```rust, ignore
+/// fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+///     let x = feature_scope("encoder", || self.encoder.forward(input));
+///     let x = feature("neck", self.neck.forward(x));
+///     self.head.forward(x)
+/// }
+/// ```
+///
+/// Calling [feature] or [feature_scope] outside of an [extraction](FeatureExtractor::forward) has
+/// no effect, and only the tensors of the selected paths are kept, so models can expose their
+/// features without slowing down their forward pass.
+#[derive(Debug, Clone)]
+pub struct FeatureExtractor<M> {
+    model: M,
+    paths: Vec<String>,
+}
+
+/// The features recorded while running a [feature extractor](FeatureExtractor).
+#[derive(Debug, Default)]
+pub struct Features {
+    tensors: BTreeMap<String, Box<dyn Any + Send>>,
+}
+
+impl<M> FeatureExtractor<M> {
+    /// Create a feature extractor for the given model, extracting no features.
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            paths: Vec::new(),
+        }
+    }
+
+    /// Extract the feature at the given path.
+    pub fn with_feature<S: Into<String>>(mut self, path: S) -> Self {
+        self.paths.push(path.into());
+        self
+    }
+
+    /// Extract the features at the given paths.
+    pub fn with_features<S: Into<String>, I: IntoIterator<Item = S>>(mut self, paths: I) -> Self {
+        self.paths.extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// The paths of the extracted features.
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+
+    /// Run the forward pass of the model on the input, returning its output and the features
+    /// recorded at the selected paths.
+    ///
+    /// The features are only recorded on the current thread, so the forward pass shouldn't run
+    /// the submodules on other threads.
+    pub fn forward<I, O, F>(&self, input: I, forward: F) -> (O, Features)
+    where
+        F: FnOnce(&M, I) -> O,
+    {
+        let extraction = Extraction {
+            paths: self.paths.clone(),
+            scope: Vec::new(),
+            features: BTreeMap::new(),
+        };
+        // Extractions can be nested, e.g. when the forward pass uses another feature extractor.
+        let previous = EXTRACTION.with_borrow_mut(|state| state.replace(extraction));
+
+        let output = forward(&self.model, input);
+
+        let extraction = EXTRACTION.with_borrow_mut(|state| core::mem::replace(state, previous));
+        let features = Features {
+            tensors: extraction.map(|state| state.features).unwrap_or_default(),
+        };
+
+        (output, features)
+    }
+
+    /// The model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Consumes the feature extractor, returning the model.
+    pub fn into_model(self) -> M {
+        self.model
+    }
+}
+
+impl Features {
+    /// The feature at the given path, if it was recorded.
+    ///
+    /// # Panics
+    ///
+    /// If the feature isn't of the given type, e.g. `Tensor<B, 4>`.
+    pub fn get<T: 'static>(&self, path: &str) -> Option<&T> {
+        self.tensors
+            .get(path)
+            .map(|tensor| tensor.downcast_ref().unwrap_or_else(|| mismatch::<T>(path)))
+    }
+
+    /// Remove the feature at the given path, if it was recorded.
+    ///
+    /// # Panics
+    ///
+    /// If the feature isn't of the given type, e.g. `Tensor<B, 4>`.
+    pub fn remove<T: 'static>(&mut self, path: &str) -> Option<T> {
+        self.tensors
+            .remove(path)
+            .map(|tensor| *tensor.downcast().unwrap_or_else(|_| mismatch::<T>(path)))
+    }
+
+    /// The paths of the recorded features, sorted.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.tensors.keys().map(String::as_str)
+    }
+
+    /// The number of recorded features.
+    pub fn len(&self) -> usize {
+        self.tensors.len()
+    }
+
+    /// If no features were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.tensors.is_empty()
+    }
+}
+
+fn mismatch<T>(path: &str) -> ! {
+    panic!(
+        "The feature {path} isn't a {}.",
+        core::any::type_name::<T>()
+    )
+}
+
+/// Record the tensor as the feature with the given name in the current
+/// [scope](feature_scope), if its path is extracted by the running
+/// [feature extractor](FeatureExtractor).
+///
+/// The tensor is returned unchanged, so the call can wrap the output of a submodule.
+pub fn feature<B, const D: usize, K>(name: &str, tensor: Tensor<B, D, K>) -> Tensor<B, D, K>
+where
+    B: Backend,
+    K: TensorKind<B> + 'static,
+{
+    EXTRACTION.with_borrow_mut(|state| {
+        if let Some(state) = state {
+            let path = state.path(name);
+
+            if state.paths.contains(&path) {
+                state.features.insert(path, Box::new(tensor.clone()));
+            }
+        }
+    });
+
+    tensor
+}
+
+/// Run the function in the scope of the given name, prefixing the paths of the
+/// [features](feature) recorded by the function, e.g. when running a submodule.
+pub fn feature_scope<R, F: FnOnce() -> R>(name: &str, func: F) -> R {
+    let entered = EXTRACTION.with_borrow_mut(|state| match state {
+        Some(state) => {
+            state.scope.push(name.to_string());
+            true
+        }
+        None => false,
+    });
+
+    let output = func();
+
+    if entered {
+        EXTRACTION.with_borrow_mut(|state| {
+            if let Some(state) = state {
+                state.scope.pop();
+            }
+        });
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::Module;
+    use crate::nn::{Linear, LinearConfig, Relu};
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[derive(Module, Debug)]
+    struct Block<B: Backend> {
+        linear: Linear<B>,
+        activation: Relu,
+    }
+
+    impl<B: Backend> Block<B> {
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = feature("linear", self.linear.forward(input));
+            feature("activation", self.activation.forward(x))
+        }
+    }
+
+    #[derive(Module, Debug)]
+    struct Net<B: Backend> {
+        block1: Block<B>,
+        block2: Block<B>,
+    }
+
+    impl<B: Backend> Net<B> {
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = feature_scope("block1", || self.block1.forward(input));
+            feature_scope("block2", || self.block2.forward(x))
+        }
+    }
+
+    fn net() -> Net<TestBackend> {
+        let device = Default::default();
+        let block = || Block {
+            linear: LinearConfig::new(4, 4).init(&device),
+            activation: Relu::new(),
+        };
+
+        Net {
+            block1: block(),
+            block2: block(),
+        }
+    }
+
+    #[test]
+    fn should_extract_the_selected_features() {
+        let net = net();
+        let input = Tensor::random([2, 4], Distribution::Default, &Default::default());
+        let expected = net.block1.linear.forward(input.clone());
+
+        let extractor = FeatureExtractor::new(net).with_features(["block1.linear", "block2"]);
+        let (output, mut features) = extractor.forward(input.clone(), Net::forward);
+
+        assert_eq!(features.paths().collect::<Vec<_>>(), vec!["block1.linear"]);
+        features
+            .remove::<Tensor<TestBackend, 2>>("block1.linear")
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+        extractor
+            .model()
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&output.into_data(), 5);
+    }
+
+    #[test]
+    fn should_not_record_features_outside_of_the_extraction() {
+        let net = net();
+        let input = Tensor::<TestBackend, 2>::zeros([2, 4], &Default::default());
+        let extractor = FeatureExtractor::new(net).with_feature("block1.linear");
+
+        let _ = extractor.model().forward(input.clone());
+        let (_, features) = extractor.forward(input, Net::forward);
+
+        assert_eq!(features.len(), 1);
+    }
+
+    #[test]
+    #[should_panic = "The feature block2.activation isn't a"]
+    fn should_panic_on_the_wrong_feature_type() {
+        let input = Tensor::<TestBackend, 2>::zeros([2, 4], &Default::default());
+        let extractor = FeatureExtractor::new(net()).with_feature("block2.activation");
+
+        let (_, features) = extractor.forward(input, Net::forward);
+
+        features.get::<Tensor<TestBackend, 3>>("block2.activation");
+    }
+}
@@ -0,0 +1,202 @@
+use super::{AutodiffModule, Module, ModuleMapper, ModuleVisitor, ParamId};
+use crate::optim::GradientsParams;
+use crate::tensor::backend::{AutodiffBackend, Backend};
+use burn_tensor::{container::TensorContainer, Tensor};
+
+/// Parameters supplied to a module instead of its own, by [parameter id](ParamId), to evaluate
+/// the module [functionally](Module::forward_with).
+///
+/// The tensors keep their graph when they are [loaded](Module::load_params) in a module, so the
+/// outputs of the module are differentiable with respect to the tensors the parameters are
+/// computed from, e.g. the output of a hypernetwork, or the parameters of the module itself for
+/// the parameters adapted by a [gradient step](ModuleParams::from_gradient_step) in
+/// meta-learning.
+#[derive(Default, Debug)]
+pub struct ModuleParams {
+    container: TensorContainer<ParamId>,
+}
+
+impl ModuleParams {
+    /// Creates new empty [parameters](ModuleParams).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collect the float parameters of the module, e.g. to perturb them.
+    pub fn from_module<B: Backend, M: Module<B>>(module: &M) -> Self {
+        let mut collector = ParamsCollector {
+            params: Self::new(),
+        };
+        module.visit(&mut collector);
+
+        collector.params
+    }
+
+    /// The parameters of the module updated by a step of gradient descent with the given
+    /// gradients, e.g. the adapted parameters of the inner loop of first-order
+    /// [MAML](https://arxiv.org/abs/1703.03400).
+    ///
+    /// The updated parameters are differentiable with respect to the parameters of the module,
+    /// the gradients being constants.
+    pub fn from_gradient_step<B: AutodiffBackend, M: AutodiffModule<B>>(
+        module: &M,
+        grads: &GradientsParams,
+        lr: f64,
+    ) -> Self {
+        let mut stepper = GradientStep {
+            grads,
+            lr,
+            params: Self::new(),
+        };
+        module.visit(&mut stepper);
+
+        stepper.params
+    }
+
+    /// Get the parameter with the given [id](ParamId).
+    pub fn get<B: Backend, const D: usize>(&self, id: &ParamId) -> Option<Tensor<B, D>> {
+        self.container.get(id)
+    }
+
+    /// Remove the parameter with the given [id](ParamId).
+    pub fn remove<B: Backend, const D: usize>(&mut self, id: &ParamId) -> Option<Tensor<B, D>> {
+        self.container.remove(id)
+    }
+
+    /// Register a parameter for the given [id](ParamId), replacing the previous one.
+    pub fn register<B: Backend, const D: usize>(&mut self, id: ParamId, value: Tensor<B, D>) {
+        self.container.register(id, value)
+    }
+
+    /// The number of parameters registered.
+    pub fn len(&self) -> usize {
+        self.container.len()
+    }
+
+    /// If any parameter is registered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Replace the float parameters of the module by the registered ones of the same id.
+pub(crate) fn load_params<B: Backend, M: Module<B>>(module: M, params: &ModuleParams) -> M {
+    let mut loader = ParamsLoader { params };
+    module.map(&mut loader)
+}
+
+struct ParamsCollector {
+    params: ModuleParams,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamsCollector {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params.register(id.clone(), tensor.clone());
+    }
+}
+
+struct GradientStep<'a> {
+    grads: &'a GradientsParams,
+    lr: f64,
+    params: ModuleParams,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GradientStep<'a> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let param = match self.grads.get::<B::InnerBackend, D>(id) {
+            Some(grad) => tensor
+                .clone()
+                .sub(Tensor::from_inner(grad).mul_scalar(self.lr)),
+            None => tensor.clone(),
+        };
+
+        self.params.register(id.clone(), param);
+    }
+}
+
+struct ParamsLoader<'a> {
+    params: &'a ModuleParams,
+}
+
+impl<'a, B: Backend> ModuleMapper<B> for ParamsLoader<'a> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let Some(param) = self.params.get::<B, D>(id) else {
+            return tensor;
+        };
+        assert_eq!(
+            param.shape(),
+            tensor.shape(),
+            "The shape of the supplied parameter {id} doesn't match the shape of the module's."
+        );
+
+        param
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::Data;
+    use crate::TestAutodiffBackend;
+
+    fn linear(weight: f32) -> Linear<TestAutodiffBackend> {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(1, 1)
+            .with_bias(false)
+            .init::<TestAutodiffBackend>(&device);
+        linear.weight = Param::from_data([[weight]], &device);
+        linear
+    }
+
+    #[test]
+    fn forward_with_should_use_the_supplied_params() {
+        let device = Default::default();
+        let linear = linear(2.0);
+        let mut params = ModuleParams::new();
+        params.register(
+            linear.weight.id.clone(),
+            Tensor::<TestAutodiffBackend, 2>::from_floats([[5.0]], &device),
+        );
+
+        let input = Tensor::<TestAutodiffBackend, 2>::from_floats([[3.0]], &device);
+        let output = linear.forward_with(&params, |linear| linear.forward(input.clone()));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[15.0]]), 3);
+        linear
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&Data::from([[6.0]]), 3);
+    }
+
+    #[test]
+    fn gradient_step_params_should_be_differentiable() {
+        let device = Default::default();
+        let linear = linear(2.0);
+        let input = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0]], &device);
+
+        // The inner loss is the output, of gradient 1 with respect to the weight.
+        let grads = linear.forward(input).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let adapted = ModuleParams::from_gradient_step(&linear, &grads, 0.5);
+
+        let input = Tensor::<TestAutodiffBackend, 2>::from_floats([[3.0]], &device);
+        let output = linear.forward_with(&adapted, |linear| linear.forward(input));
+        output
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[4.5]]), 3);
+
+        // The outer gradient flows back to the weight of the module.
+        let grads = output.sum().backward();
+        linear
+            .weight
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0]]), 3);
+    }
+}
@@ -0,0 +1,462 @@
+use super::{Module, ModuleVisitor, ParamId};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Bool, Int, Tensor};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+/// The hierarchy of the submodules and the parameters of a module, to visualize its
+/// architecture, e.g. to verify a model imported from ONNX or PyTorch.
+///
+/// The graph can be exported to the [DOT](ModuleGraph::to_dot) format of Graphviz, or to an
+/// [ONNX](ModuleGraph::to_onnx) file that can be opened with [Netron](https://netron.app).
+///
+/// Only the fields of the modules are known, not how the forward pass connects them, so the graph
+/// shows which module contains which, not the flow of the tensors between them. Fields without
+/// parameters, e.g. activations or constants, aren't shown unless they are modules with fields.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleGraph {
+    /// The module the graph was created from.
+    pub root: ModuleNode,
+}
+
+/// A module of a [module graph](ModuleGraph).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleNode {
+    /// The name of the field of the module in its parent, empty for the root.
+    pub name: String,
+    /// The name of the type of the module, e.g. `Linear`, if it has fields.
+    pub module_type: Option<String>,
+    /// The parameters of the module, without the ones of its submodules.
+    pub params: Vec<ModuleParam>,
+    /// The submodules, in the order of the fields.
+    pub children: Vec<ModuleNode>,
+}
+
+/// A parameter of a [module node](ModuleNode).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleParam {
+    /// The name of the field of the parameter in its module.
+    pub name: String,
+    /// The id of the parameter.
+    pub id: ParamId,
+    /// The kind of the tensor of the parameter.
+    pub kind: ParamKind,
+    /// The shape of the tensor of the parameter.
+    pub shape: Vec<usize>,
+}
+
+/// The kind of the tensor of a [module parameter](ModuleParam).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParamKind {
+    /// Float tensor.
+    Float,
+    /// Int tensor.
+    Int,
+    /// Bool tensor.
+    Bool,
+}
+
+impl ModuleGraph {
+    /// Create the graph of the given module.
+    pub fn new<B: Backend, M: Module<B>>(module: &M) -> Self {
+        let mut builder = ModuleGraphBuilder {
+            stack: alloc::vec![ModuleNode::new(String::new())],
+        };
+        module.visit(&mut builder);
+
+        Self {
+            root: builder.stack.remove(0),
+        }
+    }
+
+    /// Export the graph in the DOT format of [Graphviz](https://graphviz.org), e.g. to render it
+    /// with `dot -Tsvg model.dot -o model.svg`.
+    ///
+    /// Each module with submodules is drawn as a cluster containing them, the other modules as
+    /// boxes listing the shapes of their parameters.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+
+        dot.push_str("digraph {\n");
+        dot.push_str("    node [shape=box, fontname=monospace];\n");
+        write_dot_node(&mut dot, &self.root, "", 1);
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// Export the graph as an ONNX model that can be opened with
+    /// [Netron](https://netron.app).
+    ///
+    /// Every module with parameters is exported as a node named by its path, e.g.
+    /// `encoder.layers.0.linear`, with the type of the module as its operator in the `burn`
+    /// domain. The parameters are the inputs of the graph, with their shapes but without their
+    /// values, so the file only describes the architecture and can't be executed.
+    pub fn to_onnx(&self) -> Vec<u8> {
+        let mut graph = Vec::new();
+        let mut inputs = Vec::new();
+        write_onnx_node(&mut graph, &mut inputs, &self.root, "");
+        protobuf::string(&mut graph, 2, "burn");
+        for input in inputs {
+            protobuf::bytes(&mut graph, 11, &input);
+        }
+
+        let mut opset = Vec::new();
+        protobuf::string(&mut opset, 1, "burn");
+        protobuf::varint(&mut opset, 2, 1);
+
+        let mut model = Vec::new();
+        protobuf::varint(&mut model, 1, ONNX_IR_VERSION);
+        protobuf::string(&mut model, 2, "burn");
+        protobuf::bytes(&mut model, 7, &graph);
+        protobuf::bytes(&mut model, 8, &opset);
+
+        model
+    }
+
+    /// The number of parameters of the module, i.e. the sum of the number of elements of their
+    /// tensors.
+    pub fn num_params(&self) -> usize {
+        self.root.num_params()
+    }
+}
+
+impl ModuleNode {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            module_type: None,
+            params: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// The number of parameters of the module and its submodules.
+    pub fn num_params(&self) -> usize {
+        let params = self
+            .params
+            .iter()
+            .map(|param| param.shape.iter().product::<usize>())
+            .sum::<usize>();
+        let children = self
+            .children
+            .iter()
+            .map(ModuleNode::num_params)
+            .sum::<usize>();
+
+        params + children
+    }
+
+    fn label(&self) -> String {
+        match (self.name.is_empty(), &self.module_type) {
+            (true, Some(module_type)) => module_type.clone(),
+            (true, None) => "Module".to_string(),
+            (false, Some(module_type)) => format!("{}: {}", self.name, module_type),
+            (false, None) => self.name.clone(),
+        }
+    }
+}
+
+impl ModuleParam {
+    fn label(&self) -> String {
+        match self.kind {
+            ParamKind::Float => format!("{}: {:?}", self.name, self.shape),
+            kind => format!("{}: {:?}{:?}", self.name, kind, self.shape),
+        }
+    }
+}
+
+struct ModuleGraphBuilder {
+    stack: Vec<ModuleNode>,
+}
+
+impl ModuleGraphBuilder {
+    fn param(&mut self, id: &ParamId, kind: ParamKind, shape: Vec<usize>) {
+        let node = self.stack.last_mut().expect("The root node should exist");
+
+        node.params.push(ModuleParam {
+            name: String::new(),
+            id: id.clone(),
+            kind,
+            shape,
+        });
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for ModuleGraphBuilder {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.param(id, ParamKind::Float, tensor.shape().dims.to_vec());
+    }
+
+    fn visit_int<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D, Int>) {
+        self.param(id, ParamKind::Int, tensor.shape().dims.to_vec());
+    }
+
+    fn visit_bool<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D, Bool>) {
+        self.param(id, ParamKind::Bool, tensor.shape().dims.to_vec());
+    }
+
+    fn enter_module(&mut self, name: &str, container_type: &str) {
+        let parent = self.stack.last_mut().expect("The root node should exist");
+        parent
+            .module_type
+            .get_or_insert_with(|| container_type.to_string());
+
+        self.stack.push(ModuleNode::new(name.to_string()));
+    }
+
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        let mut node = self
+            .stack
+            .pop()
+            .expect("The field should have been entered");
+        let parent = self.stack.last_mut().expect("The root node should exist");
+
+        if node.module_type.is_some() || !node.children.is_empty() {
+            parent.children.push(node);
+            return;
+        }
+
+        // A field without fields is either a parameter or a constant, which has no parameters.
+        let num_params = node.params.len();
+        for (i, mut param) in node.params.drain(..).enumerate() {
+            param.name = match num_params {
+                1 => node.name.clone(),
+                _ => format!("{}.{i}", node.name),
+            };
+            parent.params.push(param);
+        }
+    }
+}
+
+fn child_path(path: &str, name: &str) -> String {
+    match path.is_empty() {
+        true => name.to_string(),
+        false => format!("{path}.{name}"),
+    }
+}
+
+fn write_dot_node(dot: &mut String, node: &ModuleNode, path: &str, depth: usize) {
+    let indent = "    ".repeat(depth);
+    let id = match path.is_empty() {
+        true => "root",
+        false => path,
+    };
+    let params = node
+        .params
+        .iter()
+        .map(|param| dot_escape(&param.label()))
+        .collect::<Vec<_>>();
+
+    if node.children.is_empty() {
+        let mut label = dot_escape(&node.label());
+        for param in params {
+            label.push_str("\\n");
+            label.push_str(&param);
+        }
+        writeln!(dot, "{indent}\"{}\" [label=\"{label}\"];", dot_escape(id)).unwrap();
+        return;
+    }
+
+    let id = dot_escape(id);
+    writeln!(dot, "{indent}subgraph \"cluster_{id}\" {{").unwrap();
+    writeln!(dot, "{indent}    label=\"{}\";", dot_escape(&node.label())).unwrap();
+    if !params.is_empty() {
+        writeln!(
+            dot,
+            "{indent}    \"{id}\" [label=\"{}\", shape=note];",
+            params.join("\\n")
+        )
+        .unwrap();
+    }
+    for child in node.children.iter() {
+        write_dot_node(dot, child, &child_path(path, &child.name), depth + 1);
+    }
+    writeln!(dot, "{indent}}}").unwrap();
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+const ONNX_IR_VERSION: u64 = 8;
+
+fn write_onnx_node(graph: &mut Vec<u8>, inputs: &mut Vec<Vec<u8>>, node: &ModuleNode, path: &str) {
+    if !node.params.is_empty() {
+        let name = match path.is_empty() {
+            true => "root",
+            false => path,
+        };
+        let mut proto = Vec::new();
+
+        for param in node.params.iter() {
+            let input = child_path(path, &param.name);
+            protobuf::string(&mut proto, 1, &input);
+            inputs.push(onnx_value_info(&input, param));
+        }
+        protobuf::string(&mut proto, 2, &format!("{name}.output"));
+        protobuf::string(&mut proto, 3, name);
+        protobuf::string(
+            &mut proto,
+            4,
+            node.module_type.as_deref().unwrap_or("Module"),
+        );
+        protobuf::string(&mut proto, 7, "burn");
+        protobuf::bytes(graph, 1, &proto);
+    }
+
+    for child in node.children.iter() {
+        write_onnx_node(graph, inputs, child, &child_path(path, &child.name));
+    }
+}
+
+/// The `ValueInfoProto` of a parameter, a tensor of its element type and shape.
+fn onnx_value_info(name: &str, param: &ModuleParam) -> Vec<u8> {
+    // The `TensorProto.DataType` of the elements of the tensor.
+    let elem_type = match param.kind {
+        ParamKind::Float => 1,
+        ParamKind::Int => 7,
+        ParamKind::Bool => 9,
+    };
+
+    let mut shape = Vec::new();
+    for dim in param.shape.iter() {
+        let mut dimension = Vec::new();
+        protobuf::varint(&mut dimension, 1, *dim as u64);
+        protobuf::bytes(&mut shape, 1, &dimension);
+    }
+
+    let mut tensor = Vec::new();
+    protobuf::varint(&mut tensor, 1, elem_type);
+    protobuf::bytes(&mut tensor, 2, &shape);
+
+    let mut ty = Vec::new();
+    protobuf::bytes(&mut ty, 1, &tensor);
+
+    let mut value_info = Vec::new();
+    protobuf::string(&mut value_info, 1, name);
+    protobuf::bytes(&mut value_info, 2, &ty);
+
+    value_info
+}
+
+/// The few parts of the protobuf wire format needed to write an ONNX graph.
+mod protobuf {
+    use alloc::vec::Vec;
+
+    const WIRE_VARINT: u64 = 0;
+    const WIRE_LEN: u64 = 2;
+
+    fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        buf.push(value as u8);
+    }
+
+    pub(super) fn varint(buf: &mut Vec<u8>, field: u64, value: u64) {
+        write_varint(buf, (field << 3) | WIRE_VARINT);
+        write_varint(buf, value);
+    }
+
+    pub(super) fn bytes(buf: &mut Vec<u8>, field: u64, value: &[u8]) {
+        write_varint(buf, (field << 3) | WIRE_LEN);
+        write_varint(buf, value.len() as u64);
+        buf.extend_from_slice(value);
+    }
+
+    pub(super) fn string(buf: &mut Vec<u8>, field: u64, value: &str) {
+        bytes(buf, field, value.as_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::nn::{Dropout, DropoutConfig, Linear, LinearConfig};
+    use crate::TestBackend;
+
+    #[derive(Module, Debug)]
+    struct Block<B: Backend> {
+        linear: Linear<B>,
+        dropout: Dropout,
+    }
+
+    #[derive(Module, Debug)]
+    struct Net<B: Backend> {
+        blocks: Vec<Block<B>>,
+        output: Linear<B>,
+    }
+
+    fn net() -> Net<TestBackend> {
+        let device = Default::default();
+        let block = || Block {
+            linear: LinearConfig::new(4, 4).init(&device),
+            dropout: DropoutConfig::new(0.1).init(),
+        };
+
+        Net {
+            blocks: alloc::vec![block(), block()],
+            output: LinearConfig::new(4, 2).with_bias(false).init(&device),
+        }
+    }
+
+    #[test]
+    fn graph_should_follow_the_module_hierarchy() {
+        let net = net();
+
+        let graph = ModuleGraph::new(&net);
+
+        let root = &graph.root;
+        assert_eq!(root.module_type.as_deref(), Some("Net"));
+        assert_eq!(root.children.len(), 2);
+        let blocks = &root.children[0];
+        assert_eq!(blocks.name, "blocks");
+        assert_eq!(blocks.module_type.as_deref(), Some("Vec"));
+        let block = &blocks.children[1];
+        assert_eq!(block.name, "1");
+        assert_eq!(block.module_type.as_deref(), Some("Block"));
+        let linear = &block.children[0];
+        assert_eq!(linear.module_type.as_deref(), Some("Linear"));
+        assert_eq!(linear.params[0].name, "weight");
+        assert_eq!(linear.params[0].shape, alloc::vec![4, 4]);
+        assert_eq!(linear.params[1].name, "bias");
+        assert_eq!(block.children[1].module_type.as_deref(), Some("Dropout"));
+        assert_eq!(root.children[1].params.len(), 1);
+        assert_eq!(graph.num_params(), net.num_params());
+    }
+
+    #[test]
+    fn graph_should_be_exported_to_dot() {
+        let graph = ModuleGraph::new(&net());
+
+        let dot = graph.to_dot();
+
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.contains("subgraph \"cluster_blocks.0\" {"));
+        assert!(dot.contains(
+            "\"blocks.0.linear\" [label=\"linear: Linear\\nweight: [4, 4]\\nbias: [4]\"];"
+        ));
+        assert!(dot.contains("\"output\" [label=\"output: Linear\\nweight: [4, 2]\"];"));
+    }
+
+    #[test]
+    fn graph_should_be_exported_to_onnx() {
+        let graph = ModuleGraph::new(&net());
+
+        let onnx = graph.to_onnx();
+
+        // The model starts with the IR version, then the name of the producer.
+        assert_eq!(&onnx[..2], &[0x08, ONNX_IR_VERSION as u8]);
+        assert_eq!(&onnx[2..8], b"\x12\x04burn");
+        let contains = |value: &[u8]| onnx.windows(value.len()).any(|window| window == value);
+        assert!(contains(b"blocks.1.linear.weight"));
+        assert!(contains(b"output.output"));
+    }
+}
@@ -1,5 +1,17 @@
 mod base;
+#[cfg(feature = "std")]
+mod feature;
+mod functional;
+mod graph;
 mod param;
+mod surgery;
+mod warmup;
 
 pub use base::*;
+#[cfg(feature = "std")]
+pub use feature::*;
+pub use functional::*;
+pub use graph::*;
 pub use param::*;
+pub use surgery::*;
+pub use warmup::*;
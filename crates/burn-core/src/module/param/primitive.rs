@@ -1,5 +1,5 @@
-use crate::module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor};
-use alloc::vec::Vec;
+use crate::module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor, SubmoduleMapper};
+use alloc::{string::ToString, vec::Vec};
 use burn_tensor::backend::{AutodiffBackend, Backend};
 use core::fmt::Debug;
 
@@ -20,6 +20,13 @@ where
         self.map(|module| module.map(mapper))
     }
 
+    fn map_submodules<M: SubmoduleMapper<B>>(self, mapper: &mut M) -> Self
+    where
+        Self: 'static,
+    {
+        self.map(|module| module.map_submodules(mapper))
+    }
+
     fn load_record(self, record: Self::Record) -> Self {
         self.zip(record)
             .map(|(module, record)| module.load_record(record))
@@ -75,8 +82,12 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(i, module)| {
+            let name = i.to_string();
+
+            visitor.enter_module(&name, "Vec");
             module.visit(visitor);
+            visitor.exit_module(&name, "Vec");
         });
     }
 
@@ -84,6 +95,16 @@ where
         self.into_iter().map(|module| module.map(mapper)).collect()
     }
 
+    fn map_submodules<M: SubmoduleMapper<B>>(self, mapper: &mut M) -> Self
+    where
+        Self: 'static,
+    {
+        self.into_iter()
+            .enumerate()
+            .map(|(i, module)| mapper.map_submodule(&i.to_string(), module))
+            .collect()
+    }
+
     fn into_record(self) -> Self::Record {
         self.into_iter().map(Module::into_record).collect()
     }
@@ -152,8 +173,12 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(i, module)| {
+            let name = i.to_string();
+
+            visitor.enter_module(&name, "Array");
             module.visit(visitor);
+            visitor.exit_module(&name, "Array");
         });
     }
 
@@ -161,6 +186,19 @@ where
         self.map(|module| module.map(mapper))
     }
 
+    fn map_submodules<M: SubmoduleMapper<B>>(self, mapper: &mut M) -> Self
+    where
+        Self: 'static,
+    {
+        let mut i = 0;
+
+        self.map(|module| {
+            let module = mapper.map_submodule(&i.to_string(), module);
+            i += 1;
+            module
+        })
+    }
+
     fn load_record(self, record: Self::Record) -> Self {
         self.into_iter()
             .zip(record)
@@ -228,13 +266,24 @@ macro_rules! impl_module_tuple {
             }
 
             fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-                $(self.$i.visit(visitor);)*
+                $(
+                    visitor.enter_module(stringify!($i), "Tuple");
+                    self.$i.visit(visitor);
+                    visitor.exit_module(stringify!($i), "Tuple");
+                )*
             }
 
             fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
                 ($(self.$i.map(mapper),)*)
             }
 
+            fn map_submodules<M: SubmoduleMapper<B>>(self, mapper: &mut M) -> Self
+            where
+                Self: 'static,
+            {
+                ($(mapper.map_submodule(stringify!($i), self.$i),)*)
+            }
+
             fn load_record(self, record: Self::Record) -> Self {
                 ($(self.$i.load_record(record.$i),)*)
             }
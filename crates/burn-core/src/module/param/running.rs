@@ -4,7 +4,8 @@ use alloc::sync::Arc;
 use alloc::vec::Vec;
 use burn_common::stub::Mutex;
 use burn_tensor::{
-    backend::{AutodiffBackend, Backend},
+    backend::{AutodiffBackend, Backend, BackendBridge},
+    ops::{Device, FullPrecisionBackend},
     Tensor,
 };
 
@@ -220,3 +221,175 @@ impl<const D: usize, B: AutodiffBackend> AutodiffModule<B> for RunningState<Tens
         RunningState::with_id(self.id.clone(), value.inner())
     }
 }
+
+/// A [running state](RunningState) kept in full precision, independently of the float element
+/// type of the backend.
+///
+/// This avoids drifting when small updates are accumulated over many steps on half precision
+/// backends (i.e. f16 or bf16), such as the running statistics of
+/// [batch normalization](crate::nn::BatchNorm). The state is also recorded in full precision.
+///
+/// # Notes
+///
+/// The state is visited and mapped in the float element type of the backend. The conversion is
+/// kept until the next update, and the mapped value is only as precise as that type.
+#[derive(Clone, Debug)]
+pub struct FullPrecisionRunningState<B: Backend, const D: usize> {
+    state: RunningState<Tensor<FullPrecisionBackend<B>, D>>,
+    converted: Arc<Mutex<Option<Tensor<B, D>>>>,
+}
+
+/// The record of a [full precision running state](FullPrecisionRunningState).
+#[derive(new, Clone, Debug)]
+pub struct FullPrecisionParam<B: Backend, const D: usize> {
+    /// The unique ID of the state.
+    pub id: ParamId,
+    /// The value of the state.
+    pub value: Tensor<FullPrecisionBackend<B>, D>,
+}
+
+impl<const D: usize, B: Backend> Module<B> for FullPrecisionRunningState<B, D> {
+    type Record = FullPrecisionParam<B, D>;
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        let tensor = self.value_converted();
+
+        visitor.visit_float(&self.state.id, &tensor)
+    }
+
+    fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
+        let tensor_out = mapper.map_float(&self.state.id, self.value_converted());
+
+        let mut value = self.state.value.lock().unwrap();
+        *value = tensor_out.clone().into_full_precision();
+        core::mem::drop(value);
+
+        let mut converted = self.converted.lock().unwrap();
+        *converted = Some(tensor_out);
+        core::mem::drop(converted);
+
+        self
+    }
+
+    fn into_record(self) -> Self::Record {
+        let (id, value) = self.state.into_record().consume();
+
+        FullPrecisionParam::new(id, value)
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        let state = self
+            .state
+            .load_record(Param::initialized(record.id, record.value));
+        let module = Self {
+            state,
+            converted: self.converted,
+        };
+        module.invalidate();
+
+        module
+    }
+
+    fn to_device(self, device: &<B as Backend>::Device) -> Self {
+        let state = self.state.to_device(&full_precision_device::<B>(device));
+        let module = Self {
+            state,
+            converted: self.converted,
+        };
+        module.invalidate();
+
+        module
+    }
+
+    fn fork(self, device: &<B as Backend>::Device) -> Self {
+        self.to_device(device) // Same thing here since no grad.
+    }
+
+    fn collect_devices(
+        &self,
+        mut devices: Vec<<B as Backend>::Device>,
+    ) -> Vec<<B as Backend>::Device> {
+        let device = self.state.value.lock().unwrap().device();
+        let device = <B::FullPrecisionBridge as BackendBridge<B>>::origin_device(&device);
+
+        if !devices.contains(&device) {
+            devices.push(device)
+        }
+
+        devices
+    }
+}
+
+impl<const D: usize, B: Backend> FullPrecisionRunningState<B, D> {
+    /// Create a new full precision running state.
+    pub fn new(value: Tensor<FullPrecisionBackend<B>, D>) -> Self {
+        Self::with_id(ParamId::new(), value)
+    }
+
+    /// Create a new full precision running state.
+    pub fn with_id(id: ParamId, value: Tensor<FullPrecisionBackend<B>, D>) -> Self {
+        Self {
+            state: RunningState::with_id(id, value),
+            converted: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Update the value on the current thread.
+    pub fn update(&self, value: Tensor<FullPrecisionBackend<B>, D>) {
+        self.state.update(value);
+        // Invalidated after the update, so that a conversion in between can't be kept.
+        self.invalidate();
+    }
+
+    /// Get the current value,
+    ///
+    /// # Note
+    ///
+    /// The current value might be outdated by one update.
+    pub fn value(&self) -> Tensor<FullPrecisionBackend<B>, D> {
+        self.state.value()
+    }
+
+    /// Get the current value and make sure it is sync.
+    ///
+    /// See [RunningState::value_sync].
+    pub fn value_sync(&self) -> Tensor<FullPrecisionBackend<B>, D> {
+        self.state.value_sync()
+    }
+
+    /// Get the current value converted to the float element type of the backend.
+    ///
+    /// The value is synchronized, and only converted once until the next update.
+    pub fn value_converted(&self) -> Tensor<B, D> {
+        self.state.sync();
+        let mut converted = self.converted.lock().unwrap();
+
+        converted
+            .get_or_insert_with(|| Tensor::from_full_precision(self.state.value()))
+            .clone()
+    }
+
+    fn invalidate(&self) {
+        let mut converted = self.converted.lock().unwrap();
+        *converted = None;
+    }
+}
+
+impl<const D: usize, B: AutodiffBackend> AutodiffModule<B> for FullPrecisionRunningState<B, D> {
+    type InnerModule = FullPrecisionRunningState<B::InnerBackend, D>;
+
+    fn valid(&self) -> Self::InnerModule {
+        // The value goes through the float element type of the backend, which is fine for
+        // inference since the statistics are converted to that type anyway.
+        let value = self.value_converted().inner().into_full_precision();
+
+        FullPrecisionRunningState::with_id(self.state.id.clone(), value)
+    }
+}
+
+/// The device of the full precision backend matching the given device.
+pub(crate) fn full_precision_device<B: Backend>(
+    device: &Device<B>,
+) -> Device<FullPrecisionBackend<B>> {
+    <B::FullPrecisionBridge as BackendBridge<B>>::target_device(device)
+}
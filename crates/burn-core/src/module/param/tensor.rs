@@ -79,6 +79,33 @@ impl<B: Backend, const D: usize> Param<Tensor<B, D>> {
         let value = Tensor::from_data(data, device);
         Param::initialized(ParamId::new(), value.require_grad())
     }
+
+    /// Move the value of the parameter to host memory, e.g. for a frozen layer that isn't used
+    /// for a while, releasing its device memory.
+    ///
+    /// The value is paged back to the same device on its next use, and stays there until the
+    /// parameter is offloaded again.
+    pub fn offload(self) -> Self {
+        #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+        todo!("Offloading parameters isn't yet supported on wasm.");
+
+        #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+        {
+            let device = self.lazy_device();
+            let is_require_grad = self.lazy_is_require_grad();
+            let (id, tensor) = self.consume();
+            let data = tensor.into_data();
+
+            Param::uninitialized(
+                id,
+                move |device, is_require_grad| {
+                    Tensor::from_data(data.clone(), device).set_require_grad(is_require_grad)
+                },
+                device,
+                is_require_grad,
+            )
+        }
+    }
 }
 
 impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
@@ -307,4 +334,33 @@ mod tests {
         assert!(!no_grad_is_require_grad);
         assert!(with_default_is_require_grad);
     }
+
+    #[test]
+    fn test_offload_keeps_id_value_and_require_grad() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let param = Param::from_tensor(tensor.clone());
+        let id = param.id.clone();
+
+        let offloaded = param.offload();
+
+        assert_eq!(offloaded.id, id);
+        assert_eq!(offloaded.lazy_device(), device);
+        offloaded
+            .val()
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+        assert!(offloaded.is_require_grad());
+    }
+
+    #[test]
+    fn test_offload_keeps_no_grad() {
+        let device = Default::default();
+        let tensor = Tensor::<TestAutodiffBackend, 2>::ones([2, 2], &device);
+
+        let offloaded = Param::from_tensor(tensor).no_grad().offload();
+
+        assert!(!offloaded.is_require_grad());
+    }
 }
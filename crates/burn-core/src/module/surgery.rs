@@ -0,0 +1,330 @@
+use super::Module;
+use crate::tensor::backend::Backend;
+use alloc::string::String;
+use core::any::{type_name, Any};
+
+/// Submodule mapper trait, to edit the structure of a module.
+///
+/// See [replace_submodule](Module::replace_submodule),
+/// [replace_submodules](Module::replace_submodules) and
+/// [insert_submodule](Module::insert_submodule).
+pub trait SubmoduleMapper<B: Backend> {
+    /// Map the submodule in the field of the given name of its parent module. The items of
+    /// vectors, arrays and tuples are named by their index.
+    ///
+    /// The mapper is responsible for mapping the submodules of the submodule, with
+    /// [map_submodules](Module::map_submodules).
+    fn map_submodule<M: Module<B> + 'static>(&mut self, name: &str, module: M) -> M;
+}
+
+/// Cast the value to the given type, returning it unchanged if it's of another type.
+fn cast<T: 'static, M: 'static>(value: M) -> Result<T, M> {
+    let mut value = Some(value);
+
+    match (&mut value as &mut dyn Any).downcast_mut::<Option<T>>() {
+        Some(cast) => Ok(cast.take().unwrap()),
+        None => Err(value.unwrap()),
+    }
+}
+
+/// The path of the submodule being mapped.
+#[derive(Default)]
+struct SubmodulePath {
+    path: String,
+}
+
+impl SubmodulePath {
+    /// Enter the field, returning the length of the path of its parent to [exit](Self::exit) it.
+    fn enter(&mut self, name: &str) -> usize {
+        let len = self.path.len();
+        if len > 0 {
+            self.path.push('.');
+        }
+        self.path.push_str(name);
+
+        len
+    }
+
+    fn exit(&mut self, len: usize) {
+        self.path.truncate(len);
+    }
+
+    /// If the given path is the one of a submodule of the current module.
+    fn contains(&self, path: &str) -> bool {
+        path.strip_prefix(self.path.as_str())
+            .is_some_and(|rest| rest.starts_with('.'))
+    }
+}
+
+/// Replace the submodule at the target path.
+struct SubmoduleReplacer<T> {
+    target: String,
+    path: SubmodulePath,
+    module: Option<T>,
+    found: Option<&'static str>,
+}
+
+impl<B: Backend, T: 'static> SubmoduleMapper<B> for SubmoduleReplacer<T> {
+    fn map_submodule<M: Module<B> + 'static>(&mut self, name: &str, module: M) -> M {
+        let len = self.path.enter(name);
+
+        let module = if self.path.path == self.target {
+            match cast::<T, M>(module) {
+                Ok(_) => cast(self.module.take().unwrap()).unwrap_or_else(|_| unreachable!()),
+                Err(module) => {
+                    self.found = Some(type_name::<M>());
+                    module
+                }
+            }
+        } else if self.path.contains(&self.target) {
+            module.map_submodules(self)
+        } else {
+            module
+        };
+
+        self.path.exit(len);
+        module
+    }
+}
+
+/// Replace the submodules of a type with the output of a function.
+struct SubmodulesReplacer<T, F> {
+    func: F,
+    path: SubmodulePath,
+    _module: core::marker::PhantomData<T>,
+}
+
+impl<B, T, F> SubmoduleMapper<B> for SubmodulesReplacer<T, F>
+where
+    B: Backend,
+    T: 'static,
+    F: FnMut(&str, T) -> T,
+{
+    fn map_submodule<M: Module<B> + 'static>(&mut self, name: &str, module: M) -> M {
+        let len = self.path.enter(name);
+
+        let module = match cast::<T, M>(module) {
+            Ok(module) => {
+                let module = (self.func)(&self.path.path, module);
+                cast(module).unwrap_or_else(|_| unreachable!())
+            }
+            Err(module) => module.map_submodules(self),
+        };
+
+        self.path.exit(len);
+        module
+    }
+}
+
+/// Insert a module in the vector of modules at the target path.
+struct SubmoduleInserter<T> {
+    target: String,
+    index: usize,
+    path: SubmodulePath,
+    module: Option<T>,
+    found: Option<&'static str>,
+}
+
+impl<B: Backend, T: 'static> SubmoduleMapper<B> for SubmoduleInserter<T> {
+    fn map_submodule<M: Module<B> + 'static>(&mut self, name: &str, module: M) -> M {
+        let len = self.path.enter(name);
+
+        let module = if self.path.path == self.target {
+            match cast::<alloc::vec::Vec<T>, M>(module) {
+                Ok(mut modules) => {
+                    assert!(
+                        self.index <= modules.len(),
+                        "The index {} is out of bounds of the {} modules of {}.",
+                        self.index,
+                        modules.len(),
+                        self.target
+                    );
+                    modules.insert(self.index, self.module.take().unwrap());
+                    cast(modules).unwrap_or_else(|_| unreachable!())
+                }
+                Err(module) => {
+                    self.found = Some(type_name::<M>());
+                    module
+                }
+            }
+        } else if self.path.contains(&self.target) {
+            module.map_submodules(self)
+        } else {
+            module
+        };
+
+        self.path.exit(len);
+        module
+    }
+}
+
+fn missing<T>(path: &str, found: Option<&'static str>) -> ! {
+    match found {
+        Some(found) => panic!(
+            "The submodule {path} is a {found}, not a {}.",
+            type_name::<T>()
+        ),
+        None => panic!("There is no submodule at the path {path}."),
+    }
+}
+
+pub(crate) fn replace_submodule<B, M, T>(module: M, path: &str, replacement: T) -> M
+where
+    B: Backend,
+    M: Module<B> + 'static,
+    T: Module<B> + 'static,
+{
+    let mut replacer = SubmoduleReplacer {
+        target: path.into(),
+        path: SubmodulePath::default(),
+        module: Some(replacement),
+        found: None,
+    };
+    let module = module.map_submodules(&mut replacer);
+
+    if replacer.module.is_some() {
+        missing::<T>(path, replacer.found);
+    }
+
+    module
+}
+
+pub(crate) fn replace_submodules<B, M, T, F>(module: M, func: F) -> M
+where
+    B: Backend,
+    M: Module<B> + 'static,
+    T: Module<B> + 'static,
+    F: FnMut(&str, T) -> T,
+{
+    let mut replacer = SubmodulesReplacer {
+        func,
+        path: SubmodulePath::default(),
+        _module: core::marker::PhantomData,
+    };
+
+    module.map_submodules(&mut replacer)
+}
+
+pub(crate) fn insert_submodule<B, M, T>(module: M, path: &str, inserted: T) -> M
+where
+    B: Backend,
+    M: Module<B> + 'static,
+    T: Module<B> + 'static,
+{
+    let (target, index) = path
+        .rsplit_once('.')
+        .and_then(|(target, index)| Some((target, index.parse::<usize>().ok()?)))
+        .unwrap_or_else(|| panic!("The path {path} should end with the index of the module."));
+
+    let mut inserter = SubmoduleInserter {
+        target: target.into(),
+        index,
+        path: SubmodulePath::default(),
+        module: Some(inserted),
+        found: None,
+    };
+    let module = module.map_submodules(&mut inserter);
+
+    if inserter.module.is_some() {
+        missing::<alloc::vec::Vec<T>>(target, inserter.found);
+    }
+
+    module
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::nn::{Dropout, DropoutConfig, Linear, LinearConfig};
+    use crate::TestBackend;
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Module, Debug)]
+    struct Block<B: Backend> {
+        linear: Linear<B>,
+        dropout: Dropout,
+    }
+
+    #[derive(Module, Debug)]
+    struct Net<B: Backend> {
+        blocks: Vec<Block<B>>,
+        head: Linear<B>,
+    }
+
+    fn block(device: &<TestBackend as Backend>::Device) -> Block<TestBackend> {
+        Block {
+            linear: LinearConfig::new(4, 4).init(device),
+            dropout: DropoutConfig::new(0.1).init(),
+        }
+    }
+
+    fn net() -> Net<TestBackend> {
+        let device = Default::default();
+
+        Net {
+            blocks: vec![block(&device), block(&device)],
+            head: LinearConfig::new(4, 10).init(&device),
+        }
+    }
+
+    #[test]
+    fn should_replace_the_submodule_at_the_path() {
+        let device = Default::default();
+        let head = LinearConfig::new(4, 2).init(&device);
+        let linear = LinearConfig::new(4, 4).init(&device);
+        let expected = linear.weight.val();
+
+        let net = net()
+            .replace_submodule("head", head)
+            .replace_submodule("blocks.1.linear", linear);
+
+        assert_eq!(net.head.weight.dims(), [4, 2]);
+        net.blocks[1]
+            .linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn should_replace_the_submodules_of_a_type() {
+        let mut paths = Vec::new();
+
+        let net = net().replace_submodules(|path: &str, _dropout: Dropout| {
+            paths.push(path.to_string());
+            DropoutConfig::new(0.5).init()
+        });
+
+        assert_eq!(paths, vec!["blocks.0.dropout", "blocks.1.dropout"]);
+        assert_eq!(net.blocks[0].dropout.prob, 0.5);
+        assert_eq!(net.blocks[1].dropout.prob, 0.5);
+    }
+
+    #[test]
+    fn should_insert_the_submodule_in_the_vector() {
+        let device = Default::default();
+        let mut inserted = block(&device);
+        inserted.dropout = DropoutConfig::new(0.2).init();
+
+        let net = net().insert_submodule("blocks.1", inserted);
+
+        assert_eq!(net.blocks.len(), 3);
+        assert_eq!(net.blocks[1].dropout.prob, 0.2);
+    }
+
+    #[test]
+    #[should_panic = "The submodule head is a"]
+    fn should_panic_when_the_submodule_is_of_another_type() {
+        net().replace_submodule("head", DropoutConfig::new(0.1).init());
+    }
+
+    #[test]
+    #[should_panic = "There is no submodule at the path blocks.2.linear."]
+    fn should_panic_when_there_is_no_submodule_at_the_path() {
+        let device = Default::default();
+
+        net().replace_submodule("blocks.2.linear", LinearConfig::new(4, 4).init(&device));
+    }
+}
@@ -0,0 +1,177 @@
+use crate as burn;
+
+use super::Module;
+use crate::config::Config;
+use crate::tensor::backend::{Backend, MemoryUsage, SyncType};
+use crate::tensor::{Element, Float, Numeric, Shape, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to [warm up](WarmupConfig::warmup) a module for inputs of a fixed shape.
+///
+/// Warming up only executes the forward pass ahead of time: the activations aren't allocated from
+/// a pre-planned arena, and the module isn't compiled into an executor without shape dispatch.
+/// Both would need the backends to expose their memory management and kernel launches, which
+/// they don't, so they are out of the scope of this API.
+#[derive(Config, Debug)]
+pub struct WarmupConfig {
+    /// The number of times the forward pass is executed while warming up. Default: 2
+    ///
+    /// The first run builds the kernels and autotunes them, the following ones let the memory
+    /// pools grow to the size of the activations.
+    #[config(default = 2)]
+    pub num_runs: usize,
+}
+
+/// A module warmed up for inputs of a fixed shape, created with [WarmupConfig::warmup].
+///
+/// On backends compiling their kernels just in time, every kernel of the forward pass is built and
+/// autotuned for the shape of the inputs while warming up, and the memory pools of the device
+/// already hold as much memory as the activations need.
+///
+/// The [forward pass](WarmedUpModule::forward) still executes the operations of the module one by
+/// one, each of them dispatching on the shapes of its inputs, and the pools allocate and reuse the
+/// memory of the activations as usual, so a backend with a different allocation pattern on each
+/// run may still grow them.
+pub struct WarmedUpModule<B: Backend, M, F, const D: usize, K = Float> {
+    module: M,
+    forward: F,
+    shape: Shape<D>,
+    memory_usage: Option<MemoryUsage>,
+    _input: PhantomData<fn() -> (B, K)>,
+}
+
+impl WarmupConfig {
+    /// Warm up the forward pass of the module for inputs of the given shape on the given device.
+    ///
+    /// The forward pass is executed with inputs filled with zeros, so it shouldn't depend on the
+    /// values of the inputs to select the kernels it executes.
+    pub fn warmup<B, M, F, O, const D: usize, K, S>(
+        &self,
+        module: M,
+        shape: S,
+        device: &B::Device,
+        forward: F,
+    ) -> WarmedUpModule<B, M, F, D, K>
+    where
+        B: Backend,
+        M: Module<B>,
+        F: Fn(&M, Tensor<B, D, K>) -> O,
+        K: Numeric<B>,
+        K::Elem: Element,
+        S: Into<Shape<D>>,
+    {
+        let module = module.to_device(device);
+        let shape = shape.into();
+
+        for _ in 0..self.num_runs {
+            let output = forward(&module, Tensor::zeros(shape.clone(), device));
+            core::mem::drop(output);
+            B::sync(device, SyncType::Wait);
+        }
+
+        WarmedUpModule {
+            module,
+            forward,
+            shape,
+            memory_usage: B::memory_usage(device),
+            _input: PhantomData,
+        }
+    }
+}
+
+impl<B, M, F, O, const D: usize, K> WarmedUpModule<B, M, F, D, K>
+where
+    B: Backend,
+    M: Module<B>,
+    F: Fn(&M, Tensor<B, D, K>) -> O,
+    K: Numeric<B>,
+{
+    /// Applies the forward pass on the input.
+    ///
+    /// # Panics
+    ///
+    /// If the shape of the input isn't the one the module was warmed up for.
+    pub fn forward(&self, input: Tensor<B, D, K>) -> O {
+        let shape = input.shape();
+        assert_eq!(
+            shape, self.shape,
+            "The module was warmed up for inputs of shape {:?}, got {:?}.",
+            self.shape.dims, shape.dims
+        );
+
+        (self.forward)(&self.module, input)
+    }
+
+    /// The shape of the inputs the module was warmed up for.
+    pub fn shape(&self) -> &Shape<D> {
+        &self.shape
+    }
+
+    /// The memory usage of the device once warmed up, which includes the memory pooled for the
+    /// activations, if the backend reports it.
+    pub fn memory_usage(&self) -> Option<&MemoryUsage> {
+        self.memory_usage.as_ref()
+    }
+
+    /// The warmed up module.
+    pub fn module(&self) -> &M {
+        &self.module
+    }
+
+    /// Consumes the warmed up module, returning the module to warm it up for other shapes.
+    pub fn into_module(self) -> M {
+        self.module
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Embedding, EmbeddingConfig, Linear, LinearConfig};
+    use crate::tensor::{Distribution, Int};
+    use crate::TestBackend;
+
+    #[test]
+    fn warmed_up_module_should_give_the_same_outputs() {
+        let device = Default::default();
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 3).init(&device);
+        let input = Tensor::random([2, 4], Distribution::Default, &device);
+
+        let expected = linear.forward(input.clone());
+        let warmed_up =
+            WarmupConfig::new().warmup(linear, Shape::new([2, 4]), &device, Linear::forward);
+
+        assert_eq!(warmed_up.shape(), &Shape::new([2, 4]));
+        warmed_up
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn should_warm_up_modules_with_int_inputs() {
+        let device = Default::default();
+        let embedding: Embedding<TestBackend> = EmbeddingConfig::new(8, 3).init(&device);
+
+        let warmed_up = WarmupConfig::new().warmup(
+            embedding,
+            Shape::new([2, 5]),
+            &device,
+            |embedding, input: Tensor<TestBackend, 2, Int>| embedding.forward(input),
+        );
+        let output = warmed_up.forward(Tensor::ones([2, 5], &device));
+
+        assert_eq!(output.shape(), Shape::new([2, 5, 3]));
+    }
+
+    #[test]
+    #[should_panic = "The module was warmed up for inputs of shape"]
+    fn should_panic_on_other_shapes() {
+        let device = Default::default();
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 3).init(&device);
+
+        let warmed_up =
+            WarmupConfig::new().warmup(linear, Shape::new([2, 4]), &device, Linear::forward);
+        warmed_up.forward(Tensor::zeros([3, 4], &device));
+    }
+}
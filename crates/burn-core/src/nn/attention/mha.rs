@@ -1,12 +1,13 @@
 use crate as burn;
 
+use super::{PagedKvCache, SequenceId};
 use crate::nn::cache::TensorCache;
 use crate::nn::Initializer;
 use crate::{
     config::Config,
     module::Module,
     nn,
-    tensor::{activation, backend::Backend, Bool, Tensor},
+    tensor::{activation, backend::Backend, Bool, NestedTensor, Tensor},
 };
 
 #[cfg(not(feature = "std"))]
@@ -70,13 +71,13 @@ pub struct MultiHeadAttention<B: Backend> {
 #[derive(Debug, Clone)]
 pub struct MhaInput<B: Backend> {
     /// Shape `[batch_size, seq_length_1, d_model]`
-    query: Tensor<B, 3>,
+    pub(super) query: Tensor<B, 3>,
     /// Shape `[batch_size, seq_length_2, d_model]`
-    key: Tensor<B, 3>,
+    pub(super) key: Tensor<B, 3>,
     /// Shape `[batch_size, seq_length_2, d_model]`
-    value: Tensor<B, 3>,
-    mask_pad: Option<Tensor<B, 2, Bool>>,
-    mask_attn: Option<Tensor<B, 3, Bool>>,
+    pub(super) value: Tensor<B, 3>,
+    pub(super) mask_pad: Option<Tensor<B, 2, Bool>>,
+    pub(super) mask_attn: Option<Tensor<B, 3, Bool>>,
 }
 
 impl MultiHeadAttentionConfig {
@@ -182,6 +183,25 @@ impl<B: Backend> MultiHeadAttention<B> {
         MhaOutput { weights, context }
     }
 
+    /// Applies self attention over a batch of sequences with different lengths.
+    ///
+    /// The sequences are padded and masked so that no position attends to the padding, and the
+    /// padding is removed from the output.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[seq_length, d_model]` for each sequence
+    /// - output: `[seq_length, d_model]` for each sequence
+    pub fn forward_nested(&self, input: NestedTensor<B, 2>) -> NestedTensor<B, 2> {
+        let lengths = input.lengths();
+        let mask_pad = input.padding_mask();
+        let padded = input.to_padded::<3, _>(0.0);
+
+        let output = self.forward(MhaInput::self_attn(padded).mask_pad(mask_pad));
+
+        NestedTensor::from_padded(output.context, &lengths)
+    }
+
     /// Applies the forward pass using a cache.
     ///
     /// # Shapes
@@ -216,6 +236,44 @@ impl<B: Backend> MultiHeadAttention<B> {
         MhaOutput { weights, context }
     }
 
+    /// Applies self attention to the new tokens of a batch of sequences, with the keys and values
+    /// of their previous tokens stored in a [paged KV cache](PagedKvCache).
+    ///
+    /// The new tokens should be [reserved](PagedKvCache::reserve) in the cache before the forward
+    /// pass of the first layer. Each sequence attends to its previous tokens and causally to the
+    /// new ones.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`, the new tokens of each sequence.
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward_paged(
+        &self,
+        input: Tensor<B, 3>,
+        cache: &mut PagedKvCache<B>,
+        layer: usize,
+        sequences: &[SequenceId],
+    ) -> Tensor<B, 3> {
+        let [batch_size, seq_length, d_model] = input.dims();
+
+        let query = self.attention_linear(input.clone(), &self.query);
+        let key = self.attention_linear(input.clone(), &self.key);
+        let value = self.attention_linear(input, &self.value);
+
+        cache.write(layer, sequences, key, value);
+        let (key, value, mask_attn) = cache.read(layer, sequences, seq_length);
+
+        let attn_scores = self.attn_scores(query, key);
+        let weights = self.attn_weights(attn_scores, None, Some(mask_attn));
+
+        let context = weights.matmul(value);
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length, d_model]);
+
+        self.output.forward(context)
+    }
+
     fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
         let attn_scores = query
             .matmul(key.transpose())
@@ -226,33 +284,17 @@ impl<B: Backend> MultiHeadAttention<B> {
 
     fn attn_weights(
         &self,
-        mut attn_scores: Tensor<B, 4>,
+        attn_scores: Tensor<B, 4>,
         mask_pad: Option<Tensor<B, 2, Bool>>,
         mask_attn: Option<Tensor<B, 3, Bool>>,
     ) -> Tensor<B, 4> {
-        if let Some(mask_pad) = mask_pad {
-            let [batch_size, seq_length] = mask_pad.dims();
-
-            attn_scores = attn_scores.mask_fill(
-                mask_pad.reshape([batch_size, 1, 1, seq_length]),
-                self.min_float,
-            );
-        }
-
-        if let Some(mask_attn) = mask_attn {
-            let [batch_size, seq_length_1, seq_length_2] = mask_attn.dims();
-
-            attn_scores = attn_scores.mask_fill(
-                mask_attn.reshape([batch_size, 1, seq_length_1, seq_length_2]),
-                self.min_float,
-            );
-        }
-
-        if self.quiet_softmax {
-            activation::quiet_softmax(attn_scores, 3)
-        } else {
-            activation::softmax(attn_scores, 3)
-        }
+        attn_weights(
+            attn_scores,
+            mask_pad,
+            mask_attn,
+            self.min_float,
+            self.quiet_softmax,
+        )
     }
 
     fn attention_linear(&self, x: Tensor<B, 3>, linear: &nn::Linear<B>) -> Tensor<B, 4> {
@@ -264,6 +306,37 @@ impl<B: Backend> MultiHeadAttention<B> {
     }
 }
 
+/// Mask the attention scores and normalize them into attention weights.
+pub(super) fn attn_weights<B: Backend>(
+    mut attn_scores: Tensor<B, 4>,
+    mask_pad: Option<Tensor<B, 2, Bool>>,
+    mask_attn: Option<Tensor<B, 3, Bool>>,
+    min_float: f64,
+    quiet_softmax: bool,
+) -> Tensor<B, 4> {
+    if let Some(mask_pad) = mask_pad {
+        let [batch_size, seq_length] = mask_pad.dims();
+
+        attn_scores =
+            attn_scores.mask_fill(mask_pad.reshape([batch_size, 1, 1, seq_length]), min_float);
+    }
+
+    if let Some(mask_attn) = mask_attn {
+        let [batch_size, seq_length_1, seq_length_2] = mask_attn.dims();
+
+        attn_scores = attn_scores.mask_fill(
+            mask_attn.reshape([batch_size, 1, seq_length_1, seq_length_2]),
+            min_float,
+        );
+    }
+
+    if quiet_softmax {
+        activation::quiet_softmax(attn_scores, 3)
+    } else {
+        activation::softmax(attn_scores, 3)
+    }
+}
+
 /// Cache for the [Multi Head Attention](MultiHeadAttention) layer.
 ///
 /// To be used during inference when decoding tokens.
@@ -442,6 +515,39 @@ mod tests {
             );
     }
 
+    #[test]
+    fn test_self_attention_nested_should_match_each_sequence() {
+        let [d_model, n_heads] = [16, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+        let sequences = [5, 2, 7]
+            .into_iter()
+            .map(|seq_length| {
+                Tensor::<TestBackend, 2>::random(
+                    [seq_length, d_model],
+                    Distribution::Default,
+                    &device,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let output = mha.forward_nested(NestedTensor::new(sequences.clone()));
+
+        assert_eq!(output.lengths(), vec![5, 2, 7]);
+
+        for (i, sequence) in sequences.into_iter().enumerate() {
+            let expected = mha
+                .forward(MhaInput::self_attn(sequence.unsqueeze()))
+                .context
+                .squeeze::<2>(0);
+
+            output
+                .get(i)
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 3);
+        }
+    }
+
     #[test]
     fn test_autoregressive_mask_should_have_same_output_as_autoregressive_decoding() {
         let [batch_size, seq_length, d_model, n_heads] = [3, 4, 12, 2];
@@ -1,5 +1,9 @@
 mod mask;
 mod mha;
+mod paged;
+mod parallel;
 
 pub use mask::*;
 pub use mha::*;
+pub use paged::*;
+pub use parallel::*;
@@ -0,0 +1,556 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::nn::{dequantize_columns, quantize_columns, WeightQuantization};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Bool, Data, ElementConversion, Int, Shape, Tensor};
+use alloc::vec::Vec;
+use core::ops::Range;
+use hashbrown::HashMap;
+
+const QUANTIZATION: WeightQuantization = WeightQuantization::Int8;
+
+/// Configuration to create a [paged KV cache](PagedKvCache) using the
+/// [init function](PagedKvCacheConfig::init).
+#[derive(Config, Debug)]
+pub struct PagedKvCacheConfig {
+    /// The number of attention layers sharing the cache.
+    pub num_layers: usize,
+    /// The number of heads of the attention layers.
+    pub n_heads: usize,
+    /// The size of each head, `d_model / n_heads`.
+    pub d_k: usize,
+    /// The number of blocks allocated on the device, shared by all the sequences.
+    pub num_blocks: usize,
+    /// The number of tokens stored in each block. Default: 16
+    #[config(default = 16)]
+    pub block_size: usize,
+    /// Store the keys and values in int8 with a scale for each head of each token, roughly
+    /// halving the memory of the cache compared to half precision. Default: false
+    #[config(default = false)]
+    pub quantize: bool,
+}
+
+/// The identifier of a sequence in a [paged KV cache](PagedKvCache).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SequenceId(u64);
+
+/// Error returned by a [paged KV cache](PagedKvCache).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PagedKvCacheError {
+    /// There are not enough free blocks for the new tokens.
+    OutOfBlocks {
+        /// The number of blocks required.
+        required: usize,
+        /// The number of free blocks.
+        available: usize,
+    },
+    /// The sequence isn't in the cache.
+    UnknownSequence(SequenceId),
+}
+
+impl core::fmt::Display for PagedKvCacheError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBlocks {
+                required,
+                available,
+            } => write!(
+                f,
+                "Paged KV cache error => {required} blocks required, {available} available"
+            ),
+            Self::UnknownSequence(id) => {
+                write!(f, "Paged KV cache error => unknown sequence {id:?}")
+            }
+        }
+    }
+}
+
+/// Allocate the fixed-size blocks of a [paged KV cache](PagedKvCache).
+#[derive(Debug, Clone)]
+pub struct BlockAllocator {
+    free: Vec<usize>,
+    num_blocks: usize,
+}
+
+impl BlockAllocator {
+    /// Create an allocator of `num_blocks` blocks, all free.
+    pub fn new(num_blocks: usize) -> Self {
+        Self {
+            free: (0..num_blocks).rev().collect(),
+            num_blocks,
+        }
+    }
+
+    /// Allocate a block, if any is free.
+    pub fn allocate(&mut self) -> Option<usize> {
+        self.free.pop()
+    }
+
+    /// Free the block, so it can be allocated again.
+    pub fn free(&mut self, block: usize) {
+        debug_assert!(block < self.num_blocks, "Block {block} out of range.");
+        debug_assert!(!self.free.contains(&block), "Block {block} freed twice.");
+
+        self.free.push(block);
+    }
+
+    /// The number of free blocks.
+    pub fn num_free(&self) -> usize {
+        self.free.len()
+    }
+
+    /// The total number of blocks.
+    pub fn num_blocks(&self) -> usize {
+        self.num_blocks
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct BlockTable {
+    blocks: Vec<usize>,
+    length: usize,
+}
+
+/// A KV cache for the [attention](super::MultiHeadAttention) of many concurrent sequences, stored
+/// in fixed-size blocks allocated as the sequences grow.
+///
+/// Each sequence has a table of the blocks holding its keys and values, so no memory is reserved
+/// for the maximum length of the sequences, and the blocks of finished sequences are reused by the
+/// new ones. The keys and values of each layer are stored in a single tensor of shape
+/// `[num_blocks, n_heads, block_size, d_k]`, which can be [quantized](PagedKvCacheConfig::quantize)
+/// to int8 and is then dequantized when the blocks are gathered for the attention.
+///
+/// The tokens are added with [reserve](PagedKvCache::reserve) before the forward pass, then each
+/// layer writes and reads its keys and values with
+/// [forward_paged](super::MultiHeadAttention::forward_paged).
+///
+/// Should be created with [PagedKvCacheConfig].
+pub struct PagedKvCache<B: Backend> {
+    keys: Vec<BlockStorage<B>>,
+    values: Vec<BlockStorage<B>>,
+    allocator: BlockAllocator,
+    tables: HashMap<SequenceId, BlockTable>,
+    block_size: usize,
+    next_id: u64,
+}
+
+impl PagedKvCacheConfig {
+    /// Initialize a new [paged KV cache](PagedKvCache), allocating all its blocks on the device.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> PagedKvCache<B> {
+        let shape = [self.num_blocks, self.n_heads, self.block_size, self.d_k];
+        let blocks = || {
+            (0..self.num_layers)
+                .map(|_| BlockStorage::new(shape, self.quantize, device))
+                .collect()
+        };
+
+        PagedKvCache {
+            keys: blocks(),
+            values: blocks(),
+            allocator: BlockAllocator::new(self.num_blocks),
+            tables: HashMap::new(),
+            block_size: self.block_size,
+            next_id: 0,
+        }
+    }
+}
+
+impl<B: Backend> PagedKvCache<B> {
+    /// Add an empty sequence to the cache.
+    pub fn add_sequence(&mut self) -> SequenceId {
+        let id = SequenceId(self.next_id);
+        self.next_id += 1;
+        self.tables.insert(id, BlockTable::default());
+
+        id
+    }
+
+    /// Remove the sequence from the cache, freeing its blocks.
+    pub fn remove_sequence(&mut self, id: SequenceId) -> Result<(), PagedKvCacheError> {
+        let table = self
+            .tables
+            .remove(&id)
+            .ok_or(PagedKvCacheError::UnknownSequence(id))?;
+
+        for block in table.blocks {
+            self.allocator.free(block);
+        }
+
+        Ok(())
+    }
+
+    /// Reserve the space of new tokens at the end of the sequence, allocating blocks if needed.
+    ///
+    /// Nothing is allocated if there aren't enough free blocks.
+    pub fn reserve(&mut self, id: SequenceId, num_tokens: usize) -> Result<(), PagedKvCacheError> {
+        let required = self.num_blocks_required(id, num_tokens)?;
+        if required > self.allocator.num_free() {
+            return Err(PagedKvCacheError::OutOfBlocks {
+                required,
+                available: self.allocator.num_free(),
+            });
+        }
+
+        let table = self.tables.get_mut(&id).unwrap();
+        for _ in 0..required {
+            table.blocks.push(self.allocator.allocate().unwrap());
+        }
+        table.length += num_tokens;
+
+        Ok(())
+    }
+
+    /// The number of new blocks required to add the tokens to the sequence.
+    pub fn num_blocks_required(
+        &self,
+        id: SequenceId,
+        num_tokens: usize,
+    ) -> Result<usize, PagedKvCacheError> {
+        let table = self.table(id)?;
+        let num_blocks = (table.length + num_tokens).div_ceil(self.block_size);
+
+        Ok(num_blocks.saturating_sub(table.blocks.len()))
+    }
+
+    /// The number of tokens of the sequence.
+    pub fn sequence_length(&self, id: SequenceId) -> Result<usize, PagedKvCacheError> {
+        Ok(self.table(id)?.length)
+    }
+
+    /// The number of free blocks.
+    pub fn num_free_blocks(&self) -> usize {
+        self.allocator.num_free()
+    }
+
+    /// The number of tokens stored in each block.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    fn table(&self, id: SequenceId) -> Result<&BlockTable, PagedKvCacheError> {
+        self.tables
+            .get(&id)
+            .ok_or(PagedKvCacheError::UnknownSequence(id))
+    }
+
+    /// Write the keys and values of the last tokens of the sequences in their blocks.
+    ///
+    /// # Shapes
+    ///
+    /// - key: `[batch_size, n_heads, seq_length, d_k]`
+    /// - value: `[batch_size, n_heads, seq_length, d_k]`
+    pub(crate) fn write(
+        &mut self,
+        layer: usize,
+        sequences: &[SequenceId],
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+    ) {
+        let [_, n_heads, seq_length, d_k] = key.dims();
+
+        for (index, id) in sequences.iter().enumerate() {
+            let table = self
+                .tables
+                .get(id)
+                .unwrap_or_else(|| panic!("Unknown sequence {id:?}"));
+            assert!(
+                table.length >= seq_length,
+                "The tokens should be reserved before the forward pass."
+            );
+            let start = table.length - seq_length;
+            let mut position = start;
+
+            // Copy the runs of tokens stored in the same block.
+            while position < table.length {
+                let block = table.blocks[position / self.block_size];
+                let offset = position % self.block_size;
+                let length = (self.block_size - offset).min(table.length - position);
+                let source = [
+                    index..index + 1,
+                    0..n_heads,
+                    position - start..position - start + length,
+                    0..d_k,
+                ];
+                let target = [
+                    block..block + 1,
+                    0..n_heads,
+                    offset..offset + length,
+                    0..d_k,
+                ];
+
+                self.keys[layer].assign(target.clone(), key.clone().slice(source.clone()));
+                self.values[layer].assign(target, value.clone().slice(source));
+
+                position += length;
+            }
+        }
+    }
+
+    /// Gather the keys and values of the sequences from their blocks, padded to the longest
+    /// sequence, with the attention mask of their last `seq_length` tokens.
+    ///
+    /// # Shapes
+    ///
+    /// - key: `[batch_size, n_heads, max_length, d_k]`
+    /// - value: `[batch_size, n_heads, max_length, d_k]`
+    /// - mask: `[batch_size, seq_length, max_length]`, where the padding and the future tokens
+    ///   are true.
+    pub(crate) fn read(
+        &self,
+        layer: usize,
+        sequences: &[SequenceId],
+        seq_length: usize,
+    ) -> (Tensor<B, 4>, Tensor<B, 4>, Tensor<B, 3, Bool>) {
+        let tables = sequences
+            .iter()
+            .map(|id| {
+                self.tables
+                    .get(id)
+                    .unwrap_or_else(|| panic!("Unknown sequence {id:?}"))
+            })
+            .collect::<Vec<_>>();
+        let batch_size = tables.len();
+        let max_blocks = tables
+            .iter()
+            .map(|table| table.blocks.len())
+            .max()
+            .unwrap_or(0);
+        let max_length = max_blocks * self.block_size;
+
+        // The missing blocks of the shorter sequences are padded with the first block, which is
+        // masked.
+        let indices = tables
+            .iter()
+            .flat_map(|table| {
+                (0..max_blocks).map(|index| (*table.blocks.get(index).unwrap_or(&0) as i64).elem())
+            })
+            .collect();
+        let device = self.keys[layer].device();
+        let indices = Tensor::<B, 1, Int>::from_data(
+            Data::new(indices, Shape::new([batch_size * max_blocks])),
+            &device,
+        );
+
+        let gather = |blocks: &BlockStorage<B>| {
+            let [_, n_heads, block_size, d_k] = blocks.dims();
+
+            blocks
+                .select(indices.clone())
+                .reshape([batch_size, max_blocks, n_heads, block_size, d_k])
+                .swap_dims(1, 2)
+                .reshape([batch_size, n_heads, max_length, d_k])
+        };
+
+        let mask = tables
+            .iter()
+            .flat_map(|table| {
+                let start = table.length - seq_length;
+
+                (0..seq_length).flat_map(move |query| {
+                    (0..max_length).map(move |position| position > start + query)
+                })
+            })
+            .collect();
+        let mask = Tensor::from_data(
+            Data::new(mask, Shape::new([batch_size, seq_length, max_length])),
+            &device,
+        );
+
+        (gather(&self.keys[layer]), gather(&self.values[layer]), mask)
+    }
+}
+
+/// The keys or the values of a layer for all the blocks.
+enum BlockStorage<B: Backend> {
+    Float(Tensor<B, 4>),
+    /// The int8 values packed along the last dimension, with a scale for each head of each token
+    /// of shape `[num_blocks, n_heads, block_size, 1]`.
+    Int8 {
+        packed: Tensor<B, 4, Int>,
+        scales: Tensor<B, 4>,
+        d_k: usize,
+    },
+}
+
+impl<B: Backend> BlockStorage<B> {
+    fn new(shape: [usize; 4], quantize: bool, device: &B::Device) -> Self {
+        if !quantize {
+            return Self::Float(Tensor::zeros(shape, device));
+        }
+
+        let [num_blocks, n_heads, block_size, d_k] = shape;
+        let num_packed = d_k.div_ceil(QUANTIZATION.num_packed());
+
+        Self::Int8 {
+            packed: Tensor::zeros([num_blocks, n_heads, block_size, num_packed], device),
+            scales: Tensor::ones([num_blocks, n_heads, block_size, 1], device),
+            d_k,
+        }
+    }
+
+    fn dims(&self) -> [usize; 4] {
+        match self {
+            Self::Float(tensor) => tensor.dims(),
+            Self::Int8 { scales, d_k, .. } => {
+                let [num_blocks, n_heads, block_size, _] = scales.dims();
+                [num_blocks, n_heads, block_size, *d_k]
+            }
+        }
+    }
+
+    fn device(&self) -> B::Device {
+        match self {
+            Self::Float(tensor) => tensor.device(),
+            Self::Int8 { scales, .. } => scales.device(),
+        }
+    }
+
+    /// Write the tensor of shape `[1, n_heads, length, d_k]` in the slots of a block.
+    fn assign(&mut self, ranges: [Range<usize>; 4], tensor: Tensor<B, 4>) {
+        match self {
+            Self::Float(blocks) => {
+                *blocks = blocks.clone().slice_assign(ranges, tensor);
+            }
+            Self::Int8 { packed, scales, .. } => {
+                let [_, n_heads, length, d_k] = tensor.dims();
+                let num_rows = n_heads * length;
+
+                // Each row of the tensor is quantized with its own scale.
+                let (values, scale) =
+                    quantize_columns(tensor.reshape([num_rows, d_k]).transpose(), &QUANTIZATION);
+                let [num_packed, _] = values.dims();
+                let [blocks, heads, slots, _] = ranges;
+
+                *packed = packed.clone().slice_assign(
+                    [blocks.clone(), heads.clone(), slots.clone(), 0..num_packed],
+                    values.transpose().reshape([1, n_heads, length, num_packed]),
+                );
+                *scales = scales.clone().slice_assign(
+                    [blocks, heads, slots, 0..1],
+                    scale.reshape([1, n_heads, length, 1]),
+                );
+            }
+        }
+    }
+
+    /// Select the blocks, dequantizing them if needed.
+    ///
+    /// # Shapes
+    ///
+    /// - indices: `[num_selected]`
+    /// - output: `[num_selected, n_heads, block_size, d_k]`
+    fn select(&self, indices: Tensor<B, 1, Int>) -> Tensor<B, 4> {
+        match self {
+            Self::Float(blocks) => blocks.clone().select(0, indices),
+            Self::Int8 {
+                packed,
+                scales,
+                d_k,
+            } => {
+                let packed = packed.clone().select(0, indices.clone());
+                let scales = scales.clone().select(0, indices);
+                let [num_selected, n_heads, block_size, num_packed] = packed.dims();
+                let num_rows = num_selected * n_heads * block_size;
+
+                dequantize_columns(
+                    packed.reshape([num_rows, num_packed]).transpose(),
+                    scales.reshape([1, num_rows]),
+                    &QUANTIZATION,
+                    *d_k,
+                )
+                .transpose()
+                .reshape([num_selected, n_heads, block_size, *d_k])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::attention::{generate_autoregressive_mask, MhaInput, MultiHeadAttentionConfig};
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    fn cache(num_blocks: usize, quantize: bool) -> PagedKvCache<TestBackend> {
+        PagedKvCacheConfig::new(1, 2, 4, num_blocks)
+            .with_block_size(2)
+            .with_quantize(quantize)
+            .init(&Default::default())
+    }
+
+    #[test]
+    fn should_allocate_blocks_as_the_sequences_grow() {
+        let mut cache = cache(4, false);
+        let first = cache.add_sequence();
+        let second = cache.add_sequence();
+
+        cache.reserve(first, 3).unwrap();
+        cache.reserve(second, 1).unwrap();
+        assert_eq!(cache.num_free_blocks(), 1);
+
+        cache.reserve(second, 1).unwrap();
+        assert_eq!(cache.num_free_blocks(), 1);
+
+        assert_eq!(
+            cache.reserve(second, 3),
+            Err(PagedKvCacheError::OutOfBlocks {
+                required: 2,
+                available: 1
+            })
+        );
+        assert_eq!(cache.sequence_length(second), Ok(2));
+
+        cache.remove_sequence(first).unwrap();
+        assert_eq!(cache.num_free_blocks(), 3);
+        cache.reserve(second, 3).unwrap();
+        assert_eq!(cache.num_free_blocks(), 1);
+    }
+
+    #[test]
+    fn should_match_the_attention_without_cache() {
+        assert_matches_attention_without_cache(false, 3);
+    }
+
+    #[test]
+    fn should_approximate_the_attention_with_a_quantized_cache() {
+        assert_matches_attention_without_cache(true, 1);
+    }
+
+    fn assert_matches_attention_without_cache(quantize: bool, precision: usize) {
+        let device = Default::default();
+        let [batch_size, seq_length, d_model] = [2, 5, 8];
+        let mha = MultiHeadAttentionConfig::new(d_model, 2)
+            .with_dropout(0.0)
+            .init::<TestBackend>(&device);
+        let mut cache = cache(8, quantize);
+        let sequences = [cache.add_sequence(), cache.add_sequence()];
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &device);
+
+        let expected = mha
+            .forward(MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn))
+            .context;
+
+        // The prompt of three tokens, then one token at a time.
+        let mut outputs = Vec::new();
+        for range in [0..3, 3..4, 4..5] {
+            for id in sequences {
+                cache.reserve(id, range.len()).unwrap();
+            }
+            let input = tensor
+                .clone()
+                .slice([0..batch_size, range.clone(), 0..d_model]);
+            outputs.push(mha.forward_paged(input, &mut cache, 0, &sequences));
+        }
+
+        Tensor::cat(outputs, 1)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), precision);
+    }
+}
@@ -0,0 +1,190 @@
+use crate as burn;
+
+use super::mha::attn_weights;
+use super::{MhaInput, MhaOutput};
+use crate::nn::{self, Initializer};
+use crate::{
+    config::Config,
+    module::Module,
+    tensor::{backend::Backend, Tensor},
+};
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration to create a [parallel multi head attention](ParallelMultiHeadAttention) layer
+/// using the [init function](ParallelMultiHeadAttentionConfig::init).
+#[derive(Config)]
+pub struct ParallelMultiHeadAttentionConfig {
+    /// The size of each linear layer.
+    pub d_model: usize,
+    /// The number of heads, split evenly between the devices.
+    pub n_heads: usize,
+    /// The dropout rate. Default: 0.1
+    #[config(default = 0.1)]
+    pub dropout: f64,
+    /// The minimum value a float can take. Default: -1.0e4
+    /// This is used to mask attention scores before calculating attention weights.
+    /// A value too low might result in NaN.
+    #[config(default = -1.0e4)]
+    pub min_float: f64,
+    /// Use "quiet softmax" instead of regular softmax.
+    #[config(default = false)]
+    pub quiet_softmax: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// A [multihead attention](super::MultiHeadAttention) whose heads are split between devices.
+///
+/// The query, key and value projections are [column-parallel](nn::ColumnParallelLinear), so each
+/// device computes the attention of its heads, and the output projection is
+/// [row-parallel](nn::RowParallelLinear), summing the contributions of the heads on the device
+/// of the first shard. Only the input and the output are exchanged between the devices.
+///
+/// Should be created with [ParallelMultiHeadAttentionConfig].
+#[derive(Module, Debug)]
+pub struct ParallelMultiHeadAttention<B: Backend> {
+    query: nn::ColumnParallelLinear<B>,
+    key: nn::ColumnParallelLinear<B>,
+    value: nn::ColumnParallelLinear<B>,
+    output: nn::RowParallelLinear<B>,
+    dropout: nn::Dropout,
+    n_heads_shard: usize,
+    d_k: usize,
+    min_float: f64,
+    quiet_softmax: bool,
+}
+
+impl ParallelMultiHeadAttentionConfig {
+    /// Initialize a new [parallel multihead attention](ParallelMultiHeadAttention) module, with a
+    /// shard of the heads on each device.
+    pub fn init<B: Backend>(&self, devices: &[B::Device]) -> ParallelMultiHeadAttention<B> {
+        let n_heads_shard = nn::shard_size(self.n_heads, devices.len());
+        let column = || {
+            nn::ColumnParallelLinearConfig::new(self.d_model, self.d_model)
+                .with_initializer(self.initializer.clone())
+                .init(devices)
+        };
+
+        ParallelMultiHeadAttention {
+            query: column(),
+            key: column(),
+            value: column(),
+            output: nn::RowParallelLinearConfig::new(self.d_model, self.d_model)
+                .with_initializer(self.initializer.clone())
+                .init(devices),
+            dropout: nn::DropoutConfig::new(self.dropout).init(),
+            n_heads_shard,
+            d_k: self.d_model / self.n_heads,
+            min_float: self.min_float,
+            quiet_softmax: self.quiet_softmax,
+        }
+    }
+}
+
+impl<B: Backend> ParallelMultiHeadAttention<B> {
+    /// Applies the forward pass on the input tensors. The outputs are on the device of the first
+    /// shard.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
+        let [batch_size, seq_length_1, _d_model] = input.query.dims();
+
+        let queries = self.query.forward_sharded(input.query);
+        let keys = self.key.forward_sharded(input.key);
+        let values = self.value.forward_sharded(input.value);
+
+        let (contexts, weights): (Vec<_>, Vec<_>) = queries
+            .into_iter()
+            .zip(keys.into_iter().zip(values))
+            .map(|(query, (key, value))| {
+                let device = query.device();
+                let query = self.split_heads(query);
+                let key = self.split_heads(key);
+                let value = self.split_heads(value);
+
+                let attn_scores = query
+                    .matmul(key.transpose())
+                    .div_scalar((self.d_k as f32).sqrt());
+                let attn_scores = self.dropout.forward(attn_scores);
+                let weights = attn_weights(
+                    attn_scores,
+                    input.mask_pad.clone().map(|mask| mask.to_device(&device)),
+                    input.mask_attn.clone().map(|mask| mask.to_device(&device)),
+                    self.min_float,
+                    self.quiet_softmax,
+                );
+
+                let context = weights.clone().matmul(value).swap_dims(1, 2).reshape([
+                    batch_size,
+                    seq_length_1,
+                    self.n_heads_shard * self.d_k,
+                ]);
+
+                (context, weights)
+            })
+            .unzip();
+
+        let context = self.output.forward_sharded(contexts);
+        let device = context.device();
+        let weights = weights
+            .into_iter()
+            .map(|weights| weights.to_device(&device))
+            .collect();
+
+        MhaOutput {
+            weights: Tensor::cat(weights, 1),
+            context,
+        }
+    }
+
+    fn split_heads(&self, x: Tensor<B, 3>) -> Tensor<B, 4> {
+        let [batch_size, seq_length, _d_shard] = x.dims();
+
+        x.reshape([batch_size, seq_length, self.n_heads_shard, self.d_k])
+            .swap_dims(1, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::{Distribution, Shape};
+    use crate::TestBackend;
+
+    #[test]
+    fn test_parallel_self_attention_shapes() {
+        let [batch_size, seq_length, d_model, n_heads] = [7, 13, 32, 4];
+        let devices = vec![<TestBackend as Backend>::Device::default(); 2];
+        let mha =
+            ParallelMultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&devices);
+        let input = MhaInput::self_attn(Tensor::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &devices[0],
+        ));
+
+        let output = mha.forward(input);
+
+        assert_eq!(
+            output.context.shape(),
+            Shape::new([batch_size, seq_length, d_model]),
+            "Context should have the correct shape",
+        );
+        assert_eq!(
+            output.weights.shape(),
+            Shape::new([batch_size, n_heads, seq_length, seq_length]),
+            "Weights should have the correct shape",
+        );
+    }
+}
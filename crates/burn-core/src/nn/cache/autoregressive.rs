@@ -17,14 +17,20 @@ impl<B: Backend, const D: usize> TensorCache<B, D> {
         let mut tensor_old = CacheState::Empty;
         core::mem::swap(&mut self.state, &mut tensor_old);
 
-        let tensor_new = match tensor_old {
-            CacheState::Value(tensor_old) => {
-                let [batch_size, seq_length, d_model] = tensor.dims();
-                let next_seq_token =
-                    tensor.slice([0..batch_size, (seq_length - 1)..seq_length, 0..d_model]);
-                let next_seq_token = func(next_seq_token);
+        let [batch_size, seq_length, d_model] = tensor.dims();
 
-                Tensor::cat(vec![tensor_old, next_seq_token], dim_cat)
+        let tensor_new = match tensor_old {
+            CacheState::Value(tensor_old) if seq_length > 1 => {
+                // Only the tokens after the cached ones are computed. When the sequence is
+                // shorter than the cache, e.g. after rejecting the tokens of a draft model, the
+                // cache is truncated and the last token computed again.
+                let num_cached = tensor_old.dims()[dim_cat].min(seq_length - 1);
+                let tensor_old = tensor_old.narrow(dim_cat, 0, num_cached);
+                let next_seq_tokens =
+                    tensor.slice([0..batch_size, num_cached..seq_length, 0..d_model]);
+                let next_seq_tokens = func(next_seq_tokens);
+
+                Tensor::cat(vec![tensor_old, next_seq_tokens], dim_cat)
             }
             _ => func(tensor),
         };
@@ -4,11 +4,14 @@ use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
 use crate::nn::conv::checks;
-use crate::nn::{Initializer, PaddingConfig1d};
+use crate::nn::{
+    stack_biases, stack_params, EnsembleForward, EnsembleMember, Initializer, PaddingConfig1d,
+};
 use crate::tensor::backend::Backend;
 use crate::tensor::module::conv1d;
 use crate::tensor::ops::ConvOptions;
 use crate::tensor::Tensor;
+use alloc::vec::Vec;
 
 /// Configuration to create a [1D convolution](Conv1d) layer using the [init function](Conv1dConfig::init).
 #[derive(Config, Debug)]
@@ -118,6 +121,102 @@ impl<B: Backend> Conv1d<B> {
     }
 }
 
+/// The parameters of the members of an [ensemble](crate::nn::Ensemble) of
+/// [1D convolutions](Conv1d), stacked along a leading dimension.
+#[derive(Module, Debug)]
+pub struct StackedConv1d<B: Backend> {
+    /// Tensor of shape `[num_members, channels_out, channels_in / groups, kernel_size]`
+    pub weight: Param<Tensor<B, 4>>,
+    /// Tensor of shape `[num_members, channels_out]`
+    pub bias: Option<Param<Tensor<B, 2>>>,
+    stride: usize,
+    kernel_size: usize,
+    dilation: usize,
+    groups: usize,
+    padding: PaddingConfig1d,
+}
+
+impl<B: Backend> EnsembleMember<B> for Conv1d<B> {
+    type Stacked = StackedConv1d<B>;
+
+    fn stack(members: Vec<Self>) -> StackedConv1d<B> {
+        let first = &members[0];
+        let (stride, kernel_size, dilation, groups, padding) = (
+            first.stride,
+            first.kernel_size,
+            first.dilation,
+            first.groups,
+            first.padding.clone(),
+        );
+        assert!(
+            members.iter().all(|member| member.stride == stride
+                && member.kernel_size == kernel_size
+                && member.dilation == dilation
+                && member.groups == groups
+                && member.padding == padding),
+            "The members of an ensemble should have the same configuration."
+        );
+
+        let (weights, biases) = members
+            .into_iter()
+            .map(|member| (member.weight, member.bias))
+            .unzip();
+
+        StackedConv1d {
+            weight: stack_params(weights),
+            bias: stack_biases(biases),
+            stride,
+            kernel_size,
+            dilation,
+            groups,
+            padding,
+        }
+    }
+}
+
+impl<B: Backend> EnsembleForward<B, 3, 4> for StackedConv1d<B> {
+    /// Applies the forward pass of every member on the same input.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, length_in]`
+    /// - output: `[num_members, batch_size, channels_out, length_out]`
+    fn forward_members(&self, input: Tensor<B, 3>) -> Tensor<B, 4> {
+        let [batch_size, _channels_in, length] = input.dims();
+        let [num_members, channels_out, channels_per_group, kernel_size] = self.weight.dims();
+        let padding = self
+            .padding
+            .calculate_padding_1d(length, self.kernel_size, self.stride);
+
+        // Each member is a group of the convolution, reading its own copy of the input channels.
+        let weight = self.weight.val().reshape([
+            num_members * channels_out,
+            channels_per_group,
+            kernel_size,
+        ]);
+        let bias = self
+            .bias
+            .as_ref()
+            .map(|bias| bias.val().reshape([num_members * channels_out]));
+        let output = conv1d(
+            input.repeat(1, num_members),
+            weight,
+            bias,
+            ConvOptions::new(
+                [self.stride],
+                [padding],
+                [self.dilation],
+                self.groups * num_members,
+            ),
+        );
+
+        let [_, _, length_out] = output.dims();
+        output
+            .reshape([batch_size, num_members, channels_out, length_out])
+            .swap_dims(0, 1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
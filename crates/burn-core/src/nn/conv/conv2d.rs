@@ -5,10 +5,12 @@ use crate::module::Module;
 use crate::module::Param;
 use crate::nn::Initializer;
 use crate::nn::PaddingConfig2d;
+use crate::nn::{stack_biases, stack_params, EnsembleForward, EnsembleMember};
 use crate::tensor::backend::Backend;
 use crate::tensor::module::conv2d;
 use crate::tensor::ops::ConvOptions;
 use crate::tensor::Tensor;
+use alloc::vec::Vec;
 
 use crate::nn::conv::checks;
 
@@ -122,6 +124,104 @@ impl<B: Backend> Conv2d<B> {
     }
 }
 
+/// The parameters of the members of an [ensemble](crate::nn::Ensemble) of
+/// [2D convolutions](Conv2d), stacked along a leading dimension.
+#[derive(Module, Debug)]
+pub struct StackedConv2d<B: Backend> {
+    /// Tensor of shape `[num_members, channels_out, channels_in / groups, kernel_size_1, kernel_size_2]`
+    pub weight: Param<Tensor<B, 5>>,
+    /// Tensor of shape `[num_members, channels_out]`
+    pub bias: Option<Param<Tensor<B, 2>>>,
+    stride: [usize; 2],
+    kernel_size: [usize; 2],
+    dilation: [usize; 2],
+    groups: usize,
+    padding: PaddingConfig2d,
+}
+
+impl<B: Backend> EnsembleMember<B> for Conv2d<B> {
+    type Stacked = StackedConv2d<B>;
+
+    fn stack(members: Vec<Self>) -> StackedConv2d<B> {
+        let first = &members[0];
+        let (stride, kernel_size, dilation, groups, padding) = (
+            first.stride,
+            first.kernel_size,
+            first.dilation,
+            first.groups,
+            first.padding.clone(),
+        );
+        assert!(
+            members.iter().all(|member| member.stride == stride
+                && member.kernel_size == kernel_size
+                && member.dilation == dilation
+                && member.groups == groups
+                && member.padding == padding),
+            "The members of an ensemble should have the same configuration."
+        );
+
+        let (weights, biases) = members
+            .into_iter()
+            .map(|member| (member.weight, member.bias))
+            .unzip();
+
+        StackedConv2d {
+            weight: stack_params(weights),
+            bias: stack_biases(biases),
+            stride,
+            kernel_size,
+            dilation,
+            groups,
+            padding,
+        }
+    }
+}
+
+impl<B: Backend> EnsembleForward<B, 4, 5> for StackedConv2d<B> {
+    /// Applies the forward pass of every member on the same input.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[num_members, batch_size, channels_out, height_out, width_out]`
+    fn forward_members(&self, input: Tensor<B, 4>) -> Tensor<B, 5> {
+        let [batch_size, _channels_in, height_in, width_in] = input.dims();
+        let [num_members, channels_out, channels_per_group, kernel_height, kernel_width] =
+            self.weight.dims();
+        let padding =
+            self.padding
+                .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
+
+        // Each member is a group of the convolution, reading its own copy of the input channels.
+        let weight = self.weight.val().reshape([
+            num_members * channels_out,
+            channels_per_group,
+            kernel_height,
+            kernel_width,
+        ]);
+        let bias = self
+            .bias
+            .as_ref()
+            .map(|bias| bias.val().reshape([num_members * channels_out]));
+        let output = conv2d(
+            input.repeat(1, num_members),
+            weight,
+            bias,
+            ConvOptions::new(
+                self.stride,
+                padding,
+                self.dilation,
+                self.groups * num_members,
+            ),
+        );
+
+        let [_, _, height_out, width_out] = output.dims();
+        output
+            .reshape([batch_size, num_members, channels_out, height_out, width_out])
+            .swap_dims(0, 1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,6 +2,9 @@ mod conv1d;
 mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod spectral;
+mod spectral_conv1d;
+mod spectral_conv2d;
 
 pub(crate) mod checks;
 
@@ -9,3 +12,5 @@ pub use conv1d::*;
 pub use conv2d::*;
 pub use conv_transpose1d::*;
 pub use conv_transpose2d::*;
+pub use spectral_conv1d::*;
+pub use spectral_conv2d::*;
@@ -0,0 +1,180 @@
+//! Truncated discrete Fourier transforms computed with matrix products, the complex tensors being
+//! pairs of real and imaginary parts.
+//!
+//! Only a few low frequencies are kept by the spectral convolutions, so the products with the
+//! Fourier basis of these frequencies are cheap and differentiable on any backend.
+
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, Shape, Tensor};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Computes the real discrete Fourier transform of the last dimension of a signal, truncated to
+/// its `modes` lowest frequencies.
+///
+/// # Shapes
+///
+/// - signal: `[..., length]`
+/// - output: `(real, imag)`, both of shape `[..., modes]`
+pub(crate) fn rfft<B: Backend, const D: usize>(
+    signal: Tensor<B, D>,
+    modes: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let length = signal.dims()[D - 1];
+    let frequencies: Vec<usize> = (0..modes).collect();
+    let (cos, sin) = fourier_basis::<B>(length, &frequencies, 1.0, &signal.device());
+
+    let real = signal.clone().matmul(cos.unsqueeze());
+    let imag = signal.matmul(sin.unsqueeze()).neg();
+
+    (real, imag)
+}
+
+/// Computes the inverse of the [real transform](rfft) of a signal of the given length, the
+/// frequencies that aren't given being zeros.
+///
+/// # Shapes
+///
+/// - real, imag: `[..., modes]`
+/// - output: `[..., length]`
+pub(crate) fn irfft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    length: usize,
+) -> Tensor<B, D> {
+    let modes = real.dims()[D - 1];
+    let frequencies: Vec<usize> = (0..modes).collect();
+    let (cos, sin) = fourier_basis::<B>(length, &frequencies, 1.0 / length as f64, &real.device());
+
+    // The frequencies other than zero and the Nyquist frequency also stand for their conjugates.
+    let counts: Vec<f32> = frequencies
+        .iter()
+        .map(
+            |frequency| match *frequency == 0 || frequency * 2 == length {
+                true => 1.0,
+                false => 2.0,
+            },
+        )
+        .collect();
+    let counts = Tensor::<B, 2>::from_data(
+        Data::new(counts, Shape::new([modes, 1])).convert(),
+        &real.device(),
+    );
+    let cos = cos.transpose().mul(counts.clone());
+    let sin = sin.transpose().mul(counts);
+
+    real.matmul(cos.unsqueeze())
+        .sub(imag.matmul(sin.unsqueeze()))
+}
+
+/// Computes the discrete Fourier transform of the last dimension of a complex signal at the given
+/// frequencies.
+///
+/// # Shapes
+///
+/// - real, imag: `[..., length]`
+/// - output: `(real, imag)`, both of shape `[..., frequencies.len()]`
+pub(crate) fn fft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    frequencies: &[usize],
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let length = real.dims()[D - 1];
+    let (cos, sin) = fourier_basis::<B>(length, frequencies, 1.0, &real.device());
+    let (cos, sin): (Tensor<B, D>, Tensor<B, D>) = (cos.unsqueeze(), sin.unsqueeze());
+
+    let output_real = real
+        .clone()
+        .matmul(cos.clone())
+        .add(imag.clone().matmul(sin.clone()));
+    let output_imag = imag.matmul(cos).sub(real.matmul(sin));
+
+    (output_real, output_imag)
+}
+
+/// Computes the inverse discrete Fourier transform of the last dimension of a complex signal of
+/// the given length, known at the given frequencies and zero at the others.
+///
+/// # Shapes
+///
+/// - real, imag: `[..., frequencies.len()]`
+/// - output: `(real, imag)`, both of shape `[..., length]`
+pub(crate) fn ifft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    frequencies: &[usize],
+    length: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (cos, sin) = fourier_basis::<B>(length, frequencies, 1.0 / length as f64, &real.device());
+    let (cos, sin): (Tensor<B, D>, Tensor<B, D>) =
+        (cos.transpose().unsqueeze(), sin.transpose().unsqueeze());
+
+    let output_real = real
+        .clone()
+        .matmul(cos.clone())
+        .sub(imag.clone().matmul(sin.clone()));
+    let output_imag = real.matmul(sin).add(imag.matmul(cos));
+
+    (output_real, output_imag)
+}
+
+/// Multiplies the complex coefficients of each mode with the complex weights of the mode, mixing
+/// the channels.
+///
+/// # Shapes
+///
+/// - real, imag: `[batch_size, channels_in, modes]`
+/// - weight_real, weight_imag: `[channels_in, channels_out, modes]`
+/// - output: `(real, imag)`, both of shape `[batch_size, channels_out, modes]`
+pub(crate) fn mix_modes<B: Backend>(
+    real: Tensor<B, 3>,
+    imag: Tensor<B, 3>,
+    weight_real: Tensor<B, 3>,
+    weight_imag: Tensor<B, 3>,
+) -> (Tensor<B, 3>, Tensor<B, 3>) {
+    // The modes are the batch dimension of the products.
+    let (real, imag) = (real.permute([2, 0, 1]), imag.permute([2, 0, 1]));
+    let (weight_real, weight_imag) = (
+        weight_real.permute([2, 0, 1]),
+        weight_imag.permute([2, 0, 1]),
+    );
+
+    let output_real = real
+        .clone()
+        .matmul(weight_real.clone())
+        .sub(imag.clone().matmul(weight_imag.clone()));
+    let output_imag = real.matmul(weight_imag).add(imag.matmul(weight_real));
+
+    (
+        output_real.permute([1, 2, 0]),
+        output_imag.permute([1, 2, 0]),
+    )
+}
+
+/// Computes the cosines and sines of the angles `2π k t / length` of the positions `t` and the
+/// frequencies `k`, times the scale, of shape `[length, frequencies.len()]`.
+fn fourier_basis<B: Backend>(
+    length: usize,
+    frequencies: &[usize],
+    scale: f64,
+    device: &B::Device,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let angles: Vec<f64> = (0..length)
+        .flat_map(|position| {
+            frequencies.iter().map(move |frequency| {
+                2.0 * PI * ((frequency * position) % length) as f64 / length as f64
+            })
+        })
+        .collect();
+    let shape = Shape::new([length, frequencies.len()]);
+    let cos = angles.iter().map(|angle| angle.cos() * scale).collect();
+    let sin = angles.iter().map(|angle| angle.sin() * scale).collect();
+
+    (
+        Tensor::from_data(Data::new(cos, shape.clone()).convert(), device),
+        Tensor::from_data(Data::new(sin, shape).convert(), device),
+    )
+}
@@ -0,0 +1,143 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::nn::conv::spectral;
+use crate::nn::Initializer;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [1D spectral convolution](SpectralConv1d) layer using the
+/// [init function](SpectralConv1dConfig::init).
+#[derive(Config, Debug)]
+pub struct SpectralConv1dConfig {
+    /// The number of input channels.
+    pub channels_in: usize,
+    /// The number of output channels.
+    pub channels_out: usize,
+    /// The number of lowest frequencies that are kept.
+    pub modes: usize,
+}
+
+/// Applies a 1D spectral convolution over input tensors, the layer of the
+/// [Fourier neural operators](https://arxiv.org/abs/2010.08895).
+///
+/// The input is transformed to the frequency domain, where the lowest frequencies are multiplied
+/// by learned complex weights mixing the channels, and transformed back to the spatial domain,
+/// the higher frequencies being zeros. Since the weights are in the frequency domain, the layer can
+/// be applied to inputs of any length with at least `2 * (modes - 1)` elements.
+///
+/// Should be created with [SpectralConv1dConfig].
+#[derive(Module, Debug)]
+pub struct SpectralConv1d<B: Backend> {
+    /// The real part of the weights, of shape `[channels_in, channels_out, modes]`.
+    pub weight_real: Param<Tensor<B, 3>>,
+    /// The imaginary part of the weights, of shape `[channels_in, channels_out, modes]`.
+    pub weight_imag: Param<Tensor<B, 3>>,
+    modes: usize,
+}
+
+impl SpectralConv1dConfig {
+    /// Initialize a new [spectral conv1d](SpectralConv1d) module.
+    ///
+    /// The weights are drawn uniformly between zero and `1 / (channels_in * channels_out)`, as in
+    /// the reference implementation.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> SpectralConv1d<B> {
+        assert!(
+            self.modes > 0,
+            "The number of modes should be greater than zero."
+        );
+
+        let shape = [self.channels_in, self.channels_out, self.modes];
+        let initializer = Initializer::Uniform {
+            min: 0.0,
+            max: 1.0 / (self.channels_in * self.channels_out) as f64,
+        };
+
+        SpectralConv1d {
+            weight_real: initializer.init(shape, device),
+            weight_imag: initializer.init(shape, device),
+            modes: self.modes,
+        }
+    }
+}
+
+impl<B: Backend> SpectralConv1d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, length]`
+    /// - output: `[batch_size, channels_out, length]`
+    ///
+    /// # Panics
+    ///
+    /// If the input has fewer frequencies than the modes, i.e. `modes > length / 2 + 1`.
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [_batch_size, _channels_in, length] = input.dims();
+        assert!(
+            self.modes <= length / 2 + 1,
+            "The input of length {length} has fewer frequencies than the {} modes.",
+            self.modes
+        );
+
+        let (real, imag) = spectral::rfft(input, self.modes);
+        let (real, imag) =
+            spectral::mix_modes(real, imag, self.weight_real.val(), self.weight_imag.val());
+
+        spectral::irfft(real, imag, length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+
+    #[test]
+    fn initializer_default() {
+        TestBackend::seed(0);
+
+        let config = SpectralConv1dConfig::new(2, 3, 4);
+        let conv = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(conv.weight_real.dims(), [2, 3, 4]);
+        conv.weight_imag
+            .to_data()
+            .assert_within_range(0.0..1.0 / 6.0);
+    }
+
+    #[test]
+    fn forward_should_keep_the_lowest_frequencies() {
+        let device = Default::default();
+        let mut conv = SpectralConv1dConfig::new(1, 1, 2).init::<TestBackend>(&device);
+        conv.weight_real = Param::from_data([[[1.0, 1.0]]], &device);
+        conv.weight_imag = Param::from_data([[[0.0, 0.0]]], &device);
+
+        // The constant and the first frequency are kept, the second frequency is removed.
+        let input = Tensor::<TestBackend, 3>::from_floats([[[3.0, 1.0, 1.0, -1.0]]], &device);
+        let output = conv.forward(input);
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[[2.0, 2.0, 0.0, 0.0]]]), 3);
+    }
+
+    #[test]
+    fn forward_should_shift_with_imaginary_weights() {
+        let device = Default::default();
+        let mut conv = SpectralConv1dConfig::new(1, 1, 2).init::<TestBackend>(&device);
+        conv.weight_real = Param::from_data([[[1.0, 0.0]]], &device);
+        conv.weight_imag = Param::from_data([[[0.0, 1.0]]], &device);
+
+        // Multiplying the first frequency by i shifts the cosine by a quarter of its period.
+        let input = Tensor::<TestBackend, 3>::from_floats([[[1.0, 0.0, -1.0, 0.0]]], &device);
+        let output = conv.forward(input);
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.0, -1.0, 0.0, 1.0]]]), 3);
+    }
+}
@@ -0,0 +1,184 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::nn::conv::spectral;
+use crate::nn::Initializer;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use alloc::vec::Vec;
+
+/// Configuration to create a [2D spectral convolution](SpectralConv2d) layer using the
+/// [init function](SpectralConv2dConfig::init).
+#[derive(Config, Debug)]
+pub struct SpectralConv2dConfig {
+    /// The number of channels.
+    pub channels: [usize; 2],
+    /// The number of lowest frequencies that are kept along the height and the width.
+    pub modes: [usize; 2],
+}
+
+/// Applies a 2D spectral convolution over input tensors, the layer of the
+/// [Fourier neural operators](https://arxiv.org/abs/2010.08895).
+///
+/// The input is transformed to the frequency domain, where the lowest frequencies are multiplied
+/// by learned complex weights mixing the channels, and transformed back to the spatial domain,
+/// the higher frequencies being zeros. Along the height, the `modes[0]` lowest positive and
+/// negative frequencies are kept, while only the `modes[1]` lowest frequencies of the width are
+/// kept since the input is real.
+///
+/// Should be created with [SpectralConv2dConfig].
+#[derive(Module, Debug)]
+pub struct SpectralConv2d<B: Backend> {
+    /// The real part of the weights, of shape `[channels_in, channels_out, 2 * modes_height,
+    /// modes_width]`, the positive frequencies of the height being before the negative ones.
+    pub weight_real: Param<Tensor<B, 4>>,
+    /// The imaginary part of the weights, of the same shape as the real part.
+    pub weight_imag: Param<Tensor<B, 4>>,
+    modes: [usize; 2],
+}
+
+impl SpectralConv2dConfig {
+    /// Initialize a new [spectral conv2d](SpectralConv2d) module.
+    ///
+    /// The weights are drawn uniformly between zero and `1 / (channels_in * channels_out)`, as in
+    /// the reference implementation.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> SpectralConv2d<B> {
+        let [channels_in, channels_out] = self.channels;
+        let [modes_height, modes_width] = self.modes;
+        assert!(
+            modes_height > 0 && modes_width > 0,
+            "The number of modes should be greater than zero."
+        );
+
+        let shape = [channels_in, channels_out, 2 * modes_height, modes_width];
+        let initializer = Initializer::Uniform {
+            min: 0.0,
+            max: 1.0 / (channels_in * channels_out) as f64,
+        };
+
+        SpectralConv2d {
+            weight_real: initializer.init(shape, device),
+            weight_imag: initializer.init(shape, device),
+            modes: self.modes,
+        }
+    }
+}
+
+impl<B: Backend> SpectralConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height, width]`
+    /// - output: `[batch_size, channels_out, height, width]`
+    ///
+    /// # Panics
+    ///
+    /// If the input has fewer frequencies than the modes, i.e. `2 * modes[0] > height` or
+    /// `modes[1] > width / 2 + 1`.
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [batch_size, channels_in, height, width] = input.dims();
+        let [_, channels_out, _, _] = self.weight_real.dims();
+        let [modes_height, modes_width] = self.modes;
+        assert!(
+            2 * modes_height <= height && modes_width <= width / 2 + 1,
+            "The input of size {:?} has fewer frequencies than the {:?} modes.",
+            [height, width],
+            self.modes
+        );
+
+        let frequencies: Vec<usize> = (0..modes_height)
+            .chain(height - modes_height..height)
+            .collect();
+        let num_modes = frequencies.len() * modes_width;
+
+        // The transform along the width, then along the height, to shape
+        // [batch_size, channels_in, modes_width, 2 * modes_height].
+        let (real, imag) = spectral::rfft(input, modes_width);
+        let (real, imag) = spectral::fft(real.swap_dims(2, 3), imag.swap_dims(2, 3), &frequencies);
+
+        let flatten = |tensor: Tensor<B, 4>, channels: usize| {
+            tensor.reshape([batch_size, channels, num_modes])
+        };
+        let flatten_weight = |weight: Tensor<B, 4>| {
+            weight
+                .swap_dims(2, 3)
+                .reshape([channels_in, channels_out, num_modes])
+        };
+        let (real, imag) = spectral::mix_modes(
+            flatten(real, channels_in),
+            flatten(imag, channels_in),
+            flatten_weight(self.weight_real.val()),
+            flatten_weight(self.weight_imag.val()),
+        );
+
+        let unflatten = |tensor: Tensor<B, 3>| {
+            tensor.reshape([batch_size, channels_out, modes_width, frequencies.len()])
+        };
+        let (real, imag) = spectral::ifft(unflatten(real), unflatten(imag), &frequencies, height);
+
+        spectral::irfft(real.swap_dims(2, 3), imag.swap_dims(2, 3), width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+
+    #[test]
+    fn initializer_default() {
+        TestBackend::seed(0);
+
+        let config = SpectralConv2dConfig::new([2, 3], [2, 3]);
+        let conv = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(conv.weight_real.dims(), [2, 3, 4, 3]);
+        conv.weight_imag
+            .to_data()
+            .assert_within_range(0.0..1.0 / 6.0);
+    }
+
+    #[test]
+    fn forward_should_keep_the_lowest_frequencies() {
+        let device = Default::default();
+        let mut conv = SpectralConv2dConfig::new([1, 1], [1, 2]).init::<TestBackend>(&device);
+        conv.weight_real = Param::from_data([[[[1.0, 1.0], [1.0, 1.0]]]], &device);
+        conv.weight_imag = Param::from_data([[[[0.0, 0.0], [0.0, 0.0]]]], &device);
+
+        // The frequencies of the 2 x 4 input are all kept, except the Nyquist frequency of the
+        // width.
+        let input = Tensor::<TestBackend, 4>::from_floats(
+            [[[[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]]],
+            &device,
+        );
+        let output = conv.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[[[1.5, 1.5, 3.5, 3.5], [5.5, 5.5, 7.5, 7.5]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn forward_should_mix_the_channels() {
+        let device = Default::default();
+        let mut conv = SpectralConv2dConfig::new([2, 1], [1, 1]).init::<TestBackend>(&device);
+        conv.weight_real = Param::from_data([[[[1.0], [0.0]]], [[[2.0], [0.0]]]], &device);
+        conv.weight_imag = Param::from_data([[[[0.0], [0.0]]], [[[0.0], [0.0]]]], &device);
+
+        // Only the mean of the channels is kept, weighted by the constant frequency.
+        let input = Tensor::<TestBackend, 4>::from_floats(
+            [[[[1.0, 3.0], [1.0, 3.0]], [[2.0, 2.0], [2.0, 2.0]]]],
+            &device,
+        );
+        let output = conv.forward(input);
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[6.0, 6.0], [6.0, 6.0]]]]), 3);
+    }
+}
@@ -0,0 +1,147 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+use alloc::vec::Vec;
+
+/// Configuration to create a [DropPath](DropPath) layer using the [init function](DropPathConfig::init).
+#[derive(Config, Debug)]
+pub struct DropPathConfig {
+    /// The probability of dropping the path of each sample during training.
+    pub prob: f64,
+}
+
+/// Drop the whole input of randomly chosen samples of the batch during training, also known as
+/// stochastic depth when applied to the branches of residual blocks, as described in the paper
+/// [Deep Networks with Stochastic Depth](https://arxiv.org/abs/1603.09382).
+///
+/// Unlike [dropout](crate::nn::Dropout), which zeroes individual elements, a single decision is
+/// taken for each sample along the first (batch) dimension. The kept samples are scaled during
+/// training to `1 / (1 - prob)`, so the layer is the identity during inference.
+///
+/// Should be created with [DropPathConfig].
+#[derive(Module, Clone, Debug)]
+pub struct DropPath {
+    prob: f64,
+}
+
+impl DropPathConfig {
+    /// Initialize a new [drop path](DropPath) module.
+    pub fn init(&self) -> DropPath {
+        assert!(
+            (0.0..1.0).contains(&self.prob),
+            "The drop path probability {} should be in [0, 1).",
+            self.prob
+        );
+
+        DropPath { prob: self.prob }
+    }
+
+    /// Initialize a [drop path](DropPath) module for each of the given number of residual blocks,
+    /// with a probability increasing linearly from zero for the first block to the configured one
+    /// for the last block, the usual stochastic depth schedule of vision transformers.
+    pub fn init_stochastic_depth(&self, num_blocks: usize) -> Vec<DropPath> {
+        (0..num_blocks)
+            .map(|block| {
+                let prob = match num_blocks {
+                    1 => self.prob,
+                    _ => self.prob * block as f64 / (num_blocks - 1) as f64,
+                };
+
+                DropPathConfig::new(prob).init()
+            })
+            .collect()
+    }
+}
+
+impl DropPath {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [DropPath](DropPath) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, ...]`
+    /// - output: `[batch_size, ...]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let prob_keep = 1.0 - self.prob;
+        let mut shape = [1; D];
+        shape[0] = input.dims()[0];
+        let mask = Tensor::random(shape, Distribution::Bernoulli(prob_keep), &input.device());
+
+        input * mask.div_scalar(prob_keep)
+    }
+
+    /// Applies the forward pass on the branch of a residual block, adding the result to the
+    /// residual, so that the whole block is skipped for the dropped samples.
+    ///
+    /// # Shapes
+    ///
+    /// - residual: `[batch_size, ...]`
+    /// - branch: `[batch_size, ...]`
+    /// - output: `[batch_size, ...]`
+    pub fn forward_residual<B: Backend, const D: usize>(
+        &self,
+        residual: Tensor<B, D>,
+        branch: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        residual + self.forward(branch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Shape;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_drop_whole_samples() {
+        let tensor =
+            Tensor::<TestAutodiffBackend, 3>::ones(Shape::new([100, 4, 8]), &Default::default());
+        let drop_path = DropPathConfig::new(0.5).init();
+
+        let output = drop_path.forward(tensor.clone());
+
+        // Every element of a sample is either dropped or scaled by 2.
+        let min = output.clone().min_dim(2).min_dim(1).into_data();
+        let max = output.max_dim(2).max_dim(1).into_data();
+        assert_eq!(min, max);
+        assert!(min.value.iter().all(|value| *value == 0.0 || *value == 2.0));
+        assert!(min.value.iter().any(|value| *value == 0.0));
+        assert!(min.value.iter().any(|value| *value == 2.0));
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<TestBackend, 3>::ones(Shape::new([100, 4, 8]), &Default::default());
+        let drop_path = DropPathConfig::new(0.5).init();
+
+        let output = drop_path.forward_residual(tensor.clone(), tensor.clone());
+
+        assert_eq!((tensor.clone() + tensor).to_data(), output.to_data());
+    }
+
+    #[test]
+    fn stochastic_depth_should_increase_the_prob_linearly() {
+        let blocks = DropPathConfig::new(0.3).init_stochastic_depth(4);
+
+        let probs: Vec<f64> = blocks.iter().map(|block| block.prob).collect();
+        assert_eq!(blocks.len(), 4);
+        for (prob, expected) in probs.iter().zip([0.0, 0.1, 0.2, 0.3]) {
+            assert!((prob - expected).abs() < 1e-9);
+        }
+    }
+}
@@ -3,7 +3,7 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Module;
 use crate::tensor::backend::Backend;
-use crate::tensor::{Distribution, Tensor};
+use crate::tensor::{Distribution, Generator, Tensor};
 
 /// Configuration to create a [Dropout](Dropout) layer using the [init function](DropoutConfig::init).
 #[derive(Config, Debug)]
@@ -52,6 +52,31 @@ impl Dropout {
 
         x * (1.0 / prob_keep)
     }
+
+    /// Applies the forward pass on the input tensor, sampling the dropped elements with the given
+    /// [generator](Generator) instead of the global random state of the backend.
+    ///
+    /// See [Dropout](Dropout) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward_with<B: Backend, const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        generator: &mut Generator,
+    ) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let prob_keep = 1.0 - self.prob;
+        let random = input.random_like_with(Distribution::Bernoulli(prob_keep), generator);
+        let x = input * random;
+
+        x * (1.0 / prob_keep)
+    }
 }
 
 #[cfg(test)]
@@ -77,6 +102,20 @@ mod tests {
         assert_ne!(tensor.to_data(), output.to_data());
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_generator_should_be_deterministic() {
+        let tensor =
+            Tensor::<TestAutodiffBackend, 2>::ones(Shape::new([100, 100]), &Default::default());
+        let dropout = DropoutConfig::new(0.5).init();
+
+        let output = dropout.forward_with(tensor.clone(), &mut Generator::new(42));
+        let expected = dropout.forward_with(tensor.clone(), &mut Generator::new(42));
+
+        assert_ne!(tensor.to_data(), output.to_data());
+        assert_eq!(expected.to_data(), output.to_data());
+    }
+
     #[test]
     fn without_ad_backend_should_not_change_input() {
         let tensor = Tensor::<TestBackend, 2>::ones(Shape::new([100, 100]), &Default::default());
@@ -0,0 +1,263 @@
+use crate as burn;
+
+use crate::module::{Module, Param};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// A [deep ensemble](https://arxiv.org/abs/1612.01474) of independently initialized modules of
+/// the same type, whose outputs are combined, e.g. to estimate the uncertainty of the predictions
+/// with their variance.
+///
+/// The parameters of the members are stacked once along a leading dimension of size
+/// `num_members`, in the [stacked module](EnsembleMember::Stacked) of their type, so all the
+/// members are evaluated with a single batched [forward pass](Ensemble::forward). The ensemble is
+/// a module, so the stacked parameters are trained, saved and loaded together.
+#[derive(Module, Debug)]
+pub struct Ensemble<B: Backend, M> {
+    /// The parameters of the members, stacked along a leading dimension.
+    pub members: M,
+    num_members: usize,
+    _backend: PhantomData<B>,
+}
+
+/// A module whose parameters can be stacked with the ones of the other members of an
+/// [ensemble](Ensemble).
+pub trait EnsembleMember<B: Backend>: Module<B> {
+    /// The module holding the parameters of all the members, stacked along a leading dimension.
+    type Stacked: Module<B>;
+
+    /// Stack the parameters of the members.
+    ///
+    /// # Panics
+    ///
+    /// If the members don't have the same configuration, or if only some of them have a bias.
+    fn stack(members: Vec<Self>) -> Self::Stacked;
+}
+
+/// The stacked parameters of the members of an [ensemble](Ensemble), evaluated on the same input
+/// with a single forward pass.
+pub trait EnsembleForward<B: Backend, const D: usize, const D2: usize>: Module<B> {
+    /// Evaluate all the members on the same input.
+    ///
+    /// # Shapes
+    ///
+    /// - output: the outputs of the members stacked along a leading dimension of size
+    ///   `num_members`.
+    fn forward_members(&self, input: Tensor<B, D>) -> Tensor<B, D2>;
+}
+
+impl<B: Backend, M: Module<B>> Ensemble<B, M> {
+    /// Create an ensemble of the given members, stacking their parameters.
+    ///
+    /// # Panics
+    ///
+    /// If there is no member, or if the members can't be [stacked](EnsembleMember::stack).
+    pub fn new<T: EnsembleMember<B, Stacked = M>>(members: Vec<T>) -> Self {
+        assert!(
+            !members.is_empty(),
+            "An ensemble should have at least one member."
+        );
+
+        Self {
+            num_members: members.len(),
+            members: T::stack(members),
+            _backend: PhantomData,
+        }
+    }
+
+    /// Create an ensemble of the given number of members, each initialized by the function from
+    /// its index, e.g. with the `init` function of its config so that the members have different
+    /// parameters.
+    pub fn init<T, F>(num_members: usize, init: F) -> Self
+    where
+        T: EnsembleMember<B, Stacked = M>,
+        F: FnMut(usize) -> T,
+    {
+        Self::new((0..num_members).map(init).collect())
+    }
+
+    /// The number of members of the ensemble.
+    pub fn num_members(&self) -> usize {
+        self.num_members
+    }
+
+    /// Evaluate all the members on the same input with a single batched forward pass.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[num_members, ...]`, the outputs of the members stacked along a leading
+    ///   dimension.
+    pub fn forward<const D: usize, const D2: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D2>
+    where
+        M: EnsembleForward<B, D, D2>,
+    {
+        self.members.forward_members(input)
+    }
+
+    /// Evaluate all the members on the same input, returning the mean and the variance of their
+    /// outputs, e.g. the prediction of the ensemble and its uncertainty.
+    pub fn forward_mean_var<const D: usize, const D2: usize>(
+        &self,
+        input: Tensor<B, D>,
+    ) -> (Tensor<B, D>, Tensor<B, D>)
+    where
+        M: EnsembleForward<B, D, D2>,
+    {
+        let outputs: Tensor<B, D2> = self.forward(input);
+        let mean = outputs.clone().mean_dim(0);
+        let var = outputs.sub(mean.clone()).powf_scalar(2.0).mean_dim(0);
+
+        (mean.squeeze(0), var.squeeze(0))
+    }
+}
+
+/// Stack the parameters of the members in a new parameter.
+pub(crate) fn stack_params<B: Backend, const D: usize, const D2: usize>(
+    params: Vec<Param<Tensor<B, D>>>,
+) -> Param<Tensor<B, D2>> {
+    let values = params.into_iter().map(|param| param.into_value()).collect();
+
+    Param::from_tensor(Tensor::stack(values, 0).detach())
+}
+
+/// Stack the biases of the members, which should either all or none have a bias.
+pub(crate) fn stack_biases<B: Backend>(
+    biases: Vec<Option<Param<Tensor<B, 1>>>>,
+) -> Option<Param<Tensor<B, 2>>> {
+    let num_members = biases.len();
+    let biases: Vec<_> = biases.into_iter().flatten().collect();
+
+    match biases.len() {
+        0 => None,
+        num_biases if num_biases == num_members => Some(stack_params(biases)),
+        _ => panic!("Either all the members of an ensemble or none of them should have a bias."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::conv::{Conv1dConfig, Conv2dConfig, StackedConv1d, StackedConv2d};
+    use crate::nn::{Linear, LinearConfig, PaddingConfig2d, StackedLinear};
+    use crate::tensor::{Data, Distribution};
+    use crate::TestBackend;
+
+    fn ensemble() -> Ensemble<TestBackend, StackedLinear<TestBackend>> {
+        let device = Default::default();
+
+        Ensemble::init(3, |index| {
+            let mut linear = LinearConfig::new(1, 1).with_bias(false).init(&device);
+            linear.weight = Param::from_data([[index as f32 + 1.0]], &device);
+            linear
+        })
+    }
+
+    #[test]
+    fn forward_should_stack_the_outputs_of_the_members() {
+        let ensemble = ensemble();
+        let input = Tensor::<TestBackend, 2>::from_floats([[2.0]], &Default::default());
+
+        let output: Tensor<TestBackend, 3> = ensemble.forward(input);
+
+        assert_eq!(ensemble.num_members(), 3);
+        assert_eq!(ensemble.num_params(), 3);
+        assert_eq!(ensemble.members.weight.dims(), [3, 1, 1]);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[2.0]], [[4.0]], [[6.0]]]), 3);
+    }
+
+    #[test]
+    fn forward_mean_var_should_combine_the_outputs() {
+        let ensemble = ensemble();
+        let input = Tensor::<TestBackend, 2>::from_floats([[2.0]], &Default::default());
+
+        let (mean, var) = ensemble.forward_mean_var::<2, 3>(input);
+
+        mean.into_data().assert_approx_eq(&Data::from([[4.0]]), 3);
+        var.into_data()
+            .assert_approx_eq(&Data::from([[8.0 / 3.0]]), 3);
+    }
+
+    #[test]
+    fn linear_forward_should_match_the_forward_pass_of_each_member() {
+        let device = Default::default();
+        let members: Vec<Linear<TestBackend>> = (0..2)
+            .map(|_| LinearConfig::new(3, 2).init(&device))
+            .collect();
+        let input = Tensor::<TestBackend, 3>::random([4, 5, 3], Distribution::Default, &device);
+        let expected: Tensor<TestBackend, 4> = Tensor::stack(
+            members
+                .iter()
+                .map(|member| member.forward(input.clone()))
+                .collect(),
+            0,
+        );
+
+        let output: Tensor<TestBackend, 4> = Ensemble::new(members).forward(input);
+
+        assert_eq!(output.dims(), [2, 4, 5, 2]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn conv1d_forward_should_match_the_forward_pass_of_each_member() {
+        let device = Default::default();
+        let config = Conv1dConfig::new(4, 6, 3).with_groups(2).with_stride(2);
+        let members: Vec<_> = (0..3).map(|_| config.init(&device)).collect();
+        let input = Tensor::<TestBackend, 3>::random([2, 4, 9], Distribution::Default, &device);
+        let expected: Tensor<TestBackend, 4> = Tensor::stack(
+            members
+                .iter()
+                .map(|member| member.forward(input.clone()))
+                .collect(),
+            0,
+        );
+
+        let ensemble: Ensemble<TestBackend, StackedConv1d<TestBackend>> = Ensemble::new(members);
+        let output: Tensor<TestBackend, 4> = ensemble.forward(input);
+
+        assert_eq!(output.dims(), [3, 2, 6, 4]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn conv2d_forward_should_match_the_forward_pass_of_each_member() {
+        let device = Default::default();
+        let config = Conv2dConfig::new([2, 4], [3, 3]).with_padding(PaddingConfig2d::Same);
+        let members: Vec<_> = (0..2).map(|_| config.init(&device)).collect();
+        let input = Tensor::<TestBackend, 4>::random([3, 2, 5, 5], Distribution::Default, &device);
+        let expected: Tensor<TestBackend, 5> = Tensor::stack(
+            members
+                .iter()
+                .map(|member| member.forward(input.clone()))
+                .collect(),
+            0,
+        );
+
+        let ensemble: Ensemble<TestBackend, StackedConv2d<TestBackend>> = Ensemble::new(members);
+        let output: Tensor<TestBackend, 5> = ensemble.forward(input);
+
+        assert_eq!(output.dims(), [2, 3, 4, 5, 5]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic = "Either all the members of an ensemble or none of them should have a bias."]
+    fn should_panic_when_only_some_members_have_a_bias() {
+        let device = Default::default();
+
+        Ensemble::<TestBackend, StackedLinear<TestBackend>>::new(vec![
+            LinearConfig::new(2, 2).init(&device),
+            LinearConfig::new(2, 2).with_bias(false).init(&device),
+        ]);
+    }
+}
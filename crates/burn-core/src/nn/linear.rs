@@ -4,8 +4,9 @@ use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
 use crate::tensor::{backend::Backend, Tensor};
+use alloc::vec::Vec;
 
-use super::Initializer;
+use super::{stack_biases, stack_params, EnsembleForward, EnsembleMember, Initializer};
 
 /// Configuration to create a [Linear](Linear) layer using the [init function](LinearConfig::init).
 #[derive(Config, Debug)]
@@ -83,6 +84,67 @@ impl<B: Backend> Linear<B> {
     }
 }
 
+/// The parameters of the members of an [ensemble](super::Ensemble) of [linear](Linear) layers,
+/// stacked along a leading dimension.
+#[derive(Module, Debug)]
+pub struct StackedLinear<B: Backend> {
+    /// Tensor of shape `[num_members, d_input, d_output]`
+    pub weight: Param<Tensor<B, 3>>,
+    /// Tensor of shape `[num_members, d_output]`
+    pub bias: Option<Param<Tensor<B, 2>>>,
+}
+
+impl<B: Backend> EnsembleMember<B> for Linear<B> {
+    type Stacked = StackedLinear<B>;
+
+    fn stack(members: Vec<Self>) -> StackedLinear<B> {
+        let (weights, biases) = members
+            .into_iter()
+            .map(|member| (member.weight, member.bias))
+            .unzip();
+
+        StackedLinear {
+            weight: stack_params(weights),
+            bias: stack_biases(biases),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize, const D2: usize> EnsembleForward<B, D, D2> for StackedLinear<B> {
+    /// Applies the forward pass of every member on the same input.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[num_members, ..., d_output]`, so `D2` must be `D + 1`.
+    fn forward_members(&self, input: Tensor<B, D>) -> Tensor<B, D2> {
+        assert_eq!(
+            D2,
+            D + 1,
+            "The outputs of the ensemble should have one more dimension than the input."
+        );
+
+        let dims = input.dims();
+        let [num_members, d_input, d_output] = self.weight.dims();
+
+        // The rows of the input are shared by all the members, the matmul broadcasting them.
+        let output = input
+            .reshape([1, -1, d_input as i32])
+            .matmul(self.weight.val());
+        let output = match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze_dim::<3>(1),
+            None => output,
+        };
+
+        let mut shape = [0; D2];
+        shape[0] = num_members;
+        shape[1..D].copy_from_slice(&dims[..D - 1]);
+        shape[D2 - 1] = d_output;
+
+        output.reshape(shape)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
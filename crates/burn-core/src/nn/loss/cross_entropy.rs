@@ -1,6 +1,7 @@
 use crate as burn;
 
 use crate::tensor::activation::log_softmax;
+use crate::tensor::loss::cross_entropy;
 use crate::tensor::{backend::Backend, Bool, Int, Tensor};
 use crate::{config::Config, module::Module};
 use alloc::vec;
@@ -101,6 +102,47 @@ impl<B: Backend> CrossEntropyLoss<B> {
         }
     }
 
+    /// Compute the criterion on the input tensor with soft targets, such as the class
+    /// probabilities of images mixed by [mixup or cutmix](crate::preprocessing::Augmentation).
+    ///
+    /// The label smoothing and the weights are applied to the soft targets the same way as to
+    /// the hard targets, while the pad tokens are ignored.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - targets: `[batch_size, num_targets]`
+    pub fn forward_soft(&self, logits: Tensor<B, 2>, targets: Tensor<B, 2>) -> Tensor<B, 1> {
+        assert_eq!(
+            logits.dims(),
+            targets.dims(),
+            "Shape of soft targets should correspond to the shape of logits."
+        );
+        let tensor = if self.logits {
+            log_softmax(logits, 1)
+        } else {
+            logits.log()
+        };
+        let [batch_size, nr_classes] = tensor.dims();
+        let smoothed_targets = match self.smoothing {
+            Some(alpha) => targets.clone() * (1. - alpha) + alpha / nr_classes as f32,
+            None => targets.clone(),
+        };
+        let tensor = tensor * smoothed_targets;
+
+        match &self.weights {
+            Some(weights) => {
+                let weights = weights
+                    .clone()
+                    .reshape([1, nr_classes])
+                    .repeat(0, batch_size);
+                let tensor = tensor * weights.clone();
+                tensor.sum().neg() / (targets * weights).sum()
+            }
+            None => tensor.sum_dim(1).mean().neg(),
+        }
+    }
+
     fn forward_smoothed(
         &self,
         logits: Tensor<B, 2>,
@@ -136,22 +178,20 @@ impl<B: Backend> CrossEntropyLoss<B> {
     }
 
     fn forward_default(&self, logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
-        let [batch_size] = targets.dims();
-
         let mask = self.padding_mask(&targets);
-        let tensor = log_softmax(logits, 1);
-        let tensor = tensor.gather(1, targets.clone().reshape([batch_size, 1]));
+        // Fused log softmax and negative log likelihood, implemented by the backend.
+        let tensor = cross_entropy(logits, targets.clone());
 
         match &self.weights {
             Some(weights) => {
                 let weights = weights.clone().gather(0, targets);
-                let tensor = tensor.reshape([batch_size]) * weights.clone();
+                let tensor = tensor * weights.clone();
                 let tensor = Self::apply_mask_1d(tensor, mask);
-                tensor.sum().neg() / weights.sum()
+                tensor.sum() / weights.sum()
             }
             None => {
-                let tensor = Self::apply_mask_1d(tensor.reshape([batch_size]), mask);
-                tensor.mean().neg()
+                let tensor = Self::apply_mask_1d(tensor, mask);
+                tensor.mean()
             }
         }
     }
@@ -288,6 +328,21 @@ mod tests {
         loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
     }
 
+    #[test]
+    fn test_soft_targets_should_match_one_hot_targets() {
+        let (logits, targets, targets_logits) = setup!();
+        let device = Default::default();
+        let loss = CrossEntropyLossConfig::new()
+            .with_weights(Some(vec![1.0, 2., 3., 4., 5.]))
+            .with_smoothing(Some(0.1))
+            .init(&device);
+
+        let loss_1 = loss.forward(logits.clone(), targets);
+        let loss_2 = loss.forward_soft(logits, targets_logits);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
     #[test]
     fn test_label_smoothing_with_weights_and_alpha_zero() {
         let (logits, targets, _) = setup!();
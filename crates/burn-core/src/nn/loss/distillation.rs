@@ -0,0 +1,217 @@
+use crate as burn;
+
+use crate::tensor::activation::{log_softmax, softmax};
+use crate::tensor::{backend::Backend, Int, Tensor};
+use crate::{config::Config, module::Module};
+use alloc::vec::Vec;
+
+use super::{CrossEntropyLoss, CrossEntropyLossConfig};
+
+/// Configuration to create a [distillation loss](DistillationLoss) using the
+/// [init function](DistillationLossConfig::init).
+#[derive(Config, Debug)]
+pub struct DistillationLossConfig {
+    /// The temperature softening the distributions of the student and the teacher.
+    #[config(default = 2.0)]
+    pub temperature: f64,
+    /// The weight of the soft loss with the teacher, the hard loss with the targets having a
+    /// weight of `1 - alpha`.
+    #[config(default = 0.5)]
+    pub alpha: f64,
+    /// The weight of the feature matching loss.
+    #[config(default = 1.0)]
+    pub feature_weight: f64,
+}
+
+impl DistillationLossConfig {
+    /// Initialize [distillation loss](DistillationLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> DistillationLoss<B> {
+        self.assertions();
+        DistillationLoss {
+            temperature: self.temperature,
+            alpha: self.alpha,
+            feature_weight: self.feature_weight,
+            cross_entropy: CrossEntropyLossConfig::new().init(device),
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.temperature > 0.0,
+            "The temperature of the distillation loss should be positive, got {}.",
+            self.temperature
+        );
+        assert!(
+            (0.0..=1.0).contains(&self.alpha),
+            "The alpha of the distillation loss should be in interval [0, 1], got {}.",
+            self.alpha
+        );
+    }
+}
+
+/// Calculate the knowledge distillation loss of a student model from the outputs of a teacher
+/// model, as in [Distilling the Knowledge in a Neural Network](https://arxiv.org/abs/1503.02531).
+///
+/// The soft loss is the Kullback-Leibler divergence between the distributions of the teacher and
+/// of the student softened by the temperature `T`, scaled by `T^2` so its gradients keep the same
+/// magnitude as the hard loss when the temperature changes:
+///
+/// `L = alpha * T^2 * KL(softmax(t / T) || softmax(s / T)) + (1 - alpha) * CE(s, y)`
+///
+/// The intermediate features of selected layers can also be matched with
+/// [forward_features](DistillationLoss::forward_features).
+#[derive(Module, Debug)]
+pub struct DistillationLoss<B: Backend> {
+    temperature: f64,
+    alpha: f64,
+    feature_weight: f64,
+    cross_entropy: CrossEntropyLoss<B>,
+}
+
+impl<B: Backend> DistillationLoss<B> {
+    /// Compute the distillation loss, combining the soft loss with the teacher and the hard loss
+    /// with the targets.
+    ///
+    /// # Shapes
+    ///
+    /// - student_logits: `[batch_size, num_classes]`
+    /// - teacher_logits: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward(
+        &self,
+        student_logits: Tensor<B, 2>,
+        teacher_logits: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let hard = self.cross_entropy.forward(student_logits.clone(), targets);
+        let soft = self.forward_soft(student_logits, teacher_logits);
+
+        soft.mul_scalar(self.alpha)
+            .add(hard.mul_scalar(1.0 - self.alpha))
+    }
+
+    /// Compute the soft loss with the teacher only, averaged over the batch.
+    ///
+    /// The teacher logits are detached, so no gradient flows to the teacher.
+    ///
+    /// # Shapes
+    ///
+    /// - student_logits: `[batch_size, num_classes]`
+    /// - teacher_logits: `[batch_size, num_classes]`
+    /// - output: `[1]`
+    pub fn forward_soft(
+        &self,
+        student_logits: Tensor<B, 2>,
+        teacher_logits: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = student_logits.dims();
+        let teacher_logits = teacher_logits.detach().div_scalar(self.temperature);
+
+        let teacher_log_probs = log_softmax(teacher_logits.clone(), 1);
+        let teacher_probs = softmax(teacher_logits, 1);
+        let student_log_probs = log_softmax(student_logits.div_scalar(self.temperature), 1);
+
+        let divergence = (teacher_probs * (teacher_log_probs - student_log_probs)).sum();
+
+        divergence.mul_scalar(self.temperature * self.temperature / batch_size as f64)
+    }
+
+    /// Compute the feature matching loss, the mean squared error between the features of the
+    /// student and of the teacher for each selected layer, averaged over the layers.
+    ///
+    /// The features of the student should be projected to the shape of the features of the
+    /// teacher when they differ, e.g. with a [linear](crate::nn::Linear) layer trained with the
+    /// student. The teacher features are detached.
+    ///
+    /// # Shapes
+    ///
+    /// - features: pairs of student and teacher features of shape `[...dims]`
+    /// - output: `[1]`
+    pub fn forward_features<const D: usize>(
+        &self,
+        features: Vec<(Tensor<B, D>, Tensor<B, D>)>,
+    ) -> Tensor<B, 1> {
+        let num_layers = features.len();
+        assert!(
+            num_layers > 0,
+            "At least one pair of features should be matched."
+        );
+
+        let losses = features
+            .into_iter()
+            .map(|(student, teacher)| (student - teacher.detach()).powf_scalar(2.0).mean())
+            .collect();
+
+        Tensor::cat(losses, 0)
+            .sum()
+            .mul_scalar(self.feature_weight / num_layers as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::{activation, Data};
+    use crate::TestBackend;
+    use alloc::vec;
+
+    #[test]
+    fn soft_loss_should_be_zero_when_matching_the_teacher() {
+        let device = Default::default();
+        let loss = DistillationLossConfig::new().init::<TestBackend>(&device);
+        let logits =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 0.5], [0.0, -1.0, 3.0]], &device);
+
+        let output = loss.forward_soft(logits.clone(), logits);
+
+        output.into_data().assert_approx_eq(&Data::from([0.0]), 5);
+    }
+
+    #[test]
+    fn should_compute_the_soft_and_hard_losses() {
+        let device = Default::default();
+        let loss = DistillationLossConfig::new()
+            .with_temperature(2.0)
+            .with_alpha(0.25)
+            .init::<TestBackend>(&device);
+        let student = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [0.0, -1.0]], &device);
+        let teacher = Tensor::<TestBackend, 2>::from_floats([[3.0, 1.0], [1.0, 1.0]], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([1, 0], &device);
+
+        let output = loss.forward(student.clone(), teacher.clone(), targets.clone());
+
+        let teacher_probs = activation::softmax(teacher.div_scalar(2.0), 1);
+        let student_probs = activation::softmax(student.clone().div_scalar(2.0), 1);
+        let divergence = (teacher_probs.clone() * (teacher_probs.log() - student_probs.log()))
+            .sum()
+            .mul_scalar(4.0 / 2.0);
+        let hard = CrossEntropyLossConfig::new()
+            .init(&device)
+            .forward(student, targets);
+        let expected = divergence.mul_scalar(0.25) + hard.mul_scalar(0.75);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn should_average_the_feature_losses_over_the_layers() {
+        let device = Default::default();
+        let loss = DistillationLossConfig::new().init::<TestBackend>(&device);
+        let features = vec![
+            (
+                Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0]], &device),
+                Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0]], &device),
+            ),
+            (
+                Tensor::<TestBackend, 2>::from_floats([[0.0, 0.0]], &device),
+                Tensor::<TestBackend, 2>::from_floats([[0.0, 0.0]], &device),
+            ),
+        ];
+
+        let output = loss.forward_features(features);
+
+        output.into_data().assert_approx_eq(&Data::from([1.0]), 5);
+    }
+}
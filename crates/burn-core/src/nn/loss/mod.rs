@@ -1,11 +1,13 @@
 mod binary_cross_entropy;
 mod cross_entropy;
+mod distillation;
 mod huber;
 mod mse;
 mod reduction;
 
 pub use binary_cross_entropy::*;
 pub use cross_entropy::*;
+pub use distillation::*;
 pub use huber::*;
 pub use mse::*;
 pub use reduction::*;
@@ -16,34 +16,46 @@ pub mod pool;
 /// Transformer module
 pub mod transformer;
 
+mod drop_path;
 mod dropout;
 mod embedding;
+mod ensemble;
 mod gelu;
 mod initializer;
 mod leaky_relu;
 mod linear;
 mod norm;
 mod padding;
+mod parallel;
 mod pos_encoding;
 mod prelu;
+mod qlinear;
 mod relu;
 mod rnn;
 mod rope_encoding;
+mod sparse24;
 mod swiglu;
 mod unfold;
+mod variational_dropout;
 
+pub use drop_path::*;
 pub use dropout::*;
 pub use embedding::*;
+pub use ensemble::*;
 pub use gelu::*;
 pub use initializer::*;
 pub use leaky_relu::*;
 pub use linear::*;
 pub use norm::*;
 pub use padding::*;
+pub use parallel::*;
 pub use pos_encoding::*;
 pub use prelu::*;
+pub use qlinear::*;
 pub use relu::*;
 pub use rnn::*;
 pub use rope_encoding::*;
+pub use sparse24::*;
 pub use swiglu::*;
 pub use unfold::*;
+pub use variational_dropout::*;
@@ -3,8 +3,8 @@ use crate as burn;
 use crate::nn::Initializer;
 use crate::{
     config::Config,
-    module::{Module, Param, RunningState},
-    tensor::{backend::Backend, Tensor},
+    module::{full_precision_device, FullPrecisionRunningState, Module, Param, RunningState},
+    tensor::{backend::Backend, ops::FullPrecisionBackend, Tensor},
 };
 
 /// Configuration to create a [BatchNorm](BatchNorm) layer using the [init function](BatchNormConfig::init).
@@ -18,6 +18,10 @@ pub struct BatchNormConfig {
     /// Momentum used to update the metrics. Default: 0.1
     #[config(default = 0.1)]
     pub momentum: f64,
+    /// If the running statistics are also accumulated in full precision, so they don't drift on
+    /// half precision backends. Default: false
+    #[config(default = false)]
+    pub full_precision_stats: bool,
 }
 
 /// Applies Batch Normalization over a tensor as described in the paper [Batch Normalization](https://arxiv.org/abs/1502.03167)
@@ -42,6 +46,12 @@ pub struct BatchNorm<B: Backend, const D: usize> {
     pub running_mean: RunningState<Tensor<B, 1>>,
     /// The running variance.
     pub running_var: RunningState<Tensor<B, 1>>,
+    /// The running mean in full precision, when
+    /// [enabled](BatchNormConfig::full_precision_stats).
+    pub running_mean_full: Option<FullPrecisionRunningState<B, 1>>,
+    /// The running variance in full precision, when
+    /// [enabled](BatchNormConfig::full_precision_stats).
+    pub running_var_full: Option<FullPrecisionRunningState<B, 1>>,
     momentum: f64,
     epsilon: f64,
 }
@@ -55,11 +65,30 @@ impl BatchNormConfig {
         let running_mean = Tensor::zeros([self.num_features], device);
         let running_var = Tensor::ones([self.num_features], device);
 
+        let (running_mean_full, running_var_full) = match self.full_precision_stats {
+            true => {
+                let device_full = full_precision_device::<B>(device);
+                (
+                    Some(FullPrecisionRunningState::new(Tensor::zeros(
+                        [self.num_features],
+                        &device_full,
+                    ))),
+                    Some(FullPrecisionRunningState::new(Tensor::ones(
+                        [self.num_features],
+                        &device_full,
+                    ))),
+                )
+            }
+            false => (None, None),
+        };
+
         BatchNorm {
             gamma,
             beta,
             running_mean: RunningState::new(running_mean),
             running_var: RunningState::new(running_var),
+            running_mean_full,
+            running_var_full,
             momentum: self.momentum,
             epsilon: self.epsilon,
         }
@@ -98,7 +127,8 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
         }
     }
 
-    fn forward_inference<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
+    /// Normalizes the input with the running statistics.
+    pub(crate) fn forward_inference<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
         let device = input.device();
         let channels = input.dims()[1];
         let mean = self.running_mean.value().to_device(&device);
@@ -111,7 +141,6 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
     }
 
     fn forward_train<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
-        let device = input.device();
         let dims = input.dims();
         let batch_size = dims[0];
         let channels = dims[1];
@@ -140,29 +169,70 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
             .mean_dim(1)
             .reshape(shape_unsqueeze);
 
+        self.update_running_stats(
+            mean.clone().reshape([channels]),
+            var.clone().reshape([channels]),
+        );
+
+        self.forward_shared(input, mean, var)
+    }
+
+    /// Updates the running statistics with the mean and the variance of each channel of a batch.
+    pub(crate) fn update_running_stats(&self, mean: Tensor<B, 1>, var: Tensor<B, 1>) {
+        let device = mean.device();
+        let mean = mean.detach();
+        let var = var.detach();
+
+        if let (Some(running_mean_full), Some(running_var_full)) =
+            (&self.running_mean_full, &self.running_var_full)
+        {
+            // The statistics are accumulated in full precision, then converted to the float
+            // element type of the backend for the other running states.
+            let running_mean = self.update_full_precision(running_mean_full, mean);
+            let running_var = self.update_full_precision(running_var_full, var);
+
+            self.running_mean
+                .update(Tensor::from_full_precision(running_mean).to_device(&device));
+            self.running_var
+                .update(Tensor::from_full_precision(running_var).to_device(&device));
+
+            return;
+        }
+
         let running_mean = self.running_mean.value_sync().to_device(&device);
         let running_var = self.running_var.value_sync().to_device(&device);
 
-        let running_mean = running_mean.mul_scalar(1.0 - self.momentum).add(
-            mean.clone()
-                .detach()
-                .mul_scalar(self.momentum)
-                .reshape([channels]),
-        );
-        let running_var = running_var.mul_scalar(1.0 - self.momentum).add(
-            var.clone()
-                .detach()
-                .mul_scalar(self.momentum)
-                .reshape([channels]),
-        );
+        let running_mean = running_mean
+            .mul_scalar(1.0 - self.momentum)
+            .add(mean.mul_scalar(self.momentum));
+        let running_var = running_var
+            .mul_scalar(1.0 - self.momentum)
+            .add(var.mul_scalar(self.momentum));
 
         self.running_mean.update(running_mean.detach());
         self.running_var.update(running_var.detach());
+    }
 
-        self.forward_shared(input, mean, var)
+    fn update_full_precision(
+        &self,
+        state: &FullPrecisionRunningState<B, 1>,
+        value: Tensor<B, 1>,
+    ) -> Tensor<FullPrecisionBackend<B>, 1> {
+        let value = value.into_full_precision();
+        let running = state
+            .value_sync()
+            .to_device(&value.device())
+            .mul_scalar(1.0 - self.momentum)
+            .add(value.mul_scalar(self.momentum));
+
+        state.update(running.clone());
+
+        running
     }
 
-    fn forward_shared<const DI: usize>(
+    /// Normalizes the input with the given mean and variance of each channel, then applies the
+    /// learnable weights.
+    pub(crate) fn forward_shared<const DI: usize>(
         &self,
         x: Tensor<B, DI>,
         mean: Tensor<B, DI>,
@@ -247,6 +317,7 @@ mod tests_1d {
 #[cfg(test)]
 mod tests_2d {
     use super::*;
+    use crate::record::{FullPrecisionSettings, Record};
     use crate::tensor::Data;
     use crate::{module::AutodiffModule, TestAutodiffBackend};
 
@@ -330,6 +401,53 @@ mod tests_2d {
             .assert_approx_eq(&Data::from([0.9106, 0.9105, 0.9045]), 2);
     }
 
+    #[test]
+    fn batch_norm_full_precision_running_var() {
+        let device = Default::default();
+        let config = BatchNormConfig::new(3).with_full_precision_stats(true);
+        let module = config.init::<TestAutodiffBackend, 2>(&device);
+
+        let _output = module.forward(input_tensor(&device));
+
+        let running_var_full = module.running_var_full.as_ref().unwrap();
+        running_var_full
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.9106, 0.9105, 0.9045]), 2);
+        module
+            .running_var
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.9106, 0.9105, 0.9045]), 2);
+    }
+
+    #[test]
+    fn batch_norm_full_precision_running_stats_record() {
+        let device = Default::default();
+        let config = BatchNormConfig::new(3).with_full_precision_stats(true);
+        let module = config.init::<TestAutodiffBackend, 2>(&device);
+        let _output = module.forward(input_tensor(&device));
+
+        let item = module
+            .clone()
+            .into_record()
+            .into_item::<FullPrecisionSettings>();
+        let record = BatchNormRecord::from_item::<FullPrecisionSettings>(item, &device);
+        let module_loaded = config
+            .init::<TestAutodiffBackend, 2>(&device)
+            .load_record(record);
+
+        module_loaded
+            .running_var_full
+            .unwrap()
+            .value()
+            .into_data()
+            .assert_approx_eq(
+                &module.running_var_full.unwrap().value_sync().into_data(),
+                5,
+            );
+    }
+
     #[test]
     fn batch_norm_running_mean_inner_module() {
         let device = Default::default();
@@ -3,9 +3,13 @@ mod group;
 mod instance;
 mod layer;
 mod rms;
+#[cfg(feature = "std")]
+mod sync_batch;
 
 pub use batch::*;
 pub use group::*;
 pub use instance::*;
 pub use layer::*;
 pub use rms::*;
+#[cfg(feature = "std")]
+pub use sync_batch::*;
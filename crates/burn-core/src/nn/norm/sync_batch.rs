@@ -0,0 +1,192 @@
+use crate as burn;
+
+use crate::collective::{CollectiveGroup, ReduceOperation};
+use crate::nn::{BatchNorm, BatchNormConfig};
+use crate::{
+    config::Config,
+    module::{Ignored, Module},
+    tensor::{backend::Backend, Tensor},
+};
+
+/// Configuration to create a [SyncBatchNorm](SyncBatchNorm) layer using the
+/// [init function](SyncBatchNormConfig::init).
+#[derive(Config, Debug)]
+pub struct SyncBatchNormConfig {
+    /// The number of features.
+    pub num_features: usize,
+    /// The number of data-parallel replicas, usually the number of devices used for training.
+    pub num_replicas: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// Momentum used to update the metrics. Default: 0.1
+    #[config(default = 0.1)]
+    pub momentum: f64,
+    /// If the running statistics are also accumulated in full precision, see
+    /// [BatchNormConfig::full_precision_stats]. Default: false
+    #[config(default = false)]
+    pub full_precision_stats: bool,
+}
+
+/// Applies Batch Normalization with the statistics of the batches of all data-parallel replicas,
+/// which is required when the batch size on each device is small.
+///
+/// During training, the sum and the sum of squares of each channel are
+/// [all-reduced](CollectiveGroup::all_reduce) between the replicas, which are the forks of the
+/// module on each device. The normalization is otherwise done by the inner [BatchNorm], and
+/// inference only uses the running statistics.
+///
+/// # Notes
+///
+/// Every replica has to execute the forward pass the same number of times, so the number of
+/// batches of an epoch should be a multiple of the number of replicas. The gradients only flow
+/// through the statistics of the local batch, the contribution of the other replicas being
+/// treated as a constant.
+///
+/// Should be created using [SyncBatchNormConfig].
+#[derive(Module, Debug)]
+pub struct SyncBatchNorm<B: Backend, const D: usize> {
+    /// The batch norm applied with the statistics of all replicas.
+    pub norm: BatchNorm<B, D>,
+    group: Ignored<CollectiveGroup>,
+}
+
+impl SyncBatchNormConfig {
+    /// Initializes a new [sync batch norm](SyncBatchNorm) module.
+    pub fn init<B: Backend, const D: usize>(&self, device: &B::Device) -> SyncBatchNorm<B, D> {
+        let norm = BatchNormConfig::new(self.num_features)
+            .with_epsilon(self.epsilon)
+            .with_momentum(self.momentum)
+            .with_full_precision_stats(self.full_precision_stats)
+            .init(device);
+
+        SyncBatchNorm {
+            norm,
+            group: Ignored(CollectiveGroup::new(self.num_replicas)),
+        }
+    }
+}
+
+impl<const D: usize, B: Backend> SyncBatchNorm<B, D> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [SyncBatchNorm](SyncBatchNorm) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, ...]`
+    /// - output: `[batch_size, channels, ...]`
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the input tensor has a dimension different from `D + 2`.
+    pub fn forward<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
+        if D + 2 != DI {
+            panic!(
+                "SyncBatchNorm{}D can only be applied on tensors of size {} with the following \
+                 shape [batch_size, channels, ...], received {}D tensor",
+                D,
+                D + 2,
+                DI
+            );
+        }
+
+        match B::ad_enabled() {
+            true => self.forward_train(input),
+            false => self.norm.forward_inference(input),
+        }
+    }
+
+    fn forward_train<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
+        let dims = input.dims();
+        let channels = dims[1];
+        let flatten_size = dims.iter().product::<usize>() / channels;
+
+        let mut shape_unsqueeze = [1; DI];
+        shape_unsqueeze[1] = channels;
+
+        let flatten = input
+            .clone()
+            .swap_dims(0, 1)
+            .reshape([channels, flatten_size]);
+        let count = Tensor::full([1], flatten_size as f32, &input.device());
+        let stats = Tensor::cat(
+            vec![
+                flatten.clone().sum_dim(1).reshape([channels]),
+                flatten.powf_scalar(2.0).sum_dim(1).reshape([channels]),
+                count,
+            ],
+            0,
+        );
+
+        // Only the local statistics are tracked by autodiff, the graph of each replica stays
+        // separated.
+        let stats_local = stats.clone().detach();
+        let stats = stats.add(
+            self.group
+                .all_reduce(stats_local.clone(), ReduceOperation::Sum)
+                .sub(stats_local),
+        );
+
+        let count = stats.clone().slice([2 * channels..2 * channels + 1]);
+        let mean = stats.clone().slice([0..channels]).div(count.clone());
+        let var = stats
+            .slice([channels..2 * channels])
+            .div(count)
+            .sub(mean.clone().powf_scalar(2.0));
+
+        self.norm.update_running_stats(mean.clone(), var.clone());
+
+        self.norm.forward_shared(
+            input,
+            mean.reshape(shape_unsqueeze),
+            var.reshape(shape_unsqueeze),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn sync_batch_norm_should_match_batch_norm_on_the_full_batch() {
+        let device = Default::default();
+        let input = Tensor::<TestAutodiffBackend, 4>::random(
+            [4, 3, 2, 2],
+            burn_tensor::Distribution::Default,
+            &device,
+        );
+        let expected = BatchNormConfig::new(3)
+            .init::<TestAutodiffBackend, 2>(&device)
+            .forward(input.clone());
+
+        let module = SyncBatchNormConfig::new(3, 2).init::<TestAutodiffBackend, 2>(&device);
+        let handles = (0..2)
+            .map(|i| {
+                let module = module.clone();
+                let input = input.clone().slice([2 * i..2 * i + 2]);
+                std::thread::spawn(move || module.forward(input).into_data())
+            })
+            .collect::<Vec<_>>();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            let output = handle.join().unwrap();
+            output.assert_approx_eq(&expected.clone().slice([2 * i..2 * i + 2]).into_data(), 3);
+        }
+
+        // The record synchronizes the updates of all replicas.
+        let running_mean = module.into_record().norm.running_mean.val();
+        running_mean.into_data().assert_approx_eq(
+            &input
+                .swap_dims(0, 1)
+                .reshape([3, 16])
+                .mean_dim(1)
+                .mul_scalar(0.1)
+                .reshape([3])
+                .into_data(),
+            3,
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, Param};
+use crate::tensor::{backend::Backend, Tensor};
+use alloc::vec::Vec;
+
+use super::{Initializer, Linear};
+
+/// Configuration to create a [ColumnParallelLinear](ColumnParallelLinear) layer using the
+/// [init function](ColumnParallelLinearConfig::init).
+#[derive(Config, Debug)]
+pub struct ColumnParallelLinearConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the output features, split evenly between the devices.
+    pub d_output: usize,
+    /// If a bias should be applied during the linear transformation.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Configuration to create a [RowParallelLinear](RowParallelLinear) layer using the
+/// [init function](RowParallelLinearConfig::init).
+#[derive(Config, Debug)]
+pub struct RowParallelLinearConfig {
+    /// The size of the input features, split evenly between the devices.
+    pub d_input: usize,
+    /// The size of the output features.
+    pub d_output: usize,
+    /// If a bias should be applied during the linear transformation.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// A [linear](Linear) layer whose weight is split by columns, the output features, between
+/// devices.
+///
+/// Each device computes its slice of the output features from the whole input, the slices being
+/// gathered by [forward](ColumnParallelLinear::forward). Followed by a
+/// [row-parallel linear](RowParallelLinear), the sharded outputs of
+/// [forward_sharded](ColumnParallelLinear::forward_sharded) can be used directly, so the
+/// activations in between stay split between the devices.
+///
+/// Should be created with [ColumnParallelLinearConfig].
+#[derive(Module, Debug)]
+pub struct ColumnParallelLinear<B: Backend> {
+    /// The linear layer of each device, with a slice of the output features.
+    pub shards: Vec<Linear<B>>,
+}
+
+/// A [linear](Linear) layer whose weight is split by rows, the input features, between devices.
+///
+/// Each device computes a partial output from its slice of the input features, the partial
+/// outputs being summed on the device of the first shard, which holds the bias.
+///
+/// Should be created with [RowParallelLinearConfig].
+#[derive(Module, Debug)]
+pub struct RowParallelLinear<B: Backend> {
+    /// The linear layer of each device, with a slice of the input features and without bias.
+    pub shards: Vec<Linear<B>>,
+    /// Vector of size `d_output`, on the device of the first shard.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+}
+
+impl ColumnParallelLinearConfig {
+    /// Initialize a new [column-parallel linear](ColumnParallelLinear) module, with a shard on
+    /// each device.
+    pub fn init<B: Backend>(&self, devices: &[B::Device]) -> ColumnParallelLinear<B> {
+        let d_shard = shard_size(self.d_output, devices.len());
+
+        let shards = devices
+            .iter()
+            .map(|device| {
+                let (fan_in, fan_out) = (Some(self.d_input), Some(self.d_output));
+
+                Linear {
+                    weight: self.initializer.init_with(
+                        [self.d_input, d_shard],
+                        fan_in,
+                        fan_out,
+                        device,
+                    ),
+                    bias: self.bias.then(|| {
+                        self.initializer
+                            .init_with([d_shard], fan_in, fan_out, device)
+                    }),
+                }
+            })
+            .collect();
+
+        ColumnParallelLinear { shards }
+    }
+}
+
+impl RowParallelLinearConfig {
+    /// Initialize a new [row-parallel linear](RowParallelLinear) module, with a shard on each
+    /// device.
+    pub fn init<B: Backend>(&self, devices: &[B::Device]) -> RowParallelLinear<B> {
+        let d_shard = shard_size(self.d_input, devices.len());
+
+        let shards = devices
+            .iter()
+            .map(|device| Linear {
+                weight: self.initializer.init_with(
+                    [d_shard, self.d_output],
+                    Some(self.d_input),
+                    Some(self.d_output),
+                    device,
+                ),
+                bias: None,
+            })
+            .collect();
+        let bias = self.bias.then(|| {
+            self.initializer.init_with(
+                [self.d_output],
+                Some(self.d_input),
+                Some(self.d_output),
+                &devices[0],
+            )
+        });
+
+        RowParallelLinear { shards, bias }
+    }
+}
+
+impl<B: Backend> ColumnParallelLinear<B> {
+    /// Applies the forward pass on the input tensor, gathering the output features on the device
+    /// of the input.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let device = input.device();
+        let outputs = self
+            .forward_sharded(input)
+            .into_iter()
+            .map(|output| output.to_device(&device))
+            .collect();
+
+        Tensor::cat(outputs, D - 1)
+    }
+
+    /// Applies the forward pass on the input tensor, keeping the slice of the output features
+    /// computed by each shard on its device.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output / num_shards]` for each shard
+    pub fn forward_sharded<const D: usize>(&self, input: Tensor<B, D>) -> Vec<Tensor<B, D>> {
+        self.shards
+            .iter()
+            .map(|shard| shard.forward(input.clone().to_device(&shard.weight.device())))
+            .collect()
+    }
+}
+
+impl<B: Backend> RowParallelLinear<B> {
+    /// Applies the forward pass on the input tensor, split between the shards. The output is on
+    /// the device of the first shard.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let inputs = input.chunk(self.shards.len(), D - 1);
+        self.forward_sharded(inputs)
+    }
+
+    /// Applies the forward pass on the slices of the input features of each shard, e.g. the
+    /// outputs of a [column-parallel linear](ColumnParallelLinear::forward_sharded). The output
+    /// is on the device of the first shard.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: `[..., d_input / num_shards]` for each shard
+    /// - output: `[..., d_output]`
+    pub fn forward_sharded<const D: usize>(&self, inputs: Vec<Tensor<B, D>>) -> Tensor<B, D> {
+        assert_eq!(
+            inputs.len(),
+            self.shards.len(),
+            "Each shard should have its slice of the input features."
+        );
+        let device = self.shards[0].weight.device();
+
+        let output = self
+            .shards
+            .iter()
+            .zip(inputs)
+            .map(|(shard, input)| {
+                shard
+                    .forward(input.to_device(&shard.weight.device()))
+                    .to_device(&device)
+            })
+            .reduce(|acc, output| acc + output)
+            .unwrap();
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+/// The size of the slice of each device.
+pub(crate) fn shard_size(size: usize, num_shards: usize) -> usize {
+    assert!(num_shards > 0, "At least one device is needed.");
+    assert_eq!(
+        size % num_shards,
+        0,
+        "The size {size} should be divisible by the number of devices {num_shards}."
+    );
+
+    size / num_shards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::{Distribution, Shape};
+    use crate::TestBackend;
+
+    #[test]
+    fn column_parallel_should_match_linear() {
+        let devices = vec![<TestBackend as Backend>::Device::default(); 2];
+        let linear = ColumnParallelLinearConfig::new(4, 6).init::<TestBackend>(&devices);
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 4], Distribution::Default, &devices[0]);
+
+        let expected = Linear {
+            weight: Param::from_tensor(Tensor::cat(
+                linear
+                    .shards
+                    .iter()
+                    .map(|shard| shard.weight.val())
+                    .collect(),
+                1,
+            )),
+            bias: Some(Param::from_tensor(Tensor::cat(
+                linear
+                    .shards
+                    .iter()
+                    .map(|shard| shard.bias.as_ref().unwrap().val())
+                    .collect(),
+                0,
+            ))),
+        };
+        let output = linear.forward(input.clone());
+
+        assert_eq!(output.shape(), Shape::new([2, 3, 6]));
+        output
+            .into_data()
+            .assert_approx_eq(&expected.forward(input).into_data(), 3);
+    }
+
+    #[test]
+    fn row_parallel_should_match_linear() {
+        let devices = vec![<TestBackend as Backend>::Device::default(); 2];
+        let linear = RowParallelLinearConfig::new(6, 4).init::<TestBackend>(&devices);
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 6], Distribution::Default, &devices[0]);
+
+        let expected = Linear {
+            weight: Param::from_tensor(Tensor::cat(
+                linear
+                    .shards
+                    .iter()
+                    .map(|shard| shard.weight.val())
+                    .collect(),
+                0,
+            )),
+            bias: linear.bias.clone(),
+        };
+        let output = linear.forward(input.clone());
+
+        assert_eq!(output.shape(), Shape::new([2, 3, 4]));
+        output
+            .into_data()
+            .assert_approx_eq(&expected.forward(input).into_data(), 3);
+    }
+}
@@ -0,0 +1,254 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, Param};
+use crate::tensor::{backend::Backend, Data, ElementConversion, Int, Shape, Tensor};
+use alloc::vec::Vec;
+
+use super::Linear;
+
+/// The integer format of the weights of a [quantized linear](QLinear) layer.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum WeightQuantization {
+    /// 8 bits per weight, three weights packed in each integer.
+    Int8,
+    /// 4 bits per weight, seven weights packed in each integer.
+    Int4,
+}
+
+impl WeightQuantization {
+    fn bits(&self) -> usize {
+        match self {
+            Self::Int8 => 8,
+            Self::Int4 => 4,
+        }
+    }
+
+    /// The number of weights packed in each integer, using at most 31 bits so they fit in any
+    /// integer element type.
+    fn num_packed(&self) -> usize {
+        31 / self.bits()
+    }
+
+    /// The largest magnitude of the quantized weights, which are symmetric around zero.
+    fn max_value(&self) -> i64 {
+        (1 << (self.bits() - 1)) - 1
+    }
+
+    /// The offset making the quantized weights positive, so they can be packed.
+    fn offset(&self) -> i64 {
+        self.max_value() + 1
+    }
+
+    fn base(&self) -> i64 {
+        1 << self.bits()
+    }
+}
+
+/// Applies a linear transformation with weights quantized to integers, dequantized on the fly
+/// during the forward pass:
+///
+/// `O = I(Q * s) + b`
+///
+/// The weights are quantized symmetrically with a scale per output channel and packed in integer
+/// tensors, so only the memory of the quantized weights is used between forward passes. This
+/// targets the inference of models bound by the memory of their weights, e.g. large language
+/// models, and isn't meant for training.
+///
+/// Should be created from a trained [linear](Linear) layer with [QLinear::from_linear].
+#[derive(Module, Debug)]
+pub struct QLinear<B: Backend> {
+    /// The packed quantized weights of shape `[ceil(d_input / num_packed), d_output]`, packing
+    /// consecutive input features of each output channel.
+    pub weight: Param<Tensor<B, 2, Int>>,
+    /// The scale of each output channel, of size `d_output`.
+    pub scale: Param<Tensor<B, 1>>,
+    /// Vector of size `d_output`, kept in floating point.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    quantization: WeightQuantization,
+    d_input: usize,
+}
+
+impl<B: Backend> QLinear<B> {
+    /// Quantize the weights of the linear layer.
+    pub fn from_linear(linear: Linear<B>, quantization: WeightQuantization) -> Self {
+        let weight = linear.weight.val();
+        let [d_input, d_output] = weight.dims();
+        let (packed, scale) = quantize_columns(weight, &quantization);
+
+        Self {
+            weight: Param::initialized(linear.weight.id.clone(), packed),
+            scale: Param::from_tensor(scale.reshape([d_output])),
+            bias: linear.bias,
+            quantization,
+            d_input,
+        }
+    }
+
+    /// Unpack and dequantize the weights, of shape `[d_input, d_output]`.
+    pub fn dequantize(&self) -> Tensor<B, 2> {
+        dequantize_columns(
+            self.weight.val(),
+            self.scale.val().unsqueeze(),
+            &self.quantization,
+            self.d_input,
+        )
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if D == 1 {
+            // Insert and remove an extra batch dimension for the batch matmul to work.
+            return Self::forward::<2>(self, input.unsqueeze()).flatten(0, 1);
+        }
+
+        let output = input.matmul(self.dequantize().unsqueeze());
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+/// Quantize each column of the tensor symmetrically with its own scale, packing the quantized
+/// values of consecutive rows in each integer.
+///
+/// # Shapes
+///
+/// - tensor: `[num_rows, num_columns]`
+/// - output: `[ceil(num_rows / num_packed), num_columns]` and the scales `[1, num_columns]`
+pub(crate) fn quantize_columns<B: Backend>(
+    tensor: Tensor<B, 2>,
+    quantization: &WeightQuantization,
+) -> (Tensor<B, 2, Int>, Tensor<B, 2>) {
+    let [num_rows, num_columns] = tensor.dims();
+    let device = tensor.device();
+    let num_packed = quantization.num_packed();
+
+    let scale = tensor
+        .clone()
+        .abs()
+        .max_dim(0)
+        .clamp_min(f32::EPSILON)
+        .div_scalar(quantization.max_value() as f64);
+
+    // The values shifted by the offset are positive, so truncating them after adding a half
+    // rounds them to the nearest integer.
+    let quantized = (tensor / scale.clone())
+        .add_scalar(quantization.offset() as f64 + 0.5)
+        .int()
+        .clamp(
+            quantization.offset() - quantization.max_value(),
+            quantization.offset() + quantization.max_value(),
+        );
+
+    let num_packed_rows = num_rows.div_ceil(num_packed);
+    let padding = num_packed_rows * num_packed - num_rows;
+    let quantized = match padding {
+        0 => quantized,
+        _ => Tensor::cat(
+            alloc::vec![quantized, Tensor::zeros([padding, num_columns], &device)],
+            0,
+        ),
+    };
+
+    let powers = (0..num_packed)
+        .map(|index| quantization.base().pow(index as u32).elem())
+        .collect::<Vec<_>>();
+    let powers =
+        Tensor::<B, 3, Int>::from_data(Data::new(powers, Shape::new([1, num_packed, 1])), &device);
+    let packed = (quantized.reshape([num_packed_rows, num_packed, num_columns]) * powers)
+        .sum_dim(1)
+        .reshape([num_packed_rows, num_columns]);
+
+    (packed, scale)
+}
+
+/// Unpack and dequantize the columns quantized with [quantize_columns].
+///
+/// # Shapes
+///
+/// - packed: `[ceil(num_rows / num_packed), num_columns]`
+/// - scale: `[1, num_columns]`
+/// - output: `[num_rows, num_columns]`
+pub(crate) fn dequantize_columns<B: Backend>(
+    packed: Tensor<B, 2, Int>,
+    scale: Tensor<B, 2>,
+    quantization: &WeightQuantization,
+    num_rows: usize,
+) -> Tensor<B, 2> {
+    let [num_packed_rows, num_columns] = packed.dims();
+    let num_packed = quantization.num_packed();
+    let base = quantization.base();
+
+    let unpacked = (0..num_packed)
+        .map(|index| {
+            packed
+                .clone()
+                .div_scalar(base.pow(index as u32))
+                .remainder_scalar(base)
+        })
+        .collect();
+    let quantized = Tensor::<B, 3, Int>::stack(unpacked, 1)
+        .reshape([num_packed_rows * num_packed, num_columns])
+        .narrow(0, 0, num_rows);
+
+    quantized
+        .float()
+        .sub_scalar(quantization.offset() as f64)
+        .mul(scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::LinearConfig;
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_dequantize_the_representable_weights_exactly() {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(4, 2).init::<TestBackend>(&device);
+        linear.weight = Param::from_tensor(Tensor::from_floats(
+            [[7.0, -1.0], [-3.0, 2.0], [0.0, -7.0], [5.0, 1.0]],
+            &device,
+        ));
+        let qlinear = QLinear::from_linear(linear.clone(), WeightQuantization::Int4);
+
+        assert_eq!(qlinear.weight.dims(), [1, 2]);
+        qlinear
+            .dequantize()
+            .into_data()
+            .assert_approx_eq(&linear.weight.val().into_data(), 5);
+    }
+
+    #[test]
+    fn should_approximate_the_linear_layer() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let linear = LinearConfig::new(10, 6).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 10], Distribution::Default, &device);
+        let expected = linear.forward(input.clone()).into_data();
+
+        let qlinear = QLinear::from_linear(linear.clone(), WeightQuantization::Int8);
+        assert_eq!(qlinear.weight.dims(), [4, 6]);
+        qlinear
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq_diff(&expected, 0.01);
+
+        let qlinear = QLinear::from_linear(linear, WeightQuantization::Int4);
+        assert_eq!(qlinear.weight.dims(), [2, 6]);
+        qlinear
+            .forward(input)
+            .into_data()
+            .assert_approx_eq_diff(&expected, 0.1);
+    }
+}
@@ -0,0 +1,139 @@
+use crate as burn;
+
+use crate::module::{Module, Param};
+use crate::tensor::module::{sparse24_compress, sparse24_decompress, sparse24_matmul};
+use crate::tensor::{backend::Backend, Bool, Int, Tensor};
+
+use super::Linear;
+
+/// Prune the weights to the 2:4 semi-structured sparsity pattern, zeroing the two values with
+/// the smallest magnitude in each group of four consecutive input features of each output.
+///
+/// The pruned weights can be fine-tuned while [masking](prune_24_mask) their gradients or
+/// re-pruned after each step, then converted with [Sparse24Linear::from_linear].
+///
+/// # Shapes
+///
+/// - weight: `[d_input, d_output]`, where `d_input` is a multiple of 4.
+pub fn prune_24<B: Backend>(weight: Tensor<B, 2>) -> Tensor<B, 2> {
+    let (values, metadata) = sparse24_compress(weight);
+
+    sparse24_decompress(values, metadata)
+}
+
+/// The mask of the weights kept by [prune_24], where the pruned weights are true.
+pub fn prune_24_mask<B: Backend>(weight: Tensor<B, 2>) -> Tensor<B, 2, Bool> {
+    let (values, metadata) = sparse24_compress(weight);
+
+    sparse24_decompress(values.ones_like(), metadata).equal_elem(0.0)
+}
+
+/// Applies a linear transformation with weights stored in the 2:4 semi-structured sparse format:
+///
+/// `O = IW + b`
+///
+/// Only the two values kept in each group of four input features of each output are stored,
+/// with their positions, and the matrix multiplication uses the
+/// [sparse kernel](crate::tensor::ops::ModuleOps::sparse24_matmul) of the backend, which falls
+/// back to a dense one when the backend has none.
+///
+/// Should be created from a [linear](Linear) layer with [Sparse24Linear::from_linear].
+#[derive(Module, Debug)]
+pub struct Sparse24Linear<B: Backend> {
+    /// The values kept of the weights, of shape `[d_input / 2, d_output]`.
+    pub values: Param<Tensor<B, 2>>,
+    /// The positions of the values kept in each group of four input features, of shape
+    /// `[d_input / 4, d_output]`.
+    pub metadata: Param<Tensor<B, 2, Int>>,
+    /// Vector of size `d_output`.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+}
+
+impl<B: Backend> Sparse24Linear<B> {
+    /// Compress the weights of the linear layer, pruning them to the 2:4 pattern if they don't
+    /// already follow it.
+    ///
+    /// # Panics
+    ///
+    /// If the number of input features isn't a multiple of 4.
+    pub fn from_linear(linear: Linear<B>) -> Self {
+        let (values, metadata) = sparse24_compress(linear.weight.val());
+
+        Self {
+            values: Param::initialized(linear.weight.id.clone(), values),
+            metadata: Param::initialized(Default::default(), metadata),
+            bias: linear.bias,
+        }
+    }
+
+    /// The dense weights, of shape `[d_input, d_output]`.
+    pub fn to_dense(&self) -> Tensor<B, 2> {
+        sparse24_decompress(self.values.val(), self.metadata.val())
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let dims = input.dims();
+        let d_input = dims[D - 1];
+        let [_, d_output] = self.values.dims();
+
+        let output = sparse24_matmul(
+            input.reshape([-1, d_input as i32]),
+            self.values.val(),
+            self.metadata.val(),
+        );
+
+        let mut shape = dims;
+        shape[D - 1] = d_output;
+        let output = output.reshape(shape);
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::LinearConfig;
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_match_the_pruned_linear_layer() {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(8, 3).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 4, 8], Distribution::Default, &device);
+
+        linear.weight = linear.weight.map(prune_24);
+        let expected = linear.forward(input.clone());
+        let sparse = Sparse24Linear::from_linear(linear.clone());
+
+        assert_eq!(sparse.values.dims(), [4, 3]);
+        sparse
+            .to_dense()
+            .into_data()
+            .assert_approx_eq(&linear.weight.val().into_data(), 5);
+        sparse
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_mask_half_of_the_weights() {
+        let weight =
+            Tensor::<TestBackend, 2>::random([8, 3], Distribution::Default, &Default::default());
+
+        let mask = prune_24_mask(weight);
+
+        assert_eq!(mask.int().sum().into_scalar(), 12);
+    }
+}
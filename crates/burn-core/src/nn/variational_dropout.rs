@@ -0,0 +1,128 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Shape, Tensor};
+
+/// Configuration to create a [VariationalDropout](VariationalDropout) layer using the
+/// [init function](VariationalDropoutConfig::init).
+#[derive(Config, Debug)]
+pub struct VariationalDropoutConfig {
+    /// The probability of randomly zeroes some features of each sample during training.
+    pub prob: f64,
+}
+
+/// Set at random some features of each sample to zero during training, with the same mask for
+/// every time step of a sequence, as described in the paper
+/// [A Theoretically Grounded Application of Dropout in Recurrent Neural Networks](https://arxiv.org/abs/1512.05287).
+///
+/// Unlike [dropout](crate::nn::Dropout), which samples a new mask for every element, the mask is
+/// sampled once per sample of the batch and shared along the sequence dimension. The input is also
+/// scaled during training to `1 / (1 - prob)`, so the layer is the identity during inference.
+///
+/// Should be created with [VariationalDropoutConfig].
+#[derive(Module, Clone, Debug)]
+pub struct VariationalDropout {
+    prob: f64,
+}
+
+impl VariationalDropoutConfig {
+    /// Initialize a new [variational dropout](VariationalDropout) module.
+    pub fn init(&self) -> VariationalDropout {
+        assert!(
+            (0.0..1.0).contains(&self.prob),
+            "The dropout probability {} should be in [0, 1).",
+            self.prob
+        );
+
+        VariationalDropout { prob: self.prob }
+    }
+}
+
+impl VariationalDropout {
+    /// Applies the forward pass on the input sequences, with the same mask for every time step.
+    ///
+    /// See [VariationalDropout](VariationalDropout) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, ...]`
+    /// - output: `[batch_size, seq_length, ...]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        assert!(
+            D >= 2,
+            "The input of the variational dropout should have a sequence dimension."
+        );
+
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let mut shape = input.dims();
+        shape[1] = 1;
+        let mask = self.mask(shape, &input.device());
+
+        input * mask
+    }
+
+    /// Sample a scaled mask of the given shape, to be multiplied with the input of every step of
+    /// a recurrent computation, e.g. the hidden state of an [LSTM](crate::nn::Lstm) unrolled
+    /// manually, so that the same features are dropped at every step.
+    ///
+    /// The mask is filled with ones during inference.
+    pub fn mask<B: Backend, const D: usize, S: Into<Shape<D>>>(
+        &self,
+        shape: S,
+        device: &B::Device,
+    ) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return Tensor::ones(shape, device);
+        }
+
+        let prob_keep = 1.0 - self.prob;
+        let mask = Tensor::random(shape, Distribution::Bernoulli(prob_keep), device);
+
+        mask.div_scalar(prob_keep)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_share_the_mask_over_time() {
+        let tensor =
+            Tensor::<TestAutodiffBackend, 3>::ones(Shape::new([8, 10, 100]), &Default::default());
+        let dropout = VariationalDropoutConfig::new(0.5).init();
+
+        let output = dropout.forward(tensor.clone());
+
+        assert_ne!(tensor.to_data(), output.to_data());
+        let first_step = output.clone().narrow(1, 0, 1).repeat(1, 10);
+        assert_eq!(first_step.to_data(), output.to_data());
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<TestBackend, 3>::ones(Shape::new([8, 10, 100]), &Default::default());
+        let dropout = VariationalDropoutConfig::new(0.5).init();
+
+        let output = dropout.forward(tensor.clone());
+        let mask = dropout.mask::<TestBackend, 2, _>([8, 100], &Default::default());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+        assert_eq!(
+            mask.to_data(),
+            Tensor::<TestBackend, 2>::ones([8, 100], &Default::default()).to_data()
+        );
+    }
+}
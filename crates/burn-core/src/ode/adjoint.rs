@@ -0,0 +1,174 @@
+use super::{OdeFunction, OdeSolver};
+use crate::module::AutodiffModule;
+use crate::optim::{GradientsAccumulator, GradientsParams};
+use crate::tensor::backend::AutodiffBackend;
+use crate::tensor::Tensor;
+
+/// The weights and the time offsets of the stages of the classic Runge-Kutta method.
+const RK4: [(f64, f64); 4] = [
+    (1.0 / 6.0, 0.0),
+    (1.0 / 3.0, 0.5),
+    (1.0 / 3.0, 0.5),
+    (1.0 / 6.0, 1.0),
+];
+
+impl OdeSolver {
+    /// Computes the gradients of a loss with respect to the initial state and the parameters of
+    /// the dynamics with the [adjoint method](https://arxiv.org/abs/1806.07366).
+    ///
+    /// The state and the adjoint state are integrated backward in time from the final state,
+    /// with the classic Runge-Kutta method and the step size of the solver, instead of
+    /// backpropagating through the steps of the forward solution. The memory used doesn't grow
+    /// with the number of steps, so the forward solution is usually computed without gradients,
+    /// e.g. with the [inner module](AutodiffModule::valid).
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The dynamics.
+    /// * `state` - The final state, at the end time.
+    /// * `grad` - The gradient of the loss with respect to the final state.
+    /// * `start` - The start time of the forward solution.
+    /// * `end` - The end time of the forward solution.
+    ///
+    /// # Returns
+    ///
+    /// The gradient of the loss with respect to the initial state, and the gradients of the
+    /// parameters of the module.
+    pub fn adjoint<B, const D: usize, M>(
+        &self,
+        module: &M,
+        state: Tensor<B::InnerBackend, D>,
+        grad: Tensor<B::InnerBackend, D>,
+        start: f64,
+        end: f64,
+    ) -> (Tensor<B::InnerBackend, D>, GradientsParams)
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B> + OdeFunction<B, D>,
+    {
+        let num_steps = self.num_fixed_steps(start, end);
+        let step = (start - end) / num_steps as f64;
+        let mut accumulator = GradientsAccumulator::<M>::new();
+        let mut state = state;
+        let mut adjoint = grad;
+
+        for i in 0..num_steps {
+            let time = end + i as f64 * step;
+            let mut state_next = state.clone();
+            let mut adjoint_next = adjoint.clone();
+            let mut stage: Option<(Tensor<B::InnerBackend, D>, Tensor<B::InnerBackend, D>)> = None;
+
+            for (weight, offset) in RK4 {
+                let (state_stage, adjoint_stage) = match stage {
+                    Some((derivative, adjoint_derivative)) => (
+                        state.clone() + derivative.mul_scalar(offset * step),
+                        adjoint.clone() + adjoint_derivative.mul_scalar(offset * step),
+                    ),
+                    None => (state.clone(), adjoint.clone()),
+                };
+
+                // The parameters accumulate the integral of -a^T df/dp over the step, which is
+                // backward in time.
+                let (derivative, vjp) = vector_jacobian_product(
+                    module,
+                    time + offset * step,
+                    state_stage,
+                    adjoint_stage,
+                    -weight * step,
+                    &mut accumulator,
+                );
+                let adjoint_derivative = vjp.neg();
+
+                state_next = state_next + derivative.clone().mul_scalar(weight * step);
+                adjoint_next = adjoint_next + adjoint_derivative.clone().mul_scalar(weight * step);
+                stage = Some((derivative, adjoint_derivative));
+            }
+
+            state = state_next;
+            adjoint = adjoint_next;
+        }
+
+        (adjoint, accumulator.grads())
+    }
+}
+
+/// Evaluates the dynamics and the product of the adjoint state with their Jacobian with respect
+/// to the state, and accumulates the product with their Jacobian with respect to the parameters
+/// scaled by the weight.
+fn vector_jacobian_product<B, const D: usize, M>(
+    module: &M,
+    time: f64,
+    state: Tensor<B::InnerBackend, D>,
+    adjoint: Tensor<B::InnerBackend, D>,
+    weight: f64,
+    accumulator: &mut GradientsAccumulator<M>,
+) -> (Tensor<B::InnerBackend, D>, Tensor<B::InnerBackend, D>)
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B> + OdeFunction<B, D>,
+{
+    let state = Tensor::<B, D>::from_inner(state).require_grad();
+    let derivative = module.derivative(time, state.clone());
+    let adjoint_weighted = Tensor::from_inner(adjoint.clone().mul_scalar(weight));
+    let grads = (derivative.clone() * adjoint_weighted).sum().backward();
+
+    // The dynamics may not depend on the state.
+    let vjp = match state.grad(&grads) {
+        Some(grad) => grad.div_scalar(weight),
+        None => adjoint.zeros_like(),
+    };
+    accumulator.accumulate(module, GradientsParams::from_grads(grads, module));
+
+    (derivative.inner(), vjp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::{Module, Param};
+    use crate::ode::{OdeMethod, OdeSolverConfig};
+    use crate::tensor::backend::Backend;
+    use crate::TestAutodiffBackend;
+    use burn_tensor::Data;
+
+    #[derive(Module, Debug)]
+    struct Growth<B: Backend> {
+        rate: Param<Tensor<B, 1>>,
+    }
+
+    impl<B: Backend> OdeFunction<B, 1> for Growth<B> {
+        fn derivative(&self, _time: f64, state: Tensor<B, 1>) -> Tensor<B, 1> {
+            state * self.rate.val()
+        }
+    }
+
+    #[test]
+    fn should_compute_the_gradients_with_the_adjoint_method() {
+        let device = Default::default();
+        let module = Growth::<TestAutodiffBackend> {
+            rate: Param::from_tensor(Tensor::from_floats([0.5], &device)),
+        };
+        let solver = OdeSolverConfig::new()
+            .with_method(OdeMethod::Rk4)
+            .with_step_size(0.01)
+            .init();
+        let initial = Tensor::from_floats([2.0], &device);
+
+        let state = solver.integrate(&module.valid(), initial, 0.0, 1.0);
+        let (grad_initial, mut grads) =
+            solver.adjoint(&module, state, Tensor::ones([1], &device), 0.0, 1.0);
+
+        // The final state is y0 exp(rate t).
+        let exp = 0.5f32.exp();
+        grad_initial
+            .into_data()
+            .assert_approx_eq(&Data::from([exp]), 3);
+        let grad_rate = grads
+            .remove::<<TestAutodiffBackend as AutodiffBackend>::InnerBackend, 1>(&module.rate.id)
+            .unwrap();
+        grad_rate
+            .into_data()
+            .assert_approx_eq(&Data::from([2.0 * exp]), 3);
+    }
+}
@@ -0,0 +1,4 @@
+mod adjoint;
+mod solver;
+
+pub use solver::*;
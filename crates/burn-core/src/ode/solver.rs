@@ -0,0 +1,396 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::tensor::backend::Backend;
+use crate::tensor::{ElementConversion, Tensor};
+
+/// The coefficients of the stages of the Dormand-Prince method.
+const DORMAND_PRINCE: [(f64, &[f64]); 6] = [
+    (1.0 / 5.0, &[1.0 / 5.0]),
+    (3.0 / 10.0, &[3.0 / 40.0, 9.0 / 40.0]),
+    (4.0 / 5.0, &[44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0]),
+    (
+        8.0 / 9.0,
+        &[
+            19372.0 / 6561.0,
+            -25360.0 / 2187.0,
+            64448.0 / 6561.0,
+            -212.0 / 729.0,
+        ],
+    ),
+    (
+        1.0,
+        &[
+            9017.0 / 3168.0,
+            -355.0 / 33.0,
+            46732.0 / 5247.0,
+            49.0 / 176.0,
+            -5103.0 / 18656.0,
+        ],
+    ),
+    (
+        1.0,
+        &[
+            35.0 / 384.0,
+            0.0,
+            500.0 / 1113.0,
+            125.0 / 192.0,
+            -2187.0 / 6784.0,
+            11.0 / 84.0,
+        ],
+    ),
+];
+
+/// The difference between the fifth and fourth order solutions of the Dormand-Prince method.
+const DORMAND_PRINCE_ERROR: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+/// The dynamics of an ordinary differential equation `dy/dt = f(t, y)`, such as a
+/// [module](crate::module::Module) for neural ODEs.
+///
+/// It is implemented for the closures taking the time and the state.
+pub trait OdeFunction<B: Backend, const D: usize> {
+    /// Computes the derivative of the state at the given time.
+    fn derivative(&self, time: f64, state: Tensor<B, D>) -> Tensor<B, D>;
+}
+
+impl<B, const D: usize, F> OdeFunction<B, D> for F
+where
+    B: Backend,
+    F: Fn(f64, Tensor<B, D>) -> Tensor<B, D>,
+{
+    fn derivative(&self, time: f64, state: Tensor<B, D>) -> Tensor<B, D> {
+        self(time, state)
+    }
+}
+
+/// The integration method of an [ODE solver](OdeSolver).
+#[derive(Config, Debug, PartialEq, Eq)]
+pub enum OdeMethod {
+    /// The explicit Euler method, of order 1 with fixed steps.
+    Euler,
+    /// The classic Runge-Kutta method, of order 4 with fixed steps.
+    Rk4,
+    /// The [Dormand-Prince](https://en.wikipedia.org/wiki/Dormand%E2%80%93Prince_method) method,
+    /// of order 5 with adaptive steps.
+    DormandPrince,
+}
+
+/// Configuration to create an [ODE solver](OdeSolver) using the [init function](OdeSolverConfig::init).
+#[derive(Config, Debug)]
+pub struct OdeSolverConfig {
+    /// The integration method.
+    #[config(default = "OdeMethod::DormandPrince")]
+    pub method: OdeMethod,
+    /// The step size of the fixed-step methods, and the initial step size of the adaptive ones.
+    #[config(default = 0.1)]
+    pub step_size: f64,
+    /// The relative tolerance of the local error of the adaptive methods.
+    #[config(default = 1e-5)]
+    pub rtol: f64,
+    /// The absolute tolerance of the local error of the adaptive methods.
+    #[config(default = 1e-7)]
+    pub atol: f64,
+    /// The maximum number of steps between two times, after which the solver panics.
+    #[config(default = 10000)]
+    pub max_steps: usize,
+}
+
+/// Integrates ordinary differential equations, e.g. the dynamics of
+/// [neural ODEs](https://arxiv.org/abs/1806.07366).
+///
+/// The solutions are differentiable by backpropagating through the steps of the solver, or with
+/// the [adjoint method](OdeSolver::adjoint), which doesn't keep the steps in memory.
+///
+/// Should be created with [OdeSolverConfig].
+#[derive(Debug, Clone)]
+pub struct OdeSolver {
+    pub(crate) method: OdeMethod,
+    pub(crate) step_size: f64,
+    rtol: f64,
+    atol: f64,
+    max_steps: usize,
+}
+
+impl OdeSolverConfig {
+    /// Initialize a new [ODE solver](OdeSolver).
+    pub fn init(&self) -> OdeSolver {
+        assert!(self.step_size > 0.0, "The step size should be positive.");
+
+        OdeSolver {
+            method: self.method.clone(),
+            step_size: self.step_size,
+            rtol: self.rtol,
+            atol: self.atol,
+            max_steps: self.max_steps,
+        }
+    }
+}
+
+impl OdeSolver {
+    /// Solves the initial value problem, from the initial state at the first time.
+    ///
+    /// The times are either increasing or decreasing, the solution going backward in time in the
+    /// latter case.
+    ///
+    /// # Returns
+    ///
+    /// The state at each time, the first one being the initial state.
+    pub fn solve<B: Backend, const D: usize, F: OdeFunction<B, D>>(
+        &self,
+        function: &F,
+        state: Tensor<B, D>,
+        times: &[f64],
+    ) -> Vec<Tensor<B, D>> {
+        let mut states = vec![state];
+
+        for window in times.windows(2) {
+            let state = states.last().unwrap().clone();
+            states.push(self.integrate(function, state, window[0], window[1]));
+        }
+
+        states
+    }
+
+    /// Integrates the state from the start to the end time.
+    pub fn integrate<B: Backend, const D: usize, F: OdeFunction<B, D>>(
+        &self,
+        function: &F,
+        state: Tensor<B, D>,
+        start: f64,
+        end: f64,
+    ) -> Tensor<B, D> {
+        if start == end {
+            return state;
+        }
+
+        match self.method {
+            OdeMethod::Euler | OdeMethod::Rk4 => {
+                let num_steps = self.num_fixed_steps(start, end);
+                let step = (end - start) / num_steps as f64;
+
+                (0..num_steps).fold(state, |state, i| {
+                    let time = start + i as f64 * step;
+                    match self.method {
+                        OdeMethod::Euler => euler_step(function, state, time, step),
+                        _ => rk4_step(function, state, time, step),
+                    }
+                })
+            }
+            OdeMethod::DormandPrince => self.integrate_adaptive(function, state, start, end),
+        }
+    }
+
+    /// The number of steps of the fixed-step methods between two times.
+    pub(crate) fn num_fixed_steps(&self, start: f64, end: f64) -> usize {
+        let num_steps = ((end - start).abs() / self.step_size).ceil() as usize;
+        assert!(
+            num_steps <= self.max_steps,
+            "The integration from {start} to {end} requires more than {} steps.",
+            self.max_steps
+        );
+
+        num_steps.max(1)
+    }
+
+    fn integrate_adaptive<B: Backend, const D: usize, F: OdeFunction<B, D>>(
+        &self,
+        function: &F,
+        mut state: Tensor<B, D>,
+        start: f64,
+        end: f64,
+    ) -> Tensor<B, D> {
+        let direction = (end - start).signum();
+        let mut time = start;
+        let mut step = self.step_size.min((end - start).abs()) * direction;
+        let mut derivative = function.derivative(time, state.clone());
+
+        for _ in 0..self.max_steps {
+            // The last step ends at the end time.
+            let remaining = end - time;
+            let last = step.abs() >= remaining.abs();
+            if last {
+                step = remaining;
+            }
+
+            // The last stage is the derivative at the fifth order solution, which is also the
+            // first stage of the next step.
+            let mut stages = vec![derivative.clone()];
+            let mut next = state.clone();
+            for (fraction, coefficients) in DORMAND_PRINCE.iter() {
+                next = state.clone() + weighted_sum(&stages, coefficients, step);
+                stages.push(function.derivative(time + fraction * step, next.clone()));
+            }
+            let error = weighted_sum(&stages, &DORMAND_PRINCE_ERROR, step);
+
+            let error = self.error_norm(state.clone(), next.clone(), error);
+            let factor = match error > 0.0 {
+                true => (0.9 * error.powf(-0.2)).clamp(0.2, 5.0),
+                false => 5.0,
+            };
+
+            if error <= 1.0 {
+                if last {
+                    return next;
+                }
+                time += step;
+                state = next;
+                derivative = stages.pop().unwrap();
+            }
+
+            step *= factor;
+        }
+
+        panic!(
+            "The integration from {start} to {end} requires more than {} steps.",
+            self.max_steps
+        );
+    }
+
+    /// The root mean square of the local error relative to the tolerances, the step being
+    /// accepted if it is at most one.
+    fn error_norm<B: Backend, const D: usize>(
+        &self,
+        state: Tensor<B, D>,
+        next: Tensor<B, D>,
+        error: Tensor<B, D>,
+    ) -> f64 {
+        let scale = state
+            .detach()
+            .abs()
+            .max_pair(next.detach().abs())
+            .mul_scalar(self.rtol)
+            .add_scalar(self.atol);
+
+        error
+            .detach()
+            .div(scale)
+            .powf_scalar(2.0)
+            .mean()
+            .into_scalar()
+            .elem::<f64>()
+            .sqrt()
+    }
+}
+
+fn euler_step<B: Backend, const D: usize, F: OdeFunction<B, D>>(
+    function: &F,
+    state: Tensor<B, D>,
+    time: f64,
+    step: f64,
+) -> Tensor<B, D> {
+    state.clone() + function.derivative(time, state).mul_scalar(step)
+}
+
+pub(crate) fn rk4_step<B: Backend, const D: usize, F: OdeFunction<B, D>>(
+    function: &F,
+    state: Tensor<B, D>,
+    time: f64,
+    step: f64,
+) -> Tensor<B, D> {
+    let k1 = function.derivative(time, state.clone());
+    let k2 = function.derivative(
+        time + step / 2.0,
+        state.clone() + k1.clone().mul_scalar(step / 2.0),
+    );
+    let k3 = function.derivative(
+        time + step / 2.0,
+        state.clone() + k2.clone().mul_scalar(step / 2.0),
+    );
+    let k4 = function.derivative(time + step, state.clone() + k3.clone().mul_scalar(step));
+
+    state
+        + weighted_sum(
+            &[k1, k2, k3, k4],
+            &[1.0 / 6.0, 1.0 / 3.0, 1.0 / 3.0, 1.0 / 6.0],
+            step,
+        )
+}
+
+/// The sum of the stages weighted by the coefficients, times the step.
+fn weighted_sum<B: Backend, const D: usize>(
+    stages: &[Tensor<B, D>],
+    coefficients: &[f64],
+    step: f64,
+) -> Tensor<B, D> {
+    stages
+        .iter()
+        .zip(coefficients)
+        .filter(|(_, coefficient)| **coefficient != 0.0)
+        .map(|(stage, coefficient)| stage.clone().mul_scalar(coefficient * step))
+        .reduce(|sum, term| sum + term)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    fn decay(_time: f64, state: Tensor<TestBackend, 1>) -> Tensor<TestBackend, 1> {
+        state.neg()
+    }
+
+    #[test]
+    fn should_solve_the_exponential_decay_with_each_method() {
+        let device = Default::default();
+        let expected = Data::from([(-1.0f32).exp(), 2.0 * (-1.0f32).exp()]);
+
+        for (method, precision) in [
+            (OdeMethod::Euler, 1),
+            (OdeMethod::Rk4, 4),
+            (OdeMethod::DormandPrince, 4),
+        ] {
+            let solver = OdeSolverConfig::new()
+                .with_method(method)
+                .with_step_size(0.01)
+                .init();
+            let state = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &device);
+
+            let states = solver.solve(&decay, state, &[0.0, 0.5, 1.0]);
+
+            assert_eq!(states.len(), 3);
+            states[2]
+                .clone()
+                .into_data()
+                .assert_approx_eq(&expected, precision);
+        }
+    }
+
+    #[test]
+    fn should_solve_backward_in_time() {
+        let device = Default::default();
+        let solver = OdeSolverConfig::new().init();
+        let state = Tensor::<TestBackend, 1>::from_floats([(-2.0f32).exp()], &device);
+
+        let state = solver.integrate(&decay, state, 2.0, 0.0);
+
+        state.into_data().assert_approx_eq(&Data::from([1.0]), 4);
+    }
+
+    #[test]
+    fn should_solve_the_harmonic_oscillator_with_adaptive_steps() {
+        let device = Default::default();
+        let solver = OdeSolverConfig::new().with_step_size(1.0).init();
+        let oscillator = |_time: f64, state: Tensor<TestBackend, 2>| {
+            let position = state.clone().narrow(1, 0, 1);
+            let velocity = state.narrow(1, 1, 1);
+            Tensor::cat(vec![velocity, position.neg()], 1)
+        };
+        let state = Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0]], &device);
+
+        let state = solver.integrate(&oscillator, state, 0.0, 3.0);
+
+        state
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0f32.cos(), -3.0f32.sin()]]), 3);
+    }
+}
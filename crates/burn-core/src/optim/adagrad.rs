@@ -9,6 +9,7 @@ use super::{
 };
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::{GradientNoiseConfig, LayerLrDecayConfig};
 use crate::tensor::{backend::AutodiffBackend, Tensor};
 use burn_tensor::backend::Backend;
 
@@ -23,6 +24,10 @@ pub struct AdaGradConfig {
     weight_decay: Option<WeightDecayConfig>,
     /// [Gradient Clipping](GradientClippingConfig) config.
     grad_clipping: Option<GradientClippingConfig>,
+    /// [Gradient Noise](GradientNoiseConfig) config.
+    grad_noise: Option<GradientNoiseConfig>,
+    /// [Layer-wise learning rate decay](LayerLrDecayConfig) config.
+    layer_lr_decay: Option<LayerLrDecayConfig>,
 }
 
 /// AdaGrad optimizer
@@ -95,6 +100,12 @@ impl AdaGradConfig {
         if let Some(config) = &self.grad_clipping {
             optim = optim.with_grad_clipping(config.init());
         }
+        if let Some(config) = &self.grad_noise {
+            optim = optim.with_grad_noise(config.init());
+        }
+        if let Some(config) = &self.layer_lr_decay {
+            optim = optim.with_layer_lr_decay(config.init());
+        }
         optim
     }
 }
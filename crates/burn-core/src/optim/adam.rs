@@ -9,6 +9,7 @@ use super::{
 };
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::{GradientNoiseConfig, LayerLrDecayConfig};
 use crate::tensor::{backend::AutodiffBackend, Tensor};
 use burn_tensor::{backend::Backend, ElementConversion};
 
@@ -28,6 +29,10 @@ pub struct AdamConfig {
     weight_decay: Option<WeightDecayConfig>,
     /// [Gradient Clipping](GradientClippingConfig) config.
     grad_clipping: Option<GradientClippingConfig>,
+    /// [Gradient Noise](GradientNoiseConfig) config.
+    grad_noise: Option<GradientNoiseConfig>,
+    /// [Layer-wise learning rate decay](LayerLrDecayConfig) config.
+    layer_lr_decay: Option<LayerLrDecayConfig>,
 }
 
 /// Adam optimizer as described in the paper [Adam: A Method for Stochastic Optimization](https://arxiv.org/pdf/1412.6980.pdf).
@@ -102,6 +107,12 @@ impl AdamConfig {
         if let Some(config) = &self.grad_clipping {
             optim = optim.with_grad_clipping(config.init());
         }
+        if let Some(config) = &self.grad_noise {
+            optim = optim.with_grad_noise(config.init());
+        }
+        if let Some(config) = &self.layer_lr_decay {
+            optim = optim.with_layer_lr_decay(config.init());
+        }
         optim
     }
 }
@@ -7,6 +7,7 @@ use std::marker::PhantomData;
 use super::SimpleOptimizer;
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::{GradientNoiseConfig, LayerLrDecayConfig};
 use crate::tensor::{backend::AutodiffBackend, Tensor};
 use burn_tensor::{backend::Backend, ElementConversion};
 
@@ -27,6 +28,10 @@ pub struct AdamWConfig {
     weight_decay: f32,
     /// [Gradient Clipping](GradientClippingConfig) config.
     grad_clipping: Option<GradientClippingConfig>,
+    /// [Gradient Noise](GradientNoiseConfig) config.
+    grad_noise: Option<GradientNoiseConfig>,
+    /// [Layer-wise learning rate decay](LayerLrDecayConfig) config.
+    layer_lr_decay: Option<LayerLrDecayConfig>,
 }
 
 /// AdamW optimizer as described in the paper [Decoupled Weight Decay Regularization, Loshchilov and Hutter, 2019](https://arxiv.org/abs/1711.05101).
@@ -101,6 +106,12 @@ impl AdamWConfig {
         if let Some(config) = &self.grad_clipping {
             optim = optim.with_grad_clipping(config.init());
         }
+        if let Some(config) = &self.grad_noise {
+            optim = optim.with_grad_noise(config.init());
+        }
+        if let Some(config) = &self.layer_lr_decay {
+            optim = optim.with_layer_lr_decay(config.init());
+        }
         optim
     }
 }
@@ -0,0 +1,82 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::tensor::{Distribution, Tensor};
+use burn_tensor::backend::Backend;
+
+/// Configuration to create [gradient noise](GradientNoise).
+#[derive(Config)]
+pub struct GradientNoiseConfig {
+    /// The initial variance of the noise.
+    #[config(default = 0.01)]
+    pub eta: f64,
+    /// How fast the variance decays with the number of steps.
+    #[config(default = 0.55)]
+    pub gamma: f64,
+}
+
+impl GradientNoiseConfig {
+    /// Initialize the gradient noise.
+    pub fn init(&self) -> GradientNoise {
+        GradientNoise {
+            eta: self.eta,
+            gamma: self.gamma,
+        }
+    }
+}
+
+/// Add gaussian noise to the gradients, as described in the paper
+/// [Adding Gradient Noise Improves Learning for Very Deep Networks](https://arxiv.org/abs/1511.06807).
+///
+/// The variance of the noise at step `t` is `eta / (1 + t)^gamma`, so the noise helps exploring
+/// early in training and vanishes as it goes on.
+#[derive(Clone)]
+pub struct GradientNoise {
+    eta: f64,
+    gamma: f64,
+}
+
+impl GradientNoise {
+    /// The standard deviation of the noise at the given step, starting at 0.
+    pub fn std(&self, step: usize) -> f64 {
+        (self.eta / (1.0 + step as f64).powf(self.gamma)).sqrt()
+    }
+
+    /// Add noise to the gradient.
+    ///
+    /// # Arguments
+    ///
+    /// * `grad` - The gradient.
+    /// * `step` - The optimizer step, starting at 0.
+    ///
+    /// # Returns
+    ///
+    /// The noisy gradient.
+    pub fn add_noise<B: Backend, const D: usize>(
+        &self,
+        grad: Tensor<B, D>,
+        step: usize,
+    ) -> Tensor<B, D> {
+        let noise = Tensor::random(
+            grad.shape(),
+            Distribution::Normal(0.0, self.std(step)),
+            &grad.device(),
+        );
+
+        grad + noise
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_noise_std_decays_with_steps() {
+        let noise = GradientNoiseConfig::new().with_eta(0.3).init();
+
+        assert!((noise.std(0) - 0.3f64.sqrt()).abs() < 1e-12);
+        assert!((noise.std(9) - (0.3 / 10f64.powf(0.55)).sqrt()).abs() < 1e-12);
+        assert!(noise.std(100) < noise.std(10));
+    }
+}
@@ -0,0 +1,265 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, ModuleVisitor, ParamId};
+use crate::tensor::Tensor;
+use crate::LearningRate;
+use alloc::string::{String, ToString};
+use alloc::{vec, vec::Vec};
+use burn_tensor::backend::Backend;
+use hashbrown::HashMap;
+
+/// Configuration to create [layer-wise learning rate decay](LayerLrDecay), which is part of the
+/// configuration of every optimizer.
+///
+/// The layers are given by their paths in the optimized module, from the input to the output,
+/// and are registered on the first step of the optimizer. A path ending with `.*` registers each
+/// element of a vector or an array of layers, e.g. `["embedding", "blocks.*"]` for the embedding
+/// followed by each block of a transformer.
+#[derive(Config)]
+pub struct LayerLrDecayConfig {
+    /// The factor applied to the learning rate for each layer below the output.
+    #[config(default = 0.75)]
+    pub decay: f64,
+    /// The paths of the layers, from the input to the output.
+    #[config(default = "Vec::new()")]
+    pub layers: Vec<String>,
+}
+
+impl LayerLrDecayConfig {
+    /// Initialize the layer-wise learning rate decay, whose layers are registered from their
+    /// paths on the first step of the optimizer.
+    pub fn init(&self) -> LayerLrDecay {
+        LayerLrDecay {
+            decay: self.decay,
+            depths: HashMap::new(),
+            num_layers: 0,
+            paths: self.layers.clone(),
+        }
+    }
+}
+
+/// Scale the learning rate of each parameter by its depth in the model, as commonly done when
+/// fine-tuning transformers (e.g. ViT, BERT).
+///
+/// Layers are registered from the input to the output, with their [paths](LayerLrDecayConfig)
+/// or with [with_layer](LayerLrDecay::with_layer). With `n` registered layers, the learning rate of the parameters of the layer at depth `d` (starting at 0)
+/// is multiplied by `decay^(n - d)`, while parameters that aren't part of any registered layer (e.g.
+/// the classification head) use the full learning rate.
+#[derive(Clone)]
+pub struct LayerLrDecay {
+    decay: f64,
+    depths: HashMap<ParamId, usize>,
+    num_layers: usize,
+    paths: Vec<String>,
+}
+
+impl LayerLrDecay {
+    /// Register the parameters of the next layer, e.g. the embeddings followed by each block.
+    pub fn with_layer<B: Backend, M: Module<B>>(mut self, layer: &M) -> Self {
+        let mut visitor = ParamIdCollector {
+            depth: self.num_layers,
+            depths: &mut self.depths,
+        };
+        layer.visit(&mut visitor);
+        self.num_layers += 1;
+        self
+    }
+
+    /// Register the parameters of multiple layers, in order.
+    pub fn with_layers<'a, B: Backend, M: Module<B> + 'a, I: IntoIterator<Item = &'a M>>(
+        self,
+        layers: I,
+    ) -> Self {
+        layers
+            .into_iter()
+            .fold(self, |decay, layer| decay.with_layer(layer))
+    }
+
+    /// Register the layers of the module at the configured paths, before the layers registered
+    /// with [with_layer](LayerLrDecay::with_layer).
+    ///
+    /// # Panics
+    ///
+    /// If a path doesn't match any parameter of the module.
+    pub fn register_paths<B: Backend, M: Module<B>>(&mut self, module: &M) {
+        if self.paths.is_empty() {
+            return;
+        }
+
+        let mut collector = ParamPathCollector::default();
+        module.visit(&mut collector);
+
+        let mut layers: Vec<Vec<ParamId>> = Vec::new();
+        for path in core::mem::take(&mut self.paths) {
+            let num_layers = layers.len();
+
+            match path.strip_suffix(".*") {
+                Some(parent) => {
+                    // The children of the parent, in the order they are visited.
+                    let mut children: Vec<(String, Vec<ParamId>)> = Vec::new();
+                    for (param_path, id) in collector.params.iter() {
+                        let child = match strip_path_prefix(param_path, parent) {
+                            Some(rest) => rest.split('.').next().unwrap_or(rest),
+                            None => continue,
+                        };
+                        match children.iter_mut().find(|(name, _)| name == child) {
+                            Some((_, ids)) => ids.push(id.clone()),
+                            None => children.push((child.to_string(), vec![id.clone()])),
+                        }
+                    }
+                    layers.extend(children.into_iter().map(|(_, ids)| ids));
+                }
+                None => {
+                    let ids = collector
+                        .params
+                        .iter()
+                        .filter(|(param_path, _)| strip_path_prefix(param_path, &path).is_some())
+                        .map(|(_, id)| id.clone())
+                        .collect::<Vec<_>>();
+                    if !ids.is_empty() {
+                        layers.push(ids);
+                    }
+                }
+            }
+
+            assert!(
+                layers.len() > num_layers,
+                "The layer path `{path}` doesn't match any parameter of the module."
+            );
+        }
+
+        // The layers registered with their paths come before the other ones.
+        let num_paths = layers.len();
+        for depth in self.depths.values_mut() {
+            *depth += num_paths;
+        }
+        for (depth, ids) in layers.into_iter().enumerate() {
+            for id in ids {
+                self.depths.insert(id, depth);
+            }
+        }
+        self.num_layers += num_paths;
+    }
+
+    /// The learning rate of the given parameter.
+    pub fn lr(&self, id: &ParamId, lr: LearningRate) -> LearningRate {
+        match self.depths.get(id) {
+            Some(depth) => lr * self.decay.powi((self.num_layers - depth) as i32),
+            None => lr,
+        }
+    }
+}
+
+/// The rest of the path of a parameter after the given module path, if the parameter is part of
+/// the module.
+fn strip_path_prefix<'a>(param_path: &'a str, path: &str) -> Option<&'a str> {
+    if param_path == path {
+        return Some("");
+    }
+
+    param_path.strip_prefix(path)?.strip_prefix('.')
+}
+
+/// Collect the paths of the parameters, with the names of the fields separated by dots.
+#[derive(Default)]
+struct ParamPathCollector {
+    stack: Vec<String>,
+    params: Vec<(String, ParamId)>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamPathCollector {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        self.params.push((self.stack.join("."), id.clone()));
+    }
+
+    fn enter_module(&mut self, name: &str, _container_type: &str) {
+        self.stack.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.stack.pop();
+    }
+}
+
+struct ParamIdCollector<'a> {
+    depth: usize,
+    depths: &'a mut HashMap<ParamId, usize>,
+}
+
+impl<'a, B: Backend> ModuleVisitor<B> for ParamIdCollector<'a> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        self.depths.insert(id.clone(), self.depth);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::TestBackend;
+
+    #[test]
+    fn test_lr_decays_with_depth() {
+        let device = Default::default();
+        let layers: Vec<Linear<TestBackend>> = (0..2)
+            .map(|_| LinearConfig::new(4, 4).init(&device))
+            .collect();
+        let head: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device);
+
+        let decay = LayerLrDecayConfig::new()
+            .with_decay(0.5)
+            .init()
+            .with_layers(layers.iter());
+
+        assert_eq!(decay.lr(&layers[0].weight.id, 1.0), 0.25);
+        assert_eq!(decay.lr(&layers[1].weight.id, 1.0), 0.5);
+        assert_eq!(decay.lr(&head.weight.id, 1.0), 1.0);
+    }
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        embedding: Linear<B>,
+        blocks: Vec<Linear<B>>,
+        head: Linear<B>,
+    }
+
+    #[test]
+    fn test_layers_are_registered_from_their_paths() {
+        let device = Default::default();
+        let model: Model<TestBackend> = Model {
+            embedding: LinearConfig::new(4, 4).init(&device),
+            blocks: (0..2)
+                .map(|_| LinearConfig::new(4, 4).init(&device))
+                .collect(),
+            head: LinearConfig::new(4, 2).init(&device),
+        };
+
+        let mut decay = LayerLrDecayConfig::new()
+            .with_decay(0.5)
+            .with_layers(vec!["embedding".to_string(), "blocks.*".to_string()])
+            .init();
+        decay.register_paths(&model);
+
+        assert_eq!(decay.lr(&model.embedding.weight.id, 1.0), 0.125);
+        assert_eq!(
+            decay.lr(&model.embedding.bias.as_ref().unwrap().id, 1.0),
+            0.125
+        );
+        assert_eq!(decay.lr(&model.blocks[0].weight.id, 1.0), 0.25);
+        assert_eq!(decay.lr(&model.blocks[1].weight.id, 1.0), 0.5);
+        assert_eq!(decay.lr(&model.head.weight.id, 1.0), 1.0);
+    }
+
+    #[test]
+    #[should_panic = "The layer path `encoder` doesn't match any parameter of the module."]
+    fn test_unknown_layer_path_should_panic() {
+        let device = Default::default();
+        let layer: Linear<TestBackend> = LinearConfig::new(4, 4).init(&device);
+
+        LayerLrDecayConfig::new()
+            .with_layers(vec!["encoder".to_string()])
+            .init()
+            .register_paths(&layer);
+    }
+}
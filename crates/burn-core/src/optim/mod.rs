@@ -9,9 +9,15 @@ mod adam;
 mod adamw;
 mod base;
 mod grad_accum;
+mod grad_noise;
 mod grads;
+mod layer_decay;
+mod offload;
+mod per_sample;
+mod privacy;
 mod rmsprop;
 mod sgd;
+mod sharded;
 mod simple;
 mod visitor;
 
@@ -20,7 +26,13 @@ pub use adam::*;
 pub use adamw::*;
 pub use base::*;
 pub use grad_accum::*;
+pub use grad_noise::*;
 pub use grads::*;
+pub use layer_decay::*;
+pub use offload::*;
+pub use per_sample::*;
+pub use privacy::*;
 pub use rmsprop::*;
 pub use sgd::*;
+pub use sharded::*;
 pub use simple::*;
@@ -0,0 +1,112 @@
+use crate::module::{Module, ModuleVisitor, ParamId};
+use crate::tensor::Tensor;
+use burn_tensor::backend::Backend;
+use hashbrown::HashSet;
+
+/// Selects the parameters whose optimizer states are kept in host memory between the steps,
+/// ZeRO-offload style.
+///
+/// An offloaded state is paged back to the device of its parameter when the parameter is updated,
+/// then offloaded again, so only the states of the parameter being updated use device memory.
+/// This trades the time spent copying the states for the memory of optimizers keeping large
+/// states, such as [Adam](crate::optim::Adam).
+#[derive(Clone, Debug, Default)]
+pub enum OffloadPolicy {
+    /// Keep every state on the device.
+    #[default]
+    Never,
+    /// Offload the states of every parameter.
+    All,
+    /// Offload the states of the parameters with at least the given number of elements, which
+    /// hold most of the memory.
+    MinNumElements(usize),
+    /// Offload the states of the given parameters, e.g. the ones of
+    /// [a module](OffloadPolicy::from_module).
+    Params(HashSet<ParamId>),
+}
+
+impl OffloadPolicy {
+    /// Offload the states of the parameters of the given module, e.g. the layers that are rarely
+    /// updated.
+    pub fn from_module<B: Backend, M: Module<B>>(module: &M) -> Self {
+        let mut visitor = ParamIdCollector {
+            ids: HashSet::new(),
+        };
+        module.visit(&mut visitor);
+
+        Self::Params(visitor.ids)
+    }
+
+    /// If the state of the parameter should be offloaded.
+    pub fn should_offload(&self, id: &ParamId, num_elements: usize) -> bool {
+        match self {
+            Self::Never => false,
+            Self::All => true,
+            Self::MinNumElements(min) => num_elements >= *min,
+            Self::Params(ids) => ids.contains(id),
+        }
+    }
+}
+
+struct ParamIdCollector {
+    ids: HashSet<ParamId>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamIdCollector {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        self.ids.insert(id.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::optim::{AdamConfig, GradientsParams, Optimizer};
+    use crate::tensor::Distribution;
+    use crate::{nn, TestAutodiffBackend};
+
+    #[test]
+    fn offloaded_states_should_give_the_same_updates() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init::<TestAutodiffBackend>(&device);
+        let mut optim = AdamConfig::new().init();
+        let mut optim_offloaded = AdamConfig::new().init().with_offload(OffloadPolicy::All);
+
+        let mut updated = linear.clone();
+        let mut updated_offloaded = linear;
+
+        for _ in 0..2 {
+            let x = Tensor::random([2, 6], Distribution::Default, &device);
+
+            let grads = updated.forward(x.clone()).backward();
+            let grads = GradientsParams::from_grads(grads, &updated);
+            updated = optim.step(0.01, updated, grads);
+
+            let grads = updated_offloaded.forward(x).backward();
+            let grads = GradientsParams::from_grads(grads, &updated_offloaded);
+            updated_offloaded = optim_offloaded.step(0.01, updated_offloaded, grads);
+        }
+
+        updated
+            .weight
+            .to_data()
+            .assert_approx_eq(&updated_offloaded.weight.to_data(), 5);
+        assert_eq!(optim_offloaded.to_record().len(), 2);
+    }
+
+    #[test]
+    fn should_only_offload_selected_params() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init::<TestAutodiffBackend>(&device);
+        let other = Param::from_tensor(Tensor::<TestAutodiffBackend, 1>::zeros([6], &device));
+
+        let policy = OffloadPolicy::from_module(&linear);
+        assert!(policy.should_offload(&linear.weight.id, 36));
+        assert!(!policy.should_offload(&other.id, 6));
+
+        let policy = OffloadPolicy::MinNumElements(10);
+        assert!(policy.should_offload(&linear.weight.id, 36));
+        assert!(!policy.should_offload(&other.id, 6));
+    }
+}
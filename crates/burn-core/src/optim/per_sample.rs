@@ -0,0 +1,236 @@
+use super::{GradientsAccumulator, GradientsParams};
+use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
+use crate::tensor::backend::AutodiffBackend;
+use crate::tensor::Tensor;
+
+#[cfg(feature = "vmap")]
+use crate::backend::{
+    vmap::{shared, split_examples},
+    Batched,
+};
+#[cfg(feature = "vmap")]
+use crate::tensor::backend::Backend;
+#[cfg(feature = "vmap")]
+use core::marker::PhantomData;
+
+/// Compute the gradients of each sample of a batch in a single backward pass, e.g. to
+/// [clip](ClippedGradientsAccumulator) them for differentially private training.
+///
+/// The module should be on the [batched backend](Batched), and the loss of each sample should be
+/// computed from a [batch](crate::backend::vmap::batch) of the given size, e.g. with
+/// `module.forward(batch(inputs)).sum()`, so its operations never mix the samples. Since the
+/// parameters are shared by the samples, their gradients are batched and split into the gradients
+/// of each sample.
+#[cfg(feature = "vmap")]
+pub fn per_sample_grads<I, B, M>(
+    module: &M,
+    batch_size: usize,
+    loss: Tensor<B, 1>,
+) -> Vec<GradientsParams>
+where
+    I: Backend,
+    B: AutodiffBackend<InnerBackend = Batched<I>>,
+    M: AutodiffModule<B>,
+{
+    let mut splitter = GradientsSplitter::<I, B> {
+        grads: GradientsParams::from_grads(loss.backward(), module),
+        samples: (0..batch_size).map(|_| GradientsParams::new()).collect(),
+        phantom: PhantomData,
+    };
+    module.visit(&mut splitter);
+
+    splitter.samples
+}
+
+/// Accumulate the gradients of each sample after clipping their L2 norm, computed over every
+/// parameter of the module, so that no sample contributes more than the maximum norm to the sum.
+///
+/// This is the per-sample clipping of
+/// [DP-SGD](https://arxiv.org/abs/1607.00133), whose sum should be
+/// [privatized](super::DpOptimizer) before updating the module.
+pub struct ClippedGradientsAccumulator<M> {
+    max_norm: f64,
+    accumulator: GradientsAccumulator<M>,
+    num_samples: usize,
+}
+
+impl<M> ClippedGradientsAccumulator<M> {
+    /// Create a new accumulator clipping the gradients of each sample to the given L2 norm.
+    pub fn new(max_norm: f64) -> Self {
+        Self {
+            max_norm,
+            accumulator: GradientsAccumulator::new(),
+            num_samples: 0,
+        }
+    }
+
+    /// Clip and accumulate the gradients of a sample for each parameter in the given module.
+    pub fn accumulate<B: AutodiffBackend>(&mut self, module: &M, mut grads: GradientsParams)
+    where
+        M: AutodiffModule<B>,
+    {
+        let mut norm = GradientsNorm::<B> {
+            grads: &grads,
+            squared: None,
+        };
+        module.visit(&mut norm);
+
+        if let Some(squared) = norm.squared {
+            let scale = squared
+                .sqrt()
+                .add_scalar(1e-6)
+                .recip()
+                .mul_scalar(self.max_norm)
+                .clamp_max(1.0);
+            let mut scaler = GradientsScaler::<B> {
+                grads: &mut grads,
+                scale,
+            };
+            module.visit(&mut scaler);
+        }
+
+        self.accumulator.accumulate(module, grads);
+        self.num_samples += 1;
+    }
+
+    /// The number of samples accumulated since the last [sum](ClippedGradientsAccumulator::grads).
+    pub fn num_samples(&self) -> usize {
+        self.num_samples
+    }
+
+    /// Return the sum of the clipped gradients and reset the accumulator state.
+    pub fn grads(&mut self) -> GradientsParams {
+        self.num_samples = 0;
+        self.accumulator.grads()
+    }
+}
+
+/// Sum the squares of the gradients of every parameter.
+struct GradientsNorm<'a, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    squared: Option<Tensor<B::InnerBackend, 1>>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GradientsNorm<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) else {
+            return;
+        };
+        let squared = grad.powf_scalar(2.0).sum();
+
+        self.squared = Some(match self.squared.take() {
+            Some(total) => total.add(squared),
+            None => squared,
+        });
+    }
+}
+
+/// Multiply the gradients of every parameter by a scale of shape `[1]`.
+struct GradientsScaler<'a, B: AutodiffBackend> {
+    grads: &'a mut GradientsParams,
+    scale: Tensor<B::InnerBackend, 1>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GradientsScaler<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) else {
+            return;
+        };
+        let scale = self.scale.clone().reshape([1; D]);
+
+        self.grads
+            .register::<B::InnerBackend, D>(id.clone(), grad.mul(scale));
+    }
+}
+
+/// Split the batched gradients of every parameter into the gradients of each sample.
+#[cfg(feature = "vmap")]
+struct GradientsSplitter<I: Backend, B: AutodiffBackend<InnerBackend = Batched<I>>> {
+    grads: GradientsParams,
+    samples: Vec<GradientsParams>,
+    phantom: PhantomData<(I, B)>,
+}
+
+#[cfg(feature = "vmap")]
+impl<I, B> ModuleVisitor<B> for GradientsSplitter<I, B>
+where
+    I: Backend,
+    B: AutodiffBackend<InnerBackend = Batched<I>>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.remove::<Batched<I>, D>(id) else {
+            return;
+        };
+        let grads = split_examples(grad, self.samples.len());
+
+        for (sample, grad) in self.samples.iter_mut().zip(grads) {
+            sample.register::<Batched<I>, D>(id.clone(), shared(grad));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::Data;
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    #[cfg(feature = "vmap")]
+    fn per_sample_grads_should_match_the_grads_of_each_sample() {
+        use crate::backend::vmap::batch;
+        use crate::tensor::Float;
+        use crate::TestBackend;
+
+        type BatchedBackend = burn_autodiff::Autodiff<Batched<TestBackend>>;
+
+        let device = Default::default();
+        let mut linear: Linear<BatchedBackend> =
+            LinearConfig::new(2, 1).with_bias(false).init(&device);
+        linear.weight = Param::from_data([[1.0], [1.0]], &device);
+        let inputs = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let input = Tensor::from_inner(batch::<_, Float, 1, 2>(inputs));
+        let loss = linear.forward(input).powf_scalar(2.0).sum();
+        let grads = per_sample_grads(&linear, 2, loss);
+
+        // The gradient of `(x w)^2` is `2 (x w) x`, with `x w` equal to 3 and 7.
+        assert_eq!(grads.len(), 2);
+        for (grads, expected) in grads.iter().zip([[[6.0], [12.0]], [[42.0], [56.0]]]) {
+            grads
+                .get::<Batched<TestBackend>, 2>(&linear.weight.id)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq(&Data::from(expected), 3);
+        }
+    }
+
+    #[test]
+    fn should_clip_the_norm_of_each_sample() {
+        let device = Default::default();
+        let mut linear: Linear<TestAutodiffBackend> =
+            LinearConfig::new(2, 1).with_bias(false).init(&device);
+        linear.weight = Param::from_data([[1.0], [1.0]], &device);
+        let inputs =
+            Tensor::<TestAutodiffBackend, 2>::from_floats([[3.0, 4.0], [0.3, 0.4]], &device);
+
+        let mut accumulator = ClippedGradientsAccumulator::new(1.0);
+        for index in 0..2 {
+            let input = inputs.clone().narrow(0, index, 1);
+            let grads = linear.forward(input).sum().backward();
+            accumulator.accumulate(&linear, GradientsParams::from_grads(grads, &linear));
+        }
+
+        // The first gradient of norm 5 is clipped to a norm of 1, the second one of norm 0.5 is
+        // kept.
+        assert_eq!(accumulator.num_samples(), 2);
+        accumulator
+            .grads()
+            .get::<<TestAutodiffBackend as AutodiffBackend>::InnerBackend, 2>(&linear.weight.id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.9], [1.2]]), 3);
+    }
+}
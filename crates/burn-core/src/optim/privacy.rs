@@ -0,0 +1,339 @@
+use crate as burn;
+
+use core::marker::PhantomData;
+
+use super::{ClippedGradientsAccumulator, GradientsParams, Optimizer};
+use crate::config::Config;
+use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
+use crate::tensor::backend::AutodiffBackend;
+use crate::tensor::{Distribution, Tensor};
+use crate::LearningRate;
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The largest order of the Rényi divergences of the [privacy accountant](PrivacyAccountant).
+const MAX_ORDER: usize = 256;
+
+/// Configuration to create a [differentially private optimizer](DpOptimizer).
+#[derive(Config)]
+pub struct DpOptimizerConfig {
+    /// The expected number of samples of each batch, by which the noisy sum of the gradients is
+    /// divided.
+    pub batch_size: usize,
+    /// The number of samples of the training dataset, from which each batch is sampled.
+    pub dataset_size: usize,
+    /// The maximum L2 norm of the gradients of each sample.
+    #[config(default = 1.0)]
+    pub max_norm: f64,
+    /// The ratio of the standard deviation of the noise to the maximum norm of the gradients.
+    #[config(default = 1.0)]
+    pub noise_multiplier: f64,
+}
+
+impl DpOptimizerConfig {
+    /// Make the given optimizer differentially private.
+    pub fn init<O>(&self, optim: O) -> DpOptimizer<O> {
+        assert!(
+            self.batch_size > 0 && self.batch_size <= self.dataset_size,
+            "The batch size {} should be between 1 and the dataset size {}.",
+            self.batch_size,
+            self.dataset_size
+        );
+
+        DpOptimizer {
+            optim,
+            batch_size: self.batch_size,
+            max_norm: self.max_norm,
+            noise_multiplier: self.noise_multiplier,
+            accountant: PrivacyAccountant::new(
+                self.noise_multiplier,
+                self.batch_size as f64 / self.dataset_size as f64,
+            ),
+        }
+    }
+}
+
+/// Wraps an optimizer to train with [DP-SGD](https://arxiv.org/abs/1607.00133), so that the trained
+/// module is differentially private with respect to the samples of the dataset.
+///
+/// The gradients of each sample, e.g. computed in a single backward pass with
+/// [per_sample_grads](super::per_sample_grads), are clipped by the
+/// [accumulator](DpOptimizer::accumulator). On each step, Gaussian noise of standard
+/// deviation `noise_multiplier * max_norm` is added to the sum of the clipped gradients, which is
+/// then divided by the expected batch size before updating the module with the wrapped optimizer.
+///
+/// The privacy spent by the steps is tracked by the [accountant](DpOptimizer::accountant), which
+/// assumes that each sample is part of a batch with probability `batch_size / dataset_size`, e.g.
+/// with Poisson sampling.
+#[derive(Clone)]
+pub struct DpOptimizer<O> {
+    optim: O,
+    batch_size: usize,
+    max_norm: f64,
+    noise_multiplier: f64,
+    accountant: PrivacyAccountant,
+}
+
+impl<O> DpOptimizer<O> {
+    /// Create an accumulator clipping the gradients of each sample to the maximum norm, whose sum
+    /// is given to the [step](Optimizer::step).
+    pub fn accumulator<M>(&self) -> ClippedGradientsAccumulator<M> {
+        ClippedGradientsAccumulator::new(self.max_norm)
+    }
+
+    /// The accountant of the privacy spent by the steps.
+    pub fn accountant(&self) -> &PrivacyAccountant {
+        &self.accountant
+    }
+}
+
+impl<O, M, B> Optimizer<M, B> for DpOptimizer<O>
+where
+    O: Optimizer<M, B>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    /// The record of the wrapped optimizer and the number of steps recorded by the
+    /// [accountant](PrivacyAccountant), so a resumed training keeps the privacy already spent.
+    type Record = (O::Record, usize);
+
+    /// Update the module with the sum of the clipped gradients of each sample of the batch.
+    fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        let mut noise = GradientsNoise::<B> {
+            grads: &mut grads,
+            std: self.noise_multiplier * self.max_norm,
+            batch_size: self.batch_size,
+            phantom: PhantomData,
+        };
+        module.visit(&mut noise);
+        self.accountant.step();
+
+        self.optim.step(lr, module, grads)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (self.optim.to_record(), self.accountant.steps)
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        let (record, steps) = record;
+        self.optim = self.optim.load_record(record);
+        self.accountant.steps = steps;
+        self
+    }
+}
+
+/// Add the noise to the gradients of every parameter requiring gradients, including the ones
+/// without gradients, and divide them by the batch size.
+struct GradientsNoise<'a, B> {
+    grads: &'a mut GradientsParams,
+    std: f64,
+    batch_size: usize,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GradientsNoise<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if !tensor.is_require_grad() {
+            return;
+        }
+
+        let grad = self
+            .grads
+            .remove::<B::InnerBackend, D>(id)
+            .unwrap_or_else(|| Tensor::zeros(tensor.shape(), &tensor.device()));
+        let noise = Tensor::random(
+            grad.shape(),
+            Distribution::Normal(0.0, self.std),
+            &grad.device(),
+        );
+
+        self.grads.register::<B::InnerBackend, D>(
+            id.clone(),
+            grad.add(noise).div_scalar(self.batch_size as f64),
+        );
+    }
+}
+
+/// Tracks the privacy spent by the steps of [DP-SGD](DpOptimizer) with the Rényi differential
+/// privacy of the [sampled Gaussian mechanism](https://arxiv.org/abs/1908.10530).
+///
+/// The Rényi divergences of the integer orders up to 256 are composed over the steps, then
+/// converted to the `(epsilon, delta)` differential privacy of the training.
+#[derive(Clone, Debug)]
+pub struct PrivacyAccountant {
+    noise_multiplier: f64,
+    sample_rate: f64,
+    steps: usize,
+}
+
+impl PrivacyAccountant {
+    /// Create an accountant of the steps with the given noise multiplier, each sample being part
+    /// of a batch with the given probability.
+    pub fn new(noise_multiplier: f64, sample_rate: f64) -> Self {
+        assert!(
+            noise_multiplier > 0.0,
+            "The noise multiplier should be positive to provide privacy."
+        );
+        assert!(
+            (0.0..=1.0).contains(&sample_rate),
+            "The sample rate {sample_rate} should be between 0 and 1."
+        );
+
+        Self {
+            noise_multiplier,
+            sample_rate,
+            steps: 0,
+        }
+    }
+
+    /// Record a step of the training.
+    pub fn step(&mut self) {
+        self.steps += 1;
+    }
+
+    /// The number of steps recorded.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// The privacy budget `epsilon` spent by the steps, which is `(epsilon, delta)`-differentially
+    /// private for the given `delta`, e.g. smaller than the inverse of the dataset size.
+    pub fn epsilon(&self, delta: f64) -> f64 {
+        assert!(
+            delta > 0.0 && delta < 1.0,
+            "The delta {delta} should be between 0 and 1."
+        );
+
+        (2..=MAX_ORDER)
+            .map(|order| {
+                let divergence = self.steps as f64 * self.divergence(order);
+                divergence + (1.0 / delta).ln() / (order - 1) as f64
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// The Rényi divergence of a step of the given integer order, computed with the binomial
+    /// expansion of the moments of the mixture of Gaussians in log space.
+    fn divergence(&self, order: usize) -> f64 {
+        let log_rate = self.sample_rate.ln();
+        let log_complement = (1.0 - self.sample_rate).ln();
+        let variance = self.noise_multiplier * self.noise_multiplier;
+
+        // The powers of zero probabilities are zero, except the zeroth ones.
+        let log_power = |exponent: usize, log_base: f64| match exponent {
+            0 => 0.0,
+            _ => exponent as f64 * log_base,
+        };
+
+        let mut log_binomial = 0.0;
+        let log_terms: Vec<f64> = (0..=order)
+            .map(|k| {
+                if k > 0 {
+                    log_binomial += ((order - k + 1) as f64).ln() - (k as f64).ln();
+                }
+                let exponent = (k * k - k) as f64 / (2.0 * variance);
+                log_binomial
+                    + log_power(order - k, log_complement)
+                    + log_power(k, log_rate)
+                    + exponent
+            })
+            .collect();
+
+        let max = log_terms.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let log_moment = max
+            + log_terms
+                .iter()
+                .map(|term| (term - max).exp())
+                .sum::<f64>()
+                .ln();
+
+        log_moment / (order - 1) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::optim::SgdConfig;
+    use crate::tensor::Data;
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn accountant_should_match_the_gaussian_mechanism_without_sampling() {
+        let mut accountant = PrivacyAccountant::new(1.0, 1.0);
+        accountant.step();
+
+        // The divergence of order a is a / 2, the best order being 6.
+        let expected = 3.0 + 1e5f64.ln() / 5.0;
+        assert!((accountant.epsilon(1e-5) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn accountant_should_spend_less_privacy_with_sampling() {
+        let mut full = PrivacyAccountant::new(1.0, 1.0);
+        let mut sampled = PrivacyAccountant::new(1.0, 0.01);
+        for _ in 0..10 {
+            full.step();
+            sampled.step();
+        }
+
+        assert_eq!(sampled.steps(), 10);
+        assert!(sampled.epsilon(1e-5) < full.epsilon(1e-5));
+        assert!(sampled.epsilon(1e-5) > PrivacyAccountant::new(1.0, 0.01).epsilon(1e-5));
+    }
+
+    #[test]
+    fn step_should_average_the_clipped_grads_without_noise() {
+        let device = Default::default();
+        let mut linear: Linear<TestAutodiffBackend> =
+            LinearConfig::new(2, 1).with_bias(false).init(&device);
+        linear.weight = Param::from_data([[1.0], [1.0]], &device);
+        let inputs =
+            Tensor::<TestAutodiffBackend, 2>::from_floats([[3.0, 4.0], [0.3, 0.4]], &device);
+
+        let mut optim = DpOptimizerConfig::new(2, 10)
+            .with_noise_multiplier(1e-12)
+            .init(SgdConfig::new().init());
+        let mut accumulator = optim.accumulator();
+        for index in 0..2 {
+            let grads = linear
+                .forward(inputs.clone().narrow(0, index, 1))
+                .sum()
+                .backward();
+            accumulator.accumulate(&linear, GradientsParams::from_grads(grads, &linear));
+        }
+        let linear = optim.step(1.0, linear, accumulator.grads());
+
+        // The clipped gradients [0.6, 0.8] and [0.3, 0.4] are averaged.
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.55], [0.4]]), 3);
+        assert_eq!(optim.accountant().steps(), 1);
+    }
+
+    #[test]
+    fn record_should_keep_the_steps_of_the_accountant() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(2, 1).init(&device);
+        let config = DpOptimizerConfig::new(2, 10);
+        let sgd = || SgdConfig::new().init::<TestAutodiffBackend, Linear<TestAutodiffBackend>>();
+
+        let mut optim = config.init(sgd());
+        let linear = optim.step(1.0, linear, GradientsParams::new());
+        let _linear = optim.step(1.0, linear, GradientsParams::new());
+
+        let resumed = config.init(sgd()).load_record(optim.to_record());
+
+        assert_eq!(resumed.accountant().steps(), 2);
+        assert_eq!(
+            resumed.accountant().epsilon(1e-5),
+            optim.accountant().epsilon(1e-5)
+        );
+    }
+}
@@ -9,6 +9,7 @@ use super::{
 };
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::{GradientNoiseConfig, LayerLrDecayConfig};
 use crate::tensor::{backend::AutodiffBackend, Tensor};
 use burn_tensor::backend::Backend;
 
@@ -31,6 +32,10 @@ pub struct RmsPropConfig {
     weight_decay: Option<WeightDecayConfig>,
     /// [Gradient Clipping](GradientClippingConfig) config.
     grad_clipping: Option<GradientClippingConfig>,
+    /// [Gradient Noise](GradientNoiseConfig) config.
+    grad_noise: Option<GradientNoiseConfig>,
+    /// [Layer-wise learning rate decay](LayerLrDecayConfig) config.
+    layer_lr_decay: Option<LayerLrDecayConfig>,
 }
 
 impl RmsPropConfig {
@@ -57,6 +62,12 @@ impl RmsPropConfig {
         if let Some(config) = &self.grad_clipping {
             optim = optim.with_grad_clipping(config.init());
         }
+        if let Some(config) = &self.grad_noise {
+            optim = optim.with_grad_noise(config.init());
+        }
+        if let Some(config) = &self.layer_lr_decay {
+            optim = optim.with_layer_lr_decay(config.init());
+        }
 
         optim
     }
@@ -545,6 +556,8 @@ mod tests {
             weight_decay: Some(WeightDecayConfig { penalty: 0.05 }),
             momentum: 0.9,
             grad_clipping: None,
+            grad_noise: None,
+            layer_lr_decay: None,
         }
         .init()
     }
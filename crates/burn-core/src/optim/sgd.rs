@@ -7,6 +7,7 @@ use super::momentum::{Momentum, MomentumConfig, MomentumState};
 use super::SimpleOptimizer;
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::{GradientNoiseConfig, LayerLrDecayConfig};
 use crate::record::Record;
 use crate::tensor::Tensor;
 use burn_tensor::backend::{AutodiffBackend, Backend};
@@ -20,6 +21,10 @@ pub struct SgdConfig {
     momentum: Option<MomentumConfig>,
     /// [Gradient Clipping](GradientClippingConfig) config.
     gradient_clipping: Option<GradientClippingConfig>,
+    /// [Gradient Noise](GradientNoiseConfig) config.
+    grad_noise: Option<GradientNoiseConfig>,
+    /// [Layer-wise learning rate decay](LayerLrDecayConfig) config.
+    layer_lr_decay: Option<LayerLrDecayConfig>,
 }
 
 /// Optimizer that implements stochastic gradient descent with momentum.
@@ -52,6 +57,12 @@ impl SgdConfig {
         if let Some(config) = &self.gradient_clipping {
             optim = optim.with_grad_clipping(config.init());
         }
+        if let Some(config) = &self.grad_noise {
+            optim = optim.with_grad_noise(config.init());
+        }
+        if let Some(config) = &self.layer_lr_decay {
+            optim = optim.with_layer_lr_decay(config.init());
+        }
         optim
     }
 }
@@ -155,6 +166,59 @@ mod tests {
         assert_eq!(record.len(), state_restored.len());
     }
 
+    #[test]
+    fn layer_lr_decay_should_scale_the_update() {
+        let device = Default::default();
+        let layer = layer::<TestAutodiffBackend>(&device);
+        let input = random_tensor::<TestAutodiffBackend>(&device);
+        let weight = layer.weight.val();
+
+        let mut optim = SgdConfig::new().init();
+        let grads = layer.forward(input.clone()).backward();
+        let grads = GradientsParams::from_grads(grads, &layer);
+        let delta = weight.clone() - optim.step(LEARNING_RATE, layer.clone(), grads).weight.val();
+
+        let mut optim = SgdConfig::new()
+            .with_layer_lr_decay(Some(LayerLrDecayConfig::new().with_decay(0.5)))
+            .init()
+            .with_lr_decay_layer(&layer);
+        let grads = layer.forward(input).backward();
+        let grads = GradientsParams::from_grads(grads, &layer);
+        let delta_decayed = weight - optim.step(LEARNING_RATE, layer, grads).weight.val();
+
+        delta_decayed
+            .into_data()
+            .assert_approx_eq(&delta.mul_scalar(0.5).into_data(), 3);
+    }
+
+    #[test]
+    fn layer_lr_decay_should_apply_the_configured_layers() {
+        let device = Default::default();
+        let layer = layer::<TestAutodiffBackend>(&device);
+        let input = random_tensor::<TestAutodiffBackend>(&device);
+        let weight = layer.weight.val();
+
+        let mut optim = SgdConfig::new().init();
+        let grads = layer.forward(input.clone()).backward();
+        let grads = GradientsParams::from_grads(grads, &layer);
+        let delta = weight.clone() - optim.step(LEARNING_RATE, layer.clone(), grads).weight.val();
+
+        let mut optim = SgdConfig::new()
+            .with_layer_lr_decay(Some(
+                LayerLrDecayConfig::new()
+                    .with_decay(0.5)
+                    .with_layers(vec!["weight".to_string()]),
+            ))
+            .init();
+        let grads = layer.forward(input).backward();
+        let grads = GradientsParams::from_grads(grads, &layer);
+        let delta_decayed = weight - optim.step(LEARNING_RATE, layer, grads).weight.val();
+
+        delta_decayed
+            .into_data()
+            .assert_approx_eq(&delta.mul_scalar(0.5).into_data(), 3);
+    }
+
     fn random_tensor<B: Backend>(device: &B::Device) -> Tensor<B, 2> {
         Tensor::<B, 2>::random(Shape::new([2, 20]), Distribution::Default, device)
     }
@@ -173,6 +237,8 @@ mod tests {
                 nesterov: true,
             }),
             gradient_clipping: None,
+            grad_noise: None,
+            layer_lr_decay: None,
         }
         .init()
     }
@@ -0,0 +1,244 @@
+use core::marker::PhantomData;
+
+use super::{GradientsParams, Optimizer};
+use crate::collective::{CollectiveGroup, ReduceOperation};
+use crate::module::{AutodiffModule, ModuleMapper, ModuleVisitor, ParamId};
+use crate::tensor::backend::AutodiffBackend;
+use crate::tensor::Tensor;
+use crate::LearningRate;
+use hashbrown::{HashMap, HashSet};
+
+/// Shards the states of an optimizer between the data-parallel replicas of a
+/// [collective group](CollectiveGroup), ZeRO style.
+///
+/// Each parameter is owned by a single replica, the parameters being balanced between the
+/// replicas by their number of elements. On each step, the gradients of every parameter are
+/// averaged on its owner, the only replica updating it and keeping its optimizer states, then the
+/// updated parameter is gathered by every replica. Both the optimizer states and the averaged
+/// gradients are thus split between the replicas, so a model can be trained when the combined
+/// memory of the devices suffices.
+///
+/// Each replica runs on its own thread with a fork of the same module, and is identified by its
+/// rank in the group. The [record](Optimizer::to_record) of each replica only holds the states of
+/// the parameters it owns, so it should be loaded back on the replica of the same rank.
+///
+/// # Notes
+///
+/// Every replica has to step with the gradients of the same parameters, since the gradients and
+/// the parameters are exchanged with [collective operations](CollectiveGroup).
+#[derive(Clone)]
+pub struct ShardedOptimizer<O> {
+    optim: O,
+    group: CollectiveGroup,
+    rank: usize,
+}
+
+impl<O> ShardedOptimizer<O> {
+    /// Shard the states of the given optimizer, for the replica of the given rank in the group.
+    pub fn new(optim: O, group: CollectiveGroup, rank: usize) -> Self {
+        assert!(
+            rank < group.num_replicas(),
+            "The rank {rank} should be lower than the number of replicas {}.",
+            group.num_replicas()
+        );
+
+        Self { optim, group, rank }
+    }
+
+    /// The rank of the replica in the group.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+}
+
+impl<O, M, B> Optimizer<M, B> for ShardedOptimizer<O>
+where
+    O: Optimizer<M, B>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    type Record = O::Record;
+
+    fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        let mut assigner = ShardAssigner::new(self.group.num_replicas());
+        module.visit(&mut assigner);
+        let owners = assigner.owners;
+
+        let mut reducer = GradientsReducer::<B> {
+            group: &self.group,
+            rank: self.rank,
+            owners: &owners,
+            grads: &mut grads,
+            sharded: GradientsParams::new(),
+            updated: HashSet::new(),
+            phantom: PhantomData,
+        };
+        module.visit(&mut reducer);
+        let (sharded, updated) = (reducer.sharded, reducer.updated);
+
+        let module = self.optim.step(lr, module, sharded);
+
+        let mut gatherer = ParamsGatherer {
+            group: &self.group,
+            rank: self.rank,
+            owners: &owners,
+            updated: &updated,
+        };
+        module.map(&mut gatherer)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.optim.to_record()
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.optim = self.optim.load_record(record);
+        self
+    }
+}
+
+/// Assign each parameter to the replica owning the fewest elements so far, in the order of the
+/// module, which is the same on every replica.
+struct ShardAssigner {
+    num_elements: Vec<usize>,
+    owners: HashMap<ParamId, usize>,
+}
+
+impl ShardAssigner {
+    fn new(num_replicas: usize) -> Self {
+        Self {
+            num_elements: vec![0; num_replicas],
+            owners: HashMap::new(),
+        }
+    }
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for ShardAssigner {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let (rank, num_elements) = self
+            .num_elements
+            .iter_mut()
+            .enumerate()
+            .min_by_key(|(_, num_elements)| **num_elements)
+            .unwrap();
+
+        *num_elements += tensor.shape().num_elements();
+        self.owners.insert(id.clone(), rank);
+    }
+}
+
+/// Average the gradients of each parameter on its owner.
+struct GradientsReducer<'a, B> {
+    group: &'a CollectiveGroup,
+    rank: usize,
+    owners: &'a HashMap<ParamId, usize>,
+    grads: &'a mut GradientsParams,
+    sharded: GradientsParams,
+    updated: HashSet<ParamId>,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GradientsReducer<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) else {
+            return;
+        };
+        let is_owner = self.owners[id] == self.rank;
+
+        if let Some(grad) = self.group.reduce(grad, ReduceOperation::Mean, is_owner) {
+            self.sharded
+                .register::<B::InnerBackend, D>(id.clone(), grad);
+        }
+        self.updated.insert(id.clone());
+    }
+}
+
+/// Gather each updated parameter from its owner.
+struct ParamsGatherer<'a> {
+    group: &'a CollectiveGroup,
+    rank: usize,
+    owners: &'a HashMap<ParamId, usize>,
+    updated: &'a HashSet<ParamId>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleMapper<B> for ParamsGatherer<'a> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if !self.updated.contains(id) {
+            return tensor;
+        }
+
+        let device = tensor.device();
+        let is_require_grad = tensor.is_require_grad();
+        let is_owner = self.owners[id] == self.rank;
+
+        // Exchanged without autodiff, to keep the graph of each replica separated.
+        let tensor = self
+            .group
+            .broadcast(is_owner.then(|| tensor.inner()), &device);
+        let mut tensor = Tensor::from_inner(tensor);
+
+        if is_require_grad {
+            tensor = tensor.require_grad();
+        }
+
+        tensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::AdamConfig;
+    use crate::tensor::Distribution;
+    use crate::{nn, TestAutodiffBackend};
+
+    #[test]
+    fn sharded_states_should_give_the_same_updates() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init::<TestAutodiffBackend>(&device);
+        let inputs = (0..2)
+            .map(|_| Tensor::random([2, 6], Distribution::Default, &device))
+            .collect::<Vec<_>>();
+        let group = CollectiveGroup::new(2);
+
+        let handles = (0..2)
+            .map(|rank| {
+                let (mut linear, inputs) = (linear.clone(), inputs.clone());
+                let mut optim =
+                    ShardedOptimizer::new(AdamConfig::new().init(), group.clone(), rank);
+
+                std::thread::spawn(move || {
+                    for x in inputs {
+                        let grads = linear.forward(x).backward();
+                        let grads = GradientsParams::from_grads(grads, &linear);
+                        linear = optim.step(0.01, linear, grads);
+                    }
+                    (linear, optim.to_record().len())
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut optim = AdamConfig::new().init();
+        let mut expected = linear;
+        for x in inputs {
+            let grads = expected.forward(x).backward();
+            let grads = GradientsParams::from_grads(grads, &expected);
+            expected = optim.step(0.01, expected, grads);
+        }
+
+        for handle in handles {
+            // The weight and the bias are owned by different replicas.
+            let (updated, num_states) = handle.join().unwrap();
+            assert_eq!(num_states, 1);
+            updated
+                .weight
+                .to_data()
+                .assert_approx_eq(&expected.weight.to_data(), 5);
+            updated
+                .bias
+                .unwrap()
+                .to_data()
+                .assert_approx_eq(&expected.bias.clone().unwrap().to_data(), 5);
+        }
+    }
+}
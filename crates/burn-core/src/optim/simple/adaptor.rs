@@ -1,8 +1,9 @@
 use super::{record::AdaptorRecord, SimpleOptimizer};
 use crate::{
     grad_clipping::GradientClipping,
-    module::{AutodiffModule, ModuleMapper, ParamId},
-    optim::{GradientsParams, Optimizer},
+    module::{AutodiffModule, Module, ModuleMapper, ParamId},
+    optim::{GradientNoise, GradientsParams, LayerLrDecay, OffloadPolicy, Optimizer},
+    record::OffloadedRecord,
     LearningRate,
 };
 use burn_tensor::{backend::AutodiffBackend, Tensor};
@@ -20,8 +21,13 @@ where
 {
     optim: O,
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
+    offloaded: HashMap<ParamId, OffloadedRecord<AdaptorRecord<O, B>, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    grad_noise: Option<GradientNoise>,
+    layer_lr_decay: Option<LayerLrDecay>,
+    offload: OffloadPolicy,
+    num_steps: usize,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -34,8 +40,13 @@ where
         Self {
             optim,
             records: HashMap::new(),
+            offloaded: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            grad_noise: None,
+            layer_lr_decay: None,
+            offload: OffloadPolicy::Never,
+            num_steps: 0,
         }
     }
 }
@@ -60,6 +71,70 @@ where
         self
     }
 
+    /// Sets the gradient noise.
+    ///
+    /// # Notes
+    ///
+    /// The number of steps used to decay the noise isn't part of the record, so it restarts
+    /// from 0 when the optimizer is loaded from a checkpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `grad_noise` - The gradient noise.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_grad_noise(mut self, grad_noise: GradientNoise) -> Self {
+        self.grad_noise = Some(grad_noise);
+        self
+    }
+
+    /// Sets the layer-wise learning rate decay.
+    ///
+    /// # Arguments
+    ///
+    /// * `layer_lr_decay` - The layer-wise learning rate decay.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_layer_lr_decay(mut self, layer_lr_decay: LayerLrDecay) -> Self {
+        self.layer_lr_decay = Some(layer_lr_decay);
+        self
+    }
+
+    /// Register the parameters of the next layer for the
+    /// [layer-wise learning rate decay](LayerLrDecay), from the input to the output, after the
+    /// layers [configured](crate::optim::LayerLrDecayConfig) with their paths.
+    ///
+    /// # Panics
+    ///
+    /// If the layer-wise learning rate decay isn't set.
+    pub fn with_lr_decay_layer<L: Module<B>>(mut self, layer: &L) -> Self {
+        let layer_lr_decay = self
+            .layer_lr_decay
+            .take()
+            .expect("The layer-wise learning rate decay should be set to register a layer.");
+        self.layer_lr_decay = Some(layer_lr_decay.with_layer(layer));
+        self
+    }
+
+    /// Sets the policy selecting the parameters whose states are kept in host memory between the
+    /// steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `offload` - The offload policy.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_offload(mut self, offload: OffloadPolicy) -> Self {
+        self.offload = offload;
+        self
+    }
+
     #[cfg(test)]
     pub(crate) fn has_gradient_clipping(&self) -> bool {
         self.grad_clipping.is_some()
@@ -75,22 +150,43 @@ where
     type Record = HashMap<ParamId, AdaptorRecord<O, B>>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        if let Some(layer_lr_decay) = self.layer_lr_decay.as_mut() {
+            layer_lr_decay.register_paths(&module);
+        }
+
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
             &self.optim,
             &mut self.records,
+            &mut self.offloaded,
+            &self.offload,
             &mut grads,
             lr,
             self.grad_clipping.as_ref(),
+            self.grad_noise
+                .as_ref()
+                .map(|noise| (noise, self.num_steps)),
+            self.layer_lr_decay.as_ref(),
         );
+        self.num_steps += 1;
+
         module.map(&mut mapper)
     }
 
+    /// The offloaded states are loaded back on their device to be part of the record.
     fn to_record(&self) -> Self::Record {
-        self.records.clone()
+        let mut records = self.records.clone();
+
+        for (id, record) in self.offloaded.iter() {
+            records.insert(id.clone(), record.clone().load());
+        }
+
+        records
     }
 
+    /// The states are offloaded again following the policy when their parameter is updated.
     fn load_record(mut self, record: Self::Record) -> Self {
         self.records = record;
+        self.offloaded.clear();
         self
     }
 }
@@ -104,10 +200,14 @@ where
 {
     optimizer: &'a O,
     records: &'a mut HashMap<ParamId, AdaptorRecord<O, B>>,
+    offloaded: &'a mut HashMap<ParamId, OffloadedRecord<AdaptorRecord<O, B>, B>>,
+    offload: &'a OffloadPolicy,
     grads: &'a mut GradientsParams,
     lr: LearningRate,
     phantom: PhantomData<M>,
     grad_clipping: Option<&'a GradientClipping>,
+    grad_noise: Option<(&'a GradientNoise, usize)>,
+    layer_lr_decay: Option<&'a LayerLrDecay>,
 }
 
 impl<'a, M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'a, M, B, O>
@@ -122,7 +222,11 @@ where
         if let Some(grad) = grad {
             let device = grad.device();
             let is_require_grad = tensor.is_require_grad();
-            let (key, record) = self.records.remove_entry(id).unzip();
+            let num_elements = tensor.shape().num_elements();
+            let (key, record) = match self.offloaded.remove_entry(id) {
+                Some((key, record)) => (Some(key), Some(record.load())),
+                None => self.records.remove_entry(id).unzip(),
+            };
 
             let clipped_grad = if let Some(g_clipping) = self.grad_clipping {
                 g_clipping.clip_gradient(grad)
@@ -130,18 +234,35 @@ where
                 grad
             };
 
+            let noisy_grad = if let Some((noise, step)) = self.grad_noise {
+                noise.add_noise(clipped_grad, step)
+            } else {
+                clipped_grad
+            };
+
+            let lr = if let Some(layer_lr_decay) = self.layer_lr_decay {
+                layer_lr_decay.lr(id, self.lr)
+            } else {
+                self.lr
+            };
+
             let (tensor, state) = self.optimizer.step(
-                self.lr,
+                lr,
                 tensor.inner(),
-                clipped_grad,
+                noisy_grad,
                 record.map(|record| O::to_device(record.into_state(), &device)),
             );
 
             if let Some(state) = state {
-                self.records.insert(
-                    key.unwrap_or_else(|| id.clone()),
-                    AdaptorRecord::from_state(state),
-                );
+                let key = key.unwrap_or_else(|| id.clone());
+                let record = AdaptorRecord::from_state(state);
+
+                if self.offload.should_offload(id, num_elements) {
+                    self.offloaded
+                        .insert(key, OffloadedRecord::new(record, &device));
+                } else {
+                    self.records.insert(key, record);
+                }
             }
 
             let mut tensor = Tensor::from_inner(tensor);
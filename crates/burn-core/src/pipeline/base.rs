@@ -0,0 +1,250 @@
+use crate as burn;
+
+use crate::module::{AutodiffModule, Ignored, Module};
+use crate::optim::{GradientsAccumulator, GradientsParams};
+use crate::tensor::backend::{AutodiffBackend, Backend};
+use crate::tensor::Tensor;
+
+/// A stage of a [pipeline](Pipeline), transforming the activations of the previous stage.
+pub trait PipelineStage<B: Backend, const D: usize>: Module<B> {
+    /// Applies the stage on the activations of the previous stage.
+    fn forward(&self, input: Tensor<B, D>) -> Tensor<B, D>;
+}
+
+/// The order in which the micro-batches go through a [pipeline](Pipeline) during training.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PipelineSchedule {
+    /// All the micro-batches go through the forward pass before the backward passes, as in GPipe.
+    #[default]
+    GPipe,
+    /// Each micro-batch goes through the backward pass as soon as it leaves the last stage, so at
+    /// most one micro-batch per stage keeps its activations, as in the 1F1B schedule.
+    OneForwardOneBackward,
+}
+
+/// A module split in stages across devices, running micro-batches with pipeline parallelism.
+///
+/// Each stage is moved to its own device, and the activations are moved to the device of the
+/// next stage. The micro-batches are scheduled as a wavefront: on each clock, every stage runs
+/// the next micro-batch, so the devices, which execute their tasks asynchronously, work on
+/// different micro-batches at the same time.
+///
+/// # Notes
+///
+/// The pipeline is a [module](Module) to be optimized as a whole, but moving or forking it puts
+/// every stage on the same device.
+#[derive(Module, Debug)]
+pub struct Pipeline<B: Backend, M> {
+    stages: Vec<M>,
+    devices: Ignored<Vec<B::Device>>,
+}
+
+impl<B: Backend, M: Module<B>> Pipeline<B, M> {
+    /// Creates a pipeline moving each stage to its device.
+    pub fn new(stages: Vec<M>, devices: Vec<B::Device>) -> Self {
+        assert_eq!(
+            stages.len(),
+            devices.len(),
+            "Each stage of the pipeline should have its own device."
+        );
+        assert!(!stages.is_empty(), "A pipeline needs at least one stage.");
+
+        let stages = stages
+            .into_iter()
+            .zip(devices.iter())
+            .map(|(stage, device)| stage.to_device(device))
+            .collect();
+
+        Self {
+            stages,
+            devices: Ignored(devices),
+        }
+    }
+
+    /// The stages of the pipeline.
+    pub fn stages(&self) -> &[M] {
+        &self.stages
+    }
+
+    /// The stages of the pipeline, e.g. to merge them back in a single module.
+    pub fn into_stages(self) -> Vec<M> {
+        self.stages
+    }
+
+    /// Applies all the stages on a single batch, the output is on the device of the last stage.
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D>
+    where
+        M: PipelineStage<B, D>,
+    {
+        (0..self.stages.len()).fold(input, |input, stage| self.forward_stage(stage, input))
+    }
+
+    /// Applies all the stages on each micro-batch, the outputs are on the device of the last
+    /// stage, in the order of the inputs.
+    pub fn forward_micro_batches<const D: usize>(
+        &self,
+        inputs: Vec<Tensor<B, D>>,
+    ) -> Vec<Tensor<B, D>>
+    where
+        M: PipelineStage<B, D>,
+    {
+        let mut outputs = Vec::with_capacity(inputs.len());
+        self.run(inputs, |_, output| outputs.push(output));
+
+        outputs
+    }
+
+    /// Run the micro-batches in a wavefront, calling `on_output` with the index and the output
+    /// of each micro-batch as soon as it leaves the last stage.
+    fn run<const D: usize, F>(&self, inputs: Vec<Tensor<B, D>>, mut on_output: F)
+    where
+        M: PipelineStage<B, D>,
+        F: FnMut(usize, Tensor<B, D>),
+    {
+        let num_stages = self.stages.len();
+        let num_micro_batches = inputs.len();
+        let mut activations = inputs.into_iter().map(Some).collect::<Vec<_>>();
+
+        for clock in 0..num_micro_batches + num_stages - 1 {
+            // The last stages first, to hand the outputs over as soon as possible.
+            for stage in (0..num_stages).rev() {
+                let Some(micro_batch) = clock.checked_sub(stage) else {
+                    continue;
+                };
+                if micro_batch >= num_micro_batches {
+                    continue;
+                }
+
+                let input = activations[micro_batch].take().unwrap();
+                let output = self.forward_stage(stage, input);
+
+                if stage == num_stages - 1 {
+                    on_output(micro_batch, output);
+                } else {
+                    activations[micro_batch] = Some(output);
+                }
+            }
+        }
+    }
+
+    fn forward_stage<const D: usize>(&self, stage: usize, input: Tensor<B, D>) -> Tensor<B, D>
+    where
+        M: PipelineStage<B, D>,
+    {
+        let input = input.to_device(&self.devices[stage]);
+        self.stages[stage].forward(input)
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> Pipeline<B, M> {
+    /// Runs the forward and the backward passes of each micro-batch with the given schedule,
+    /// returning the gradients summed over the micro-batches.
+    ///
+    /// The `loss` function computes the loss of a micro-batch from its index and its output, and
+    /// should be divided by the number of micro-batches to get the gradients of the mean loss.
+    pub fn backward_micro_batches<const D: usize, F>(
+        &self,
+        inputs: Vec<Tensor<B, D>>,
+        schedule: PipelineSchedule,
+        mut loss: F,
+    ) -> GradientsParams
+    where
+        M: PipelineStage<B, D>,
+        F: FnMut(usize, Tensor<B, D>) -> Tensor<B, 1>,
+    {
+        let mut accumulator = GradientsAccumulator::new();
+        let mut backward = |index, output| {
+            let grads = loss(index, output).backward();
+            accumulator.accumulate(self, GradientsParams::from_grads(grads, self));
+        };
+
+        match schedule {
+            PipelineSchedule::GPipe => {
+                for (index, output) in self.forward_micro_batches(inputs).into_iter().enumerate() {
+                    backward(index, output);
+                }
+            }
+            PipelineSchedule::OneForwardOneBackward => self.run(inputs, backward),
+        }
+
+        accumulator.grads()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::Distribution;
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    impl<B: Backend, const D: usize> PipelineStage<B, D> for Linear<B> {
+        fn forward(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+            Linear::forward(self, input)
+        }
+    }
+
+    fn pipeline() -> Pipeline<TestAutodiffBackend, Linear<TestAutodiffBackend>> {
+        let device = Default::default();
+        let stages = (0..3)
+            .map(|_| LinearConfig::new(4, 4).init(&device))
+            .collect();
+
+        Pipeline::new(stages, vec![device; 3])
+    }
+
+    fn micro_batches() -> Vec<Tensor<TestAutodiffBackend, 2>> {
+        (0..4)
+            .map(|_| Tensor::random([2, 4], Distribution::Default, &Default::default()))
+            .collect()
+    }
+
+    #[test]
+    fn micro_batches_should_give_the_same_outputs_as_batches() {
+        let pipeline = pipeline();
+        let inputs = micro_batches();
+
+        let outputs = pipeline.forward_micro_batches(inputs.clone());
+
+        for (input, output) in inputs.into_iter().zip(outputs) {
+            pipeline
+                .forward(input)
+                .into_data()
+                .assert_approx_eq(&output.into_data(), 5);
+        }
+    }
+
+    #[test]
+    fn schedules_should_give_the_same_gradients() {
+        let pipeline = pipeline();
+        let inputs = micro_batches();
+        let loss = |_: usize, output: Tensor<TestAutodiffBackend, 2>| output.mean();
+
+        let mut gpipe =
+            pipeline.backward_micro_batches(inputs.clone(), PipelineSchedule::GPipe, loss);
+        let mut one_f_one_b = pipeline.backward_micro_batches(
+            inputs.clone(),
+            PipelineSchedule::OneForwardOneBackward,
+            loss,
+        );
+
+        let batch = Tensor::cat(inputs, 0);
+        let grads = pipeline.forward(batch).mean().mul_scalar(4).backward();
+        let mut expected = GradientsParams::from_grads(grads, &pipeline);
+
+        for stage in pipeline.stages() {
+            let id = &stage.weight.id;
+            let expected = expected.remove::<TestBackend, 2>(id).unwrap().into_data();
+            gpipe
+                .remove::<TestBackend, 2>(id)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq(&expected, 4);
+            one_f_one_b
+                .remove::<TestBackend, 2>(id)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq(&expected, 4);
+        }
+    }
+}
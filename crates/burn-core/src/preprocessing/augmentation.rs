@@ -0,0 +1,572 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::tensor::backend::Backend;
+use crate::tensor::module::{affine_grid_2d, grid_sample_2d};
+use crate::tensor::ops::GridSampleOptions;
+use crate::tensor::{Int, Tensor};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::{Beta, Distribution};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The number of attempts to sample a box fitting in the images before falling back to a default.
+const NUM_BOX_ATTEMPTS: usize = 10;
+
+/// A random augmentation of a batch of images, applied on the device of the images by an
+/// [Augmenter].
+///
+/// The random parameters are sampled for each image on the CPU, while the images are transformed
+/// with tensor operations, so the augmentations don't slow down the data loading.
+#[derive(Config, Debug, PartialEq)]
+pub enum Augmentation {
+    /// Crop a random area of each image and resize it to the given size with bilinear
+    /// interpolation.
+    RandomResizedCrop {
+        /// The height of the cropped images.
+        height: usize,
+        /// The width of the cropped images.
+        width: usize,
+        /// The range of the area of the crops, as a fraction of the area of the images.
+        scale: (f64, f64),
+        /// The range of the aspect ratio (width / height) of the crops.
+        ratio: (f64, f64),
+    },
+    /// Randomly change the brightness, contrast, saturation and hue of each image, in that order.
+    ///
+    /// The factors are sampled uniformly in `[1 - value, 1 + value]` and the hue shift in
+    /// `[-hue, hue]` turns. The saturation and hue require RGB images in `[0, 1]`.
+    ColorJitter {
+        /// How much to jitter the brightness.
+        brightness: f64,
+        /// How much to jitter the contrast.
+        contrast: f64,
+        /// How much to jitter the saturation.
+        saturation: f64,
+        /// How much to shift the hue, at most `0.5`.
+        hue: f64,
+    },
+    /// Blend each image and its target with another image of the batch, the weight being sampled
+    /// from a `Beta(alpha, alpha)` distribution for the whole batch.
+    ///
+    /// Requires the [targets](Augmenter::augment_with_targets).
+    Mixup {
+        /// The parameter of the Beta distribution.
+        alpha: f64,
+    },
+    /// Paste a random box of another image of the batch in each image, mixing the targets in
+    /// proportion of the pasted area, sampled from a `Beta(alpha, alpha)` distribution for the
+    /// whole batch.
+    ///
+    /// Requires the [targets](Augmenter::augment_with_targets).
+    CutMix {
+        /// The parameter of the Beta distribution.
+        alpha: f64,
+    },
+    /// Fill a random box of each image with a value.
+    RandomErasing {
+        /// The probability of erasing a box of an image.
+        probability: f64,
+        /// The range of the area of the boxes, as a fraction of the area of the images.
+        scale: (f64, f64),
+        /// The range of the aspect ratio (width / height) of the boxes.
+        ratio: (f64, f64),
+        /// The value filling the boxes.
+        value: f32,
+    },
+}
+
+/// Configuration to create an [Augmenter] using the [init function](AugmenterConfig::init).
+#[derive(Config, Debug)]
+pub struct AugmenterConfig {
+    /// The augmentations, applied in order.
+    pub augmentations: Vec<Augmentation>,
+    /// The seed of the random number generator, a random seed is used when not set.
+    pub seed: Option<u64>,
+}
+
+/// Applies random [augmentations](Augmentation) to batches of images during training.
+///
+/// Should be created with [AugmenterConfig]. The augmenter can be shared between threads, for
+/// instance by the batchers of a data loader created on the training device, and its clones share
+/// the same random number generator.
+#[derive(Clone, Debug)]
+pub struct Augmenter {
+    augmentations: Vec<Augmentation>,
+    rng: Arc<spin::Mutex<StdRng>>,
+}
+
+impl AugmenterConfig {
+    /// Initialize a new [augmenter](Augmenter).
+    pub fn init(&self) -> Augmenter {
+        let rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        Augmenter {
+            augmentations: self.augmentations.clone(),
+            rng: Arc::new(spin::Mutex::new(rng)),
+        }
+    }
+}
+
+impl Augmenter {
+    /// Augments a batch of images.
+    ///
+    /// # Panics
+    ///
+    /// If the augmenter mixes the images, which requires
+    /// [the targets](Self::augment_with_targets).
+    ///
+    /// # Shapes
+    ///
+    /// - images: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size, channels, height_out, width_out]`
+    pub fn augment<B: Backend>(&self, images: Tensor<B, 4>) -> Tensor<B, 4> {
+        let mut rng = self.rng.lock();
+
+        self.augmentations
+            .iter()
+            .fold(images, |images, augmentation| {
+                augmentation.apply(images, None, &mut rng).0
+            })
+    }
+
+    /// Augments a batch of images along with their targets, such as one-hot encoded classes,
+    /// mixed by [Mixup](Augmentation::Mixup) and [CutMix](Augmentation::CutMix).
+    ///
+    /// # Shapes
+    ///
+    /// - images: `[batch_size, channels, height, width]`
+    /// - targets: `[batch_size, num_classes]`
+    /// - output: (`[batch_size, channels, height_out, width_out]`, `[batch_size, num_classes]`)
+    pub fn augment_with_targets<B: Backend>(
+        &self,
+        images: Tensor<B, 4>,
+        targets: Tensor<B, 2>,
+    ) -> (Tensor<B, 4>, Tensor<B, 2>) {
+        let mut rng = self.rng.lock();
+
+        let (images, targets) = self.augmentations.iter().fold(
+            (images, Some(targets)),
+            |(images, targets), augmentation| augmentation.apply(images, targets, &mut rng),
+        );
+
+        (images, targets.unwrap())
+    }
+}
+
+impl Augmentation {
+    fn apply<B: Backend>(
+        &self,
+        images: Tensor<B, 4>,
+        targets: Option<Tensor<B, 2>>,
+        rng: &mut StdRng,
+    ) -> (Tensor<B, 4>, Option<Tensor<B, 2>>) {
+        match self {
+            Self::RandomResizedCrop {
+                height,
+                width,
+                scale,
+                ratio,
+            } => (
+                random_resized_crop(images, [*height, *width], *scale, *ratio, rng),
+                targets,
+            ),
+            Self::ColorJitter {
+                brightness,
+                contrast,
+                saturation,
+                hue,
+            } => (
+                color_jitter(images, *brightness, *contrast, *saturation, *hue, rng),
+                targets,
+            ),
+            Self::Mixup { alpha } => {
+                let targets = targets.expect("Mixup requires the targets of the images.");
+                let lambda = Beta::new(*alpha, *alpha).unwrap().sample(rng);
+                let (shuffled_images, shuffled_targets) = shuffle_batch(&images, &targets, rng);
+
+                (
+                    images.mul_scalar(lambda) + shuffled_images.mul_scalar(1.0 - lambda),
+                    Some(targets.mul_scalar(lambda) + shuffled_targets.mul_scalar(1.0 - lambda)),
+                )
+            }
+            Self::CutMix { alpha } => {
+                let targets = targets.expect("CutMix requires the targets of the images.");
+                let [_, _, height, width] = images.dims();
+                let lambda = Beta::new(*alpha, *alpha).unwrap().sample(rng);
+
+                // A box of area `1 - lambda` clipped to the images, lambda is then adjusted to
+                // the actual area.
+                let cut = (1.0 - lambda).sqrt();
+                let (center_y, center_x) = (rng.gen::<f64>(), rng.gen::<f64>());
+                let bound = |center: f64, size: usize| {
+                    let start = ((center - cut / 2.0).max(0.0) * size as f64).round() as usize;
+                    let end = ((center + cut / 2.0).min(1.0) * size as f64).round() as usize;
+                    (start, end)
+                };
+                let (y0, y1) = bound(center_y, height);
+                let (x0, x1) = bound(center_x, width);
+                let lambda = 1.0 - ((y1 - y0) * (x1 - x0)) as f64 / (height * width) as f64;
+
+                let mask = box_masks::<B>(&[[y0, y1, x0, x1]], [height, width], &images.device());
+                let (shuffled_images, shuffled_targets) = shuffle_batch(&images, &targets, rng);
+
+                (
+                    images * mask.clone().neg().add_scalar(1.0) + shuffled_images * mask,
+                    Some(targets.mul_scalar(lambda) + shuffled_targets.mul_scalar(1.0 - lambda)),
+                )
+            }
+            Self::RandomErasing {
+                probability,
+                scale,
+                ratio,
+                value,
+            } => {
+                let [batch_size, _, height, width] = images.dims();
+                let boxes = (0..batch_size)
+                    .map(|_| match rng.gen_bool(*probability) {
+                        true => sample_box([height, width], *scale, *ratio, rng)
+                            .map(|[y, x, h, w]| {
+                                let [y, x] = [y.round() as usize, x.round() as usize];
+                                [y, y + h.round() as usize, x, x + w.round() as usize]
+                            })
+                            .unwrap_or([0; 4]),
+                        false => [0; 4],
+                    })
+                    .collect::<Vec<_>>();
+                let mask = box_masks::<B>(&boxes, [height, width], &images.device());
+
+                (
+                    images * mask.clone().neg().add_scalar(1.0) + mask.mul_scalar(*value),
+                    targets,
+                )
+            }
+        }
+    }
+}
+
+fn random_resized_crop<B: Backend>(
+    images: Tensor<B, 4>,
+    size: [usize; 2],
+    scale: (f64, f64),
+    ratio: (f64, f64),
+    rng: &mut StdRng,
+) -> Tensor<B, 4> {
+    let [batch_size, channels, height, width] = images.dims();
+    let (height_in, width_in) = (height as f64, width as f64);
+
+    // Affine transformations mapping the output to the crops in normalized coordinates.
+    let theta = (0..batch_size)
+        .flat_map(|_| {
+            let [y, x, h, w] = sample_box([height, width], scale, ratio, rng)
+                .unwrap_or([0.0, 0.0, height_in, width_in]);
+
+            [
+                (w / width_in) as f32,
+                0.0,
+                ((2.0 * x + w) / width_in - 1.0) as f32,
+                0.0,
+                (h / height_in) as f32,
+                ((2.0 * y + h) / height_in - 1.0) as f32,
+            ]
+        })
+        .collect::<Vec<_>>();
+    let theta =
+        Tensor::<B, 1>::from_floats(theta.as_slice(), &images.device()).reshape([batch_size, 2, 3]);
+
+    let grid = affine_grid_2d(theta, [batch_size, channels, size[0], size[1]], false);
+    grid_sample_2d(images, grid, GridSampleOptions::default())
+}
+
+fn color_jitter<B: Backend>(
+    images: Tensor<B, 4>,
+    brightness: f64,
+    contrast: f64,
+    saturation: f64,
+    hue: f64,
+    rng: &mut StdRng,
+) -> Tensor<B, 4> {
+    let [batch_size, channels, height, width] = images.dims();
+    let device = images.device();
+    let mut factors = |value: f64| {
+        let factors = (0..batch_size)
+            .map(|_| rng.gen_range((1.0 - value).max(0.0)..=1.0 + value) as f32)
+            .collect::<Vec<_>>();
+        Tensor::<B, 1>::from_floats(factors.as_slice(), &device).reshape([batch_size, 1, 1, 1])
+    };
+    let mut images = images;
+
+    if brightness > 0.0 {
+        images = images * factors(brightness);
+    }
+
+    if contrast > 0.0 {
+        let mean = grayscale(images.clone())
+            .mean_dim(2)
+            .mean_dim(3)
+            .expand([batch_size, channels, height, width]);
+        images = (images - mean.clone()) * factors(contrast) + mean;
+    }
+
+    if saturation > 0.0 {
+        let gray = grayscale(images.clone()).expand([batch_size, channels, height, width]);
+        images = (images - gray.clone()) * factors(saturation) + gray;
+    }
+
+    if hue > 0.0 {
+        assert_eq!(channels, 3, "The hue jitter requires RGB images.");
+
+        // Rotation of the chroma in the YIQ color space.
+        let rotations = (0..batch_size)
+            .flat_map(|_| hue_rotation(rng.gen_range(-hue..=hue) * 2.0 * PI))
+            .collect::<Vec<_>>();
+        let rotations =
+            Tensor::<B, 1>::from_floats(rotations.as_slice(), &device).reshape([batch_size, 3, 3]);
+
+        images = rotations
+            .matmul(images.reshape([batch_size, 3, height * width]))
+            .reshape([batch_size, 3, height, width]);
+    }
+
+    images
+}
+
+/// The luma of RGB images, or the mean of the channels of other images.
+fn grayscale<B: Backend>(images: Tensor<B, 4>) -> Tensor<B, 4> {
+    let [_, channels, _, _] = images.dims();
+
+    match channels {
+        3 => {
+            let weights = Tensor::<B, 1>::from_floats([0.299, 0.587, 0.114], &images.device())
+                .reshape([1, 3, 1, 1]);
+            (images * weights).sum_dim(1)
+        }
+        _ => images.mean_dim(1),
+    }
+}
+
+/// The matrix rotating the hue of RGB pixels by the given angle, in row-major order.
+fn hue_rotation(angle: f64) -> [f32; 9] {
+    const RGB_TO_YIQ: [[f64; 3]; 3] = [
+        [0.299, 0.587, 0.114],
+        [0.596, -0.274, -0.322],
+        [0.211, -0.523, 0.312],
+    ];
+    const YIQ_TO_RGB: [[f64; 3]; 3] = [
+        [1.0, 0.956, 0.621],
+        [1.0, -0.272, -0.647],
+        [1.0, -1.106, 1.703],
+    ];
+    let (sin, cos) = angle.sin_cos();
+    let rotation = [[1.0, 0.0, 0.0], [0.0, cos, -sin], [0.0, sin, cos]];
+
+    let matmul = |lhs: [[f64; 3]; 3], rhs: [[f64; 3]; 3]| {
+        let mut out = [[0.0; 3]; 3];
+        for (i, row) in out.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| lhs[i][k] * rhs[k][j]).sum();
+            }
+        }
+        out
+    };
+
+    let matrix = matmul(YIQ_TO_RGB, matmul(rotation, RGB_TO_YIQ));
+    let mut out = [0.0; 9];
+    for (i, value) in matrix.iter().flatten().enumerate() {
+        out[i] = *value as f32;
+    }
+    out
+}
+
+/// Samples a box `[y, x, height, width]` in pixels with an area and an aspect ratio in the given
+/// ranges, fitting in an image of the given size.
+fn sample_box(
+    size: [usize; 2],
+    scale: (f64, f64),
+    ratio: (f64, f64),
+    rng: &mut StdRng,
+) -> Option<[f64; 4]> {
+    let (height, width) = (size[0] as f64, size[1] as f64);
+    let log_ratio = (ratio.0.ln(), ratio.1.ln());
+
+    for _ in 0..NUM_BOX_ATTEMPTS {
+        let area = height * width * rng.gen_range(scale.0..=scale.1);
+        let aspect_ratio = rng.gen_range(log_ratio.0..=log_ratio.1).exp();
+        let w = (area * aspect_ratio).sqrt();
+        let h = (area / aspect_ratio).sqrt();
+
+        if w <= width && h <= height {
+            let y = rng.gen_range(0.0..=height - h);
+            let x = rng.gen_range(0.0..=width - w);
+            return Some([y, x, h, w]);
+        }
+    }
+
+    None
+}
+
+/// Masks of shape `[num_boxes, 1, height, width]` set to one inside the boxes `[y0, y1, x0, x1]`.
+fn box_masks<B: Backend>(
+    boxes: &[[usize; 4]],
+    size: [usize; 2],
+    device: &B::Device,
+) -> Tensor<B, 4> {
+    let [height, width] = size;
+    let shape = [boxes.len(), 1, height, width];
+    let bounds = boxes
+        .iter()
+        .flat_map(|bounds| bounds.map(|bound| bound as i32))
+        .collect::<Vec<_>>();
+    let bounds =
+        Tensor::<B, 1, Int>::from_ints(bounds.as_slice(), device).reshape([boxes.len(), 4, 1, 1]);
+    let bound = |index: usize| bounds.clone().narrow(1, index, 1).expand(shape);
+
+    let ys = Tensor::<B, 1, Int>::arange(0..height as i64, device)
+        .reshape([1, 1, height, 1])
+        .expand(shape);
+    let xs = Tensor::<B, 1, Int>::arange(0..width as i64, device)
+        .reshape([1, 1, 1, width])
+        .expand(shape);
+
+    ys.clone().greater_equal(bound(0)).float()
+        * ys.lower(bound(1)).float()
+        * xs.clone().greater_equal(bound(2)).float()
+        * xs.lower(bound(3)).float()
+}
+
+/// Shuffles the images and the targets of a batch with the same permutation.
+fn shuffle_batch<B: Backend>(
+    images: &Tensor<B, 4>,
+    targets: &Tensor<B, 2>,
+    rng: &mut StdRng,
+) -> (Tensor<B, 4>, Tensor<B, 2>) {
+    let [batch_size, _, _, _] = images.dims();
+    let mut permutation = (0..batch_size as i32).collect::<Vec<_>>();
+    permutation.shuffle(rng);
+    let permutation = Tensor::<B, 1, Int>::from_ints(permutation.as_slice(), &images.device());
+
+    (
+        images.clone().select(0, permutation.clone()),
+        targets.clone().select(0, permutation),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Distribution as TensorDistribution;
+    use crate::TestBackend;
+    use alloc::vec;
+
+    fn images() -> Tensor<TestBackend, 4> {
+        Tensor::random(
+            [4, 3, 8, 8],
+            TensorDistribution::Uniform(0.0, 1.0),
+            &Default::default(),
+        )
+    }
+
+    #[test]
+    fn full_crop_should_keep_the_images() {
+        let augmenter = AugmenterConfig::new(vec![Augmentation::RandomResizedCrop {
+            height: 8,
+            width: 8,
+            scale: (1.0, 1.0),
+            ratio: (1.0, 1.0),
+        }])
+        .init();
+        let images = images();
+
+        let output = augmenter.augment(images.clone());
+
+        output.into_data().assert_approx_eq(&images.into_data(), 3);
+    }
+
+    #[test]
+    fn augmentations_should_be_deterministic_with_a_seed() {
+        let config = AugmenterConfig::new(vec![
+            Augmentation::RandomResizedCrop {
+                height: 4,
+                width: 6,
+                scale: (0.3, 1.0),
+                ratio: (0.75, 1.33),
+            },
+            Augmentation::ColorJitter {
+                brightness: 0.4,
+                contrast: 0.4,
+                saturation: 0.4,
+                hue: 0.1,
+            },
+            Augmentation::RandomErasing {
+                probability: 0.5,
+                scale: (0.1, 0.3),
+                ratio: (0.5, 2.0),
+                value: 0.0,
+            },
+        ])
+        .with_seed(Some(42));
+        let images = images();
+
+        let output = config.init().augment(images.clone());
+        let output_2 = config.init().augment(images);
+
+        assert_eq!(output.dims(), [4, 3, 4, 6]);
+        output
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 5);
+    }
+
+    #[test]
+    fn mixing_should_keep_the_targets_normalized() {
+        let augmenter = AugmenterConfig::new(vec![
+            Augmentation::Mixup { alpha: 0.4 },
+            Augmentation::CutMix { alpha: 1.0 },
+        ])
+        .with_seed(Some(0))
+        .init();
+        let targets = Tensor::<TestBackend, 2>::from_floats(
+            [
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+                [1.0, 0.0, 0.0],
+            ],
+            &Default::default(),
+        );
+
+        let (images, targets) = augmenter.augment_with_targets(images(), targets);
+
+        assert_eq!(images.dims(), [4, 3, 8, 8]);
+        targets.sum_dim(1).into_data().assert_approx_eq(
+            &Tensor::<TestBackend, 2>::ones([4, 1], &Default::default()).into_data(),
+            5,
+        );
+    }
+
+    #[test]
+    fn box_masks_should_cover_the_boxes() {
+        let masks =
+            box_masks::<TestBackend>(&[[0, 1, 1, 3], [0, 0, 0, 0]], [2, 3], &Default::default());
+
+        masks.into_data().assert_approx_eq(
+            &Tensor::<TestBackend, 4>::from_floats(
+                [
+                    [[[0.0, 1.0, 1.0], [0.0, 0.0, 0.0]]],
+                    [[[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]],
+                ],
+                &Default::default(),
+            )
+            .into_data(),
+            3,
+        );
+    }
+}
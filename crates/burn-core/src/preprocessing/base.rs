@@ -0,0 +1,98 @@
+use crate as burn;
+
+use super::{ImagePreprocessingConfig, TabularPreprocessingConfig, TextPreprocessingConfig};
+use crate::config::Config;
+use crate::module::Module;
+use crate::record::{PrecisionSettings, Record};
+use crate::tensor::backend::Backend;
+
+/// The preprocessing of the inputs of a model, to be [bundled](BundledRecord) with its record so
+/// the inputs are preprocessed the same way during training and at deployment.
+#[derive(Config, Debug, PartialEq)]
+pub enum PreprocessingConfig {
+    /// The preprocessing of images.
+    Image(ImagePreprocessingConfig),
+    /// The preprocessing of text.
+    Text(TextPreprocessingConfig),
+    /// The preprocessing of tabular data.
+    Tabular(TabularPreprocessingConfig),
+}
+
+impl<B: Backend> Record<B> for PreprocessingConfig {
+    type Item<S: PrecisionSettings> = PreprocessingConfig;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        self
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, _device: &B::Device) -> Self {
+        item
+    }
+}
+
+/// The record of a model bundled with the preprocessing of its inputs.
+///
+/// The bundle is saved and loaded with any [recorder](crate::record::Recorder), so deployment
+/// targets get the training-time preprocessing along with the weights.
+///
+/// ```rust, ignore
+/// let bundle = BundledRecord::new(model.into_record(), preprocessing);
+/// recorder.record(bundle, "model".into())?;
+///
+/// let bundle: BundledRecord<B, Model<B>> = recorder.load("model".into(), &device)?;
+/// let model = model.load_record(bundle.model);
+/// let images = match bundle.preprocessing {
+///     PreprocessingConfig::Image(config) => config.apply(images),
+///     _ => panic!("The model takes images."),
+/// };
+/// ```
+#[derive(Record, new)]
+pub struct BundledRecord<B: Backend, M: Module<B>> {
+    /// The record of the model.
+    pub model: M::Record,
+    /// The preprocessing of the inputs of the model.
+    pub preprocessing: PreprocessingConfig,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::preprocessing::ImageTransform;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::TestBackend;
+    use alloc::vec;
+
+    #[test]
+    fn should_save_and_load_the_preprocessing_with_the_model() {
+        let device = Default::default();
+        let model = LinearConfig::new(2, 2).init::<TestBackend>(&device);
+        let preprocessing = PreprocessingConfig::Image(ImagePreprocessingConfig::new(vec![
+            ImageTransform::Rescale {
+                factor: 1.0 / 255.0,
+            },
+        ]));
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder
+            .record(
+                BundledRecord::<TestBackend, Linear<TestBackend>>::new(
+                    model.clone().into_record(),
+                    preprocessing.clone(),
+                ),
+                (),
+            )
+            .unwrap();
+        let bundle: BundledRecord<TestBackend, Linear<TestBackend>> =
+            recorder.load(bytes, &device).unwrap();
+        let loaded = LinearConfig::new(2, 2)
+            .init::<TestBackend>(&device)
+            .load_record(bundle.model);
+
+        assert_eq!(bundle.preprocessing, preprocessing);
+        loaded
+            .weight
+            .to_data()
+            .assert_approx_eq(&model.weight.to_data(), 5);
+    }
+}
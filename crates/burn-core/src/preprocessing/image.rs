@@ -0,0 +1,141 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::tensor::backend::Backend;
+use crate::tensor::module::interpolate;
+use crate::tensor::ops::{InterpolateMode, InterpolateOptions};
+use crate::tensor::Tensor;
+use alloc::vec::Vec;
+
+/// The algorithm used to [resize](ImageTransform::Resize) images.
+#[derive(Config, Debug, PartialEq)]
+pub enum ResizeMode {
+    /// Nearest-neighbor interpolation.
+    Nearest,
+    /// Bilinear interpolation.
+    Bilinear,
+    /// Bicubic interpolation.
+    Bicubic,
+}
+
+/// A transformation of a batch of images, applied by [ImagePreprocessingConfig].
+#[derive(Config, Debug, PartialEq)]
+pub enum ImageTransform {
+    /// Resize the images to the given size.
+    Resize {
+        /// The height of the resized images.
+        height: usize,
+        /// The width of the resized images.
+        width: usize,
+        /// The interpolation algorithm.
+        mode: ResizeMode,
+    },
+    /// Multiply the pixels by a factor, e.g. `1 / 255` to map bytes to `[0, 1]`.
+    Rescale {
+        /// The factor multiplying the pixels.
+        factor: f32,
+    },
+    /// Normalize each channel with its mean and standard deviation.
+    Normalize {
+        /// The mean of each channel.
+        mean: Vec<f32>,
+        /// The standard deviation of each channel.
+        std: Vec<f32>,
+    },
+}
+
+/// Configuration of the preprocessing of images, applying its [transforms](ImageTransform) in
+/// order.
+#[derive(Config, Debug, PartialEq)]
+pub struct ImagePreprocessingConfig {
+    /// The transforms, applied in order.
+    pub transforms: Vec<ImageTransform>,
+}
+
+impl ImagePreprocessingConfig {
+    /// Applies the transforms on a batch of images.
+    ///
+    /// # Shapes
+    ///
+    /// - images: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size, channels, height_out, width_out]`
+    pub fn apply<B: Backend>(&self, images: Tensor<B, 4>) -> Tensor<B, 4> {
+        self.transforms
+            .iter()
+            .fold(images, |images, transform| transform.apply(images))
+    }
+}
+
+impl ImageTransform {
+    /// Applies the transform on a batch of images of shape `[batch_size, channels, height, width]`.
+    pub fn apply<B: Backend>(&self, images: Tensor<B, 4>) -> Tensor<B, 4> {
+        match self {
+            Self::Resize {
+                height,
+                width,
+                mode,
+            } => {
+                let mode = match mode {
+                    ResizeMode::Nearest => InterpolateMode::Nearest,
+                    ResizeMode::Bilinear => InterpolateMode::Bilinear,
+                    ResizeMode::Bicubic => InterpolateMode::Bicubic,
+                };
+
+                interpolate(images, [*height, *width], InterpolateOptions::new(mode))
+            }
+            Self::Rescale { factor } => images.mul_scalar(*factor),
+            Self::Normalize { mean, std } => {
+                let [_, channels, _, _] = images.dims();
+                assert!(
+                    mean.len() == channels && std.len() == channels,
+                    "The mean and standard deviation should have one value per channel, got {} \
+                     and {} for {channels} channels.",
+                    mean.len(),
+                    std.len()
+                );
+
+                let device = images.device();
+                let mean = Tensor::<B, 1>::from_floats(mean.as_slice(), &device)
+                    .reshape([1, channels, 1, 1]);
+                let std = Tensor::<B, 1>::from_floats(std.as_slice(), &device)
+                    .reshape([1, channels, 1, 1]);
+
+                (images - mean) / std
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+    use alloc::vec;
+
+    #[test]
+    fn should_apply_the_transforms_in_order() {
+        let config = ImagePreprocessingConfig::new(vec![
+            ImageTransform::Resize {
+                height: 1,
+                width: 2,
+                mode: ResizeMode::Nearest,
+            },
+            ImageTransform::Rescale { factor: 0.5 },
+            ImageTransform::Normalize {
+                mean: vec![1.0, 0.0],
+                std: vec![2.0, 1.0],
+            },
+        ]);
+        let images = Tensor::<TestBackend, 4>::from_floats(
+            [[[[2.0, 4.0], [2.0, 4.0]], [[6.0, 8.0], [6.0, 8.0]]]],
+            &Default::default(),
+        );
+
+        let output = config.apply(images);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 0.5]], [[3.0, 4.0]]]]), 3);
+    }
+}
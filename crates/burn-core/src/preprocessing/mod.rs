@@ -0,0 +1,11 @@
+mod augmentation;
+mod base;
+mod image;
+mod tabular;
+mod text;
+
+pub use augmentation::*;
+pub use base::*;
+pub use image::*;
+pub use tabular::*;
+pub use text::*;
@@ -0,0 +1,504 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, Shape, Tensor};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// A value of a column of a tabular dataset.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TabularValue {
+    /// A numerical value.
+    Number(f64),
+    /// A categorical value.
+    Category(String),
+    /// A missing value.
+    Missing,
+}
+
+impl From<f64> for TabularValue {
+    fn from(value: f64) -> Self {
+        match value.is_nan() {
+            true => Self::Missing,
+            false => Self::Number(value),
+        }
+    }
+}
+
+impl From<&str> for TabularValue {
+    fn from(value: &str) -> Self {
+        Self::Category(value.to_string())
+    }
+}
+
+impl From<String> for TabularValue {
+    fn from(value: String) -> Self {
+        Self::Category(value)
+    }
+}
+
+impl<T: Into<TabularValue>> From<Option<T>> for TabularValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Self::Missing)
+    }
+}
+
+/// How the missing values of a [numerical column](ColumnSpec::Numeric) are replaced.
+#[derive(Config, Debug, PartialEq)]
+pub enum Imputation {
+    /// The mean of the column.
+    Mean,
+    /// The median of the column.
+    Median,
+    /// A constant value.
+    Constant {
+        /// The value replacing the missing values.
+        value: f64,
+    },
+}
+
+/// How a column of a tabular dataset is [fitted](TabularPreprocessingConfig::fit) and encoded
+/// into features.
+#[derive(Config, Debug, PartialEq)]
+pub enum ColumnSpec {
+    /// A numerical column, encoded as one feature.
+    Numeric {
+        /// How the missing values are replaced.
+        imputation: Imputation,
+        /// If the values are standardized with the mean and the standard deviation of the column.
+        standardize: bool,
+        /// If a feature is added, being one when the value is missing and zero otherwise.
+        missing_indicator: bool,
+    },
+    /// A categorical column, encoded as one feature per category.
+    OneHot {
+        /// The maximum number of categories, only the most frequent ones being kept.
+        max_categories: Option<usize>,
+    },
+    /// A categorical column, encoded as one feature being the mean target of its category.
+    ///
+    /// The mean of each category is smoothed towards the mean of all the targets, so rare
+    /// categories don't overfit: `(sum + smoothing * prior) / (count + smoothing)`.
+    TargetEncoding {
+        /// The weight of the mean of all the targets, in number of items.
+        smoothing: f64,
+    },
+    /// A column which isn't used by the model.
+    Drop,
+}
+
+/// The encoding of a column fitted on the training data.
+///
+/// Unknown and missing categories are encoded as zeros for the
+/// [one-hot encoding](ColumnTransform::OneHot) and as the prior for the
+/// [target encoding](ColumnTransform::TargetEncoding).
+#[derive(Config, Debug, PartialEq)]
+pub enum ColumnTransform {
+    /// A numerical column.
+    Numeric {
+        /// The value replacing the missing values.
+        fill: f64,
+        /// The value subtracted from the values.
+        mean: f64,
+        /// The value dividing the centered values.
+        std: f64,
+        /// If a feature is added, being one when the value is missing and zero otherwise.
+        missing_indicator: bool,
+    },
+    /// A one-hot encoded categorical column.
+    OneHot {
+        /// The categories, in order of their features.
+        categories: Vec<String>,
+    },
+    /// A target encoded categorical column.
+    TargetEncoding {
+        /// The categories.
+        categories: Vec<String>,
+        /// The encoding of each category.
+        values: Vec<f64>,
+        /// The encoding of the unknown and missing categories.
+        default: f64,
+    },
+    /// A column which isn't used by the model.
+    Drop,
+}
+
+impl ColumnTransform {
+    /// Fits the encoding of a column on its values.
+    ///
+    /// # Panics
+    ///
+    /// If the values don't have the type of the column, or if the targets are missing for the
+    /// target encoding.
+    pub fn fit(spec: &ColumnSpec, values: &[&TabularValue], targets: Option<&[f64]>) -> Self {
+        match spec {
+            ColumnSpec::Numeric {
+                imputation,
+                standardize,
+                missing_indicator,
+            } => {
+                let mut numbers: Vec<f64> =
+                    values.iter().filter_map(|value| number(value)).collect();
+                let count = numbers.len().max(1) as f64;
+                let mean = numbers.iter().sum::<f64>() / count;
+                let variance = numbers.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / count;
+
+                let fill = match imputation {
+                    Imputation::Mean => mean,
+                    Imputation::Median => median(&mut numbers),
+                    Imputation::Constant { value } => *value,
+                };
+                let (mean, std) = match standardize {
+                    true if variance.sqrt() > f64::EPSILON => (mean, variance.sqrt()),
+                    true => (mean, 1.0),
+                    false => (0.0, 1.0),
+                };
+
+                Self::Numeric {
+                    fill,
+                    mean,
+                    std,
+                    missing_indicator: *missing_indicator,
+                }
+            }
+            ColumnSpec::OneHot { max_categories } => {
+                let mut counts = BTreeMap::<&str, usize>::new();
+                for category in values.iter().filter_map(|value| category(value)) {
+                    *counts.entry(category).or_default() += 1;
+                }
+
+                // Most frequent first, ties sorted by name so the fit is deterministic.
+                let mut counts: Vec<_> = counts.into_iter().collect();
+                counts.sort_by(|(a, count_a), (b, count_b)| count_b.cmp(count_a).then(a.cmp(b)));
+                if let Some(max_categories) = max_categories {
+                    counts.truncate(*max_categories);
+                }
+
+                Self::OneHot {
+                    categories: counts
+                        .into_iter()
+                        .map(|(category, _)| category.to_string())
+                        .collect(),
+                }
+            }
+            ColumnSpec::TargetEncoding { smoothing } => {
+                let targets = targets.expect("The target encoding requires the targets.");
+                assert_eq!(
+                    values.len(),
+                    targets.len(),
+                    "There should be one target per row."
+                );
+                let prior = targets.iter().sum::<f64>() / targets.len().max(1) as f64;
+
+                let mut sums = BTreeMap::<&str, (f64, f64)>::new();
+                for (value, target) in values.iter().zip(targets) {
+                    if let Some(category) = category(value) {
+                        let (sum, count) = sums.entry(category).or_default();
+                        *sum += target;
+                        *count += 1.0;
+                    }
+                }
+
+                let (categories, values) = sums
+                    .into_iter()
+                    .map(|(category, (sum, count))| {
+                        let value = (sum + smoothing * prior) / (count + smoothing);
+                        (category.to_string(), value)
+                    })
+                    .unzip();
+
+                Self::TargetEncoding {
+                    categories,
+                    values,
+                    default: prior,
+                }
+            }
+            ColumnSpec::Drop => Self::Drop,
+        }
+    }
+
+    /// The number of features encoding the column.
+    pub fn num_features(&self) -> usize {
+        match self {
+            Self::Numeric {
+                missing_indicator, ..
+            } => 1 + *missing_indicator as usize,
+            Self::OneHot { categories } => categories.len(),
+            Self::TargetEncoding { .. } => 1,
+            Self::Drop => 0,
+        }
+    }
+
+    /// Encodes a value of the column, appending its features.
+    pub fn encode(&self, value: &TabularValue, features: &mut Vec<f32>) {
+        match self {
+            Self::Numeric {
+                fill,
+                mean,
+                std,
+                missing_indicator,
+            } => {
+                let number = number(value);
+                features.push(((number.unwrap_or(*fill) - mean) / std) as f32);
+                if *missing_indicator {
+                    features.push(number.is_none() as u8 as f32);
+                }
+            }
+            Self::OneHot { categories } => {
+                let index = category(value)
+                    .and_then(|value| categories.iter().position(|category| category == value));
+                features.extend((0..categories.len()).map(|i| (Some(i) == index) as u8 as f32));
+            }
+            Self::TargetEncoding {
+                categories,
+                values,
+                default,
+            } => {
+                let encoding = category(value)
+                    .and_then(|value| {
+                        categories
+                            .binary_search_by(|category| category.as_str().cmp(value))
+                            .ok()
+                    })
+                    .map(|index| values[index])
+                    .unwrap_or(*default);
+                features.push(encoding as f32);
+            }
+            Self::Drop => {}
+        }
+    }
+}
+
+/// Configuration of the preprocessing of tabular data, encoding each row of a table into the
+/// features of a model, e.g. a multilayer perceptron.
+///
+/// The configuration is [fitted](TabularPreprocessingConfig::fit) once on the training split,
+/// then applied to all the splits and [bundled](super::BundledRecord) with the model, so the
+/// rows are encoded the same way at deployment.
+///
+/// ```rust, ignore
+/// let preprocessing = TabularPreprocessingConfig::fit(
+///     &[
+///         ColumnSpec::Numeric {
+///             imputation: Imputation::Median,
+///             standardize: true,
+///             missing_indicator: true,
+///         },
+///         ColumnSpec::OneHot { max_categories: Some(32) },
+///         ColumnSpec::TargetEncoding { smoothing: 10.0 },
+///     ],
+///     &train_rows,
+///     Some(&train_targets),
+/// );
+/// let features: Tensor<B, 2> = preprocessing.apply(&valid_rows, &device);
+/// ```
+#[derive(Config, Debug, PartialEq)]
+pub struct TabularPreprocessingConfig {
+    /// The fitted encoding of each column.
+    pub columns: Vec<ColumnTransform>,
+}
+
+impl TabularPreprocessingConfig {
+    /// Fits the encoding of each column on the rows of the training split.
+    ///
+    /// The targets are only required by the [target encoding](ColumnSpec::TargetEncoding).
+    /// Since the encoding of the training rows then includes their own targets, a large
+    /// smoothing helps the model not to rely on it too much.
+    ///
+    /// # Panics
+    ///
+    /// If a row doesn't have one value per column.
+    pub fn fit(specs: &[ColumnSpec], rows: &[Vec<TabularValue>], targets: Option<&[f64]>) -> Self {
+        Self::check_rows(specs.len(), rows);
+
+        let columns = specs
+            .iter()
+            .enumerate()
+            .map(|(column, spec)| {
+                let values: Vec<&TabularValue> = rows.iter().map(|row| &row[column]).collect();
+                ColumnTransform::fit(spec, &values, targets)
+            })
+            .collect();
+
+        Self::new(columns)
+    }
+
+    /// The number of features of each row.
+    pub fn num_features(&self) -> usize {
+        self.columns.iter().map(ColumnTransform::num_features).sum()
+    }
+
+    /// Encodes the features of a single row.
+    pub fn encode(&self, row: &[TabularValue]) -> Vec<f32> {
+        let mut features = Vec::with_capacity(self.num_features());
+        for (column, value) in self.columns.iter().zip(row) {
+            column.encode(value, &mut features);
+        }
+
+        features
+    }
+
+    /// Encodes a batch of rows.
+    ///
+    /// # Returns
+    ///
+    /// The features of shape `[batch_size, num_features]`.
+    ///
+    /// # Panics
+    ///
+    /// If a row doesn't have one value per column.
+    pub fn apply<B: Backend>(
+        &self,
+        rows: &[Vec<TabularValue>],
+        device: &B::Device,
+    ) -> Tensor<B, 2> {
+        Self::check_rows(self.columns.len(), rows);
+
+        let num_features = self.num_features();
+        let mut features = Vec::with_capacity(rows.len() * num_features);
+        for row in rows {
+            for (column, value) in self.columns.iter().zip(row) {
+                column.encode(value, &mut features);
+            }
+        }
+
+        Tensor::from_data(
+            Data::new(features, Shape::new([rows.len(), num_features])).convert(),
+            device,
+        )
+    }
+
+    fn check_rows(num_columns: usize, rows: &[Vec<TabularValue>]) {
+        for row in rows {
+            assert_eq!(
+                row.len(),
+                num_columns,
+                "Each row should have one value per column."
+            );
+        }
+    }
+}
+
+fn number(value: &TabularValue) -> Option<f64> {
+    match value {
+        TabularValue::Number(number) => Some(*number),
+        TabularValue::Missing => None,
+        TabularValue::Category(category) => {
+            panic!("Expected a number in a numerical column, got the category {category}.")
+        }
+    }
+}
+
+fn category(value: &TabularValue) -> Option<&str> {
+    match value {
+        TabularValue::Category(category) => Some(category),
+        TabularValue::Missing => None,
+        TabularValue::Number(number) => {
+            panic!("Expected a category in a categorical column, got the number {number}.")
+        }
+    }
+}
+
+fn median(numbers: &mut [f64]) -> f64 {
+    if numbers.is_empty() {
+        return 0.0;
+    }
+
+    numbers.sort_by(|a, b| a.total_cmp(b));
+    let middle = numbers.len() / 2;
+    match numbers.len() % 2 {
+        0 => (numbers[middle - 1] + numbers[middle]) / 2.0,
+        _ => numbers[middle],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use alloc::vec;
+
+    fn rows() -> Vec<Vec<TabularValue>> {
+        vec![
+            vec![TabularValue::Number(1.0), "a".into(), "x".into()],
+            vec![TabularValue::Number(2.0), "b".into(), "x".into()],
+            vec![TabularValue::Missing, "a".into(), "y".into()],
+            vec![TabularValue::Number(5.0), TabularValue::Missing, "y".into()],
+        ]
+    }
+
+    fn specs() -> Vec<ColumnSpec> {
+        vec![
+            ColumnSpec::Numeric {
+                imputation: Imputation::Median,
+                standardize: false,
+                missing_indicator: true,
+            },
+            ColumnSpec::OneHot {
+                max_categories: None,
+            },
+            ColumnSpec::TargetEncoding { smoothing: 2.0 },
+        ]
+    }
+
+    #[test]
+    fn should_encode_missing_values_categories_and_targets() {
+        let targets = [1.0, 0.0, 1.0, 1.0];
+        let config = TabularPreprocessingConfig::fit(&specs(), &rows(), Some(&targets));
+
+        assert_eq!(config.num_features(), 5);
+        // The prior is 0.75, x gets (1 + 2 * 0.75) / 4 and y gets (2 + 2 * 0.75) / 4.
+        let features = config.apply::<TestBackend>(
+            &[
+                vec![TabularValue::Missing, "a".into(), "y".into()],
+                vec![TabularValue::Number(3.0), "c".into(), "x".into()],
+                vec![TabularValue::Number(4.0), "b".into(), "z".into()],
+            ],
+            &Default::default(),
+        );
+
+        features.into_data().assert_approx_eq(
+            &Data::from([
+                [2.0, 1.0, 1.0, 0.0, 0.875],
+                [3.0, 0.0, 0.0, 0.0, 0.625],
+                [4.0, 0.0, 0.0, 1.0, 0.75],
+            ]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_standardize_and_keep_the_most_frequent_categories() {
+        let specs = [
+            ColumnSpec::Numeric {
+                imputation: Imputation::Constant { value: 0.0 },
+                standardize: true,
+                missing_indicator: false,
+            },
+            ColumnSpec::OneHot {
+                max_categories: Some(1),
+            },
+            ColumnSpec::Drop,
+        ];
+        let config = TabularPreprocessingConfig::fit(&specs, &rows(), None);
+
+        // The mean is 8 / 3 and the standard deviation is sqrt(26 / 9).
+        let std = (26.0f64 / 9.0).sqrt();
+        let features = config.encode(&[TabularValue::Number(5.0), "a".into(), "x".into()]);
+
+        assert_eq!(features.len(), 2);
+        assert!((features[0] as f64 - (5.0 - 8.0 / 3.0) / std).abs() < 1e-5);
+        assert_eq!(features[1], 1.0);
+        assert_eq!(
+            config.columns[1],
+            ColumnTransform::OneHot {
+                categories: vec!["a".to_string()]
+            }
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Bool, Data, Int, Shape, Tensor};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Encode text into tokens, e.g. with the tokenizer of a language model.
+pub trait Tokenizer {
+    /// Encode the text into tokens, without special tokens.
+    fn encode(&self, text: &str) -> Vec<usize>;
+}
+
+/// Configuration of the preprocessing of text, tokenizing and padding a batch of texts.
+///
+/// The tokenizer itself isn't part of the configuration, only its name, so the same tokenizer
+/// can be loaded at deployment.
+#[derive(Config, Debug, PartialEq)]
+pub struct TextPreprocessingConfig {
+    /// The name of the tokenizer, e.g. the name of its pretrained vocabulary.
+    pub tokenizer: String,
+    /// The maximum number of tokens of each text, including the special tokens, longer texts
+    /// being truncated.
+    pub max_length: usize,
+    /// The token used to pad the texts to the same length.
+    pub pad_token: usize,
+    /// The token added at the start of each text.
+    pub bos_token: Option<usize>,
+    /// The token added at the end of each text.
+    pub eos_token: Option<usize>,
+    /// If the texts are lowercased before being tokenized.
+    #[config(default = false)]
+    pub lowercase: bool,
+}
+
+impl TextPreprocessingConfig {
+    /// Tokenize and pad a batch of texts to the length of the longest one.
+    ///
+    /// # Returns
+    ///
+    /// The tokens of shape `[batch_size, seq_length]` and the padding mask of the same shape,
+    /// where the padding tokens are true.
+    pub fn apply<B: Backend, T: Tokenizer>(
+        &self,
+        tokenizer: &T,
+        texts: &[&str],
+        device: &B::Device,
+    ) -> (Tensor<B, 2, Int>, Tensor<B, 2, Bool>) {
+        let sequences: Vec<Vec<usize>> = texts
+            .iter()
+            .map(|text| self.encode(tokenizer, text))
+            .collect();
+        let seq_length = sequences.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut tokens = Vec::with_capacity(texts.len() * seq_length);
+        let mut mask = Vec::with_capacity(texts.len() * seq_length);
+
+        for sequence in sequences {
+            let num_padding = seq_length - sequence.len();
+            tokens.extend(sequence.iter().map(|token| *token as i64));
+            tokens.extend(core::iter::repeat(self.pad_token as i64).take(num_padding));
+            mask.extend(core::iter::repeat(false).take(sequence.len()));
+            mask.extend(core::iter::repeat(true).take(num_padding));
+        }
+
+        let shape = Shape::new([texts.len(), seq_length]);
+        let tokens = Tensor::from_data(
+            Data::new(tokens, shape.clone()).convert::<B::IntElem>(),
+            device,
+        );
+        let mask = Tensor::from_data(Data::new(mask, shape), device);
+
+        (tokens, mask)
+    }
+
+    /// Tokenize a single text, with its special tokens and truncated to the maximum length.
+    pub fn encode<T: Tokenizer>(&self, tokenizer: &T, text: &str) -> Vec<usize> {
+        let tokens = match self.lowercase {
+            true => tokenizer.encode(&text.to_lowercase()),
+            false => tokenizer.encode(text),
+        };
+
+        let num_special = self.bos_token.is_some() as usize + self.eos_token.is_some() as usize;
+        let num_tokens = self
+            .max_length
+            .saturating_sub(num_special)
+            .min(tokens.len());
+
+        self.bos_token
+            .into_iter()
+            .chain(tokens.into_iter().take(num_tokens))
+            .chain(self.eos_token)
+            .take(self.max_length)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    /// Encodes each character as its code point.
+    struct CharTokenizer;
+
+    impl Tokenizer for CharTokenizer {
+        fn encode(&self, text: &str) -> Vec<usize> {
+            text.chars().map(|char| char as usize).collect()
+        }
+    }
+
+    #[test]
+    fn should_tokenize_truncate_and_pad_the_texts() {
+        let config = TextPreprocessingConfig::new("chars".into(), 4, 0)
+            .with_bos_token(Some(1))
+            .with_eos_token(Some(2))
+            .with_lowercase(true);
+
+        let (tokens, mask) =
+            config.apply::<TestBackend, _>(&CharTokenizer, &["ABC", "a"], &Default::default());
+
+        assert_eq!(
+            tokens.into_data(),
+            Data::from([[1, 97, 98, 2], [1, 97, 2, 0]])
+        );
+        assert_eq!(
+            mask.into_data(),
+            Data::from([[false, false, false, false], [false, false, false, true]])
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+use crate::tensor::backend::Backend;
+use crate::tensor::{ElementConversion, Tensor};
+use hashbrown::HashMap;
+
+/// How the pruned weights are selected among the weights of a module.
+#[derive(Config, Debug, PartialEq)]
+pub enum PruningScope {
+    /// The same fraction of the weights of each parameter is pruned.
+    PerLayer,
+    /// The weights with the lowest scores in the whole module are pruned, so the layers with
+    /// the least important weights are pruned more.
+    Global,
+}
+
+/// Configuration to compute the [masks](PruningMasks) pruning the weights of a module.
+///
+/// Only the weights with at least two dimensions are pruned, e.g. the weights of linear and
+/// convolution layers but not their biases or the parameters of normalization layers.
+#[derive(Config, Debug)]
+pub struct PruningConfig {
+    /// The fraction of the weights pruned, between 0 and 1.
+    pub sparsity: f64,
+    /// How the pruned weights are selected.
+    #[config(default = "PruningScope::PerLayer")]
+    pub scope: PruningScope,
+}
+
+/// The masks of the weights of a module kept by pruning, see [PruningConfig].
+///
+/// Modules don't have forward hooks, so the masks are [applied](PruningMasks::apply) to the
+/// weights themselves, e.g. after each optimizer step while fine-tuning a pruned model so the
+/// pruned weights stay at zero.
+#[derive(Clone, Debug)]
+pub struct PruningMasks<B: Backend> {
+    /// The flattened masks of each parameter, with ones for the kept weights.
+    masks: HashMap<ParamId, Tensor<B, 1>>,
+}
+
+impl PruningConfig {
+    /// Compute the masks pruning the weights with the smallest magnitude.
+    pub fn magnitude<B: Backend, M: Module<B>>(&self, module: &M) -> PruningMasks<B> {
+        let mut collector = WeightCollector::default();
+        module.visit(&mut collector);
+
+        let scores = collector
+            .weights
+            .into_iter()
+            .map(|(id, weight)| (id, weight.abs()))
+            .collect();
+
+        self.from_scores(scores)
+    }
+
+    /// Compute the masks pruning the weights with the lowest scores, given the flattened scores
+    /// of the weights of each parameter.
+    pub fn from_scores<B: Backend>(&self, scores: Vec<(ParamId, Tensor<B, 1>)>) -> PruningMasks<B> {
+        assert!(
+            (0.0..=1.0).contains(&self.sparsity),
+            "The sparsity should be between 0 and 1, got {}.",
+            self.sparsity
+        );
+
+        let global_threshold = match self.scope {
+            PruningScope::Global if !scores.is_empty() => {
+                let all = scores.iter().map(|(_, score)| score.clone()).collect();
+                threshold(Tensor::cat(all, 0), self.sparsity)
+            }
+            _ => None,
+        };
+
+        let masks = scores
+            .into_iter()
+            .map(|(id, score)| {
+                let threshold = match self.scope {
+                    PruningScope::Global => global_threshold,
+                    PruningScope::PerLayer => threshold(score.clone(), self.sparsity),
+                };
+                let mask = match threshold {
+                    Some(threshold) => score.greater_equal_elem(threshold).float(),
+                    None => score.ones_like(),
+                };
+
+                (id, mask)
+            })
+            .collect();
+
+        PruningMasks { masks }
+    }
+}
+
+/// The lowest score kept, or none if no weight is pruned.
+fn threshold<B: Backend>(scores: Tensor<B, 1>, sparsity: f64) -> Option<f64> {
+    let [num_weights] = scores.dims();
+    let num_pruned = (sparsity * num_weights as f64).floor() as usize;
+
+    if num_pruned == 0 {
+        return None;
+    }
+    if num_pruned >= num_weights {
+        return Some(f64::INFINITY);
+    }
+
+    let sorted = scores.sort(0);
+    Some(
+        sorted
+            .slice([num_pruned..num_pruned + 1])
+            .into_scalar()
+            .elem::<f64>(),
+    )
+}
+
+impl<B: Backend> PruningMasks<B> {
+    /// Zero the pruned weights of the module.
+    pub fn apply<M: Module<B>>(&self, module: M) -> M {
+        module.map(&mut MaskApplier { masks: &self.masks })
+    }
+
+    /// The mask of the parameter, with the shape of its weights and ones for the kept weights.
+    pub fn mask<const D: usize>(&self, id: &ParamId, shape: [usize; D]) -> Option<Tensor<B, D>> {
+        self.masks.get(id).map(|mask| mask.clone().reshape(shape))
+    }
+
+    /// The fraction of the masked weights which are pruned.
+    pub fn sparsity(&self) -> f64 {
+        let (num_kept, num_weights) = self
+            .masks
+            .values()
+            .map(|mask| {
+                (
+                    mask.clone().sum().into_scalar().elem::<f64>(),
+                    mask.dims()[0],
+                )
+            })
+            .fold((0.0, 0), |(kept, total), (num_kept, num_weights)| {
+                (kept + num_kept, total + num_weights)
+            });
+
+        match num_weights {
+            0 => 0.0,
+            _ => 1.0 - num_kept / num_weights as f64,
+        }
+    }
+
+    /// The flattened masks of each parameter.
+    pub fn masks(&self) -> &HashMap<ParamId, Tensor<B, 1>> {
+        &self.masks
+    }
+}
+
+/// Collect the flattened weights with at least two dimensions.
+#[derive(Default)]
+pub(crate) struct WeightCollector<B: Backend> {
+    pub(crate) weights: Vec<(ParamId, Tensor<B, 1>)>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for WeightCollector<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if D < 2 {
+            return;
+        }
+
+        let num_weights = tensor.shape().num_elements();
+        self.weights
+            .push((id.clone(), tensor.clone().reshape([num_weights])));
+    }
+}
+
+struct MaskApplier<'a, B: Backend> {
+    masks: &'a HashMap<ParamId, Tensor<B, 1>>,
+}
+
+impl<'a, B: Backend> ModuleMapper<B> for MaskApplier<'a, B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.masks.get(id) {
+            Some(mask) => {
+                let mask = mask.clone().reshape(tensor.shape());
+                tensor * mask
+            }
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::Data;
+    use crate::TestBackend;
+
+    fn linear(weight: [[f32; 2]; 2]) -> Linear<TestBackend> {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(2, 2).init(&device);
+        linear.weight = Param::from_tensor(Tensor::from_floats(weight, &device));
+
+        linear
+    }
+
+    #[test]
+    fn should_prune_the_smallest_weights_of_each_layer() {
+        let layers = [
+            linear([[1.0, -4.0], [0.5, 2.0]]),
+            linear([[10.0, 20.0], [-30.0, 40.0]]),
+        ];
+
+        let masks = PruningConfig::new(0.5).magnitude(&layers.to_vec());
+        let layers = masks.apply(layers.to_vec());
+
+        layers[0]
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, -4.0], [0.0, 2.0]]), 3);
+        layers[1]
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [-30.0, 40.0]]), 3);
+        assert_eq!(masks.sparsity(), 0.5);
+    }
+
+    #[test]
+    fn should_prune_the_smallest_weights_of_the_whole_module() {
+        let layers = vec![
+            linear([[1.0, -4.0], [0.5, 2.0]]),
+            linear([[10.0, 20.0], [-30.0, 40.0]]),
+        ];
+
+        let masks = PruningConfig::new(0.5)
+            .with_scope(PruningScope::Global)
+            .magnitude(&layers);
+        let layers = masks.apply(layers);
+
+        layers[0]
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [0.0, 0.0]]), 3);
+        layers[1]
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[10.0, 20.0], [-30.0, 40.0]]), 3);
+    }
+}
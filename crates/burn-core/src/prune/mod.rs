@@ -0,0 +1,7 @@
+mod masks;
+mod movement;
+mod shrink;
+
+pub use masks::*;
+pub use movement::*;
+pub use shrink::*;
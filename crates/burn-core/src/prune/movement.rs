@@ -0,0 +1,137 @@
+use super::{PruningConfig, PruningMasks};
+use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
+use crate::optim::GradientsParams;
+use crate::tensor::backend::AutodiffBackend;
+use crate::tensor::Tensor;
+use hashbrown::HashMap;
+
+/// The scores of movement pruning, accumulated during fine-tuning.
+///
+/// The score of each weight is the sum of `-w * g` over the training steps, which is high for
+/// the weights moving away from zero, so the weights moving toward zero are pruned regardless of
+/// their magnitude. This works better than magnitude pruning when fine-tuning pretrained models,
+/// whose magnitudes mostly reflect the pretraining task.
+///
+/// ```rust, ignore
+/// let mut scores = MovementScores::new();
+///
+/// for batch in dataloader.iter() {
+///     let grads = GradientsParams::from_grads(model.forward(batch).backward(), &model);
+///     scores.accumulate(&model, &grads);
+///     model = optim.step(lr, model, grads);
+/// }
+///
+/// let masks = scores.masks(&PruningConfig::new(0.9));
+/// let model = masks.apply(model);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MovementScores<B: AutodiffBackend> {
+    scores: HashMap<ParamId, Tensor<B::InnerBackend, 1>>,
+}
+
+impl<B: AutodiffBackend> Default for MovementScores<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: AutodiffBackend> MovementScores<B> {
+    /// Create empty scores.
+    pub fn new() -> Self {
+        Self {
+            scores: HashMap::new(),
+        }
+    }
+
+    /// Accumulate the scores of the weights of the module with their gradients, before the
+    /// optimizer step.
+    pub fn accumulate<M: AutodiffModule<B>>(&mut self, module: &M, grads: &GradientsParams) {
+        let mut visitor = ScoreAccumulator {
+            scores: &mut self.scores,
+            grads,
+        };
+        module.visit(&mut visitor);
+    }
+
+    /// Compute the masks pruning the weights with the lowest scores.
+    pub fn masks(&self, config: &PruningConfig) -> PruningMasks<B> {
+        let scores = self
+            .scores
+            .iter()
+            .map(|(id, score)| (id.clone(), Tensor::from_inner(score.clone())))
+            .collect();
+
+        config.from_scores(scores)
+    }
+}
+
+struct ScoreAccumulator<'a, B: AutodiffBackend> {
+    scores: &'a mut HashMap<ParamId, Tensor<B::InnerBackend, 1>>,
+    grads: &'a GradientsParams,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for ScoreAccumulator<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if D < 2 {
+            return;
+        }
+        let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) else {
+            return;
+        };
+
+        let num_weights = tensor.shape().num_elements();
+        let score = (tensor.clone().inner() * grad).neg().reshape([num_weights]);
+
+        let score = match self.scores.remove(id) {
+            Some(previous) => previous + score,
+            None => score,
+        };
+        self.scores.insert(id.clone(), score);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::{Data, Distribution};
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn should_prune_the_weights_moving_toward_zero() {
+        let device = Default::default();
+        let mut linear: Linear<TestAutodiffBackend> = LinearConfig::new(2, 1).init(&device);
+        linear.weight = Param::from_tensor(Tensor::from_floats([[3.0], [0.1]], &device));
+        let mut scores = MovementScores::new();
+
+        // Minimizing the output pushes the large weight toward zero and the small one away
+        // from zero, as the inputs have opposite signs.
+        let input = Tensor::from_floats([[1.0, -1.0]], &device);
+        let grads = linear.forward(input).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        scores.accumulate(&linear, &grads);
+
+        let linear = scores.masks(&PruningConfig::new(0.5)).apply(linear);
+
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0], [0.1]]), 3);
+    }
+
+    #[test]
+    fn should_accumulate_the_scores_of_all_the_steps() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(4, 4).init(&device);
+        let mut scores = MovementScores::new();
+
+        for _ in 0..2 {
+            let input = Tensor::random([2, 4], Distribution::Default, &device);
+            let grads = linear.forward(input).sum().backward();
+            scores.accumulate(&linear, &GradientsParams::from_grads(grads, &linear));
+        }
+
+        assert_eq!(scores.masks(&PruningConfig::new(0.25)).sparsity(), 0.25);
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+
+use crate::nn::Linear;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, Int, Shape, Tensor};
+
+/// Remove the output channels of the first linear layer whose weights and bias are all zero,
+/// with the matching input channels of the second layer, so a pruned model becomes smaller
+/// instead of only sparser.
+///
+/// The zeroed channels always output zero, so they only change the output of the second layer
+/// if the activation between both layers doesn't map zero to zero, e.g. a sigmoid. The
+/// parameter ids are kept, so the shrunk layers can still be matched with their records or the
+/// state of an optimizer.
+///
+/// When all the channels are zeroed, the layers are returned unchanged.
+pub fn shrink_linear_pair<B: Backend>(
+    first: Linear<B>,
+    second: Linear<B>,
+) -> (Linear<B>, Linear<B>) {
+    let [_, d_hidden] = first.weight.dims();
+    let [d_input, _] = second.weight.dims();
+    assert_eq!(
+        d_hidden, d_input,
+        "The output features of the first layer should be the input features of the second one."
+    );
+
+    let mut magnitude = first.weight.val().abs().sum_dim(0);
+    if let Some(bias) = &first.bias {
+        magnitude = magnitude + bias.val().abs().unsqueeze();
+    }
+
+    let kept: Vec<i32> = magnitude
+        .into_data()
+        .convert::<f32>()
+        .value
+        .into_iter()
+        .enumerate()
+        .filter(|(_, magnitude)| *magnitude != 0.0)
+        .map(|(index, _)| index as i32)
+        .collect();
+
+    if kept.is_empty() || kept.len() == d_hidden {
+        return (first, second);
+    }
+
+    let device = first.weight.device();
+    let num_kept = kept.len();
+    let indices = Tensor::<B, 1, Int>::from_ints(Data::new(kept, Shape::new([num_kept])), &device);
+
+    let first = Linear {
+        weight: first.weight.map(|weight| weight.select(1, indices.clone())),
+        bias: first
+            .bias
+            .map(|bias| bias.map(|bias| bias.select(0, indices.clone()))),
+    };
+    let second = Linear {
+        weight: second
+            .weight
+            .map(|weight| weight.select(0, indices.clone())),
+        bias: second.bias,
+    };
+
+    (first, second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::nn::LinearConfig;
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_remove_the_zeroed_channels() {
+        let device = Default::default();
+        let mut first = LinearConfig::new(2, 3).init::<TestBackend>(&device);
+        let second = LinearConfig::new(3, 2).init::<TestBackend>(&device);
+        first.weight = Param::from_tensor(Tensor::from_floats(
+            [[1.0, 0.0, 2.0], [3.0, 0.0, -1.0]],
+            &device,
+        ));
+        first.bias = Some(Param::from_tensor(Tensor::from_floats(
+            [0.5, 0.0, 0.0],
+            &device,
+        )));
+        let input = Tensor::<TestBackend, 2>::random([4, 2], Distribution::Default, &device);
+        let expected = second.forward(first.forward(input.clone()).clamp_min(0.0));
+        let ids = (first.weight.id.clone(), second.weight.id.clone());
+
+        let (first, second) = shrink_linear_pair(first, second);
+        let output = second.forward(first.forward(input).clamp_min(0.0));
+
+        assert_eq!(first.weight.dims(), [2, 2]);
+        assert_eq!(second.weight.dims(), [2, 2]);
+        assert_eq!((first.weight.id.clone(), second.weight.id.clone()), ids);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+}
@@ -0,0 +1,504 @@
+use super::{BurnMetadata, RecorderError};
+use flate2::read::GzDecoder;
+use serde_json::{Map, Value};
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+/// Statistics of the values of a tensor, ignoring the NaN values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensorStats {
+    /// The minimum value.
+    pub min: f64,
+    /// The maximum value.
+    pub max: f64,
+    /// The mean of the values.
+    pub mean: f64,
+    /// The standard deviation of the values.
+    pub std: f64,
+    /// The number of NaN values.
+    pub num_nan: usize,
+}
+
+/// A tensor contained in a record file, found by [RecordInspection].
+#[derive(Debug, Clone)]
+pub struct TensorInfo {
+    /// The path of the tensor in the record, e.g. `layers.0.weight`.
+    pub path: String,
+    /// The shape of the tensor.
+    pub shape: Vec<usize>,
+    /// The element type of the tensor.
+    pub dtype: String,
+    /// The hash of the values, identical for the same tensor saved with different recorders.
+    pub checksum: u64,
+    /// The statistics of the values.
+    pub stats: TensorStats,
+    values: Vec<f64>,
+}
+
+/// The tensors contained in a record file, to debug weight loading issues without having to
+/// define the module the record belongs to.
+///
+/// The record must be saved with a self-describing format, i.e. with the
+/// [named msgpack](super::NamedMpkFileRecorder) or the [json](super::PrettyJsonFileRecorder)
+/// recorders, compressed or not.
+///
+/// # Notes
+///
+/// Half precision floats are saved as their bits, which can't be told apart from integers. The
+/// tensors of [parameters](crate::module::Param) are assumed to be floats, other integer tensors
+/// are assumed to be integers.
+#[derive(Debug)]
+pub struct RecordInspection {
+    /// The metadata of the record.
+    pub metadata: BurnMetadata,
+    /// The tensors of the record, ordered by path.
+    pub tensors: Vec<TensorInfo>,
+}
+
+/// The differences between two [record inspections](RecordInspection).
+#[derive(Debug, Clone, Default)]
+pub struct RecordDiff {
+    /// The tensors only found in the first record.
+    pub only_in_first: Vec<String>,
+    /// The tensors only found in the second record.
+    pub only_in_second: Vec<String>,
+    /// The tensors with different shapes, with their shape in the first and second record.
+    pub shape_mismatches: Vec<(String, Vec<usize>, Vec<usize>)>,
+    /// The tensors with different element types, with their type in the first and second record.
+    pub dtype_mismatches: Vec<(String, String, String)>,
+    /// The tensors with different values, with their maximum absolute difference.
+    pub value_mismatches: Vec<(String, f64)>,
+}
+
+impl RecordInspection {
+    /// Inspects the record saved at the given path, with the format deduced from its extension.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, RecorderError> {
+        let path = path.as_ref();
+        let file_name = path.to_string_lossy();
+        let file = File::open(path).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+            _ => RecorderError::Unknown(err.to_string()),
+        })?;
+        let reader = BufReader::new(file);
+
+        let value = if file_name.ends_with(".mpk.gz") {
+            read_mpk(GzDecoder::new(reader))?
+        } else if file_name.ends_with(".mpk") {
+            read_mpk(reader)?
+        } else if file_name.ends_with(".json.gz") {
+            read_json(GzDecoder::new(reader))?
+        } else if file_name.ends_with(".json") {
+            read_json(reader)?
+        } else {
+            return Err(RecorderError::Unknown(format!(
+                "Can't inspect {file_name}, only the named msgpack and json formats are \
+                 self-describing."
+            )));
+        };
+
+        Self::from_value(value)
+    }
+
+    /// Inspects a record deserialized into a generic value.
+    pub fn from_value(value: Value) -> Result<Self, RecorderError> {
+        let mut record = match value {
+            Value::Object(record) => record,
+            _ => {
+                return Err(RecorderError::DeserializeError(
+                    "The record should be a map.".into(),
+                ))
+            }
+        };
+        let metadata: BurnMetadata = record
+            .remove("metadata")
+            .map(serde_json::from_value)
+            .ok_or_else(|| RecorderError::DeserializeError("Missing metadata.".into()))?
+            .map_err(|err| RecorderError::DeserializeError(err.to_string()))?;
+        let item = record
+            .remove("item")
+            .ok_or_else(|| RecorderError::DeserializeError("Missing item.".into()))?;
+
+        let mut collector = TensorCollector {
+            float: short_type_name(&metadata.float),
+            int: short_type_name(&metadata.int),
+            path: Vec::new(),
+            tensors: Vec::new(),
+        };
+        collector.visit(&item, false);
+        let tensors = collector.tensors;
+
+        Ok(Self { metadata, tensors })
+    }
+
+    /// Returns the tensor at the given path.
+    pub fn tensor(&self, path: &str) -> Option<&TensorInfo> {
+        self.tensors.iter().find(|tensor| tensor.path == path)
+    }
+
+    /// Returns the total number of elements of the tensors.
+    pub fn num_elements(&self) -> usize {
+        self.tensors
+            .iter()
+            .map(|tensor| tensor.shape.iter().product::<usize>())
+            .sum()
+    }
+
+    /// Compares the tensors of two records, e.g. a checkpoint before and after being converted.
+    ///
+    /// The values of tensors with the same shape are considered different when their absolute
+    /// difference exceeds the tolerance.
+    pub fn diff(&self, other: &Self, tolerance: f64) -> RecordDiff {
+        let mut diff = RecordDiff::default();
+
+        for tensor in self.tensors.iter() {
+            let other = match other.tensor(&tensor.path) {
+                Some(other) => other,
+                None => {
+                    diff.only_in_first.push(tensor.path.clone());
+                    continue;
+                }
+            };
+
+            if tensor.dtype != other.dtype {
+                diff.dtype_mismatches.push((
+                    tensor.path.clone(),
+                    tensor.dtype.clone(),
+                    other.dtype.clone(),
+                ));
+            }
+
+            if tensor.shape != other.shape {
+                diff.shape_mismatches.push((
+                    tensor.path.clone(),
+                    tensor.shape.clone(),
+                    other.shape.clone(),
+                ));
+            } else if tensor.checksum != other.checksum {
+                let max_diff = tensor
+                    .values
+                    .iter()
+                    .zip(other.values.iter())
+                    .map(|(a, b)| (a - b).abs())
+                    .fold(0.0, f64::max);
+
+                if max_diff > tolerance {
+                    diff.value_mismatches.push((tensor.path.clone(), max_diff));
+                }
+            }
+        }
+
+        diff.only_in_second = other
+            .tensors
+            .iter()
+            .filter(|tensor| self.tensor(&tensor.path).is_none())
+            .map(|tensor| tensor.path.clone())
+            .collect();
+
+        diff
+    }
+}
+
+impl RecordDiff {
+    /// Returns true if both records contain the same tensors.
+    pub fn is_empty(&self) -> bool {
+        self.only_in_first.is_empty()
+            && self.only_in_second.is_empty()
+            && self.shape_mismatches.is_empty()
+            && self.dtype_mismatches.is_empty()
+            && self.value_mismatches.is_empty()
+    }
+}
+
+impl core::fmt::Display for RecordInspection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(
+            f,
+            "{} tensors, {} elements (float: {}, int: {}, burn {})",
+            self.tensors.len(),
+            self.num_elements(),
+            self.metadata.float,
+            self.metadata.int,
+            self.metadata.version
+        )?;
+
+        for tensor in self.tensors.iter() {
+            writeln!(f, "{tensor}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for TensorInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let stats = &self.stats;
+        write!(
+            f,
+            "{} {:?} {} checksum={:016x} min={:.6} max={:.6} mean={:.6} std={:.6}",
+            self.path,
+            self.shape,
+            self.dtype,
+            self.checksum,
+            stats.min,
+            stats.max,
+            stats.mean,
+            stats.std
+        )?;
+
+        if stats.num_nan > 0 {
+            write!(f, " nan={}", stats.num_nan)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for RecordDiff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "The records contain the same tensors.");
+        }
+
+        for path in self.only_in_first.iter() {
+            writeln!(f, "- {path}")?;
+        }
+        for path in self.only_in_second.iter() {
+            writeln!(f, "+ {path}")?;
+        }
+        for (path, first, second) in self.shape_mismatches.iter() {
+            writeln!(f, "~ {path}: shape {first:?} != {second:?}")?;
+        }
+        for (path, first, second) in self.dtype_mismatches.iter() {
+            writeln!(f, "~ {path}: dtype {first} != {second}")?;
+        }
+        for (path, max_diff) in self.value_mismatches.iter() {
+            writeln!(f, "~ {path}: max absolute difference {max_diff:e}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn read_mpk<R: Read>(reader: R) -> Result<Value, RecorderError> {
+    rmp_serde::decode::from_read(reader)
+        .map_err(|err| RecorderError::DeserializeError(err.to_string()))
+}
+
+fn read_json<R: Read>(reader: R) -> Result<Value, RecorderError> {
+    serde_json::from_reader(reader).map_err(|err| RecorderError::DeserializeError(err.to_string()))
+}
+
+fn short_type_name(name: &str) -> String {
+    name.rsplit("::").next().unwrap_or(name).to_string()
+}
+
+/// Walks a record to find its tensors, serialized as a map with a `value` and a `shape`.
+struct TensorCollector {
+    float: String,
+    int: String,
+    path: Vec<String>,
+    tensors: Vec<TensorInfo>,
+}
+
+impl TensorCollector {
+    fn visit(&mut self, value: &Value, is_param: bool) {
+        match value {
+            Value::Object(map) => {
+                if let Some((values, shape)) = as_tensor(map) {
+                    self.register(values, shape, is_param);
+                } else if let (2, Some(param), true) =
+                    (map.len(), map.get("param"), map.contains_key("id"))
+                {
+                    self.visit(param, true);
+                } else {
+                    for (key, value) in map.iter() {
+                        self.path.push(key.clone());
+                        self.visit(value, false);
+                        self.path.pop();
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for (index, value) in items.iter().enumerate() {
+                    self.path.push(index.to_string());
+                    self.visit(value, false);
+                    self.path.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn register(&mut self, values: &[Value], shape: Vec<usize>, is_param: bool) {
+        let dtype = if values.iter().any(Value::is_boolean) {
+            "bool".to_string()
+        } else if values.iter().any(Value::is_f64) || is_param {
+            self.float.clone()
+        } else {
+            self.int.clone()
+        };
+
+        let values: Vec<f64> = values
+            .iter()
+            .map(|value| match value {
+                Value::Bool(value) => *value as u8 as f64,
+                Value::Number(number) => match dtype.as_str() {
+                    "f16" if !number.is_f64() => {
+                        half::f16::from_bits(number.as_u64().unwrap_or(0) as u16).to_f64()
+                    }
+                    "bf16" if !number.is_f64() => {
+                        half::bf16::from_bits(number.as_u64().unwrap_or(0) as u16).to_f64()
+                    }
+                    _ => number.as_f64().unwrap_or(f64::NAN),
+                },
+                _ => f64::NAN,
+            })
+            .collect();
+
+        self.tensors.push(TensorInfo {
+            path: self.path.join("."),
+            checksum: checksum(&values, &dtype),
+            stats: stats(&values),
+            shape,
+            dtype,
+            values,
+        });
+    }
+}
+
+fn as_tensor(map: &Map<String, Value>) -> Option<(&[Value], Vec<usize>)> {
+    if map.len() != 2 {
+        return None;
+    }
+
+    let values = map.get("value")?.as_array()?;
+    let shape = map
+        .get("shape")?
+        .as_array()?
+        .iter()
+        .map(|dim| dim.as_u64().map(|dim| dim as usize))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some((values, shape))
+}
+
+/// FNV-1a hash of the values, rounded to the precision of their type so floats parsed from json
+/// and msgpack hash the same.
+fn checksum(values: &[f64], dtype: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut write = |bytes: &[u8]| {
+        for byte in bytes {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    };
+
+    for value in values {
+        match dtype {
+            "f64" => write(&value.to_bits().to_le_bytes()),
+            "f32" | "f16" | "bf16" => write(&(*value as f32).to_bits().to_le_bytes()),
+            _ => write(&(*value as i64).to_le_bytes()),
+        }
+    }
+
+    hash
+}
+
+fn stats(values: &[f64]) -> TensorStats {
+    let num_nan = values.iter().filter(|value| value.is_nan()).count();
+    let count = (values.len() - num_nan).max(1) as f64;
+    let values = values.iter().filter(|value| !value.is_nan());
+
+    let min = values.clone().fold(f64::INFINITY, |a, b| a.min(*b));
+    let max = values.clone().fold(f64::NEG_INFINITY, |a, b| a.max(*b));
+    let mean = values.clone().sum::<f64>() / count;
+    let var = values.map(|value| (value - mean).powi(2)).sum::<f64>() / count;
+
+    TensorStats {
+        min,
+        max,
+        mean,
+        std: var.sqrt(),
+        num_nan,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Module;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::record::{
+        FileRecorder, FullPrecisionSettings, NamedMpkFileRecorder, PrettyJsonFileRecorder, Recorder,
+    };
+    use crate::TestBackend;
+
+    fn save<R: FileRecorder<TestBackend>>(recorder: R, model: Linear<TestBackend>, name: &str) {
+        let path = std::env::temp_dir().join(name);
+        recorder.record(model.into_record(), path).unwrap();
+    }
+
+    #[test]
+    fn should_list_the_tensors_of_a_record() {
+        let model = LinearConfig::new(3, 2).init::<TestBackend>(&Default::default());
+        save(
+            NamedMpkFileRecorder::<FullPrecisionSettings>::new(),
+            model.clone(),
+            "burn_test_inspect_list",
+        );
+
+        let inspection =
+            RecordInspection::from_file(std::env::temp_dir().join("burn_test_inspect_list.mpk"))
+                .unwrap();
+
+        let weight = inspection.tensor("weight").unwrap();
+        assert_eq!(inspection.tensors.len(), 2);
+        assert_eq!(weight.shape, vec![3, 2]);
+        assert_eq!(weight.dtype, "f32");
+        assert_eq!(inspection.tensor("bias").unwrap().shape, vec![2]);
+
+        let values = model.weight.val().into_data().convert::<f32>().value;
+        let max = values.iter().fold(f32::NEG_INFINITY, |a, b| a.max(*b));
+        assert_eq!(weight.stats.max, max as f64);
+        assert_eq!(weight.stats.num_nan, 0);
+    }
+
+    #[test]
+    fn should_diff_records_saved_with_different_formats() {
+        let device = Default::default();
+        let model = LinearConfig::new(3, 2).init::<TestBackend>(&device);
+        let other = LinearConfig::new(3, 4)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        save(
+            PrettyJsonFileRecorder::<FullPrecisionSettings>::new(),
+            model.clone(),
+            "burn_test_inspect_diff_1",
+        );
+        save(
+            NamedMpkFileRecorder::<FullPrecisionSettings>::new(),
+            model,
+            "burn_test_inspect_diff_2",
+        );
+        save(
+            NamedMpkFileRecorder::<FullPrecisionSettings>::new(),
+            other,
+            "burn_test_inspect_diff_3",
+        );
+        let inspect = |name: &str| RecordInspection::from_file(std::env::temp_dir().join(name));
+        let first = inspect("burn_test_inspect_diff_1.json").unwrap();
+        let second = inspect("burn_test_inspect_diff_2.mpk").unwrap();
+        let third = inspect("burn_test_inspect_diff_3.mpk").unwrap();
+
+        let same = first.diff(&second, 0.0);
+        let different = second.diff(&third, 0.0);
+
+        assert!(same.is_empty());
+        assert_eq!(different.only_in_first, vec!["bias".to_string()]);
+        assert_eq!(
+            different.shape_mismatches,
+            vec![("weight".to_string(), vec![3, 2], vec![3, 4])]
+        );
+    }
+}
@@ -3,12 +3,16 @@ mod tensor;
 
 mod base;
 mod memory;
+mod offload;
 mod recorder;
+mod rng;
 mod settings;
 
 pub use base::*;
 pub use memory::*;
+pub use offload::*;
 pub use recorder::*;
+pub use rng::*;
 pub use settings::*;
 
 #[cfg(feature = "std")]
@@ -16,6 +20,11 @@ mod file;
 #[cfg(feature = "std")]
 pub use file::*;
 
+#[cfg(feature = "std")]
+mod inspect;
+#[cfg(feature = "std")]
+pub use inspect::*;
+
 pub use primitive::ParamSerde;
 
 #[cfg(feature = "record-item-custom-serde")]
@@ -0,0 +1,60 @@
+use super::{bin_config, FullPrecisionSettings, Record};
+use alloc::vec::Vec;
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+
+/// A [record](Record) offloaded to host memory, releasing the memory of its tensors on the
+/// device until it is [loaded](OffloadedRecord::load) back.
+///
+/// The tensors are kept with their full precision, so offloading a record is lossless.
+pub struct OffloadedRecord<R, B: Backend> {
+    bytes: Vec<u8>,
+    device: B::Device,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R: Record<B>, B: Backend> OffloadedRecord<R, B> {
+    /// Offload the record to host memory, to be loaded back on the given device.
+    pub fn new(record: R, device: &B::Device) -> Self {
+        let item = record.into_item::<FullPrecisionSettings>();
+        let bytes = bincode::serde::encode_to_vec(item, bin_config()).unwrap();
+
+        Self {
+            bytes,
+            device: device.clone(),
+            _record: PhantomData,
+        }
+    }
+
+    /// Load the record back on its device.
+    pub fn load(self) -> R {
+        let (item, _) = bincode::serde::decode_from_slice(&self.bytes, bin_config())
+            .expect("The offloaded record should be decoded as it was encoded");
+
+        R::from_item::<FullPrecisionSettings>(item, &self.device)
+    }
+
+    /// The number of bytes of host memory used by the record.
+    pub fn size(&self) -> usize {
+        self.bytes.len()
+    }
+}
+
+impl<R, B: Backend> Clone for OffloadedRecord<R, B> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+            device: self.device.clone(),
+            _record: PhantomData,
+        }
+    }
+}
+
+impl<R, B: Backend> core::fmt::Debug for OffloadedRecord<R, B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OffloadedRecord")
+            .field("size", &self.bytes.len())
+            .field("device", &self.device)
+            .finish()
+    }
+}
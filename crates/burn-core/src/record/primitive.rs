@@ -7,7 +7,7 @@ use core::{fmt, marker::PhantomData};
 
 use super::tensor::{BoolTensorSerde, FloatTensorSerde, IntTensorSerde};
 use super::{PrecisionSettings, Record};
-use crate::module::{Param, ParamId};
+use crate::module::{full_precision_device, FullPrecisionParam, Param, ParamId};
 
 use burn_tensor::{backend::Backend, Bool, DataSerialize, Element, Int, Tensor};
 
@@ -188,6 +188,24 @@ where
     }
 }
 
+impl<B, const D: usize> Record<B> for FullPrecisionParam<B, D>
+where
+    B: Backend,
+{
+    type Item<S: PrecisionSettings> = ParamSerde<FloatTensorSerde<S>>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        ParamSerde::new(self.id.into_string(), self.value.into_item())
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        FullPrecisionParam::new(
+            ParamId::from(item.id),
+            Tensor::from_item(item.param, &full_precision_device::<B>(device)),
+        )
+    }
+}
+
 impl<B, const D: usize> Record<B> for Param<Tensor<B, D, Int>>
 where
     B: Backend,
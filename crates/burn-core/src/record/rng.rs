@@ -0,0 +1,73 @@
+use crate as burn;
+
+use super::Record;
+use burn_tensor::backend::Backend;
+
+/// The state of the random number generator of a backend, to be saved with the other records of
+/// a training, e.g. in a checkpoint, so that the random values of the dropout masks and the
+/// augmentations continue deterministically when resuming from it.
+///
+/// [Capturing](RngState::capture) the state [forks](Backend::fork_seed) the random number
+/// generator of the backend, so the random values after a capture are the same as the ones after
+/// [restoring](RngState::restore) it.
+#[derive(Record, Clone, Debug)]
+pub struct RngState {
+    seed: Option<u64>,
+}
+
+impl RngState {
+    /// Capture the state of the random number generator of the backend.
+    ///
+    /// The state is empty if the backend doesn't support restoring it.
+    pub fn capture<B: Backend>() -> Self {
+        Self {
+            seed: B::fork_seed(),
+        }
+    }
+
+    /// Restore the state of the random number generator of the backend, if not empty.
+    pub fn restore<B: Backend>(&self) {
+        if let Some(seed) = self.seed {
+            B::seed(seed);
+        }
+    }
+
+    /// If the state is empty, the backend it was captured from not supporting it.
+    pub fn is_empty(&self) -> bool {
+        self.seed.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Distribution, Tensor};
+    use crate::TestBackend;
+
+    fn random() -> Tensor<TestBackend, 1> {
+        Tensor::random([8], Distribution::Default, &Default::default())
+    }
+
+    #[test]
+    fn restored_state_should_replay_the_random_values() {
+        TestBackend::seed(0);
+        random();
+
+        let state = RngState::capture::<TestBackend>();
+        assert!(!state.is_empty());
+        let expected = random();
+
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = Recorder::<TestBackend>::record(&recorder, state, ()).unwrap();
+        random();
+
+        let state: RngState =
+            Recorder::<TestBackend>::load(&recorder, bytes, &Default::default()).unwrap();
+        state.restore::<TestBackend>();
+
+        random()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+}
@@ -0,0 +1,369 @@
+use crate::{
+    ir::{
+        Atomic, Branch, CubeDim, Elem, KernelDefinition, Operation, Operator, Procedure, Scope,
+        Subcube, Variable,
+    },
+    Compiler,
+};
+
+/// Static estimation of the work done by each unit of a kernel, used to find out whether the
+/// kernel is memory-bound or compute-bound on a device.
+///
+/// Only the floating point operations are counted as FLOPs, and only the accesses to global
+/// memory are counted as bytes moved. The branches are assumed to be taken, and the loops whose
+/// bounds aren't known at compile time are assumed to be executed once, in which case the
+/// estimation is flagged with [dynamic loops](KernelAnalysis::dynamic_loops).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KernelAnalysis {
+    /// The number of floating point operations per unit.
+    pub flops: u64,
+    /// The number of bytes read from global memory per unit.
+    pub bytes_read: u64,
+    /// The number of bytes written to global memory per unit.
+    pub bytes_written: u64,
+    /// The number of units in a cube.
+    pub cube_dim: CubeDim,
+    /// Whether the kernel has loops whose number of iterations is unknown at compile time.
+    pub dynamic_loops: bool,
+}
+
+/// Whether a kernel is limited by the memory bandwidth or by the arithmetic throughput.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KernelBound {
+    /// The kernel is limited by the memory bandwidth.
+    Memory,
+    /// The kernel is limited by the arithmetic throughput.
+    Compute,
+}
+
+/// The peak performance of a device, defining its roofline.
+#[derive(new, Debug, Clone, Copy, PartialEq)]
+pub struct DeviceRoofline {
+    /// The peak number of floating point operations per second.
+    pub peak_flops: f64,
+    /// The peak global memory bandwidth, in bytes per second.
+    pub peak_bandwidth: f64,
+}
+
+impl DeviceRoofline {
+    /// The arithmetic intensity, in FLOPs per byte, above which kernels are compute-bound.
+    pub fn ridge_point(&self) -> f64 {
+        self.peak_flops / self.peak_bandwidth
+    }
+
+    /// Whether a kernel with the given arithmetic intensity is memory-bound or compute-bound.
+    pub fn bound(&self, arithmetic_intensity: f64) -> KernelBound {
+        match arithmetic_intensity < self.ridge_point() {
+            true => KernelBound::Memory,
+            false => KernelBound::Compute,
+        }
+    }
+}
+
+impl KernelAnalysis {
+    /// Analyze the [kernel definition](KernelDefinition) with the element sizes of the compiler.
+    pub fn new<C: Compiler>(definition: &KernelDefinition) -> Self {
+        let mut counter = Counter::new(C::elem_size);
+        counter.scope(&definition.body);
+
+        Self {
+            flops: counter.flops,
+            bytes_read: counter.bytes_read,
+            bytes_written: counter.bytes_written,
+            cube_dim: definition.cube_dim,
+            dynamic_loops: counter.dynamic_loops,
+        }
+    }
+
+    /// The number of bytes moved from and to global memory per unit.
+    pub fn bytes(&self) -> u64 {
+        self.bytes_read + self.bytes_written
+    }
+
+    /// The number of FLOPs per byte moved.
+    pub fn arithmetic_intensity(&self) -> f64 {
+        match self.bytes() {
+            0 => f64::INFINITY,
+            bytes => self.flops as f64 / bytes as f64,
+        }
+    }
+
+    /// The number of units in a cube.
+    pub fn num_units_per_cube(&self) -> u64 {
+        self.cube_dim.x as u64 * self.cube_dim.y as u64 * self.cube_dim.z as u64
+    }
+}
+
+struct Counter {
+    elem_size: fn(Elem) -> usize,
+    flops: u64,
+    bytes_read: u64,
+    bytes_written: u64,
+    dynamic_loops: bool,
+}
+
+impl Counter {
+    fn new(elem_size: fn(Elem) -> usize) -> Self {
+        Self {
+            elem_size,
+            flops: 0,
+            bytes_read: 0,
+            bytes_written: 0,
+            dynamic_loops: false,
+        }
+    }
+
+    fn scope(&mut self, scope: &Scope) {
+        // Processing the scope adds the global reads and writes registered with the scope.
+        let processing = scope.clone().process();
+
+        for operation in processing.operations.iter() {
+            self.operation(operation);
+        }
+    }
+
+    fn operation(&mut self, operation: &Operation) {
+        match operation {
+            Operation::Operator(operator) => self.operator(operator),
+            Operation::Procedure(procedure) => self.procedure(procedure),
+            Operation::Branch(branch) => self.branch(branch),
+            Operation::Subcube(subcube) => self.subcube(subcube),
+            Operation::Atomic(atomic) => self.atomic(atomic),
+            Operation::Metadata(_) | Operation::Synchronization(_) => {}
+        }
+    }
+
+    fn operator(&mut self, operator: &Operator) {
+        match operator {
+            Operator::Add(op)
+            | Operator::Sub(op)
+            | Operator::Mul(op)
+            | Operator::Div(op)
+            | Operator::Powf(op)
+            | Operator::Modulo(op)
+            | Operator::Remainder(op)
+            | Operator::Max(op)
+            | Operator::Min(op) => self.flop(op.out),
+            Operator::Abs(op)
+            | Operator::Exp(op)
+            | Operator::Log(op)
+            | Operator::Log1p(op)
+            | Operator::Cos(op)
+            | Operator::Sin(op)
+            | Operator::Tanh(op)
+            | Operator::Sqrt(op)
+            | Operator::Floor(op)
+            | Operator::Ceil(op)
+            | Operator::Erf(op)
+            | Operator::Recip(op) => self.flop(op.out),
+            Operator::Clamp(op) => self.flop(op.out),
+            Operator::Index(op) | Operator::UncheckedIndex(op) => self.read(op.lhs),
+            Operator::IndexAssign(op) | Operator::UncheckedIndexAssign(op) => self.write(op.out),
+            _ => {}
+        }
+    }
+
+    fn procedure(&mut self, procedure: &Procedure) {
+        match procedure {
+            Procedure::ReadGlobal(proc) => self.read(proc.global),
+            Procedure::ReadGlobalWithLayout(proc) => {
+                proc.globals.iter().for_each(|global| self.read(*global))
+            }
+            Procedure::WriteGlobal(proc) => self.write(proc.global),
+            Procedure::CheckedIndex(proc) => self.read(proc.lhs),
+            Procedure::CheckedIndexAssign(proc) => self.write(proc.out),
+            Procedure::IndexOffsetGlobalWithLayout(_) | Procedure::ConditionalAssign(_) => {}
+        }
+    }
+
+    fn branch(&mut self, branch: &Branch) {
+        match branch {
+            Branch::If(op) => self.scope(&op.scope),
+            Branch::IfElse(op) => {
+                let mut counter_if = Counter::new(self.elem_size);
+                let mut counter_else = Counter::new(self.elem_size);
+                counter_if.scope(&op.scope_if);
+                counter_else.scope(&op.scope_else);
+
+                // Only one branch is executed, so the most expensive one is counted.
+                self.flops += u64::max(counter_if.flops, counter_else.flops);
+                self.bytes_read += u64::max(counter_if.bytes_read, counter_else.bytes_read);
+                self.bytes_written +=
+                    u64::max(counter_if.bytes_written, counter_else.bytes_written);
+                self.dynamic_loops |= counter_if.dynamic_loops || counter_else.dynamic_loops;
+            }
+            Branch::RangeLoop(op) => {
+                let mut counter = Counter::new(self.elem_size);
+                counter.scope(&op.scope);
+
+                let num_iterations = match (op.start, op.end) {
+                    (Variable::ConstantScalar(start, _), Variable::ConstantScalar(end, _)) => {
+                        f64::max(end - start, 0.0) as u64
+                    }
+                    _ => {
+                        self.dynamic_loops = true;
+                        1
+                    }
+                };
+
+                self.flops += counter.flops * num_iterations;
+                self.bytes_read += counter.bytes_read * num_iterations;
+                self.bytes_written += counter.bytes_written * num_iterations;
+                self.dynamic_loops |= counter.dynamic_loops;
+            }
+            Branch::Loop(op) => {
+                self.dynamic_loops = true;
+                self.scope(&op.scope);
+            }
+            Branch::Return | Branch::Break => {}
+        }
+    }
+
+    fn subcube(&mut self, subcube: &Subcube) {
+        match subcube {
+            Subcube::Sum(op) | Subcube::Prod(op) | Subcube::Min(op) | Subcube::Max(op) => {
+                self.flop(op.out)
+            }
+            _ => {}
+        }
+    }
+
+    fn atomic(&mut self, atomic: &Atomic) {
+        let array = atomic.array();
+
+        match atomic {
+            Atomic::Load(_) => self.read(array),
+            Atomic::Store(_) => self.write(array),
+            _ => {
+                self.read(array);
+                self.write(array);
+            }
+        }
+    }
+
+    fn flop(&mut self, out: Variable) {
+        let item = out.item();
+
+        if let Elem::Float(_) = item.elem {
+            self.flops += item.vectorization as u64;
+        }
+    }
+
+    fn read(&mut self, array: Variable) {
+        if let Some(bytes) = self.global_bytes(array) {
+            self.bytes_read += bytes;
+        }
+    }
+
+    fn write(&mut self, array: Variable) {
+        if let Some(bytes) = self.global_bytes(array) {
+            self.bytes_written += bytes;
+        }
+    }
+
+    fn global_bytes(&self, array: Variable) -> Option<u64> {
+        match array {
+            Variable::GlobalInputArray(_, item) | Variable::GlobalOutputArray(_, item) => {
+                Some(((self.elem_size)(item.elem) * item.vectorization as usize) as u64)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{cpa, FloatKind, IntKind, Item};
+
+    fn elem_size(elem: Elem) -> usize {
+        match elem {
+            Elem::Float(FloatKind::F64) | Elem::Int(IntKind::I64) => 8,
+            Elem::Float(FloatKind::F16) | Elem::Float(FloatKind::BF16) => 2,
+            _ => 4,
+        }
+    }
+
+    fn analyze(scope: &Scope) -> Counter {
+        let mut counter = Counter::new(elem_size);
+        counter.scope(scope);
+        counter
+    }
+
+    #[test]
+    fn should_count_elemwise_operations() {
+        let mut scope = Scope::root();
+        let item = Item::new(Elem::Float(FloatKind::F32));
+        let input = Variable::GlobalInputArray(0, item);
+        let output = Variable::GlobalOutputArray(0, item);
+        let position = Variable::AbsolutePos;
+
+        let value = scope.create_local(item);
+        cpa!(scope, value = input[position]);
+        cpa!(scope, value = exp(value));
+        cpa!(scope, value = value + value);
+        cpa!(scope, output[position] = value);
+
+        let counter = analyze(&scope);
+
+        assert_eq!(counter.flops, 2);
+        assert_eq!(counter.bytes_read, 4);
+        assert_eq!(counter.bytes_written, 4);
+        assert!(!counter.dynamic_loops);
+    }
+
+    #[test]
+    fn should_multiply_by_the_iterations_of_static_loops() {
+        let mut scope = Scope::root();
+        let item = Item::vectorized(Elem::Float(FloatKind::F32), 4);
+        let input = Variable::GlobalInputArray(0, item);
+        let acc = scope.create_local(item);
+
+        cpa!(
+            &mut scope,
+            range(0u32, 8u32).for_each(|i, scope| {
+                let value = scope.create_local(item);
+                cpa!(scope, value = input[i]);
+                cpa!(scope, acc = acc + value);
+            })
+        );
+
+        let counter = analyze(&scope);
+
+        assert_eq!(counter.flops, 8 * 4);
+        assert_eq!(counter.bytes_read, 8 * 16);
+        assert!(!counter.dynamic_loops);
+    }
+
+    #[test]
+    fn should_flag_dynamic_loops() {
+        let mut scope = Scope::root();
+        let item = Item::new(Elem::Float(FloatKind::F32));
+        let input = Variable::GlobalInputArray(0, item);
+        let end = Variable::GlobalScalar(0, Elem::UInt);
+        let acc = scope.create_local(item);
+
+        cpa!(
+            &mut scope,
+            range(0u32, end).for_each(|i, scope| {
+                let value = scope.create_local(item);
+                cpa!(scope, value = input[i]);
+                cpa!(scope, acc = acc + value);
+            })
+        );
+
+        let counter = analyze(&scope);
+
+        assert_eq!(counter.flops, 1);
+        assert!(counter.dynamic_loops);
+    }
+
+    #[test]
+    fn should_find_the_bound_with_the_ridge_point() {
+        let roofline = DeviceRoofline::new(10e12, 1e12);
+
+        assert_eq!(roofline.ridge_point(), 10.0);
+        assert_eq!(roofline.bound(0.25), KernelBound::Memory);
+        assert_eq!(roofline.bound(64.0), KernelBound::Compute);
+    }
+}
@@ -289,7 +289,7 @@ fn execute_settings<'a, R: Runtime, E1: CubeElement, E2: CubeElement, E3: CubeEl
         }
     }
 
-    let info = client.create(bytemuck::cast_slice(&info));
+    let info = client.create_uniform(bytemuck::cast_slice(&info));
 
     // Finally we finish with the named bindings.
     let handles_scalars =
@@ -320,6 +320,7 @@ fn create_scalar_handles<R: Runtime, E1: CubeElement, E2: CubeElement, E3: CubeE
         Elem::Int(_) => 1,
         Elem::UInt => 2,
         Elem::Bool => panic!("Bool scalars are not supported"),
+        Elem::AtomicInt(_) | Elem::AtomicUInt => panic!("Atomic scalars are not supported"),
     };
     let scalar_priorities: [usize; 3] = [
         element_priority(E1::cube_elem()),
@@ -333,15 +334,15 @@ fn create_scalar_handles<R: Runtime, E1: CubeElement, E2: CubeElement, E3: CubeE
             if scalar_priority == &i {
                 if j == 0 {
                     if let Some(values) = &scalars_0 {
-                        handles_scalars.push(client.create(bytemuck::cast_slice(values)));
+                        handles_scalars.push(client.create_uniform(bytemuck::cast_slice(values)));
                     }
                 } else if j == 1 {
                     if let Some(values) = &scalars_1 {
-                        handles_scalars.push(client.create(bytemuck::cast_slice(values)));
+                        handles_scalars.push(client.create_uniform(bytemuck::cast_slice(values)));
                     }
                 } else if j == 2 {
                     if let Some(values) = &scalars_2 {
-                        handles_scalars.push(client.create(bytemuck::cast_slice(values)));
+                        handles_scalars.push(client.create_uniform(bytemuck::cast_slice(values)));
                     }
                 }
             }
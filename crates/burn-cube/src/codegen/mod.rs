@@ -1,8 +1,10 @@
+mod analysis;
 mod execution;
 mod integrator;
 
 mod compiler;
 
+pub use analysis::*;
 pub use compiler::*;
 pub use execution::*;
 pub use integrator::*;
@@ -1,5 +1,6 @@
-use crate::{codegen::CompilerRepresentation, ir::CubeDim, Compiler, Kernel};
+use crate::{codegen::CompilerRepresentation, ir::CubeDim, Compiler, Kernel, KernelAnalysis};
 use alloc::sync::Arc;
+use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
 
 /// A kernel, compiled in the target language
@@ -33,6 +34,12 @@ pub trait CubeTask: Send + Sync {
     fn label(&self) -> Option<&'static str> {
         None
     }
+
+    /// Estimate the FLOPs and bytes moved by each unit of the kernel, when its definition is
+    /// available.
+    fn analysis(&self) -> Option<KernelAnalysis> {
+        None
+    }
 }
 
 /// Wraps a [kernel](Kernel) with its [cube count](CubeCount) to create a [cube task](CubeTask).
@@ -67,6 +74,10 @@ impl<C: Compiler, K: Kernel> CubeTask for KernelTask<C, K> {
             cube_count: self.cube_count.clone(),
         }
     }
+
+    fn analysis(&self) -> Option<KernelAnalysis> {
+        Some(KernelAnalysis::new::<C>(&self.kernel_definition.define()))
+    }
 }
 
 impl CubeTask for Arc<dyn CubeTask> {
@@ -81,6 +92,10 @@ impl CubeTask for Arc<dyn CubeTask> {
     fn launch_settings(&self) -> LaunchSettings {
         self.as_ref().launch_settings()
     }
+
+    fn analysis(&self) -> Option<KernelAnalysis> {
+        self.as_ref().analysis()
+    }
 }
 
 impl CubeTask for Box<dyn CubeTask> {
@@ -95,10 +110,14 @@ impl CubeTask for Box<dyn CubeTask> {
     fn launch_settings(&self) -> LaunchSettings {
         self.as_ref().launch_settings()
     }
+
+    fn analysis(&self) -> Option<KernelAnalysis> {
+        self.as_ref().analysis()
+    }
 }
 
 /// Provides launch information specifying the number of work groups to be used by a compute shader.
-#[derive(new, Clone, Debug)]
+#[derive(new, Clone, Debug, Serialize, Deserialize)]
 pub struct CubeCount {
     /// Work groups for the x axis.
     pub x: u32,
@@ -108,6 +108,9 @@ impl<R: Runtime> KernelLauncher<R> {
                 },
                 Elem::UInt => self.scalar_u32.register::<R>(client, &mut bindings),
                 Elem::Bool => panic!("Bool can't be passed as bindings."),
+                Elem::AtomicInt(_) | Elem::AtomicUInt => {
+                    panic!("Atomics can't be passed as scalar bindings.")
+                }
             }
         }
 
@@ -201,7 +204,11 @@ impl<R: Runtime> TensorState<R> {
             }
 
             bindings_global.extend(bindings);
-            bindings_global.push(client.create(bytemuck::cast_slice(&metadata)).binding());
+            bindings_global.push(
+                client
+                    .create_uniform(bytemuck::cast_slice(&metadata))
+                    .binding(),
+            );
         }
     }
 }
@@ -222,7 +229,7 @@ impl<T: NoUninit> ScalarState<T> {
         match self {
             ScalarState::Empty => (),
             ScalarState::Some(values) => {
-                let handle = client.create(bytemuck::cast_slice(values));
+                let handle = client.create_uniform(bytemuck::cast_slice(values));
                 bindings.push(handle.binding());
             }
         }
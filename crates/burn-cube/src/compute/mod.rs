@@ -1,7 +1,13 @@
 mod builder;
 mod kernel;
 mod launcher;
+mod profiler;
+#[cfg(feature = "remote")]
+mod remote;
 
 pub use builder::*;
 pub use kernel::*;
 pub use launcher::*;
+pub use profiler::*;
+#[cfg(feature = "remote")]
+pub use remote::*;
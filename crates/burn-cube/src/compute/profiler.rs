@@ -0,0 +1,133 @@
+use crate::{
+    compute::{CubeCount, CubeTask},
+    DeviceRoofline, KernelAnalysis, KernelBound,
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static PROFILES: Mutex<Option<HashMap<String, KernelProfile>>> = Mutex::new(None);
+
+/// Collects the [analysis](KernelAnalysis) and the number of launches of every kernel executed
+/// while enabled, to estimate which kernels are memory-bound or compute-bound.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// KernelProfiler::enable();
+/// model.forward(input);
+///
+/// let roofline = DeviceRoofline::new(20e12, 500e9);
+/// for profile in KernelProfiler::report() {
+///     println!("{} {:?}", profile.id, profile.bound(&roofline));
+/// }
+/// ```
+pub struct KernelProfiler;
+
+/// The estimated work done by all the launches of a kernel.
+#[derive(Debug, Clone)]
+pub struct KernelProfile {
+    /// The kernel id.
+    pub id: String,
+    /// The analysis of one unit of the kernel, if the kernel has a definition.
+    pub analysis: Option<KernelAnalysis>,
+    /// The number of times the kernel was launched.
+    pub launches: u64,
+    /// The number of units executed over all the launches.
+    pub units: u64,
+}
+
+impl KernelProfiler {
+    /// Start profiling the kernels, forgetting the kernels profiled so far.
+    pub fn enable() {
+        *PROFILES.lock().unwrap() = Some(HashMap::new());
+        ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    /// Stop profiling the kernels, the kernels profiled so far are kept until the next
+    /// [enable](KernelProfiler::enable).
+    pub fn disable() {
+        ENABLED.store(false, Ordering::Relaxed);
+    }
+
+    /// Whether the kernels are profiled.
+    pub fn is_enabled() -> bool {
+        ENABLED.load(Ordering::Relaxed)
+    }
+
+    /// The profiles of the kernels launched while enabled, sorted by decreasing number of bytes
+    /// moved.
+    pub fn report() -> Vec<KernelProfile> {
+        let mut profiles = PROFILES
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|profiles| profiles.values().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        profiles.sort_by(|a, b| b.bytes().cmp(&a.bytes()).then_with(|| a.id.cmp(&b.id)));
+        profiles
+    }
+
+    /// Register the launch of a kernel, if the profiler is enabled.
+    ///
+    /// The kernel is only analyzed the first time it is launched.
+    pub fn record<K: CubeTask + ?Sized>(kernel: &K) {
+        if !Self::is_enabled() {
+            return;
+        }
+
+        let id = kernel.id();
+        let cube_count = kernel.launch_settings().cube_count;
+
+        let mut profiles = PROFILES.lock().unwrap();
+        let profiles = profiles.get_or_insert_with(HashMap::new);
+
+        let profile = profiles.entry(id.clone()).or_insert_with(|| KernelProfile {
+            id,
+            analysis: kernel.analysis(),
+            launches: 0,
+            units: 0,
+        });
+
+        profile.launches += 1;
+        profile.units += num_units(&cube_count, profile.analysis.as_ref());
+    }
+}
+
+impl KernelProfile {
+    /// The estimated number of floating point operations over all the launches.
+    pub fn flops(&self) -> u64 {
+        self.analysis
+            .map(|analysis| analysis.flops * self.units)
+            .unwrap_or(0)
+    }
+
+    /// The estimated number of bytes moved from and to global memory over all the launches.
+    pub fn bytes(&self) -> u64 {
+        self.analysis
+            .map(|analysis| analysis.bytes() * self.units)
+            .unwrap_or(0)
+    }
+
+    /// Whether the kernel is memory-bound or compute-bound on the device, if the kernel has a
+    /// definition.
+    pub fn bound(&self, roofline: &DeviceRoofline) -> Option<KernelBound> {
+        self.analysis
+            .map(|analysis| roofline.bound(analysis.arithmetic_intensity()))
+    }
+}
+
+fn num_units(cube_count: &CubeCount, analysis: Option<&KernelAnalysis>) -> u64 {
+    let num_cubes = cube_count.x as u64 * cube_count.y as u64 * cube_count.z as u64;
+
+    match analysis {
+        Some(analysis) => num_cubes * analysis.num_units_per_cube(),
+        None => num_cubes,
+    }
+}
@@ -0,0 +1,55 @@
+use burn_compute::remote::RemoteKernel;
+use serde::{Deserialize, Serialize};
+
+use super::{CompiledKernel, CubeCount, CubeTask, LaunchSettings};
+use crate::ir::CubeDim;
+
+/// A [cube task](CubeTask) compiled by the client of a
+/// [remote server](burn_compute::remote::RemoteServer), to be launched by its host.
+///
+/// The kernel is compiled with the compiler of the client, which must be the one of the runtime
+/// serving it on the host.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RemoteCubeTask {
+    id: String,
+    source: String,
+    cube_dim: CubeDim,
+    shared_mem_bytes: usize,
+    cube_count: CubeCount,
+}
+
+impl CubeTask for RemoteCubeTask {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn compile(&self) -> CompiledKernel {
+        CompiledKernel {
+            source: self.source.clone(),
+            cube_dim: self.cube_dim,
+            shared_mem_bytes: self.shared_mem_bytes,
+        }
+    }
+
+    fn launch_settings(&self) -> LaunchSettings {
+        LaunchSettings {
+            cube_count: self.cube_count.clone(),
+        }
+    }
+}
+
+impl RemoteKernel for Box<dyn CubeTask> {
+    type Description = RemoteCubeTask;
+
+    fn describe(self) -> RemoteCubeTask {
+        let compiled = self.compile();
+
+        RemoteCubeTask {
+            id: self.id(),
+            source: compiled.source,
+            cube_dim: compiled.cube_dim,
+            shared_mem_bytes: compiled.shared_mem_bytes,
+            cube_count: self.launch_settings().cube_count,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use super::Variable;
+use serde::{Deserialize, Serialize};
+
+/// All atomic operations, applied to one element of an array of
+/// [atomic elements](super::Elem::AtomicUInt) in global or shared memory.
+///
+/// Every operation except the store returns the value of the element before the operation.
+/// Atomic operations can't be vectorized.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(dead_code, missing_docs)] // Some variants might not be used with different flags
+pub enum Atomic {
+    Load(AtomicLoadOperator),
+    Store(AtomicStoreOperator),
+    Swap(AtomicBinaryOperator),
+    Add(AtomicBinaryOperator),
+    Sub(AtomicBinaryOperator),
+    Max(AtomicBinaryOperator),
+    Min(AtomicBinaryOperator),
+    And(AtomicBinaryOperator),
+    Or(AtomicBinaryOperator),
+    Xor(AtomicBinaryOperator),
+    CompareAndSwap(AtomicCompareAndSwapOperator),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(missing_docs)]
+pub struct AtomicLoadOperator {
+    pub array: Variable,
+    pub index: Variable,
+    pub out: Variable,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(missing_docs)]
+pub struct AtomicStoreOperator {
+    pub array: Variable,
+    pub index: Variable,
+    pub value: Variable,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(missing_docs)]
+pub struct AtomicBinaryOperator {
+    pub array: Variable,
+    pub index: Variable,
+    pub value: Variable,
+    pub out: Variable,
+}
+
+/// Store `value` in the element when it is equal to `cmp`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(missing_docs)]
+pub struct AtomicCompareAndSwapOperator {
+    pub array: Variable,
+    pub index: Variable,
+    pub cmp: Variable,
+    pub value: Variable,
+    pub out: Variable,
+}
+
+impl Atomic {
+    /// The array the operation is applied to.
+    pub fn array(&self) -> Variable {
+        match self {
+            Atomic::Load(op) => op.array,
+            Atomic::Store(op) => op.array,
+            Atomic::Swap(op)
+            | Atomic::Add(op)
+            | Atomic::Sub(op)
+            | Atomic::Max(op)
+            | Atomic::Min(op)
+            | Atomic::And(op)
+            | Atomic::Or(op)
+            | Atomic::Xor(op) => op.array,
+            Atomic::CompareAndSwap(op) => op.array,
+        }
+    }
+}
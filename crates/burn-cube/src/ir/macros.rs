@@ -209,6 +209,86 @@ macro_rules! cpa {
             cpa!(binary $lhs, $rhs, $out)
         ));
     };
+    // out = atomic_load(array, index)
+    ($scope:expr, $out:ident = atomic_load($array:expr, $index:expr)) => {
+        $scope.register($crate::ir::Atomic::Load(
+            $crate::ir::AtomicLoadOperator {
+                array: $array.into(),
+                index: $index.into(),
+                out: $out.into(),
+            }
+        ));
+    };
+    // atomic_store(array, index, value)
+    ($scope:expr, atomic_store($array:expr, $index:expr, $value:expr)) => {
+        $scope.register($crate::ir::Atomic::Store(
+            $crate::ir::AtomicStoreOperator {
+                array: $array.into(),
+                index: $index.into(),
+                value: $value.into(),
+            }
+        ));
+    };
+    // out = atomic_swap(array, index, value)
+    ($scope:expr, $out:ident = atomic_swap($array:expr, $index:expr, $value:expr)) => {
+        $scope.register($crate::ir::Atomic::Swap(
+            cpa!(atomic $array, $index, $value, $out)
+        ));
+    };
+    // out = atomic_add(array, index, value)
+    ($scope:expr, $out:ident = atomic_add($array:expr, $index:expr, $value:expr)) => {
+        $scope.register($crate::ir::Atomic::Add(
+            cpa!(atomic $array, $index, $value, $out)
+        ));
+    };
+    // out = atomic_sub(array, index, value)
+    ($scope:expr, $out:ident = atomic_sub($array:expr, $index:expr, $value:expr)) => {
+        $scope.register($crate::ir::Atomic::Sub(
+            cpa!(atomic $array, $index, $value, $out)
+        ));
+    };
+    // out = atomic_max(array, index, value)
+    ($scope:expr, $out:ident = atomic_max($array:expr, $index:expr, $value:expr)) => {
+        $scope.register($crate::ir::Atomic::Max(
+            cpa!(atomic $array, $index, $value, $out)
+        ));
+    };
+    // out = atomic_min(array, index, value)
+    ($scope:expr, $out:ident = atomic_min($array:expr, $index:expr, $value:expr)) => {
+        $scope.register($crate::ir::Atomic::Min(
+            cpa!(atomic $array, $index, $value, $out)
+        ));
+    };
+    // out = atomic_and(array, index, value)
+    ($scope:expr, $out:ident = atomic_and($array:expr, $index:expr, $value:expr)) => {
+        $scope.register($crate::ir::Atomic::And(
+            cpa!(atomic $array, $index, $value, $out)
+        ));
+    };
+    // out = atomic_or(array, index, value)
+    ($scope:expr, $out:ident = atomic_or($array:expr, $index:expr, $value:expr)) => {
+        $scope.register($crate::ir::Atomic::Or(
+            cpa!(atomic $array, $index, $value, $out)
+        ));
+    };
+    // out = atomic_xor(array, index, value)
+    ($scope:expr, $out:ident = atomic_xor($array:expr, $index:expr, $value:expr)) => {
+        $scope.register($crate::ir::Atomic::Xor(
+            cpa!(atomic $array, $index, $value, $out)
+        ));
+    };
+    // out = atomic_compare_and_swap(array, index, cmp, value)
+    ($scope:expr, $out:ident = atomic_compare_and_swap($array:expr, $index:expr, $cmp:expr, $value:expr)) => {
+        $scope.register($crate::ir::Atomic::CompareAndSwap(
+            $crate::ir::AtomicCompareAndSwapOperator {
+                array: $array.into(),
+                index: $index.into(),
+                cmp: $cmp.into(),
+                value: $value.into(),
+                out: $out.into(),
+            }
+        ));
+    };
     // out = lhs[rhs]
     ($scope:expr, $out:ident = $lhs:ident[$rhs:expr]) => {
         cpa!($scope, $out = index($lhs, $rhs))
@@ -334,6 +414,12 @@ macro_rules! cpa {
             cpa!(unary $input, $out)
         ));
     };
+    // out = bitcast(input)
+    ($scope:expr, $out:ident = bitcast($input:expr)) => {
+        $scope.register($crate::ir::Operator::Bitcast(
+            cpa!(unary $input, $out)
+        ));
+    };
     // out = shape(tensor, dim)
     ($scope:expr, $out:ident = shape($input:expr, $dim:expr)) => {
         $scope.register($crate::ir::Metadata::Shape {
@@ -381,6 +467,14 @@ macro_rules! cpa {
     ($scope:expr, if ($cond:expr).then($arg_if:expr).else($arg_else:expr)) => {
         $crate::ir::IfElse::register($scope, $cond.into(), $arg_if, $arg_else);
     };
+    (atomic $array:expr, $index:expr, $value:expr, $out:expr) => {
+        $crate::ir::AtomicBinaryOperator {
+            array: $array.into(),
+            index: $index.into(),
+            value: $value.into(),
+            out: $out.into(),
+        }
+    };
     (binary $lhs:expr, $rhs:expr, $out:expr) => {
         $crate::ir::BinaryOperator {
             lhs: $lhs.into(),
@@ -1,3 +1,4 @@
+mod atomic;
 mod branch;
 mod macros;
 mod operation;
@@ -10,6 +11,7 @@ mod synchronization;
 mod variable;
 mod vectorization;
 
+pub use atomic::*;
 pub use branch::*;
 pub use operation::*;
 pub use procedure::*;
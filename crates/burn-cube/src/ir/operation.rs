@@ -1,4 +1,4 @@
-use super::{Branch, Procedure, Subcube, Synchronization, Variable};
+use super::{Atomic, Branch, Procedure, Subcube, Synchronization, Variable};
 use serde::{Deserialize, Serialize};
 
 /// All operations that can be used in a GPU compute shader.
@@ -18,6 +18,7 @@ pub enum Operation {
     Branch(Branch),
     Synchronization(Synchronization),
     Subcube(Subcube),
+    Atomic(Atomic),
 }
 
 /// All operators that can be used in a GPU compute shader.
@@ -64,6 +65,7 @@ pub enum Operator {
     ShiftLeft(BinaryOperator),
     ShiftRight(BinaryOperator),
     Remainder(BinaryOperator),
+    Bitcast(UnaryOperator),
 }
 
 /// All metadata that can be access in a shader.
@@ -144,6 +146,12 @@ impl From<Branch> for Operation {
     }
 }
 
+impl From<Atomic> for Operation {
+    fn from(value: Atomic) -> Self {
+        Self::Atomic(value)
+    }
+}
+
 impl From<Synchronization> for Operation {
     fn from(value: Synchronization) -> Self {
         Self::Synchronization(value)
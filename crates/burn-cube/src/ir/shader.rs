@@ -40,6 +40,10 @@ pub enum Elem {
     Int(IntKind),
     UInt,
     Bool,
+    /// An int only accessed with [atomic operations](super::Atomic).
+    AtomicInt(IntKind),
+    /// An uint only accessed with [atomic operations](super::Atomic).
+    AtomicUInt,
 }
 
 impl From<Elem> for Item {
@@ -76,6 +80,8 @@ impl Display for Elem {
             Self::Int(_) => f.write_str("int"),
             Self::UInt => f.write_str("uint"),
             Self::Bool => f.write_str("bool"),
+            Self::AtomicInt(_) => f.write_str("atomic_int"),
+            Self::AtomicUInt => f.write_str("atomic_uint"),
         }
     }
 }
@@ -20,6 +20,8 @@ impl Operation {
                 "Synchronization instructions can't be vectorized, they should only be generated after vectorization."
             ),
             Operation::Subcube(op) => Operation::Subcube(op.vectorize(vectorization)),
+            // Atomics are applied to a single element.
+            Operation::Atomic(op) => Operation::Atomic(op.clone()),
         }
     }
 }
@@ -77,6 +79,7 @@ impl Operator {
             Operator::ShiftLeft(op) => Operator::ShiftLeft(op.vectorize(vectorization)),
             Operator::ShiftRight(op) => Operator::ShiftRight(op.vectorize(vectorization)),
             Operator::Remainder(op) => Operator::Remainder(op.vectorize(vectorization)),
+            Operator::Bitcast(op) => Operator::Bitcast(op.vectorize(vectorization)),
         }
     }
 }
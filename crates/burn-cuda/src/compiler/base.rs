@@ -1,6 +1,6 @@
 use burn_cube::{ir as gpu, Compiler};
 
-use super::{Instruction, WarpInstruction};
+use super::{AtomicInstruction, Instruction, WarpInstruction};
 
 #[allow(clippy::too_many_arguments)]
 #[derive(new, Clone, Debug, Default)]
@@ -140,9 +140,51 @@ impl CudaCompiler {
                     _ => todo!(),
                 }
             }
+            gpu::Operation::Atomic(op) => instructions.push(self.compile_atomic(op)),
         }
     }
 
+    fn compile_atomic(&mut self, atomic: gpu::Atomic) -> Instruction {
+        let mut binary =
+            |function: &'static str, op: gpu::AtomicBinaryOperator| AtomicInstruction::Binary {
+                function,
+                array: self.compile_variable(op.array),
+                index: self.compile_variable(op.index),
+                value: self.compile_variable(op.value),
+                out: self.compile_variable(op.out),
+            };
+
+        let instruction = match atomic {
+            gpu::Atomic::Swap(op) => binary("atomicExch", op),
+            gpu::Atomic::Add(op) => binary("atomicAdd", op),
+            gpu::Atomic::Sub(op) => binary("atomicSub", op),
+            gpu::Atomic::Max(op) => binary("atomicMax", op),
+            gpu::Atomic::Min(op) => binary("atomicMin", op),
+            gpu::Atomic::And(op) => binary("atomicAnd", op),
+            gpu::Atomic::Or(op) => binary("atomicOr", op),
+            gpu::Atomic::Xor(op) => binary("atomicXor", op),
+            gpu::Atomic::Load(op) => AtomicInstruction::Load {
+                array: self.compile_variable(op.array),
+                index: self.compile_variable(op.index),
+                out: self.compile_variable(op.out),
+            },
+            gpu::Atomic::Store(op) => AtomicInstruction::Store {
+                array: self.compile_variable(op.array),
+                index: self.compile_variable(op.index),
+                value: self.compile_variable(op.value),
+            },
+            gpu::Atomic::CompareAndSwap(op) => AtomicInstruction::CompareAndSwap {
+                array: self.compile_variable(op.array),
+                index: self.compile_variable(op.index),
+                cmp: self.compile_variable(op.cmp),
+                value: self.compile_variable(op.value),
+                out: self.compile_variable(op.out),
+            },
+        };
+
+        Instruction::Atomic(instruction)
+    }
+
     fn compile_metadata(&mut self, metadata: gpu::Metadata) -> Instruction {
         match metadata {
             gpu::Metadata::Stride { dim, var, out } => {
@@ -301,6 +343,7 @@ impl CudaCompiler {
             }),
             gpu::Operator::Floor(op) => Instruction::Floor(self.compile_unary(op)),
             gpu::Operator::Ceil(op) => Instruction::Ceil(self.compile_unary(op)),
+            gpu::Operator::Bitcast(op) => Instruction::Bitcast(self.compile_unary(op)),
             gpu::Operator::Remainder(_op) => todo!(),
         }
     }
@@ -438,6 +481,12 @@ impl CudaCompiler {
             },
             gpu::Elem::UInt => super::Elem::U32,
             gpu::Elem::Bool => super::Elem::Bool,
+            // Cuda atomics are applied to plain memory.
+            gpu::Elem::AtomicInt(kind) => match kind {
+                gpu::IntKind::I32 => super::Elem::I32,
+                gpu::IntKind::I64 => panic!("i64 isn't supported yet"),
+            },
+            gpu::Elem::AtomicUInt => super::Elem::U32,
         }
     }
 }
@@ -1,4 +1,4 @@
-use super::{binary::*, unary::*, Component, Variable, WarpInstruction};
+use super::{binary::*, unary::*, AtomicInstruction, Component, Variable, WarpInstruction};
 use std::fmt::Display;
 
 #[derive(Debug, Clone)]
@@ -98,7 +98,9 @@ pub enum Instruction {
     SyncThreads,
     Ceil(UnaryInstruction),
     Floor(UnaryInstruction),
+    Bitcast(UnaryInstruction),
     Wrap(WarpInstruction),
+    Atomic(AtomicInstruction),
 }
 
 impl Display for Instruction {
@@ -213,6 +215,7 @@ for (uint {i} = {start}; {i} < {end}; {i}++) {{
             Instruction::SyncThreads => f.write_str("__syncthreads();\n"),
             Instruction::Ceil(it) => Ceil::format(f, &it.input, &it.out),
             Instruction::Floor(it) => Floor::format(f, &it.input, &it.out),
+            Instruction::Bitcast(it) => Bitcast::format(f, &it.input, &it.out),
             Instruction::ArrayLength {
                 input,
                 out,
@@ -230,6 +233,7 @@ for (uint {i} = {start}; {i} < {end}; {i}++) {{
                 ))
             }
             Instruction::Wrap(it) => f.write_fmt(format_args!("{it}")),
+            Instruction::Atomic(it) => f.write_fmt(format_args!("{it}")),
         }
     }
 }
@@ -1,6 +1,7 @@
 pub mod binary;
 pub mod unary;
 
+mod atomic;
 mod base;
 mod body;
 mod element;
@@ -9,6 +10,7 @@ mod settings;
 mod shader;
 mod warp;
 
+pub use atomic::*;
 pub use base::*;
 pub use body::*;
 pub use element::*;
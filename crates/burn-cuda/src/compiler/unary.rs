@@ -170,6 +170,42 @@ function!(Erf, "erff");
 function!(Ceil, "ceil");
 function!(Floor, "floor");
 
+/// Reinterpret the bits of a 32-bit value as another 32-bit type.
+pub struct Bitcast;
+
+impl Unary for Bitcast {
+    fn format_scalar<Input, Out>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        out: Out,
+        elem: Elem,
+    ) -> std::fmt::Result
+    where
+        Input: Component,
+        Out: Component,
+    {
+        match (input.elem(), elem) {
+            (Elem::F32, Elem::U32) => {
+                f.write_fmt(format_args!("{out} = __float_as_uint({input});\n"))
+            }
+            (Elem::F32, Elem::I32) => {
+                f.write_fmt(format_args!("{out} = __float_as_int({input});\n"))
+            }
+            (Elem::U32, Elem::F32) => {
+                f.write_fmt(format_args!("{out} = __uint_as_float({input});\n"))
+            }
+            (Elem::I32, Elem::F32) => {
+                f.write_fmt(format_args!("{out} = __int_as_float({input});\n"))
+            }
+            (Elem::I32, Elem::U32) | (Elem::U32, Elem::I32) => {
+                f.write_fmt(format_args!("{out} = {elem}({input});\n"))
+            }
+            (from, to) if from == to => f.write_fmt(format_args!("{out} = {input};\n")),
+            (from, to) => panic!("Can't bitcast {from} to {to}"),
+        }
+    }
+}
+
 pub struct Not;
 
 impl Unary for Not {
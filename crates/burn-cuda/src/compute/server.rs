@@ -3,11 +3,13 @@ use super::storage::CudaStorage;
 use burn_compute::{
     memory_management::MemoryManagement,
     server::{self, ComputeServer},
+    storage::StorageHint,
 };
+use burn_cube::compute::KernelProfiler;
 use burn_cube::ir::CubeDim;
 use burn_cube::prelude::*;
 use burn_jit::JitAutotuneKey;
-use burn_tensor::backend::SyncType;
+use burn_tensor::backend::{MemoryUsage, SyncType};
 use cudarc::driver::sys::CUctx_st;
 use cudarc::driver::sys::CUfunc_st;
 use std::collections::HashMap;
@@ -92,25 +94,36 @@ impl<MM: MemoryManagement<CudaStorage>> ComputeServer for CudaServer<MM> {
         server::Handle::new(handle)
     }
 
-    fn execute(&mut self, kernel: Self::Kernel, bindings: Vec<server::Binding<Self>>) {
+    fn empty_with_hint(&mut self, size: usize, hint: StorageHint) -> server::Handle<Self> {
         let ctx = self.get_context();
-        let kernel_id = kernel.id();
-        let settings = kernel.launch_settings();
-
-        if !ctx.module_names.contains_key(&kernel_id) {
-            ctx.compile_kernel(&kernel_id, kernel);
-        }
+        let handle = ctx.memory_management.reserve_with_hint(size, hint);
+        server::Handle::new(handle)
+    }
 
-        let bindings = bindings
-            .into_iter()
-            .map(|binding| ctx.memory_management.get(binding.memory).as_binding())
-            .collect();
+    fn execute(&mut self, kernel: Self::Kernel, bindings: Vec<server::Binding<Self>>) {
+        KernelProfiler::record(&kernel);
 
-        ctx.execute_task(kernel_id, settings.cube_count, bindings);
+        let cube_count = kernel.launch_settings().cube_count;
+        self.execute_with_cube_count(kernel, cube_count, bindings);
         // TODO: fix this
         // self.memory_management.storage().perform_deallocations();
     }
 
+    /// Cuda kernels can't be launched with a cube count stored on the device, so it is read back
+    /// before the launch.
+    fn execute_indirect(
+        &mut self,
+        kernel: Self::Kernel,
+        cube_count: server::Binding<Self>,
+        bindings: Vec<server::Binding<Self>>,
+    ) {
+        let data = self.read(cube_count).read();
+        let count = bytemuck::cast_slice::<_, u32>(&data[0..12]);
+        let cube_count = CubeCount::new(count[0], count[1], count[2]);
+
+        self.execute_with_cube_count(kernel, cube_count, bindings);
+    }
+
     fn sync(&mut self, sync_type: SyncType) {
         match sync_type {
             // Synchronize the stream if waiting.
@@ -131,6 +144,11 @@ impl<MM: MemoryManagement<CudaStorage>> ComputeServer for CudaServer<MM> {
         ctx.memory_management.get(binding.memory)
     }
 
+    fn memory_usage(&mut self) -> MemoryUsage {
+        let ctx = self.get_context();
+        ctx.memory_management.memory_usage()
+    }
+
     fn run_custom_command(&mut self, f: impl Fn(&mut Self) + Send) {
         f(self);
     }
@@ -220,6 +238,27 @@ impl<MM: MemoryManagement<CudaStorage>> CudaContext<MM> {
 }
 
 impl<MM: MemoryManagement<CudaStorage>> CudaServer<MM> {
+    fn execute_with_cube_count(
+        &mut self,
+        kernel: Box<dyn CubeTask>,
+        cube_count: CubeCount,
+        bindings: Vec<server::Binding<Self>>,
+    ) {
+        let ctx = self.get_context();
+        let kernel_id = kernel.id();
+
+        if !ctx.module_names.contains_key(&kernel_id) {
+            ctx.compile_kernel(&kernel_id, kernel);
+        }
+
+        let bindings = bindings
+            .into_iter()
+            .map(|binding| ctx.memory_management.get(binding.memory).as_binding())
+            .collect();
+
+        ctx.execute_task(kernel_id, cube_count, bindings);
+    }
+
     /// Create a new cuda server.
     pub(crate) fn new(index: usize, init: Box<dyn Fn(usize) -> CudaContext<MM>>) -> Self {
         Self {
@@ -7,6 +7,7 @@ use burn_compute::{
     ComputeRuntime,
 };
 use burn_cube::Runtime;
+use burn_tensor::backend::{DeviceCapabilities, DeviceInfo};
 use std::sync::Arc;
 
 use crate::{
@@ -21,6 +22,51 @@ pub struct CudaRuntime;
 impl burn_jit::JitRuntime for CudaRuntime {
     type JitDevice = CudaDevice;
     type JitServer = CudaServer<SimpleMemoryManagement<CudaStorage>>;
+
+    fn devices() -> Vec<DeviceInfo<CudaDevice>> {
+        use cudarc::driver::{result::device, sys::CUdevice_attribute};
+
+        if cudarc::driver::result::init().is_err() {
+            return Vec::new();
+        }
+        let count = device::get_count().unwrap_or(0);
+
+        (0..count)
+            .filter_map(|index| {
+                let device_ptr = device::get(index).ok()?;
+                let attribute = |attribute| unsafe { device::get_attribute(device_ptr, attribute) };
+                let major =
+                    attribute(CUdevice_attribute::CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MAJOR)
+                        .unwrap_or(0);
+                let minor =
+                    attribute(CUdevice_attribute::CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MINOR)
+                        .unwrap_or(0);
+                let capabilities = DeviceCapabilities {
+                    name: device::get_name(device_ptr).unwrap_or_default(),
+                    memory_size: unsafe { device::total_mem(device_ptr) }
+                        .ok()
+                        .map(|size| size as u64),
+                    // Half precision arithmetic requires the compute capability 5.3, brain floats
+                    // the compute capability 8.0.
+                    f16: (major, minor) >= (5, 3),
+                    bf16: major >= 8,
+                    max_workgroup_size: attribute(
+                        CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MAX_THREADS_PER_BLOCK,
+                    )
+                    .ok()
+                    .map(|size| size as u32),
+                    unified_memory: attribute(CUdevice_attribute::CU_DEVICE_ATTRIBUTE_INTEGRATED)
+                        .map(|integrated| integrated == 1)
+                        .unwrap_or(false),
+                };
+
+                Some(DeviceInfo::new(
+                    CudaDevice::new(index as usize),
+                    capabilities,
+                ))
+            })
+            .collect()
+    }
 }
 
 static RUNTIME: ComputeRuntime<CudaDevice, Server, MutexComputeChannel<Server>> =
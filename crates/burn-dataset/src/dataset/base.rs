@@ -1,6 +1,15 @@
-use std::{num::NonZeroUsize, sync::Arc};
+use std::{hash::Hash, num::NonZeroUsize, path::Path, sync::Arc};
 
-use crate::{dataset::window::WindowDataset, DatasetIterator};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    dataset::window::WindowDataset,
+    transform::{
+        CacheError, CachedMapperDataset, Mapper, PartialDataset, ShuffledDataset, SubsetDataset,
+        TrainTestSplit,
+    },
+    DatasetIterator,
+};
 
 /// The dataset trait defines a basic collection of items with a predefined size.
 pub trait Dataset<I>: Send + Sync {
@@ -52,6 +61,120 @@ pub trait Dataset<I>: Send + Sync {
         let size = NonZeroUsize::new(size).expect("window size must be non-zero");
         WindowDataset::new(self, size)
     }
+
+    /// Returns a new `Dataset` with the items shuffled deterministically with the given seed.
+    fn shuffled(self, seed: u64) -> ShuffledDataset<Self, I>
+    where
+        Self: Sized,
+    {
+        ShuffledDataset::with_seed(self, seed)
+    }
+
+    /// Returns a new `Dataset` with the items at the given indices, in order.
+    fn subset(self, indices: Vec<usize>) -> SubsetDataset<Self, I>
+    where
+        Self: Sized,
+    {
+        SubsetDataset::new(self, indices)
+    }
+
+    /// Returns a new `Dataset` with the first `num` items, or all the items if there are fewer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::burn_dataset::{Dataset,InMemDataset};
+    /// let dataset = InMemDataset::new([1, 2, 3, 4].to_vec());
+    ///
+    /// let items = dataset.skip(1).take(2);
+    ///
+    /// assert_eq!(items.iter().collect::<Vec<_>>(), [2, 3].to_vec());
+    /// ```
+    fn take(self, num: usize) -> PartialDataset<Self, I>
+    where
+        Self: Sized,
+    {
+        let end = usize::min(num, self.len());
+        PartialDataset::new(self, 0, end)
+    }
+
+    /// Returns a new `Dataset` without the first `num` items.
+    fn skip(self, num: usize) -> PartialDataset<Self, I>
+    where
+        Self: Sized,
+    {
+        let end = self.len();
+        PartialDataset::new(self, usize::min(num, end), end)
+    }
+
+    /// Splits the dataset into `(train, test)` datasets after shuffling it with the given seed,
+    /// with a proportion of `test_ratio` items used for testing.
+    ///
+    /// See [TrainTestSplit] to split without shuffling.
+    ///
+    /// # Panics
+    ///
+    /// If the test ratio isn't between 0 and 1.
+    #[allow(clippy::type_complexity)]
+    fn train_test_split(
+        self,
+        test_ratio: f64,
+        seed: u64,
+    ) -> (SubsetDataset<Arc<Self>, I>, SubsetDataset<Arc<Self>, I>)
+    where
+        Self: Sized,
+    {
+        TrainTestSplit::new(test_ratio)
+            .with_shuffle(seed)
+            .split(self)
+    }
+
+    /// Splits the dataset like [train_test_split](Dataset::train_test_split), keeping the same
+    /// proportion of each label, given by `label_fn`, in both datasets.
+    ///
+    /// # Panics
+    ///
+    /// If the test ratio isn't between 0 and 1.
+    #[allow(clippy::type_complexity)]
+    fn train_test_split_stratified<L, F>(
+        self,
+        test_ratio: f64,
+        seed: u64,
+        label_fn: F,
+    ) -> (SubsetDataset<Arc<Self>, I>, SubsetDataset<Arc<Self>, I>)
+    where
+        Self: Sized,
+        L: Eq + Hash,
+        F: Fn(&I) -> L,
+    {
+        TrainTestSplit::new(test_ratio)
+            .with_shuffle(seed)
+            .split_stratified(self, label_fn)
+    }
+
+    /// Returns a new `Dataset` with the items mapped once by an expensive deterministic transform,
+    /// such as tokenization or feature extraction, and cached on disk in `cache_dir`.
+    ///
+    /// The cache is reused as long as the items of the dataset don't change, otherwise it is
+    /// recomputed. See [CachedMapperDataset] for more details.
+    ///
+    /// # Returns
+    ///
+    /// A `CachedMapperDataset` instance.
+    fn map_cached<M, O, P>(
+        self,
+        mapper: M,
+        cache_dir: P,
+    ) -> Result<CachedMapperDataset<O>, CacheError>
+    where
+        Self: Sized,
+        I: Serialize,
+        M: Mapper<I, O>,
+        O: Serialize + DeserializeOwned + Send + Sync,
+        P: AsRef<Path>,
+    {
+        CachedMapperDataset::new(self, mapper, cache_dir)
+    }
 }
 
 impl<D, I> Dataset<I> for Arc<D>
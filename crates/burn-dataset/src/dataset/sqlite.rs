@@ -1,9 +1,10 @@
 use std::{
-    collections::HashSet,
+    collections::{BTreeMap, HashSet},
     fs, io,
     marker::PhantomData,
+    ops::Range,
     path::{Path, PathBuf},
-    sync::{Arc, RwLock},
+    sync::{mpsc, Arc, Mutex, RwLock},
 };
 
 use crate::Dataset;
@@ -19,7 +20,13 @@ use r2d2_sqlite::{
 };
 use sanitize_filename::sanitize;
 use serde::{de::DeserializeOwned, Serialize};
-use serde_rusqlite::{columns_from_statement, from_row_with_columns};
+use serde_rusqlite::{columns_from_statement, from_row_with_columns, to_params_named};
+
+/// Table tracking the number of inputs ingested for each split, dropped when the writer completes.
+const PROGRESS_TABLE: &str = "_ingest_progress";
+
+/// Default number of items written per transaction by [SqliteDatasetWriter::ingest].
+const DEFAULT_BATCH_SIZE: usize = 1000;
 
 /// Result type for the sqlite dataset.
 pub type Result<T> = core::result::Result<T, SqliteDatasetError>;
@@ -39,6 +46,10 @@ pub enum SqliteDatasetError {
     #[error("Serde error: {0}")]
     Serde(#[from] rmp_serde::encode::Error),
 
+    /// Error when mapping the item fields to table columns.
+    #[error("Column mapping error: {0}")]
+    Columns(#[from] serde_rusqlite::Error),
+
     /// The database file already exists error.
     #[error("Overwrite flag is set to false and the database file already exists: {0}")]
     FileExists(PathBuf),
@@ -385,6 +396,20 @@ impl SqliteDatasetStorage {
         SqliteDatasetWriter::new(self.db_file(), overwrite)
     }
 
+    /// Provides a writer instance resuming an interrupted ingestion of the SQLite dataset.
+    ///
+    /// See [SqliteDatasetWriter::resume].
+    ///
+    /// # Returns
+    ///
+    /// * A `Result` which is `Ok` if the writer could be created, `Err` otherwise.
+    pub fn resumable_writer<I>(&self) -> Result<SqliteDatasetWriter<I>>
+    where
+        I: Clone + Send + Sync + Serialize + DeserializeOwned,
+    {
+        SqliteDatasetWriter::resume(self.db_file())
+    }
+
     /// Provides a reader instance for the SQLite dataset.
     ///
     /// # Arguments
@@ -416,12 +441,16 @@ impl SqliteDatasetStorage {
 /// - Generation of a new dataset
 /// - Storage of preprocessed data or metadata
 /// - Enlargement of a dataset's item count post preprocessing
+/// - Conversion of large raw corpora with [ingest](Self::ingest)
 #[derive(Debug)]
 pub struct SqliteDatasetWriter<I> {
     db_file: PathBuf,
     db_file_tmp: Option<Handle<Writable>>,
     splits: Arc<RwLock<HashSet<String>>>,
     overwrite: bool,
+    resumable: bool,
+    item_columns: bool,
+    batch_size: usize,
     conn_pool: Option<Pool<SqliteConnectionManager>>,
     is_completed: Arc<RwLock<bool>>,
     phantom: PhantomData<I>,
@@ -442,17 +471,84 @@ where
     ///
     /// * A `Result` which is `Ok` if the writer could be created, `Err` otherwise.
     pub fn new<P: AsRef<Path>>(db_file: P, overwrite: bool) -> Result<Self> {
-        let writer = Self {
+        let writer = Self::uninitialized(db_file, overwrite, false);
+
+        writer.init()
+    }
+
+    /// Creates a new instance of `SqliteDatasetWriter` resuming the work of a previous writer
+    /// interrupted before being completed, or starting from scratch if there is none.
+    ///
+    /// Contrary to [new](Self::new), the temporary database file is kept when the writer is
+    /// dropped or the process is interrupted, and the inputs already written by
+    /// [ingest](Self::ingest) are skipped when ingesting them again.
+    ///
+    /// # Arguments
+    ///
+    /// * `db_file` - A reference to the Path that represents the database file path.
+    ///
+    /// # Returns
+    ///
+    /// * A `Result` which is `Ok` if the writer could be created, `Err` if the database file
+    ///   already exists, in which case there is nothing to resume.
+    pub fn resume<P: AsRef<Path>>(db_file: P) -> Result<Self> {
+        let mut writer = Self::uninitialized(db_file, false, true);
+
+        if writer.db_file.exists() {
+            return Err(SqliteDatasetError::FileExists(writer.db_file));
+        }
+
+        let db_file_dir = writer
+            .db_file
+            .parent()
+            .ok_or("Unable to get parent directory")?;
+
+        if !db_file_dir.exists() {
+            fs::create_dir_all(db_file_dir)?;
+        }
+
+        // Open the existing temp database file if any, it is not removed on drop
+        let conn_pool = create_conn_pool(writer.db_file_tmp_path(), true)?;
+        writer.conn_pool = Some(conn_pool);
+
+        Ok(writer)
+    }
+
+    fn uninitialized<P: AsRef<Path>>(db_file: P, overwrite: bool, resumable: bool) -> Self {
+        Self {
             db_file: db_file.as_ref().to_path_buf(),
             db_file_tmp: None,
             splits: Arc::new(RwLock::new(HashSet::new())),
             overwrite,
+            resumable,
+            item_columns: false,
+            batch_size: DEFAULT_BATCH_SIZE,
             conn_pool: None,
             is_completed: Arc::new(RwLock::new(false)),
             phantom: PhantomData,
-        };
+        }
+    }
 
-        writer.init()
+    /// Stores each field of the items in its own column, named after the field, instead of a
+    /// single MessagePack serialized `item` column.
+    ///
+    /// The columns are derived from the serde serialization of the first item written to each
+    /// split, so the items must serialize to a flat struct of primitive values or bytes. This
+    /// is the layout used by the datasets converted from HuggingFace and is easier to query
+    /// with other tools.
+    ///
+    /// Must be set before writing any item.
+    pub fn with_item_columns(mut self, item_columns: bool) -> Self {
+        self.item_columns = item_columns;
+        self
+    }
+
+    /// Sets the number of items written per transaction by [ingest](Self::ingest).
+    ///
+    /// With a [resumable](Self::resume) writer, at most one batch is lost when interrupted.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
     }
 
     /// Initializes the dataset writer by creating the database file, tables, and connection pool.
@@ -481,8 +577,7 @@ where
         }
 
         // Create a temp database file name as {base_dir}/{name}.db.tmp
-        let mut db_file_tmp = self.db_file.clone();
-        db_file_tmp.set_extension("db.tmp");
+        let db_file_tmp = self.db_file_tmp_path();
         if db_file_tmp.exists() {
             fs::remove_file(&db_file_tmp)?;
         }
@@ -503,6 +598,12 @@ where
         Ok(self)
     }
 
+    fn db_file_tmp_path(&self) -> PathBuf {
+        let mut db_file_tmp = self.db_file.clone();
+        db_file_tmp.set_extension("db.tmp");
+        db_file_tmp
+    }
+
     /// Serializes and writes an item to the database. The item is written to the table for the
     /// specified split. If the table does not exist, it is created. If the table exists, the item
     /// is appended to the table. The serialization is done using the [MessagePack](https://msgpack.org/)
@@ -528,30 +629,185 @@ where
 
         // create the table for the split if it does not exist
         if !self.splits.read().unwrap().contains(split) {
-            self.create_table(split)?;
+            self.create_table(split, item)?;
         }
 
         // Get a connection from the pool
         let conn_pool = self.conn_pool.as_ref().unwrap();
         let conn = conn_pool.get()?;
+        self.set_durability(&conn)?;
+
+        self.insert(&conn, split, item)
+    }
 
-        // Serialize the item using MessagePack
-        let serialized_item = rmp_serde::to_vec(item)?;
+    /// Writes a batch of items to the table of the split in a single transaction, which is much
+    /// faster than writing them one by one.
+    ///
+    /// # Arguments
+    ///
+    /// * `split` - A string slice that defines the data split for writing (e.g., "train", "test").
+    /// * `items` - The items to be written to the database.
+    ///
+    /// # Returns
+    ///
+    /// * A `Result` containing the range of indices of the inserted rows if successful, an error
+    ///   otherwise.
+    pub fn write_batch(&self, split: &str, items: &[I]) -> Result<Range<usize>> {
+        let is_completed = self.is_completed.read().unwrap();
 
-        // Turn off the synchronous and journal mode for speed up
-        // We are sacrificing durability for speed but it's okay because
-        // we always recreate the dataset if it is not completed.
-        pragma_update_with_error_handling(&conn, "synchronous", "OFF")?;
-        pragma_update_with_error_handling(&conn, "journal_mode", "OFF")?;
+        if *is_completed {
+            return Err(SqliteDatasetError::Other(
+                "Cannot save to a completed dataset writer",
+            ));
+        }
 
-        // Insert the serialized item into the database
-        let insert_statement = format!("insert into {split} (item) values (?)", split = split);
-        conn.execute(insert_statement.as_str(), [serialized_item])?;
+        let first = match items.first() {
+            Some(first) => first,
+            None => return Ok(0..0),
+        };
 
-        // Get the primary key of the last inserted row and convert to index (row_id-1)
-        let index = (conn.last_insert_rowid() - 1) as usize;
+        if !self.splits.read().unwrap().contains(split) {
+            self.create_table(split, first)?;
+        }
+
+        let conn_pool = self.conn_pool.as_ref().unwrap();
+        let mut conn = conn_pool.get()?;
+        self.set_durability(&conn)?;
+
+        // The rows of a transaction are contiguous since sqlite has a single writer.
+        let transaction = conn.transaction()?;
+        let start = self.insert(&transaction, split, first)?;
+        for item in items[1..].iter() {
+            self.insert(&transaction, split, item)?;
+        }
+        transaction.commit()?;
+
+        Ok(start..start + items.len())
+    }
+
+    /// Converts the inputs into items using `num_workers` threads and writes them in order to the
+    /// table of the split, one batch per transaction.
+    ///
+    /// The progress is saved along with each batch, so a [resumed](Self::resume) writer skips
+    /// the inputs already written when given the same inputs in the same order.
+    ///
+    /// # Arguments
+    ///
+    /// * `split` - A string slice that defines the data split for writing (e.g., "train", "test").
+    /// * `inputs` - The raw inputs, such as file paths or lines of a corpus.
+    /// * `num_workers` - The number of threads converting the inputs.
+    /// * `convert` - The function converting an input into an item.
+    ///
+    /// # Returns
+    ///
+    /// * A `Result` containing the number of items written if successful, an error otherwise.
+    pub fn ingest<R, It, F>(
+        &self,
+        split: &str,
+        inputs: It,
+        num_workers: usize,
+        convert: F,
+    ) -> Result<usize>
+    where
+        It: IntoIterator<Item = R>,
+        It::IntoIter: Send,
+        R: Send,
+        F: Fn(R) -> I + Sync,
+    {
+        let is_completed = self.is_completed.read().unwrap();
+
+        if *is_completed {
+            return Err(SqliteDatasetError::Other(
+                "Cannot save to a completed dataset writer",
+            ));
+        }
+
+        let conn_pool = self.conn_pool.as_ref().unwrap();
+        let mut conn = conn_pool.get()?;
+        self.set_durability(&conn)?;
+
+        conn.execute(
+            &format!(
+                "create table if not exists {PROGRESS_TABLE} (split text primary key not null, \
+                 num_inputs integer not null)"
+            ),
+            [],
+        )?;
+        let num_skipped: usize = conn
+            .query_row(
+                &format!("select num_inputs from {PROGRESS_TABLE} where split = ?"),
+                [split],
+                |row| row.get(0),
+            )
+            .optional()?
+            .unwrap_or(0);
+
+        let batch_size = self.batch_size;
+        let num_workers = num_workers.max(1);
+        let inputs = Mutex::new((0, inputs.into_iter().skip(num_skipped)));
+        let (sender, receiver) = mpsc::sync_channel::<(usize, Vec<I>)>(num_workers * 2);
+
+        std::thread::scope(|scope| {
+            // Owned by the scope closure, so the workers stop as soon as writing fails.
+            let receiver = receiver;
+
+            for _ in 0..num_workers {
+                let sender = sender.clone();
+                let (inputs, convert) = (&inputs, &convert);
+
+                scope.spawn(move || loop {
+                    let (index, batch) = {
+                        let mut inputs = inputs.lock().unwrap();
+                        let batch = inputs.1.by_ref().take(batch_size).collect::<Vec<R>>();
+                        inputs.0 += 1;
+                        (inputs.0 - 1, batch)
+                    };
+
+                    if batch.is_empty() {
+                        break;
+                    }
+
+                    let items = batch.into_iter().map(convert).collect();
+                    if sender.send((index, items)).is_err() {
+                        break;
+                    }
+                });
+            }
+            drop(sender);
+
+            // Write the batches in order, the progress being the number of inputs written.
+            let mut pending = BTreeMap::new();
+            let mut next = 0;
+            let mut num_written = 0;
+
+            for (index, items) in receiver.iter() {
+                pending.insert(index, items);
+
+                while let Some(items) = pending.remove(&next) {
+                    if let Some(first) = items.first() {
+                        self.create_table(split, first)?;
+                    }
+
+                    let transaction = conn.transaction()?;
+                    for item in items.iter() {
+                        self.insert(&transaction, split, item)?;
+                    }
+                    num_written += items.len();
+                    transaction.execute(
+                        &format!(
+                            "insert or replace into {PROGRESS_TABLE} (split, num_inputs) values \
+                             (?, ?)"
+                        ),
+                        rusqlite::params![split, num_skipped + num_written],
+                    )?;
+                    transaction.commit()?;
+
+                    next += 1;
+                }
+            }
 
-        Ok(index)
+            Ok(num_written)
+        })
     }
 
     /// Marks the dataset as completed and persists the temporary database file.
@@ -562,21 +818,67 @@ where
         // This is required on Windows platform where the connection pool prevents
         // from persisting the db by renaming the temp file.
         if let Some(pool) = self.conn_pool.take() {
+            pool.get()?
+                .execute(&format!("drop table if exists {PROGRESS_TABLE}"), [])?;
             std::mem::drop(pool);
         }
 
         // Rename the database file from tmp to db
-        let _file_result = self
-            .db_file_tmp
-            .take() // take ownership of the temporary file and set to None
-            .unwrap() // unwrap the temporary file
-            .persist(&self.db_file)?
-            .ok_or("Unable to persist the database file")?;
+        match self.db_file_tmp.take() {
+            Some(db_file_tmp) => {
+                let _file_result = db_file_tmp
+                    .persist(&self.db_file)?
+                    .ok_or("Unable to persist the database file")?;
+            }
+            // The temp file of a resumable writer isn't wrapped in a handle.
+            None => fs::rename(self.db_file_tmp_path(), &self.db_file)?,
+        }
 
         *is_completed = true;
         Ok(())
     }
 
+    /// Sets the durability of the connection: a resumable writer needs the committed
+    /// transactions to survive an interruption, otherwise it is sacrificed for speed.
+    fn set_durability(&self, conn: &PooledConnection<SqliteConnectionManager>) -> Result<()> {
+        let (synchronous, journal_mode) = match self.resumable {
+            true => ("NORMAL", "DELETE"),
+            false => ("OFF", "OFF"),
+        };
+
+        pragma_update_with_error_handling(conn, "synchronous", synchronous)?;
+        pragma_update_with_error_handling(conn, "journal_mode", journal_mode)
+    }
+
+    /// Inserts an item in the table of the split, returning its index.
+    fn insert(&self, conn: &rusqlite::Connection, split: &str, item: &I) -> Result<usize> {
+        if self.item_columns {
+            let params = to_params_named(item)?;
+            let params = params.to_slice();
+            let names = params.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            let columns = names
+                .iter()
+                .map(|name| name.trim_start_matches(':'))
+                .collect::<Vec<_>>();
+
+            let insert_statement = format!(
+                "insert into {split} ({}) values ({})",
+                columns.join(", "),
+                names.join(", ")
+            );
+            conn.execute(insert_statement.as_str(), params.as_slice())?;
+        } else {
+            // Serialize the item using MessagePack
+            let serialized_item = rmp_serde::to_vec(item)?;
+
+            let insert_statement = format!("insert into {split} (item) values (?)");
+            conn.execute(insert_statement.as_str(), [serialized_item])?;
+        }
+
+        // Get the primary key of the last inserted row and convert to index (row_id-1)
+        Ok((conn.last_insert_rowid() - 1) as usize)
+    }
+
     /// Creates table for the data split.
     ///
     /// Note: call is idempotent and thread-safe.
@@ -584,23 +886,34 @@ where
     /// # Arguments
     ///
     /// * `split` - A string slice that defines the data split for the table (e.g., "train", "test").
+    /// * `item` - An item of the split, from which the columns are derived when the fields are
+    ///   stored in their own columns.
     ///
     /// # Returns
     ///
     /// * A `Result` which is `Ok` if the table could be created, `Err` otherwise.
-    ///
-    /// TODO (@antimora): add support creating a table with columns corresponding to the item fields
-    fn create_table(&self, split: &str) -> Result<()> {
+    fn create_table(&self, split: &str, item: &I) -> Result<()> {
         // Check if the split already exists
         if self.splits.read().unwrap().contains(split) {
             return Ok(());
         }
 
+        let columns = match self.item_columns {
+            // Columns without type accept any value, as serialized by serde_rusqlite.
+            true => to_params_named(item)?
+                .to_slice()
+                .iter()
+                .map(|(name, _)| name.trim_start_matches(':').to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            false => "item blob not null".to_string(),
+        };
+
         let conn_pool = self.conn_pool.as_ref().unwrap();
         let connection = conn_pool.get()?;
         let create_table_statement = format!(
-            "create table if not exists  {split} (row_id integer primary key autoincrement not \
-             null, item blob not null)"
+            "create table if not exists {split} (row_id integer primary key autoincrement not \
+             null, {columns})"
         );
 
         connection.execute(create_table_statement.as_str(), [])?;
@@ -851,4 +1164,99 @@ mod tests {
         assert_eq!(train.len(), record_count as usize / 2);
         assert_eq!(test.len(), record_count as usize / 2);
     }
+
+    #[rstest]
+    pub fn sqlite_writer_write_batch(writer_fixture: (Writer, TempDir)) {
+        let (writer, _tmp_dir) = writer_fixture;
+        let items = (0..5).map(complex_item).collect::<Vec<_>>();
+
+        assert_eq!(writer.write("train", &items[0]).unwrap(), 0);
+        assert_eq!(writer.write_batch("train", &items[1..]).unwrap(), 1..5);
+        assert_eq!(writer.write_batch("train", &[]).unwrap(), 0..0);
+
+        let mut writer = writer;
+        writer.set_completed().unwrap();
+
+        let dataset = SqliteDataset::<Complex>::from_db_file(writer.db_file, "train").unwrap();
+        assert_eq!(dataset.iter().collect::<Vec<_>>(), items);
+    }
+
+    #[test]
+    fn sqlite_writer_ingest_resume() {
+        let tmp_dir = tmp_dir();
+        let storage = SqliteDatasetStorage::from_name("ingested").with_base_dir(tmp_dir.path());
+
+        // Interrupted after the first 6 inputs, in 2 batches.
+        let writer = storage
+            .resumable_writer::<Complex>()
+            .unwrap()
+            .with_batch_size(4);
+        assert_eq!(writer.ingest("train", 0..6, 3, complex_item).unwrap(), 6);
+        std::mem::drop(writer);
+        assert!(!storage.exists());
+
+        let mut writer = storage
+            .resumable_writer::<Complex>()
+            .unwrap()
+            .with_batch_size(4);
+        assert_eq!(writer.ingest("train", 0..20, 3, complex_item).unwrap(), 14);
+        writer.set_completed().unwrap();
+
+        let dataset = storage.reader::<Complex>("train").unwrap();
+        assert_eq!(
+            dataset.iter().collect::<Vec<_>>(),
+            (0..20).map(complex_item).collect::<Vec<_>>()
+        );
+        assert!(storage.resumable_writer::<Complex>().is_err());
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct Flat {
+        column_str: String,
+        column_int: i64,
+        column_bool: bool,
+        column_float: f64,
+    }
+
+    #[rstest]
+    pub fn sqlite_writer_item_columns(tmp_dir: TempDir) {
+        let db_file = tmp_dir.path().join("columns.db");
+        let mut writer = SqliteDatasetWriter::<Flat>::new(&db_file, true)
+            .unwrap()
+            .with_item_columns(true);
+        let items = (0..3)
+            .map(|index| Flat {
+                column_str: format!("HI{index}"),
+                column_int: index,
+                column_bool: index % 2 == 0,
+                column_float: index as f64 / 2.0,
+            })
+            .collect::<Vec<_>>();
+
+        writer.write_batch("train", &items).unwrap();
+        writer.set_completed().unwrap();
+
+        let dataset = SqliteDataset::<Flat>::from_db_file(&db_file, "train").unwrap();
+        assert_eq!(dataset.iter().collect::<Vec<_>>(), items);
+
+        let conn = rusqlite::Connection::open(&db_file).unwrap();
+        let mut statement = conn.prepare("select column_str from train").unwrap();
+        let columns = statement
+            .query_map([], |row| row.get::<_, String>(0))
+            .unwrap()
+            .collect::<core::result::Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(columns, vec!["HI0", "HI1", "HI2"]);
+    }
+
+    fn complex_item(index: i64) -> Complex {
+        Complex {
+            column_str: format!("test_{index}"),
+            column_bytes: vec![index as u8, 2, 3],
+            column_int: index,
+            column_bool: true,
+            column_float: 1.0,
+            column_complex: vec![vec![vec![[1, index as u8, 3]]]],
+        }
+    }
 }
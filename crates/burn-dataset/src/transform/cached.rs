@@ -0,0 +1,255 @@
+use crate::{transform::Mapper, Dataset};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    any::type_name,
+    fs::{self, File},
+    io::{self, BufWriter, Read, Seek, SeekFrom, Write},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+const DATA_EXTENSION: &str = "cache";
+const INDEX_EXTENSION: &str = "index";
+
+/// Cached mapper dataset error.
+#[derive(thiserror::Error, Debug)]
+pub enum CacheError {
+    /// IO related error.
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    /// Serialization related error.
+    #[error("Serialization error: {0}")]
+    Encode(#[from] rmp_serde::encode::Error),
+
+    /// Deserialization related error.
+    #[error("Deserialization error: {0}")]
+    Decode(#[from] rmp_serde::decode::Error),
+}
+
+/// Dataset mapping each item of an inner dataset with an expensive deterministic transform, such
+/// as tokenization or feature extraction, materialized once in a cache on disk.
+///
+/// The cache is keyed by a fingerprint of the inner items, the mapper type and the output type,
+/// so it is recomputed when the inner dataset changes. Caches with other fingerprints found in the
+/// cache directory are considered stale and removed, so each dataset should use its own directory.
+///
+/// # Notes
+///
+/// The fingerprint doesn't capture the implementation of the mapper: clear the cache directory
+/// after changing it.
+pub struct CachedMapperDataset<O> {
+    path: PathBuf,
+    offsets: Vec<u64>,
+    output: PhantomData<O>,
+}
+
+impl<O> CachedMapperDataset<O>
+where
+    O: Serialize + DeserializeOwned + Send + Sync,
+{
+    /// Maps all the items of the dataset into the cache, unless the cache of the same items
+    /// already exists.
+    pub fn new<D, M, I, P>(dataset: D, mapper: M, cache_dir: P) -> Result<Self, CacheError>
+    where
+        D: Dataset<I>,
+        M: Mapper<I, O>,
+        I: Serialize,
+        P: AsRef<Path>,
+    {
+        let cache_dir = cache_dir.as_ref();
+        fs::create_dir_all(cache_dir)?;
+
+        let fingerprint = fingerprint::<D, M, I, O>(&dataset)?;
+        let path = cache_dir.join(format!("{fingerprint:016x}"));
+        let index_path = path.with_extension(INDEX_EXTENSION);
+        remove_stale(cache_dir, &path)?;
+
+        // The index is written last, so it only exists when the cache is complete.
+        let offsets = match File::open(&index_path) {
+            Ok(file) => rmp_serde::decode::from_read(io::BufReader::new(file))?,
+            Err(_) => {
+                let offsets = write_cache(&dataset, &mapper, &path)?;
+
+                let tmp_path = path.with_extension("index.tmp");
+                let mut writer = BufWriter::new(File::create(&tmp_path)?);
+                rmp_serde::encode::write(&mut writer, &offsets)?;
+                writer.flush()?;
+                fs::rename(tmp_path, &index_path)?;
+
+                offsets
+            }
+        };
+
+        Ok(Self {
+            path: path.with_extension(DATA_EXTENSION),
+            offsets,
+            output: PhantomData,
+        })
+    }
+
+    fn read(&self, index: usize) -> Result<O, CacheError> {
+        let start = self.offsets[index];
+        let end = self.offsets[index + 1];
+
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(start))?;
+        let mut bytes = vec![0; (end - start) as usize];
+        file.read_exact(&mut bytes)?;
+
+        Ok(rmp_serde::from_slice(&bytes)?)
+    }
+}
+
+impl<O> Dataset<O> for CachedMapperDataset<O>
+where
+    O: Serialize + DeserializeOwned + Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<O> {
+        if index >= self.len() {
+            return None;
+        }
+
+        match self.read(index) {
+            Ok(item) => Some(item),
+            Err(err) => panic!("Failed to read item {index} from the dataset cache: {err}"),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+}
+
+/// Writes the mapped items one after the other, returning the offset of each item followed by
+/// the size of the file.
+fn write_cache<D, M, I, O>(dataset: &D, mapper: &M, path: &Path) -> Result<Vec<u64>, CacheError>
+where
+    D: Dataset<I>,
+    M: Mapper<I, O>,
+    O: Serialize,
+{
+    let mut writer = BufWriter::new(File::create(path.with_extension(DATA_EXTENSION))?);
+    let mut offsets = Vec::with_capacity(dataset.len() + 1);
+    let mut offset = 0;
+    offsets.push(offset);
+
+    for index in 0..dataset.len() {
+        let item = dataset.get(index).expect("Dataset items should exist");
+        let bytes = rmp_serde::to_vec(&mapper.map(&item))?;
+        writer.write_all(&bytes)?;
+
+        offset += bytes.len() as u64;
+        offsets.push(offset);
+    }
+    writer.flush()?;
+
+    Ok(offsets)
+}
+
+/// FNV-1a hash of the serialized items along with the mapper and output types, stable across
+/// runs contrary to the hasher of the standard library.
+fn fingerprint<D, M, I, O>(dataset: &D) -> Result<u64, CacheError>
+where
+    D: Dataset<I>,
+    I: Serialize,
+{
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut write = |bytes: &[u8]| {
+        for byte in bytes {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    };
+
+    write(type_name::<M>().as_bytes());
+    write(type_name::<O>().as_bytes());
+    write(&(dataset.len() as u64).to_le_bytes());
+
+    for item in dataset.iter() {
+        write(&rmp_serde::to_vec(&item)?);
+    }
+
+    Ok(hash)
+}
+
+/// Removes the cache files of the directory not belonging to the given cache.
+fn remove_stale(cache_dir: &Path, path: &Path) -> Result<(), CacheError> {
+    for entry in fs::read_dir(cache_dir)? {
+        let entry = entry?.path();
+        let is_cache = matches!(
+            entry.extension().and_then(|ext| ext.to_str()),
+            Some(DATA_EXTENSION) | Some(INDEX_EXTENSION) | Some("tmp")
+        );
+        let is_current = entry
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split('.').next())
+            == path.file_name().and_then(|name| name.to_str());
+
+        if is_cache && !is_current {
+            fs::remove_file(entry)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_data, InMemDataset};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    struct CountChars {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Mapper<String, usize> for CountChars {
+        fn map(&self, item: &String) -> usize {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            item.len()
+        }
+    }
+
+    #[test]
+    fn should_map_the_items_once() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mapper = CountChars {
+            calls: Arc::new(AtomicUsize::new(0)),
+        };
+        let dataset = || InMemDataset::new(test_data::string_items());
+
+        let first = dataset().map_cached(mapper.clone(), &cache_dir).unwrap();
+        let second = dataset().map_cached(mapper.clone(), &cache_dir).unwrap();
+
+        assert_eq!(first.iter().collect::<Vec<_>>(), vec![6, 7, 7, 7]);
+        assert_eq!(second.iter().collect::<Vec<_>>(), vec![6, 7, 7, 7]);
+        assert_eq!(mapper.calls.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn should_invalidate_the_cache_when_the_items_change() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mapper = CountChars {
+            calls: Arc::new(AtomicUsize::new(0)),
+        };
+        let mut items = test_data::string_items();
+
+        let first = InMemDataset::new(items.clone())
+            .map_cached(mapper.clone(), &cache_dir)
+            .unwrap();
+        items[0] = "1".to_string();
+        let second = InMemDataset::new(items)
+            .map_cached(mapper.clone(), &cache_dir)
+            .unwrap();
+
+        assert_eq!(second.get(0), Some(1));
+        assert_eq!(second.get(4), None);
+        assert_eq!(mapper.calls.load(Ordering::Relaxed), 8);
+        assert!(!first.path.exists(), "The stale cache should be removed.");
+        assert_eq!(fs::read_dir(&cache_dir).unwrap().count(), 2);
+    }
+}
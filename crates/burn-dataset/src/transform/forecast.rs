@@ -0,0 +1,351 @@
+use crate::Dataset;
+use serde::{Deserialize, Serialize};
+use std::{fs, io, path::Path};
+
+/// Per-feature normalization statistics of a time series, computed once on the training data and
+/// saved along with the dataset so the same normalization is used at inference.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NormalizationStats {
+    /// The mean of each feature.
+    pub mean: Vec<f64>,
+    /// The standard deviation of each feature.
+    pub std: Vec<f64>,
+}
+
+impl NormalizationStats {
+    /// Computes the statistics of the time steps of the dataset, each item being the features of
+    /// a time step.
+    ///
+    /// Constant features get a standard deviation of one, so they are only centered.
+    pub fn compute<D>(dataset: &D) -> Self
+    where
+        D: Dataset<Vec<f32>>,
+    {
+        // Welford's algorithm, numerically stable in a single pass.
+        let mut count = 0.0;
+        let mut mean: Vec<f64> = Vec::new();
+        let mut m2: Vec<f64> = Vec::new();
+
+        for item in dataset.iter() {
+            if mean.is_empty() {
+                mean = vec![0.0; item.len()];
+                m2 = vec![0.0; item.len()];
+            }
+            assert_eq!(
+                item.len(),
+                mean.len(),
+                "All the time steps should have the same number of features."
+            );
+
+            count += 1.0;
+            for (feature, value) in item.iter().enumerate() {
+                let value = *value as f64;
+                let delta = value - mean[feature];
+                mean[feature] += delta / count;
+                m2[feature] += delta * (value - mean[feature]);
+            }
+        }
+
+        let std = m2
+            .into_iter()
+            .map(|m2| match (m2 / count).sqrt() {
+                std if std > f64::EPSILON => std,
+                _ => 1.0,
+            })
+            .collect();
+
+        Self { mean, std }
+    }
+
+    /// Normalizes the features of a time step.
+    pub fn normalize(&self, features: &mut [f32]) {
+        for ((value, mean), std) in features.iter_mut().zip(&self.mean).zip(&self.std) {
+            *value = ((*value as f64 - mean) / std) as f32;
+        }
+    }
+
+    /// Reverts the normalization of the given features, such as the predictions of a model
+    /// trained on normalized targets.
+    pub fn denormalize(&self, features: &mut [f32], indices: &[usize]) {
+        for (value, index) in features.iter_mut().zip(indices) {
+            *value = (*value as f64 * self.std[*index] + self.mean[*index]) as f32;
+        }
+    }
+
+    /// Saves the statistics to a JSON file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)
+    }
+
+    /// Loads the statistics from a JSON file.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+/// A forecasting sample: a window of past time steps and the time steps to predict.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForecastItem {
+    /// The features of the past time steps, of shape `[lookback, num_features]`.
+    pub input: Vec<Vec<f32>>,
+    /// The target features of the next time steps, of shape `[horizon, num_targets]`.
+    pub target: Vec<Vec<f32>>,
+}
+
+/// Sliding windows over a time series for forecasting, each item of the inner dataset being the
+/// features of a time step.
+///
+/// The window at index `i` starts at time step `i * stride`, its input covers `lookback` time
+/// steps and its target the next `horizon` time steps.
+pub struct ForecastDataset<D> {
+    dataset: D,
+    lookback: usize,
+    horizon: usize,
+    stride: usize,
+    targets: Option<Vec<usize>>,
+    stats: Option<NormalizationStats>,
+}
+
+impl<D> ForecastDataset<D>
+where
+    D: Dataset<Vec<f32>>,
+{
+    /// Creates the windows of `lookback` time steps predicting the next `horizon` time steps.
+    ///
+    /// # Panics
+    ///
+    /// If the lookback or the horizon is zero.
+    pub fn new(dataset: D, lookback: usize, horizon: usize) -> Self {
+        assert!(
+            lookback > 0 && horizon > 0,
+            "The lookback and the horizon should be greater than zero."
+        );
+
+        Self {
+            dataset,
+            lookback,
+            horizon,
+            stride: 1,
+            targets: None,
+            stats: None,
+        }
+    }
+
+    /// Sets the number of time steps between the starts of consecutive windows.
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        self.stride = stride.max(1);
+        self
+    }
+
+    /// Only predict the features at the given indices, all the features are predicted by default.
+    pub fn with_targets(mut self, targets: Vec<usize>) -> Self {
+        self.targets = Some(targets);
+        self
+    }
+
+    /// Normalize the inputs and the targets with the given statistics.
+    pub fn with_normalization(mut self, stats: NormalizationStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    /// Normalize the inputs and the targets with the statistics of the inner dataset.
+    ///
+    /// Only use on the training split, the validation and test splits should reuse the
+    /// [statistics](Self::stats) of the training split to avoid leaking information.
+    pub fn with_computed_normalization(self) -> Self {
+        let stats = NormalizationStats::compute(&self.dataset);
+        self.with_normalization(stats)
+    }
+
+    /// The normalization statistics, if any.
+    pub fn stats(&self) -> Option<&NormalizationStats> {
+        self.stats.as_ref()
+    }
+
+    fn time_step(&self, index: usize) -> Option<Vec<f32>> {
+        let mut features = self.dataset.get(index)?;
+        if let Some(stats) = self.stats.as_ref() {
+            stats.normalize(&mut features);
+        }
+
+        Some(features)
+    }
+}
+
+impl<D> Dataset<ForecastItem> for ForecastDataset<D>
+where
+    D: Dataset<Vec<f32>>,
+{
+    fn get(&self, index: usize) -> Option<ForecastItem> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let start = index * self.stride;
+        let input = (start..start + self.lookback)
+            .map(|index| self.time_step(index))
+            .collect::<Option<Vec<_>>>()?;
+        let target = (start + self.lookback..start + self.lookback + self.horizon)
+            .map(|index| {
+                let features = self.time_step(index)?;
+                Some(match &self.targets {
+                    Some(targets) => targets.iter().map(|target| features[*target]).collect(),
+                    None => features,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(ForecastItem { input, target })
+    }
+
+    fn len(&self) -> usize {
+        match self.dataset.len().checked_sub(self.lookback + self.horizon) {
+            Some(remaining) => remaining / self.stride + 1,
+            None => 0,
+        }
+    }
+}
+
+/// How the values of a period are aggregated when [resampling](resample) a time series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    /// The mean of the values.
+    Mean,
+    /// The sum of the values.
+    Sum,
+    /// The last value.
+    Last,
+}
+
+/// Resamples an irregular time series of `(timestamp, features)` sorted by timestamp into
+/// regular periods starting at the first timestamp.
+///
+/// The features of the time steps in each period are aggregated, and the periods without time
+/// steps repeat the features of the previous period.
+pub fn resample(
+    series: &[(i64, Vec<f32>)],
+    period: i64,
+    aggregation: Aggregation,
+) -> Vec<(i64, Vec<f32>)> {
+    assert!(period > 0, "The period should be greater than zero.");
+
+    let (first, last) = match (series.first(), series.last()) {
+        (Some(first), Some(last)) => (first.0, last.0),
+        _ => return Vec::new(),
+    };
+    let num_periods = ((last - first) / period + 1) as usize;
+    let mut buckets: Vec<Vec<&[f32]>> = vec![Vec::new(); num_periods];
+
+    for (timestamp, features) in series {
+        buckets[((timestamp - first) / period) as usize].push(features);
+    }
+
+    let mut previous: Option<Vec<f32>> = None;
+    let mut resampled = Vec::with_capacity(num_periods);
+
+    for (index, bucket) in buckets.into_iter().enumerate() {
+        let features = match bucket.last() {
+            None => previous.clone().expect("The first period has a time step."),
+            Some(last) => match aggregation {
+                Aggregation::Last => last.to_vec(),
+                Aggregation::Sum | Aggregation::Mean => {
+                    let mut features = vec![0.0; last.len()];
+                    for values in bucket.iter() {
+                        for (sum, value) in features.iter_mut().zip(values.iter()) {
+                            *sum += value;
+                        }
+                    }
+                    if aggregation == Aggregation::Mean {
+                        features
+                            .iter_mut()
+                            .for_each(|sum| *sum /= bucket.len() as f32);
+                    }
+                    features
+                }
+            },
+        };
+
+        resampled.push((first + index as i64 * period, features.clone()));
+        previous = Some(features);
+    }
+
+    resampled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+
+    fn series() -> InMemDataset<Vec<f32>> {
+        InMemDataset::new(
+            (0..10)
+                .map(|t| vec![t as f32, 2.0 * t as f32, 1.0])
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn windows_should_follow_the_lookback_horizon_and_stride() {
+        let dataset = ForecastDataset::new(series(), 3, 2)
+            .with_stride(2)
+            .with_targets(vec![1]);
+
+        // Windows start at 0, 2 and 4, the last one ending at the time step 8.
+        assert_eq!(dataset.len(), 3);
+        let item = dataset.get(1).unwrap();
+        assert_eq!(item.input.len(), 3);
+        assert_eq!(item.input[0], vec![2.0, 4.0, 1.0]);
+        assert_eq!(item.target, vec![vec![10.0], vec![12.0]]);
+        assert_eq!(dataset.get(3), None);
+    }
+
+    #[test]
+    fn normalization_stats_should_be_reused() {
+        let train = ForecastDataset::new(series(), 2, 1).with_computed_normalization();
+        let stats = train.stats().unwrap().clone();
+
+        for (mean, expected) in stats.mean.iter().zip([4.5, 9.0, 1.0]) {
+            assert!((mean - expected).abs() < 1e-9);
+        }
+        assert_eq!(stats.std[2], 1.0);
+        let item = train.get(0).unwrap();
+        assert!((item.input[0][0] + 4.5 / stats.std[0] as f32).abs() < 1e-5);
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        stats.save(file.path()).unwrap();
+        let loaded = NormalizationStats::load(file.path()).unwrap();
+        assert_eq!(loaded, stats);
+
+        let mut prediction = item.target[0].clone();
+        loaded.denormalize(&mut prediction, &[0, 1, 2]);
+        assert!((prediction[0] - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn resample_should_aggregate_and_fill_periods() {
+        let series = vec![
+            (0, vec![1.0]),
+            (3, vec![3.0]),
+            (5, vec![4.0]),
+            (16, vec![8.0]),
+        ];
+
+        let resampled = resample(&series, 5, Aggregation::Mean);
+
+        assert_eq!(
+            resampled,
+            vec![
+                (0, vec![2.0]),
+                (5, vec![4.0]),
+                (10, vec![4.0]),
+                (15, vec![8.0])
+            ]
+        );
+        assert_eq!(resample(&series, 5, Aggregation::Sum)[0], (0, vec![4.0]));
+        assert_eq!(resample(&series, 5, Aggregation::Last)[0], (0, vec![3.0]));
+    }
+}
@@ -0,0 +1,174 @@
+use crate::Dataset;
+use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
+use std::{marker::PhantomData, sync::Arc};
+
+/// Only use the items of an existing dataset at the given indices, in order.
+#[derive(new)]
+pub struct SubsetDataset<D, I> {
+    dataset: D,
+    indices: Vec<usize>,
+    input: PhantomData<I>,
+}
+
+impl<D, I> Dataset<I> for SubsetDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Clone + Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        let index = self.indices.get(index)?;
+        self.dataset.get(*index)
+    }
+
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+/// Split a dataset into folds for cross-validation.
+///
+/// Each fold uses a different slice of the dataset for validation and the remaining items for
+/// training, so every item is used exactly once for validation.
+#[derive(Clone, Debug)]
+pub struct KFold {
+    num_folds: usize,
+    seed: Option<u64>,
+}
+
+impl KFold {
+    /// Creates a new k-fold splitter, keeping the dataset order.
+    ///
+    /// # Panics
+    ///
+    /// If the number of folds is smaller than 2.
+    pub fn new(num_folds: usize) -> Self {
+        assert!(
+            num_folds >= 2,
+            "Cross-validation requires at least 2 folds, got {num_folds}."
+        );
+
+        Self {
+            num_folds,
+            seed: None,
+        }
+    }
+
+    /// Shuffle the dataset with the given seed before splitting it.
+    pub fn with_shuffle(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// The number of folds.
+    pub fn num_folds(&self) -> usize {
+        self.num_folds
+    }
+
+    /// Splits the dataset into `(train, valid)` pairs, one per fold.
+    ///
+    /// When the dataset size isn't a multiple of the number of folds, the first folds have one more
+    /// validation item than the others.
+    #[allow(clippy::type_complexity)]
+    pub fn split<D, I>(
+        &self,
+        dataset: D,
+    ) -> Vec<(SubsetDataset<Arc<D>, I>, SubsetDataset<Arc<D>, I>)>
+    where
+        D: Dataset<I>,
+    {
+        let dataset = Arc::new(dataset); // cheap cloning.
+
+        let mut indices = (0..dataset.len()).collect::<Vec<_>>();
+        if let Some(seed) = self.seed {
+            indices.shuffle(&mut StdRng::seed_from_u64(seed));
+        }
+
+        let fold_size = indices.len() / self.num_folds;
+        let remainder = indices.len() % self.num_folds;
+        let mut start = 0;
+
+        (0..self.num_folds)
+            .map(|fold| {
+                let end = start + fold_size + usize::from(fold < remainder);
+
+                let valid = indices[start..end].to_vec();
+                let train = indices[..start]
+                    .iter()
+                    .chain(indices[end..].iter())
+                    .copied()
+                    .collect();
+                start = end;
+
+                (
+                    SubsetDataset::new(dataset.clone(), train),
+                    SubsetDataset::new(dataset.clone(), valid),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FakeDataset;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_subset_follows_indices() {
+        let dataset_original = FakeDataset::<String>::new(10);
+        let items = dataset_original.iter().collect::<Vec<_>>();
+
+        let dataset = SubsetDataset::new(dataset_original, vec![7, 2, 2]);
+
+        assert_eq!(dataset.len(), 3);
+        assert_eq!(
+            dataset.iter().collect::<Vec<_>>(),
+            vec![items[7].clone(), items[2].clone(), items[2].clone()]
+        );
+        assert_eq!(dataset.get(3), None);
+    }
+
+    #[test]
+    fn test_kfold_each_item_validated_once() {
+        let dataset_original = FakeDataset::<String>::new(27);
+        let items = dataset_original.iter().collect::<HashSet<_>>();
+
+        let folds = KFold::new(4).with_shuffle(42).split(dataset_original);
+        let mut items_valid = Vec::new();
+
+        assert_eq!(folds.len(), 4);
+        for (train, valid) in folds.iter() {
+            assert_eq!(train.len() + valid.len(), 27);
+            assert!(valid.len() == 6 || valid.len() == 7);
+
+            let items_train = train.iter().collect::<HashSet<_>>();
+            for item in valid.iter() {
+                assert!(!items_train.contains(&item));
+                items_valid.push(item);
+            }
+        }
+
+        assert_eq!(items_valid.len(), 27);
+        assert_eq!(items_valid.into_iter().collect::<HashSet<_>>(), items);
+    }
+
+    #[test]
+    fn test_kfold_without_shuffle_keeps_order() {
+        let dataset_original = FakeDataset::<String>::new(6);
+        let items = dataset_original.iter().collect::<Vec<_>>();
+
+        let folds = KFold::new(3).split(dataset_original);
+
+        assert_eq!(folds[1].1.iter().collect::<Vec<_>>(), items[2..4].to_vec());
+        assert_eq!(
+            folds[1].0.iter().collect::<Vec<_>>(),
+            vec![
+                items[0].clone(),
+                items[1].clone(),
+                items[4].clone(),
+                items[5].clone()
+            ]
+        );
+    }
+}
@@ -1,11 +1,19 @@
+mod cached;
 mod composed;
+mod forecast;
+mod kfold;
 mod mapper;
 mod partial;
 mod random;
 mod sampler;
+mod split;
 
+pub use cached::*;
 pub use composed::*;
+pub use forecast::*;
+pub use kfold::*;
 pub use mapper::*;
 pub use partial::*;
 pub use random::*;
 pub use sampler::*;
+pub use split::*;
@@ -1,5 +1,5 @@
 use crate::Dataset;
-use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
+use rand::{prelude::SliceRandom, rngs::StdRng, RngCore, SeedableRng};
 use std::marker::PhantomData;
 
 /// Shuffled a dataset, consider using [sampler dataset](crate::transform::SamplerDataset) is you
@@ -14,8 +14,8 @@ impl<D, I> ShuffledDataset<D, I>
 where
     D: Dataset<I>,
 {
-    /// Creates a new shuffled dataset.
-    pub fn new(dataset: D, rng: &mut StdRng) -> Self {
+    /// Creates a new shuffled dataset with the given random number generator.
+    pub fn new<R: RngCore>(dataset: D, rng: &mut R) -> Self {
         let mut indices = Vec::with_capacity(dataset.len());
         for i in 0..dataset.len() {
             indices.push(i);
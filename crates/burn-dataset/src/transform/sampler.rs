@@ -1,5 +1,5 @@
 use crate::Dataset;
-use rand::{distributions::Uniform, rngs::StdRng, seq::IteratorRandom, Rng, SeedableRng};
+use rand::{distributions::Uniform, rngs::StdRng, seq::IteratorRandom, Rng, RngCore, SeedableRng};
 use std::{marker::PhantomData, ops::DerefMut, sync::Mutex};
 
 /// Sample items from a dataset.
@@ -59,6 +59,23 @@ where
         }
     }
 
+    /// Seed the sampler from the given random number generator, instead of the entropy of the
+    /// system, so the sampled items are deterministic.
+    pub fn with_rng<R: RngCore>(self, rng: &mut R) -> Self {
+        let seeded = StdRng::seed_from_u64(rng.next_u64());
+        let state = match self.state.into_inner().unwrap() {
+            SamplerState::WithReplacement(_) => SamplerState::WithReplacement(seeded),
+            SamplerState::WithoutReplacement(_, indices) => {
+                SamplerState::WithoutReplacement(seeded, indices)
+            }
+        };
+
+        Self {
+            state: Mutex::new(state),
+            ..self
+        }
+    }
+
     fn index(&self) -> usize {
         let mut state = self.state.lock().unwrap();
 
@@ -143,4 +160,15 @@ mod tests {
         }
         assert_eq!(total, factor * len_original);
     }
+
+    #[test]
+    fn sampler_dataset_with_rng_should_be_deterministic() {
+        let sample = |seed| {
+            let dataset = SamplerDataset::without_replacement(FakeDataset::<String>::new(10), 10)
+                .with_rng(&mut StdRng::seed_from_u64(seed));
+            dataset.iter().collect::<Vec<_>>()
+        };
+
+        assert_eq!(sample(42), sample(42));
+    }
 }
@@ -0,0 +1,192 @@
+use crate::{transform::SubsetDataset, Dataset};
+use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
+use std::{collections::HashMap, hash::Hash, sync::Arc};
+
+/// Split a dataset into a training and a test dataset.
+#[derive(Clone, Debug)]
+pub struct TrainTestSplit {
+    test_ratio: f64,
+    seed: Option<u64>,
+}
+
+impl TrainTestSplit {
+    /// Creates a new splitter keeping the dataset order, the last items being used for testing.
+    ///
+    /// # Panics
+    ///
+    /// If the test ratio isn't between 0 and 1.
+    pub fn new(test_ratio: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&test_ratio),
+            "The test ratio should be between 0 and 1, got {test_ratio}."
+        );
+
+        Self {
+            test_ratio,
+            seed: None,
+        }
+    }
+
+    /// Shuffle the dataset with the given seed before splitting it.
+    pub fn with_shuffle(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Splits the dataset into `(train, test)` datasets.
+    pub fn split<D, I>(&self, dataset: D) -> (SubsetDataset<Arc<D>, I>, SubsetDataset<Arc<D>, I>)
+    where
+        D: Dataset<I>,
+    {
+        let mut indices = (0..dataset.len()).collect::<Vec<_>>();
+        if let Some(seed) = self.seed {
+            indices.shuffle(&mut StdRng::seed_from_u64(seed));
+        }
+
+        let (train, test) = self.split_indices(indices);
+        Self::subsets(dataset, train, test)
+    }
+
+    /// Splits the dataset into `(train, test)` datasets with the same proportion of each label,
+    /// given by `label_fn`, as the whole dataset.
+    pub fn split_stratified<D, I, L, F>(
+        &self,
+        dataset: D,
+        label_fn: F,
+    ) -> (SubsetDataset<Arc<D>, I>, SubsetDataset<Arc<D>, I>)
+    where
+        D: Dataset<I>,
+        L: Eq + Hash,
+        F: Fn(&I) -> L,
+    {
+        // Group the indices by label, keeping the labels in order of appearance.
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut labels = HashMap::new();
+        for (index, item) in dataset.iter().enumerate() {
+            let group = *labels.entry(label_fn(&item)).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(index);
+        }
+
+        let mut rng = self.seed.map(StdRng::seed_from_u64);
+        let mut train = Vec::with_capacity(dataset.len());
+        let mut test = Vec::new();
+
+        for mut group in groups {
+            if let Some(rng) = rng.as_mut() {
+                group.shuffle(rng);
+            }
+
+            let (group_train, group_test) = self.split_indices(group);
+            train.extend(group_train);
+            test.extend(group_test);
+        }
+
+        // Interleave the labels, otherwise they would be sorted.
+        match rng.as_mut() {
+            Some(rng) => {
+                train.shuffle(rng);
+                test.shuffle(rng);
+            }
+            None => {
+                train.sort_unstable();
+                test.sort_unstable();
+            }
+        }
+
+        Self::subsets(dataset, train, test)
+    }
+
+    fn split_indices(&self, mut indices: Vec<usize>) -> (Vec<usize>, Vec<usize>) {
+        let num_test = (indices.len() as f64 * self.test_ratio).round() as usize;
+        let test = indices.split_off(indices.len() - num_test);
+
+        (indices, test)
+    }
+
+    fn subsets<D, I>(
+        dataset: D,
+        train: Vec<usize>,
+        test: Vec<usize>,
+    ) -> (SubsetDataset<Arc<D>, I>, SubsetDataset<Arc<D>, I>)
+    where
+        D: Dataset<I>,
+    {
+        let dataset = Arc::new(dataset); // cheap cloning.
+
+        (
+            SubsetDataset::new(dataset.clone(), train),
+            SubsetDataset::new(dataset, test),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FakeDataset, InMemDataset};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_split_without_shuffle_keeps_order() {
+        let dataset_original = FakeDataset::<String>::new(10);
+        let items = dataset_original.iter().collect::<Vec<_>>();
+
+        let (train, test) = TrainTestSplit::new(0.2).split(dataset_original);
+
+        assert_eq!(train.iter().collect::<Vec<_>>(), items[..8].to_vec());
+        assert_eq!(test.iter().collect::<Vec<_>>(), items[8..].to_vec());
+    }
+
+    #[test]
+    fn test_split_with_shuffle_is_deterministic() {
+        let dataset_original = FakeDataset::<String>::new(27);
+        let items = dataset_original.iter().collect::<HashSet<_>>();
+        let dataset = InMemDataset::new(dataset_original.iter().collect());
+
+        let (train, test) = TrainTestSplit::new(0.3)
+            .with_shuffle(42)
+            .split(dataset_original);
+        let (train_2, test_2) = TrainTestSplit::new(0.3).with_shuffle(42).split(dataset);
+
+        assert_eq!(test.len(), 8);
+        assert_eq!(train.len(), 19);
+        assert_eq!(
+            test.iter().collect::<Vec<_>>(),
+            test_2.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            train.iter().collect::<Vec<_>>(),
+            train_2.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            train.iter().chain(test.iter()).collect::<HashSet<_>>(),
+            items
+        );
+    }
+
+    #[test]
+    fn test_split_stratified_keeps_label_proportions() {
+        // 8 items with label 0 and 4 items with label 1.
+        let dataset = InMemDataset::new((0..12).collect::<Vec<usize>>());
+
+        let (train, test) = TrainTestSplit::new(0.25)
+            .with_shuffle(42)
+            .split_stratified(dataset, |item| usize::from(item % 3 == 0));
+
+        let count = |items: Vec<usize>, label: usize| {
+            items
+                .into_iter()
+                .filter(|item| usize::from(item % 3 == 0) == label)
+                .count()
+        };
+        let test = test.iter().collect::<Vec<_>>();
+        let train = train.iter().collect::<Vec<_>>();
+        assert_eq!(count(test.clone(), 0), 2);
+        assert_eq!(count(test, 1), 1);
+        assert_eq!(count(train.clone(), 0), 6);
+        assert_eq!(count(train, 1), 3);
+    }
+}
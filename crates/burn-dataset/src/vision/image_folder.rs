@@ -8,7 +8,7 @@ use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
-const SUPPORTED_FILES: [&str; 4] = ["bmp", "jpg", "jpeg", "png"];
+pub(crate) const SUPPORTED_FILES: [&str; 4] = ["bmp", "jpg", "jpeg", "png"];
 
 /// Image data type.
 #[derive(Debug, Clone, PartialEq)]
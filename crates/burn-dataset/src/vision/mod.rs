@@ -1,5 +1,7 @@
 mod image_folder;
 mod mnist;
+mod video;
 
 pub use image_folder::*;
 pub use mnist::*;
+pub use video::*;
@@ -0,0 +1,472 @@
+use crate::Dataset;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use thiserror::Error;
+
+use super::image_folder::SUPPORTED_FILES;
+
+/// A decoded video frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoFrame {
+    /// The pixels of the frame in `[height, width, channels]` order.
+    pub pixels: Vec<u8>,
+    /// The height of the frame.
+    pub height: usize,
+    /// The width of the frame.
+    pub width: usize,
+    /// The number of channels of the frame.
+    pub channels: usize,
+}
+
+/// Error type for [VideoFolderDataset](VideoFolderDataset).
+#[derive(Error, Debug)]
+pub enum VideoLoaderError {
+    /// Unknown error.
+    #[error("unknown: `{0}`")]
+    Unknown(String),
+
+    /// I/O operation error.
+    #[error("I/O error: `{0}`")]
+    IOError(String),
+
+    /// Decoding error.
+    #[error("Could not decode the video `{0}`: {1}")]
+    DecodeError(PathBuf, String),
+
+    /// The video has no frames.
+    #[error("The video `{0}` has no frames")]
+    EmptyVideo(PathBuf),
+
+    /// The frames of the video don't all have the same size.
+    #[error("The frames of the video `{0}` have different sizes")]
+    FrameSizeMismatch(PathBuf),
+}
+
+/// Decodes the frames of videos, to be implemented with the video library of your choice.
+///
+/// The [FrameFolderDecoder] reads videos whose frames were extracted beforehand.
+pub trait VideoDecoder: Send + Sync {
+    /// The number of frames of the video.
+    fn num_frames(&self, path: &Path) -> Result<usize, VideoLoaderError>;
+
+    /// Decodes the frames of the video at the given indices, which are sorted and unique.
+    fn decode(&self, path: &Path, indices: &[usize]) -> Result<Vec<VideoFrame>, VideoLoaderError>;
+}
+
+/// Decoder of videos stored as directories of frame images, ordered by file name.
+///
+/// The frames can be extracted with `ffmpeg -i video.mp4 video/%06d.jpg`.
+#[derive(Debug, Clone, Default)]
+pub struct FrameFolderDecoder;
+
+impl FrameFolderDecoder {
+    fn frame_paths(&self, path: &Path) -> Result<Vec<PathBuf>, VideoLoaderError> {
+        let mut frames = fs::read_dir(path)
+            .map_err(|err| VideoLoaderError::IOError(err.to_string()))?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .and_then(|ext| ext.to_str())
+                    .map(|ext| SUPPORTED_FILES.contains(&ext))
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<_>>();
+        frames.sort();
+
+        Ok(frames)
+    }
+}
+
+impl VideoDecoder for FrameFolderDecoder {
+    fn num_frames(&self, path: &Path) -> Result<usize, VideoLoaderError> {
+        Ok(self.frame_paths(path)?.len())
+    }
+
+    fn decode(&self, path: &Path, indices: &[usize]) -> Result<Vec<VideoFrame>, VideoLoaderError> {
+        let frames = self.frame_paths(path)?;
+
+        indices
+            .iter()
+            .map(|index| {
+                let frame = frames.get(*index).ok_or_else(|| {
+                    VideoLoaderError::DecodeError(
+                        path.to_path_buf(),
+                        format!("frame {index} out of {} frames", frames.len()),
+                    )
+                })?;
+                let image = image::open(frame)
+                    .map_err(|err| VideoLoaderError::DecodeError(frame.clone(), err.to_string()))?
+                    .into_rgb8();
+
+                Ok(VideoFrame {
+                    height: image.height() as usize,
+                    width: image.width() as usize,
+                    channels: 3,
+                    pixels: image.into_raw(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Strategy selecting the frames of a clip in a video.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipSampling {
+    /// A clip starting at a random frame, for training.
+    Random,
+    /// The clip at the center of the video, for evaluation.
+    Center,
+    /// One frame in each of the segments of equal length covering the whole video, as in
+    /// temporal segment networks.
+    Segments,
+}
+
+/// Samples the indices of the frames of a clip.
+///
+/// Each frame of the clip is sampled in an interval of the video: consecutive intervals of
+/// `stride` frames for the [random](ClipSampling::Random) and [center](ClipSampling::Center)
+/// clips, or the [segments](ClipSampling::Segments) of the video. Without temporal jitter, the
+/// first frame of each interval is selected (the middle one for segments), otherwise a random
+/// frame of the interval. The last frame is repeated when the video is too short.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClipSampler {
+    num_frames: usize,
+    stride: usize,
+    sampling: ClipSampling,
+    jitter: bool,
+    seed: Option<u64>,
+}
+
+impl ClipSampler {
+    /// Creates a sampler of clips of `num_frames` consecutive frames.
+    pub fn new(num_frames: usize, sampling: ClipSampling) -> Self {
+        assert!(num_frames > 0, "Clips should have at least one frame.");
+
+        Self {
+            num_frames,
+            stride: 1,
+            sampling,
+            jitter: false,
+            seed: None,
+        }
+    }
+
+    /// Sample one frame every `stride` frames, ignored by [segments](ClipSampling::Segments).
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        self.stride = stride.max(1);
+        self
+    }
+
+    /// Select a random frame in each interval of the clip.
+    pub fn with_temporal_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// The seed of the random number generator, a random seed is used when not set.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// The number of frames of the clips.
+    pub fn num_frames(&self) -> usize {
+        self.num_frames
+    }
+
+    /// Samples the indices of the frames of a clip in a video of `video_len` frames.
+    pub fn sample<R: Rng>(&self, video_len: usize, rng: &mut R) -> Vec<usize> {
+        let intervals: Vec<(usize, usize)> = match self.sampling {
+            ClipSampling::Random | ClipSampling::Center => {
+                let span = self.num_frames * self.stride;
+                let max_start = video_len.saturating_sub(span);
+                let start = match self.sampling {
+                    ClipSampling::Random => rng.gen_range(0..=max_start),
+                    _ => max_start / 2,
+                };
+
+                (0..self.num_frames)
+                    .map(|i| (start + i * self.stride, start + (i + 1) * self.stride))
+                    .collect()
+            }
+            ClipSampling::Segments => {
+                let segment = video_len as f64 / self.num_frames as f64;
+
+                (0..self.num_frames)
+                    .map(|i| {
+                        let start = (i as f64 * segment) as usize;
+                        let end = ((i + 1) as f64 * segment) as usize;
+                        (start, end.max(start + 1))
+                    })
+                    .collect()
+            }
+        };
+
+        intervals
+            .into_iter()
+            .map(|(start, end)| {
+                let index = match (self.jitter, self.sampling) {
+                    (true, _) => rng.gen_range(start..end),
+                    (false, ClipSampling::Segments) => (start + end - 1) / 2,
+                    (false, _) => start,
+                };
+                index.min(video_len.saturating_sub(1))
+            })
+            .collect()
+    }
+}
+
+/// Video dataset item.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoDatasetItem {
+    /// The frames of the clip in `[num_frames, channels, height, width]` order, ready to be
+    /// loaded in a tensor.
+    pub frames: Vec<u8>,
+
+    /// The shape of the frames, `[num_frames, channels, height, width]`.
+    pub shape: [usize; 4],
+
+    /// The indices of the sampled frames in the video.
+    pub frame_indices: Vec<usize>,
+
+    /// The class label of the video.
+    pub label: usize,
+}
+
+/// A dataset of video clips for action recognition, sampling a new clip of each video on every
+/// access.
+pub struct VideoFolderDataset<D> {
+    items: Vec<(PathBuf, usize)>,
+    decoder: D,
+    sampler: ClipSampler,
+    rng: Mutex<StdRng>,
+}
+
+impl<D: VideoDecoder> Dataset<VideoDatasetItem> for VideoFolderDataset<D> {
+    fn get(&self, index: usize) -> Option<VideoDatasetItem> {
+        let (path, label) = self.items.get(index)?;
+
+        match self.load(path, *label) {
+            Ok(item) => Some(item),
+            Err(err) => panic!("Failed to load the video {}: {err}", path.display()),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+impl<D: VideoDecoder> VideoFolderDataset<D> {
+    /// Create a video classification dataset from the root folder, where each video is an entry
+    /// of the folder named after its class, e.g. `root/class/video`.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Dataset root folder.
+    /// * `decoder` - The decoder of the videos.
+    /// * `sampler` - The sampler of the clips.
+    ///
+    /// # Returns
+    /// A new dataset instance.
+    pub fn new_classification<P: AsRef<Path>>(
+        root: P,
+        decoder: D,
+        sampler: ClipSampler,
+    ) -> Result<Self, VideoLoaderError> {
+        let read_dir = |path: &Path| {
+            let mut entries = fs::read_dir(path)
+                .map_err(|err| VideoLoaderError::IOError(err.to_string()))?
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .collect::<Vec<_>>();
+            entries.sort();
+            Ok::<_, VideoLoaderError>(entries)
+        };
+
+        let mut items = Vec::new();
+        let mut classes = HashSet::new();
+        for class_dir in read_dir(root.as_ref())?.into_iter().filter(|p| p.is_dir()) {
+            let label = class_dir
+                .file_name()
+                .ok_or_else(|| {
+                    VideoLoaderError::IOError("Could not resolve the class folder name".into())
+                })?
+                .to_string_lossy()
+                .into_owned();
+
+            for video in read_dir(&class_dir)? {
+                items.push((video, label.clone()));
+            }
+            classes.insert(label);
+        }
+
+        // Sort class names
+        let mut classes = classes.into_iter().collect::<Vec<_>>();
+        classes.sort();
+
+        Self::new_classification_with_items(items, &classes, decoder, sampler)
+    }
+
+    /// Create a video classification dataset with the specified items.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - List of dataset items, each item represented by a tuple `(video path, label)`.
+    /// * `classes` - Dataset class names.
+    /// * `decoder` - The decoder of the videos.
+    /// * `sampler` - The sampler of the clips.
+    ///
+    /// # Returns
+    /// A new dataset instance.
+    pub fn new_classification_with_items<P: AsRef<Path>, S: AsRef<str>>(
+        items: Vec<(P, String)>,
+        classes: &[S],
+        decoder: D,
+        sampler: ClipSampler,
+    ) -> Result<Self, VideoLoaderError> {
+        let classes: HashMap<_, _> = classes
+            .iter()
+            .enumerate()
+            .map(|(idx, cls)| (cls.as_ref().to_string(), idx))
+            .collect();
+
+        let items = items
+            .into_iter()
+            .map(|(path, label)| match classes.get(&label) {
+                Some(label) => Ok((path.as_ref().to_path_buf(), *label)),
+                None => Err(VideoLoaderError::Unknown(format!(
+                    "Invalid class `{label}`"
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let rng = match sampler.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        Ok(Self {
+            items,
+            decoder,
+            sampler,
+            rng: Mutex::new(rng),
+        })
+    }
+
+    /// Samples a clip of the video and decodes its frames.
+    fn load(&self, path: &Path, label: usize) -> Result<VideoDatasetItem, VideoLoaderError> {
+        let video_len = self.decoder.num_frames(path)?;
+        if video_len == 0 {
+            return Err(VideoLoaderError::EmptyVideo(path.to_path_buf()));
+        }
+
+        let frame_indices = self
+            .sampler
+            .sample(video_len, &mut *self.rng.lock().unwrap());
+
+        // Short videos repeat frames, which are only decoded once.
+        let mut unique = frame_indices.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        let decoded = self.decoder.decode(path, &unique)?;
+
+        let first = decoded
+            .first()
+            .ok_or_else(|| VideoLoaderError::EmptyVideo(path.to_path_buf()))?;
+        let (height, width, channels) = (first.height, first.width, first.channels);
+        if decoded
+            .iter()
+            .any(|frame| (frame.height, frame.width, frame.channels) != (height, width, channels))
+        {
+            return Err(VideoLoaderError::FrameSizeMismatch(path.to_path_buf()));
+        }
+
+        // Transpose each frame from [H, W, C] to [C, H, W].
+        let mut frames = Vec::with_capacity(frame_indices.len() * channels * height * width);
+        for index in frame_indices.iter() {
+            let frame = &decoded[unique.binary_search(index).unwrap()];
+            for channel in 0..channels {
+                frames.extend(frame.pixels.iter().skip(channel).step_by(channels));
+            }
+        }
+
+        Ok(VideoDatasetItem {
+            frames,
+            shape: [frame_indices.len(), channels, height, width],
+            frame_indices,
+            label,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage};
+
+    #[test]
+    fn clip_sampler_should_select_consecutive_frames() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let center = ClipSampler::new(4, ClipSampling::Center).with_stride(2);
+        assert_eq!(center.sample(20, &mut rng), vec![6, 8, 10, 12]);
+
+        let random = ClipSampler::new(4, ClipSampling::Random).with_stride(2);
+        let indices = random.sample(20, &mut rng);
+        assert!(indices.windows(2).all(|w| w[1] == w[0] + 2));
+        assert!(indices[3] < 20);
+
+        // The last frame is repeated.
+        assert_eq!(center.sample(5, &mut rng), vec![0, 2, 4, 4]);
+    }
+
+    #[test]
+    fn clip_sampler_should_cover_the_segments() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let segments = ClipSampler::new(3, ClipSampling::Segments);
+        assert_eq!(segments.sample(9, &mut rng), vec![1, 4, 7]);
+
+        let jittered = segments.with_temporal_jitter(true);
+        for _ in 0..10 {
+            let indices = jittered.sample(9, &mut rng);
+            for (i, index) in indices.into_iter().enumerate() {
+                assert!((i * 3..(i + 1) * 3).contains(&index));
+            }
+        }
+    }
+
+    #[test]
+    fn video_folder_dataset_should_load_clips() {
+        let root = tempfile::tempdir().unwrap();
+        for (class, num_frames) in [("jump", 3), ("run", 5)] {
+            let video = root.path().join(class).join("video");
+            fs::create_dir_all(&video).unwrap();
+            for i in 0..num_frames {
+                RgbImage::from_pixel(2, 1, Rgb([i, 10, 20]))
+                    .save(video.join(format!("{i:06}.png")))
+                    .unwrap();
+            }
+        }
+        let sampler = ClipSampler::new(4, ClipSampling::Center);
+
+        let dataset =
+            VideoFolderDataset::new_classification(root.path(), FrameFolderDecoder, sampler)
+                .unwrap();
+
+        assert_eq!(dataset.len(), 2);
+        let item = dataset.get(0).unwrap();
+        assert_eq!(item.label, 0);
+        assert_eq!(item.shape, [4, 3, 1, 2]);
+        assert_eq!(item.frame_indices, vec![0, 1, 2, 2]);
+        assert_eq!(item.frames[..6], [0, 0, 10, 10, 20, 20]);
+        assert_eq!(item.frames[18..], [2, 2, 10, 10, 20, 20]);
+        assert_eq!(dataset.get(1).unwrap().frame_indices, vec![0, 1, 2, 3]);
+    }
+}
@@ -97,6 +97,23 @@ impl ModuleCodegen for EnumModuleCodegen {
         }
     }
 
+    fn gen_map_submodules(&self) -> TokenStream {
+        let match_body = self.gen_variants_match_fn(|variant| {
+            quote! {
+                Self::#variant(burn::module::Module::<B>::map_submodules(module, mapper))
+            }
+        });
+
+        quote! {
+            fn map_submodules<Mapper: burn::module::SubmoduleMapper<B>>(self, mapper: &mut Mapper) -> Self
+            where
+                Self: 'static,
+            {
+                #match_body
+            }
+        }
+    }
+
     fn gen_valid(&self) -> TokenStream {
         let match_body = self.gen_variants_match_fn(|variant| {
             quote! {
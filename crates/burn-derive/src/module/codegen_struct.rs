@@ -4,6 +4,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
 pub(crate) struct StructModuleCodegen {
+    pub name: Ident,
     pub fields: Vec<FieldTypeAnalyzer>,
 }
 
@@ -27,9 +28,14 @@ impl ModuleCodegen for StructModuleCodegen {
     }
 
     fn gen_visit(&self) -> TokenStream {
+        let container_type = self.name.to_string();
         let body = self.gen_fields_fn(|name| {
+            let name_str = name.to_string();
+
             quote! {
+                visitor.enter_module(#name_str, #container_type);
                 burn::module::Module::visit(&self.#name, visitor);
+                visitor.exit_module(#name_str, #container_type);
             }
         });
 
@@ -113,6 +119,29 @@ impl ModuleCodegen for StructModuleCodegen {
         }
     }
 
+    fn gen_map_submodules(&self) -> TokenStream {
+        let (names, body) = self.gen_fields_fn_names(|name| {
+            let name_str = name.to_string();
+
+            quote! {
+                let #name = burn::module::SubmoduleMapper::<B>::map_submodule(mapper, #name_str, self.#name);
+            }
+        });
+
+        quote! {
+            fn map_submodules<Mapper: burn::module::SubmoduleMapper<B>>(self, mapper: &mut Mapper) -> Self
+            where
+                Self: 'static,
+            {
+                #body
+
+                Self {
+                    #(#names),*
+                }
+            }
+        }
+    }
+
     fn gen_valid(&self) -> TokenStream {
         let (names, body) = self.gen_fields_fn_names(|name| {
             quote! {
@@ -189,6 +218,7 @@ impl ModuleCodegen for StructModuleCodegen {
 impl StructModuleCodegen {
     pub fn from_ast(ast: &syn::DeriveInput) -> Self {
         Self {
+            name: ast.ident.clone(),
             fields: parse_fields(ast)
                 .into_iter()
                 .map(FieldTypeAnalyzer::new)
@@ -0,0 +1,83 @@
+use burn_tensor::backend::{Backend, DeviceInfo, MemoryUsage, SyncType};
+
+use crate::{variant::*, DynBoolTensor, DynBridge, DynDevice, DynIntTensor, DynTensor};
+
+/// A backend selected at runtime, dispatching every operation to one of the backends enabled
+/// with the features of the crate.
+///
+/// The backend executing an operation is the one of the [device](DynDevice) its tensors are on,
+/// so applications can select the backend from their configuration without compiling a binary
+/// per backend. Like any backend, it can be decorated, e.g. with `Autodiff<DynBackend>`.
+///
+/// The elements of the backend are `f32` and `i64`, they are converted to the elements of the
+/// selected backend. Tensors are moved between backends with `to_device`, which copies their data
+/// through the host, the other operations panic when their tensors are on different backends.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DynBackend;
+
+impl Backend for DynBackend {
+    type Device = DynDevice;
+
+    type FullPrecisionBridge = DynBridge;
+
+    type FloatTensorPrimitive<const D: usize> = DynTensor<D>;
+    type FloatElem = f32;
+
+    type IntTensorPrimitive<const D: usize> = DynIntTensor<D>;
+    type IntElem = i64;
+
+    type BoolTensorPrimitive<const D: usize> = DynBoolTensor<D>;
+
+    fn name() -> String {
+        let backends = BackendKind::enabled()
+            .iter()
+            .map(|kind| dispatch!(kind, |B| B::name()))
+            .collect::<Vec<_>>();
+
+        format!("dyn<{}>", backends.join(", "))
+    }
+
+    /// Seed every enabled backend, except candle which doesn't support seeding.
+    fn seed(seed: u64) {
+        for kind in BackendKind::enabled() {
+            #[cfg(feature = "candle")]
+            if *kind == BackendKind::Candle {
+                continue;
+            }
+
+            dispatch!(kind, |B| B::seed(seed));
+        }
+    }
+
+    /// Fork the random number generator of the first enabled backend supporting it, then seed
+    /// every enabled backend with the new seed.
+    fn fork_seed() -> Option<u64> {
+        let seed = BackendKind::enabled()
+            .iter()
+            .find_map(|kind| dispatch!(kind, |B| B::fork_seed()))?;
+        Self::seed(seed);
+
+        Some(seed)
+    }
+
+    fn sync(device: &DynDevice, sync_type: SyncType) {
+        dispatch!(device.kind(), |B| B::sync(B::device_ref(device), sync_type))
+    }
+
+    fn memory_usage(device: &DynDevice) -> Option<MemoryUsage> {
+        dispatch!(device.kind(), |B| B::memory_usage(B::device_ref(device)))
+    }
+
+    /// The devices of every enabled backend.
+    fn devices() -> Vec<DeviceInfo<DynDevice>> {
+        BackendKind::enabled()
+            .iter()
+            .flat_map(|kind| {
+                dispatch!(kind, |B| B::devices()
+                    .into_iter()
+                    .map(|info| DeviceInfo::new(B::from_device(info.device), info.capabilities))
+                    .collect::<Vec<_>>())
+            })
+            .collect()
+    }
+}
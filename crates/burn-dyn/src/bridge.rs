@@ -0,0 +1,41 @@
+use burn_tensor::{
+    backend::BackendBridge,
+    ops::{FloatTensor, FloatTensorOps},
+    Device,
+};
+
+use crate::DynBackend;
+
+/// The [backend bridge](BackendBridge) of the [dyn backend](DynBackend), whose floats are
+/// already in full precision.
+#[derive(Debug)]
+pub struct DynBridge;
+
+impl BackendBridge<DynBackend> for DynBridge {
+    type Target = DynBackend;
+
+    fn into_target<const D: usize>(
+        tensor: FloatTensor<DynBackend, D>,
+        device: Option<Device<Self::Target>>,
+    ) -> FloatTensor<Self::Target, D> {
+        match device {
+            Some(device) => DynBackend::float_to_device(tensor, &device),
+            None => tensor,
+        }
+    }
+
+    fn from_target<const D: usize>(
+        tensor: FloatTensor<Self::Target, D>,
+        device: Option<Device<DynBackend>>,
+    ) -> FloatTensor<DynBackend, D> {
+        Self::into_target(tensor, device)
+    }
+
+    fn target_device(device: &Device<DynBackend>) -> Device<Self::Target> {
+        device.clone()
+    }
+
+    fn origin_device(device: &Device<Self::Target>) -> Device<DynBackend> {
+        device.clone()
+    }
+}
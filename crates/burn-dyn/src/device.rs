@@ -0,0 +1,299 @@
+use burn_tensor::backend::{DeviceId, DeviceOps};
+use core::{fmt::Display, str::FromStr};
+
+use crate::variant::*;
+
+/// The device of the [dyn backend](crate::DynBackend), which selects the backend executing the
+/// operations of its tensors.
+///
+/// Devices can be parsed from strings, e.g. read from the configuration of an application:
+///
+/// | Backend   | Devices                                                                      |
+/// | --------- | ---------------------------------------------------------------------------- |
+/// | `ndarray` | `ndarray`, `ndarray:cpu`                                                     |
+/// | `wgpu`    | `wgpu`, `wgpu:discrete:N`, `wgpu:integrated:N`, `wgpu:virtual:N`, `wgpu:cpu` |
+/// | `tch`     | `tch`, `tch:cpu`, `tch:cuda:N`, `tch:mps`, `tch:vulkan`                      |
+/// | `candle`  | `candle`, `candle:cpu`, `candle:cuda:N`, `candle:metal:N`                    |
+///
+/// The name of a backend alone is its default device.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_dyn::DynDevice;
+///
+/// let device: DynDevice = "ndarray".parse().unwrap();
+/// assert_eq!(device.to_string(), "ndarray:cpu");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DynDevice {
+    /// A device of the [ndarray backend](NdArrayBackend).
+    #[cfg(feature = "ndarray")]
+    NdArray(burn_ndarray::NdArrayDevice),
+    /// A device of the [wgpu backend](WgpuBackend).
+    #[cfg(feature = "wgpu")]
+    Wgpu(burn_wgpu::WgpuDevice),
+    /// A device of the [LibTorch backend](LibTorchBackend).
+    #[cfg(feature = "tch")]
+    LibTorch(burn_tch::LibTorchDevice),
+    /// A device of the [candle backend](CandleBackend).
+    #[cfg(feature = "candle")]
+    Candle(burn_candle::CandleDevice),
+}
+
+impl DynDevice {
+    /// The backend of the device.
+    pub fn kind(&self) -> BackendKind {
+        match self {
+            #[cfg(feature = "ndarray")]
+            Self::NdArray(_) => BackendKind::NdArray,
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(_) => BackendKind::Wgpu,
+            #[cfg(feature = "tch")]
+            Self::LibTorch(_) => BackendKind::LibTorch,
+            #[cfg(feature = "candle")]
+            Self::Candle(_) => BackendKind::Candle,
+        }
+    }
+
+    /// The default device of the given backend.
+    pub fn default_of(kind: BackendKind) -> Self {
+        dispatch!(kind, |B| B::from_device(Default::default()))
+    }
+
+    /// The first available device of the candidates, in order, e.g. to fall back to the
+    /// [ndarray backend](NdArrayBackend) when no GPU is available.
+    ///
+    /// The wgpu devices are initialized with the default
+    /// [runtime options](burn_wgpu::RuntimeOptions), unless they are already initialized, and
+    /// are skipped when neither them nor their fallback devices can be created. The devices of
+    /// the other backends are considered available.
+    ///
+    /// Returns `None` when none of the candidates is available.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_dyn::DynDevice;
+    ///
+    /// let candidates = ["ndarray".parse().unwrap()];
+    /// let device = DynDevice::select(&candidates).unwrap();
+    /// assert_eq!(device.to_string(), "ndarray:cpu");
+    /// ```
+    pub fn select(candidates: &[DynDevice]) -> Option<DynDevice> {
+        candidates
+            .iter()
+            .find(|device| device.is_available())
+            .cloned()
+    }
+
+    fn is_available(&self) -> bool {
+        match self {
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(device) => {
+                use burn_wgpu::{AutoGraphicsApi, RuntimeOptions, RuntimeOptionsError};
+
+                match RuntimeOptions::builder().init::<AutoGraphicsApi>(device) {
+                    Err(RuntimeOptionsError::Unavailable(reason)) => {
+                        log::warn!("The device {self} is unavailable: {reason}");
+                        false
+                    }
+                    // Already initialized, possibly with other options.
+                    Ok(()) | Err(RuntimeOptionsError::AlreadyInitialized(_)) => true,
+                }
+            }
+            #[allow(unreachable_patterns)]
+            _ => true,
+        }
+    }
+}
+
+impl Default for DynDevice {
+    /// The default device of the first enabled backend, in the order ndarray, wgpu, tch and
+    /// candle.
+    fn default() -> Self {
+        Self::default_of(BackendKind::enabled()[0])
+    }
+}
+
+impl DeviceOps for DynDevice {
+    fn id(&self) -> DeviceId {
+        let id = dispatch!(self.kind(), |B| B::device_ref(self).id());
+
+        // Keep the ids of the devices of different backends distinct.
+        DeviceId::new(((self.kind() as u16) << 8) | id.type_id, id.index_id)
+    }
+}
+
+/// The error returned when a [device](DynDevice) can't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDeviceError {
+    device: String,
+}
+
+impl Display for ParseDeviceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let backends = BackendKind::enabled()
+            .iter()
+            .map(BackendKind::name)
+            .collect::<Vec<_>>();
+
+        write!(
+            f,
+            "Unknown device `{}`, the enabled backends are {}.",
+            self.device,
+            backends.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ParseDeviceError {}
+
+impl FromStr for DynDevice {
+    type Err = ParseDeviceError;
+
+    fn from_str(device: &str) -> Result<Self, Self::Err> {
+        let parts = device.split(':').collect::<Vec<_>>();
+        // Only the devices of some backends are indexed.
+        #[allow(unused_variables)]
+        let index = |part: &str| part.parse::<usize>().ok();
+
+        let parsed = match parts[0] {
+            #[cfg(feature = "ndarray")]
+            "ndarray" => match parts[1..] {
+                [] | ["cpu"] => Some(Self::NdArray(burn_ndarray::NdArrayDevice::Cpu)),
+                _ => None,
+            },
+            #[cfg(feature = "wgpu")]
+            "wgpu" => {
+                use burn_wgpu::WgpuDevice;
+
+                match parts[1..] {
+                    [] => Some(WgpuDevice::BestAvailable),
+                    ["discrete", num] => index(num).map(WgpuDevice::DiscreteGpu),
+                    ["integrated", num] => index(num).map(WgpuDevice::IntegratedGpu),
+                    ["virtual", num] => index(num).map(WgpuDevice::VirtualGpu),
+                    ["cpu"] => Some(WgpuDevice::Cpu),
+                    _ => None,
+                }
+                .map(Self::Wgpu)
+            }
+            #[cfg(feature = "tch")]
+            "tch" => {
+                use burn_tch::LibTorchDevice;
+
+                match parts[1..] {
+                    [] | ["cpu"] => Some(LibTorchDevice::Cpu),
+                    ["cuda", num] => index(num).map(LibTorchDevice::Cuda),
+                    ["mps"] => Some(LibTorchDevice::Mps),
+                    ["vulkan"] => Some(LibTorchDevice::Vulkan),
+                    _ => None,
+                }
+                .map(Self::LibTorch)
+            }
+            #[cfg(feature = "candle")]
+            "candle" => {
+                use burn_candle::CandleDevice;
+
+                match parts[1..] {
+                    [] | ["cpu"] => Some(CandleDevice::Cpu),
+                    ["cuda", num] => index(num).map(CandleDevice::Cuda),
+                    ["metal", num] => index(num).map(CandleDevice::Metal),
+                    _ => None,
+                }
+                .map(Self::Candle)
+            }
+            _ => None,
+        };
+
+        parsed.ok_or_else(|| ParseDeviceError {
+            device: device.to_string(),
+        })
+    }
+}
+
+impl Display for DynDevice {
+    /// Format the device as the string it is [parsed](FromStr) from.
+    ///
+    /// Existing wgpu devices, which can't be parsed, are formatted with their id.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            #[cfg(feature = "ndarray")]
+            Self::NdArray(_) => f.write_str("ndarray:cpu"),
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(device) => {
+                use burn_wgpu::WgpuDevice;
+
+                match device {
+                    WgpuDevice::DiscreteGpu(num) => write!(f, "wgpu:discrete:{num}"),
+                    WgpuDevice::IntegratedGpu(num) => write!(f, "wgpu:integrated:{num}"),
+                    WgpuDevice::VirtualGpu(num) => write!(f, "wgpu:virtual:{num}"),
+                    WgpuDevice::Cpu => f.write_str("wgpu:cpu"),
+                    WgpuDevice::BestAvailable => f.write_str("wgpu"),
+                    WgpuDevice::Existing(id) => write!(f, "wgpu:existing:{}", id.inner()),
+                }
+            }
+            #[cfg(feature = "tch")]
+            Self::LibTorch(device) => {
+                use burn_tch::LibTorchDevice;
+
+                match device {
+                    LibTorchDevice::Cpu => f.write_str("tch:cpu"),
+                    LibTorchDevice::Cuda(num) => write!(f, "tch:cuda:{num}"),
+                    LibTorchDevice::Mps => f.write_str("tch:mps"),
+                    LibTorchDevice::Vulkan => f.write_str("tch:vulkan"),
+                }
+            }
+            #[cfg(feature = "candle")]
+            Self::Candle(device) => {
+                use burn_candle::CandleDevice;
+
+                match device {
+                    CandleDevice::Cpu => f.write_str("candle:cpu"),
+                    CandleDevice::Cuda(num) => write!(f, "candle:cuda:{num}"),
+                    CandleDevice::Metal(num) => write!(f, "candle:metal:{num}"),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ndarray"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_devices() {
+        let device: DynDevice = "ndarray".parse().unwrap();
+
+        assert_eq!(device, DynDevice::NdArray(burn_ndarray::NdArrayDevice::Cpu));
+        assert_eq!(device.kind(), BackendKind::NdArray);
+        assert_eq!(device.to_string().parse::<DynDevice>(), Ok(device));
+    }
+
+    #[test]
+    fn should_select_the_first_available_device() {
+        let ndarray = DynDevice::NdArray(burn_ndarray::NdArrayDevice::Cpu);
+
+        assert_eq!(DynDevice::select(&[ndarray.clone()]), Some(ndarray));
+        assert_eq!(DynDevice::select(&[]), None);
+    }
+
+    #[cfg(feature = "wgpu")]
+    #[test]
+    fn unavailable_wgpu_device_should_fall_back_to_ndarray() {
+        let candidates = [
+            format!("wgpu:virtual:{}", usize::MAX),
+            "ndarray".to_string(),
+        ]
+        .map(|device| device.parse::<DynDevice>().unwrap());
+
+        assert_eq!(DynDevice::select(&candidates), Some(candidates[1].clone()));
+    }
+
+    #[test]
+    fn should_not_parse_unknown_devices() {
+        assert!("ndarray:cuda:0".parse::<DynDevice>().is_err());
+        assert!("unknown".parse::<DynDevice>().is_err());
+    }
+}
@@ -0,0 +1,40 @@
+#![warn(missing_docs)]
+
+//! # Burn Dyn
+//!
+//! This library is a part of the Burn project. It provides a backend that dispatches its
+//! operations to one of the backends enabled with the features of the crate, selected at runtime
+//! by the [device](DynDevice) the tensors are created on.
+
+#[cfg(not(any(
+    feature = "ndarray",
+    feature = "wgpu",
+    feature = "tch",
+    feature = "candle"
+)))]
+compile_error!("At least one backend feature should be enabled: ndarray, wgpu, tch or candle.");
+
+#[macro_use]
+mod variant;
+
+mod backend;
+mod bridge;
+mod device;
+mod ops;
+mod tensor;
+
+pub use backend::*;
+pub use bridge::*;
+pub use device::*;
+pub use tensor::*;
+pub use variant::*;
+
+#[cfg(all(test, feature = "ndarray"))]
+mod tests {
+    type TestBackend = crate::DynBackend;
+    type TestTensor<const D: usize> = burn_tensor::Tensor<TestBackend, D>;
+    type TestTensorInt<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Int>;
+    type TestTensorBool<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Bool>;
+
+    burn_tensor::testgen_all!();
+}
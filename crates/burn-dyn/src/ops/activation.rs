@@ -0,0 +1,121 @@
+use burn_tensor::{
+    ops::{ActivationOps, FloatElem, FloatTensor, IntTensor},
+    ElementConversion,
+};
+
+use crate::{variant::*, DynBackend};
+
+impl ActivationOps<Self> for DynBackend {
+    fn leaky_relu<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        negative_slope: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::leaky_relu(B::into_float(tensor), negative_slope.elem());
+            B::from_float(output)
+        })
+    }
+
+    fn relu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::relu(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn relu_backward<const D: usize>(
+        output: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(output.kind(), |B| {
+            let output = B::relu_backward(B::into_float(output), B::into_float(grad));
+            B::from_float(output)
+        })
+    }
+
+    fn gelu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::gelu(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn prelu<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        alpha: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::prelu(B::into_float(tensor), B::into_float(alpha));
+            B::from_float(output)
+        })
+    }
+
+    fn gelu_backward<const D: usize>(
+        x: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(x.kind(), |B| {
+            let output = B::gelu_backward(B::into_float(x), B::into_float(grad));
+            B::from_float(output)
+        })
+    }
+
+    fn sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::sigmoid(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn sigmoid_backward<const D: usize>(
+        output: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(output.kind(), |B| {
+            let output = B::sigmoid_backward(B::into_float(output), B::into_float(grad));
+            B::from_float(output)
+        })
+    }
+
+    fn log_sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::log_sigmoid(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn log_sigmoid_backward<const D: usize>(
+        x: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(x.kind(), |B| {
+            let output = B::log_sigmoid_backward(B::into_float(x), B::into_float(grad));
+            B::from_float(output)
+        })
+    }
+
+    fn cross_entropy(
+        logits: FloatTensor<Self, 2>,
+        targets: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, 1> {
+        dispatch!(logits.kind(), |B| {
+            let output = B::cross_entropy(B::into_float(logits), B::into_int(targets));
+            B::from_float(output)
+        })
+    }
+
+    fn cross_entropy_backward(
+        logits: FloatTensor<Self, 2>,
+        targets: IntTensor<Self, 1>,
+        grad: FloatTensor<Self, 1>,
+    ) -> FloatTensor<Self, 2> {
+        dispatch!(logits.kind(), |B| {
+            let output = B::cross_entropy_backward(
+                B::into_float(logits),
+                B::into_int(targets),
+                B::into_float(grad),
+            );
+            B::from_float(output)
+        })
+    }
+}
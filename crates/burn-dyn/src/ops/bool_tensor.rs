@@ -0,0 +1,261 @@
+use burn_tensor::{
+    ops::{BoolTensor, BoolTensorOps, FloatTensor, IntTensor},
+    Data, Device, Reader, Shape,
+};
+use core::ops::Range;
+
+use crate::{variant::*, DynBackend};
+
+impl BoolTensorOps<Self> for DynBackend {
+    fn bool_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> BoolTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::bool_empty(shape, B::device_ref(device));
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<Self, D>) -> Shape<D> {
+        dispatch!(tensor.kind(), |B| B::bool_shape(B::bool_ref(tensor)))
+    }
+
+    fn bool_into_data<const D: usize>(tensor: BoolTensor<Self, D>) -> Reader<Data<bool, D>> {
+        dispatch!(tensor.kind(), |B| B::bool_into_data(B::into_bool(tensor)))
+    }
+
+    fn bool_to_data<const D: usize>(tensor: &BoolTensor<Self, D>) -> Reader<Data<bool, D>> {
+        dispatch!(tensor.kind(), |B| B::bool_to_data(B::bool_ref(tensor)))
+    }
+
+    fn bool_from_data<const D: usize>(
+        data: Data<bool, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::bool_from_data(data, B::device_ref(device));
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_into_int<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_into_int(B::into_bool(tensor));
+            B::from_int(output)
+        })
+    }
+
+    fn bool_into_float<const D: usize>(tensor: BoolTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_into_float(B::into_bool(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<Self, D>) -> Device<Self> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_device(B::bool_ref(tensor));
+            B::from_device(output)
+        })
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_reshape(B::into_bool(tensor), shape);
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_slice<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> BoolTensor<Self, D1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_slice(B::into_bool(tensor), ranges);
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_slice_assign<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: BoolTensor<Self, D1>,
+    ) -> BoolTensor<Self, D1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_slice_assign(B::into_bool(tensor), ranges, B::into_bool(value));
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_repeat<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_repeat(B::into_bool(tensor), dim, times);
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_cat<const D: usize>(
+        tensors: Vec<BoolTensor<Self, D>>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(tensors[0].kind(), |B| {
+            let output = B::bool_cat(tensors.into_iter().map(B::into_bool).collect(), dim);
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::bool_equal(B::into_bool(lhs), B::into_bool(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_not_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::bool_not_equal(B::into_bool(lhs), B::into_bool(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_not<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_not(B::into_bool(tensor));
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_transpose<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_transpose(B::into_bool(tensor));
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_swap_dims<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_swap_dims(B::into_bool(tensor), dim1, dim2);
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_permute<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        axes: [usize; D],
+    ) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_permute(B::into_bool(tensor), axes);
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_flip<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        axes: &[usize],
+    ) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_flip(B::into_bool(tensor), axes);
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_narrow<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_narrow(B::into_bool(tensor), dim, start, length);
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_chunk<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<BoolTensor<Self, D>> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_chunk(B::into_bool(tensor), chunks, dim);
+            output.into_iter().map(B::from_bool).collect()
+        })
+    }
+
+    fn bool_any<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_any(B::into_bool(tensor));
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_any_dim<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_any_dim(B::into_bool(tensor), dim);
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_all<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_all(B::into_bool(tensor));
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_all_dim<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_all_dim(B::into_bool(tensor), dim);
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::bool_expand(B::into_bool(tensor), shape);
+            B::from_bool(output)
+        })
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        if tensor.kind() == device.kind() {
+            return dispatch!(tensor.kind(), |B| {
+                let output = B::bool_to_device(B::into_bool(tensor), B::device_ref(device));
+                B::from_bool(output)
+            });
+        }
+
+        // Tensors are moved between backends through the host.
+        let data = Self::bool_into_data(tensor).read_sync().expect(
+            "Can't read the data of the tensor synchronously to move it to another backend.",
+        );
+        Self::bool_from_data(data, device)
+    }
+}
@@ -0,0 +1,757 @@
+use burn_tensor::{
+    ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
+    Data, Device, Distribution, ElementConversion, Reader, Shape,
+};
+use core::ops::Range;
+
+use crate::{variant::*, DynBackend};
+
+impl IntTensorOps<Self> for DynBackend {
+    fn int_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::int_empty(shape, B::device_ref(device));
+            B::from_int(output)
+        })
+    }
+
+    fn int_shape<const D: usize>(tensor: &IntTensor<Self, D>) -> Shape<D> {
+        dispatch!(tensor.kind(), |B| B::int_shape(B::int_ref(tensor)))
+    }
+
+    fn int_into_data<const D: usize>(tensor: IntTensor<Self, D>) -> Reader<Data<IntElem<Self>, D>> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_into_data(B::into_int(tensor));
+            output.map(|data| data.convert())
+        })
+    }
+
+    fn int_to_data<const D: usize>(tensor: &IntTensor<Self, D>) -> Reader<Data<IntElem<Self>, D>> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_to_data(B::int_ref(tensor));
+            output.map(|data| data.convert())
+        })
+    }
+
+    fn int_from_data<const D: usize>(
+        data: Data<IntElem<Self>, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::int_from_data(data.convert(), B::device_ref(device));
+            B::from_int(output)
+        })
+    }
+
+    fn int_device<const D: usize>(tensor: &IntTensor<Self, D>) -> Device<Self> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_device(B::int_ref(tensor));
+            B::from_device(output)
+        })
+    }
+
+    fn int_reshape<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_reshape(B::into_int(tensor), shape);
+            B::from_int(output)
+        })
+    }
+
+    fn int_slice<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        indices: [Range<usize>; D2],
+    ) -> IntTensor<Self, D1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_slice(B::into_int(tensor), indices);
+            B::from_int(output)
+        })
+    }
+
+    fn int_slice_assign<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        indices: [Range<usize>; D2],
+        value: IntTensor<Self, D1>,
+    ) -> IntTensor<Self, D1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_slice_assign(B::into_int(tensor), indices, B::into_int(value));
+            B::from_int(output)
+        })
+    }
+
+    fn int_into_float<const D: usize>(tensor: IntTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_into_float(B::into_int(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn int_mask_where<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        source: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output =
+                B::int_mask_where(B::into_int(tensor), B::into_bool(mask), B::into_int(source));
+            B::from_int(output)
+        })
+    }
+
+    fn int_mask_fill<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_mask_fill(B::into_int(tensor), B::into_bool(mask), value.elem());
+            B::from_int(output)
+        })
+    }
+
+    fn int_gather<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_gather(dim, B::into_int(tensor), B::into_int(indices));
+            B::from_int(output)
+        })
+    }
+
+    fn int_scatter<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_scatter(
+                dim,
+                B::into_int(tensor),
+                B::into_int(indices),
+                B::into_int(value),
+            );
+            B::from_int(output)
+        })
+    }
+
+    fn int_select<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_select(B::into_int(tensor), dim, B::into_int(indices));
+            B::from_int(output)
+        })
+    }
+
+    fn int_select_assign<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_select_assign(
+                B::into_int(tensor),
+                dim,
+                B::into_int(indices),
+                B::into_int(value),
+            );
+            B::from_int(output)
+        })
+    }
+
+    fn int_repeat<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_repeat(B::into_int(tensor), dim, times);
+            B::from_int(output)
+        })
+    }
+
+    fn int_cat<const D: usize>(tensors: Vec<IntTensor<Self, D>>, dim: usize) -> IntTensor<Self, D> {
+        dispatch!(tensors[0].kind(), |B| {
+            let output = B::int_cat(tensors.into_iter().map(B::into_int).collect(), dim);
+            B::from_int(output)
+        })
+    }
+
+    fn int_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_equal(B::into_int(lhs), B::into_int(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn int_not_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_not_equal(B::into_int(lhs), B::into_int(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn int_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_equal_elem(B::into_int(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn int_not_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_not_equal_elem(B::into_int(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn int_greater<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_greater(B::into_int(lhs), B::into_int(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn int_greater_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_greater_elem(B::into_int(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn int_greater_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_greater_equal(B::into_int(lhs), B::into_int(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn int_greater_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_greater_equal_elem(B::into_int(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn int_lower<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_lower(B::into_int(lhs), B::into_int(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn int_lower_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_lower_elem(B::into_int(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn int_lower_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_lower_equal(B::into_int(lhs), B::into_int(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn int_lower_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_lower_equal_elem(B::into_int(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn int_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_add(B::into_int(lhs), B::into_int(rhs));
+            B::from_int(output)
+        })
+    }
+
+    fn int_add_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_add_scalar(B::into_int(lhs), rhs.elem());
+            B::from_int(output)
+        })
+    }
+
+    fn int_powi<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_powi(B::into_int(lhs), B::into_int(rhs));
+            B::from_int(output)
+        })
+    }
+
+    fn int_powf<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_powf(B::into_int(lhs), B::into_float(rhs));
+            B::from_int(output)
+        })
+    }
+
+    fn int_powi_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_powi_scalar(B::into_int(lhs), rhs.elem());
+            B::from_int(output)
+        })
+    }
+
+    fn int_powf_scalar<const D: usize>(lhs: IntTensor<Self, D>, rhs: f32) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_powf_scalar(B::into_int(lhs), rhs);
+            B::from_int(output)
+        })
+    }
+
+    fn int_clamp_min<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_clamp_min(B::into_int(tensor), min.elem());
+            B::from_int(output)
+        })
+    }
+
+    fn int_clamp_max<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_clamp_max(B::into_int(tensor), max.elem());
+            B::from_int(output)
+        })
+    }
+
+    fn int_clamp<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_clamp(B::into_int(tensor), min.elem(), max.elem());
+            B::from_int(output)
+        })
+    }
+
+    fn int_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_sub(B::into_int(lhs), B::into_int(rhs));
+            B::from_int(output)
+        })
+    }
+
+    fn int_sub_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_sub_scalar(B::into_int(lhs), rhs.elem());
+            B::from_int(output)
+        })
+    }
+
+    fn int_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_mul(B::into_int(lhs), B::into_int(rhs));
+            B::from_int(output)
+        })
+    }
+
+    fn int_mul_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_mul_scalar(B::into_int(lhs), rhs.elem());
+            B::from_int(output)
+        })
+    }
+
+    fn int_div<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_div(B::into_int(lhs), B::into_int(rhs));
+            B::from_int(output)
+        })
+    }
+
+    fn int_div_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_div_scalar(B::into_int(lhs), rhs.elem());
+            B::from_int(output)
+        })
+    }
+
+    fn int_remainder_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::int_remainder_scalar(B::into_int(lhs), rhs.elem());
+            B::from_int(output)
+        })
+    }
+
+    fn int_neg<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_neg(B::into_int(tensor));
+            B::from_int(output)
+        })
+    }
+
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::int_zeros(shape, B::device_ref(device));
+            B::from_int(output)
+        })
+    }
+
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::int_ones(shape, B::device_ref(device));
+            B::from_int(output)
+        })
+    }
+
+    fn int_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: IntElem<Self>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::int_full(shape, fill_value.elem(), B::device_ref(device));
+            B::from_int(output)
+        })
+    }
+
+    fn int_sum<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_sum(B::into_int(tensor));
+            B::from_int(output)
+        })
+    }
+
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_sum_dim(B::into_int(tensor), dim);
+            B::from_int(output)
+        })
+    }
+
+    fn int_prod<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_prod(B::into_int(tensor));
+            B::from_int(output)
+        })
+    }
+
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_prod_dim(B::into_int(tensor), dim);
+            B::from_int(output)
+        })
+    }
+
+    fn int_mean<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_mean(B::into_int(tensor));
+            B::from_int(output)
+        })
+    }
+
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_mean_dim(B::into_int(tensor), dim);
+            B::from_int(output)
+        })
+    }
+
+    fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_argmax(B::into_int(tensor), dim);
+            B::from_int(output)
+        })
+    }
+
+    fn int_argmin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_argmin(B::into_int(tensor), dim);
+            B::from_int(output)
+        })
+    }
+
+    fn int_max<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_max(B::into_int(tensor));
+            B::from_int(output)
+        })
+    }
+
+    fn int_max_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_max_dim(B::into_int(tensor), dim);
+            B::from_int(output)
+        })
+    }
+
+    fn int_max_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        dispatch!(tensor.kind(), |B| {
+            let (values, indices) = B::int_max_dim_with_indices(B::into_int(tensor), dim);
+            (B::from_int(values), B::from_int(indices))
+        })
+    }
+
+    fn int_min<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_min(B::into_int(tensor));
+            B::from_int(output)
+        })
+    }
+
+    fn int_min_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_min_dim(B::into_int(tensor), dim);
+            B::from_int(output)
+        })
+    }
+
+    fn int_min_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        dispatch!(tensor.kind(), |B| {
+            let (values, indices) = B::int_min_dim_with_indices(B::into_int(tensor), dim);
+            (B::from_int(values), B::from_int(indices))
+        })
+    }
+
+    fn int_abs<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_abs(B::into_int(tensor));
+            B::from_int(output)
+        })
+    }
+
+    fn int_transpose<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_transpose(B::into_int(tensor));
+            B::from_int(output)
+        })
+    }
+
+    fn int_swap_dims<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_swap_dims(B::into_int(tensor), dim1, dim2);
+            B::from_int(output)
+        })
+    }
+
+    fn int_permute<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        axes: [usize; D],
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_permute(B::into_int(tensor), axes);
+            B::from_int(output)
+        })
+    }
+
+    fn int_flip<const D: usize>(tensor: IntTensor<Self, D>, axes: &[usize]) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_flip(B::into_int(tensor), axes);
+            B::from_int(output)
+        })
+    }
+
+    fn int_narrow<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_narrow(B::into_int(tensor), dim, start, length);
+            B::from_int(output)
+        })
+    }
+
+    fn int_chunk<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<IntTensor<Self, D>> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_chunk(B::into_int(tensor), chunks, dim);
+            output.into_iter().map(B::from_int).collect()
+        })
+    }
+
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::int_random(shape, distribution, B::device_ref(device));
+            B::from_int(output)
+        })
+    }
+
+    fn int_arange_step(
+        range: Range<i64>,
+        step: usize,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, 1> {
+        dispatch!(device.kind(), |B| {
+            let output = B::int_arange_step(range, step, B::device_ref(device));
+            B::from_int(output)
+        })
+    }
+
+    fn int_arange(range: Range<i64>, device: &Device<Self>) -> IntTensor<Self, 1> {
+        dispatch!(device.kind(), |B| {
+            let output = B::int_arange(range, B::device_ref(device));
+            B::from_int(output)
+        })
+    }
+
+    fn int_any<const D: usize>(tensor: IntTensor<Self, D>) -> BoolTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_any(B::into_int(tensor));
+            B::from_bool(output)
+        })
+    }
+
+    fn int_any_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_any_dim(B::into_int(tensor), dim);
+            B::from_bool(output)
+        })
+    }
+
+    fn int_all<const D: usize>(tensor: IntTensor<Self, D>) -> BoolTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_all(B::into_int(tensor));
+            B::from_bool(output)
+        })
+    }
+
+    fn int_all_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_all_dim(B::into_int(tensor), dim);
+            B::from_bool(output)
+        })
+    }
+
+    fn int_sign<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_sign(B::into_int(tensor));
+            B::from_int(output)
+        })
+    }
+
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::int_expand(B::into_int(tensor), shape);
+            B::from_int(output)
+        })
+    }
+
+    fn int_to_device<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        if tensor.kind() == device.kind() {
+            return dispatch!(tensor.kind(), |B| {
+                let output = B::int_to_device(B::into_int(tensor), B::device_ref(device));
+                B::from_int(output)
+            });
+        }
+
+        // Tensors are moved between backends through the host.
+        let data = Self::int_into_data(tensor).read_sync().expect(
+            "Can't read the data of the tensor synchronously to move it to another backend.",
+        );
+        Self::int_from_data(data, device)
+    }
+}
@@ -0,0 +1,479 @@
+use burn_tensor::ops::{
+    Conv1dBackward, Conv2dBackward, ConvOptions, ConvTransposeOptions, FloatTensor,
+    GridSampleOptions, IntTensor, InterpolateOptions, MaxPool1dBackward, MaxPool1dWithIndices,
+    MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps, UnfoldOptions,
+};
+
+use crate::{variant::*, DynBackend};
+
+impl ModuleOps<Self> for DynBackend {
+    fn embedding(
+        weights: FloatTensor<Self, 2>,
+        indices: IntTensor<Self, 2>,
+    ) -> FloatTensor<Self, 3> {
+        dispatch!(weights.kind(), |B| {
+            let output = B::embedding(B::into_float(weights), B::into_int(indices));
+            B::from_float(output)
+        })
+    }
+
+    fn embedding_backward(
+        weights: FloatTensor<Self, 2>,
+        output_grad: FloatTensor<Self, 3>,
+        indices: IntTensor<Self, 2>,
+    ) -> FloatTensor<Self, 2> {
+        dispatch!(weights.kind(), |B| {
+            let output = B::embedding_backward(
+                B::into_float(weights),
+                B::into_float(output_grad),
+                B::into_int(indices),
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn conv1d(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<1>,
+    ) -> FloatTensor<Self, 3> {
+        dispatch!(x.kind(), |B| {
+            let output = B::conv1d(
+                B::into_float(x),
+                B::into_float(weight),
+                bias.map(B::into_float),
+                options,
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn conv2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        dispatch!(x.kind(), |B| {
+            let output = B::conv2d(
+                B::into_float(x),
+                B::into_float(weight),
+                bias.map(B::into_float),
+                options,
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn conv_transpose1d(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<1>,
+    ) -> FloatTensor<Self, 3> {
+        dispatch!(x.kind(), |B| {
+            let output = B::conv_transpose1d(
+                B::into_float(x),
+                B::into_float(weight),
+                bias.map(B::into_float),
+                options,
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        dispatch!(x.kind(), |B| {
+            let output = B::conv_transpose2d(
+                B::into_float(x),
+                B::into_float(weight),
+                bias.map(B::into_float),
+                options,
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn unfold4d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> FloatTensor<Self, 3> {
+        dispatch!(x.kind(), |B| {
+            let output = B::unfold4d(B::into_float(x), kernel_size, options);
+            B::from_float(output)
+        })
+    }
+
+    fn avg_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 3> {
+        dispatch!(x.kind(), |B| {
+            let output = B::avg_pool1d(
+                B::into_float(x),
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn avg_pool1d_backward(
+        x: FloatTensor<Self, 3>,
+        grad: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 3> {
+        dispatch!(x.kind(), |B| {
+            let output = B::avg_pool1d_backward(
+                B::into_float(x),
+                B::into_float(grad),
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        dispatch!(x.kind(), |B| {
+            let output = B::avg_pool2d(
+                B::into_float(x),
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        dispatch!(x.kind(), |B| {
+            let output = B::avg_pool2d_backward(
+                B::into_float(x),
+                B::into_float(grad),
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        dispatch!(x.kind(), |B| {
+            let output = B::adaptive_avg_pool2d(B::into_float(x), output_size);
+            B::from_float(output)
+        })
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+    ) -> FloatTensor<Self, 4> {
+        dispatch!(x.kind(), |B| {
+            let output = B::adaptive_avg_pool2d_backward(B::into_float(x), B::into_float(grad));
+            B::from_float(output)
+        })
+    }
+
+    fn adaptive_avg_pool1d(x: FloatTensor<Self, 3>, output_size: usize) -> FloatTensor<Self, 3> {
+        dispatch!(x.kind(), |B| {
+            let output = B::adaptive_avg_pool1d(B::into_float(x), output_size);
+            B::from_float(output)
+        })
+    }
+
+    fn adaptive_avg_pool1d_backward(
+        x: FloatTensor<Self, 3>,
+        grad: FloatTensor<Self, 3>,
+    ) -> FloatTensor<Self, 3> {
+        dispatch!(x.kind(), |B| {
+            let output = B::adaptive_avg_pool1d_backward(B::into_float(x), B::into_float(grad));
+            B::from_float(output)
+        })
+    }
+
+    fn max_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> FloatTensor<Self, 3> {
+        dispatch!(x.kind(), |B| {
+            let output = B::max_pool1d(B::into_float(x), kernel_size, stride, padding, dilation);
+            B::from_float(output)
+        })
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        dispatch!(x.kind(), |B| {
+            let output = B::max_pool2d(B::into_float(x), kernel_size, stride, padding, dilation);
+            B::from_float(output)
+        })
+    }
+
+    fn interpolate(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        dispatch!(x.kind(), |B| {
+            let output = B::interpolate(B::into_float(x), output_size, options);
+            B::from_float(output)
+        })
+    }
+
+    fn interpolate_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        dispatch!(x.kind(), |B| {
+            let output = B::interpolate_backward(
+                B::into_float(x),
+                B::into_float(grad),
+                output_size,
+                options,
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn grid_sample_2d(
+        x: FloatTensor<Self, 4>,
+        grid: FloatTensor<Self, 4>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self, 4> {
+        dispatch!(x.kind(), |B| {
+            let output = B::grid_sample_2d(B::into_float(x), B::into_float(grid), options);
+            B::from_float(output)
+        })
+    }
+
+    fn conv1d_backward(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 3>,
+        options: ConvOptions<1>,
+    ) -> Conv1dBackward<Self> {
+        dispatch!(x.kind(), |B| {
+            let backward = B::conv1d_backward(
+                B::into_float(x),
+                B::into_float(weight),
+                bias.map(B::into_float),
+                B::into_float(output_grad),
+                options,
+            );
+
+            Conv1dBackward::new(
+                B::from_float(backward.x_grad),
+                B::from_float(backward.weights_grad),
+                backward.bias_grad.map(B::from_float),
+            )
+        })
+    }
+
+    fn conv2d_backward(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 4>,
+        options: ConvOptions<2>,
+    ) -> Conv2dBackward<Self> {
+        dispatch!(x.kind(), |B| {
+            let backward = B::conv2d_backward(
+                B::into_float(x),
+                B::into_float(weight),
+                bias.map(B::into_float),
+                B::into_float(output_grad),
+                options,
+            );
+
+            Conv2dBackward::new(
+                B::from_float(backward.x_grad),
+                B::from_float(backward.weights_grad),
+                backward.bias_grad.map(B::from_float),
+            )
+        })
+    }
+
+    fn conv_transpose1d_backward(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 3>,
+        options: ConvTransposeOptions<1>,
+    ) -> Conv1dBackward<Self> {
+        dispatch!(x.kind(), |B| {
+            let backward = B::conv_transpose1d_backward(
+                B::into_float(x),
+                B::into_float(weight),
+                bias.map(B::into_float),
+                B::into_float(output_grad),
+                options,
+            );
+
+            Conv1dBackward::new(
+                B::from_float(backward.x_grad),
+                B::from_float(backward.weights_grad),
+                backward.bias_grad.map(B::from_float),
+            )
+        })
+    }
+
+    fn conv_transpose2d_backward(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 4>,
+        options: ConvTransposeOptions<2>,
+    ) -> Conv2dBackward<Self> {
+        dispatch!(x.kind(), |B| {
+            let backward = B::conv_transpose2d_backward(
+                B::into_float(x),
+                B::into_float(weight),
+                bias.map(B::into_float),
+                B::into_float(output_grad),
+                options,
+            );
+
+            Conv2dBackward::new(
+                B::from_float(backward.x_grad),
+                B::from_float(backward.weights_grad),
+                backward.bias_grad.map(B::from_float),
+            )
+        })
+    }
+
+    fn max_pool1d_with_indices(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> MaxPool1dWithIndices<Self> {
+        dispatch!(x.kind(), |B| {
+            let output = B::max_pool1d_with_indices(
+                B::into_float(x),
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+            );
+
+            MaxPool1dWithIndices::new(B::from_float(output.output), B::from_int(output.indices))
+        })
+    }
+
+    fn max_pool1d_with_indices_backward(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+        output_grad: FloatTensor<Self, 3>,
+        indices: IntTensor<Self, 3>,
+    ) -> MaxPool1dBackward<Self> {
+        dispatch!(x.kind(), |B| {
+            let backward = B::max_pool1d_with_indices_backward(
+                B::into_float(x),
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                B::into_float(output_grad),
+                B::into_int(indices),
+            );
+
+            MaxPool1dBackward::new(B::from_float(backward.x_grad))
+        })
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        dispatch!(x.kind(), |B| {
+            let output = B::max_pool2d_with_indices(
+                B::into_float(x),
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+            );
+
+            MaxPool2dWithIndices::new(B::from_float(output.output), B::from_int(output.indices))
+        })
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<Self, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        dispatch!(x.kind(), |B| {
+            let backward = B::max_pool2d_with_indices_backward(
+                B::into_float(x),
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                B::into_float(output_grad),
+                B::into_int(indices),
+            );
+
+            MaxPool2dBackward::new(B::from_float(backward.x_grad))
+        })
+    }
+}
@@ -0,0 +1,864 @@
+use burn_tensor::{
+    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntElem, IntTensor},
+    Data, Device, Distribution, ElementConversion, Reader, Shape,
+};
+use core::ops::Range;
+
+use crate::{variant::*, DynBackend};
+
+impl FloatTensorOps<Self> for DynBackend {
+    fn float_from_data<const D: usize>(
+        data: Data<FloatElem<Self>, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::float_from_data(data.convert(), B::device_ref(device));
+            B::from_float(output)
+        })
+    }
+
+    fn float_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::float_random(shape, distribution, B::device_ref(device));
+            B::from_float(output)
+        })
+    }
+
+    fn float_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::float_zeros(shape, B::device_ref(device));
+            B::from_float(output)
+        })
+    }
+
+    fn float_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::float_ones(shape, B::device_ref(device));
+            B::from_float(output)
+        })
+    }
+
+    fn float_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: FloatElem<Self>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::float_full(shape, fill_value.elem(), B::device_ref(device));
+            B::from_float(output)
+        })
+    }
+
+    fn float_shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
+        dispatch!(tensor.kind(), |B| B::float_shape(B::float_ref(tensor)))
+    }
+
+    fn float_to_data<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+    ) -> Reader<Data<FloatElem<Self>, D>> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_to_data(B::float_ref(tensor));
+            output.map(|data| data.convert())
+        })
+    }
+
+    fn float_into_data<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> Reader<Data<FloatElem<Self>, D>> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_into_data(B::into_float(tensor));
+            output.map(|data| data.convert())
+        })
+    }
+
+    fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_device(B::float_ref(tensor));
+            B::from_device(output)
+        })
+    }
+
+    fn float_into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_into_int(B::into_float(tensor));
+            B::from_int(output)
+        })
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_bits<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, 2> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_bits(B::into_float(tensor));
+            B::from_int(output)
+        })
+    }
+
+    fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        dispatch!(device.kind(), |B| {
+            let output = B::float_empty(shape, B::device_ref(device));
+            B::from_float(output)
+        })
+    }
+
+    fn float_repeat<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_repeat(B::into_float(tensor), dim, times);
+            B::from_float(output)
+        })
+    }
+
+    fn float_add<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_add(B::into_float(lhs), B::into_float(rhs));
+            B::from_float(output)
+        })
+    }
+
+    fn float_add_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_add_scalar(B::into_float(lhs), rhs.elem());
+            B::from_float(output)
+        })
+    }
+
+    fn float_clamp_min<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_clamp_min(B::into_float(tensor), min.elem());
+            B::from_float(output)
+        })
+    }
+
+    fn float_clamp_max<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_clamp_max(B::into_float(tensor), max.elem());
+            B::from_float(output)
+        })
+    }
+
+    fn float_clamp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_clamp(B::into_float(tensor), min.elem(), max.elem());
+            B::from_float(output)
+        })
+    }
+
+    fn float_sub<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_sub(B::into_float(lhs), B::into_float(rhs));
+            B::from_float(output)
+        })
+    }
+
+    fn float_sub_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_sub_scalar(B::into_float(lhs), rhs.elem());
+            B::from_float(output)
+        })
+    }
+
+    fn float_mul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_mul(B::into_float(lhs), B::into_float(rhs));
+            B::from_float(output)
+        })
+    }
+
+    fn float_mul_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_mul_scalar(B::into_float(lhs), rhs.elem());
+            B::from_float(output)
+        })
+    }
+
+    fn float_div<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_div(B::into_float(lhs), B::into_float(rhs));
+            B::from_float(output)
+        })
+    }
+
+    fn float_div_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_div_scalar(B::into_float(lhs), rhs.elem());
+            B::from_float(output)
+        })
+    }
+
+    fn float_remainder_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_remainder_scalar(B::into_float(lhs), rhs.elem());
+            B::from_float(output)
+        })
+    }
+
+    fn float_matmul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_matmul(B::into_float(lhs), B::into_float(rhs));
+            B::from_float(output)
+        })
+    }
+
+    fn float_neg<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_neg(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_recip(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_transpose<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_transpose(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_swap_dims<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_swap_dims(B::into_float(tensor), dim1, dim2);
+            B::from_float(output)
+        })
+    }
+
+    fn float_permute<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: [usize; D],
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_permute(B::into_float(tensor), axes);
+            B::from_float(output)
+        })
+    }
+
+    fn float_flip<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: &[usize],
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_flip(B::into_float(tensor), axes);
+            B::from_float(output)
+        })
+    }
+
+    fn float_reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_reshape(B::into_float(tensor), shape);
+            B::from_float(output)
+        })
+    }
+
+    fn float_gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_gather(dim, B::into_float(tensor), B::into_int(indices));
+            B::from_float(output)
+        })
+    }
+
+    fn float_scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_scatter(
+                dim,
+                B::into_float(tensor),
+                B::into_int(indices),
+                B::into_float(value),
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn float_select<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_select(B::into_float(tensor), dim, B::into_int(indices));
+            B::from_float(output)
+        })
+    }
+
+    fn float_select_assign<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_select_assign(
+                B::into_float(tensor),
+                dim,
+                B::into_int(indices),
+                B::into_float(value),
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn float_slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> FloatTensor<Self, D1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_slice(B::into_float(tensor), ranges);
+            B::from_float(output)
+        })
+    }
+
+    fn float_slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: FloatTensor<Self, D1>,
+    ) -> FloatTensor<Self, D1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_slice_assign(B::into_float(tensor), ranges, B::into_float(value));
+            B::from_float(output)
+        })
+    }
+
+    fn float_mask_where<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_mask_where(
+                B::into_float(tensor),
+                B::into_bool(mask),
+                B::into_float(value),
+            );
+            B::from_float(output)
+        })
+    }
+
+    fn float_mask_fill<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output =
+                B::float_mask_fill(B::into_float(tensor), B::into_bool(mask), value.elem());
+            B::from_float(output)
+        })
+    }
+
+    fn float_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_equal(B::into_float(lhs), B::into_float(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn float_not_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_not_equal(B::into_float(lhs), B::into_float(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn float_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_equal_elem(B::into_float(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn float_not_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_not_equal_elem(B::into_float(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn float_greater<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_greater(B::into_float(lhs), B::into_float(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn float_greater_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_greater_elem(B::into_float(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn float_greater_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_greater_equal(B::into_float(lhs), B::into_float(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn float_greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_greater_equal_elem(B::into_float(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn float_lower<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_lower(B::into_float(lhs), B::into_float(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn float_lower_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_lower_elem(B::into_float(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn float_lower_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_lower_equal(B::into_float(lhs), B::into_float(rhs));
+            B::from_bool(output)
+        })
+    }
+
+    fn float_lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_lower_equal_elem(B::into_float(lhs), rhs.elem());
+            B::from_bool(output)
+        })
+    }
+
+    fn float_sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_sum(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_sum_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_sum_dim(B::into_float(tensor), dim);
+            B::from_float(output)
+        })
+    }
+
+    fn float_prod<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_prod(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_prod_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_prod_dim(B::into_float(tensor), dim);
+            B::from_float(output)
+        })
+    }
+
+    fn float_mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_mean(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_mean_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_mean_dim(B::into_float(tensor), dim);
+            B::from_float(output)
+        })
+    }
+
+    fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_exp(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_log(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_log1p(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_powf(B::into_float(lhs), B::into_float(rhs));
+            B::from_float(output)
+        })
+    }
+
+    fn float_powi<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_powi(B::into_float(lhs), B::into_int(rhs));
+            B::from_float(output)
+        })
+    }
+
+    fn float_powi_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(lhs.kind(), |B| {
+            let output = B::float_powi_scalar(B::into_float(lhs), rhs.elem());
+            B::from_float(output)
+        })
+    }
+
+    fn float_powf_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        value: f32,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_powf_scalar(B::into_float(tensor), value);
+            B::from_float(output)
+        })
+    }
+
+    fn float_sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_sqrt(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_abs(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_cos(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_sin(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_tanh(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_erf(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<Self, D>>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensors[0].kind(), |B| {
+            let output = B::float_cat(tensors.into_iter().map(B::into_float).collect(), dim);
+            B::from_float(output)
+        })
+    }
+
+    fn float_argmax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_argmax(B::into_float(tensor), dim);
+            B::from_int(output)
+        })
+    }
+
+    fn float_argmin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_argmin(B::into_float(tensor), dim);
+            B::from_int(output)
+        })
+    }
+
+    fn float_max<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_max(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_max_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_max_dim(B::into_float(tensor), dim);
+            B::from_float(output)
+        })
+    }
+
+    fn float_max_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        dispatch!(tensor.kind(), |B| {
+            let (values, indices) = B::float_max_dim_with_indices(B::into_float(tensor), dim);
+            (B::from_float(values), B::from_int(indices))
+        })
+    }
+
+    fn float_min<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_min(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_min_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_min_dim(B::into_float(tensor), dim);
+            B::from_float(output)
+        })
+    }
+
+    fn float_min_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        dispatch!(tensor.kind(), |B| {
+            let (values, indices) = B::float_min_dim_with_indices(B::into_float(tensor), dim);
+            (B::from_float(values), B::from_int(indices))
+        })
+    }
+
+    fn float_narrow<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_narrow(B::into_float(tensor), dim, start, length);
+            B::from_float(output)
+        })
+    }
+
+    fn float_chunk<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<FloatTensor<Self, D>> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_chunk(B::into_float(tensor), chunks, dim);
+            output.into_iter().map(B::from_float).collect()
+        })
+    }
+
+    fn float_any<const D: usize>(tensor: FloatTensor<Self, D>) -> BoolTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_any(B::into_float(tensor));
+            B::from_bool(output)
+        })
+    }
+
+    fn float_any_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_any_dim(B::into_float(tensor), dim);
+            B::from_bool(output)
+        })
+    }
+
+    fn float_all<const D: usize>(tensor: FloatTensor<Self, D>) -> BoolTensor<Self, 1> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_all(B::into_float(tensor));
+            B::from_bool(output)
+        })
+    }
+
+    fn float_all_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_all_dim(B::into_float(tensor), dim);
+            B::from_bool(output)
+        })
+    }
+
+    fn float_sign<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_sign(B::into_float(tensor));
+            B::from_float(output)
+        })
+    }
+
+    fn float_expand<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        dispatch!(tensor.kind(), |B| {
+            let output = B::float_expand(B::into_float(tensor), shape);
+            B::from_float(output)
+        })
+    }
+
+    fn float_to_device<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        if tensor.kind() == device.kind() {
+            return dispatch!(tensor.kind(), |B| {
+                let output = B::float_to_device(B::into_float(tensor), B::device_ref(device));
+                B::from_float(output)
+            });
+        }
+
+        // Tensors are moved between backends through the host.
+        let data = Self::float_into_data(tensor).read_sync().expect(
+            "Can't read the data of the tensor synchronously to move it to another backend.",
+        );
+        Self::float_from_data(data, device)
+    }
+}
@@ -0,0 +1,56 @@
+use burn_tensor::ops::{BoolTensor, FloatTensor, IntTensor};
+
+use crate::variant::*;
+
+macro_rules! dyn_tensor {
+    ($(#[$meta:meta])* $name:ident, $primitive:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug)]
+        pub enum $name<const D: usize> {
+            /// A tensor of the [ndarray backend](NdArrayBackend).
+            #[cfg(feature = "ndarray")]
+            NdArray($primitive<NdArrayBackend, D>),
+            /// A tensor of the [wgpu backend](WgpuBackend).
+            #[cfg(feature = "wgpu")]
+            Wgpu($primitive<WgpuBackend, D>),
+            /// A tensor of the [LibTorch backend](LibTorchBackend).
+            #[cfg(feature = "tch")]
+            LibTorch($primitive<LibTorchBackend, D>),
+            /// A tensor of the [candle backend](CandleBackend).
+            #[cfg(feature = "candle")]
+            Candle($primitive<CandleBackend, D>),
+        }
+
+        impl<const D: usize> $name<D> {
+            /// The backend of the tensor.
+            pub fn kind(&self) -> BackendKind {
+                match self {
+                    #[cfg(feature = "ndarray")]
+                    Self::NdArray(_) => BackendKind::NdArray,
+                    #[cfg(feature = "wgpu")]
+                    Self::Wgpu(_) => BackendKind::Wgpu,
+                    #[cfg(feature = "tch")]
+                    Self::LibTorch(_) => BackendKind::LibTorch,
+                    #[cfg(feature = "candle")]
+                    Self::Candle(_) => BackendKind::Candle,
+                }
+            }
+        }
+    };
+}
+
+dyn_tensor!(
+    /// The float tensor of the [dyn backend](crate::DynBackend), a tensor of one of its backends.
+    DynTensor,
+    FloatTensor
+);
+dyn_tensor!(
+    /// The int tensor of the [dyn backend](crate::DynBackend), a tensor of one of its backends.
+    DynIntTensor,
+    IntTensor
+);
+dyn_tensor!(
+    /// The bool tensor of the [dyn backend](crate::DynBackend), a tensor of one of its backends.
+    DynBoolTensor,
+    BoolTensor
+);
@@ -0,0 +1,213 @@
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntTensor},
+};
+
+use crate::{DynBoolTensor, DynDevice, DynIntTensor, DynTensor};
+
+/// The ndarray backend used by the [dyn backend](crate::DynBackend).
+#[cfg(feature = "ndarray")]
+pub type NdArrayBackend = burn_ndarray::NdArray<f32>;
+
+/// The wgpu backend used by the [dyn backend](crate::DynBackend).
+#[cfg(feature = "wgpu")]
+pub type WgpuBackend = burn_wgpu::Wgpu<burn_wgpu::AutoGraphicsApi, f32, i32>;
+
+/// The LibTorch backend used by the [dyn backend](crate::DynBackend).
+#[cfg(feature = "tch")]
+pub type LibTorchBackend = burn_tch::LibTorch<f32>;
+
+/// The candle backend used by the [dyn backend](crate::DynBackend).
+#[cfg(feature = "candle")]
+pub type CandleBackend = burn_candle::Candle<f32, i64>;
+
+/// The backends the [dyn backend](crate::DynBackend) can dispatch its operations to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BackendKind {
+    /// The [ndarray backend](NdArrayBackend).
+    #[cfg(feature = "ndarray")]
+    NdArray,
+    /// The [wgpu backend](WgpuBackend).
+    #[cfg(feature = "wgpu")]
+    Wgpu,
+    /// The [LibTorch backend](LibTorchBackend).
+    #[cfg(feature = "tch")]
+    LibTorch,
+    /// The [candle backend](CandleBackend).
+    #[cfg(feature = "candle")]
+    Candle,
+}
+
+impl BackendKind {
+    /// The backends enabled with the features of the crate.
+    pub fn enabled() -> &'static [BackendKind] {
+        &[
+            #[cfg(feature = "ndarray")]
+            BackendKind::NdArray,
+            #[cfg(feature = "wgpu")]
+            BackendKind::Wgpu,
+            #[cfg(feature = "tch")]
+            BackendKind::LibTorch,
+            #[cfg(feature = "candle")]
+            BackendKind::Candle,
+        ]
+    }
+
+    /// The name of the backend, the prefix of its [devices](DynDevice).
+    pub fn name(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "ndarray")]
+            BackendKind::NdArray => "ndarray",
+            #[cfg(feature = "wgpu")]
+            BackendKind::Wgpu => "wgpu",
+            #[cfg(feature = "tch")]
+            BackendKind::LibTorch => "tch",
+            #[cfg(feature = "candle")]
+            BackendKind::Candle => "candle",
+        }
+    }
+}
+
+impl core::fmt::Display for BackendKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Execute the expression with the given identifier aliasing the backend of the given kind.
+macro_rules! dispatch {
+    ($kind:expr, |$backend:ident| $body:expr) => {
+        match $kind {
+            #[cfg(feature = "ndarray")]
+            $crate::BackendKind::NdArray => {
+                type $backend = $crate::NdArrayBackend;
+                $body
+            }
+            #[cfg(feature = "wgpu")]
+            $crate::BackendKind::Wgpu => {
+                type $backend = $crate::WgpuBackend;
+                $body
+            }
+            #[cfg(feature = "tch")]
+            $crate::BackendKind::LibTorch => {
+                type $backend = $crate::LibTorchBackend;
+                $body
+            }
+            #[cfg(feature = "candle")]
+            $crate::BackendKind::Candle => {
+                type $backend = $crate::CandleBackend;
+                $body
+            }
+        }
+    };
+}
+
+/// Conversions between the tensors and devices of a backend and the ones of the
+/// [dyn backend](crate::DynBackend).
+///
+/// The conversions to the tensors of the backend panic when the tensor is on another backend.
+pub(crate) trait DynVariant: Backend {
+    const KIND: BackendKind;
+
+    fn into_float<const D: usize>(tensor: DynTensor<D>) -> FloatTensor<Self, D>;
+    fn float_ref<const D: usize>(tensor: &DynTensor<D>) -> &FloatTensor<Self, D>;
+    fn from_float<const D: usize>(tensor: FloatTensor<Self, D>) -> DynTensor<D>;
+
+    fn into_int<const D: usize>(tensor: DynIntTensor<D>) -> IntTensor<Self, D>;
+    fn int_ref<const D: usize>(tensor: &DynIntTensor<D>) -> &IntTensor<Self, D>;
+    fn from_int<const D: usize>(tensor: IntTensor<Self, D>) -> DynIntTensor<D>;
+
+    fn into_bool<const D: usize>(tensor: DynBoolTensor<D>) -> BoolTensor<Self, D>;
+    fn bool_ref<const D: usize>(tensor: &DynBoolTensor<D>) -> &BoolTensor<Self, D>;
+    fn from_bool<const D: usize>(tensor: BoolTensor<Self, D>) -> DynBoolTensor<D>;
+
+    fn device_ref(device: &DynDevice) -> &Self::Device;
+    fn from_device(device: Self::Device) -> DynDevice;
+}
+
+fn mismatch(expected: BackendKind, found: BackendKind) -> ! {
+    panic!(
+        "Expected a tensor of the {expected} backend, found a tensor of the {found} backend. \
+        Tensors should be moved to the same backend with `to_device`."
+    )
+}
+
+macro_rules! variant {
+    ($feature:literal, $kind:ident, $backend:ty) => {
+        #[cfg(feature = $feature)]
+        #[allow(unreachable_patterns)]
+        impl DynVariant for $backend {
+            const KIND: BackendKind = BackendKind::$kind;
+
+            fn into_float<const D: usize>(tensor: DynTensor<D>) -> FloatTensor<Self, D> {
+                match tensor {
+                    DynTensor::$kind(tensor) => tensor,
+                    tensor => mismatch(Self::KIND, tensor.kind()),
+                }
+            }
+
+            fn float_ref<const D: usize>(tensor: &DynTensor<D>) -> &FloatTensor<Self, D> {
+                match tensor {
+                    DynTensor::$kind(tensor) => tensor,
+                    tensor => mismatch(Self::KIND, tensor.kind()),
+                }
+            }
+
+            fn from_float<const D: usize>(tensor: FloatTensor<Self, D>) -> DynTensor<D> {
+                DynTensor::$kind(tensor)
+            }
+
+            fn into_int<const D: usize>(tensor: DynIntTensor<D>) -> IntTensor<Self, D> {
+                match tensor {
+                    DynIntTensor::$kind(tensor) => tensor,
+                    tensor => mismatch(Self::KIND, tensor.kind()),
+                }
+            }
+
+            fn int_ref<const D: usize>(tensor: &DynIntTensor<D>) -> &IntTensor<Self, D> {
+                match tensor {
+                    DynIntTensor::$kind(tensor) => tensor,
+                    tensor => mismatch(Self::KIND, tensor.kind()),
+                }
+            }
+
+            fn from_int<const D: usize>(tensor: IntTensor<Self, D>) -> DynIntTensor<D> {
+                DynIntTensor::$kind(tensor)
+            }
+
+            fn into_bool<const D: usize>(tensor: DynBoolTensor<D>) -> BoolTensor<Self, D> {
+                match tensor {
+                    DynBoolTensor::$kind(tensor) => tensor,
+                    tensor => mismatch(Self::KIND, tensor.kind()),
+                }
+            }
+
+            fn bool_ref<const D: usize>(tensor: &DynBoolTensor<D>) -> &BoolTensor<Self, D> {
+                match tensor {
+                    DynBoolTensor::$kind(tensor) => tensor,
+                    tensor => mismatch(Self::KIND, tensor.kind()),
+                }
+            }
+
+            fn from_bool<const D: usize>(tensor: BoolTensor<Self, D>) -> DynBoolTensor<D> {
+                DynBoolTensor::$kind(tensor)
+            }
+
+            fn device_ref(device: &DynDevice) -> &Self::Device {
+                match device {
+                    DynDevice::$kind(device) => device,
+                    device => mismatch(Self::KIND, device.kind()),
+                }
+            }
+
+            fn from_device(device: Self::Device) -> DynDevice {
+                DynDevice::$kind(device)
+            }
+        }
+    };
+}
+
+variant!("ndarray", NdArray, NdArrayBackend);
+variant!("wgpu", Wgpu, WgpuBackend);
+variant!("tch", LibTorch, LibTorchBackend);
+variant!("candle", Candle, CandleBackend);
@@ -2,7 +2,7 @@ use crate::{
     client::FusionClient, stream::Context, FusionClientLocator, FusionTensor, PrecisionBridge,
 };
 use burn_tensor::{
-    backend::{Backend, DeviceOps, SyncType},
+    backend::{Backend, DeviceInfo, DeviceOps, MemoryUsage, SyncType},
     ops::FloatTensor,
     repr::{OperationDescription, ReprBackend},
     Device,
@@ -16,6 +16,24 @@ pub(crate) fn get_client<B: FusionBackend>(device: &Device<B>) -> Client<B::Fusi
     CLIENTS.client::<B::FusionRuntime>(device)
 }
 
+/// Release the fusion clients of the device, of every runtime, after executing their queued
+/// operations, and returns whether a client was created for the device.
+///
+/// The fusion clients hold the handles of the tensors, so the memory of the device isn't freed
+/// while they are registered, even once its compute clients are released. The handles are
+/// dropped once every tensor of a released client is dropped, and a new client is created when
+/// the device is used again.
+pub fn release_clients<D: DeviceOps + 'static>(device: &D) -> bool {
+    CLIENTS.release(device)
+}
+
+/// Release the fusion clients of every device of the given type.
+///
+/// See [release_clients].
+pub fn release_all_clients<D: DeviceOps + 'static>() {
+    CLIENTS.release_all::<D>()
+}
+
 /// Enable dynamic operation fusion on a backend that implements [fusion backend](crate::FusionBackend).
 #[derive(Clone, Debug, Default)]
 pub struct Fusion<B: FusionBackend> {
@@ -45,12 +63,24 @@ impl<B: FusionBackend> Backend for Fusion<B> {
         B::seed(seed);
     }
 
+    fn fork_seed() -> Option<u64> {
+        B::fork_seed()
+    }
+
     fn sync(device: &Self::Device, sync_type: SyncType) {
         let client = CLIENTS.client::<B::FusionRuntime>(&device.clone());
         client.drain();
         B::sync(device, sync_type);
     }
 
+    fn memory_usage(device: &Self::Device) -> Option<MemoryUsage> {
+        B::memory_usage(device)
+    }
+
+    fn devices() -> Vec<DeviceInfo<Self::Device>> {
+        B::devices()
+    }
+
     fn ad_enabled() -> bool {
         false
     }
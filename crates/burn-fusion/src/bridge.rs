@@ -38,6 +38,18 @@ where
     ) -> FloatTensor<Fusion<BInput>, D> {
         cast::<R, BTarget, BInput, D>(tensor)
     }
+
+    fn target_device(
+        device: &burn_tensor::Device<Fusion<BInput>>,
+    ) -> burn_tensor::Device<Self::Target> {
+        device.clone()
+    }
+
+    fn origin_device(
+        device: &burn_tensor::Device<Self::Target>,
+    ) -> burn_tensor::Device<Fusion<BInput>> {
+        device.clone()
+    }
 }
 
 fn cast<R, BInput, BTarget, const D: usize>(
@@ -5,14 +5,38 @@ use burn_tensor::{
 
 use crate::{client::FusionClient, Client, FusionDevice, FusionRuntime};
 
-use std::{any::Any, collections::HashMap, ops::DerefMut};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    ops::DerefMut,
+};
 
 /// Type alias for [representation backend handle](burn_tensor::repr::ReprBackend::Handle).
 pub type Handle<B> = <B as ReprBackend>::Handle;
-type Key = (core::any::TypeId, DeviceId);
+type Key = (TypeId, DeviceId);
 
 pub(crate) struct FusionClientLocator {
-    clients: spin::Mutex<Option<HashMap<Key, Box<dyn core::any::Any + Send>>>>,
+    clients: spin::Mutex<Option<HashMap<Key, ClientEntry>>>,
+}
+
+/// A registered client, with the type of its device and a function draining its queue of
+/// operations, so that it can be released without knowing its runtime.
+struct ClientEntry {
+    client: Box<dyn Any + Send>,
+    device_type: TypeId,
+    drain: Box<dyn Fn() + Send>,
+}
+
+impl ClientEntry {
+    fn new<R: FusionRuntime + 'static>(client: Client<R>) -> Self {
+        let drained = client.clone();
+
+        Self {
+            client: Box::new(client),
+            device_type: TypeId::of::<FusionDevice<R>>(),
+            drain: Box::new(move || drained.drain()),
+        }
+    }
 }
 
 impl FusionClientLocator {
@@ -28,7 +52,7 @@ impl FusionClientLocator {
     /// Provide the init function to create a new client if it isn't already initialized.
     pub fn client<R: FusionRuntime + 'static>(&self, device: &FusionDevice<R>) -> Client<R> {
         let device_id = device.id();
-        let client_id = (core::any::TypeId::of::<R>(), device_id);
+        let client_id = (TypeId::of::<R>(), device_id);
         let mut clients = self.clients.lock();
 
         if clients.is_none() {
@@ -38,14 +62,13 @@ impl FusionClientLocator {
 
         match clients.deref_mut() {
             Some(clients) => match clients.get(&client_id) {
-                Some(client) => {
-                    let client: &Client<R> = client.downcast_ref().unwrap();
+                Some(entry) => {
+                    let client: &Client<R> = entry.client.downcast_ref().unwrap();
                     client.clone()
                 }
                 None => {
                     let client = Client::<R>::new(device.clone());
-                    let any = Box::new(client.clone());
-                    clients.insert(client_id, any);
+                    clients.insert(client_id, ClientEntry::new::<R>(client.clone()));
                     client
                 }
             },
@@ -53,10 +76,51 @@ impl FusionClientLocator {
         }
     }
 
+    /// Release the clients of the given device, of every runtime, after executing their queued
+    /// operations, and returns whether a client was registered.
+    pub fn release<D: DeviceOps + 'static>(&self, device: &D) -> bool {
+        let device_id = device.id();
+
+        self.release_matching::<D>(|id| *id == device_id)
+    }
+
+    /// Release the clients of every device of the given type.
+    pub fn release_all<D: DeviceOps + 'static>(&self) {
+        self.release_matching::<D>(|_| true);
+    }
+
+    fn release_matching<D: DeviceOps + 'static>(
+        &self,
+        matches: impl Fn(&DeviceId) -> bool,
+    ) -> bool {
+        let device_type = TypeId::of::<D>();
+        let released = match self.clients.lock().as_mut() {
+            Some(clients) => {
+                let keys: Vec<Key> = clients
+                    .iter()
+                    .filter(|(key, entry)| entry.device_type == device_type && matches(&key.1))
+                    .map(|(key, _)| *key)
+                    .collect();
+
+                keys.iter()
+                    .filter_map(|key| clients.remove(key))
+                    .collect::<Vec<_>>()
+            }
+            None => Vec::new(),
+        };
+
+        // The operations are executed without holding the lock, since they can use other clients.
+        for entry in released.iter() {
+            (entry.drain)();
+        }
+
+        !released.is_empty()
+    }
+
     fn register_inner<R: FusionRuntime + 'static>(
         key: Key,
         client: Client<R>,
-        clients: &mut Option<HashMap<Key, Box<dyn Any + Send>>>,
+        clients: &mut Option<HashMap<Key, ClientEntry>>,
     ) {
         if clients.is_none() {
             *clients = Some(HashMap::new());
@@ -67,7 +131,7 @@ impl FusionClientLocator {
                 panic!("Client already created for device {:?}", key);
             }
 
-            clients.insert(key, Box::new(client));
+            clients.insert(key, ClientEntry::new::<R>(client));
         }
     }
 }
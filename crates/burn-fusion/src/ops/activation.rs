@@ -1,4 +1,99 @@
-use crate::{Fusion, FusionBackend};
-use burn_tensor::ops::ActivationOps;
+use crate::{client::FusionClient, stream::execution::Operation, Fusion, FusionBackend};
+use burn_tensor::{
+    ops::{ActivationOps, FloatTensor, IntTensor},
+    repr::*,
+    Element,
+};
+use std::marker::PhantomData;
 
-impl<B: FusionBackend> ActivationOps<Self> for Fusion<B> {}
+impl<B: FusionBackend> ActivationOps<Self> for Fusion<B> {
+    fn cross_entropy(
+        logits: FloatTensor<Self, 2>,
+        targets: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, 1> {
+        #[derive(new)]
+        struct CrossEntropyOps<B: FusionBackend> {
+            desc: CrossEntropyDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for CrossEntropyOps<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let logits = handles.get_float_tensor::<B, 2>(&self.desc.logits);
+                let targets = handles.get_int_tensor::<B, 1>(&self.desc.targets);
+
+                let output = B::cross_entropy(logits, targets);
+
+                handles.register_float_tensor::<B, 1>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream_1 = logits.stream;
+        let stream_2 = targets.stream;
+        let out = logits
+            .client
+            .tensor_uninitialized(vec![logits.shape[0]], B::FloatElem::dtype());
+
+        let desc = CrossEntropyDescription {
+            logits: logits.into_description(),
+            targets: targets.into_description(),
+            out: out.to_description_out(),
+        };
+
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Module(ModuleOperationDescription::CrossEntropy(desc.clone())),
+            CrossEntropyOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn cross_entropy_backward(
+        logits: FloatTensor<Self, 2>,
+        targets: IntTensor<Self, 1>,
+        grad: FloatTensor<Self, 1>,
+    ) -> FloatTensor<Self, 2> {
+        #[derive(new)]
+        struct CrossEntropyBackwardOps<B: FusionBackend> {
+            desc: CrossEntropyBackwardDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for CrossEntropyBackwardOps<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let logits = handles.get_float_tensor::<B, 2>(&self.desc.logits);
+                let targets = handles.get_int_tensor::<B, 1>(&self.desc.targets);
+                let grad = handles.get_float_tensor::<B, 1>(&self.desc.grad);
+
+                let output = B::cross_entropy_backward(logits, targets, grad);
+
+                handles.register_float_tensor::<B, 2>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream_1 = logits.stream;
+        let stream_2 = targets.stream;
+        let stream_3 = grad.stream;
+        let out = logits
+            .client
+            .tensor_uninitialized(logits.shape.clone(), B::FloatElem::dtype());
+
+        let desc = CrossEntropyBackwardDescription {
+            logits: logits.into_description(),
+            targets: targets.into_description(),
+            grad: grad.into_description(),
+            out: out.to_description_out(),
+        };
+
+        out.client.register(
+            vec![stream_1, stream_2, stream_3],
+            OperationDescription::Module(ModuleOperationDescription::CrossEntropyBackward(
+                desc.clone(),
+            )),
+            CrossEntropyBackwardOps::<B>::new(desc),
+        );
+
+        out
+    }
+}
@@ -738,6 +738,67 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    #[cfg(not(target_family = "wasm"))]
+    fn float_scatter_reduce<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+        reduction: burn_tensor::ops::ScatterReduction,
+        deterministic: bool,
+    ) -> FloatTensor<Self, D> {
+        #[derive(new)]
+        struct ScatterReduceOps<B: FusionBackend, const D: usize> {
+            desc: ScatterReduceOperationDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for ScatterReduceOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B, D>(&self.desc.tensor);
+                let indices = handles.get_int_tensor::<B, D>(&self.desc.indices);
+                let value = handles.get_float_tensor::<B, D>(&self.desc.value);
+
+                let output = B::float_scatter_reduce(
+                    self.desc.dim,
+                    tensor,
+                    indices,
+                    value,
+                    self.desc.reduction,
+                    self.desc.deterministic,
+                );
+
+                handles.register_float_tensor::<B, D>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream_1 = tensor.stream;
+        let stream_2 = indices.stream;
+        let stream_3 = value.stream;
+        let shape: Vec<usize> = tensor.shape.clone();
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = ScatterReduceOperationDescription {
+            tensor: tensor.into_description(),
+            dim,
+            indices: indices.into_description(),
+            value: value.into_description(),
+            reduction,
+            deterministic,
+            out: out.to_description_out(),
+        };
+
+        out.client.register(
+            vec![stream_1, stream_2, stream_3],
+            OperationDescription::Float(FloatOperationDescription::ScatterReduce(desc.clone())),
+            ScatterReduceOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
     fn float_select<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -320,6 +320,21 @@ impl RelativeOps for ModuleOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            ModuleOperationDescription::CrossEntropy(desc) => {
+                ModuleOperationDescription::CrossEntropy(CrossEntropyDescription {
+                    logits: desc.logits.to_relative(converter),
+                    targets: desc.targets.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            ModuleOperationDescription::CrossEntropyBackward(desc) => {
+                ModuleOperationDescription::CrossEntropyBackward(CrossEntropyBackwardDescription {
+                    logits: desc.logits.to_relative(converter),
+                    targets: desc.targets.to_relative(converter),
+                    grad: desc.grad.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
         }
     }
 }
@@ -407,6 +422,17 @@ impl RelativeOps for FloatOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            FloatOperationDescription::ScatterReduce(desc) => {
+                FloatOperationDescription::ScatterReduce(ScatterReduceOperationDescription {
+                    tensor: desc.tensor.to_relative(converter),
+                    dim: desc.dim,
+                    indices: desc.indices.to_relative(converter),
+                    value: desc.value.to_relative(converter),
+                    reduction: desc.reduction,
+                    deterministic: desc.deterministic,
+                    out: desc.out.to_relative(converter),
+                })
+            }
         }
     }
 }
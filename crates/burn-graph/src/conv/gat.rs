@@ -0,0 +1,219 @@
+use burn_core as burn;
+
+use crate::{add_self_loops, split_edge_index};
+use burn::config::Config;
+use burn::module::{Module, Param};
+use burn::nn::{Dropout, DropoutConfig, Initializer, Linear, LinearConfig};
+use burn::tensor::activation::leaky_relu;
+use burn::tensor::backend::Backend;
+use burn::tensor::ops::ScatterReduction;
+use burn::tensor::{Int, Tensor};
+
+/// Configuration to create a [graph attention](GatConv) layer using the
+/// [init function](GatConvConfig::init).
+#[derive(Config, Debug)]
+pub struct GatConvConfig {
+    /// The size of the input node features.
+    pub d_input: usize,
+    /// The size of the output node features of each head.
+    pub d_output: usize,
+    /// The number of attention heads.
+    #[config(default = 1)]
+    pub num_heads: usize,
+    /// If the outputs of the heads are concatenated, otherwise they are averaged.
+    #[config(default = true)]
+    pub concat: bool,
+    /// The negative slope of the leaky ReLU applied to the attention scores.
+    #[config(default = 0.2)]
+    pub negative_slope: f64,
+    /// The dropout rate of the attention coefficients.
+    #[config(default = 0.0)]
+    pub dropout: f64,
+    /// If a bias should be added to the aggregated messages.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize the weights.
+    #[config(default = "Initializer::XavierUniform{gain:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// The graph attention layer of [Veličković et al.](https://arxiv.org/abs/1710.10903).
+///
+/// Each node aggregates the transformed features of its neighbors and of itself, weighted by
+/// attention coefficients normalized over the incoming edges of the node:
+///
+/// `alpha_ij = softmax_j(leaky_relu(a_s . x_j W + a_t . x_i W))`
+///
+/// Should be created with [GatConvConfig].
+#[derive(Module, Debug)]
+pub struct GatConv<B: Backend> {
+    /// The transformation of the node features, for all the heads.
+    pub linear: Linear<B>,
+    /// The attention weights of the source nodes of shape `[num_heads, d_output]`.
+    pub attention_source: Param<Tensor<B, 2>>,
+    /// The attention weights of the target nodes of shape `[num_heads, d_output]`.
+    pub attention_target: Param<Tensor<B, 2>>,
+    /// The bias added to the aggregated messages, initialized to zeros.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    dropout: Dropout,
+    num_heads: usize,
+    concat: bool,
+    negative_slope: f64,
+}
+
+impl GatConvConfig {
+    /// Initialize a new [graph attention](GatConv) layer.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> GatConv<B> {
+        let d_hidden = self.num_heads * self.d_output;
+        let linear = LinearConfig::new(self.d_input, d_hidden)
+            .with_bias(false)
+            .with_initializer(self.initializer.clone())
+            .init(device);
+        let attention = || {
+            self.initializer.init_with(
+                [self.num_heads, self.d_output],
+                Some(self.d_output),
+                Some(1),
+                device,
+            )
+        };
+        let d_bias = match self.concat {
+            true => d_hidden,
+            false => self.d_output,
+        };
+        let bias = self.bias.then(|| Initializer::Zeros.init([d_bias], device));
+
+        GatConv {
+            linear,
+            attention_source: attention(),
+            attention_target: attention(),
+            bias,
+            dropout: DropoutConfig::new(self.dropout).init(),
+            num_heads: self.num_heads,
+            concat: self.concat,
+            negative_slope: self.negative_slope,
+        }
+    }
+}
+
+impl<B: Backend> GatConv<B> {
+    /// Applies the forward pass on the node features.
+    ///
+    /// # Shapes
+    ///
+    /// - node_features: `[num_nodes, d_input]`
+    /// - edge_index: `[2, num_edges]`
+    /// - output: `[num_nodes, num_heads * d_output]` if the heads are concatenated, otherwise
+    ///   `[num_nodes, d_output]`
+    pub fn forward(
+        &self,
+        node_features: Tensor<B, 2>,
+        edge_index: Tensor<B, 2, Int>,
+    ) -> Tensor<B, 2> {
+        let [num_nodes, _] = node_features.dims();
+        let [_, d_output] = self.attention_source.dims();
+        let edge_index = add_self_loops(edge_index, num_nodes);
+        let [_, num_edges] = edge_index.dims();
+        let (sources, targets) = split_edge_index(edge_index);
+
+        let hidden =
+            self.linear
+                .forward(node_features)
+                .reshape([num_nodes, self.num_heads, d_output]);
+        let score = |attention: &Param<Tensor<B, 2>>| {
+            (hidden.clone() * attention.val().unsqueeze())
+                .sum_dim(2)
+                .reshape([num_nodes, self.num_heads])
+        };
+        let scores = score(&self.attention_source).select(0, sources.clone())
+            + score(&self.attention_target).select(0, targets.clone());
+        let scores = leaky_relu(scores, self.negative_slope);
+
+        let attention = self.softmax(scores, targets.clone(), num_nodes, num_edges);
+        let attention = self.dropout.forward(attention);
+
+        let output = (hidden.select(0, sources) * attention.unsqueeze_dim(2))
+            .segment_sum(targets, num_nodes);
+        let output = match self.concat {
+            true => output.reshape([num_nodes, self.num_heads * d_output]),
+            false => output.mean_dim(1).reshape([num_nodes, d_output]),
+        };
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+
+    /// Normalizes the scores of shape `[num_edges, num_heads]` over the incoming edges of each
+    /// node, which all have at least their self-loop.
+    fn softmax(
+        &self,
+        scores: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        num_nodes: usize,
+        num_edges: usize,
+    ) -> Tensor<B, 2> {
+        let device = scores.device();
+        let indices = targets
+            .clone()
+            .reshape([num_edges, 1])
+            .repeat(1, self.num_heads);
+
+        // The maximum score of each node only makes the exponential stable, without gradients.
+        let max = Tensor::full([num_nodes, self.num_heads], f32::NEG_INFINITY, &device)
+            .scatter_reduce(0, indices, scores.clone().detach(), ScatterReduction::Max)
+            .select(0, targets.clone());
+        let exp = (scores - max).exp();
+        let sum = exp
+            .clone()
+            .segment_sum(targets.clone(), num_nodes)
+            .select(0, targets);
+
+        exp / sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn::tensor::{Data, Distribution};
+
+    #[test]
+    fn should_average_the_neighbors_with_uniform_attention() {
+        let device = Default::default();
+        let mut conv = GatConvConfig::new(1, 1)
+            .with_num_heads(2)
+            .with_concat(false)
+            .init::<TestBackend>(&device);
+        conv.linear.weight = Param::from_tensor(Tensor::ones([1, 2], &device));
+        conv.attention_source = Param::from_tensor(Tensor::zeros([2, 1], &device));
+        conv.attention_target = Param::from_tensor(Tensor::zeros([2, 1], &device));
+
+        let output = conv.forward(
+            Tensor::from_floats([[1.0], [2.0], [6.0]], &device),
+            Tensor::from_ints([[0, 2], [1, 1]], &device),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [3.0], [6.0]]), 5);
+    }
+
+    #[test]
+    fn should_backpropagate_to_the_attention_weights() {
+        let device = Default::default();
+        let conv = GatConvConfig::new(3, 4)
+            .with_num_heads(2)
+            .init::<TestAutodiffBackend>(&device);
+
+        let output = conv.forward(
+            Tensor::random([4, 3], Distribution::Default, &device),
+            Tensor::from_ints([[0, 1, 2, 3], [1, 2, 3, 0]], &device),
+        );
+        let grads = output.sum().backward();
+
+        assert_eq!(conv.attention_source.grad(&grads).unwrap().dims(), [2, 4]);
+    }
+}
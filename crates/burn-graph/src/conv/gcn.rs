@@ -0,0 +1,112 @@
+use burn_core as burn;
+
+use crate::{add_self_loops, segment_counts, split_edge_index};
+use burn::config::Config;
+use burn::module::{Module, Param};
+use burn::nn::{Initializer, Linear, LinearConfig};
+use burn::tensor::backend::Backend;
+use burn::tensor::{Int, Tensor};
+
+/// Configuration to create a [graph convolution](GcnConv) layer using the
+/// [init function](GcnConvConfig::init).
+#[derive(Config, Debug)]
+pub struct GcnConvConfig {
+    /// The size of the input node features.
+    pub d_input: usize,
+    /// The size of the output node features.
+    pub d_output: usize,
+    /// If a bias should be added to the aggregated messages.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize the weights.
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// The graph convolution of [Kipf & Welling](https://arxiv.org/abs/1609.02907).
+///
+/// Each node sums the transformed features of its neighbors and of itself, normalized by the
+/// degrees of both ends of each edge:
+///
+/// `x'_i = sum_{j in N(i) + i} x_j W / sqrt(deg(i) deg(j)) + b`
+///
+/// Should be created with [GcnConvConfig].
+#[derive(Module, Debug)]
+pub struct GcnConv<B: Backend> {
+    /// The transformation of the node features.
+    pub linear: Linear<B>,
+    /// The bias added to the aggregated messages, initialized to zeros.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+}
+
+impl GcnConvConfig {
+    /// Initialize a new [graph convolution](GcnConv) layer.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> GcnConv<B> {
+        let linear = LinearConfig::new(self.d_input, self.d_output)
+            .with_bias(false)
+            .with_initializer(self.initializer.clone())
+            .init(device);
+        let bias = self
+            .bias
+            .then(|| Initializer::Zeros.init([self.d_output], device));
+
+        GcnConv { linear, bias }
+    }
+}
+
+impl<B: Backend> GcnConv<B> {
+    /// Applies the forward pass on the node features.
+    ///
+    /// # Shapes
+    ///
+    /// - node_features: `[num_nodes, d_input]`
+    /// - edge_index: `[2, num_edges]`
+    /// - output: `[num_nodes, d_output]`
+    pub fn forward(
+        &self,
+        node_features: Tensor<B, 2>,
+        edge_index: Tensor<B, 2, Int>,
+    ) -> Tensor<B, 2> {
+        let [num_nodes, _] = node_features.dims();
+        let edge_index = add_self_loops(edge_index, num_nodes);
+        let (sources, targets) = split_edge_index(edge_index);
+
+        // Each node has a self-loop, so the degrees are at least one.
+        let norm = segment_counts(targets.clone(), num_nodes).powf_scalar(-0.5);
+        let weights = norm.clone().select(0, sources.clone()) * norm.select(0, targets.clone());
+        let messages =
+            self.linear.forward(node_features).select(0, sources) * weights.unsqueeze_dim(1);
+        let output = messages.segment_sum(targets, num_nodes);
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn::tensor::Data;
+
+    #[test]
+    fn should_normalize_by_the_degrees() {
+        let device = Default::default();
+        let mut conv = GcnConvConfig::new(1, 1).init::<TestBackend>(&device);
+        conv.linear.weight = Param::from_tensor(Tensor::ones([1, 1], &device));
+
+        let output = conv.forward(
+            Tensor::from_floats([[1.0], [2.0]], &device),
+            Tensor::from_ints([[0], [1]], &device),
+        );
+
+        // The node 1 has a degree of 2, with the messages 2 / 2 and 1 / sqrt(2).
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0 + 1.0 / 2.0f32.sqrt()]]), 5);
+    }
+}
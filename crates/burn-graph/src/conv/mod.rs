@@ -0,0 +1,7 @@
+mod gat;
+mod gcn;
+mod sage;
+
+pub use gat::*;
+pub use gcn::*;
+pub use sage::*;
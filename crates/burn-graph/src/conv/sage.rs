@@ -0,0 +1,126 @@
+use burn_core as burn;
+
+use crate::{segment_counts, split_edge_index};
+use burn::config::Config;
+use burn::module::Module;
+use burn::nn::{Initializer, Linear, LinearConfig};
+use burn::tensor::backend::Backend;
+use burn::tensor::{Int, Tensor};
+
+/// Configuration to create a [GraphSAGE](SageConv) layer using the
+/// [init function](SageConvConfig::init).
+#[derive(Config, Debug)]
+pub struct SageConvConfig {
+    /// The size of the input node features.
+    pub d_input: usize,
+    /// The size of the output node features.
+    pub d_output: usize,
+    /// If a bias should be applied during the transformation of the node features.
+    #[config(default = true)]
+    pub bias: bool,
+    /// If the output features of each node are normalized to a unit L2 norm.
+    #[config(default = false)]
+    pub normalize: bool,
+    /// The type of function used to initialize the weights.
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// The GraphSAGE layer of [Hamilton et al.](https://arxiv.org/abs/1706.02216) with the mean
+/// aggregation.
+///
+/// Each node combines its own features with the mean of the features of its neighbors:
+///
+/// `x'_i = x_i W_1 + b + mean_{j in N(i)} x_j W_2`
+///
+/// Should be created with [SageConvConfig].
+#[derive(Module, Debug)]
+pub struct SageConv<B: Backend> {
+    /// The transformation of the features of each node.
+    pub linear_self: Linear<B>,
+    /// The transformation of the mean features of the neighbors of each node.
+    pub linear_neighbors: Linear<B>,
+    normalize: bool,
+}
+
+impl SageConvConfig {
+    /// Initialize a new [GraphSAGE](SageConv) layer.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> SageConv<B> {
+        let linear = |bias| {
+            LinearConfig::new(self.d_input, self.d_output)
+                .with_bias(bias)
+                .with_initializer(self.initializer.clone())
+                .init(device)
+        };
+
+        SageConv {
+            linear_self: linear(self.bias),
+            linear_neighbors: linear(false),
+            normalize: self.normalize,
+        }
+    }
+}
+
+impl<B: Backend> SageConv<B> {
+    /// Applies the forward pass on the node features.
+    ///
+    /// The nodes without neighbors only use their own features.
+    ///
+    /// # Shapes
+    ///
+    /// - node_features: `[num_nodes, d_input]`
+    /// - edge_index: `[2, num_edges]`
+    /// - output: `[num_nodes, d_output]`
+    pub fn forward(
+        &self,
+        node_features: Tensor<B, 2>,
+        edge_index: Tensor<B, 2, Int>,
+    ) -> Tensor<B, 2> {
+        let [num_nodes, _] = node_features.dims();
+        let (sources, targets) = split_edge_index(edge_index);
+
+        let degrees = segment_counts(targets.clone(), num_nodes).clamp_min(1.0);
+        let neighbors = node_features
+            .clone()
+            .select(0, sources)
+            .segment_sum(targets, num_nodes)
+            / degrees.unsqueeze_dim(1);
+        let output =
+            self.linear_self.forward(node_features) + self.linear_neighbors.forward(neighbors);
+
+        match self.normalize {
+            true => {
+                let norm = output.clone().powf_scalar(2.0).sum_dim(1).sqrt();
+                output / norm.clamp_min(1e-12)
+            }
+            false => output,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn::module::Param;
+    use burn::tensor::Data;
+
+    #[test]
+    fn should_add_the_mean_of_the_neighbors() {
+        let device = Default::default();
+        let mut conv = SageConvConfig::new(1, 1)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        conv.linear_self.weight = Param::from_tensor(Tensor::ones([1, 1], &device));
+        conv.linear_neighbors.weight = Param::from_tensor(Tensor::ones([1, 1], &device));
+
+        let output = conv.forward(
+            Tensor::from_floats([[1.0], [2.0], [4.0]], &device),
+            Tensor::from_ints([[0, 2], [1, 1]], &device),
+        );
+
+        assert_eq!(output.into_data(), Data::from([[1.0], [4.5], [4.0]]));
+    }
+}
@@ -0,0 +1,274 @@
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Data, Int, Shape, Tensor};
+use serde::{Deserialize, Serialize};
+
+/// A graph stored on the CPU, such as an item of a graph dataset.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GraphItem {
+    /// The features of each node.
+    pub node_features: Vec<Vec<f32>>,
+    /// The directed edges, as `(source, target)` node indices.
+    pub edges: Vec<(usize, usize)>,
+    /// The features of each edge, if any.
+    pub edge_features: Option<Vec<Vec<f32>>>,
+}
+
+impl GraphItem {
+    /// The number of nodes of the graph.
+    pub fn num_nodes(&self) -> usize {
+        self.node_features.len()
+    }
+
+    /// The number of edges of the graph.
+    pub fn num_edges(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+/// A batch of graphs on a device, stored as a single graph whose connected components are the
+/// graphs of the batch.
+#[derive(Debug, Clone)]
+pub struct Graph<B: Backend> {
+    /// The node features of shape `[num_nodes, d_node]`.
+    pub node_features: Tensor<B, 2>,
+    /// The edge index of shape `[2, num_edges]`, the first row being the source node of each
+    /// edge and the second row its target node.
+    pub edge_index: Tensor<B, 2, Int>,
+    /// The edge features of shape `[num_edges, d_edge]`, if any.
+    pub edge_features: Option<Tensor<B, 2>>,
+    /// The graph of each node of shape `[num_nodes]`.
+    pub batch: Tensor<B, 1, Int>,
+    /// The number of graphs in the batch.
+    pub num_graphs: usize,
+}
+
+impl<B: Backend> Graph<B> {
+    /// Creates a batch of a single graph.
+    pub fn from_item(item: &GraphItem, device: &B::Device) -> Self {
+        Self::from_items(core::slice::from_ref(item), device)
+    }
+
+    /// Batches the graphs, the node indices of each graph being offset by the number of nodes of
+    /// the previous graphs.
+    ///
+    /// # Panics
+    ///
+    /// If the graphs don't have the same number of features, or if only some of them have edge
+    /// features.
+    pub fn from_items(items: &[GraphItem], device: &B::Device) -> Self {
+        let num_nodes = items.iter().map(GraphItem::num_nodes).sum::<usize>();
+        let num_edges = items.iter().map(GraphItem::num_edges).sum::<usize>();
+        let d_node = features_size(items.iter().flat_map(|item| &item.node_features));
+        let has_edge_features = items.first().map(|item| item.edge_features.is_some());
+
+        let mut node_features = Vec::with_capacity(num_nodes * d_node);
+        let mut sources = Vec::with_capacity(num_edges);
+        let mut targets = Vec::with_capacity(num_edges);
+        let mut edge_features = Vec::new();
+        let mut batch = Vec::with_capacity(num_nodes);
+        let mut offset = 0;
+
+        for (graph, item) in items.iter().enumerate() {
+            for features in item.node_features.iter() {
+                node_features.extend_from_slice(features);
+            }
+            for (source, target) in item.edges.iter() {
+                assert!(
+                    *source < item.num_nodes() && *target < item.num_nodes(),
+                    "The edge ({source}, {target}) connects nodes outside of the graph."
+                );
+                sources.push((source + offset) as i64);
+                targets.push((target + offset) as i64);
+            }
+            assert_eq!(
+                item.edge_features.is_some(),
+                has_edge_features.unwrap_or_default(),
+                "Either all the graphs or none of them should have edge features."
+            );
+            if let Some(features) = item.edge_features.as_ref() {
+                assert_eq!(
+                    features.len(),
+                    item.num_edges(),
+                    "There should be features for each edge."
+                );
+                features
+                    .iter()
+                    .for_each(|features| edge_features.extend_from_slice(features));
+            }
+            batch.extend(core::iter::repeat(graph as i64).take(item.num_nodes()));
+            offset += item.num_nodes();
+        }
+
+        assert_eq!(
+            node_features.len(),
+            num_nodes * d_node,
+            "All the nodes should have the same number of features."
+        );
+
+        sources.extend(targets);
+        let edge_features = has_edge_features.filter(|has| *has).map(|_| {
+            let d_edge = edge_features.len() / num_edges.max(1);
+            Tensor::from_data(
+                Data::new(edge_features, Shape::new([num_edges, d_edge])).convert(),
+                device,
+            )
+        });
+
+        Self {
+            node_features: Tensor::from_data(
+                Data::new(node_features, Shape::new([num_nodes, d_node])).convert(),
+                device,
+            ),
+            edge_index: Tensor::from_data(
+                Data::new(sources, Shape::new([2, num_edges])).convert(),
+                device,
+            ),
+            edge_features,
+            batch: Tensor::from_data(Data::new(batch, Shape::new([num_nodes])).convert(), device),
+            num_graphs: items.len(),
+        }
+    }
+
+    /// The number of nodes of all the graphs.
+    pub fn num_nodes(&self) -> usize {
+        self.node_features.dims()[0]
+    }
+
+    /// The number of edges of all the graphs.
+    pub fn num_edges(&self) -> usize {
+        self.edge_index.dims()[1]
+    }
+}
+
+/// Splits an edge index of shape `[2, num_edges]` into its source and target nodes.
+pub fn split_edge_index<B: Backend>(
+    edge_index: Tensor<B, 2, Int>,
+) -> (Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+    let [_, num_edges] = edge_index.dims();
+    let sources = edge_index.clone().narrow(0, 0, 1).reshape([num_edges]);
+    let targets = edge_index.narrow(0, 1, 1).reshape([num_edges]);
+
+    (sources, targets)
+}
+
+/// Adds an edge from each node to itself to an edge index of shape `[2, num_edges]`.
+pub fn add_self_loops<B: Backend>(
+    edge_index: Tensor<B, 2, Int>,
+    num_nodes: usize,
+) -> Tensor<B, 2, Int> {
+    let nodes = Tensor::arange(0..num_nodes as i64, &edge_index.device())
+        .reshape([1, num_nodes])
+        .repeat(0, 2);
+
+    Tensor::cat(vec![edge_index, nodes], 1)
+}
+
+/// Sums the features of the nodes of each graph.
+///
+/// # Shapes
+///
+/// - node_features: `[num_nodes, d_node]`
+/// - batch: `[num_nodes]`
+/// - output: `[num_graphs, d_node]`
+pub fn global_add_pool<B: Backend>(
+    node_features: Tensor<B, 2>,
+    batch: Tensor<B, 1, Int>,
+    num_graphs: usize,
+) -> Tensor<B, 2> {
+    node_features.segment_sum(batch, num_graphs)
+}
+
+/// Averages the features of the nodes of each graph, the graphs without nodes being zeros.
+///
+/// # Shapes
+///
+/// - node_features: `[num_nodes, d_node]`
+/// - batch: `[num_nodes]`
+/// - output: `[num_graphs, d_node]`
+pub fn global_mean_pool<B: Backend>(
+    node_features: Tensor<B, 2>,
+    batch: Tensor<B, 1, Int>,
+    num_graphs: usize,
+) -> Tensor<B, 2> {
+    let counts = segment_counts(batch.clone(), num_graphs);
+    global_add_pool(node_features, batch, num_graphs) / counts.clamp_min(1.0).unsqueeze_dim(1)
+}
+
+/// The number of elements of each segment.
+pub(crate) fn segment_counts<B: Backend>(
+    segment_ids: Tensor<B, 1, Int>,
+    num_segments: usize,
+) -> Tensor<B, 1> {
+    let [num_elements] = segment_ids.dims();
+    Tensor::ones([num_elements], &segment_ids.device()).segment_sum(segment_ids, num_segments)
+}
+
+fn features_size<'a>(mut features: impl Iterator<Item = &'a Vec<f32>>) -> usize {
+    features.next().map(Vec::len).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    fn triangle() -> GraphItem {
+        GraphItem {
+            node_features: vec![vec![1.0, 0.0], vec![2.0, 1.0], vec![3.0, 2.0]],
+            edges: vec![(0, 1), (1, 2), (2, 0)],
+            edge_features: Some(vec![vec![0.5], vec![1.5], vec![2.5]]),
+        }
+    }
+
+    fn pair() -> GraphItem {
+        GraphItem {
+            node_features: vec![vec![4.0, 3.0], vec![6.0, 5.0]],
+            edges: vec![(1, 0)],
+            edge_features: Some(vec![vec![3.5]]),
+        }
+    }
+
+    #[test]
+    fn should_batch_graphs_with_offset_edges() {
+        let graph = Graph::<TestBackend>::from_items(&[triangle(), pair()], &Default::default());
+
+        assert_eq!(graph.num_nodes(), 5);
+        assert_eq!(graph.num_edges(), 4);
+        assert_eq!(graph.num_graphs, 2);
+        assert_eq!(
+            graph.edge_index.into_data(),
+            Data::from([[0, 1, 2, 4], [1, 2, 0, 3]])
+        );
+        assert_eq!(graph.batch.into_data(), Data::from([0, 0, 0, 1, 1]));
+        assert_eq!(
+            graph.edge_features.unwrap().into_data(),
+            Data::from([[0.5], [1.5], [2.5], [3.5]])
+        );
+    }
+
+    #[test]
+    fn should_pool_the_nodes_of_each_graph() {
+        let graph = Graph::<TestBackend>::from_items(&[triangle(), pair()], &Default::default());
+
+        let sum = global_add_pool(
+            graph.node_features.clone(),
+            graph.batch.clone(),
+            graph.num_graphs,
+        );
+        let mean = global_mean_pool(graph.node_features, graph.batch, graph.num_graphs);
+
+        assert_eq!(sum.into_data(), Data::from([[6.0, 3.0], [10.0, 8.0]]));
+        assert_eq!(mean.into_data(), Data::from([[2.0, 1.0], [5.0, 4.0]]));
+    }
+
+    #[test]
+    fn should_add_self_loops() {
+        let graph = Graph::<TestBackend>::from_item(&pair(), &Default::default());
+
+        let edge_index = add_self_loops(graph.edge_index, graph.num_nodes());
+        let (sources, targets) = split_edge_index(edge_index);
+
+        assert_eq!(sources.into_data(), Data::from([1, 0, 1]));
+        assert_eq!(targets.into_data(), Data::from([0, 0, 1]));
+    }
+}
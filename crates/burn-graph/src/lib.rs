@@ -0,0 +1,27 @@
+#![warn(missing_docs)]
+
+//! Graph neural networks for the Burn framework.
+//!
+//! Graphs are represented by their [node features and edge index](Graph), several small graphs
+//! being [batched](Graph::from_items) into a single disconnected graph. The
+//! [message-passing layers](conv) aggregate the messages sent along the edges with the segment
+//! reductions of the tensors, and the [batchers](GraphBatcher) load graph datasets or
+//! [sample the neighborhoods](NeighborSampler) of the nodes of a large graph.
+
+#[macro_use]
+extern crate derive_new;
+
+/// Message-passing layers.
+pub mod conv;
+
+mod graph;
+mod sampler;
+
+pub use graph::*;
+pub use sampler::*;
+
+#[cfg(test)]
+pub(crate) type TestBackend = burn_ndarray::NdArray<f32>;
+
+#[cfg(test)]
+pub(crate) type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
@@ -0,0 +1,292 @@
+use crate::{Graph, GraphItem};
+use burn_core::data::dataloader::batcher::Batcher;
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Data, Int, Shape, Tensor};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A batch of graphs with their class targets.
+#[derive(new, Debug, Clone)]
+pub struct GraphBatch<B: Backend> {
+    /// The batched graphs.
+    pub graph: Graph<B>,
+    /// The class targets of shape `[batch_size]`.
+    ///
+    /// With the [neighbor sampler](NeighborSampler), the targets are the classes of the seed
+    /// nodes, which are the first `batch_size` nodes of the graph.
+    pub targets: Tensor<B, 1, Int>,
+}
+
+/// Batches the graphs of a graph dataset, the items being either graphs or graphs with their
+/// class.
+#[derive(new, Debug, Clone)]
+pub struct GraphBatcher<B: Backend> {
+    device: B::Device,
+}
+
+impl<B: Backend> Batcher<GraphItem, Graph<B>> for GraphBatcher<B> {
+    fn batch(&self, items: Vec<GraphItem>) -> Graph<B> {
+        Graph::from_items(&items, &self.device)
+    }
+}
+
+impl<B: Backend> Batcher<(GraphItem, usize), GraphBatch<B>> for GraphBatcher<B> {
+    fn batch(&self, items: Vec<(GraphItem, usize)>) -> GraphBatch<B> {
+        let (graphs, targets): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+
+        GraphBatch::new(
+            Graph::from_items(&graphs, &self.device),
+            targets_tensor(&targets, &self.device),
+        )
+    }
+}
+
+/// The subgraph sampled around seed nodes by a [neighbor sampler](NeighborSampler).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampledSubgraph {
+    /// The subgraph, whose first nodes are the seed nodes.
+    pub graph: GraphItem,
+    /// The index in the full graph of each node of the subgraph.
+    pub nodes: Vec<usize>,
+    /// The number of distinct seed nodes.
+    pub num_seeds: usize,
+}
+
+/// Samples the neighborhoods of batches of nodes of a large graph, so node classification
+/// models can be trained with mini-batches as in [GraphSAGE](https://arxiv.org/abs/1706.02216).
+///
+/// The items are the indices of the seed nodes, e.g. an
+/// [in-memory dataset](burn_core::data::dataset::InMemDataset) of the training nodes. For each
+/// hop, up to `fanouts[hop]` incoming edges of the nodes reached by the previous hop are sampled,
+/// so a model with as many message-passing layers as hops sees the same neighborhood for the
+/// seed nodes as on the full graph, up to the sampling.
+#[derive(Clone)]
+pub struct NeighborSampler<B: Backend> {
+    graph: Arc<GraphItem>,
+    labels: Arc<Vec<usize>>,
+    incoming: Arc<Vec<Vec<usize>>>,
+    fanouts: Vec<usize>,
+    rng: Arc<Mutex<StdRng>>,
+    device: B::Device,
+}
+
+impl<B: Backend> NeighborSampler<B> {
+    /// Creates a sampler of the given graph, with the class of each node and the maximum number
+    /// of neighbors sampled for each hop.
+    ///
+    /// # Panics
+    ///
+    /// If there isn't a label for each node.
+    pub fn new(
+        graph: GraphItem,
+        labels: Vec<usize>,
+        fanouts: Vec<usize>,
+        device: B::Device,
+    ) -> Self {
+        assert_eq!(
+            labels.len(),
+            graph.num_nodes(),
+            "There should be a label for each node."
+        );
+
+        let mut incoming = vec![Vec::new(); graph.num_nodes()];
+        for (edge, (_, target)) in graph.edges.iter().enumerate() {
+            incoming[*target].push(edge);
+        }
+
+        Self {
+            graph: Arc::new(graph),
+            labels: Arc::new(labels),
+            incoming: Arc::new(incoming),
+            fanouts,
+            rng: Arc::new(Mutex::new(StdRng::from_entropy())),
+            device,
+        }
+    }
+
+    /// Sample the neighbors with the given seed.
+    ///
+    /// The clones of the sampler, e.g. in the workers of a data loader, share the same random
+    /// number generator.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Arc::new(Mutex::new(StdRng::seed_from_u64(seed)));
+        self
+    }
+
+    /// Samples the subgraph of the neighborhoods of the seed nodes.
+    pub fn sample(&self, seeds: &[usize]) -> SampledSubgraph {
+        let mut rng = self.rng.lock().unwrap();
+        let mut local = HashMap::new();
+        let mut nodes = Vec::new();
+
+        for seed in seeds {
+            local.entry(*seed).or_insert_with(|| {
+                nodes.push(*seed);
+                nodes.len() - 1
+            });
+        }
+
+        let num_seeds = nodes.len();
+        let mut frontier = nodes.clone();
+        let mut edges = Vec::new();
+        let mut edge_ids = Vec::new();
+
+        for fanout in self.fanouts.iter() {
+            let mut next = Vec::new();
+
+            for node in frontier {
+                let target = local[&node];
+                let incoming = &self.incoming[node];
+                let sampled: Vec<usize> = match incoming.len() > *fanout {
+                    true => incoming
+                        .choose_multiple(&mut *rng, *fanout)
+                        .copied()
+                        .collect(),
+                    false => incoming.clone(),
+                };
+
+                for edge in sampled {
+                    let (source, _) = self.graph.edges[edge];
+                    let source = *local.entry(source).or_insert_with(|| {
+                        nodes.push(source);
+                        next.push(source);
+                        nodes.len() - 1
+                    });
+                    edges.push((source, target));
+                    edge_ids.push(edge);
+                }
+            }
+
+            frontier = next;
+        }
+
+        let graph = GraphItem {
+            node_features: nodes
+                .iter()
+                .map(|node| self.graph.node_features[*node].clone())
+                .collect(),
+            edges,
+            edge_features: self.graph.edge_features.as_ref().map(|features| {
+                edge_ids
+                    .iter()
+                    .map(|edge| features[*edge].clone())
+                    .collect()
+            }),
+        };
+
+        SampledSubgraph {
+            graph,
+            nodes,
+            num_seeds,
+        }
+    }
+}
+
+impl<B: Backend> Batcher<usize, GraphBatch<B>> for NeighborSampler<B> {
+    fn batch(&self, seeds: Vec<usize>) -> GraphBatch<B> {
+        let subgraph = self.sample(&seeds);
+        let targets: Vec<usize> = subgraph.nodes[..subgraph.num_seeds]
+            .iter()
+            .map(|node| self.labels[*node])
+            .collect();
+
+        GraphBatch::new(
+            Graph::from_item(&subgraph.graph, &self.device),
+            targets_tensor(&targets, &self.device),
+        )
+    }
+}
+
+fn targets_tensor<B: Backend>(targets: &[usize], device: &B::Device) -> Tensor<B, 1, Int> {
+    let targets: Vec<i64> = targets.iter().map(|target| *target as i64).collect();
+    let shape = Shape::new([targets.len()]);
+
+    Tensor::from_data(Data::new(targets, shape).convert(), device)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    /// The nodes 1, 2 and 3 point to the node 0, and the node 4 points to the node 1.
+    fn star() -> GraphItem {
+        GraphItem {
+            node_features: (0..5).map(|node| vec![node as f32]).collect(),
+            edges: vec![(1, 0), (2, 0), (3, 0), (4, 1)],
+            edge_features: Some((0..4).map(|edge| vec![edge as f32 * 10.0]).collect()),
+        }
+    }
+
+    #[test]
+    fn should_sample_up_to_the_fanout_of_each_hop() {
+        let sampler =
+            NeighborSampler::<TestBackend>::new(star(), vec![0; 5], vec![2, 1], Default::default())
+                .with_seed(42);
+
+        let subgraph = sampler.sample(&[0]);
+
+        assert_eq!(subgraph.num_seeds, 1);
+        assert_eq!(subgraph.nodes[0], 0);
+        assert_eq!(subgraph.graph.node_features[0], vec![0.0]);
+        // Two neighbors of the seed, and the neighbor of the node 1 if it was sampled.
+        let hop_1 = subgraph
+            .graph
+            .edges
+            .iter()
+            .filter(|edge| edge.1 == 0)
+            .count();
+        assert_eq!(hop_1, 2);
+        assert_eq!(
+            subgraph.nodes.len(),
+            3 + subgraph.nodes[1..3].contains(&1) as usize
+        );
+        for ((source, target), features) in subgraph
+            .graph
+            .edges
+            .iter()
+            .zip(subgraph.graph.edge_features.as_ref().unwrap())
+        {
+            let edge = star()
+                .edges
+                .iter()
+                .position(|edge| *edge == (subgraph.nodes[*source], subgraph.nodes[*target]))
+                .unwrap();
+            assert_eq!(features, &vec![edge as f32 * 10.0]);
+        }
+    }
+
+    #[test]
+    fn should_batch_the_seeds_first_with_their_labels() {
+        let sampler = NeighborSampler::<TestBackend>::new(
+            star(),
+            vec![3, 1, 4, 1, 5],
+            vec![3, 3],
+            Default::default(),
+        );
+
+        let batch = sampler.batch(vec![1, 0]);
+
+        assert_eq!(batch.targets.into_data(), Data::from([1, 3]));
+        assert_eq!(batch.graph.num_nodes(), 5);
+        assert_eq!(batch.graph.num_edges(), 4);
+        assert_eq!(
+            batch.graph.node_features.narrow(0, 0, 2).into_data(),
+            Data::from([[1.0], [0.0]])
+        );
+    }
+
+    #[test]
+    fn should_batch_labeled_graphs() {
+        let batcher = GraphBatcher::<TestBackend>::new(Default::default());
+
+        let batch: GraphBatch<TestBackend> = batcher.batch(vec![(star(), 2), (star(), 0)]);
+
+        assert_eq!(batch.graph.num_graphs, 2);
+        assert_eq!(batch.graph.num_nodes(), 10);
+        assert_eq!(batch.targets.into_data(), Data::from([2, 0]));
+    }
+}
@@ -1,6 +1,6 @@
 use super::{BurnImports, Scope, Type};
 use crate::burn::{
-    node::{Node, NodeCodegen},
+    node::{unsupported::UnsupportedNode, Node, NodeCodegen},
     TensorKind, TensorType,
 };
 use burn::record::{
@@ -206,6 +206,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
         let codegen_imports = self.imports.codegen();
         let codegen_struct = self.codegen_struct();
+        let codegen_unsupported = self.codegen_unsupported();
         let codegen_new = self.codegen_new();
         let codegen_forward = self.codegen_forward();
 
@@ -239,6 +240,8 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             #codegen_struct
             #maybe_blank
 
+            #codegen_unsupported
+
             #codegen_default
 
             impl<B: Backend> Model<B> {
@@ -251,6 +254,61 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         }
     }
 
+    /// Generate a placeholder implementation of the `UnsupportedOps` trait for the model, to be
+    /// completed by the user and included next to the generated model.
+    ///
+    /// Returns `None` when all the nodes of the graph are supported.
+    pub fn codegen_unsupported_stub(&self) -> Option<TokenStream> {
+        let methods = self
+            .unsupported_nodes()
+            .map(|node| node.stub_method())
+            .collect::<Vec<_>>();
+
+        if methods.is_empty() {
+            return None;
+        }
+
+        Some(quote! {
+            impl<B: Backend> UnsupportedOps<B> for Model<B> {
+                #(#methods)*
+            }
+        })
+    }
+
+    fn unsupported_nodes(&self) -> impl Iterator<Item = &UnsupportedNode> {
+        self.nodes.iter().filter_map(|node| match node {
+            Node::Unsupported(node) => Some(node),
+            _ => None,
+        })
+    }
+
+    fn codegen_unsupported(&self) -> TokenStream {
+        let methods = self
+            .unsupported_nodes()
+            .map(|node| node.trait_method())
+            .collect::<Vec<_>>();
+
+        if methods.is_empty() {
+            return quote! {};
+        }
+
+        let maybe_blank = match self.blank_spaces {
+            true => quote! {
+                _blank_!();
+            },
+            false => quote! {},
+        };
+
+        quote! {
+            /// Operations of the ONNX model that aren't supported by burn-import, to be implemented
+            /// for the model.
+            pub trait UnsupportedOps<B: Backend> {
+                #(#methods)*
+            }
+            #maybe_blank
+        }
+    }
+
     fn register_imports(&mut self) {
         // Register imports from nodes
         self.nodes
@@ -508,12 +566,21 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             .map(|(index, node)| node.forward(&mut self.scope, index))
             .for_each(|code| body.extend(code));
 
+        // The forward pass calls the unsupported nodes on the model itself.
+        let where_clause = match self.unsupported_nodes().next() {
+            Some(_) => quote! {
+                where
+                    Self: UnsupportedOps<B>,
+            },
+            None => quote! {},
+        };
+
         // TODO Return the result without a `let` binding from a block,
         // otherwise let_and_return error will be triggered by clippy.
         // For now, we just disable the warning.
         quote! {
             #[allow(clippy::let_and_return, clippy::approx_constant)]
-            pub fn forward(&self, #input_def) -> #output_type_def {
+            pub fn forward(&self, #input_def) -> #output_type_def #where_clause {
                 #body
 
                 #output_return_def
@@ -8,7 +8,7 @@ use super::{
     max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, prelu::PReluNode,
     random_normal::RandomNormalNode, random_uniform::RandomUniformNode, range::RangeNode,
     reshape::ReshapeNode, resize::ResizeNode, slice::SliceNode, squeeze::SqueezeNode, sum::SumNode,
-    unary::UnaryNode, unsqueeze::UnsqueezeNode,
+    unary::UnaryNode, unsqueeze::UnsqueezeNode, unsupported::UnsupportedNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::backend::NdArray;
@@ -108,6 +108,7 @@ pub enum Node<PS: PrecisionSettings> {
     Sum(SumNode),
     Unary(UnaryNode),
     Unsqueeze(UnsqueezeNode),
+    Unsupported(UnsupportedNode),
     Where(WhereNode),
     RandomUniform(RandomUniformNode),
     RandomNormal(RandomNormalNode),
@@ -147,6 +148,7 @@ macro_rules! match_all {
             Node::Sum(node) => $func(node),
             Node::Unary(node) => $func(node),
             Node::Unsqueeze(node) => $func(node),
+            Node::Unsupported(node) => $func(node),
             Node::Where(node) => $func(node),
             Node::RandomNormal(node) => $func(node),
             Node::RandomUniform(node) => $func(node),
@@ -196,6 +198,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Sum(_) => "add",
             Node::Unary(unary) => unary.kind.as_str(),
             Node::Unsqueeze(_) => "unsqueeze",
+            Node::Unsupported(_) => "unsupported",
             Node::Where(_) => "where",
             Node::RandomNormal(_) => "random_normal",
             Node::RandomUniform(_) => "random_uniform",
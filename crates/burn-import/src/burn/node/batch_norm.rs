@@ -96,6 +96,8 @@ macro_rules! batch_norm_serialize {
                 ParamId::new(),
                 Tensor::from_data($self.running_var.clone().convert(), &device),
             ),
+            running_mean_full: None,
+            running_var_full: None,
             epsilon: ConstantRecord::new(),
             momentum: ConstantRecord::new(),
         }
@@ -1,8 +1,8 @@
 use super::{Node, NodeCodegen, SerializationBackend};
 use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
 use burn::{
-    module::{Param, ParamId},
-    nn::{LinearConfig, LinearRecord},
+    module::{Module, Param, ParamId},
+    nn::{LinearConfig, LinearRecord, QLinear, WeightQuantization},
     record::{PrecisionSettings, Record},
     tensor::{DataSerialize, Tensor},
 };
@@ -18,6 +18,8 @@ pub struct LinearNode<PS: PrecisionSettings> {
     pub data_weights: DataSerialize<PS::FloatElem>,
     pub data_bias: Option<DataSerialize<PS::FloatElem>>,
     pub config: LinearConfig,
+    /// The quantization of the weights, imported as a [quantized linear](QLinear) layer.
+    pub quantization: Option<WeightQuantization>,
 }
 
 impl<PS: PrecisionSettings> LinearNode<PS> {
@@ -41,8 +43,18 @@ impl<PS: PrecisionSettings> LinearNode<PS> {
             data_weights,
             data_bias,
             config,
+            quantization: None,
         }
     }
+
+    /// Import the layer as a quantized linear layer, requantizing the weights at import time.
+    pub fn with_quantization(mut self, quantization: WeightQuantization) -> Self {
+        self.field.ty = quote! {
+            QLinear<B>
+        };
+        self.quantization = Some(quantization);
+        self
+    }
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for LinearNode<PS> {
@@ -62,10 +74,27 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for LinearNode<PS> {
         let d_input = self.config.d_input.to_tokens();
         let d_output = self.config.d_output.to_tokens();
         let bias = self.config.bias;
-        let tokens = quote! {
-            let #name = LinearConfig::new(#d_input, #d_output)
-                .with_bias(#bias)
-                .init(device);
+        let tokens = match &self.quantization {
+            Some(quantization) => {
+                let quantization = match quantization {
+                    WeightQuantization::Int8 => quote! { WeightQuantization::Int8 },
+                    WeightQuantization::Int4 => quote! { WeightQuantization::Int4 },
+                };
+
+                quote! {
+                    let #name = QLinear::from_linear(
+                        LinearConfig::new(#d_input, #d_output)
+                            .with_bias(#bias)
+                            .init(device),
+                        #quantization,
+                    );
+                }
+            }
+            None => quote! {
+                let #name = LinearConfig::new(#d_input, #d_output)
+                    .with_bias(#bias)
+                    .init(device);
+            },
         };
 
         Some(tokens)
@@ -86,6 +115,17 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for LinearNode<PS> {
             }),
         };
 
+        if let Some(quantization) = &self.quantization {
+            let linear = self
+                .config
+                .init::<SerializationBackend>(&device)
+                .load_record(record);
+            let record = QLinear::from_linear(linear, quantization.clone()).into_record();
+            let item = Record::into_item::<PS>(record);
+
+            return item.serialize(serializer);
+        }
+
         let item = Record::into_item::<PS>(record);
         item.serialize(serializer)
     }
@@ -101,7 +141,12 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for LinearNode<PS> {
     }
 
     fn register_imports(&self, imports: &mut BurnImports) {
-        imports.register("burn::nn::Linear");
+        if self.quantization.is_some() {
+            imports.register("burn::nn::QLinear");
+            imports.register("burn::nn::WeightQuantization");
+        } else {
+            imports.register("burn::nn::Linear");
+        }
         imports.register("burn::nn::LinearConfig");
     }
 
@@ -170,4 +215,66 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_quantized() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(
+            LinearNode::new(
+                "linear",
+                TensorType::new_float("input", 4),
+                TensorType::new_float("output", 4),
+                Data::from([2.]).serialize(),
+                None,
+                LinearConfig::new(128, 128),
+            )
+            .with_quantization(WeightQuantization::Int8),
+        );
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::LinearConfig;
+            use burn::nn::QLinear;
+            use burn::nn::WeightQuantization;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                linear: QLinear<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let linear = QLinear::from_linear(
+                        LinearConfig::new(128, 128)
+                            .with_bias(true)
+                            .init(device),
+                        WeightQuantization::Int8,
+                    );
+
+                    Self {
+                        linear,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.linear.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -33,6 +33,7 @@ pub(crate) mod squeeze;
 pub(crate) mod sum;
 pub(crate) mod unary;
 pub(crate) mod unsqueeze;
+pub(crate) mod unsupported;
 pub(crate) use base::*;
 
 #[cfg(test)]
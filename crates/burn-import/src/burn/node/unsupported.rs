@@ -0,0 +1,223 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorKind, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+/// Node that burn-import doesn't support, delegated to a method of the generated
+/// `UnsupportedOps` trait to be implemented for the model.
+#[derive(Debug, Clone, new)]
+pub struct UnsupportedNode {
+    pub name: String,
+    pub op_type: String,
+    pub inputs: Vec<Type>,
+    pub outputs: Vec<Type>,
+}
+
+impl UnsupportedNode {
+    fn method(&self) -> Ident {
+        Ident::new(&self.name, Span::call_site())
+    }
+
+    fn output_type(&self) -> TokenStream {
+        let outputs = self.outputs.iter().map(Type::ty);
+
+        match self.outputs.len() {
+            1 => quote! { #(#outputs)* },
+            _ => quote! { (#(#outputs),*) },
+        }
+    }
+
+    fn signature(&self) -> TokenStream {
+        let method = self.method();
+        let output = self.output_type();
+        let inputs = self.inputs.iter().enumerate().map(|(i, input)| {
+            let name = Ident::new(&format!("input{}", i + 1), Span::call_site());
+            let ty = input.ty();
+
+            quote! { #name: #ty }
+        });
+
+        quote! {
+            fn #method(&self, #(#inputs),*) -> #output
+        }
+    }
+
+    /// The declaration of the node in the `UnsupportedOps` trait.
+    pub fn trait_method(&self) -> TokenStream {
+        let doc = format!("The ONNX `{}` node `{}`.", self.op_type, self.name);
+        let signature = self.signature();
+
+        quote! {
+            #[doc = #doc]
+            #signature;
+        }
+    }
+
+    /// A placeholder implementation of the node, to be filled by the user.
+    pub fn stub_method(&self) -> TokenStream {
+        let message = format!("Implement the ONNX `{}` node", self.op_type);
+        let signature = self.signature();
+
+        quote! {
+            #[allow(unused_variables)]
+            #signature {
+                todo!(#message)
+            }
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for UnsupportedNode {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs.clone()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let method = self.method();
+        let inputs = self.inputs.iter().map(|input| match input {
+            Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+            _ => {
+                let name = input.name();
+                quote! { #name }
+            }
+        });
+        let outputs = self.outputs.iter().map(Type::name);
+        let outputs = match self.outputs.len() {
+            1 => quote! { #(#outputs)* },
+            _ => quote! { (#(#outputs),*) },
+        };
+
+        quote! {
+            let #outputs = self.#method(#(#inputs),*);
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        for ty in self.inputs.iter().chain(self.outputs.iter()) {
+            match ty {
+                Type::Tensor(TensorType {
+                    kind: TensorKind::Int,
+                    ..
+                }) => imports.register("burn::tensor::Int"),
+                Type::Tensor(TensorType {
+                    kind: TensorKind::Bool,
+                    ..
+                }) => imports.register("burn::tensor::Bool"),
+                _ => {}
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Unsupported(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{test::assert_tokens, unary::UnaryNode},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UnsupportedNode::new(
+            "topk1".to_string(),
+            "TopK".to_string(),
+            vec![Type::Tensor(TensorType::new_float("tensor1", 2))],
+            vec![
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                Type::Tensor(TensorType::new_int("tensor3", 2)),
+            ],
+        ));
+        graph.register(UnaryNode::relu(
+            Type::Tensor(TensorType::new_float("tensor2", 2)),
+            Type::Tensor(TensorType::new_float("tensor4", 2)),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["tensor4".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            /// Operations of the ONNX model that aren't supported by burn-import, to be implemented
+            /// for the model.
+            pub trait UnsupportedOps<B: Backend> {
+                #[doc = "The ONNX `TopK` node `topk1`."]
+                fn topk1(&self, input1: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2, Int>);
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2, Int>)
+                where
+                    Self: UnsupportedOps<B>,
+                {
+                    let (tensor2, tensor3) = self.topk1(tensor1);
+                    let tensor4 = burn::tensor::activation::relu(tensor2);
+
+                    (tensor4, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_stub() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UnsupportedNode::new(
+            "nonzero1".to_string(),
+            "NonZero".to_string(),
+            vec![Type::Tensor(TensorType::new_float("tensor1", 2))],
+            vec![Type::Tensor(TensorType::new_int("tensor2", 2))],
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            impl<B: Backend> UnsupportedOps<B> for Model<B> {
+                #[allow(unused_variables)]
+                fn nonzero1(&self, input1: Tensor<B, 2>) -> Tensor<B, 2, Int> {
+                    todo!("Implement the ONNX `NonZero` node")
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen_unsupported_stub().unwrap(), expected);
+    }
+}
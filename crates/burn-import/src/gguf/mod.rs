@@ -0,0 +1,4 @@
+mod reader;
+mod recorder;
+pub use crate::pytorch::LoadArgs;
+pub use recorder::GgufFileRecorder;
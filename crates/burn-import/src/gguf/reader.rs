@@ -0,0 +1,103 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use crate::pytorch::{
+    error::Error,
+    reader::{deserialize_tensors, select_top_level_key, CandleTensor, ImportedTensor},
+};
+
+use burn::{
+    record::{
+        serde::{
+            data::{NestedValue, Serializable},
+            error,
+            ser::Serializer,
+        },
+        PrecisionSettings,
+    },
+    tensor::backend::Backend,
+};
+
+use candle_core::quantized::gguf_file::{Content, TensorInfo};
+use regex::Regex;
+use serde::de::DeserializeOwned;
+
+/// Deserializes a GGUF file, dequantizing its tensors.
+///
+/// Only the header of the file is read at first, then each tensor is read and dequantized when
+/// it is converted.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path of the file to read.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `top_level_key` - An optional key under which the tensors to load are nested.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    top_level_key: Option<&str>,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    let mut reader = BufReader::new(File::open(path)?);
+    let content = Content::read(&mut reader)?;
+    let reader = RefCell::new(reader);
+
+    let tensors: HashMap<String, GgufTensor> = content
+        .tensor_infos
+        .iter()
+        .map(|(key, info)| {
+            let tensor = GgufTensor {
+                content: &content,
+                reader: &reader,
+                name: key.clone(),
+                info,
+            };
+            (key.clone(), tensor)
+        })
+        .collect();
+    let tensors = select_top_level_key(tensors, top_level_key);
+
+    deserialize_tensors::<PS, D, B, _>(tensors, key_remap, debug)
+}
+
+/// A tensor of a GGUF file, only read when it is serialized.
+struct GgufTensor<'a> {
+    content: &'a Content,
+    reader: &'a RefCell<BufReader<File>>,
+    name: String,
+    info: &'a TensorInfo,
+}
+
+impl Serializable for GgufTensor<'_> {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        let device = candle_core::Device::Cpu;
+        let tensor = self
+            .content
+            .tensor(&mut *self.reader.borrow_mut(), &self.name, &device)
+            .and_then(|qtensor| qtensor.dequantize(&device))
+            .map_err(|err| error::Error::Other(format!("Candle GGUF error: {err}")))?;
+
+        CandleTensor(tensor).serialize::<PS>(serializer)
+    }
+}
+
+impl ImportedTensor for GgufTensor<'_> {
+    fn debug_shape(&self) -> Vec<usize> {
+        self.info.shape.dims().to_vec()
+    }
+
+    fn debug_dtype(&self) -> String {
+        format!("{:?}", self.info.ggml_dtype)
+    }
+}
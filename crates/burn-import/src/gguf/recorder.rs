@@ -0,0 +1,118 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::reader::from_file;
+use crate::pytorch::LoadArgs;
+
+/// A recorder that loads GGUF files (`.gguf`), the format of the quantized models of llama.cpp,
+/// into Burn modules.
+///
+/// Burn tensors aren't quantized, so the tensors are dequantized to floats when they are loaded.
+/// The shapes of the tensors are the ones of PyTorch, e.g. `[d_output, d_input]` for the weights
+/// of linear layers, and their names are the ones of llama.cpp, e.g. `blk.0.attn_q.weight`, which
+/// usually have to be [remapped](LoadArgs::with_key_remap) to the names of the fields of the
+/// module.
+///
+/// The recorder shares the [LoadArgs] of the [PyTorch recorder](crate::pytorch::PyTorchFileRecorder).
+#[derive(new, Debug, Default, Clone)]
+pub struct GgufFileRecorder<PS: PrecisionSettings> {
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for GgufFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = LoadArgs;
+
+    fn save_item<I: Serialize>(
+        &self,
+        _item: I,
+        _file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        Err(RecorderError::Unknown(
+            "The GGUF recorder can only load records".into(),
+        ))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, _file: Self::LoadArgs) -> Result<I, RecorderError> {
+        Err(RecorderError::Unknown(
+            "The GGUF recorder can only load records".into(),
+        ))
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item = from_file::<PS, R::Item<Self::Settings>, B>(
+            &args.file,
+            args.key_remap,
+            args.top_level_key.as_deref(),
+            args.debug,
+        )?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::backend::NdArray;
+    use burn::module::Module;
+    use burn::nn::Linear;
+    use burn::record::FullPrecisionSettings;
+    use candle_core::quantized::{gguf_file, GgmlDType, QTensor};
+
+    type TestBackend = NdArray<f32>;
+
+    #[derive(Module, Debug)]
+    struct Net<B: Backend> {
+        fc: Linear<B>,
+    }
+
+    #[test]
+    fn should_load_dequantized_linear_weights() {
+        let device = candle_core::Device::Cpu;
+        // The weights of linear layers have the PyTorch layout [d_output, d_input].
+        let weight = candle_core::Tensor::arange(0f32, 64.0, &device)
+            .unwrap()
+            .reshape((2, 32))
+            .unwrap();
+        let bias = candle_core::Tensor::new(&[0.5f32, -0.5], &device).unwrap();
+        let weight = QTensor::quantize(&weight, GgmlDType::F32).unwrap();
+        let bias = QTensor::quantize(&bias, GgmlDType::F32).unwrap();
+        let path = std::env::temp_dir().join("burn_import_should_load_gguf_weights.gguf");
+        let mut file = std::fs::File::create(&path).unwrap();
+        gguf_file::write(
+            &mut file,
+            &[],
+            &[("blk.fc.weight", &weight), ("blk.fc.bias", &bias)],
+        )
+        .unwrap();
+
+        let args = LoadArgs::new(path.clone()).with_top_level_key("blk");
+        let record: NetRecord<TestBackend> = GgufFileRecorder::<FullPrecisionSettings>::default()
+            .load(args, &Default::default())
+            .unwrap();
+
+        assert_eq!(record.fc.weight.val().dims(), [32, 2]);
+        assert_eq!(
+            record.fc.weight.val().transpose().into_data().value,
+            (0..64).map(|value| value as f32).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            record.fc.bias.unwrap().val().into_data().value,
+            vec![0.5, -0.5]
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -29,5 +29,13 @@ pub mod burn;
 #[cfg(feature = "pytorch")]
 pub mod pytorch;
 
+/// The safetensors module for recorder.
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
+/// The GGUF module for recorder.
+#[cfg(feature = "gguf")]
+pub mod gguf;
+
 mod formatter;
 pub use formatter::*;
@@ -0,0 +1,109 @@
+use std::fmt;
+
+use super::ir::{ArgType, Argument, Node};
+
+/// An ONNX node that can't be converted to Burn.
+#[derive(Debug, Clone)]
+pub struct UnsupportedOp {
+    /// The position of the node in the graph, after the nodes were remapped and coalesced.
+    pub position: usize,
+    /// The name of the node in the generated code.
+    pub name: String,
+    /// The ONNX operator type of the node.
+    pub op_type: String,
+    inputs: Vec<Argument>,
+    outputs: Vec<Argument>,
+}
+
+impl UnsupportedOp {
+    pub(crate) fn new(position: usize, node: &Node) -> Self {
+        Self {
+            position,
+            name: node.name.clone(),
+            op_type: node.node_type.to_string(),
+            inputs: node.inputs.clone(),
+            outputs: node.outputs.clone(),
+        }
+    }
+}
+
+/// Report of all the nodes of an ONNX graph that can't be converted to Burn.
+///
+/// The output types of unsupported nodes aren't inferred, they are copied from their first
+/// input, so every node after them may be imported with wrong types as well.
+#[derive(Debug, Clone, Default)]
+pub struct UnsupportedOpsReport {
+    /// The unsupported nodes, in the order of the graph.
+    pub ops: Vec<UnsupportedOp>,
+}
+
+impl UnsupportedOpsReport {
+    /// Returns true if all the nodes of the graph are supported.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// The distinct operator types that aren't supported.
+    pub fn op_types(&self) -> Vec<&str> {
+        let mut op_types = Vec::new();
+        for op in self.ops.iter() {
+            if !op_types.contains(&op.op_type.as_str()) {
+                op_types.push(op.op_type.as_str());
+            }
+        }
+        op_types
+    }
+}
+
+impl fmt::Display for UnsupportedOpsReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Unsupported ops: {:?} ({} nodes)",
+            self.op_types(),
+            self.ops.len()
+        )?;
+
+        for op in self.ops.iter() {
+            writeln!(f, "  #{} {} ({})", op.position, op.name, op.op_type)?;
+            writeln!(f, "      inputs:  {}", format_args(&op.inputs))?;
+            writeln!(
+                f,
+                "      outputs: {} (not inferred, copied from the first input)",
+                format_args(&op.outputs)
+            )?;
+        }
+
+        write!(
+            f,
+            "Enable `ModelGen::partial_import` to generate the supported nodes and implement the \
+             unsupported ones with the generated `UnsupportedOps` trait."
+        )
+    }
+}
+
+fn format_args(args: &[Argument]) -> String {
+    let args = args
+        .iter()
+        .map(|arg| {
+            let ty = match &arg.ty {
+                ArgType::Scalar(elem_type) => format!("{elem_type:?}"),
+                ArgType::Shape(dim) => format!("Shape[{dim}]"),
+                ArgType::Tensor(tensor) => match &tensor.shape {
+                    Some(shape) if !shape.is_empty() => {
+                        format!("Tensor<{:?}, {}> {:?}", tensor.elem_type, tensor.dim, shape)
+                    }
+                    _ => format!("Tensor<{:?}, {}>", tensor.elem_type, tensor.dim),
+                },
+            };
+            let constant = match arg.value {
+                Some(_) => " (constant)",
+                None => "",
+            };
+
+            format!("{}: {ty}{constant}", arg.name)
+        })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", args.join(", "))
+}
@@ -8,9 +8,10 @@ use crate::onnx::node_remap::remap_node_type;
 
 use super::{
     coalesce::coalesce,
-    ir::{Data, OnnxGraph, TensorType},
+    ir::{AttributeValue, Data, OnnxGraph, TensorType},
     proto_conversion::convert_node_proto,
     protos::{ModelProto, NodeProto, TensorProto, ValueInfoProto},
+    qdq::{dequantize_constant, is_activation_qdq},
 };
 
 use super::dim_inference::dim_inference;
@@ -195,6 +196,8 @@ pub(crate) struct OnnxGraphBuilder {
     constants_types: HashSet<NodeType>,
     /// Map from identity node output names to indices of identity nodes
     identity_idx: HashMap<String, usize>,
+    /// Names of the dequantized weights whose quantization maps onto burn's int8 quantization
+    quantized_weights: HashSet<String>,
     node_name_counter: HashMap<NodeType, usize>,
 }
 
@@ -213,9 +216,15 @@ impl OnnxGraphBuilder {
         while let Some(node_proto) = node_iter.next() {
             let mut node = convert_node_proto(node_proto, &graph_data);
 
+            if self.fold_dequantize(&node, &mut graph_data) {
+                continue;
+            }
+
             remap_node_type(&mut node);
             self.handle_node_renaming(&mut node);
+            self.handle_activation_qdq(&mut node);
             coalesce(&mut node, &mut node_iter, &graph_data);
+            self.handle_quantized_weights(&mut node);
             self.handle_identity(&mut node, &graph_data);
             self.check_constants(&mut node, &graph_data);
             // NOTE: potential start of custom functions
@@ -306,20 +315,82 @@ impl OnnxGraphBuilder {
     }
 
     fn handle_identity(&mut self, node: &mut Node, graph_data: &GraphData) {
+        // Remap the inputs of identity nodes too, so chained identity nodes are all removed
+        node.inputs.iter_mut().for_each(|x| {
+            if let Some(identity_idx) = self.identity_idx.get(&x.name) {
+                let input_name = &graph_data.processed_nodes[*identity_idx].inputs[0].name;
+
+                x.name.clone_from(input_name);
+            }
+        });
+
         if node.node_type == NodeType::Identity && node.inputs[0].value.is_none() {
             log::debug!("\nfound identity node:\n{:?}\n", &node);
             let i = graph_data.get_current_index();
             //map the output name to check for pass through values
             self.identity_idx.insert(format!("{}_out1", &node.name), i);
             self.nodes_to_remove.insert(i);
+        }
+    }
+
+    /// Fold the DequantizeLinear node of constant weights into a new initializer, so the weights
+    /// are imported like any other constant. Needs to be called before renaming, since the
+    /// initializer replaces the original output of the node.
+    fn fold_dequantize(&mut self, node: &Node, graph_data: &mut GraphData) -> bool {
+        let Some(constant) = dequantize_constant(node) else {
+            return false;
+        };
+        let name = constant.argument.name.clone();
+
+        if constant.symmetric_int8 {
+            self.quantized_weights.insert(name.clone());
         } else {
-            node.inputs.iter_mut().for_each(|x| {
-                if let Some(identity_idx) = self.identity_idx.get(&x.name) {
-                    let input_name = &graph_data.processed_nodes[*identity_idx].inputs[0].name;
+            log::warn!(
+                "Weights {} don't use a symmetric int8 quantization, they are imported dequantized",
+                name
+            );
+        }
+        graph_data.initializers.insert(name, constant.argument);
 
-                    x.name.clone_from(input_name);
-                }
-            });
+        true
+    }
+
+    /// Remap the QuantizeLinear and DequantizeLinear nodes of activations to identity nodes,
+    /// since burn computes the activations in floating point
+    fn handle_activation_qdq(&mut self, node: &mut Node) {
+        if is_activation_qdq(node) {
+            log::warn!(
+                "Node {} quantizes activations, which are computed in floating point",
+                node.name
+            );
+            node.node_type = NodeType::Identity;
+            node.inputs.truncate(1);
+        }
+    }
+
+    /// Mark the linear nodes with quantized weights, which are imported as quantized linear
+    /// layers. Needs to be called after coalescing, since MatMul and Gemm nodes become linear
+    /// nodes there
+    fn handle_quantized_weights(&mut self, node: &mut Node) {
+        let Some(input) = node
+            .inputs
+            .iter()
+            .find(|input| self.quantized_weights.contains(&input.name))
+        else {
+            return;
+        };
+
+        if node.node_type == NodeType::Linear && node.inputs[1].name == input.name {
+            node.attrs.insert(
+                "weight_quantization".to_string(),
+                AttributeValue::String("int8".to_string()),
+            );
+        } else {
+            log::warn!(
+                "The quantized weights of node {} are imported dequantized, only linear layers \
+                 are quantized",
+                node.name
+            );
         }
     }
 }
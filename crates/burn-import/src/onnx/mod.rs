@@ -1,4 +1,5 @@
 mod coalesce;
+mod diagnostics;
 mod dim_inference;
 mod from_onnx;
 mod ir;
@@ -6,9 +7,12 @@ mod node_remap;
 mod op_configuration;
 mod proto_conversion;
 mod protos;
+mod qdq;
 mod to_burn;
 
 pub use to_burn::*;
 
+pub use diagnostics::{UnsupportedOp, UnsupportedOpsReport};
+
 pub use from_onnx::parse_onnx;
 pub use ir::OnnxGraph;
@@ -2,7 +2,7 @@ use burn::nn::{
     conv::{Conv1dConfig, Conv2dConfig, ConvTranspose2dConfig},
     pool::{AvgPool1dConfig, AvgPool2dConfig, MaxPool1dConfig, MaxPool2dConfig},
     BatchNormConfig, DropoutConfig, LayerNormConfig, LinearConfig, PaddingConfig1d,
-    PaddingConfig2d,
+    PaddingConfig2d, WeightQuantization,
 };
 
 use super::ir::{ArgType, AttributeValue, Data, Node};
@@ -386,6 +386,17 @@ pub fn linear_config(node: &Node) -> LinearConfig {
     LinearConfig::new(in_size, out_size).with_bias(bias)
 }
 
+/// Get the quantization of the weights of a linear node imported from dequantized weights
+pub fn linear_weight_quantization(node: &Node) -> Option<WeightQuantization> {
+    match node.attrs.get("weight_quantization") {
+        Some(AttributeValue::String(quantization)) if quantization == "int8" => {
+            Some(WeightQuantization::Int8)
+        }
+        Some(quantization) => panic!("Linear: unsupported weight quantization {:?}", quantization),
+        None => None,
+    }
+}
+
 /// Create a DropoutConfig from an attribute and state of the node
 pub fn dropout_config(node: &Node) -> DropoutConfig {
     // Opset 7 and older store probability as an attribute
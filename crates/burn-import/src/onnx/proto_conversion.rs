@@ -35,6 +35,23 @@ impl TryFrom<TensorProto> for Tensor {
                     Data::Float32s(tensor.float_data)
                 },
             ),
+            // The 8-bit integers of quantized models are converted to int32
+            DataType::INT8 => (
+                ElementType::Int32,
+                if !tensor.raw_data.is_empty() {
+                    Data::Int32s(tensor.raw_data.iter().map(|x| *x as i8 as i32).collect())
+                } else {
+                    Data::Int32s(tensor.int32_data)
+                },
+            ),
+            DataType::UINT8 => (
+                ElementType::Int32,
+                if !tensor.raw_data.is_empty() {
+                    Data::Int32s(tensor.raw_data.iter().map(|x| *x as i32).collect())
+                } else {
+                    Data::Int32s(tensor.int32_data)
+                },
+            ),
             DataType::INT16 => {
                 // TODO : Add support for int16 by converting to int32
                 todo!("Add support for int16");
@@ -0,0 +1,111 @@
+use super::ir::{ArgType, Argument, AttributeValue, Data, ElementType, Node, NodeType, TensorType};
+
+/// The weights of a DequantizeLinear node folded into a constant at import time.
+pub(crate) struct DequantizedConstant {
+    /// The dequantized values, named after the output of the node.
+    pub(crate) argument: Argument,
+    /// If the quantized values have a zero point of zero and fit in `[-127, 127]`, which maps
+    /// onto the symmetric int8 quantization of burn.
+    pub(crate) symmetric_int8: bool,
+}
+
+/// Check if the node is a QuantizeLinear or DequantizeLinear node applied on activations, which
+/// burn doesn't quantize, as opposed to constant weights.
+pub(crate) fn is_activation_qdq(node: &Node) -> bool {
+    matches!(
+        node.node_type,
+        NodeType::QuantizeLinear | NodeType::DequantizeLinear
+    ) && node.inputs[0].value.is_none()
+}
+
+/// Dequantize the constant input of a DequantizeLinear node:
+///
+/// `y = (x - x_zero_point) * x_scale`
+///
+/// The scale and zero point are either scalars or, with per-axis quantization, vectors along the
+/// `axis` attribute.
+pub(crate) fn dequantize_constant(node: &Node) -> Option<DequantizedConstant> {
+    if node.node_type != NodeType::DequantizeLinear || node.inputs[0].value.is_none() {
+        return None;
+    }
+
+    let input = &node.inputs[0];
+    let shape = match &input.ty {
+        ArgType::Tensor(tensor) => tensor.shape.clone().unwrap_or_default(),
+        _ => Vec::new(),
+    };
+    let values = int_values(input.value.clone().unwrap());
+    let scales = float_values(
+        node.inputs[1]
+            .value
+            .clone()
+            .expect("DequantizeLinear: the scale should be a constant"),
+    );
+    let zero_points = match node.inputs.get(2).and_then(|input| input.value.clone()) {
+        Some(value) => int_values(value),
+        None => vec![0; scales.len()],
+    };
+    assert_eq!(
+        scales.len(),
+        zero_points.len(),
+        "DequantizeLinear: the scale and zero point should have the same size"
+    );
+
+    let axis = match node.attrs.get("axis") {
+        Some(AttributeValue::Int64(axis)) if *axis < 0 => (*axis + shape.len() as i64) as usize,
+        Some(AttributeValue::Int64(axis)) => *axis as usize,
+        _ => 1,
+    };
+    // The stride between the elements of consecutive indices along the axis.
+    let stride: usize = shape.iter().skip(axis + 1).product();
+
+    let dequantized = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let channel = match scales.len() {
+                1 => 0,
+                num_channels => (index / stride) % num_channels,
+            };
+
+            (value - zero_points[channel]) as f32 * scales[channel]
+        })
+        .collect();
+
+    let dim = shape.len();
+    let argument = Argument {
+        name: node.outputs[0].name.clone(),
+        ty: ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            dim,
+            shape: Some(shape),
+        }),
+        value: Some(Data::Float32s(dequantized)),
+        passed: false,
+    };
+    let symmetric_int8 = zero_points.iter().all(|zero_point| *zero_point == 0)
+        && values.iter().all(|value| (-127..=127).contains(value));
+
+    Some(DequantizedConstant {
+        argument,
+        symmetric_int8,
+    })
+}
+
+fn int_values(data: Data) -> Vec<i64> {
+    match data {
+        Data::Int32(value) => vec![value as i64],
+        Data::Int32s(values) => values.into_iter().map(|value| value as i64).collect(),
+        Data::Int64(value) => vec![value],
+        Data::Int64s(values) => values,
+        data => panic!("DequantizeLinear: expected integers, got {:?}", data),
+    }
+}
+
+fn float_values(data: Data) -> Vec<f32> {
+    match data {
+        Data::Float32(value) => vec![value],
+        Data::Float32s(values) => values,
+        data => panic!("DequantizeLinear: expected a float32 scale, got {:?}", data),
+    }
+}
@@ -10,6 +10,7 @@ use burn::{
     tensor::{DataSerialize, Element},
 };
 use log::warn;
+use quote::quote;
 
 use crate::{
     burn::{
@@ -48,12 +49,14 @@ use crate::{
             sum::SumNode,
             unary::UnaryNode,
             unsqueeze::UnsqueezeNode,
+            unsupported::UnsupportedNode,
         },
         ScalarKind, ScalarType, TensorKind, TensorType, Type,
     },
     format_tokens,
     logger::init_log,
     onnx::{
+        diagnostics::{UnsupportedOp, UnsupportedOpsReport},
         from_onnx::convert_constant_value,
         ir::{Node, NodeType},
         op_configuration::*,
@@ -81,6 +84,7 @@ pub struct ModelGen {
     half_precision: bool,
     record_type: RecordType,
     embed_states: bool,
+    partial_import: bool,
 }
 
 impl ModelGen {
@@ -156,6 +160,21 @@ impl ModelGen {
         self
     }
 
+    /// Specify whether to import the model even if some of its nodes aren't supported.
+    ///
+    /// The unsupported nodes are delegated to the methods of an `UnsupportedOps` trait generated
+    /// along the model, which must be implemented for the model before calling its forward pass.
+    /// A placeholder implementation is saved next to the generated model as a `.stub.rs` file.
+    ///
+    /// # Arguments
+    ///
+    /// * `partial_import` - If true, the supported nodes are generated. Otherwise, the conversion
+    /// fails with a report of all the unsupported nodes.
+    pub fn partial_import(&mut self, partial_import: bool) -> &mut Self {
+        self.partial_import = partial_import;
+        self
+    }
+
     /// Run code generation.
     fn run(&self, is_build_script: bool) {
         log::info!("Starting to convert ONNX to Burn");
@@ -209,37 +228,79 @@ impl ModelGen {
         let blank_space = true;
         let top_comment = Some(format!("Generated from ONNX {input:?} by burn-import"));
 
-        let code = if self.half_precision {
-            graph
-                .into_burn::<HalfPrecisionSettings>()
+        let (code, stub) = if self.half_precision {
+            let graph = match self.partial_import {
+                true => graph.into_burn_partial::<HalfPrecisionSettings>(),
+                false => graph.into_burn::<HalfPrecisionSettings>(),
+            };
+            let stub = graph.codegen_unsupported_stub();
+            let code = graph
                 .with_record(out_file.clone(), self.record_type, self.embed_states)
                 .with_blank_space(blank_space)
                 .with_top_comment(top_comment)
-                .codegen()
+                .codegen();
+            (code, stub)
         } else {
-            graph
-                .into_burn::<FullPrecisionSettings>()
+            let graph = match self.partial_import {
+                true => graph.into_burn_partial::<FullPrecisionSettings>(),
+                false => graph.into_burn::<FullPrecisionSettings>(),
+            };
+            let stub = graph.codegen_unsupported_stub();
+            let code = graph
                 .with_record(out_file.clone(), self.record_type, self.embed_states)
                 .with_blank_space(blank_space)
                 .with_top_comment(top_comment)
-                .codegen()
+                .codegen();
+            (code, stub)
         };
 
         let code_str = format_tokens(code);
         fs::write(out_file.with_extension("rs"), code_str).unwrap();
 
+        if let Some(stub) = stub {
+            let comment = format!(
+                "Implementation of the nodes of {input:?} unsupported by burn-import, to be completed \
+                 and included next to the generated model"
+            );
+            let stub_file = out_file.with_extension("stub.rs");
+            let stub_str = format_tokens(quote! {
+                _comment_!(#comment);
+                #stub
+            });
+            fs::write(&stub_file, stub_str).unwrap();
+
+            warn!(
+                "The unsupported nodes must be implemented for the model, see {:?}",
+                stub_file
+            );
+        }
+
         log::info!("Model generated");
     }
 }
 
 impl OnnxGraph {
     /// Converts ONNX graph to Burn graph.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a [report](UnsupportedOpsReport) of all the unsupported nodes of the graph.
     pub fn into_burn<PS: PrecisionSettings + 'static>(self) -> BurnGraph<PS> {
+        self.convert(false)
+    }
+
+    /// Converts ONNX graph to Burn graph, delegating the unsupported nodes to the methods of an
+    /// `UnsupportedOps` trait generated along the model, to be implemented by the user.
+    pub fn into_burn_partial<PS: PrecisionSettings + 'static>(self) -> BurnGraph<PS> {
+        self.convert(true)
+    }
+
+    fn convert<PS: PrecisionSettings + 'static>(self, partial: bool) -> BurnGraph<PS> {
         let mut graph = BurnGraph::<PS>::default();
 
-        let mut unsupported_ops = vec![];
+        let mut unsupported_ops = UnsupportedOpsReport::default();
 
-        for node in self.nodes {
+        for (position, node) in self.nodes.into_iter().enumerate() {
             match node.node_type {
                 NodeType::Add => graph.register(Self::add_conversion(node)),
                 NodeType::ArgMax => graph.register(Self::argmax_conversion(node)),
@@ -316,12 +377,22 @@ impl OnnxGraph {
                 NodeType::Squeeze => graph.register(Self::squeeze_conversion(node)),
                 NodeType::RandomUniform => graph.register(Self::random_uniform_conversion(node)),
                 NodeType::RandomNormal => graph.register(Self::random_normal_conversion(node)),
-                node_type => unsupported_ops.push(node_type),
+                _ => {
+                    unsupported_ops
+                        .ops
+                        .push(UnsupportedOp::new(position, &node));
+                    if partial {
+                        graph.register(Self::unsupported_conversion(node));
+                    }
+                }
             }
         }
 
         if !unsupported_ops.is_empty() {
-            panic!("Unsupported ops: {:?}", unsupported_ops);
+            match partial {
+                true => warn!("{}", unsupported_ops),
+                false => panic!("{}", unsupported_ops),
+            }
         }
 
         // Get input and output names
@@ -393,6 +464,27 @@ impl OnnxGraph {
         ConstantNode::new(node.name.clone(), const_value, output.to_type())
     }
 
+    fn unsupported_conversion(node: Node) -> UnsupportedNode {
+        let inputs = node
+            .inputs
+            .iter()
+            .filter(|input| {
+                if input.value.is_some() {
+                    warn!(
+                        "The constant input {} of the unsupported node {} isn't passed to its \
+                         implementation.",
+                        input.name, node.name
+                    );
+                }
+                input.value.is_none()
+            })
+            .map(Argument::to_type)
+            .collect();
+        let outputs = node.outputs.iter().map(Argument::to_type).collect();
+
+        UnsupportedNode::new(node.name, node.node_type.to_string(), inputs, outputs)
+    }
+
     fn random_uniform_conversion(node: Node) -> RandomUniformNode {
         let output = node.outputs.first().unwrap();
         // cannot use output.to_tensor_type() here, since it drops the shape info...
@@ -789,8 +881,12 @@ impl OnnxGraph {
         let weight = extract_data_serialize::<PS::FloatElem>(1, &node).expect("Weight is required");
 
         let bias = extract_data_serialize::<PS::FloatElem>(2, &node);
+        let node_linear = LinearNode::new(name, input, output, weight, bias, config);
 
-        LinearNode::new(name, input, output, weight, bias, config)
+        match linear_weight_quantization(&node) {
+            Some(quantization) => node_linear.with_quantization(quantization),
+            None => node_linear,
+        }
     }
 
     fn dropout_conversion(node: Node) -> DropoutNode {
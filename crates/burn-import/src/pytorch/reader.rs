@@ -48,6 +48,49 @@ where
         .map(|(key, tensor)| (key, CandleTensor(tensor)))
         .collect();
 
+    deserialize_tensors::<PS, D, B, _>(tensors, key_remap, debug)
+}
+
+/// A tensor read from a file, with the information printed by the debug output of the readers.
+pub(crate) trait ImportedTensor: Serializable {
+    /// The shape of the tensor.
+    fn debug_shape(&self) -> Vec<usize>;
+
+    /// The element type of the tensor in the file.
+    fn debug_dtype(&self) -> String;
+}
+
+impl ImportedTensor for CandleTensor {
+    fn debug_shape(&self) -> Vec<usize> {
+        self.dims().to_vec()
+    }
+
+    fn debug_dtype(&self) -> String {
+        format!("{:?}", self.dtype())
+    }
+}
+
+/// Deserializes the tensors read from a file into a record, with the PyTorch layouts.
+///
+/// The readers of the other formats, e.g. safetensors, share this function, their files being
+/// mostly exported from PyTorch.
+///
+/// # Arguments
+///
+/// * `tensors` - The tensors of the file, by key.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `debug` - Whether to print the keys and the shapes of the tensors.
+pub(crate) fn deserialize_tensors<PS, D, B, T>(
+    tensors: HashMap<String, T>,
+    key_remap: Vec<(Regex, String)>,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+    T: ImportedTensor,
+{
     // Remap the keys (replace the keys in the map with the new keys)
     let (tensors, remapped_keys) = remap(tensors, key_remap);
 
@@ -64,15 +107,15 @@ where
                 println!("Key: {}", new_key);
             }
 
-            let shape = tensors[&new_key].shape();
-            let dtype = tensors[&new_key].dtype();
+            let shape = tensors[&new_key].debug_shape();
+            let dtype = tensors[&new_key].debug_dtype();
             println!("Shape: {shape:?}");
-            println!("Dtype: {dtype:?}");
+            println!("Dtype: {dtype}");
             println!("---");
         }
     }
 
-    // Convert the vector of Candle tensors to a nested value data structure
+    // Convert the tensors to a nested value data structure
     let nested_value = unflatten::<PS, _>(tensors)?;
 
     // Create a deserializer with PyTorch adapter and nested value
@@ -83,6 +126,26 @@ where
     Ok(value)
 }
 
+/// Keeps the tensors nested under the given top-level key of a flat file, e.g. a safetensors
+/// file, removing the key from their names.
+pub(crate) fn select_top_level_key<T>(
+    tensors: HashMap<String, T>,
+    top_level_key: Option<&str>,
+) -> HashMap<String, T> {
+    let Some(top_level_key) = top_level_key else {
+        return tensors;
+    };
+    let prefix = format!("{top_level_key}.");
+
+    tensors
+        .into_iter()
+        .filter_map(|(key, tensor)| {
+            key.strip_prefix(&prefix)
+                .map(|key| (key.to_string(), tensor))
+        })
+        .collect()
+}
+
 /// Serializes a candle tensor.
 ///
 /// Tensors are wrapped in a `Param` struct (learnable parameters) and serialized as a `DataSerialize` struct.
@@ -145,7 +208,7 @@ where
 }
 
 /// New type struct for Candle tensors because we need to implement the `Serializable` trait for it.
-struct CandleTensor(candle_core::Tensor);
+pub(crate) struct CandleTensor(pub(crate) candle_core::Tensor);
 
 impl Deref for CandleTensor {
     type Target = candle_core::Tensor;
@@ -54,7 +54,8 @@ impl<PS: PrecisionSettings, B: Backend> Recorder<B> for PyTorchFileRecorder<PS>
     }
 }
 
-/// Arguments for loading a PyTorch file.
+/// Arguments for loading a PyTorch file, also used by the recorders of the safetensors and GGUF
+/// files.
 ///
 /// # Fields
 ///
@@ -92,6 +93,9 @@ pub struct LoadArgs {
 
     /// Top-level key to load state_dict from the file.
     /// Sometimes the state_dict is nested under a top-level key in a dict.
+    ///
+    /// The tensors of the flat formats, e.g. safetensors, are nested under the key when their
+    /// names start with it followed by a dot.
     pub top_level_key: Option<String>,
 
     /// Whether to print debug information.
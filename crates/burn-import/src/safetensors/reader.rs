@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::pytorch::{
+    error::Error,
+    reader::{deserialize_tensors, select_top_level_key, CandleTensor, ImportedTensor},
+};
+
+use burn::{
+    record::{
+        serde::{
+            data::{NestedValue, Serializable},
+            error,
+            ser::Serializer,
+        },
+        PrecisionSettings,
+    },
+    tensor::backend::Backend,
+};
+
+use candle_core::safetensors::MmapedSafetensors;
+use regex::Regex;
+use serde::de::DeserializeOwned;
+
+/// Deserializes a safetensors file.
+///
+/// The file is memory mapped instead of being read, but every tensor is converted before the
+/// record is deserialized, so the whole record is held in memory while it is loaded, like with
+/// the other recorders.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path of the file to read.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `top_level_key` - An optional key under which the tensors to load are nested.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    top_level_key: Option<&str>,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    // Safety: the file is only mapped while the record is loaded.
+    let safetensors = unsafe { MmapedSafetensors::new(path)? };
+    let tensors: HashMap<String, MmapedTensor> = safetensors
+        .tensors()
+        .into_iter()
+        .map(|(key, view)| {
+            let tensor = MmapedTensor {
+                safetensors: &safetensors,
+                name: key.clone(),
+                shape: view.shape().to_vec(),
+                dtype: format!("{:?}", view.dtype()),
+            };
+            (key, tensor)
+        })
+        .collect();
+    let tensors = select_top_level_key(tensors, top_level_key);
+
+    deserialize_tensors::<PS, D, B, _>(tensors, key_remap, debug)
+}
+
+/// A tensor of a memory mapped safetensors file, only read when it is serialized.
+struct MmapedTensor<'a> {
+    safetensors: &'a MmapedSafetensors,
+    name: String,
+    shape: Vec<usize>,
+    dtype: String,
+}
+
+impl Serializable for MmapedTensor<'_> {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        let tensor = self
+            .safetensors
+            .load(&self.name, &candle_core::Device::Cpu)
+            .map_err(|err| error::Error::Other(format!("Candle safetensors error: {err}")))?;
+
+        CandleTensor(tensor).serialize::<PS>(serializer)
+    }
+}
+
+impl ImportedTensor for MmapedTensor<'_> {
+    fn debug_shape(&self) -> Vec<usize> {
+        self.shape.clone()
+    }
+
+    fn debug_dtype(&self) -> String {
+        self.dtype.clone()
+    }
+}
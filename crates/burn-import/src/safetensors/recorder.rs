@@ -0,0 +1,143 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::reader::from_file;
+use crate::pytorch::LoadArgs;
+
+/// A recorder that loads safetensors files (`.safetensors`) into Burn modules.
+///
+/// The file is memory mapped instead of being read, but the loaded record is held in memory like
+/// with the other recorders. The tensors are expected to have the layouts of PyTorch, like the
+/// weights of the models of the Hugging Face hub.
+///
+/// The recorder shares the [LoadArgs] of the [PyTorch recorder](crate::pytorch::PyTorchFileRecorder),
+/// the top-level key being the prefix of the names of the tensors to load, e.g. `model` for
+/// `model.fc.weight`.
+#[derive(new, Debug, Default, Clone)]
+pub struct SafetensorsFileRecorder<PS: PrecisionSettings> {
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = LoadArgs;
+
+    fn save_item<I: Serialize>(
+        &self,
+        _item: I,
+        _file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        Err(RecorderError::Unknown(
+            "The safetensors recorder can only load records".into(),
+        ))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, _file: Self::LoadArgs) -> Result<I, RecorderError> {
+        Err(RecorderError::Unknown(
+            "The safetensors recorder can only load records".into(),
+        ))
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item = from_file::<PS, R::Item<Self::Settings>, B>(
+            &args.file,
+            args.key_remap,
+            args.top_level_key.as_deref(),
+            args.debug,
+        )?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::backend::NdArray;
+    use burn::module::Module;
+    use burn::nn::Linear;
+    use burn::record::FullPrecisionSettings;
+    use std::collections::HashMap;
+
+    type TestBackend = NdArray<f32>;
+
+    #[derive(Module, Debug)]
+    struct Net<B: Backend> {
+        fc: Linear<B>,
+    }
+
+    #[test]
+    fn should_load_linear_weights_with_pytorch_layout() {
+        let device = candle_core::Device::Cpu;
+        // PyTorch stores the weights of linear layers as [d_output, d_input].
+        let weight =
+            candle_core::Tensor::new(&[[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]], &device).unwrap();
+        let bias = candle_core::Tensor::new(&[0.5f32, -0.5], &device).unwrap();
+        let path = std::env::temp_dir().join("burn_import_should_load_linear_weights.safetensors");
+        candle_core::safetensors::save(
+            &HashMap::from([
+                ("model.fc.weight".to_string(), weight),
+                ("model.fc.bias".to_string(), bias),
+            ]),
+            &path,
+        )
+        .unwrap();
+
+        let args = LoadArgs::new(path.clone()).with_key_remap("model\\.(.*)", "$1");
+        let record: NetRecord<TestBackend> =
+            SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+                .load(args, &Default::default())
+                .unwrap();
+
+        assert_eq!(record.fc.weight.val().dims(), [3, 2]);
+        assert_eq!(
+            record.fc.weight.val().into_data().value,
+            vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]
+        );
+        assert_eq!(
+            record.fc.bias.unwrap().val().into_data().value,
+            vec![0.5, -0.5]
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn should_load_the_tensors_under_the_top_level_key() {
+        let device = candle_core::Device::Cpu;
+        let weight = candle_core::Tensor::new(&[[1.0f32, 2.0]], &device).unwrap();
+        let other = candle_core::Tensor::new(&[[3.0f32, 4.0]], &device).unwrap();
+        let bias = candle_core::Tensor::new(&[0.5f32], &device).unwrap();
+        let path = std::env::temp_dir().join("burn_import_should_load_top_level_key.safetensors");
+        candle_core::safetensors::save(
+            &HashMap::from([
+                ("model.fc.weight".to_string(), weight),
+                ("model.fc.bias".to_string(), bias.clone()),
+                ("ema.fc.weight".to_string(), other),
+                ("ema.fc.bias".to_string(), bias),
+            ]),
+            &path,
+        )
+        .unwrap();
+
+        let args = LoadArgs::new(path.clone()).with_top_level_key("model");
+        let record: NetRecord<TestBackend> =
+            SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+                .load(args, &Default::default())
+                .unwrap();
+
+        assert_eq!(record.fc.weight.val().into_data().value, vec![1.0, 2.0]);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -1,9 +1,10 @@
 use crate::{
     tensor::JitTensor, FloatElement, IntElement, JitAutotuneKey, JitRuntime, PrecisionBridge,
 };
+use burn_common::rand::get_seeded_rng;
 use burn_compute::server::ComputeServer;
-use burn_tensor::backend::{Backend, SyncType};
-use rand::{rngs::StdRng, SeedableRng};
+use burn_tensor::backend::{Backend, DeviceInfo, MemoryUsage, SyncType};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use std::{marker::PhantomData, sync::Mutex};
 
 pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
@@ -44,6 +45,17 @@ where
         *seed = Some(rng);
     }
 
+    fn fork_seed() -> Option<u64> {
+        let mut rng = SEED.lock().unwrap();
+        let seed = match rng.as_mut() {
+            Some(rng) => rng.gen(),
+            None => get_seeded_rng().gen(),
+        };
+        *rng = Some(StdRng::seed_from_u64(seed));
+
+        Some(seed)
+    }
+
     fn ad_enabled() -> bool {
         false
     }
@@ -52,6 +64,15 @@ where
         let client = R::client(device);
         client.sync(sync_type);
     }
+
+    fn memory_usage(device: &Self::Device) -> Option<MemoryUsage> {
+        let client = R::client(device);
+        Some(client.memory_usage())
+    }
+
+    fn devices() -> Vec<DeviceInfo<Self::Device>> {
+        R::devices()
+    }
 }
 
 impl<R: JitRuntime, F: FloatElement, I: IntElement> core::fmt::Debug for JitBackend<R, F, I> {
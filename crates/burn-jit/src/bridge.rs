@@ -66,4 +66,16 @@ where
             tensor
         }
     }
+
+    fn target_device(
+        device: &burn_tensor::Device<JitBackend<R, FOrigin, IOrigin>>,
+    ) -> burn_tensor::Device<Self::Target> {
+        device.clone()
+    }
+
+    fn origin_device(
+        device: &burn_tensor::Device<Self::Target>,
+    ) -> burn_tensor::Device<JitBackend<R, FOrigin, IOrigin>> {
+        device.clone()
+    }
 }
@@ -0,0 +1,291 @@
+use crate::{
+    element::JitElement, tensor::JitTensor, FloatElement, IntElement, JitBackend, JitRuntime,
+};
+use burn_compute::{
+    client::ComputeClient,
+    server::{Binding, Handle},
+};
+use burn_cube::{
+    compute::{CompiledKernel, CubeCount, CubeTask, KernelTask, LaunchSettings},
+    ir::{CubeDim, Elem},
+    Kernel, Runtime,
+};
+use burn_tensor::{Bool, Float, Int, Tensor};
+
+/// A hand-written or JIT-IR kernel launched on tensors of a [jit backend](JitBackend).
+///
+/// The kernel receives its bindings in the same order as the kernels of this crate:
+///
+/// | Binding            | Content                                                         |
+/// |:------------------:|:---------------------------------------------------------------:|
+/// | `0..I`             | the `I` inputs, in the order they were added                     |
+/// | `I..I + O`         | the `O` outputs, in the order they were added                    |
+/// | `I + O`            | the info buffer of `u32`                                        |
+/// | `I + O + 1..`      | the float, int and uint scalars, in that order, when provided   |
+///
+/// The info buffer holds the rank of the first tensor, followed by the strides and the shape of
+/// each tensor, inputs first. When the runtime [requires array lengths](Runtime::require_array_lengths),
+/// the number of elements of each tensor is appended.
+///
+/// Outputs are tensors allocated by the caller, e.g. with [Tensor::empty], which the kernel
+/// writes into.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let output = Tensor::<Wgpu, 2>::empty(input.shape(), &device);
+///
+/// CustomKernel::source("scale", include_str!("scale.wgsl"), CubeDim::new(16, 16, 1))
+///     .input(&input)
+///     .output(&output)
+///     .scalars(&[2.0f32])
+///     .launch(calculate_cube_count_elemwise(input.shape().num_elements(), 16));
+/// ```
+///
+/// Custom kernels are launched on the jit backend directly, so tensors of a fusion backend must
+/// be [synced](burn_tensor::backend::Backend::sync) and converted before being bound.
+pub struct CustomKernel<R: JitRuntime> {
+    task: CustomTask,
+    inputs: Vec<TensorBinding<R>>,
+    outputs: Vec<TensorBinding<R>>,
+    scalars: [Option<Vec<u8>>; 3],
+}
+
+/// The handle, strides and shape of a tensor bound to a [custom kernel](CustomKernel).
+pub struct TensorBinding<R: JitRuntime> {
+    client: ComputeClient<R::Server, R::Channel>,
+    handle: Handle<R::Server>,
+    strides: Vec<usize>,
+    shape: Vec<usize>,
+}
+
+/// A tensor that can be bound to a [custom kernel](CustomKernel).
+pub trait CustomBinding<R: JitRuntime> {
+    /// The binding of the tensor.
+    fn binding(&self) -> TensorBinding<R>;
+}
+
+enum CustomTask {
+    Ir(Box<dyn FnOnce(CubeCount) -> Box<dyn CubeTask>>),
+    Source {
+        id: String,
+        source: String,
+        cube_dim: CubeDim,
+    },
+}
+
+struct SourceTask {
+    id: String,
+    source: String,
+    cube_dim: CubeDim,
+    cube_count: CubeCount,
+}
+
+impl<R: JitRuntime> CustomKernel<R> {
+    /// Create a custom kernel from its JIT-IR [definition](Kernel).
+    ///
+    /// The [cube dim](CubeDim) is the one of the kernel definition.
+    pub fn new<K: Kernel + 'static>(kernel: K) -> Self {
+        Self::with_task(CustomTask::Ir(Box::new(move |cube_count| {
+            Box::new(KernelTask::<R::Compiler, K>::new(kernel, cube_count))
+        })))
+    }
+
+    /// Create a custom kernel from source code in the language of the runtime, e.g. WGSL for
+    /// wgpu.
+    ///
+    /// The id is used to cache the compilation, so it must be unique for each source.
+    pub fn source<I: Into<String>, S: Into<String>>(id: I, source: S, cube_dim: CubeDim) -> Self {
+        Self::with_task(CustomTask::Source {
+            id: id.into(),
+            source: source.into(),
+            cube_dim,
+        })
+    }
+
+    fn with_task(task: CustomTask) -> Self {
+        Self {
+            task,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            scalars: [None, None, None],
+        }
+    }
+
+    /// Bind a tensor read by the kernel.
+    pub fn input<T: CustomBinding<R>>(mut self, tensor: &T) -> Self {
+        self.inputs.push(tensor.binding());
+        self
+    }
+
+    /// Bind a tensor written by the kernel.
+    pub fn output<T: CustomBinding<R>>(mut self, tensor: &T) -> Self {
+        self.outputs.push(tensor.binding());
+        self
+    }
+
+    /// Bind scalars of the given element type.
+    ///
+    /// # Panics
+    ///
+    /// When scalars of the same kind, float, int or uint, are already bound, or when the element
+    /// type is bool.
+    pub fn scalars<E: JitElement>(mut self, values: &[E]) -> Self {
+        let position = match E::cube_elem() {
+            Elem::Float(_) => 0,
+            Elem::Int(_) => 1,
+            Elem::UInt => 2,
+            Elem::Bool => panic!("Bool scalars are not supported"),
+            Elem::AtomicInt(_) | Elem::AtomicUInt => panic!("Atomic scalars are not supported"),
+        };
+
+        if self.scalars[position].is_some() {
+            panic!("Only one scalar binding of each kind is supported");
+        }
+
+        self.scalars[position] = Some(E::as_bytes(values).to_vec());
+        self
+    }
+
+    /// Launch the kernel with the given [cube count](CubeCount).
+    ///
+    /// The cube count of an elementwise kernel can be computed with
+    /// [calculate_cube_count_elemwise](burn_cube::calculate_cube_count_elemwise).
+    ///
+    /// # Panics
+    ///
+    /// When no tensor is bound to the kernel.
+    pub fn launch(self, cube_count: CubeCount) {
+        let client = self.client();
+        let (task, handles) = self.prepare(&client, cube_count);
+
+        client.execute(task, handles);
+    }
+
+    /// Launch the kernel with the cube count read from the given tensor, holding the number of
+    /// cubes on the x, y and z axes as `u32`.
+    ///
+    /// The cube count can be computed by a previous kernel, e.g. from the number of elements
+    /// selected by a filter, without reading it back.
+    ///
+    /// # Panics
+    ///
+    /// When no tensor is bound to the kernel.
+    pub fn launch_indirect<T: CustomBinding<R>>(self, cube_count: &T) {
+        let cube_count = cube_count.binding().handle.binding();
+        // The cube count of the task isn't used by indirect launches.
+        let client = self.client();
+        let (task, handles) = self.prepare(&client, CubeCount::new(1, 1, 1));
+
+        client.execute_indirect(task, cube_count, handles);
+    }
+
+    fn client(&self) -> ComputeClient<R::Server, R::Channel> {
+        self.inputs
+            .first()
+            .or(self.outputs.first())
+            .map(|binding| binding.client.clone())
+            .expect("A custom kernel should have at least one tensor binding")
+    }
+
+    fn prepare(
+        self,
+        client: &ComputeClient<R::Server, R::Channel>,
+        cube_count: CubeCount,
+    ) -> (Box<dyn CubeTask>, Vec<Binding<R::Server>>) {
+        let mut info = Vec::new();
+        let mut handles = Vec::with_capacity(self.inputs.len() + self.outputs.len() + 4);
+
+        for binding in self.inputs.iter().chain(self.outputs.iter()) {
+            if info.is_empty() {
+                info.push(binding.strides.len() as u32);
+            }
+            info.extend(binding.strides.iter().map(|s| *s as u32));
+            info.extend(binding.shape.iter().map(|s| *s as u32));
+            handles.push(binding.handle.clone().binding());
+        }
+
+        if R::require_array_lengths() {
+            for binding in self.inputs.iter().chain(self.outputs.iter()) {
+                info.push(binding.shape.iter().product::<usize>() as u32);
+            }
+        }
+
+        handles.push(client.create_uniform(bytemuck::cast_slice(&info)).binding());
+
+        for values in self.scalars.iter().flatten() {
+            handles.push(client.create_uniform(values).binding());
+        }
+
+        let task: Box<dyn CubeTask> = match self.task {
+            CustomTask::Ir(task) => task(cube_count),
+            CustomTask::Source {
+                id,
+                source,
+                cube_dim,
+            } => Box::new(SourceTask {
+                id,
+                source,
+                cube_dim,
+                cube_count,
+            }),
+        };
+
+        (task, handles)
+    }
+}
+
+impl CubeTask for SourceTask {
+    fn id(&self) -> String {
+        format!("custom-{}-{:?}", self.id, self.cube_dim)
+    }
+
+    fn compile(&self) -> CompiledKernel {
+        CompiledKernel {
+            source: self.source.clone(),
+            cube_dim: self.cube_dim,
+            shared_mem_bytes: 0,
+        }
+    }
+
+    fn launch_settings(&self) -> LaunchSettings {
+        LaunchSettings {
+            cube_count: self.cube_count.clone(),
+        }
+    }
+}
+
+impl<R: JitRuntime, E: JitElement, const D: usize> CustomBinding<R> for JitTensor<R, E, D> {
+    fn binding(&self) -> TensorBinding<R> {
+        TensorBinding {
+            client: self.client.clone(),
+            handle: self.handle.clone(),
+            strides: self.strides.to_vec(),
+            shape: self.shape.dims.to_vec(),
+        }
+    }
+}
+
+impl<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize> CustomBinding<R>
+    for Tensor<JitBackend<R, F, I>, D, Float>
+{
+    fn binding(&self) -> TensorBinding<R> {
+        self.clone().into_primitive().binding()
+    }
+}
+
+impl<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize> CustomBinding<R>
+    for Tensor<JitBackend<R, F, I>, D, Int>
+{
+    fn binding(&self) -> TensorBinding<R> {
+        self.clone().into_primitive().binding()
+    }
+}
+
+impl<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize> CustomBinding<R>
+    for Tensor<JitBackend<R, F, I>, D, Bool>
+{
+    fn binding(&self) -> TensorBinding<R> {
+        self.clone().into_primitive().binding()
+    }
+}
@@ -6,6 +6,7 @@ use super::{
 };
 use crate::{
     fusion::{kernel::FusionKernel, tracing::Trace, JitFusionHandle},
+    tune::CUBE_DIM_CANDIDATES,
     tune_key::JitAutotuneKey,
     JitRuntime,
 };
@@ -29,10 +30,8 @@ pub struct CompilationPhase;
 /// Phase where the kernel should be executed.
 #[derive(new)]
 pub struct ExecutionPhase<R: JitRuntime> {
-    /// Kernel set with default workgroup size.
-    pub(super) kernel_factory_1: ElementWiseKernelFactory<R>,
-    /// Kernel set with custom workgroup size.
-    pub(super) kernel_factory_2: ElementWiseKernelFactory<R>,
+    /// Kernel sets for each [cube dimension candidate](CUBE_DIM_CANDIDATES).
+    pub(super) kernel_factories: Vec<ElementWiseKernelFactory<R>>,
 }
 
 #[derive(new, Serialize, Deserialize)]
@@ -45,18 +44,21 @@ impl<R: JitRuntime> ElementWise<R, CompilationPhase> {
     pub(crate) fn compile(self) -> ElementWise<R, ExecutionPhase<R>> {
         let info = Arc::new(self.trace.compiling());
 
-        let kernel_factory_1 = ElementWiseKernelFactory::new(
-            IdGenerator::generate(),
-            info.clone(),
-            CubeDim::default(),
-        );
-        let kernel_factory_2 =
-            ElementWiseKernelFactory::new(IdGenerator::generate(), info, CubeDim::new(16, 16, 1));
+        let kernel_factories = CUBE_DIM_CANDIDATES
+            .iter()
+            .map(|cube_dim| {
+                ElementWiseKernelFactory::new(
+                    IdGenerator::generate(),
+                    info.clone(),
+                    CubeDim::new(*cube_dim, *cube_dim, 1),
+                )
+            })
+            .collect();
 
         ElementWise {
             trace: self.trace,
             device: self.device,
-            phase: ExecutionPhase::new(kernel_factory_1, kernel_factory_2),
+            phase: ExecutionPhase::new(kernel_factories),
             num_operations: self.num_operations,
         }
     }
@@ -85,11 +87,11 @@ impl<R: JitRuntime> ElementWise<R, ExecutionPhase<R>> {
         fastest_set_index: usize,
     ) {
         let info = self.trace.running();
-        let kernel_set = match fastest_set_index {
-            0 => &self.phase.kernel_factory_1,
-            1 => &self.phase.kernel_factory_2,
-            _ => panic!("Should be 0 or 1, got {fastest_set_index}"),
-        };
+        let kernel_set = self
+            .phase
+            .kernel_factories
+            .get(fastest_set_index)
+            .unwrap_or_else(|| panic!("Fastest index is out of bound, got {fastest_set_index}"));
 
         let kernel = FusionKernel::create(
             kernel_set,
@@ -111,24 +113,24 @@ impl<R: JitRuntime> ElementWise<R, ExecutionPhase<R>> {
     ) {
         let info = self.trace.running();
 
-        let kernel_1 = FusionKernel::create(
-            &self.phase.kernel_factory_1,
-            &info,
-            context,
-            self.device.clone(),
-            client.clone(),
-            false,
-        );
-        let kernel_2 = FusionKernel::create(
-            &self.phase.kernel_factory_2,
-            &info,
-            context,
-            self.device.clone(),
-            client.clone(),
-            false,
-        );
+        let kernels = self
+            .phase
+            .kernel_factories
+            .iter()
+            .map(|factory| {
+                FusionKernel::create(
+                    factory,
+                    &info,
+                    context,
+                    self.device.clone(),
+                    client.clone(),
+                    false,
+                )
+                .into()
+            })
+            .collect();
         let kernel_default = FusionKernel::create(
-            &self.phase.kernel_factory_1,
+            &self.phase.kernel_factories[0],
             &info,
             context,
             self.device.clone(),
@@ -138,8 +140,7 @@ impl<R: JitRuntime> ElementWise<R, ExecutionPhase<R>> {
 
         client.autotune_execute(Box::new(ElementWiseAutotuneOperationSet::new(
             key,
-            kernel_1.into(),
-            kernel_2.into(),
+            kernels,
             kernel_default.into(),
         )));
     }
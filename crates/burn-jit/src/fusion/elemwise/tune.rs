@@ -9,8 +9,7 @@ use serde::{Deserialize, Serialize};
 #[derive(new)]
 pub struct ElementWiseAutotuneOperationSet<R: JitRuntime> {
     key: JitAutotuneKey,
-    kernel_1: AutotunableKernel<R>,
-    kernel_2: AutotunableKernel<R>,
+    kernels: Vec<AutotunableKernel<R>>,
     kernel_default: AutotunableKernel<R>,
 }
 
@@ -39,10 +38,10 @@ impl<R: JitRuntime> AutotuneOperationSet<JitAutotuneKey> for ElementWiseAutotune
     }
 
     fn autotunables(&self) -> Vec<Box<dyn burn_compute::tune::AutotuneOperation>> {
-        let kernel_1: Box<dyn AutotuneOperation> = self.kernel_1.clone();
-        let kernel_2: Box<dyn AutotuneOperation> = self.kernel_2.clone();
-
-        vec![kernel_1, kernel_2]
+        self.kernels
+            .iter()
+            .map(|kernel| -> Box<dyn AutotuneOperation> { kernel.clone() })
+            .collect()
     }
 
     fn fastest(self: Box<Self>, _: usize) -> Box<dyn AutotuneOperation> {
@@ -205,13 +205,13 @@ impl<R: JitRuntime> FusionKernel<R> {
         }
 
         // Create the info buffer.
-        bindings.push(client.create(bytemuck::cast_slice(&info)).binding());
+        bindings.push(client.create_uniform(bytemuck::cast_slice(&info)).binding());
 
         // Finally we finish with the named bindings.
         if running_info.scalars.num_float > 0 {
             bindings.push(
                 client
-                    .create(bytemuck::cast_slice(
+                    .create_uniform(bytemuck::cast_slice(
                         &context.scalar_floats[0..running_info.scalars.num_float],
                     ))
                     .binding(),
@@ -221,7 +221,7 @@ impl<R: JitRuntime> FusionKernel<R> {
         if running_info.scalars.num_int > 0 {
             bindings.push(
                 client
-                    .create(bytemuck::cast_slice(
+                    .create_uniform(bytemuck::cast_slice(
                         &context.scalar_ints[0..running_info.scalars.num_int],
                     ))
                     .binding(),
@@ -125,6 +125,9 @@ impl TraceBuilder {
                 self.scalars.num_bool += 1;
                 var
             }
+            Elem::AtomicInt(_) | Elem::AtomicUInt => {
+                panic!("Atomic scalars are not supported")
+            }
         }
     }
 
@@ -385,6 +388,11 @@ impl TraceBuilder {
                         &mut local_tensor_ids_input,
                         &mut local_tensor_ids_output,
                     ),
+                    Operator::Bitcast(op) => mark_unary(
+                        op,
+                        &mut local_tensor_ids_input,
+                        &mut local_tensor_ids_output,
+                    ),
                 },
                 Operation::Procedure(proc) => {
                     match proc {
@@ -423,6 +431,9 @@ impl TraceBuilder {
                 Operation::Synchronization(_) => {
                     // Nothing to do, should never impact read-write access to bindings.
                 }
+                Operation::Atomic(_) => {
+                    // Nothing to do, atomics are never used by fused kernels.
+                }
                 Operation::Subcube(op) => match op {
                     Subcube::Elect(op) => {
                         mark(&op.out, &mut local_tensor_ids_output);
@@ -1,5 +1,5 @@
-use crate::{element::JitElement, tensor::JitTensor, JitRuntime};
-use burn_cube::{frontend::TensorHandle, CubeCountSettings, Execution};
+use crate::{element::JitElement, kernel::elemwise_cube_count, tensor::JitTensor, JitRuntime};
+use burn_cube::{frontend::TensorHandle, ir::Vectorization, Execution};
 use burn_tensor::Shape;
 
 /// Creates a binary kernel.
@@ -13,6 +13,9 @@ macro_rules! binary {
     ) => {{
         binary!(operation: $ops, compiler: <$runtime as JitRuntime>::Compiler, elem_in: $elem, elem_out: $elem);
 
+        let (lhs, rhs) = ($lhs, $rhs);
+        let vectorization = $crate::kernel::elemwise_vectorization(&[&lhs, &rhs]);
+
         $crate::kernel::binary::<
             Ops<<$runtime as Runtime>::Compiler, $elem, $elem>,
             OpsInplaceLhs<<$runtime as Runtime>::Compiler, $elem, $elem>,
@@ -20,7 +23,15 @@ macro_rules! binary {
             $runtime,
             $elem,
             D
-        >($lhs, $rhs, true, Ops::new(), OpsInplaceLhs::new(), OpsInplaceRhs::new())
+        >(
+            lhs,
+            rhs,
+            true,
+            vectorization,
+            Ops::new(vectorization),
+            OpsInplaceLhs::new(vectorization),
+            OpsInplaceRhs::new(vectorization),
+        )
     }};
 
     (
@@ -31,18 +42,21 @@ macro_rules! binary {
     ) => {
         #[derive(new)]
         pub struct Ops<C, I, O> {
+            vectorization: burn_cube::ir::Vectorization,
             _c: core::marker::PhantomData<C>,
             _i: core::marker::PhantomData<I>,
             _o: core::marker::PhantomData<O>,
         }
         #[derive(new)]
         pub struct OpsInplaceLhs<C, I, O> {
+            vectorization: burn_cube::ir::Vectorization,
             _c: core::marker::PhantomData<C>,
             _i: core::marker::PhantomData<I>,
             _o: core::marker::PhantomData<O>,
         }
         #[derive(new)]
         pub struct OpsInplaceRhs<C, I, O> {
+            vectorization: burn_cube::ir::Vectorization,
             _c: core::marker::PhantomData<C>,
             _i: core::marker::PhantomData<I>,
             _o: core::marker::PhantomData<O>,
@@ -93,9 +107,16 @@ macro_rules! binary {
             O: $crate::element::JitElement
         {
             fn define(&self) -> burn_cube::ir::KernelDefinition {
-                let settings = burn_cube::KernelSettings::default();
+                let mut settings = burn_cube::KernelSettings::default();
+                if self.vectorization > 1 {
+                    settings = settings.vectorize_global(self.vectorization);
+                }
                 compile::<I, O>(settings)
             }
+
+            fn id(&self) -> String {
+                format!("{:?}-vec{}", core::any::TypeId::of::<Self>(), self.vectorization)
+            }
         }
 
         #[allow(clippy::redundant_closure_call)]
@@ -111,10 +132,17 @@ macro_rules! binary {
                     pos_input: 0,
                     pos_output: 0,
                 };
-                let settings = burn_cube::KernelSettings::default()
+                let mut settings = burn_cube::KernelSettings::default()
                     .inplace(vec![mapping]);
+                if self.vectorization > 1 {
+                    settings = settings.vectorize_global(self.vectorization);
+                }
                 compile::<I, O>(settings)
             }
+
+            fn id(&self) -> String {
+                format!("{:?}-vec{}", core::any::TypeId::of::<Self>(), self.vectorization)
+            }
         }
 
         #[allow(clippy::redundant_closure_call)]
@@ -130,19 +158,30 @@ macro_rules! binary {
                     pos_input: 1,
                     pos_output: 0,
                 };
-                let settings = burn_cube::KernelSettings::default()
+                let mut settings = burn_cube::KernelSettings::default()
                     .inplace(vec![mapping]);
+                if self.vectorization > 1 {
+                    settings = settings.vectorize_global(self.vectorization);
+                }
                 compile::<I, O>(settings)
             }
+
+            fn id(&self) -> String {
+                format!("{:?}-vec{}", core::any::TypeId::of::<Self>(), self.vectorization)
+            }
         }
     };
 }
 
 /// Launch an binary operation.
+///
+/// The kernels must be compiled with the given vectorization factor, each unit processing that
+/// many contiguous elements.
 pub fn binary<Kernel, KernelInplaceLhs, KernelInplaceRhs, R: JitRuntime, E, const D: usize>(
     lhs: JitTensor<R, E, D>,
     rhs: JitTensor<R, E, D>,
     inplace_enabled: bool,
+    vectorization: Vectorization,
     kernel: Kernel,
     kernel_inplace_lhs: KernelInplaceLhs,
     kernel_inplace_rhs: KernelInplaceRhs,
@@ -159,7 +198,7 @@ where
                 TensorHandle::<R>::new(&lhs.handle, &lhs.strides, &lhs.shape.dims),
                 TensorHandle::new(&rhs.handle, &rhs.strides, &rhs.shape.dims),
             ])
-            .execute(CubeCountSettings::Input { pos: 0 });
+            .execute(elemwise_cube_count(lhs.shape.num_elements(), vectorization));
 
         lhs
     } else if inplace_enabled && rhs.can_mut_broadcast(&lhs) {
@@ -168,7 +207,7 @@ where
                 TensorHandle::<R>::new(&lhs.handle, &lhs.strides, &lhs.shape.dims),
                 TensorHandle::new(&rhs.handle, &rhs.strides, &rhs.shape.dims),
             ])
-            .execute(CubeCountSettings::Input { pos: 1 });
+            .execute(elemwise_cube_count(rhs.shape.num_elements(), vectorization));
 
         rhs
     } else {
@@ -197,7 +236,7 @@ where
                 &out.strides,
                 &out.shape.dims,
             )])
-            .execute(CubeCountSettings::Output { pos: 0 });
+            .execute(elemwise_cube_count(num_elems, vectorization));
 
         out
     }
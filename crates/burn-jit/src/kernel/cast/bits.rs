@@ -0,0 +1,132 @@
+use burn_cube::{
+    calculate_cube_count_elemwise, cpa,
+    frontend::TensorHandle,
+    ir::{Branch, Elem, Item, KernelDefinition, Scope, Variable, Visibility},
+    CubeCountSettings, Execution, InputInfo, KernelExpansion, KernelIntegrator, KernelSettings,
+    OutputInfo, SUBCUBE_DIM_APPROX,
+};
+use burn_tensor::Shape;
+use std::marker::PhantomData;
+
+use crate::{
+    kernel::{into_contiguous, Kernel},
+    ops::empty,
+    tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+
+/// The number of bytes of a `f32`.
+const NUM_BYTES: usize = 4;
+
+#[derive(new)]
+struct FloatBitsEagerKernel<R: JitRuntime, F: FloatElement, I: IntElement> {
+    _runtime: PhantomData<R>,
+    _float_elem: PhantomData<F>,
+    _int_elem: PhantomData<I>,
+}
+
+/// Each unit reinterprets one `f32` as an unsigned integer and writes its bytes, from the least
+/// significant to the most significant, in the row of the element in the output.
+struct FloatBitsShader {
+    tensor: Variable,
+    output: Variable,
+}
+
+impl FloatBitsShader {
+    fn expand(self, scope: &mut Scope) {
+        let tensor = self.tensor;
+        let output = self.output;
+        let id = Variable::AbsolutePos;
+
+        let num_elems = scope.create_local(Elem::UInt);
+        let should_stop = scope.create_local(Elem::Bool);
+        cpa!(scope, num_elems = len(tensor));
+        cpa!(scope, should_stop = id >= num_elems);
+        cpa!(scope, if (should_stop).then(|scope| {
+            scope.register(Branch::Return);
+        }));
+
+        let value = scope.create_local(tensor.item());
+        let bits = scope.create_local(Elem::UInt);
+        let byte = scope.create_local(Elem::UInt);
+        let result = scope.create_local(output.item());
+        let index = scope.create_local(Elem::UInt);
+        let mask = scope.create_with_value(255, Elem::UInt);
+        let shift = scope.create_with_value(8, Elem::UInt);
+        let num_bytes = scope.create_with_value(NUM_BYTES as u32, Elem::UInt);
+        let one = scope.create_with_value(1, Elem::UInt);
+
+        cpa!(scope, value = tensor[id]);
+        cpa!(scope, bits = bitcast(value));
+        cpa!(scope, index = id * num_bytes);
+
+        for _ in 0..NUM_BYTES {
+            cpa!(scope, byte = bits & mask);
+            cpa!(scope, result = cast(byte));
+            cpa!(scope, output[index] = result);
+            cpa!(scope, bits = bits >> shift);
+            cpa!(scope, index += one);
+        }
+    }
+}
+
+impl<R: JitRuntime, F: FloatElement, I: IntElement> Kernel for FloatBitsEagerKernel<R, F, I> {
+    fn define(&self) -> KernelDefinition {
+        let mut scope = Scope::root();
+        let item_input: Item = F::cube_elem().into();
+        let item_output: Item = I::cube_elem().into();
+
+        let tensor = Variable::GlobalInputArray(0, item_input);
+        let output = Variable::GlobalOutputArray(0, item_output);
+
+        FloatBitsShader { tensor, output }.expand(&mut scope);
+
+        let tensor = InputInfo::Array {
+            item: item_input,
+            visibility: Visibility::Read,
+        };
+        let output = OutputInfo::Array { item: item_output };
+
+        let info = KernelExpansion {
+            inputs: vec![tensor],
+            outputs: vec![output],
+            scope,
+        };
+
+        KernelIntegrator::new(info).integrate(KernelSettings::default())
+    }
+
+    fn id(&self) -> String {
+        format!("{:?}", core::any::TypeId::of::<Self>())
+    }
+}
+
+/// Returns the bytes of the elements of a float tensor, see
+/// [float_bits](burn_tensor::ops::FloatTensorOps::float_bits), which is only valid for `f32`
+/// elements.
+pub fn float_bits<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, F, D>,
+) -> JitTensor<R, I, 2> {
+    let tensor = into_contiguous(tensor);
+    let num_elems = tensor.shape.num_elements();
+    let output = empty::<R, I, 2>(Shape::new([num_elems, NUM_BYTES]), &tensor.device);
+    let kernel = FloatBitsEagerKernel::<R, F, I>::new();
+
+    Execution::start(kernel, tensor.client)
+        .inputs(&[TensorHandle::<R>::new(
+            &tensor.handle,
+            &tensor.strides,
+            &tensor.shape.dims,
+        )])
+        .outputs(&[TensorHandle::new(
+            &output.handle,
+            &output.strides,
+            &output.shape.dims,
+        )])
+        .execute(CubeCountSettings::Custom(calculate_cube_count_elemwise(
+            num_elems,
+            SUBCUBE_DIM_APPROX,
+        )));
+
+    output
+}
@@ -1,5 +1,7 @@
 mod base;
+mod bits;
 mod bool_cast;
 
 pub use base::*;
+pub use bits::*;
 pub use bool_cast::*;
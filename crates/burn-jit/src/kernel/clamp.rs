@@ -23,6 +23,7 @@ pub(crate) fn clamp<R: JitRuntime, E: JitElement, const D: usize>(
         input,
         Some(&[min_value, max_value]),
         true,
+        1,
         Ops::new(),
         OpsInplace::new(),
     )
@@ -1,12 +1,12 @@
 use crate::{
     binary,
     element::JitElement,
-    kernel::{binary::binary, unary::unary},
+    kernel::{binary::binary, elemwise_vectorization, unary::unary},
     tensor::JitTensor,
     unary, JitRuntime,
 };
 use burn_cube::{
-    ir::{BinaryOperator, Elem, Operator, Scope, Variable},
+    ir::{BinaryOperator, Elem, Operator, Scope, Variable, Vectorization},
     Runtime,
 };
 use std::mem;
@@ -29,7 +29,7 @@ macro_rules! comparison {
             $runtime,
             E,
             D
-        >($lhs, $rhs, Ops::new(), OpsInplaceLhs::new(), OpsInplaceRhs::new())
+        >($lhs, $rhs, Ops::new, OpsInplaceLhs::new, OpsInplaceRhs::new)
     }};
 
     (
@@ -216,9 +216,9 @@ pub fn lower_equal_elem<R: JitRuntime, E: JitElement, const D: usize>(
 fn launch_binary<K, KinplaceLhs, KernelInplaceRhs, R: JitRuntime, E, const D: usize>(
     lhs: JitTensor<R, E, D>,
     rhs: JitTensor<R, E, D>,
-    kernel: K,
-    kernel_inplace_lhs: KinplaceLhs,
-    kernel_inplace_rhs: KernelInplaceRhs,
+    kernel: impl FnOnce(Vectorization) -> K,
+    kernel_inplace_lhs: impl FnOnce(Vectorization) -> KinplaceLhs,
+    kernel_inplace_rhs: impl FnOnce(Vectorization) -> KernelInplaceRhs,
 ) -> JitTensor<R, u32, D>
 where
     K: Kernel,
@@ -227,14 +227,16 @@ where
     E: JitElement,
 {
     let can_be_used_as_bool = mem::size_of::<E>() == mem::size_of::<u32>();
+    let vectorization = elemwise_vectorization(&[&lhs, &rhs]);
 
     let output = binary::<K, KinplaceLhs, KernelInplaceRhs, R, E, D>(
         lhs,
         rhs,
         can_be_used_as_bool,
-        kernel,
-        kernel_inplace_lhs,
-        kernel_inplace_rhs,
+        vectorization,
+        kernel(vectorization),
+        kernel_inplace_lhs(vectorization),
+        kernel_inplace_rhs(vectorization),
     );
 
     // We recast the tensor type.
@@ -258,6 +260,7 @@ where
         tensor,
         Some(&[scalars]),
         can_be_used_as_bool,
+        1,
         kernel,
         kernel_inplace,
     );
@@ -0,0 +1,123 @@
+use burn_tensor::{
+    ops::{conv::calculate_conv_output_size, ConvOptions},
+    Shape,
+};
+
+#[cfg(feature = "autotune")]
+use super::conv2d_autotune;
+use super::{
+    conv2d_depthwise, conv2d_direct, conv2d_implicit_gemm, conv2d_winograd, ImplicitGemmConfig,
+    WinogradTile,
+};
+use crate::{
+    kernel::into_contiguous,
+    ops::{
+        numeric::{empty_device, zeros_device},
+        reshape,
+    },
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// The strategy to be used when launching a conv2d kernel.
+#[derive(Debug, Clone)]
+pub enum Conv2dStrategy {
+    /// A direct kernel will be used, where each unit computes one output element.
+    Direct,
+    /// An implicit GEMM kernel will be used, computing the convolution as a tiled matrix
+    /// multiplication without materializing the input patches in global memory.
+    ImplicitGemm(ImplicitGemmConfig),
+    /// A Winograd algorithm will be used, which only supports 3x3 kernels with a stride and a
+    /// dilation of 1 and a single group.
+    Winograd(WinogradTile),
+    /// A depthwise kernel will be used, where each cube computes a block of a single channel with
+    /// its kernel in shared memory. It only supports a single input channel per group.
+    Depthwise,
+    #[cfg(feature = "autotune")]
+    /// Using autotune to chose the best kernel based on runtime information.
+    Autotune,
+}
+
+impl Default for Conv2dStrategy {
+    fn default() -> Self {
+        // if autotune is enabled, default to autotune
+        #[cfg(feature = "autotune")]
+        return Conv2dStrategy::Autotune;
+
+        #[cfg(not(feature = "autotune"))]
+        Conv2dStrategy::Direct
+    }
+}
+
+/// Perform a 2D convolution using the given strategy.
+pub fn conv2d<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: Option<JitTensor<R, E, 1>>,
+    options: ConvOptions<2>,
+    strategy: Conv2dStrategy,
+) -> JitTensor<R, E, 4> {
+    let input = into_contiguous(input);
+    let weight = into_contiguous(weight);
+    let output = init_conv2d_output(&input, &weight, &options);
+    let bias = init_conv2d_bias(bias, &output);
+
+    match strategy {
+        Conv2dStrategy::Direct => conv2d_direct(input, weight, bias, output, options),
+        Conv2dStrategy::ImplicitGemm(config) => {
+            conv2d_implicit_gemm(input, weight, bias, output, options, config)
+        }
+        Conv2dStrategy::Winograd(tile) => {
+            conv2d_winograd(input, weight, bias, output, options, tile)
+        }
+        Conv2dStrategy::Depthwise => conv2d_depthwise(input, weight, bias, output, options),
+        #[cfg(feature = "autotune")]
+        Conv2dStrategy::Autotune => conv2d_autotune(input, weight, bias, output, options),
+    }
+}
+
+/// Creates an empty output tensor with the conv2d output shape.
+fn init_conv2d_output<R: JitRuntime, E: FloatElement>(
+    input: &JitTensor<R, E, 4>,
+    weight: &JitTensor<R, E, 4>,
+    options: &ConvOptions<2>,
+) -> JitTensor<R, E, 4> {
+    let [batch_size, _, in_height, in_width] = input.shape.dims;
+    let [out_channels, _, kernel_0, kernel_1] = weight.shape.dims;
+
+    let out_0 = calculate_conv_output_size(
+        kernel_0,
+        options.stride[0],
+        options.padding[0],
+        options.dilation[0],
+        in_height,
+    );
+    let out_1 = calculate_conv_output_size(
+        kernel_1,
+        options.stride[1],
+        options.padding[1],
+        options.dilation[1],
+        in_width,
+    );
+
+    let shape_out = Shape::new([batch_size, out_channels, out_0, out_1]);
+
+    empty_device(input.client.clone(), input.device.clone(), shape_out)
+}
+
+/// Reshape the bias to `[out_channels, 1, 1, 1]`, using zeros when there is no bias.
+fn init_conv2d_bias<R: JitRuntime, E: FloatElement>(
+    bias: Option<JitTensor<R, E, 1>>,
+    output: &JitTensor<R, E, 4>,
+) -> JitTensor<R, E, 4> {
+    match bias {
+        Some(bias) => {
+            let shape = Shape::from([bias.shape.dims[0], 1, 1, 1]);
+            reshape(bias, shape)
+        }
+        None => {
+            let shape = Shape::from([output.shape.dims[1], 1, 1, 1]);
+            zeros_device(output.client.clone(), output.device.clone(), shape)
+        }
+    }
+}
@@ -1,19 +1,8 @@
 use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
 
-use burn_tensor::{
-    ops::{conv::calculate_conv_output_size, ConvOptions},
-    Shape,
-};
-
-use crate::{
-    kernel::into_contiguous,
-    ops::{
-        numeric::{empty_device, zeros_device},
-        reshape,
-    },
-    tensor::JitTensor,
-    FloatElement, JitRuntime,
-};
+use burn_tensor::ops::ConvOptions;
+
+use crate::{tensor::JitTensor, FloatElement, JitRuntime};
 
 #[derive(CubeLaunch)]
 struct Conv2dArgs {
@@ -52,7 +41,7 @@ fn conv2d_kernel<F: Float>(
     let oh = ABSOLUTE_POS / output.stride(2) % output.shape(2);
     let ow = ABSOLUTE_POS / output.stride(3) % output.shape(3);
 
-    let g = (weight.shape(0) + oc) % args.groups;
+    let g = oc / (weight.shape(0) / args.groups);
     let ic_start = in_channels * g;
     let ic_end = ic_start + in_channels;
     let mut sum = bias[oc];
@@ -115,50 +104,15 @@ fn conv2d_kernel<F: Float>(
     output[ABSOLUTE_POS] = sum;
 }
 
-pub(crate) fn conv2d<R: JitRuntime, E: FloatElement>(
+/// Direct convolution, where each unit computes one element of the output.
+pub(crate) fn conv2d_direct<R: JitRuntime, E: FloatElement>(
     input: JitTensor<R, E, 4>,
     weight: JitTensor<R, E, 4>,
-    bias: Option<JitTensor<R, E, 1>>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
     options: ConvOptions<2>,
 ) -> JitTensor<R, E, 4> {
-    let input = into_contiguous(input);
-    let weight = into_contiguous(weight);
-    let [batch_size, _, in_height, in_width] = input.shape.dims;
-    let [out_channels, _, kernel_0, kernel_1] = weight.shape.dims;
-
-    let out_0 = calculate_conv_output_size(
-        kernel_0,
-        options.stride[0],
-        options.padding[0],
-        options.dilation[0],
-        in_height,
-    );
-    let out_1 = calculate_conv_output_size(
-        kernel_1,
-        options.stride[1],
-        options.padding[1],
-        options.dilation[1],
-        in_width,
-    );
-
-    let shape_out = Shape::new([batch_size, out_channels, out_0, out_1]);
-
-    let output = empty_device(
-        input.client.clone(),
-        input.device.clone(),
-        shape_out.clone(),
-    );
-
-    let bias = match bias {
-        Some(bias) => {
-            let shape = Shape::from([bias.shape.dims[0], 1, 1, 1]);
-            reshape(bias, shape)
-        }
-        None => {
-            let shape = Shape::from([output.shape.dims[0], 1, 1, 1]);
-            zeros_device(input.client.clone(), input.device.clone(), shape)
-        }
-    };
+    let [_, _, kernel_0, kernel_1] = weight.shape.dims;
 
     let num_elems_output = output.shape.num_elements();
     let workgroup = calculate_cube_count_elemwise(num_elems_output, SUBCUBE_DIM_APPROX);
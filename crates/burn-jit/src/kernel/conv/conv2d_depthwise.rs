@@ -0,0 +1,156 @@
+use burn_cube::prelude::*;
+
+use burn_tensor::{ops::ConvOptions, Shape};
+
+use crate::{tensor::JitTensor, FloatElement, JitRuntime};
+
+/// Number of units per cube of the depthwise kernel, each of them computing one output position.
+const DEPTHWISE_CUBE_DIM: u32 = 256;
+
+/// Whether the [depthwise](super::Conv2dStrategy::Depthwise) kernel supports the convolution,
+/// which is the case when each group has a single input channel.
+pub fn is_depthwise_supported(weight_shape: &Shape<4>) -> bool {
+    weight_shape.dims[1] == 1
+}
+
+#[derive(CubeLaunch)]
+struct DepthwiseArgs {
+    conv_stride_0: UInt,
+    conv_stride_1: UInt,
+    dilation_0: UInt,
+    dilation_1: UInt,
+    padding_0: UInt,
+    padding_1: UInt,
+}
+
+/// Each cube computes a block of a single output channel plane, with the kernel of the channel
+/// loaded once in shared memory.
+#[cube(launch)]
+fn conv2d_depthwise_kernel<F: Float>(
+    input: Tensor<F>,
+    weight: Tensor<F>,
+    bias: Tensor<F>,
+    mut output: Tensor<F>,
+    args: DepthwiseArgs,
+    kernel_area: Comptime<UInt>,
+) {
+    let mut shared_weight = SharedMemory::<F>::new(Comptime::get(kernel_area));
+
+    let kernel_len = Comptime::runtime(kernel_area);
+    let kernel_size_1 = weight.shape(3);
+    let out_width = output.shape(3);
+    let out_area = output.shape(2) * out_width;
+
+    let b = CUBE_POS_Z;
+    let oc = CUBE_POS_Y;
+    let ic = oc / (output.shape(1) / input.shape(1));
+
+    let num_loads = (kernel_len + CUBE_DIM_X - UInt::new(1)) / CUBE_DIM_X;
+    let index_weight_0 = oc * weight.stride(0);
+
+    for i in range(0u32, num_loads, Comptime::new(false)) {
+        let index = UNIT_POS_X + i * CUBE_DIM_X;
+
+        if index < kernel_len {
+            shared_weight[index] = weight[index_weight_0 + index];
+        }
+    }
+
+    sync_units();
+
+    let position = CUBE_POS_X * CUBE_DIM_X + UNIT_POS_X;
+
+    if position < out_area {
+        let oh = position / out_width;
+        let ow = position % out_width;
+
+        let border_top = args.padding_0;
+        let border_left = args.padding_1;
+        let border_bottom = input.shape(2) + args.padding_0;
+        let border_right = input.shape(3) + args.padding_1;
+
+        let input_stride_2 = input.stride(2);
+        let input_stride_3 = input.stride(3);
+        let index_input_01 = b * input.stride(0) + ic * input.stride(1);
+
+        let ih_base = oh * args.conv_stride_0;
+        let iw_base = ow * args.conv_stride_1;
+
+        let mut sum = bias[oc];
+
+        for kh in range(0u32, weight.shape(2), Comptime::new(false)) {
+            for kw in range(0u32, kernel_size_1, Comptime::new(false)) {
+                let ih = kh * args.dilation_0 + ih_base;
+                let iw = kw * args.dilation_1 + iw_base;
+
+                let within_padding = ih >= border_top
+                    && ih < border_bottom
+                    && iw >= border_left
+                    && iw < border_right;
+
+                if within_padding {
+                    let index_input = index_input_01
+                        + (ih - args.padding_0) * input_stride_2
+                        + (iw - args.padding_1) * input_stride_3;
+
+                    sum += input[index_input] * shared_weight[kh * kernel_size_1 + kw];
+                }
+            }
+        }
+
+        let index_output = b * output.stride(0)
+            + oc * output.stride(1)
+            + oh * output.stride(2)
+            + ow * output.stride(3);
+
+        output[index_output] = sum;
+    }
+}
+
+/// Depthwise convolution, where each input channel is convolved separately with its own kernels.
+pub(crate) fn conv2d_depthwise<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvOptions<2>,
+) -> JitTensor<R, E, 4> {
+    assert!(
+        is_depthwise_supported(&weight.shape),
+        "Depthwise convolution only supports a single input channel per group."
+    );
+
+    let [batch_size, out_channels, out_height, out_width] = output.shape.dims;
+    let [_, _, kernel_0, kernel_1] = weight.shape.dims;
+
+    let cube_count = CubeCount::new(
+        f32::ceil((out_height * out_width) as f32 / DEPTHWISE_CUBE_DIM as f32) as u32,
+        out_channels as u32,
+        batch_size as u32,
+    );
+    let settings = KernelSettings::default()
+        .vectorize_input(0, 1)
+        .vectorize_output(0, 1)
+        .cube_dim(CubeDim::new(DEPTHWISE_CUBE_DIM, 1, 1));
+
+    conv2d_depthwise_kernel_launch::<E::CubeElement, R>(
+        input.client,
+        cube_count,
+        settings,
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&weight.handle, &weight.strides, &weight.shape.dims),
+        TensorHandle::new(&bias.handle, &bias.strides, &bias.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        DepthwiseArgsLaunch::new(
+            options.stride[0] as u32,
+            options.stride[1] as u32,
+            options.dilation[0] as u32,
+            options.dilation[1] as u32,
+            options.padding[0] as u32,
+            options.padding[1] as u32,
+        ),
+        UInt::new((kernel_0 * kernel_1) as u32),
+    );
+
+    output
+}
@@ -0,0 +1,247 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+
+use burn_tensor::{ops::ConvOptions, Shape};
+
+use crate::{
+    kernel::{into_contiguous, reduce::sum_dim},
+    ops::{numeric::empty_device, reshape, swap_dims},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[derive(CubeLaunch)]
+struct Conv2dGradArgs {
+    conv_stride_0: UInt,
+    conv_stride_1: UInt,
+    dilation_0: UInt,
+    dilation_1: UInt,
+    padding_0: UInt,
+    padding_1: UInt,
+    groups: UInt,
+}
+
+/// Each unit accumulates the gradient of one input element over the output channels of its group.
+#[cube(launch)]
+fn conv2d_input_grad_kernel<F: Float>(
+    output_grad: Tensor<F>,
+    weight: Tensor<F>,
+    mut input_grad: Tensor<F>,
+    args: Conv2dGradArgs,
+) {
+    if ABSOLUTE_POS >= input_grad.len() {
+        return;
+    }
+
+    let b = ABSOLUTE_POS / input_grad.stride(0) % input_grad.shape(0);
+    let ic = ABSOLUTE_POS / input_grad.stride(1) % input_grad.shape(1);
+    let ih = ABSOLUTE_POS / input_grad.stride(2) % input_grad.shape(2);
+    let iw = ABSOLUTE_POS / input_grad.stride(3) % input_grad.shape(3);
+
+    let in_channels_per_group = weight.shape(1);
+    let out_channels_per_group = weight.shape(0) / args.groups;
+    let oc_start = ic / in_channels_per_group * out_channels_per_group;
+    let oc_end = oc_start + out_channels_per_group;
+    let index_weight_1 = ic % in_channels_per_group * weight.stride(1);
+
+    let out_height = output_grad.shape(2);
+    let out_width = output_grad.shape(3);
+    let index_output_0 = b * output_grad.stride(0);
+
+    let ih_pad = ih + args.padding_0;
+    let iw_pad = iw + args.padding_1;
+
+    let mut sum = F::new(0.);
+
+    for oc in range(oc_start, oc_end, Comptime::new(false)) {
+        let index_output_1 = index_output_0 + oc * output_grad.stride(1);
+        let index_weight_01 = oc * weight.stride(0) + index_weight_1;
+
+        for kh in range(0u32, weight.shape(2), Comptime::new(false)) {
+            let kh_offset = kh * args.dilation_0;
+
+            if ih_pad >= kh_offset {
+                let oh_strided = ih_pad - kh_offset;
+                let oh = oh_strided / args.conv_stride_0;
+
+                if oh_strided % args.conv_stride_0 == UInt::new(0) && oh < out_height {
+                    for kw in range(0u32, weight.shape(3), Comptime::new(false)) {
+                        let kw_offset = kw * args.dilation_1;
+
+                        if iw_pad >= kw_offset {
+                            let ow_strided = iw_pad - kw_offset;
+                            let ow = ow_strided / args.conv_stride_1;
+
+                            if ow_strided % args.conv_stride_1 == UInt::new(0) && ow < out_width {
+                                let index_output = index_output_1
+                                    + oh * output_grad.stride(2)
+                                    + ow * output_grad.stride(3);
+                                let index_weight =
+                                    index_weight_01 + kh * weight.stride(2) + kw * weight.stride(3);
+
+                                sum += output_grad[index_output] * weight[index_weight];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    input_grad[ABSOLUTE_POS] = sum;
+}
+
+/// Each unit accumulates the gradient of one weight element over the output positions of one
+/// batch item, the partial gradients `[batch_size, weight_size]` being summed afterward.
+#[cube(launch)]
+fn conv2d_weight_grad_kernel<F: Float>(
+    input: Tensor<F>,
+    output_grad: Tensor<F>,
+    weight: Tensor<F>,
+    mut weight_grad: Tensor<F>,
+    args: Conv2dGradArgs,
+) {
+    if ABSOLUTE_POS >= weight_grad.len() {
+        return;
+    }
+
+    let b = ABSOLUTE_POS / weight_grad.stride(0) % weight_grad.shape(0);
+    let index_weight = ABSOLUTE_POS % weight_grad.shape(1);
+
+    let oc = index_weight / weight.stride(0) % weight.shape(0);
+    let ic_group = index_weight / weight.stride(1) % weight.shape(1);
+    let kh = index_weight / weight.stride(2) % weight.shape(2);
+    let kw = index_weight / weight.stride(3) % weight.shape(3);
+
+    let out_channels_per_group = weight.shape(0) / args.groups;
+    let ic = oc / out_channels_per_group * weight.shape(1) + ic_group;
+
+    let border_top = args.padding_0;
+    let border_left = args.padding_1;
+    let border_bottom = input.shape(2) + args.padding_0;
+    let border_right = input.shape(3) + args.padding_1;
+
+    let index_input_01 = b * input.stride(0) + ic * input.stride(1);
+    let index_output_01 = b * output_grad.stride(0) + oc * output_grad.stride(1);
+
+    let kh_offset = kh * args.dilation_0;
+    let kw_offset = kw * args.dilation_1;
+
+    let mut sum = F::new(0.);
+
+    for oh in range(0u32, output_grad.shape(2), Comptime::new(false)) {
+        let ih = oh * args.conv_stride_0 + kh_offset;
+
+        if ih >= border_top && ih < border_bottom {
+            let index_input_2 = index_input_01 + (ih - args.padding_0) * input.stride(2);
+            let index_output_2 = index_output_01 + oh * output_grad.stride(2);
+
+            for ow in range(0u32, output_grad.shape(3), Comptime::new(false)) {
+                let iw = ow * args.conv_stride_1 + kw_offset;
+
+                if iw >= border_left && iw < border_right {
+                    let index_input = index_input_2 + (iw - args.padding_1) * input.stride(3);
+                    let index_output = index_output_2 + ow * output_grad.stride(3);
+
+                    sum += input[index_input] * output_grad[index_output];
+                }
+            }
+        }
+    }
+
+    weight_grad[ABSOLUTE_POS] = sum;
+}
+
+/// Backward pass of a grouped conv2d, including depthwise convolutions, computed with dedicated
+/// kernels instead of one convolution per group.
+///
+/// Returns the gradients of the input, of the weight and of the bias when there is one.
+pub fn conv2d_grouped_backward<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: Option<JitTensor<R, E, 1>>,
+    output_grad: JitTensor<R, E, 4>,
+    options: ConvOptions<2>,
+) -> (
+    JitTensor<R, E, 4>,
+    JitTensor<R, E, 4>,
+    Option<JitTensor<R, E, 1>>,
+) {
+    let input = into_contiguous(input);
+    let weight = into_contiguous(weight);
+    let output_grad = into_contiguous(output_grad);
+
+    let [batch_size, out_channels, out_height, out_width] = output_grad.shape.dims;
+    let args = || {
+        Conv2dGradArgsLaunch::new(
+            options.stride[0] as u32,
+            options.stride[1] as u32,
+            options.dilation[0] as u32,
+            options.dilation[1] as u32,
+            options.padding[0] as u32,
+            options.padding[1] as u32,
+            options.groups as u32,
+        )
+    };
+
+    let input_grad = empty_device(
+        input.client.clone(),
+        input.device.clone(),
+        input.shape.clone(),
+    );
+    conv2d_input_grad_kernel_launch::<E::CubeElement, R>(
+        input.client.clone(),
+        calculate_cube_count_elemwise(input_grad.shape.num_elements(), SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(
+            &output_grad.handle,
+            &output_grad.strides,
+            &output_grad.shape.dims,
+        ),
+        TensorHandle::new(&weight.handle, &weight.strides, &weight.shape.dims),
+        TensorHandle::new(
+            &input_grad.handle,
+            &input_grad.strides,
+            &input_grad.shape.dims,
+        ),
+        args(),
+    );
+
+    let weight_grad = empty_device(
+        input.client.clone(),
+        input.device.clone(),
+        Shape::new([batch_size, weight.shape.num_elements()]),
+    );
+    conv2d_weight_grad_kernel_launch::<E::CubeElement, R>(
+        input.client.clone(),
+        calculate_cube_count_elemwise(weight_grad.shape.num_elements(), SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(
+            &output_grad.handle,
+            &output_grad.strides,
+            &output_grad.shape.dims,
+        ),
+        TensorHandle::new(&weight.handle, &weight.strides, &weight.shape.dims),
+        TensorHandle::new(
+            &weight_grad.handle,
+            &weight_grad.strides,
+            &weight_grad.shape.dims,
+        ),
+        args(),
+    );
+    let weight_grad = sum_dim(weight_grad, 0, Default::default());
+    let weight_grad = reshape(weight_grad, weight.shape.clone());
+
+    let bias_grad = bias.map(|bias| {
+        let grad = swap_dims(output_grad, 0, 1);
+        let grad = reshape(
+            grad,
+            Shape::new([out_channels, batch_size * out_height * out_width]),
+        );
+        let grad = sum_dim(grad, 1, Default::default());
+
+        reshape(grad, bias.shape)
+    });
+
+    (input_grad, weight_grad, bias_grad)
+}
@@ -0,0 +1,283 @@
+use burn_cube::{prelude::*, Compiler};
+
+use burn_tensor::ops::ConvOptions;
+
+use crate::{tensor::JitTensor, FloatElement, JitRuntime};
+
+/// Tile sizes of the [implicit GEMM](super::Conv2dStrategy::ImplicitGemm) conv2d kernel.
+///
+/// The convolution is computed as a matrix multiplication between the weight
+/// `[out_channels, in_channels * kernel_height * kernel_width]` and the patches of the input
+/// `[in_channels * kernel_height * kernel_width, batch_size * out_height * out_width]`, each cube
+/// computing a block of the output. The patches are gathered in shared memory on the fly, so they
+/// are never materialized in global memory.
+#[derive(Debug, Clone, Copy)]
+pub struct ImplicitGemmConfig {
+    /// Block size along the output channels.
+    pub block_m: usize,
+    /// Block size along the patches, which is the common dimension.
+    pub block_k: usize,
+    /// Block size along the output positions.
+    pub block_n: usize,
+}
+
+impl Default for ImplicitGemmConfig {
+    fn default() -> Self {
+        Self {
+            block_m: 16,
+            block_k: 16,
+            block_n: 16,
+        }
+    }
+}
+
+/// Compilation time information of the implicit GEMM kernel.
+#[derive(Debug, Clone, Copy)]
+struct ImplicitGemmTiling {
+    block_m: UInt,
+    block_k: UInt,
+    block_n: UInt,
+    weight_tile_size: UInt,
+    input_tile_size: UInt,
+    weight_loads: UInt,
+    input_loads: UInt,
+}
+
+impl Init for ImplicitGemmTiling {
+    fn init(self, _context: &mut CubeContext) -> Self {
+        self
+    }
+}
+
+impl ImplicitGemmTiling {
+    fn new(config: &ImplicitGemmConfig) -> Self {
+        let num_units = config.block_m * config.block_n;
+        let weight_tile_size = config.block_m * config.block_k;
+        let input_tile_size = config.block_k * config.block_n;
+
+        Self {
+            block_m: UInt::new(config.block_m as u32),
+            block_k: UInt::new(config.block_k as u32),
+            block_n: UInt::new(config.block_n as u32),
+            weight_tile_size: UInt::new(weight_tile_size as u32),
+            input_tile_size: UInt::new(input_tile_size as u32),
+            weight_loads: UInt::new(f32::ceil(weight_tile_size as f32 / num_units as f32) as u32),
+            input_loads: UInt::new(f32::ceil(input_tile_size as f32 / num_units as f32) as u32),
+        }
+    }
+}
+
+#[derive(CubeLaunch)]
+struct ImplicitGemmArgs {
+    conv_stride_0: UInt,
+    conv_stride_1: UInt,
+    dilation_0: UInt,
+    dilation_1: UInt,
+    padding_0: UInt,
+    padding_1: UInt,
+    groups: UInt,
+}
+
+#[cube(launch)]
+fn conv2d_implicit_gemm_kernel<F: Float>(
+    input: Tensor<F>,
+    weight: Tensor<F>,
+    bias: Tensor<F>,
+    mut output: Tensor<F>,
+    args: ImplicitGemmArgs,
+    tiling: Comptime<ImplicitGemmTiling>,
+) {
+    let block_m = Comptime::map(tiling, |t: ImplicitGemmTiling| t.block_m);
+    let block_k = Comptime::map(tiling, |t: ImplicitGemmTiling| t.block_k);
+    let block_n = Comptime::map(tiling, |t: ImplicitGemmTiling| t.block_n);
+    let weight_tile_size = Comptime::map(tiling, |t: ImplicitGemmTiling| t.weight_tile_size);
+    let input_tile_size = Comptime::map(tiling, |t: ImplicitGemmTiling| t.input_tile_size);
+    let weight_loads = Comptime::map(tiling, |t: ImplicitGemmTiling| t.weight_loads);
+    let input_loads = Comptime::map(tiling, |t: ImplicitGemmTiling| t.input_loads);
+
+    let mut shared_weight = SharedMemory::<F>::new(Comptime::get(weight_tile_size));
+    let mut shared_input = SharedMemory::<F>::new(Comptime::get(input_tile_size));
+
+    let tile_m = Comptime::runtime(block_m);
+    let tile_k = Comptime::runtime(block_k);
+    let tile_n = Comptime::runtime(block_n);
+    let weight_tile_len = Comptime::runtime(weight_tile_size);
+    let input_tile_len = Comptime::runtime(input_tile_size);
+    let num_units = tile_m * tile_n;
+
+    let kernel_size_1 = weight.shape(3);
+    let kernel_area = weight.shape(2) * kernel_size_1;
+    let in_channels_per_group = weight.shape(1);
+    let out_channels_per_group = weight.shape(0) / args.groups;
+    let out_width = output.shape(3);
+    let out_area = output.shape(2) * out_width;
+
+    // Dimensions of the matrix multiplication.
+    let size_k = in_channels_per_group * kernel_area;
+    let size_n = output.shape(0) * out_area;
+
+    let border_top = args.padding_0;
+    let border_left = args.padding_1;
+    let border_bottom = input.shape(2) + args.padding_0;
+    let border_right = input.shape(3) + args.padding_1;
+
+    let input_stride_0 = input.stride(0);
+    let input_stride_1 = input.stride(1);
+    let input_stride_2 = input.stride(2);
+    let input_stride_3 = input.stride(3);
+    let weight_stride_0 = weight.stride(0);
+
+    // Each group is an independent matrix multiplication.
+    let oc_start = CUBE_POS_Z * out_channels_per_group;
+    let ic_start = CUBE_POS_Z * in_channels_per_group;
+    let row_start = CUBE_POS_Y * tile_m;
+    let col_start = CUBE_POS_X * tile_n;
+    let row = row_start + UNIT_POS_Y;
+    let col = col_start + UNIT_POS_X;
+
+    let mut sum = F::new(0.);
+    let num_k_tiles = (size_k + tile_k - UInt::new(1)) / tile_k;
+
+    for k_tile in range(0u32, num_k_tiles, Comptime::new(false)) {
+        let k_start = k_tile * tile_k;
+
+        // Load the block of the weight, which is contiguous along the patches.
+        for i in range(0u32, Comptime::get(weight_loads), Comptime::new(true)) {
+            let index = UNIT_POS + i * num_units;
+
+            if index < weight_tile_len {
+                let m = row_start + index / tile_k;
+                let k = k_start + index % tile_k;
+                let mut value = F::new(0.);
+
+                if m < out_channels_per_group && k < size_k {
+                    let index_weight = (oc_start + m) * weight_stride_0 + k;
+                    value = weight[index_weight];
+                }
+
+                shared_weight[index] = value;
+            }
+        }
+
+        // Gather the block of the input patches, padding with zeros.
+        for i in range(0u32, Comptime::get(input_loads), Comptime::new(true)) {
+            let index = UNIT_POS + i * num_units;
+
+            if index < input_tile_len {
+                let k = k_start + index / tile_n;
+                let n = col_start + index % tile_n;
+                let mut value = F::new(0.);
+
+                if k < size_k && n < size_n {
+                    let ic = ic_start + k / kernel_area;
+                    let kh = k % kernel_area / kernel_size_1;
+                    let kw = k % kernel_size_1;
+
+                    let b = n / out_area;
+                    let oh = n % out_area / out_width;
+                    let ow = n % out_width;
+
+                    let ih = oh * args.conv_stride_0 + kh * args.dilation_0;
+                    let iw = ow * args.conv_stride_1 + kw * args.dilation_1;
+
+                    let within_padding = ih >= border_top
+                        && ih < border_bottom
+                        && iw >= border_left
+                        && iw < border_right;
+
+                    if within_padding {
+                        let index_input = b * input_stride_0
+                            + ic * input_stride_1
+                            + (ih - args.padding_0) * input_stride_2
+                            + (iw - args.padding_1) * input_stride_3;
+                        value = input[index_input];
+                    }
+                }
+
+                shared_input[index] = value;
+            }
+        }
+
+        sync_units();
+
+        for k in range(0u32, Comptime::get(block_k), Comptime::new(true)) {
+            let index_weight = UNIT_POS_Y * tile_k + k;
+            let index_input = k * tile_n + UNIT_POS_X;
+            sum += shared_weight[index_weight] * shared_input[index_input];
+        }
+
+        sync_units();
+    }
+
+    if row < out_channels_per_group && col < size_n {
+        let oc = oc_start + row;
+        let b = col / out_area;
+        let oh = col % out_area / out_width;
+        let ow = col % out_width;
+
+        let index_output = b * output.stride(0)
+            + oc * output.stride(1)
+            + oh * output.stride(2)
+            + ow * output.stride(3);
+
+        output[index_output] = sum + bias[oc];
+    }
+}
+
+/// Implicit GEMM convolution, where each cube computes a block of the output with the input
+/// patches gathered in shared memory.
+pub(crate) fn conv2d_implicit_gemm<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvOptions<2>,
+    config: ImplicitGemmConfig,
+) -> JitTensor<R, E, 4> {
+    assert!(
+        (config.block_m * config.block_k + config.block_k * config.block_n)
+            * core::mem::size_of::<E>()
+            <= <R::Compiler as Compiler>::max_shared_memory_size(),
+        "Shared memory limit will be busted."
+    );
+
+    let [batch_size, out_channels, out_height, out_width] = output.shape.dims;
+    let out_channels_per_group = out_channels / options.groups;
+    let size_n = batch_size * out_height * out_width;
+
+    let cube_count = CubeCount::new(
+        f32::ceil(size_n as f32 / config.block_n as f32) as u32,
+        f32::ceil(out_channels_per_group as f32 / config.block_m as f32) as u32,
+        options.groups as u32,
+    );
+    let settings = KernelSettings::default()
+        .vectorize_input(0, 1)
+        .vectorize_output(0, 1)
+        .cube_dim(CubeDim::new(
+            config.block_n as u32,
+            config.block_m as u32,
+            1,
+        ));
+
+    conv2d_implicit_gemm_kernel_launch::<E::CubeElement, R>(
+        input.client,
+        cube_count,
+        settings,
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&weight.handle, &weight.strides, &weight.shape.dims),
+        TensorHandle::new(&bias.handle, &bias.strides, &bias.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        ImplicitGemmArgsLaunch::new(
+            options.stride[0] as u32,
+            options.stride[1] as u32,
+            options.dilation[0] as u32,
+            options.dilation[1] as u32,
+            options.padding[0] as u32,
+            options.padding[1] as u32,
+            options.groups as u32,
+        ),
+        ImplicitGemmTiling::new(&config),
+    );
+
+    output
+}
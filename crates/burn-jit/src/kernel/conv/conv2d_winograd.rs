@@ -0,0 +1,294 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+
+use burn_tensor::{ops::ConvOptions, Data, ElementConversion, Shape};
+
+use crate::{
+    kernel::matmul::{matmul, MatmulStrategy, Tiling2dConfig},
+    ops::{from_data, numeric::empty_device, permute, reshape, swap_dims},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// Output tile size of the [Winograd](super::Conv2dStrategy::Winograd) conv2d algorithm.
+///
+/// The algorithm only supports 3x3 kernels with a stride and a dilation of 1 and a single group,
+/// see [is_winograd_supported].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WinogradTile {
+    /// F(2x2, 3x3), computing 2x2 outputs from 4x4 input tiles with 2.25 times fewer
+    /// multiplications than the direct convolution.
+    F2x2,
+    /// F(4x4, 3x3), computing 4x4 outputs from 6x6 input tiles with 4 times fewer
+    /// multiplications than the direct convolution, at the cost of a lower numerical accuracy.
+    F4x4,
+}
+
+impl WinogradTile {
+    fn output_size(&self) -> usize {
+        match self {
+            WinogradTile::F2x2 => 2,
+            WinogradTile::F4x4 => 4,
+        }
+    }
+
+    /// The input transform `Bᵀ [alpha, alpha]`, the weight transform `G [alpha, 3]` and the
+    /// output transform `Aᵀ [m, alpha]` from "Fast Algorithms for Convolutional Neural Networks"
+    /// (Lavin & Gray, 2016), where `alpha = m + 2`.
+    #[rustfmt::skip]
+    fn transforms(&self) -> (Vec<f32>, Vec<f32>, Vec<f32>) {
+        match self {
+            WinogradTile::F2x2 => (
+                vec![
+                    1., 0., -1., 0.,
+                    0., 1., 1., 0.,
+                    0., -1., 1., 0.,
+                    0., 1., 0., -1.,
+                ],
+                vec![
+                    1., 0., 0.,
+                    0.5, 0.5, 0.5,
+                    0.5, -0.5, 0.5,
+                    0., 0., 1.,
+                ],
+                vec![
+                    1., 1., 1., 0.,
+                    0., 1., -1., -1.,
+                ],
+            ),
+            WinogradTile::F4x4 => (
+                vec![
+                    4., 0., -5., 0., 1., 0.,
+                    0., -4., -4., 1., 1., 0.,
+                    0., 4., -4., -1., 1., 0.,
+                    0., -2., -1., 2., 1., 0.,
+                    0., 2., -1., -2., 1., 0.,
+                    0., 4., 0., -5., 0., 1.,
+                ],
+                vec![
+                    1. / 4., 0., 0.,
+                    -1. / 6., -1. / 6., -1. / 6.,
+                    -1. / 6., 1. / 6., -1. / 6.,
+                    1. / 24., 1. / 12., 1. / 6.,
+                    1. / 24., -1. / 12., 1. / 6.,
+                    0., 0., 1.,
+                ],
+                vec![
+                    1., 1., 1., 1., 1., 0.,
+                    0., 1., -1., 2., -2., 0.,
+                    0., 1., 1., 4., 4., 0.,
+                    0., 1., -1., 8., -8., 1.,
+                ],
+            ),
+        }
+    }
+}
+
+/// Whether the [Winograd](super::Conv2dStrategy::Winograd) algorithm supports the convolution.
+pub fn is_winograd_supported(weight_shape: &Shape<4>, options: &ConvOptions<2>) -> bool {
+    let [_, _, kernel_0, kernel_1] = weight_shape.dims;
+
+    kernel_0 == 3
+        && kernel_1 == 3
+        && options.stride == [1, 1]
+        && options.dilation == [1, 1]
+        && options.groups == 1
+}
+
+#[derive(CubeLaunch)]
+struct WinogradArgs {
+    padding_0: UInt,
+    padding_1: UInt,
+    tile_size: UInt,
+    tiles_0: UInt,
+    tiles_1: UInt,
+}
+
+/// Gather the overlapping input tiles `[in_channels, num_tiles, alpha, alpha]`, padding with
+/// zeros.
+#[cube(launch)]
+fn winograd_input_tiles_kernel<F: Float>(
+    input: Tensor<F>,
+    mut tiles: Tensor<F>,
+    args: WinogradArgs,
+) {
+    if ABSOLUTE_POS >= tiles.len() {
+        return;
+    }
+
+    let ic = ABSOLUTE_POS / tiles.stride(0) % tiles.shape(0);
+    let p = ABSOLUTE_POS / tiles.stride(1) % tiles.shape(1);
+    let i = ABSOLUTE_POS / tiles.stride(2) % tiles.shape(2);
+    let j = ABSOLUTE_POS / tiles.stride(3) % tiles.shape(3);
+
+    let tile_1 = p % args.tiles_1;
+    let tile_0 = p / args.tiles_1 % args.tiles_0;
+    let b = p / (args.tiles_1 * args.tiles_0);
+
+    let ih = tile_0 * args.tile_size + i;
+    let iw = tile_1 * args.tile_size + j;
+
+    let border_top = args.padding_0;
+    let border_left = args.padding_1;
+    let border_bottom = input.shape(2) + args.padding_0;
+    let border_right = input.shape(3) + args.padding_1;
+
+    let within_padding =
+        ih >= border_top && ih < border_bottom && iw >= border_left && iw < border_right;
+
+    let mut value = F::new(0.);
+
+    if within_padding {
+        let index_input = b * input.stride(0)
+            + ic * input.stride(1)
+            + (ih - args.padding_0) * input.stride(2)
+            + (iw - args.padding_1) * input.stride(3);
+        value = input[index_input];
+    }
+
+    tiles[ABSOLUTE_POS] = value;
+}
+
+/// Scatter the output tiles `[out_channels, num_tiles, m, m]` to the output, cropping the tiles
+/// exceeding the output.
+#[cube(launch)]
+fn winograd_output_tiles_kernel<F: Float>(
+    tiles: Tensor<F>,
+    bias: Tensor<F>,
+    mut output: Tensor<F>,
+    args: WinogradArgs,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let b = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let oc = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let oh = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let ow = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let p = (b * args.tiles_0 + oh / args.tile_size) * args.tiles_1 + ow / args.tile_size;
+
+    let index_tiles = oc * tiles.stride(0)
+        + p * tiles.stride(1)
+        + oh % args.tile_size * tiles.stride(2)
+        + ow % args.tile_size * tiles.stride(3);
+
+    output[ABSOLUTE_POS] = tiles[index_tiles] + bias[oc];
+}
+
+/// Winograd convolution, where the transformed tiles of the input and of the weight are
+/// multiplied with a batched matrix multiplication over the `alpha * alpha` tile positions.
+pub(crate) fn conv2d_winograd<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvOptions<2>,
+    tile: WinogradTile,
+) -> JitTensor<R, E, 4> {
+    assert!(
+        is_winograd_supported(&weight.shape, &options),
+        "Winograd convolution only supports 3x3 kernels with a stride and a dilation of 1 and a \
+         single group."
+    );
+
+    let [batch_size, in_channels, _, _] = input.shape.dims;
+    let [_, out_channels, out_height, out_width] = output.shape.dims;
+    let tile_size = tile.output_size();
+    let alpha = tile_size + 2;
+    let tiles_0 = f32::ceil(out_height as f32 / tile_size as f32) as usize;
+    let tiles_1 = f32::ceil(out_width as f32 / tile_size as f32) as usize;
+    let num_tiles = batch_size * tiles_0 * tiles_1;
+
+    let (bt, g, at) = tile.transforms();
+    let bt = transform_matrix::<R, E>(bt, alpha, alpha, &input.device);
+    let g = transform_matrix::<R, E>(g, alpha, 3, &input.device);
+    let at = transform_matrix::<R, E>(at, tile_size, alpha, &input.device);
+
+    // U = G g Gᵀ, as [alpha², out_channels, in_channels].
+    let weight = reshape(weight, Shape::new([out_channels * in_channels, 3, 3]));
+    let weight = matmul_tiled(g.clone(), weight);
+    let weight = matmul_tiled(weight, swap_dims(g, 1, 2));
+    let weight = reshape(
+        weight,
+        Shape::new([out_channels, in_channels, alpha * alpha]),
+    );
+    let weight = permute(weight, [2, 0, 1]);
+
+    let args = || {
+        WinogradArgsLaunch::new(
+            options.padding[0] as u32,
+            options.padding[1] as u32,
+            tile_size as u32,
+            tiles_0 as u32,
+            tiles_1 as u32,
+        )
+    };
+
+    // V = Bᵀ d B, as [alpha², in_channels, num_tiles].
+    let tiles = empty_device(
+        input.client.clone(),
+        input.device.clone(),
+        Shape::new([in_channels, num_tiles, alpha, alpha]),
+    );
+    winograd_input_tiles_kernel_launch::<E::CubeElement, R>(
+        input.client.clone(),
+        calculate_cube_count_elemwise(tiles.shape.num_elements(), SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorHandle::new(&tiles.handle, &tiles.strides, &tiles.shape.dims),
+        args(),
+    );
+    let tiles = reshape(tiles, Shape::new([in_channels * num_tiles, alpha, alpha]));
+    let tiles = matmul_tiled(bt.clone(), tiles);
+    let tiles = matmul_tiled(tiles, swap_dims(bt, 1, 2));
+    let tiles = reshape(tiles, Shape::new([in_channels, num_tiles, alpha * alpha]));
+    let tiles = permute(tiles, [2, 0, 1]);
+
+    // M = U V for each tile position, then Y = Aᵀ M A, as [out_channels, num_tiles, m, m].
+    let tiles = matmul_tiled(weight, tiles);
+    let tiles = reshape(tiles, Shape::new([alpha, alpha, out_channels, num_tiles]));
+    let tiles = permute(tiles, [2, 3, 0, 1]);
+    let tiles = reshape(tiles, Shape::new([out_channels * num_tiles, alpha, alpha]));
+    let tiles = matmul_tiled(at.clone(), tiles);
+    let tiles = matmul_tiled(tiles, swap_dims(at, 1, 2));
+    let tiles = reshape(
+        tiles,
+        Shape::new([out_channels, num_tiles, tile_size, tile_size]),
+    );
+
+    winograd_output_tiles_kernel_launch::<E::CubeElement, R>(
+        tiles.client.clone(),
+        calculate_cube_count_elemwise(output.shape.num_elements(), SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&tiles.handle, &tiles.strides, &tiles.shape.dims),
+        TensorHandle::new(&bias.handle, &bias.strides, &bias.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        args(),
+    );
+
+    output
+}
+
+fn transform_matrix<R: JitRuntime, E: FloatElement>(
+    values: Vec<f32>,
+    rows: usize,
+    cols: usize,
+    device: &R::Device,
+) -> JitTensor<R, E, 3> {
+    let values = values.into_iter().map(|value| value.elem()).collect();
+
+    from_data(Data::new(values, Shape::new([1, rows, cols])), device)
+}
+
+/// The convolution can be executed during autotune, where the matmul can't be autotuned.
+fn matmul_tiled<R: JitRuntime, E: FloatElement>(
+    lhs: JitTensor<R, E, 3>,
+    rhs: JitTensor<R, E, 3>,
+) -> JitTensor<R, E, 3> {
+    matmul(
+        lhs,
+        rhs,
+        MatmulStrategy::Tiling2d(Tiling2dConfig::default()),
+    )
+}
@@ -1,5 +1,21 @@
+mod base;
 mod conv2d;
+mod conv2d_depthwise;
+mod conv2d_grad;
+mod conv2d_implicit_gemm;
+mod conv2d_winograd;
 mod conv_transpose2d;
+mod tune;
+
+pub use base::*;
+pub use conv2d_depthwise::is_depthwise_supported;
+pub use conv2d_grad::*;
+pub use conv2d_implicit_gemm::ImplicitGemmConfig;
+pub use conv2d_winograd::{is_winograd_supported, WinogradTile};
+pub use tune::*;
 
 pub(crate) use conv2d::*;
+pub(crate) use conv2d_depthwise::*;
+pub(crate) use conv2d_implicit_gemm::*;
+pub(crate) use conv2d_winograd::*;
 pub(crate) use conv_transpose2d::*;
@@ -0,0 +1,352 @@
+use burn_compute::tune::{AutotuneOperation, AutotuneOperationSet};
+use burn_tensor::{ops::ConvOptions, ElementConversion};
+
+use crate::{
+    element::FloatElement,
+    kernel::{
+        conv::{
+            conv2d_depthwise, conv2d_direct, conv2d_implicit_gemm, conv2d_winograd,
+            is_depthwise_supported, is_winograd_supported, ImplicitGemmConfig, WinogradTile,
+        },
+        prng::random_like_uniform,
+    },
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    tune_key::JitAutotuneKey,
+    JitRuntime,
+};
+
+use super::Conv2dAutotuneKey;
+
+/// Tile sizes of the implicit GEMM kernels benchmarked by autotune, all of them using 256 units
+/// per cube.
+const IMPLICIT_GEMM_CONFIGS: [ImplicitGemmConfig; 4] = [
+    ImplicitGemmConfig {
+        block_m: 16,
+        block_k: 16,
+        block_n: 16,
+    },
+    ImplicitGemmConfig {
+        block_m: 16,
+        block_k: 32,
+        block_n: 16,
+    },
+    // Probably better with few output channels and large feature maps.
+    ImplicitGemmConfig {
+        block_m: 8,
+        block_k: 16,
+        block_n: 32,
+    },
+    // Probably better with many output channels and small feature maps.
+    ImplicitGemmConfig {
+        block_m: 32,
+        block_k: 16,
+        block_n: 8,
+    },
+];
+
+/// Tiles of the Winograd kernels benchmarked by autotune, when the convolution is supported.
+const WINOGRAD_TILES: [WinogradTile; 2] = [WinogradTile::F2x2, WinogradTile::F4x4];
+
+/// Set of conv2d implementations available for autotune
+/// Autotune key is given by the convolution options and the closest upper power of 2 of the
+/// input and weight dimensions
+pub(crate) struct Conv2dAutotuneOperationSet<R: JitRuntime, E: FloatElement> {
+    key: JitAutotuneKey,
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvOptions<2>,
+}
+
+impl<R: JitRuntime, E: FloatElement> Conv2dAutotuneOperationSet<R, E> {
+    fn new(
+        input: JitTensor<R, E, 4>,
+        weight: JitTensor<R, E, 4>,
+        bias: JitTensor<R, E, 4>,
+        output: JitTensor<R, E, 4>,
+        options: ConvOptions<2>,
+    ) -> Self {
+        Self {
+            key: JitAutotuneKey::Conv2d(Conv2dAutotuneKey::new(
+                &input.shape,
+                &weight.shape,
+                &options,
+            )),
+            input,
+            weight,
+            bias,
+            output,
+            options,
+        }
+    }
+}
+
+impl<R: JitRuntime, E: FloatElement> AutotuneOperationSet<JitAutotuneKey>
+    for Conv2dAutotuneOperationSet<R, E>
+{
+    fn key(&self) -> JitAutotuneKey {
+        self.key.clone()
+    }
+
+    fn autotunables(&self) -> Vec<Box<dyn AutotuneOperation>> {
+        let random_bounds: (E, E) = ((-10.0).elem::<E>(), (10.0).elem::<E>());
+        let input = random_like_uniform(&self.input, random_bounds.0, random_bounds.1);
+        let weight = random_like_uniform(&self.weight, random_bounds.0, random_bounds.1);
+        let bias = random_like_uniform(&self.bias, random_bounds.0, random_bounds.1);
+
+        let output = empty_device(
+            self.output.client.clone(),
+            self.output.device.clone(),
+            self.output.shape.clone(),
+        );
+
+        let mut autotunables: Vec<Box<dyn AutotuneOperation>> =
+            vec![Box::new(Conv2dDirectAutotune::new(
+                input.clone(),
+                weight.clone(),
+                bias.clone(),
+                output.clone(),
+                self.options.clone(),
+            ))];
+
+        for config in IMPLICIT_GEMM_CONFIGS {
+            autotunables.push(Box::new(Conv2dImplicitGemmAutotune::new(
+                input.clone(),
+                weight.clone(),
+                bias.clone(),
+                output.clone(),
+                self.options.clone(),
+                config,
+            )));
+        }
+
+        if is_winograd_supported(&self.weight.shape, &self.options) {
+            for tile in WINOGRAD_TILES {
+                autotunables.push(Box::new(Conv2dWinogradAutotune::new(
+                    input.clone(),
+                    weight.clone(),
+                    bias.clone(),
+                    output.clone(),
+                    self.options.clone(),
+                    tile,
+                )));
+            }
+        }
+
+        if is_depthwise_supported(&self.weight.shape) {
+            autotunables.push(Box::new(Conv2dDepthwiseAutotune::new(
+                input.clone(),
+                weight.clone(),
+                bias.clone(),
+                output.clone(),
+                self.options.clone(),
+            )));
+        }
+
+        autotunables
+    }
+
+    fn fastest(self: Box<Self>, fastest_index: usize) -> Box<dyn AutotuneOperation> {
+        let num_implicit_gemm = IMPLICIT_GEMM_CONFIGS.len();
+        let num_winograd = match is_winograd_supported(&self.weight.shape, &self.options) {
+            true => WINOGRAD_TILES.len(),
+            false => 0,
+        };
+        let depthwise = is_depthwise_supported(&self.weight.shape);
+
+        match fastest_index {
+            0 => Box::new(Conv2dDirectAutotune::new(
+                self.input,
+                self.weight,
+                self.bias,
+                self.output,
+                self.options,
+            )),
+            i if i <= num_implicit_gemm => Box::new(Conv2dImplicitGemmAutotune::new(
+                self.input,
+                self.weight,
+                self.bias,
+                self.output,
+                self.options,
+                IMPLICIT_GEMM_CONFIGS[i - 1],
+            )),
+            i if i <= num_implicit_gemm + num_winograd => Box::new(Conv2dWinogradAutotune::new(
+                self.input,
+                self.weight,
+                self.bias,
+                self.output,
+                self.options,
+                WINOGRAD_TILES[i - 1 - num_implicit_gemm],
+            )),
+            i if depthwise && i == num_implicit_gemm + num_winograd + 1 => {
+                Box::new(Conv2dDepthwiseAutotune::new(
+                    self.input,
+                    self.weight,
+                    self.bias,
+                    self.output,
+                    self.options,
+                ))
+            }
+            _ => panic!("Fastest index is out of bound"),
+        }
+    }
+}
+
+/// Executes autotune on conv2d operations
+pub(crate) fn conv2d_autotune<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvOptions<2>,
+) -> JitTensor<R, E, 4> {
+    let client = input.client.clone();
+
+    let operation_set = Box::new(Conv2dAutotuneOperationSet::new(
+        input,
+        weight,
+        bias,
+        output.clone(),
+        options,
+    ));
+
+    client.autotune_execute(operation_set);
+
+    output
+}
+
+#[derive(new)]
+// Probably better with few input channels and small kernels
+pub(crate) struct Conv2dDirectAutotune<R: JitRuntime, E: FloatElement> {
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvOptions<2>,
+}
+
+impl<R: JitRuntime, E: FloatElement> AutotuneOperation for Conv2dDirectAutotune<R, E> {
+    fn execute(self: Box<Self>) {
+        conv2d_direct(
+            self.input,
+            self.weight,
+            self.bias,
+            self.output,
+            self.options,
+        );
+    }
+
+    fn clone(&self) -> Box<dyn AutotuneOperation> {
+        Box::new(Self {
+            input: self.input.clone(),
+            weight: self.weight.clone(),
+            bias: self.bias.clone(),
+            output: self.output.clone(),
+            options: self.options.clone(),
+        })
+    }
+}
+
+#[derive(new)]
+// Probably better with many channels, where the patches are reused between output channels
+pub(crate) struct Conv2dImplicitGemmAutotune<R: JitRuntime, E: FloatElement> {
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvOptions<2>,
+    config: ImplicitGemmConfig,
+}
+
+impl<R: JitRuntime, E: FloatElement> AutotuneOperation for Conv2dImplicitGemmAutotune<R, E> {
+    fn execute(self: Box<Self>) {
+        conv2d_implicit_gemm(
+            self.input,
+            self.weight,
+            self.bias,
+            self.output,
+            self.options,
+            self.config,
+        );
+    }
+
+    fn clone(&self) -> Box<dyn AutotuneOperation> {
+        Box::new(Self {
+            input: self.input.clone(),
+            weight: self.weight.clone(),
+            bias: self.bias.clone(),
+            output: self.output.clone(),
+            options: self.options.clone(),
+            config: self.config,
+        })
+    }
+}
+
+#[derive(new)]
+// Probably the fastest for 3x3 kernels with many channels
+pub(crate) struct Conv2dWinogradAutotune<R: JitRuntime, E: FloatElement> {
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvOptions<2>,
+    tile: WinogradTile,
+}
+
+impl<R: JitRuntime, E: FloatElement> AutotuneOperation for Conv2dWinogradAutotune<R, E> {
+    fn execute(self: Box<Self>) {
+        conv2d_winograd(
+            self.input,
+            self.weight,
+            self.bias,
+            self.output,
+            self.options,
+            self.tile,
+        );
+    }
+
+    fn clone(&self) -> Box<dyn AutotuneOperation> {
+        Box::new(Self {
+            input: self.input.clone(),
+            weight: self.weight.clone(),
+            bias: self.bias.clone(),
+            output: self.output.clone(),
+            options: self.options.clone(),
+            tile: self.tile,
+        })
+    }
+}
+
+#[derive(new)]
+// Probably the fastest for depthwise convolutions, which have a single input channel per group
+pub(crate) struct Conv2dDepthwiseAutotune<R: JitRuntime, E: FloatElement> {
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvOptions<2>,
+}
+
+impl<R: JitRuntime, E: FloatElement> AutotuneOperation for Conv2dDepthwiseAutotune<R, E> {
+    fn execute(self: Box<Self>) {
+        conv2d_depthwise(
+            self.input,
+            self.weight,
+            self.bias,
+            self.output,
+            self.options,
+        );
+    }
+
+    fn clone(&self) -> Box<dyn AutotuneOperation> {
+        Box::new(Self {
+            input: self.input.clone(),
+            weight: self.weight.clone(),
+            bias: self.bias.clone(),
+            output: self.output.clone(),
+            options: self.options.clone(),
+        })
+    }
+}
@@ -0,0 +1,93 @@
+use crate::tune::anchor;
+use burn_tensor::{ops::ConvOptions, Shape};
+use core::fmt::Debug;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, hash::Hash};
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Serialize, Deserialize)]
+/// Autotune key representative of conv2d versions
+pub struct Conv2dAutotuneKey {
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+    dilation: [usize; 2],
+    groups: usize,
+    anchored_in_channels: usize,
+    anchored_out_channels: usize,
+    anchored_height: usize,
+    anchored_width: usize,
+    anchored_batch: usize,
+}
+
+impl Display for Conv2dAutotuneKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(
+            format!(
+                "Conv2d - kernel_size:{:?} stride:{:?} padding:{:?} dilation:{:?} groups:{:?} \
+                 in_channels:{:?} out_channels:{:?} height:{:?} width:{:?} batch:{:?}",
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.dilation,
+                self.groups,
+                self.anchored_in_channels,
+                self.anchored_out_channels,
+                self.anchored_height,
+                self.anchored_width,
+                self.anchored_batch
+            )
+            .as_str(),
+        )
+    }
+}
+
+impl Conv2dAutotuneKey {
+    /// Create a conv2d autotune key from the input and weight shapes and the options
+    pub fn new(input_shape: &Shape<4>, weight_shape: &Shape<4>, options: &ConvOptions<2>) -> Self {
+        let [batch_size, in_channels, height, width] = input_shape.dims;
+        let [out_channels, _, kernel_0, kernel_1] = weight_shape.dims;
+
+        Self {
+            kernel_size: [kernel_0, kernel_1],
+            stride: options.stride,
+            padding: options.padding,
+            dilation: options.dilation,
+            groups: options.groups,
+            anchored_in_channels: anchor(in_channels, None),
+            anchored_out_channels: anchor(out_channels, None),
+            anchored_height: anchor(height, None),
+            anchored_width: anchor(width, None),
+            anchored_batch: anchor(batch_size, Some(128)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conv2d_autotune_key_anchors_shapes() {
+        let input_shape: Shape<4> = [3, 60, 200, 128].into();
+        let weight_shape: Shape<4> = [33, 60, 3, 3].into();
+        let options = ConvOptions::new([1, 1], [1, 1], [1, 1], 1);
+        let key = Conv2dAutotuneKey::new(&input_shape, &weight_shape, &options);
+
+        assert_eq!(key.kernel_size, [3, 3]);
+        assert_eq!(key.anchored_in_channels, 64);
+        assert_eq!(key.anchored_out_channels, 64);
+        assert_eq!(key.anchored_height, 256);
+        assert_eq!(key.anchored_width, 128);
+        assert_eq!(key.anchored_batch, 4);
+    }
+
+    #[test]
+    fn conv2d_autotune_key_large_batch() {
+        let input_shape: Shape<4> = [1000, 3, 32, 32].into();
+        let weight_shape: Shape<4> = [8, 3, 3, 3].into();
+        let options = ConvOptions::new([1, 1], [0, 0], [1, 1], 1);
+        let key = Conv2dAutotuneKey::new(&input_shape, &weight_shape, &options);
+
+        assert_eq!(key.anchored_batch, 128);
+    }
+}
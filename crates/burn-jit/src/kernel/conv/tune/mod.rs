@@ -0,0 +1,5 @@
+mod base;
+mod key;
+
+pub(crate) use base::*;
+pub use key::*;
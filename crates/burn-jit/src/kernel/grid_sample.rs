@@ -0,0 +1,406 @@
+use burn_cube::{
+    cpa,
+    frontend::TensorHandle,
+    ir::{Elem, KernelDefinition, Scope, Variable, Visibility},
+    CubeCountSettings, Execution, InputInfo, KernelExpansion, KernelIntegrator, KernelSettings,
+    OutputInfo,
+};
+use burn_tensor::{
+    ops::{GridSampleOptions, GridSamplePaddingMode},
+    Shape,
+};
+use std::marker::PhantomData;
+
+use crate::{
+    kernel::{into_contiguous, Kernel},
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    JitElement, JitRuntime,
+};
+
+#[derive(new)]
+struct GridSample2dEagerKernel<R, E> {
+    options: GridSampleOptions,
+    _runtime: PhantomData<R>,
+    _elem: PhantomData<E>,
+}
+
+struct GridSample2dShader {
+    input: Variable,
+    grid: Variable,
+    output: Variable,
+    options: GridSampleOptions,
+}
+
+impl GridSample2dShader {
+    pub(crate) fn expand(self, scope: &mut Scope) {
+        let input = self.input;
+        let grid = self.grid;
+        let output = self.output;
+        let id = Variable::AbsolutePos;
+        let elem = input.item().elem();
+
+        let input_stride_0 = scope.create_local(Elem::UInt);
+        let input_stride_1 = scope.create_local(Elem::UInt);
+        let input_stride_2 = scope.create_local(Elem::UInt);
+        let input_stride_3 = scope.create_local(Elem::UInt);
+
+        let input_shape_2 = scope.create_local(Elem::UInt);
+        let input_shape_3 = scope.create_local(Elem::UInt);
+
+        let grid_stride_0 = scope.create_local(Elem::UInt);
+        let grid_stride_1 = scope.create_local(Elem::UInt);
+        let grid_stride_2 = scope.create_local(Elem::UInt);
+        let grid_stride_3 = scope.create_local(Elem::UInt);
+
+        let output_stride_0 = scope.create_local(Elem::UInt);
+        let output_stride_1 = scope.create_local(Elem::UInt);
+        let output_stride_2 = scope.create_local(Elem::UInt);
+        let output_stride_3 = scope.create_local(Elem::UInt);
+
+        let output_shape_0 = scope.create_local(Elem::UInt);
+        let output_shape_1 = scope.create_local(Elem::UInt);
+        let output_shape_2 = scope.create_local(Elem::UInt);
+        let output_shape_3 = scope.create_local(Elem::UInt);
+
+        cpa!(scope, input_stride_0 = stride(input, 0u32));
+        cpa!(scope, input_stride_1 = stride(input, 1u32));
+        cpa!(scope, input_stride_2 = stride(input, 2u32));
+        cpa!(scope, input_stride_3 = stride(input, 3u32));
+
+        cpa!(scope, input_shape_2 = shape(input, 2u32));
+        cpa!(scope, input_shape_3 = shape(input, 3u32));
+
+        cpa!(scope, grid_stride_0 = stride(grid, 0u32));
+        cpa!(scope, grid_stride_1 = stride(grid, 1u32));
+        cpa!(scope, grid_stride_2 = stride(grid, 2u32));
+        cpa!(scope, grid_stride_3 = stride(grid, 3u32));
+
+        cpa!(scope, output_stride_0 = stride(output, 0u32));
+        cpa!(scope, output_stride_1 = stride(output, 1u32));
+        cpa!(scope, output_stride_2 = stride(output, 2u32));
+        cpa!(scope, output_stride_3 = stride(output, 3u32));
+
+        cpa!(scope, output_shape_0 = shape(output, 0u32));
+        cpa!(scope, output_shape_1 = shape(output, 1u32));
+        cpa!(scope, output_shape_2 = shape(output, 2u32));
+        cpa!(scope, output_shape_3 = shape(output, 3u32));
+
+        let b = scope.create_local(Elem::UInt);
+        let c = scope.create_local(Elem::UInt);
+        let h = scope.create_local(Elem::UInt);
+        let w = scope.create_local(Elem::UInt);
+
+        cpa!(scope, b = id / output_stride_0);
+        cpa!(scope, b = b % output_shape_0);
+
+        cpa!(scope, c = id / output_stride_1);
+        cpa!(scope, c = c % output_shape_1);
+
+        cpa!(scope, h = id / output_stride_2);
+        cpa!(scope, h = h % output_shape_2);
+
+        cpa!(scope, w = id / output_stride_3);
+        cpa!(scope, w = w % output_shape_3);
+
+        // The grid holds the normalized (x, y) location sampled by each output pixel.
+        let grid_index = scope.create_local(Elem::UInt);
+        let index_tmp = scope.create_local(Elem::UInt);
+        let grid_x = scope.create_local(elem);
+        let grid_y = scope.create_local(elem);
+
+        cpa!(scope, grid_index = b * grid_stride_0);
+        cpa!(scope, index_tmp = h * grid_stride_1);
+        cpa!(scope, grid_index += index_tmp);
+        cpa!(scope, index_tmp = w * grid_stride_2);
+        cpa!(scope, grid_index += index_tmp);
+        cpa!(scope, grid_x = grid[grid_index]);
+        cpa!(scope, grid_index += grid_stride_3);
+        cpa!(scope, grid_y = grid[grid_index]);
+
+        let width = scope.create_local(elem);
+        let height = scope.create_local(elem);
+        cpa!(scope, width = cast(input_shape_3));
+        cpa!(scope, height = cast(input_shape_2));
+
+        let ix = Self::source_coordinate(scope, grid_x, width, &self.options);
+        let iy = Self::source_coordinate(scope, grid_y, height, &self.options);
+
+        let one = scope.create_with_value(1f32, elem);
+        let x0 = scope.create_local(elem);
+        let x1 = scope.create_local(elem);
+        let y0 = scope.create_local(elem);
+        let y1 = scope.create_local(elem);
+        let wx0 = scope.create_local(elem);
+        let wx1 = scope.create_local(elem);
+        let wy0 = scope.create_local(elem);
+        let wy1 = scope.create_local(elem);
+
+        cpa!(scope, x0 = floor(ix));
+        cpa!(scope, x1 = x0 + one);
+        cpa!(scope, wx1 = ix - x0);
+        cpa!(scope, wx0 = one - wx1);
+
+        cpa!(scope, y0 = floor(iy));
+        cpa!(scope, y1 = y0 + one);
+        cpa!(scope, wy1 = iy - y0);
+        cpa!(scope, wy0 = one - wy1);
+
+        let index_base = scope.create_local(Elem::UInt);
+        cpa!(scope, index_base = b * input_stride_0);
+        cpa!(scope, index_tmp = c * input_stride_1);
+        cpa!(scope, index_base += index_tmp);
+
+        let corner = Corner {
+            input,
+            index_base,
+            stride_y: input_stride_2,
+            stride_x: input_stride_3,
+            width,
+            height,
+        };
+
+        let sum = scope.zero(elem);
+        corner.accumulate(scope, sum, x0, y0, wx0, wy0);
+        corner.accumulate(scope, sum, x1, y0, wx1, wy0);
+        corner.accumulate(scope, sum, x0, y1, wx0, wy1);
+        corner.accumulate(scope, sum, x1, y1, wx1, wy1);
+
+        cpa!(scope, output[id] = sum);
+    }
+
+    /// Map a coordinate normalized in `[-1, 1]` to the pixel space of an axis of the given size,
+    /// then move the locations outside of the input according to the padding mode.
+    fn source_coordinate(
+        scope: &mut Scope,
+        coordinate: Variable,
+        size: Variable,
+        options: &GridSampleOptions,
+    ) -> Variable {
+        let elem = coordinate.item().elem();
+        let one = scope.create_with_value(1f32, elem);
+        let two = scope.create_with_value(2f32, elem);
+        let last = scope.create_local(elem);
+        let pixel = scope.create_local(elem);
+
+        cpa!(scope, last = size - one);
+        cpa!(scope, pixel = coordinate + one);
+
+        if options.align_corners {
+            cpa!(scope, pixel = pixel * last);
+            cpa!(scope, pixel = pixel / two);
+        } else {
+            cpa!(scope, pixel = pixel * size);
+            cpa!(scope, pixel = pixel - one);
+            cpa!(scope, pixel = pixel / two);
+        }
+
+        match options.padding_mode {
+            GridSamplePaddingMode::Zeros => {}
+            GridSamplePaddingMode::Border => Self::clamp(scope, pixel, last),
+            GridSamplePaddingMode::Reflection => {
+                // Reflect by the centers of the pixels on the borders, or by their outer edges.
+                let (min, span) = match options.align_corners {
+                    true => (scope.zero(elem), last),
+                    false => (scope.create_with_value(-0.5f32, elem), size),
+                };
+                Self::reflect(scope, pixel, min, span);
+                Self::clamp(scope, pixel, last);
+            }
+        }
+
+        pixel
+    }
+
+    /// Reflect the coordinate by the borders of the `[min, min + span]` interval until it is
+    /// inside of it.
+    fn reflect(scope: &mut Scope, coordinate: Variable, min: Variable, span: Variable) {
+        let elem = coordinate.item().elem();
+        let one = scope.create_with_value(1f32, elem);
+        let two = scope.create_with_value(2f32, elem);
+        let span_safe = scope.create_local(elem);
+        let distance = scope.create_local(elem);
+        let flips = scope.create_local(elem);
+        let extra = scope.create_local(elem);
+        let odd = scope.create_local(elem);
+        let tmp = scope.create_local(elem);
+
+        // An interval of a single point only contains its minimum, which is restored by the
+        // clamping of the caller.
+        cpa!(scope, span_safe = max(span, one));
+
+        cpa!(scope, distance = coordinate - min);
+        cpa!(scope, distance = abs(distance));
+        cpa!(scope, flips = distance / span_safe);
+        cpa!(scope, flips = floor(flips));
+        cpa!(scope, tmp = flips * span_safe);
+        cpa!(scope, extra = distance - tmp);
+
+        // The coordinate is reflected back when the number of flips is odd.
+        cpa!(scope, odd = flips / two);
+        cpa!(scope, odd = floor(odd));
+        cpa!(scope, odd = odd * two);
+        cpa!(scope, odd = flips - odd);
+        cpa!(scope, tmp = extra * two);
+        cpa!(scope, tmp = span_safe - tmp);
+        cpa!(scope, tmp = odd * tmp);
+        cpa!(scope, coordinate = extra + tmp);
+        cpa!(scope, coordinate += min);
+    }
+
+    /// Clamp the coordinate to the pixels of the input, from `0` to `last`.
+    fn clamp(scope: &mut Scope, coordinate: Variable, last: Variable) {
+        let zero = scope.zero(coordinate.item().elem());
+
+        cpa!(scope, coordinate = max(coordinate, zero));
+        cpa!(scope, coordinate = min(coordinate, last));
+    }
+}
+
+/// Accumulates the weighted input pixels surrounding a location, skipping the ones outside of the
+/// input.
+struct Corner {
+    input: Variable,
+    index_base: Variable,
+    stride_y: Variable,
+    stride_x: Variable,
+    width: Variable,
+    height: Variable,
+}
+
+impl Corner {
+    fn accumulate(
+        &self,
+        scope: &mut Scope,
+        sum: Variable,
+        x: Variable,
+        y: Variable,
+        weight_x: Variable,
+        weight_y: Variable,
+    ) {
+        let input = self.input;
+        let index_base = self.index_base;
+        let stride_y = self.stride_y;
+        let stride_x = self.stride_x;
+        let width = self.width;
+        let height = self.height;
+        let elem = x.item().elem();
+
+        let zero = scope.zero(elem);
+        let valid = scope.create_local(Elem::Bool);
+        let tmp = scope.create_local(Elem::Bool);
+
+        cpa!(scope, valid = x >= zero);
+        cpa!(scope, tmp = x < width);
+        cpa!(scope, valid = valid && tmp);
+        cpa!(scope, tmp = y >= zero);
+        cpa!(scope, valid = valid && tmp);
+        cpa!(scope, tmp = y < height);
+        cpa!(scope, valid = valid && tmp);
+
+        cpa!(scope, if(valid).then(|scope|{
+            let x_index = scope.create_local(Elem::UInt);
+            let y_index = scope.create_local(Elem::UInt);
+            let index = scope.create_local(Elem::UInt);
+            let value = scope.create_local(elem);
+
+            cpa!(scope, x_index = cast(x));
+            cpa!(scope, y_index = cast(y));
+            cpa!(scope, x_index = x_index * stride_x);
+            cpa!(scope, y_index = y_index * stride_y);
+            cpa!(scope, index = index_base + y_index);
+            cpa!(scope, index += x_index);
+            cpa!(scope, value = input[index]);
+            cpa!(scope, value *= weight_x);
+            cpa!(scope, value *= weight_y);
+            cpa!(scope, sum += value);
+        }));
+    }
+}
+
+impl<R: JitRuntime, E: JitElement> Kernel for GridSample2dEagerKernel<R, E> {
+    fn define(&self) -> KernelDefinition {
+        let mut scope = Scope::root();
+        let item = E::cube_elem().into();
+
+        let input = Variable::GlobalInputArray(0, item);
+        let grid = Variable::GlobalInputArray(1, item);
+        let output = Variable::GlobalOutputArray(0, item);
+
+        GridSample2dShader {
+            input,
+            grid,
+            output,
+            options: self.options.clone(),
+        }
+        .expand(&mut scope);
+
+        scope.write_global_custom(output);
+
+        let input = InputInfo::Array {
+            item,
+            visibility: Visibility::Read,
+        };
+        let grid = InputInfo::Array {
+            item,
+            visibility: Visibility::Read,
+        };
+
+        let out = OutputInfo::Array { item };
+
+        let info = KernelExpansion {
+            inputs: vec![input, grid],
+            outputs: vec![out],
+            scope,
+        };
+
+        let settings = KernelSettings::default();
+        KernelIntegrator::new(info).integrate(settings)
+    }
+
+    fn id(&self) -> String {
+        format!(
+            "{:?}padding_mode={:?}align_corners={}",
+            core::any::TypeId::of::<Self>(),
+            self.options.padding_mode,
+            self.options.align_corners
+        )
+    }
+}
+
+/// Samples the input at the locations of the grid with bilinear interpolation.
+///
+/// See [grid_sample_2d](burn_tensor::ops::ModuleOps::grid_sample_2d).
+///
+/// The input is read from a storage buffer by a compute kernel: the texture sampling hardware
+/// isn't used, since the runtimes only bind buffers to the kernels. The filtering of the
+/// textures would also only support some element types and padding modes, and be less precise
+/// than the interpolation computed by the kernel.
+pub fn grid_sample_2d<R: JitRuntime, E: JitElement>(
+    input: JitTensor<R, E, 4>,
+    grid: JitTensor<R, E, 4>,
+    options: GridSampleOptions,
+) -> JitTensor<R, E, 4> {
+    let input = into_contiguous(input);
+    let [batch_size, channels, _, _] = input.shape.dims;
+    let [_, height_out, width_out, _] = grid.shape.dims;
+
+    let shape_out = Shape::new([batch_size, channels, height_out, width_out]);
+    let output = empty_device(input.client.clone(), input.device.clone(), shape_out);
+
+    let kernel = GridSample2dEagerKernel::<R, E>::new(options);
+
+    Execution::start(kernel, input.client)
+        .inputs(&[
+            TensorHandle::<R>::new(&input.handle, &input.strides, &input.shape.dims),
+            TensorHandle::new(&grid.handle, &grid.strides, &grid.shape.dims),
+        ])
+        .outputs(&[TensorHandle::new(
+            &output.handle,
+            &output.strides,
+            &output.shape.dims,
+        )])
+        .execute(CubeCountSettings::Output { pos: 0 });
+
+    output
+}
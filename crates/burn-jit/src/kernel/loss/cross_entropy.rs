@@ -0,0 +1,181 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*};
+
+use burn_tensor::Shape;
+
+use crate::{ops::numeric::empty_device, tensor::JitTensor, FloatElement, IntElement, JitRuntime};
+
+/// Number of units reducing each row, which must be a power of 2.
+const CROSS_ENTROPY_CUBE_DIM: u32 = 256;
+
+/// Computes `log(sum(exp(x)))` of a row of the logits, where each unit keeps an online maximum
+/// and sum of exponentials over a strided subset of the classes, merged afterward in shared
+/// memory. The maximum starts from the first class rather than minus infinity, which isn't
+/// representable as a literal by every compiler.
+#[cube]
+fn row_log_sum_exp<F: Float>(logits: Tensor<F>, row: UInt, cube_dim: Comptime<UInt>) -> F {
+    let mut shared_max = SharedMemory::<F>::new(Comptime::get(cube_dim));
+    let mut shared_sum = SharedMemory::<F>::new(Comptime::get(cube_dim));
+
+    let num_units = Comptime::runtime(cube_dim);
+    let num_classes = logits.shape(1);
+    let stride_class = logits.stride(1);
+    let index_row = row * logits.stride(0);
+
+    let mut row_max = logits[index_row];
+    let mut row_sum = F::new(0.);
+    let mut class = UNIT_POS_X;
+
+    while class < num_classes {
+        let value = logits[index_row + class * stride_class];
+        let new_max = F::max(row_max, value);
+
+        row_sum = row_sum * F::exp(row_max - new_max) + F::exp(value - new_max);
+        row_max = new_max;
+        class += num_units;
+    }
+
+    shared_max[UNIT_POS_X] = row_max;
+    shared_sum[UNIT_POS_X] = row_sum;
+
+    sync_units();
+
+    let mut offset = num_units / UInt::new(2);
+
+    while offset > UInt::new(0) {
+        if UNIT_POS_X < offset {
+            let other = UNIT_POS_X + offset;
+            let max_lhs = shared_max[UNIT_POS_X];
+            let max_rhs = shared_max[other];
+            let new_max = F::max(max_lhs, max_rhs);
+
+            shared_sum[UNIT_POS_X] = shared_sum[UNIT_POS_X] * F::exp(max_lhs - new_max)
+                + shared_sum[other] * F::exp(max_rhs - new_max);
+            shared_max[UNIT_POS_X] = new_max;
+        }
+
+        sync_units();
+        offset /= UInt::new(2);
+    }
+
+    shared_max[UInt::new(0)] + F::log(shared_sum[UInt::new(0)])
+}
+
+/// Each cube computes the loss of one row.
+#[cube(launch)]
+fn cross_entropy_kernel<F: Float>(
+    logits: Tensor<F>,
+    targets: Tensor<I32>,
+    mut loss: Tensor<F>,
+    cube_dim: Comptime<UInt>,
+) {
+    let row = CUBE_POS_Y * CUBE_COUNT_X + CUBE_POS_X;
+
+    // The whole cube returns, so the synchronizations stay uniform.
+    if row >= loss.len() {
+        return;
+    }
+
+    let log_sum_exp = row_log_sum_exp::<F>(logits, row, cube_dim);
+
+    if UNIT_POS_X == UInt::new(0) {
+        let target = UInt::cast_from(targets[row * targets.stride(0)]);
+        let index_target = row * logits.stride(0) + target * logits.stride(1);
+
+        loss[row] = log_sum_exp - logits[index_target];
+    }
+}
+
+/// Each cube computes the gradient of one row, `grad * (softmax(x) - one_hot(target))`.
+#[cube(launch)]
+fn cross_entropy_backward_kernel<F: Float>(
+    logits: Tensor<F>,
+    targets: Tensor<I32>,
+    grad: Tensor<F>,
+    mut logits_grad: Tensor<F>,
+    cube_dim: Comptime<UInt>,
+) {
+    let row = CUBE_POS_Y * CUBE_COUNT_X + CUBE_POS_X;
+
+    // The whole cube returns, so the synchronizations stay uniform.
+    if row >= grad.len() {
+        return;
+    }
+
+    let log_sum_exp = row_log_sum_exp::<F>(logits, row, cube_dim);
+
+    let num_units = Comptime::runtime(cube_dim);
+    let num_classes = logits.shape(1);
+    let target = UInt::cast_from(targets[row * targets.stride(0)]);
+    let grad_row = grad[row * grad.stride(0)];
+    let mut class = UNIT_POS_X;
+
+    while class < num_classes {
+        let index = row * logits.stride(0) + class * logits.stride(1);
+        let mut value = F::exp(logits[index] - log_sum_exp);
+
+        if class == target {
+            value -= F::new(1.);
+        }
+
+        logits_grad[row * logits_grad.stride(0) + class * logits_grad.stride(1)] = value * grad_row;
+        class += num_units;
+    }
+}
+
+/// Computes the cross entropy of each row of the logits with its target class, fusing the log
+/// softmax with the negative log likelihood so the probabilities are never materialized.
+pub fn cross_entropy<R: JitRuntime, E: FloatElement, I: IntElement>(
+    logits: JitTensor<R, E, 2>,
+    targets: JitTensor<R, I, 1>,
+) -> JitTensor<R, E, 1> {
+    let [batch_size, _] = logits.shape.dims;
+    let loss = empty_device(
+        logits.client.clone(),
+        logits.device.clone(),
+        Shape::new([batch_size]),
+    );
+
+    // The int element of the backend is always `i32`.
+    cross_entropy_kernel_launch::<E::CubeElement, R>(
+        logits.client.clone(),
+        calculate_cube_count_elemwise(batch_size, 1),
+        KernelSettings::default().cube_dim(CubeDim::new(CROSS_ENTROPY_CUBE_DIM, 1, 1)),
+        TensorHandle::new(&logits.handle, &logits.strides, &logits.shape.dims),
+        TensorHandle::new(&targets.handle, &targets.strides, &targets.shape.dims),
+        TensorHandle::new(&loss.handle, &loss.strides, &loss.shape.dims),
+        UInt::new(CROSS_ENTROPY_CUBE_DIM),
+    );
+
+    loss
+}
+
+/// Computes the gradient of the [cross entropy](cross_entropy) with respect to the logits.
+pub fn cross_entropy_backward<R: JitRuntime, E: FloatElement, I: IntElement>(
+    logits: JitTensor<R, E, 2>,
+    targets: JitTensor<R, I, 1>,
+    grad: JitTensor<R, E, 1>,
+) -> JitTensor<R, E, 2> {
+    let [batch_size, _] = logits.shape.dims;
+    let logits_grad = empty_device(
+        logits.client.clone(),
+        logits.device.clone(),
+        logits.shape.clone(),
+    );
+
+    cross_entropy_backward_kernel_launch::<E::CubeElement, R>(
+        logits.client.clone(),
+        calculate_cube_count_elemwise(batch_size, 1),
+        KernelSettings::default().cube_dim(CubeDim::new(CROSS_ENTROPY_CUBE_DIM, 1, 1)),
+        TensorHandle::new(&logits.handle, &logits.strides, &logits.shape.dims),
+        TensorHandle::new(&targets.handle, &targets.strides, &targets.shape.dims),
+        TensorHandle::new(&grad.handle, &grad.strides, &grad.shape.dims),
+        TensorHandle::new(
+            &logits_grad.handle,
+            &logits_grad.strides,
+            &logits_grad.shape.dims,
+        ),
+        UInt::new(CROSS_ENTROPY_CUBE_DIM),
+    );
+
+    logits_grad
+}
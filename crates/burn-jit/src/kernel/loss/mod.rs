@@ -0,0 +1,3 @@
+mod cross_entropy;
+
+pub use cross_entropy::*;
@@ -4,7 +4,7 @@ use crate::{
     tensor::JitTensor,
     FloatElement, JitRuntime,
 };
-use burn_cube::ir::KernelDefinition;
+use burn_cube::ir::{CubeDim, KernelDefinition};
 use burn_cube::{frontend::TensorHandle, KernelSettings};
 
 use super::simple_launch_options;
@@ -118,7 +118,12 @@ pub fn matmul_simple<R: JitRuntime, E: FloatElement, const D: usize>(
     let settings = KernelSettings::default()
         .vectorize_input(0, vectorization_factor)
         .vectorize_input(1, vectorization_factor)
-        .vectorize_output(0, 1);
+        .vectorize_output(0, 1)
+        .cube_dim(CubeDim::new(
+            workgroup_size_x as u32,
+            workgroup_size_y as u32,
+            1,
+        ));
 
     matmul_kernel_launch::<E::CubeElement, R>(
         lhs.client,
@@ -9,13 +9,15 @@ use crate::{
     },
     ops::numeric::empty_device,
     tensor::JitTensor,
+    tune::CUBE_DIM_CANDIDATES,
     tune_key::JitAutotuneKey,
     JitRuntime,
 };
 
 use super::key::MatmulAutotuneKey;
 
-/// Set of matmul implementations available for autotune
+/// Set of matmul implementations available for autotune, the simple one being tried with every
+/// [cube dimension candidate](CUBE_DIM_CANDIDATES)
 /// Autotune key is given by concatenating the closest upper power of 2 of m, k and n
 pub struct MatmulAutotuneOperationSet<R: JitRuntime, E: FloatElement, const D: usize> {
     key: JitAutotuneKey,
@@ -52,13 +54,18 @@ impl<R: JitRuntime, E: FloatElement, const D: usize> AutotuneOperationSet<JitAut
             self.out.shape.clone(),
         );
 
-        vec![
-            Box::new(SimpleMatmul::new(lhs.clone(), rhs.clone(), out.clone())),
-            Box::new(SimpleMatmul16x16::new(
-                lhs.clone(),
-                rhs.clone(),
-                out.clone(),
-            )),
+        let simple = CUBE_DIM_CANDIDATES
+            .iter()
+            .map(|cube_dim| -> Box<dyn AutotuneOperation> {
+                Box::new(SimpleMatmul::new(
+                    lhs.clone(),
+                    rhs.clone(),
+                    out.clone(),
+                    *cube_dim as usize,
+                ))
+            });
+
+        let tiling2d: [Box<dyn AutotuneOperation>; 4] = [
             Box::new(Tiling2dMatmul::new(lhs.clone(), rhs.clone(), out.clone())),
             Box::new(Tiling2dMatmulPadded::new(
                 lhs.clone(),
@@ -75,19 +82,26 @@ impl<R: JitRuntime, E: FloatElement, const D: usize> AutotuneOperationSet<JitAut
                 rhs.clone(),
                 out.clone(),
             )),
-        ]
+        ];
+
+        simple.chain(tiling2d).collect()
     }
 
     fn fastest(self: Box<Self>, fastest_index: usize) -> Box<dyn AutotuneOperation> {
-        match fastest_index {
-            0 => Box::new(SimpleMatmul::new(self.lhs, self.rhs, self.out)),
-            1 => Box::new(SimpleMatmul16x16::new(self.lhs, self.rhs, self.out)),
-            2 => Box::new(Tiling2dMatmul::new(self.lhs, self.rhs, self.out)),
-            3 => Box::new(Tiling2dMatmulPadded::new(self.lhs, self.rhs, self.out)),
-            4 => Box::new(Tiling2dMatmulPaddedUnrolled::new(
+        let num_simple = CUBE_DIM_CANDIDATES.len();
+
+        if fastest_index < num_simple {
+            let cube_dim = CUBE_DIM_CANDIDATES[fastest_index] as usize;
+            return Box::new(SimpleMatmul::new(self.lhs, self.rhs, self.out, cube_dim));
+        }
+
+        match fastest_index - num_simple {
+            0 => Box::new(Tiling2dMatmul::new(self.lhs, self.rhs, self.out)),
+            1 => Box::new(Tiling2dMatmulPadded::new(self.lhs, self.rhs, self.out)),
+            2 => Box::new(Tiling2dMatmulPaddedUnrolled::new(
                 self.lhs, self.rhs, self.out,
             )),
-            5 => Box::new(Tiling2dMatmulUnrolled::new(self.lhs, self.rhs, self.out)),
+            3 => Box::new(Tiling2dMatmulUnrolled::new(self.lhs, self.rhs, self.out)),
             _ => panic!("Fastest index is out of bound"),
         }
     }
@@ -135,16 +149,35 @@ macro_rules! matmul_tune_ops {
     };
 }
 
-// Potentially better for small matrices.
-matmul_tune_ops!(
-    SimpleMatmul,
-    crate::kernel::matmul::matmul_mem_coalescing_default
-);
+// Potentially better for small matrices, tuned with every cube dimension candidate.
+#[derive(new)]
+pub(crate) struct SimpleMatmul<R: JitRuntime, E: FloatElement, const D: usize> {
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    cube_dim: usize,
+}
+
+impl<R: JitRuntime, E: FloatElement, const D: usize> AutotuneOperation for SimpleMatmul<R, E, D> {
+    fn execute(self: Box<Self>) {
+        crate::kernel::matmul::matmul_simple(
+            self.lhs,
+            self.rhs,
+            self.out,
+            self.cube_dim,
+            self.cube_dim,
+        );
+    }
 
-// Potentially better for small matrices.
-matmul_tune_ops!(SimpleMatmul16x16, |lhs, rhs, out| {
-    crate::kernel::matmul::matmul_simple(lhs, rhs, out, 16, 16)
-});
+    fn clone(&self) -> Box<dyn AutotuneOperation> {
+        Box::new(Self {
+            lhs: self.lhs.clone(),
+            rhs: self.rhs.clone(),
+            out: self.out.clone(),
+            cube_dim: self.cube_dim,
+        })
+    }
+}
 
 // Probably the fastest when fixed size, without loop unrolling
 matmul_tune_ops!(Tiling2dMatmulPadded, |lhs, rhs, out| {
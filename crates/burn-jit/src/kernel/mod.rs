@@ -6,19 +6,25 @@ mod contiguous;
 mod index;
 mod mask;
 mod unary;
+mod vectorization;
 
 pub use binary::*;
 pub use cast::*;
 pub use contiguous::*;
 pub use mask::*;
 pub use unary::*;
+pub use vectorization::elemwise_vectorization;
 
 pub use burn_cube::{Kernel, SUBCUBE_DIM_APPROX};
 
 /// Convolution kernels
 pub mod conv;
+/// Grid sampling kernels
+pub mod grid_sample;
 /// Interpolation kernels
 pub mod interpolate;
+/// Loss kernels
+pub mod loss;
 /// Matmul kernels
 pub mod matmul;
 /// Pooling kernels
@@ -27,7 +33,12 @@ pub mod pool;
 pub mod prng;
 /// Reduction algorithms
 pub mod reduce;
+/// Segment reduction kernels
+pub mod segment;
+/// Sorting kernels
+pub mod sort;
 
 pub(crate) use clamp::*;
 pub(crate) use comparison::*;
 pub(crate) use index::*;
+pub(crate) use vectorization::elemwise_cube_count;
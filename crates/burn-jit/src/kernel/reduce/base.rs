@@ -1,6 +1,6 @@
 #[cfg(feature = "autotune")]
 use crate::kernel::reduce::reduce_dim_autotune;
-use crate::{element::JitElement, tensor::JitTensor, JitRuntime};
+use crate::{element::JitElement, kernel::SUBCUBE_DIM_APPROX, tensor::JitTensor, JitRuntime};
 
 use super::{
     naive::{base::ReduceDimNaive, shader::reduce_dim_naive},
@@ -75,11 +75,11 @@ macro_rules! reduce_operation {
             match strategy {
                 ReduceStrategy::Naive => {
                     let output = init_reduce_output(&tensor, dim);
-                    reduce_dim_naive::<$ops, R, EI, EO, D>(tensor, output, dim)
+                    reduce_dim_naive::<$ops, R, EI, EO, D>(tensor, output, dim, SUBCUBE_DIM_APPROX)
                 }
                 ReduceStrategy::SharedMemory => {
                     let output = init_reduce_output(&tensor, dim);
-                    reduce_dim_shared::<$ops, R, EI, EO, D>(tensor, output, dim)
+                    reduce_dim_shared::<$ops, R, EI, EO, D>(tensor, output, dim, SUBCUBE_DIM_APPROX)
                 }
                 #[cfg(feature = "autotune")]
                 ReduceStrategy::Autotune => reduce_dim_autotune::<$ops, R, EI, EO, D>(tensor, dim),
@@ -14,7 +14,8 @@ impl<E: JitElement> ReduceDimNaive<E> for Argmax {
         input_item: Item,
         _output_item: Item,
     ) -> Self::Accumulator {
-        let index = scope.create_local(Elem::UInt);
+        // Only a strictly better value replaces the index, which keeps the first occurrence.
+        let index = scope.zero(Elem::UInt);
         let max = scope.create_local(input_item);
         let max_initial =
             Variable::ConstantScalar(E::minimum_value().to_f64().unwrap(), input_item.elem());
@@ -15,7 +15,8 @@ impl<E: JitElement> ReduceDimNaive<E> for Argmin {
         input_item: Item,
         _output_item: Item,
     ) -> Self::Accumulator {
-        let index = scope.create_local(Elem::UInt);
+        // Only a strictly better value replaces the index, which keeps the first occurrence.
+        let index = scope.zero(Elem::UInt);
         let min = scope.create_local(input_item);
         let min_initial =
             Variable::ConstantScalar(E::maximum_value().to_f64().unwrap(), input_item.elem());
@@ -1,7 +1,7 @@
 use burn_cube::{
-    cpa,
+    calculate_cube_count_elemwise, cpa,
     frontend::TensorHandle,
-    ir::{Elem, KernelDefinition, Scope, Variable, Visibility},
+    ir::{CubeDim, Elem, KernelDefinition, Scope, Variable, Visibility},
     CubeCountSettings, Execution, InputInfo, KernelExpansion, KernelIntegrator, KernelSettings,
     OutputInfo,
 };
@@ -27,6 +27,7 @@ pub(crate) struct NaiveReduceDimEagerKernel<
     EO: JitElement,
 > {
     dim: usize,
+    cube_dim: usize,
     reduce_dim: PhantomData<RD>,
     _runtime: PhantomData<R>,
     _elem_in: PhantomData<EI>,
@@ -68,12 +69,21 @@ impl<RD: ReduceDimNaive<EI>, R: JitRuntime, EI: JitElement, EO: JitElement> Kern
             scope,
         };
 
-        let settings = KernelSettings::default();
+        let settings = KernelSettings::default().cube_dim(CubeDim::new(
+            self.cube_dim as u32,
+            self.cube_dim as u32,
+            1,
+        ));
         KernelIntegrator::new(info).integrate(settings)
     }
 
     fn id(&self) -> String {
-        format!("{:?}dim={}", core::any::TypeId::of::<Self>(), self.dim)
+        format!(
+            "{:?}dim={}cube_dim={}",
+            core::any::TypeId::of::<Self>(),
+            self.dim,
+            self.cube_dim
+        )
     }
 }
 
@@ -135,7 +145,7 @@ impl<E: JitElement, RD: ReduceDimNaive<E>> NaiveReduceDimComputeShader<E, RD> {
     }
 }
 
-/// Executes the naive kernel for reduce dim
+/// Executes the naive kernel for reduce dim, with square cubes of `cube_dim` units per side
 pub fn reduce_dim_naive<
     RD: ReduceDimNaive<EI>,
     R: JitRuntime,
@@ -146,8 +156,10 @@ pub fn reduce_dim_naive<
     input: JitTensor<R, EI, D>,
     output: JitTensor<R, EO, D>,
     dim: usize,
+    cube_dim: usize,
 ) -> JitTensor<R, EO, D> {
-    let kernel = NaiveReduceDimEagerKernel::<RD, R, EI, EO>::new(dim);
+    let kernel = NaiveReduceDimEagerKernel::<RD, R, EI, EO>::new(dim, cube_dim);
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
 
     Execution::start(kernel, input.client)
         .inputs(&[TensorHandle::<R>::new(
@@ -160,7 +172,7 @@ pub fn reduce_dim_naive<
             &output.strides,
             &output.shape.dims,
         )])
-        .execute(CubeCountSettings::Output { pos: 0 });
+        .execute(CubeCountSettings::Custom(cube_count));
 
     output
 }
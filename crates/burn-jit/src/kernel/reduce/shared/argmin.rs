@@ -21,6 +21,9 @@ impl<E: JitElement> ReduceDimShared<E> for Argmin {
 
         let min = Variable::ConstantScalar(E::maximum_value().to_f64().unwrap(), input_item.elem());
         cpa!(scope, value_shared_memory[write_position] = min);
+        // Slots without any value never win a tie against an actual value.
+        let index_initial = Variable::ConstantScalar(u32::MAX as f64, Elem::UInt);
+        cpa!(scope, index_shared_memory[write_position] = index_initial);
         (value_shared_memory, index_shared_memory)
     }
 
@@ -34,8 +37,18 @@ impl<E: JitElement> ReduceDimShared<E> for Argmin {
         let current_value = scope.create_local(value.item());
         cpa!(scope, current_value = value_shared_memory[write_position]);
 
+        let current_index = scope.create_local(Elem::UInt);
+        cpa!(scope, current_index = index_shared_memory[write_position]);
+
+        // Ties are broken in favor of the first occurrence, whatever the order of the merges.
         let condition = scope.create_local(Elem::Bool);
+        let is_equal = scope.create_local(Elem::Bool);
+        let is_before = scope.create_local(Elem::Bool);
         cpa!(scope, condition = value < current_value);
+        cpa!(scope, is_equal = value == current_value);
+        cpa!(scope, is_before = index < current_index);
+        cpa!(scope, is_equal = is_equal && is_before);
+        cpa!(scope, condition = condition || is_equal);
         cpa!(scope, if(condition).then(|scope| {
             cpa!(scope, value_shared_memory[write_position] = value);
             cpa!(scope, index_shared_memory[write_position] = index);
@@ -4,12 +4,7 @@ use burn_cube::{
 };
 use std::marker::PhantomData;
 
-use crate::{
-    element::JitElement,
-    kernel::{Kernel, SUBCUBE_DIM_APPROX},
-    tensor::JitTensor,
-    JitRuntime,
-};
+use crate::{element::JitElement, kernel::Kernel, tensor::JitTensor, JitRuntime};
 use burn_cube::ir::{Branch, CubeDim, Elem, Scope, Synchronization, Variable, Visibility};
 
 use super::base::ReduceDimShared;
@@ -229,7 +224,8 @@ impl<E: JitElement, RD: ReduceDimShared<E>> SharedReduceDimComputeShader<E, RD>
     }
 }
 
-/// Executes the shared memory kernel for reduce dim
+/// Executes the shared memory kernel for reduce dim, with square cubes of `cube_dim` units per
+/// side
 pub fn reduce_dim_shared<
     RD: ReduceDimShared<EI>,
     R: JitRuntime,
@@ -240,6 +236,7 @@ pub fn reduce_dim_shared<
     input: JitTensor<R, EI, D>,
     output: JitTensor<R, EO, D>,
     dim: usize,
+    cube_dim: usize,
 ) -> JitTensor<R, EO, D> {
     let num_elems_output = output.shape.num_elements();
     let n_workgroups_x = f32::ceil(f32::sqrt(num_elems_output as f32));
@@ -247,7 +244,7 @@ pub fn reduce_dim_shared<
     let grid = CubeCount::new(n_workgroups_x as u32, n_workgroups_y as u32, 1);
 
     let reduce_group_size = input.shape.dims[dim];
-    let n_invocation_per_workgroup = SUBCUBE_DIM_APPROX * SUBCUBE_DIM_APPROX;
+    let n_invocation_per_workgroup = cube_dim * cube_dim;
     let n_input_values_per_thread =
         f32::ceil(reduce_group_size as f32 / n_invocation_per_workgroup as f32) as u32;
 
@@ -256,8 +253,8 @@ pub fn reduce_dim_shared<
 
     let kernel = SharedReduceDimEagerKernel::<RD, R, EI, EO>::new(
         dim,
-        SUBCUBE_DIM_APPROX,
-        SUBCUBE_DIM_APPROX,
+        cube_dim,
+        cube_dim,
         n_input_values_per_thread,
         divisible_shape,
     );
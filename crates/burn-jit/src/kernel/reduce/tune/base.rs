@@ -14,13 +14,15 @@ use crate::{
     },
     ops::numeric::empty_device,
     tensor::JitTensor,
+    tune::CUBE_DIM_CANDIDATES,
     tune_key::JitAutotuneKey,
     JitRuntime,
 };
 
 use super::ReduceAutotuneKey;
 
-/// Set of reduce_dim implementations available for autotune
+/// Set of reduce_dim implementations available for autotune, each with every
+/// [cube dimension candidate](CUBE_DIM_CANDIDATES)
 /// Autotune key is given by concatenating the closest upper power of 2 of
 /// dim to reduce, and product of others
 pub(crate) struct ReduceDimAutotuneOperationSet<
@@ -75,31 +77,45 @@ where
             self.output.shape.clone(),
         );
 
-        vec![
-            Box::new(ReduceDimNaiveAutotune::<RD, R, EI, EO, D>::new(
+        let mut autotunables: Vec<Box<dyn AutotuneOperation>> = Vec::new();
+
+        for cube_dim in CUBE_DIM_CANDIDATES {
+            autotunables.push(Box::new(ReduceDimNaiveAutotune::<RD, R, EI, EO, D>::new(
                 input.clone(),
                 output.clone(),
                 self.reduce_dim,
-            )),
-            Box::new(ReduceDimSharedAutotune::<RD, R, EI, EO, D>::new(
+                cube_dim as usize,
+            )));
+        }
+
+        for cube_dim in CUBE_DIM_CANDIDATES {
+            autotunables.push(Box::new(ReduceDimSharedAutotune::<RD, R, EI, EO, D>::new(
                 input.clone(),
                 output.clone(),
                 self.reduce_dim,
-            )),
-        ]
+                cube_dim as usize,
+            )));
+        }
+
+        autotunables
     }
 
     fn fastest(self: Box<Self>, fastest_index: usize) -> Box<dyn AutotuneOperation> {
-        match fastest_index {
+        let num_candidates = CUBE_DIM_CANDIDATES.len();
+        let cube_dim = CUBE_DIM_CANDIDATES[fastest_index % num_candidates] as usize;
+
+        match fastest_index / num_candidates {
             0 => Box::new(ReduceDimNaiveAutotune::<RD, R, EI, EO, D>::new(
                 self.input,
                 self.output,
                 self.reduce_dim,
+                cube_dim,
             )),
             1 => Box::new(ReduceDimSharedAutotune::<RD, R, EI, EO, D>::new(
                 self.input,
                 self.output,
                 self.reduce_dim,
+                cube_dim,
             )),
             _ => panic!("Fastest index is out of bound"),
         }
@@ -144,6 +160,7 @@ pub(crate) struct ReduceDimNaiveAutotune<
     input: JitTensor<R, EI, D>,
     output: JitTensor<R, EO, D>,
     reduce_dim: usize,
+    cube_dim: usize,
     _algorithm: PhantomData<RD>,
 }
 
@@ -156,7 +173,12 @@ where
 {
     fn execute(self: Box<Self>) {
         #[allow(clippy::redundant_closure_call)]
-        reduce_dim_naive::<RD, R, EI, EO, D>(self.input, self.output, self.reduce_dim);
+        reduce_dim_naive::<RD, R, EI, EO, D>(
+            self.input,
+            self.output,
+            self.reduce_dim,
+            self.cube_dim,
+        );
     }
 
     fn clone(&self) -> Box<dyn AutotuneOperation> {
@@ -164,6 +186,7 @@ where
             input: self.input.clone(),
             output: self.output.clone(),
             reduce_dim: self.reduce_dim,
+            cube_dim: self.cube_dim,
             _algorithm: PhantomData,
         })
     }
@@ -181,6 +204,7 @@ pub(crate) struct ReduceDimSharedAutotune<
     input: JitTensor<R, EI, D>,
     output: JitTensor<R, EO, D>,
     reduce_dim: usize,
+    cube_dim: usize,
     _algorithm: PhantomData<RD>,
 }
 
@@ -193,7 +217,12 @@ where
 {
     fn execute(self: Box<Self>) {
         #[allow(clippy::redundant_closure_call)]
-        reduce_dim_shared::<RD, R, EI, EO, D>(self.input, self.output, self.reduce_dim);
+        reduce_dim_shared::<RD, R, EI, EO, D>(
+            self.input,
+            self.output,
+            self.reduce_dim,
+            self.cube_dim,
+        );
     }
 
     fn clone(&self) -> Box<dyn AutotuneOperation> {
@@ -201,6 +230,7 @@ where
             input: self.input.clone(),
             output: self.output.clone(),
             reduce_dim: self.reduce_dim,
+            cube_dim: self.cube_dim,
             _algorithm: PhantomData,
         })
     }
@@ -0,0 +1,249 @@
+use burn_cube::{
+    calculate_cube_count_elemwise, cpa,
+    frontend::TensorHandle,
+    ir::{Branch, Elem, IntKind, Item, KernelDefinition, Scope, Variable, Visibility},
+    CubeCountSettings, Execution, InputInfo, KernelExpansion, KernelIntegrator, KernelSettings,
+    OutputInfo, SUBCUBE_DIM_APPROX,
+};
+use std::marker::PhantomData;
+
+use crate::{
+    kernel::{self, Kernel},
+    ops::numeric::{div, ones_device},
+    tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+
+use super::SegmentReduction;
+
+#[derive(new)]
+struct ScatterReduceAtomicKernel<R: JitRuntime, E: FloatElement> {
+    dim: usize,
+    reduction: SegmentReduction,
+    _runtime: PhantomData<R>,
+    _elem: PhantomData<E>,
+}
+
+/// Each unit reduces one value into the output with a compare and swap loop on the bits of the
+/// output element, so the values scattered to the same position are reduced in any order.
+struct ScatterReduceAtomicShader {
+    indices: Variable,
+    value: Variable,
+    output: Variable,
+    dim: usize,
+    reduction: SegmentReduction,
+}
+
+impl ScatterReduceAtomicShader {
+    pub fn expand(self, scope: &mut Scope) {
+        let indices = self.indices;
+        let value = self.value;
+        let output = self.output;
+        let id = Variable::AbsolutePos;
+
+        let num_elems = scope.create_local(Elem::UInt);
+        let should_stop = scope.create_local(Elem::Bool);
+        cpa!(scope, num_elems = len(value));
+        cpa!(scope, should_stop = id >= num_elems);
+        cpa!(scope, if (should_stop).then(|scope| {
+            scope.register(Branch::Return);
+        }));
+
+        // The value and indices are contiguous, so the position of the unit in the value tensor
+        // is decomposed with its strides.
+        let offset_output = scope.zero(Elem::UInt);
+        cpa!(
+            scope,
+            range(0u32, Variable::Rank).for_each(|i, scope| {
+                let should_skip = scope.create_local(Elem::Bool);
+                cpa!(scope, should_skip = i == self.dim);
+
+                cpa!(scope, if(should_skip).then(|_| {
+                    // The coordinate along the scattered dimension is the index.
+                }).else(|scope| {
+                    let stride_value = scope.create_local(Elem::UInt);
+                    let shape_value = scope.create_local(Elem::UInt);
+                    let stride_output = scope.create_local(Elem::UInt);
+                    let coordinate = scope.create_local(Elem::UInt);
+
+                    cpa!(scope, stride_value = stride(value, i));
+                    cpa!(scope, shape_value = shape(value, i));
+                    cpa!(scope, stride_output = stride(output, i));
+
+                    cpa!(scope, coordinate = id / stride_value);
+                    cpa!(scope, coordinate = coordinate % shape_value);
+                    cpa!(scope, coordinate = coordinate * stride_output);
+                    cpa!(scope, offset_output += coordinate);
+                }));
+            })
+        );
+
+        let index = scope.create_local(Elem::UInt);
+        let stride_dim = scope.create_local(Elem::UInt);
+        cpa!(scope, index = indices[id]);
+        cpa!(scope, stride_dim = stride(output, self.dim));
+        cpa!(scope, index = index * stride_dim);
+        cpa!(scope, offset_output += index);
+
+        let item_value = value.item();
+        let result_value = scope.create_local(item_value);
+        let current = scope.create_local(item_value);
+        let result = scope.create_local(item_value);
+        let current_bits = scope.create_local(Elem::UInt);
+        let result_bits = scope.create_local(Elem::UInt);
+        let previous_bits = scope.create_local(Elem::UInt);
+        let swapped = scope.create_local(Elem::Bool);
+        let reduction = self.reduction;
+
+        cpa!(scope, result_value = value[id]);
+        cpa!(scope, current_bits = atomic_load(output, offset_output));
+        cpa!(
+            scope,
+            loop(|scope| {
+                cpa!(scope, current = bitcast(current_bits));
+
+                match reduction {
+                    SegmentReduction::Sum | SegmentReduction::Mean => {
+                        cpa!(scope, result = current + result_value)
+                    }
+                    SegmentReduction::Max => cpa!(scope, result = max(current, result_value)),
+                    SegmentReduction::Min => cpa!(scope, result = min(current, result_value)),
+                }
+
+                cpa!(scope, result_bits = bitcast(result));
+                cpa!(
+                    scope,
+                    previous_bits =
+                        atomic_compare_and_swap(output, offset_output, current_bits, result_bits)
+                );
+                cpa!(scope, swapped = previous_bits == current_bits);
+                cpa!(scope, if(swapped).then(|scope| {
+                    scope.register(Branch::Break);
+                }));
+
+                // Another unit updated the element in the meantime, so retry with its value.
+                cpa!(scope, current_bits = previous_bits);
+            })
+        );
+    }
+}
+
+impl<R: JitRuntime, E: FloatElement> Kernel for ScatterReduceAtomicKernel<R, E> {
+    fn define(&self) -> KernelDefinition {
+        let mut scope = Scope::root();
+        let item_value: Item = E::cube_elem().into();
+        let item_indices: Item = Elem::Int(IntKind::I32).into();
+        let item_output: Item = Elem::AtomicUInt.into();
+
+        let indices = Variable::GlobalInputArray(0, item_indices);
+        let value = Variable::GlobalInputArray(1, item_value);
+        let output = Variable::GlobalOutputArray(0, item_output);
+
+        ScatterReduceAtomicShader {
+            indices,
+            value,
+            output,
+            dim: self.dim,
+            reduction: self.reduction,
+        }
+        .expand(&mut scope);
+
+        let indices = InputInfo::Array {
+            item: item_indices,
+            visibility: Visibility::Read,
+        };
+        let value = InputInfo::Array {
+            item: item_value,
+            visibility: Visibility::Read,
+        };
+        let output = OutputInfo::Array { item: item_output };
+
+        let info = KernelExpansion {
+            inputs: vec![indices, value],
+            outputs: vec![output],
+            scope,
+        };
+
+        KernelIntegrator::new(info).integrate(KernelSettings::default())
+    }
+
+    fn id(&self) -> String {
+        format!(
+            "{:?}dim={}reduction={:?}",
+            core::any::TypeId::of::<Self>(),
+            self.dim,
+            self.reduction
+        )
+    }
+}
+
+fn launch<R: JitRuntime, E: FloatElement, I: IntElement, const D: usize>(
+    dim: usize,
+    tensor: JitTensor<R, E, D>,
+    indices: &JitTensor<R, I, D>,
+    value: &JitTensor<R, E, D>,
+    reduction: SegmentReduction,
+) -> JitTensor<R, E, D> {
+    let output = match tensor.can_mut() {
+        true => tensor,
+        false => tensor.copy(),
+    };
+    let num_elems = value.shape.num_elements();
+    let kernel = ScatterReduceAtomicKernel::<R, E>::new(dim, reduction);
+
+    Execution::start(kernel, output.client.clone())
+        .inputs(&[
+            TensorHandle::<R>::new(&indices.handle, &indices.strides, &indices.shape.dims),
+            TensorHandle::new(&value.handle, &value.strides, &value.shape.dims),
+        ])
+        .outputs(&[TensorHandle::new(
+            &output.handle,
+            &output.strides,
+            &output.shape.dims,
+        )])
+        .execute(CubeCountSettings::Custom(calculate_cube_count_elemwise(
+            num_elems,
+            SUBCUBE_DIM_APPROX,
+        )));
+
+    output
+}
+
+/// Reduce the values with atomic operations on the 32 bits of the output elements, which is only
+/// valid for `f32` elements.
+pub(crate) fn scatter_reduce_atomic<
+    R: JitRuntime,
+    E: FloatElement,
+    I: IntElement,
+    const D: usize,
+>(
+    dim: usize,
+    tensor: JitTensor<R, E, D>,
+    indices: JitTensor<R, I, D>,
+    value: JitTensor<R, E, D>,
+    reduction: SegmentReduction,
+) -> JitTensor<R, E, D> {
+    let indices = kernel::into_contiguous(indices);
+    let value = kernel::into_contiguous(value);
+
+    if reduction != SegmentReduction::Mean {
+        return launch(dim, tensor, &indices, &value, reduction);
+    }
+
+    // The mean divides the sum by the number of values at each position, counting the element
+    // of the input tensor.
+    let counts = ones_device::<R, E, D>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+    let ones = ones_device::<R, E, D>(
+        value.client.clone(),
+        value.device.clone(),
+        value.shape.clone(),
+    );
+    let sum = launch(dim, tensor, &indices, &value, SegmentReduction::Sum);
+    let counts = launch(dim, counts, &indices, &ones, SegmentReduction::Sum);
+
+    div(sum, counts)
+}
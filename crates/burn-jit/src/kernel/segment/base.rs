@@ -0,0 +1,140 @@
+use burn_cube::{
+    ir::{Elem, FloatKind},
+    prelude::*,
+};
+use burn_tensor::ops::ScatterReduction;
+
+use crate::{tensor::JitTensor, FloatElement, IntElement, JitRuntime};
+
+use super::{
+    atomic::scatter_reduce_atomic, serial::scatter_reduce_serial, sorted::scatter_reduce_sorted,
+};
+
+/// Above this number of values reduced along the dimension, the [sorted](SegmentReduceStrategy::Sorted)
+/// strategy is selected by default.
+const SERIAL_MAX_LENGTH: usize = 64;
+
+/// Strategy used to reduce the values scattered to the same position.
+///
+/// The [serial](SegmentReduceStrategy::Serial) and [sorted](SegmentReduceStrategy::Sorted)
+/// strategies reduce the values in the order of the indices, so their results are deterministic
+/// and identical. The [atomic](SegmentReduceStrategy::Atomic) strategy is faster, but the order of
+/// the floating point operations, and so the rounding of the sums, changes between executions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentReduceStrategy {
+    /// Each unit reduces all the values of one slice along the scattered dimension, which is the
+    /// fastest when there are few values per slice.
+    Serial,
+    /// The indices are first sorted with a stable sort, then each unit reduces the contiguous run
+    /// of values scattered to one output element, which scales with the number of values.
+    Sorted,
+    /// Each unit reduces one value into the output with atomic operations, in any order. Only
+    /// supported for `f32` elements, the sorted strategy being used for the other elements.
+    Atomic,
+}
+
+impl SegmentReduceStrategy {
+    /// The deterministic strategy suited to reduce the given number of values along the
+    /// scattered dimension.
+    pub fn from_length(length: usize) -> Self {
+        match length > SERIAL_MAX_LENGTH {
+            true => Self::Sorted,
+            false => Self::Serial,
+        }
+    }
+
+    /// The strategy suited to reduce the given number of values along the scattered dimension,
+    /// the [atomic](SegmentReduceStrategy::Atomic) strategy being selected for long dimensions
+    /// when the result doesn't have to be deterministic.
+    pub fn select(length: usize, deterministic: bool) -> Self {
+        match Self::from_length(length) {
+            Self::Sorted if !deterministic => Self::Atomic,
+            strategy => strategy,
+        }
+    }
+}
+
+/// Compilation time reduction of the segment kernels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SegmentReduction {
+    Sum,
+    Mean,
+    Max,
+    Min,
+}
+
+impl Init for SegmentReduction {
+    fn init(self, _context: &mut CubeContext) -> Self {
+        self
+    }
+}
+
+impl From<ScatterReduction> for SegmentReduction {
+    fn from(reduction: ScatterReduction) -> Self {
+        match reduction {
+            ScatterReduction::Sum => Self::Sum,
+            ScatterReduction::Mean => Self::Mean,
+            ScatterReduction::Max => Self::Max,
+            ScatterReduction::Min => Self::Min,
+        }
+    }
+}
+
+/// Combine the accumulated value with a new value, the mean being accumulated as a sum.
+#[cube]
+pub(crate) fn accumulate<F: Float>(
+    accumulator: F,
+    value: F,
+    reduction: Comptime<SegmentReduction>,
+) -> F {
+    let is_max = Comptime::map(reduction, |r: SegmentReduction| r == SegmentReduction::Max);
+    let is_min = Comptime::map(reduction, |r: SegmentReduction| r == SegmentReduction::Min);
+    let is_sum = Comptime::map(reduction, |r: SegmentReduction| {
+        r == SegmentReduction::Sum || r == SegmentReduction::Mean
+    });
+    let mut result = accumulator;
+
+    if Comptime::get(is_max) {
+        result = F::max(accumulator, value);
+    }
+    if Comptime::get(is_min) {
+        result = F::min(accumulator, value);
+    }
+    if Comptime::get(is_sum) {
+        result = accumulator + value;
+    }
+
+    result
+}
+
+/// Reduce the `value` tensor into the input `tensor` at the given `indices` along a dimension,
+/// the elements of the input tensor being part of the reduction.
+pub fn scatter_reduce<R: JitRuntime, E: FloatElement, I: IntElement, const D: usize>(
+    dim: usize,
+    tensor: JitTensor<R, E, D>,
+    indices: JitTensor<R, I, D>,
+    value: JitTensor<R, E, D>,
+    reduction: ScatterReduction,
+    strategy: SegmentReduceStrategy,
+) -> JitTensor<R, E, D> {
+    if value.shape.dims[dim] == 0 {
+        return tensor;
+    }
+
+    match strategy {
+        SegmentReduceStrategy::Serial => {
+            scatter_reduce_serial::<R, E, I, D>(dim, tensor, indices, value, reduction.into())
+        }
+        SegmentReduceStrategy::Sorted => {
+            scatter_reduce_sorted::<R, E, I, D>(dim, tensor, indices, value, reduction.into())
+        }
+        SegmentReduceStrategy::Atomic => match E::cube_elem() == Elem::Float(FloatKind::F32) {
+            true => {
+                scatter_reduce_atomic::<R, E, I, D>(dim, tensor, indices, value, reduction.into())
+            }
+            false => {
+                scatter_reduce_sorted::<R, E, I, D>(dim, tensor, indices, value, reduction.into())
+            }
+        },
+    }
+}
@@ -0,0 +1,6 @@
+mod atomic;
+mod base;
+mod serial;
+mod sorted;
+
+pub use base::{scatter_reduce, SegmentReduceStrategy};
@@ -0,0 +1,118 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+
+use crate::{
+    ops::numeric::{div, ones_device},
+    tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+
+use super::{accumulate, SegmentReduction};
+
+/// Each unit reduces the values of one slice along the scattered dimension in order, so the
+/// values scattered to the same position are never updated concurrently.
+#[cube(launch)]
+fn scatter_reduce_serial_kernel<F: Float>(
+    indices: Tensor<I32>,
+    value: Tensor<F>,
+    mut output: Tensor<F>,
+    dim: UInt,
+    reduction: Comptime<SegmentReduction>,
+) {
+    let length = value.shape(dim);
+
+    if ABSOLUTE_POS >= value.len() / length {
+        return;
+    }
+
+    let rank = value.rank();
+    let mut remainder = ABSOLUTE_POS;
+    let mut offset_indices = UInt::new(0);
+    let mut offset_value = UInt::new(0);
+    let mut offset_output = UInt::new(0);
+
+    for i in range(0u32, rank, Comptime::new(false)) {
+        let d = rank - i - UInt::new(1);
+
+        if d != dim {
+            let size = value.shape(d);
+            let coordinate = remainder % size;
+            remainder /= size;
+
+            offset_indices += coordinate * indices.stride(d);
+            offset_value += coordinate * value.stride(d);
+            offset_output += coordinate * output.stride(d);
+        }
+    }
+
+    for k in range(0u32, length, Comptime::new(false)) {
+        let index = UInt::cast_from(indices[offset_indices + k * indices.stride(dim)]);
+        let position = offset_output + index * output.stride(dim);
+
+        output[position] = accumulate::<F>(
+            output[position],
+            value[offset_value + k * value.stride(dim)],
+            reduction,
+        );
+    }
+}
+
+fn launch<R: JitRuntime, E: FloatElement, I: IntElement, const D: usize>(
+    dim: usize,
+    tensor: JitTensor<R, E, D>,
+    indices: &JitTensor<R, I, D>,
+    value: &JitTensor<R, E, D>,
+    reduction: SegmentReduction,
+) -> JitTensor<R, E, D> {
+    let output = match tensor.can_mut() {
+        true => tensor,
+        false => tensor.copy(),
+    };
+    let num_slices = value.shape.num_elements() / value.shape.dims[dim];
+
+    scatter_reduce_serial_kernel_launch::<E::CubeElement, R>(
+        output.client.clone(),
+        calculate_cube_count_elemwise(num_slices, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&indices.handle, &indices.strides, &indices.shape.dims),
+        TensorHandle::new(&value.handle, &value.strides, &value.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        UInt::new(dim as u32),
+        reduction,
+    );
+
+    output
+}
+
+pub(crate) fn scatter_reduce_serial<
+    R: JitRuntime,
+    E: FloatElement,
+    I: IntElement,
+    const D: usize,
+>(
+    dim: usize,
+    tensor: JitTensor<R, E, D>,
+    indices: JitTensor<R, I, D>,
+    value: JitTensor<R, E, D>,
+    reduction: SegmentReduction,
+) -> JitTensor<R, E, D> {
+    if reduction != SegmentReduction::Mean {
+        return launch(dim, tensor, &indices, &value, reduction);
+    }
+
+    // The mean divides the sum by the number of values at each position, counting the element
+    // of the input tensor.
+    let counts = ones_device::<R, E, D>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+    let ones = ones_device::<R, E, D>(
+        value.client.clone(),
+        value.device.clone(),
+        value.shape.clone(),
+    );
+    let sum = launch(dim, tensor, &indices, &value, SegmentReduction::Sum);
+    let counts = launch(dim, counts, &indices, &ones, SegmentReduction::Sum);
+
+    div(sum, counts)
+}
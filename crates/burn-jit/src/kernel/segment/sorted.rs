@@ -0,0 +1,139 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+
+use crate::{
+    kernel::sort::int_sort_with_indices, ops::numeric::empty_device, tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+
+use super::{accumulate, SegmentReduction};
+
+/// Each unit computes one output element, finding the run of values scattered to it with a
+/// binary search in the sorted indices of its slice. The sort being stable, the run follows the
+/// original order of the values.
+#[cube(launch)]
+fn scatter_reduce_sorted_kernel<F: Float>(
+    tensor: Tensor<F>,
+    sorted_indices: Tensor<I32>,
+    permutation: Tensor<I32>,
+    value: Tensor<F>,
+    mut output: Tensor<F>,
+    dim: UInt,
+    reduction: Comptime<SegmentReduction>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let is_mean = Comptime::map(reduction, |r: SegmentReduction| r == SegmentReduction::Mean);
+    let length = sorted_indices.shape(dim);
+    let target = ABSOLUTE_POS / output.stride(dim) % output.shape(dim);
+
+    let mut offset_tensor = UInt::new(0);
+    let mut offset_sorted = UInt::new(0);
+    let mut offset_permutation = UInt::new(0);
+    let mut offset_value = UInt::new(0);
+
+    for d in range(0u32, output.rank(), Comptime::new(false)) {
+        let coordinate = ABSOLUTE_POS / output.stride(d) % output.shape(d);
+        offset_tensor += coordinate * tensor.stride(d);
+
+        if d != dim {
+            offset_sorted += coordinate * sorted_indices.stride(d);
+            offset_permutation += coordinate * permutation.stride(d);
+            offset_value += coordinate * value.stride(d);
+        }
+    }
+
+    let stride_sorted = sorted_indices.stride(dim);
+    let stride_permutation = permutation.stride(dim);
+    let stride_value = value.stride(dim);
+
+    // Lower bound of the target in the sorted indices.
+    let mut low = UInt::new(0);
+    let mut high = length;
+
+    while low < high {
+        let middle = (low + high) / UInt::new(2);
+        let index = UInt::cast_from(sorted_indices[offset_sorted + middle * stride_sorted]);
+
+        if index < target {
+            low = middle + UInt::new(1);
+        } else {
+            high = middle;
+        }
+    }
+
+    let mut result = tensor[offset_tensor];
+    let mut count = F::new(1.0);
+    let mut k = low;
+
+    loop {
+        if k >= length {
+            break;
+        }
+
+        let index = UInt::cast_from(sorted_indices[offset_sorted + k * stride_sorted]);
+
+        if index != target {
+            break;
+        }
+
+        let position = UInt::cast_from(permutation[offset_permutation + k * stride_permutation]);
+        result = accumulate::<F>(
+            result,
+            value[offset_value + position * stride_value],
+            reduction,
+        );
+        count += F::new(1.0);
+        k += UInt::new(1);
+    }
+
+    if Comptime::get(is_mean) {
+        result /= count;
+    }
+
+    output[ABSOLUTE_POS] = result;
+}
+
+pub(crate) fn scatter_reduce_sorted<
+    R: JitRuntime,
+    E: FloatElement,
+    I: IntElement,
+    const D: usize,
+>(
+    dim: usize,
+    tensor: JitTensor<R, E, D>,
+    indices: JitTensor<R, I, D>,
+    value: JitTensor<R, E, D>,
+    reduction: SegmentReduction,
+) -> JitTensor<R, E, D> {
+    let (sorted_indices, permutation) = int_sort_with_indices::<R, I, D>(indices, dim, false);
+    let output = empty_device::<R, E, D>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    scatter_reduce_sorted_kernel_launch::<E::CubeElement, R>(
+        output.client.clone(),
+        calculate_cube_count_elemwise(output.shape.num_elements(), SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+        TensorHandle::new(
+            &sorted_indices.handle,
+            &sorted_indices.strides,
+            &sorted_indices.shape.dims,
+        ),
+        TensorHandle::new(
+            &permutation.handle,
+            &permutation.strides,
+            &permutation.shape.dims,
+        ),
+        TensorHandle::new(&value.handle, &value.strides, &value.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        UInt::new(dim as u32),
+        reduction,
+    );
+
+    output
+}
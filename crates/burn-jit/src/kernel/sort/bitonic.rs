@@ -0,0 +1,321 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+
+use crate::{
+    ops::numeric::empty_device, tensor::JitTensor, FloatElement, IntElement, JitElement, JitRuntime,
+};
+
+#[derive(CubeLaunch)]
+struct BitonicArgs {
+    /// Length of the sorted dimension.
+    length: UInt,
+    /// Length of the sorted dimension padded to the next power of 2.
+    padded_length: UInt,
+    /// Stride of the sorted dimension in the padded buffers.
+    stride_dim: UInt,
+    /// Size of the bitonic sequences being merged.
+    block: UInt,
+    /// Distance between the compared elements.
+    offset: UInt,
+}
+
+/// NaN is the only value that isn't equal to itself.
+#[cube]
+fn is_nan<N: Numeric>(value: N) -> bool {
+    let other = value;
+    value != other
+}
+
+/// Whether the element `a` comes before `b`, the padding coming last and ties being broken by
+/// the original position so the sort is stable. NaNs are greater than any other value, like with
+/// the total ordering of floats used by the other backends.
+#[cube]
+fn sorts_before<N: Numeric>(
+    value_a: N,
+    index_a: UInt,
+    value_b: N,
+    index_b: UInt,
+    length: UInt,
+    descending: Comptime<bool>,
+) -> bool {
+    let mut before = index_a < index_b;
+
+    if index_a < length && index_b < length {
+        let nan_a = is_nan::<N>(value_a);
+        let nan_b = is_nan::<N>(value_b);
+        let unordered = nan_a || nan_b;
+
+        if (nan_a && !nan_b) || (nan_b && !nan_a) {
+            if Comptime::get(descending) {
+                before = nan_a;
+            } else {
+                before = nan_b;
+            }
+        }
+
+        if !unordered && value_a != value_b {
+            if Comptime::get(descending) {
+                before = value_a > value_b;
+            } else {
+                before = value_a < value_b;
+            }
+        }
+    }
+
+    before
+}
+
+/// Copy the input to the padded buffers, where each element is tagged with its position along
+/// the sorted dimension.
+#[cube(launch)]
+fn sort_init_kernel<N: Numeric>(
+    input: Tensor<N>,
+    mut values: Tensor<N>,
+    mut indices: Tensor<UInt>,
+    args: BitonicArgs,
+) {
+    if ABSOLUTE_POS >= values.len() {
+        return;
+    }
+
+    let position = ABSOLUTE_POS / args.stride_dim % args.padded_length;
+    let mut value = N::from_int(0);
+
+    if position < args.length {
+        let mut index_input = UInt::new(0);
+
+        for d in range(0u32, values.rank(), Comptime::new(false)) {
+            let coordinate = ABSOLUTE_POS / values.stride(d) % values.shape(d);
+            index_input += coordinate * input.stride(d);
+        }
+
+        value = input[index_input];
+    }
+
+    values[ABSOLUTE_POS] = value;
+    indices[ABSOLUTE_POS] = position;
+}
+
+/// One compare and swap step of the bitonic network, where each unit handles a pair of elements
+/// along the sorted dimension.
+#[cube(launch)]
+fn bitonic_step_kernel<N: Numeric>(
+    mut values: Tensor<N>,
+    mut indices: Tensor<UInt>,
+    args: BitonicArgs,
+    descending: Comptime<bool>,
+) {
+    if ABSOLUTE_POS >= values.len() / UInt::new(2) {
+        return;
+    }
+
+    let half_length = args.padded_length / UInt::new(2);
+    let inner = ABSOLUTE_POS % args.stride_dim;
+    let pair = ABSOLUTE_POS / args.stride_dim % half_length;
+    let outer = ABSOLUTE_POS / (args.stride_dim * half_length);
+
+    let position = pair / args.offset * args.offset * UInt::new(2) + pair % args.offset;
+    let base = outer * args.padded_length * args.stride_dim + inner;
+    let index_lhs = base + position * args.stride_dim;
+    let index_rhs = index_lhs + args.offset * args.stride_dim;
+
+    let value_lhs = values[index_lhs];
+    let value_rhs = values[index_rhs];
+    let position_lhs = indices[index_lhs];
+    let position_rhs = indices[index_rhs];
+
+    // The sorted order is a strict total order, so the lhs comes first when the rhs doesn't.
+    let ascending = (position & args.block) == UInt::new(0);
+    let rhs_first = sorts_before::<N>(
+        value_rhs,
+        position_rhs,
+        value_lhs,
+        position_lhs,
+        args.length,
+        descending,
+    );
+    let swap = (ascending && rhs_first) || (!ascending && !rhs_first);
+
+    if swap {
+        values[index_lhs] = value_rhs;
+        values[index_rhs] = value_lhs;
+        indices[index_lhs] = position_rhs;
+        indices[index_rhs] = position_lhs;
+    }
+}
+
+/// Gather the sorted elements from the padded buffers, dropping the padding.
+#[cube(launch)]
+fn sort_output_kernel<N: Numeric>(
+    values: Tensor<N>,
+    indices: Tensor<UInt>,
+    mut output: Tensor<N>,
+    mut output_indices: Tensor<I32>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let mut index_padded = UInt::new(0);
+
+    for d in range(0u32, output.rank(), Comptime::new(false)) {
+        let coordinate = ABSOLUTE_POS / output.stride(d) % output.shape(d);
+        index_padded += coordinate * values.stride(d);
+    }
+
+    output[ABSOLUTE_POS] = values[index_padded];
+    output_indices[ABSOLUTE_POS] = I32::cast_from(indices[index_padded]);
+}
+
+/// Sort the elements of the tensor along the given dimension with a bitonic sorting network,
+/// directly on the strided layout so sorting along any dimension doesn't require a permute.
+///
+/// The sort is stable, equal elements keeping their relative order, which is consistent with the
+/// other backends.
+pub fn sort_with_indices<R: JitRuntime, E: FloatElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+    descending: bool,
+) -> (JitTensor<R, E, D>, JitTensor<R, I, D>) {
+    bitonic_sort::<R, E::CubeElement, E, I, D>(tensor, dim, descending)
+}
+
+/// Sort the elements of the int tensor along the given dimension, see [sort_with_indices].
+pub fn int_sort_with_indices<R: JitRuntime, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, I, D>,
+    dim: usize,
+    descending: bool,
+) -> (JitTensor<R, I, D>, JitTensor<R, I, D>) {
+    // The int element of the backend is always `i32`.
+    bitonic_sort::<R, I32, I, I, D>(tensor, dim, descending)
+}
+
+fn bitonic_sort<R: JitRuntime, N: Numeric, E: JitElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+    descending: bool,
+) -> (JitTensor<R, E, D>, JitTensor<R, I, D>) {
+    let length = tensor.shape.dims[dim];
+    let padded_length = length.next_power_of_two();
+
+    let mut padded_shape = tensor.shape.clone();
+    padded_shape.dims[dim] = padded_length;
+
+    let values = empty_device::<R, E, D>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        padded_shape.clone(),
+    );
+    let indices = empty_device::<R, u32, D>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        padded_shape.clone(),
+    );
+    let stride_dim = values.strides[dim];
+    let num_elems = padded_shape.num_elements();
+
+    let args = |block: usize, offset: usize| {
+        BitonicArgsLaunch::new(
+            length as u32,
+            padded_length as u32,
+            stride_dim as u32,
+            block as u32,
+            offset as u32,
+        )
+    };
+
+    sort_init_kernel_launch::<N, R>(
+        tensor.client.clone(),
+        calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+        TensorHandle::new(&values.handle, &values.strides, &values.shape.dims),
+        TensorHandle::new(&indices.handle, &indices.strides, &indices.shape.dims),
+        args(0, 0),
+    );
+
+    let mut block = 2;
+
+    while block <= padded_length {
+        let mut offset = block / 2;
+
+        while offset > 0 {
+            bitonic_step_kernel_launch::<N, R>(
+                tensor.client.clone(),
+                calculate_cube_count_elemwise(num_elems / 2, SUBCUBE_DIM_APPROX),
+                KernelSettings::default(),
+                TensorHandle::new(&values.handle, &values.strides, &values.shape.dims),
+                TensorHandle::new(&indices.handle, &indices.strides, &indices.shape.dims),
+                args(block, offset),
+                descending,
+            );
+            offset /= 2;
+        }
+
+        block *= 2;
+    }
+
+    let output = empty_device::<R, E, D>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+    let output_indices = empty_device::<R, I, D>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    sort_output_kernel_launch::<N, R>(
+        tensor.client.clone(),
+        calculate_cube_count_elemwise(output.shape.num_elements(), SUBCUBE_DIM_APPROX),
+        KernelSettings::default(),
+        TensorHandle::new(&values.handle, &values.strides, &values.shape.dims),
+        TensorHandle::new(&indices.handle, &indices.strides, &indices.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        TensorHandle::new(
+            &output_indices.handle,
+            &output_indices.strides,
+            &output_indices.shape.dims,
+        ),
+    );
+
+    (output, output_indices)
+}
+
+/// Sort the elements of the tensor along the given dimension, see [sort_with_indices].
+pub fn sort<R: JitRuntime, E: FloatElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+    descending: bool,
+) -> JitTensor<R, E, D> {
+    sort_with_indices::<R, E, I, D>(tensor, dim, descending).0
+}
+
+/// Returns the indices that sort the elements of the tensor along the given dimension, see
+/// [sort_with_indices].
+pub fn argsort<R: JitRuntime, E: FloatElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+    descending: bool,
+) -> JitTensor<R, I, D> {
+    sort_with_indices::<R, E, I, D>(tensor, dim, descending).1
+}
+
+/// Sort the elements of the int tensor along the given dimension, see [sort_with_indices].
+pub fn int_sort<R: JitRuntime, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, I, D>,
+    dim: usize,
+    descending: bool,
+) -> JitTensor<R, I, D> {
+    int_sort_with_indices::<R, I, D>(tensor, dim, descending).0
+}
+
+/// Returns the indices that sort the elements of the int tensor along the given dimension, see
+/// [sort_with_indices].
+pub fn int_argsort<R: JitRuntime, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, I, D>,
+    dim: usize,
+    descending: bool,
+) -> JitTensor<R, I, D> {
+    int_sort_with_indices::<R, I, D>(tensor, dim, descending).1
+}
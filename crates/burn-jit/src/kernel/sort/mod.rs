@@ -0,0 +1,3 @@
+mod bitonic;
+
+pub use bitonic::*;
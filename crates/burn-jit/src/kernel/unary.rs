@@ -1,8 +1,8 @@
-use burn_cube::{frontend::TensorHandle, CubeCountSettings, Execution};
+use burn_cube::{frontend::TensorHandle, ir::Vectorization, Execution};
 
 use crate::{element::JitElement, tensor::JitTensor, JitRuntime};
 
-use super::Kernel;
+use super::{elemwise_cube_count, Kernel};
 
 /// Creates a unary kernel.
 #[macro_export]
@@ -15,13 +15,23 @@ macro_rules! unary {
     ) => {{
         unary!(operation: $ops, compiler: <$runtime as Runtime>::Compiler);
 
+        let input = $input;
+        let vectorization = $crate::kernel::elemwise_vectorization(&[&input]);
+
         $crate::kernel::unary::<
             Ops<<$runtime as Runtime>::Compiler, $elem>,
             OpsInplace<<$runtime as Runtime>::Compiler, $elem>,
             $runtime,
             $elem,
             D
-        >($input, None, true, Ops::new(), OpsInplace::new())
+        >(
+            input,
+            None,
+            true,
+            vectorization,
+            Ops::new(vectorization),
+            OpsInplace::new(vectorization),
+        )
     }};
     (
         operation: $ops:expr,
@@ -37,7 +47,7 @@ macro_rules! unary {
             $runtime,
             $elem,
             D
-        >($input, Some(&[$scalar]), true, Ops::new(), OpsInplace::new())
+        >($input, Some(&[$scalar]), true, 1, Ops::new(), OpsInplace::new())
     }};
 
     (
@@ -46,11 +56,13 @@ macro_rules! unary {
     ) => {
         #[derive(new)]
         pub struct Ops<C, E> {
+            vectorization: burn_cube::ir::Vectorization,
             _c: core::marker::PhantomData<C>,
             _e: core::marker::PhantomData<E>,
         }
         #[derive(new)]
         pub struct OpsInplace<C, E> {
+            vectorization: burn_cube::ir::Vectorization,
             _c: core::marker::PhantomData<C>,
             _e: core::marker::PhantomData<E>,
         }
@@ -93,9 +105,16 @@ macro_rules! unary {
             E: $crate::element::JitElement,
         {
             fn define(&self) -> burn_cube::ir::KernelDefinition {
-                let settings = burn_cube::KernelSettings::default();
+                let mut settings = burn_cube::KernelSettings::default();
+                if self.vectorization > 1 {
+                    settings = settings.vectorize_global(self.vectorization);
+                }
                 compile::<E>(settings)
             }
+
+            fn id(&self) -> String {
+                format!("{:?}-vec{}", core::any::TypeId::of::<Self>(), self.vectorization)
+            }
         }
 
         #[allow(clippy::redundant_closure_call)]
@@ -109,10 +128,17 @@ macro_rules! unary {
                     pos_input: 0,
                     pos_output: 0,
                 };
-                let settings = burn_cube::KernelSettings::default()
+                let mut settings = burn_cube::KernelSettings::default()
                     .inplace(vec![mapping]);
+                if self.vectorization > 1 {
+                    settings = settings.vectorize_global(self.vectorization);
+                }
                 compile::<E>(settings)
             }
+
+            fn id(&self) -> String {
+                format!("{:?}-vec{}", core::any::TypeId::of::<Self>(), self.vectorization)
+            }
         }
     };
     (
@@ -198,10 +224,14 @@ macro_rules! unary {
 }
 
 /// Launch an unary operation.
+///
+/// The kernels must be compiled with the given vectorization factor, each unit processing that
+/// many contiguous elements.
 pub fn unary<K, Kinplace, R: JitRuntime, E, const D: usize>(
     tensor: JitTensor<R, E, D>,
     scalars: Option<&[E]>,
     inplace_enabled: bool,
+    vectorization: Vectorization,
     kernel: K,
     kernel_inplace: Kinplace,
 ) -> JitTensor<R, E, D>
@@ -217,7 +247,7 @@ where
             &tensor.shape.dims,
         )];
 
-        let launch = CubeCountSettings::Input { pos: 0 };
+        let launch = elemwise_cube_count(tensor.shape.num_elements(), vectorization);
 
         match scalars {
             Some(scalars) => {
@@ -256,7 +286,7 @@ where
             &output.shape.dims,
         )];
 
-        let launch = CubeCountSettings::Output { pos: 0 };
+        let launch = elemwise_cube_count(num_elems, vectorization);
 
         match scalars {
             Some(scalars) => {
@@ -0,0 +1,41 @@
+use burn_cube::{calculate_cube_count_elemwise, ir::Vectorization, CubeCountSettings};
+
+use crate::{element::JitElement, tensor::JitTensor, JitRuntime};
+
+use super::SUBCUBE_DIM_APPROX;
+
+/// Select the vectorization factor of an elementwise kernel reading the given tensors.
+///
+/// Each unit processes a vec4 or a vec2 of contiguous elements when the last dimension of every
+/// tensor is contiguous and divisible by the factor, so that no unit has a partial vector to
+/// handle. Otherwise, one element is processed per unit.
+pub fn elemwise_vectorization<R: JitRuntime, E: JitElement, const D: usize>(
+    tensors: &[&JitTensor<R, E, D>],
+) -> Vectorization {
+    let can_vectorize = |factor: usize| {
+        tensors.iter().all(|tensor| {
+            // Last dimension strides should be 1, otherwise vecX won't be contiguous.
+            tensor.strides[D - 1] == 1 && tensor.shape.dims[D - 1] % factor == 0
+        })
+    };
+
+    if can_vectorize(4) {
+        4
+    } else if can_vectorize(2) {
+        2
+    } else {
+        1
+    }
+}
+
+/// The cube count of an elementwise kernel, where one cube unit is assigned to `vectorization`
+/// elements.
+pub(crate) fn elemwise_cube_count(
+    num_elems: usize,
+    vectorization: Vectorization,
+) -> CubeCountSettings {
+    CubeCountSettings::Custom(calculate_cube_count_elemwise(
+        num_elems / vectorization as usize,
+        SUBCUBE_DIM_APPROX,
+    ))
+}
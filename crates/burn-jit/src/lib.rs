@@ -13,6 +13,9 @@ pub mod kernel;
 /// Tensor module.
 pub mod tensor;
 
+/// Module for launching custom kernels on tensors.
+pub mod custom;
+
 pub(crate) mod tune;
 
 /// Elements for JIT backend
@@ -33,6 +36,10 @@ pub use tune_key::JitAutotuneKey;
 #[cfg(any(feature = "fusion", test))]
 mod fusion;
 
+/// Module for running the kernels on the device of another machine.
+#[cfg(feature = "remote")]
+pub mod remote;
+
 #[cfg(feature = "template")]
 /// Module for compiling custom non-jit kernels
 pub mod template;
@@ -49,4 +56,17 @@ pub trait JitRuntime: Runtime<Device = Self::JitDevice, Server = Self::JitServer
         AutotuneKey = JitAutotuneKey,
         Kernel = Box<dyn CubeTask>,
     >;
+
+    /// The devices available to the runtime with their capabilities.
+    ///
+    /// Runtimes that can't enumerate their devices only return the default device.
+    fn devices() -> Vec<burn_tensor::backend::DeviceInfo<Self::JitDevice>> {
+        let device = Self::JitDevice::default();
+        let capabilities = burn_tensor::backend::DeviceCapabilities {
+            name: format!("{device:?}"),
+            ..Default::default()
+        };
+
+        vec![burn_tensor::backend::DeviceInfo::new(device, capabilities)]
+    }
 }
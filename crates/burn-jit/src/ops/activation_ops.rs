@@ -1,5 +1,5 @@
-use crate::{FloatElement, IntElement, JitBackend, JitRuntime};
-use burn_tensor::ops::ActivationOps;
+use crate::{kernel, FloatElement, IntElement, JitBackend, JitRuntime};
+use burn_tensor::ops::{ActivationOps, FloatTensor, IntTensor};
 
 impl<R, F, I> ActivationOps<Self> for JitBackend<R, F, I>
 where
@@ -7,4 +7,18 @@ where
     F: FloatElement,
     I: IntElement,
 {
+    fn cross_entropy(
+        logits: FloatTensor<Self, 2>,
+        targets: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, 1> {
+        kernel::loss::cross_entropy(logits, targets)
+    }
+
+    fn cross_entropy_backward(
+        logits: FloatTensor<Self, 2>,
+        targets: IntTensor<Self, 1>,
+        grad: FloatTensor<Self, 1>,
+    ) -> FloatTensor<Self, 2> {
+        kernel::loss::cross_entropy_backward(logits, targets, grad)
+    }
 }
@@ -4,7 +4,7 @@ use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
 use crate::kernel::{self, reduce};
 use crate::{unary, JitBackend};
 use crate::{FloatElement, IntElement, JitRuntime};
-use burn_cube::ir::{BinaryOperator, Elem, Operator, Scope, UnaryOperator, Variable};
+use burn_cube::ir::{BinaryOperator, Elem, FloatKind, Operator, Scope, UnaryOperator, Variable};
 use burn_cube::Runtime;
 use burn_tensor::ops::{BoolTensor, Device, FloatElem, FloatTensor, IntTensor};
 use burn_tensor::{ops::FloatTensorOps, Data, Distribution, Shape};
@@ -184,6 +184,22 @@ where
         kernel::scatter(dim, tensor, indices, value)
     }
 
+    #[cfg(not(target_family = "wasm"))]
+    fn float_scatter_reduce<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+        reduction: burn_tensor::ops::ScatterReduction,
+        deterministic: bool,
+    ) -> FloatTensor<Self, D> {
+        let strategy =
+            kernel::segment::SegmentReduceStrategy::select(value.shape.dims[dim], deterministic);
+        kernel::segment::scatter_reduce::<R, F, I, D>(
+            dim, tensor, indices, value, reduction, strategy,
+        )
+    }
+
     fn float_select<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -528,4 +544,40 @@ where
     ) -> FloatTensor<Self, D> {
         kernel::flip(tensor, axes)
     }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        kernel::sort::sort::<R, F, I, D>(tensor, dim, descending)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::sort::sort_with_indices::<R, F, I, D>(tensor, dim, descending)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        kernel::sort::argsort::<R, F, I, D>(tensor, dim, descending)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_bits<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, 2> {
+        // The compilers can only reinterpret the bits of 32-bit floats.
+        match F::cube_elem() {
+            Elem::Float(FloatKind::F32) => kernel::float_bits::<R, F, I, D>(tensor),
+            _ => burn_tensor::ops::float_bits_on_host::<Self, D>(tensor),
+        }
+    }
 }
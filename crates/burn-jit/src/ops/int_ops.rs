@@ -363,4 +363,31 @@ where
     fn int_flip<const D: usize>(tensor: IntTensor<Self, D>, axes: &[usize]) -> IntTensor<Self, D> {
         kernel::flip(tensor, axes)
     }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        kernel::sort::int_sort::<R, I, D>(tensor, dim, descending)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::sort::int_sort_with_indices::<R, I, D>(tensor, dim, descending)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        kernel::sort::int_argsort::<R, I, D>(tensor, dim, descending)
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{kernel, FloatElement, IntElement, JitBackend, JitRuntime};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices,
-    ModuleOps,
+    conv, Conv2dBackward, ConvOptions, ConvTransposeOptions, GridSampleOptions, InterpolateOptions,
+    MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
 };
 use burn_tensor::ops::{FloatTensor, IntTensor};
 
@@ -17,7 +17,24 @@ where
         bias: Option<FloatTensor<Self, 1>>,
         options: ConvOptions<2>,
     ) -> FloatTensor<Self, 4> {
-        kernel::conv::conv2d(x, weight, bias, options)
+        kernel::conv::conv2d(x, weight, bias, options, Default::default())
+    }
+
+    fn conv2d_backward(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 4>,
+        options: ConvOptions<2>,
+    ) -> Conv2dBackward<Self> {
+        if options.groups == 1 {
+            return conv::conv2d_backward(x, weight, bias, output_grad, options);
+        }
+
+        let (x_grad, weight_grad, bias_grad) =
+            kernel::conv::conv2d_grouped_backward(x, weight, bias, output_grad, options);
+
+        Conv2dBackward::new(x_grad, weight_grad, bias_grad)
     }
 
     fn conv_transpose2d(
@@ -123,4 +140,12 @@ where
     ) -> FloatTensor<Self, 4> {
         kernel::interpolate::interpolate_backward(x, grad, output_size, options)
     }
+
+    fn grid_sample_2d(
+        x: FloatTensor<Self, 4>,
+        grid: FloatTensor<Self, 4>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self, 4> {
+        kernel::grid_sample::grid_sample_2d(x, grid, options)
+    }
 }
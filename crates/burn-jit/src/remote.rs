@@ -0,0 +1,164 @@
+use crate::{JitAutotuneKey, JitBackend, JitRuntime};
+use burn_common::stub::RwLock;
+use burn_compute::{
+    channel::MutexComputeChannel,
+    client::ComputeClient,
+    remote::{RemoteServer, TcpTransport},
+    tune::Tuner,
+    ComputeRuntime,
+};
+use burn_cube::{
+    compute::{CubeTask, RemoteCubeTask},
+    Runtime,
+};
+use burn_tensor::backend::{DeviceId, DeviceOps};
+use std::{
+    io,
+    marker::PhantomData,
+    net::{TcpListener, ToSocketAddrs},
+    sync::{Arc, Mutex},
+};
+
+/// The address of the host used when none is given, overridden by the `BURN_REMOTE_ADDRESS`
+/// environment variable.
+const DEFAULT_ADDRESS: &str = "127.0.0.1:3000";
+
+/// The JIT backend running its kernels on the device of another machine, see [RemoteRuntime].
+pub type Remote<R, F = f32, I = i32> = JitBackend<RemoteRuntime<R>, F, I>;
+
+/// Runtime sending the kernels of the JIT backend to a host [serving](serve) the runtime `R`,
+/// through a [TCP transport](TcpTransport).
+///
+/// The kernels are compiled on the client with the compiler of `R`, then launched by the host
+/// from their source. Subcube operations are disabled, since the features of the device of the
+/// host are unknown to the client.
+#[derive(Debug)]
+pub struct RemoteRuntime<R: Runtime> {
+    _runtime: PhantomData<R>,
+}
+
+/// A host reached by a [remote runtime](RemoteRuntime), identified by its address.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RemoteDevice {
+    /// The address of the host, e.g. `192.168.1.2:3000`.
+    pub address: String,
+}
+
+impl RemoteDevice {
+    /// Create the device of the host listening at the given address.
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+        }
+    }
+}
+
+impl Default for RemoteDevice {
+    fn default() -> Self {
+        let address =
+            std::env::var("BURN_REMOTE_ADDRESS").unwrap_or_else(|_| DEFAULT_ADDRESS.to_string());
+
+        Self::new(address)
+    }
+}
+
+/// The addresses of the hosts, in the order they were first used, giving the index of their id.
+static ADDRESSES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+impl DeviceOps for RemoteDevice {
+    fn id(&self) -> DeviceId {
+        let mut addresses = ADDRESSES.lock().unwrap();
+        let index = match addresses
+            .iter()
+            .position(|address| *address == self.address)
+        {
+            Some(index) => index,
+            None => {
+                addresses.push(self.address.clone());
+                addresses.len() - 1
+            }
+        };
+
+        DeviceId::new(0, index as u32)
+    }
+}
+
+type Server = RemoteServer<Box<dyn CubeTask>, JitAutotuneKey, TcpTransport>;
+type Channel = MutexComputeChannel<Server>;
+
+/// The clients are shared by the remote runtimes of every compiler, a host serving a single
+/// runtime.
+static RUNTIME: ComputeRuntime<RemoteDevice, Server, Channel> = ComputeRuntime::new();
+
+impl<R: Runtime> Runtime for RemoteRuntime<R> {
+    type Compiler = R::Compiler;
+    type Server = Server;
+    type Channel = Channel;
+    type Device = RemoteDevice;
+
+    fn client(device: &Self::Device) -> ComputeClient<Self::Server, Self::Channel> {
+        RUNTIME.client(device, move || {
+            let transport = TcpTransport::connect(&device.address).unwrap_or_else(|err| {
+                panic!("Can't connect to the remote host {}: {err}", device.address)
+            });
+            let server = RemoteServer::new(transport);
+
+            let tuner_device_id = format!("remote-{}-{}", R::name(), device.address);
+            ComputeClient::new(
+                MutexComputeChannel::new(server),
+                Arc::new(RwLock::new(Tuner::new("remote", &tuner_device_id))),
+            )
+        })
+    }
+
+    fn name() -> &'static str {
+        "remote"
+    }
+
+    fn require_array_lengths() -> bool {
+        R::require_array_lengths()
+    }
+}
+
+impl<R: Runtime> JitRuntime for RemoteRuntime<R> {
+    type JitDevice = RemoteDevice;
+    type JitServer = Server;
+}
+
+/// Run the kernels of the clients of [remote runtimes](RemoteRuntime) connecting to the given
+/// address on the device of the runtime `R`, each client being served on its own thread.
+///
+/// # Errors
+///
+/// When the address can't be listened to, or a connection can't be accepted. The errors of a
+/// client only end its own session, and are logged.
+pub fn serve<R, A>(device: R::Device, address: A) -> io::Result<()>
+where
+    R: Runtime,
+    R::Device: Clone + Send + 'static,
+    A: ToSocketAddrs,
+{
+    let listener = TcpListener::bind(address)?;
+
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let device = device.clone();
+
+        std::thread::spawn(move || {
+            let peer = stream.peer_addr();
+            let session = TcpTransport::new(stream).and_then(|transport| {
+                burn_compute::remote::serve(
+                    &R::client(&device),
+                    transport,
+                    |task: RemoteCubeTask| Box::new(task) as Box<dyn CubeTask>,
+                )
+            });
+
+            if let Err(err) = session {
+                log::warn!("The session of the remote client {peer:?} failed: {err}");
+            }
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(atomic)]
+mod tests {
+    use super::*;
+    use burn_cube::{
+        calculate_cube_count_elemwise, cpa,
+        ir::{Elem, IntKind, Item, KernelDefinition, Scope, Variable, Visibility},
+        InputInfo, Kernel, KernelExpansion, KernelIntegrator, KernelSettings, OutputInfo,
+    };
+    use burn_jit::custom::CustomKernel;
+    use burn_tensor::{Data, Int, Tensor};
+
+    const NUM_BINS: usize = 8;
+
+    /// Count the occurrences of each value with atomic additions and find the largest value with
+    /// an atomic max.
+    struct HistogramKernel;
+
+    impl Kernel for HistogramKernel {
+        fn define(&self) -> KernelDefinition {
+            let mut scope = Scope::root();
+            let item = Item::new(Elem::Int(IntKind::I32));
+            let item_atomic = Item::new(Elem::AtomicInt(IntKind::I32));
+
+            let input = Variable::GlobalInputArray(0, item);
+            let histogram = Variable::GlobalOutputArray(0, item_atomic);
+            let max = Variable::GlobalOutputArray(1, item_atomic);
+            let id = Variable::AbsolutePos;
+            let one = Variable::ConstantScalar(1.0, item.elem());
+            let zero = Variable::ConstantScalar(0.0, Elem::UInt);
+
+            let value = scope.create_local(item);
+            let previous = scope.create_local(item);
+            cpa!(scope, value = input[id]);
+            cpa!(scope, previous = atomic_add(histogram, value, one));
+            cpa!(scope, previous = atomic_max(max, zero, value));
+
+            let info = KernelExpansion {
+                inputs: vec![InputInfo::Array {
+                    item,
+                    visibility: Visibility::Read,
+                }],
+                outputs: vec![
+                    OutputInfo::Array { item: item_atomic },
+                    OutputInfo::Array { item: item_atomic },
+                ],
+                scope,
+            };
+
+            KernelIntegrator::new(info).integrate(KernelSettings::default())
+        }
+    }
+
+    #[test]
+    fn atomic_add_and_max_should_count_values() {
+        let device = Default::default();
+        let values = (0..256)
+            .map(|i| ((i * 7 + i / 3) % (NUM_BINS - 1)) as i32)
+            .collect::<Vec<_>>();
+        let input = Tensor::<TestBackend, 1, Int>::from_data(
+            Data::new(values.clone(), [values.len()].into()).convert(),
+            &device,
+        );
+        let histogram = Tensor::<TestBackend, 1, Int>::zeros([NUM_BINS], &device);
+        let max = Tensor::<TestBackend, 1, Int>::zeros([1], &device);
+
+        CustomKernel::<TestRuntime>::new(HistogramKernel)
+            .input(&input)
+            .output(&histogram)
+            .output(&max)
+            .launch(calculate_cube_count_elemwise(values.len(), 16));
+
+        let mut expected = vec![0; NUM_BINS];
+        for value in values.iter() {
+            expected[*value as usize] += 1;
+        }
+
+        histogram
+            .into_data()
+            .assert_eq(&Data::new(expected, [NUM_BINS].into()).convert());
+        max.into_data()
+            .assert_eq(&Data::new(vec![*values.iter().max().unwrap()], [1].into()).convert());
+    }
+}
@@ -1,7 +1,14 @@
 #[burn_tensor_testgen::testgen(conv2d)]
 mod tests {
     use super::*;
-    use burn_tensor::{module, Distribution, Tensor};
+    use burn_jit::kernel::conv::{
+        conv2d, conv2d_grouped_backward, Conv2dStrategy, ImplicitGemmConfig, WinogradTile,
+    };
+    use burn_tensor::{
+        module,
+        ops::{ConvOptions, ModuleOps},
+        Distribution, Tensor,
+    };
 
     #[test]
     fn conv2d_should_work_with_multiple_invocations() {
@@ -26,4 +33,185 @@ mod tests {
             .into_data()
             .assert_approx_eq(&output_ref.into_data(), 3);
     }
+
+    #[test]
+    fn conv2d_implicit_gemm_should_match_reference() {
+        test_strategy(
+            [2, 6, 17, 13],
+            [8, 3, 3, 3],
+            ConvOptions::new([2, 1], [1, 2], [1, 2], 2),
+            Conv2dStrategy::ImplicitGemm(ImplicitGemmConfig::default()),
+            3,
+        );
+    }
+
+    #[test]
+    fn conv2d_implicit_gemm_should_support_uneven_blocks() {
+        test_strategy(
+            [3, 5, 9, 11],
+            [7, 5, 2, 3],
+            ConvOptions::new([1, 1], [0, 1], [1, 1], 1),
+            Conv2dStrategy::ImplicitGemm(ImplicitGemmConfig {
+                block_m: 32,
+                block_k: 16,
+                block_n: 8,
+            }),
+            3,
+        );
+    }
+
+    #[test]
+    fn conv2d_winograd_f2x2_should_match_reference() {
+        test_strategy(
+            [2, 5, 9, 12],
+            [6, 5, 3, 3],
+            ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+            Conv2dStrategy::Winograd(WinogradTile::F2x2),
+            3,
+        );
+    }
+
+    #[test]
+    fn conv2d_winograd_f4x4_should_match_reference() {
+        test_strategy(
+            [2, 5, 11, 10],
+            [6, 5, 3, 3],
+            ConvOptions::new([1, 1], [2, 0], [1, 1], 1),
+            Conv2dStrategy::Winograd(WinogradTile::F4x4),
+            2,
+        );
+    }
+
+    #[test]
+    fn conv2d_depthwise_should_match_reference() {
+        test_strategy(
+            [2, 4, 10, 9],
+            [8, 1, 3, 3],
+            ConvOptions::new([1, 2], [1, 1], [2, 1], 4),
+            Conv2dStrategy::Depthwise,
+            3,
+        );
+    }
+
+    #[test]
+    fn conv2d_depthwise_backward_should_match_reference() {
+        test_grouped_backward(
+            [2, 4, 10, 9],
+            [8, 1, 3, 3],
+            ConvOptions::new([1, 2], [1, 1], [2, 1], 4),
+        );
+    }
+
+    #[test]
+    fn conv2d_grouped_backward_should_match_reference() {
+        test_grouped_backward(
+            [3, 6, 11, 8],
+            [4, 3, 3, 2],
+            ConvOptions::new([2, 1], [1, 0], [1, 2], 2),
+        );
+    }
+
+    fn test_strategy(
+        input_shape: [usize; 4],
+        weight_shape: [usize; 4],
+        options: ConvOptions<2>,
+        strategy: Conv2dStrategy,
+        precision: usize,
+    ) {
+        let test_device = Default::default();
+        let input =
+            Tensor::<TestBackend, 4>::random(input_shape, Distribution::Default, &test_device);
+        let weight =
+            Tensor::<TestBackend, 4>::random(weight_shape, Distribution::Default, &test_device);
+        let bias = Tensor::<TestBackend, 1>::random(
+            [weight_shape[0]],
+            Distribution::Default,
+            &test_device,
+        );
+        let ref_device = Default::default();
+
+        let input_ref = Tensor::<ReferenceBackend, 4>::from_data(input.to_data(), &ref_device);
+        let weight_ref = Tensor::<ReferenceBackend, 4>::from_data(weight.to_data(), &ref_device);
+        let bias_ref = Tensor::<ReferenceBackend, 1>::from_data(bias.to_data(), &ref_device);
+
+        let output = Tensor::<TestBackend, 4>::from_primitive(conv2d(
+            input.into_primitive(),
+            weight.into_primitive(),
+            Some(bias.into_primitive()),
+            options.clone(),
+            strategy,
+        ));
+        let output_ref = module::conv2d(input_ref, weight_ref, Some(bias_ref), options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), precision);
+    }
+
+    fn test_grouped_backward(
+        input_shape: [usize; 4],
+        weight_shape: [usize; 4],
+        options: ConvOptions<2>,
+    ) {
+        let test_device = Default::default();
+        let input =
+            Tensor::<TestBackend, 4>::random(input_shape, Distribution::Default, &test_device);
+        let weight =
+            Tensor::<TestBackend, 4>::random(weight_shape, Distribution::Default, &test_device);
+        let bias = Tensor::<TestBackend, 1>::random(
+            [weight_shape[0]],
+            Distribution::Default,
+            &test_device,
+        );
+        let output = module::conv2d(
+            input.clone(),
+            weight.clone(),
+            Some(bias.clone()),
+            options.clone(),
+        );
+        let output_grad =
+            Tensor::<TestBackend, 4>::random(output.shape(), Distribution::Default, &test_device);
+        let ref_device = Default::default();
+
+        let input_ref = Tensor::<ReferenceBackend, 4>::from_data(input.to_data(), &ref_device);
+        let weight_ref = Tensor::<ReferenceBackend, 4>::from_data(weight.to_data(), &ref_device);
+        let bias_ref = Tensor::<ReferenceBackend, 1>::from_data(bias.to_data(), &ref_device);
+        let output_grad_ref =
+            Tensor::<ReferenceBackend, 4>::from_data(output_grad.to_data(), &ref_device);
+
+        let (x_grad, weight_grad, bias_grad) = conv2d_grouped_backward(
+            input.into_primitive(),
+            weight.into_primitive(),
+            Some(bias.into_primitive()),
+            output_grad.into_primitive(),
+            options.clone(),
+        );
+        let grads_ref = ReferenceBackend::conv2d_backward(
+            input_ref.into_primitive(),
+            weight_ref.into_primitive(),
+            Some(bias_ref.into_primitive()),
+            output_grad_ref.into_primitive(),
+            options,
+        );
+
+        Tensor::<TestBackend, 4>::from_primitive(x_grad)
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<ReferenceBackend, 4>::from_primitive(grads_ref.x_grad).into_data(),
+                3,
+            );
+        Tensor::<TestBackend, 4>::from_primitive(weight_grad)
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<ReferenceBackend, 4>::from_primitive(grads_ref.weights_grad).into_data(),
+                2,
+            );
+        Tensor::<TestBackend, 1>::from_primitive(bias_grad.unwrap())
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<ReferenceBackend, 1>::from_primitive(grads_ref.bias_grad.unwrap())
+                    .into_data(),
+                2,
+            );
+    }
 }
@@ -0,0 +1,80 @@
+#[burn_tensor_testgen::testgen(cross_entropy)]
+mod tests {
+    use super::*;
+    use burn_tensor::{loss, ops::ActivationOps, Data, Distribution, Int, Shape, Tensor};
+
+    #[test]
+    fn cross_entropy_should_match_reference_with_many_classes() {
+        let (logits, targets, logits_ref, targets_ref) = inputs([7, 1000]);
+
+        let loss = loss::cross_entropy(logits, targets);
+        let loss_ref = loss::cross_entropy(logits_ref, targets_ref);
+
+        loss.into_data().assert_approx_eq(&loss_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn cross_entropy_should_match_reference_with_fewer_classes_than_units() {
+        let (logits, targets, logits_ref, targets_ref) = inputs([3, 5]);
+
+        let loss = loss::cross_entropy(logits, targets);
+        let loss_ref = loss::cross_entropy(logits_ref, targets_ref);
+
+        loss.into_data().assert_approx_eq(&loss_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn cross_entropy_backward_should_match_reference() {
+        let (logits, targets, logits_ref, targets_ref) = inputs([7, 1000]);
+        let test_device = Default::default();
+        let grad = Tensor::<TestBackend, 1>::random([7], Distribution::Default, &test_device);
+        let grad_ref =
+            Tensor::<ReferenceBackend, 1>::from_data(grad.to_data(), &Default::default());
+
+        let logits_grad =
+            Tensor::<TestBackend, 2>::from_primitive(TestBackend::cross_entropy_backward(
+                logits.into_primitive(),
+                targets.into_primitive(),
+                grad.into_primitive(),
+            ));
+        let logits_grad_ref = Tensor::<ReferenceBackend, 2>::from_primitive(
+            ReferenceBackend::cross_entropy_backward(
+                logits_ref.into_primitive(),
+                targets_ref.into_primitive(),
+                grad_ref.into_primitive(),
+            ),
+        );
+
+        logits_grad
+            .into_data()
+            .assert_approx_eq(&logits_grad_ref.into_data(), 3);
+    }
+
+    fn inputs(
+        shape: [usize; 2],
+    ) -> (
+        Tensor<TestBackend, 2>,
+        Tensor<TestBackend, 1, Int>,
+        Tensor<ReferenceBackend, 2>,
+        Tensor<ReferenceBackend, 1, Int>,
+    ) {
+        let [batch_size, num_classes] = shape;
+        let test_device = Default::default();
+        let logits =
+            Tensor::<TestBackend, 2>::random(shape, Distribution::Uniform(-50., 50.), &test_device);
+        let targets = (0..batch_size)
+            .map(|i| (i * 137 % num_classes) as i32)
+            .collect();
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(
+            Data::new(targets, Shape::new([batch_size])).convert(),
+            &test_device,
+        );
+        let ref_device = Default::default();
+
+        let logits_ref = Tensor::<ReferenceBackend, 2>::from_data(logits.to_data(), &ref_device);
+        let targets_ref =
+            Tensor::<ReferenceBackend, 1, Int>::from_data(targets.to_data(), &ref_device);
+
+        (logits, targets, logits_ref, targets_ref)
+    }
+}
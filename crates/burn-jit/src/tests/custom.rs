@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(custom_kernel)]
+mod tests {
+    use super::*;
+    use burn_cube::{
+        calculate_cube_count_elemwise, cpa,
+        ir::{Elem, FloatKind, Item, KernelDefinition, Scope, Variable, Visibility},
+        InputInfo, Kernel, KernelExpansion, KernelIntegrator, KernelSettings, OutputInfo,
+    };
+    use burn_jit::custom::CustomKernel;
+    use burn_tensor::{Distribution, Int, Tensor};
+
+    /// Multiply the input by a float scalar.
+    struct ScaleKernel;
+
+    impl Kernel for ScaleKernel {
+        fn define(&self) -> KernelDefinition {
+            let mut scope = Scope::root();
+            let item = Item::new(Elem::Float(FloatKind::F32));
+
+            let input = Variable::GlobalInputArray(0, item);
+            let scalar = Variable::GlobalScalar(0, item.elem());
+            let output = Variable::GlobalOutputArray(0, item);
+            let id = Variable::AbsolutePos;
+
+            let value = scope.create_local(item);
+            cpa!(scope, value = input[id]);
+            cpa!(scope, value = value * scalar);
+            cpa!(scope, output[id] = value);
+
+            let info = KernelExpansion {
+                inputs: vec![
+                    InputInfo::Array {
+                        item,
+                        visibility: Visibility::Read,
+                    },
+                    InputInfo::Scalar {
+                        elem: item.elem(),
+                        size: 1,
+                    },
+                ],
+                outputs: vec![OutputInfo::Array { item }],
+                scope,
+            };
+
+            KernelIntegrator::new(info).integrate(KernelSettings::default())
+        }
+    }
+
+    #[test]
+    fn custom_kernel_should_write_output_tensor() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 2>::random([16, 16], Distribution::Default, &device);
+        let output = Tensor::<TestBackend, 2>::empty([16, 16], &device);
+
+        CustomKernel::<TestRuntime>::new(ScaleKernel)
+            .input(&input)
+            .output(&output)
+            .scalars(&[3.0f32])
+            .launch(calculate_cube_count_elemwise(256, 16));
+
+        output
+            .into_data()
+            .assert_approx_eq(&(input * 3.0).into_data(), 3);
+    }
+
+    #[test]
+    fn custom_kernel_should_launch_with_cube_count_on_device() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 2>::random([16, 16], Distribution::Default, &device);
+        let output = Tensor::<TestBackend, 2>::empty([16, 16], &device);
+        let cube_count = Tensor::<TestBackend, 1, Int>::from_ints([1, 1, 1], &device);
+
+        CustomKernel::<TestRuntime>::new(ScaleKernel)
+            .input(&input)
+            .output(&output)
+            .scalars(&[3.0f32])
+            .launch_indirect(&cube_count);
+
+        output
+            .into_data()
+            .assert_approx_eq(&(input * 3.0).into_data(), 3);
+    }
+}
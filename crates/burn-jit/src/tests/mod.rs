@@ -1,5 +1,6 @@
 #![allow(missing_docs)]
 
+mod atomic;
 mod avg_pool2d;
 mod bernoulli;
 mod cast;
@@ -7,6 +8,8 @@ mod cat;
 mod clamp;
 mod conv2d;
 mod conv_transpose2d;
+mod cross_entropy;
+mod custom;
 mod gather;
 mod mask_fill;
 mod mask_where;
@@ -17,12 +20,15 @@ mod normal;
 mod reduce;
 mod repeat;
 mod scatter;
+mod scatter_reduce;
 mod select;
 mod select_assign;
 mod slice;
 mod slice_assign;
+mod sort;
 mod unary;
 mod uniform;
+mod vectorization;
 
 // Re-export dependencies for tests
 pub use burn_autodiff;
@@ -43,10 +49,12 @@ macro_rules! testgen_all {
                 burn_jit::testgen_reduction!();
                 burn_jit::testgen_conv2d!();
                 burn_jit::testgen_conv_transpose2d!();
+                burn_jit::testgen_cross_entropy!();
 
                 burn_jit::testgen_repeat!();
                 burn_jit::testgen_gather!();
                 burn_jit::testgen_scatter!();
+                burn_jit::testgen_scatter_reduce!();
 
                 burn_jit::testgen_select!();
                 burn_jit::testgen_select_assign!();
@@ -54,6 +62,8 @@ macro_rules! testgen_all {
                 burn_jit::testgen_slice!();
                 burn_jit::testgen_slice_assign!();
 
+                burn_jit::testgen_sort!();
+
                 burn_jit::testgen_mask_where!();
                 burn_jit::testgen_mask_fill!();
 
@@ -69,6 +79,9 @@ macro_rules! testgen_all {
                 burn_jit::testgen_cat!();
                 burn_jit::testgen_clamp!();
                 burn_jit::testgen_unary!();
+                burn_jit::testgen_vectorization!();
+                burn_jit::testgen_custom_kernel!();
+                burn_jit::testgen_atomic!();
                 burn_jit::testgen_matmul!();
             }
         }
@@ -96,6 +109,7 @@ macro_rules! testgen_jit {
         pub type ReferenceTensor<const D: usize> = burn_tensor::Tensor<ReferenceBackend, D>;
 
         burn_tensor::testgen_all!();
+        burn_tensor::testgen_consistency!();
         burn_autodiff::testgen_all!();
     };
 }
@@ -118,6 +132,7 @@ macro_rules! testgen_jit_fusion {
         pub type ReferenceTensor<const D: usize> = burn_tensor::Tensor<ReferenceBackend, D>;
 
         burn_tensor::testgen_all!();
+        burn_tensor::testgen_consistency!();
         burn_autodiff::testgen_all!();
     };
 }
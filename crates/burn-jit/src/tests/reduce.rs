@@ -184,6 +184,29 @@ mod reduction {
         val_ref.into_data().assert_approx_eq(&val.into_data(), 2);
     }
 
+    #[test]
+    #[cfg(feature = "autotune")]
+    fn reduction_sum_dim_autotune_should_match_reference() {
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [4, 1024, 50],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+        let reduce_dim = 1;
+
+        // Every cube dimension candidate of both algorithms is run while autotuning.
+        let val = Tensor::<TestBackend, 3>::from_primitive(sum_dim::<TestRuntime, f32, f32, 3>(
+            tensor.into_primitive(),
+            reduce_dim,
+            ReduceStrategy::Autotune,
+        ));
+        let val_ref = tensor_ref.sum_dim(reduce_dim);
+
+        val_ref.into_data().assert_approx_eq(&val.into_data(), 2);
+    }
+
     #[test]
     fn reduction_mean_dim_shared_memory_medium() {
         let tensor =
@@ -332,4 +355,45 @@ mod reduction {
 
         assert_eq!(1, val_shared.into_data().value[0]);
     }
+
+    #[test]
+    fn reduction_argmax_should_return_first_occurrence_on_ties() {
+        for strategy in [ReduceStrategy::Naive, ReduceStrategy::SharedMemory] {
+            let val =
+                Tensor::<TestBackend, 2, Int>::from_primitive(argmax::<TestRuntime, f32, i32, 2>(
+                    tied_tensor().into_primitive(),
+                    0,
+                    strategy,
+                ));
+
+            assert_eq!(val.into_data().value, vec![6, 5]);
+        }
+    }
+
+    #[test]
+    fn reduction_argmin_should_return_first_occurrence_on_ties() {
+        for strategy in [ReduceStrategy::Naive, ReduceStrategy::SharedMemory] {
+            let val =
+                Tensor::<TestBackend, 2, Int>::from_primitive(argmin::<TestRuntime, f32, i32, 2>(
+                    tied_tensor().into_primitive(),
+                    0,
+                    strategy,
+                ));
+
+            assert_eq!(val.into_data().value, vec![0, 6]);
+        }
+    }
+
+    /// Tensor of shape `[1000, 2]` where the values of each column repeat every 7 rows, so the
+    /// reduction along the non-contiguous dimension has many equal candidates.
+    fn tied_tensor() -> Tensor<TestBackend, 2> {
+        let values = (0..1000)
+            .flat_map(|i| [(i % 7) as f32, ((i + 1) % 7) as f32])
+            .collect();
+
+        Tensor::from_data(
+            Data::new(values, Shape::new([1000, 2])),
+            &Default::default(),
+        )
+    }
 }
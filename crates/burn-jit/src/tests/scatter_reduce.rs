@@ -0,0 +1,123 @@
+#[burn_tensor_testgen::testgen(scatter_reduce)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::segment::{scatter_reduce, SegmentReduceStrategy};
+    use burn_tensor::{backend::Backend, ops::ScatterReduction, Distribution, Int, Tensor};
+
+    #[test]
+    fn scatter_reduce_serial_should_match_reference() {
+        for reduction in reductions() {
+            scatter_reduce_same_as_ref(reduction, SegmentReduceStrategy::Serial);
+        }
+    }
+
+    #[test]
+    fn scatter_reduce_sorted_should_match_reference() {
+        for reduction in reductions() {
+            scatter_reduce_same_as_ref(reduction, SegmentReduceStrategy::Sorted);
+        }
+    }
+
+    #[test]
+    fn scatter_reduce_atomic_should_match_reference() {
+        for reduction in reductions() {
+            scatter_reduce_same_as_ref(reduction, SegmentReduceStrategy::Atomic);
+        }
+    }
+
+    #[test]
+    fn scatter_reduce_strategy_should_be_deterministic_when_requested() {
+        assert_eq!(
+            SegmentReduceStrategy::select(1000, true),
+            SegmentReduceStrategy::Sorted
+        );
+        assert_eq!(
+            SegmentReduceStrategy::select(1000, false),
+            SegmentReduceStrategy::Atomic
+        );
+        assert_eq!(
+            SegmentReduceStrategy::select(8, false),
+            SegmentReduceStrategy::Serial
+        );
+    }
+
+    #[test]
+    fn scatter_reduce_strategies_should_give_identical_sums() {
+        let (tensor, indices, value) = inputs();
+
+        let serial = scatter_reduce(
+            1,
+            tensor.clone().into_primitive(),
+            indices.clone().into_primitive(),
+            value.clone().into_primitive(),
+            ScatterReduction::Sum,
+            SegmentReduceStrategy::Serial,
+        );
+        let sorted = scatter_reduce(
+            1,
+            tensor.into_primitive(),
+            indices.into_primitive(),
+            value.into_primitive(),
+            ScatterReduction::Sum,
+            SegmentReduceStrategy::Sorted,
+        );
+
+        Tensor::<TestBackend, 3>::from_primitive(serial)
+            .into_data()
+            .assert_eq(&Tensor::<TestBackend, 3>::from_primitive(sorted).into_data());
+    }
+
+    fn scatter_reduce_same_as_ref(reduction: ScatterReduction, strategy: SegmentReduceStrategy) {
+        let (tensor, indices, value) = inputs();
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+        let indices_ref = Tensor::<ReferenceBackend, 3, Int>::from_data(
+            indices.to_data().convert(),
+            &Default::default(),
+        );
+        let value_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(value.to_data(), &Default::default());
+
+        let actual = Tensor::<TestBackend, 3>::from_primitive(scatter_reduce(
+            1,
+            tensor.into_primitive(),
+            indices.into_primitive(),
+            value.into_primitive(),
+            reduction,
+            strategy,
+        ));
+        let expected = tensor_ref.scatter_reduce(1, indices_ref, value_ref, reduction);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    fn inputs() -> (
+        Tensor<TestBackend, 3>,
+        Tensor<TestBackend, 3, Int>,
+        Tensor<TestBackend, 3>,
+    ) {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 3>::random([4, 20, 3], Distribution::Default, &device);
+        let value = Tensor::<TestBackend, 3>::random([4, 150, 3], Distribution::Default, &device);
+        let indices = Tensor::<TestBackend, 3, Int>::from_data(
+            Tensor::<TestBackend, 3>::random([4, 150, 3], Distribution::Uniform(0., 20.), &device)
+                .into_data()
+                .convert(),
+            &device,
+        );
+
+        (tensor, indices, value)
+    }
+
+    fn reductions() -> [ScatterReduction; 4] {
+        [
+            ScatterReduction::Sum,
+            ScatterReduction::Mean,
+            ScatterReduction::Max,
+            ScatterReduction::Min,
+        ]
+    }
+}
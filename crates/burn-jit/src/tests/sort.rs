@@ -0,0 +1,92 @@
+#[burn_tensor_testgen::testgen(sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Distribution, Int, Shape, Tensor};
+
+    #[test]
+    fn sort_should_match_reference_along_last_dim() {
+        let tensor =
+            Tensor::<TestBackend, 2>::random([4, 300], Distribution::Default, &Default::default());
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &Default::default());
+
+        let (values, indices) = tensor.sort_with_indices(1);
+        let (values_ref, indices_ref) = tensor_ref.sort_with_indices(1);
+
+        values
+            .into_data()
+            .assert_approx_eq(&values_ref.into_data(), 3);
+        indices.into_data().assert_eq(&indices_ref.into_data());
+    }
+
+    #[test]
+    fn sort_should_match_reference_along_non_contiguous_dim() {
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [3, 37, 5],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+
+        let (values, indices) = tensor.sort_descending_with_indices(1);
+        let (values_ref, indices_ref) = tensor_ref.sort_descending_with_indices(1);
+
+        values
+            .into_data()
+            .assert_approx_eq(&values_ref.into_data(), 3);
+        indices.into_data().assert_eq(&indices_ref.into_data());
+    }
+
+    #[test]
+    fn sort_should_work_with_transposed_input() {
+        let tensor =
+            Tensor::<TestBackend, 2>::random([20, 6], Distribution::Default, &Default::default());
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &Default::default());
+
+        let values = tensor.transpose().sort(1);
+        let values_ref = tensor_ref.transpose().sort(1);
+
+        values
+            .into_data()
+            .assert_approx_eq(&values_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn argsort_should_be_stable() {
+        let tensor = Tensor::<TestBackend, 2>::from_data(
+            Data::new(
+                vec![2., 1., 2., 0., 1., 2., 1., 1., 0., 2., 0., 1.],
+                Shape::new([2, 6]),
+            ),
+            &Default::default(),
+        );
+
+        let indices = tensor.clone().argsort(1);
+        let indices_descending = tensor.argsort_descending(1);
+
+        indices
+            .into_data()
+            .assert_eq(&Data::from([[3, 1, 4, 0, 2, 5], [2, 4, 0, 1, 5, 3]]));
+        indices_descending
+            .into_data()
+            .assert_eq(&Data::from([[0, 2, 5, 1, 4, 3], [3, 0, 1, 5, 2, 4]]));
+    }
+
+    #[test]
+    fn argsort_should_match_reference_with_many_ties() {
+        let values = (0..2 * 513).map(|i| ((i * 37) % 11) as f32).collect();
+        let tensor = Tensor::<TestBackend, 2>::from_data(
+            Data::new(values, Shape::new([513, 2])),
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &Default::default());
+
+        let indices: Tensor<TestBackend, 2, Int> = tensor.argsort(0);
+        let indices_ref = tensor_ref.argsort(0);
+
+        indices.into_data().assert_eq(&indices_ref.into_data());
+    }
+}
@@ -0,0 +1,86 @@
+#[burn_tensor_testgen::testgen(vectorization)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, Tensor};
+
+    fn unary_should_match_reference(shape: [usize; 3]) {
+        let input =
+            Tensor::<TestBackend, 3>::random(shape, Distribution::Default, &Default::default());
+        let input_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(input.to_data(), &Default::default());
+
+        let output = input.exp();
+
+        output
+            .into_data()
+            .assert_approx_eq(&input_ref.exp().into_data(), 3);
+    }
+
+    fn binary_should_match_reference(shape_lhs: [usize; 3], shape_rhs: [usize; 3]) {
+        let lhs =
+            Tensor::<TestBackend, 3>::random(shape_lhs, Distribution::Default, &Default::default());
+        let rhs =
+            Tensor::<TestBackend, 3>::random(shape_rhs, Distribution::Default, &Default::default());
+        let lhs_ref = Tensor::<ReferenceBackend, 3>::from_data(lhs.to_data(), &Default::default());
+        let rhs_ref = Tensor::<ReferenceBackend, 3>::from_data(rhs.to_data(), &Default::default());
+
+        let output = lhs.clone() * rhs.clone();
+        let output_cmp = lhs.lower(rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&(lhs_ref.clone() * rhs_ref.clone()).into_data(), 3);
+        output_cmp
+            .into_data()
+            .assert_eq(&lhs_ref.lower(rhs_ref).into_data());
+    }
+
+    #[test]
+    fn unary_vec4_should_match_reference() {
+        unary_should_match_reference([2, 7, 32]);
+    }
+
+    #[test]
+    fn unary_vec2_should_match_reference() {
+        unary_should_match_reference([2, 7, 6]);
+    }
+
+    #[test]
+    fn unary_scalar_should_match_reference() {
+        unary_should_match_reference([2, 7, 5]);
+    }
+
+    #[test]
+    fn unary_transposed_should_match_reference() {
+        let input =
+            Tensor::<TestBackend, 3>::random([2, 8, 4], Distribution::Default, &Default::default());
+        let input_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(input.to_data(), &Default::default());
+
+        let output = input.swap_dims(1, 2).exp();
+
+        output
+            .into_data()
+            .assert_approx_eq(&input_ref.swap_dims(1, 2).exp().into_data(), 3);
+    }
+
+    #[test]
+    fn binary_vec4_should_match_reference() {
+        binary_should_match_reference([2, 7, 32], [2, 7, 32]);
+    }
+
+    #[test]
+    fn binary_vec4_broadcast_should_match_reference() {
+        binary_should_match_reference([2, 7, 32], [1, 7, 32]);
+    }
+
+    #[test]
+    fn binary_vec2_should_match_reference() {
+        binary_should_match_reference([2, 7, 6], [2, 1, 6]);
+    }
+
+    #[test]
+    fn binary_broadcast_last_dim_should_match_reference() {
+        binary_should_match_reference([2, 7, 8], [2, 7, 1]);
+    }
+}
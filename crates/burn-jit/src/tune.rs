@@ -14,3 +14,11 @@ pub fn anchor(x: usize, max: Option<usize>) -> usize {
         power_of_2
     }
 }
+
+/// The cube dimensions, as the number of units along x and y, tried when autotuning the kernels
+/// whose results don't depend on their cube dimensions.
+///
+/// Integrated GPUs often perform better with small cubes, while discrete GPUs need larger cubes
+/// to hide the memory latency. The cubes are square so that they can be used by the elementwise
+/// kernels, and they have at most 256 units, the minimum limit guaranteed by WebGPU.
+pub(crate) const CUBE_DIM_CANDIDATES: [u32; 2] = [8, 16];
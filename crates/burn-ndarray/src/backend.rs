@@ -1,10 +1,12 @@
 use crate::NdArrayTensor;
 use crate::{element::FloatNdArrayElement, PrecisionBridge};
 use alloc::string::String;
+use alloc::{vec, vec::Vec};
+use burn_common::rand::get_seeded_rng;
 use burn_common::stub::Mutex;
-use burn_tensor::backend::{Backend, DeviceId, DeviceOps};
+use burn_tensor::backend::{Backend, DeviceCapabilities, DeviceId, DeviceInfo, DeviceOps};
 use core::marker::PhantomData;
-use rand::{rngs::StdRng, SeedableRng};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
 
@@ -63,4 +65,25 @@ impl<E: FloatNdArrayElement> Backend for NdArray<E> {
         let mut seed = SEED.lock().unwrap();
         *seed = Some(rng);
     }
+
+    fn fork_seed() -> Option<u64> {
+        let mut rng = SEED.lock().unwrap();
+        let seed = match rng.as_mut() {
+            Some(rng) => rng.gen(),
+            None => get_seeded_rng().gen(),
+        };
+        *rng = Some(StdRng::seed_from_u64(seed));
+
+        Some(seed)
+    }
+
+    fn devices() -> Vec<DeviceInfo<NdArrayDevice>> {
+        let capabilities = DeviceCapabilities {
+            name: String::from("CPU"),
+            unified_memory: true,
+            ..Default::default()
+        };
+
+        vec![DeviceInfo::new(NdArrayDevice::Cpu, capabilities)]
+    }
 }
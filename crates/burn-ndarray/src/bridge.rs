@@ -32,4 +32,12 @@ where
 
         NdArrayTensor::new(array)
     }
+
+    fn target_device(device: &NdArrayDevice) -> NdArrayDevice {
+        device.clone()
+    }
+
+    fn origin_device(device: &NdArrayDevice) -> NdArrayDevice {
+        device.clone()
+    }
 }
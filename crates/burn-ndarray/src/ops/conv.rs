@@ -90,7 +90,7 @@ pub(crate) fn conv2d<E: FloatNdArrayElement>(
                 |(k, mut output)| {
                     let b = k / out_channels;
                     let oc = k % out_channels;
-                    let g = k % options.groups;
+                    let g = oc / (out_channels / options.groups);
 
                     for ic in (in_channels * g)..(in_channels * (g + 1)) {
                         let weight_ic = ic - (g * in_channels);
@@ -203,7 +203,7 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
         iter_range_par!(0, batch_size * out_channels * options.groups).for_each(|k| unsafe {
             let b = k / (out_channels * options.groups);
             let oc = k % out_channels;
-            let g = k % options.groups;
+            let g = k / out_channels % options.groups;
 
             let output = unsafe_shared_out.get();
 
@@ -0,0 +1,278 @@
+//! The decompositions of matrices, which compute each matrix of the batch in double precision, in
+//! parallel when possible.
+
+use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
+use crate::{iter_range_par, run_par};
+use alloc::vec::Vec;
+use burn_tensor::{ops::FloatTensorOps, ElementConversion, Shape};
+use ndarray::{s, Array2, Axis, IxDyn};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The maximum number of sweeps of the Jacobi methods, which usually converge in less than 10.
+const MAX_SWEEPS: usize = 100;
+
+pub(crate) fn qr<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let shape = tensor.shape();
+    let (rows, cols) = (shape.dims[D - 2], shape.dims[D - 1]);
+    let size = rows.min(cols);
+    let (q, r) = map_matrices(tensor, qr_matrix).into_iter().unzip();
+
+    (
+        from_matrices(&shape, rows, size, q),
+        from_matrices(&shape, size, cols, r),
+    )
+}
+
+pub(crate) fn svd<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (
+    NdArrayTensor<E, D>,
+    NdArrayTensor<E, D>,
+    NdArrayTensor<E, D>,
+) {
+    let shape = tensor.shape();
+    let (rows, cols) = (shape.dims[D - 2], shape.dims[D - 1]);
+    let size = rows.min(cols);
+    let mut u = Vec::new();
+    let mut s = Vec::new();
+    let mut vt = Vec::new();
+
+    for (left, values, right) in map_matrices(tensor, svd_matrix) {
+        u.push(left);
+        s.push(Array2::from_shape_vec((1, size), values).unwrap());
+        vt.push(right);
+    }
+
+    (
+        from_matrices(&shape, rows, size, u),
+        from_matrices(&shape, 1, size, s),
+        from_matrices(&shape, size, cols, vt),
+    )
+}
+
+pub(crate) fn eigh<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let shape = tensor.shape();
+    let size = shape.dims[D - 1];
+    let (values, vectors) = map_matrices(tensor, |matrix| {
+        let (values, vectors) = eigh_matrix(matrix);
+        (Array2::from_shape_vec((1, size), values).unwrap(), vectors)
+    })
+    .into_iter()
+    .unzip();
+
+    (
+        from_matrices(&shape, 1, size, values),
+        from_matrices(&shape, size, size, vectors),
+    )
+}
+
+/// Applies the function to each matrix of the last two dimensions of the tensor.
+fn map_matrices<E, const D: usize, O, F>(tensor: NdArrayTensor<E, D>, func: F) -> Vec<O>
+where
+    E: FloatNdArrayElement,
+    O: Send,
+    F: Fn(Array2<f64>) -> O + Send + Sync,
+{
+    let shape = tensor.shape();
+    let (rows, cols) = (shape.dims[D - 2], shape.dims[D - 1]);
+    let num_matrices = shape.dims[..D - 2].iter().product::<usize>();
+    let array = NdArray::<E>::float_reshape(tensor, Shape::new([num_matrices, rows, cols])).array;
+
+    run_par!(|| {
+        iter_range_par!(0, num_matrices)
+            .map(|i| func(array.slice(s![i, .., ..]).mapv(|value| value.elem::<f64>())))
+            .collect()
+    })
+}
+
+/// Creates a tensor from the matrices of each element of the batch of the given shape, which all
+/// have the given size.
+fn from_matrices<E: FloatNdArrayElement, const D: usize>(
+    shape: &Shape<D>,
+    rows: usize,
+    cols: usize,
+    matrices: Vec<Array2<f64>>,
+) -> NdArrayTensor<E, D> {
+    let mut dims = shape.dims;
+    dims[D - 2] = rows;
+    dims[D - 1] = cols;
+    let values = matrices
+        .iter()
+        .flat_map(|matrix| matrix.iter().map(|value| value.elem::<E>()))
+        .collect();
+    let array = ndarray::Array::from_shape_vec(IxDyn(&dims), values).unwrap();
+
+    NdArrayTensor::new(array.into_shared())
+}
+
+/// The reduced QR decomposition with Householder reflections, the diagonal of `R` being
+/// non-negative.
+fn qr_matrix(mut r: Array2<f64>) -> (Array2<f64>, Array2<f64>) {
+    let (rows, cols) = r.dim();
+    let size = rows.min(cols);
+    let mut q = Array2::eye(rows);
+
+    for j in 0..size {
+        let column = r.slice(s![j.., j]);
+        let norm = column.dot(&column).sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+
+        // The reflection of the column onto `alpha e_j`, with the sign avoiding cancellations.
+        let alpha = if r[[j, j]] > 0.0 { -norm } else { norm };
+        let mut v = column.to_owned();
+        v[0] -= alpha;
+        let scale = 2.0 / v.dot(&v);
+        let v_column = v.view().insert_axis(Axis(1));
+        let v_row = v.view().insert_axis(Axis(0));
+
+        let dots = v_row.dot(&r.slice(s![j.., j..]));
+        r.slice_mut(s![j.., j..])
+            .scaled_add(-scale, &v_column.dot(&dots));
+
+        let dots = q.slice(s![.., j..]).dot(&v_column);
+        q.slice_mut(s![.., j..])
+            .scaled_add(-scale, &dots.dot(&v_row));
+    }
+
+    let mut q = q.slice(s![.., ..size]).to_owned();
+    let mut r = r.slice(s![..size, ..]).to_owned();
+
+    for i in 0..size {
+        r.slice_mut(s![i, ..i]).fill(0.0);
+        if r[[i, i]] < 0.0 {
+            r.row_mut(i).mapv_inplace(|value| -value);
+            q.column_mut(i).mapv_inplace(|value| -value);
+        }
+    }
+
+    (q, r)
+}
+
+/// The eigenvalues in ascending order and the eigenvectors as columns of a symmetric matrix, with
+/// the cyclic Jacobi method.
+fn eigh_matrix(mut a: Array2<f64>) -> (Vec<f64>, Array2<f64>) {
+    let size = a.nrows();
+    let mut vectors = Array2::eye(size);
+    let norm: f64 = a.iter().map(|x| x * x).sum();
+
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal: f64 = (0..size)
+            .flat_map(|i| (i + 1..size).map(move |j| (i, j)))
+            .map(|(i, j)| a[[i, j]] * a[[i, j]])
+            .sum();
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * norm {
+            break;
+        }
+
+        for p in 0..size {
+            for q in p + 1..size {
+                if a[[p, q]] == 0.0 {
+                    continue;
+                }
+                let (cos, sin) = jacobi_rotation(a[[p, p]], a[[q, q]], a[[p, q]]);
+                rotate_columns(&mut a, p, q, cos, sin);
+                rotate_rows(&mut a, p, q, cos, sin);
+                rotate_columns(&mut vectors, p, q, cos, sin);
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..size).collect();
+    order.sort_by(|i, j| a[[*i, *i]].total_cmp(&a[[*j, *j]]));
+    let values = order.iter().map(|i| a[[*i, *i]]).collect();
+
+    (values, vectors.select(Axis(1), &order))
+}
+
+/// The thin singular value decomposition `U diag(S) V^T`, with the singular values in descending
+/// order, with the one-sided Jacobi method.
+fn svd_matrix(matrix: Array2<f64>) -> (Array2<f64>, Vec<f64>, Array2<f64>) {
+    if matrix.nrows() < matrix.ncols() {
+        let (u, singular_values, vt) = svd_matrix(matrix.reversed_axes());
+        return (vt.reversed_axes(), singular_values, u.reversed_axes());
+    }
+
+    let cols = matrix.ncols();
+    let mut u = matrix;
+    let mut v = Array2::eye(cols);
+
+    for _ in 0..MAX_SWEEPS {
+        let mut converged = true;
+
+        for p in 0..cols {
+            for q in p + 1..cols {
+                let alpha = u.column(p).dot(&u.column(p));
+                let beta = u.column(q).dot(&u.column(q));
+                let gamma = u.column(p).dot(&u.column(q));
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                converged = false;
+                let (cos, sin) = jacobi_rotation(alpha, beta, gamma);
+                rotate_columns(&mut u, p, q, cos, sin);
+                rotate_columns(&mut v, p, q, cos, sin);
+            }
+        }
+
+        if converged {
+            break;
+        }
+    }
+
+    let norms: Vec<f64> = u
+        .columns()
+        .into_iter()
+        .map(|column| column.dot(&column).sqrt())
+        .collect();
+    let mut order: Vec<usize> = (0..cols).collect();
+    order.sort_by(|i, j| norms[*j].total_cmp(&norms[*i]));
+
+    let mut u = u.select(Axis(1), &order);
+    let singular_values: Vec<f64> = order.iter().map(|j| norms[*j]).collect();
+    for (mut column, value) in u.columns_mut().into_iter().zip(singular_values.iter()) {
+        if *value > 0.0 {
+            column.mapv_inplace(|x| x / value);
+        }
+    }
+
+    (
+        u,
+        singular_values,
+        v.select(Axis(1), &order).reversed_axes(),
+    )
+}
+
+/// Rotates the columns `p` and `q` by the given cosine and sine.
+fn rotate_columns(matrix: &mut Array2<f64>, p: usize, q: usize, cos: f64, sin: f64) {
+    for mut row in matrix.rows_mut() {
+        let (kp, kq) = (row[p], row[q]);
+        row[p] = cos * kp - sin * kq;
+        row[q] = sin * kp + cos * kq;
+    }
+}
+
+/// Rotates the rows `p` and `q` by the given cosine and sine.
+fn rotate_rows(matrix: &mut Array2<f64>, p: usize, q: usize, cos: f64, sin: f64) {
+    for mut column in matrix.columns_mut() {
+        let (pk, qk) = (column[p], column[q]);
+        column[p] = cos * pk - sin * qk;
+        column[q] = sin * pk + cos * qk;
+    }
+}
+
+/// The rotation zeroing the off-diagonal element of the symmetric matrix `[[app, apq], [apq, aqq]]`.
+fn jacobi_rotation(app: f64, aqq: f64, apq: f64) -> (f64, f64) {
+    let theta = (aqq - app) / (2.0 * apq);
+    let tan = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+    let cos = 1.0 / (tan * tan + 1.0).sqrt();
+
+    (cos, tan * cos)
+}
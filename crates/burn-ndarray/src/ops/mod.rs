@@ -9,6 +9,8 @@ pub(crate) mod adaptive_avgpool;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
 pub(crate) mod interpolate;
+#[cfg(not(target_family = "wasm"))]
+pub(crate) mod linalg;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
@@ -501,4 +501,29 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
     ) -> burn_tensor::ops::FloatTensor<Self, D2> {
         NdArrayOps::expand(tensor, shape)
     }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_qr<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        super::linalg::qr(tensor)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_svd<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+    ) {
+        super::linalg::svd(tensor)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_eigh<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        super::linalg::eigh(tensor)
+    }
 }
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+use super::Variable;
+
+#[derive(Clone, Debug)]
+pub enum AtomicInstruction {
+    Load {
+        array: Variable,
+        index: Variable,
+        out: Variable,
+    },
+    Store {
+        array: Variable,
+        index: Variable,
+        value: Variable,
+    },
+    Binary {
+        function: &'static str,
+        array: Variable,
+        index: Variable,
+        value: Variable,
+        out: Variable,
+    },
+    CompareAndSwap {
+        array: Variable,
+        index: Variable,
+        cmp: Variable,
+        value: Variable,
+        out: Variable,
+    },
+}
+
+impl Display for AtomicInstruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            // OpenCL 1.2 doesn't have an atomic load, adding zero returns the current value.
+            AtomicInstruction::Load { array, index, out } => {
+                f.write_fmt(format_args!("{out} = atomic_add(&{array}[{index}], 0);\n"))
+            }
+            AtomicInstruction::Store {
+                array,
+                index,
+                value,
+            } => f.write_fmt(format_args!("atomic_xchg(&{array}[{index}], {value});\n")),
+            AtomicInstruction::Binary {
+                function,
+                array,
+                index,
+                value,
+                out,
+            } => f.write_fmt(format_args!(
+                "{out} = {function}(&{array}[{index}], {value});\n"
+            )),
+            AtomicInstruction::CompareAndSwap {
+                array,
+                index,
+                cmp,
+                value,
+                out,
+            } => f.write_fmt(format_args!(
+                "{out} = atomic_cmpxchg(&{array}[{index}], {cmp}, {value});\n"
+            )),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+pub mod binary;
+pub mod unary;
+
+mod atomic;
+mod base;
+mod body;
+mod element;
+mod instruction;
+mod settings;
+mod shader;
+mod warp;
+
+pub use atomic::*;
+pub use base::*;
+pub use body::*;
+pub use element::*;
+pub use instruction::*;
+pub use settings::*;
+pub use shader::*;
+pub use warp::*;
@@ -0,0 +1,6 @@
+#[derive(Debug, Default)]
+pub struct InstructionSettings {
+    pub native_vec4: bool,
+    pub native_vec3: bool,
+    pub native_vec2: bool,
+}
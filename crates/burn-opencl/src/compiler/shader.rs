@@ -0,0 +1,170 @@
+use burn_cube::{ir::CubeDim, CompilerRepresentation};
+
+use super::{Body, Item};
+use std::fmt::Display;
+
+/// An optional OpenCL extension used by a kernel, enabled in its header.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Extension {
+    /// Subgroup queries, votes, broadcasts and add/min/max reductions.
+    Subgroups,
+    /// Subgroup shuffles, used by the product reduction.
+    SubgroupShuffle,
+    /// The election of a single invocation of a subgroup.
+    SubgroupVote,
+    /// The bitwise subgroup reductions.
+    SubgroupArithmetic,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Binding {
+    pub item: Item,
+    pub size: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SharedMemory {
+    pub index: u16,
+    pub item: Item,
+    pub size: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LocalArray {
+    pub index: u16,
+    pub item: Item,
+    pub depth: u8,
+    pub size: u32,
+}
+
+impl LocalArray {
+    pub fn new(index: u16, item: Item, depth: u8, size: u32) -> Self {
+        Self {
+            index,
+            item,
+            depth,
+            size,
+        }
+    }
+}
+
+impl SharedMemory {
+    pub fn new(index: u16, item: Item, size: u32) -> Self {
+        Self { index, item, size }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ComputeShader {
+    pub inputs: Vec<Binding>,
+    pub outputs: Vec<Binding>,
+    pub named: Vec<(String, Binding)>,
+    pub cube_dim: CubeDim,
+    pub extensions: Vec<Extension>,
+    pub body: Body,
+}
+
+impl CompilerRepresentation for ComputeShader {
+    fn shared_memory_size(&self) -> usize {
+        let mut current = 0usize;
+
+        for var in self.body.shared_memories.iter() {
+            let factor = match var.item {
+                Item::Vec4(_) => 4,
+                Item::Vec3(_) => 3,
+                Item::Vec2(_) => 2,
+                Item::Scalar(_) => 1,
+            };
+
+            let elem_size_bytes = var.item.elem().size();
+            current += (var.size as usize) * factor * elem_size_bytes;
+        }
+
+        current
+    }
+}
+
+impl Display for ComputeShader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "
+#ifdef cl_khr_fp16
+#pragma OPENCL EXTENSION cl_khr_fp16 : enable
+#endif
+",
+        )?;
+
+        for extension in self.extensions.iter() {
+            f.write_fmt(format_args!(
+                "#pragma OPENCL EXTENSION {extension} : enable\n"
+            ))?;
+        }
+
+        f.write_str(
+            "
+typedef struct {
+    bool x;
+    bool y;
+} bool2;
+
+typedef struct {
+    bool x;
+    bool y;
+    bool z;
+} bool3;
+
+typedef struct {
+    bool x;
+    bool y;
+    bool z;
+    bool w;
+} bool4;
+
+__kernel void burn_kernel(
+",
+        )?;
+
+        let num_bindings = self.inputs.len() + self.outputs.len() + self.named.len();
+        let mut binding_index = 0;
+        for (index, binding) in self.inputs.iter().enumerate() {
+            binding_index += 1;
+            f.write_fmt(format_args!("__global {}* input_{}", binding.item, index))?;
+            if binding_index < num_bindings {
+                f.write_str(",")?;
+            }
+        }
+        for (index, binding) in self.outputs.iter().enumerate() {
+            binding_index += 1;
+            f.write_fmt(format_args!("__global {}* output_{}", binding.item, index))?;
+            if binding_index < num_bindings {
+                f.write_str(",")?;
+            }
+        }
+        for (name, binding) in self.named.iter() {
+            binding_index += 1;
+            f.write_fmt(format_args!("__global {}* {}", binding.item, name))?;
+
+            if binding_index < num_bindings {
+                f.write_str(",")?;
+            }
+        }
+
+        f.write_str("\n) {\n")?;
+
+        f.write_fmt(format_args!("{}", self.body))?;
+        f.write_str("\n}")?;
+
+        Ok(())
+    }
+}
+
+impl Display for Extension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Extension::Subgroups => f.write_str("cl_khr_subgroups"),
+            Extension::SubgroupShuffle => f.write_str("cl_khr_subgroup_shuffle"),
+            Extension::SubgroupVote => f.write_str("cl_khr_subgroup_non_uniform_vote"),
+            Extension::SubgroupArithmetic => f.write_str("cl_khr_subgroup_non_uniform_arithmetic"),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use super::{Component, Elem, Extension, Variable};
+
+/// Subgroup operations, requiring the `cl_khr_subgroups` extension, and the
+/// [extensions](WarpInstruction::extensions) of the operations not part of it.
+#[derive(Clone, Debug)]
+pub enum WarpInstruction {
+    ReduceSum {
+        input: Variable,
+        out: Variable,
+    },
+    ReduceProd {
+        input: Variable,
+        out: Variable,
+    },
+    ReduceMax {
+        input: Variable,
+        out: Variable,
+    },
+    ReduceMin {
+        input: Variable,
+        out: Variable,
+    },
+    ReduceAnd {
+        input: Variable,
+        out: Variable,
+    },
+    ReduceOr {
+        input: Variable,
+        out: Variable,
+    },
+    ReduceXor {
+        input: Variable,
+        out: Variable,
+    },
+    Elect {
+        out: Variable,
+    },
+    All {
+        input: Variable,
+        out: Variable,
+    },
+    Any {
+        input: Variable,
+        out: Variable,
+    },
+    Broadcast {
+        input: Variable,
+        id: Variable,
+        out: Variable,
+    },
+}
+
+impl WarpInstruction {
+    /// The extensions required by the instruction.
+    pub fn extensions(&self) -> Vec<Extension> {
+        match self {
+            WarpInstruction::ReduceProd { .. } => {
+                vec![Extension::Subgroups, Extension::SubgroupShuffle]
+            }
+            WarpInstruction::ReduceAnd { .. }
+            | WarpInstruction::ReduceOr { .. }
+            | WarpInstruction::ReduceXor { .. } => {
+                vec![Extension::Subgroups, Extension::SubgroupArithmetic]
+            }
+            WarpInstruction::Elect { .. } => vec![Extension::Subgroups, Extension::SubgroupVote],
+            _ => vec![Extension::Subgroups],
+        }
+    }
+}
+
+impl Display for WarpInstruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WarpInstruction::ReduceSum { input, out } => {
+                f.write_fmt(format_args!("{out} = sub_group_reduce_add({input});\n"))
+            }
+            // There is no builtin product reduction, the values are exchanged with butterfly
+            // shuffles instead.
+            WarpInstruction::ReduceProd { input, out } => f.write_fmt(format_args!(
+                "
+{out} = {input};
+                    {{
+    for (uint offset = warpSizeChecked / 2; offset > 0; offset /= 2) {{
+        {out} *= sub_group_shuffle_xor({out}, offset);
+    }}
+}}
+                        "
+            )),
+            WarpInstruction::ReduceMax { input, out } => {
+                f.write_fmt(format_args!("{out} = sub_group_reduce_max({input});\n"))
+            }
+            WarpInstruction::ReduceMin { input, out } => {
+                f.write_fmt(format_args!("{out} = sub_group_reduce_min({input});\n"))
+            }
+            WarpInstruction::ReduceAnd { input, out } => reduce_bitwise(f, "and", input, out),
+            WarpInstruction::ReduceOr { input, out } => reduce_bitwise(f, "or", input, out),
+            WarpInstruction::ReduceXor { input, out } => reduce_bitwise(f, "xor", input, out),
+            WarpInstruction::Elect { out } => {
+                f.write_fmt(format_args!("{out} = sub_group_elect();\n"))
+            }
+            WarpInstruction::All { input, out } => {
+                f.write_fmt(format_args!("{out} = sub_group_all({input});\n"))
+            }
+            WarpInstruction::Any { input, out } => {
+                f.write_fmt(format_args!("{out} = sub_group_any({input});\n"))
+            }
+            WarpInstruction::Broadcast { input, id, out } => f.write_fmt(format_args!(
+                "{out} = sub_group_broadcast({input}, {id});\n"
+            )),
+        }
+    }
+}
+
+/// The bitwise reductions are only defined for integers, booleans are reduced as integers.
+fn reduce_bitwise(
+    f: &mut std::fmt::Formatter<'_>,
+    op: &str,
+    input: &Variable,
+    out: &Variable,
+) -> std::fmt::Result {
+    match input.elem() {
+        Elem::Bool => f.write_fmt(format_args!(
+            "{out} = sub_group_reduce_{op}((uint){input}) != 0;\n"
+        )),
+        _ => f.write_fmt(format_args!("{out} = sub_group_reduce_{op}({input});\n")),
+    }
+}
@@ -0,0 +1,5 @@
+mod server;
+mod storage;
+
+pub use server::*;
+pub use storage::*;
@@ -0,0 +1,284 @@
+use super::storage::OpenClStorage;
+use burn_compute::{
+    memory_management::MemoryManagement,
+    server::{self, ComputeServer},
+    storage::StorageHint,
+};
+use burn_cube::compute::KernelProfiler;
+use burn_cube::ir::CubeDim;
+use burn_cube::prelude::*;
+use burn_jit::JitAutotuneKey;
+use burn_tensor::backend::{MemoryUsage, SyncType};
+use opencl3::command_queue::CommandQueue;
+use opencl3::context::Context;
+use opencl3::kernel::{ExecuteKernel, Kernel};
+use opencl3::program::Program;
+use opencl3::types::{cl_mem, CL_BLOCKING};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The name of the kernel function in the compiled [shaders](crate::compiler::ComputeShader).
+const KERNEL_NAME: &str = "burn_kernel";
+
+#[derive(Debug)]
+pub struct OpenClServer<MM: MemoryManagement<OpenClStorage>> {
+    state: OpenClServerState<MM>,
+}
+
+pub(crate) enum OpenClServerState<MM: MemoryManagement<OpenClStorage>> {
+    Uninitialized {
+        device_index: usize,
+        init: Box<dyn Fn(usize) -> OpenClContext<MM>>,
+    },
+    Initialized {
+        ctx: OpenClContext<MM>,
+    },
+}
+
+impl<MM: MemoryManagement<OpenClStorage>> core::fmt::Debug for OpenClServerState<MM> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Context")
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct OpenClContext<MM: MemoryManagement<OpenClStorage>> {
+    memory_management: MM,
+    queue: CommandQueue,
+    context: Arc<Context>,
+    kernels: HashMap<String, CompiledKernel>,
+}
+
+#[derive(Debug)]
+struct CompiledKernel {
+    cube_dim: CubeDim,
+    kernel: Kernel,
+}
+
+unsafe impl<MM: MemoryManagement<OpenClStorage>> Send for OpenClServer<MM> {}
+
+impl<MM: MemoryManagement<OpenClStorage>> ComputeServer for OpenClServer<MM> {
+    type Kernel = Box<dyn CubeTask>;
+    type Storage = OpenClStorage;
+    type MemoryManagement = MM;
+    type AutotuneKey = JitAutotuneKey;
+
+    fn read(&mut self, binding: server::Binding<Self>) -> burn_tensor::Reader<Vec<u8>> {
+        let ctx = self.get_context();
+        let resource = ctx.memory_management.get(binding.memory);
+        let mut data = vec![0; resource.size()];
+
+        if !data.is_empty() {
+            unsafe {
+                ctx.queue
+                    .enqueue_read_buffer(
+                        &resource.buffer(),
+                        CL_BLOCKING,
+                        resource.offset(),
+                        &mut data,
+                        &[],
+                    )
+                    .unwrap();
+            }
+        }
+
+        burn_tensor::Reader::Concrete(data)
+    }
+
+    fn create(&mut self, data: &[u8]) -> server::Handle<Self> {
+        let ctx = self.get_context();
+        let handle = ctx.memory_management.reserve(data.len());
+        let handle = server::Handle::new(handle);
+        let binding = handle.clone().binding().memory;
+        let resource = ctx.memory_management.get(binding);
+
+        if !data.is_empty() {
+            unsafe {
+                ctx.queue
+                    .enqueue_write_buffer(
+                        &mut resource.buffer(),
+                        CL_BLOCKING,
+                        resource.offset(),
+                        data,
+                        &[],
+                    )
+                    .unwrap();
+            }
+        }
+
+        handle
+    }
+
+    fn empty(&mut self, size: usize) -> server::Handle<Self> {
+        let ctx = self.get_context();
+        let handle = ctx.memory_management.reserve(size);
+        server::Handle::new(handle)
+    }
+
+    fn empty_with_hint(&mut self, size: usize, hint: StorageHint) -> server::Handle<Self> {
+        let ctx = self.get_context();
+        let handle = ctx.memory_management.reserve_with_hint(size, hint);
+        server::Handle::new(handle)
+    }
+
+    fn execute(&mut self, kernel: Self::Kernel, bindings: Vec<server::Binding<Self>>) {
+        KernelProfiler::record(&kernel);
+
+        let cube_count = kernel.launch_settings().cube_count;
+        self.execute_with_cube_count(kernel, cube_count, bindings);
+    }
+
+    /// OpenCL kernels can't be launched with a cube count stored on the device, so it is read
+    /// back before the launch.
+    fn execute_indirect(
+        &mut self,
+        kernel: Self::Kernel,
+        cube_count: server::Binding<Self>,
+        bindings: Vec<server::Binding<Self>>,
+    ) {
+        let data = self.read(cube_count).read();
+        let count = bytemuck::cast_slice::<_, u32>(&data[0..12]);
+        let cube_count = CubeCount::new(count[0], count[1], count[2]);
+
+        self.execute_with_cube_count(kernel, cube_count, bindings);
+    }
+
+    fn sync(&mut self, sync_type: SyncType) {
+        let ctx = self.get_context();
+        match sync_type {
+            // Wait for all the commands of the queue to complete.
+            SyncType::Wait => ctx.queue.finish().unwrap(),
+            // Submit the commands of the queue to the device without waiting for them.
+            SyncType::Flush => ctx.queue.flush().unwrap(),
+        }
+    }
+
+    fn get_resource(
+        &mut self,
+        binding: server::Binding<Self>,
+    ) -> <Self::Storage as burn_compute::storage::ComputeStorage>::Resource {
+        let ctx = self.get_context();
+        ctx.memory_management.get(binding.memory)
+    }
+
+    fn memory_usage(&mut self) -> MemoryUsage {
+        let ctx = self.get_context();
+        ctx.memory_management.memory_usage()
+    }
+
+    fn run_custom_command(&mut self, f: impl Fn(&mut Self) + Send) {
+        f(self);
+    }
+}
+
+impl<MM: MemoryManagement<OpenClStorage>> OpenClContext<MM> {
+    pub fn new(memory_management: MM, context: Arc<Context>, queue: CommandQueue) -> Self {
+        Self {
+            memory_management,
+            queue,
+            context,
+            kernels: HashMap::new(),
+        }
+    }
+
+    fn compile_kernel(&mut self, kernel_id: &str, kernel: Box<dyn CubeTask>) {
+        let kernel_compiled = kernel.compile();
+        let cube_dim = kernel_compiled.cube_dim;
+
+        let program =
+            match Program::create_and_build_from_source(&self.context, &kernel_compiled.source, "")
+            {
+                Ok(program) => program,
+                Err(log) => {
+                    let mut message = "[Compilation Error] ".to_string();
+                    for line in log.split('\n') {
+                        if !line.is_empty() {
+                            message += format!("\n    {line}").as_str();
+                        }
+                    }
+                    let source = kernel_compiled.source;
+                    panic!("{message}\n[Source]  \n{source}");
+                }
+            };
+        let kernel = Kernel::create(&program, KERNEL_NAME).unwrap();
+
+        self.kernels
+            .insert(kernel_id.to_string(), CompiledKernel { cube_dim, kernel });
+    }
+
+    fn execute_task(&mut self, kernel_id: String, cube_count: CubeCount, bindings: Vec<cl_mem>) {
+        let kernel = self.kernels.get(&kernel_id).unwrap();
+        let cube_dim = kernel.cube_dim;
+        let local_work_sizes = [
+            cube_dim.x as usize,
+            cube_dim.y as usize,
+            cube_dim.z as usize,
+        ];
+        // OpenCL launches a number of units rather than a number of cubes.
+        let global_work_sizes = [
+            cube_count.x as usize * local_work_sizes[0],
+            cube_count.y as usize * local_work_sizes[1],
+            cube_count.z as usize * local_work_sizes[2],
+        ];
+
+        let mut execution = ExecuteKernel::new(&kernel.kernel);
+        for binding in bindings.iter() {
+            unsafe {
+                execution.set_arg(binding);
+            }
+        }
+
+        unsafe {
+            execution
+                .set_global_work_sizes(&global_work_sizes)
+                .set_local_work_sizes(&local_work_sizes)
+                .enqueue_nd_range(&self.queue)
+                .unwrap();
+        }
+    }
+}
+
+impl<MM: MemoryManagement<OpenClStorage>> OpenClServer<MM> {
+    fn execute_with_cube_count(
+        &mut self,
+        kernel: Box<dyn CubeTask>,
+        cube_count: CubeCount,
+        bindings: Vec<server::Binding<Self>>,
+    ) {
+        let ctx = self.get_context();
+        let kernel_id = kernel.id();
+
+        if !ctx.kernels.contains_key(&kernel_id) {
+            ctx.compile_kernel(&kernel_id, kernel);
+        }
+
+        let bindings = bindings
+            .into_iter()
+            .map(|binding| ctx.memory_management.get(binding.memory).buffer)
+            .collect();
+
+        ctx.execute_task(kernel_id, cube_count, bindings);
+    }
+
+    /// Create a new OpenCL server.
+    pub(crate) fn new(index: usize, init: Box<dyn Fn(usize) -> OpenClContext<MM>>) -> Self {
+        Self {
+            state: OpenClServerState::Uninitialized {
+                device_index: index,
+                init,
+            },
+        }
+    }
+
+    fn get_context(&mut self) -> &mut OpenClContext<MM> {
+        if let OpenClServerState::Uninitialized { device_index, init } = &self.state {
+            let ctx = init(*device_index);
+            self.state = OpenClServerState::Initialized { ctx };
+        }
+        if let OpenClServerState::Initialized { ctx } = &mut self.state {
+            ctx
+        } else {
+            panic!("Context should be initialized");
+        }
+    }
+}
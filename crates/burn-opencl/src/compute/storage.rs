@@ -0,0 +1,113 @@
+use burn_compute::storage::{ComputeStorage, StorageHandle, StorageId, StorageUtilization};
+use opencl3::context::Context;
+use opencl3::memory::{Buffer, ClMem, CL_MEM_READ_WRITE};
+use opencl3::types::cl_mem;
+use std::collections::HashMap;
+use std::mem::ManuallyDrop;
+use std::sync::Arc;
+
+/// Buffer storage for OpenCL.
+pub struct OpenClStorage {
+    memory: HashMap<StorageId, Buffer<u8>>,
+    context: Arc<Context>,
+}
+
+unsafe impl Send for OpenClStorage {}
+
+impl core::fmt::Debug for OpenClStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(format!("OpenClStorage {{ context: {:?} }}", self.context.get()).as_str())
+    }
+}
+
+/// Keeps actual OpenCL buffer references in a hashmap with ids as key.
+impl OpenClStorage {
+    /// Create a new storage on the given context.
+    pub fn new(context: Arc<Context>) -> Self {
+        Self {
+            memory: HashMap::new(),
+            context,
+        }
+    }
+}
+
+/// The memory resource that can be allocated for OpenCL.
+#[derive(new, Debug)]
+pub struct OpenClResource {
+    /// The OpenCL buffer.
+    pub buffer: cl_mem,
+    /// How the resource is used.
+    pub kind: OpenClResourceKind,
+}
+
+unsafe impl Send for OpenClResource {}
+
+impl OpenClResource {
+    /// Return the buffer, without releasing it when dropped since it is owned by the storage.
+    pub fn buffer(&self) -> ManuallyDrop<Buffer<u8>> {
+        ManuallyDrop::new(Buffer::new(self.buffer))
+    }
+
+    /// Return the buffer size.
+    pub fn size(&self) -> usize {
+        match self.kind {
+            OpenClResourceKind::Full { size } => size,
+            OpenClResourceKind::Slice { size, offset: _ } => size,
+        }
+    }
+
+    /// Return the buffer offset.
+    pub fn offset(&self) -> usize {
+        match self.kind {
+            OpenClResourceKind::Full { size: _ } => 0,
+            OpenClResourceKind::Slice { size: _, offset } => offset,
+        }
+    }
+}
+
+/// How the resource is used, either as a slice or fully.
+#[derive(Debug)]
+pub enum OpenClResourceKind {
+    /// Represents an entire buffer.
+    Full { size: usize },
+    /// A slice over a buffer.
+    Slice { size: usize, offset: usize },
+}
+
+impl ComputeStorage for OpenClStorage {
+    type Resource = OpenClResource;
+
+    fn get(&mut self, handle: &StorageHandle) -> Self::Resource {
+        let buffer = self.memory.get(&handle.id).unwrap();
+        match handle.utilization {
+            StorageUtilization::Full(size) => {
+                OpenClResource::new(buffer.get(), OpenClResourceKind::Full { size })
+            }
+            StorageUtilization::Slice { offset, size } => {
+                OpenClResource::new(buffer.get(), OpenClResourceKind::Slice { size, offset })
+            }
+        }
+    }
+
+    fn alloc(&mut self, size: usize) -> StorageHandle {
+        let id = StorageId::new();
+        // OpenCL doesn't allow empty buffers.
+        let buffer = unsafe {
+            Buffer::<u8>::create(
+                &self.context,
+                CL_MEM_READ_WRITE,
+                usize::max(size, 1),
+                std::ptr::null_mut(),
+            )
+            .unwrap()
+        };
+        self.memory.insert(id.clone(), buffer);
+        StorageHandle::new(id, StorageUtilization::Full(size))
+    }
+
+    /// The buffer is only released by OpenCL once the kernels using it are completed, so it can
+    /// be dropped right away.
+    fn dealloc(&mut self, id: StorageId) {
+        self.memory.remove(&id);
+    }
+}
@@ -0,0 +1,13 @@
+use burn_tensor::backend::{DeviceId, DeviceOps};
+
+/// An OpenCL device, indexed in the order the devices are reported by the installed platforms.
+#[derive(new, Clone, Debug, PartialEq, Eq, Default, Hash)]
+pub struct OpenClDevice {
+    pub index: usize,
+}
+
+impl DeviceOps for OpenClDevice {
+    fn id(&self) -> DeviceId {
+        DeviceId::new(0, self.index as u32)
+    }
+}
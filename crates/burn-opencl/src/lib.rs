@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate derive_new;
+extern crate alloc;
+
+mod compute;
+mod device;
+mod runtime;
+
+pub mod compiler;
+pub use device::*;
+
+use burn_jit::JitBackend;
+pub use runtime::OpenClRuntime;
+
+#[cfg(not(feature = "fusion"))]
+pub type OpenCl<F = f32, I = i32> = JitBackend<OpenClRuntime, F, I>;
+
+#[cfg(feature = "fusion")]
+pub type OpenCl<F = f32, I = i32> = burn_fusion::Fusion<JitBackend<OpenClRuntime, F, I>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub type TestRuntime = crate::OpenClRuntime;
+
+    burn_jit::testgen_all!();
+    burn_cube::testgen_all!();
+}
@@ -0,0 +1,144 @@
+use burn_common::stub::RwLock;
+use burn_compute::{
+    channel::MutexComputeChannel,
+    client::ComputeClient,
+    memory_management::simple::{DeallocStrategy, SimpleMemoryManagement, SliceStrategy},
+    tune::Tuner,
+    ComputeRuntime,
+};
+use burn_cube::Runtime;
+use burn_tensor::backend::{DeviceCapabilities, DeviceInfo};
+use opencl3::{
+    command_queue::CommandQueue,
+    context::Context,
+    device::{get_all_devices, Device, CL_DEVICE_TYPE_ALL},
+};
+use std::sync::{Arc, OnceLock};
+
+use crate::{
+    compiler::OpenClCompiler,
+    compute::{OpenClContext, OpenClServer, OpenClStorage},
+    device::OpenClDevice,
+};
+
+/// The runtime running the kernels of the JIT backend on OpenCL devices.
+#[derive(Debug)]
+pub struct OpenClRuntime;
+
+impl burn_jit::JitRuntime for OpenClRuntime {
+    type JitDevice = OpenClDevice;
+    type JitServer = OpenClServer<SimpleMemoryManagement<OpenClStorage>>;
+
+    fn devices() -> Vec<DeviceInfo<OpenClDevice>> {
+        let devices = get_all_devices(CL_DEVICE_TYPE_ALL).unwrap_or_default();
+
+        devices
+            .into_iter()
+            .enumerate()
+            .map(|(index, id)| {
+                let device = Device::new(id);
+                let capabilities = DeviceCapabilities {
+                    name: device.name().unwrap_or_default(),
+                    memory_size: device.global_mem_size().ok(),
+                    f16: device
+                        .extensions()
+                        .map(|extensions| extensions.contains("cl_khr_fp16"))
+                        .unwrap_or(false),
+                    // The compiler doesn't support brain floats.
+                    bf16: false,
+                    max_workgroup_size: device.max_work_group_size().ok().map(|size| size as u32),
+                    #[allow(deprecated)]
+                    unified_memory: device.host_unified_memory().unwrap_or(false),
+                };
+
+                DeviceInfo::new(OpenClDevice::new(index), capabilities)
+            })
+            .collect()
+    }
+}
+
+static RUNTIME: ComputeRuntime<OpenClDevice, Server, MutexComputeChannel<Server>> =
+    ComputeRuntime::new();
+
+type Server = OpenClServer<SimpleMemoryManagement<OpenClStorage>>;
+
+impl Runtime for OpenClRuntime {
+    type Compiler = OpenClCompiler;
+    type Server = OpenClServer<SimpleMemoryManagement<OpenClStorage>>;
+
+    type Channel = MutexComputeChannel<OpenClServer<SimpleMemoryManagement<OpenClStorage>>>;
+    type Device = OpenClDevice;
+
+    fn client(device: &Self::Device) -> ComputeClient<Self::Server, Self::Channel> {
+        fn init(index: usize) -> OpenClContext<SimpleMemoryManagement<OpenClStorage>> {
+            let devices = get_all_devices(CL_DEVICE_TYPE_ALL).unwrap();
+            let device = Device::new(*devices.get(index).unwrap_or_else(|| {
+                panic!(
+                    "No OpenCL device at index {index}, found {} devices.",
+                    devices.len()
+                )
+            }));
+            log::info!(
+                "Created OpenCL context on {} ({})",
+                device.name().unwrap_or_default(),
+                device.version().unwrap_or_default()
+            );
+
+            let context = Arc::new(Context::from_device(&device).unwrap());
+            #[allow(deprecated)]
+            let queue = CommandQueue::create_default(&context, 0).unwrap();
+            let storage = OpenClStorage::new(context.clone());
+            // OpenCL buffers can only be bound from offsets aligned to the base address alignment
+            // of the device, so the memory is never sliced.
+            let memory_management = SimpleMemoryManagement::new(
+                storage,
+                DeallocStrategy::new_period_tick(1),
+                SliceStrategy::Never,
+            );
+            OpenClContext::new(memory_management, context, queue)
+        }
+
+        RUNTIME.client(device, move || {
+            let server = OpenClServer::new(device.index, Box::new(init));
+
+            let tuner_device_id = tuner_device_id(device);
+            ComputeClient::new(
+                MutexComputeChannel::new(server),
+                Arc::new(RwLock::new(Tuner::new("opencl", &tuner_device_id))),
+            )
+        })
+    }
+
+    fn name() -> &'static str {
+        "opencl"
+    }
+
+    fn require_array_lengths() -> bool {
+        true
+    }
+
+    fn subcube() -> bool {
+        // The flag isn't per device, so subcube operations are only enabled when every device
+        // supports the extensions used by the compiled reductions.
+        static SUBCUBE: OnceLock<bool> = OnceLock::new();
+
+        *SUBCUBE.get_or_init(|| {
+            let devices = get_all_devices(CL_DEVICE_TYPE_ALL).unwrap_or_default();
+
+            !devices.is_empty()
+                && devices.into_iter().all(|id| {
+                    Device::new(id)
+                        .extensions()
+                        .map(|extensions| {
+                            extensions.contains("cl_khr_subgroups")
+                                && extensions.contains("cl_khr_subgroup_shuffle")
+                        })
+                        .unwrap_or(false)
+                })
+        })
+    }
+}
+
+fn tuner_device_id(device: &OpenClDevice) -> String {
+    format!("opencl-{}", device.index)
+}
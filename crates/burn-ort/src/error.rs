@@ -0,0 +1,35 @@
+/// An error of an [ONNX Runtime session](crate::OrtSession).
+#[derive(Debug)]
+pub enum OrtError {
+    /// An error reported by ONNX Runtime.
+    Runtime(ort::OrtError),
+    /// The inputs don't match the inputs of the graph.
+    InvalidInputs(String),
+    /// An output can't be converted to a tensor.
+    InvalidOutput(String),
+}
+
+impl core::fmt::Display for OrtError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Runtime(err) => write!(f, "ONNX Runtime error: {err}"),
+            Self::InvalidInputs(reason) => write!(f, "Invalid inputs: {reason}"),
+            Self::InvalidOutput(reason) => write!(f, "Invalid output: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for OrtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Runtime(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ort::OrtError> for OrtError {
+    fn from(err: ort::OrtError) -> Self {
+        Self::Runtime(err)
+    }
+}
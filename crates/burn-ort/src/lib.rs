@@ -0,0 +1,19 @@
+#![warn(missing_docs)]
+
+//! Run exported ONNX graphs with [ONNX Runtime](https://onnxruntime.ai) on Burn tensors.
+//!
+//! An [ONNX Runtime session](OrtSession) runs a whole graph, e.g. a model exported from PyTorch,
+//! with the [execution providers](ExecutionProvider) of the platform. The inputs and outputs are
+//! the tensors of any backend, so the numerics and the performance of a Burn model can be compared
+//! with the same graph run by ONNX Runtime.
+//!
+//! The graphs are delegated to ONNX Runtime as a whole: the crate doesn't implement the
+//! [Backend](burn_tensor::backend::Backend) trait op by op.
+
+mod error;
+mod options;
+mod session;
+
+pub use error::*;
+pub use options::*;
+pub use session::*;
@@ -0,0 +1,78 @@
+use ort::execution_providers::{
+    CUDAExecutionProviderOptions, DirectMLExecutionProviderOptions,
+    TensorRTExecutionProviderOptions,
+};
+
+/// An execution provider of ONNX Runtime, i.e. the kernels of a hardware platform.
+///
+/// Except for the CPU, the execution providers need ONNX Runtime to be built with them, e.g. with
+/// the `cuda` feature. An unavailable provider is skipped, falling back to the next one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExecutionProvider {
+    /// The default CPU kernels.
+    Cpu,
+    /// The CUDA kernels, on the GPU of the given index.
+    Cuda(usize),
+    /// The TensorRT engines, on the GPU of the given index.
+    TensorRt(usize),
+    /// The DirectML kernels, on the GPU of the given index.
+    DirectMl(usize),
+    /// The CoreML kernels of Apple platforms.
+    CoreMl,
+}
+
+impl ExecutionProvider {
+    pub(crate) fn dispatch(&self) -> ort::ExecutionProvider {
+        match self {
+            Self::Cpu => ort::ExecutionProvider::CPU(Default::default()),
+            Self::Cuda(index) => ort::ExecutionProvider::CUDA(CUDAExecutionProviderOptions {
+                device_id: *index as u32,
+                ..Default::default()
+            }),
+            Self::TensorRt(index) => {
+                ort::ExecutionProvider::TensorRT(TensorRTExecutionProviderOptions {
+                    device_id: *index as u32,
+                    ..Default::default()
+                })
+            }
+            Self::DirectMl(index) => {
+                ort::ExecutionProvider::DirectML(DirectMLExecutionProviderOptions {
+                    device_id: *index as u32,
+                })
+            }
+            Self::CoreMl => ort::ExecutionProvider::CoreML(Default::default()),
+        }
+    }
+}
+
+/// The options of an [ONNX Runtime session](crate::OrtSession).
+#[derive(Clone, Debug)]
+pub struct OrtOptions {
+    /// The execution providers, in order of preference. Default: the CPU.
+    pub execution_providers: Vec<ExecutionProvider>,
+    /// The number of threads used to run an op, or the default of ONNX Runtime.
+    pub intra_threads: Option<usize>,
+}
+
+impl Default for OrtOptions {
+    fn default() -> Self {
+        Self {
+            execution_providers: vec![ExecutionProvider::Cpu],
+            intra_threads: None,
+        }
+    }
+}
+
+impl OrtOptions {
+    /// Set the execution providers, in order of preference.
+    pub fn with_execution_providers(mut self, providers: Vec<ExecutionProvider>) -> Self {
+        self.execution_providers = providers;
+        self
+    }
+
+    /// Set the number of threads used to run an op.
+    pub fn with_intra_threads(mut self, intra_threads: usize) -> Self {
+        self.intra_threads = Some(intra_threads);
+        self
+    }
+}
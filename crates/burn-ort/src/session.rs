@@ -0,0 +1,141 @@
+use std::path::Path;
+
+use burn_tensor::{backend::Backend, Data, Shape, Tensor};
+use ndarray::{CowArray, IxDyn};
+use ort::{Environment, GraphOptimizationLevel, Session, SessionBuilder, Value};
+
+use crate::{OrtError, OrtOptions};
+
+/// An ONNX graph loaded by ONNX Runtime, run on the tensors of any backend.
+///
+/// The tensors are copied to the host to be given to ONNX Runtime, and the outputs are copied
+/// back to the device of the first input. Only graphs with float inputs and outputs are
+/// supported, their tensors being exchanged as `f32`.
+pub struct OrtSession {
+    session: Session,
+}
+
+impl OrtSession {
+    /// Load the ONNX graph of the given file.
+    pub fn from_file<P: AsRef<Path>>(path: P, options: &OrtOptions) -> Result<Self, OrtError> {
+        let session = builder(options)?.with_model_from_file(path)?;
+
+        Ok(Self { session })
+    }
+
+    /// Load the ONNX graph of the given bytes, e.g. embedded with [include_bytes].
+    pub fn from_bytes(bytes: &[u8], options: &OrtOptions) -> Result<Self, OrtError> {
+        let session = builder(options)?.with_model_from_memory(bytes)?;
+
+        Ok(Self { session })
+    }
+
+    /// The names of the inputs of the graph, in order.
+    pub fn input_names(&self) -> Vec<&str> {
+        self.session
+            .inputs
+            .iter()
+            .map(|input| input.name.as_str())
+            .collect()
+    }
+
+    /// The names of the outputs of the graph, in order.
+    pub fn output_names(&self) -> Vec<&str> {
+        self.session
+            .outputs
+            .iter()
+            .map(|output| output.name.as_str())
+            .collect()
+    }
+
+    /// Run the graph on the given inputs, in the order of the [inputs](Self::input_names) of the
+    /// graph, returning its outputs in order.
+    ///
+    /// # Errors
+    ///
+    /// When the number of inputs or the rank of an output don't match the graph, or when ONNX
+    /// Runtime fails to run it.
+    pub fn run<B: Backend, const D: usize, const D_OUT: usize>(
+        &self,
+        inputs: Vec<Tensor<B, D>>,
+    ) -> Result<Vec<Tensor<B, D_OUT>>, OrtError> {
+        if inputs.len() != self.session.inputs.len() {
+            return Err(OrtError::InvalidInputs(format!(
+                "The graph has {} inputs, got {}.",
+                self.session.inputs.len(),
+                inputs.len()
+            )));
+        }
+
+        let device = inputs
+            .first()
+            .map(|input| input.device())
+            .unwrap_or_default();
+        let arrays = inputs
+            .into_iter()
+            .map(|tensor| {
+                let data = tensor.into_data().convert::<f32>();
+                let array = ndarray::Array::from_shape_vec(IxDyn(&data.shape.dims), data.value)
+                    .expect("The shape should match the number of values.");
+
+                CowArray::from(array)
+            })
+            .collect::<Vec<_>>();
+        let values = arrays
+            .iter()
+            .map(|array| Value::from_array(self.session.allocator(), array))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let outputs = self.session.run(values)?;
+
+        self.session
+            .outputs
+            .iter()
+            .zip(outputs.iter())
+            .map(|(output, value)| {
+                let tensor = value.try_extract::<f32>()?;
+                let view = tensor.view();
+                let dims: [usize; D_OUT] = view.shape().try_into().map_err(|_| {
+                    OrtError::InvalidOutput(format!(
+                        "The output {} has {} dimensions, expected {D_OUT}.",
+                        output.name,
+                        view.ndim()
+                    ))
+                })?;
+                let data = Data::new(view.iter().copied().collect(), Shape::new(dims));
+
+                Ok(Tensor::from_data(data.convert(), &device))
+            })
+            .collect()
+    }
+}
+
+impl core::fmt::Debug for OrtSession {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OrtSession")
+            .field("inputs", &self.input_names())
+            .field("outputs", &self.output_names())
+            .finish()
+    }
+}
+
+fn builder(options: &OrtOptions) -> Result<SessionBuilder, OrtError> {
+    let execution_providers = options
+        .execution_providers
+        .iter()
+        .map(|provider| provider.dispatch())
+        .collect::<Vec<_>>();
+    let environment = Environment::builder()
+        .with_name("burn")
+        .with_execution_providers(execution_providers)
+        .build()?
+        .into_arc();
+    let mut builder = SessionBuilder::new(&environment)?
+        .with_optimization_level(GraphOptimizationLevel::Level3)?;
+
+    if let Some(intra_threads) = options.intra_threads {
+        builder = builder.with_intra_threads(intra_threads as i16)?;
+    }
+
+    Ok(builder)
+}
@@ -0,0 +1,33 @@
+// The library is only available without configuring the environment when it is downloaded.
+#![cfg(feature = "download-binaries")]
+
+use burn_ndarray::NdArray;
+use burn_ort::{OrtOptions, OrtSession};
+use burn_tensor::{activation, Distribution, Tensor};
+
+type TestBackend = NdArray<f32>;
+
+const RELU: &[u8] = include_bytes!("../../burn-import/onnx-tests/tests/relu/relu.onnx");
+
+#[test]
+fn graph_should_match_the_burn_ops() {
+    let session = OrtSession::from_bytes(RELU, &OrtOptions::default()).unwrap();
+    let device = Default::default();
+    let input = Tensor::<TestBackend, 2>::random([2, 3], Distribution::Default, &device);
+
+    let outputs = session.run::<_, 2, 2>(vec![input.clone()]).unwrap();
+
+    assert_eq!(outputs.len(), 1);
+    outputs[0]
+        .to_data()
+        .assert_approx_eq(&activation::relu(input).into_data(), 5);
+}
+
+#[test]
+fn wrong_number_of_inputs_should_fail() {
+    let session = OrtSession::from_bytes(RELU, &OrtOptions::default()).unwrap();
+
+    let outputs = session.run::<TestBackend, 2, 2>(Vec::new());
+
+    assert!(outputs.is_err());
+}
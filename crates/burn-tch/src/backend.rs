@@ -2,7 +2,9 @@ use crate::PrecisionBridge;
 
 use super::element::TchElement;
 use super::TchTensor;
-use burn_tensor::backend::{Backend, DeviceId, DeviceOps, SyncType};
+use burn_tensor::backend::{
+    Backend, DeviceCapabilities, DeviceId, DeviceInfo, DeviceOps, SyncType,
+};
 use burn_tensor::ops::IntTensorOps;
 use burn_tensor::{Int, Tensor};
 
@@ -131,4 +133,42 @@ impl<E: TchElement> Backend for LibTorch<E> {
             }
         }
     }
+
+    fn devices() -> Vec<DeviceInfo<Self::Device>> {
+        // LibTorch doesn't report the properties of the devices, the supported precisions are the
+        // ones of its kernels for each kind of device.
+        let capabilities =
+            |name: String, f16: bool, bf16: bool, unified_memory: bool| DeviceCapabilities {
+                name,
+                f16,
+                bf16,
+                unified_memory,
+                ..Default::default()
+            };
+        let mut devices = vec![DeviceInfo::new(
+            LibTorchDevice::Cpu,
+            capabilities("CPU".to_string(), false, true, true),
+        )];
+
+        for index in 0..tch::Cuda::device_count() as usize {
+            devices.push(DeviceInfo::new(
+                LibTorchDevice::Cuda(index),
+                capabilities(format!("CUDA {index}"), true, true, false),
+            ));
+        }
+        if tch::utils::has_mps() {
+            devices.push(DeviceInfo::new(
+                LibTorchDevice::Mps,
+                capabilities("MPS".to_string(), true, false, true),
+            ));
+        }
+        if tch::utils::has_vulkan() {
+            devices.push(DeviceInfo::new(
+                LibTorchDevice::Vulkan,
+                capabilities("Vulkan".to_string(), false, false, false),
+            ));
+        }
+
+        devices
+    }
 }
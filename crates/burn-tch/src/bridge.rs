@@ -46,4 +46,12 @@ where
             tensor
         }
     }
+
+    fn target_device(device: &Device<LibTorch<OElem>>) -> Device<Self::Target> {
+        device.clone()
+    }
+
+    fn origin_device(device: &Device<Self::Target>) -> Device<LibTorch<OElem>> {
+        device.clone()
+    }
 }
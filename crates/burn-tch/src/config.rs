@@ -0,0 +1,104 @@
+/// Global settings of LibTorch, applied to the whole process with [apply](LibTorchConfig::apply).
+///
+/// The settings left to `None` keep the defaults of LibTorch.
+///
+/// The operations are always executed on the current CUDA stream of LibTorch, which is the
+/// default stream: the bindings of LibTorch don't expose the streams, so they can't be configured
+/// here. Copies from [pinned memory](crate::TchTensor::pin_memory) are still asynchronous.
+///
+/// # Example
+///
+/// ```no_run
+/// use burn_tch::LibTorchConfig;
+///
+/// // Let cuDNN benchmark the convolution algorithms for the shapes of the model, like
+/// // `torch.backends.cudnn.benchmark = True`.
+/// LibTorchConfig::new().with_cudnn_benchmark(true).apply();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LibTorchConfig {
+    /// If cuDNN benchmarks the algorithms of the convolutions for each new input shape and uses
+    /// the fastest one. It speeds up models with fixed input shapes, but the selected algorithms
+    /// may differ between runs, so it should be disabled for reproducible results.
+    pub cudnn_benchmark: Option<bool>,
+    /// If cuDNN is used for the operations it supports. Disabling it makes LibTorch use its own
+    /// kernels, which are slower but deterministic for most operations.
+    pub cudnn_enabled: Option<bool>,
+    /// The number of threads used within the CPU operations.
+    pub num_threads: Option<usize>,
+    /// The number of threads used to run independent CPU operations in parallel.
+    pub num_interop_threads: Option<usize>,
+}
+
+impl LibTorchConfig {
+    /// Create a new configuration, keeping the defaults of LibTorch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set if cuDNN benchmarks the algorithms of the convolutions.
+    pub fn with_cudnn_benchmark(mut self, cudnn_benchmark: bool) -> Self {
+        self.cudnn_benchmark = Some(cudnn_benchmark);
+        self
+    }
+
+    /// Set if cuDNN is used.
+    pub fn with_cudnn_enabled(mut self, cudnn_enabled: bool) -> Self {
+        self.cudnn_enabled = Some(cudnn_enabled);
+        self
+    }
+
+    /// Set the number of threads used within the CPU operations.
+    pub fn with_num_threads(mut self, num_threads: usize) -> Self {
+        self.num_threads = Some(num_threads);
+        self
+    }
+
+    /// Set the number of threads used to run independent CPU operations in parallel.
+    ///
+    /// LibTorch only allows setting it once, before any parallel CPU operation is executed.
+    pub fn with_num_interop_threads(mut self, num_interop_threads: usize) -> Self {
+        self.num_interop_threads = Some(num_interop_threads);
+        self
+    }
+
+    /// A configuration for reproducible results: cuDNN doesn't benchmark its algorithms and is
+    /// disabled, so the convolutions use the deterministic kernels of LibTorch.
+    ///
+    /// Combined with a fixed [seed](burn_tensor::backend::Backend::seed), the same training gives
+    /// the same results on the same hardware, at the cost of slower convolutions.
+    ///
+    /// Note that `torch.backends.cudnn.deterministic` isn't exposed by the bindings of LibTorch,
+    /// so cuDNN can't be kept while only selecting its deterministic algorithms.
+    pub fn deterministic() -> Self {
+        Self::new()
+            .with_cudnn_benchmark(false)
+            .with_cudnn_enabled(false)
+    }
+
+    /// Apply the configuration to LibTorch.
+    pub fn apply(&self) {
+        if let Some(cudnn_benchmark) = self.cudnn_benchmark {
+            tch::Cuda::cudnn_set_benchmark(cudnn_benchmark);
+        }
+        if let Some(cudnn_enabled) = self.cudnn_enabled {
+            tch::Cuda::set_user_enabled_cudnn(cudnn_enabled);
+        }
+        if let Some(num_threads) = self.num_threads {
+            tch::set_num_threads(num_threads as i32);
+        }
+        if let Some(num_interop_threads) = self.num_interop_threads {
+            tch::set_num_interop_threads(num_interop_threads as i32);
+        }
+    }
+
+    /// The configuration currently used by LibTorch.
+    pub fn current() -> Self {
+        Self {
+            cudnn_benchmark: None,
+            cudnn_enabled: Some(tch::Cuda::user_enabled_cudnn()),
+            num_threads: Some(tch::get_num_threads() as usize),
+            num_interop_threads: Some(tch::get_num_interop_threads() as usize),
+        }
+    }
+}
@@ -5,12 +5,14 @@
 
 mod backend;
 mod bridge;
+mod config;
 mod element;
 mod ops;
 mod tensor;
 
 pub use backend::*;
 pub use bridge::*;
+pub use config::*;
 pub use element::*;
 pub use tensor::*;
 
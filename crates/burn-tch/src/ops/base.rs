@@ -21,7 +21,17 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
             return tensor;
         }
 
-        TchTensor::new(tensor.tensor.to(device))
+        // Copies from pinned host memory to a CUDA device are asynchronous, they are ordered with
+        // the operations of the current stream, which will use the copied tensor.
+        let non_blocking = matches!(device, tch::Device::Cuda(_))
+            && tensor.tensor.device() == tch::Device::Cpu
+            && tensor.tensor.is_pinned(device);
+
+        TchTensor::new(
+            tensor
+                .tensor
+                .to_device_(device, tensor.tensor.kind(), non_blocking, false),
+        )
     }
 
     pub fn reshape<const D1: usize, const D2: usize>(
@@ -502,7 +512,7 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         dim: usize,
         descending: bool,
     ) -> TchTensor<E, D> {
-        TchTensor::new(tensor.tensor.sort(dim as i64, descending).0)
+        TchTensor::new(tensor.tensor.sort_stable(true, dim as i64, descending).0)
     }
 
     pub fn argsort<const D: usize>(
@@ -510,6 +520,6 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         dim: usize,
         descending: bool,
     ) -> TchTensor<i64, D> {
-        TchTensor::new(tensor.tensor.argsort(dim as i64, descending))
+        TchTensor::new(tensor.tensor.argsort_stable(true, dim as i64, descending))
     }
 }
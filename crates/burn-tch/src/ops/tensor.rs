@@ -513,4 +513,61 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
     ) -> <LibTorch<E> as Backend>::IntTensorPrimitive<D> {
         TchOps::argsort(tensor, dim, descending)
     }
+
+    fn float_cholesky<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.linalg_cholesky(false))
+    }
+
+    fn float_solve<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::linalg_solve(&lhs.tensor, &rhs.tensor, true))
+    }
+
+    fn float_inverse<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::linalg_inv(&tensor.tensor))
+    }
+
+    fn float_det<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        let det = tch::Tensor::linalg_det(&tensor.tensor);
+        TchTensor::new(det.unsqueeze(-1).unsqueeze(-1))
+    }
+
+    fn float_slogdet<const D: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (sign, logabsdet) = tch::Tensor::linalg_slogdet(&tensor.tensor);
+        (
+            TchTensor::new(sign.unsqueeze(-1).unsqueeze(-1)),
+            TchTensor::new(logabsdet.unsqueeze(-1).unsqueeze(-1)),
+        )
+    }
+
+    fn float_qr<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (q, r) = tensor.tensor.linalg_qr("reduced");
+        // LibTorch doesn't fix the signs of the factors, so the diagonal of `R` is made
+        // non-negative.
+        let signs = r.diagonal(0, -2, -1).ge(0.0).to_kind(r.kind()) * 2.0 - 1.0;
+        (
+            TchTensor::new(q * signs.unsqueeze(-2)),
+            TchTensor::new(r * signs.unsqueeze(-1)),
+        )
+    }
+
+    fn float_svd<const D: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>, TchTensor<E, D>) {
+        let (u, s, vt) = tch::Tensor::linalg_svd(&tensor.tensor, false, None::<&str>);
+        (
+            TchTensor::new(u),
+            TchTensor::new(s.unsqueeze(-2)),
+            TchTensor::new(vt),
+        )
+    }
+
+    fn float_eigh<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (values, vectors) = tensor.tensor.linalg_eigh("L");
+        (
+            TchTensor::new(values.unsqueeze(-2)),
+            TchTensor::new(vectors),
+        )
+    }
 }
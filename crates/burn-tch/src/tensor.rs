@@ -139,6 +139,41 @@ impl<E: tch::kind::Element, const D: usize> TchTensor<E, D> {
     }
 }
 
+impl<E: tch::kind::Element, const D: usize> TchTensor<E, D> {
+    /// Copy the tensor to page-locked (pinned) host memory, so it can be copied to the given
+    /// accelerator device asynchronously.
+    ///
+    /// [to_device](burn_tensor::Tensor::to_device) of a pinned tensor to a CUDA device doesn't
+    /// wait for the copy to complete, which lets the next batch of a data loader be copied while
+    /// the model is executed. Pinned memory can't be swapped out, so only the tensors about to be
+    /// copied should be pinned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use burn_tch::{LibTorch, LibTorchDevice};
+    /// use burn_tensor::Tensor;
+    ///
+    /// let device = LibTorchDevice::Cuda(0);
+    /// let batch = Tensor::<LibTorch, 2>::ones([32, 784], &LibTorchDevice::Cpu);
+    /// let batch = Tensor::<LibTorch, 2>::from_primitive(batch.into_primitive().pin_memory(&device));
+    ///
+    /// let batch = batch.to_device(&device);
+    /// ```
+    pub fn pin_memory(self, device: &LibTorchDevice) -> Self {
+        if self.is_pinned(device) {
+            return self;
+        }
+
+        Self::new(self.tensor.pin_memory((*device).into()))
+    }
+
+    /// If the tensor is stored in pinned host memory for the given accelerator device.
+    pub fn is_pinned(&self, device: &LibTorchDevice) -> bool {
+        self.tensor.device() == tch::Device::Cpu && self.tensor.is_pinned((*device).into())
+    }
+}
+
 impl<E: TchElement, const D: usize> std::ops::Add for TchTensor<E, D> {
     type Output = Self;
 
@@ -0,0 +1,184 @@
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use burn_common::rand::{SeedableRng, StdRng};
+use num_traits::Float;
+
+use crate::{backend::Backend, Data, Distribution, Shape, Tensor};
+
+/// A sequence of operations that can be executed on any backend, to be
+/// [compared](compare_backends) between two backends.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::consistency::OpSequence;
+/// use burn_tensor::{activation, Tensor};
+///
+/// struct MatmulSoftmax;
+///
+/// impl OpSequence<2, 2> for MatmulSoftmax {
+///     fn run<B: Backend>(&self, mut inputs: Vec<Tensor<B, 2>>) -> Tensor<B, 2> {
+///         let rhs = inputs.pop().unwrap();
+///         let lhs = inputs.pop().unwrap();
+///
+///         activation::softmax(lhs.matmul(rhs), 1)
+///     }
+/// }
+/// ```
+pub trait OpSequence<const D: usize, const D2: usize> {
+    /// Execute the operations on the given inputs.
+    fn run<B: Backend>(&self, inputs: Vec<Tensor<B, D>>) -> Tensor<B, D2>;
+}
+
+/// The difference between the outputs of the same operations executed on two backends.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsistencyReport {
+    /// The number of compared elements.
+    pub num_elements: usize,
+    /// The maximum absolute error.
+    pub max_abs_error: f64,
+    /// The mean absolute error.
+    pub mean_abs_error: f64,
+    /// The maximum error relative to the reference value.
+    pub max_rel_error: f64,
+    /// The number of elements that are NaN on only one of the backends.
+    pub nan_mismatches: usize,
+}
+
+/// Execute the operations on random inputs of the given shapes, sampled from the default
+/// distribution with the given seed, on the backend `B` and on the reference backend `R`.
+///
+/// # Panics
+///
+/// If the outputs of the backends have different shapes.
+pub fn compare_backends<B, R, S, const D: usize, const D2: usize>(
+    sequence: &S,
+    shapes: &[[usize; D]],
+    seed: u64,
+) -> ConsistencyReport
+where
+    B: Backend,
+    R: Backend,
+    S: OpSequence<D, D2>,
+{
+    let mut rng = StdRng::seed_from_u64(seed);
+    let inputs = shapes
+        .iter()
+        .map(|shape| Data::random(Shape::new(*shape), Distribution::Default, &mut rng))
+        .collect();
+
+    compare_backends_with_inputs::<B, R, S, D, D2>(sequence, inputs)
+}
+
+/// Execute the operations on the given inputs on the backend `B` and on the reference
+/// backend `R`.
+///
+/// # Panics
+///
+/// If the outputs of the backends have different shapes.
+pub fn compare_backends_with_inputs<B, R, S, const D: usize, const D2: usize>(
+    sequence: &S,
+    inputs: Vec<Data<f32, D>>,
+) -> ConsistencyReport
+where
+    B: Backend,
+    R: Backend,
+    S: OpSequence<D, D2>,
+{
+    let device = Default::default();
+    let device_ref = Default::default();
+
+    let inputs_ref = inputs
+        .iter()
+        .map(|data| Tensor::<R, D>::from_data(data.clone().convert(), &device_ref))
+        .collect();
+    let inputs = inputs
+        .into_iter()
+        .map(|data| Tensor::<B, D>::from_data(data.convert(), &device))
+        .collect();
+
+    let output = sequence.run::<B>(inputs).into_data().convert::<f64>();
+    let output_ref = sequence.run::<R>(inputs_ref).into_data().convert::<f64>();
+
+    assert_eq!(
+        output.shape, output_ref.shape,
+        "The outputs of the backends have different shapes."
+    );
+
+    ConsistencyReport::new(&output.value, &output_ref.value)
+}
+
+impl ConsistencyReport {
+    fn new(values: &[f64], values_ref: &[f64]) -> Self {
+        let mut report = Self {
+            num_elements: values.len(),
+            max_abs_error: 0.0,
+            mean_abs_error: 0.0,
+            max_rel_error: 0.0,
+            nan_mismatches: 0,
+        };
+        let mut sum_abs_error = 0.0;
+        let mut num_compared = 0;
+
+        for (value, value_ref) in values.iter().zip(values_ref) {
+            if value.is_nan() || value_ref.is_nan() {
+                if value.is_nan() != value_ref.is_nan() {
+                    report.nan_mismatches += 1;
+                }
+                continue;
+            }
+
+            // Infinities of the same sign are equal, otherwise the error is infinite.
+            let abs_error = match value == value_ref {
+                true => 0.0,
+                false => Float::abs(value - value_ref),
+            };
+            let rel_error = match abs_error > 0.0 {
+                true => abs_error / Float::abs(*value_ref).max(f64::EPSILON),
+                false => 0.0,
+            };
+
+            report.max_abs_error = report.max_abs_error.max(abs_error);
+            report.max_rel_error = report.max_rel_error.max(rel_error);
+            sum_abs_error += abs_error;
+            num_compared += 1;
+        }
+
+        if num_compared > 0 {
+            report.mean_abs_error = sum_abs_error / num_compared as f64;
+        }
+
+        report
+    }
+
+    /// Asserts the maximum absolute error is within the tolerance, and that the backends agree
+    /// on which elements are NaN.
+    ///
+    /// # Panics
+    ///
+    /// If the outputs of the backends aren't consistent.
+    #[track_caller]
+    pub fn assert_max_abs_error(&self, tolerance: f64) {
+        assert!(
+            self.nan_mismatches == 0 && self.max_abs_error <= tolerance,
+            "The backends aren't consistent with a tolerance of {tolerance}: {self}"
+        );
+    }
+}
+
+impl Display for ConsistencyReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "max abs error {:e}, mean abs error {:e}, max rel error {:e}, {} NaN mismatches \
+             over {} elements",
+            self.max_abs_error,
+            self.mean_abs_error,
+            self.max_rel_error,
+            self.nan_mismatches,
+            self.num_elements
+        )
+    }
+}
@@ -15,6 +15,10 @@ mod tensor;
 #[cfg(feature = "repr")]
 pub mod repr;
 
+/// Harness comparing the results of operations between backends.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub mod consistency;
+
 #[cfg(feature = "export_tests")]
 #[allow(missing_docs)]
 mod tests;
@@ -2,7 +2,9 @@ use serde::{Deserialize, Serialize};
 use std::ops::Range;
 
 use crate::{
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateMode, InterpolateOptions},
+    ops::{
+        ConvOptions, ConvTransposeOptions, InterpolateMode, InterpolateOptions, ScatterReduction,
+    },
     repr::tensor::TensorDescription,
     Distribution, Element,
 };
@@ -59,6 +61,9 @@ pub enum FloatOperationDescription {
     Random(RandomOperationDescription),
     /// Operation corresponding to [recip](crate::ops::FloatTensorOps::float_recip).
     Recip(UnaryOperationDescription),
+    /// Operation corresponding to
+    /// [scatter reduce](crate::ops::FloatTensorOps::float_scatter_reduce).
+    ScatterReduce(ScatterReduceOperationDescription),
 }
 
 /// Operation description specific to module.
@@ -120,6 +125,11 @@ pub enum ModuleOperationDescription {
     Interpolate(InterpolateDescription),
     /// Operation corresponding to [interpolate backward](crate::ops::ModuleOps::interpolate_backward).
     InterpolateBackward(InterpolateBackwardDescription),
+    /// Operation corresponding to [cross entropy](crate::ops::ActivationOps::cross_entropy).
+    CrossEntropy(CrossEntropyDescription),
+    /// Operation corresponding to
+    /// [cross entropy backward](crate::ops::ActivationOps::cross_entropy_backward).
+    CrossEntropyBackward(CrossEntropyBackwardDescription),
 }
 
 /// Basic operations that can be done on any tensor type.
@@ -558,6 +568,18 @@ pub struct ScatterOperationDescription {
     pub out: TensorDescription,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct ScatterReduceOperationDescription {
+    pub tensor: TensorDescription,
+    pub dim: usize,
+    pub indices: TensorDescription,
+    pub value: TensorDescription,
+    pub reduction: ScatterReduction,
+    pub deterministic: bool,
+    pub out: TensorDescription,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct SelectOperationDescription {
@@ -1053,6 +1075,23 @@ pub struct InterpolateBackwardDescription {
     pub out: TensorDescription,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct CrossEntropyDescription {
+    pub logits: TensorDescription,
+    pub targets: TensorDescription,
+    pub out: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct CrossEntropyBackwardDescription {
+    pub logits: TensorDescription,
+    pub targets: TensorDescription,
+    pub grad: TensorDescription,
+    pub out: TensorDescription,
+}
+
 impl OperationDescription {
     /// Cleanup the remaining tensor handles that have not been used.
     pub fn nodes(&self) -> Vec<&TensorDescription> {
@@ -1263,6 +1302,9 @@ impl FloatOperationDescription {
             FloatOperationDescription::Sin(desc) => vec![&desc.input, &desc.out],
             FloatOperationDescription::Tanh(desc) => vec![&desc.input, &desc.out],
             FloatOperationDescription::IntoInt(desc) => vec![&desc.input, &desc.out],
+            FloatOperationDescription::ScatterReduce(desc) => {
+                vec![&desc.tensor, &desc.indices, &desc.value, &desc.out]
+            }
         }
     }
 }
@@ -1370,6 +1412,12 @@ impl ModuleOperationDescription {
             ModuleOperationDescription::InterpolateBackward(desc) => {
                 vec![&desc.x, &desc.out, &desc.grad]
             }
+            ModuleOperationDescription::CrossEntropy(desc) => {
+                vec![&desc.logits, &desc.targets, &desc.out]
+            }
+            ModuleOperationDescription::CrossEntropyBackward(desc) => {
+                vec![&desc.logits, &desc.targets, &desc.grad, &desc.out]
+            }
         }
     }
 }
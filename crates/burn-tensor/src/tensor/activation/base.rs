@@ -32,6 +32,7 @@ pub fn gelu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
 /// ` PReLu(x) = max(0,x) + \alpha * min(0,x)`
 /// tensor is assumed to be of shape \[batch_size, channels, ...\]
 /// alpha is assumed to be of shape \[channels\] or \[1\]
+#[track_caller]
 pub fn prelu<const D: usize, B: Backend>(
     tensor: Tensor<B, D>,
     alpha: Tensor<B, 1>,
@@ -65,6 +66,7 @@ pub fn prelu<const D: usize, B: Backend>(
 ///
 /// The dimension argument `dim` specifies the dimension along which the function will be computed.
 /// It must in the range of `0` and `D-1`.
+#[track_caller]
 pub fn softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     check!(TensorCheck::dim_ops::<D>("softmax", dim));
 
@@ -93,6 +95,7 @@ pub fn softplus<const D: usize, B: Backend>(tensor: Tensor<B, D>, beta: f64) ->
 ///
 /// The dimension argument `dim` specifies the dimension along which the function will be computed.
 /// It must in the range of `0` and `D-1`.
+#[track_caller]
 pub fn quiet_softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     check!(TensorCheck::dim_ops::<D>("softmax", dim));
 
@@ -111,6 +114,7 @@ pub fn quiet_softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usiz
 ///
 /// The dimension argument `dim` specifies the dimension along which the function will be computed.
 /// It must in the range of `0` and `D-1`.
+#[track_caller]
 pub fn log_softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     check!(TensorCheck::dim_ops::<D>("log softmax", dim));
 
@@ -25,6 +25,15 @@ impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
     pub fn grad_replace(&self, grads: &mut B::Gradients, grad: Tensor<B::InnerBackend, D>) {
         B::grad_replace(&self.primitive, grads, grad.primitive);
     }
+
+    /// Keeps the value of the tensor in host memory for the backward pass, e.g. for a large
+    /// activation, so its device memory is released once the forward pass doesn't use it anymore.
+    ///
+    /// Only the value saved for the backward pass is offloaded, and it is loaded back on its device
+    /// when needed. The operations of the forward pass should use the returned tensor.
+    pub fn offload(self) -> Self {
+        Self::new(B::offload(self.primitive))
+    }
 }
 
 impl<const D: usize, B: AutodiffBackend, K: BasicAutodiffOps<B>> Tensor<B, D, K> {
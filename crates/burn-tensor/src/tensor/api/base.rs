@@ -108,6 +108,7 @@ where
     ///    println!("{:?}", reshaped_tensor.shape());
     /// }
     /// ```
+    #[track_caller]
     pub fn reshape<const D2: usize, S: ReshapeArgs<D2>>(self, shape: S) -> Tensor<B, D2, K> {
         // Convert reshape args to shape
         let shape = shape.into_shape(&self);
@@ -235,6 +236,7 @@ where
     /// }
     ///
     /// ```
+    #[track_caller]
     pub fn flatten<const D2: usize>(self, start_dim: usize, end_dim: usize) -> Tensor<B, D2, K> {
         check!(TensorCheck::flatten::<D, D2>(start_dim, end_dim));
 
@@ -286,6 +288,7 @@ where
     ///     println!("{:?}", squeezed_tensor.shape());
     /// }
     /// ```
+    #[track_caller]
     pub fn squeeze<const D2: usize>(self, dim: usize) -> Tensor<B, D2, K> {
         check!(TensorCheck::squeeze::<D2>(dim, &self.shape().dims));
 
@@ -335,6 +338,7 @@ where
     ///     println!("{:?}", squeezed_tensor.shape());
     /// }
     /// ```
+    #[track_caller]
     pub fn squeeze_dims<const D2: usize>(self, dims: &[isize]) -> Tensor<B, D2, K> {
         let current_dims = self.shape().dims;
         let mut dim_indices: Vec<usize>;
@@ -405,6 +409,7 @@ where
     ///     // Shape { dims: [1, 1, 3, 3] }
     /// }
     /// ```
+    #[track_caller]
     pub fn unsqueeze<const D2: usize>(self) -> Tensor<B, D2, K> {
         check!(TensorCheck::unsqueeze::<D, D2>());
 
@@ -434,6 +439,7 @@ where
     ///     // Shape { dims: [3, 1, 3] }
     /// }
     /// ```
+    #[track_caller]
     pub fn unsqueeze_dim<const D2: usize>(self, dim: usize) -> Tensor<B, D2, K> {
         check!(TensorCheck::unsqueeze_dim::<{ D }>(dim));
 
@@ -471,6 +477,7 @@ where
     ///     // Shape { dims: [1, 3, 4, 5, 1, 1] }
     /// }
     /// ```
+    #[track_caller]
     pub fn unsqueeze_dims<const D2: usize>(self, axes: &[isize]) -> Tensor<B, D2, K> {
         let mut new_dims = [1; D2];
         let old_dims = self.shape().dims;
@@ -662,6 +669,7 @@ where
     /// # Panics
     ///
     /// If the two tensors don't have the same shape.
+    #[track_caller]
     pub fn equal(self, other: Self) -> Tensor<B, D, Bool> {
         check!(TensorCheck::binary_ops_ew("Equal", &self, &other));
         K::equal(self.primitive, other.primitive)
@@ -672,6 +680,7 @@ where
     /// # Panics
     ///
     /// If the two tensors don't have the same shape.
+    #[track_caller]
     pub fn not_equal(self, other: Self) -> Tensor<B, D, Bool> {
         check!(TensorCheck::binary_ops_ew("NotEqual", &self, &other));
         K::not_equal(self.primitive, other.primitive)
@@ -682,6 +691,7 @@ where
     /// # Panics
     ///
     /// If all tensors don't have the same shape.
+    #[track_caller]
     pub fn cat(tensors: Vec<Self>, dim: usize) -> Self {
         check!(TensorCheck::cat(&tensors, dim));
 
@@ -697,6 +707,7 @@ where
     ///
     /// If all tensors don't have the same shape.
     /// Given dimension is not with range of 0..D2
+    #[track_caller]
     pub fn stack<const D2: usize>(tensors: Vec<Tensor<B, D, K>>, dim: usize) -> Tensor<B, D2, K> {
         check!(TensorCheck::stack(&tensors, dim));
         let tensors = tensors.into_iter().map(|t| t.unsqueeze_dim(dim)).collect();
@@ -712,6 +723,7 @@ where
     /// # Returns
     ///
     /// A tensor iterator.
+    #[track_caller]
     pub fn iter_dim(self, dim: usize) -> DimIter<B, D, K> {
         check!(TensorCheck::dim_ops::<D>("iter_dim", dim));
         DimIter::new(self, dim)
@@ -727,6 +739,7 @@ where
     /// # Returns
     ///
     /// A new tensor with the given dimension narrowed to the given range.
+    #[track_caller]
     pub fn narrow(self, dim: usize, start: usize, length: usize) -> Self {
         check!(TensorCheck::dim_ops::<D>("narrow", dim));
         check!(TensorCheck::narrow(&self, dim, start, length));
@@ -745,6 +758,7 @@ where
     ///
     /// # Returns
     /// A vector of tensors.
+    #[track_caller]
     pub fn chunk(self, chunks: usize, dim: usize) -> Vec<Self> {
         check!(TensorCheck::dim_ops::<D>("chunk", dim));
         chunk::<B, D, K>(self.primitive, chunks, dim)
@@ -819,6 +833,7 @@ where
     ///
     /// If the tensor doesn't have one element.
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    #[track_caller]
     pub fn into_scalar(self) -> K::Elem {
         check!(TensorCheck::into_scalar(&self.shape()));
         let data = self.into_data();
@@ -853,6 +868,7 @@ where
     /// # Returns
     ///
     /// A new tensor with the given shape.
+    #[track_caller]
     pub fn expand<const D2: usize, S: BroadcastArgs<D, D2>>(self, shape: S) -> Tensor<B, D2, K> {
         let shape = shape.into_shape(&self.shape());
         check!(TensorCheck::expand("expand", &self.shape(), &shape,));
@@ -926,13 +942,40 @@ impl<B: Backend, const D: usize, K: BasicOps<B>> DimIter<B, D, K> {
     }
 }
 
-impl<B, const D: usize, K> Tensor<B, D, K>
-where
-    B: Backend,
-    K: BasicOps<B>,
-    <K as BasicOps<B>>::Elem: Debug,
-{
-    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+/// The data of a tensor read back once for display, with the options used to format it.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+struct DisplayData<'a, E> {
+    values: &'a [E],
+    dims: &'a [usize],
+    strides: Vec<usize>,
+    edge_items: usize,
+    precision: Option<usize>,
+    summarize: bool,
+}
+
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+impl<'a, E: Debug + 'static> DisplayData<'a, E> {
+    fn new(
+        values: &'a [E],
+        dims: &'a [usize],
+        print_options: &PrintOptions,
+        precision: Option<usize>,
+    ) -> Self {
+        let mut strides = vec![1; dims.len()];
+        for i in (0..dims.len().saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * dims[i + 1];
+        }
+
+        Self {
+            values,
+            dims,
+            strides,
+            edge_items: print_options.edge_items,
+            precision,
+            summarize: values.len() > print_options.threshold,
+        }
+    }
+
     #[inline]
     fn push_newline_indent(acc: &mut String, indent: usize) {
         acc.push('\n');
@@ -941,7 +984,13 @@ where
         }
     }
 
-    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn push_elem(&self, acc: &mut String, elem: &E) {
+        match (self.precision, float_to_f64(elem)) {
+            (Some(precision), Some(value)) => acc.push_str(&format!("{value:.precision$}")),
+            _ => acc.push_str(&format!("{elem:?}")),
+        }
+    }
+
     fn fmt_inner_tensor(
         &self,
         acc: &mut String,
@@ -955,22 +1004,21 @@ where
                 acc.push_str(", ");
             }
             multi_index[depth] = i;
-            let range: [core::ops::Range<usize>; D] =
-                core::array::from_fn(|i| multi_index[i]..multi_index[i] + 1);
+            let index = multi_index
+                .iter()
+                .zip(self.strides.iter())
+                .map(|(index, stride)| index * stride)
+                .sum::<usize>();
 
-            let elem = &self.clone().slice(range).into_data().value[0];
-            acc.push_str(&format!("{elem:?}"));
+            self.push_elem(acc, &self.values[index]);
         }
     }
 
-    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
     fn fmt_outer_tensor(
         &self,
         acc: &mut String,
         depth: usize,
         multi_index: &mut [usize],
-        print_options: &PrintOptions,
-        summarize: bool,
         range: (usize, usize),
     ) {
         let (start, end) = range;
@@ -981,7 +1029,7 @@ where
             }
             acc.push('[');
             multi_index[depth] = i;
-            self.display_recursive(acc, depth + 1, multi_index, print_options, summarize);
+            self.display_recursive(acc, depth + 1, multi_index);
             acc.push(']');
         }
     }
@@ -998,72 +1046,39 @@ where
     /// * `acc` - A mutable reference to a `String` used as an accumulator for the formatted output.
     /// * `depth` - The current depth of the tensor dimensions being processed.
     /// * `multi_index` - A mutable slice of `usize` representing the current indices in each dimension.
-    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-    fn display_recursive(
-        &self,
-        acc: &mut String,
-        depth: usize,
-        multi_index: &mut [usize],
-        print_options: &PrintOptions,
-        summarize: bool,
-    ) {
-        let edge_items = print_options.edge_items;
+    fn display_recursive(&self, acc: &mut String, depth: usize, multi_index: &mut [usize]) {
+        let edge_items = self.edge_items;
+        let size = self.dims[depth];
 
         if depth == 0 {
             acc.push('[');
         }
 
-        if depth == self.dims().len() - 1 {
+        if depth == self.dims.len() - 1 {
             // if we are at the innermost dimension, just push its elements into the accumulator
-            if summarize && self.dims()[depth] > 2 * edge_items {
+            if self.summarize && size > 2 * edge_items {
                 // print the starting `edge_items` elements
                 self.fmt_inner_tensor(acc, depth, multi_index, (0, edge_items));
                 acc.push_str(", ...");
                 // print the last `edge_items` elements
-                self.fmt_inner_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    (self.dims()[depth] - edge_items, self.dims()[depth]),
-                );
+                self.fmt_inner_tensor(acc, depth, multi_index, (size - edge_items, size));
             } else {
                 // print all the elements
-                self.fmt_inner_tensor(acc, depth, multi_index, (0, self.dims()[depth]));
+                self.fmt_inner_tensor(acc, depth, multi_index, (0, size));
             }
         } else {
             // otherwise, iterate through the current dimension and recursively display the inner tensors
-            if summarize && self.dims()[depth] > 2 * edge_items {
-                self.fmt_outer_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    print_options,
-                    summarize,
-                    (0, edge_items),
-                );
+            if self.summarize && size > 2 * edge_items {
+                self.fmt_outer_tensor(acc, depth, multi_index, (0, edge_items));
 
                 acc.push(',');
                 Self::push_newline_indent(acc, depth + 1);
                 acc.push_str("...");
                 Self::push_newline_indent(acc, depth + 1);
 
-                self.fmt_outer_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    print_options,
-                    summarize,
-                    (self.dims()[depth] - edge_items, self.dims()[depth]),
-                );
+                self.fmt_outer_tensor(acc, depth, multi_index, (size - edge_items, size));
             } else {
-                self.fmt_outer_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    print_options,
-                    summarize,
-                    (0, self.dims()[depth]),
-                );
+                self.fmt_outer_tensor(acc, depth, multi_index, (0, size));
             }
         }
 
@@ -1071,14 +1086,93 @@ where
             acc.push(']');
         }
     }
+
+    /// Formats the minimum, maximum and mean of the elements, or `None` when the elements aren't
+    /// numbers or when there are no elements.
+    fn stats(&self) -> Option<String> {
+        if self.values.is_empty() {
+            return None;
+        }
+
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        let mut sum = 0.0;
+
+        for elem in self.values {
+            let value = elem_to_f64(elem)?;
+
+            min = min.min(value);
+            max = max.max(value);
+            sum += value;
+        }
+
+        let mean = sum / self.values.len() as f64;
+
+        Some(match self.precision {
+            Some(precision) => format!(
+                "{{ min: {min:.precision$}, max: {max:.precision$}, mean: {mean:.precision$} }}"
+            ),
+            None => format!("{{ min: {min:?}, max: {max:?}, mean: {mean:?} }}"),
+        })
+    }
+}
+
+/// Converts the element to `f64` if it's a float.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn float_to_f64<E: 'static>(elem: &E) -> Option<f64> {
+    let elem = elem as &dyn core::any::Any;
+
+    if let Some(elem) = elem.downcast_ref::<f64>() {
+        return Some(*elem);
+    }
+    if let Some(elem) = elem.downcast_ref::<f32>() {
+        return Some(*elem as f64);
+    }
+    if let Some(elem) = elem.downcast_ref::<half::f16>() {
+        return Some(elem.to_f64());
+    }
+    if let Some(elem) = elem.downcast_ref::<half::bf16>() {
+        return Some(elem.to_f64());
+    }
+
+    None
+}
+
+/// Converts the element to `f64` if it's a number.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn elem_to_f64<E: 'static>(elem: &E) -> Option<f64> {
+    macro_rules! downcast_int {
+        ($elem:ident, $($ty:ty),*) => {
+            $(
+                if let Some(elem) = $elem.downcast_ref::<$ty>() {
+                    return Some(*elem as f64);
+                }
+            )*
+        };
+    }
+
+    if let Some(value) = float_to_f64(elem) {
+        return Some(value);
+    }
+
+    let elem = elem as &dyn core::any::Any;
+    downcast_int!(elem, i64, i32, i16, i8, u64, u32, u16, u8);
+
+    None
 }
 
 /// Options for Tensor pretty printing
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PrintOptions {
     /// number of elements to start summarizing tensor
     pub threshold: usize,
     /// number of starting elements and ending elements to display
     pub edge_items: usize,
+    /// number of digits after the decimal point of float elements, overridden by the precision
+    /// of the formatter (e.g. `{:.2}`), they are displayed in full when `None`
+    pub precision: Option<usize>,
+    /// whether to display the minimum, maximum and mean of the summarized tensors
+    pub stats: bool,
 }
 
 static PRINT_OPTS: Mutex<PrintOptions> = Mutex::new(PrintOptions::const_default());
@@ -1089,16 +1183,29 @@ impl PrintOptions {
         Self {
             threshold: 1000,
             edge_items: 3,
+            precision: None,
+            stats: true,
         }
     }
 }
 
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
 /// Set print options
 pub fn set_print_options(options: PrintOptions) {
     *PRINT_OPTS.lock().unwrap() = options
 }
 
 /// Pretty print tensors
+///
+/// The data is read back from the device once, and tensors with more elements than the
+/// [threshold](PrintOptions::threshold) are summarized, showing only the first and last
+/// [edge items](PrintOptions::edge_items) of each dimension along with statistics of all the
+/// elements. See [set_print_options] to configure how tensors are displayed.
 impl<B, const D: usize, K> core::fmt::Display for Tensor<B, D, K>
 where
     B: Backend,
@@ -1111,16 +1218,28 @@ where
 
         #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
         {
-            let po = PRINT_OPTS.lock().unwrap();
+            let po = PRINT_OPTS.lock().unwrap().clone();
+            let data = self.to_data();
+            let display = DisplayData::new(
+                &data.value,
+                &data.shape.dims,
+                &po,
+                f.precision().or(po.precision),
+            );
             let mut acc = String::new();
             let mut multi_index = vec![0; D];
-            let summarize = self.shape().num_elements() > po.threshold;
 
-            self.display_recursive(&mut acc, 0, &mut multi_index, &po, summarize);
+            display.display_recursive(&mut acc, 0, &mut multi_index);
 
             writeln!(f, "  data:")?;
             write!(f, "{acc}")?;
             writeln!(f, ",")?;
+
+            if display.summarize && po.stats {
+                if let Some(stats) = display.stats() {
+                    writeln!(f, "  stats:  {stats},")?;
+                }
+            }
         }
 
         writeln!(f, "  shape:  {:?},", self.dims())?;
@@ -1618,6 +1737,7 @@ impl<B: Backend> BasicOps<B> for Float {
         B::float_shape(tensor)
     }
 
+    #[track_caller]
     fn reshape<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         shape: Shape<D2>,
@@ -1629,6 +1749,7 @@ impl<B: Backend> BasicOps<B> for Float {
         B::float_transpose(tensor)
     }
 
+    #[track_caller]
     fn swap_dims<const D: usize>(
         tensor: Self::Primitive<D>,
         dim1: usize,
@@ -1743,6 +1864,7 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_shape(tensor)
     }
 
+    #[track_caller]
     fn reshape<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         shape: Shape<D2>,
@@ -1754,6 +1876,7 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_transpose(tensor)
     }
 
+    #[track_caller]
     fn swap_dims<const D: usize>(
         tensor: Self::Primitive<D>,
         dim1: usize,
@@ -1868,6 +1991,7 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_shape(tensor)
     }
 
+    #[track_caller]
     fn reshape<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         shape: Shape<D2>,
@@ -1879,6 +2003,7 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_transpose(tensor)
     }
 
+    #[track_caller]
     fn swap_dims<const D: usize>(
         tensor: Self::Primitive<D>,
         dim1: usize,
@@ -1993,6 +2118,7 @@ pub trait ReshapeArgs<const D2: usize> {
 }
 
 impl<const D2: usize> ReshapeArgs<D2> for Shape<D2> {
+    #[track_caller]
     fn into_shape<B: Backend, const D: usize, K: BasicOps<B>>(
         self,
         tensor: &Tensor<B, D, K>,
@@ -2003,6 +2129,7 @@ impl<const D2: usize> ReshapeArgs<D2> for Shape<D2> {
     }
 }
 impl<const D2: usize> ReshapeArgs<D2> for [usize; D2] {
+    #[track_caller]
     fn into_shape<B: Backend, const D: usize, K: BasicOps<B>>(
         self,
         tensor: &Tensor<B, D, K>,
@@ -2016,6 +2143,7 @@ impl<const D2: usize> ReshapeArgs<D2> for [usize; D2] {
 }
 
 impl<const D2: usize> ReshapeArgs<D2> for [i32; D2] {
+    #[track_caller]
     fn into_shape<B: Backend, const D: usize, K: BasicOps<B>>(
         self,
         tensor: &Tensor<B, D, K>,
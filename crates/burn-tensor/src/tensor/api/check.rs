@@ -1,9 +1,10 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use crate::{backend::Backend, BasicOps, Bool, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
 use alloc::vec::Vec;
 use core::ops::Range;
+use core::panic::Location;
 
 /// The struct should always be used with the [check](crate::check) macro.
 ///
@@ -49,6 +50,28 @@ impl TensorCheck {
             .binary_ops_ew_shape(ops, &lhs.shape(), &rhs.shape())
     }
 
+    /// Checks device and shape compatibility of the mask and value tensors for the mask where
+    /// operation.
+    pub(crate) fn mask_where<B: Backend, const D: usize, K: BasicOps<B>>(
+        tensor: &Tensor<B, D, K>,
+        mask: &Tensor<B, D, Bool>,
+        value: &Tensor<B, D, K>,
+    ) -> Self {
+        Self::binary_ops_ew("Mask Where", tensor, value)
+            .binary_ops_device("Mask Where", &tensor.device(), &mask.device())
+            .binary_ops_ew_shape("Mask Where", &tensor.shape(), &mask.shape())
+    }
+
+    /// Checks device and shape compatibility of the mask for the mask fill operation.
+    pub(crate) fn mask_fill<B: Backend, const D: usize, K: BasicOps<B>>(
+        tensor: &Tensor<B, D, K>,
+        mask: &Tensor<B, D, Bool>,
+    ) -> Self {
+        Self::Ok
+            .binary_ops_device("Mask Fill", &tensor.device(), &mask.device())
+            .binary_ops_ew_shape("Mask Fill", &tensor.shape(), &mask.shape())
+    }
+
     pub(crate) fn into_scalar<const D: usize>(shape: &Shape<D>) -> Self {
         let mut check = Self::Ok;
 
@@ -863,6 +886,7 @@ impl TensorCheck {
         Self::Failed(FailedTensorCheck {
             ops: ops.to_string(),
             errors,
+            location: None,
         })
     }
 
@@ -969,19 +993,180 @@ impl TensorCheck {
 
         check
     }
+
+    /// Checks if a dimension with the given name exists.
+    #[cfg(feature = "experimental-named-tensor")]
+    pub(crate) fn named_dim(ops: &str, names: &[String], name: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if !names.iter().any(|current| current == name) {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The tensor doesn't have a dimension named '{name}'."
+                ))
+                .details(format!("Tensor dimensions {names:?}.")),
+            );
+        }
+
+        check
+    }
+
+    /// Checks if named shapes are compatible for element wise operations supporting broadcasting.
+    #[cfg(feature = "experimental-named-tensor")]
+    pub(crate) fn named_binary_ops_ew<const D: usize>(
+        ops: &str,
+        names: &[String],
+        lhs: &Shape<D>,
+        rhs: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for i in 0..D {
+            let d_lhs = lhs.dims[i];
+            let d_rhs = rhs.dims[i];
+
+            if d_lhs != d_rhs && d_lhs != 1 && d_rhs != 1 {
+                check = check.register(
+                    ops,
+                    TensorError::new("The provided tensors have incompatible shapes.").details(
+                        format!(
+                            "Incompatible size at dimension '{}' => '{} != {}', which can't be \
+                             broadcasted. Lhs tensor shape {}, Rhs tensor shape {}.",
+                            names[i],
+                            d_lhs,
+                            d_rhs,
+                            format_named_shape(names, lhs),
+                            format_named_shape(names, rhs),
+                        ),
+                    ),
+                );
+            }
+        }
+
+        check
+    }
+
+    /// Checks if the inner dimension of a named matmul is the same for both tensors.
+    #[cfg(feature = "experimental-named-tensor")]
+    pub(crate) fn named_matmul<const D: usize>(
+        names_lhs: &[String],
+        lhs: &Shape<D>,
+        names_rhs: &[String],
+        rhs: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            return check;
+        }
+
+        let dim_lhs = lhs.dims[D - 1];
+        let dim_rhs = rhs.dims[D - 2];
+
+        if dim_lhs != dim_rhs {
+            check = check.register(
+                "Matmul",
+                TensorError::new(format!(
+                    "The inner dimension '{}' of matmul should be the same, but got {dim_lhs} \
+                     and {dim_rhs}.",
+                    names_lhs[D - 1]
+                ))
+                .details(format!(
+                    "Lhs shape {}, rhs shape {}.",
+                    format_named_shape(names_lhs, lhs),
+                    format_named_shape(names_rhs, rhs),
+                )),
+            );
+        }
+
+        check
+    }
+}
+
+/// Formats a shape with the name of each dimension, e.g. `[Batch: 2, Channels: 3]`.
+#[cfg(feature = "experimental-named-tensor")]
+fn format_named_shape<const D: usize>(names: &[String], shape: &Shape<D>) -> String {
+    let dims = names
+        .iter()
+        .zip(shape.dims.iter())
+        .map(|(name, size)| format!("{name}: {size}"))
+        .collect::<Vec<_>>();
+
+    format!("[{}]", dims.join(", "))
 }
 
 pub(crate) struct FailedTensorCheck {
     ops: String,
     errors: Vec<TensorError>,
+    location: Option<&'static Location<'static>>,
+}
+
+/// Error returned by the fallible tensor operations, such as [try_add](Tensor::try_add), when the
+/// arguments of the operation are invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TensorOpError {
+    /// The name of the operation.
+    pub operation: String,
+    /// The reasons why the operation is invalid.
+    pub reasons: Vec<String>,
+}
+
+impl core::fmt::Display for TensorOpError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "=== Tensor Operation Error ===\n  Operation: '{}'\n  Reason:",
+            self.operation
+        )?;
+
+        for (number, reason) in self.reasons.iter().enumerate() {
+            write!(f, "\n    {}. {reason}", number + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TensorOpError {}
+
+impl From<FailedTensorCheck> for TensorOpError {
+    fn from(check: FailedTensorCheck) -> Self {
+        Self {
+            operation: check.ops,
+            reasons: check.errors.into_iter().map(TensorError::reason).collect(),
+        }
+    }
+}
+
+impl TensorCheck {
+    /// Converts the check into a result, for the fallible operations.
+    pub(crate) fn into_result(self) -> Result<(), TensorOpError> {
+        match self {
+            Self::Ok => Ok(()),
+            Self::Failed(check) => Err(check.into()),
+        }
+    }
 }
 
 impl FailedTensorCheck {
+    /// Register the location of the user code calling the failed operation.
+    pub(crate) fn location(mut self, location: &'static Location<'static>) -> Self {
+        self.location = Some(location);
+        self
+    }
+
     /// Format all the checks into a single message ready to be printed by a [panic](core::panic).
     pub(crate) fn format(self) -> String {
+        let location = match self.location {
+            Some(location) => format!("\n  Location: {location}"),
+            None => String::new(),
+        };
+
         self.errors.into_iter().enumerate().fold(
             format!(
-                "=== Tensor Operation Error ===\n  Operation: '{}'\n  Reason:",
+                "=== Tensor Operation Error ===\n  Operation: '{}'{location}\n  Reason:",
                 self.ops
             ),
             |accum, (number, error)| accum + error.format(number + 1).as_str(),
@@ -1019,6 +1204,13 @@ impl TensorError {
 
         message
     }
+
+    fn reason(self) -> String {
+        match self.details {
+            Some(details) => format!("{} {details}", self.description),
+            None => self.description,
+        }
+    }
 }
 
 /// Module where we defined macros that can be used only in the project.
@@ -1026,9 +1218,15 @@ pub(crate) mod macros {
     /// We use a macro for all checks, since the panic message file and line number will match the
     /// function that does the check instead of a the generic error.rs crate private unrelated file
     /// and line number.
+    ///
+    /// In debug builds, the message includes the location of the user code calling the
+    /// operation, which is tracked through the functions annotated with `#[track_caller]`.
     macro_rules! check {
         ($check:expr) => {
             if let TensorCheck::Failed(check) = $check {
+                #[cfg(debug_assertions)]
+                let check = check.location(core::panic::Location::caller());
+
                 core::panic!("{}", check.format());
             }
         };
@@ -1050,6 +1248,16 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Location: ")]
+    fn failed_check_reports_location() {
+        check!(TensorCheck::reshape_args_usize(
+            &Shape::new([2, 2]),
+            &Shape::new([1, 3])
+        ));
+    }
+
     #[test]
     fn reshape_valid_shape() {
         check!(TensorCheck::reshape_args_usize(
@@ -1094,6 +1302,29 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[cfg(feature = "experimental-named-tensor")]
+    #[should_panic(expected = "Incompatible size at dimension 'Channels'")]
+    fn named_binary_ops_shapes_no_broadcast() {
+        check!(TensorCheck::named_binary_ops_ew(
+            "TestOps",
+            &["Batch".to_string(), "Channels".to_string()],
+            &Shape::new([3, 5]),
+            &Shape::new([3, 6])
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "experimental-named-tensor")]
+    #[should_panic(expected = "doesn't have a dimension named 'Height'")]
+    fn named_dim_missing() {
+        check!(TensorCheck::named_dim(
+            "TestOps",
+            &["Batch".to_string(), "Channels".to_string()],
+            "Height"
+        ));
+    }
+
     #[test]
     #[should_panic]
     fn binary_ops_devices() {
@@ -0,0 +1,208 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::check::{TensorCheck, TensorOpError};
+use crate::{
+    backend::Backend, BasicOps, Bool, Element, ElementConversion, Int, Numeric, Shape, Tensor,
+};
+
+/// Fallible versions of the operations that validate their arguments, returning an error instead
+/// of panicking when the arguments are invalid.
+///
+/// Only the arguments are validated, so errors raised by the backend while executing the
+/// operation, such as running out of memory, still panic.
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Fallible version of [reshape](Tensor::reshape).
+    pub fn try_reshape<const D2: usize, S: Into<Shape<D2>>>(
+        self,
+        shape: S,
+    ) -> Result<Tensor<B, D2, K>, TensorOpError> {
+        let shape = shape.into();
+        TensorCheck::reshape_args_usize(&self.shape(), &shape).into_result()?;
+
+        Ok(self.reshape(shape))
+    }
+
+    /// Fallible version of [squeeze](Tensor::squeeze).
+    pub fn try_squeeze<const D2: usize>(
+        self,
+        dim: usize,
+    ) -> Result<Tensor<B, D2, K>, TensorOpError> {
+        TensorCheck::squeeze::<D2>(dim, &self.shape().dims).into_result()?;
+
+        Ok(self.squeeze(dim))
+    }
+
+    /// Fallible version of [unsqueeze_dim](Tensor::unsqueeze_dim).
+    pub fn try_unsqueeze_dim<const D2: usize>(
+        self,
+        dim: usize,
+    ) -> Result<Tensor<B, D2, K>, TensorOpError> {
+        TensorCheck::unsqueeze_dim::<D>(dim).into_result()?;
+
+        Ok(self.unsqueeze_dim(dim))
+    }
+
+    /// Fallible version of [slice](Tensor::slice).
+    pub fn try_slice<const D2: usize>(
+        self,
+        ranges: [Range<usize>; D2],
+    ) -> Result<Self, TensorOpError> {
+        TensorCheck::slice(&self.shape(), &ranges).into_result()?;
+
+        Ok(self.slice(ranges))
+    }
+
+    /// Fallible version of [slice_assign](Tensor::slice_assign).
+    pub fn try_slice_assign<const D2: usize>(
+        self,
+        ranges: [Range<usize>; D2],
+        values: Self,
+    ) -> Result<Self, TensorOpError> {
+        TensorCheck::slice_assign(&self.shape(), &values.shape(), &ranges).into_result()?;
+
+        Ok(self.slice_assign(ranges, values))
+    }
+
+    /// Fallible version of [cat](Tensor::cat).
+    pub fn try_cat(tensors: Vec<Self>, dim: usize) -> Result<Self, TensorOpError> {
+        TensorCheck::cat(&tensors, dim).into_result()?;
+
+        Ok(Self::cat(tensors, dim))
+    }
+
+    /// Fallible version of [stack](Tensor::stack).
+    pub fn try_stack<const D2: usize>(
+        tensors: Vec<Self>,
+        dim: usize,
+    ) -> Result<Tensor<B, D2, K>, TensorOpError> {
+        TensorCheck::stack(&tensors, dim).into_result()?;
+
+        Ok(Self::stack(tensors, dim))
+    }
+
+    /// Fallible version of [narrow](Tensor::narrow).
+    pub fn try_narrow(
+        self,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> Result<Self, TensorOpError> {
+        TensorCheck::dim_ops::<D>("narrow", dim).into_result()?;
+        TensorCheck::narrow(&self, dim, start, length).into_result()?;
+
+        Ok(self.narrow(dim, start, length))
+    }
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Fallible version of [add](Tensor::add).
+    pub fn try_add(self, other: Self) -> Result<Self, TensorOpError> {
+        TensorCheck::binary_ops_ew("Add", &self, &other).into_result()?;
+
+        Ok(self.add(other))
+    }
+
+    /// Fallible version of [sub](Tensor::sub).
+    pub fn try_sub(self, other: Self) -> Result<Self, TensorOpError> {
+        TensorCheck::binary_ops_ew("Sub", &self, &other).into_result()?;
+
+        Ok(self.sub(other))
+    }
+
+    /// Fallible version of [mul](Tensor::mul).
+    pub fn try_mul(self, other: Self) -> Result<Self, TensorOpError> {
+        TensorCheck::binary_ops_ew("Mul", &self, &other).into_result()?;
+
+        Ok(self.mul(other))
+    }
+
+    /// Fallible version of [div](Tensor::div).
+    pub fn try_div(self, other: Self) -> Result<Self, TensorOpError> {
+        TensorCheck::binary_ops_ew("Div", &self, &other).into_result()?;
+
+        Ok(self.div(other))
+    }
+
+    /// Fallible version of [mask_where](Tensor::mask_where).
+    pub fn try_mask_where(
+        self,
+        mask: Tensor<B, D, Bool>,
+        value: Self,
+    ) -> Result<Self, TensorOpError> {
+        TensorCheck::mask_where(&self, &mask, &value).into_result()?;
+
+        Ok(self.mask_where(mask, value))
+    }
+
+    /// Fallible version of [mask_fill](Tensor::mask_fill).
+    pub fn try_mask_fill<E: ElementConversion>(
+        self,
+        mask: Tensor<B, D, Bool>,
+        value: E,
+    ) -> Result<Self, TensorOpError> {
+        TensorCheck::mask_fill(&self, &mask).into_result()?;
+
+        Ok(self.mask_fill(mask, value))
+    }
+
+    /// Fallible version of [gather](Tensor::gather).
+    pub fn try_gather(self, dim: usize, indices: Tensor<B, D, Int>) -> Result<Self, TensorOpError> {
+        TensorCheck::gather::<D>(dim, &self.shape(), &indices.shape()).into_result()?;
+
+        Ok(self.gather(dim, indices))
+    }
+
+    /// Fallible version of [scatter](Tensor::scatter).
+    pub fn try_scatter(
+        self,
+        dim: usize,
+        indices: Tensor<B, D, Int>,
+        values: Self,
+    ) -> Result<Self, TensorOpError> {
+        TensorCheck::scatter::<D>(dim, &self.shape(), &indices.shape(), &values.shape())
+            .into_result()?;
+
+        Ok(self.scatter(dim, indices, values))
+    }
+
+    /// Fallible version of [select](Tensor::select).
+    pub fn try_select(self, dim: usize, indices: Tensor<B, 1, Int>) -> Result<Self, TensorOpError> {
+        TensorCheck::select::<D>(dim).into_result()?;
+
+        Ok(self.select(dim, indices))
+    }
+
+    /// Fallible version of [select_assign](Tensor::select_assign).
+    pub fn try_select_assign(
+        self,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self,
+    ) -> Result<Self, TensorOpError> {
+        TensorCheck::select_assign::<D>(dim).into_result()?;
+
+        Ok(self.select_assign(dim, indices, values))
+    }
+}
+
+impl<B, const D: usize> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Fallible version of [matmul](Tensor::matmul).
+    pub fn try_matmul(self, other: Self) -> Result<Self, TensorOpError> {
+        TensorCheck::matmul(&self, &other).into_result()?;
+
+        Ok(self.matmul(other))
+    }
+}
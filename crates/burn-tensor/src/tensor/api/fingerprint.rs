@@ -0,0 +1,173 @@
+use crate::{
+    backend::Backend, ops::IntElem, Bool, DType, Data, Element, ElementConversion, Int, Shape,
+    Tensor,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The number of bytes reduced together on the device.
+const CHUNK_SIZE: usize = 256;
+
+/// The number of weighted sums computed for each chunk.
+const NUM_SUMS: usize = 4;
+
+/// The largest prime below 2^23, so that the product of a byte with a weight, and the sum of the
+/// remainders of a chunk, fit in a 32-bit integer.
+const MODULUS: i64 = 8_388_593;
+
+/// The number of values of a byte.
+const BYTE: i64 = 256;
+
+const PRIME_1: u64 = 0x9E37_79B1_85EB_CA87;
+const PRIME_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const PRIME_3: u64 = 0x1656_67B1_9E37_79F9;
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Returns a hash of the shape and the values of the tensor, e.g. as the key of a cache, to
+    /// find duplicated tensors or to check cheaply that a computation didn't change.
+    ///
+    /// The bit patterns of the elements are split into bytes on the device, see
+    /// [float_bits](crate::ops::FloatTensorOps::float_bits), which are reduced by chunks of 256
+    /// bytes with integer weighted sums modulo a prime, the weights depending on the position of
+    /// each byte in its chunk. Only the sums of the chunks are read back, then hashed with the
+    /// shape on the host with the rounds of [xxHash64](https://github.com/Cyan4973/xxHash).
+    ///
+    /// The reduction being exact, the fingerprint only depends on the bits of the elements, so it
+    /// is the same for all the backends with the same element type. A change of a single byte,
+    /// e.g. of the last bit of a value or of the sign of a zero, always changes the sums of its
+    /// chunk.
+    pub fn fingerprint(&self) -> u64 {
+        fingerprint(&self.dims(), 0, || {
+            Tensor::new(B::float_bits(self.clone().into_primitive()))
+        })
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D, Int> {
+    /// Returns a hash of the shape and the values of the integer tensor.
+    ///
+    /// The integers are split into bytes on the device. See [fingerprint](Tensor::fingerprint) of
+    /// float tensors for more information.
+    pub fn fingerprint(&self) -> u64 {
+        let num_bytes = core::mem::size_of::<IntElem<B>>();
+        let num_elements = self.shape().num_elements();
+
+        fingerprint(&self.dims(), 1, || {
+            // The remainder being positive, the value minus its remainder is a multiple of 256, so
+            // the division is exact, even for negative values.
+            let mut values = self.clone().reshape([num_elements, 1]);
+            let mut bytes = Vec::with_capacity(num_bytes);
+            for _ in 0..num_bytes {
+                let byte = values.clone().remainder_scalar(BYTE);
+                values = values.sub(byte.clone()).div_scalar(BYTE);
+                bytes.push(byte);
+            }
+
+            Tensor::cat(bytes, 1)
+        })
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D, Bool> {
+    /// Returns a hash of the shape and the values of the boolean tensor.
+    ///
+    /// See [fingerprint](Tensor::fingerprint) of float tensors for more information.
+    pub fn fingerprint(&self) -> u64 {
+        let num_elements = self.shape().num_elements();
+
+        fingerprint(&self.dims(), 2, || {
+            self.clone().int().reshape([num_elements, 1])
+        })
+    }
+}
+
+/// Hashes the shape of a tensor and the bytes of its elements, of shape
+/// `[num_elements, num_bytes]`, which are only computed when the tensor isn't empty.
+fn fingerprint<B: Backend, F>(dims: &[usize], seed: u64, bytes: F) -> u64
+where
+    F: FnOnce() -> Tensor<B, 2, Int>,
+{
+    assert!(
+        matches!(
+            IntElem::<B>::dtype(),
+            DType::I64 | DType::I32 | DType::U64 | DType::U32
+        ),
+        "The fingerprint is computed with integers of at least 32 bits."
+    );
+
+    let mut hash = dims
+        .iter()
+        .fold(round(seed, dims.len() as u64), |hash, dim| {
+            round(hash, *dim as u64)
+        });
+
+    if dims.iter().product::<usize>() == 0 {
+        return avalanche(hash);
+    }
+
+    let bytes = bytes();
+    let [num_elements, num_bytes] = bytes.dims();
+    hash = round(hash, num_bytes as u64);
+    let num_bytes = num_elements * num_bytes;
+
+    let device = bytes.device();
+    let chunk_size = CHUNK_SIZE.min(num_bytes);
+    let num_chunks = num_bytes.div_ceil(chunk_size);
+    let padding = num_chunks * chunk_size - num_bytes;
+
+    let mut bytes = bytes.reshape([num_bytes]);
+    if padding > 0 {
+        bytes = Tensor::cat(vec![bytes, Tensor::zeros([padding], &device)], 0);
+    }
+    let bytes = bytes.reshape([num_chunks, 1, chunk_size]);
+
+    let weights = Tensor::<B, 2, Int>::from_data(
+        Data::new(
+            weights::<IntElem<B>>(chunk_size),
+            Shape::new([NUM_SUMS, chunk_size]),
+        ),
+        &device,
+    )
+    .reshape([1, NUM_SUMS, chunk_size]);
+
+    // The modulus being a prime larger than the difference of two bytes, changing a byte always
+    // changes the remainder of its product with a weight.
+    let sums = bytes.mul(weights).remainder_scalar(MODULUS).sum_dim(2);
+    for sum in sums.into_data().value {
+        hash = round(hash, sum.elem::<i64>() as u64);
+    }
+
+    avalanche(hash)
+}
+
+/// Returns the weights of the sums of the chunks, in `[1, MODULUS)`, generated with
+/// [SplitMix64](https://prng.di.unimi.it/splitmix64.c) so that they are the same for all the
+/// backends.
+fn weights<E: Element>(chunk_size: usize) -> Vec<E> {
+    let mut state = 0u64;
+    (0..NUM_SUMS * chunk_size)
+        .map(|_| {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut value = state;
+            value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            value ^= value >> 31;
+
+            (1 + (value % (MODULUS as u64 - 1)) as i64).elem()
+        })
+        .collect()
+}
+
+fn round(hash: u64, value: u64) -> u64 {
+    hash.wrapping_add(value.wrapping_mul(PRIME_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME_1)
+}
+
+fn avalanche(mut hash: u64) -> u64 {
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(PRIME_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(PRIME_3);
+    hash ^ (hash >> 32)
+}
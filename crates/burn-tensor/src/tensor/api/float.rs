@@ -3,12 +3,12 @@ use core::convert::TryInto;
 
 use crate::check;
 use crate::check::TensorCheck;
-use crate::ops::FullPrecisionBackend;
+use crate::ops::{FullPrecisionBackend, ScatterReduction};
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
-use crate::tensor::{Data, Distribution, Shape};
-use crate::Int;
+use crate::tensor::{Data, Distribution, Generator, Shape};
 use crate::Tensor;
+use crate::{Bool, Int};
 
 #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
 use crate::{argsort, sort, sort_with_indices, Float};
@@ -88,6 +88,17 @@ where
         Self::new(B::float_tanh(self.primitive))
     }
 
+    /// Returns a boolean tensor indicating whether each element is NaN.
+    pub fn is_nan(&self) -> Tensor<B, D, Bool> {
+        // NaN is the only value not equal to itself.
+        self.clone().not_equal(self.clone())
+    }
+
+    /// Returns a boolean tensor indicating whether each element is positive or negative infinity.
+    pub fn is_inf(&self) -> Tensor<B, D, Bool> {
+        self.clone().abs().equal_elem(f64::INFINITY)
+    }
+
     /// Create a tensor from floats (f32) on a given device.
     ///
     /// # Example
@@ -141,6 +152,12 @@ where
         Tensor::new(B::float_random(self.shape(), distribution, &self.device()))
     }
 
+    /// Returns a new tensor with the same shape and device as the current tensor filled random
+    /// values sampled from the given distribution with the given [generator](Generator).
+    pub fn random_like_with(&self, distribution: Distribution, generator: &mut Generator) -> Self {
+        Tensor::random_with(self.shape(), distribution, generator, &self.device())
+    }
+
     /// Create a one hot tensor.
     ///
     /// # Example
@@ -156,6 +173,7 @@ where
     ///     // [0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]
     /// }
     /// ```
+    #[track_caller]
     pub fn one_hot(index: usize, num_classes: usize, device: &B::Device) -> Self {
         check!(TensorCheck::one_hot(index, num_classes));
 
@@ -177,6 +195,7 @@ where
     /// # Panics
     ///
     /// If the two tensors dont' have a compatible shape.
+    #[track_caller]
     pub fn matmul(self, other: Self) -> Self {
         check!(TensorCheck::matmul(&self, &other));
         Self::new(B::float_matmul(self.primitive, other.primitive))
@@ -250,6 +269,82 @@ where
         Self::new(B::relu(self.primitive))
     }
 
+    /// Reduce the values into the tensor at the given indices along the specified dimension.
+    ///
+    /// Example using a 3D tensor and the [sum](ScatterReduction::Sum) reduction:
+    ///
+    /// `input[indices[i, j, k], j, k] += values[i, j, k]; // dim = 0`
+    /// `input[i, indices[i, j, k], k] += values[i, j, k]; // dim = 1`
+    /// `input[i, j, indices[i, j, k]] += values[i, j, k]; // dim = 2`
+    ///
+    /// # Notes
+    ///
+    /// The elements of the tensor are part of the reduction, so the elements receiving no value
+    /// are left unchanged and the [mean](ScatterReduction::Mean) counts the original element.
+    ///
+    /// The index tensor should have the same shape as the original tensor except for the specified
+    /// dimension. The value and index tensors should have the same shape.
+    ///
+    /// The values scattered to the same position are reduced in the order of the indices, so the
+    /// result is deterministic. See [scatter_reduce_atomic](Tensor::scatter_reduce_atomic) for a
+    /// faster version on the backends supporting atomic operations.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    #[track_caller]
+    pub fn scatter_reduce(
+        self,
+        dim: usize,
+        indices: Tensor<B, D, Int>,
+        values: Self,
+        reduction: ScatterReduction,
+    ) -> Self {
+        check!(TensorCheck::scatter::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self::new(B::float_scatter_reduce(
+            dim,
+            self.primitive,
+            indices.primitive,
+            values.primitive,
+            reduction,
+            true,
+        ))
+    }
+
+    /// Reduce the values into the tensor at the given indices along the specified dimension, like
+    /// [scatter_reduce](Tensor::scatter_reduce), letting the backend reduce the values scattered
+    /// to the same position in any order, e.g. with atomic operations.
+    ///
+    /// The rounding of the sums and means can change between executions.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    #[track_caller]
+    pub fn scatter_reduce_atomic(
+        self,
+        dim: usize,
+        indices: Tensor<B, D, Int>,
+        values: Self,
+        reduction: ScatterReduction,
+    ) -> Self {
+        check!(TensorCheck::scatter::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self::new(B::float_scatter_reduce(
+            dim,
+            self.primitive,
+            indices.primitive,
+            values.primitive,
+            reduction,
+            false,
+        ))
+    }
+
     /// Calculate covaraince matrix between different entries alongside a given dimension.
     ///
     /// # Arguments
@@ -268,7 +363,7 @@ where
 
     /// Sort the elements by value in ascending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn sort(self, dim: usize) -> Tensor<B, D> {
         Tensor::new(sort::<B, D, Float>(self.primitive, dim, /*descending*/ false).await)
@@ -276,7 +371,7 @@ where
 
     /// Sort the elements by value in descending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn sort_descending(self, dim: usize) -> Tensor<B, D> {
         Tensor::new(sort::<B, D, Float>(self.primitive, dim, /*descending*/ true).await)
@@ -285,7 +380,7 @@ where
     /// Sort the elements by value in ascending order along a given dimension.
     /// Also returns the indices.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn sort_with_indices(self, dim: usize) -> (Tensor<B, D>, Tensor<B, D, Int>) {
         check!(TensorCheck::sort_dim::<D>("Sort_with_indices", dim));
@@ -297,7 +392,7 @@ where
     /// Sort the elements by value in descending order along a given dimension.
     /// Also returns the indices.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn sort_descending_with_indices(
         self,
@@ -311,7 +406,7 @@ where
 
     /// Returns the indices that sort the elements by value in ascending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn argsort(self, dim: usize) -> Tensor<B, D, Int> {
         check!(TensorCheck::sort_dim::<D>("Argsort", dim));
@@ -320,7 +415,7 @@ where
 
     /// Returns the indices that sort the elements by value in descending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn argsort_descending(self, dim: usize) -> Tensor<B, D, Int> {
         check!(TensorCheck::sort_dim::<D>("Argsort", dim));
@@ -103,7 +103,7 @@ where
 
     /// Sort the elements by value in ascending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn sort(self, dim: usize) -> Tensor<B, D, Int> {
         Tensor::new(sort::<B, D, Int>(self.primitive, dim, /* descending */ false).await)
@@ -111,7 +111,7 @@ where
 
     /// Sort the elements by value in descending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn sort_descending(self, dim: usize) -> Tensor<B, D, Int> {
         Tensor::new(sort::<B, D, Int>(self.primitive, dim, /* descending */ true).await)
@@ -120,7 +120,7 @@ where
     /// Sort the elements by value in ascending order along a given dimension.
     /// Also returns the indices.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn sort_with_indices(self, dim: usize) -> (Tensor<B, D, Int>, Tensor<B, D, Int>) {
         check!(TensorCheck::sort_dim::<D>("Sort_with_indices", dim));
@@ -132,7 +132,7 @@ where
     /// Sort the elements by value in descending order along a given dimension.
     /// Also returns the indices.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn sort_descending_with_indices(
         self,
@@ -146,7 +146,7 @@ where
 
     /// Returns the indices that sort the elements by value in ascending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn argsort(self, dim: usize) -> Tensor<B, D, Int> {
         check!(TensorCheck::sort_dim::<D>("Argsort", dim));
@@ -155,7 +155,7 @@ where
 
     /// Returns the indices that sort the elements by value in descending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     pub async fn argsort_descending(self, dim: usize) -> Tensor<B, D, Int> {
         check!(TensorCheck::sort_dim::<D>("Argsort", dim));
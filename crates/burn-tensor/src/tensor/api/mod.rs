@@ -6,19 +6,27 @@ mod base;
 mod bool;
 mod cartesian_grid;
 mod chunk;
+mod fallible;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+mod fingerprint;
 mod float;
 mod int;
 mod kind;
 mod narrow;
 mod numeric;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+mod scatter_reduce;
 mod sort;
 
 pub use argwhere::argwhere;
 pub use autodiff::*;
 pub use base::*;
 pub use cartesian_grid::cartesian_grid;
+pub use check::TensorOpError;
 pub use chunk::chunk;
 pub use kind::*;
 pub use narrow::narrow;
 pub use numeric::*;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub use scatter_reduce::scatter_reduce;
 pub use sort::{argsort, sort, sort_with_indices};
@@ -3,11 +3,14 @@ use alloc::vec::Vec;
 use crate::alloc::borrow::ToOwned;
 
 use crate::{
-    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Distribution, Element,
-    ElementConversion, Float, Int, Shape, Tensor, TensorKind,
+    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Data, Distribution, Element,
+    ElementConversion, Float, Generator, Int, Shape, Tensor, TensorKind,
 };
 use num_traits::Zero;
 
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use crate::ops::ScatterReduction;
+
 impl<B, const D: usize, K> Tensor<B, D, K>
 where
     B: Backend,
@@ -18,6 +21,7 @@ where
     ///
     /// `y = x2 + x1`
     #[allow(clippy::should_implement_trait)]
+    #[track_caller]
     pub fn add(self, other: Self) -> Self {
         check!(TensorCheck::binary_ops_ew("Add", &self, &other));
         Self::new(K::add(self.primitive, other.primitive))
@@ -34,6 +38,7 @@ where
     ///
     /// `y = x2 - x1`
     #[allow(clippy::should_implement_trait)]
+    #[track_caller]
     pub fn sub(self, other: Self) -> Self {
         check!(TensorCheck::binary_ops_ew("Sub", &self, &other));
         Self::new(K::sub(self.primitive, other.primitive))
@@ -50,6 +55,7 @@ where
     ///
     /// `y = x2 / x1`
     #[allow(clippy::should_implement_trait)]
+    #[track_caller]
     pub fn div(self, other: Self) -> Self {
         check!(TensorCheck::binary_ops_ew("Div", &self, &other));
         Self::new(K::div(self.primitive, other.primitive))
@@ -74,6 +80,7 @@ where
     ///
     /// `y = x2 * x1`
     #[allow(clippy::should_implement_trait)]
+    #[track_caller]
     pub fn mul(self, other: Self) -> Self {
         check!(TensorCheck::binary_ops_ew("Mul", &self, &other));
         Self::new(K::mul(self.primitive, other.primitive))
@@ -130,6 +137,7 @@ where
 
     /// Aggregate all elements along the given *dimension* or *axis*
     /// in the tensor with the mean operation.
+    #[track_caller]
     pub fn mean_dim(self, dim: usize) -> Self {
         check!(TensorCheck::aggregate_dim::<D>("Mean", dim));
         Self::new(K::mean_dim(self.primitive, dim))
@@ -137,6 +145,7 @@ where
 
     /// Aggregate all elements along the given *dimension* or *axis*
     /// in the tensor with the sum operation.
+    #[track_caller]
     pub fn sum_dim(self, dim: usize) -> Self {
         check!(TensorCheck::aggregate_dim::<D>("Sum", dim));
         Self::new(K::sum_dim(self.primitive, dim))
@@ -150,6 +159,7 @@ where
 
     /// Aggregate all elements along the given *dimension* or *axis*
     /// in the tensor with the product operation.
+    #[track_caller]
     pub fn prod_dim(self, dim: usize) -> Self {
         check!(TensorCheck::aggregate_dim::<D>("Prod", dim));
         Self::new(K::prod_dim(self.primitive, dim))
@@ -170,6 +180,7 @@ where
     /// # Panics
     ///
     /// If the two tensors don't have the same shape.
+    #[track_caller]
     pub fn greater(self, other: Self) -> Tensor<B, D, Bool> {
         check!(TensorCheck::binary_ops_ew("Greater", &self, &other));
         K::greater(self.primitive, other.primitive)
@@ -180,6 +191,7 @@ where
     /// # Panics
     ///
     /// If the two tensors don't have the same shape.
+    #[track_caller]
     pub fn greater_equal(self, other: Self) -> Tensor<B, D, Bool> {
         check!(TensorCheck::binary_ops_ew("Greater_equal", &self, &other));
         K::greater_equal(self.primitive, other.primitive)
@@ -190,6 +202,7 @@ where
     /// # Panics
     ///
     /// If the two tensors don't have the same shape.
+    #[track_caller]
     pub fn lower(self, other: Self) -> Tensor<B, D, Bool> {
         check!(TensorCheck::binary_ops_ew("Lower", &self, &other));
         K::lower(self.primitive, other.primitive)
@@ -200,6 +213,7 @@ where
     /// # Panics
     ///
     /// If the two tensors don't have the same shape.
+    #[track_caller]
     pub fn lower_equal(self, other: Self) -> Tensor<B, D, Bool> {
         check!(TensorCheck::binary_ops_ew("Lower_equal", &self, &other));
         K::lower_equal(self.primitive, other.primitive)
@@ -229,7 +243,9 @@ where
     ///
     /// This is similar to [mask_fill](Tensor::mask_fill), however the value is a tensor instead of
     /// a scalar.
+    #[track_caller]
     pub fn mask_where(self, mask: Tensor<B, D, Bool>, value: Self) -> Self {
+        check!(TensorCheck::mask_where(&self, &mask, &value));
         Self::new(K::mask_where(self.primitive, mask, value.primitive))
     }
 
@@ -237,7 +253,9 @@ where
     ///
     /// This is similar to [mask_where](Tensor::mask_where), however the value is a scalar instead of
     /// a tensor.
+    #[track_caller]
     pub fn mask_fill<E: ElementConversion>(self, mask: Tensor<B, D, Bool>, value: E) -> Self {
+        check!(TensorCheck::mask_fill(&self, &mask));
         Self::new(K::mask_fill(self.primitive, mask, value.elem()))
     }
 
@@ -253,6 +271,7 @@ where
     ///
     /// The index tensor should have the same shape as the original tensor except for the dim
     /// specified.
+    #[track_caller]
     pub fn gather(self, dim: usize, indices: Tensor<B, D, Int>) -> Self {
         check!(TensorCheck::gather::<D>(
             dim,
@@ -278,6 +297,7 @@ where
     /// dimension. The value and index tensors should have the same shape.
     ///
     /// Other references to the input tensor will not be modified by this operation.
+    #[track_caller]
     pub fn scatter(self, dim: usize, indices: Tensor<B, D, Int>, values: Self) -> Self {
         check!(TensorCheck::scatter::<D>(
             dim,
@@ -296,6 +316,7 @@ where
     /// `output[i, j, k] = input[indices[i], j, k]; // dim = 0`
     /// `output[i, j, k] = input[i, indices[j], k]; // dim = 1`
     /// `output[i, j, k] = input[i, j, indices[k]]; // dim = 2`
+    #[track_caller]
     pub fn select(self, dim: usize, indices: Tensor<B, 1, Int>) -> Self {
         check!(TensorCheck::select::<D>(dim));
         Self::new(K::select(self.primitive, dim, indices))
@@ -309,6 +330,7 @@ where
     /// `input[indices[i], j, k] += values[i, j, k]; // dim = 0`
     /// `input[i, indices[j], k] += values[i, j, k]; // dim = 1`
     /// `input[i, j, indices[k]] += values[i, j, k]; // dim = 2`
+    #[track_caller]
     pub fn select_assign(
         self,
         dim: usize,
@@ -325,8 +347,78 @@ where
         ))
     }
 
+    /// Add the values to the tensor at the given indices along the specified dimension.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `input[indices[i], j, k] += values[i, j, k]; // dim = 0`
+    /// `input[i, indices[j], k] += values[i, j, k]; // dim = 1`
+    /// `input[i, j, indices[k]] += values[i, j, k]; // dim = 2`
+    ///
+    /// The values added to the same index are summed with the
+    /// [sum scatter reduction](crate::ops::ScatterReduction::Sum), in the order of the indices, so
+    /// the result is deterministic. See [index_add_atomic](Tensor::index_add_atomic) for a faster
+    /// version on the backends supporting atomic operations.
+    #[track_caller]
+    pub fn index_add(self, dim: usize, indices: Tensor<B, 1, Int>, values: Self) -> Self {
+        check!(TensorCheck::select_assign::<D>(dim));
+
+        Self::new(K::index_add(
+            self.primitive,
+            dim,
+            indices,
+            values.primitive,
+            true,
+        ))
+    }
+
+    /// Add the values to the tensor at the given indices along the specified dimension, like
+    /// [index_add](Tensor::index_add), letting the backend sum the values added to the same index
+    /// in any order, e.g. with atomic operations.
+    ///
+    /// The rounding of the float sums can change between executions.
+    #[track_caller]
+    pub fn index_add_atomic(self, dim: usize, indices: Tensor<B, 1, Int>, values: Self) -> Self {
+        check!(TensorCheck::select_assign::<D>(dim));
+
+        Self::new(K::index_add(
+            self.primitive,
+            dim,
+            indices,
+            values.primitive,
+            false,
+        ))
+    }
+
+    /// Sum the slices of the tensor along the first dimension that belong to the same segment.
+    ///
+    /// `output[segment_ids[i], j, k] += input[i, j, k]`
+    ///
+    /// The output has `num_segments` slices along the first dimension, the empty segments being
+    /// zeros. The slices are summed with [index_add](Tensor::index_add), so the result is
+    /// deterministic.
+    #[track_caller]
+    pub fn segment_sum(self, segment_ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        let mut shape = self.shape();
+        shape.dims[0] = num_segments;
+
+        Self::zeros(shape, &self.device()).index_add(0, segment_ids, self)
+    }
+
+    /// Sum the slices of the tensor along the first dimension that belong to the same segment,
+    /// like [segment_sum](Tensor::segment_sum), with [index_add_atomic](Tensor::index_add_atomic).
+    #[track_caller]
+    pub fn segment_sum_atomic(self, segment_ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        let mut shape = self.shape();
+        shape.dims[0] = num_segments;
+
+        Self::zeros(shape, &self.device()).index_add_atomic(0, segment_ids, self)
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
+    /// When several elements are equal to the maximum, the index of the first one is returned.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -351,6 +443,7 @@ where
     }
 
     /// Find the maximum value along the given dimension.
+    #[track_caller]
     pub fn max_dim(self, dim: usize) -> Tensor<B, D, K> {
         check!(TensorCheck::aggregate_dim::<D>("Max", dim));
 
@@ -360,6 +453,7 @@ where
     /// Find the maximum value along the given dimension.
     ///
     /// Also returns the indices.
+    #[track_caller]
     pub fn max_dim_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
         check!(TensorCheck::aggregate_dim::<D>("Max", dim));
 
@@ -381,6 +475,7 @@ where
     ///
     /// A tensor with the same shape as the input tensors containing the maximum value found
     /// in the input tensors.
+    #[track_caller]
     pub fn max_pair(self, other: Self) -> Self {
         let mask = self.clone().lower(other.clone());
         self.mask_where(mask, other)
@@ -388,6 +483,8 @@ where
 
     /// Applies the argmin function along the given dimension and returns an integer tensor.
     ///
+    /// When several elements are equal to the minimum, the index of the first one is returned.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -412,6 +509,7 @@ where
     }
 
     /// Find the minimum value along the given dimension.
+    #[track_caller]
     pub fn min_dim(self, dim: usize) -> Tensor<B, D, K> {
         check!(TensorCheck::aggregate_dim::<D>("Min", dim));
         Tensor::new(K::min_dim(self.primitive, dim))
@@ -420,6 +518,7 @@ where
     /// Find the minimum value along the given dimension.
     ///
     /// Also returns the indices.
+    #[track_caller]
     pub fn min_dim_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
         check!(TensorCheck::aggregate_dim::<D>("Min", dim));
 
@@ -441,6 +540,7 @@ where
     ///
     /// A tensor with the same shape as the input tensors containing the minimum value found
     /// between each element of the two source tensors.
+    #[track_caller]
     pub fn min_pair(self, other: Self) -> Self {
         let mask = other.clone().lower(self.clone());
         self.mask_where(mask, other)
@@ -456,6 +556,7 @@ where
     /// # Returns
     ///
     /// A new tensor with the values clamped between the given min and max values.
+    #[track_caller]
     pub fn clamp<E: ElementConversion>(self, min: E, max: E) -> Self {
         Self::new(K::clamp(self.primitive, min.elem(), max.elem()))
     }
@@ -521,6 +622,7 @@ where
     ///    // ], ... }
     /// }
     /// ```
+    #[track_caller]
     pub fn triu(self, diagonal: i64) -> Self {
         check!(TensorCheck::tri::<{ D }>());
 
@@ -559,6 +661,7 @@ where
     ///    // ], ... }
     /// }
     /// ```
+    #[track_caller]
     pub fn tril(self, diagonal: i64) -> Self {
         check!(TensorCheck::tri::<{ D }>());
 
@@ -570,7 +673,9 @@ where
     }
 
     /// Applies element wise power operation with a float Tensor
+    #[track_caller]
     pub fn powf(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Powf", &self, &other));
         Self::new(K::powf(self.primitive, other.primitive))
     }
 
@@ -669,10 +774,24 @@ where
         Self::new(K::random(shape.into(), distribution, device))
     }
 
+    /// Create a random tensor of the given shape on the given device where each element is
+    /// sampled from the given distribution with the given [generator](Generator), instead of the
+    /// global random state of the backend.
+    pub fn random_with<S: Into<Shape<D>>>(
+        shape: S,
+        distribution: Distribution,
+        generator: &mut Generator,
+        device: &B::Device,
+    ) -> Self {
+        let data = Data::<K::Elem, D>::random(shape.into(), distribution, generator);
+        Self::from_data(data, device)
+    }
+
     /// Sort the elements by value in ascending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    #[track_caller]
     pub fn sort(self, dim: usize) -> Tensor<B, D, K> {
         check!(TensorCheck::sort_dim::<D>("Sort", dim));
         Tensor::new(K::sort(self.primitive, dim, /*descending*/ false))
@@ -680,8 +799,9 @@ where
 
     /// Sort the elements by value in descending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    #[track_caller]
     pub fn sort_descending(self, dim: usize) -> Tensor<B, D, K> {
         check!(TensorCheck::sort_dim::<D>("Sort", dim));
         Tensor::new(K::sort(self.primitive, dim, /*descending*/ true))
@@ -690,8 +810,9 @@ where
     /// Sort the elements by value in ascending order along a given dimension.
     /// Also returns the indices.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    #[track_caller]
     pub fn sort_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
         check!(TensorCheck::sort_dim::<D>("Sort_with_indices", dim));
         let (values, indices) =
@@ -702,8 +823,9 @@ where
     /// Sort the elements by value in descending order along a given dimension.
     /// Also returns the indices.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    #[track_caller]
     pub fn sort_descending_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
         check!(TensorCheck::sort_dim::<D>("Sort_with_indices", dim));
         let (values, indices) = K::sort_with_indices(self.primitive, dim, /*descending*/ true);
@@ -712,8 +834,9 @@ where
 
     /// Returns the indices that sort the elements by value in ascending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    #[track_caller]
     pub fn argsort(self, dim: usize) -> Tensor<B, D, Int> {
         check!(TensorCheck::sort_dim::<D>("Argsort", dim));
         Tensor::new(K::argsort(self.primitive, dim, /*descending*/ false))
@@ -721,8 +844,9 @@ where
 
     /// Returns the indices that sort the elements by value in descending order along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    #[track_caller]
     pub fn argsort_descending(self, dim: usize) -> Tensor<B, D, Int> {
         check!(TensorCheck::sort_dim::<D>("Argsort", dim));
         Tensor::new(K::argsort(self.primitive, dim, /*descending*/ true))
@@ -730,6 +854,7 @@ where
 
     /// Returns the `k` largest elements of the given input tensor along a given dimension.
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    #[track_caller]
     pub fn topk(self, k: usize, dim: usize) -> Tensor<B, D, K> {
         let k_indices = Tensor::arange(0..k as i64, &self.device());
         self.sort_descending(dim).select(dim, k_indices)
@@ -738,6 +863,7 @@ where
     /// Returns the `k` largest elements of the given input tensor along a given dimension.
     /// Also returns the indices.
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    #[track_caller]
     pub fn topk_with_indices(self, k: usize, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
         let k_indices = Tensor::arange(0..k as i64, &self.device());
         let (values, indices) = self.sort_descending_with_indices(dim);
@@ -1683,6 +1809,37 @@ where
         values: Self::Primitive<D>,
     ) -> Self::Primitive<D>;
 
+    /// Add the values to the tensor at the given indices along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to add the values to.
+    /// * `dim` - The axis along which to add the values.
+    /// * `indices` - The indices where to add the values.
+    /// * `values` - The values to add to the tensor.
+    /// * `deterministic` - If the values added to the same index should be summed in the same
+    ///   order on every execution.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, with the values added.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For adding values to a tensor along an axis, users should prefer the
+    /// [Tensor::index_add](Tensor::index_add) function, which is more high-level and designed for public use.
+    fn index_add<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self::Primitive<D>,
+        deterministic: bool,
+    ) -> Self::Primitive<D>;
+
     /// Gets the indices of the maximum elements of a tensor along an axis.
     ///
     /// # Arguments
@@ -2011,7 +2168,7 @@ where
 
     /// Sort the elements of the input `tensor` by value along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     ///
     /// # Arguments
     ///
@@ -2039,7 +2196,7 @@ where
 
     /// Sort the elements of the input `tensor` by value along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     ///
     /// # Arguments
     ///
@@ -2069,7 +2226,7 @@ where
 
     /// Returns the indices that sort the elements of the input `tensor` by value along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     ///
     /// # Arguments
     ///
@@ -2285,6 +2442,17 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> Self::Primitive<D> {
         B::int_select_assign(tensor, dim, indices.primitive, values)
     }
+
+    fn index_add<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self::Primitive<D>,
+        _deterministic: bool,
+    ) -> Self::Primitive<D> {
+        // The integer sums are exact, so always deterministic.
+        B::int_select_assign(tensor, dim, indices.primitive, values)
+    }
     fn gather<const D: usize>(
         dim: usize,
         tensor: Self::Primitive<D>,
@@ -2346,6 +2514,7 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_min_dim_with_indices(tensor, dim)
     }
 
+    #[track_caller]
     fn clamp<const D: usize>(
         tensor: Self::Primitive<D>,
         min: B::IntElem,
@@ -2632,6 +2801,40 @@ impl<B: Backend> Numeric<B> for Float {
         B::float_select_assign(tensor, dim, indices.primitive, values)
     }
 
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn index_add<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self::Primitive<D>,
+        deterministic: bool,
+    ) -> Self::Primitive<D> {
+        // The indices are broadcasted to the shape of the values to be scattered.
+        let mut shape = [1; D];
+        shape[dim] = indices.dims()[0];
+        let indices = indices.reshape(shape).expand(B::float_shape(&values));
+
+        B::float_scatter_reduce(
+            dim,
+            tensor,
+            indices.primitive,
+            values,
+            ScatterReduction::Sum,
+            deterministic,
+        )
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    fn index_add<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self::Primitive<D>,
+        _deterministic: bool,
+    ) -> Self::Primitive<D> {
+        B::float_select_assign(tensor, dim, indices.primitive, values)
+    }
+
     fn gather<const D: usize>(
         dim: usize,
         tensor: Self::Primitive<D>,
@@ -2693,6 +2896,7 @@ impl<B: Backend> Numeric<B> for Float {
         B::float_min_dim_with_indices(tensor, dim)
     }
 
+    #[track_caller]
     fn clamp<const D: usize>(
         tensor: Self::Primitive<D>,
         min: B::FloatElem,
@@ -0,0 +1,79 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor, ScatterReduction},
+};
+use alloc::vec;
+
+/// Reduce the `value` tensor into the input `tensor` at the given `indices` along a dimension.
+///
+/// The elements of the input tensor are part of the reduction.
+///
+/// # Arguments
+///
+/// * `dim` - The dimension to scatter into.
+/// * `tensor` - The input tensor.
+/// * `indices` - The indices to scatter into, with the same shape as the value tensor.
+/// * `value` - The values to reduce.
+/// * `reduction` - The reduction applied to the values scattered to the same position.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, with the reduced values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn scatter_reduce<B: Backend, const D: usize>(
+    dim: usize,
+    tensor: FloatTensor<B, D>,
+    indices: IntTensor<B, D>,
+    value: FloatTensor<B, D>,
+    reduction: ScatterReduction,
+) -> FloatTensor<B, D> {
+    let device = B::float_device(&tensor);
+    let mut data = B::float_into_data(tensor).read().convert::<f64>();
+    let indices = B::int_into_data(indices).read().convert::<i64>();
+    let value = B::float_into_data(value).read().convert::<f64>();
+
+    let mut strides = [1; D];
+    for d in (0..D - 1).rev() {
+        strides[d] = strides[d + 1] * data.shape.dims[d + 1];
+    }
+
+    // Each element of the input tensor counts in the mean.
+    let mut counts = vec![1usize; data.value.len()];
+
+    for (i, (index, value)) in indices.value.iter().zip(value.value.iter()).enumerate() {
+        let mut remainder = i;
+        let mut offset = 0;
+
+        for d in (0..D).rev() {
+            let size = indices.shape.dims[d];
+            let coordinate = match d == dim {
+                true => *index as usize,
+                false => remainder % size,
+            };
+            remainder /= size;
+            offset += coordinate * strides[d];
+        }
+
+        let current = &mut data.value[offset];
+        *current = match reduction {
+            ScatterReduction::Sum | ScatterReduction::Mean => *current + *value,
+            ScatterReduction::Max => current.max(*value),
+            ScatterReduction::Min => current.min(*value),
+        };
+        counts[offset] += 1;
+    }
+
+    if reduction == ScatterReduction::Mean {
+        for (value, count) in data.value.iter_mut().zip(counts) {
+            *value /= count as f64;
+        }
+    }
+
+    B::float_from_data(data.convert(), &device)
+}
@@ -9,7 +9,7 @@ use alloc::vec::Vec;
 
 /// Sort the elements of the input `tensor` by value along a given dimension.
 ///
-/// This sort is unstable (i.e., may reorder equal elements).
+/// This sort is stable (i.e., equal elements keep their relative order).
 ///
 /// # Arguments
 ///
@@ -44,7 +44,7 @@ where
 
 /// Sort the elements of the input `tensor` by value along a given dimension.
 ///
-/// This sort is unstable (i.e., may reorder equal elements).
+/// This sort is stable (i.e., equal elements keep their relative order).
 ///
 /// # Arguments
 ///
@@ -89,8 +89,7 @@ where
     let dims = data.shape.dims;
     if D == 1 {
         // 1D sort
-        data.value
-            .sort_unstable_by(|&a, &b| compare(&a, &b, descending));
+        data.value.sort_by(|&a, &b| compare(&a, &b, descending));
     } else {
         sort_slice::<B, D, K>(&mut data.value, &dims, dim, None, false, descending);
     }
@@ -100,7 +99,7 @@ where
 
 /// Sort the elements of the input `tensor` by value along a given dimension.
 ///
-/// This sort is unstable (i.e., may reorder equal elements).
+/// This sort is stable (i.e., equal elements keep their relative order).
 ///
 /// # Arguments
 ///
@@ -136,7 +135,7 @@ where
 
 /// Sort the elements of the input `tensor` by value along a given dimension.
 ///
-/// This sort is unstable (i.e., may reorder equal elements).
+/// This sort is stable (i.e., equal elements keep their relative order).
 ///
 /// # Arguments
 ///
@@ -183,7 +182,7 @@ where
     let mut indices_data = dim_indices::<B, D>(&dims, dim);
     if D == 1 {
         // 1D sort
-        indices_data.sort_unstable_by(|&a, &b| {
+        indices_data.sort_by(|&a, &b| {
             compare(
                 &data.value[a.elem::<i64>() as usize],
                 &data.value[b.elem::<i64>() as usize],
@@ -234,7 +233,7 @@ where
 
 /// Returns the indices that sort the elements of the input `tensor` along a given dimension.
 ///
-/// This sort is unstable (i.e., may reorder equal elements).
+/// This sort is stable (i.e., equal elements keep their relative order).
 ///
 /// # Arguments
 ///
@@ -269,7 +268,7 @@ where
 
 /// Returns the indices that sort the elements of the input `tensor` along a given dimension.
 ///
-/// This sort is unstable (i.e., may reorder equal elements).
+/// This sort is stable (i.e., equal elements keep their relative order).
 ///
 /// # Arguments
 ///
@@ -315,7 +314,7 @@ where
     let mut indices_data = dim_indices::<B, D>(&dims, dim);
     if D == 1 {
         // 1D sort
-        indices_data.sort_unstable_by(|&a, &b| {
+        indices_data.sort_by(|&a, &b| {
             compare(
                 &data.value[a.elem::<i64>() as usize],
                 &data.value[b.elem::<i64>() as usize],
@@ -342,7 +341,7 @@ where
 /// Otherwise, the `indices` are sorted based on the value of the elements in `data`,
 /// and if `permute_both` is enabled then the data is also sorted.
 ///
-/// This sort is unstable (i.e., may reorder equal elements).
+/// This sort is stable (i.e., equal elements keep their relative order).
 fn sort_slice<B: Backend, const D: usize, K: BasicOps<B>>(
     data: &mut [<K as BasicOps<B>>::Elem],
     dims: &[usize; D],
@@ -392,7 +391,7 @@ fn sort_slice<B: Backend, const D: usize, K: BasicOps<B>>(
         }
 
         // For each group, sort the indices based on the element values
-        // NOTE: Sorting methods like `sort_unstable_by` are in-place but we need to sort
+        // NOTE: Sorting methods like `sort_by` are in-place but we need to sort
         // different views/groups of the underlying data, so the swap is performed on the elements
         // of the (flat index, element value) collection.
         let mut elements = (0..shape_dim)
@@ -403,7 +402,7 @@ fn sort_slice<B: Backend, const D: usize, K: BasicOps<B>>(
             })
             .collect::<Vec<_>>();
 
-        elements.sort_unstable_by(|&(_, _, a), &(_, _, b)| compare(&a, &b, descending));
+        elements.sort_by(|&(_, _, a), &(_, _, b)| compare(&a, &b, descending));
 
         // Permute data in-place by the sorted indices
         for idx in 0..elements.len() {
@@ -1,10 +1,13 @@
 use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+pub use burn_common::memory_usage::MemoryUsage;
 pub use burn_common::sync_type::SyncType;
 
 use crate::ops::*;
 use crate::tensor::Element;
 
-use super::{BackendBridge, DeviceOps};
+use super::{BackendBridge, DeviceCapabilities, DeviceInfo, DeviceOps};
 
 /// This trait defines all types and functions needed for a backend to be used with burn.
 ///
@@ -96,8 +99,40 @@ pub trait Backend:
     /// Seed the backend.
     fn seed(seed: u64);
 
+    /// Fork the random number generator of the backend: a new seed is drawn from its current
+    /// state and the backend is [seeded](Backend::seed) with it.
+    ///
+    /// Seeding the backend with the returned seed later restores the random state of this point,
+    /// e.g. to continue the random values deterministically when resuming from a checkpoint.
+    ///
+    /// Returns `None` if the state of the random number generator of the backend can't be
+    /// restored.
+    fn fork_seed() -> Option<u64> {
+        None
+    }
+
     /// Sync the backend, ensure that all computation are finished.
     fn sync(_device: &Self::Device, _sync_type: SyncType) {}
+
+    /// Returns the memory usage of the given device, if the backend tracks it.
+    fn memory_usage(_device: &Self::Device) -> Option<MemoryUsage> {
+        None
+    }
+
+    /// Returns the devices available to the backend with their capabilities, so applications can
+    /// select the devices they use.
+    ///
+    /// Backends that can't enumerate their devices only return the default device, with unknown
+    /// capabilities.
+    fn devices() -> Vec<DeviceInfo<Self::Device>> {
+        let device = Self::Device::default();
+        let capabilities = DeviceCapabilities {
+            name: alloc::format!("{device:?}"),
+            ..Default::default()
+        };
+
+        vec![DeviceInfo::new(device, capabilities)]
+    }
 }
 
 /// Trait that allows a backend to support autodiff.
@@ -242,4 +277,16 @@ pub trait AutodiffBackend: Backend {
     fn bool_from_inner<const D: usize>(
         tensor: BoolTensor<Self::InnerBackend, D>,
     ) -> BoolTensor<Self, D>;
+
+    /// Keeps the value of the tensor in host memory for the backward pass, instead of on its
+    /// device.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to offload.
+    ///
+    /// # Returns
+    ///
+    /// The same tensor, whose value is loaded back on its device when the backward pass needs it.
+    fn offload<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D>;
 }
@@ -18,4 +18,10 @@ pub trait BackendBridge<Origin: Backend>: Send + Sync + core::fmt::Debug {
         tensor: FloatTensor<Self::Target, D>,
         device: Option<Device<Origin>>,
     ) -> FloatTensor<Origin, D>;
+
+    /// The device of the target backend matching the given device of the origin backend.
+    fn target_device(device: &Device<Origin>) -> Device<Self::Target>;
+
+    /// The device of the origin backend matching the given device of the target backend.
+    fn origin_device(device: &Device<Self::Target>) -> Device<Origin>;
 }
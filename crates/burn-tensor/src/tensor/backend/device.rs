@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 /// The device id.
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, new)]
 pub struct DeviceId {
@@ -12,3 +14,46 @@ pub trait DeviceOps: Clone + Default + PartialEq + Send + Sync + core::fmt::Debu
     /// Return the [device id](DeviceId).
     fn id(&self) -> DeviceId;
 }
+
+/// The capabilities of a device, as reported by its backend.
+///
+/// The capabilities a backend can't query are left to their default value, e.g. the memory size
+/// of the devices of the wgpu backend is `None`, since wgpu doesn't report it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceCapabilities {
+    /// The name of the device, e.g. the name of the GPU.
+    pub name: String,
+    /// The size of the memory of the device in bytes, if known.
+    pub memory_size: Option<u64>,
+    /// If the backend can execute operations on half precision floats (f16) on the device.
+    pub f16: bool,
+    /// If the backend can execute operations on brain floats (bf16) on the device.
+    pub bf16: bool,
+    /// The maximum number of invocations of a workgroup, for devices executing kernels in
+    /// workgroups.
+    pub max_workgroup_size: Option<u32>,
+    /// If the device shares its memory with the host, e.g. the CPU and integrated GPUs, so
+    /// transferring tensors between them is cheap.
+    pub unified_memory: bool,
+}
+
+/// A device available to a backend, listed by [devices](crate::backend::Backend::devices).
+///
+/// # Example
+///
+/// Select the device with the most memory instead of hard-coding it:
+///
+/// ```rust,ignore
+/// let device = B::devices()
+///     .into_iter()
+///     .max_by_key(|info| info.capabilities.memory_size)
+///     .map(|info| info.device)
+///     .unwrap_or_default();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, new)]
+pub struct DeviceInfo<D> {
+    /// The device.
+    pub device: D,
+    /// The capabilities of the device.
+    pub capabilities: DeviceCapabilities,
+}
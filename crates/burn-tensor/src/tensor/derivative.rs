@@ -0,0 +1,150 @@
+//! Derivatives of functions with respect to their inputs, e.g. the derivatives of the outputs of a
+//! physics-informed network with respect to the coordinates.
+//!
+//! The samples of a batch are expected to be computed independently of each other, e.g. without
+//! batch normalization. The derivatives of each output with respect to its own input are then the
+//! derivatives of the sum of the outputs of the batch, which are computed with a single graph for
+//! the whole batch instead of one graph per sample.
+//!
+//! The second derivatives are computed with nested autodiff backends, e.g.
+//! `Autodiff<Autodiff<NdArray>>`, the backward pass of the outer backend being recorded by the
+//! inner one.
+
+use crate::backend::AutodiffBackend;
+use crate::Tensor;
+use alloc::vec::Vec;
+
+/// The backend of the second derivatives of the tensors of a nested autodiff backend.
+pub type SecondOrderBackend<B> =
+    <<B as AutodiffBackend>::InnerBackend as AutodiffBackend>::InnerBackend;
+
+/// Computes the gradient of the sum of the outputs with respect to the inputs, with a single
+/// backward pass.
+///
+/// The gradient is a tensor of the inner backend, which is itself tracked when the inner backend
+/// is an autodiff backend, so that it can be differentiated again. The inputs that don't
+/// contribute to the outputs have a gradient of zero.
+///
+/// # Shapes
+///
+/// - outputs: `[batch_size, ...]`
+/// - inputs: `[batch_size, ...]`
+/// - output: same as the inputs
+pub fn gradient<B: AutodiffBackend, const D1: usize, const D2: usize>(
+    outputs: Tensor<B, D1>,
+    inputs: &Tensor<B, D2>,
+) -> Tensor<B::InnerBackend, D2> {
+    let grads = outputs.sum().backward();
+
+    inputs
+        .grad(&grads)
+        .unwrap_or_else(|| Tensor::zeros(inputs.shape(), &inputs.device()))
+}
+
+/// Computes the Jacobian of the outputs of a function with respect to the inputs of each sample.
+///
+/// Each row of the Jacobians is computed for the whole batch with one evaluation of the function
+/// and one backward pass, since the backward pass consumes the graph.
+///
+/// # Shapes
+///
+/// - inputs: `[batch_size, d_input]`
+/// - function: `[batch_size, d_input]` to `[batch_size, d_output]`
+/// - output: `[batch_size, d_output, d_input]`
+pub fn jacobian<B, F>(function: F, inputs: Tensor<B::InnerBackend, 2>) -> Tensor<B::InnerBackend, 3>
+where
+    B: AutodiffBackend,
+    F: Fn(Tensor<B, 2>) -> Tensor<B, 2>,
+{
+    let [batch_size, d_input] = inputs.dims();
+    let mut rows = Vec::new();
+    let mut d_output = 1;
+
+    while rows.len() < d_output {
+        let inputs = Tensor::<B, 2>::from_inner(inputs.clone()).require_grad();
+        let outputs = function(inputs.clone());
+        d_output = outputs.dims()[1];
+
+        let row = outputs.narrow(1, rows.len(), 1);
+        rows.push(gradient(row, &inputs).reshape([batch_size, 1, d_input]));
+    }
+
+    Tensor::cat(rows, 1)
+}
+
+/// Computes the Hessian of the output of a function with respect to the inputs of each sample, with
+/// a nested autodiff backend.
+///
+/// Each row of the Hessians is computed for the whole batch with one evaluation of the function
+/// and two backward passes.
+///
+/// # Shapes
+///
+/// - inputs: `[batch_size, d_input]`
+/// - function: `[batch_size, d_input]` to `[batch_size, 1]`
+/// - output: `[batch_size, d_input, d_input]`
+pub fn hessian<B, F>(
+    function: F,
+    inputs: Tensor<SecondOrderBackend<B>, 2>,
+) -> Tensor<SecondOrderBackend<B>, 3>
+where
+    B: AutodiffBackend,
+    B::InnerBackend: AutodiffBackend,
+    F: Fn(Tensor<B, 2>) -> Tensor<B, 2>,
+{
+    let [batch_size, d_input] = inputs.dims();
+    let rows = (0..d_input)
+        .map(|row| {
+            let (gradients, inputs) = tracked_gradient(&function, inputs.clone());
+            gradient(gradients.narrow(1, row, 1), &inputs).reshape([batch_size, 1, d_input])
+        })
+        .collect();
+
+    Tensor::cat(rows, 1)
+}
+
+/// Computes the Laplacian, the trace of the [Hessian](hessian), of the output of a function with
+/// respect to the inputs of each sample, with a nested autodiff backend.
+///
+/// # Shapes
+///
+/// - inputs: `[batch_size, d_input]`
+/// - function: `[batch_size, d_input]` to `[batch_size, 1]`
+/// - output: `[batch_size, 1]`
+pub fn laplacian<B, F>(
+    function: F,
+    inputs: Tensor<SecondOrderBackend<B>, 2>,
+) -> Tensor<SecondOrderBackend<B>, 2>
+where
+    B: AutodiffBackend,
+    B::InnerBackend: AutodiffBackend,
+    F: Fn(Tensor<B, 2>) -> Tensor<B, 2>,
+{
+    let [batch_size, d_input] = inputs.dims();
+    let device = inputs.device();
+
+    (0..d_input)
+        .map(|index| {
+            let (gradients, inputs) = tracked_gradient(&function, inputs.clone());
+            gradient(gradients.narrow(1, index, 1), &inputs).narrow(1, index, 1)
+        })
+        .reduce(Tensor::add)
+        .unwrap_or_else(|| Tensor::zeros([batch_size, 1], &device))
+}
+
+/// Computes the gradient of the output of a function, tracked by the inner backend with respect
+/// to the returned inputs.
+fn tracked_gradient<B, F>(
+    function: &F,
+    inputs: Tensor<SecondOrderBackend<B>, 2>,
+) -> (Tensor<B::InnerBackend, 2>, Tensor<B::InnerBackend, 2>)
+where
+    B: AutodiffBackend,
+    B::InnerBackend: AutodiffBackend,
+    F: Fn(Tensor<B, 2>) -> Tensor<B, 2>,
+{
+    let inner = Tensor::<B::InnerBackend, 2>::from_inner(inputs).require_grad();
+    let outer = Tensor::<B, 2>::from_inner(inner.clone()).require_grad();
+
+    (gradient(function(outer.clone()), &outer), inner)
+}
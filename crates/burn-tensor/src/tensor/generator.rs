@@ -0,0 +1,98 @@
+use crate::backend::{DeviceId, DeviceOps};
+use alloc::vec::Vec;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+/// A random number generator owned by its user, to be passed explicitly to the random operations,
+/// e.g. [random_with](crate::Tensor::random_with), instead of using the global random state of the
+/// backend.
+///
+/// Since each generator has its own state, concurrent code using different generators doesn't
+/// race on the global seed, so its random values are deterministic. A generator can be
+/// [forked](Generator::fork) for each worker, or derived [for each device](DeviceGenerators) from
+/// a global seed.
+///
+/// The random values are generated on the host, then moved to the device of the tensors.
+///
+/// The generator also implements [RngCore], so it can be used with the random utilities of the
+/// [rand] crate, e.g. to shuffle or sample a dataset.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    rng: StdRng,
+}
+
+impl Generator {
+    /// Create a generator from the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Create the generator of the given device from the global seed, independent of the
+    /// generators of the other devices.
+    pub fn for_device<D: DeviceOps>(seed: u64, device: &D) -> Self {
+        let DeviceId { type_id, index_id } = device.id();
+        let id = ((type_id as u64) << 32) | index_id as u64;
+
+        Self::new(seed ^ id.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
+
+    /// Create a new generator seeded from this one, e.g. for a worker thread, advancing the state
+    /// of this generator.
+    pub fn fork(&mut self) -> Self {
+        Self::new(self.rng.next_u64())
+    }
+}
+
+impl RngCore for Generator {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+/// The [generators](Generator) of the devices, derived from a global seed when first used.
+///
+/// The generator of each device only depends on the global seed and the device, so the random
+/// values of each device are the same whatever the order the devices are used in.
+#[derive(Clone, Debug)]
+pub struct DeviceGenerators {
+    seed: u64,
+    generators: Vec<(DeviceId, Generator)>,
+}
+
+impl DeviceGenerators {
+    /// Create the generators of the devices from the given global seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            generators: Vec::new(),
+        }
+    }
+
+    /// The generator of the given device.
+    pub fn get<D: DeviceOps>(&mut self, device: &D) -> &mut Generator {
+        let id = device.id();
+        let position = match self.generators.iter().position(|(other, _)| *other == id) {
+            Some(position) => position,
+            None => {
+                let generator = Generator::for_device(self.seed, device);
+                self.generators.push((id, generator));
+                self.generators.len() - 1
+            }
+        };
+
+        &mut self.generators[position].1
+    }
+}
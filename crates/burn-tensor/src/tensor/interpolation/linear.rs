@@ -0,0 +1,44 @@
+use super::{check_knots, interval};
+use crate::{backend::Backend, Tensor};
+
+/// Linearly interpolates the values of a piecewise linear function at the given points, as
+/// [numpy.interp](https://numpy.org/doc/stable/reference/generated/numpy.interp.html), e.g. for
+/// lookup-table activations or tone-mapping curves.
+///
+/// The points outside of the knots take the value of the closest knot. The output is
+/// differentiable with respect to the points, the knots and the values.
+///
+/// # Arguments
+///
+/// * `x` - The points where the function is evaluated.
+/// * `xp` - The knots of shape `[num_knots]`, in increasing order.
+/// * `fp` - The values of the function at the knots of shape `[num_knots]`.
+///
+/// # Returns
+///
+/// The interpolated values with the same shape as the points.
+///
+/// # Panics
+///
+/// If there are less than two knots or if there isn't a value for each knot.
+pub fn interp1d<B: Backend, const D: usize>(
+    x: Tensor<B, D>,
+    xp: Tensor<B, 1>,
+    fp: Tensor<B, 1>,
+) -> Tensor<B, D> {
+    check_knots(&xp, &fp);
+
+    let shape = x.shape();
+    let x = x.reshape([shape.num_elements()]);
+    let start = interval(x.clone(), xp.clone());
+    let end = start.clone().add_scalar(1);
+
+    let x0 = xp.clone().select(0, start.clone());
+    let x1 = xp.select(0, end.clone());
+    let y0 = fp.clone().select(0, start);
+    let y1 = fp.select(0, end);
+
+    let weight = (x - x0.clone()).div(x1 - x0).clamp(0.0, 1.0);
+
+    (y0.clone() + weight * (y1 - y0)).reshape(shape)
+}
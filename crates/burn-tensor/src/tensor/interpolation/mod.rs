@@ -0,0 +1,39 @@
+mod linear;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+mod spline;
+
+pub use linear::*;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub use spline::*;
+
+use crate::{backend::Backend, Int, Tensor};
+
+/// The index of the interval `[xp[i], xp[i + 1]]` of the sorted knots containing each point, the
+/// points outside of the knots being in the first or last interval.
+pub(crate) fn interval<B: Backend>(x: Tensor<B, 1>, xp: Tensor<B, 1>) -> Tensor<B, 1, Int> {
+    let [num_points] = x.dims();
+    let [num_knots] = xp.dims();
+    let shape = [num_points, num_knots];
+
+    x.reshape([num_points, 1])
+        .expand(shape)
+        .greater_equal(xp.reshape([1, num_knots]).expand(shape))
+        .int()
+        .sum_dim(1)
+        .reshape([num_points])
+        .sub_scalar(1)
+        .clamp(0, num_knots as i64 - 2)
+}
+
+pub(crate) fn check_knots<B: Backend>(xp: &Tensor<B, 1>, fp: &Tensor<B, 1>) {
+    let [num_knots] = xp.dims();
+    assert!(
+        num_knots >= 2,
+        "The interpolation requires at least two knots, got {num_knots}."
+    );
+    assert_eq!(
+        fp.dims(),
+        [num_knots],
+        "There should be a value for each knot."
+    );
+}
@@ -0,0 +1,136 @@
+use super::{check_knots, interval};
+use crate::{backend::Backend, linalg, ElementConversion, Tensor};
+use alloc::vec;
+use core::ops::Range;
+
+/// A natural cubic spline, whose second derivative is zero at the first and last knots, e.g. for
+/// smooth lookup tables.
+///
+/// The spline is differentiable with respect to the knots, the values and the evaluated points.
+#[derive(Debug, Clone)]
+pub struct CubicSpline<B: Backend> {
+    knots: Tensor<B, 1>,
+    values: Tensor<B, 1>,
+    second_derivatives: Tensor<B, 1>,
+}
+
+impl<B: Backend> CubicSpline<B> {
+    /// Fits the natural cubic spline going through the values at the knots.
+    ///
+    /// # Arguments
+    ///
+    /// * `xp` - The knots of shape `[num_knots]`, in increasing order.
+    /// * `fp` - The values at the knots of shape `[num_knots]`.
+    ///
+    /// # Panics
+    ///
+    /// If there are less than two knots or if there isn't a value for each knot.
+    pub fn new(xp: Tensor<B, 1>, fp: Tensor<B, 1>) -> Self {
+        check_knots(&xp, &fp);
+        let [num_knots] = xp.dims();
+        let device = xp.device();
+
+        if num_knots == 2 {
+            return Self {
+                knots: xp,
+                values: fp,
+                second_derivatives: Tensor::zeros([2], &device),
+            };
+        }
+
+        let num_intervals = num_knots - 1;
+        let slice = |tensor: Tensor<B, 1>, range: Range<usize>| tensor.slice([range]);
+        let widths = slice(xp.clone(), 1..num_knots) - slice(xp.clone(), 0..num_intervals);
+        let slopes = (slice(fp.clone(), 1..num_knots) - slice(fp.clone(), 0..num_intervals))
+            / widths.clone();
+
+        // The tridiagonal system of the continuity of the first derivatives at the inner knots,
+        // the second derivatives being zero at the first and last knots.
+        let zero = || Tensor::<B, 1>::zeros([1], &device);
+        let one = || Tensor::<B, 1>::ones([1], &device);
+        let diagonal = Tensor::cat(
+            vec![
+                one(),
+                (slice(widths.clone(), 0..num_intervals - 1)
+                    + slice(widths.clone(), 1..num_intervals))
+                .mul_scalar(2.0),
+                one(),
+            ],
+            0,
+        );
+        let above = Tensor::cat(vec![zero(), slice(widths.clone(), 1..num_intervals)], 0);
+        let below = Tensor::cat(vec![slice(widths, 0..num_intervals - 1), zero()], 0);
+        let system = diag(diagonal)
+            + diag(above).pad((1, 0, 0, 1), 0.elem())
+            + diag(below).pad((0, 1, 1, 0), 0.elem());
+
+        let rhs = Tensor::cat(
+            vec![
+                zero(),
+                (slice(slopes.clone(), 1..num_intervals) - slice(slopes, 0..num_intervals - 1))
+                    .mul_scalar(6.0),
+                zero(),
+            ],
+            0,
+        );
+        let second_derivatives =
+            linalg::solve(system, rhs.reshape([num_knots, 1])).reshape([num_knots]);
+
+        Self {
+            knots: xp,
+            values: fp,
+            second_derivatives,
+        }
+    }
+
+    /// Evaluates the spline at the given points, the points outside of the knots taking the value
+    /// of the closest knot.
+    pub fn evaluate<const D: usize>(&self, x: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = x.shape();
+        let x = x.reshape([shape.num_elements()]);
+        let start = interval(x.clone(), self.knots.clone());
+        let end = start.clone().add_scalar(1);
+
+        let select = |tensor: &Tensor<B, 1>, indices| tensor.clone().select(0, indices);
+        let (x0, x1) = (
+            select(&self.knots, start.clone()),
+            select(&self.knots, end.clone()),
+        );
+        let (y0, y1) = (
+            select(&self.values, start.clone()),
+            select(&self.values, end.clone()),
+        );
+        let m0 = select(&self.second_derivatives, start);
+        let m1 = select(&self.second_derivatives, end);
+
+        let x = x.max_pair(x0.clone()).min_pair(x1.clone());
+        let width = x1.clone() - x0.clone();
+        let to_end = x1 - x.clone();
+        let from_start = x - x0;
+
+        let cubic = m0.clone() * to_end.clone().powf_scalar(3.0)
+            + m1.clone() * from_start.clone().powf_scalar(3.0);
+        let linear = (y0 - m0 * width.clone().powf_scalar(2.0).div_scalar(6.0)) * to_end
+            + (y1 - m1 * width.clone().powf_scalar(2.0).div_scalar(6.0)) * from_start;
+
+        (cubic.div_scalar(6.0) + linear).div(width).reshape(shape)
+    }
+}
+
+/// Evaluates the [natural cubic spline](CubicSpline) going through the values `fp` at the knots
+/// `xp` at the given points.
+///
+/// To evaluate the same spline several times, [CubicSpline] fits it only once.
+pub fn cubic_spline<B: Backend, const D: usize>(
+    x: Tensor<B, D>,
+    xp: Tensor<B, 1>,
+    fp: Tensor<B, 1>,
+) -> Tensor<B, D> {
+    CubicSpline::new(xp, fp).evaluate(x)
+}
+
+/// The square matrix with the values on its diagonal.
+fn diag<B: Backend>(values: Tensor<B, 1>) -> Tensor<B, 2> {
+    let [size] = values.dims();
+    Tensor::eye(size, &values.device()) * values.unsqueeze()
+}
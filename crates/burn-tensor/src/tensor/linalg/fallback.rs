@@ -0,0 +1,184 @@
+//! Fallback implementations of the decompositions, which compute each matrix of the batch in
+//! double precision on the host.
+//!
+//! They are used only when the backend doesn't have the corresponding implementation. Ideally,
+//! the decompositions are implemented by the backend and resolved by static dispatch.
+
+use super::matrix::{self, Lu, Matrix};
+use crate::{backend::Backend, ops::FloatTensor, Data, Shape};
+use alloc::vec::Vec;
+
+pub(crate) fn cholesky<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let device = B::float_device(&tensor);
+    let (shape, matrices) = into_matrices::<B, D>(tensor);
+    let lower = matrices.iter().map(matrix::cholesky).collect();
+
+    from_matrices::<B, D>(shape, lower, &device)
+}
+
+pub(crate) fn solve<B: Backend, const D: usize>(
+    lhs: FloatTensor<B, D>,
+    rhs: FloatTensor<B, D>,
+) -> FloatTensor<B, D> {
+    let device = B::float_device(&lhs);
+    let (_, lhs) = into_matrices::<B, D>(lhs);
+    let (shape, rhs) = into_matrices::<B, D>(rhs);
+    let solutions = lhs
+        .iter()
+        .zip(rhs.iter())
+        .map(|(lhs, rhs)| Lu::new(lhs).solve(rhs))
+        .collect();
+
+    from_matrices::<B, D>(shape, solutions, &device)
+}
+
+pub(crate) fn inverse<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let device = B::float_device(&tensor);
+    let (shape, matrices) = into_matrices::<B, D>(tensor);
+    let inverses = matrices
+        .iter()
+        .map(|matrix| Lu::new(matrix).solve(&Matrix::identity(matrix.rows)))
+        .collect();
+
+    from_matrices::<B, D>(shape, inverses, &device)
+}
+
+pub(crate) fn det<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let device = B::float_device(&tensor);
+    let (shape, matrices) = into_matrices::<B, D>(tensor);
+    let dets = matrices
+        .iter()
+        .map(|matrix| Matrix::new(1, 1, [Lu::new(matrix).det()].to_vec()))
+        .collect();
+
+    from_matrices::<B, D>(shape, dets, &device)
+}
+
+pub(crate) fn slogdet<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let (shape, matrices) = into_matrices::<B, D>(tensor);
+    let (signs, logabsdets) = matrices
+        .iter()
+        .map(|matrix| {
+            let (sign, logabsdet) = Lu::new(matrix).slogdet();
+            (
+                Matrix::new(1, 1, [sign].to_vec()),
+                Matrix::new(1, 1, [logabsdet].to_vec()),
+            )
+        })
+        .unzip();
+
+    (
+        from_matrices::<B, D>(shape.clone(), signs, &device),
+        from_matrices::<B, D>(shape, logabsdets, &device),
+    )
+}
+
+pub(crate) fn pinverse<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+    rcond: f64,
+) -> FloatTensor<B, D> {
+    let device = B::float_device(&tensor);
+    let (mut shape, matrices) = into_matrices::<B, D>(tensor);
+    shape.dims.swap(D - 2, D - 1);
+    let inverses = matrices
+        .iter()
+        .map(|matrix| matrix::pinverse(matrix, rcond))
+        .collect();
+
+    from_matrices::<B, D>(shape, inverses, &device)
+}
+
+pub(crate) fn qr<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let (shape, matrices) = into_matrices::<B, D>(tensor);
+    let (q, r) = matrices.iter().map(matrix::qr).unzip();
+
+    (
+        from_matrices::<B, D>(shape.clone(), q, &device),
+        from_matrices::<B, D>(shape, r, &device),
+    )
+}
+
+pub(crate) fn svd<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let (shape, matrices) = into_matrices::<B, D>(tensor);
+    let mut u = Vec::with_capacity(matrices.len());
+    let mut s = Vec::with_capacity(matrices.len());
+    let mut vt = Vec::with_capacity(matrices.len());
+
+    for matrix in matrices.iter() {
+        let (left, values, right) = matrix::svd(matrix);
+        u.push(left);
+        s.push(Matrix::new(1, values.len(), values));
+        vt.push(right);
+    }
+
+    (
+        from_matrices::<B, D>(shape.clone(), u, &device),
+        from_matrices::<B, D>(shape.clone(), s, &device),
+        from_matrices::<B, D>(shape, vt, &device),
+    )
+}
+
+pub(crate) fn eigh<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let (shape, matrices) = into_matrices::<B, D>(tensor);
+    let (values, vectors) = matrices
+        .iter()
+        .map(|matrix| {
+            let (values, vectors) = matrix::eigh(matrix);
+            (Matrix::new(1, values.len(), values), vectors)
+        })
+        .unzip();
+
+    (
+        from_matrices::<B, D>(shape.clone(), values, &device),
+        from_matrices::<B, D>(shape, vectors, &device),
+    )
+}
+
+/// Reads the matrices of the last two dimensions of a tensor.
+fn into_matrices<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> (Shape<D>, Vec<Matrix>) {
+    let data = B::float_into_data(tensor).read().convert::<f64>();
+    let rows = data.shape.dims[D - 2];
+    let cols = data.shape.dims[D - 1];
+    let num_matrices = data.shape.dims[..D - 2].iter().product::<usize>();
+    let matrices = (0..num_matrices)
+        .map(|i| {
+            let values = &data.value[i * rows * cols..(i + 1) * rows * cols];
+            Matrix::new(rows, cols, values.to_vec())
+        })
+        .collect();
+
+    (data.shape, matrices)
+}
+
+/// Creates a tensor from the matrices of each element of the batch of the given shape, which all
+/// have the same size.
+fn from_matrices<B: Backend, const D: usize>(
+    mut shape: Shape<D>,
+    matrices: Vec<Matrix>,
+    device: &B::Device,
+) -> FloatTensor<B, D> {
+    let (rows, cols) = matrices
+        .first()
+        .map(|matrix| (matrix.rows, matrix.cols))
+        .unwrap_or((shape.dims[D - 2], shape.dims[D - 1]));
+    shape.dims[D - 2] = rows;
+    shape.dims[D - 1] = cols;
+    let values = matrices
+        .into_iter()
+        .flat_map(|matrix| matrix.values)
+        .collect();
+
+    B::float_from_data(Data::new(values, shape).convert(), device)
+}
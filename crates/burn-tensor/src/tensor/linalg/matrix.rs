@@ -0,0 +1,390 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The maximum number of sweeps of the Jacobi methods, which usually converge in less than 10.
+const MAX_SWEEPS: usize = 100;
+
+/// A dense row-major matrix on the host, used by the fallback decompositions.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Matrix {
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+    pub(crate) values: Vec<f64>,
+}
+
+impl Matrix {
+    pub(crate) fn new(rows: usize, cols: usize, values: Vec<f64>) -> Self {
+        debug_assert_eq!(values.len(), rows * cols);
+        Self { rows, cols, values }
+    }
+
+    pub(crate) fn zeros(rows: usize, cols: usize) -> Self {
+        Self::new(rows, cols, vec![0.0; rows * cols])
+    }
+
+    pub(crate) fn identity(size: usize) -> Self {
+        let mut matrix = Self::zeros(size, size);
+        for i in 0..size {
+            matrix[(i, i)] = 1.0;
+        }
+        matrix
+    }
+
+    pub(crate) fn transpose(&self) -> Self {
+        let mut matrix = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                matrix[(j, i)] = self[(i, j)];
+            }
+        }
+        matrix
+    }
+
+    /// Rotates the columns `p` and `q` by the given cosine and sine.
+    fn rotate_columns(&mut self, p: usize, q: usize, cos: f64, sin: f64) {
+        for k in 0..self.rows {
+            let (kp, kq) = (self[(k, p)], self[(k, q)]);
+            self[(k, p)] = cos * kp - sin * kq;
+            self[(k, q)] = sin * kp + cos * kq;
+        }
+    }
+
+    /// Rotates the rows `p` and `q` by the given cosine and sine.
+    fn rotate_rows(&mut self, p: usize, q: usize, cos: f64, sin: f64) {
+        for k in 0..self.cols {
+            let (pk, qk) = (self[(p, k)], self[(q, k)]);
+            self[(p, k)] = cos * pk - sin * qk;
+            self[(q, k)] = sin * pk + cos * qk;
+        }
+    }
+
+    fn column_dot(&self, p: usize, q: usize) -> f64 {
+        (0..self.rows).map(|k| self[(k, p)] * self[(k, q)]).sum()
+    }
+
+    /// Keeps the given columns in the given order.
+    fn select_columns(&self, columns: &[usize]) -> Self {
+        let mut matrix = Self::zeros(self.rows, columns.len());
+        for i in 0..self.rows {
+            for (j, column) in columns.iter().enumerate() {
+                matrix[(i, j)] = self[(i, *column)];
+            }
+        }
+        matrix
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &f64 {
+        &self.values[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        &mut self.values[row * self.cols + col]
+    }
+}
+
+/// The lower triangular Cholesky factor of a symmetric positive-definite matrix, with NaN values
+/// if the matrix isn't positive-definite.
+pub(crate) fn cholesky(matrix: &Matrix) -> Matrix {
+    let size = matrix.rows;
+    let mut lower = Matrix::zeros(size, size);
+
+    for j in 0..size {
+        let sum: f64 = (0..j).map(|k| lower[(j, k)] * lower[(j, k)]).sum();
+        let diagonal = match matrix[(j, j)] - sum {
+            value if value > 0.0 => value.sqrt(),
+            _ => f64::NAN,
+        };
+        lower[(j, j)] = diagonal;
+
+        for i in j + 1..size {
+            let sum: f64 = (0..j).map(|k| lower[(i, k)] * lower[(j, k)]).sum();
+            lower[(i, j)] = (matrix[(i, j)] - sum) / diagonal;
+        }
+    }
+
+    lower
+}
+
+/// The LU decomposition with partial pivoting of a square matrix.
+pub(crate) struct Lu {
+    /// The unit lower triangular factor below the diagonal, and the upper triangular factor.
+    factors: Matrix,
+    /// The row of the matrix at each row of the factors.
+    permutation: Vec<usize>,
+    /// The sign of the permutation.
+    sign: f64,
+}
+
+impl Lu {
+    pub(crate) fn new(matrix: &Matrix) -> Self {
+        let size = matrix.rows;
+        let mut factors = matrix.clone();
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut sign = 1.0;
+
+        for k in 0..size {
+            let pivot = (k..size)
+                .max_by(|a, b| factors[(*a, k)].abs().total_cmp(&factors[(*b, k)].abs()))
+                .unwrap();
+
+            if pivot != k {
+                for j in 0..size {
+                    factors.values.swap(k * size + j, pivot * size + j);
+                }
+                permutation.swap(k, pivot);
+                sign = -sign;
+            }
+
+            // The matrix is singular, the solutions will be infinite.
+            if factors[(k, k)] == 0.0 {
+                continue;
+            }
+
+            for i in k + 1..size {
+                let factor = factors[(i, k)] / factors[(k, k)];
+                factors[(i, k)] = factor;
+                for j in k + 1..size {
+                    factors[(i, j)] -= factor * factors[(k, j)];
+                }
+            }
+        }
+
+        Self {
+            factors,
+            permutation,
+            sign,
+        }
+    }
+
+    pub(crate) fn det(&self) -> f64 {
+        (0..self.factors.rows).fold(self.sign, |det, i| det * self.factors[(i, i)])
+    }
+
+    /// The sign and the natural logarithm of the absolute value of the determinant.
+    pub(crate) fn slogdet(&self) -> (f64, f64) {
+        let mut sign = self.sign;
+        let mut logabsdet = 0.0;
+
+        for i in 0..self.factors.rows {
+            let diagonal = self.factors[(i, i)];
+            if diagonal == 0.0 {
+                return (0.0, f64::NEG_INFINITY);
+            }
+            sign *= diagonal.signum();
+            logabsdet += diagonal.abs().ln();
+        }
+
+        (sign, logabsdet)
+    }
+
+    /// Solves `A X = B` for the right-hand sides of shape `[size, num_columns]`.
+    pub(crate) fn solve(&self, rhs: &Matrix) -> Matrix {
+        let size = self.factors.rows;
+        let mut solution = Matrix::zeros(size, rhs.cols);
+
+        for (i, row) in self.permutation.iter().enumerate() {
+            for j in 0..rhs.cols {
+                solution[(i, j)] = rhs[(*row, j)];
+            }
+        }
+
+        for j in 0..rhs.cols {
+            for i in 0..size {
+                for k in 0..i {
+                    solution[(i, j)] -= self.factors[(i, k)] * solution[(k, j)];
+                }
+            }
+            for i in (0..size).rev() {
+                for k in i + 1..size {
+                    solution[(i, j)] -= self.factors[(i, k)] * solution[(k, j)];
+                }
+                solution[(i, j)] /= self.factors[(i, i)];
+            }
+        }
+
+        solution
+    }
+}
+
+/// The reduced QR decomposition with Householder reflections, the diagonal of `R` being
+/// non-negative.
+pub(crate) fn qr(matrix: &Matrix) -> (Matrix, Matrix) {
+    let (rows, cols) = (matrix.rows, matrix.cols);
+    let size = rows.min(cols);
+    let mut r = matrix.clone();
+    let mut q = Matrix::identity(rows);
+
+    for j in 0..size {
+        let norm = (j..rows).map(|i| r[(i, j)] * r[(i, j)]).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+
+        // The reflection of the column onto `alpha e_j`, with the sign avoiding cancellations.
+        let alpha = if r[(j, j)] > 0.0 { -norm } else { norm };
+        let mut v: Vec<f64> = (j..rows).map(|i| r[(i, j)]).collect();
+        v[0] -= alpha;
+        let v_norm: f64 = v.iter().map(|x| x * x).sum();
+
+        for c in j..cols {
+            let dot: f64 = v.iter().zip(j..).map(|(x, i)| x * r[(i, c)]).sum();
+            for (x, i) in v.iter().zip(j..) {
+                r[(i, c)] -= 2.0 * dot / v_norm * x;
+            }
+        }
+        for row in 0..rows {
+            let dot: f64 = v.iter().zip(j..).map(|(x, i)| q[(row, i)] * x).sum();
+            for (x, i) in v.iter().zip(j..) {
+                q[(row, i)] -= 2.0 * dot / v_norm * x;
+            }
+        }
+    }
+
+    let mut q = q.select_columns(&(0..size).collect::<Vec<_>>());
+    let mut r = Matrix::new(size, cols, r.values[..size * cols].to_vec());
+
+    for i in 0..size {
+        for j in 0..i.min(cols) {
+            r[(i, j)] = 0.0;
+        }
+        if r[(i, i)] < 0.0 {
+            for j in 0..cols {
+                r[(i, j)] = -r[(i, j)];
+            }
+            for k in 0..rows {
+                q[(k, i)] = -q[(k, i)];
+            }
+        }
+    }
+
+    (q, r)
+}
+
+/// The eigenvalues in ascending order and the eigenvectors as columns of a symmetric matrix, with
+/// the cyclic Jacobi method.
+pub(crate) fn eigh(matrix: &Matrix) -> (Vec<f64>, Matrix) {
+    let size = matrix.rows;
+    let mut a = matrix.clone();
+    let mut vectors = Matrix::identity(size);
+    let norm: f64 = a.values.iter().map(|x| x * x).sum();
+
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal: f64 = (0..size)
+            .flat_map(|i| (i + 1..size).map(move |j| (i, j)))
+            .map(|(i, j)| a[(i, j)] * a[(i, j)])
+            .sum();
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * norm {
+            break;
+        }
+
+        for p in 0..size {
+            for q in p + 1..size {
+                if a[(p, q)] == 0.0 {
+                    continue;
+                }
+                let (cos, sin) = jacobi_rotation(a[(p, p)], a[(q, q)], a[(p, q)]);
+                a.rotate_columns(p, q, cos, sin);
+                a.rotate_rows(p, q, cos, sin);
+                vectors.rotate_columns(p, q, cos, sin);
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..size).collect();
+    order.sort_by(|i, j| a[(*i, *i)].total_cmp(&a[(*j, *j)]));
+    let values = order.iter().map(|i| a[(*i, *i)]).collect();
+
+    (values, vectors.select_columns(&order))
+}
+
+/// The thin singular value decomposition `U diag(S) V^T`, with the singular values in descending
+/// order, with the one-sided Jacobi method.
+pub(crate) fn svd(matrix: &Matrix) -> (Matrix, Vec<f64>, Matrix) {
+    if matrix.rows < matrix.cols {
+        let (u, singular_values, vt) = svd(&matrix.transpose());
+        return (vt.transpose(), singular_values, u.transpose());
+    }
+
+    let cols = matrix.cols;
+    let mut u = matrix.clone();
+    let mut v = Matrix::identity(cols);
+
+    for _ in 0..MAX_SWEEPS {
+        let mut converged = true;
+
+        for p in 0..cols {
+            for q in p + 1..cols {
+                let alpha = u.column_dot(p, p);
+                let beta = u.column_dot(q, q);
+                let gamma = u.column_dot(p, q);
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                converged = false;
+                let (cos, sin) = jacobi_rotation(alpha, beta, gamma);
+                u.rotate_columns(p, q, cos, sin);
+                v.rotate_columns(p, q, cos, sin);
+            }
+        }
+
+        if converged {
+            break;
+        }
+    }
+
+    let norms: Vec<f64> = (0..cols).map(|j| u.column_dot(j, j).sqrt()).collect();
+    let mut order: Vec<usize> = (0..cols).collect();
+    order.sort_by(|i, j| norms[*j].total_cmp(&norms[*i]));
+
+    let mut u = u.select_columns(&order);
+    let singular_values: Vec<f64> = order.iter().map(|j| norms[*j]).collect();
+    for (j, value) in singular_values.iter().enumerate() {
+        if *value > 0.0 {
+            for i in 0..u.rows {
+                u[(i, j)] /= value;
+            }
+        }
+    }
+
+    (u, singular_values, v.select_columns(&order).transpose())
+}
+
+/// The Moore-Penrose pseudo-inverse from the singular value decomposition, the singular values
+/// smaller than `rcond` times the largest one being treated as zeros.
+pub(crate) fn pinverse(matrix: &Matrix, rcond: f64) -> Matrix {
+    let (u, singular_values, vt) = svd(matrix);
+    let cutoff = rcond * singular_values.first().copied().unwrap_or_default();
+    let mut inverse = Matrix::zeros(matrix.cols, matrix.rows);
+
+    for (k, value) in singular_values.iter().enumerate() {
+        if *value <= cutoff || *value == 0.0 {
+            continue;
+        }
+        for i in 0..matrix.cols {
+            for j in 0..matrix.rows {
+                inverse[(i, j)] += vt[(k, i)] * u[(j, k)] / value;
+            }
+        }
+    }
+
+    inverse
+}
+
+/// The rotation zeroing the off-diagonal element of the symmetric matrix `[[app, apq], [apq, aqq]]`.
+fn jacobi_rotation(app: f64, aqq: f64, apq: f64) -> (f64, f64) {
+    let theta = (aqq - app) / (2.0 * apq);
+    let tan = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+    let cos = 1.0 / (tan * tan + 1.0).sqrt();
+
+    (cos, tan * cos)
+}
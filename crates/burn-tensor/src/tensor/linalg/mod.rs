@@ -0,0 +1,217 @@
+pub(crate) mod fallback;
+mod matrix;
+
+use crate::{backend::Backend, ElementConversion, Tensor};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The order of the Taylor series of the matrix exponential, accurate to the double precision for
+/// the matrices scaled to a norm of at most `0.5`.
+const MATRIX_EXP_ORDER: usize = 12;
+
+/// Computes the Cholesky decomposition `A = L L^T` of symmetric positive-definite matrices.
+///
+/// Only the lower triangle of the matrices is read. The factors of the matrices that aren't
+/// positive-definite contain NaN values.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - output: `[..., n, n]`, lower triangular
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check_square("cholesky", &tensor);
+    Tensor::new(B::float_cholesky(tensor.primitive))
+}
+
+/// Solves the linear systems `A X = B` of square matrices, with an LU decomposition with partial
+/// pivoting.
+///
+/// # Shapes
+///
+/// - lhs: `[..., n, n]`
+/// - rhs: `[..., n, k]`
+/// - output: `[..., n, k]`
+pub fn solve<B: Backend, const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+    check_square("solve", &lhs);
+    let lhs_dims = lhs.dims();
+    let rhs_dims = rhs.dims();
+    assert!(
+        lhs_dims[..D - 1] == rhs_dims[..D - 1],
+        "The right-hand side of shape {rhs_dims:?} doesn't match the matrices of shape \
+         {lhs_dims:?}."
+    );
+    Tensor::new(B::float_solve(lhs.primitive, rhs.primitive))
+}
+
+/// Computes the inverse of square matrices, the inverses of singular matrices containing infinite
+/// or NaN values.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - output: `[..., n, n]`
+pub fn inverse<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check_square("inverse", &tensor);
+    Tensor::new(B::float_inverse(tensor.primitive))
+}
+
+/// Computes the determinant of square matrices.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - output: `[..., 1, 1]`
+pub fn det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check_square("det", &tensor);
+    Tensor::new(B::float_det(tensor.primitive))
+}
+
+/// Computes the sign and the natural logarithm of the absolute value of the determinant of square
+/// matrices, which doesn't overflow like the [determinant](det) of large matrices.
+///
+/// The logarithms are differentiable, the sign being zero for singular matrices.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - output: `(sign, logabsdet)`, both of shape `[..., 1, 1]`
+pub fn slogdet<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check_square("slogdet", &tensor);
+    let (sign, logabsdet) = B::float_slogdet(tensor.primitive);
+    (Tensor::new(sign), Tensor::new(logabsdet))
+}
+
+/// Computes the natural logarithm of the determinant of square matrices, e.g. of covariance
+/// matrices, which is NaN for the matrices with a negative determinant.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - output: `[..., 1, 1]`
+pub fn logdet<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let (sign, logabsdet) = slogdet(tensor);
+    logabsdet.mask_fill(sign.lower_elem(0.0), f32::NAN)
+}
+
+/// Computes the Moore-Penrose pseudo-inverse of matrices with the singular value decomposition.
+///
+/// The singular values smaller than `rcond` times the largest singular value are treated as
+/// zeros, e.g. `max(m, n) * f32::EPSILON` for single precision matrices. The pseudo-inverse is
+/// differentiable where the rank of the matrices doesn't change.
+///
+/// # Shapes
+///
+/// - tensor: `[..., m, n]`
+/// - output: `[..., n, m]`
+pub fn pinverse<B: Backend, const D: usize>(tensor: Tensor<B, D>, rcond: f64) -> Tensor<B, D> {
+    check_matrix::<D>("pinverse");
+    Tensor::new(B::float_pinverse(tensor.primitive, rcond))
+}
+
+/// Computes the exponential of square matrices, with the scaling and squaring of a Taylor series.
+///
+/// The exponential is computed with matrix products, so it is differentiable on any backend.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - output: `[..., n, n]`
+pub fn matrix_exp<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check_square("matrix_exp", &tensor);
+    let size = tensor.dims()[D - 1];
+    let device = tensor.device();
+
+    // The matrices are scaled by a power of two to a 1-norm of at most 0.5, the exponential of
+    // the original matrices being the exponential of the scaled ones squared as many times.
+    let norm = tensor
+        .clone()
+        .detach()
+        .abs()
+        .sum_dim(D - 2)
+        .max()
+        .into_scalar()
+        .elem::<f64>();
+    let squarings = (norm * 2.0).log2().ceil().clamp(0.0, 63.0) as u32;
+    let scaled = tensor.div_scalar((1u64 << squarings) as f64);
+
+    // The Taylor series evaluated with the Horner method.
+    let identity = Tensor::<B, 2>::eye(size, &device).unsqueeze::<D>();
+    let mut exp = scaled.clone().div_scalar(MATRIX_EXP_ORDER as f64) + identity.clone();
+    for order in (1..MATRIX_EXP_ORDER).rev() {
+        exp = scaled.clone().matmul(exp).div_scalar(order as f64) + identity.clone();
+    }
+
+    for _ in 0..squarings {
+        exp = exp.clone().matmul(exp);
+    }
+
+    exp
+}
+
+/// Computes the reduced QR decomposition `A = Q R`, where `Q` has orthonormal columns and `R` is
+/// upper triangular with a non-negative diagonal.
+///
+/// The decomposition is only differentiable for matrices with at least as many rows as columns,
+/// i.e. `m >= n`.
+///
+/// # Shapes
+///
+/// - tensor: `[..., m, n]`
+/// - output: `(Q, R)` of shapes `[..., m, k]` and `[..., k, n]` with `k = min(m, n)`
+pub fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check_matrix::<D>("qr");
+    let (q, r) = B::float_qr(tensor.primitive);
+    (Tensor::new(q), Tensor::new(r))
+}
+
+/// Computes the thin singular value decomposition `A = U diag(S) V^T`, with the singular values
+/// in descending order.
+///
+/// The decomposition isn't differentiable: its outputs are detached from the graph of the input,
+/// the singular vectors not being unique when singular values are repeated.
+///
+/// # Shapes
+///
+/// - tensor: `[..., m, n]`
+/// - output: `(U, S, V^T)` of shapes `[..., m, k]`, `[..., 1, k]` and `[..., k, n]` with
+///   `k = min(m, n)`
+pub fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check_matrix::<D>("svd");
+    let (u, s, vt) = B::float_svd(tensor.primitive);
+    (Tensor::new(u), Tensor::new(s), Tensor::new(vt))
+}
+
+/// Computes the eigenvalues in ascending order and the eigenvectors of symmetric matrices.
+///
+/// The gradient of the eigenvectors is only defined when the eigenvalues are distinct.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - output: `(eigenvalues, eigenvectors)` of shapes `[..., 1, n]` and `[..., n, n]`, the
+///   eigenvectors being the columns
+pub fn eigh<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check_square("eigh", &tensor);
+    let (values, vectors) = B::float_eigh(tensor.primitive);
+    (Tensor::new(values), Tensor::new(vectors))
+}
+
+fn check_matrix<const D: usize>(op: &str) {
+    assert!(
+        D >= 2,
+        "The {op} operation requires tensors of at least two dimensions."
+    );
+}
+
+fn check_square<B: Backend, const D: usize>(op: &str, tensor: &Tensor<B, D>) {
+    check_matrix::<D>(op);
+    let dims = tensor.dims();
+    assert_eq!(
+        dims[D - 2],
+        dims[D - 1],
+        "The {op} operation requires square matrices, got shape {dims:?}."
+    );
+}
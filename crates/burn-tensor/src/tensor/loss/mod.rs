@@ -1,5 +1,5 @@
 use crate::backend::Backend;
-use crate::{activation, Tensor};
+use crate::{activation, Int, Tensor};
 
 /// Computes the log softmax cross entropy between logits and target probabilities.
 ///
@@ -21,3 +21,18 @@ pub fn cross_entropy_with_logits<B: Backend, const D: usize>(
 
     tensor.mean().neg()
 }
+
+/// Computes the cross entropy between logits and target classes, without materializing the log
+/// softmax.
+///
+/// # Arguments
+///
+/// * `logits` - The logits of shape `[batch_size, num_classes]`.
+/// * `targets` - The target classes of shape `[batch_size]`.
+///
+/// # Returns
+///
+/// The cross entropy of each sample, of shape `[batch_size]`.
+pub fn cross_entropy<B: Backend>(logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
+    Tensor::from_primitive(B::cross_entropy(logits.primitive, targets.primitive))
+}
@@ -3,11 +3,15 @@ pub(crate) mod stats;
 mod api;
 mod data;
 mod element;
+mod generator;
+mod nested;
 mod shape;
 
 pub use api::*;
 pub use data::*;
 pub use element::*;
+pub use generator::*;
+pub use nested::*;
 pub use shape::*;
 
 /// The activation module.
@@ -19,6 +23,16 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// The derivative module.
+pub mod derivative;
+
+/// The interpolation module.
+pub mod interpolation;
+
+/// The linear algebra module.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub mod linalg;
+
 /// The loss module.
 pub mod loss;
 
@@ -28,6 +42,9 @@ pub mod module;
 /// Operations on tensors module.
 pub mod ops;
 
+/// The vision module.
+pub mod vision;
+
 #[cfg(feature = "experimental-named-tensor")]
 mod named;
 #[cfg(feature = "experimental-named-tensor")]
@@ -1,6 +1,8 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
+    ops::{
+        ConvOptions, ConvTransposeOptions, GridSampleOptions, InterpolateOptions, UnfoldOptions,
+    },
     Int, Tensor,
 };
 
@@ -231,3 +233,74 @@ where
 {
     Tensor::new(B::interpolate(x.primitive, output_size, options))
 }
+
+/// Applies a [2D grid sampling](crate::ops::ModuleOps::grid_sample_2d).
+pub fn grid_sample_2d<B>(
+    x: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    options: GridSampleOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::grid_sample_2d(x.primitive, grid.primitive, options))
+}
+
+/// Computes the sampling grid of a batch of 2D affine transformations, to be used with
+/// [grid_sample_2d].
+///
+/// Each transformation maps the normalized `(x, y, 1)` coordinates of an output pixel to the
+/// normalized coordinates sampled in the input, which makes [grid_sample_2d] warp the input. The
+/// coordinates of the pixels are the ones of their centers, normalized like in [grid_sample_2d]
+/// with the same `align_corners`.
+///
+/// # Shapes
+///
+/// theta: `[batch_size, 2, 3]`,
+/// size: `[batch_size, channels, height_out, width_out]`, the size of the warped output,
+/// output: `[batch_size, height_out, width_out, 2]`.
+pub fn affine_grid_2d<B>(theta: Tensor<B, 3>, size: [usize; 4], align_corners: bool) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    crate::ops::affine_grid_2d(theta, size, align_corners)
+}
+
+/// Compresses a tensor to the [2:4 sparse format](crate::ops::ModuleOps::sparse24_matmul),
+/// keeping the two values with the largest magnitude in each group of four consecutive rows of
+/// each column.
+///
+/// # Shapes
+///
+/// tensor: `[k, n]`, where `k` is a multiple of 4,
+/// output: the values `[k / 2, n]` and the metadata `[k / 4, n]`.
+pub fn sparse24_compress<B>(tensor: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2, Int>)
+where
+    B: Backend,
+{
+    crate::ops::sparse24_compress(tensor)
+}
+
+/// Decompresses a tensor stored in the [2:4 sparse format](crate::ops::ModuleOps::sparse24_matmul).
+pub fn sparse24_decompress<B>(values: Tensor<B, 2>, metadata: Tensor<B, 2, Int>) -> Tensor<B, 2>
+where
+    B: Backend,
+{
+    crate::ops::sparse24_decompress(values, metadata)
+}
+
+/// Applies a [matrix multiplication with a 2:4 sparse right hand side](crate::ops::ModuleOps::sparse24_matmul).
+pub fn sparse24_matmul<B>(
+    lhs: Tensor<B, 2>,
+    values: Tensor<B, 2>,
+    metadata: Tensor<B, 2, Int>,
+) -> Tensor<B, 2>
+where
+    B: Backend,
+{
+    Tensor::new(B::sparse24_matmul(
+        lhs.primitive,
+        values.primitive,
+        metadata.primitive,
+    ))
+}
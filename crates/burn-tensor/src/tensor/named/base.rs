@@ -1,7 +1,10 @@
 use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 use crate::backend::Backend;
-use crate::{Distribution, NamedDims, Shape, Tensor};
+use crate::check::TensorCheck;
+use crate::{check, Distribution, ElementConversion, NamedDims, Shape, Tensor};
 
 /// A tensor with named dimensions.
 #[derive(Debug, Clone)]
@@ -57,19 +60,160 @@ where
         Self::from_tensor(Tensor::random(shape, distribution, device))
     }
 
+    /// Create a named tensor of the given shape where each element is zero.
+    pub fn zeros<S: Into<Shape<D>>>(shape: S, device: &B::Device) -> Self {
+        Self::from_tensor(Tensor::zeros(shape, device))
+    }
+
+    /// Create a named tensor of the given shape where each element is one.
+    pub fn ones<S: Into<Shape<D>>>(shape: S, device: &B::Device) -> Self {
+        Self::from_tensor(Tensor::ones(shape, device))
+    }
+
+    /// Returns the underlying tensor.
+    pub fn tensor(&self) -> &Tensor<B, D> {
+        &self.tensor
+    }
+
+    /// Converts the named tensor into the underlying tensor.
+    pub fn into_tensor(self) -> Tensor<B, D> {
+        self.tensor
+    }
+
     /// Returns the shape of the current tensor.
     pub fn shape(&self) -> Shape<D> {
         self.tensor.shape()
     }
 
+    /// Applies a function on the underlying tensor that keeps the same dimensions, like an
+    /// activation.
+    pub fn map<F: FnOnce(Tensor<B, D>) -> Tensor<B, D>>(self, func: F) -> Self {
+        Self::from_tensor(func(self.tensor))
+    }
+
+    /// Applies element wise addition operation.
+    ///
+    /// `y = x2 + x1`
+    #[allow(clippy::should_implement_trait)]
+    #[track_caller]
+    pub fn add(self, rhs: Self) -> Self {
+        check!(TensorCheck::named_binary_ops_ew(
+            "Add",
+            &ND::names(),
+            &self.shape(),
+            &rhs.shape()
+        ));
+        Self::from_tensor(self.tensor.add(rhs.tensor))
+    }
+
+    /// Applies element wise subtraction operation.
+    ///
+    /// `y = x2 - x1`
+    #[allow(clippy::should_implement_trait)]
+    #[track_caller]
+    pub fn sub(self, rhs: Self) -> Self {
+        check!(TensorCheck::named_binary_ops_ew(
+            "Sub",
+            &ND::names(),
+            &self.shape(),
+            &rhs.shape()
+        ));
+        Self::from_tensor(self.tensor.sub(rhs.tensor))
+    }
+
+    /// Applies element wise division operation.
+    ///
+    /// `y = x2 / x1`
+    #[allow(clippy::should_implement_trait)]
+    #[track_caller]
+    pub fn div(self, rhs: Self) -> Self {
+        check!(TensorCheck::named_binary_ops_ew(
+            "Div",
+            &ND::names(),
+            &self.shape(),
+            &rhs.shape()
+        ));
+        Self::from_tensor(self.tensor.div(rhs.tensor))
+    }
+
+    /// Applies element wise multiplication operation with a scalar.
+    ///
+    /// `y = x * s`
+    pub fn mul_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::from_tensor(self.tensor.mul_scalar(other))
+    }
+
+    /// Applies element wise addition operation with a scalar.
+    ///
+    /// `y = x + s`
+    pub fn add_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::from_tensor(self.tensor.add_scalar(other))
+    }
+
     /// Applies element wise multiplication operation.
     ///
     /// `y = x2 * x1`
     #[allow(clippy::should_implement_trait)]
+    #[track_caller]
     pub fn mul(self, rhs: Self) -> Self {
+        check!(TensorCheck::named_binary_ops_ew(
+            "Mul",
+            &ND::names(),
+            &self.shape(),
+            &rhs.shape()
+        ));
         Self::from_tensor(self.tensor.mul(rhs.tensor))
     }
 
+    /// Returns the name of each dimension.
+    pub fn dim_names(&self) -> Vec<String> {
+        ND::names()
+    }
+
+    /// Returns the index of the dimension with the given name.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor doesn't have a dimension with the given name.
+    #[track_caller]
+    pub fn dim(&self, name: &str) -> usize {
+        dim_index::<B, ND>("Dim", name)
+    }
+
+    /// Returns the size of the dimension with the given name.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor doesn't have a dimension with the given name.
+    #[track_caller]
+    pub fn size(&self, name: &str) -> usize {
+        self.shape().dims[dim_index::<B, ND>("Size", name)]
+    }
+
+    /// Aggregate all elements along the dimension with the given name with the sum operation,
+    /// keeping the dimension with a size of one.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor doesn't have a dimension with the given name.
+    #[track_caller]
+    pub fn sum_dim(self, name: &str) -> Self {
+        let dim = dim_index::<B, ND>("Sum Dim", name);
+        Self::from_tensor(self.tensor.sum_dim(dim))
+    }
+
+    /// Aggregate all elements along the dimension with the given name with the mean operation,
+    /// keeping the dimension with a size of one.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor doesn't have a dimension with the given name.
+    #[track_caller]
+    pub fn mean_dim(self, name: &str) -> Self {
+        let dim = dim_index::<B, ND>("Mean Dim", name);
+        Self::from_tensor(self.tensor.mean_dim(dim))
+    }
+
     /// Reshape the tensor to have the given shape.
     ///
     /// # Panics
@@ -83,3 +227,11 @@ where
         NamedTensor::from_tensor(self.tensor.reshape(shape.into()))
     }
 }
+
+#[track_caller]
+fn dim_index<B: Backend, ND: NamedDims<B>>(ops: &str, name: &str) -> usize {
+    let names = ND::names();
+    check!(TensorCheck::named_dim(ops, &names, name));
+
+    names.iter().position(|current| current == name).unwrap()
+}
@@ -1,5 +1,7 @@
 use alloc::format;
 use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
 
 use crate::backend::Backend;
 use crate::Tensor;
@@ -17,6 +19,9 @@ pub trait NamedDims<B: Backend>: core::fmt::Debug {
 
     /// Converts the named dimensions to a string.
     fn to_string() -> String;
+
+    /// Returns the name of each dimension.
+    fn names() -> Vec<String>;
 }
 
 /// Named dimension macro.
@@ -42,6 +47,10 @@ where
     fn to_string() -> String {
         format!("[{}]", D1::to_string())
     }
+
+    fn names() -> Vec<String> {
+        vec![D1::to_string()]
+    }
 }
 
 impl<B: Backend, D1, D2> NamedDims<B> for (D1, D2)
@@ -54,6 +63,10 @@ where
     fn to_string() -> String {
         format!("[{}, {}]", D1::to_string(), D2::to_string())
     }
+
+    fn names() -> Vec<String> {
+        vec![D1::to_string(), D2::to_string()]
+    }
 }
 
 impl<B: Backend, D1, D2, D3> NamedDims<B> for (D1, D2, D3)
@@ -72,6 +85,10 @@ where
             D3::to_string()
         )
     }
+
+    fn names() -> Vec<String> {
+        vec![D1::to_string(), D2::to_string(), D3::to_string()]
+    }
 }
 
 impl<B: Backend, D1, D2, D3, D4> NamedDims<B> for (D1, D2, D3, D4)
@@ -92,4 +109,13 @@ where
             D4::to_string()
         )
     }
+
+    fn names() -> Vec<String> {
+        vec![
+            D1::to_string(),
+            D2::to_string(),
+            D3::to_string(),
+            D4::to_string(),
+        ]
+    }
 }
@@ -1,5 +1,6 @@
 use crate::backend::Backend;
-use crate::{Dim, NamedDims, NamedTensor, Tensor};
+use crate::check::TensorCheck;
+use crate::{check, Dim, NamedDims, NamedTensor, Tensor};
 
 pub trait Matmul<Rhs, Out> {
     fn matmul(self, rhs: Rhs) -> Out;
@@ -16,6 +17,7 @@ where
     /// # Panics
     ///
     /// If the two tensors dont' have a compatible shape.
+    #[track_caller]
     pub fn matmul<NamedDimsRhs, NamedDimsOut>(
         self,
         rhs: NamedTensor<B, NamedDimsRhs>,
@@ -25,6 +27,12 @@ where
         NamedDimsOut: NamedDims<B, Tensor = Tensor<B, D>>,
         Self: Matmul<NamedTensor<B, NamedDimsRhs>, NamedTensor<B, NamedDimsOut>>,
     {
+        check!(TensorCheck::named_matmul(
+            &ND::names(),
+            &self.shape(),
+            &NamedDimsRhs::names(),
+            &rhs.shape()
+        ));
         Matmul::matmul(self, rhs)
     }
 }
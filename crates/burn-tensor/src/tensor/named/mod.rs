@@ -1,6 +1,7 @@
 mod base;
 mod dims;
 mod matmul;
+mod squeeze;
 mod swap_dims;
 
 pub use base::*;
@@ -0,0 +1,69 @@
+use crate::backend::Backend;
+use crate::{Dim, NamedDims, NamedTensor, Tensor};
+
+pub trait Squeeze<N, const DIM: usize> {
+    fn squeeze(self) -> N;
+}
+
+impl<B: Backend, const D: usize, ND> NamedTensor<B, ND>
+where
+    ND: NamedDims<B, Tensor = Tensor<B, D>>,
+{
+    /// Remove a dimension of size one, the dimension being removed from the named dimensions.
+    ///
+    /// This can be combined with a reduction to aggregate a dimension away, e.g.
+    /// `tensor.sum_dim("Seq").squeeze::<_, 1>()`.
+    ///
+    /// # Panics
+    ///
+    /// If the size of the dimension isn't one.
+    pub fn squeeze<ND2, const DIM: usize>(self) -> NamedTensor<B, ND2>
+    where
+        ND2: NamedDims<B>,
+        Self: Squeeze<NamedTensor<B, ND2>, DIM>,
+    {
+        Squeeze::squeeze(self)
+    }
+}
+
+macro_rules! generate_squeeze {
+    (2 => ($($output:ident),*), $dim:expr) => {
+        impl<B: Backend, D1: Dim, D2: Dim> Squeeze<NamedTensor<B, ($($output,)*)>, $dim>
+            for NamedTensor<B, (D1, D2)>
+        {
+            fn squeeze(self) -> NamedTensor<B, ($($output,)*)> {
+                NamedTensor::from_tensor(self.tensor.squeeze($dim))
+            }
+        }
+    };
+
+    (3 => ($($output:ident),*), $dim:expr) => {
+        impl<B: Backend, D1: Dim, D2: Dim, D3: Dim> Squeeze<NamedTensor<B, ($($output,)*)>, $dim>
+            for NamedTensor<B, (D1, D2, D3)>
+        {
+            fn squeeze(self) -> NamedTensor<B, ($($output,)*)> {
+                NamedTensor::from_tensor(self.tensor.squeeze($dim))
+            }
+        }
+    };
+
+    (4 => ($($output:ident),*), $dim:expr) => {
+        impl<B: Backend, D1: Dim, D2: Dim, D3: Dim, D4: Dim>
+            Squeeze<NamedTensor<B, ($($output,)*)>, $dim> for NamedTensor<B, (D1, D2, D3, D4)>
+        {
+            fn squeeze(self) -> NamedTensor<B, ($($output,)*)> {
+                NamedTensor::from_tensor(self.tensor.squeeze($dim))
+            }
+        }
+    };
+}
+
+generate_squeeze!(2 => (D2), 0);
+generate_squeeze!(2 => (D1), 1);
+generate_squeeze!(3 => (D2, D3), 0);
+generate_squeeze!(3 => (D1, D3), 1);
+generate_squeeze!(3 => (D1, D2), 2);
+generate_squeeze!(4 => (D2, D3, D4), 0);
+generate_squeeze!(4 => (D1, D3, D4), 1);
+generate_squeeze!(4 => (D1, D2, D4), 2);
+generate_squeeze!(4 => (D1, D2, D3), 3);
@@ -0,0 +1,338 @@
+use alloc::vec::Vec;
+
+use crate::{
+    backend::Backend, BasicOps, Bool, Data, Element, ElementConversion, Float, Int, Numeric, Shape,
+    Tensor, TensorKind,
+};
+
+/// A list of tensors with different lengths along their first dimension, stored in one tensor.
+///
+/// The tensors are concatenated along the first dimension, and the offsets track where each
+/// tensor starts. Element-wise operations can be applied on all the tensors at once with
+/// [map](NestedTensor::map), without computing anything on padding elements.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{NestedTensor, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let nested = NestedTensor::new(vec![
+///         Tensor::<B, 2>::ones([3, 4], &device),
+///         Tensor::<B, 2>::ones([1, 4], &device),
+///     ]);
+///
+///     // Shape [2, 3, 4], the second tensor being padded with zeros.
+///     let padded = nested.clone().to_padded::<3, _>(0.0);
+///     // Shape [2, 3], true for the padding elements.
+///     let mask = nested.padding_mask();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct NestedTensor<B, const D: usize, K = Float>
+where
+    B: Backend,
+    K: TensorKind<B>,
+{
+    values: Tensor<B, D, K>,
+    offsets: Vec<usize>,
+}
+
+impl<B, const D: usize, K> NestedTensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Create a nested tensor from a list of tensors, which must have the same shape except
+    /// for the first dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the list is empty or the shapes of the tensors are incompatible.
+    pub fn new(tensors: Vec<Tensor<B, D, K>>) -> Self {
+        let lengths = tensors
+            .iter()
+            .map(|tensor| tensor.dims()[0])
+            .collect::<Vec<_>>();
+
+        Self::from_values(Tensor::cat(tensors, 0), &lengths)
+    }
+
+    /// Create a nested tensor from the concatenated values of the tensors along the first
+    /// dimension, and the length of each tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the lengths don't sum to the size of the first dimension of the values.
+    pub fn from_values(values: Tensor<B, D, K>, lengths: &[usize]) -> Self {
+        let mut offsets = Vec::with_capacity(lengths.len() + 1);
+        let mut offset = 0;
+
+        offsets.push(offset);
+        for length in lengths {
+            offset += length;
+            offsets.push(offset);
+        }
+
+        assert_eq!(
+            offset,
+            values.dims()[0],
+            "The lengths of the nested tensors ({offset} in total) must sum to the size of the \
+             first dimension of the values ({}).",
+            values.dims()[0]
+        );
+
+        Self { values, offsets }
+    }
+
+    /// The number of tensors.
+    pub fn num_tensors(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// The length of each tensor along the first dimension.
+    pub fn lengths(&self) -> Vec<usize> {
+        self.offsets
+            .windows(2)
+            .map(|offsets| offsets[1] - offsets[0])
+            .collect()
+    }
+
+    /// The offset of each tensor in the values, followed by the total length.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// The length of the longest tensor.
+    pub fn max_length(&self) -> usize {
+        self.lengths().into_iter().max().unwrap_or(0)
+    }
+
+    /// The concatenated values of the tensors along the first dimension.
+    pub fn values(&self) -> &Tensor<B, D, K> {
+        &self.values
+    }
+
+    /// Returns the concatenated values of the tensors along the first dimension.
+    pub fn into_values(self) -> Tensor<B, D, K> {
+        self.values
+    }
+
+    /// Returns the device of the values.
+    pub fn device(&self) -> B::Device {
+        self.values.device()
+    }
+
+    /// Returns the tensor at the given index.
+    pub fn get(&self, index: usize) -> Tensor<B, D, K> {
+        let start = self.offsets[index];
+        let end = self.offsets[index + 1];
+
+        self.values.clone().narrow(0, start, end - start)
+    }
+
+    /// Returns the list of tensors.
+    pub fn into_tensors(self) -> Vec<Tensor<B, D, K>> {
+        (0..self.num_tensors()).map(|i| self.get(i)).collect()
+    }
+
+    /// Apply a function on the values of all the tensors at once.
+    ///
+    /// # Panics
+    ///
+    /// If the function changes the size of the first dimension of the values.
+    pub fn map<const D2: usize, K2, F>(self, func: F) -> NestedTensor<B, D2, K2>
+    where
+        K2: BasicOps<B>,
+        F: FnOnce(Tensor<B, D, K>) -> Tensor<B, D2, K2>,
+    {
+        let lengths = self.lengths();
+
+        NestedTensor::from_values(func(self.values), &lengths)
+    }
+
+    /// The index of the tensor each element of the first dimension of the values belongs to.
+    pub fn segment_ids(&self) -> Tensor<B, 1, Int> {
+        let ids = self
+            .lengths()
+            .into_iter()
+            .enumerate()
+            .flat_map(|(id, length)| core::iter::repeat(id as i64).take(length))
+            .collect::<Vec<_>>();
+        let num_values = ids.len();
+
+        Tensor::from_data(
+            Data::new(ids, Shape::new([num_values])).convert(),
+            &self.device(),
+        )
+    }
+
+    /// Returns the padding mask of the [padded](NestedTensor::to_padded) tensors, of shape
+    /// `[num_tensors, max_length]`, where padding elements are true.
+    ///
+    /// This is the mask expected by the attention modules.
+    pub fn padding_mask(&self) -> Tensor<B, 2, Bool> {
+        let max_length = self.max_length();
+        let mask = self
+            .lengths()
+            .into_iter()
+            .flat_map(|length| (0..max_length).map(move |position| position >= length))
+            .collect();
+
+        Tensor::from_data(
+            Data::new(mask, Shape::new([self.num_tensors(), max_length])),
+            &self.device(),
+        )
+    }
+}
+
+impl<B, const D: usize, K> NestedTensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Stack the tensors in a new tensor of shape `[num_tensors, max_length, ...]`, where the
+    /// tensors shorter than the longest one are padded with the given value.
+    ///
+    /// # Panics
+    ///
+    /// If `D2` isn't equal to `D + 1`.
+    pub fn to_padded<const D2: usize, E: ElementConversion>(
+        self,
+        pad_value: E,
+    ) -> Tensor<B, D2, K> {
+        check_padded_rank::<D, D2>();
+
+        let device = self.device();
+        let num_tensors = self.num_tensors();
+        let max_length = self.max_length();
+
+        let mut shape = [0; D2];
+        shape[0] = num_tensors;
+        shape[1..].copy_from_slice(&self.values.dims());
+        shape[1] = max_length;
+
+        if self.offsets[num_tensors] == 0 {
+            return Tensor::full(shape, pad_value, &device);
+        }
+
+        // The padding elements gather the first value, and are then masked.
+        let indices = self
+            .lengths()
+            .into_iter()
+            .zip(self.offsets.iter())
+            .flat_map(|(length, offset)| {
+                (0..max_length).map(move |position| match position < length {
+                    true => (offset + position) as i64,
+                    false => 0,
+                })
+            })
+            .collect::<Vec<_>>();
+        let indices = Tensor::from_data(
+            Data::new(indices, Shape::new([num_tensors * max_length])).convert(),
+            &device,
+        );
+
+        let mut shape_mask = [1; D2];
+        shape_mask[0] = num_tensors;
+        shape_mask[1] = max_length;
+
+        let mask = self.padding_mask().reshape(shape_mask);
+
+        self.values
+            .select(0, indices)
+            .reshape(shape)
+            .mask_fill(mask, pad_value)
+    }
+
+    /// Create a nested tensor from padded tensors of shape `[num_tensors, max_length, ...]` and
+    /// the length of each tensor, dropping the padding elements.
+    ///
+    /// # Panics
+    ///
+    /// If `D2` isn't equal to `D + 1`, or if a length is greater than the padded length.
+    pub fn from_padded<const D2: usize>(padded: Tensor<B, D2, K>, lengths: &[usize]) -> Self {
+        check_padded_rank::<D, D2>();
+
+        let dims = padded.dims();
+        let [num_tensors, max_length] = [dims[0], dims[1]];
+
+        assert_eq!(
+            num_tensors,
+            lengths.len(),
+            "The number of lengths ({}) must match the number of padded tensors ({num_tensors}).",
+            lengths.len()
+        );
+        assert!(
+            lengths.iter().all(|length| *length <= max_length),
+            "The lengths of the nested tensors must not exceed the padded length ({max_length})."
+        );
+
+        let indices = lengths
+            .iter()
+            .enumerate()
+            .flat_map(|(i, length)| {
+                (0..*length).map(move |position| (i * max_length + position) as i64)
+            })
+            .collect::<Vec<_>>();
+        let num_values = indices.len();
+        let indices = Tensor::from_data(
+            Data::new(indices, Shape::new([num_values])).convert(),
+            &padded.device(),
+        );
+
+        let mut shape = [0; D];
+        shape.copy_from_slice(&dims[1..]);
+        shape[0] = num_tensors * max_length;
+
+        Self::from_values(padded.reshape(shape).select(0, indices), lengths)
+    }
+
+    /// Sum each tensor along its first dimension, returning a tensor of shape `[num_tensors, ...]`.
+    ///
+    /// The sum of an empty tensor is zero.
+    pub fn sum(self) -> Tensor<B, D, K> {
+        let num_tensors = self.num_tensors();
+        let segment_ids = self.segment_ids();
+
+        self.values.segment_sum(segment_ids, num_tensors)
+    }
+}
+
+impl<B, const D: usize> NestedTensor<B, D>
+where
+    B: Backend,
+{
+    /// Average each tensor along its first dimension, returning a tensor of shape
+    /// `[num_tensors, ...]`.
+    ///
+    /// The mean of an empty tensor is NaN.
+    pub fn mean(self) -> Tensor<B, D> {
+        let device = self.device();
+        let lengths = self
+            .lengths()
+            .into_iter()
+            .map(|length| length as f32)
+            .collect::<Vec<_>>();
+
+        let mut shape = [1; D];
+        shape[0] = lengths.len();
+
+        let lengths = Tensor::from_data(Data::new(lengths, Shape::new(shape)).convert(), &device);
+
+        self.sum().div(lengths)
+    }
+}
+
+fn check_padded_rank<const D: usize, const D2: usize>() {
+    assert_eq!(
+        D + 1,
+        D2,
+        "The padded tensors must have one more dimension ({}) than the nested tensors ({}).",
+        D2,
+        D
+    );
+}
@@ -1,8 +1,8 @@
 use crate::tensor::ops::tensor::FloatTensorOps;
-use crate::{backend::Backend, ElementConversion};
+use crate::{backend::Backend, ElementConversion, Shape};
 use core::f64::consts::SQRT_2;
 
-use super::{FloatTensor, FullPrecisionBackend};
+use super::{FloatTensor, FullPrecisionBackend, IntTensor, IntTensorOps};
 
 /// Activation function operations.
 ///
@@ -275,4 +275,66 @@ pub trait ActivationOps<B: Backend> {
             ),
         )
     }
+
+    /// Applies the cross entropy between the logits and the target classes, fusing the log
+    /// softmax over the classes with the negative log likelihood.
+    ///
+    /// # Arguments
+    ///
+    /// * `logits` - The logits of shape `[batch_size, num_classes]`.
+    /// * `targets` - The target classes of shape `[batch_size]`.
+    ///
+    /// # Returns
+    ///
+    /// The loss of each sample, of shape `[batch_size]`.
+    fn cross_entropy(logits: FloatTensor<B, 2>, targets: IntTensor<B, 1>) -> FloatTensor<B, 1> {
+        let [batch_size, _] = B::float_shape(&logits).dims;
+
+        // -log(softmax(x)[t]) = log(sum(exp(x - max(x)))) - (x[t] - max(x))
+        let max = B::float_max_dim(logits.clone(), 1);
+        let shifted = B::float_sub(logits, max);
+        let log_sum_exp = B::float_log(B::float_sum_dim(B::float_exp(shifted.clone()), 1));
+
+        let targets = B::int_reshape(targets, Shape::new([batch_size, 1]));
+        let target_logits = B::float_gather(1, shifted, targets);
+
+        B::float_reshape(
+            B::float_sub(log_sum_exp, target_logits),
+            Shape::new([batch_size]),
+        )
+    }
+
+    /// Applies the cross entropy backward.
+    ///
+    /// # Arguments
+    ///
+    /// * `logits` - The logits of shape `[batch_size, num_classes]`.
+    /// * `targets` - The target classes of shape `[batch_size]`.
+    /// * `grad` - The gradient of the loss of each sample, of shape `[batch_size]`.
+    ///
+    /// # Returns
+    ///
+    /// The gradient of the logits.
+    fn cross_entropy_backward(
+        logits: FloatTensor<B, 2>,
+        targets: IntTensor<B, 1>,
+        grad: FloatTensor<B, 1>,
+    ) -> FloatTensor<B, 2> {
+        let [batch_size, _] = B::float_shape(&logits).dims;
+        let device = B::float_device(&logits);
+
+        let max = B::float_max_dim(logits.clone(), 1);
+        let exp = B::float_exp(B::float_sub(logits, max));
+        let softmax = B::float_div(exp.clone(), B::float_sum_dim(exp, 1));
+
+        // grad * (softmax(x) - one_hot(t))
+        let targets = B::int_reshape(targets, Shape::new([batch_size, 1]));
+        let minus_ones = B::float_full(Shape::new([batch_size, 1]), (-1.0).elem(), &device);
+        let softmax_grad = B::float_scatter(1, softmax, targets, minus_ones);
+
+        B::float_mul(
+            softmax_grad,
+            B::float_reshape(grad, Shape::new([batch_size, 1])),
+        )
+    }
 }
@@ -0,0 +1,35 @@
+use super::{FloatElem, FloatTensor, IntTensor};
+use crate::{backend::Backend, DType, Data, Element, ElementConversion, Shape};
+use alloc::vec::Vec;
+
+/// Returns the bit patterns of the elements of a float tensor, read on the host, see
+/// [float_bits](super::FloatTensorOps::float_bits).
+///
+/// This is the default implementation of `float_bits`, which backends can also use for the
+/// element types they can't reinterpret on the device.
+pub fn float_bits_on_host<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> IntTensor<B, 2> {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor).read();
+    let num_elements = data.value.len();
+
+    let (num_bytes, bits): (usize, fn(FloatElem<B>) -> u64) = match FloatElem::<B>::dtype() {
+        DType::F64 => (8, |value| value.elem::<f64>().to_bits()),
+        DType::F32 => (4, |value| value.elem::<f32>().to_bits() as u64),
+        DType::F16 => (2, |value| value.elem::<half::f16>().to_bits() as u64),
+        DType::BF16 => (2, |value| value.elem::<half::bf16>().to_bits() as u64),
+        dtype => panic!("{dtype:?} isn't a float element"),
+    };
+
+    let mut bytes = Vec::with_capacity(num_elements * num_bytes);
+    for value in data.value {
+        let bits = bits(value);
+        bytes.extend((0..num_bytes).map(|i| (((bits >> (8 * i)) & 0xFF) as i64).elem()));
+    }
+
+    B::int_from_data(
+        Data::new(bytes, Shape::new([num_elements, num_bytes])),
+        &device,
+    )
+}
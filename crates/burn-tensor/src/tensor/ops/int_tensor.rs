@@ -820,6 +820,8 @@ pub trait IntTensorOps<B: Backend> {
 
     /// Gets the indices of the maximum elements along a dimension.
     ///
+    /// When several elements are equal to the maximum, the index of the first one is returned.
+    ///
     /// # Arguments
     ///
     /// * `tensor` - The tensor to get the maximum indices of.
@@ -832,6 +834,8 @@ pub trait IntTensorOps<B: Backend> {
 
     /// Gets the indices of the minimum elements along a dimension.
     ///
+    /// When several elements are equal to the minimum, the index of the first one is returned.
+    ///
     /// # Arguments
     ///
     /// * `tensor` - The tensor to get the minimum indices of.
@@ -1236,7 +1240,7 @@ pub trait IntTensorOps<B: Backend> {
 
     /// Sort the elements of the input `tensor` by value along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     ///
     /// # Arguments
     ///
@@ -1258,7 +1262,7 @@ pub trait IntTensorOps<B: Backend> {
 
     /// Sort the elements of the input `tensor` by value along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     ///
     /// # Arguments
     ///
@@ -1281,7 +1285,7 @@ pub trait IntTensorOps<B: Backend> {
     /// Returns the indices that sort the elements of the input `tensor` by value
     /// along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     ///
     /// # Arguments
     ///
@@ -1,5 +1,7 @@
 mod activation;
 mod alias;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+mod bits;
 mod bool_tensor;
 mod int_tensor;
 mod modules;
@@ -7,6 +9,8 @@ mod tensor;
 
 pub use activation::*;
 pub use alias::*;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub use bits::*;
 pub use bool_tensor::*;
 pub use int_tensor::*;
 pub use modules::*;
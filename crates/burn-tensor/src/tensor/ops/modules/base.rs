@@ -1,4 +1,7 @@
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{
+    conv, grid_sample::grid_sample_2d_with_gather, pool, sparse::sparse24_matmul_with_dense,
+    unfold::unfold4d_using_conv2d,
+};
 use crate::{
     backend::Backend,
     ops::{FloatTensor, IntTensor},
@@ -137,6 +140,37 @@ pub struct InterpolateOptions {
     pub mode: InterpolateMode,
 }
 
+/// How the locations outside of the input are sampled by
+/// [grid_sample_2d](ModuleOps::grid_sample_2d).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSamplePaddingMode {
+    /// Sample zeros.
+    Zeros,
+
+    /// Sample the pixels on the border of the input, clamping the locations.
+    Border,
+
+    /// Sample the input reflected by its borders.
+    Reflection,
+}
+
+/// Grid sampling options.
+#[derive(new, Debug, Clone)]
+pub struct GridSampleOptions {
+    /// How the locations outside of the input are sampled.
+    pub padding_mode: GridSamplePaddingMode,
+
+    /// If `-1` and `1` are the centers of the first and last pixels of the input, instead of
+    /// their outer edges.
+    pub align_corners: bool,
+}
+
+impl Default for GridSampleOptions {
+    fn default() -> Self {
+        Self::new(GridSamplePaddingMode::Zeros, false)
+    }
+}
+
 /// Gradient computed during the backward pass for each tensor used by [interpolate](ModuleOps::interpolate).
 #[derive(new)]
 pub struct InterpolateBackward<B: Backend> {
@@ -488,4 +522,45 @@ pub trait ModuleOps<B: Backend> {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<B, 4>;
+
+    /// Samples the input at the locations of the grid with bilinear interpolation.
+    ///
+    /// The grid holds the `(x, y)` locations normalized in `[-1, 1]`, where `-1` and `1` are the
+    /// outer edges of the first and last pixels of the input, or their centers with
+    /// [align_corners](GridSampleOptions::align_corners). Locations outside of the input are
+    /// sampled according to the [padding mode](GridSamplePaddingMode).
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height_in, width_in]`,
+    /// grid: `[batch_size, height_out, width_out, 2]`,
+    /// output: `[batch_size, channels, height_out, width_out]`.
+    fn grid_sample_2d(
+        x: FloatTensor<B, 4>,
+        grid: FloatTensor<B, 4>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<B, 4> {
+        grid_sample_2d_with_gather::<B>(x, grid, options)
+    }
+
+    /// Matrix multiplication with a right hand side stored in the 2:4 semi-structured sparse
+    /// format, where two values are kept in each group of four consecutive rows of each column.
+    ///
+    /// The metadata encodes the positions `i0 < i1` of the two values kept in each group as
+    /// `i0 + 4 * i1`. The default implementation decompresses the right hand side and computes a
+    /// dense matrix multiplication, backends with sparse kernels can override it.
+    ///
+    /// # Shapes
+    ///
+    /// lhs: `[m, k]`,
+    /// values: `[k / 2, n]`,
+    /// metadata: `[k / 4, n]`,
+    /// output: `[m, n]`.
+    fn sparse24_matmul(
+        lhs: FloatTensor<B, 2>,
+        values: FloatTensor<B, 2>,
+        metadata: IntTensor<B, 2>,
+    ) -> FloatTensor<B, 2> {
+        sparse24_matmul_with_dense::<B>(lhs, values, metadata)
+    }
 }
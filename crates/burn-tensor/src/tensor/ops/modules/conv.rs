@@ -118,7 +118,7 @@ pub(crate) fn conv1d_backward<B: Backend>(
 }
 
 /// Calculate the [2D convolution](crate::ops::ModuleOps::conv2d) backward pass using convolutions.
-pub(crate) fn conv2d_backward<B: Backend>(
+pub fn conv2d_backward<B: Backend>(
     x: FloatTensor<B, 4>,
     weight: FloatTensor<B, 4>,
     bias: Option<FloatTensor<B, 1>>,
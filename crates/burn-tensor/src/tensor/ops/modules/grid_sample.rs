@@ -0,0 +1,176 @@
+use super::{GridSampleOptions, GridSamplePaddingMode};
+use crate::{backend::Backend, ops::FloatTensor, Int, Tensor};
+use alloc::vec;
+
+/// Compute the grid_sample_2d operation using gather operations.
+///
+/// # Notes
+///
+/// Each output element is the sum of the four input elements surrounding its location, weighted
+/// by their distance to it. Using only tensor operations makes the operation differentiable with
+/// respect to both the input and the grid on every backend.
+pub(crate) fn grid_sample_2d_with_gather<B: Backend>(
+    x: FloatTensor<B, 4>,
+    grid: FloatTensor<B, 4>,
+    options: GridSampleOptions,
+) -> FloatTensor<B, 4> {
+    let x = Tensor::<B, 4>::from_primitive(x);
+    let grid = Tensor::<B, 4>::from_primitive(grid);
+
+    let [batch_size, channels, height_in, width_in] = x.dims();
+    let [_, height_out, width_out, _] = grid.dims();
+    let num_points = height_out * width_out;
+
+    let grid = grid.reshape([batch_size, num_points, 2]).swap_dims(1, 2);
+    let ix = source_coordinates(grid.clone().narrow(1, 0, 1), width_in, &options);
+    let iy = source_coordinates(grid.narrow(1, 1, 1), height_in, &options);
+
+    let (x0, x1) = surrounding_coordinates(ix.clone(), width_in);
+    let (y0, y1) = surrounding_coordinates(iy.clone(), height_in);
+
+    let wx1 = ix - x0.clone();
+    let wx0 = wx1.clone().neg().add_scalar(1);
+    let wy1 = iy - y0.clone();
+    let wy0 = wy1.clone().neg().add_scalar(1);
+
+    let x = x.reshape([batch_size, channels, height_in * width_in]);
+    let size = [channels, height_in, width_in];
+
+    let output = sample(x.clone(), x0.clone(), y0.clone(), size) * (wx0.clone() * wy0.clone())
+        + sample(x.clone(), x1.clone(), y0, size) * (wx1.clone() * wy0)
+        + sample(x.clone(), x0, y1.clone(), size) * (wx0 * wy1.clone())
+        + sample(x, x1, y1, size) * (wx1 * wy1);
+
+    output
+        .reshape([batch_size, channels, height_out, width_out])
+        .into_primitive()
+}
+
+/// Compute the grid used to apply a batch of affine transformations with
+/// [grid_sample_2d](crate::ops::ModuleOps::grid_sample_2d).
+pub(crate) fn affine_grid_2d<B: Backend>(
+    theta: Tensor<B, 3>,
+    size: [usize; 4],
+    align_corners: bool,
+) -> Tensor<B, 4> {
+    let [batch_size, _, height, width] = size;
+    let device = theta.device();
+
+    let xs = pixel_centers::<B>(width, align_corners, &device)
+        .reshape([1, width])
+        .repeat(0, height);
+    let ys = pixel_centers::<B>(height, align_corners, &device)
+        .reshape([height, 1])
+        .repeat(1, width);
+    let ones = Tensor::ones([height, width], &device);
+
+    let base = Tensor::stack::<3>(vec![xs, ys, ones], 2)
+        .reshape([1, height * width, 3])
+        .repeat(0, batch_size);
+
+    base.matmul(theta.swap_dims(1, 2))
+        .reshape([batch_size, height, width, 2])
+}
+
+/// The normalized coordinates of the centers of the pixels along an axis of the given size.
+fn pixel_centers<B: Backend>(size: usize, align_corners: bool, device: &B::Device) -> Tensor<B, 1> {
+    let pixels = Tensor::<B, 1, Int>::arange(0..size as i64, device).float();
+
+    match align_corners {
+        // The center of a single pixel is both the first and the last one, so it is centered.
+        true if size == 1 => pixels,
+        true => pixels.mul_scalar(2.0 / (size - 1) as f32).sub_scalar(1),
+        false => pixels
+            .mul_scalar(2)
+            .add_scalar(1)
+            .div_scalar(size as f32)
+            .sub_scalar(1),
+    }
+}
+
+/// Map coordinates normalized in `[-1, 1]` to the pixel space of an axis of the given size, then
+/// move the locations outside of the input according to the padding mode.
+fn source_coordinates<B: Backend>(
+    coordinates: Tensor<B, 3>,
+    size: usize,
+    options: &GridSampleOptions,
+) -> Tensor<B, 3> {
+    let size = size as f32;
+    let coordinates = match options.align_corners {
+        true => coordinates.add_scalar(1).mul_scalar((size - 1.0) / 2.0),
+        false => coordinates
+            .add_scalar(1)
+            .mul_scalar(size)
+            .sub_scalar(1)
+            .div_scalar(2),
+    };
+
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => coordinates,
+        GridSamplePaddingMode::Border => coordinates.clamp(0.0, size - 1.0),
+        GridSamplePaddingMode::Reflection => {
+            // Reflect by the centers of the pixels on the borders, or by their outer edges.
+            let (min, span) = match options.align_corners {
+                true => (0.0, size - 1.0),
+                false => (-0.5, size),
+            };
+
+            reflect(coordinates, min, span).clamp(0.0, size - 1.0)
+        }
+    }
+}
+
+/// Reflect the coordinates by the borders of the `[min, min + span]` interval until they are
+/// inside of it.
+fn reflect<B: Backend>(coordinates: Tensor<B, 3>, min: f32, span: f32) -> Tensor<B, 3> {
+    // An interval of a single point only contains its minimum, which is restored by the clamping
+    // of the caller.
+    let span = span.max(1.0);
+
+    let distance = coordinates.sub_scalar(min).abs();
+    let flips = distance.clone().div_scalar(span).detach().int().float();
+    let extra = distance - flips.clone().mul_scalar(span);
+
+    // The coordinates are reflected back when the number of flips is odd.
+    let odd = flips.remainder_scalar(2);
+    (extra.clone() + odd * extra.mul_scalar(-2).add_scalar(span)).add_scalar(min)
+}
+
+/// The coordinates of the pixels before and after each location along an axis.
+fn surrounding_coordinates<B: Backend>(
+    coordinates: Tensor<B, 3>,
+    size: usize,
+) -> (Tensor<B, 3>, Tensor<B, 3>) {
+    // Locations further than one pixel outside of the input only sample zeros, so clamping them
+    // keeps the coordinates positive, where the conversion to int is a floor.
+    let before = coordinates
+        .detach()
+        .clamp(-1.0, size as f32)
+        .add_scalar(1)
+        .int()
+        .float()
+        .sub_scalar(1);
+    let after = before.clone().add_scalar(1);
+
+    (before, after)
+}
+
+/// Gather the input elements at the given pixel coordinates, with zeros outside of the input.
+fn sample<B: Backend>(
+    x: Tensor<B, 3>,
+    xs: Tensor<B, 3>,
+    ys: Tensor<B, 3>,
+    [channels, height, width]: [usize; 3],
+) -> Tensor<B, 3> {
+    let valid = xs.clone().greater_equal_elem(0).float()
+        * xs.clone().lower_elem(width as f32).float()
+        * ys.clone().greater_equal_elem(0).float()
+        * ys.clone().lower_elem(height as f32).float();
+
+    let indices = (ys.clamp(0.0, height as f32 - 1.0).mul_scalar(width as f32)
+        + xs.clamp(0.0, width as f32 - 1.0))
+    .int()
+    .repeat(1, channels);
+
+    x.gather(2, indices) * valid
+}
@@ -3,8 +3,12 @@ pub mod conv;
 
 /// Module with cat operation
 pub(crate) mod cat;
+/// Module with grid sample operations.
+pub(crate) mod grid_sample;
 /// Module with repeat operation
 pub(crate) mod repeat;
+/// Module with 2:4 sparse operations.
+pub(crate) mod sparse;
 /// Module with unfold operations.
 pub(crate) mod unfold;
 
@@ -14,3 +18,5 @@ pub mod pool;
 mod base;
 
 pub use base::*;
+pub(crate) use grid_sample::affine_grid_2d;
+pub(crate) use sparse::{sparse24_compress, sparse24_decompress};
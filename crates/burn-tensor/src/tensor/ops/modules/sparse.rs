@@ -0,0 +1,81 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    Int, Tensor,
+};
+
+/// Compress a tensor to the 2:4 semi-structured sparse format, keeping the two values with the
+/// largest magnitude in each group of four consecutive rows of each column.
+///
+/// The positions of the two values kept in a group, `i0 < i1`, are encoded in a single integer
+/// `i0 + 4 * i1`.
+///
+/// # Shapes
+///
+/// tensor: `[k, n]`, where `k` is a multiple of 4,
+/// output: the values `[k / 2, n]` and the metadata `[k / 4, n]`.
+pub(crate) fn sparse24_compress<B: Backend>(
+    tensor: Tensor<B, 2>,
+) -> (Tensor<B, 2>, Tensor<B, 2, Int>) {
+    let [k, n] = tensor.dims();
+    assert!(
+        k % 4 == 0,
+        "The number of rows should be a multiple of 4, got {k}."
+    );
+    let groups = tensor.reshape([k / 4, 4, n]);
+
+    let indices = groups
+        .clone()
+        .abs()
+        .argsort_descending(1)
+        .narrow(1, 0, 2)
+        .sort(1);
+    let values = groups.gather(1, indices.clone()).reshape([k / 2, n]);
+
+    let first = indices.clone().narrow(1, 0, 1);
+    let second = indices.narrow(1, 1, 1);
+    let metadata = (first + second.mul_scalar(4)).reshape([k / 4, n]);
+
+    (values, metadata)
+}
+
+/// Decompress a tensor stored in the 2:4 semi-structured sparse format of [sparse24_compress].
+///
+/// # Shapes
+///
+/// values: `[k / 2, n]`,
+/// metadata: `[k / 4, n]`,
+/// output: `[k, n]`.
+pub(crate) fn sparse24_decompress<B: Backend>(
+    values: Tensor<B, 2>,
+    metadata: Tensor<B, 2, Int>,
+) -> Tensor<B, 2> {
+    let [num_groups, n] = metadata.dims();
+    let metadata = metadata.reshape([num_groups, 1, n]);
+
+    let indices = Tensor::cat(
+        alloc::vec![metadata.clone().remainder_scalar(4), metadata.div_scalar(4)],
+        1,
+    );
+    let values = values.reshape([num_groups, 2, n]);
+
+    Tensor::zeros([num_groups, 4, n], &values.device())
+        .scatter(1, indices, values)
+        .reshape([num_groups * 4, n])
+}
+
+/// Compute the matrix multiplication with a 2:4 sparse right hand side by decompressing it, for
+/// the backends without sparse kernels.
+pub(crate) fn sparse24_matmul_with_dense<B: Backend>(
+    lhs: FloatTensor<B, 2>,
+    values: FloatTensor<B, 2>,
+    metadata: IntTensor<B, 2>,
+) -> FloatTensor<B, 2> {
+    let lhs = Tensor::<B, 2>::from_primitive(lhs);
+    let rhs = sparse24_decompress(
+        Tensor::<B, 2>::from_primitive(values),
+        Tensor::<B, 2, Int>::from_primitive(metadata),
+    );
+
+    lhs.matmul(rhs).into_primitive()
+}
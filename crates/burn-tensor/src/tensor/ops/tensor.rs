@@ -9,9 +9,24 @@ use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
 use num_traits::ToPrimitive;
+use serde::{Deserialize, Serialize};
 
 #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-use crate::{argsort, sort, sort_with_indices};
+use crate::{argsort, linalg::fallback, scatter_reduce, sort, sort_with_indices};
+
+/// Reduction applied to the values scattered to the same position, see
+/// [scatter_reduce](crate::Tensor::scatter_reduce).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScatterReduction {
+    /// Sum of the values.
+    Sum,
+    /// Mean of the values.
+    Mean,
+    /// Maximum of the values.
+    Max,
+    /// Minimum of the values.
+    Min,
+}
 
 /// Operations on float tensors.
 pub trait FloatTensorOps<B: Backend> {
@@ -509,6 +524,53 @@ pub trait FloatTensorOps<B: Backend> {
         value: FloatTensor<B, D>,
     ) -> FloatTensor<B, D>;
 
+    /// Scatter elements into a tensor with the given reduction.
+    ///
+    /// The elements of the tensor are part of the reduction, so the elements receiving no value
+    /// are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `value` - The value to scatter.
+    /// * `reduction` - The reduction applied to the values scattered to the same position.
+    /// * `deterministic` - If the values scattered to the same position should always be reduced
+    ///   in the same order, otherwise the backend may use faster atomic operations, the rounding
+    ///   of the sums changing between executions.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the reduced elements.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_scatter_reduce<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: FloatTensor<B, D>,
+        reduction: ScatterReduction,
+        _deterministic: bool,
+    ) -> FloatTensor<B, D> {
+        match reduction {
+            ScatterReduction::Sum => B::float_scatter(dim, tensor, indices, value),
+            ScatterReduction::Mean => {
+                let device = B::float_device(&tensor);
+                let counts = B::float_scatter(
+                    dim,
+                    B::float_ones(B::float_shape(&tensor), &device),
+                    indices.clone(),
+                    B::float_ones(B::float_shape(&value), &device),
+                );
+
+                B::float_div(B::float_scatter(dim, tensor, indices, value), counts)
+            }
+            ScatterReduction::Max | ScatterReduction::Min => {
+                scatter_reduce::<B, D>(dim, tensor, indices, value, reduction)
+            }
+        }
+    }
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -1112,6 +1174,8 @@ pub trait FloatTensorOps<B: Backend> {
 
     /// Gets the indices of the maximum elements of a tensor along an axis.
     ///
+    /// When several elements are equal to the maximum, the index of the first one is returned.
+    ///
     /// # Arguments
     ///
     /// * `tensor` - The tensor to get the maximum elements of.
@@ -1124,6 +1188,8 @@ pub trait FloatTensorOps<B: Backend> {
 
     /// Gets the indices of the minimum elements of a tensor along an axis.
     ///
+    /// When several elements are equal to the minimum, the index of the first one is returned.
+    ///
     /// # Arguments
     ///
     /// * `tensor` - The tensor to get the minimum elements of.
@@ -1381,7 +1447,7 @@ pub trait FloatTensorOps<B: Backend> {
 
     /// Sort the elements of the input `tensor` by value in along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     ///
     /// # Arguments
     ///
@@ -1403,7 +1469,7 @@ pub trait FloatTensorOps<B: Backend> {
 
     /// Sort the elements of the input `tensor` by value in along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     ///
     /// # Arguments
     ///
@@ -1426,7 +1492,7 @@ pub trait FloatTensorOps<B: Backend> {
 
     /// Returns the indices that sort the elements of the input `tensor` by value along a given dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// This sort is stable (i.e., equal elements keep their relative order).
     ///
     /// # Arguments
     ///
@@ -1445,4 +1511,174 @@ pub trait FloatTensorOps<B: Backend> {
     ) -> IntTensor<B, D> {
         argsort::<B, D, Float>(tensor, dim, descending)
     }
+
+    /// Computes the Cholesky decomposition of symmetric positive-definite matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`, of which only the lower triangle is read.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular factors `L` such that `A = L L^T`, with NaN values for the matrices
+    /// that aren't positive-definite.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        fallback::cholesky::<B, D>(tensor)
+    }
+
+    /// Solves the linear systems `A X = B` of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The matrices `A` of shape `[..., n, n]`.
+    /// * `rhs` - The right-hand sides `B` of shape `[..., n, k]`.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `X` of shape `[..., n, k]`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_solve<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        fallback::solve::<B, D>(lhs, rhs)
+    }
+
+    /// Computes the inverse of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The inverses of shape `[..., n, n]`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_inverse<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        fallback::inverse::<B, D>(tensor)
+    }
+
+    /// Computes the determinant of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The determinants of shape `[..., 1, 1]`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_det<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        fallback::det::<B, D>(tensor)
+    }
+
+    /// Computes the sign and the logarithm of the absolute value of the determinant of square
+    /// matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The signs, which are zero for singular matrices, and the logarithms of the absolute values
+    /// of the determinants, both of shape `[..., 1, 1]`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_slogdet<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        fallback::slogdet::<B, D>(tensor)
+    }
+
+    /// Computes the Moore-Penrose pseudo-inverse of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    /// * `rcond` - The singular values smaller than `rcond` times the largest singular value are
+    ///   treated as zeros.
+    ///
+    /// # Returns
+    ///
+    /// The pseudo-inverses of shape `[..., n, m]`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_pinverse<const D: usize>(tensor: FloatTensor<B, D>, rcond: f64) -> FloatTensor<B, D> {
+        fallback::pinverse::<B, D>(tensor, rcond)
+    }
+
+    /// Computes the reduced QR decomposition of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The factors `Q` of shape `[..., m, k]` with orthonormal columns and `R` of shape
+    /// `[..., k, n]`, upper triangular with a non-negative diagonal, where `k = min(m, n)`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_qr<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        fallback::qr::<B, D>(tensor)
+    }
+
+    /// Computes the thin singular value decomposition of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The left singular vectors `U` of shape `[..., m, k]`, the singular values `S` in
+    /// descending order of shape `[..., 1, k]` and the right singular vectors `V^T` of shape
+    /// `[..., k, n]`, where `k = min(m, n)`.
+    ///
+    /// The decomposition isn't differentiable, so autodiff backends return outputs detached from
+    /// the graph of the input.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+        fallback::svd::<B, D>(tensor)
+    }
+
+    /// Computes the eigendecomposition of symmetric matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The symmetric matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues in ascending order of shape `[..., 1, n]` and the eigenvectors as the
+    /// columns of matrices of shape `[..., n, n]`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        fallback::eigh::<B, D>(tensor)
+    }
+
+    /// Returns the bit patterns of the elements of the float `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor of shape `[num_elements, num_bytes]`, where each row contains the bytes of an
+    /// element of `tensor`, in row-major order, from the least significant to the most
+    /// significant. There are 8 bytes per element for `f64`, 4 for `f32` and 2 for `f16` and
+    /// `bf16`, each in `[0, 255]`.
+    ///
+    /// The default implementation reads the tensor on the host, backends should reinterpret the
+    /// elements on the device.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_bits<const D: usize>(tensor: FloatTensor<B, D>) -> IntTensor<B, 2> {
+        super::float_bits_on_host::<B, D>(tensor)
+    }
 }
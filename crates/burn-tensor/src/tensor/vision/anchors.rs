@@ -0,0 +1,68 @@
+use crate::backend::Backend;
+use crate::{Data, Device, Shape, Tensor};
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Generates the anchor boxes of a feature map, as in the region proposal networks of
+/// [Faster R-CNN](https://arxiv.org/abs/1506.01497) and the heads of
+/// [RetinaNet](https://arxiv.org/abs/1708.02002).
+///
+/// Each location of the feature map has an anchor for each combination of aspect ratio and size,
+/// centered on the top-left corner of the location in the image.
+///
+/// # Arguments
+///
+/// * `sizes` - The square root of the area of the anchors, in pixels.
+/// * `aspect_ratios` - The `height / width` ratios of the anchors.
+/// * `feature_size` - The `[height, width]` of the feature map.
+/// * `stride` - The `[height, width]` stride of the feature map, in pixels.
+/// * `device` - The device of the anchors.
+///
+/// # Returns
+///
+/// The anchors of shape `[height * width * num_anchors, 4]` in `(x1, y1, x2, y2)` format, where
+/// `num_anchors = aspect_ratios.len() * sizes.len()`, ordered by location, then aspect ratio, then
+/// size.
+pub fn generate_anchors<B: Backend>(
+    sizes: &[f32],
+    aspect_ratios: &[f32],
+    feature_size: [usize; 2],
+    stride: [usize; 2],
+    device: &Device<B>,
+) -> Tensor<B, 2> {
+    let [height, width] = feature_size;
+    let [stride_y, stride_x] = stride;
+
+    // The anchors centered at zero, with rounded coordinates.
+    let base: Vec<[f32; 4]> = aspect_ratios
+        .iter()
+        .flat_map(|ratio| {
+            let ratio_height = ratio.sqrt();
+            sizes.iter().map(move |size| {
+                let half_width = size / ratio_height / 2.0;
+                let half_height = size * ratio_height / 2.0;
+                [-half_width, -half_height, half_width, half_height].map(f32::round)
+            })
+        })
+        .collect();
+
+    let num_anchors = height * width * base.len();
+    let mut anchors = Vec::with_capacity(num_anchors * 4);
+
+    for y in 0..height {
+        for x in 0..width {
+            let shift_x = (x * stride_x) as f32;
+            let shift_y = (y * stride_y) as f32;
+            for [x1, y1, x2, y2] in base.iter() {
+                anchors.extend([x1 + shift_x, y1 + shift_y, x2 + shift_x, y2 + shift_y]);
+            }
+        }
+    }
+
+    Tensor::from_data(
+        Data::new(anchors, Shape::new([num_anchors, 4])).convert(),
+        device,
+    )
+}
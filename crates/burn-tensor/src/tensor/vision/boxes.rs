@@ -0,0 +1,370 @@
+use crate::backend::Backend;
+use crate::{Data, Device, ElementConversion, Int, Shape, Tensor};
+use alloc::vec::Vec;
+
+/// Computes the area of a set of boxes.
+///
+/// # Arguments
+///
+/// * `boxes` - The boxes of shape `[num_boxes, 4]` in `(x1, y1, x2, y2)` format.
+///
+/// # Returns
+///
+/// A tensor of shape `[num_boxes]` containing the area of each box. Degenerate boxes (where
+/// `x2 < x1` or `y2 < y1`) have an area of zero.
+pub fn box_area<B: Backend>(boxes: Tensor<B, 2>) -> Tensor<B, 1> {
+    let [num_boxes, _] = boxes.dims();
+    let (x1, y1, x2, y2) = box_coordinates(boxes);
+
+    let width = x2.sub(x1).clamp_min(0.0);
+    let height = y2.sub(y1).clamp_min(0.0);
+
+    width.mul(height).reshape([num_boxes])
+}
+
+/// Computes the intersection over union (Jaccard index) between two sets of boxes.
+///
+/// # Arguments
+///
+/// * `boxes1` - The first set of boxes of shape `[n, 4]` in `(x1, y1, x2, y2)` format.
+/// * `boxes2` - The second set of boxes of shape `[m, 4]` in `(x1, y1, x2, y2)` format.
+///
+/// # Returns
+///
+/// A tensor of shape `[n, m]` where the element at `[i, j]` is the IoU between `boxes1[i]` and
+/// `boxes2[j]`.
+pub fn box_iou<B: Backend>(boxes1: Tensor<B, 2>, boxes2: Tensor<B, 2>) -> Tensor<B, 2> {
+    let [n, _] = boxes1.dims();
+    let [m, _] = boxes2.dims();
+
+    let area1 = box_area(boxes1.clone()).reshape([n, 1]).expand([n, m]);
+    let area2 = box_area(boxes2.clone()).reshape([1, m]).expand([n, m]);
+
+    let (a_x1, a_y1, a_x2, a_y2) = box_coordinates(boxes1);
+    let (b_x1, b_y1, b_x2, b_y2) = box_coordinates(boxes2);
+
+    // Each coordinate of the first set is broadcast along the columns, and each coordinate of the
+    // second set along the rows, so that every pair of boxes is compared.
+    let rows = |tensor: Tensor<B, 2>| tensor.expand([n, m]);
+    let cols = |tensor: Tensor<B, 2>| tensor.reshape([1, m]).expand([n, m]);
+
+    let left = rows(a_x1).max_pair(cols(b_x1));
+    let top = rows(a_y1).max_pair(cols(b_y1));
+    let right = rows(a_x2).min_pair(cols(b_x2));
+    let bottom = rows(a_y2).min_pair(cols(b_y2));
+
+    let intersection = right
+        .sub(left)
+        .clamp_min(0.0)
+        .mul(bottom.sub(top).clamp_min(0.0));
+    let union = area1.add(area2).sub(intersection.clone());
+
+    // Avoid a division by zero when both boxes are degenerate.
+    intersection.div(union.clamp_min(f32::EPSILON))
+}
+
+/// Performs non-maximum suppression (NMS) on the boxes according to their intersection over
+/// union (IoU).
+///
+/// Boxes are processed in decreasing order of score, and any box that has an IoU greater than
+/// `iou_threshold` with an already selected box is discarded.
+///
+/// # Arguments
+///
+/// * `boxes` - The boxes of shape `[num_boxes, 4]` in `(x1, y1, x2, y2)` format.
+/// * `scores` - The score of each box of shape `[num_boxes]`.
+/// * `iou_threshold` - Boxes with an IoU strictly greater than this value are suppressed.
+///
+/// # Returns
+///
+/// The indices of the kept boxes, sorted in decreasing order of score.
+///
+/// # Notes
+///
+/// The number of kept boxes depends on the data, so the IoU matrix must be read back to the host.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn nms<B: Backend>(
+    boxes: Tensor<B, 2>,
+    scores: Tensor<B, 1>,
+    iou_threshold: f32,
+) -> Tensor<B, 1, Int> {
+    let device = boxes.device();
+    let [num_boxes, _] = boxes.dims();
+
+    if num_boxes == 0 {
+        let data = Data::<B::IntElem, 1>::new(Vec::new(), Shape::new([0]));
+        return Tensor::from_data(data, &device);
+    }
+
+    let order = scores.argsort_descending(0);
+    let sorted = boxes.select(0, order.clone());
+    let ious = box_iou(sorted.clone(), sorted);
+
+    nms_data::<B>(ious.into_data(), order.into_data(), iou_threshold, &device)
+}
+
+/// Performs non-maximum suppression (NMS) on the boxes according to their intersection over
+/// union (IoU).
+///
+/// Boxes are processed in decreasing order of score, and any box that has an IoU greater than
+/// `iou_threshold` with an already selected box is discarded.
+///
+/// # Arguments
+///
+/// * `boxes` - The boxes of shape `[num_boxes, 4]` in `(x1, y1, x2, y2)` format.
+/// * `scores` - The score of each box of shape `[num_boxes]`.
+/// * `iou_threshold` - Boxes with an IoU strictly greater than this value are suppressed.
+///
+/// # Returns
+///
+/// The indices of the kept boxes, sorted in decreasing order of score.
+///
+/// # Notes
+///
+/// The number of kept boxes depends on the data, so the IoU matrix must be read back to the host.
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+pub async fn nms<B: Backend>(
+    boxes: Tensor<B, 2>,
+    scores: Tensor<B, 1>,
+    iou_threshold: f32,
+) -> Tensor<B, 1, Int> {
+    let device = boxes.device();
+    let [num_boxes, _] = boxes.dims();
+
+    if num_boxes == 0 {
+        let data = Data::<B::IntElem, 1>::new(Vec::new(), Shape::new([0]));
+        return Tensor::from_data(data, &device);
+    }
+
+    let order = scores.argsort_descending(0);
+    let sorted = boxes.select(0, order.clone());
+    let ious = box_iou(sorted.clone(), sorted);
+
+    nms_data::<B>(
+        ious.into_data().await,
+        order.into_data().await,
+        iou_threshold,
+        &device,
+    )
+}
+
+/// Performs non-maximum suppression (NMS) independently for each category.
+///
+/// Boxes belonging to different categories never suppress each other. This is done by offsetting
+/// every box by its category index times the largest coordinate, so that boxes of different
+/// categories never overlap, and then running a single [nms] pass over all the boxes.
+///
+/// # Arguments
+///
+/// * `boxes` - The boxes of shape `[num_boxes, 4]` in `(x1, y1, x2, y2)` format.
+/// * `scores` - The score of each box of shape `[num_boxes]`.
+/// * `categories` - The category index of each box of shape `[num_boxes]`.
+/// * `iou_threshold` - Boxes with an IoU strictly greater than this value are suppressed.
+///
+/// # Returns
+///
+/// The indices of the kept boxes, sorted in decreasing order of score.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn batched_nms<B: Backend>(
+    boxes: Tensor<B, 2>,
+    scores: Tensor<B, 1>,
+    categories: Tensor<B, 1, Int>,
+    iou_threshold: f32,
+) -> Tensor<B, 1, Int> {
+    let boxes = offset_boxes_by_category(boxes, categories);
+
+    nms(boxes, scores, iou_threshold)
+}
+
+/// Performs non-maximum suppression (NMS) independently for each category.
+///
+/// Boxes belonging to different categories never suppress each other. This is done by offsetting
+/// every box by its category index times the largest coordinate, so that boxes of different
+/// categories never overlap, and then running a single [nms] pass over all the boxes.
+///
+/// # Arguments
+///
+/// * `boxes` - The boxes of shape `[num_boxes, 4]` in `(x1, y1, x2, y2)` format.
+/// * `scores` - The score of each box of shape `[num_boxes]`.
+/// * `categories` - The category index of each box of shape `[num_boxes]`.
+/// * `iou_threshold` - Boxes with an IoU strictly greater than this value are suppressed.
+///
+/// # Returns
+///
+/// The indices of the kept boxes, sorted in decreasing order of score.
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+pub async fn batched_nms<B: Backend>(
+    boxes: Tensor<B, 2>,
+    scores: Tensor<B, 1>,
+    categories: Tensor<B, 1, Int>,
+    iou_threshold: f32,
+) -> Tensor<B, 1, Int> {
+    let boxes = offset_boxes_by_category(boxes, categories);
+
+    nms(boxes, scores, iou_threshold).await
+}
+
+/// The largest scale of the decoded boxes relative to their anchors, which avoids overflowing
+/// the exponential of large deltas, as in [Detectron](https://github.com/facebookresearch/Detectron).
+const MAX_SCALE_DELTA: f32 = 4.135_167; // ln(1000 / 16)
+
+/// Encodes the boxes as the deltas relative to their anchors regressed by the detection heads of
+/// [Faster R-CNN](https://arxiv.org/abs/1506.01497).
+///
+/// The deltas are the offsets of the centers normalized by the size of the anchors and the log of
+/// the ratios of the sizes, each multiplied by its weight:
+///
+/// `(wx * (cx - cx_a) / w_a, wy * (cy - cy_a) / h_a, ww * ln(w / w_a), wh * ln(h / h_a))`
+///
+/// # Arguments
+///
+/// * `boxes` - The boxes of shape `[num_boxes, 4]` in `(x1, y1, x2, y2)` format.
+/// * `anchors` - The anchor of each box of shape `[num_boxes, 4]` in `(x1, y1, x2, y2)` format.
+/// * `weights` - The `(wx, wy, ww, wh)` weights of the deltas, e.g. `[1.0, 1.0, 1.0, 1.0]`.
+///
+/// # Returns
+///
+/// The deltas of shape `[num_boxes, 4]`.
+pub fn encode_boxes<B: Backend>(
+    boxes: Tensor<B, 2>,
+    anchors: Tensor<B, 2>,
+    weights: [f32; 4],
+) -> Tensor<B, 2> {
+    let (cx, cy, w, h) = box_centers(boxes);
+    let (anchor_cx, anchor_cy, anchor_w, anchor_h) = box_centers(anchors);
+    let [weight_x, weight_y, weight_w, weight_h] = weights;
+
+    Tensor::cat(
+        alloc::vec![
+            cx.sub(anchor_cx).div(anchor_w.clone()).mul_scalar(weight_x),
+            cy.sub(anchor_cy).div(anchor_h.clone()).mul_scalar(weight_y),
+            w.div(anchor_w).log().mul_scalar(weight_w),
+            h.div(anchor_h).log().mul_scalar(weight_h),
+        ],
+        1,
+    )
+}
+
+/// Decodes the deltas regressed by a detection head into boxes, the inverse of [encode_boxes].
+///
+/// The scale deltas are clamped to `ln(1000 / 16)`, so the boxes stay finite early in training.
+///
+/// # Arguments
+///
+/// * `deltas` - The deltas of shape `[num_boxes, 4]`.
+/// * `anchors` - The anchor of each box of shape `[num_boxes, 4]` in `(x1, y1, x2, y2)` format.
+/// * `weights` - The `(wx, wy, ww, wh)` weights used to [encode](encode_boxes) the boxes.
+///
+/// # Returns
+///
+/// The boxes of shape `[num_boxes, 4]` in `(x1, y1, x2, y2)` format.
+pub fn decode_boxes<B: Backend>(
+    deltas: Tensor<B, 2>,
+    anchors: Tensor<B, 2>,
+    weights: [f32; 4],
+) -> Tensor<B, 2> {
+    let (anchor_cx, anchor_cy, anchor_w, anchor_h) = box_centers(anchors);
+    let (dx, dy, dw, dh) = box_coordinates(deltas);
+    let [weight_x, weight_y, weight_w, weight_h] = weights;
+
+    let cx = dx.div_scalar(weight_x).mul(anchor_w.clone()).add(anchor_cx);
+    let cy = dy.div_scalar(weight_y).mul(anchor_h.clone()).add(anchor_cy);
+    let half_w = dw
+        .div_scalar(weight_w)
+        .clamp_max(MAX_SCALE_DELTA)
+        .exp()
+        .mul(anchor_w)
+        .div_scalar(2.0);
+    let half_h = dh
+        .div_scalar(weight_h)
+        .clamp_max(MAX_SCALE_DELTA)
+        .exp()
+        .mul(anchor_h)
+        .div_scalar(2.0);
+
+    Tensor::cat(
+        alloc::vec![
+            cx.clone().sub(half_w.clone()),
+            cy.clone().sub(half_h.clone()),
+            cx.add(half_w),
+            cy.add(half_h),
+        ],
+        1,
+    )
+}
+
+fn offset_boxes_by_category<B: Backend>(
+    boxes: Tensor<B, 2>,
+    categories: Tensor<B, 1, Int>,
+) -> Tensor<B, 2> {
+    let [num_boxes, _] = boxes.dims();
+
+    if num_boxes == 0 {
+        return boxes;
+    }
+
+    // The offset stays on the device, no need to read the maximum coordinate back.
+    let max_coordinate = boxes.clone().max().add_scalar(1.0).reshape([1, 1]);
+    let offsets = categories
+        .float()
+        .reshape([num_boxes, 1])
+        .mul(max_coordinate.expand([num_boxes, 1]));
+
+    boxes.add(offsets.expand([num_boxes, 4]))
+}
+
+fn nms_data<B: Backend>(
+    ious: Data<B::FloatElem, 2>,
+    order: Data<B::IntElem, 1>,
+    iou_threshold: f32,
+    device: &Device<B>,
+) -> Tensor<B, 1, Int> {
+    let [num_boxes] = order.shape.dims;
+    let mut suppressed = alloc::vec![false; num_boxes];
+    let mut keep = Vec::new();
+
+    for i in 0..num_boxes {
+        if suppressed[i] {
+            continue;
+        }
+
+        keep.push(order.value[i]);
+
+        for j in (i + 1)..num_boxes {
+            if !suppressed[j] && ious.value[i * num_boxes + j].elem::<f32>() > iou_threshold {
+                suppressed[j] = true;
+            }
+        }
+    }
+
+    let num_kept = keep.len();
+    Tensor::from_data(Data::new(keep, Shape::new([num_kept])), device)
+}
+
+fn box_coordinates<B: Backend>(
+    boxes: Tensor<B, 2>,
+) -> (Tensor<B, 2>, Tensor<B, 2>, Tensor<B, 2>, Tensor<B, 2>) {
+    let [num_boxes, _] = boxes.dims();
+
+    (
+        boxes.clone().slice([0..num_boxes, 0..1]),
+        boxes.clone().slice([0..num_boxes, 1..2]),
+        boxes.clone().slice([0..num_boxes, 2..3]),
+        boxes.slice([0..num_boxes, 3..4]),
+    )
+}
+
+fn box_centers<B: Backend>(
+    boxes: Tensor<B, 2>,
+) -> (Tensor<B, 2>, Tensor<B, 2>, Tensor<B, 2>, Tensor<B, 2>) {
+    let (x1, y1, x2, y2) = box_coordinates(boxes);
+    let width = x2.sub(x1.clone());
+    let height = y2.sub(y1.clone());
+
+    (
+        x1.add(width.clone().div_scalar(2.0)),
+        y1.add(height.clone().div_scalar(2.0)),
+        width,
+        height,
+    )
+}
@@ -0,0 +1,109 @@
+use crate::backend::Backend;
+use crate::{Bool, Int, Tensor};
+use alloc::vec;
+
+/// The pixels considered adjacent by [connected_components].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// The pixels sharing an edge.
+    Four,
+    /// The pixels sharing an edge or a corner.
+    Eight,
+}
+
+/// Labels the connected components of the foreground of each mask.
+///
+/// Each foreground pixel starts with its own label, then the largest label of the neighbors is
+/// propagated to each pixel, and each label jumps to the label of the pixel it points to, until
+/// the labels don't change anymore. All the operations run on the device, only the convergence
+/// flag is read back after each iteration.
+///
+/// # Arguments
+///
+/// * `masks` - The masks of shape `[batch_size, height, width]`, true for the foreground.
+/// * `connectivity` - The pixels considered adjacent.
+///
+/// # Returns
+///
+/// The labels of shape `[batch_size, height, width]`, zero for the background. The labels aren't
+/// consecutive: each component is labeled with the largest index of its pixels in the flattened
+/// masks, plus one, so the labels of different masks are distinct.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn connected_components<B: Backend>(
+    masks: Tensor<B, 3, Bool>,
+    connectivity: Connectivity,
+) -> Tensor<B, 3, Int> {
+    let [batch_size, height, width] = masks.dims();
+    let num_pixels = batch_size * height * width;
+    let foreground = masks.int();
+
+    let mut labels = Tensor::<B, 1, Int>::arange(1..num_pixels as i64 + 1, &foreground.device())
+        .reshape([batch_size, height, width])
+        .mul(foreground.clone());
+
+    loop {
+        let previous = labels.clone();
+
+        let propagated = neighbors_max(labels, connectivity).mul(foreground.clone());
+        // The label of each pixel is the label of a pixel of the same component plus one, whose
+        // own label is at least as large.
+        let pointers = propagated
+            .clone()
+            .reshape([num_pixels])
+            .sub_scalar(1)
+            .clamp_min(0);
+        labels = propagated
+            .reshape([num_pixels])
+            .select(0, pointers)
+            .reshape([batch_size, height, width])
+            .mul(foreground.clone());
+
+        if labels.clone().equal(previous).all().into_scalar() {
+            return labels;
+        }
+    }
+}
+
+fn neighbors_max<B: Backend>(
+    labels: Tensor<B, 3, Int>,
+    connectivity: Connectivity,
+) -> Tensor<B, 3, Int> {
+    let offsets = match connectivity {
+        Connectivity::Four => vec![(-1, 0), (1, 0), (0, -1), (0, 1)],
+        Connectivity::Eight => vec![
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ],
+    };
+
+    offsets.into_iter().fold(labels.clone(), |max, (dy, dx)| {
+        max.max_pair(shift(shift(labels.clone(), 1, dy), 2, dx))
+    })
+}
+
+/// Shifts the tensor along a dimension, the element at `i` becoming the element at `i + offset`
+/// and the elements shifted in being zeros.
+fn shift<B: Backend>(tensor: Tensor<B, 3, Int>, dim: usize, offset: isize) -> Tensor<B, 3, Int> {
+    let size = tensor.dims()[dim];
+    if offset == 0 {
+        return tensor;
+    }
+
+    let mut shape = tensor.dims();
+    shape[dim] = 1;
+    let zeros = Tensor::zeros(shape, &tensor.device());
+    if size == 1 {
+        return zeros;
+    }
+
+    match offset > 0 {
+        true => Tensor::cat(vec![tensor.narrow(dim, 1, size - 1), zeros], dim),
+        false => Tensor::cat(vec![zeros, tensor.narrow(dim, 0, size - 1)], dim),
+    }
+}
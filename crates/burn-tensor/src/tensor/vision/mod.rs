@@ -0,0 +1,11 @@
+mod anchors;
+mod boxes;
+mod components;
+mod morphology;
+mod roi_align;
+
+pub use anchors::*;
+pub use boxes::*;
+pub use components::*;
+pub use morphology::*;
+pub use roi_align::*;
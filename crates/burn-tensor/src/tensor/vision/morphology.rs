@@ -0,0 +1,117 @@
+use crate::backend::Backend;
+use crate::module::max_pool2d;
+use crate::{Bool, Data, Device, Shape, Tensor};
+use alloc::vec::Vec;
+
+/// Dilates the images with a rectangular structuring element, each pixel becoming the maximum
+/// of its neighborhood.
+///
+/// Binary masks can be dilated as float tensors of zeros and ones.
+///
+/// # Arguments
+///
+/// * `images` - The images of shape `[batch_size, channels, height, width]`.
+/// * `kernel_size` - The odd `[height, width]` of the structuring element.
+///
+/// # Returns
+///
+/// The dilated images of the same shape as the images.
+pub fn dilate<B: Backend>(images: Tensor<B, 4>, kernel_size: [usize; 2]) -> Tensor<B, 4> {
+    assert!(
+        kernel_size.iter().all(|size| size % 2 == 1),
+        "The kernel size should be odd, got {kernel_size:?}."
+    );
+    let [kernel_height, kernel_width] = kernel_size;
+
+    max_pool2d(
+        images,
+        kernel_size,
+        [1, 1],
+        [kernel_height / 2, kernel_width / 2],
+        [1, 1],
+    )
+}
+
+/// Erodes the images with a rectangular structuring element, each pixel becoming the minimum
+/// of its neighborhood.
+///
+/// The pixels outside of the images are ignored, so the borders aren't eroded.
+///
+/// # Arguments
+///
+/// * `images` - The images of shape `[batch_size, channels, height, width]`.
+/// * `kernel_size` - The odd `[height, width]` of the structuring element.
+///
+/// # Returns
+///
+/// The eroded images of the same shape as the images.
+pub fn erode<B: Backend>(images: Tensor<B, 4>, kernel_size: [usize; 2]) -> Tensor<B, 4> {
+    dilate(images.neg(), kernel_size).neg()
+}
+
+/// Opens the images, an [erosion](erode) followed by a [dilation](dilate), which removes the
+/// structures smaller than the structuring element.
+pub fn opening<B: Backend>(images: Tensor<B, 4>, kernel_size: [usize; 2]) -> Tensor<B, 4> {
+    dilate(erode(images, kernel_size), kernel_size)
+}
+
+/// Closes the images, a [dilation](dilate) followed by an [erosion](erode), which fills the
+/// holes smaller than the structuring element.
+pub fn closing<B: Backend>(images: Tensor<B, 4>, kernel_size: [usize; 2]) -> Tensor<B, 4> {
+    erode(dilate(images, kernel_size), kernel_size)
+}
+
+/// Computes the exact Euclidean distance of each pixel of the masks to the nearest background
+/// pixel, the background pixels having a distance of zero.
+///
+/// The squared distances are computed separably along the columns then the rows, as the minimum
+/// over all the pixels of each column and row, which takes `O(height * width * (height + width))`
+/// operations and memory per mask but runs in parallel on the device.
+///
+/// # Arguments
+///
+/// * `masks` - The masks of shape `[batch_size, height, width]`, true for the foreground.
+///
+/// # Returns
+///
+/// The distances of shape `[batch_size, height, width]`. The masks without background pixels get
+/// distances greater than their diagonal.
+pub fn distance_transform<B: Backend>(masks: Tensor<B, 3, Bool>) -> Tensor<B, 3> {
+    let [batch_size, height, width] = masks.dims();
+    let device = masks.device();
+    let infinity = (height * height + width * width) as f32;
+
+    let distances =
+        Tensor::<B, 3>::zeros([batch_size, height, width], &device).mask_fill(masks, infinity);
+
+    // Along the columns: d[b, i, j] = min_k (i - k)^2 + d[b, k, j].
+    let distances = distances
+        .reshape([batch_size, 1, height, width])
+        .add(squared_offsets::<B>(height, &device).reshape([1, height, height, 1]))
+        .min_dim(2)
+        .reshape([batch_size, height, 1, width]);
+
+    // Along the rows: d[b, i, j] = min_k (j - k)^2 + d[b, i, k].
+    distances
+        .add(squared_offsets::<B>(width, &device).reshape([1, 1, width, width]))
+        .min_dim(3)
+        .reshape([batch_size, height, width])
+        .sqrt()
+}
+
+/// The squared offsets `(i - k)^2` between the indices of an axis, of shape `[size, size]`.
+fn squared_offsets<B: Backend>(size: usize, device: &Device<B>) -> Tensor<B, 2> {
+    let offsets: Vec<f32> = (0..size)
+        .flat_map(|i| {
+            (0..size).map(move |k| {
+                let offset = i as f32 - k as f32;
+                offset * offset
+            })
+        })
+        .collect();
+
+    Tensor::from_data(
+        Data::new(offsets, Shape::new([size, size])).convert(),
+        device,
+    )
+}
@@ -0,0 +1,170 @@
+use crate::backend::Backend;
+use crate::{Data, Int, Shape, Tensor};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Pools the features of each region of interest into a fixed size with bilinear sampling, as in
+/// [Mask R-CNN](https://arxiv.org/abs/1703.06870).
+///
+/// Each region is divided into `output_size` bins, and each bin averages `sampling_ratio` by
+/// `sampling_ratio` regularly spaced samples bilinearly interpolated from the features. The
+/// samples outside of the feature maps are zeros.
+///
+/// The output is computed with [select](Tensor::select) on the flattened features, so it is
+/// differentiable with respect to the features on any backend.
+///
+/// # Arguments
+///
+/// * `features` - The feature maps of shape `[batch_size, channels, height, width]`.
+/// * `rois` - The regions of shape `[num_rois, 5]` in `(batch_index, x1, y1, x2, y2)` format,
+///   in the coordinates of the input image.
+/// * `output_size` - The `[height, width]` of the pooled features.
+/// * `spatial_scale` - The scale from the image coordinates to the feature map coordinates, e.g.
+///   `1 / 16` for features with a stride of 16.
+/// * `sampling_ratio` - The number of samples of each bin along each axis.
+/// * `aligned` - If the pixel centers are shifted by half a pixel, which aligns the samples with
+///   the pixels. Otherwise, the regions are at least one pixel wide, as in the original RoIAlign.
+///
+/// # Returns
+///
+/// The pooled features of shape `[num_rois, channels, output_height, output_width]`.
+///
+/// # Panics
+///
+/// If the sampling ratio is zero.
+pub fn roi_align<B: Backend>(
+    features: Tensor<B, 4>,
+    rois: Tensor<B, 2>,
+    output_size: [usize; 2],
+    spatial_scale: f32,
+    sampling_ratio: usize,
+    aligned: bool,
+) -> Tensor<B, 4> {
+    assert!(
+        sampling_ratio > 0,
+        "The sampling ratio should be greater than zero."
+    );
+
+    let [batch_size, channels, height, width] = features.dims();
+    let [num_rois, _] = rois.dims();
+    let [output_height, output_width] = output_size;
+    let offset = if aligned { 0.5 } else { 0.0 };
+
+    let coordinate = |column: usize| {
+        rois.clone()
+            .slice([0..num_rois, column..column + 1])
+            .mul_scalar(spatial_scale)
+            .sub_scalar(offset)
+    };
+    let (x1, y1, x2, y2) = (coordinate(1), coordinate(2), coordinate(3), coordinate(4));
+    let roi_width = x2.sub(x1.clone());
+    let roi_height = y2.sub(y1.clone());
+    let (roi_width, roi_height) = match aligned {
+        true => (roi_width, roi_height),
+        false => (roi_width.clamp_min(1.0), roi_height.clamp_min(1.0)),
+    };
+
+    let (index_y, weight_y) = sample_axis(y1, roi_height, output_height, sampling_ratio, height);
+    let (index_x, weight_x) = sample_axis(x1, roi_width, output_width, sampling_ratio, width);
+
+    // Each sample interpolates the 2 x 2 pixels around it.
+    let num_y = output_height * sampling_ratio;
+    let num_x = output_width * sampling_ratio;
+    let shape = [num_rois, num_y, 2, num_x, 2];
+    let num_samples = shape.iter().product::<usize>();
+
+    let batch = rois
+        .slice([0..num_rois, 0..1])
+        .int()
+        .mul_scalar((height * width) as i64)
+        .reshape([num_rois, 1, 1, 1, 1])
+        .expand(shape);
+    let rows = index_y
+        .mul_scalar(width as i64)
+        .reshape([num_rois, num_y, 2, 1, 1])
+        .expand(shape);
+    let columns = index_x.reshape([num_rois, 1, 1, num_x, 2]).expand(shape);
+    let indices = batch.add(rows).add(columns).reshape([num_samples]);
+    let weights = weight_y
+        .reshape([num_rois, num_y, 2, 1, 1])
+        .mul(weight_x.reshape([num_rois, 1, 1, num_x, 2]))
+        .reshape([num_samples, 1]);
+
+    let pixels = features
+        .permute([0, 2, 3, 1])
+        .reshape([batch_size * height * width, channels]);
+
+    pixels
+        .select(0, indices)
+        .mul(weights)
+        .reshape([
+            num_rois,
+            output_height,
+            sampling_ratio * 2,
+            output_width,
+            sampling_ratio * 2,
+            channels,
+        ])
+        .sum_dim(4)
+        .sum_dim(2)
+        .reshape([num_rois, output_height, output_width, channels])
+        .div_scalar((sampling_ratio * sampling_ratio) as f32)
+        .permute([0, 3, 1, 2])
+}
+
+/// Computes the pixels interpolated by the samples of each region along an axis, and their
+/// bilinear weights, both of shape `[num_rois, num_bins * sampling_ratio, 2]`.
+fn sample_axis<B: Backend>(
+    start: Tensor<B, 2>,
+    size: Tensor<B, 2>,
+    num_bins: usize,
+    sampling_ratio: usize,
+    length: usize,
+) -> (Tensor<B, 3, Int>, Tensor<B, 3>) {
+    let [num_rois, _] = start.dims();
+    let num_samples = num_bins * sampling_ratio;
+
+    // The position of each sample in bins, at the center of its sub-bin.
+    let steps: Vec<f32> = (0..num_samples)
+        .map(|sample| {
+            let sub_bin = (sample % sampling_ratio) as f32 + 0.5;
+            (sample / sampling_ratio) as f32 + sub_bin / sampling_ratio as f32
+        })
+        .collect();
+    let steps = Tensor::<B, 1>::from_data(
+        Data::new(steps, Shape::new([num_samples])).convert(),
+        &start.device(),
+    )
+    .reshape([1, num_samples]);
+
+    let positions = start.add(steps.mul(size.div_scalar(num_bins as f32)));
+    let valid = positions
+        .clone()
+        .greater_equal_elem(-1.0)
+        .float()
+        .mul(positions.clone().lower_equal_elem(length as f32).float());
+
+    // The positions are non-negative, so the conversion to integers is their floor.
+    let positions = positions.clamp(0.0, (length - 1) as f32);
+    let low = positions.clone().int();
+    let high = low.clone().add_scalar(1).clamp_max(length as i64 - 1);
+    let fraction = positions.sub(low.clone().float());
+
+    let column = |tensor: Tensor<B, 2>| tensor.reshape([num_rois, num_samples, 1]);
+    let weights = Tensor::cat(
+        vec![
+            column(fraction.clone().neg().add_scalar(1.0).mul(valid.clone())),
+            column(fraction.mul(valid)),
+        ],
+        2,
+    );
+    let indices = Tensor::cat(
+        vec![
+            low.reshape([num_rois, num_samples, 1]),
+            high.reshape([num_rois, num_samples, 1]),
+        ],
+        2,
+    );
+
+    (indices, weights)
+}
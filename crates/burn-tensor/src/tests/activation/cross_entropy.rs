@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(cross_entropy)]
+mod tests {
+    use super::*;
+    use burn_tensor::{loss, Data};
+
+    #[test]
+    fn test_cross_entropy() {
+        let logits = TestTensor::from([[1.0, 2.0, 3.0], [0.5, -1.0, 2.0]]);
+        let targets = TestTensorInt::from([0, 2]);
+
+        let data_actual = loss::cross_entropy(logits, targets).into_data();
+
+        let data_expected = Data::from([2.4076059, 0.2413113]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+
+    #[test]
+    fn test_cross_entropy_numerical_stability() {
+        let logits = TestTensor::from([[1000.0, 0.0, -1000.0], [-1000.0, -1000.0, -1000.0]]);
+        let targets = TestTensorInt::from([1, 2]);
+
+        let data_actual = loss::cross_entropy(logits, targets).into_data();
+
+        let data_expected = Data::from([1000.0, 1.0986123]);
+        data_actual.assert_approx_eq(&data_expected, 3);
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod cross_entropy;
 pub(crate) mod gelu;
 pub(crate) mod leaky_relu;
 pub(crate) mod log_sigmoid;
@@ -0,0 +1,87 @@
+#[burn_tensor_testgen::testgen(consistency)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::consistency::{compare_backends, OpSequence};
+    use burn_tensor::module::conv2d;
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::{activation, Tensor};
+
+    const TOLERANCE: f64 = 1e-3;
+
+    struct MatmulSoftmax;
+
+    impl OpSequence<2, 2> for MatmulSoftmax {
+        fn run<B: Backend>(&self, mut inputs: Vec<Tensor<B, 2>>) -> Tensor<B, 2> {
+            let rhs = inputs.pop().unwrap();
+            let lhs = inputs.pop().unwrap();
+
+            activation::softmax(lhs.matmul(rhs), 1)
+        }
+    }
+
+    struct Conv2dRelu;
+
+    impl OpSequence<4, 4> for Conv2dRelu {
+        fn run<B: Backend>(&self, mut inputs: Vec<Tensor<B, 4>>) -> Tensor<B, 4> {
+            let weight = inputs.pop().unwrap();
+            let x = inputs.pop().unwrap();
+            let options = ConvOptions::new([1, 1], [1, 1], [1, 1], 1);
+
+            activation::relu(conv2d(x.sub_scalar(0.5), weight, None, options))
+        }
+    }
+
+    struct Normalize;
+
+    impl OpSequence<3, 3> for Normalize {
+        fn run<B: Backend>(&self, mut inputs: Vec<Tensor<B, 3>>) -> Tensor<B, 3> {
+            let x = inputs.pop().unwrap();
+            let (var, mean) = x.clone().var_mean_bias(2);
+
+            x.sub(mean).div(var.add_scalar(1e-5).sqrt())
+        }
+    }
+
+    struct ReduceExp;
+
+    impl OpSequence<2, 2> for ReduceExp {
+        fn run<B: Backend>(&self, mut inputs: Vec<Tensor<B, 2>>) -> Tensor<B, 2> {
+            let x = inputs.pop().unwrap();
+
+            x.clone().exp().sum_dim(1).log() - x.max_dim(1)
+        }
+    }
+
+    #[test]
+    fn should_be_consistent_matmul_softmax() {
+        compare_backends::<TestBackend, ReferenceBackend, _, 2, 2>(
+            &MatmulSoftmax,
+            &[[16, 32], [32, 8]],
+            0,
+        )
+        .assert_max_abs_error(TOLERANCE);
+    }
+
+    #[test]
+    fn should_be_consistent_conv2d_relu() {
+        compare_backends::<TestBackend, ReferenceBackend, _, 4, 4>(
+            &Conv2dRelu,
+            &[[2, 3, 10, 10], [4, 3, 3, 3]],
+            0,
+        )
+        .assert_max_abs_error(TOLERANCE);
+    }
+
+    #[test]
+    fn should_be_consistent_normalize() {
+        compare_backends::<TestBackend, ReferenceBackend, _, 3, 3>(&Normalize, &[[4, 8, 32]], 0)
+            .assert_max_abs_error(TOLERANCE);
+    }
+
+    #[test]
+    fn should_be_consistent_reduce_exp() {
+        compare_backends::<TestBackend, ReferenceBackend, _, 2, 2>(&ReduceExp, &[[64, 128]], 0)
+            .assert_max_abs_error(TOLERANCE);
+    }
+}
@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(interpolation)]
+mod tests {
+    use super::*;
+    use burn_tensor::{interpolation, Data};
+
+    #[test]
+    fn test_interp1d() {
+        let x = TestTensor::from([[-1.0, 0.5], [2.0, 4.0]]);
+        let xp = TestTensor::from([0.0, 1.0, 3.0]);
+        let fp = TestTensor::from([0.0, 2.0, 6.0]);
+
+        let data_actual = interpolation::interp1d(x, xp, fp).into_data();
+
+        // The points outside of the knots take the value of the closest knot.
+        let data_expected = Data::from([[0.0, 1.0], [4.0, 6.0]]);
+        data_expected.assert_approx_eq(&data_actual, 5);
+    }
+
+    #[test]
+    fn test_interp1d_at_the_knots() {
+        let x = TestTensor::from([0.0, 1.0, 3.0]);
+        let xp = TestTensor::from([0.0, 1.0, 3.0]);
+        let fp = TestTensor::from([5.0, -2.0, 6.0]);
+
+        let data_actual = interpolation::interp1d(x, xp, fp).into_data();
+
+        data_actual.assert_approx_eq(&Data::from([5.0, -2.0, 6.0]), 5);
+    }
+
+    #[test]
+    fn test_cubic_spline() {
+        let x = TestTensor::from([0.0, 0.5, 1.0, 1.5, 2.0]);
+        let xp = TestTensor::from([0.0, 1.0, 2.0]);
+        let fp = TestTensor::from([0.0, 1.0, 0.0]);
+
+        let data_actual = interpolation::cubic_spline(x, xp, fp).into_data();
+
+        // The second derivative at the middle knot is -3.
+        let data_expected = Data::from([0.0, 0.6875, 1.0, 0.6875, 0.0]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+
+    #[test]
+    fn test_cubic_spline_reproduces_lines() {
+        let x = TestTensor::from([[0.5, 1.25], [2.5, 7.0]]);
+        let xp = TestTensor::from([0.0, 1.0, 2.0, 4.0]);
+        let fp = TestTensor::from([1.0, 3.0, 5.0, 9.0]);
+
+        let spline = interpolation::CubicSpline::new(xp, fp);
+        let data_actual = spline.evaluate(x).into_data();
+
+        let data_expected = Data::from([[2.0, 3.5], [6.0, 9.0]]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+}
@@ -0,0 +1,188 @@
+#[burn_tensor_testgen::testgen(linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn test_cholesky() {
+        let tensor = TestTensor::from([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        let data_actual = linalg::cholesky(tensor).into_data();
+
+        let data_expected = Data::from([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+
+    #[test]
+    fn test_cholesky_not_positive_definite_is_nan() {
+        let tensor = TestTensor::from([[1.0, 2.0], [2.0, 1.0]]);
+
+        let data_actual = linalg::cholesky(tensor).into_data().convert::<f32>();
+
+        assert!(data_actual.value.iter().any(|value| value.is_nan()));
+    }
+
+    #[test]
+    fn test_solve() {
+        let lhs = TestTensor::from([[[3.0, 1.0], [1.0, 2.0]], [[0.0, 2.0], [1.0, 0.0]]]);
+        let rhs = TestTensor::from([[[9.0, 1.0], [8.0, 2.0]], [[4.0, 0.0], [3.0, 1.0]]]);
+
+        let data_actual = linalg::solve(lhs, rhs).into_data();
+
+        let data_expected = Data::from([[[2.0, 0.0], [3.0, 1.0]], [[3.0, 1.0], [2.0, 0.0]]]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let tensor = TestTensor::from([[4.0, 7.0], [2.0, 6.0]]);
+
+        let data_actual = linalg::inverse(tensor).into_data();
+
+        let data_expected = Data::from([[0.6, -0.7], [-0.2, 0.4]]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+
+    #[test]
+    fn test_det() {
+        let tensor = TestTensor::from([
+            [[1.0, 2.0, 0.0], [3.0, 4.0, 0.0], [0.0, 0.0, 1.0]],
+            [[0.0, 2.0, 0.0], [3.0, 0.0, 0.0], [0.0, 0.0, 5.0]],
+        ]);
+
+        let data_actual = linalg::det(tensor).into_data();
+
+        let data_expected = Data::from([[[-2.0]], [[-30.0]]]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+
+    #[test]
+    fn test_slogdet() {
+        let tensor = TestTensor::from([
+            [[1.0, 2.0], [3.0, 4.0]],
+            [[100.0, 0.0], [0.0, 100.0]],
+            [[1.0, 2.0], [2.0, 4.0]],
+        ]);
+
+        let (sign, logabsdet) = linalg::slogdet(tensor);
+
+        sign.into_data()
+            .assert_approx_eq(&Data::from([[[-1.0]], [[1.0]], [[0.0]]]), 4);
+        let logabsdet = logabsdet.into_data().convert::<f32>().value;
+        assert!((logabsdet[0] - 2.0f32.ln()).abs() < 1e-4);
+        assert!((logabsdet[1] - 10000.0f32.ln()).abs() < 1e-4);
+        assert_eq!(logabsdet[2], f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_pinverse() {
+        let tensor = TestTensor::from([[[1.0, 2.0], [2.0, 4.0]], [[4.0, 7.0], [2.0, 6.0]]]);
+
+        let data_actual = linalg::pinverse(tensor, 1e-6).into_data();
+
+        // The pseudo-inverse of the matrix of rank one is its transpose divided by its squared norm.
+        let data_expected = Data::from([[[0.04, 0.08], [0.08, 0.16]], [[0.6, -0.7], [-0.2, 0.4]]]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+
+    #[test]
+    fn test_pinverse_rectangular() {
+        let tensor = TestTensor::from([[1.0, 0.0], [0.0, 2.0], [0.0, 0.0]]);
+
+        let data_actual = linalg::pinverse(tensor, 1e-6).into_data();
+
+        let data_expected = Data::from([[1.0, 0.0, 0.0], [0.0, 0.5, 0.0]]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+
+    #[test]
+    fn test_matrix_exp() {
+        let angle = 2.0f32;
+        let tensor = TestTensor::from([[[0.0, angle], [-angle, 0.0]], [[1.0, 0.0], [0.0, -3.0]]]);
+
+        let data_actual = linalg::matrix_exp(tensor).into_data();
+
+        // The exponential of a skew-symmetric matrix is a rotation.
+        let data_expected = Data::from([
+            [[angle.cos(), angle.sin()], [-angle.sin(), angle.cos()]],
+            [[1.0f32.exp(), 0.0], [0.0, (-3.0f32).exp()]],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+
+    #[test]
+    fn test_matrix_exp_nilpotent() {
+        let tensor = TestTensor::from([[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]);
+
+        let data_actual = linalg::matrix_exp(tensor).into_data();
+
+        let data_expected = Data::from([[1.0, 1.0, 0.5], [0.0, 1.0, 1.0], [0.0, 0.0, 1.0]]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+
+    #[test]
+    fn test_qr() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [3, 2]);
+        assert_eq!(r.dims(), [2, 2]);
+        let r_data = r.clone().into_data().convert::<f32>().value;
+        assert!(r_data[0] > 0.0 && r_data[3] > 0.0);
+        assert_eq!(r_data[2], 0.0);
+        q.clone()
+            .transpose()
+            .matmul(q.clone())
+            .into_data()
+            .assert_approx_eq(&TestTensor::eye(2, &Default::default()).into_data(), 4);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+    }
+
+    #[test]
+    fn test_svd() {
+        let tensor = TestTensor::from([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+
+        let (u, s, vt) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [2, 2]);
+        assert_eq!(vt.dims(), [2, 3]);
+        s.clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[5.0, 3.0]]), 4);
+        u.mul(s)
+            .matmul(vt)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+    }
+
+    #[test]
+    fn test_eigh() {
+        let tensor = TestTensor::from([[[2.0, 1.0], [1.0, 2.0]], [[3.0, 0.0], [0.0, -1.0]]]);
+
+        let (values, vectors) = linalg::eigh(tensor.clone());
+
+        values
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[[1.0, 3.0]], [[-1.0, 3.0]]]), 4);
+        tensor
+            .matmul(vectors.clone())
+            .into_data()
+            .assert_approx_eq(&vectors.mul(values).into_data(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_should_panic_for_non_square_matrices() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        linalg::inverse(tensor);
+    }
+}
@@ -1,14 +1,19 @@
 mod activation;
 mod clone_invariance;
+mod consistency;
+mod interpolation;
+mod linalg;
 mod module;
 mod ops;
 mod stats;
+mod vision;
 
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_all {
     () => {
         // test activation
+        burn_tensor::testgen_cross_entropy!();
         burn_tensor::testgen_gelu!();
         burn_tensor::testgen_mish!();
         burn_tensor::testgen_relu!();
@@ -36,6 +41,8 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_nearest_interpolate!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
+        burn_tensor::testgen_module_grid_sample!();
+        burn_tensor::testgen_module_sparse24!();
 
         // test ops
         burn_tensor::testgen_add!();
@@ -53,6 +60,7 @@ macro_rules! testgen_all {
         burn_tensor::testgen_div!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
+        burn_tensor::testgen_fallible!();
         burn_tensor::testgen_flatten!();
         burn_tensor::testgen_full!();
         burn_tensor::testgen_gather_scatter!();
@@ -67,12 +75,14 @@ macro_rules! testgen_all {
         burn_tensor::testgen_mul!();
         burn_tensor::testgen_narrow!();
         burn_tensor::testgen_neg!();
+        burn_tensor::testgen_nested!();
         burn_tensor::testgen_one_hot!();
         burn_tensor::testgen_powf_scalar!();
         burn_tensor::testgen_random!();
         burn_tensor::testgen_recip!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_scatter_reduce!();
         burn_tensor::testgen_select!();
         burn_tensor::testgen_sin!();
         burn_tensor::testgen_slice!();
@@ -98,6 +108,8 @@ macro_rules! testgen_all {
         burn_tensor::testgen_topk!();
         burn_tensor::testgen_remainder!();
         burn_tensor::testgen_cartesian_grid!();
+        burn_tensor::testgen_fingerprint!();
+        burn_tensor::testgen_is_nan_inf!();
 
         // test stats
         burn_tensor::testgen_var!();
@@ -105,6 +117,20 @@ macro_rules! testgen_all {
         burn_tensor::testgen_eye!();
         burn_tensor::testgen_display!();
 
+        // test vision
+        burn_tensor::testgen_box_iou!();
+        burn_tensor::testgen_nms!();
+        burn_tensor::testgen_roi_align!();
+        burn_tensor::testgen_box_coder!();
+        burn_tensor::testgen_morphology!();
+        burn_tensor::testgen_connected_components!();
+
+        // test interpolation
+        burn_tensor::testgen_interpolation!();
+
+        // test linalg
+        burn_tensor::testgen_linalg!();
+
         // test clone invariance
         burn_tensor::testgen_clone_invariance!();
 
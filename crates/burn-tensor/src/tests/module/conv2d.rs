@@ -69,6 +69,41 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_conv2d_groups_multiple_channels() {
+        let test = Conv2dTestCase {
+            batch_size: 2,
+            channels_in: 4,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            padding_1: 0,
+            padding_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 2,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from([
+            [
+                [[268., 296.], [352., 380.]],
+                [[685., 777.], [961., 1053.]],
+                [[3910., 4066.], [4378., 4534.]],
+                [[5479., 5699.], [6139., 6359.]],
+            ],
+            [
+                [[1276., 1304.], [1360., 1388.]],
+                [[3997., 4089.], [4273., 4365.]],
+                [[9526., 9682.], [9994., 10150.]],
+                [[13399., 13619.], [14059., 14279.]],
+            ],
+        ]));
+    }
+
     #[test]
     fn test_conv2d_complex() {
         let test = Conv2dTestCase {
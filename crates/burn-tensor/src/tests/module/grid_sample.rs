@@ -0,0 +1,125 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{affine_grid_2d, grid_sample_2d};
+    use burn_tensor::ops::{GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_grid_sample_2d_bilinear() {
+        let x = TestTensor::from([[[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]]]);
+        let grid =
+            TestTensor::from([[[[0.0, 0.0], [1.0, -1.0], [-1.0, 1.0], [0.5, 0.0], [3.0, 3.0]]]]);
+
+        let output = grid_sample_2d(x, grid, GridSampleOptions::default());
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[1.5, 0.25, 0.5, 2.0, 0.0]], [[5.5, 1.25, 1.5, 6.0, 0.0]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_affine_grid_2d_identity_should_sample_the_input() {
+        let device = Default::default();
+        let shape = Shape::new([2, 1, 3, 4]);
+        let x = TestTensorInt::arange(0..shape.num_elements() as i64, &device)
+            .reshape(shape)
+            .float();
+        let theta = TestTensor::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]).repeat(0, 2);
+
+        let grid = affine_grid_2d(theta, [2, 1, 3, 4], false);
+        let output = grid_sample_2d(x.clone(), grid, GridSampleOptions::default());
+
+        output.into_data().assert_approx_eq(&x.into_data(), 3);
+    }
+
+    #[test]
+    fn test_affine_grid_2d_translation() {
+        let x = TestTensor::from([[[[0.0, 1.0], [2.0, 3.0]]]]);
+        let theta = TestTensor::from([[[1.0, 0.0, 0.5], [0.0, 1.0, 0.0]]]);
+
+        let grid = affine_grid_2d(theta, [1, 1, 2, 2], false);
+        let output = grid_sample_2d(x, grid, GridSampleOptions::default());
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.5, 0.5], [2.5, 1.5]]]]), 3);
+    }
+
+    #[test]
+    fn test_affine_grid_2d_identity_values() {
+        let theta = TestTensor::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        let grid: Tensor<TestBackend, 4> = affine_grid_2d(theta, [1, 1, 2, 2], false);
+
+        grid.into_data().assert_approx_eq(
+            &Data::from([[[[-0.5, -0.5], [0.5, -0.5]], [[-0.5, 0.5], [0.5, 0.5]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_2d_border() {
+        let x = TestTensor::from([[[[0.0, 1.0], [2.0, 3.0]]]]);
+        let grid = TestTensor::from([[[[3.0, 3.0], [-3.0, 0.0]]]]);
+        let options = GridSampleOptions::new(GridSamplePaddingMode::Border, false);
+
+        let output = grid_sample_2d(x, grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[3.0, 1.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_2d_reflection() {
+        let x = TestTensor::from([[[[0.0, 1.0], [2.0, 3.0]]]]);
+        let grid = TestTensor::from([[[[1.5, -1.5], [0.0, 2.0]]]]);
+        let options = GridSampleOptions::new(GridSamplePaddingMode::Reflection, false);
+
+        let output = grid_sample_2d(x, grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[1.0, 1.5]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_2d_reflection_align_corners() {
+        let x = TestTensor::from([[[[0.0, 1.0], [2.0, 3.0]]]]);
+        let grid = TestTensor::from([[[[2.0, -1.0], [-1.0, 4.0]]]]);
+        let options = GridSampleOptions::new(GridSamplePaddingMode::Reflection, true);
+
+        let output = grid_sample_2d(x, grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.5, 1.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_2d_align_corners() {
+        let x = TestTensor::from([[[[0.0, 1.0], [2.0, 3.0]]]]);
+        let grid = TestTensor::from([[[[0.0, 0.0], [1.0, -1.0], [0.5, 0.0]]]]);
+        let options = GridSampleOptions::new(GridSamplePaddingMode::Zeros, true);
+
+        let output = grid_sample_2d(x, grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[1.5, 1.0, 1.75]]]]), 3);
+    }
+
+    #[test]
+    fn test_affine_grid_2d_identity_values_align_corners() {
+        let theta = TestTensor::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        let grid: Tensor<TestBackend, 4> = affine_grid_2d(theta, [1, 1, 2, 2], true);
+
+        grid.into_data().assert_approx_eq(
+            &Data::from([[[[-1.0, -1.0], [1.0, -1.0]], [[-1.0, 1.0], [1.0, 1.0]]]]),
+            3,
+        );
+    }
+}
@@ -9,7 +9,9 @@ mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod forward;
+mod grid_sample;
 mod maxpool1d;
 mod maxpool2d;
 mod nearest_interpolate;
+mod sparse24;
 mod unfold4d;
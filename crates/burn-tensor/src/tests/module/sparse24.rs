@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(module_sparse24)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{sparse24_compress, sparse24_decompress, sparse24_matmul};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_sparse24_should_keep_the_largest_values_of_each_group() {
+        let tensor = TestTensor::from([
+            [1.0, -4.0],
+            [-3.0, 0.5],
+            [0.5, 2.0],
+            [2.0, 1.0],
+            [0.0, 0.0],
+            [5.0, 0.0],
+            [0.0, 0.0],
+            [-6.0, 1.0],
+        ]);
+
+        let (values, metadata) = sparse24_compress(tensor);
+        let output = sparse24_decompress(values, metadata);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [0.0, -4.0],
+                [-3.0, 0.0],
+                [0.0, 2.0],
+                [2.0, 0.0],
+                [0.0, 0.0],
+                [5.0, 0.0],
+                [0.0, 0.0],
+                [-6.0, 1.0],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_sparse24_matmul_should_match_the_dense_matmul() {
+        let lhs = TestTensor::from([[1.0, 2.0, 3.0, 4.0], [-1.0, 0.5, 0.0, 2.0]]);
+        let rhs = TestTensor::from([[0.0, 1.0], [2.0, 0.0], [0.0, -3.0], [1.0, 0.0]]);
+        let (values, metadata) = sparse24_compress(rhs.clone());
+
+        let output = sparse24_matmul(lhs.clone(), values, metadata);
+
+        output
+            .into_data()
+            .assert_approx_eq(&lhs.matmul(rhs).into_data(), 3);
+    }
+}
@@ -0,0 +1,54 @@
+#[burn_tensor_testgen::testgen(fallible)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn try_ops_should_return_the_result_with_valid_arguments() {
+        let device = Default::default();
+        let lhs = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let rhs = TestTensor::from_data([[1.0, 1.0]], &device);
+
+        let output = lhs.try_add(rhs).unwrap().try_reshape([4]).unwrap();
+
+        assert_eq!(output.into_data(), Data::from([2.0, 3.0, 4.0, 5.0]));
+    }
+
+    #[test]
+    fn try_add_should_return_an_error_with_incompatible_shapes() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::zeros([2, 3], &device);
+        let rhs = TestTensor::<2>::zeros([2, 4], &device);
+
+        let error = lhs.try_add(rhs).unwrap_err();
+
+        assert_eq!(error.operation, "Add");
+        assert_eq!(error.reasons.len(), 1);
+        assert!(error.reasons[0].contains("2, 3"));
+        assert!(error.reasons[0].contains("2, 4"));
+    }
+
+    #[test]
+    fn try_matmul_should_return_an_error_with_incompatible_inner_dims() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::zeros([2, 3], &device);
+        let rhs = TestTensor::<2>::zeros([2, 3], &device);
+
+        let error = lhs.try_matmul(rhs).unwrap_err();
+
+        assert_eq!(error.operation, "Matmul");
+    }
+
+    #[test]
+    fn try_reshape_and_slice_should_return_errors_with_invalid_arguments() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([2, 3], &device);
+
+        assert!(tensor.clone().try_reshape([4, 2]).is_err());
+        assert!(tensor.clone().try_slice([0..3, 0..1]).is_err());
+        assert!(
+            Tensor::try_cat(vec![tensor.clone(), TestTensor::zeros([2, 2], &device)], 0).is_err()
+        );
+        assert!(tensor.try_narrow(1, 2, 2).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(fingerprint)]
+mod tests {
+    use super::*;
+    use burn_tensor::ops::{float_bits_on_host, FloatTensorOps};
+    use burn_tensor::{Int, Tensor};
+
+    #[test]
+    fn should_be_equal_for_equal_tensors() {
+        let device = Default::default();
+        let tensor =
+            TestTensor::<2>::random([40, 100], burn_tensor::Distribution::Default, &device);
+        let copy = TestTensor::<2>::from_data(tensor.to_data(), &device);
+
+        assert_eq!(tensor.fingerprint(), copy.fingerprint());
+    }
+
+    #[test]
+    fn should_depend_on_the_values_and_their_order() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+        let changed = TestTensor::<1>::from([1.0, 2.0, 4.0]);
+        let swapped = TestTensor::<1>::from([2.0, 1.0, 3.0]);
+
+        assert_ne!(tensor.fingerprint(), changed.fingerprint());
+        assert_ne!(tensor.fingerprint(), swapped.fingerprint());
+    }
+
+    #[test]
+    fn should_depend_on_the_shape() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0]]);
+        let reshaped = tensor.clone().reshape([2, 2]);
+        let padded = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0, 0.0]]);
+
+        assert_ne!(tensor.fingerprint(), reshaped.fingerprint());
+        assert_ne!(tensor.fingerprint(), padded.fingerprint());
+    }
+
+    #[test]
+    fn should_depend_on_the_kind() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data([0, 1, 1], &device);
+
+        assert_eq!(
+            tensor.fingerprint(),
+            Tensor::<TestBackend, 1, Int>::from_data([0, 1, 1], &device).fingerprint()
+        );
+        assert_ne!(tensor.fingerprint(), tensor.clone().float().fingerprint());
+        assert_ne!(tensor.fingerprint(), tensor.clone().bool().fingerprint());
+    }
+
+    #[test]
+    fn should_detect_a_change_of_a_single_bit() {
+        let tensor = TestTensor::<1>::from([1.0, 0.0, 3.0]);
+        let negative_zero = TestTensor::<1>::from([1.0, -0.0, 3.0]);
+
+        assert_ne!(tensor.fingerprint(), negative_zero.fingerprint());
+
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data([-1, 256, 3], &device);
+        let changed = Tensor::<TestBackend, 1, Int>::from_data([-1, 257, 3], &device);
+        let negated = Tensor::<TestBackend, 1, Int>::from_data([1, 256, 3], &device);
+
+        assert_ne!(tensor.fingerprint(), changed.fingerprint());
+        assert_ne!(tensor.fingerprint(), negated.fingerprint());
+    }
+
+    #[test]
+    fn should_return_the_same_bytes_as_on_the_host() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::random([8, 30], burn_tensor::Distribution::Default, &device)
+            .transpose();
+
+        let bytes = Tensor::<TestBackend, 2, Int>::new(TestBackend::float_bits(
+            tensor.clone().into_primitive(),
+        ));
+        let expected = Tensor::<TestBackend, 2, Int>::new(float_bits_on_host::<TestBackend, 2>(
+            tensor.into_primitive(),
+        ));
+
+        assert_eq!(bytes.into_data(), expected.into_data());
+    }
+}
@@ -0,0 +1,28 @@
+#[burn_tensor_testgen::testgen(is_nan_inf)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_is_nan() {
+        let tensor = TestTensor::from([[0.0, f32::NAN, 2.0], [f32::INFINITY, -1.0, f32::NAN]]);
+
+        let data_actual = tensor.is_nan().into_data();
+
+        let data_expected = Data::from([[false, true, false], [false, false, true]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_is_inf() {
+        let tensor = TestTensor::from([
+            [0.0, f32::INFINITY, 2.0],
+            [f32::NEG_INFINITY, -1.0, f32::NAN],
+        ]);
+
+        let data_actual = tensor.is_inf().into_data();
+
+        let data_expected = Data::from([[false, true, false], [true, false, false]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
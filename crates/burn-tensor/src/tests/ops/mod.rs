@@ -20,11 +20,14 @@ mod div;
 mod erf;
 mod exp;
 mod expand;
+mod fallible;
+mod fingerprint;
 mod flatten;
 mod flip;
 mod full;
 mod gather_scatter;
 mod init;
+mod is_nan_inf;
 mod iter_dim;
 mod log;
 mod log1p;
@@ -35,6 +38,7 @@ mod maxmin;
 mod mul;
 mod narrow;
 mod neg;
+mod nested;
 mod one_hot;
 mod padding;
 mod permute;
@@ -45,6 +49,7 @@ mod recip;
 mod remainder;
 mod repeat;
 mod reshape;
+mod scatter_reduce;
 mod select;
 mod sign;
 mod sin;
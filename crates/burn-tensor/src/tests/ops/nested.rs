@@ -0,0 +1,107 @@
+#[burn_tensor_testgen::testgen(nested)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, NestedTensor};
+
+    #[test]
+    fn should_track_lengths_and_offsets() {
+        let nested = nested_tensor();
+
+        assert_eq!(nested.num_tensors(), 3);
+        assert_eq!(nested.lengths(), vec![2, 0, 3]);
+        assert_eq!(nested.offsets(), &[0, 2, 2, 5]);
+        assert_eq!(nested.max_length(), 3);
+        assert_eq!(
+            nested.get(2).into_data(),
+            Data::from([[5.0, 6.0], [7.0, 8.0], [9.0, 10.0]])
+        );
+    }
+
+    #[test]
+    fn should_pad_and_unpad() {
+        let nested = nested_tensor();
+        let lengths = nested.lengths();
+
+        let padded = nested.to_padded::<3, _>(-1.0);
+
+        assert_eq!(
+            padded.clone().into_data(),
+            Data::from([
+                [[1.0, 2.0], [3.0, 4.0], [-1.0, -1.0]],
+                [[-1.0, -1.0], [-1.0, -1.0], [-1.0, -1.0]],
+                [[5.0, 6.0], [7.0, 8.0], [9.0, 10.0]],
+            ])
+        );
+
+        let nested = NestedTensor::from_padded(padded, &lengths);
+
+        assert_eq!(nested.lengths(), lengths);
+        assert_eq!(
+            nested.into_values().into_data(),
+            Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0], [9.0, 10.0]])
+        );
+    }
+
+    #[test]
+    fn should_generate_padding_mask() {
+        let mask = nested_tensor().padding_mask();
+
+        assert_eq!(
+            mask.into_data(),
+            Data::from([
+                [false, false, true],
+                [true, true, true],
+                [false, false, false]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_map_values() {
+        let nested = nested_tensor().map(|values| values.mul_scalar(2.0).sum_dim(1));
+
+        assert_eq!(nested.lengths(), vec![2, 0, 3]);
+        assert_eq!(nested.get(0).into_data(), Data::from([[6.0], [14.0]]));
+    }
+
+    #[test]
+    fn should_reduce_each_tensor() {
+        let sum = nested_tensor().sum();
+        let mean = nested_tensor().mean();
+
+        assert_eq!(
+            sum.into_data(),
+            Data::from([[4.0, 6.0], [0.0, 0.0], [21.0, 24.0]])
+        );
+        mean.slice([0..1])
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 3.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_nested_int_tensors() {
+        let device = Default::default();
+        let nested = NestedTensor::new(vec![
+            TestTensorInt::<1>::from_data([1, 2, 3], &device),
+            TestTensorInt::<1>::from_data([4], &device),
+        ]);
+
+        assert_eq!(
+            nested.clone().to_padded::<2, _>(0).into_data(),
+            Data::from([[1, 2, 3], [4, 0, 0]])
+        );
+        assert_eq!(nested.sum().into_data(), Data::from([6, 4]));
+    }
+
+    fn nested_tensor() -> NestedTensor<TestBackend, 2> {
+        let device = Default::default();
+
+        NestedTensor::from_values(
+            TestTensor::from_data(
+                [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0], [9.0, 10.0]],
+                &device,
+            ),
+            &[2, 0, 3],
+        )
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(random)]
 mod tests {
     use super::*;
-    use burn_tensor::{Distribution, Tensor};
+    use burn_tensor::{DeviceGenerators, Distribution, Generator, Tensor};
 
     #[test]
     fn rand_default() {
@@ -33,4 +33,51 @@ mod tests {
 
         assert_eq!(tensor.into_data(), [1.; 20].into());
     }
+
+    #[test]
+    fn rand_with_generator_should_be_deterministic() {
+        let device = Default::default();
+        let mut generator = Generator::new(42);
+        let mut other = Generator::new(42);
+
+        let tensor = Tensor::<TestBackend, 1>::random_with(
+            [20],
+            Distribution::Uniform(4., 5.),
+            &mut generator,
+            &device,
+        );
+        let expected = Tensor::<TestBackend, 1>::random_with(
+            [20],
+            Distribution::Uniform(4., 5.),
+            &mut other,
+            &device,
+        );
+
+        tensor.clone().into_data().assert_within_range(4.0..5.0);
+        assert_eq!(tensor.into_data(), expected.into_data());
+    }
+
+    #[test]
+    fn device_generators_should_keep_the_state_of_each_device() {
+        let device = Default::default();
+        let mut generators = DeviceGenerators::new(42);
+        let mut expected = Generator::for_device(42, &device);
+
+        for _ in 0..2 {
+            let tensor = Tensor::<TestBackend, 1>::random_with(
+                [20],
+                Distribution::Default,
+                generators.get(&device),
+                &device,
+            );
+            let other = Tensor::<TestBackend, 1>::random_with(
+                [20],
+                Distribution::Default,
+                &mut expected,
+                &device,
+            );
+
+            assert_eq!(tensor.into_data(), other.into_data());
+        }
+    }
 }
@@ -0,0 +1,171 @@
+#[burn_tensor_testgen::testgen(scatter_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ops::ScatterReduction, Data, Tensor};
+
+    #[test]
+    fn should_scatter_reduce_sum_2d_dim0() {
+        let output = scatter_reduce_2d_dim0(1.0, ScatterReduction::Sum);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[71.0, 102.0, 153.0], [54.0, 55.0, 36.0]]), 3);
+    }
+
+    #[test]
+    fn should_scatter_reduce_mean_2d_dim0() {
+        let output = scatter_reduce_2d_dim0(1.0, ScatterReduction::Mean);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[35.5, 34.0, 51.0], [18.0, 27.5, 18.0]]), 3);
+    }
+
+    #[test]
+    fn should_scatter_reduce_max_2d_dim0() {
+        let output = scatter_reduce_2d_dim0(1.0, ScatterReduction::Max);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[70.0, 80.0, 90.0], [40.0, 50.0, 30.0]]), 3);
+    }
+
+    #[test]
+    fn should_scatter_reduce_min_2d_dim0() {
+        let output = scatter_reduce_2d_dim0(-1.0, ScatterReduction::Min);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[-70.0, -80.0, -90.0], [-40.0, -50.0, -30.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_leave_untouched_elements_unchanged() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[0.0, 0.0, 0.0, 0.0]], &device);
+        let values = TestTensor::from_data([[1.0, 3.0, 5.0]], &device);
+        let indices = TestTensorInt::from_data([[2, 2, 0]], &device);
+
+        let max = tensor.clone().scatter_reduce(
+            1,
+            indices.clone(),
+            values.clone(),
+            ScatterReduction::Max,
+        );
+        let mean = tensor.scatter_reduce(1, indices, values, ScatterReduction::Mean);
+
+        max.into_data()
+            .assert_approx_eq(&Data::from([[5.0, 0.0, 3.0, 0.0]]), 3);
+        mean.into_data()
+            .assert_approx_eq(&Data::from([[2.5, 0.0, 1.3333, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_index_add() {
+        let device = Default::default();
+        let tensor = TestTensor::zeros([3, 2], &device);
+        let values = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+        let indices = TestTensorInt::from_data([2, 0, 2], &device);
+
+        let output = tensor.index_add(0, indices, values);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[3.0, 4.0], [0.0, 0.0], [6.0, 8.0]])
+        );
+    }
+
+    #[test]
+    fn should_segment_sum() {
+        let device = Default::default();
+        let tensor =
+            TestTensor::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]], &device);
+        let segment_ids = TestTensorInt::from_data([0, 2, 0, 2], &device);
+
+        let output = tensor.segment_sum(segment_ids, 4);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[6.0, 8.0], [0.0, 0.0], [10.0, 12.0], [0.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_atomic_match_deterministic() {
+        for reduction in [
+            ScatterReduction::Sum,
+            ScatterReduction::Mean,
+            ScatterReduction::Max,
+            ScatterReduction::Min,
+        ] {
+            let device = Default::default();
+            let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+            let values = TestTensor::from_data(
+                [[10.0, 20.0, 30.0], [40.0, 50.0, 60.0], [70.0, 80.0, 90.0]],
+                &device,
+            );
+            let indices = TestTensorInt::from_data([[1, 0, 1], [1, 1, 0], [0, 0, 0]], &device);
+
+            let expected = scatter_reduce_2d_dim0(1.0, reduction);
+            let output = tensor.scatter_reduce_atomic(0, indices, values, reduction);
+
+            output
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn should_index_add_atomic() {
+        let device = Default::default();
+        let tensor = TestTensor::zeros([2, 3], &device);
+        let values = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let indices = TestTensorInt::from_data([1, 1, 0], &device);
+
+        let output = tensor.index_add_atomic(1, indices, values);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0, 3.0, 0.0], [6.0, 9.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_segment_sum_atomic() {
+        let device = Default::default();
+        let tensor =
+            TestTensor::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]], &device);
+        let segment_ids = TestTensorInt::from_data([0, 2, 0, 2], &device);
+
+        let output = tensor.segment_sum_atomic(segment_ids, 4);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[6.0, 8.0], [0.0, 0.0], [10.0, 12.0], [0.0, 0.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_segment_sum_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::from_data([1, 2, 3, 4, 5], &device);
+        let segment_ids = TestTensorInt::from_data([1, 1, 0, 1, 0], &device);
+
+        let output = tensor.segment_sum(segment_ids, 2);
+
+        assert_eq!(output.into_data(), Data::from([8, 7]));
+    }
+
+    fn scatter_reduce_2d_dim0(sign: f32, reduction: ScatterReduction) -> TestTensor<2> {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let values = TestTensor::from_data(
+            [[10.0, 20.0, 30.0], [40.0, 50.0, 60.0], [70.0, 80.0, 90.0]],
+            &device,
+        )
+        .mul_scalar(sign);
+        let indices = TestTensorInt::from_data([[1, 0, 1], [1, 1, 0], [0, 0, 0]], &device);
+
+        tensor.scatter_reduce(0, indices, values, reduction)
+    }
+}
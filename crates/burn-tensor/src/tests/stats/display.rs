@@ -172,6 +172,7 @@ mod tests {
    [0.0, 0.0, 0.0, ..., 0.0, 0.0, 0.0]],
   [[0.0, 0.0, 0.0, ..., 0.0, 0.0, 0.0],
    [0.0, 0.0, 0.0, ..., 0.0, 0.0, 0.0]]]],
+  stats:  {{ min: 0.0, max: 0.0, mean: 0.0 }},
   shape:  [2, 2, 2, 1000],
   device:  {:?},
   backend:  {:?},
@@ -221,6 +222,7 @@ mod tests {
    [0.0, 0.0, 0.0, ..., 0.0, 0.0, 0.0],
    [0.0, 0.0, 0.0, ..., 0.0, 0.0, 0.0],
    [0.0, 0.0, 0.0, ..., 0.0, 0.0, 0.0]]]],
+  stats:  {{ min: 0.0, max: 0.0, mean: 0.0 }},
   shape:  [2, 2, 20, 100],
   device:  {:?},
   backend:  {:?},
@@ -270,6 +272,7 @@ mod tests {
    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0]]]],
+  stats:  {{ min: 0.0, max: 0.0, mean: 0.0 }},
   shape:  [2, 2, 200, 6],
   device:  {:?},
   backend:  {:?},
@@ -281,4 +284,53 @@ mod tests {
         );
         assert_eq!(output, expected);
     }
+
+    #[test]
+    fn test_display_tensor_summarize_stats() {
+        let tensor: burn_tensor::Tensor<TestBackend, 1, burn_tensor::Int> =
+            Tensor::arange(0..2000, &Default::default());
+
+        let output = format!("{}", tensor);
+        let expected = format!(
+            r#"Tensor {{
+  data:
+[0, 1, 2, ..., 1997, 1998, 1999],
+  stats:  {{ min: 0.0, max: 1999.0, mean: 999.5 }},
+  shape:  [2000],
+  device:  {:?},
+  backend:  {:?},
+  kind:  "Int",
+  dtype:  "{dtype}",
+}}"#,
+            tensor.device(),
+            TestBackend::name(),
+            dtype = core::any::type_name::<IntElem>(),
+        );
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_display_tensor_precision() {
+        let float_data = Data::from([[1.0, 2.25], [-3.14789, 4.5]]);
+        let tensor_float: burn_tensor::Tensor<TestBackend, 2, burn_tensor::Float> =
+            Tensor::from_data(float_data, &Default::default());
+
+        let output = format!("{:.2}", tensor_float);
+        let expected = format!(
+            r#"Tensor {{
+  data:
+[[1.00, 2.25],
+ [-3.15, 4.50]],
+  shape:  [2, 2],
+  device:  {:?},
+  backend:  {:?},
+  kind:  "Float",
+  dtype:  "{dtype}",
+}}"#,
+            tensor_float.device(),
+            TestBackend::name(),
+            dtype = core::any::type_name::<FloatElem>(),
+        );
+        assert_eq!(output, expected);
+    }
 }
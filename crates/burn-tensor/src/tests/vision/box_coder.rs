@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(box_coder)]
+mod tests {
+    use super::*;
+    use burn_tensor::{vision, Data};
+
+    #[test]
+    fn test_encode_boxes() {
+        let boxes = TestTensor::from([[2.0, 2.0, 6.0, 10.0]]);
+        let anchors = TestTensor::from([[0.0, 0.0, 4.0, 4.0]]);
+
+        let data_actual = vision::encode_boxes(boxes, anchors, [10.0, 10.0, 5.0, 5.0]).into_data();
+
+        // The center moves from (2, 2) to (4, 6) and the height doubles.
+        let data_expected = Data::from([[5.0, 10.0, 0.0, 5.0 * 2.0f32.ln()]]);
+        data_expected.assert_approx_eq(&data_actual, 5);
+    }
+
+    #[test]
+    fn test_decode_boxes_inverts_encode_boxes() {
+        let boxes = TestTensor::from([[2.0, 3.0, 6.0, 10.0], [-1.0, 0.5, 1.0, 8.0]]);
+        let anchors = TestTensor::from([[0.0, 0.0, 4.0, 4.0], [0.0, 0.0, 2.0, 2.0]]);
+        let weights = [10.0, 10.0, 5.0, 5.0];
+
+        let deltas = vision::encode_boxes(boxes.clone(), anchors.clone(), weights);
+        let data_actual = vision::decode_boxes(deltas, anchors, weights).into_data();
+
+        boxes.into_data().assert_approx_eq(&data_actual, 4);
+    }
+
+    #[test]
+    fn test_generate_anchors() {
+        let data_actual = vision::generate_anchors::<TestBackend>(
+            &[4.0],
+            &[1.0, 4.0],
+            [1, 2],
+            [8, 8],
+            &Default::default(),
+        )
+        .into_data();
+
+        let data_expected = Data::from([
+            [-2.0, -2.0, 2.0, 2.0],
+            [-1.0, -4.0, 1.0, 4.0],
+            [6.0, -2.0, 10.0, 2.0],
+            [7.0, -4.0, 9.0, 4.0],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 5);
+    }
+}
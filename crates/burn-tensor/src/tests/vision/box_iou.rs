@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(box_iou)]
+mod tests {
+    use super::*;
+    use burn_tensor::{vision, Data};
+
+    #[test]
+    fn test_box_area() {
+        let boxes = TestTensor::from([
+            [0.0, 0.0, 2.0, 2.0],
+            [1.0, 0.0, 2.0, 3.0],
+            [2.0, 2.0, 1.0, 1.0],
+        ]);
+
+        let data_actual = vision::box_area(boxes).into_data();
+
+        let data_expected = Data::from([4.0, 3.0, 0.0]);
+        data_expected.assert_approx_eq(&data_actual, 5);
+    }
+
+    #[test]
+    fn test_box_iou() {
+        let boxes1 = TestTensor::from([[0.0, 0.0, 2.0, 2.0], [1.0, 1.0, 3.0, 3.0]]);
+        let boxes2 = TestTensor::from([
+            [0.0, 0.0, 2.0, 2.0],
+            [2.0, 2.0, 4.0, 4.0],
+            [1.0, 0.0, 2.0, 2.0],
+        ]);
+
+        let data_actual = vision::box_iou(boxes1, boxes2).into_data();
+
+        let data_expected = Data::from([[1.0, 0.0, 0.5], [0.142857, 0.142857, 0.2]]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+
+    #[test]
+    fn test_box_iou_degenerate_boxes_should_be_zero() {
+        let boxes1 = TestTensor::from([[1.0, 1.0, 1.0, 1.0]]);
+        let boxes2 = TestTensor::from([[1.0, 1.0, 1.0, 1.0], [0.0, 0.0, 2.0, 2.0]]);
+
+        let data_actual = vision::box_iou(boxes1, boxes2).into_data();
+
+        let data_expected = Data::from([[0.0, 0.0]]);
+        data_expected.assert_approx_eq(&data_actual, 5);
+    }
+}
@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(connected_components)]
+mod tests {
+    use super::*;
+    use burn_tensor::vision::{connected_components, Connectivity};
+    use burn_tensor::{Bool, Data, Tensor};
+
+    fn masks(data: Data<bool, 3>) -> Tensor<TestBackend, 3, Bool> {
+        Tensor::from_data(data, &Default::default())
+    }
+
+    #[test]
+    fn test_connected_components_are_labeled_by_their_largest_pixel() {
+        let masks = masks(Data::from([[
+            [true, true, false, false],
+            [false, false, false, true],
+            [true, false, true, true],
+        ]]));
+
+        let data_actual = connected_components(masks, Connectivity::Four).into_data();
+
+        let data_expected = Data::from([[[2, 2, 0, 0], [0, 0, 0, 12], [9, 0, 12, 12]]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_connected_components_connectivity_and_batch() {
+        let masks = masks(Data::from([
+            [[true, false], [false, true]],
+            [[true, true], [false, false]],
+        ]));
+
+        let data_four = connected_components(masks.clone(), Connectivity::Four).into_data();
+        let data_eight = connected_components(masks, Connectivity::Eight).into_data();
+
+        assert_eq!(data_four, Data::from([[[1, 0], [0, 4]], [[6, 6], [0, 0]]]));
+        assert_eq!(data_eight, Data::from([[[4, 0], [0, 4]], [[6, 6], [0, 0]]]));
+    }
+
+    #[test]
+    fn test_connected_components_of_a_spiral() {
+        // A long path, whose labels need many propagation steps without the jumps.
+        let masks = masks(Data::from([[
+            [true, true, true, true, true],
+            [false, false, false, false, true],
+            [true, true, true, false, true],
+            [true, false, false, false, true],
+            [true, true, true, true, true],
+        ]]));
+
+        let data_actual = connected_components(masks, Connectivity::Four).into_data();
+
+        let data_expected = Data::from([[
+            [25, 25, 25, 25, 25],
+            [0, 0, 0, 0, 25],
+            [25, 25, 25, 0, 25],
+            [25, 0, 0, 0, 25],
+            [25, 25, 25, 25, 25],
+        ]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
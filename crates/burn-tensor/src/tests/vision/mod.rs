@@ -0,0 +1,6 @@
+mod box_coder;
+mod box_iou;
+mod components;
+mod morphology;
+mod nms;
+mod roi_align;
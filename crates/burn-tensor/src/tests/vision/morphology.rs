@@ -0,0 +1,71 @@
+#[burn_tensor_testgen::testgen(morphology)]
+mod tests {
+    use super::*;
+    use burn_tensor::{vision, Bool, Data, Tensor};
+
+    fn pixel() -> TestTensor<4> {
+        TestTensor::from([[[
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+        ]]])
+    }
+
+    fn square() -> TestTensor<4> {
+        TestTensor::from([[[
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 1.0, 1.0, 0.0],
+            [0.0, 1.0, 1.0, 1.0, 0.0],
+            [0.0, 1.0, 1.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+        ]]])
+    }
+
+    #[test]
+    fn test_dilate() {
+        let data_actual = vision::dilate(pixel(), [3, 3]).into_data();
+
+        data_actual.assert_approx_eq(&square().into_data(), 5);
+    }
+
+    #[test]
+    fn test_erode() {
+        let data_actual = vision::erode(square(), [3, 3]).into_data();
+
+        data_actual.assert_approx_eq(&pixel().into_data(), 5);
+    }
+
+    #[test]
+    fn test_opening_removes_small_structures() {
+        let data_actual = vision::opening(pixel(), [3, 3]).into_data();
+
+        data_actual.assert_approx_eq(
+            &TestTensor::<4>::zeros([1, 1, 5, 5], &Default::default()).into_data(),
+            5,
+        );
+    }
+
+    #[test]
+    fn test_distance_transform() {
+        let masks = Tensor::<TestBackend, 3, Bool>::from_data(
+            Data::from([[
+                [true, true, true, true],
+                [true, true, false, true],
+                [true, true, true, true],
+            ]]),
+            &Default::default(),
+        );
+
+        let data_actual = vision::distance_transform(masks).into_data();
+
+        let sqrt_2 = 2.0f32.sqrt();
+        let data_expected = Data::from([[
+            [2.236068, sqrt_2, 1.0, sqrt_2],
+            [2.0, 1.0, 0.0, 1.0],
+            [2.236068, sqrt_2, 1.0, sqrt_2],
+        ]]);
+        data_expected.assert_approx_eq(&data_actual, 5);
+    }
+}
@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(nms)]
+mod tests {
+    use super::*;
+    use burn_tensor::{vision, Data};
+
+    fn boxes() -> (TestTensor<2>, TestTensor<1>) {
+        let boxes = TestTensor::from([
+            [0.0, 0.0, 10.0, 10.0],
+            [1.0, 1.0, 11.0, 11.0],
+            [20.0, 20.0, 30.0, 30.0],
+            [0.0, 0.0, 10.0, 10.5],
+        ]);
+        let scores = TestTensor::from([0.9, 0.8, 0.7, 0.95]);
+
+        (boxes, scores)
+    }
+
+    #[test]
+    fn test_nms() {
+        let (boxes, scores) = boxes();
+
+        let data_actual = vision::nms(boxes, scores, 0.5).into_data();
+
+        let data_expected = Data::from([3, 2]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_nms_high_threshold_keeps_all_boxes_sorted_by_score() {
+        let (boxes, scores) = boxes();
+
+        let data_actual = vision::nms(boxes, scores, 0.96).into_data();
+
+        let data_expected = Data::from([3, 0, 1, 2]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_batched_nms_should_not_suppress_across_categories() {
+        let (boxes, scores) = boxes();
+        let categories = TestTensorInt::from([0, 1, 0, 0]);
+
+        let data_actual = vision::batched_nms(boxes, scores, categories, 0.5).into_data();
+
+        let data_expected = Data::from([3, 0, 2]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
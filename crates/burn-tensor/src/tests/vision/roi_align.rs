@@ -0,0 +1,41 @@
+#[burn_tensor_testgen::testgen(roi_align)]
+mod tests {
+    use super::*;
+    use burn_tensor::{vision, Data, Int, Tensor};
+
+    #[test]
+    fn test_roi_align_interpolates_the_bins() {
+        // The features are linear in the coordinates, so the samples are exact.
+        let features = Tensor::<TestBackend, 1, Int>::arange(0..16, &Default::default())
+            .float()
+            .reshape([1, 1, 4, 4]);
+        let rois = TestTensor::from([[0.0, 0.0, 0.0, 4.0, 4.0]]);
+
+        let data_actual = vision::roi_align(features, rois, [2, 2], 1.0, 2, true).into_data();
+
+        let data_expected = Data::from([[[[2.5, 4.5], [10.5, 12.5]]]]);
+        data_expected.assert_approx_eq(&data_actual, 5);
+    }
+
+    #[test]
+    fn test_roi_align_selects_the_image_and_zeros_outside() {
+        let features = Tensor::cat(
+            vec![
+                TestTensor::<4>::zeros([1, 2, 4, 4], &Default::default()),
+                TestTensor::<4>::ones([1, 2, 4, 4], &Default::default()),
+            ],
+            0,
+        );
+        let rois = TestTensor::from([
+            [1.0, 2.0, 2.0, 6.0, 6.0],
+            [1.0, 10.0, 10.0, 14.0, 14.0],
+            [0.0, 2.0, 2.0, 6.0, 6.0],
+        ]);
+
+        let data_actual = vision::roi_align(features, rois, [1, 1], 0.5, 2, false).into_data();
+
+        let data_expected =
+            Data::from([[[[1.0]], [[1.0]]], [[[0.0]], [[0.0]]], [[[0.0]], [[0.0]]]]);
+        data_expected.assert_approx_eq(&data_actual, 5);
+    }
+}
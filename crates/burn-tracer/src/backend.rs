@@ -0,0 +1,52 @@
+use crate::TracerBridge;
+use burn_tensor::backend::{Backend, DeviceInfo, MemoryUsage, SyncType};
+use core::marker::PhantomData;
+
+/// Record the operations executed on a backend.
+///
+/// This works as a backend decorator: the tensors are the ones of the inner backend, and every
+/// operation is forwarded to it. While a trace is [started](crate::start_trace), each operation
+/// is recorded with the shapes of its inputs and outputs, and optionally the hashes or the values
+/// of its outputs.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Tracer<B> {
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> Backend for Tracer<B> {
+    type Device = B::Device;
+
+    type FullPrecisionBridge = TracerBridge<B::FullPrecisionBridge>;
+
+    type FloatTensorPrimitive<const D: usize> = B::FloatTensorPrimitive<D>;
+    type FloatElem = B::FloatElem;
+
+    type IntTensorPrimitive<const D: usize> = B::IntTensorPrimitive<D>;
+    type IntElem = B::IntElem;
+
+    type BoolTensorPrimitive<const D: usize> = B::BoolTensorPrimitive<D>;
+
+    fn name() -> String {
+        format!("tracer<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn fork_seed() -> Option<u64> {
+        B::fork_seed()
+    }
+
+    fn sync(device: &B::Device, sync_type: SyncType) {
+        B::sync(device, sync_type)
+    }
+
+    fn memory_usage(device: &B::Device) -> Option<MemoryUsage> {
+        B::memory_usage(device)
+    }
+
+    fn devices() -> Vec<DeviceInfo<B::Device>> {
+        B::devices()
+    }
+}
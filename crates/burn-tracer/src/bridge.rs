@@ -0,0 +1,43 @@
+use crate::Tracer;
+use burn_tensor::{
+    backend::{Backend, BackendBridge},
+    ops::FloatTensor,
+    Device,
+};
+use core::marker::PhantomData;
+
+/// Record the operations of a [backend bridge](BackendBridge).
+#[derive(Debug)]
+pub struct TracerBridge<Bridge> {
+    _p: PhantomData<Bridge>,
+}
+
+impl<B, Bridge> BackendBridge<Tracer<B>> for TracerBridge<Bridge>
+where
+    B: Backend,
+    Bridge: BackendBridge<B> + 'static,
+{
+    type Target = Tracer<Bridge::Target>;
+
+    fn into_target<const D: usize>(
+        tensor: FloatTensor<Tracer<B>, D>,
+        device: Option<Device<Self::Target>>,
+    ) -> FloatTensor<Self::Target, D> {
+        Bridge::into_target(tensor, device)
+    }
+
+    fn from_target<const D: usize>(
+        tensor: FloatTensor<Self::Target, D>,
+        device: Option<Device<Tracer<B>>>,
+    ) -> FloatTensor<Tracer<B>, D> {
+        Bridge::from_target(tensor, device)
+    }
+
+    fn target_device(device: &Device<Tracer<B>>) -> Device<Self::Target> {
+        Bridge::target_device(device)
+    }
+
+    fn origin_device(device: &Device<Self::Target>) -> Device<Tracer<B>> {
+        Bridge::origin_device(device)
+    }
+}
@@ -0,0 +1,28 @@
+#![warn(missing_docs)]
+
+//! # Burn Tracer
+//!
+//! This library is a part of the Burn project. It is a standalone crate providing a backend
+//! decorator that records every operation executed on a backend, making it possible to inspect
+//! the intermediate tensors of a model and to compare them with a reference implementation.
+
+mod backend;
+mod bridge;
+mod ops;
+mod trace;
+
+pub use backend::*;
+pub use bridge::*;
+pub use trace::{
+    end_trace, start_trace, OpRecord, Trace, TraceConfig, TraceTensor, TraceTensorKind,
+};
+
+#[cfg(test)]
+mod tests {
+    type TestBackend = crate::Tracer<burn_ndarray::NdArray<f32>>;
+    type TestTensor<const D: usize> = burn_tensor::Tensor<TestBackend, D>;
+    type TestTensorInt<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Int>;
+    type TestTensorBool<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Bool>;
+
+    burn_tensor::testgen_all!();
+}
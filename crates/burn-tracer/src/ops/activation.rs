@@ -0,0 +1,139 @@
+use crate::{trace::OpTrace, Tracer};
+use burn_tensor::{
+    backend::Backend,
+    ops::{ActivationOps, FloatElem, FloatTensor, IntTensor},
+};
+
+impl<B: Backend> ActivationOps<Self> for Tracer<B> {
+    fn leaky_relu<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        negative_slope: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("leaky_relu").float(&tensor);
+        let output = B::leaky_relu(tensor, negative_slope);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn relu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("relu").float(&tensor);
+        let output = B::relu(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn relu_backward<const D: usize>(
+        output: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("relu_backward")
+            .float(&output)
+            .float(&grad);
+        let output = B::relu_backward(output, grad);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn gelu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("gelu").float(&tensor);
+        let output = B::gelu(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn prelu<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        alpha: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("prelu").float(&tensor).float(&alpha);
+        let output = B::prelu(tensor, alpha);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn gelu_backward<const D: usize>(
+        x: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("gelu_backward").float(&x).float(&grad);
+        let output = B::gelu_backward(x, grad);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("sigmoid").float(&tensor);
+        let output = B::sigmoid(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn sigmoid_backward<const D: usize>(
+        output: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("sigmoid_backward")
+            .float(&output)
+            .float(&grad);
+        let output = B::sigmoid_backward(output, grad);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn log_sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("log_sigmoid").float(&tensor);
+        let output = B::log_sigmoid(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn log_sigmoid_backward<const D: usize>(
+        x: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("log_sigmoid_backward")
+            .float(&x)
+            .float(&grad);
+        let output = B::log_sigmoid_backward(x, grad);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn cross_entropy(
+        logits: FloatTensor<Self, 2>,
+        targets: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("cross_entropy")
+            .float(&logits)
+            .int(&targets);
+        let output = B::cross_entropy(logits, targets);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn cross_entropy_backward(
+        logits: FloatTensor<Self, 2>,
+        targets: IntTensor<Self, 1>,
+        grad: FloatTensor<Self, 1>,
+    ) -> FloatTensor<Self, 2> {
+        let trace = OpTrace::<B>::new("cross_entropy_backward")
+            .float(&logits)
+            .int(&targets)
+            .float(&grad);
+        let output = B::cross_entropy_backward(logits, targets, grad);
+        trace.float_output(&output).finish();
+
+        output
+    }
+}
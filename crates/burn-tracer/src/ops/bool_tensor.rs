@@ -0,0 +1,298 @@
+use crate::{trace::OpTrace, Tracer};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, BoolTensorOps, FloatTensor, IntTensor},
+    Data, Device, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> BoolTensorOps<Self> for Tracer<B> {
+    fn bool_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_empty");
+        let output = B::bool_empty(shape, device);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<Self, D>) -> Shape<D> {
+        B::bool_shape(tensor)
+    }
+
+    fn bool_into_data<const D: usize>(tensor: BoolTensor<Self, D>) -> Reader<Data<bool, D>> {
+        let trace = OpTrace::<B>::new("bool_into_data").bool(&tensor);
+        let output = B::bool_into_data(tensor);
+        trace.finish();
+
+        output
+    }
+
+    fn bool_to_data<const D: usize>(tensor: &BoolTensor<Self, D>) -> Reader<Data<bool, D>> {
+        let trace = OpTrace::<B>::new("bool_to_data").bool(tensor);
+        let output = B::bool_to_data(tensor);
+        trace.finish();
+
+        output
+    }
+
+    fn bool_from_data<const D: usize>(
+        data: Data<bool, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_from_data");
+        let output = B::bool_from_data(data, device);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_into_int<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_into_int").bool(&tensor);
+        let output = B::bool_into_int(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn bool_into_float<const D: usize>(tensor: BoolTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_into_float").bool(&tensor);
+        let output = B::bool_into_float(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<Self, D>) -> Device<Self> {
+        B::bool_device(tensor)
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        B::bool_to_device(tensor, device)
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        let trace = OpTrace::<B>::new("bool_reshape").bool(&tensor);
+        let output = B::bool_reshape(tensor, shape);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_slice<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> BoolTensor<Self, D1> {
+        let trace = OpTrace::<B>::new("bool_slice").bool(&tensor);
+        let output = B::bool_slice(tensor, ranges);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_slice_assign<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: BoolTensor<Self, D1>,
+    ) -> BoolTensor<Self, D1> {
+        let trace = OpTrace::<B>::new("bool_slice_assign")
+            .bool(&tensor)
+            .bool(&value);
+        let output = B::bool_slice_assign(tensor, ranges, value);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_repeat<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_repeat").bool(&tensor);
+        let output = B::bool_repeat(tensor, dim, times);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_cat<const D: usize>(
+        tensors: Vec<BoolTensor<Self, D>>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_cat").bools(&tensors);
+        let output = B::bool_cat(tensors, dim);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_equal").bool(&lhs).bool(&rhs);
+        let output = B::bool_equal(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_not_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_not_equal").bool(&lhs).bool(&rhs);
+        let output = B::bool_not_equal(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_not<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_not").bool(&tensor);
+        let output = B::bool_not(tensor);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_transpose<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_transpose").bool(&tensor);
+        let output = B::bool_transpose(tensor);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_swap_dims<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_swap_dims").bool(&tensor);
+        let output = B::bool_swap_dims(tensor, dim1, dim2);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_permute<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        axes: [usize; D],
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_permute").bool(&tensor);
+        let output = B::bool_permute(tensor, axes);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_flip<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        axes: &[usize],
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_flip").bool(&tensor);
+        let output = B::bool_flip(tensor, axes);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_narrow<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_narrow").bool(&tensor);
+        let output = B::bool_narrow(tensor, dim, start, length);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_chunk<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<BoolTensor<Self, D>> {
+        let trace = OpTrace::<B>::new("bool_chunk").bool(&tensor);
+        let output = B::bool_chunk(tensor, chunks, dim);
+        trace.bool_outputs(&output).finish();
+
+        output
+    }
+
+    fn bool_any<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("bool_any").bool(&tensor);
+        let output = B::bool_any(tensor);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_any_dim<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_any_dim").bool(&tensor);
+        let output = B::bool_any_dim(tensor, dim);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_all<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("bool_all").bool(&tensor);
+        let output = B::bool_all(tensor);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn bool_all_dim<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("bool_all_dim").bool(&tensor);
+        let output = B::bool_all_dim(tensor, dim);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn bool_argwhere<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, 2> {
+        let trace = OpTrace::<B>::new("bool_argwhere").bool(&tensor);
+        let output = B::bool_argwhere(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn bool_nonzero<const D: usize>(tensor: BoolTensor<Self, D>) -> Vec<IntTensor<Self, 1>> {
+        let trace = OpTrace::<B>::new("bool_nonzero").bool(&tensor);
+        let output = B::bool_nonzero(tensor);
+        trace.int_outputs(&output).finish();
+
+        output
+    }
+
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        let trace = OpTrace::<B>::new("bool_expand").bool(&tensor);
+        let output = B::bool_expand(tensor, shape);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+}
@@ -0,0 +1,871 @@
+use crate::{trace::OpTrace, Tracer};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
+    Data, Device, Distribution, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> IntTensorOps<Self> for Tracer<B> {
+    fn int_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_empty");
+        let output = B::int_empty(shape, device);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_shape<const D: usize>(tensor: &IntTensor<Self, D>) -> Shape<D> {
+        B::int_shape(tensor)
+    }
+
+    fn int_into_data<const D: usize>(tensor: IntTensor<Self, D>) -> Reader<Data<IntElem<Self>, D>> {
+        let trace = OpTrace::<B>::new("int_into_data").int(&tensor);
+        let output = B::int_into_data(tensor);
+        trace.finish();
+
+        output
+    }
+
+    fn int_to_data<const D: usize>(tensor: &IntTensor<Self, D>) -> Reader<Data<IntElem<Self>, D>> {
+        let trace = OpTrace::<B>::new("int_to_data").int(tensor);
+        let output = B::int_to_data(tensor);
+        trace.finish();
+
+        output
+    }
+
+    fn int_from_data<const D: usize>(
+        data: Data<IntElem<Self>, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_from_data");
+        let output = B::int_from_data(data, device);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_device<const D: usize>(tensor: &IntTensor<Self, D>) -> Device<Self> {
+        B::int_device(tensor)
+    }
+
+    fn int_to_device<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        B::int_to_device(tensor, device)
+    }
+
+    fn int_reshape<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        let trace = OpTrace::<B>::new("int_reshape").int(&tensor);
+        let output = B::int_reshape(tensor, shape);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_slice<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        indices: [Range<usize>; D2],
+    ) -> IntTensor<Self, D1> {
+        let trace = OpTrace::<B>::new("int_slice").int(&tensor);
+        let output = B::int_slice(tensor, indices);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_slice_assign<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        indices: [Range<usize>; D2],
+        value: IntTensor<Self, D1>,
+    ) -> IntTensor<Self, D1> {
+        let trace = OpTrace::<B>::new("int_slice_assign")
+            .int(&tensor)
+            .int(&value);
+        let output = B::int_slice_assign(tensor, indices, value);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_into_float<const D: usize>(tensor: IntTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_into_float").int(&tensor);
+        let output = B::int_into_float(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn int_mask_where<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        source: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_mask_where")
+            .int(&tensor)
+            .bool(&mask)
+            .int(&source);
+        let output = B::int_mask_where(tensor, mask, source);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_mask_fill<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_mask_fill").int(&tensor).bool(&mask);
+        let output = B::int_mask_fill(tensor, mask, value);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_gather<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_gather").int(&tensor).int(&indices);
+        let output = B::int_gather(dim, tensor, indices);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_scatter<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_scatter")
+            .int(&tensor)
+            .int(&indices)
+            .int(&value);
+        let output = B::int_scatter(dim, tensor, indices, value);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_select<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_select").int(&tensor).int(&indices);
+        let output = B::int_select(tensor, dim, indices);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_select_assign<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_select_assign")
+            .int(&tensor)
+            .int(&indices)
+            .int(&value);
+        let output = B::int_select_assign(tensor, dim, indices, value);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_repeat<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_repeat").int(&tensor);
+        let output = B::int_repeat(tensor, dim, times);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_cat<const D: usize>(tensors: Vec<IntTensor<Self, D>>, dim: usize) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_cat").ints(&tensors);
+        let output = B::int_cat(tensors, dim);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_equal").int(&lhs).int(&rhs);
+        let output = B::int_equal(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_not_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_not_equal").int(&lhs).int(&rhs);
+        let output = B::int_not_equal(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_equal_elem").int(&lhs);
+        let output = B::int_equal_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_not_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_not_equal_elem").int(&lhs);
+        let output = B::int_not_equal_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_greater<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_greater").int(&lhs).int(&rhs);
+        let output = B::int_greater(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_greater_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_greater_elem").int(&lhs);
+        let output = B::int_greater_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_greater_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_greater_equal").int(&lhs).int(&rhs);
+        let output = B::int_greater_equal(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_greater_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_greater_equal_elem").int(&lhs);
+        let output = B::int_greater_equal_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_lower<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_lower").int(&lhs).int(&rhs);
+        let output = B::int_lower(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_lower_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_lower_elem").int(&lhs);
+        let output = B::int_lower_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_lower_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_lower_equal").int(&lhs).int(&rhs);
+        let output = B::int_lower_equal(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_lower_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_lower_equal_elem").int(&lhs);
+        let output = B::int_lower_equal_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_add").int(&lhs).int(&rhs);
+        let output = B::int_add(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_add_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_add_scalar").int(&lhs);
+        let output = B::int_add_scalar(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_powi<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_powi").int(&lhs).int(&rhs);
+        let output = B::int_powi(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_powf<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_powf").int(&lhs).float(&rhs);
+        let output = B::int_powf(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_powi_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_powi_scalar").int(&lhs);
+        let output = B::int_powi_scalar(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_powf_scalar<const D: usize>(lhs: IntTensor<Self, D>, rhs: f32) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_powf_scalar").int(&lhs);
+        let output = B::int_powf_scalar(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_clamp_min<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_clamp_min").int(&tensor);
+        let output = B::int_clamp_min(tensor, min);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_clamp_max<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_clamp_max").int(&tensor);
+        let output = B::int_clamp_max(tensor, max);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_clamp<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_clamp").int(&tensor);
+        let output = B::int_clamp(tensor, min, max);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_sub").int(&lhs).int(&rhs);
+        let output = B::int_sub(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_sub_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_sub_scalar").int(&lhs);
+        let output = B::int_sub_scalar(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_mul").int(&lhs).int(&rhs);
+        let output = B::int_mul(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_mul_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_mul_scalar").int(&lhs);
+        let output = B::int_mul_scalar(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_div<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_div").int(&lhs).int(&rhs);
+        let output = B::int_div(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_div_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_div_scalar").int(&lhs);
+        let output = B::int_div_scalar(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_remainder_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_remainder_scalar").int(&lhs);
+        let output = B::int_remainder_scalar(lhs, rhs);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_neg<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_neg").int(&tensor);
+        let output = B::int_neg(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_zeros");
+        let output = B::int_zeros(shape, device);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_ones");
+        let output = B::int_ones(shape, device);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: IntElem<Self>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_full");
+        let output = B::int_full(shape, fill_value, device);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_sum<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("int_sum").int(&tensor);
+        let output = B::int_sum(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_sum_dim").int(&tensor);
+        let output = B::int_sum_dim(tensor, dim);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_prod<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("int_prod").int(&tensor);
+        let output = B::int_prod(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_prod_dim").int(&tensor);
+        let output = B::int_prod_dim(tensor, dim);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_mean<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("int_mean").int(&tensor);
+        let output = B::int_mean(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_mean_dim").int(&tensor);
+        let output = B::int_mean_dim(tensor, dim);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_argmax").int(&tensor);
+        let output = B::int_argmax(tensor, dim);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_argmin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_argmin").int(&tensor);
+        let output = B::int_argmin(tensor, dim);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_max<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("int_max").int(&tensor);
+        let output = B::int_max(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_max_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_max_dim").int(&tensor);
+        let output = B::int_max_dim(tensor, dim);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_max_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        let trace = OpTrace::<B>::new("int_max_dim_with_indices").int(&tensor);
+        let output = B::int_max_dim_with_indices(tensor, dim);
+        trace.int_output(&output.0).int_output(&output.1).finish();
+
+        output
+    }
+
+    fn int_min<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("int_min").int(&tensor);
+        let output = B::int_min(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_min_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_min_dim").int(&tensor);
+        let output = B::int_min_dim(tensor, dim);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_min_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        let trace = OpTrace::<B>::new("int_min_dim_with_indices").int(&tensor);
+        let output = B::int_min_dim_with_indices(tensor, dim);
+        trace.int_output(&output.0).int_output(&output.1).finish();
+
+        output
+    }
+
+    fn int_abs<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_abs").int(&tensor);
+        let output = B::int_abs(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_transpose<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_transpose").int(&tensor);
+        let output = B::int_transpose(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_swap_dims<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_swap_dims").int(&tensor);
+        let output = B::int_swap_dims(tensor, dim1, dim2);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_permute<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        axes: [usize; D],
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_permute").int(&tensor);
+        let output = B::int_permute(tensor, axes);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_flip<const D: usize>(tensor: IntTensor<Self, D>, axes: &[usize]) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_flip").int(&tensor);
+        let output = B::int_flip(tensor, axes);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_narrow<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_narrow").int(&tensor);
+        let output = B::int_narrow(tensor, dim, start, length);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_cartesian_grid<S: Into<Shape<D>>, const D: usize, const D2: usize>(
+        shape: S,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D2> {
+        let trace = OpTrace::<B>::new("int_cartesian_grid");
+        let output = B::int_cartesian_grid::<S, D, D2>(shape, device);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_chunk<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<IntTensor<Self, D>> {
+        let trace = OpTrace::<B>::new("int_chunk").int(&tensor);
+        let output = B::int_chunk(tensor, chunks, dim);
+        trace.int_outputs(&output).finish();
+
+        output
+    }
+
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_random");
+        let output = B::int_random(shape, distribution, device);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_arange_step(
+        range: Range<i64>,
+        step: usize,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("int_arange_step");
+        let output = B::int_arange_step(range, step, device);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_arange(range: Range<i64>, device: &Device<Self>) -> IntTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("int_arange");
+        let output = B::int_arange(range, device);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_any<const D: usize>(tensor: IntTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("int_any").int(&tensor);
+        let output = B::int_any(tensor);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_any_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_any_dim").int(&tensor);
+        let output = B::int_any_dim(tensor, dim);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_all<const D: usize>(tensor: IntTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("int_all").int(&tensor);
+        let output = B::int_all(tensor);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_all_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_all_dim").int(&tensor);
+        let output = B::int_all_dim(tensor, dim);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn int_sign<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_sign").int(&tensor);
+        let output = B::int_sign(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        let trace = OpTrace::<B>::new("int_expand").int(&tensor);
+        let output = B::int_expand(tensor, shape);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_sort").int(&tensor);
+        let output = B::int_sort(tensor, dim, descending);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        let trace = OpTrace::<B>::new("int_sort_with_indices").int(&tensor);
+        let output = B::int_sort_with_indices(tensor, dim, descending);
+        trace.int_output(&output.0).int_output(&output.1).finish();
+
+        output
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("int_argsort").int(&tensor);
+        let output = B::int_argsort(tensor, dim, descending);
+        trace.int_output(&output).finish();
+
+        output
+    }
+}
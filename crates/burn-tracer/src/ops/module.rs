@@ -0,0 +1,453 @@
+use crate::{trace::OpTrace, Tracer};
+use burn_tensor::{
+    backend::Backend,
+    ops::{
+        Conv1dBackward, Conv2dBackward, ConvOptions, ConvTransposeOptions, FloatTensor, IntTensor,
+        InterpolateOptions, MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dBackward,
+        MaxPool2dWithIndices, ModuleOps, UnfoldOptions,
+    },
+};
+
+impl<B: Backend> ModuleOps<Self> for Tracer<B> {
+    fn embedding(
+        weights: FloatTensor<Self, 2>,
+        indices: IntTensor<Self, 2>,
+    ) -> FloatTensor<Self, 3> {
+        let trace = OpTrace::<B>::new("embedding").float(&weights).int(&indices);
+        let output = B::embedding(weights, indices);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn embedding_backward(
+        weights: FloatTensor<Self, 2>,
+        output_grad: FloatTensor<Self, 3>,
+        indices: IntTensor<Self, 2>,
+    ) -> FloatTensor<Self, 2> {
+        let trace = OpTrace::<B>::new("embedding_backward")
+            .float(&weights)
+            .float(&output_grad)
+            .int(&indices);
+        let output = B::embedding_backward(weights, output_grad, indices);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn conv1d(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<1>,
+    ) -> FloatTensor<Self, 3> {
+        let trace = OpTrace::<B>::new("conv1d")
+            .float(&x)
+            .float(&weight)
+            .float_opt(bias.as_ref());
+        let output = B::conv1d(x, weight, bias, options);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn conv1d_backward(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 3>,
+        options: ConvOptions<1>,
+    ) -> Conv1dBackward<Self> {
+        let trace = OpTrace::<B>::new("conv1d_backward")
+            .float(&x)
+            .float(&weight)
+            .float_opt(bias.as_ref())
+            .float(&output_grad);
+        let output = B::conv1d_backward(x, weight, bias, output_grad, options);
+        trace
+            .float_output(&output.x_grad)
+            .float_output(&output.weights_grad)
+            .float_output_opt(output.bias_grad.as_ref())
+            .finish();
+
+        Conv1dBackward::new(output.x_grad, output.weights_grad, output.bias_grad)
+    }
+
+    fn conv2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        let trace = OpTrace::<B>::new("conv2d")
+            .float(&x)
+            .float(&weight)
+            .float_opt(bias.as_ref());
+        let output = B::conv2d(x, weight, bias, options);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn conv2d_backward(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 4>,
+        options: ConvOptions<2>,
+    ) -> Conv2dBackward<Self> {
+        let trace = OpTrace::<B>::new("conv2d_backward")
+            .float(&x)
+            .float(&weight)
+            .float_opt(bias.as_ref())
+            .float(&output_grad);
+        let output = B::conv2d_backward(x, weight, bias, output_grad, options);
+        trace
+            .float_output(&output.x_grad)
+            .float_output(&output.weights_grad)
+            .float_output_opt(output.bias_grad.as_ref())
+            .finish();
+
+        Conv2dBackward::new(output.x_grad, output.weights_grad, output.bias_grad)
+    }
+
+    fn conv_transpose1d(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<1>,
+    ) -> FloatTensor<Self, 3> {
+        let trace = OpTrace::<B>::new("conv_transpose1d")
+            .float(&x)
+            .float(&weight)
+            .float_opt(bias.as_ref());
+        let output = B::conv_transpose1d(x, weight, bias, options);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn conv_transpose1d_backward(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 3>,
+        options: ConvTransposeOptions<1>,
+    ) -> Conv1dBackward<Self> {
+        let trace = OpTrace::<B>::new("conv_transpose1d_backward")
+            .float(&x)
+            .float(&weight)
+            .float_opt(bias.as_ref())
+            .float(&output_grad);
+        let output = B::conv_transpose1d_backward(x, weight, bias, output_grad, options);
+        trace
+            .float_output(&output.x_grad)
+            .float_output(&output.weights_grad)
+            .float_output_opt(output.bias_grad.as_ref())
+            .finish();
+
+        Conv1dBackward::new(output.x_grad, output.weights_grad, output.bias_grad)
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        let trace = OpTrace::<B>::new("conv_transpose2d")
+            .float(&x)
+            .float(&weight)
+            .float_opt(bias.as_ref());
+        let output = B::conv_transpose2d(x, weight, bias, options);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn conv_transpose2d_backward(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 4>,
+        options: ConvTransposeOptions<2>,
+    ) -> Conv2dBackward<Self> {
+        let trace = OpTrace::<B>::new("conv_transpose2d_backward")
+            .float(&x)
+            .float(&weight)
+            .float_opt(bias.as_ref())
+            .float(&output_grad);
+        let output = B::conv_transpose2d_backward(x, weight, bias, output_grad, options);
+        trace
+            .float_output(&output.x_grad)
+            .float_output(&output.weights_grad)
+            .float_output_opt(output.bias_grad.as_ref())
+            .finish();
+
+        Conv2dBackward::new(output.x_grad, output.weights_grad, output.bias_grad)
+    }
+
+    fn unfold4d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> FloatTensor<Self, 3> {
+        let trace = OpTrace::<B>::new("unfold4d").float(&x);
+        let output = B::unfold4d(x, kernel_size, options);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn avg_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 3> {
+        let trace = OpTrace::<B>::new("avg_pool1d").float(&x);
+        let output = B::avg_pool1d(x, kernel_size, stride, padding, count_include_pad);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn avg_pool1d_backward(
+        x: FloatTensor<Self, 3>,
+        grad: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 3> {
+        let trace = OpTrace::<B>::new("avg_pool1d_backward")
+            .float(&x)
+            .float(&grad);
+        let output =
+            B::avg_pool1d_backward(x, grad, kernel_size, stride, padding, count_include_pad);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        let trace = OpTrace::<B>::new("avg_pool2d").float(&x);
+        let output = B::avg_pool2d(x, kernel_size, stride, padding, count_include_pad);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        let trace = OpTrace::<B>::new("avg_pool2d_backward")
+            .float(&x)
+            .float(&grad);
+        let output =
+            B::avg_pool2d_backward(x, grad, kernel_size, stride, padding, count_include_pad);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        let trace = OpTrace::<B>::new("adaptive_avg_pool2d").float(&x);
+        let output = B::adaptive_avg_pool2d(x, output_size);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+    ) -> FloatTensor<Self, 4> {
+        let trace = OpTrace::<B>::new("adaptive_avg_pool2d_backward")
+            .float(&x)
+            .float(&grad);
+        let output = B::adaptive_avg_pool2d_backward(x, grad);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn adaptive_avg_pool1d(x: FloatTensor<Self, 3>, output_size: usize) -> FloatTensor<Self, 3> {
+        let trace = OpTrace::<B>::new("adaptive_avg_pool1d").float(&x);
+        let output = B::adaptive_avg_pool1d(x, output_size);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn adaptive_avg_pool1d_backward(
+        x: FloatTensor<Self, 3>,
+        grad: FloatTensor<Self, 3>,
+    ) -> FloatTensor<Self, 3> {
+        let trace = OpTrace::<B>::new("adaptive_avg_pool1d_backward")
+            .float(&x)
+            .float(&grad);
+        let output = B::adaptive_avg_pool1d_backward(x, grad);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn max_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> FloatTensor<Self, 3> {
+        let trace = OpTrace::<B>::new("max_pool1d").float(&x);
+        let output = B::max_pool1d(x, kernel_size, stride, padding, dilation);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn max_pool1d_with_indices(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> MaxPool1dWithIndices<Self> {
+        let trace = OpTrace::<B>::new("max_pool1d_with_indices").float(&x);
+        let output = B::max_pool1d_with_indices(x, kernel_size, stride, padding, dilation);
+        trace
+            .float_output(&output.output)
+            .int_output(&output.indices)
+            .finish();
+
+        MaxPool1dWithIndices::new(output.output, output.indices)
+    }
+
+    fn max_pool1d_with_indices_backward(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+        output_grad: FloatTensor<Self, 3>,
+        indices: IntTensor<Self, 3>,
+    ) -> MaxPool1dBackward<Self> {
+        let trace = OpTrace::<B>::new("max_pool1d_with_indices_backward")
+            .float(&x)
+            .float(&output_grad)
+            .int(&indices);
+        let output = B::max_pool1d_with_indices_backward(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            output_grad,
+            indices,
+        );
+        trace.float_output(&output.x_grad).finish();
+
+        MaxPool1dBackward::new(output.x_grad)
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        let trace = OpTrace::<B>::new("max_pool2d").float(&x);
+        let output = B::max_pool2d(x, kernel_size, stride, padding, dilation);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        let trace = OpTrace::<B>::new("max_pool2d_with_indices").float(&x);
+        let output = B::max_pool2d_with_indices(x, kernel_size, stride, padding, dilation);
+        trace
+            .float_output(&output.output)
+            .int_output(&output.indices)
+            .finish();
+
+        MaxPool2dWithIndices::new(output.output, output.indices)
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<Self, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        let trace = OpTrace::<B>::new("max_pool2d_with_indices_backward")
+            .float(&x)
+            .float(&output_grad)
+            .int(&indices);
+        let output = B::max_pool2d_with_indices_backward(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            output_grad,
+            indices,
+        );
+        trace.float_output(&output.x_grad).finish();
+
+        MaxPool2dBackward::new(output.x_grad)
+    }
+
+    fn interpolate(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        let trace = OpTrace::<B>::new("interpolate").float(&x);
+        let output = B::interpolate(x, output_size, options);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn interpolate_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        let trace = OpTrace::<B>::new("interpolate_backward")
+            .float(&x)
+            .float(&grad);
+        let output = B::interpolate_backward(x, grad, output_size, options);
+        trace.float_output(&output).finish();
+
+        output
+    }
+}
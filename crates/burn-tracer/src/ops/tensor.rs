@@ -0,0 +1,1016 @@
+use crate::{trace::OpTrace, Tracer};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntElem, IntTensor},
+    Data, Device, Distribution, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> FloatTensorOps<Self> for Tracer<B> {
+    fn float_from_data<const D: usize>(
+        data: Data<FloatElem<Self>, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_from_data");
+        let output = B::float_from_data(data, device);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_random");
+        let output = B::float_random(shape, distribution, device);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_zeros");
+        let output = B::float_zeros(shape, device);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_ones");
+        let output = B::float_ones(shape, device);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: FloatElem<Self>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_full");
+        let output = B::float_full(shape, fill_value, device);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
+        B::float_shape(tensor)
+    }
+
+    fn float_to_data<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+    ) -> Reader<Data<FloatElem<Self>, D>> {
+        let trace = OpTrace::<B>::new("float_to_data").float(tensor);
+        let output = B::float_to_data(tensor);
+        trace.finish();
+
+        output
+    }
+
+    fn float_into_data<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> Reader<Data<FloatElem<Self>, D>> {
+        let trace = OpTrace::<B>::new("float_into_data").float(&tensor);
+        let output = B::float_into_data(tensor);
+        trace.finish();
+
+        output
+    }
+
+    fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
+        B::float_device(tensor)
+    }
+
+    fn float_to_device<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        B::float_to_device(tensor, device)
+    }
+
+    fn float_into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_into_int").float(&tensor);
+        let output = B::float_into_int(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_empty");
+        let output = B::float_empty(shape, device);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_repeat<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_repeat").float(&tensor);
+        let output = B::float_repeat(tensor, dim, times);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_add<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_add").float(&lhs).float(&rhs);
+        let output = B::float_add(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_add_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_add_scalar").float(&lhs);
+        let output = B::float_add_scalar(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_clamp_min<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_clamp_min").float(&tensor);
+        let output = B::float_clamp_min(tensor, min);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_clamp_max<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_clamp_max").float(&tensor);
+        let output = B::float_clamp_max(tensor, max);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_clamp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_clamp").float(&tensor);
+        let output = B::float_clamp(tensor, min, max);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_sub<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_sub").float(&lhs).float(&rhs);
+        let output = B::float_sub(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_sub_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_sub_scalar").float(&lhs);
+        let output = B::float_sub_scalar(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_mul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_mul").float(&lhs).float(&rhs);
+        let output = B::float_mul(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_mul_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_mul_scalar").float(&lhs);
+        let output = B::float_mul_scalar(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_div<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_div").float(&lhs).float(&rhs);
+        let output = B::float_div(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_div_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_div_scalar").float(&lhs);
+        let output = B::float_div_scalar(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_remainder_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_remainder_scalar").float(&lhs);
+        let output = B::float_remainder_scalar(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_matmul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_matmul").float(&lhs).float(&rhs);
+        let output = B::float_matmul(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_neg<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_neg").float(&tensor);
+        let output = B::float_neg(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_recip").float(&tensor);
+        let output = B::float_recip(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_transpose<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_transpose").float(&tensor);
+        let output = B::float_transpose(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_swap_dims<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_swap_dims").float(&tensor);
+        let output = B::float_swap_dims(tensor, dim1, dim2);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_permute<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: [usize; D],
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_permute").float(&tensor);
+        let output = B::float_permute(tensor, axes);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_flip<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: &[usize],
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_flip").float(&tensor);
+        let output = B::float_flip(tensor, axes);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        let trace = OpTrace::<B>::new("float_reshape").float(&tensor);
+        let output = B::float_reshape(tensor, shape);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_gather")
+            .float(&tensor)
+            .int(&indices);
+        let output = B::float_gather(dim, tensor, indices);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_scatter")
+            .float(&tensor)
+            .int(&indices)
+            .float(&value);
+        let output = B::float_scatter(dim, tensor, indices, value);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_scatter_reduce<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+        reduction: burn_tensor::ops::ScatterReduction,
+        deterministic: bool,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_scatter_reduce")
+            .float(&tensor)
+            .int(&indices)
+            .float(&value);
+        let output = B::float_scatter_reduce(dim, tensor, indices, value, reduction, deterministic);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_select<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_select")
+            .float(&tensor)
+            .int(&indices);
+        let output = B::float_select(tensor, dim, indices);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_select_assign<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_select_assign")
+            .float(&tensor)
+            .int(&indices)
+            .float(&value);
+        let output = B::float_select_assign(tensor, dim, indices, value);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> FloatTensor<Self, D1> {
+        let trace = OpTrace::<B>::new("float_slice").float(&tensor);
+        let output = B::float_slice(tensor, ranges);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: FloatTensor<Self, D1>,
+    ) -> FloatTensor<Self, D1> {
+        let trace = OpTrace::<B>::new("float_slice_assign")
+            .float(&tensor)
+            .float(&value);
+        let output = B::float_slice_assign(tensor, ranges, value);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_mask_where<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_mask_where")
+            .float(&tensor)
+            .bool(&mask)
+            .float(&value);
+        let output = B::float_mask_where(tensor, mask, value);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_mask_fill<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_mask_fill")
+            .float(&tensor)
+            .bool(&mask);
+        let output = B::float_mask_fill(tensor, mask, value);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_equal").float(&lhs).float(&rhs);
+        let output = B::float_equal(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_not_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_not_equal").float(&lhs).float(&rhs);
+        let output = B::float_not_equal(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_equal_elem").float(&lhs);
+        let output = B::float_equal_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_not_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_not_equal_elem").float(&lhs);
+        let output = B::float_not_equal_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_greater<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_greater").float(&lhs).float(&rhs);
+        let output = B::float_greater(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_greater_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_greater_elem").float(&lhs);
+        let output = B::float_greater_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_greater_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_greater_equal")
+            .float(&lhs)
+            .float(&rhs);
+        let output = B::float_greater_equal(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_greater_equal_elem").float(&lhs);
+        let output = B::float_greater_equal_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_lower<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_lower").float(&lhs).float(&rhs);
+        let output = B::float_lower(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_lower_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_lower_elem").float(&lhs);
+        let output = B::float_lower_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_lower_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_lower_equal")
+            .float(&lhs)
+            .float(&rhs);
+        let output = B::float_lower_equal(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_lower_equal_elem").float(&lhs);
+        let output = B::float_lower_equal_elem(lhs, rhs);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_detach<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        B::float_detach(tensor)
+    }
+
+    fn float_set_require_grad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        require_grad: bool,
+    ) -> FloatTensor<Self, D> {
+        B::float_set_require_grad(tensor, require_grad)
+    }
+
+    fn float_is_require_grad<const D: usize>(tensor: &FloatTensor<Self, D>) -> bool {
+        B::float_is_require_grad(tensor)
+    }
+
+    fn float_sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("float_sum").float(&tensor);
+        let output = B::float_sum(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_sum_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_sum_dim").float(&tensor);
+        let output = B::float_sum_dim(tensor, dim);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_prod<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("float_prod").float(&tensor);
+        let output = B::float_prod(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_prod_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_prod_dim").float(&tensor);
+        let output = B::float_prod_dim(tensor, dim);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("float_mean").float(&tensor);
+        let output = B::float_mean(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_mean_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_mean_dim").float(&tensor);
+        let output = B::float_mean_dim(tensor, dim);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_exp").float(&tensor);
+        let output = B::float_exp(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_log").float(&tensor);
+        let output = B::float_log(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_log1p").float(&tensor);
+        let output = B::float_log1p(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_powf").float(&lhs).float(&rhs);
+        let output = B::float_powf(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_powi<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_powi").float(&lhs).int(&rhs);
+        let output = B::float_powi(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_powi_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_powi_scalar").float(&lhs);
+        let output = B::float_powi_scalar(lhs, rhs);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_powf_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        value: f32,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_powf_scalar").float(&tensor);
+        let output = B::float_powf_scalar(tensor, value);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_sqrt").float(&tensor);
+        let output = B::float_sqrt(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_abs").float(&tensor);
+        let output = B::float_abs(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_cos").float(&tensor);
+        let output = B::float_cos(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_sin").float(&tensor);
+        let output = B::float_sin(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_tanh").float(&tensor);
+        let output = B::float_tanh(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_erf").float(&tensor);
+        let output = B::float_erf(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<Self, D>>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_cat").floats(&tensors);
+        let output = B::float_cat(tensors, dim);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_argmax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_argmax").float(&tensor);
+        let output = B::float_argmax(tensor, dim);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn float_argmin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_argmin").float(&tensor);
+        let output = B::float_argmin(tensor, dim);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    fn float_max<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("float_max").float(&tensor);
+        let output = B::float_max(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_max_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_max_dim").float(&tensor);
+        let output = B::float_max_dim(tensor, dim);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_max_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        let trace = OpTrace::<B>::new("float_max_dim_with_indices").float(&tensor);
+        let output = B::float_max_dim_with_indices(tensor, dim);
+        trace.float_output(&output.0).int_output(&output.1).finish();
+
+        output
+    }
+
+    fn float_min<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("float_min").float(&tensor);
+        let output = B::float_min(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_min_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_min_dim").float(&tensor);
+        let output = B::float_min_dim(tensor, dim);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_min_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        let trace = OpTrace::<B>::new("float_min_dim_with_indices").float(&tensor);
+        let output = B::float_min_dim_with_indices(tensor, dim);
+        trace.float_output(&output.0).int_output(&output.1).finish();
+
+        output
+    }
+
+    fn float_narrow<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_narrow").float(&tensor);
+        let output = B::float_narrow(tensor, dim, start, length);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_chunk<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<FloatTensor<Self, D>> {
+        let trace = OpTrace::<B>::new("float_chunk").float(&tensor);
+        let output = B::float_chunk(tensor, chunks, dim);
+        trace.float_outputs(&output).finish();
+
+        output
+    }
+
+    fn float_any<const D: usize>(tensor: FloatTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("float_any").float(&tensor);
+        let output = B::float_any(tensor);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_any_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_any_dim").float(&tensor);
+        let output = B::float_any_dim(tensor, dim);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_all<const D: usize>(tensor: FloatTensor<Self, D>) -> BoolTensor<Self, 1> {
+        let trace = OpTrace::<B>::new("float_all").float(&tensor);
+        let output = B::float_all(tensor);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_all_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_all_dim").float(&tensor);
+        let output = B::float_all_dim(tensor, dim);
+        trace.bool_output(&output).finish();
+
+        output
+    }
+
+    fn float_sign<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_sign").float(&tensor);
+        let output = B::float_sign(tensor);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    fn float_expand<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        let trace = OpTrace::<B>::new("float_expand").float(&tensor);
+        let output = B::float_expand(tensor, shape);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_sort").float(&tensor);
+        let output = B::float_sort(tensor, dim, descending);
+        trace.float_output(&output).finish();
+
+        output
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        let trace = OpTrace::<B>::new("float_sort_with_indices").float(&tensor);
+        let output = B::float_sort_with_indices(tensor, dim, descending);
+        trace.float_output(&output.0).int_output(&output.1).finish();
+
+        output
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        let trace = OpTrace::<B>::new("float_argsort").float(&tensor);
+        let output = B::float_argsort(tensor, dim, descending);
+        trace.int_output(&output).finish();
+
+        output
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_bits<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, 2> {
+        let trace = OpTrace::<B>::new("float_bits").float(&tensor);
+        let output = B::float_bits(tensor);
+        trace.int_output(&output).finish();
+
+        output
+    }
+}
@@ -0,0 +1,648 @@
+use burn_common::timeline;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntTensor},
+};
+use core::{cell::RefCell, fmt::Display, marker::PhantomData};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+thread_local! {
+    static STATE: RefCell<Option<TraceState>> = const { RefCell::new(None) };
+}
+
+struct TraceState {
+    config: TraceConfig,
+    ops: Vec<OpRecord>,
+    next_id: usize,
+}
+
+/// Configuration of a trace.
+#[derive(Clone, Debug, Default)]
+pub struct TraceConfig {
+    /// Whether to hash the values of the output tensors.
+    pub hash: bool,
+    /// The directory where the output tensors are saved in the NumPy `.npy` format.
+    pub dump_dir: Option<PathBuf>,
+}
+
+impl TraceConfig {
+    /// Create a configuration recording only the operations and the shapes of their tensors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hash the values of the output tensors, which is useful to find where two runs diverge.
+    pub fn with_hash(mut self, hash: bool) -> Self {
+        self.hash = hash;
+        self
+    }
+
+    /// Save the output tensors in the given directory, which is created if it doesn't exist.
+    ///
+    /// Each tensor is saved in a file named `{op id}_{op name}_{output index}.npy`, that can be
+    /// loaded with `numpy.load`. Float tensors are saved as `f32`, int tensors as `i64` and bool
+    /// tensors as `bool`.
+    pub fn with_dump_dir<P: Into<PathBuf>>(mut self, dump_dir: P) -> Self {
+        self.dump_dir = Some(dump_dir.into());
+        self
+    }
+
+    fn read_values(&self) -> bool {
+        self.hash || self.dump_dir.is_some()
+    }
+}
+
+/// Start recording the operations executed on the [tracer](crate::Tracer) backends.
+///
+/// Only the operations executed on the current thread are recorded, so the traces of two runs of
+/// the same model can be compared operation by operation. They are recorded until the trace is
+/// [ended](end_trace), and starting a new trace discards the operations recorded so far.
+///
+/// # Panics
+///
+/// If the dump directory can't be created.
+pub fn start_trace(config: TraceConfig) {
+    if let Some(dump_dir) = &config.dump_dir {
+        std::fs::create_dir_all(dump_dir).unwrap_or_else(|err| {
+            panic!(
+                "Failed to create the trace directory {}: {err}",
+                dump_dir.display()
+            )
+        });
+    }
+
+    STATE.with_borrow_mut(|state| {
+        *state = Some(TraceState {
+            config,
+            ops: Vec::new(),
+            next_id: 0,
+        })
+    });
+}
+
+/// Stop recording the operations, returning the operations recorded since the trace was
+/// [started](start_trace).
+pub fn end_trace() -> Trace {
+    let ops = STATE
+        .with_borrow_mut(Option::take)
+        .map(|state| state.ops)
+        .unwrap_or_default();
+
+    Trace { ops }
+}
+
+/// The operations recorded during a trace.
+#[derive(Clone, Debug, Default)]
+pub struct Trace {
+    /// The operations, in the order they were executed.
+    pub ops: Vec<OpRecord>,
+}
+
+/// An operation recorded during a trace.
+#[derive(Clone, Debug)]
+pub struct OpRecord {
+    /// The position of the operation in the trace.
+    pub id: usize,
+    /// The name of the backend operation, e.g. `float_matmul`.
+    pub name: &'static str,
+    /// The input tensors.
+    pub inputs: Vec<TraceTensor>,
+    /// The output tensors.
+    pub outputs: Vec<TraceTensor>,
+}
+
+/// A tensor recorded during a trace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceTensor {
+    /// The kind of the tensor.
+    pub kind: TraceTensorKind,
+    /// The shape of the tensor.
+    pub shape: Vec<usize>,
+    /// The hash of the values, only computed for the outputs when enabled in the config.
+    pub hash: Option<u64>,
+    /// The file where the values are saved, only for the outputs when enabled in the config.
+    pub path: Option<PathBuf>,
+}
+
+/// The kind of a [traced tensor](TraceTensor).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TraceTensorKind {
+    /// Float tensor.
+    Float,
+    /// Int tensor.
+    Int,
+    /// Bool tensor.
+    Bool,
+}
+
+impl Trace {
+    /// Returns the operations with the given name.
+    pub fn ops_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a OpRecord> {
+        self.ops.iter().filter(move |op| op.name == name)
+    }
+
+    /// Export the trace in the DOT format of [Graphviz](https://graphviz.org), each operation
+    /// being a node showing the shapes of its inputs and outputs.
+    ///
+    /// The trace doesn't record which tensors are passed from an operation to another, so the
+    /// operations are connected in the order they were executed, not by the flow of the tensors.
+    pub fn to_dot(&self) -> String {
+        let join = |tensors: &[TraceTensor]| {
+            tensors
+                .iter()
+                .map(|tensor| format!("{:?}{:?}", tensor.kind, tensor.shape))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut dot = String::from("digraph {\n    node [shape=box, fontname=monospace];\n");
+
+        for op in self.ops.iter() {
+            dot.push_str(&format!(
+                "    op{} [label=\"{:06} {}\\n({}) -> ({})\"];\n",
+                op.id,
+                op.id,
+                op.name,
+                join(&op.inputs),
+                join(&op.outputs)
+            ));
+        }
+        for ops in self.ops.windows(2) {
+            dot.push_str(&format!("    op{} -> op{};\n", ops[0].id, ops[1].id));
+        }
+        dot.push_str("}\n");
+
+        dot
+    }
+}
+
+impl Display for Trace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for op in self.ops.iter() {
+            writeln!(f, "{op}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for OpRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let join = |tensors: &[TraceTensor]| {
+            tensors
+                .iter()
+                .map(|tensor| tensor.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        write!(
+            f,
+            "{:06} {}({}) -> ({})",
+            self.id,
+            self.name,
+            join(&self.inputs),
+            join(&self.outputs)
+        )
+    }
+}
+
+impl Display for TraceTensor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}{:?}", self.kind, self.shape)?;
+
+        if let Some(hash) = self.hash {
+            write!(f, " #{hash:016x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Records an operation when a trace is started, doing nothing otherwise.
+pub(crate) struct OpTrace<B: Backend> {
+    op: Option<(OpRecord, TraceConfig)>,
+    // Ends before the outputs are read, so it only measures the operation.
+    span: Option<timeline::Span>,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> OpTrace<B> {
+    pub(crate) fn new(name: &'static str) -> Self {
+        let op = STATE.with_borrow_mut(|state| {
+            state.as_mut().map(|state| {
+                let id = state.next_id;
+                state.next_id += 1;
+
+                let op = OpRecord {
+                    id,
+                    name,
+                    inputs: Vec::new(),
+                    outputs: Vec::new(),
+                };
+
+                (op, state.config.clone())
+            })
+        });
+
+        Self {
+            op,
+            span: Some(timeline::span(name, "op")),
+            _b: PhantomData,
+        }
+    }
+
+    pub(crate) fn float<const D: usize>(mut self, tensor: &FloatTensor<B, D>) -> Self {
+        if let Some((op, _)) = &mut self.op {
+            let shape = B::float_shape(tensor).dims.to_vec();
+            op.inputs
+                .push(TraceTensor::new(TraceTensorKind::Float, shape));
+        }
+
+        self
+    }
+
+    pub(crate) fn int<const D: usize>(mut self, tensor: &IntTensor<B, D>) -> Self {
+        if let Some((op, _)) = &mut self.op {
+            let shape = B::int_shape(tensor).dims.to_vec();
+            op.inputs
+                .push(TraceTensor::new(TraceTensorKind::Int, shape));
+        }
+
+        self
+    }
+
+    pub(crate) fn bool<const D: usize>(mut self, tensor: &BoolTensor<B, D>) -> Self {
+        if let Some((op, _)) = &mut self.op {
+            let shape = B::bool_shape(tensor).dims.to_vec();
+            op.inputs
+                .push(TraceTensor::new(TraceTensorKind::Bool, shape));
+        }
+
+        self
+    }
+
+    pub(crate) fn float_opt<const D: usize>(self, tensor: Option<&FloatTensor<B, D>>) -> Self {
+        match tensor {
+            Some(tensor) => self.float(tensor),
+            None => self,
+        }
+    }
+
+    pub(crate) fn floats<const D: usize>(self, tensors: &[FloatTensor<B, D>]) -> Self {
+        tensors
+            .iter()
+            .fold(self, |trace, tensor| trace.float(tensor))
+    }
+
+    pub(crate) fn ints<const D: usize>(self, tensors: &[IntTensor<B, D>]) -> Self {
+        tensors.iter().fold(self, |trace, tensor| trace.int(tensor))
+    }
+
+    pub(crate) fn bools<const D: usize>(self, tensors: &[BoolTensor<B, D>]) -> Self {
+        tensors
+            .iter()
+            .fold(self, |trace, tensor| trace.bool(tensor))
+    }
+
+    pub(crate) fn float_output<const D: usize>(mut self, tensor: &FloatTensor<B, D>) -> Self {
+        self.span.take();
+
+        if let Some((op, config)) = &mut self.op {
+            let shape = B::float_shape(tensor).dims.to_vec();
+            let values = match config.read_values() {
+                true => B::float_to_data(tensor).read_sync().map(|data| {
+                    data.convert::<f32>()
+                        .value
+                        .into_iter()
+                        .flat_map(f32::to_le_bytes)
+                        .collect()
+                }),
+                false => None,
+            };
+
+            op.output(config, TraceTensorKind::Float, shape, values);
+        }
+
+        self
+    }
+
+    pub(crate) fn int_output<const D: usize>(mut self, tensor: &IntTensor<B, D>) -> Self {
+        self.span.take();
+
+        if let Some((op, config)) = &mut self.op {
+            let shape = B::int_shape(tensor).dims.to_vec();
+            let values = match config.read_values() {
+                true => B::int_to_data(tensor).read_sync().map(|data| {
+                    data.convert::<i64>()
+                        .value
+                        .into_iter()
+                        .flat_map(i64::to_le_bytes)
+                        .collect()
+                }),
+                false => None,
+            };
+
+            op.output(config, TraceTensorKind::Int, shape, values);
+        }
+
+        self
+    }
+
+    pub(crate) fn bool_output<const D: usize>(mut self, tensor: &BoolTensor<B, D>) -> Self {
+        self.span.take();
+
+        if let Some((op, config)) = &mut self.op {
+            let shape = B::bool_shape(tensor).dims.to_vec();
+            let values = match config.read_values() {
+                true => B::bool_to_data(tensor)
+                    .read_sync()
+                    .map(|data| data.value.into_iter().map(u8::from).collect()),
+                false => None,
+            };
+
+            op.output(config, TraceTensorKind::Bool, shape, values);
+        }
+
+        self
+    }
+
+    pub(crate) fn float_output_opt<const D: usize>(
+        self,
+        tensor: Option<&FloatTensor<B, D>>,
+    ) -> Self {
+        match tensor {
+            Some(tensor) => self.float_output(tensor),
+            None => self,
+        }
+    }
+
+    pub(crate) fn float_outputs<const D: usize>(self, tensors: &[FloatTensor<B, D>]) -> Self {
+        tensors
+            .iter()
+            .fold(self, |trace, tensor| trace.float_output(tensor))
+    }
+
+    pub(crate) fn int_outputs<const D: usize>(self, tensors: &[IntTensor<B, D>]) -> Self {
+        tensors
+            .iter()
+            .fold(self, |trace, tensor| trace.int_output(tensor))
+    }
+
+    pub(crate) fn bool_outputs<const D: usize>(self, tensors: &[BoolTensor<B, D>]) -> Self {
+        tensors
+            .iter()
+            .fold(self, |trace, tensor| trace.bool_output(tensor))
+    }
+
+    pub(crate) fn finish(mut self) {
+        self.span.take();
+
+        if let Some((op, _)) = self.op {
+            STATE.with_borrow_mut(|state| {
+                if let Some(state) = state.as_mut() {
+                    state.ops.push(op);
+                }
+            });
+        }
+    }
+}
+
+impl TraceTensor {
+    fn new(kind: TraceTensorKind, shape: Vec<usize>) -> Self {
+        Self {
+            kind,
+            shape,
+            hash: None,
+            path: None,
+        }
+    }
+}
+
+impl OpRecord {
+    /// Record an output tensor, hashing and saving its values in their little-endian
+    /// representation when enabled.
+    fn output(
+        &mut self,
+        config: &TraceConfig,
+        kind: TraceTensorKind,
+        shape: Vec<usize>,
+        values: Option<Vec<u8>>,
+    ) {
+        let mut tensor = TraceTensor::new(kind, shape);
+
+        if let Some(values) = values {
+            if config.hash {
+                tensor.hash = Some(fnv1a(&values));
+            }
+
+            if let Some(dump_dir) = &config.dump_dir {
+                let path = dump_dir.join(format!(
+                    "{:06}_{}_{}.npy",
+                    self.id,
+                    self.name,
+                    self.outputs.len()
+                ));
+
+                write_npy(&path, kind, &tensor.shape, &values).unwrap_or_else(|err| {
+                    panic!(
+                        "Failed to write the traced tensor {}: {err}",
+                        path.display()
+                    )
+                });
+                tensor.path = Some(path);
+            }
+        }
+
+        self.outputs.push(tensor);
+    }
+}
+
+/// The 64-bit FNV-1a hash, which is stable across platforms and versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Write the values in the version 1.0 of the NumPy `.npy` format.
+fn write_npy(
+    path: &Path,
+    kind: TraceTensorKind,
+    shape: &[usize],
+    values: &[u8],
+) -> std::io::Result<()> {
+    let descr = match kind {
+        TraceTensorKind::Float => "<f4",
+        TraceTensorKind::Int => "<i8",
+        TraceTensorKind::Bool => "|b1",
+    };
+    let shape = match shape {
+        [size] => format!("({size},)"),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|size| size.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+
+    // The header is padded with spaces and terminated by a newline, so the data is aligned on
+    // 64 bytes accounting for the magic string, the version and the header length.
+    let mut header = format!("{{'descr': '{descr}', 'fortran_order': False, 'shape': {shape}, }}");
+    let padding = (64 - (10 + header.len() + 1) % 64) % 64;
+    header.extend(core::iter::repeat(' ').take(padding));
+    header.push('\n');
+
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_all(b"\x93NUMPY\x01\x00")?;
+    file.write_all(&(header.len() as u16).to_le_bytes())?;
+    file.write_all(header.as_bytes())?;
+    file.write_all(values)?;
+    file.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tracer;
+    use burn_tensor::{Int, Tensor};
+
+    type TestBackend = Tracer<burn_ndarray::NdArray<f32>>;
+
+    #[test]
+    fn should_record_op_spans_on_the_timeline() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2>::ones([2, 3], &device);
+        let rhs = Tensor::<TestBackend, 2>::ones([3, 4], &device);
+
+        timeline::start();
+        let _output = lhs.matmul(rhs);
+        let timeline = timeline::stop();
+
+        // Other tests can record ops at the same time on other threads.
+        assert!(timeline
+            .events
+            .iter()
+            .any(|event| event.name == "float_matmul"
+                && event.category == "op"
+                && matches!(event.kind, timeline::TimelineEventKind::Span { .. })));
+    }
+
+    #[test]
+    fn should_record_ops_and_shapes() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2>::ones([2, 3], &device);
+        let rhs = Tensor::<TestBackend, 2>::ones([3, 4], &device);
+
+        start_trace(TraceConfig::new());
+        let _output = lhs.matmul(rhs).argmax(1);
+        let trace = end_trace();
+
+        assert_eq!(trace.ops.len(), 2);
+        assert_eq!(trace.ops[0].name, "float_matmul");
+        assert_eq!(
+            trace.ops[0].inputs,
+            vec![
+                TraceTensor::new(TraceTensorKind::Float, vec![2, 3]),
+                TraceTensor::new(TraceTensorKind::Float, vec![3, 4]),
+            ]
+        );
+        assert_eq!(
+            trace.ops[0].outputs,
+            vec![TraceTensor::new(TraceTensorKind::Float, vec![2, 4])]
+        );
+        assert_eq!(trace.ops[1].name, "float_argmax");
+        assert_eq!(
+            trace.ops[1].outputs,
+            vec![TraceTensor::new(TraceTensorKind::Int, vec![2, 1])]
+        );
+        assert_eq!(
+            trace.to_string(),
+            "000000 float_matmul(Float[2, 3], Float[3, 4]) -> (Float[2, 4])\n\
+             000001 float_argmax(Float[2, 4]) -> (Int[2, 1])\n"
+        );
+    }
+
+    #[test]
+    fn should_export_the_trace_to_dot() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::ones([2, 3], &device);
+
+        start_trace(TraceConfig::new());
+        let _output = tensor.exp().sum_dim(1);
+        let trace = end_trace();
+
+        assert_eq!(
+            trace.to_dot(),
+            "digraph {\n    node [shape=box, fontname=monospace];\n    \
+             op0 [label=\"000000 float_exp\\n(Float[2, 3]) -> (Float[2, 3])\"];\n    \
+             op1 [label=\"000001 float_sum_dim\\n(Float[2, 3]) -> (Float[2, 1])\"];\n    \
+             op0 -> op1;\n}\n"
+        );
+    }
+
+    #[test]
+    fn should_hash_outputs() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0, 3.0], &device);
+
+        start_trace(TraceConfig::new().with_hash(true));
+        let _ = tensor.clone().exp();
+        let _ = tensor.clone().exp();
+        let _ = tensor.log();
+        let trace = end_trace();
+
+        let hashes = trace
+            .ops
+            .iter()
+            .map(|op| op.outputs[0].hash.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[2]);
+    }
+
+    #[test]
+    fn should_dump_outputs_in_npy_format() {
+        let device = Default::default();
+        let dump_dir = std::env::temp_dir().join("burn-tracer-test");
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(1..4, &device);
+
+        start_trace(TraceConfig::new().with_dump_dir(&dump_dir));
+        let _ = tensor.add_scalar(1);
+        let trace = end_trace();
+
+        let path = trace.ops[0].outputs[0].path.clone().unwrap();
+        assert_eq!(path, dump_dir.join("000000_int_add_scalar_0.npy"));
+
+        let bytes = std::fs::read(path).unwrap();
+        let (header, values) = bytes.split_at(64);
+        assert_eq!(&header[..6], b"\x93NUMPY");
+        assert!(String::from_utf8_lossy(header)
+            .contains("{'descr': '<i8', 'fortran_order': False, 'shape': (3,), }"));
+        assert_eq!(
+            values,
+            [2i64, 3, 4]
+                .into_iter()
+                .flat_map(i64::to_le_bytes)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_not_record_ops_after_the_end_of_the_trace() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::ones([2], &device);
+
+        start_trace(TraceConfig::new());
+        end_trace();
+        let _ = tensor.exp();
+
+        assert!(end_trace().ops.is_empty());
+    }
+}
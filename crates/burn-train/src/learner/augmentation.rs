@@ -0,0 +1,165 @@
+use crate::{TrainOutput, TrainStep, ValidStep};
+use burn_core as burn;
+use burn_core::module::{AutodiffModule, Ignored, Module};
+use burn_core::preprocessing::Augmenter;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::{Int, Tensor};
+use core::marker::PhantomData;
+
+/// A training batch of images with class targets, which can be augmented by
+/// [BatchAugmentation].
+pub trait AugmentableBatch<B: Backend> {
+    /// Splits the batch into its images and its targets.
+    ///
+    /// # Shapes
+    ///
+    /// - images: `[batch_size, channels, height, width]`
+    /// - targets: `[batch_size]`
+    fn into_images_and_targets(self) -> (Tensor<B, 4>, Tensor<B, 1, Int>);
+}
+
+impl<B: Backend> AugmentableBatch<B> for (Tensor<B, 4>, Tensor<B, 1, Int>) {
+    fn into_images_and_targets(self) -> (Tensor<B, 4>, Tensor<B, 1, Int>) {
+        self
+    }
+}
+
+/// A batch of augmented images with soft class targets, produced by [BatchAugmentation].
+#[derive(new, Debug, Clone)]
+pub struct AugmentedBatch<B: Backend> {
+    /// The augmented images of shape `[batch_size, channels, height, width]`.
+    pub images: Tensor<B, 4>,
+    /// The class probabilities of shape `[batch_size, num_classes]`, to be used with
+    /// [forward_soft](burn_core::nn::loss::CrossEntropyLoss::forward_soft).
+    pub targets: Tensor<B, 2>,
+    /// The most likely class of each image of shape `[batch_size]`, for the metrics.
+    pub classes: Tensor<B, 1, Int>,
+}
+
+/// A model trained on batches augmented by an [augmenter](Augmenter) before each training step,
+/// to be trained with the [Learner](crate::Learner) as any other model.
+///
+/// The augmentations run on the training device and rewrite both the images and their targets,
+/// so mixup and cutmix can be used without changing the batch type of the dataloader: the
+/// one-hot encoded targets of each [batch](AugmentableBatch) are mixed into the soft targets of
+/// the [augmented batch](AugmentedBatch) received by the model, whose loss should support them,
+/// e.g. with [forward_soft](burn_core::nn::loss::CrossEntropyLoss::forward_soft), which also
+/// applies the label smoothing of the loss. The validation runs the model on the batches as is.
+#[derive(Module, Debug)]
+pub struct BatchAugmentation<B: Backend, M> {
+    /// The model, which is trained on the augmented batches.
+    pub model: M,
+    augmenter: Ignored<Augmenter>,
+    num_classes: usize,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend, M: Module<B>> BatchAugmentation<B, M> {
+    /// Creates the augmentation of the training batches of the model.
+    pub fn new(model: M, augmenter: Augmenter, num_classes: usize) -> Self {
+        Self {
+            model,
+            augmenter: Ignored(augmenter),
+            num_classes,
+            backend: PhantomData,
+        }
+    }
+
+    /// The trained model.
+    pub fn into_model(self) -> M {
+        self.model
+    }
+
+    /// Augments a training batch.
+    pub fn augment<TI: AugmentableBatch<B>>(&self, item: TI) -> AugmentedBatch<B> {
+        let (images, targets) = item.into_images_and_targets();
+        let [batch_size] = targets.dims();
+        let device = targets.device();
+
+        let one_hot = Tensor::zeros([batch_size, self.num_classes], &device).scatter(
+            1,
+            targets.reshape([batch_size, 1]),
+            Tensor::ones([batch_size, 1], &device),
+        );
+        let (images, targets) = self.augmenter.augment_with_targets(images, one_hot);
+        let classes = targets.clone().argmax(1).reshape([batch_size]);
+
+        AugmentedBatch::new(images, targets, classes)
+    }
+}
+
+impl<B, M, TI, TO> TrainStep<TI, TO> for BatchAugmentation<B, M>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B> + TrainStep<AugmentedBatch<B>, TO>,
+    TI: AugmentableBatch<B>,
+{
+    fn step(&self, item: TI) -> TrainOutput<TO> {
+        TrainStep::step(&self.model, self.augment(item))
+    }
+}
+
+impl<B, M, VI, VO> ValidStep<VI, VO> for BatchAugmentation<B, M>
+where
+    B: Backend,
+    M: ValidStep<VI, VO>,
+{
+    fn step(&self, item: VI) -> VO {
+        ValidStep::step(&self.model, item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::nn::loss::CrossEntropyLossConfig;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::preprocessing::{Augmentation, AugmenterConfig};
+    use burn_core::tensor::Distribution;
+
+    type Model = Linear<TestAutodiffBackend>;
+
+    impl TrainStep<AugmentedBatch<TestAutodiffBackend>, ()> for Model {
+        fn step(&self, batch: AugmentedBatch<TestAutodiffBackend>) -> TrainOutput<()> {
+            let [batch_size, _, _, _] = batch.images.dims();
+            let logits = self.forward(batch.images.reshape([batch_size, 12]));
+            let loss = CrossEntropyLossConfig::new()
+                .with_smoothing(Some(0.1))
+                .init(&logits.device())
+                .forward_soft(logits, batch.targets);
+
+            TrainOutput::new(self, loss.backward(), ())
+        }
+    }
+
+    #[test]
+    fn should_train_on_mixed_batches() {
+        let device = Default::default();
+        let model = LinearConfig::new(12, 3).init::<TestAutodiffBackend>(&device);
+        let augmenter = AugmenterConfig::new(vec![
+            Augmentation::Mixup { alpha: 0.4 },
+            Augmentation::CutMix { alpha: 1.0 },
+        ])
+        .with_seed(Some(42))
+        .init();
+        let augmentation =
+            BatchAugmentation::<TestAutodiffBackend, Model>::new(model.clone(), augmenter, 3);
+        let images = Tensor::random([4, 3, 2, 2], Distribution::Default, &device);
+        let targets = Tensor::from_ints([0, 2, 1, 2], &device);
+
+        let batch = augmentation.augment((images.clone(), targets.clone()));
+        assert_eq!(batch.images.dims(), [4, 3, 2, 2]);
+        assert_eq!(batch.classes.dims(), [4]);
+        batch.targets.sum_dim(1).into_data().assert_approx_eq(
+            &Tensor::<TestAutodiffBackend, 2>::ones([4, 1], &device).into_data(),
+            5,
+        );
+
+        let output = TrainStep::step(&augmentation, (images, targets));
+        assert!(output
+            .grads
+            .get::<TestBackend, 2>(&model.weight.id)
+            .is_some());
+    }
+}
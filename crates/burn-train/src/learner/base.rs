@@ -1,11 +1,14 @@
-use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
+use crate::checkpoint::{
+    AsyncCheckpointer, Checkpointer, CheckpointingAction, CheckpointingStrategy,
+};
 use crate::components::LearnerComponents;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::{EarlyStoppingStrategy, NonFinitePolicy};
 use crate::metric::store::EventStoreClient;
 use crate::LearnerSummaryConfig;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::Module;
 use burn_core::optim::Optimizer;
+use burn_core::record::RngState;
 use burn_core::tensor::backend::Backend;
 use burn_core::tensor::Device;
 use std::rc::Rc;
@@ -22,6 +25,8 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
     pub(crate) grad_accumulation: Option<usize>,
+    pub(crate) non_finite_policy: Option<NonFinitePolicy>,
+    pub(crate) param_stats: Option<usize>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
     pub(crate) interrupter: TrainingInterrupter,
@@ -29,6 +34,7 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) event_processor: LC::EventProcessor,
     pub(crate) event_store: Rc<EventStoreClient>,
     pub(crate) summary: Option<LearnerSummaryConfig>,
+    pub(crate) num_tests: usize,
 }
 
 #[derive(new)]
@@ -36,6 +42,7 @@ pub(crate) struct LearnerCheckpointer<LC: LearnerComponents> {
     model: LC::CheckpointerModel,
     optim: LC::CheckpointerOptimizer,
     lr_scheduler: LC::CheckpointerLrScheduler,
+    rng: AsyncCheckpointer<RngState, LC::Backend>,
     strategy: LC::CheckpointerStrategy,
 }
 
@@ -62,6 +69,9 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
                     self.lr_scheduler
                         .delete(epoch)
                         .expect("Can delete learning rate scheduler checkpoint.");
+                    self.rng
+                        .delete(epoch)
+                        .expect("Can delete random state checkpoint.");
                 }
                 CheckpointingAction::Save => {
                     self.model
@@ -73,6 +83,11 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
                     self.lr_scheduler
                         .save(epoch, scheduler.to_record())
                         .expect("Can save learning rate scheduler checkpoint.");
+                    // Forks the random state of the backend, so the training continues with
+                    // the same random values when resuming from the checkpoint.
+                    self.rng
+                        .save(epoch, RngState::capture::<LC::Backend>())
+                        .expect("Can save random state checkpoint.");
                 }
             }
         }
@@ -104,6 +119,12 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
             .expect("Can load learning rate scheduler checkpoint.");
         let scheduler = scheduler.load_record(record);
 
+        // Checkpoints saved before the random state was recorded don't have one.
+        match self.rng.restore(epoch, device) {
+            Ok(state) => state.restore::<LC::Backend>(),
+            Err(err) => log::warn!("Can't load random state checkpoint: {err:?}"),
+        }
+
         (model, optim, scheduler)
     }
 }
@@ -8,12 +8,12 @@ use crate::checkpoint::{
 };
 use crate::components::LearnerComponentsMarker;
 use crate::learner::base::TrainingInterrupter;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::{EarlyStoppingStrategy, NonFinitePolicy};
 use crate::logger::{FileMetricLogger, MetricLogger};
 use crate::metric::processor::{FullEventProcessor, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
 use crate::metric::{Adaptor, LossMetric, Metric};
-use crate::renderer::{default_renderer, MetricsRenderer};
+use crate::renderer::{default_renderer, JsonlMetricsRenderer, JsonlTarget, MetricsRenderer};
 use crate::{
     ApplicationLoggerInstaller, FileApplicationLoggerInstaller, LearnerCheckpointer,
     LearnerSummaryConfig,
@@ -21,7 +21,7 @@ use crate::{
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::AutodiffModule;
 use burn_core::optim::Optimizer;
-use burn_core::record::FileRecorder;
+use burn_core::record::{FileRecorder, RngState};
 use burn_core::tensor::backend::AutodiffBackend;
 
 /// Struct to configure and create a [learner](Learner).
@@ -42,11 +42,14 @@ where
         AsyncCheckpointer<M::Record, B>,
         AsyncCheckpointer<O::Record, B>,
         AsyncCheckpointer<S::Record, B>,
+        AsyncCheckpointer<RngState, B>,
     )>,
     num_epochs: usize,
     checkpoint: Option<usize>,
     directory: String,
     grad_accumulation: Option<usize>,
+    non_finite_policy: Option<NonFinitePolicy>,
+    param_stats: Option<usize>,
     devices: Vec<B::Device>,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: Metrics<T, V>,
@@ -81,6 +84,8 @@ where
             checkpointers: None,
             directory: directory.to_string(),
             grad_accumulation: None,
+            non_finite_policy: None,
+            param_stats: None,
             devices: vec![B::Device::default()],
             metrics: Metrics::default(),
             event_store: LogEventStore::default(),
@@ -145,6 +150,16 @@ where
         self
     }
 
+    /// Replace the default CLI renderer with a [headless renderer](JsonlMetricsRenderer) that
+    /// writes one JSON object per iteration.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Where the events are written.
+    pub fn with_jsonl_renderer(self, target: JsonlTarget) -> Self {
+        self.renderer(JsonlMetricsRenderer::new(target))
+    }
+
     /// Register a training metric.
     pub fn metric_train<Me: Metric + 'static>(mut self, metric: Me) -> Self
     where
@@ -178,6 +193,33 @@ where
         self
     }
 
+    /// Check the gradients of each training step for NaN or infinite values, and apply the given
+    /// [policy](NonFinitePolicy) when they aren't finite.
+    ///
+    /// # Notes
+    ///
+    /// This reads back a value from the device at every step, which adds a synchronization point.
+    pub fn with_non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite_policy = Some(policy);
+        self
+    }
+
+    /// Record the [statistics](crate::ParamStats) of the parameters of the model every `interval`
+    /// training iterations, starting with the first one, which are tracked by the
+    /// [GradientNormMetric](crate::metric::GradientNormMetric),
+    /// [WeightNormMetric](crate::metric::WeightNormMetric),
+    /// [UpdateRatioMetric](crate::metric::UpdateRatioMetric) and
+    /// [ParamStatsMetric](crate::metric::ParamStatsMetric).
+    ///
+    /// # Notes
+    ///
+    /// This reads back the statistics from the device at each recorded iteration, which adds a
+    /// synchronization point, so the interval shouldn't be too small.
+    pub fn with_param_stats(mut self, interval: usize) -> Self {
+        self.param_stats = Some(interval.max(1));
+        self
+    }
+
     /// Register a [numeric](crate::metric::Numeric) training [metric](Metric).
     pub fn metric_train_numeric<Me>(mut self, metric: Me) -> Self
     where
@@ -247,7 +289,8 @@ where
     }
 
     /// Register a checkpointer that will save the [optimizer](Optimizer), the
-    /// [model](AutodiffModule) and the [scheduler](LrScheduler) to different files.
+    /// [model](AutodiffModule), the [scheduler](LrScheduler) and the
+    /// [random state](RngState) of the backend to different files.
     pub fn with_file_checkpointer<FR>(mut self, recorder: FR) -> Self
     where
         FR: FileRecorder<B> + 'static,
@@ -267,15 +310,21 @@ where
             "optim",
         );
         let checkpointer_scheduler: FileCheckpointer<FR> = FileCheckpointer::new(
-            recorder,
+            recorder.clone(),
             format!("{}/checkpoint", self.directory).as_str(),
             "scheduler",
         );
+        let checkpointer_rng: FileCheckpointer<FR> = FileCheckpointer::new(
+            recorder,
+            format!("{}/checkpoint", self.directory).as_str(),
+            "rng",
+        );
 
         self.checkpointers = Some((
             AsyncCheckpointer::new(checkpointer_model),
             AsyncCheckpointer::new(checkpointer_optimizer),
             AsyncCheckpointer::new(checkpointer_scheduler),
+            AsyncCheckpointer::new(checkpointer_rng),
         ));
 
         self
@@ -336,13 +385,15 @@ where
                 .register_logger_valid(FileMetricLogger::new(
                     format!("{directory}/valid").as_str(),
                 ));
+            self.event_store
+                .register_logger_test(FileMetricLogger::new(format!("{directory}/test").as_str()));
         }
 
         let event_store = Rc::new(EventStoreClient::new(self.event_store));
         let event_processor = FullEventProcessor::new(self.metrics, renderer, event_store.clone());
 
-        let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
-            LearnerCheckpointer::new(model, optim, scheduler, self.checkpointer_strategy)
+        let checkpointer = self.checkpointers.map(|(model, optim, scheduler, rng)| {
+            LearnerCheckpointer::new(model, optim, scheduler, rng, self.checkpointer_strategy)
         });
 
         let summary = if self.summary {
@@ -364,10 +415,13 @@ where
             event_store,
             checkpoint: self.checkpoint,
             grad_accumulation: self.grad_accumulation,
+            non_finite_policy: self.non_finite_policy,
+            param_stats: self.param_stats,
             devices: self.devices,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
             summary,
+            num_tests: 0,
         }
     }
 }
@@ -0,0 +1,213 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use crate::LearnerSummary;
+use burn_core::data::dataset::transform::{KFold, SubsetDataset};
+use burn_core::data::dataset::Dataset;
+
+/// K-fold cross-validation driver.
+///
+/// The dataset is split into folds with [KFold], and a fresh model is trained on each fold by the
+/// provided closure, which is expected to build a [learner](crate::Learner) in the given fold
+/// directory. Once every fold is trained, the final validation value of the requested metrics is
+/// read back from each fold directory to compute the mean and standard deviation over the folds.
+pub struct CrossValidation {
+    directory: String,
+    kfold: KFold,
+    metrics: Vec<String>,
+}
+
+/// The per-fold and aggregated results of a [cross-validation](CrossValidation).
+pub struct CrossValidationSummary {
+    /// The summary of each metric over all folds.
+    pub metrics: Vec<CrossValidationMetric>,
+}
+
+/// The results of a metric over all folds.
+pub struct CrossValidationMetric {
+    /// The metric name.
+    pub name: String,
+    /// The final validation value of each fold, `None` if the metric wasn't recorded.
+    pub folds: Vec<Option<f64>>,
+    /// The mean over the folds where the metric was recorded.
+    pub mean: f64,
+    /// The standard deviation over the folds where the metric was recorded.
+    pub std: f64,
+}
+
+impl CrossValidation {
+    /// Creates a new cross-validation driver.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory where each fold saves its artifacts, under `fold-{k}`.
+    /// * `num_folds` - The number of folds.
+    pub fn new(directory: &str, num_folds: usize) -> Self {
+        Self {
+            directory: directory.to_string(),
+            kfold: KFold::new(num_folds),
+            metrics: Vec::new(),
+        }
+    }
+
+    /// Shuffle the dataset with the given seed before splitting it into folds.
+    pub fn with_shuffle(mut self, seed: u64) -> Self {
+        self.kfold = self.kfold.with_shuffle(seed);
+        self
+    }
+
+    /// Register the names of the validation metrics to aggregate, e.g. `LossMetric::NAME`.
+    pub fn with_metrics<S: AsRef<str>>(mut self, metrics: &[S]) -> Self {
+        self.metrics = metrics
+            .iter()
+            .map(|metric| metric.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Runs the cross-validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset` - The dataset to split into folds.
+    /// * `train_fold` - Trains a fresh model with the fold directory, the training dataset and
+    ///   the validation dataset of the fold.
+    ///
+    /// # Returns
+    ///
+    /// The summary of the registered metrics over all folds.
+    pub fn run<D, I, F>(&self, dataset: D, mut train_fold: F) -> CrossValidationSummary
+    where
+        D: Dataset<I>,
+        F: FnMut(&str, SubsetDataset<Arc<D>, I>, SubsetDataset<Arc<D>, I>),
+    {
+        let folds = self.kfold.split(dataset);
+        let mut values = vec![Vec::with_capacity(folds.len()); self.metrics.len()];
+
+        for (fold, (dataset_train, dataset_valid)) in folds.into_iter().enumerate() {
+            let directory = format!("{}/fold-{}", self.directory, fold + 1);
+            log::info!(
+                "Training fold {}/{} in {directory}",
+                fold + 1,
+                self.kfold.num_folds()
+            );
+
+            train_fold(&directory, dataset_train, dataset_valid);
+
+            let summary = match LearnerSummary::new(&directory, &self.metrics) {
+                Ok(summary) => Some(summary),
+                Err(err) => {
+                    log::error!(
+                        "Could not retrieve the summary of fold {}:\n{err}",
+                        fold + 1
+                    );
+                    None
+                }
+            };
+
+            for (name, values) in self.metrics.iter().zip(values.iter_mut()) {
+                let value = summary.as_ref().and_then(|summary| {
+                    summary
+                        .metrics
+                        .valid
+                        .iter()
+                        .find(|metric| &metric.name == name)
+                        .and_then(|metric| metric.entries.last())
+                        .map(|entry| entry.value)
+                });
+                values.push(value);
+            }
+        }
+
+        let metrics = self
+            .metrics
+            .iter()
+            .zip(values)
+            .map(|(name, folds)| CrossValidationMetric::new(name.clone(), folds))
+            .collect();
+
+        CrossValidationSummary { metrics }
+    }
+}
+
+impl CrossValidationMetric {
+    fn new(name: String, folds: Vec<Option<f64>>) -> Self {
+        let recorded = folds.iter().flatten().copied().collect::<Vec<_>>();
+        let num_recorded = recorded.len() as f64;
+
+        let mean = recorded.iter().sum::<f64>() / num_recorded;
+        let variance = recorded
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / num_recorded;
+
+        Self {
+            name,
+            folds,
+            mean,
+            std: variance.sqrt(),
+        }
+    }
+}
+
+impl Display for CrossValidationSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let max_metric_len = self
+            .metrics
+            .iter()
+            .map(|metric| metric.name.len())
+            .fold("Metric".len(), usize::max);
+
+        writeln!(
+            f,
+            "{:=>width_symbol$} Cross-Validation Summary {:=>width_symbol$}",
+            "",
+            "",
+            width_symbol = 24,
+        )?;
+
+        writeln!(
+            f,
+            "| {:<width_metric$} | Mean     | Std.     | Folds\n|{:->width_metric$}--|----------|----------|----------",
+            "Metric", "",
+            width_metric = max_metric_len,
+        )?;
+
+        for metric in self.metrics.iter() {
+            let folds = metric
+                .folds
+                .iter()
+                .map(|value| match value {
+                    Some(value) => format!("{value:.3}"),
+                    None => "-".to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            writeln!(
+                f,
+                "| {:<width_metric$} | {:<9.3?}| {:<9.3?}| {folds}",
+                metric.name,
+                metric.mean,
+                metric.std,
+                width_metric = max_metric_len,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metric_mean_and_std_ignore_missing_folds() {
+        let metric = CrossValidationMetric::new("Loss".into(), vec![Some(1.0), None, Some(3.0)]);
+
+        assert_eq!(metric.mean, 2.0);
+        assert_eq!(metric.std, 1.0);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{TrainOutput, TrainStep, ValidStep};
+use burn_core as burn;
+use burn_core::module::{AutodiffModule, Module};
+use burn_core::nn::loss::DistillationLoss;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+
+/// Trait to be implemented for training a student model by distillation from a teacher, see
+/// [Distillation].
+pub trait DistillationStep<B: Backend, T, TI, TO> {
+    /// Runs the training step of the student, which executes the forward passes of the teacher
+    /// and of the student on the same item, then the backward pass of the student.
+    ///
+    /// # Arguments
+    ///
+    /// * `teacher` - The frozen teacher model.
+    /// * `loss` - The distillation loss.
+    /// * `item` - The training input for the models.
+    ///
+    /// # Returns
+    ///
+    /// The training output containing the student output and the gradients.
+    fn step(&self, teacher: &T, loss: &DistillationLoss<B>, item: TI) -> TrainOutput<TO>;
+}
+
+/// A student model trained by knowledge distillation from a frozen teacher model, to be trained
+/// with the [Learner](crate::Learner) as any other model.
+///
+/// Both models share the training dataloader: each [step](DistillationStep::step) of the student
+/// receives the teacher along with the item. The teacher doesn't require gradients, so its
+/// forward pass isn't tracked by the autodiff backend and the optimizer leaves it unchanged, and
+/// the validation only runs the student with its [ValidStep].
+///
+/// # Notes
+///
+/// The teacher is part of the module, so it is saved in the checkpoints along with the student;
+/// use [into_student](Distillation::into_student) to get the trained student back.
+#[derive(Module, Debug)]
+pub struct Distillation<B: Backend, S, T> {
+    /// The student model, which is trained.
+    pub student: S,
+    /// The frozen teacher model.
+    pub teacher: T,
+    /// The distillation loss.
+    pub loss: DistillationLoss<B>,
+}
+
+impl<B: Backend, S: Module<B>, T: Module<B>> Distillation<B, S, T> {
+    /// Creates a distillation of the teacher into the student, freezing the teacher.
+    pub fn new(student: S, teacher: T, loss: DistillationLoss<B>) -> Self {
+        Self {
+            student,
+            teacher: teacher.no_grad(),
+            loss,
+        }
+    }
+
+    /// The trained student model.
+    pub fn into_student(self) -> S {
+        self.student
+    }
+}
+
+impl<B, S, T, TI, TO> TrainStep<TI, TO> for Distillation<B, S, T>
+where
+    B: AutodiffBackend,
+    S: AutodiffModule<B> + DistillationStep<B, T, TI, TO>,
+    T: AutodiffModule<B>,
+{
+    fn step(&self, item: TI) -> TrainOutput<TO> {
+        self.student.step(&self.teacher, &self.loss, item)
+    }
+}
+
+impl<B, S, T, VI, VO> ValidStep<VI, VO> for Distillation<B, S, T>
+where
+    B: Backend,
+    S: ValidStep<VI, VO>,
+{
+    fn step(&self, item: VI) -> VO {
+        self.student.step(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::nn::loss::DistillationLossConfig;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::{Optimizer, SgdConfig};
+    use burn_core::tensor::{Distribution, Int, Tensor};
+
+    type Batch = (
+        Tensor<TestAutodiffBackend, 2>,
+        Tensor<TestAutodiffBackend, 1, Int>,
+    );
+
+    type Model = Linear<TestAutodiffBackend>;
+
+    impl DistillationStep<TestAutodiffBackend, Model, Batch, ()> for Model {
+        fn step(
+            &self,
+            teacher: &Model,
+            loss: &DistillationLoss<TestAutodiffBackend>,
+            (input, targets): Batch,
+        ) -> TrainOutput<()> {
+            let teacher_logits = teacher.forward(input.clone());
+            let loss = loss.forward(self.forward(input), teacher_logits, targets);
+
+            TrainOutput::new(self, loss.backward(), ())
+        }
+    }
+
+    #[test]
+    fn should_only_train_the_student() {
+        let device = Default::default();
+        let student = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let teacher = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let distillation = Distillation::new(
+            student.clone(),
+            teacher.clone(),
+            DistillationLossConfig::new().init(&device),
+        );
+        let mut optim = SgdConfig::new().init();
+        let input = Tensor::random([2, 4], Distribution::Default, &device);
+        let targets = Tensor::from_ints([0, 2], &device);
+
+        let output = TrainStep::step(&distillation, (input, targets));
+        assert!(output
+            .grads
+            .get::<TestBackend, 2>(&teacher.weight.id)
+            .is_none());
+        let distillation = optim.step(0.1, distillation, output.grads);
+
+        distillation
+            .teacher
+            .weight
+            .to_data()
+            .assert_approx_eq(&teacher.weight.to_data(), 5);
+        assert_ne!(
+            distillation.student.weight.to_data().value,
+            student.weight.to_data().value
+        );
+    }
+}
@@ -1,12 +1,17 @@
 use burn_core::{
-    data::dataloader::DataLoader, lr_scheduler::LrScheduler, module::AutodiffModule,
-    optim::GradientsAccumulator, tensor::backend::Backend,
+    data::dataloader::DataLoader,
+    lr_scheduler::LrScheduler,
+    module::AutodiffModule,
+    optim::{GradientsAccumulator, GradientsParams},
+    tensor::backend::{AutodiffBackend, Backend},
 };
 use std::sync::Arc;
 
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
 use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
-use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
+use crate::{
+    learner::ParamStatsRecording, MultiDevicesTrainStep, NonFinitePolicy, TrainStep, ValidStep,
+};
 
 /// A validation epoch.
 #[derive(new)]
@@ -23,6 +28,8 @@ pub struct TrainEpoch<TI> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    non_finite_policy: Option<NonFinitePolicy>,
+    param_stats: Option<usize>,
 }
 
 impl<VI> ValidEpoch<VI> {
@@ -111,22 +118,31 @@ impl<TI> TrainEpoch<TI> {
 
             let progress = iterator.progress();
             let item = model.step(item);
+            let grads = self.check_grads::<LC>(&model, item.grads, iteration, interrupter);
+            let recording = self.record_param_stats::<LC>(&model, grads.as_ref(), iteration);
+            let mut optimized = false;
 
-            match self.grad_accumulation {
-                Some(accumulation) => {
-                    accumulator.accumulate(&model, item.grads);
+            match (self.grad_accumulation, grads) {
+                (Some(accumulation), Some(grads)) => {
+                    accumulator.accumulate(&model, grads);
                     accumulation_current += 1;
 
                     if accumulation <= accumulation_current {
                         let grads = accumulator.grads();
                         model = model.optimize(&mut optim, lr, grads);
                         accumulation_current = 0;
+                        optimized = true;
                     }
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
+                (None, Some(grads)) => {
+                    model = model.optimize(&mut optim, lr, grads);
+                    optimized = true;
+                }
+                // The optimizer update is skipped.
+                (_, None) => {}
             }
 
-            let item = LearnerItem::new(
+            let mut item = LearnerItem::new(
                 item.item,
                 progress,
                 self.epoch,
@@ -134,6 +150,8 @@ impl<TI> TrainEpoch<TI> {
                 iteration,
                 Some(lr),
             );
+            item.param_stats = recording
+                .map(|recording| Arc::new(recording.finish::<LC::Backend, _>(&model, optimized)));
 
             processor.process_train(Event::ProcessedItem(item));
 
@@ -207,17 +225,23 @@ impl<TI> TrainEpoch<TI> {
                 let progress = iterator.progress();
 
                 let grads = item.grads.to_device(&device_main, &model);
+                let grads = self.check_grads::<LC>(&model, grads, iteration, interrupter);
+                let recording = self.record_param_stats::<LC>(&model, grads.as_ref(), iteration);
+                let mut optimized = false;
 
-                accumulator.accumulate(&model, grads);
-                accumulation_current += 1;
+                if let Some(grads) = grads {
+                    accumulator.accumulate(&model, grads);
+                    accumulation_current += 1;
 
-                if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
-                    model = model.optimize(&mut optim, lr, grads);
-                    accumulation_current = 0;
+                    if accumulation <= accumulation_current {
+                        let grads = accumulator.grads();
+                        model = model.optimize(&mut optim, lr, grads);
+                        accumulation_current = 0;
+                        optimized = true;
+                    }
                 }
 
-                let item = LearnerItem::new(
+                let mut item = LearnerItem::new(
                     item.item,
                     progress,
                     self.epoch,
@@ -225,6 +249,9 @@ impl<TI> TrainEpoch<TI> {
                     iteration,
                     Some(lr),
                 );
+                item.param_stats = recording.map(|recording| {
+                    Arc::new(recording.finish::<LC::Backend, _>(&model, optimized))
+                });
 
                 processor.process_train(Event::ProcessedItem(item));
 
@@ -245,3 +272,41 @@ impl<TI> TrainEpoch<TI> {
         (model, optim)
     }
 }
+
+impl<TI> TrainEpoch<TI> {
+    /// Apply the [non-finite policy](NonFinitePolicy) to the gradients of a step, returning `None`
+    /// if the optimizer update should be skipped.
+    fn check_grads<LC: LearnerComponents>(
+        &self,
+        model: &LC::Model,
+        grads: GradientsParams,
+        iteration: usize,
+        interrupter: &TrainingInterrupter,
+    ) -> Option<GradientsParams> {
+        match &self.non_finite_policy {
+            Some(policy) => {
+                policy.check::<LC::Backend, _>(model, grads, self.epoch, iteration, interrupter)
+            }
+            None => Some(grads),
+        }
+    }
+
+    /// Start recording the [statistics](crate::ParamStats) of the parameters if they are enabled
+    /// and the iteration is one of the recorded ones.
+    fn record_param_stats<LC: LearnerComponents>(
+        &self,
+        model: &LC::Model,
+        grads: Option<&GradientsParams>,
+        iteration: usize,
+    ) -> Option<ParamStatsRecording<<LC::Backend as AutodiffBackend>::InnerBackend>> {
+        let interval = self.param_stats?;
+
+        if (iteration - 1) % interval != 0 {
+            return None;
+        }
+
+        Some(ParamStatsRecording::start::<LC::Backend, _>(
+            model, grads, iteration,
+        ))
+    }
+}
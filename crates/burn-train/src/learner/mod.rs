@@ -1,20 +1,31 @@
 mod application_logger;
+mod augmentation;
 mod base;
 mod builder;
 mod classification;
+mod cross_validation;
+mod distillation;
 mod early_stopping;
 mod epoch;
+mod non_finite;
+mod param_stats;
+mod predict;
 mod regression;
 mod step;
 mod summary;
 mod train_val;
 
 pub use application_logger::*;
+pub use augmentation::*;
 pub use base::*;
 pub use builder::*;
 pub use classification::*;
+pub use cross_validation::*;
+pub use distillation::*;
 pub use early_stopping::*;
 pub use epoch::*;
+pub use non_finite::*;
+pub use param_stats::*;
 pub use regression::*;
 pub use step::*;
 pub use summary::*;
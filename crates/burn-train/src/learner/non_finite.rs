@@ -0,0 +1,271 @@
+use crate::TrainingInterrupter;
+use burn_core::module::{AutodiffModule, ModuleVisitor, ParamId};
+use burn_core::optim::GradientsParams;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::{Bool, Tensor};
+use core::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+/// What the [learner](crate::Learner) should do when a training step produces non-finite
+/// gradients, which happens after rare numerical spikes in long runs.
+///
+/// # Notes
+///
+/// A non-finite loss (NaN or infinity) always results in non-finite gradients, so only the
+/// gradients are checked, after each step and before they are accumulated or applied.
+#[derive(Clone)]
+pub enum NonFinitePolicy {
+    /// Skip the optimizer update of the step and keep training.
+    SkipStep,
+    /// Skip the optimizer update of the step and reduce the scale of the [loss scaler](LossScaler).
+    ///
+    /// The gradients of the other steps are divided by the current scale before being applied.
+    ReduceLossScale(LossScaler),
+    /// Stop the training, logging the parameters with non-finite gradients.
+    Abort,
+}
+
+/// Dynamic loss scaling, to be used with [NonFinitePolicy::ReduceLossScale].
+///
+/// The loss should be multiplied by the [scale](LossScaler::scale) in the
+/// [training step](crate::TrainStep) before the backward pass, which can be done with
+/// [scale_loss](LossScaler::scale_loss). The scale is reduced each time the gradients overflow,
+/// and increased after a number of consecutive steps with finite gradients.
+///
+/// Clones share the same scale, so a clone can be kept in the training step.
+#[derive(Clone)]
+pub struct LossScaler {
+    growth_factor: f64,
+    backoff_factor: f64,
+    growth_interval: usize,
+    state: Arc<Mutex<LossScalerState>>,
+}
+
+struct LossScalerState {
+    scale: f64,
+    num_finite_steps: usize,
+}
+
+impl LossScaler {
+    /// Creates a new loss scaler.
+    ///
+    /// By default, the scale is halved when the gradients aren't finite and doubled after 2000
+    /// consecutive steps with finite gradients.
+    pub fn new(initial_scale: f64) -> Self {
+        Self {
+            growth_factor: 2.0,
+            backoff_factor: 0.5,
+            growth_interval: 2000,
+            state: Arc::new(Mutex::new(LossScalerState {
+                scale: initial_scale,
+                num_finite_steps: 0,
+            })),
+        }
+    }
+
+    /// The factor multiplying the scale after `growth_interval` steps with finite gradients.
+    pub fn with_growth_factor(mut self, growth_factor: f64) -> Self {
+        self.growth_factor = growth_factor;
+        self
+    }
+
+    /// The factor multiplying the scale when the gradients aren't finite.
+    pub fn with_backoff_factor(mut self, backoff_factor: f64) -> Self {
+        self.backoff_factor = backoff_factor;
+        self
+    }
+
+    /// The number of consecutive steps with finite gradients before the scale is increased.
+    pub fn with_growth_interval(mut self, growth_interval: usize) -> Self {
+        self.growth_interval = growth_interval;
+        self
+    }
+
+    /// The current scale.
+    pub fn scale(&self) -> f64 {
+        self.state.lock().unwrap().scale
+    }
+
+    /// Multiply the loss by the current scale.
+    pub fn scale_loss<B: Backend, const D: usize>(&self, loss: Tensor<B, D>) -> Tensor<B, D> {
+        loss.mul_scalar(self.scale())
+    }
+
+    fn update(&self, finite: bool) -> f64 {
+        let mut state = self.state.lock().unwrap();
+
+        if finite {
+            state.num_finite_steps += 1;
+            if state.num_finite_steps >= self.growth_interval {
+                state.scale *= self.growth_factor;
+                state.num_finite_steps = 0;
+            }
+        } else {
+            state.scale *= self.backoff_factor;
+            state.num_finite_steps = 0;
+        }
+
+        state.scale
+    }
+}
+
+impl NonFinitePolicy {
+    /// Check the gradients of a training step.
+    ///
+    /// # Returns
+    ///
+    /// The gradients to apply, or `None` if the optimizer update should be skipped.
+    pub(crate) fn check<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        module: &M,
+        grads: GradientsParams,
+        epoch: usize,
+        iteration: usize,
+        interrupter: &TrainingInterrupter,
+    ) -> Option<GradientsParams> {
+        let mut flags = Vec::with_capacity(grads.len());
+        module.visit(&mut GradientsNonFinite::<M, B>::new(&grads, &mut flags));
+
+        let finite = match flags.is_empty() {
+            true => true,
+            false => {
+                !is_true(Tensor::cat(flags.iter().map(|(_, flag)| flag.clone()).collect(), 0).any())
+            }
+        };
+
+        if finite {
+            return match self {
+                Self::ReduceLossScale(scaler) => {
+                    let scale = scaler.scale();
+                    scaler.update(true);
+                    Some(unscale(module, grads, scale))
+                }
+                _ => Some(grads),
+            };
+        }
+
+        match self {
+            Self::SkipStep => log::warn!(
+                "Skipping the optimizer update of epoch {epoch} iteration {iteration}, the \
+                 gradients aren't finite."
+            ),
+            Self::ReduceLossScale(scaler) => {
+                let scale = scaler.update(false);
+                log::warn!(
+                    "Skipping the optimizer update of epoch {epoch} iteration {iteration}, the \
+                     gradients aren't finite. Reducing the loss scale to {scale}."
+                );
+            }
+            Self::Abort => {
+                let params = flags
+                    .into_iter()
+                    .filter(|(_, flag)| is_true(flag.clone()))
+                    .map(|(id, _)| id.to_string())
+                    .collect::<Vec<_>>();
+                log::error!(
+                    "Aborting the training at epoch {epoch} iteration {iteration}, the gradients \
+                     of {} out of {} parameters aren't finite: {}",
+                    params.len(),
+                    grads.len(),
+                    params.join(", ")
+                );
+                interrupter.stop();
+            }
+        }
+
+        None
+    }
+}
+
+/// Whether any value of the tensor is NaN or infinite.
+fn has_non_finite<B: Backend, const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, 1, Bool> {
+    Tensor::cat(vec![tensor.is_nan().any(), tensor.is_inf().any()], 0).any()
+}
+
+fn is_true<B: Backend>(flag: Tensor<B, 1, Bool>) -> bool {
+    flag.into_scalar()
+}
+
+fn unscale<B: AutodiffBackend, M: AutodiffModule<B>>(
+    module: &M,
+    mut grads: GradientsParams,
+    scale: f64,
+) -> GradientsParams {
+    module.visit(&mut GradientsScale::<M, B>::new(1.0 / scale, &mut grads));
+    grads
+}
+
+#[derive(new)]
+struct GradientsNonFinite<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    flags: &'a mut Vec<(ParamId, Tensor<B::InnerBackend, 1, Bool>)>,
+    phantom: PhantomData<M>,
+}
+
+#[derive(new)]
+struct GradientsScale<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
+    factor: f64,
+    grads: &'a mut GradientsParams,
+    phantom: PhantomData<M>,
+}
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsNonFinite<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            self.flags.push((id.clone(), has_non_finite(&grad)));
+        }
+    }
+}
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsScale<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            self.grads
+                .register::<B::InnerBackend, D>(id.clone(), grad.mul_scalar(self.factor));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_loss_scaler_backoff_and_growth() {
+        let scaler = LossScaler::new(1024.0).with_growth_interval(2);
+        let shared = scaler.clone();
+
+        assert_eq!(scaler.update(false), 512.0);
+        assert_eq!(scaler.update(true), 512.0);
+        assert_eq!(scaler.update(true), 1024.0);
+        // An overflow resets the number of finite steps.
+        scaler.update(true);
+        assert_eq!(scaler.update(false), 512.0);
+        assert_eq!(scaler.update(true), 512.0);
+        assert_eq!(shared.scale(), 512.0);
+    }
+
+    #[test]
+    fn test_has_non_finite() {
+        let device = Default::default();
+        let finite = Tensor::<TestBackend, 1>::from_floats([1.0, -2.0], &device);
+        let nan = Tensor::<TestBackend, 1>::from_floats([1.0, f32::NAN], &device);
+        let inf = Tensor::<TestBackend, 1>::from_floats([f32::INFINITY, 2.0], &device);
+        // The sum overflows although every value is finite.
+        let large = Tensor::<TestBackend, 1>::from_floats([f32::MAX, f32::MAX], &device);
+
+        assert!(!is_true(has_non_finite(&finite)));
+        assert!(is_true(has_non_finite(&nan)));
+        assert!(is_true(has_non_finite(&inf)));
+        assert!(!is_true(has_non_finite(&large)));
+    }
+}
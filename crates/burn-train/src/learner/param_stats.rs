@@ -0,0 +1,269 @@
+use burn_core::module::{AutodiffModule, ModuleVisitor, ParamId};
+use burn_core::optim::GradientsParams;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::Tensor;
+use std::collections::HashMap;
+
+/// The statistics of the parameters of a model at a training iteration, recorded by the
+/// [learner](crate::Learner) when [enabled](crate::LearnerBuilder::with_param_stats).
+///
+/// The statistics must be enabled on the learner for the metrics using them to report values.
+/// They are exposed to the metrics through the [metadata](crate::metric::MetricMetadata), see
+/// [GradientNormMetric](crate::metric::GradientNormMetric),
+/// [WeightNormMetric](crate::metric::WeightNormMetric),
+/// [UpdateRatioMetric](crate::metric::UpdateRatioMetric) and
+/// [ParamStatsMetric](crate::metric::ParamStatsMetric).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamStats {
+    /// The iteration the statistics were recorded at.
+    pub iteration: usize,
+    /// The statistics of each float parameter, in the order of the fields of the model.
+    pub params: Vec<ParamStat>,
+}
+
+/// The statistics of a parameter, see [ParamStats].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamStat {
+    /// The path of the parameter in the model, e.g. `encoder.linear.weight`.
+    pub name: String,
+    /// The L2 norm of the gradients of the iteration, before they are accumulated, if the
+    /// parameter has gradients.
+    pub grad_norm: Option<f64>,
+    /// The L2 norm of the parameter after the optimizer step.
+    pub weight_norm: f64,
+    /// The L2 norm of the change of the parameter made by the optimizer step, if the optimizer
+    /// updated the model at this iteration.
+    pub update_norm: Option<f64>,
+}
+
+impl ParamStat {
+    /// The norm of the update relative to the norm of the parameter, which should usually stay
+    /// around `1e-3`: much larger ratios are a sign of a too high learning rate.
+    pub fn update_ratio(&self) -> Option<f64> {
+        self.update_norm.map(|norm| ratio(norm, self.weight_norm))
+    }
+}
+
+impl ParamStats {
+    /// The L2 norm of the gradients of all the parameters, if any has gradients.
+    pub fn grad_norm(&self) -> Option<f64> {
+        global_norm(self.params.iter().filter_map(|param| param.grad_norm))
+    }
+
+    /// The L2 norm of all the parameters.
+    pub fn weight_norm(&self) -> f64 {
+        global_norm(self.params.iter().map(|param| param.weight_norm)).unwrap_or(0.0)
+    }
+
+    /// The norm of the update of all the parameters relative to their norm, if the optimizer
+    /// updated the model at this iteration.
+    pub fn update_ratio(&self) -> Option<f64> {
+        let update_norm = global_norm(self.params.iter().filter_map(|param| param.update_norm))?;
+
+        Some(ratio(update_norm, self.weight_norm()))
+    }
+}
+
+fn global_norm<I: Iterator<Item = f64>>(norms: I) -> Option<f64> {
+    norms
+        .map(|norm| norm * norm)
+        .reduce(|a, b| a + b)
+        .map(f64::sqrt)
+}
+
+fn ratio(norm: f64, weight_norm: f64) -> f64 {
+    if weight_norm == 0.0 {
+        return 0.0;
+    }
+
+    norm / weight_norm
+}
+
+/// The statistics of the parameters being recorded around an optimizer step.
+pub(crate) struct ParamStatsRecording<B: Backend> {
+    iteration: usize,
+    params: Vec<RecordedParam<B>>,
+}
+
+struct RecordedParam<B: Backend> {
+    id: ParamId,
+    name: String,
+    weight: Tensor<B, 1>,
+    grad: Option<Tensor<B, 1>>,
+}
+
+impl<B: Backend> ParamStatsRecording<B> {
+    /// Start recording the statistics of the parameters of the model, before the optimizer step.
+    pub(crate) fn start<AB, M>(model: &M, grads: Option<&GradientsParams>, iteration: usize) -> Self
+    where
+        AB: AutodiffBackend<InnerBackend = B>,
+        M: AutodiffModule<AB>,
+    {
+        let mut collector = ParamCollector::<AB> {
+            grads,
+            path: Vec::new(),
+            params: Vec::new(),
+        };
+        model.visit(&mut collector);
+
+        Self {
+            iteration,
+            params: collector.params,
+        }
+    }
+
+    /// Compute the statistics of the parameters of the model after the optimizer step, which
+    /// reads the values from the device once.
+    pub(crate) fn finish<AB, M>(self, model: &M, optimized: bool) -> ParamStats
+    where
+        AB: AutodiffBackend<InnerBackend = B>,
+        M: AutodiffModule<AB>,
+    {
+        let mut collector = ParamCollector::<AB> {
+            grads: None,
+            path: Vec::new(),
+            params: Vec::new(),
+        };
+        model.visit(&mut collector);
+        let mut weights = collector
+            .params
+            .into_iter()
+            .map(|param| (param.id, param.weight))
+            .collect::<HashMap<_, _>>();
+
+        // The squared norms of each parameter, with the index of the norm of the gradients and
+        // the update in the list.
+        let mut squares = Vec::new();
+        let mut indices = Vec::with_capacity(self.params.len());
+        let mut push = |tensor: Tensor<B, 1>| {
+            squares.push(tensor.powf_scalar(2.0).sum());
+            squares.len() - 1
+        };
+
+        for param in self.params.iter() {
+            let weight = weights
+                .remove(&param.id)
+                .unwrap_or_else(|| param.weight.clone());
+            let grad = param.grad.clone().map(&mut push);
+            let update = optimized.then(|| push(weight.clone() - param.weight.clone()));
+            let weight = push(weight);
+
+            indices.push((grad, weight, update));
+        }
+
+        let norms = match squares.is_empty() {
+            true => Vec::new(),
+            false => Tensor::cat(squares, 0)
+                .into_data()
+                .convert::<f64>()
+                .value
+                .into_iter()
+                .map(f64::sqrt)
+                .collect(),
+        };
+
+        let params = self
+            .params
+            .into_iter()
+            .zip(indices)
+            .map(|(param, (grad, weight, update))| ParamStat {
+                name: param.name,
+                grad_norm: grad.map(|index| norms[index]),
+                weight_norm: norms[weight],
+                update_norm: update.map(|index| norms[index]),
+            })
+            .collect();
+
+        ParamStats {
+            iteration: self.iteration,
+            params,
+        }
+    }
+}
+
+struct ParamCollector<'a, B: AutodiffBackend> {
+    grads: Option<&'a GradientsParams>,
+    path: Vec<String>,
+    params: Vec<RecordedParam<B::InnerBackend>>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for ParamCollector<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let num_elements = tensor.shape().num_elements();
+        let grad = self
+            .grads
+            .and_then(|grads| grads.get::<B::InnerBackend, D>(id))
+            .map(|grad| grad.reshape([num_elements]));
+
+        self.params.push(RecordedParam {
+            id: id.clone(),
+            name: self.path.join("."),
+            weight: tensor.clone().inner().reshape([num_elements]),
+            grad,
+        });
+    }
+
+    fn enter_module(&mut self, name: &str, _container_type: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::{GradientsParams, Optimizer, SgdConfig};
+    use burn_core::tensor::{Distribution, ElementConversion};
+
+    #[test]
+    fn should_record_the_stats_of_each_param() {
+        let device = Default::default();
+        let model: Linear<TestAutodiffBackend> = LinearConfig::new(4, 2).init(&device);
+        let input = Tensor::random([3, 4], Distribution::Default, &device);
+        let grads = model.forward(input).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &model);
+        let mut optim = SgdConfig::new().init();
+
+        let recording =
+            ParamStatsRecording::start::<TestAutodiffBackend, _>(&model, Some(&grads), 1);
+        let weight = model.weight.val().inner();
+        let model = optim.step(0.1, model, grads);
+        let stats = recording.finish::<TestAutodiffBackend, _>(&model, true);
+
+        let names = stats
+            .params
+            .iter()
+            .map(|param| param.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["weight", "bias"]);
+        let expected = (model.weight.val().inner() - weight)
+            .powf_scalar(2.0)
+            .sum()
+            .sqrt()
+            .into_scalar()
+            .elem::<f64>();
+        let update_norm = stats.params[0].update_norm.unwrap();
+        assert!((update_norm - expected).abs() < 1e-5);
+        // The gradients of the weight of a linear layer scaled by 0.1.
+        assert!((update_norm - 0.1 * stats.params[0].grad_norm.unwrap()).abs() < 1e-5);
+        assert!(stats.update_ratio().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn should_not_record_updates_without_optimizer_step() {
+        let device = Default::default();
+        let model: Linear<TestAutodiffBackend> = LinearConfig::new(4, 2).init(&device);
+
+        let recording = ParamStatsRecording::start::<TestAutodiffBackend, _>(&model, None, 1);
+        let stats = recording.finish::<TestAutodiffBackend, _>(&model, false);
+
+        assert_eq!(stats.grad_norm(), None);
+        assert_eq!(stats.update_ratio(), None);
+        assert!(stats.weight_norm() > 0.0);
+    }
+}
@@ -0,0 +1,262 @@
+use crate::components::LearnerComponents;
+use crate::learner::base::TrainingInterrupter;
+use crate::metric::processor::{Event, EventProcessor, LearnerItem};
+use crate::{Learner, ValidStep};
+use burn_core::data::dataloader::{DataLoader, Progress};
+use burn_core::module::{AutodiffModule, Module};
+use burn_core::tensor::Device;
+use std::sync::mpsc::channel;
+use std::sync::Arc;
+
+impl<LC: LearnerComponents> Learner<LC> {
+    /// Runs the model on every item of the dataloader and returns the outputs.
+    ///
+    /// The [validation step](ValidStep) of the model is used, so no gradients are computed. When
+    /// multiple devices are configured, the batches are dispatched to the devices in a
+    /// round-robin fashion. The outputs are returned in the same order as the dataloader items.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloader` - The dataloader.
+    ///
+    /// # Returns
+    ///
+    /// The output of each item.
+    pub fn predict<Input, Output>(&self, dataloader: Arc<dyn DataLoader<Input>>) -> Vec<Output>
+    where
+        Input: Send,
+        Output: Send,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<Input, Output>,
+    {
+        log::info!("Predicting with {}", self.model.to_string());
+
+        let mut outputs = Vec::new();
+        run_inference::<LC, _, _, _>(
+            self.model.valid(),
+            &self.devices,
+            dataloader,
+            &self.interrupter,
+            |output, _progress, _iteration| outputs.push(output),
+        );
+
+        outputs
+    }
+
+    /// Evaluates the model on a test set and returns the outputs.
+    ///
+    /// Like [predict](Learner::predict), but the outputs are also processed by the validation
+    /// metrics, which are rendered and logged apart from the validation epochs, each call being
+    /// logged as a new test run, e.g. in the `test/epoch-1` directory for the first one.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloader` - The test dataloader.
+    ///
+    /// # Returns
+    ///
+    /// The output of each item.
+    pub fn test<Input, Output>(&mut self, dataloader: Arc<dyn DataLoader<Input>>) -> Vec<Output>
+    where
+        Input: Send,
+        Output: Send + Clone,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<Input, Output>,
+        LC::EventProcessor: EventProcessor<ItemValid = Output>,
+    {
+        log::info!("Testing {}", self.model.to_string());
+
+        let mut outputs = Vec::new();
+        let processor = &mut self.event_processor;
+        self.num_tests += 1;
+        let run = self.num_tests;
+
+        run_inference::<LC, _, _, _>(
+            self.model.valid(),
+            &self.devices,
+            dataloader,
+            &self.interrupter,
+            |output, progress, iteration| {
+                outputs.push(output.clone());
+                let item = LearnerItem::new(output, progress, 1, 1, iteration, None);
+                processor.process_test(Event::ProcessedItem(item));
+            },
+        );
+        processor.process_test(Event::EndEpoch(run));
+
+        outputs
+    }
+}
+
+/// Runs the validation step of the model on every item of the dataloader.
+///
+/// With a single device, the items are processed on the current thread. Otherwise, a worker
+/// thread is started for each device, and each worker receives one item per round.
+fn run_inference<LC, I, O, F>(
+    model: <LC::Model as AutodiffModule<LC::Backend>>::InnerModule,
+    devices: &[Device<LC::Backend>],
+    dataloader: Arc<dyn DataLoader<I>>,
+    interrupter: &TrainingInterrupter,
+    mut callback: F,
+) where
+    LC: LearnerComponents,
+    <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<I, O>,
+    I: Send,
+    O: Send,
+    F: FnMut(O, Progress, usize),
+{
+    let mut iterator = dataloader.iter();
+    let mut iteration = 0;
+
+    if devices.len() <= 1 {
+        let model = match devices.first() {
+            Some(device) => model.fork(device),
+            None => model,
+        };
+
+        while let Some(item) = iterator.next() {
+            iteration += 1;
+            let progress = iterator.progress();
+            callback(model.step(item), progress, iteration);
+
+            if interrupter.should_stop() {
+                log::info!("Inference interrupted.");
+                break;
+            }
+        }
+
+        return;
+    }
+
+    std::thread::scope(|scope| {
+        let workers = devices
+            .iter()
+            .map(|device| {
+                let (sender_input, receiver_input) = channel::<I>();
+                let (sender_output, receiver_output) = channel::<O>();
+                let model = model.clone().fork(device);
+
+                scope.spawn(move || {
+                    for item in receiver_input.iter() {
+                        if sender_output.send(model.step(item)).is_err() {
+                            break;
+                        }
+                    }
+                });
+
+                (sender_input, receiver_output)
+            })
+            .collect::<Vec<_>>();
+
+        loop {
+            let mut progresses = Vec::with_capacity(workers.len());
+
+            for (sender, _) in workers.iter() {
+                match iterator.next() {
+                    Some(item) => {
+                        sender
+                            .send(item)
+                            .expect("The inference worker should be alive.");
+                        progresses.push(iterator.progress());
+                    }
+                    None => break,
+                }
+            }
+
+            if progresses.is_empty() {
+                break;
+            }
+
+            // Receive in the same order as the items were sent, so the outputs stay ordered.
+            for ((_, receiver), progress) in workers.iter().zip(progresses) {
+                let output = receiver
+                    .recv()
+                    .expect("The inference worker should send an output.");
+                iteration += 1;
+                callback(output, progress, iteration);
+            }
+
+            if interrupter.should_stop() {
+                log::info!("Inference interrupted.");
+                break;
+            }
+        }
+        // Dropping the senders stops the workers before the end of the scope.
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::logger::{InMemoryMetricLogger, MetricLogger};
+    use crate::metric::processor::{Event, EventProcessor, LearnerItem};
+    use crate::metric::processor::{Metrics, MinimalEventProcessor};
+    use crate::metric::store::{Aggregate, EventStoreClient, LogEventStore, Split};
+    use crate::metric::{LossMetric, MetricEntry, NumericEntry};
+    use crate::TestBackend;
+    use burn_core::data::dataloader::Progress;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
+
+    /// Records the names of the logged entries and the ends of the epochs.
+    #[derive(Clone, Default)]
+    struct RecordingLogger {
+        records: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl MetricLogger for RecordingLogger {
+        fn log(&mut self, item: &MetricEntry) {
+            self.records.lock().unwrap().push(item.name.clone());
+        }
+
+        fn end_epoch(&mut self, epoch: usize) {
+            self.records.lock().unwrap().push(format!("end-{epoch}"));
+        }
+
+        fn read_numeric(
+            &mut self,
+            _name: &str,
+            _epoch: usize,
+        ) -> Result<Vec<NumericEntry>, String> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn item(value: f64) -> Event<f64> {
+        let progress = Progress {
+            items_processed: 1,
+            items_total: 1,
+        };
+
+        Event::ProcessedItem(LearnerItem::new(value, progress, 1, 1, 1, None))
+    }
+
+    #[test]
+    fn test_run_should_not_overwrite_the_validation_epochs() {
+        let mut store = LogEventStore::default();
+        let mut metrics = Metrics::<f64, f64>::default();
+        let logger_test = RecordingLogger::default();
+
+        store.register_logger_valid(InMemoryMetricLogger::default());
+        store.register_logger_test(logger_test.clone());
+        metrics.register_valid_metric_numeric(LossMetric::<TestBackend>::new());
+
+        let store = Rc::new(EventStoreClient::new(store));
+        let mut processor = MinimalEventProcessor::new(metrics, store.clone());
+
+        processor.process_valid(item(1.0));
+        processor.process_valid(Event::EndEpoch(1));
+        processor.process_test(item(5.0));
+        processor.process_test(Event::EndEpoch(1));
+
+        assert_eq!(
+            store.find_metric("Loss", 1, Aggregate::Mean, Split::Valid),
+            Some(1.0)
+        );
+        assert_eq!(
+            store.find_metric("Loss", 2, Aggregate::Mean, Split::Valid),
+            None
+        );
+        assert_eq!(
+            *logger_test.records.lock().unwrap(),
+            vec!["Loss".to_string(), "end-1".to_string()]
+        );
+    }
+}
@@ -150,6 +150,8 @@ impl<LC: LearnerComponents> Learner<LC> {
                 epoch,
                 self.num_epochs,
                 self.grad_accumulation,
+                self.non_finite_policy.clone(),
+                self.param_stats,
             );
 
             if self.devices.len() > 1 {
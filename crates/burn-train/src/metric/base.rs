@@ -16,6 +16,9 @@ pub struct MetricMetadata {
 
     /// The current learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The statistics of the parameters, if recorded at the current iteration.
+    pub param_stats: Option<std::sync::Arc<crate::ParamStats>>,
 }
 
 impl MetricMetadata {
@@ -30,6 +33,7 @@ impl MetricMetadata {
             epoch_total: 1,
             iteration: 0,
             lr: None,
+            param_stats: None,
         }
     }
 }
@@ -90,6 +94,11 @@ pub enum NumericEntry {
     Value(f64),
     /// Aggregated numeric (value, number of elements).
     Aggregated(f64, usize),
+    /// The value over every item of the epoch so far, the last one being the value of the epoch.
+    ///
+    /// Used by the metrics computed from statistics of the whole epoch, e.g. the corpus
+    /// [BLEU score](crate::metric::BleuMetric), which aren't the mean of their batch values.
+    Running(f64),
 }
 
 impl NumericEntry {
@@ -97,6 +106,7 @@ impl NumericEntry {
         match self {
             Self::Value(v) => v.to_string(),
             Self::Aggregated(v, n) => format!("{v},{n}"),
+            Self::Running(v) => format!("{v},running"),
         }
     }
 
@@ -112,9 +122,10 @@ impl NumericEntry {
                 Err(err) => Err(err.to_string()),
             }
         } else if num_values == 2 {
-            // Aggregated numeric (value, number of elements)
+            // Aggregated numeric (value, number of elements) or running value
             let (value, numel) = (values[0], values[1]);
             match value.parse::<f64>() {
+                Ok(value) if numel == "running" => Ok(NumericEntry::Running(value)),
                 Ok(value) => match numel.parse::<usize>() {
                     Ok(numel) => Ok(NumericEntry::Aggregated(value, numel)),
                     Err(err) => Err(err.to_string()),
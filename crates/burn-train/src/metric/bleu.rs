@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+
+use super::tokenization::Tokenization;
+use super::{format_float, MetricEntry, MetricMetadata, NumericEntry};
+use crate::metric::{Metric, Numeric};
+
+/// Smoothing applied to the n-gram precisions of the [BLEU score](BleuMetric).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BleuSmoothing {
+    /// No smoothing, any n-gram order without a match results in a score of zero.
+    #[default]
+    None,
+    /// Add one to the matches and the totals of every n-gram order above unigrams.
+    AddOne,
+    /// Replace zero matches by the given value.
+    Floor(f64),
+}
+
+/// Options used to compute the [BLEU score](BleuMetric).
+#[derive(Debug, Clone)]
+pub struct BleuOptions {
+    max_order: usize,
+    smoothing: BleuSmoothing,
+    tokenization: Tokenization,
+    lowercase: bool,
+}
+
+impl Default for BleuOptions {
+    fn default() -> Self {
+        Self {
+            max_order: 4,
+            smoothing: BleuSmoothing::None,
+            tokenization: Tokenization::default(),
+            lowercase: false,
+        }
+    }
+}
+
+impl BleuOptions {
+    /// Sets the maximum n-gram order.
+    pub fn with_max_order(mut self, max_order: usize) -> Self {
+        self.max_order = max_order;
+        self
+    }
+
+    /// Sets the smoothing method.
+    pub fn with_smoothing(mut self, smoothing: BleuSmoothing) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Sets the tokenization applied to candidates and references.
+    pub fn with_tokenization(mut self, tokenization: Tokenization) -> Self {
+        self.tokenization = tokenization;
+        self
+    }
+
+    /// Sets if the text is converted to lowercase before tokenization.
+    pub fn with_lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+}
+
+/// Sufficient statistics to compute the corpus BLEU score.
+///
+/// Corpus BLEU isn't the mean of the sentence scores: the n-gram matches and the lengths are
+/// summed over every sentence, and the score is computed once from the totals.
+#[derive(Debug, Clone)]
+pub struct BleuStats {
+    matches: Vec<usize>,
+    totals: Vec<usize>,
+    candidate_length: usize,
+    reference_length: usize,
+}
+
+impl BleuStats {
+    /// Creates empty statistics for n-grams up to `max_order`.
+    pub fn new(max_order: usize) -> Self {
+        Self {
+            matches: vec![0; max_order],
+            totals: vec![0; max_order],
+            candidate_length: 0,
+            reference_length: 0,
+        }
+    }
+
+    /// Adds a tokenized candidate and its references to the statistics.
+    pub fn update<S: AsRef<str>>(&mut self, candidate: &[S], references: &[Vec<S>]) {
+        let candidate_length = candidate.len();
+        let reference_length = references
+            .iter()
+            .map(|reference| reference.len())
+            .min_by_key(|&length| (length.abs_diff(candidate_length), length))
+            .unwrap_or(0);
+
+        self.candidate_length += candidate_length;
+        self.reference_length += reference_length;
+
+        for order in 1..=self.matches.len() {
+            let counts = ngram_counts(candidate, order);
+
+            // Each n-gram count is clipped by its maximum count in any of the references.
+            let mut max_reference_counts = HashMap::<Vec<&str>, usize>::new();
+            for reference in references {
+                for (ngram, count) in ngram_counts(reference, order) {
+                    let max_count = max_reference_counts.entry(ngram).or_insert(0);
+                    *max_count = usize::max(*max_count, count);
+                }
+            }
+
+            let matches = counts
+                .iter()
+                .map(|(ngram, &count)| {
+                    usize::min(count, *max_reference_counts.get(ngram).unwrap_or(&0))
+                })
+                .sum::<usize>();
+
+            self.matches[order - 1] += matches;
+            self.totals[order - 1] += candidate_length.saturating_sub(order - 1);
+        }
+    }
+
+    /// Merges other statistics into these.
+    pub fn merge(&mut self, other: &Self) {
+        for (matches, other) in self.matches.iter_mut().zip(other.matches.iter()) {
+            *matches += other;
+        }
+        for (totals, other) in self.totals.iter_mut().zip(other.totals.iter()) {
+            *totals += other;
+        }
+        self.candidate_length += other.candidate_length;
+        self.reference_length += other.reference_length;
+    }
+
+    /// Computes the BLEU score in percentage from the statistics.
+    pub fn score(&self, smoothing: BleuSmoothing) -> f64 {
+        if self.candidate_length == 0 || self.matches.is_empty() {
+            return 0.0;
+        }
+
+        let mut sum_log_precisions = 0.0;
+
+        for (order, (&matches, &total)) in self.matches.iter().zip(self.totals.iter()).enumerate() {
+            let precision = match smoothing {
+                BleuSmoothing::AddOne if order > 0 => (matches + 1) as f64 / (total + 1) as f64,
+                BleuSmoothing::Floor(floor) if matches == 0 && total > 0 => floor / total as f64,
+                _ if total == 0 => 0.0,
+                _ => matches as f64 / total as f64,
+            };
+
+            if precision <= 0.0 {
+                return 0.0;
+            }
+
+            sum_log_precisions += precision.ln();
+        }
+
+        let candidate_length = self.candidate_length as f64;
+        let reference_length = self.reference_length as f64;
+        let brevity_penalty = match candidate_length > reference_length {
+            true => 1.0,
+            false => f64::exp(1.0 - reference_length / candidate_length),
+        };
+
+        100.0 * brevity_penalty * f64::exp(sum_log_precisions / self.matches.len() as f64)
+    }
+}
+
+/// Computes the corpus BLEU score in percentage.
+///
+/// # Arguments
+///
+/// * `candidates` - The generated texts.
+/// * `references` - One or more reference texts for each candidate.
+/// * `options` - The tokenization, smoothing and maximum n-gram order.
+pub fn corpus_bleu<S: AsRef<str>>(
+    candidates: &[S],
+    references: &[Vec<S>],
+    options: &BleuOptions,
+) -> f64 {
+    bleu_stats(candidates, references, options).score(options.smoothing)
+}
+
+fn bleu_stats<S: AsRef<str>>(
+    candidates: &[S],
+    references: &[Vec<S>],
+    options: &BleuOptions,
+) -> BleuStats {
+    assert_eq!(
+        candidates.len(),
+        references.len(),
+        "Each candidate should have its references."
+    );
+
+    let tokenize = |text: &S| {
+        options
+            .tokenization
+            .tokenize(text.as_ref(), options.lowercase)
+    };
+
+    let mut stats = BleuStats::new(options.max_order);
+
+    for (candidate, references) in candidates.iter().zip(references.iter()) {
+        let candidate = tokenize(candidate);
+        let references = references.iter().map(tokenize).collect::<Vec<_>>();
+
+        stats.update(&candidate, &references);
+    }
+
+    stats
+}
+
+fn ngram_counts<S: AsRef<str>>(tokens: &[S], order: usize) -> HashMap<Vec<&str>, usize> {
+    let mut counts = HashMap::new();
+
+    if order == 0 || tokens.len() < order {
+        return counts;
+    }
+
+    for window in tokens.windows(order) {
+        let ngram = window
+            .iter()
+            .map(|token| token.as_ref())
+            .collect::<Vec<_>>();
+        *counts.entry(ngram).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+/// The corpus BLEU score metric for text generation.
+///
+/// The [value](Numeric::value) is the corpus BLEU of all the candidates seen since the last
+/// [clear](Metric::clear), which is also the value logged for the epoch, while the batch value
+/// only uses the candidates of the current batch.
+#[derive(Default)]
+pub struct BleuMetric {
+    options: BleuOptions,
+    stats: Option<BleuStats>,
+}
+
+/// The [BLEU metric](BleuMetric) input type.
+#[derive(new)]
+pub struct BleuInput {
+    candidates: Vec<String>,
+    references: Vec<Vec<String>>,
+}
+
+impl BleuMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the options used to compute the score.
+    pub fn with_options(mut self, options: BleuOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl Metric for BleuMetric {
+    const NAME: &'static str = "BLEU";
+
+    type Input = BleuInput;
+
+    fn update(&mut self, input: &BleuInput, _metadata: &MetricMetadata) -> MetricEntry {
+        let batch = bleu_stats(&input.candidates, &input.references, &self.options);
+        let batch_score = batch.score(self.options.smoothing);
+
+        let corpus = self
+            .stats
+            .get_or_insert_with(|| BleuStats::new(self.options.max_order));
+        corpus.merge(&batch);
+        let corpus_score = corpus.score(self.options.smoothing);
+
+        let formatted = format!(
+            "epoch {} - batch {}",
+            format_float(corpus_score, 2),
+            format_float(batch_score, 2)
+        );
+        let serialized = NumericEntry::Running(corpus_score).serialize();
+
+        MetricEntry::new(Self::NAME.to_string(), formatted, serialized)
+    }
+
+    fn clear(&mut self) {
+        self.stats = None;
+    }
+}
+
+impl Numeric for BleuMetric {
+    fn value(&self) -> f64 {
+        self.stats
+            .as_ref()
+            .map(|stats| stats.score(self.options.smoothing))
+            .unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bleu_identical_sentences() {
+        let score = corpus_bleu(
+            &["the cat is on the mat"],
+            &[vec!["the cat is on the mat"]],
+            &BleuOptions::default(),
+        );
+
+        assert!((score - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bleu_without_matches_should_be_zero() {
+        let options = BleuOptions::default().with_max_order(2);
+        let score = corpus_bleu(&["the the the the"], &[vec!["the cat"]], &options);
+
+        assert_eq!(score, 0.0);
+    }
+
+    #[test]
+    fn test_bleu_add_one_smoothing() {
+        let options = BleuOptions::default()
+            .with_max_order(2)
+            .with_smoothing(BleuSmoothing::AddOne);
+        let score = corpus_bleu(&["the the the the"], &[vec!["the cat"]], &options);
+
+        // Unigram precision is 1/4 and smoothed bigram precision is (0 + 1) / (3 + 1).
+        assert!((score - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bleu_brevity_penalty() {
+        let options = BleuOptions::default().with_max_order(2);
+        let score = corpus_bleu(&["the cat"], &[vec!["the cat sat on"]], &options);
+
+        assert!((score - 100.0 * f64::exp(-1.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bleu_uses_closest_reference_length() {
+        let options = BleuOptions::default().with_max_order(2);
+        let score = corpus_bleu(&["the cat"], &[vec!["the cat sat on", "the cat"]], &options);
+
+        assert!((score - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bleu_metric_epoch_is_corpus_score() {
+        let mut metric = BleuMetric::new().with_options(BleuOptions::default().with_max_order(1));
+
+        let _entry = metric.update(
+            &BleuInput::new(vec!["a b".to_string()], vec![vec!["a b".to_string()]]),
+            &MetricMetadata::fake(),
+        );
+        assert!((metric.value() - 100.0).abs() < 1e-9);
+
+        let entry = metric.update(
+            &BleuInput::new(vec!["c d".to_string()], vec![vec!["a b".to_string()]]),
+            &MetricMetadata::fake(),
+        );
+        assert!((metric.value() - 50.0).abs() < 1e-9);
+        assert_eq!(entry.formatted, "epoch 50.00 - batch 0.00e0");
+        assert!(matches!(
+            NumericEntry::deserialize(&entry.serialize),
+            Ok(NumericEntry::Running(value)) if (value - 50.0).abs() < 1e-9
+        ));
+    }
+}
@@ -0,0 +1,93 @@
+use super::{MetricEntry, MetricMetadata, Numeric};
+use crate::metric::Metric;
+use burn_core::tensor::backend::{Backend, MemoryUsage};
+use std::time::{Duration, Instant};
+
+/// Track the memory used by the tensors of a device.
+///
+/// The value is read from the backend memory management, so it doesn't include the memory
+/// allocated by other processes. The metric is unavailable on backends that don't report their
+/// [memory usage](Backend::memory_usage).
+pub struct DeviceMemoryMetric<B: Backend> {
+    device: B::Device,
+    last_refresh: Option<Instant>,
+    refresh_frequency: Duration,
+    usage: Option<MemoryUsage>,
+}
+
+impl<B: Backend> DeviceMemoryMetric<B> {
+    /// Creates a new device memory metric.
+    pub fn new(device: &B::Device) -> Self {
+        Self {
+            device: device.clone(),
+            last_refresh: None,
+            refresh_frequency: Duration::from_millis(200),
+            usage: None,
+        }
+    }
+
+    fn refresh(&mut self) {
+        self.usage = B::memory_usage(&self.device);
+        self.last_refresh = Some(Instant::now());
+    }
+}
+
+impl<B: Backend> Metric for DeviceMemoryMetric<B> {
+    const NAME: &'static str = "Device Memory";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), _metadata: &MetricMetadata) -> MetricEntry {
+        let should_refresh = match self.last_refresh {
+            Some(last_refresh) => last_refresh.elapsed() >= self.refresh_frequency,
+            None => true,
+        };
+        if should_refresh {
+            self.refresh();
+        }
+
+        let (formatted, raw) = match self.usage {
+            Some(usage) => {
+                let used = bytes2gb(usage.bytes_in_use);
+                let formatted = format!("{:.2} / {:.2} Gb", used, bytes2gb(usage.bytes_reserved));
+
+                (formatted, used)
+            }
+            None => ("Unavailable".to_string(), f64::NAN),
+        };
+
+        MetricEntry::new(Self::NAME.to_string(), formatted, raw.to_string())
+    }
+
+    fn clear(&mut self) {}
+}
+
+impl<B: Backend> Numeric for DeviceMemoryMetric<B> {
+    fn value(&self) -> f64 {
+        match self.usage {
+            Some(usage) => bytes2gb(usage.bytes_in_use),
+            None => f64::NAN,
+        }
+    }
+}
+
+fn bytes2gb(bytes: usize) -> f64 {
+    bytes as f64 / 1e9
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_device_memory_unavailable() {
+        let device = Default::default();
+        let mut metric = DeviceMemoryMetric::<TestBackend>::new(&device);
+
+        let entry = metric.update(&(), &MetricMetadata::fake());
+
+        assert_eq!(entry.formatted, "Unavailable");
+        assert!(metric.value().is_nan());
+    }
+}
@@ -0,0 +1,379 @@
+use core::marker::PhantomData;
+use std::collections::BTreeMap;
+
+use super::{format_float, MetricEntry, MetricMetadata, NumericEntry};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::{backend::Backend, vision::box_iou, Int, Tensor};
+
+/// The mean average precision (mAP) metric for object detection.
+///
+/// The metric is computed the same way as the COCO evaluation: the average precision of each
+/// class is obtained with a 101-point interpolated precision/recall curve, and then averaged over
+/// all classes and all IoU thresholds.
+///
+/// # Notes
+///
+/// The average precision is a dataset-level metric, so the reported value is the mAP over every
+/// image seen since the last [clear](Metric::clear). The predictions of each image are matched
+/// with its targets once, and only the matches of each class are kept, to be sorted by score when
+/// the metric is computed.
+pub struct MeanAveragePrecisionMetric<B: Backend> {
+    iou_thresholds: Vec<f64>,
+    max_detections: usize,
+    classes: BTreeMap<i64, ClassMatches>,
+    current: f64,
+    _b: PhantomData<B>,
+}
+
+/// The [mean average precision metric](MeanAveragePrecisionMetric) input type.
+///
+/// Each item of `predictions` and `targets` corresponds to the same image.
+#[derive(new)]
+pub struct MeanAveragePrecisionInput<B: Backend> {
+    predictions: Vec<DetectionPrediction<B>>,
+    targets: Vec<DetectionTarget<B>>,
+}
+
+/// The predicted boxes for one image.
+#[derive(new, Clone)]
+pub struct DetectionPrediction<B: Backend> {
+    /// The boxes of shape `[num_boxes, 4]` in `(x1, y1, x2, y2)` format.
+    pub boxes: Tensor<B, 2>,
+    /// The confidence score of each box of shape `[num_boxes]`.
+    pub scores: Tensor<B, 1>,
+    /// The class of each box of shape `[num_boxes]`.
+    pub labels: Tensor<B, 1, Int>,
+}
+
+/// The ground truth boxes for one image.
+#[derive(new, Clone)]
+pub struct DetectionTarget<B: Backend> {
+    /// The boxes of shape `[num_boxes, 4]` in `(x1, y1, x2, y2)` format.
+    pub boxes: Tensor<B, 2>,
+    /// The class of each box of shape `[num_boxes]`.
+    pub labels: Tensor<B, 1, Int>,
+}
+
+/// The matches of the predictions of one class, accumulated over the images.
+#[derive(Default)]
+struct ClassMatches {
+    num_targets: usize,
+    /// For each IoU threshold, the score of every prediction and whether it matched a target, in
+    /// the order of the images.
+    matches: Vec<Vec<(f64, bool)>>,
+    /// If the matches were sorted by decreasing score since the last image.
+    sorted: bool,
+}
+
+impl<B: Backend> MeanAveragePrecisionMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the IoU thresholds used to decide if a prediction matches a target.
+    ///
+    /// The default is the COCO range `0.50:0.05:0.95`.
+    pub fn with_iou_thresholds(mut self, thresholds: Vec<f64>) -> Self {
+        self.iou_thresholds = thresholds;
+        self
+    }
+
+    /// Sets the maximum number of detections per image, keeping the highest scores.
+    pub fn with_max_detections(mut self, max_detections: usize) -> Self {
+        self.max_detections = max_detections;
+        self
+    }
+
+    fn register(&mut self, prediction: &DetectionPrediction<B>, target: &DetectionTarget<B>) {
+        let scores = prediction.scores.clone().into_data().convert::<f64>().value;
+        let labels = prediction.labels.clone().into_data().convert::<i64>().value;
+        let target_labels = target.labels.clone().into_data().convert::<i64>().value;
+
+        let ious = if scores.is_empty() || target_labels.is_empty() {
+            Vec::new()
+        } else {
+            box_iou(prediction.boxes.clone(), target.boxes.clone())
+                .into_data()
+                .convert::<f64>()
+                .value
+        };
+
+        // Only keep the detections with the highest scores.
+        let mut order = (0..scores.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+        order.truncate(self.max_detections);
+
+        let num_targets = target_labels.len();
+        let num_thresholds = self.iou_thresholds.len();
+        for &label in target_labels.iter() {
+            self.class(label).num_targets += 1;
+        }
+
+        for threshold_index in 0..num_thresholds {
+            let iou_threshold = self.iou_thresholds[threshold_index];
+            let mut matched = vec![false; num_targets];
+
+            // Predictions are sorted by decreasing score.
+            for &index in order.iter() {
+                let label = labels[index];
+                let ious = &ious[index * num_targets..(index + 1) * num_targets];
+                let mut best: Option<(usize, f64)> = None;
+
+                for (target, &iou) in ious.iter().enumerate() {
+                    if matched[target] || target_labels[target] != label {
+                        continue;
+                    }
+                    let is_better = match best {
+                        Some((_, best_iou)) => iou > best_iou,
+                        None => true,
+                    };
+                    if iou >= iou_threshold && is_better {
+                        best = Some((target, iou));
+                    }
+                }
+
+                if let Some((target, _)) = best {
+                    matched[target] = true;
+                }
+
+                let class = self.class(label);
+                class.matches.resize_with(num_thresholds, Vec::new);
+                class.matches[threshold_index].push((scores[index], best.is_some()));
+                class.sorted = false;
+            }
+        }
+    }
+
+    fn class(&mut self, label: i64) -> &mut ClassMatches {
+        self.classes.entry(label).or_default()
+    }
+
+    fn compute(&mut self) -> f64 {
+        for class in self.classes.values_mut().filter(|class| !class.sorted) {
+            // The sort is stable, so ties keep the image order like the COCO evaluation, and
+            // only merges the matches of the new images with the ones already sorted.
+            for matches in class.matches.iter_mut() {
+                matches.sort_by(|a, b| b.0.total_cmp(&a.0));
+            }
+            class.sorted = true;
+        }
+
+        let classes = self
+            .classes
+            .values()
+            .filter(|class| class.num_targets > 0)
+            .collect::<Vec<_>>();
+
+        if classes.is_empty() || self.iou_thresholds.is_empty() {
+            return 0.0;
+        }
+
+        let mut sum = 0.0;
+        for class in classes.iter() {
+            for threshold_index in 0..self.iou_thresholds.len() {
+                let matches = class
+                    .matches
+                    .get(threshold_index)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                sum += average_precision(matches, class.num_targets);
+            }
+        }
+
+        sum / (classes.len() * self.iou_thresholds.len()) as f64
+    }
+}
+
+/// Computes the 101-point interpolated average precision of a single class from its matches,
+/// sorted by decreasing score.
+fn average_precision(matches: &[(f64, bool)], num_targets: usize) -> f64 {
+    let mut true_positives = 0;
+    let mut recalls = Vec::with_capacity(matches.len());
+    let mut precisions = Vec::with_capacity(matches.len());
+
+    for (count, (_, is_true_positive)) in matches.iter().enumerate() {
+        if *is_true_positive {
+            true_positives += 1;
+        }
+        recalls.push(true_positives as f64 / num_targets as f64);
+        precisions.push(true_positives as f64 / (count + 1) as f64);
+    }
+
+    // Make the precision monotonically decreasing.
+    for i in (1..precisions.len()).rev() {
+        precisions[i - 1] = f64::max(precisions[i - 1], precisions[i]);
+    }
+
+    let num_points = 101;
+    let mut sum = 0.0;
+    for point in 0..num_points {
+        let recall = point as f64 / (num_points - 1) as f64;
+        let index = recalls.partition_point(|&value| value < recall);
+
+        if let Some(precision) = precisions.get(index) {
+            sum += precision;
+        }
+    }
+
+    sum / num_points as f64
+}
+
+impl<B: Backend> Default for MeanAveragePrecisionMetric<B> {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            iou_thresholds: (0..10).map(|i| 0.5 + 0.05 * i as f64).collect(),
+            max_detections: 100,
+            classes: BTreeMap::new(),
+            current: f64::NAN,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for MeanAveragePrecisionMetric<B> {
+    const NAME: &'static str = "Mean Average Precision";
+
+    type Input = MeanAveragePrecisionInput<B>;
+
+    fn update(
+        &mut self,
+        input: &MeanAveragePrecisionInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        assert_eq!(
+            input.predictions.len(),
+            input.targets.len(),
+            "Each image should have both predictions and targets."
+        );
+
+        for (prediction, target) in input.predictions.iter().zip(input.targets.iter()) {
+            self.register(prediction, target);
+        }
+
+        self.current = 100.0 * self.compute();
+
+        let formatted = format!("epoch {} %", format_float(self.current, 2));
+        let serialized = NumericEntry::Running(self.current).serialize();
+
+        MetricEntry::new(Self::NAME.to_string(), formatted, serialized)
+    }
+
+    fn clear(&mut self) {
+        self.classes.clear();
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for MeanAveragePrecisionMetric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    fn prediction(
+        boxes: [[f32; 4]; 2],
+        scores: [f32; 2],
+        labels: [i32; 2],
+    ) -> DetectionPrediction<TestBackend> {
+        let device = Default::default();
+
+        DetectionPrediction::new(
+            Tensor::from_floats(boxes, &device),
+            Tensor::from_floats(scores, &device),
+            Tensor::from_ints(labels, &device),
+        )
+    }
+
+    fn target(boxes: [[f32; 4]; 2], labels: [i32; 2]) -> DetectionTarget<TestBackend> {
+        let device = Default::default();
+
+        DetectionTarget::new(
+            Tensor::from_floats(boxes, &device),
+            Tensor::from_ints(labels, &device),
+        )
+    }
+
+    #[test]
+    fn test_map_perfect_predictions() {
+        let mut metric = MeanAveragePrecisionMetric::<TestBackend>::new();
+        let boxes = [[0.0, 0.0, 10.0, 10.0], [20.0, 20.0, 30.0, 30.0]];
+        let input = MeanAveragePrecisionInput::new(
+            vec![prediction(boxes, [0.9, 0.8], [0, 1])],
+            vec![target(boxes, [0, 1])],
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert_eq!(100.0, metric.value());
+    }
+
+    #[test]
+    fn test_map_with_false_positive() {
+        let mut metric = MeanAveragePrecisionMetric::<TestBackend>::new();
+        let input = MeanAveragePrecisionInput::new(
+            vec![prediction(
+                [[0.0, 0.0, 10.0, 10.0], [50.0, 50.0, 60.0, 60.0]],
+                [0.9, 0.8],
+                [0, 0],
+            )],
+            vec![target(
+                [[0.0, 0.0, 10.0, 10.0], [20.0, 20.0, 30.0, 30.0]],
+                [0, 0],
+            )],
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        // Precision is 1 up to a recall of 0.5, which covers 51 of the 101 recall points.
+        let expected = 100.0 * 51.0 / 101.0;
+        assert!((metric.value() - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_map_accumulates_images_until_cleared() {
+        let mut metric = MeanAveragePrecisionMetric::<TestBackend>::new();
+        let boxes = [[0.0, 0.0, 10.0, 10.0], [20.0, 20.0, 30.0, 30.0]];
+        let wrong_boxes = [[40.0, 40.0, 50.0, 50.0], [60.0, 60.0, 70.0, 70.0]];
+
+        let _entry = metric.update(
+            &MeanAveragePrecisionInput::new(
+                vec![prediction(wrong_boxes, [0.9, 0.8], [0, 0])],
+                vec![target(boxes, [0, 0])],
+            ),
+            &MetricMetadata::fake(),
+        );
+        assert_eq!(0.0, metric.value());
+
+        let entry = metric.update(
+            &MeanAveragePrecisionInput::new(
+                vec![prediction(boxes, [0.7, 0.6], [0, 0])],
+                vec![target(boxes, [0, 0])],
+            ),
+            &MetricMetadata::fake(),
+        );
+        // The false positives of the first image have higher scores.
+        assert!(metric.value() > 0.0 && metric.value() < 100.0);
+        // The value of all images is logged instead of the value of the batch.
+        assert!(matches!(
+            NumericEntry::deserialize(&entry.serialize),
+            Ok(NumericEntry::Running(value)) if value == metric.value()
+        ));
+
+        metric.clear();
+        let _entry = metric.update(
+            &MeanAveragePrecisionInput::new(
+                vec![prediction(boxes, [0.7, 0.6], [0, 0])],
+                vec![target(boxes, [0, 0])],
+            ),
+            &MetricMetadata::fake(),
+        );
+        assert_eq!(100.0, metric.value());
+    }
+}
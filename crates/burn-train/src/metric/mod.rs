@@ -3,31 +3,49 @@ pub mod state;
 
 mod acc;
 mod base;
+mod bleu;
 #[cfg(feature = "metrics")]
 mod cpu_temp;
 #[cfg(feature = "metrics")]
 mod cpu_use;
 #[cfg(feature = "metrics")]
 mod cuda;
+mod device_memory;
 mod hamming;
 mod learning_rate;
 mod loss;
+mod map;
 #[cfg(feature = "metrics")]
 mod memory_use;
+mod param_stats;
+mod perplexity;
+mod rouge;
+mod throughput;
+mod time_remaining;
+mod tokenization;
 
 pub use acc::*;
 pub use base::*;
+pub use bleu::*;
 #[cfg(feature = "metrics")]
 pub use cpu_temp::*;
 #[cfg(feature = "metrics")]
 pub use cpu_use::*;
 #[cfg(feature = "metrics")]
 pub use cuda::*;
+pub use device_memory::*;
 pub use hamming::*;
 pub use learning_rate::*;
 pub use loss::*;
+pub use map::*;
 #[cfg(feature = "metrics")]
 pub use memory_use::*;
+pub use param_stats::*;
+pub use perplexity::*;
+pub use rouge::*;
+pub use throughput::*;
+pub use time_remaining::*;
+pub use tokenization::*;
 
 pub(crate) mod processor;
 /// Module responsible to save and exposes data collected during training.
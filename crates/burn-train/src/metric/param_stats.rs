@@ -0,0 +1,267 @@
+use super::{
+    state::{FormatOptions, NumericMetricState},
+    MetricMetadata, Numeric, NumericEntry,
+};
+use crate::metric::{Metric, MetricEntry};
+use crate::ParamStats;
+
+/// The state of the metrics of the [parameter statistics](ParamStats), which are only recorded
+/// every few iterations: the last entry is repeated until the next statistics are recorded.
+struct ParamStatsState {
+    state: NumericMetricState,
+    last: Option<MetricEntry>,
+}
+
+impl ParamStatsState {
+    fn new() -> Self {
+        Self {
+            state: NumericMetricState::new(),
+            last: None,
+        }
+    }
+
+    fn update<F>(&mut self, metadata: &MetricMetadata, name: &str, value: F) -> MetricEntry
+    where
+        F: Fn(&ParamStats) -> Option<f64>,
+    {
+        let value = metadata.param_stats.as_deref().and_then(value);
+
+        match (value, &self.last) {
+            (Some(value), _) => {
+                let entry = self
+                    .state
+                    .update(value, 1, FormatOptions::new(name).precision(4));
+                self.last = Some(entry.clone());
+                entry
+            }
+            (None, Some(last)) => last.clone(),
+            (None, None) => MetricEntry::new(
+                name.to_string(),
+                format!("{name}: not recorded yet"),
+                NumericEntry::Value(f64::NAN).serialize(),
+            ),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.state.reset();
+        self.last = None;
+    }
+}
+
+/// Track the L2 norm of the gradients of all the parameters of the model, from the
+/// [parameter statistics](ParamStats).
+pub struct GradientNormMetric {
+    state: ParamStatsState,
+}
+
+/// Track the L2 norm of all the parameters of the model, from the
+/// [parameter statistics](ParamStats).
+pub struct WeightNormMetric {
+    state: ParamStatsState,
+}
+
+/// Track the norm of the updates of the parameters of the model made by the optimizer, relative
+/// to the norm of the parameters, from the [parameter statistics](ParamStats).
+pub struct UpdateRatioMetric {
+    state: ParamStatsState,
+}
+
+macro_rules! param_stats_metric {
+    ($metric:ident, $name:expr, $value:expr) => {
+        impl $metric {
+            /// Creates the metric.
+            pub fn new() -> Self {
+                Self {
+                    state: ParamStatsState::new(),
+                }
+            }
+        }
+
+        impl Default for $metric {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl Metric for $metric {
+            const NAME: &'static str = $name;
+
+            type Input = ();
+
+            fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+                self.state.update(metadata, Self::NAME, $value)
+            }
+
+            fn clear(&mut self) {
+                self.state.reset()
+            }
+        }
+
+        impl Numeric for $metric {
+            fn value(&self) -> f64 {
+                self.state.state.value()
+            }
+        }
+    };
+}
+
+param_stats_metric!(GradientNormMetric, "Gradient Norm", ParamStats::grad_norm);
+param_stats_metric!(WeightNormMetric, "Weight Norm", |stats: &ParamStats| Some(
+    stats.weight_norm()
+));
+param_stats_metric!(UpdateRatioMetric, "Update Ratio", ParamStats::update_ratio);
+
+/// Log the [statistics](ParamStats) of each parameter of the model, to find the layers where a
+/// training diverges.
+///
+/// The gradient norm, weight norm and update ratio of every parameter are serialized in the logs
+/// as `name=grad_norm,weight_norm,update_ratio` separated by `;`, with empty values when they
+/// aren't available, and the parameter with the largest gradient norm is displayed.
+#[derive(Default)]
+pub struct ParamStatsMetric {
+    last: Option<MetricEntry>,
+}
+
+impl ParamStatsMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Metric for ParamStatsMetric {
+    const NAME: &'static str = "Parameter Stats";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let stats = match (&metadata.param_stats, &self.last) {
+            (Some(stats), _) => stats,
+            (None, Some(last)) => return last.clone(),
+            (None, None) => {
+                return MetricEntry::new(
+                    Self::NAME.to_string(),
+                    format!("{}: not recorded yet", Self::NAME),
+                    String::new(),
+                )
+            }
+        };
+
+        let optional =
+            |value: Option<f64>| value.map(|value| value.to_string()).unwrap_or_default();
+        let serialized = stats
+            .params
+            .iter()
+            .map(|param| {
+                format!(
+                    "{}={},{},{}",
+                    param.name,
+                    optional(param.grad_norm),
+                    param.weight_norm,
+                    optional(param.update_ratio())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(";");
+
+        let largest = stats
+            .params
+            .iter()
+            .filter_map(|param| param.grad_norm.map(|norm| (param, norm)))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+        let formatted = match largest {
+            Some((param, norm)) => format!(
+                "{}: iteration {} - largest gradient norm {norm:.4} ({})",
+                Self::NAME,
+                stats.iteration,
+                param.name
+            ),
+            None => format!(
+                "{}: iteration {} - no gradients",
+                Self::NAME,
+                stats.iteration
+            ),
+        };
+
+        let entry = MetricEntry::new(Self::NAME.to_string(), formatted, serialized);
+        self.last = Some(entry.clone());
+        entry
+    }
+
+    fn clear(&mut self) {
+        self.last = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParamStat;
+    use std::sync::Arc;
+
+    fn metadata(stats: Option<ParamStats>) -> MetricMetadata {
+        let mut metadata = MetricMetadata::fake();
+        metadata.param_stats = stats.map(Arc::new);
+        metadata
+    }
+
+    fn stats() -> ParamStats {
+        ParamStats {
+            iteration: 10,
+            params: vec![
+                ParamStat {
+                    name: "linear.weight".to_string(),
+                    grad_norm: Some(3.0),
+                    weight_norm: 8.0,
+                    update_norm: Some(0.5),
+                },
+                ParamStat {
+                    name: "linear.bias".to_string(),
+                    grad_norm: Some(4.0),
+                    weight_norm: 6.0,
+                    update_norm: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn should_compute_the_global_norms() {
+        let mut grad_norm = GradientNormMetric::new();
+        let mut update_ratio = UpdateRatioMetric::new();
+
+        grad_norm.update(&(), &metadata(Some(stats())));
+        update_ratio.update(&(), &metadata(Some(stats())));
+
+        assert_eq!(grad_norm.value(), 5.0);
+        assert_eq!(update_ratio.value(), 0.05);
+    }
+
+    #[test]
+    fn should_repeat_the_last_entry_between_recordings() {
+        let mut metric = WeightNormMetric::new();
+
+        let entry = metric.update(&(), &metadata(Some(stats())));
+        let repeated = metric.update(&(), &metadata(None));
+
+        assert_eq!(metric.value(), 10.0);
+        assert_eq!(repeated.serialize, entry.serialize);
+    }
+
+    #[test]
+    fn should_serialize_the_stats_of_each_param() {
+        let mut metric = ParamStatsMetric::new();
+
+        let entry = metric.update(&(), &metadata(Some(stats())));
+
+        assert_eq!(
+            entry.serialize,
+            "linear.weight=3,8,0.0625;linear.bias=4,6,".to_string()
+        );
+        assert_eq!(
+            entry.formatted,
+            "Parameter Stats: iteration 10 - largest gradient norm 4.0000 (linear.bias)"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use super::{format_float, MetricEntry, MetricMetadata, NumericEntry};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Tensor};
+
+/// The perplexity metric for language models.
+///
+/// The perplexity is the exponential of the mean negative log-likelihood per token. The epoch
+/// value, which is also the value logged for the epoch, is computed from the total loss over all
+/// the tokens seen since the last [clear](Metric::clear), not as the mean of the batch
+/// perplexities.
+#[derive(Default)]
+pub struct PerplexityMetric<B: Backend> {
+    sum_loss: f64,
+    num_tokens: usize,
+    current: f64,
+    _b: B,
+}
+
+/// The [perplexity metric](PerplexityMetric) input type.
+#[derive(new)]
+pub struct PerplexityInput<B: Backend> {
+    /// The negative log-likelihood of each token, with padding tokens already removed.
+    losses: Tensor<B, 1>,
+}
+
+impl<B: Backend> PerplexityMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B: Backend> Metric for PerplexityMetric<B> {
+    const NAME: &'static str = "Perplexity";
+
+    type Input = PerplexityInput<B>;
+
+    fn update(&mut self, input: &PerplexityInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [num_tokens] = input.losses.dims();
+        let sum_loss = input.losses.clone().sum().into_scalar().elem::<f64>();
+
+        self.sum_loss += sum_loss;
+        self.num_tokens += num_tokens;
+
+        self.current = f64::exp(sum_loss / num_tokens as f64);
+        let running = f64::exp(self.sum_loss / self.num_tokens as f64);
+
+        let formatted = format!(
+            "epoch {} - batch {}",
+            format_float(running, 2),
+            format_float(self.current, 2)
+        );
+        let serialized = NumericEntry::Running(running).serialize();
+
+        MetricEntry::new(Self::NAME.to_string(), formatted, serialized)
+    }
+
+    fn clear(&mut self) {
+        self.sum_loss = 0.0;
+        self.num_tokens = 0;
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for PerplexityMetric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_perplexity() {
+        let device = Default::default();
+        let mut metric = PerplexityMetric::<TestBackend>::new();
+        let ln_2 = core::f32::consts::LN_2;
+
+        let _entry = metric.update(
+            &PerplexityInput::new(Tensor::from_floats([ln_2, ln_2], &device)),
+            &MetricMetadata::fake(),
+        );
+        assert!((metric.value() - 2.0).abs() < 1e-5);
+
+        let entry = metric.update(
+            &PerplexityInput::new(Tensor::from_floats([3.0 * ln_2], &device)),
+            &MetricMetadata::fake(),
+        );
+        assert!((metric.value() - 8.0).abs() < 1e-5);
+        // The epoch value is 2^(5/3) and not the mean of the batch perplexities.
+        assert_eq!(entry.formatted, "epoch 3.17 - batch 8.00");
+        assert!(matches!(
+            NumericEntry::deserialize(&entry.serialize),
+            Ok(NumericEntry::Running(value)) if (value - 2f64.powf(5.0 / 3.0)).abs() < 1e-5
+        ));
+    }
+}
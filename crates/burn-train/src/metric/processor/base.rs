@@ -1,5 +1,7 @@
+use crate::ParamStats;
 use burn_core::data::dataloader::Progress;
 use burn_core::LearningRate;
+use std::sync::Arc;
 
 /// Event happening during the training/validation process.
 pub enum Event<T> {
@@ -20,6 +22,13 @@ pub trait EventProcessor {
     fn process_train(&mut self, event: Event<Self::ItemTrain>);
     /// Collect a validation event.
     fn process_valid(&mut self, event: Event<Self::ItemValid>);
+    /// Collect a test event, processed with the validation metrics but kept apart from the
+    /// validation epochs, each [end of epoch](Event::EndEpoch) ending a test run.
+    ///
+    /// The test events are ignored by default.
+    fn process_test(&mut self, event: Event<Self::ItemValid>) {
+        let _ = event;
+    }
 }
 
 /// A learner item.
@@ -42,4 +51,8 @@ pub struct LearnerItem<T> {
 
     /// The learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The statistics of the parameters, if recorded at this iteration.
+    #[new(default)]
+    pub param_stats: Option<Arc<ParamStats>>,
 }
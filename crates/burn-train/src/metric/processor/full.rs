@@ -60,6 +60,7 @@ impl<T, V> EventProcessor for FullEventProcessor<T, V> {
                 self.metrics.end_epoch_train();
                 self.store
                     .add_event_train(crate::metric::store::Event::EndEpoch(epoch));
+                self.renderer.end_epoch_train(epoch);
             }
         }
     }
@@ -94,6 +95,42 @@ impl<T, V> EventProcessor for FullEventProcessor<T, V> {
                 self.metrics.end_epoch_valid();
                 self.store
                     .add_event_valid(crate::metric::store::Event::EndEpoch(epoch));
+                self.renderer.end_epoch_valid(epoch);
+            }
+        }
+    }
+
+    fn process_test(&mut self, event: Event<Self::ItemValid>) {
+        match event {
+            Event::ProcessedItem(item) => {
+                let progress = (&item).into();
+                let metadata = (&item).into();
+
+                let update = self.metrics.update_valid(&item, &metadata);
+
+                self.store
+                    .add_event_test(crate::metric::store::Event::MetricsUpdate(update.clone()));
+
+                update
+                    .entries
+                    .into_iter()
+                    .for_each(|entry| self.renderer.update_valid(MetricState::Generic(entry)));
+
+                update
+                    .entries_numeric
+                    .into_iter()
+                    .for_each(|(entry, value)| {
+                        self.renderer
+                            .update_valid(MetricState::Numeric(entry, value))
+                    });
+
+                self.renderer.render_valid(progress);
+            }
+            Event::EndEpoch(run) => {
+                self.metrics.end_epoch_valid();
+                self.store
+                    .add_event_test(crate::metric::store::Event::EndEpoch(run));
+                self.renderer.end_epoch_valid(run);
             }
         }
     }
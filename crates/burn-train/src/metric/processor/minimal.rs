@@ -49,4 +49,22 @@ impl<T, V> EventProcessor for MinimalEventProcessor<T, V> {
             }
         }
     }
+
+    fn process_test(&mut self, event: Event<Self::ItemValid>) {
+        match event {
+            Event::ProcessedItem(item) => {
+                let metadata = (&item).into();
+
+                let update = self.metrics.update_valid(&item, &metadata);
+
+                self.store
+                    .add_event_test(crate::metric::store::Event::MetricsUpdate(update));
+            }
+            Event::EndEpoch(run) => {
+                self.metrics.end_epoch_valid();
+                self.store
+                    .add_event_test(crate::metric::store::Event::EndEpoch(run));
+            }
+        }
+    }
 }
@@ -0,0 +1,236 @@
+use super::tokenization::Tokenization;
+use super::{format_float, MetricEntry, MetricMetadata, NumericEntry};
+use crate::metric::{Metric, Numeric};
+
+/// Computes the ROUGE-L F-measure between a tokenized candidate and reference.
+///
+/// The score is based on the longest common subsequence (LCS) of the two sequences, where the
+/// precision is `lcs / candidate_length` and the recall is `lcs / reference_length`.
+///
+/// # Arguments
+///
+/// * `candidate` - The generated tokens.
+/// * `reference` - The reference tokens.
+/// * `beta` - The weight of the recall relative to the precision, `1.0` gives the F1 score.
+///
+/// # Returns
+///
+/// The F-measure between `0.0` and `1.0`.
+pub fn rouge_l<S: AsRef<str>>(candidate: &[S], reference: &[S], beta: f64) -> f64 {
+    if candidate.is_empty() || reference.is_empty() {
+        return 0.0;
+    }
+
+    let lcs = longest_common_subsequence(candidate, reference) as f64;
+
+    if lcs == 0.0 {
+        return 0.0;
+    }
+
+    let precision = lcs / candidate.len() as f64;
+    let recall = lcs / reference.len() as f64;
+    let beta_squared = beta * beta;
+
+    (1.0 + beta_squared) * precision * recall / (recall + beta_squared * precision)
+}
+
+fn longest_common_subsequence<S: AsRef<str>>(lhs: &[S], rhs: &[S]) -> usize {
+    // Only the previous row of the dynamic programming table is needed.
+    let mut previous = vec![0; rhs.len() + 1];
+    let mut current = vec![0; rhs.len() + 1];
+
+    for token in lhs {
+        for (j, other) in rhs.iter().enumerate() {
+            current[j + 1] = match token.as_ref() == other.as_ref() {
+                true => previous[j] + 1,
+                false => usize::max(previous[j + 1], current[j]),
+            };
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[rhs.len()]
+}
+
+/// The ROUGE-L metric for text generation.
+///
+/// Each candidate is scored against every one of its references, keeping the best F-measure.
+/// The epoch value, which is also the value logged for the epoch, is the mean score of all the
+/// candidates seen since the last [clear](Metric::clear), while the batch value only uses the
+/// candidates of the current batch.
+pub struct RougeLMetric {
+    sum: f64,
+    count: usize,
+    current: f64,
+    tokenization: Tokenization,
+    lowercase: bool,
+    beta: f64,
+}
+
+/// The [ROUGE-L metric](RougeLMetric) input type.
+#[derive(new)]
+pub struct RougeLInput {
+    candidates: Vec<String>,
+    references: Vec<Vec<String>>,
+}
+
+impl RougeLMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the tokenization applied to candidates and references.
+    pub fn with_tokenization(mut self, tokenization: Tokenization) -> Self {
+        self.tokenization = tokenization;
+        self
+    }
+
+    /// Sets if the text is converted to lowercase before tokenization.
+    pub fn with_lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Sets the weight of the recall relative to the precision.
+    pub fn with_beta(mut self, beta: f64) -> Self {
+        self.beta = beta;
+        self
+    }
+}
+
+impl Default for RougeLMetric {
+    /// Creates a new metric instance with default values.
+    fn default() -> Self {
+        Self {
+            sum: 0.0,
+            count: 0,
+            current: f64::NAN,
+            tokenization: Tokenization::default(),
+            lowercase: true,
+            beta: 1.0,
+        }
+    }
+}
+
+impl Metric for RougeLMetric {
+    const NAME: &'static str = "ROUGE-L";
+
+    type Input = RougeLInput;
+
+    fn update(&mut self, input: &RougeLInput, _metadata: &MetricMetadata) -> MetricEntry {
+        assert_eq!(
+            input.candidates.len(),
+            input.references.len(),
+            "Each candidate should have its references."
+        );
+
+        let batch_size = input.candidates.len();
+        let tokenize = |text: &String| self.tokenization.tokenize(text, self.lowercase);
+
+        let sum = input
+            .candidates
+            .iter()
+            .zip(input.references.iter())
+            .map(|(candidate, references)| {
+                let candidate = tokenize(candidate);
+
+                references
+                    .iter()
+                    .map(|reference| rouge_l(&candidate, &tokenize(reference), self.beta))
+                    .fold(0.0, f64::max)
+            })
+            .sum::<f64>();
+
+        self.sum += sum;
+        self.count += batch_size;
+
+        self.current = match batch_size {
+            0 => 0.0,
+            _ => 100.0 * sum / batch_size as f64,
+        };
+        let running = match self.count {
+            0 => 0.0,
+            _ => 100.0 * self.sum / self.count as f64,
+        };
+
+        let formatted = format!(
+            "epoch {} % - batch {} %",
+            format_float(running, 2),
+            format_float(self.current, 2)
+        );
+        let serialized = NumericEntry::Running(running).serialize();
+
+        MetricEntry::new(Self::NAME.to_string(), formatted, serialized)
+    }
+
+    fn clear(&mut self) {
+        self.sum = 0.0;
+        self.count = 0;
+        self.current = f64::NAN;
+    }
+}
+
+impl Numeric for RougeLMetric {
+    fn value(&self) -> f64 {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rouge_l() {
+        let candidate = ["the", "cat", "sat"];
+        let reference = ["the", "cat", "was", "sat"];
+
+        // LCS of 3, precision of 3/3 and recall of 3/4.
+        let score = rouge_l(&candidate, &reference, 1.0);
+
+        assert!((score - 6.0 / 7.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rouge_l_metric_keeps_best_reference() {
+        let mut metric = RougeLMetric::new();
+        let input = RougeLInput::new(
+            vec!["The cat sat.".to_string(), "a dog".to_string()],
+            vec![
+                vec!["a bird".to_string(), "the cat sat.".to_string()],
+                vec!["a cat".to_string()],
+            ],
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        // The first candidate perfectly matches its second reference, the second gets 0.5.
+        assert!((metric.value() - 75.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rouge_l_metric_epoch_is_the_mean_of_all_candidates() {
+        let mut metric = RougeLMetric::new();
+
+        let _entry = metric.update(
+            &RougeLInput::new(
+                vec!["a b".to_string(), "c d".to_string()],
+                vec![vec!["a b".to_string()], vec!["a b".to_string()]],
+            ),
+            &MetricMetadata::fake(),
+        );
+        let entry = metric.update(
+            &RougeLInput::new(vec!["a b".to_string()], vec![vec!["a b".to_string()]]),
+            &MetricMetadata::fake(),
+        );
+
+        // The mean of the three candidates, not of the two batches.
+        assert_eq!(metric.value(), 100.0);
+        assert_eq!(entry.formatted, "epoch 66.67 % - batch 100.00 %");
+        assert!(matches!(
+            NumericEntry::deserialize(&entry.serialize),
+            Ok(NumericEntry::Running(value)) if (value - 200.0 / 3.0).abs() < 1e-9
+        ));
+    }
+}
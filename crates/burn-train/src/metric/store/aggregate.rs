@@ -48,6 +48,13 @@ impl NumericMetricsAggregate {
             return None;
         }
 
+        // The last running value already covers the whole epoch.
+        if let Some(NumericEntry::Running(value)) = points.last() {
+            let value = *value;
+            self.value_for_each_epoch.insert(key, value);
+            return Some(value);
+        }
+
         // Accurately compute the aggregated value based on the *actual* number of points
         // since not all mini-batches are guaranteed to have the specified batch size
         let (sum, num_points) = points
@@ -57,6 +64,7 @@ impl NumericMetricsAggregate {
                 // Right now the mean is the only aggregate available, so we can assume that the sum
                 // of an entry corresponds to (value * number of elements)
                 NumericEntry::Aggregated(v, n) => (v * n as f64, n),
+                NumericEntry::Running(v) => (v, 1),
             })
             .reduce(|(acc_v, acc_n), (v, n)| (acc_v + v, acc_n + n))
             .unwrap();
@@ -202,4 +210,25 @@ mod tests {
         // Average should be (0.5 + 1.25 * 2) / 3 = 1.0, not (0.5 + 1.25) / 2 = 0.875
         assert_eq!(value, 1.0);
     }
+
+    #[test]
+    fn should_use_the_last_running_entry() {
+        let mut logger = InMemoryMetricLogger::default();
+        let mut aggregate = NumericMetricsAggregate::default();
+        let metric_name = "BLEU";
+
+        for value in [100.0, 50.0, 40.0] {
+            logger.log(&MetricEntry::new(
+                metric_name.to_string(),
+                value.to_string(),
+                NumericEntry::Running(value).serialize(),
+            ));
+        }
+
+        let value = aggregate
+            .aggregate(metric_name, 1, Aggregate::Mean, &mut [Box::new(logger)])
+            .unwrap();
+
+        assert_eq!(value, 40.0);
+    }
 }
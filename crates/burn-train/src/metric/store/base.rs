@@ -24,6 +24,13 @@ pub trait EventStore: Send {
     /// Collect a training/validation event.
     fn add_event(&mut self, event: Event, split: Split);
 
+    /// Collect a test event, each [end of epoch](Event::EndEpoch) ending a test run.
+    ///
+    /// The test events are ignored by default.
+    fn add_event_test(&mut self, event: Event) {
+        let _ = event;
+    }
+
     /// Find the epoch following the given criteria from the collected data.
     fn find_epoch(
         &mut self,
@@ -9,8 +9,9 @@ pub struct EventStoreClient {
 }
 
 impl EventStoreClient {
-    /// Create a new [event store](EventStore) client.
-    pub(crate) fn new<C>(store: C) -> Self
+    /// Create a new [event store](EventStore) client, e.g. to test an
+    /// [early stopping strategy](crate::EarlyStoppingStrategy) with a custom store.
+    pub fn new<C>(store: C) -> Self
     where
         C: EventStore + 'static,
     {
@@ -39,6 +40,13 @@ impl EventStoreClient {
             .expect("Can send event to event store thread.");
     }
 
+    /// Add a test event to the [event store](EventStore).
+    pub(crate) fn add_event_test(&self, event: Event) {
+        self.sender
+            .send(Message::OnEventTest(event))
+            .expect("Can send event to event store thread.");
+    }
+
     /// Find the epoch following the given criteria from the collected data.
     pub fn find_epoch(
         &self,
@@ -120,6 +128,7 @@ where
                 }
                 Message::OnEventTrain(event) => self.store.add_event(event, Split::Train),
                 Message::OnEventValid(event) => self.store.add_event(event, Split::Valid),
+                Message::OnEventTest(event) => self.store.add_event_test(event),
             }
         }
     }
@@ -128,6 +137,7 @@ where
 enum Message {
     OnEventTrain(Event),
     OnEventValid(Event),
+    OnEventTest(Event),
     End,
     FindEpoch(
         String,
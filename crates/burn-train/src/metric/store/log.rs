@@ -5,6 +5,7 @@ use crate::logger::MetricLogger;
 pub(crate) struct LogEventStore {
     loggers_train: Vec<Box<dyn MetricLogger>>,
     loggers_valid: Vec<Box<dyn MetricLogger>>,
+    loggers_test: Vec<Box<dyn MetricLogger>>,
     aggregate_train: NumericMetricsAggregate,
     aggregate_valid: NumericMetricsAggregate,
 }
@@ -49,6 +50,24 @@ impl EventStore for LogEventStore {
         }
     }
 
+    fn add_event_test(&mut self, event: Event) {
+        match event {
+            Event::MetricsUpdate(update) => update
+                .entries
+                .iter()
+                .chain(update.entries_numeric.iter().map(|(entry, _value)| entry))
+                .for_each(|entry| {
+                    self.loggers_test
+                        .iter_mut()
+                        .for_each(|logger| logger.log(entry));
+                }),
+            Event::EndEpoch(run) => self
+                .loggers_test
+                .iter_mut()
+                .for_each(|logger| logger.end_epoch(run)),
+        }
+    }
+
     fn find_epoch(
         &mut self,
         name: &str,
@@ -98,4 +117,9 @@ impl LogEventStore {
     pub(crate) fn register_logger_valid<ML: MetricLogger + 'static>(&mut self, logger: ML) {
         self.loggers_valid.push(Box::new(logger));
     }
+
+    /// Register a logger for test metrics.
+    pub(crate) fn register_logger_test<ML: MetricLogger + 'static>(&mut self, logger: ML) {
+        self.loggers_test.push(Box::new(logger));
+    }
 }
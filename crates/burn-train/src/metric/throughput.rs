@@ -0,0 +1,175 @@
+use super::{format_float, MetricEntry, MetricMetadata, Numeric, NumericEntry};
+use crate::metric::Metric;
+use std::time::Instant;
+
+/// Track the number of items processed per second.
+///
+/// The number of items is obtained from the dataloader progress, so it corresponds to the number
+/// of samples when each dataset item is a sample.
+#[derive(Default)]
+pub struct ThroughputMetric {
+    state: ThroughputState,
+    items_processed: usize,
+}
+
+/// Track the number of tokens processed per second.
+#[derive(Default)]
+pub struct TokenThroughputMetric {
+    state: ThroughputState,
+}
+
+/// The [token throughput metric](TokenThroughputMetric) input type.
+#[derive(new)]
+pub struct TokenThroughputInput {
+    /// The number of tokens in the batch, padding excluded.
+    num_tokens: usize,
+}
+
+impl ThroughputMetric {
+    /// Creates a new throughput metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TokenThroughputMetric {
+    /// Creates a new token throughput metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Metric for ThroughputMetric {
+    const NAME: &'static str = "Throughput";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let items_processed = metadata.progress.items_processed;
+        let num_items = items_processed.saturating_sub(self.items_processed);
+        self.items_processed = items_processed;
+
+        self.state.update(num_items, Self::NAME, "items/s")
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.items_processed = 0;
+    }
+}
+
+impl Numeric for ThroughputMetric {
+    fn value(&self) -> f64 {
+        self.state.current
+    }
+}
+
+impl Metric for TokenThroughputMetric {
+    const NAME: &'static str = "Token Throughput";
+
+    type Input = TokenThroughputInput;
+
+    fn update(&mut self, item: &TokenThroughputInput, _metadata: &MetricMetadata) -> MetricEntry {
+        self.state.update(item.num_tokens, Self::NAME, "tokens/s")
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+    }
+}
+
+impl Numeric for TokenThroughputMetric {
+    fn value(&self) -> f64 {
+        self.state.current
+    }
+}
+
+/// Measures the time between two updates to compute a rate.
+///
+/// The first update of an epoch only starts the timer, since the time taken by the first batch
+/// can't be known.
+#[derive(Default)]
+struct ThroughputState {
+    last_update: Option<Instant>,
+    num_items: usize,
+    secs: f64,
+    current: f64,
+}
+
+impl ThroughputState {
+    fn update(&mut self, num_items: usize, name: &str, unit: &str) -> MetricEntry {
+        let now = Instant::now();
+        let elapsed = self
+            .last_update
+            .replace(now)
+            .map(|last_update| now.duration_since(last_update).as_secs_f64());
+
+        let (formatted, serialized) = match elapsed {
+            Some(secs) if secs > 0.0 => {
+                self.current = num_items as f64 / secs;
+                self.num_items += num_items;
+                self.secs += secs;
+
+                let running = self.num_items as f64 / self.secs;
+                let formatted = format!(
+                    "epoch {} {unit} - batch {} {unit}",
+                    format_float(running, 2),
+                    format_float(self.current, 2),
+                );
+
+                (formatted, NumericEntry::Aggregated(self.current, num_items))
+            }
+            // Nothing is measured yet, the entry doesn't count in the epoch aggregate.
+            _ => (format!("--- {unit}"), NumericEntry::Aggregated(0.0, 0)),
+        };
+
+        MetricEntry::new(name.to_string(), formatted, serialized.serialize())
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::data::dataloader::Progress;
+
+    fn metadata(items_processed: usize) -> MetricMetadata {
+        MetricMetadata {
+            progress: Progress::new(items_processed, 100),
+            ..MetricMetadata::fake()
+        }
+    }
+
+    #[test]
+    fn test_throughput_needs_two_updates() {
+        let mut metric = ThroughputMetric::new();
+
+        let entry = metric.update(&(), &metadata(10));
+        assert_eq!(entry.formatted, "--- items/s");
+        assert_eq!(metric.value(), 0.0);
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        let _entry = metric.update(&(), &metadata(20));
+        // 10 items in at least 10 ms.
+        assert!(metric.value() > 0.0 && metric.value() <= 1000.0);
+
+        metric.clear();
+        let entry = metric.update(&(), &metadata(10));
+        assert_eq!(entry.formatted, "--- items/s");
+    }
+
+    #[test]
+    fn test_token_throughput() {
+        let mut metric = TokenThroughputMetric::new();
+
+        let _entry = metric.update(&TokenThroughputInput::new(64), &MetricMetadata::fake());
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        let entry = metric.update(&TokenThroughputInput::new(64), &MetricMetadata::fake());
+
+        assert!(metric.value() > 0.0 && metric.value() <= 6400.0);
+        assert!(entry.formatted.ends_with("tokens/s"));
+    }
+}
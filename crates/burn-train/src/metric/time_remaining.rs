@@ -0,0 +1,132 @@
+use super::{MetricEntry, MetricMetadata, Numeric, NumericEntry};
+use crate::metric::Metric;
+use std::time::Instant;
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * 60 * 60;
+
+/// Estimate the time remaining before the end of the training.
+///
+/// The estimate is based on the mean time per item since the first update, so the time spent
+/// outside of the split where the metric is registered (e.g. validation) is included.
+#[derive(Default)]
+pub struct TimeRemainingMetric {
+    start: Option<(Instant, f64)>,
+    remaining_secs: Option<f64>,
+}
+
+impl TimeRemainingMetric {
+    /// Creates a new time remaining metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Metric for TimeRemainingMetric {
+    const NAME: &'static str = "Time Remaining";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let progress = training_progress(metadata);
+        let (start, start_progress) = *self.start.get_or_insert_with(|| (Instant::now(), progress));
+
+        let elapsed = start.elapsed().as_secs_f64();
+        self.remaining_secs = match progress > start_progress {
+            true => Some(elapsed * (1.0 - progress) / (progress - start_progress)),
+            false => None,
+        };
+
+        let (formatted, serialized) = match self.remaining_secs {
+            Some(secs) => (format_eta(secs as u64), NumericEntry::Value(secs)),
+            None => ("---".to_string(), NumericEntry::Value(f64::NAN)),
+        };
+
+        MetricEntry::new(Self::NAME.to_string(), formatted, serialized.serialize())
+    }
+
+    // The estimate spans the whole training, so it is kept between epochs.
+    fn clear(&mut self) {}
+}
+
+impl Numeric for TimeRemainingMetric {
+    fn value(&self) -> f64 {
+        self.remaining_secs.unwrap_or(f64::NAN)
+    }
+}
+
+/// Fraction of the training that is done, between 0 and 1.
+fn training_progress(metadata: &MetricMetadata) -> f64 {
+    let items_total = metadata.progress.items_total * metadata.epoch_total;
+
+    if items_total == 0 {
+        return 0.0;
+    }
+
+    let items_processed = metadata.epoch.saturating_sub(1) * metadata.progress.items_total
+        + metadata.progress.items_processed;
+
+    f64::min(items_processed as f64 / items_total as f64, 1.0)
+}
+
+/// Format a duration in seconds with its largest unit, e.g. `2 hours`.
+pub(crate) fn format_eta(eta_secs: u64) -> String {
+    let seconds = eta_secs % 60;
+    let minutes = eta_secs / MINUTE % 60;
+    let hours = eta_secs / HOUR % 24;
+    let days = eta_secs / DAY;
+
+    if days > 1 {
+        format!("{days} days")
+    } else if days == 1 {
+        "1 day".to_string()
+    } else if hours > 1 {
+        format!("{hours} hours")
+    } else if hours == 1 {
+        "1 hour".to_string()
+    } else if minutes > 1 {
+        format!("{minutes} mins")
+    } else if minutes == 1 {
+        "1 min".to_string()
+    } else if seconds > 1 {
+        format!("{seconds} secs")
+    } else {
+        "1 sec".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::data::dataloader::Progress;
+
+    fn metadata(epoch: usize, items_processed: usize) -> MetricMetadata {
+        MetricMetadata {
+            progress: Progress::new(items_processed, 10),
+            epoch,
+            epoch_total: 4,
+            ..MetricMetadata::fake()
+        }
+    }
+
+    #[test]
+    fn test_training_progress() {
+        assert_eq!(0.25, training_progress(&metadata(1, 10)));
+        assert_eq!(0.625, training_progress(&metadata(3, 5)));
+        assert_eq!(1.0, training_progress(&metadata(4, 10)));
+    }
+
+    #[test]
+    fn test_time_remaining_needs_progress() {
+        let mut metric = TimeRemainingMetric::new();
+
+        let entry = metric.update(&(), &metadata(1, 1));
+        assert_eq!(entry.formatted, "---");
+        assert!(metric.value().is_nan());
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        let _entry = metric.update(&(), &metadata(1, 2));
+        assert!(metric.value() > 0.0);
+    }
+}
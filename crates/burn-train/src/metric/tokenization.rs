@@ -0,0 +1,75 @@
+/// Tokenization applied to the text of sequence-generation metrics such as [BLEU](super::BleuMetric)
+/// and [ROUGE-L](super::RougeLMetric).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tokenization {
+    /// Split the text on whitespace.
+    Whitespace,
+    /// Split the text on whitespace and separate every punctuation character into its own token,
+    /// similar to the `13a` tokenizer used by `sacrebleu`.
+    #[default]
+    Punctuation,
+    /// Each non-whitespace character is a token, useful for languages without word delimiters.
+    Character,
+}
+
+impl Tokenization {
+    /// Split the text into tokens, optionally converting it to lowercase first.
+    pub fn tokenize(&self, text: &str, lowercase: bool) -> Vec<String> {
+        let text = match lowercase {
+            true => text.to_lowercase(),
+            false => text.to_string(),
+        };
+
+        match self {
+            Tokenization::Whitespace => text.split_whitespace().map(str::to_string).collect(),
+            Tokenization::Punctuation => {
+                let mut tokens = Vec::new();
+
+                for word in text.split_whitespace() {
+                    let mut current = String::new();
+
+                    for c in word.chars() {
+                        if c.is_ascii_punctuation() {
+                            if !current.is_empty() {
+                                tokens.push(core::mem::take(&mut current));
+                            }
+                            tokens.push(c.to_string());
+                        } else {
+                            current.push(c);
+                        }
+                    }
+
+                    if !current.is_empty() {
+                        tokens.push(current);
+                    }
+                }
+
+                tokens
+            }
+            Tokenization::Character => text
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .map(|c| c.to_string())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punctuation_tokenization() {
+        let tokens = Tokenization::Punctuation.tokenize("Hello, World! It's", true);
+
+        assert_eq!(tokens, vec!["hello", ",", "world", "!", "it", "'", "s"]);
+    }
+
+    #[test]
+    fn test_character_tokenization() {
+        let tokens = Tokenization::Character.tokenize("ab c", false);
+
+        assert_eq!(tokens, vec!["a", "b", "c"]);
+    }
+}
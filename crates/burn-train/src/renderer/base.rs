@@ -31,6 +31,20 @@ pub trait MetricsRenderer: Send + Sync {
     ///
     /// * `item` - The validation progress.
     fn render_valid(&mut self, item: TrainingProgress);
+
+    /// Called at the end of a training epoch, after its last [render](Self::render_train).
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The epoch that ended.
+    fn end_epoch_train(&mut self, _epoch: usize) {}
+
+    /// Called at the end of a validation epoch, after its last [render](Self::render_valid).
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The epoch that ended.
+    fn end_epoch_valid(&mut self, _epoch: usize) {}
 }
 
 /// The state of a metric.
@@ -0,0 +1,359 @@
+use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+use serde::Serialize;
+use std::{collections::BTreeMap, io::Write, path::PathBuf, time::Instant};
+
+/// Where the [JSON lines renderer](JsonlMetricsRenderer) writes its events.
+#[derive(Debug, Clone)]
+pub enum JsonlTarget {
+    /// Write the events to the standard output.
+    Stdout,
+    /// Write the events to a file, which is truncated when the renderer is created.
+    File(PathBuf),
+}
+
+/// A headless renderer that writes one JSON object per line for every training and validation
+/// iteration, and at the start and the end of every epoch.
+///
+/// Each iteration event contains the split, the progress, the elapsed time and the latest value
+/// of every metric, so the output can be scraped by schedulers and dashboards where a terminal UI
+/// isn't available. The epoch end event contains the duration of the epoch and the last value of
+/// every metric.
+///
+/// ```json
+/// {"event":"epoch_start","split":"train","epoch":1,"epoch_total":10,"elapsed_secs":0.0}
+/// {"event":"iteration","split":"train","epoch":1,"epoch_total":10,"iteration":42,"items_processed":1344,"items_total":60000,"elapsed_secs":12.5,"iteration_secs":0.29,"metrics":{"Loss":{"formatted":"epoch 0.512 - batch 0.498","value":0.498}}}
+/// {"event":"epoch_end","split":"train","epoch":1,"elapsed_secs":545.1,"epoch_secs":545.1,"metrics":{"Loss":{"formatted":"epoch 0.431 - batch 0.402","value":0.402}}}
+/// ```
+pub struct JsonlMetricsRenderer {
+    writer: Box<dyn Write + Send + Sync>,
+    start: Instant,
+    train: SplitState,
+    valid: SplitState,
+}
+
+#[derive(Default)]
+struct SplitState {
+    metrics: BTreeMap<String, MetricEvent>,
+    last_render: Option<Instant>,
+    epoch: Option<(usize, Instant)>,
+}
+
+#[derive(Serialize)]
+struct MetricEvent {
+    formatted: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct IterationEvent<'a> {
+    event: &'static str,
+    split: &'a str,
+    epoch: usize,
+    epoch_total: usize,
+    iteration: usize,
+    items_processed: usize,
+    items_total: usize,
+    elapsed_secs: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iteration_secs: Option<f64>,
+    metrics: &'a BTreeMap<String, MetricEvent>,
+}
+
+#[derive(Serialize)]
+struct EpochStartEvent<'a> {
+    event: &'static str,
+    split: &'a str,
+    epoch: usize,
+    epoch_total: usize,
+    elapsed_secs: f64,
+}
+
+#[derive(Serialize)]
+struct EpochEndEvent<'a> {
+    event: &'static str,
+    split: &'a str,
+    epoch: usize,
+    elapsed_secs: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    epoch_secs: Option<f64>,
+    metrics: &'a BTreeMap<String, MetricEvent>,
+}
+
+impl JsonlMetricsRenderer {
+    /// Create a new JSON lines renderer.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Where the events are written.
+    pub fn new(target: JsonlTarget) -> Self {
+        let writer: Box<dyn Write + Send + Sync> = match target {
+            JsonlTarget::Stdout => Box::new(std::io::stdout()),
+            JsonlTarget::File(path) => {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent).ok();
+                }
+                let file = std::fs::File::create(&path).unwrap_or_else(|err| {
+                    panic!(
+                        "Should be able to create the new file '{}': {err}",
+                        path.display()
+                    )
+                });
+                Box::new(file)
+            }
+        };
+
+        Self::from_writer(writer)
+    }
+
+    fn from_writer(writer: Box<dyn Write + Send + Sync>) -> Self {
+        Self {
+            writer,
+            start: Instant::now(),
+            train: SplitState::default(),
+            valid: SplitState::default(),
+        }
+    }
+
+    fn render(&mut self, split: &str, item: TrainingProgress) {
+        let now = Instant::now();
+        let elapsed_secs = now.duration_since(self.start).as_secs_f64();
+        let state = match split {
+            "train" => &mut self.train,
+            _ => &mut self.valid,
+        };
+
+        if state.epoch.map(|(epoch, _)| epoch) != Some(item.epoch) {
+            state.epoch = Some((item.epoch, now));
+            write_event(
+                &mut self.writer,
+                &EpochStartEvent {
+                    event: "epoch_start",
+                    split,
+                    epoch: item.epoch,
+                    epoch_total: item.epoch_total,
+                    elapsed_secs,
+                },
+            );
+        }
+
+        let iteration_secs = state
+            .last_render
+            .replace(now)
+            .map(|last_render| now.duration_since(last_render).as_secs_f64());
+
+        let event = IterationEvent {
+            event: "iteration",
+            split,
+            epoch: item.epoch,
+            epoch_total: item.epoch_total,
+            iteration: item.iteration,
+            items_processed: item.progress.items_processed,
+            items_total: item.progress.items_total,
+            elapsed_secs,
+            iteration_secs,
+            metrics: &state.metrics,
+        };
+
+        write_event(&mut self.writer, &event);
+    }
+
+    fn end_epoch(&mut self, split: &str, epoch: usize) {
+        let now = Instant::now();
+        let state = match split {
+            "train" => &mut self.train,
+            _ => &mut self.valid,
+        };
+
+        let event = EpochEndEvent {
+            event: "epoch_end",
+            split,
+            epoch,
+            elapsed_secs: now.duration_since(self.start).as_secs_f64(),
+            epoch_secs: state
+                .epoch
+                .map(|(_, start)| now.duration_since(start).as_secs_f64()),
+            metrics: &state.metrics,
+        };
+        write_event(&mut self.writer, &event);
+
+        // The next epoch starts without the metrics and the timings of this one.
+        *state = SplitState::default();
+    }
+}
+
+fn write_event<E: Serialize>(writer: &mut Box<dyn Write + Send + Sync>, event: &E) {
+    // A logging failure shouldn't stop the training.
+    let result = serde_json::to_writer(&mut *writer, event)
+        .map_err(std::io::Error::from)
+        .and_then(|_| writeln!(writer))
+        .and_then(|_| writer.flush());
+
+    if let Err(err) = result {
+        log::warn!("Unable to write the training event: {err}");
+    }
+}
+
+impl SplitState {
+    fn update(&mut self, state: MetricState) {
+        let (entry, value) = match state {
+            MetricState::Generic(entry) => (entry, None),
+            MetricState::Numeric(entry, value) => (entry, Some(value)),
+        };
+
+        self.metrics.insert(
+            entry.name,
+            MetricEvent {
+                formatted: entry.formatted,
+                // NaN and infinite values can't be represented in JSON.
+                value: value.filter(|value| value.is_finite()),
+            },
+        );
+    }
+}
+
+impl MetricsRenderer for JsonlMetricsRenderer {
+    fn update_train(&mut self, state: MetricState) {
+        self.train.update(state);
+    }
+
+    fn update_valid(&mut self, state: MetricState) {
+        self.valid.update(state);
+    }
+
+    fn render_train(&mut self, item: TrainingProgress) {
+        self.render("train", item);
+    }
+
+    fn render_valid(&mut self, item: TrainingProgress) {
+        self.render("valid", item);
+    }
+
+    fn end_epoch_train(&mut self, epoch: usize) {
+        self.end_epoch("train", epoch);
+    }
+
+    fn end_epoch_valid(&mut self, epoch: usize) {
+        self.end_epoch("valid", epoch);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::MetricEntry;
+    use burn_core::data::dataloader::Progress;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn progress(iteration: usize) -> TrainingProgress {
+        TrainingProgress {
+            progress: Progress::new(iteration * 8, 64),
+            epoch: 1,
+            epoch_total: 2,
+            iteration,
+        }
+    }
+
+    #[test]
+    fn test_writes_one_event_per_iteration() {
+        let buffer = SharedBuffer::default();
+        let mut renderer = JsonlMetricsRenderer::from_writer(Box::new(buffer.clone()));
+
+        renderer.update_train(MetricState::Numeric(
+            MetricEntry::new("Loss".into(), "0.5".into(), "0.5".into()),
+            0.5,
+        ));
+        renderer.render_train(progress(1));
+        renderer.update_valid(MetricState::Generic(MetricEntry::new(
+            "Status".into(),
+            "ok".into(),
+            "ok".into(),
+        )));
+        renderer.render_valid(progress(2));
+
+        let lines = events(&buffer);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0]["event"], "epoch_start");
+        assert_eq!(lines[2]["event"], "epoch_start");
+
+        let train = &lines[1];
+        assert_eq!(train["event"], "iteration");
+        assert_eq!(train["split"], "train");
+        assert_eq!(train["iteration"], 1);
+        assert_eq!(train["items_processed"], 8);
+        assert_eq!(train["metrics"]["Loss"]["value"], 0.5);
+        assert!(train.get("iteration_secs").is_none());
+
+        let valid = &lines[3];
+        assert_eq!(valid["split"], "valid");
+        assert_eq!(valid["metrics"]["Status"]["formatted"], "ok");
+        assert!(valid["metrics"]["Status"].get("value").is_none());
+        assert!(valid["metrics"].get("Loss").is_none());
+    }
+
+    #[test]
+    fn test_writes_epoch_events_and_resets_the_split() {
+        let buffer = SharedBuffer::default();
+        let mut renderer = JsonlMetricsRenderer::from_writer(Box::new(buffer.clone()));
+
+        renderer.update_train(MetricState::Numeric(
+            MetricEntry::new("Loss".into(), "0.5".into(), "0.5".into()),
+            0.5,
+        ));
+        renderer.render_train(progress(1));
+        renderer.render_train(progress(2));
+        renderer.end_epoch_train(1);
+        renderer.render_train(TrainingProgress {
+            epoch: 2,
+            ..progress(1)
+        });
+
+        let lines = events(&buffer);
+        let kinds = lines
+            .iter()
+            .map(|line| line["event"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                "epoch_start",
+                "iteration",
+                "iteration",
+                "epoch_end",
+                "epoch_start",
+                "iteration"
+            ]
+        );
+
+        assert_eq!(lines[3]["epoch"], 1);
+        assert_eq!(lines[3]["metrics"]["Loss"]["value"], 0.5);
+        assert!(lines[3].get("epoch_secs").is_some());
+        assert!(lines[2].get("iteration_secs").is_some());
+
+        assert_eq!(lines[4]["epoch"], 2);
+        assert!(lines[5].get("iteration_secs").is_none());
+        assert!(lines[5]["metrics"].get("Loss").is_none());
+    }
+
+    fn events(buffer: &SharedBuffer) -> Vec<serde_json::Value> {
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+
+        output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+}
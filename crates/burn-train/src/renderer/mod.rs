@@ -1,5 +1,7 @@
 mod base;
+mod jsonl;
 pub use base::*;
+pub use jsonl::*;
 
 #[cfg(not(feature = "tui"))]
 mod cli;
@@ -1,5 +1,5 @@
 use super::TerminalFrame;
-use crate::renderer::TrainingProgress;
+use crate::{metric::format_eta, renderer::TrainingProgress};
 use ratatui::{
     prelude::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style, Stylize},
@@ -17,10 +17,6 @@ pub(crate) struct ProgressBarState {
     estimate: ProgressEstimate,
 }
 
-const MINUTE: u64 = 60;
-const HOUR: u64 = 60 * 60;
-const DAY: u64 = 24 * 60 * 60;
-
 impl ProgressBarState {
     pub fn new(checkpoint: Option<usize>) -> Self {
         Self {
@@ -181,31 +177,6 @@ fn calculate_progress(
     num_items as f64 / total_items as f64
 }
 
-fn format_eta(eta_secs: u64) -> String {
-    let seconds = eta_secs % 60;
-    let minutes = eta_secs / MINUTE % 60;
-    let hours = eta_secs / HOUR % 24;
-    let days = eta_secs / DAY;
-
-    if days > 1 {
-        format!("{days} days")
-    } else if days == 1 {
-        "1 day".to_string()
-    } else if hours > 1 {
-        format!("{hours} hours")
-    } else if hours == 1 {
-        "1 hour".to_string()
-    } else if minutes > 1 {
-        format!("{minutes} mins")
-    } else if minutes == 1 {
-        "1 min".to_string()
-    } else if seconds > 1 {
-        format!("{seconds} secs")
-    } else {
-        "1 sec".to_string()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
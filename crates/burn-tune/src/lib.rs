@@ -0,0 +1,17 @@
+#![warn(missing_docs)]
+
+//! Hyperparameter search for models trained with the burn-train [learner](burn_train::Learner).
+//!
+//! A [study](Study) samples the hyperparameters of each trial from a [search space](SearchSpace),
+//! trains a model with them, and keeps track of the best trial. Unpromising trials can be stopped
+//! early with a [pruner](Pruner) such as [ASHA](AshaPruner).
+
+mod pruner;
+mod sampler;
+mod space;
+mod study;
+
+pub use pruner::*;
+pub use sampler::*;
+pub use space::*;
+pub use study::*;
@@ -0,0 +1,132 @@
+use burn_train::metric::store::Direction;
+use std::collections::BTreeMap;
+
+/// Decide if a trial should be stopped early based on its intermediate results.
+pub trait Pruner {
+    /// Report the value of the objective of a trial at the given step (i.e., epoch), and returns
+    /// if the trial should be stopped.
+    fn should_prune(&mut self, trial: usize, step: usize, value: f64) -> bool;
+}
+
+/// A pruner inspired by the asynchronous successive halving algorithm (ASHA).
+///
+/// Trials are compared at rungs, which are the steps `min_resource * reduction_factor^k`. When a
+/// trial reaches a rung, it continues only if its value is in the top `1 / reduction_factor` of
+/// all the values reported at that rung so far, otherwise it is pruned. The comparison is done
+/// against the previous trials only, so no trial has to wait for the others.
+pub struct AshaPruner {
+    min_resource: usize,
+    reduction_factor: usize,
+    direction: Direction,
+    rungs: BTreeMap<usize, Vec<f64>>,
+}
+
+impl AshaPruner {
+    /// Creates a new ASHA pruner.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - If the objective should be minimized or maximized.
+    /// * `min_resource` - The step of the first rung.
+    /// * `reduction_factor` - The fraction of trials promoted at each rung is `1 / reduction_factor`.
+    pub fn new(direction: Direction, min_resource: usize, reduction_factor: usize) -> Self {
+        assert!(min_resource > 0, "The minimum resource should be positive.");
+        assert!(
+            reduction_factor > 1,
+            "The reduction factor should be greater than 1."
+        );
+
+        Self {
+            min_resource,
+            reduction_factor,
+            direction,
+            rungs: BTreeMap::new(),
+        }
+    }
+
+    fn is_rung(&self, step: usize) -> bool {
+        let mut rung = self.min_resource;
+        while rung < step {
+            rung *= self.reduction_factor;
+        }
+        rung == step
+    }
+}
+
+impl Pruner for AshaPruner {
+    fn should_prune(&mut self, trial: usize, step: usize, value: f64) -> bool {
+        if !self.is_rung(step) {
+            return false;
+        }
+
+        if value.is_nan() {
+            log::info!("Pruning trial {trial} at step {step}, the objective is NaN.");
+            return true;
+        }
+
+        let values = self.rungs.entry(step).or_default();
+        values.push(value);
+
+        let mut ranked = values.clone();
+        match self.direction {
+            Direction::Lowest => ranked.sort_by(f64::total_cmp),
+            Direction::Highest => ranked.sort_by(|a, b| b.total_cmp(a)),
+        }
+
+        let num_promoted = usize::max(ranked.len() / self.reduction_factor, 1);
+        let threshold = ranked[num_promoted - 1];
+        let should_prune = match self.direction {
+            Direction::Lowest => value > threshold,
+            Direction::Highest => value < threshold,
+        };
+
+        if should_prune {
+            log::info!(
+                "Pruning trial {trial} at step {step}, {value} isn't in the top {num_promoted} of \
+                 {} trials.",
+                ranked.len()
+            );
+        }
+
+        should_prune
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_prune_at_rungs() {
+        let pruner = AshaPruner::new(Direction::Lowest, 2, 3);
+
+        assert!(pruner.is_rung(2));
+        assert!(pruner.is_rung(6));
+        assert!(pruner.is_rung(18));
+        assert!(!pruner.is_rung(1));
+        assert!(!pruner.is_rung(4));
+    }
+
+    #[test]
+    fn test_prune_trials_outside_of_the_top_fraction() {
+        let mut pruner = AshaPruner::new(Direction::Lowest, 1, 2);
+
+        assert!(!pruner.should_prune(0, 1, 0.5));
+        // Not at a rung.
+        assert!(!pruner.should_prune(1, 3, 10.0));
+        // Only the best of two trials is promoted.
+        assert!(pruner.should_prune(1, 1, 0.8));
+        assert!(!pruner.should_prune(2, 1, 0.3));
+        // Top two of four.
+        assert!(!pruner.should_prune(3, 1, 0.4));
+    }
+
+    #[test]
+    fn test_prune_maximized_objective() {
+        let mut pruner = AshaPruner::new(Direction::Highest, 1, 2);
+
+        assert!(!pruner.should_prune(0, 1, 80.0));
+        assert!(pruner.should_prune(1, 1, 70.0));
+        assert!(!pruner.should_prune(2, 1, 90.0));
+    }
+}
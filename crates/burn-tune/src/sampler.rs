@@ -0,0 +1,154 @@
+use crate::{Distribution, ParamValue, Params, SearchSpace};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Choose the hyperparameters of the next trial.
+pub trait Sampler {
+    /// Sample the hyperparameters of a trial from the search space.
+    ///
+    /// Returns `None` when the sampler has no more configurations to try.
+    fn sample(&mut self, space: &SearchSpace, trial: usize) -> Option<Params>;
+}
+
+/// Sample every hyperparameter independently from its distribution.
+pub struct RandomSampler {
+    rng: StdRng,
+}
+
+impl RandomSampler {
+    /// Creates a new random sampler with a fixed seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Sampler for RandomSampler {
+    fn sample(&mut self, space: &SearchSpace, _trial: usize) -> Option<Params> {
+        let mut params = Params::default();
+
+        for (name, distribution) in space.params.iter() {
+            let value = match distribution {
+                Distribution::Uniform { low, high } => {
+                    ParamValue::Float(*low + (*high - *low) * self.rng.gen::<f64>())
+                }
+                Distribution::LogUniform { low, high } => {
+                    let (low, high) = (low.ln(), high.ln());
+                    ParamValue::Float((low + (high - low) * self.rng.gen::<f64>()).exp())
+                }
+                Distribution::Int { low, high } => {
+                    ParamValue::Int(self.rng.gen_range(*low..=*high))
+                }
+                Distribution::Choice(values) => values[self.rng.gen_range(0..values.len())].clone(),
+            };
+            params.insert(name.clone(), value);
+        }
+
+        Some(params)
+    }
+}
+
+/// Try every combination of hyperparameters, in order.
+///
+/// Continuous distributions are discretized into evenly spaced points, in the log domain for
+/// [log uniform](Distribution::LogUniform) distributions.
+pub struct GridSampler {
+    num_points: usize,
+}
+
+impl GridSampler {
+    /// Creates a new grid sampler.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_points` - The number of points used for each float hyperparameter.
+    pub fn new(num_points: usize) -> Self {
+        assert!(
+            num_points > 0,
+            "The grid needs at least one point per dimension."
+        );
+        Self { num_points }
+    }
+
+    fn values(&self, distribution: &Distribution) -> Vec<ParamValue> {
+        let points = |low: f64, high: f64| {
+            (0..self.num_points).map(move |i| match self.num_points {
+                1 => (low + high) / 2.0,
+                n => low + (high - low) * i as f64 / (n - 1) as f64,
+            })
+        };
+
+        match distribution {
+            Distribution::Uniform { low, high } => {
+                points(*low, *high).map(ParamValue::Float).collect()
+            }
+            Distribution::LogUniform { low, high } => points(low.ln(), high.ln())
+                .map(|value| ParamValue::Float(value.exp()))
+                .collect(),
+            Distribution::Int { low, high } => (*low..=*high).map(ParamValue::Int).collect(),
+            Distribution::Choice(values) => values.clone(),
+        }
+    }
+}
+
+impl Sampler for GridSampler {
+    fn sample(&mut self, space: &SearchSpace, trial: usize) -> Option<Params> {
+        let mut params = Params::default();
+        // The last hyperparameter changes the fastest.
+        let mut index = trial;
+
+        for (name, distribution) in space.params.iter().rev() {
+            let values = self.values(distribution);
+            params.insert(name.clone(), values[index % values.len()].clone());
+            index /= values.len();
+        }
+
+        match index {
+            0 => Some(params),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_sampler_respects_bounds() {
+        let space = SearchSpace::new()
+            .uniform("dropout", 0.1, 0.5)
+            .log_uniform("lr", 1e-5, 1e-2)
+            .int("layers", 2, 4)
+            .choice("activation", vec!["relu", "gelu"]);
+        let mut sampler = RandomSampler::new(0);
+
+        for trial in 0..32 {
+            let params = sampler.sample(&space, trial).unwrap();
+
+            assert!((0.1..=0.5).contains(&params.float("dropout")));
+            assert!((1e-5..=1e-2).contains(&params.float("lr")));
+            assert!((2..=4).contains(&params.int("layers")));
+            assert!(["relu", "gelu"].contains(&params.str("activation")));
+        }
+    }
+
+    #[test]
+    fn test_grid_sampler_covers_every_combination() {
+        let space = SearchSpace::new()
+            .log_uniform("lr", 1e-4, 1e-2)
+            .choice("batch_size", vec![16i64, 32]);
+        let mut sampler = GridSampler::new(3);
+
+        let combinations = (0..)
+            .map_while(|trial| sampler.sample(&space, trial))
+            .map(|params| (params.float("lr"), params.int("batch_size")))
+            .collect::<Vec<_>>();
+
+        assert_eq!(combinations.len(), 6);
+        assert_eq!(combinations[0].1, 16);
+        assert_eq!(combinations[1].1, 32);
+        assert!((combinations[2].0 - 1e-3).abs() < 1e-12);
+        assert!((combinations[5].0 - 1e-2).abs() < 1e-12);
+    }
+}
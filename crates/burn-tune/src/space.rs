@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The value of a hyperparameter.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum ParamValue {
+    /// A boolean value.
+    Bool(bool),
+    /// An integer value.
+    Int(i64),
+    /// A floating point value.
+    Float(f64),
+    /// A string value.
+    Str(String),
+}
+
+impl From<bool> for ParamValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i64> for ParamValue {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<f64> for ParamValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<&str> for ParamValue {
+    fn from(value: &str) -> Self {
+        Self::Str(value.to_string())
+    }
+}
+
+impl From<String> for ParamValue {
+    fn from(value: String) -> Self {
+        Self::Str(value)
+    }
+}
+
+/// The distribution a hyperparameter is sampled from.
+#[derive(Clone, Debug)]
+pub enum Distribution {
+    /// A float sampled uniformly in `[low, high]`.
+    Uniform {
+        /// The lower bound.
+        low: f64,
+        /// The upper bound.
+        high: f64,
+    },
+    /// A float sampled uniformly in the log domain of `[low, high]`, useful for learning rates.
+    LogUniform {
+        /// The lower bound, must be positive.
+        low: f64,
+        /// The upper bound.
+        high: f64,
+    },
+    /// An integer sampled uniformly in `[low, high]`.
+    Int {
+        /// The lower bound.
+        low: i64,
+        /// The upper bound.
+        high: i64,
+    },
+    /// One of the given values.
+    Choice(Vec<ParamValue>),
+}
+
+/// The set of hyperparameters to search, with the distribution of each one.
+#[derive(Clone, Debug, Default)]
+pub struct SearchSpace {
+    pub(crate) params: Vec<(String, Distribution)>,
+}
+
+impl SearchSpace {
+    /// Creates an empty search space.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a float hyperparameter sampled uniformly in `[low, high]`.
+    pub fn uniform(self, name: &str, low: f64, high: f64) -> Self {
+        assert!(
+            low <= high,
+            "The lower bound of {name} should not exceed its upper bound."
+        );
+        self.param(name, Distribution::Uniform { low, high })
+    }
+
+    /// Add a float hyperparameter sampled uniformly in the log domain of `[low, high]`.
+    pub fn log_uniform(self, name: &str, low: f64, high: f64) -> Self {
+        assert!(
+            0.0 < low && low <= high,
+            "The bounds of {name} should be positive and ordered."
+        );
+        self.param(name, Distribution::LogUniform { low, high })
+    }
+
+    /// Add an integer hyperparameter sampled uniformly in `[low, high]`.
+    pub fn int(self, name: &str, low: i64, high: i64) -> Self {
+        assert!(
+            low <= high,
+            "The lower bound of {name} should not exceed its upper bound."
+        );
+        self.param(name, Distribution::Int { low, high })
+    }
+
+    /// Add a hyperparameter taking one of the given values.
+    pub fn choice<V: Into<ParamValue>>(self, name: &str, values: Vec<V>) -> Self {
+        assert!(
+            !values.is_empty(),
+            "{name} should have at least one choice."
+        );
+        let values = values.into_iter().map(Into::into).collect();
+        self.param(name, Distribution::Choice(values))
+    }
+
+    fn param(mut self, name: &str, distribution: Distribution) -> Self {
+        assert!(
+            self.params.iter().all(|(existing, _)| existing != name),
+            "The hyperparameter {name} is already registered."
+        );
+        self.params.push((name.to_string(), distribution));
+        self
+    }
+}
+
+/// The hyperparameters sampled for a trial.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Params {
+    values: BTreeMap<String, ParamValue>,
+}
+
+impl Params {
+    pub(crate) fn insert(&mut self, name: String, value: ParamValue) {
+        self.values.insert(name, value);
+    }
+
+    /// Get the value of a hyperparameter.
+    pub fn get(&self, name: &str) -> Option<&ParamValue> {
+        self.values.get(name)
+    }
+
+    /// Get a float hyperparameter, integers are converted.
+    ///
+    /// # Panics
+    ///
+    /// If the hyperparameter doesn't exist or isn't numeric.
+    pub fn float(&self, name: &str) -> f64 {
+        match self.get(name) {
+            Some(ParamValue::Float(value)) => *value,
+            Some(ParamValue::Int(value)) => *value as f64,
+            value => panic!("Expected the hyperparameter {name} to be a float, got {value:?}"),
+        }
+    }
+
+    /// Get an integer hyperparameter.
+    ///
+    /// # Panics
+    ///
+    /// If the hyperparameter doesn't exist or isn't an integer.
+    pub fn int(&self, name: &str) -> i64 {
+        match self.get(name) {
+            Some(ParamValue::Int(value)) => *value,
+            value => panic!("Expected the hyperparameter {name} to be an integer, got {value:?}"),
+        }
+    }
+
+    /// Get a boolean hyperparameter.
+    ///
+    /// # Panics
+    ///
+    /// If the hyperparameter doesn't exist or isn't a boolean.
+    pub fn bool(&self, name: &str) -> bool {
+        match self.get(name) {
+            Some(ParamValue::Bool(value)) => *value,
+            value => panic!("Expected the hyperparameter {name} to be a boolean, got {value:?}"),
+        }
+    }
+
+    /// Get a string hyperparameter.
+    ///
+    /// # Panics
+    ///
+    /// If the hyperparameter doesn't exist or isn't a string.
+    pub fn str(&self, name: &str) -> &str {
+        match self.get(name) {
+            Some(ParamValue::Str(value)) => value,
+            value => panic!("Expected the hyperparameter {name} to be a string, got {value:?}"),
+        }
+    }
+
+    /// Iterate over the hyperparameters by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &ParamValue)> {
+        self.values.iter()
+    }
+}
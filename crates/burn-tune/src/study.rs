@@ -0,0 +1,453 @@
+use crate::{Params, Pruner, RandomSampler, Sampler, SearchSpace};
+use burn_train::metric::store::{Aggregate, Direction, EventStoreClient, Split};
+use burn_train::metric::Metric;
+use burn_train::{EarlyStoppingStrategy, LearnerSummary};
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, fs::File, path::Path, rc::Rc};
+
+/// The metric optimized by a [study](Study).
+#[derive(Clone)]
+pub struct Objective {
+    metric: String,
+    direction: Direction,
+    split: Split,
+}
+
+impl Objective {
+    /// Optimize the mean of a validation metric over an epoch.
+    ///
+    /// # Notes
+    ///
+    /// The metric should be registered on the learner of every trial, otherwise no data is
+    /// collected.
+    pub fn new<Me: Metric>(direction: Direction) -> Self {
+        Self {
+            metric: Me::NAME.to_string(),
+            direction,
+            split: Split::Valid,
+        }
+    }
+
+    /// Use the metric of the given split instead of the validation split.
+    pub fn with_split(mut self, split: Split) -> Self {
+        self.split = split;
+        self
+    }
+
+    fn is_better(&self, value: f64, other: f64) -> bool {
+        match self.direction {
+            Direction::Lowest => value < other,
+            Direction::Highest => value > other,
+        }
+    }
+}
+
+/// The state of a trial once it's done.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrialStatus {
+    /// The trial ran until the end of its training.
+    Completed,
+    /// The trial was stopped early by the [pruner](Pruner).
+    Pruned,
+    /// The objective was never recorded during the trial.
+    Failed,
+}
+
+/// The persisted result of a trial.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TrialResult {
+    /// The trial number.
+    pub id: usize,
+    /// The hyperparameters of the trial.
+    pub params: Params,
+    /// The state of the trial.
+    pub status: TrialStatus,
+    /// The last value of the objective.
+    pub value: Option<f64>,
+    /// The value of the objective at the end of each epoch, as `(epoch, value)`.
+    pub intermediate_values: Vec<(usize, f64)>,
+}
+
+#[derive(Serialize)]
+struct StudyRecord<'a> {
+    best_trial: Option<usize>,
+    trials: &'a [TrialResult],
+}
+
+/// A hyperparameter search, where each trial trains a model with a [learner](burn_train::Learner)
+/// using sampled hyperparameters.
+///
+/// Each trial saves its artifacts in `trial-{id}` under the study directory, along with a
+/// `trial.json` file containing its result. The results of all trials are also saved in
+/// `study.json` after every trial.
+pub struct Study {
+    directory: String,
+    space: SearchSpace,
+    objective: Objective,
+    sampler: Box<dyn Sampler>,
+    pruner: Option<Rc<RefCell<dyn Pruner>>>,
+    num_trials: usize,
+    trials: Vec<TrialResult>,
+}
+
+impl Study {
+    /// Creates a new study.
+    ///
+    /// By default, 10 trials are sampled randomly and none of them are pruned.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory where the trials are saved.
+    /// * `space` - The hyperparameters to search.
+    /// * `objective` - The metric to optimize.
+    pub fn new(directory: &str, space: SearchSpace, objective: Objective) -> Self {
+        Self {
+            directory: directory.to_string(),
+            space,
+            objective,
+            sampler: Box::new(RandomSampler::new(0)),
+            pruner: None,
+            num_trials: 10,
+            trials: Vec::new(),
+        }
+    }
+
+    /// Replace the default random sampler.
+    pub fn with_sampler<S: Sampler + 'static>(mut self, sampler: S) -> Self {
+        self.sampler = Box::new(sampler);
+        self
+    }
+
+    /// Stop unpromising trials early with the given pruner.
+    ///
+    /// # Notes
+    ///
+    /// Each trial has to register its [early stopping strategy](Trial::early_stopping) on its
+    /// learner for the intermediate values to be reported.
+    pub fn with_pruner<P: Pruner + 'static>(mut self, pruner: P) -> Self {
+        self.pruner = Some(Rc::new(RefCell::new(pruner)));
+        self
+    }
+
+    /// The maximum number of trials, fewer are run if the sampler is exhausted.
+    pub fn with_num_trials(mut self, num_trials: usize) -> Self {
+        self.num_trials = num_trials;
+        self
+    }
+
+    /// Runs the trials.
+    ///
+    /// # Arguments
+    ///
+    /// * `train` - Trains a model with the hyperparameters of the trial, in the trial directory.
+    ///
+    /// # Returns
+    ///
+    /// The best completed trial, if any.
+    pub fn optimize<F: FnMut(&Trial)>(&mut self, mut train: F) -> Option<&TrialResult> {
+        std::fs::create_dir_all(&self.directory).ok();
+
+        for _ in 0..self.num_trials {
+            let id = self.trials.len();
+            let params = match self.sampler.sample(&self.space, id) {
+                Some(params) => params,
+                None => break,
+            };
+
+            let trial = Trial {
+                id,
+                params,
+                directory: format!("{}/trial-{id}", self.directory),
+                objective: self.objective.clone(),
+                pruner: self.pruner.clone(),
+                report: Rc::new(RefCell::new(TrialReport::default())),
+            };
+            log::info!("Starting trial {id} with {:?}", trial.params);
+
+            train(&trial);
+
+            let result = trial.result();
+            log::info!(
+                "Trial {id} finished with status {:?} and value {:?}",
+                result.status,
+                result.value
+            );
+            save(Path::new(&trial.directory).join("trial.json"), &result);
+            self.trials.push(result);
+
+            let record = StudyRecord {
+                best_trial: self.best_trial().map(|trial| trial.id),
+                trials: &self.trials,
+            };
+            save(Path::new(&self.directory).join("study.json"), &record);
+        }
+
+        self.best_trial()
+    }
+
+    /// The results of the trials that ran so far.
+    pub fn trials(&self) -> &[TrialResult] {
+        &self.trials
+    }
+
+    /// The completed trial with the best objective value.
+    pub fn best_trial(&self) -> Option<&TrialResult> {
+        self.trials
+            .iter()
+            .filter(|trial| trial.status == TrialStatus::Completed)
+            .filter_map(|trial| trial.value.map(|value| (trial, value)))
+            .filter(|(_, value)| !value.is_nan())
+            .reduce(
+                |best, current| match self.objective.is_better(current.1, best.1) {
+                    true => current,
+                    false => best,
+                },
+            )
+            .map(|(trial, _)| trial)
+    }
+}
+
+/// A trial of a [study](Study), with its sampled hyperparameters.
+pub struct Trial {
+    id: usize,
+    params: Params,
+    directory: String,
+    objective: Objective,
+    pruner: Option<Rc<RefCell<dyn Pruner>>>,
+    report: Rc<RefCell<TrialReport>>,
+}
+
+#[derive(Default)]
+struct TrialReport {
+    intermediate_values: Vec<(usize, f64)>,
+    pruned: bool,
+}
+
+impl Trial {
+    /// The trial number.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The hyperparameters of the trial.
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    /// The directory where the learner of the trial should save its artifacts.
+    pub fn directory(&self) -> &str {
+        &self.directory
+    }
+
+    /// The [early stopping strategy](EarlyStoppingStrategy) to register on the learner, which
+    /// reports the objective after every epoch and stops the training if the trial is pruned.
+    pub fn early_stopping(&self) -> TrialEarlyStopping {
+        TrialEarlyStopping {
+            trial: self.id,
+            objective: self.objective.clone(),
+            pruner: self.pruner.clone(),
+            report: self.report.clone(),
+        }
+    }
+
+    fn result(&self) -> TrialResult {
+        let report = self.report.borrow();
+        let value = match report.intermediate_values.last() {
+            Some((_, value)) => Some(*value),
+            // The early stopping strategy wasn't registered, read the logged metric instead.
+            None => self.logged_value(),
+        };
+
+        let status = match (report.pruned, value) {
+            (true, _) => TrialStatus::Pruned,
+            (false, Some(_)) => TrialStatus::Completed,
+            (false, None) => TrialStatus::Failed,
+        };
+
+        TrialResult {
+            id: self.id,
+            params: self.params.clone(),
+            status,
+            value,
+            intermediate_values: report.intermediate_values.clone(),
+        }
+    }
+
+    fn logged_value(&self) -> Option<f64> {
+        let summary = LearnerSummary::new(&self.directory, &[&self.objective.metric]).ok()?;
+        let metrics = match self.objective.split {
+            Split::Train => summary.metrics.train,
+            Split::Valid => summary.metrics.valid,
+        };
+
+        metrics
+            .into_iter()
+            .next()
+            .and_then(|metric| metric.entries.last().map(|entry| entry.value))
+    }
+}
+
+/// The [early stopping strategy](EarlyStoppingStrategy) of a [trial](Trial).
+pub struct TrialEarlyStopping {
+    trial: usize,
+    objective: Objective,
+    pruner: Option<Rc<RefCell<dyn Pruner>>>,
+    report: Rc<RefCell<TrialReport>>,
+}
+
+impl EarlyStoppingStrategy for TrialEarlyStopping {
+    fn should_stop(&mut self, epoch: usize, store: &EventStoreClient) -> bool {
+        let value = match store.find_metric(
+            &self.objective.metric,
+            epoch,
+            Aggregate::Mean,
+            self.objective.split,
+        ) {
+            Some(value) => value,
+            None => {
+                log::warn!("Can't find the objective metric of trial {}.", self.trial);
+                return false;
+            }
+        };
+
+        let mut report = self.report.borrow_mut();
+        report.intermediate_values.push((epoch, value));
+
+        if let Some(pruner) = &self.pruner {
+            report.pruned = pruner.borrow_mut().should_prune(self.trial, epoch, value);
+        }
+
+        report.pruned
+    }
+}
+
+fn save<T: Serialize, P: AsRef<Path>>(path: P, item: &T) {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+
+    let result = File::create(path)
+        .map_err(|err| err.to_string())
+        .and_then(|file| serde_json::to_writer_pretty(file, item).map_err(|err| err.to_string()));
+
+    if let Err(err) = result {
+        log::error!(
+            "Could not save the study results to {}: {err}",
+            path.display()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AshaPruner, GridSampler};
+    use burn_train::metric::store::{Event, EventStore};
+    use burn_train::metric::LearningRateMetric;
+
+    /// A store holding the value of the objective at each epoch.
+    struct ObjectiveStore {
+        values: Vec<f64>,
+    }
+
+    impl EventStore for ObjectiveStore {
+        fn add_event(&mut self, _event: Event, _split: Split) {}
+
+        fn find_epoch(
+            &mut self,
+            _name: &str,
+            _aggregate: Aggregate,
+            _direction: Direction,
+            _split: Split,
+        ) -> Option<usize> {
+            None
+        }
+
+        fn find_metric(
+            &mut self,
+            name: &str,
+            epoch: usize,
+            _aggregate: Aggregate,
+            _split: Split,
+        ) -> Option<f64> {
+            match name == LearningRateMetric::NAME {
+                true => self.values.get(epoch - 1).copied(),
+                false => None,
+            }
+        }
+    }
+
+    /// Runs the epochs of a trial with the given values of the objective, until it's stopped.
+    fn train(trial: &Trial, values: Vec<f64>) {
+        let num_epochs = values.len();
+        let store = EventStoreClient::new(ObjectiveStore { values });
+        let mut early_stopping = trial.early_stopping();
+
+        for epoch in 1..=num_epochs {
+            if early_stopping.should_stop(epoch, &store) {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn test_study_finds_best_trial_and_persists_results() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path().to_str().unwrap();
+
+        let space = SearchSpace::new().choice("lr", vec![0.3, 0.1, 0.2]);
+        let objective = Objective::new::<LearningRateMetric>(Direction::Lowest);
+        let mut study = Study::new(directory, space, objective)
+            .with_sampler(GridSampler::new(1))
+            .with_pruner(AshaPruner::new(Direction::Lowest, 1, 2));
+
+        let best = study.optimize(|trial| {
+            let lr = trial.params().float("lr");
+            train(trial, vec![lr, lr / 2.0]);
+        });
+
+        assert_eq!(best.map(|trial| trial.id), Some(1));
+
+        let statuses = study
+            .trials()
+            .iter()
+            .map(|trial| trial.status)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                TrialStatus::Completed,
+                TrialStatus::Completed,
+                TrialStatus::Pruned
+            ]
+        );
+        assert_eq!(
+            study.trials()[1].intermediate_values,
+            vec![(1, 0.1), (2, 0.05)]
+        );
+
+        assert!(Path::new(directory).join("study.json").exists());
+        let trial: TrialResult = serde_json::from_reader(
+            File::open(Path::new(directory).join("trial-2").join("trial.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(trial.status, TrialStatus::Pruned);
+        assert_eq!(trial.params.float("lr"), 0.2);
+    }
+
+    #[test]
+    fn test_trial_fails_when_the_objective_is_never_recorded() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path().to_str().unwrap();
+
+        let space = SearchSpace::new().choice("lr", vec![0.1]);
+        let objective = Objective::new::<LearningRateMetric>(Direction::Lowest);
+        let mut study = Study::new(directory, space, objective).with_sampler(GridSampler::new(1));
+
+        let best = study.optimize(|trial| train(trial, Vec::new()));
+
+        assert!(best.is_none());
+        assert_eq!(study.trials()[0].status, TrialStatus::Failed);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{BatchedBridge, BatchedTensor};
+use burn_tensor::backend::{Backend, DeviceInfo, MemoryUsage, SyncType};
+use core::marker::PhantomData;
+
+/// Apply the operations to batches of examples.
+///
+/// This works as a backend decorator: the tensors hold the values of all the examples of a batch
+/// in a tensor of the inner backend, and every operation is applied to the whole batch at once by
+/// the inner backend, so a function written for a single example is batched without changing
+/// it. See [vmap](crate::vmap) to lift a function of single examples to batches.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Batched<B> {
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> Backend for Batched<B> {
+    type Device = B::Device;
+
+    type FullPrecisionBridge = BatchedBridge<B::FullPrecisionBridge>;
+
+    type FloatTensorPrimitive<const D: usize> = BatchedTensor<B::FloatTensorPrimitive<D>>;
+    type FloatElem = B::FloatElem;
+
+    type IntTensorPrimitive<const D: usize> = BatchedTensor<B::IntTensorPrimitive<D>>;
+    type IntElem = B::IntElem;
+
+    type BoolTensorPrimitive<const D: usize> = BatchedTensor<B::BoolTensorPrimitive<D>>;
+
+    fn name() -> String {
+        format!("batched<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn fork_seed() -> Option<u64> {
+        B::fork_seed()
+    }
+
+    fn sync(device: &B::Device, sync_type: SyncType) {
+        B::sync(device, sync_type)
+    }
+
+    fn memory_usage(device: &B::Device) -> Option<MemoryUsage> {
+        B::memory_usage(device)
+    }
+
+    fn devices() -> Vec<DeviceInfo<B::Device>> {
+        B::devices()
+    }
+}
@@ -0,0 +1,43 @@
+use crate::Batched;
+use burn_tensor::{
+    backend::{Backend, BackendBridge},
+    ops::FloatTensor,
+    Device,
+};
+use core::marker::PhantomData;
+
+/// Apply a [backend bridge](BackendBridge) to batches of examples.
+#[derive(Debug)]
+pub struct BatchedBridge<Bridge> {
+    _p: PhantomData<Bridge>,
+}
+
+impl<B, Bridge> BackendBridge<Batched<B>> for BatchedBridge<Bridge>
+where
+    B: Backend,
+    Bridge: BackendBridge<B> + 'static,
+{
+    type Target = Batched<Bridge::Target>;
+
+    fn into_target<const D: usize>(
+        tensor: FloatTensor<Batched<B>, D>,
+        device: Option<Device<Self::Target>>,
+    ) -> FloatTensor<Self::Target, D> {
+        tensor.map(|tensor| Bridge::into_target(tensor, device))
+    }
+
+    fn from_target<const D: usize>(
+        tensor: FloatTensor<Self::Target, D>,
+        device: Option<Device<Batched<B>>>,
+    ) -> FloatTensor<Batched<B>, D> {
+        tensor.map(|tensor| Bridge::from_target(tensor, device))
+    }
+
+    fn target_device(device: &Device<Batched<B>>) -> Device<Self::Target> {
+        Bridge::target_device(device)
+    }
+
+    fn origin_device(device: &Device<Self::Target>) -> Device<Batched<B>> {
+        Bridge::origin_device(device)
+    }
+}
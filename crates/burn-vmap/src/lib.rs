@@ -0,0 +1,29 @@
+#![warn(missing_docs)]
+
+//! # Burn Vmap
+//!
+//! This library is a part of the Burn project. It is a standalone crate providing a backend
+//! decorator that applies the operations of a function written for single examples to whole
+//! batches of examples, so per-example computations, e.g. per-sample gradients, are batched
+//! without looping over the examples.
+
+mod backend;
+mod bridge;
+mod ops;
+mod tensor;
+mod vmap;
+
+pub use backend::*;
+pub use bridge::*;
+pub use tensor::BatchedTensor;
+pub use vmap::*;
+
+#[cfg(test)]
+mod tests {
+    type TestBackend = crate::Batched<burn_ndarray::NdArray<f32>>;
+    type TestTensor<const D: usize> = burn_tensor::Tensor<TestBackend, D>;
+    type TestTensorInt<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Int>;
+    type TestTensorBool<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Bool>;
+
+    burn_tensor::testgen_all!();
+}
@@ -0,0 +1,134 @@
+use crate::{
+    tensor::{
+        binary, cat, expand, flip, permute, repeat, reshape, shape, slice, slice_assign, swap_dims,
+    },
+    Batched, BatchedTensor,
+};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, BoolTensorOps, FloatTensor, IntTensor},
+    Bool, Data, Device, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> BoolTensorOps<Self> for Batched<B> {
+    fn bool_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> BoolTensor<Self, D> {
+        BatchedTensor::unbatched(B::bool_empty(shape, device))
+    }
+
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<Self, D>) -> Shape<D> {
+        shape::<B, Bool, D>(tensor)
+    }
+
+    fn bool_into_data<const D: usize>(tensor: BoolTensor<Self, D>) -> Reader<Data<bool, D>> {
+        tensor.shared();
+        B::bool_into_data(tensor.primitive)
+    }
+
+    fn bool_to_data<const D: usize>(tensor: &BoolTensor<Self, D>) -> Reader<Data<bool, D>> {
+        B::bool_to_data(tensor.shared())
+    }
+
+    fn bool_from_data<const D: usize>(
+        data: Data<bool, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        BatchedTensor::unbatched(B::bool_from_data(data, device))
+    }
+
+    fn bool_into_int<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, D> {
+        tensor.map(B::bool_into_int)
+    }
+
+    fn bool_into_float<const D: usize>(tensor: BoolTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::bool_into_float)
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<Self, D>) -> Device<Self> {
+        B::bool_device(&tensor.primitive)
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        tensor.map(|tensor| B::bool_to_device(tensor, device))
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        reshape::<B, Bool, D1, D2>(tensor, shape)
+    }
+
+    fn bool_slice<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> BoolTensor<Self, D1> {
+        slice::<B, Bool, D1, D2>(tensor, ranges)
+    }
+
+    fn bool_slice_assign<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: BoolTensor<Self, D1>,
+    ) -> BoolTensor<Self, D1> {
+        slice_assign::<B, Bool, D1, D2>(tensor, ranges, value)
+    }
+
+    fn bool_repeat<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> BoolTensor<Self, D> {
+        repeat::<B, Bool, D>(tensor, dim, times)
+    }
+
+    fn bool_cat<const D: usize>(
+        tensors: Vec<BoolTensor<Self, D>>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        cat::<B, Bool, D>(tensors, dim)
+    }
+
+    fn bool_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        binary::<B, Bool, D, _>(lhs, rhs, B::bool_equal)
+    }
+
+    fn bool_not<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        tensor.map(B::bool_not)
+    }
+
+    fn bool_swap_dims<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> BoolTensor<Self, D> {
+        swap_dims::<B, Bool, D>(tensor, dim1, dim2)
+    }
+
+    fn bool_permute<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        axes: [usize; D],
+    ) -> BoolTensor<Self, D> {
+        permute::<B, Bool, D>(tensor, axes)
+    }
+
+    fn bool_flip<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        axes: &[usize],
+    ) -> BoolTensor<Self, D> {
+        flip::<B, Bool, D>(tensor, axes)
+    }
+
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        expand::<B, Bool, D1, D2>(tensor, shape)
+    }
+}
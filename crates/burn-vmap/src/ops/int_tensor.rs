@@ -0,0 +1,607 @@
+use crate::{
+    ops::tensor::select_indices,
+    tensor::{
+        along_dim, batch_size, binary, broadcast, broadcast_dim, cat, examples, expand, flip,
+        permute, reduce, repeat, reshape, shape, slice, slice_assign, swap_dims, unview_first_dim,
+        view_first_dim,
+    },
+    Batched, BatchedTensor,
+};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
+    Bool, Data, Device, Distribution, Int, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> IntTensorOps<Self> for Batched<B> {
+    fn int_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        BatchedTensor::unbatched(B::int_empty(shape, device))
+    }
+
+    fn int_shape<const D: usize>(tensor: &IntTensor<Self, D>) -> Shape<D> {
+        shape::<B, Int, D>(tensor)
+    }
+
+    fn int_into_data<const D: usize>(tensor: IntTensor<Self, D>) -> Reader<Data<IntElem<Self>, D>> {
+        tensor.shared();
+        B::int_into_data(tensor.primitive)
+    }
+
+    fn int_to_data<const D: usize>(tensor: &IntTensor<Self, D>) -> Reader<Data<IntElem<Self>, D>> {
+        B::int_to_data(tensor.shared())
+    }
+
+    fn int_from_data<const D: usize>(
+        data: Data<IntElem<Self>, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        BatchedTensor::unbatched(B::int_from_data(data, device))
+    }
+
+    fn int_device<const D: usize>(tensor: &IntTensor<Self, D>) -> Device<Self> {
+        B::int_device(&tensor.primitive)
+    }
+
+    fn int_to_device<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        tensor.map(|tensor| B::int_to_device(tensor, device))
+    }
+
+    fn int_reshape<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        reshape::<B, Int, D1, D2>(tensor, shape)
+    }
+
+    fn int_slice<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> IntTensor<Self, D1> {
+        slice::<B, Int, D1, D2>(tensor, ranges)
+    }
+
+    fn int_slice_assign<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: IntTensor<Self, D1>,
+    ) -> IntTensor<Self, D1> {
+        slice_assign::<B, Int, D1, D2>(tensor, ranges, value)
+    }
+
+    fn int_into_float<const D: usize>(tensor: IntTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::int_into_float)
+    }
+
+    fn int_gather<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let Some(batch_size) = batch_size(&[tensor.batch_size, indices.batch_size]) else {
+            return BatchedTensor::unbatched(B::int_gather(
+                dim,
+                tensor.primitive,
+                indices.primitive,
+            ));
+        };
+
+        let tensor = examples::<B, Int, D>(tensor, batch_size);
+        let indices = examples::<B, Int, D>(indices, batch_size);
+
+        if dim > 0 {
+            return BatchedTensor::new(B::int_gather(dim, tensor, indices), Some(batch_size));
+        }
+
+        let dims = B::int_shape(&indices).dims;
+        let output = B::int_gather(
+            1,
+            view_first_dim::<B, Int, D>(tensor, batch_size),
+            view_first_dim::<B, Int, D>(indices, batch_size),
+        );
+
+        BatchedTensor::new(
+            unview_first_dim::<B, Int, D>(output, dims),
+            Some(batch_size),
+        )
+    }
+
+    fn int_scatter<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let Some(batch_size) =
+            batch_size(&[tensor.batch_size, indices.batch_size, value.batch_size])
+        else {
+            return BatchedTensor::unbatched(B::int_scatter(
+                dim,
+                tensor.primitive,
+                indices.primitive,
+                value.primitive,
+            ));
+        };
+
+        let tensor = examples::<B, Int, D>(tensor, batch_size);
+        let indices = examples::<B, Int, D>(indices, batch_size);
+        let value = examples::<B, Int, D>(value, batch_size);
+
+        if dim > 0 {
+            return BatchedTensor::new(
+                B::int_scatter(dim, tensor, indices, value),
+                Some(batch_size),
+            );
+        }
+
+        let dims = B::int_shape(&tensor).dims;
+        let output = B::int_scatter(
+            1,
+            view_first_dim::<B, Int, D>(tensor, batch_size),
+            view_first_dim::<B, Int, D>(indices, batch_size),
+            view_first_dim::<B, Int, D>(value, batch_size),
+        );
+
+        BatchedTensor::new(
+            unview_first_dim::<B, Int, D>(output, dims),
+            Some(batch_size),
+        )
+    }
+
+    fn int_select<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> IntTensor<Self, D> {
+        if tensor.batch_size.is_none() && dim == 0 {
+            // The elements selected by all the examples are rows of the shared tensor.
+            return indices.map(|indices| B::int_select(tensor.primitive, dim, indices));
+        }
+
+        if indices.batch_size.is_some() {
+            // The examples select different elements, which are gathered.
+            let indices = select_indices::<B, D>(Self::int_shape(&tensor), dim, indices);
+            return Self::int_gather(dim, tensor, indices);
+        }
+
+        let indices_3d = indices.primitive.clone();
+        along_dim::<B, Int, Int, D>(
+            tensor,
+            dim,
+            |tensor, dim| B::int_select(tensor, dim, indices.primitive),
+            |tensor, dim| B::int_select(tensor, dim, indices_3d),
+        )
+    }
+
+    fn int_select_assign<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        if indices.batch_size.is_some() {
+            // The values are summed at the selected elements, as they are by a scatter.
+            let indices = select_indices::<B, D>(Self::int_shape(&value), dim, indices);
+            return Self::int_scatter(dim, tensor, indices, value);
+        }
+
+        let Some(batch_size) = batch_size(&[tensor.batch_size, value.batch_size]) else {
+            return BatchedTensor::unbatched(B::int_select_assign(
+                tensor.primitive,
+                dim,
+                indices.primitive,
+                value.primitive,
+            ));
+        };
+
+        let tensor = examples::<B, Int, D>(tensor, batch_size);
+        let value = examples::<B, Int, D>(value, batch_size);
+
+        if dim > 0 {
+            return BatchedTensor::new(
+                B::int_select_assign(tensor, dim, indices.primitive, value),
+                Some(batch_size),
+            );
+        }
+
+        let dims = B::int_shape(&tensor).dims;
+        let output = B::int_select_assign(
+            view_first_dim::<B, Int, D>(tensor, batch_size),
+            1,
+            indices.primitive,
+            view_first_dim::<B, Int, D>(value, batch_size),
+        );
+
+        BatchedTensor::new(
+            unview_first_dim::<B, Int, D>(output, dims),
+            Some(batch_size),
+        )
+    }
+
+    fn int_slice<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> IntTensor<Self, D1> {
+        slice::<B, Int, D1, D2>(tensor, ranges)
+    }
+
+    fn int_slice_assign<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: IntTensor<Self, D1>,
+    ) -> IntTensor<Self, D1> {
+        slice_assign::<B, Int, D1, D2>(tensor, ranges, value)
+    }
+
+    fn int_mask_where<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        source: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let Some(batch_size) = batch_size(&[tensor.batch_size, mask.batch_size, source.batch_size])
+        else {
+            return BatchedTensor::unbatched(B::int_mask_where(
+                tensor.primitive,
+                mask.primitive,
+                source.primitive,
+            ));
+        };
+
+        let dim = broadcast_dim(&[
+            shape::<B, Int, D>(&tensor).dims[0],
+            shape::<B, Bool, D>(&mask).dims[0],
+            shape::<B, Int, D>(&source).dims[0],
+        ]);
+        let output = B::int_mask_where(
+            broadcast::<B, Int, D>(tensor, batch_size, dim),
+            broadcast::<B, Bool, D>(mask, batch_size, dim),
+            broadcast::<B, Int, D>(source, batch_size, dim),
+        );
+
+        BatchedTensor::new(output, Some(batch_size))
+    }
+
+    fn int_mask_fill<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let Some(batch_size) = batch_size(&[tensor.batch_size, mask.batch_size]) else {
+            return BatchedTensor::unbatched(B::int_mask_fill(
+                tensor.primitive,
+                mask.primitive,
+                value,
+            ));
+        };
+
+        let dim = broadcast_dim(&[
+            shape::<B, Int, D>(&tensor).dims[0],
+            shape::<B, Bool, D>(&mask).dims[0],
+        ]);
+        let output = B::int_mask_fill(
+            broadcast::<B, Int, D>(tensor, batch_size, dim),
+            broadcast::<B, Bool, D>(mask, batch_size, dim),
+            value,
+        );
+
+        BatchedTensor::new(output, Some(batch_size))
+    }
+
+    fn int_repeat<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> IntTensor<Self, D> {
+        repeat::<B, Int, D>(tensor, dim, times)
+    }
+
+    fn int_cat<const D: usize>(tensors: Vec<IntTensor<Self, D>>, dim: usize) -> IntTensor<Self, D> {
+        cat::<B, Int, D>(tensors, dim)
+    }
+
+    fn int_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        binary::<B, Int, D, _>(lhs, rhs, B::int_equal)
+    }
+
+    fn int_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::int_equal_elem(lhs, rhs))
+    }
+
+    fn int_greater<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        binary::<B, Int, D, _>(lhs, rhs, B::int_greater)
+    }
+
+    fn int_greater_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::int_greater_elem(lhs, rhs))
+    }
+
+    fn int_greater_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        binary::<B, Int, D, _>(lhs, rhs, B::int_greater_equal)
+    }
+
+    fn int_greater_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::int_greater_equal_elem(lhs, rhs))
+    }
+
+    fn int_lower<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        binary::<B, Int, D, _>(lhs, rhs, B::int_lower)
+    }
+
+    fn int_lower_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::int_lower_elem(lhs, rhs))
+    }
+
+    fn int_lower_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        binary::<B, Int, D, _>(lhs, rhs, B::int_lower_equal)
+    }
+
+    fn int_lower_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::int_lower_equal_elem(lhs, rhs))
+    }
+
+    fn int_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary::<B, Int, D, _>(lhs, rhs, B::int_add)
+    }
+
+    fn int_add_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_add_scalar(lhs, rhs))
+    }
+
+    fn int_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary::<B, Int, D, _>(lhs, rhs, B::int_sub)
+    }
+
+    fn int_sub_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_sub_scalar(lhs, rhs))
+    }
+
+    fn int_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary::<B, Int, D, _>(lhs, rhs, B::int_mul)
+    }
+
+    fn int_mul_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_mul_scalar(lhs, rhs))
+    }
+
+    fn int_div<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary::<B, Int, D, _>(lhs, rhs, B::int_div)
+    }
+
+    fn int_div_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_div_scalar(lhs, rhs))
+    }
+
+    fn int_remainder_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        lhs.map(|lhs| B::int_remainder_scalar(lhs, rhs))
+    }
+
+    fn int_clamp_min<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        tensor.map(|tensor| B::int_clamp_min(tensor, min))
+    }
+
+    fn int_clamp_max<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        tensor.map(|tensor| B::int_clamp_max(tensor, max))
+    }
+
+    fn int_clamp<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        tensor.map(|tensor| B::int_clamp(tensor, min, max))
+    }
+
+    fn int_neg<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        tensor.map(B::int_neg)
+    }
+
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        BatchedTensor::unbatched(B::int_zeros(shape, device))
+    }
+
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        BatchedTensor::unbatched(B::int_ones(shape, device))
+    }
+
+    fn int_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: IntElem<Self>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        BatchedTensor::unbatched(B::int_full(shape, fill_value, device))
+    }
+
+    fn int_sum<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        reduce::<B, Int, Int, D>(tensor, B::int_sum, B::int_sum_dim)
+    }
+
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        along_dim::<B, Int, Int, D>(tensor, dim, B::int_sum_dim, B::int_sum_dim)
+    }
+
+    fn int_prod<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        reduce::<B, Int, Int, D>(tensor, B::int_prod, B::int_prod_dim)
+    }
+
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        along_dim::<B, Int, Int, D>(tensor, dim, B::int_prod_dim, B::int_prod_dim)
+    }
+
+    fn int_mean<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        reduce::<B, Int, Int, D>(tensor, B::int_mean, B::int_mean_dim)
+    }
+
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        along_dim::<B, Int, Int, D>(tensor, dim, B::int_mean_dim, B::int_mean_dim)
+    }
+
+    fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        along_dim::<B, Int, Int, D>(tensor, dim, B::int_argmax, B::int_argmax)
+    }
+
+    fn int_argmin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        along_dim::<B, Int, Int, D>(tensor, dim, B::int_argmin, B::int_argmin)
+    }
+
+    fn int_abs<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        tensor.map(B::int_abs)
+    }
+
+    fn int_swap_dims<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> IntTensor<Self, D> {
+        swap_dims::<B, Int, D>(tensor, dim1, dim2)
+    }
+
+    fn int_permute<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        axes: [usize; D],
+    ) -> IntTensor<Self, D> {
+        permute::<B, Int, D>(tensor, axes)
+    }
+
+    fn int_flip<const D: usize>(tensor: IntTensor<Self, D>, axes: &[usize]) -> IntTensor<Self, D> {
+        flip::<B, Int, D>(tensor, axes)
+    }
+
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        // The random values are sampled once, and shared by all the examples.
+        BatchedTensor::unbatched(B::int_random(shape, distribution, device))
+    }
+
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        expand::<B, Int, D1, D2>(tensor, shape)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        along_dim::<B, Int, Int, D>(
+            tensor,
+            dim,
+            |tensor, dim| B::int_sort(tensor, dim, descending),
+            |tensor, dim| B::int_sort(tensor, dim, descending),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        let batch_size = tensor.batch_size;
+        match batch_size {
+            Some(size) if dim == 0 => {
+                let dims = B::int_shape(&tensor.primitive).dims;
+                let view = view_first_dim::<B, Int, D>(tensor.primitive, size);
+                let (values, indices) = B::int_sort_with_indices(view, 1, descending);
+
+                (
+                    BatchedTensor::new(unview_first_dim::<B, Int, D>(values, dims), batch_size),
+                    BatchedTensor::new(unview_first_dim::<B, Int, D>(indices, dims), batch_size),
+                )
+            }
+            _ => {
+                let (values, indices) = B::int_sort_with_indices(tensor.primitive, dim, descending);
+
+                (
+                    BatchedTensor::new(values, batch_size),
+                    BatchedTensor::new(indices, batch_size),
+                )
+            }
+        }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        along_dim::<B, Int, Int, D>(
+            tensor,
+            dim,
+            |tensor, dim| B::int_argsort(tensor, dim, descending),
+            |tensor, dim| B::int_argsort(tensor, dim, descending),
+        )
+    }
+}
@@ -0,0 +1,271 @@
+use crate::{
+    tensor::{batch_size, examples},
+    Batched, BatchedTensor,
+};
+use burn_tensor::{
+    backend::Backend,
+    ops::{
+        ConvOptions, ConvTransposeOptions, FloatTensor, IntTensor, InterpolateOptions,
+        MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    },
+    Float, Int, Shape,
+};
+
+impl<B: Backend> ModuleOps<Self> for Batched<B> {
+    fn conv2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        let Some(batch_size) = conv_batch_size(&x, &weight, &bias) else {
+            return x.map(|x| {
+                B::conv2d(
+                    x,
+                    weight.primitive,
+                    bias.map(|bias| bias.primitive),
+                    options,
+                )
+            });
+        };
+
+        let options = ConvOptions {
+            groups: options.groups * batch_size,
+            ..options
+        };
+        let (x, weight, bias) = into_groups::<B>(x, weight, bias, batch_size);
+        let output = B::conv2d(x, weight, bias, options);
+
+        BatchedTensor::new(from_groups::<B>(output, batch_size), Some(batch_size))
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        let Some(batch_size) = conv_batch_size(&x, &weight, &bias) else {
+            return x.map(|x| {
+                B::conv_transpose2d(
+                    x,
+                    weight.primitive,
+                    bias.map(|bias| bias.primitive),
+                    options,
+                )
+            });
+        };
+
+        // The input channels of the weights are first, so the weights of the examples are stacked
+        // along the input channels of the groups.
+        let options = ConvTransposeOptions {
+            groups: options.groups * batch_size,
+            ..options
+        };
+        let (x, weight, bias) = into_groups::<B>(x, weight, bias, batch_size);
+        let output = B::conv_transpose2d(x, weight, bias, options);
+
+        BatchedTensor::new(from_groups::<B>(output, batch_size), Some(batch_size))
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        x.map(|x| B::avg_pool2d(x, kernel_size, stride, padding, count_include_pad))
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        let batch_size = batch_size(&[x.batch_size, grad.batch_size]);
+        let (x, grad) = (examples_of(x, batch_size), examples_of(grad, batch_size));
+        let output =
+            B::avg_pool2d_backward(x, grad, kernel_size, stride, padding, count_include_pad);
+
+        BatchedTensor::new(output, batch_size)
+    }
+
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        x.map(|x| B::adaptive_avg_pool2d(x, output_size))
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+    ) -> FloatTensor<Self, 4> {
+        let batch_size = batch_size(&[x.batch_size, grad.batch_size]);
+        let (x, grad) = (examples_of(x, batch_size), examples_of(grad, batch_size));
+
+        BatchedTensor::new(B::adaptive_avg_pool2d_backward(x, grad), batch_size)
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        x.map(|x| B::max_pool2d(x, kernel_size, stride, padding, dilation))
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        let batch_size = x.batch_size;
+        let output =
+            B::max_pool2d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+
+        MaxPool2dWithIndices::new(
+            BatchedTensor::new(output.output, batch_size),
+            BatchedTensor::new(output.indices, batch_size),
+        )
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<Self, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        let batch_size = batch_size(&[x.batch_size, output_grad.batch_size, indices.batch_size]);
+        let x = examples_of(x, batch_size);
+        let output_grad = examples_of(output_grad, batch_size);
+        let indices = match batch_size {
+            Some(batch_size) => examples::<B, Int, 4>(indices, batch_size),
+            None => indices.primitive,
+        };
+        let output = B::max_pool2d_with_indices_backward(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            output_grad,
+            indices,
+        );
+
+        MaxPool2dBackward::new(BatchedTensor::new(output.x_grad, batch_size))
+    }
+
+    fn interpolate(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        x.map(|x| B::interpolate(x, output_size, options))
+    }
+
+    fn interpolate_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        let batch_size = batch_size(&[x.batch_size, grad.batch_size]);
+        let (x, grad) = (examples_of(x, batch_size), examples_of(grad, batch_size));
+
+        BatchedTensor::new(
+            B::interpolate_backward(x, grad, output_size, options),
+            batch_size,
+        )
+    }
+}
+
+/// Returns the values of the tensor for all the examples of the batch, if any.
+fn examples_of<B: Backend>(
+    tensor: FloatTensor<Batched<B>, 4>,
+    batch_size: Option<usize>,
+) -> B::FloatTensorPrimitive<4> {
+    match batch_size {
+        Some(batch_size) => examples::<B, Float, 4>(tensor, batch_size),
+        None => tensor.primitive,
+    }
+}
+
+/// Returns the batch size of a convolution whose examples have their own weights, or `None` if
+/// the weights are shared by all the examples, which are then convolved as a single batch.
+fn conv_batch_size<B: Backend>(
+    x: &FloatTensor<Batched<B>, 4>,
+    weight: &FloatTensor<Batched<B>, 4>,
+    bias: &Option<FloatTensor<Batched<B>, 1>>,
+) -> Option<usize> {
+    let bias = bias.as_ref().and_then(|bias| bias.batch_size);
+    let batch_size = batch_size(&[x.batch_size, weight.batch_size, bias]);
+
+    match (weight.batch_size, bias) {
+        (None, None) => None,
+        _ => batch_size,
+    }
+}
+
+/// Merges the examples with the channels of a convolution, so each example is convolved with its
+/// own weights as a group of channels.
+///
+/// The input of shape `[batch_size * n, c, h, w]` is viewed as `[n, batch_size * c, h, w]`, the
+/// weights of the examples are stacked along their first dimension, and so are the biases.
+fn into_groups<B: Backend>(
+    x: FloatTensor<Batched<B>, 4>,
+    weight: FloatTensor<Batched<B>, 4>,
+    bias: Option<FloatTensor<Batched<B>, 1>>,
+    batch_size: usize,
+) -> (
+    B::FloatTensorPrimitive<4>,
+    B::FloatTensorPrimitive<4>,
+    Option<B::FloatTensorPrimitive<1>>,
+) {
+    let x = examples::<B, Float, 4>(x, batch_size);
+    let [size, channels, height, width] = B::float_shape(&x).dims;
+    let x = B::float_reshape(
+        x,
+        Shape::new([batch_size, size / batch_size, channels, height, width]),
+    );
+    let x = B::float_swap_dims(x, 0, 1);
+    let x = B::float_reshape(
+        x,
+        Shape::new([size / batch_size, batch_size * channels, height, width]),
+    );
+
+    let weight = examples::<B, Float, 4>(weight, batch_size);
+    let bias = bias.map(|bias| examples::<B, Float, 1>(bias, batch_size));
+
+    (x, weight, bias)
+}
+
+/// Reverts the [merge](into_groups) of the examples with the channels of the output of a
+/// convolution, of shape `[n, batch_size * c, h, w]`, to `[batch_size * n, c, h, w]`.
+fn from_groups<B: Backend>(
+    output: B::FloatTensorPrimitive<4>,
+    batch_size: usize,
+) -> B::FloatTensorPrimitive<4> {
+    let [size, channels, height, width] = B::float_shape(&output).dims;
+    let output = B::float_reshape(
+        output,
+        Shape::new([size, batch_size, channels / batch_size, height, width]),
+    );
+    let output = B::float_swap_dims(output, 0, 1);
+
+    B::float_reshape(
+        output,
+        Shape::new([batch_size * size, channels / batch_size, height, width]),
+    )
+}
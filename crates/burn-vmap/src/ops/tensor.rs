@@ -0,0 +1,953 @@
+use crate::{
+    tensor::{
+        along_dim, batch_size, binary, broadcast, broadcast_dim, cat, examples, expand, flip,
+        permute, reduce, repeat, reshape, shape, slice, slice_assign, swap_dims, unview_first_dim,
+        view_first_dim,
+    },
+    Batched, BatchedTensor,
+};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor, IntTensorOps},
+    Bool, Data, Device, Distribution, Float, Int, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> FloatTensorOps<Self> for Batched<B> {
+    fn float_from_data<const D: usize>(
+        data: Data<FloatElem<Self>, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        BatchedTensor::unbatched(B::float_from_data(data, device))
+    }
+
+    fn float_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        // The random values are sampled once, and shared by all the examples.
+        BatchedTensor::unbatched(B::float_random(shape, distribution, device))
+    }
+
+    fn float_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        BatchedTensor::unbatched(B::float_zeros(shape, device))
+    }
+
+    fn float_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        BatchedTensor::unbatched(B::float_ones(shape, device))
+    }
+
+    fn float_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: FloatElem<Self>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        BatchedTensor::unbatched(B::float_full(shape, fill_value, device))
+    }
+
+    fn float_shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
+        shape::<B, Float, D>(tensor)
+    }
+
+    fn float_to_data<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+    ) -> Reader<Data<FloatElem<Self>, D>> {
+        B::float_to_data(tensor.shared())
+    }
+
+    fn float_into_data<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> Reader<Data<FloatElem<Self>, D>> {
+        tensor.shared();
+        B::float_into_data(tensor.primitive)
+    }
+
+    fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
+        B::float_device(&tensor.primitive)
+    }
+
+    fn float_to_device<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        tensor.map(|tensor| B::float_to_device(tensor, device))
+    }
+
+    fn float_into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, D> {
+        tensor.map(B::float_into_int)
+    }
+
+    fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        BatchedTensor::unbatched(B::float_empty(shape, device))
+    }
+
+    fn float_repeat<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> FloatTensor<Self, D> {
+        repeat::<B, Float, D>(tensor, dim, times)
+    }
+
+    fn float_add<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary::<B, Float, D, _>(lhs, rhs, B::float_add)
+    }
+
+    fn float_add_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        lhs.map(|lhs| B::float_add_scalar(lhs, rhs))
+    }
+
+    fn float_clamp_min<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        tensor.map(|tensor| B::float_clamp_min(tensor, min))
+    }
+
+    fn float_clamp_max<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        tensor.map(|tensor| B::float_clamp_max(tensor, max))
+    }
+
+    fn float_clamp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        tensor.map(|tensor| B::float_clamp(tensor, min, max))
+    }
+
+    fn float_sub<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary::<B, Float, D, _>(lhs, rhs, B::float_sub)
+    }
+
+    fn float_sub_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        lhs.map(|lhs| B::float_sub_scalar(lhs, rhs))
+    }
+
+    fn float_mul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary::<B, Float, D, _>(lhs, rhs, B::float_mul)
+    }
+
+    fn float_mul_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        lhs.map(|lhs| B::float_mul_scalar(lhs, rhs))
+    }
+
+    fn float_div<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary::<B, Float, D, _>(lhs, rhs, B::float_div)
+    }
+
+    fn float_div_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        lhs.map(|lhs| B::float_div_scalar(lhs, rhs))
+    }
+
+    fn float_remainder_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        lhs.map(|lhs| B::float_remainder_scalar(lhs, rhs))
+    }
+
+    fn float_matmul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        if D == 2 {
+            match (lhs.batch_size, rhs.batch_size) {
+                (None, None) => {}
+                // The rows of all the examples are multiplied by the shared matrix at once.
+                (Some(_), None) => {
+                    return lhs.map(|lhs| B::float_matmul(lhs, rhs.primitive));
+                }
+                _ => {
+                    return from_matrices::<B, D>(Self::float_matmul(
+                        to_matrices::<B, D>(lhs),
+                        to_matrices::<B, D>(rhs),
+                    ))
+                }
+            }
+        }
+
+        binary::<B, Float, D, _>(lhs, rhs, B::float_matmul)
+    }
+
+    fn float_neg<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_neg)
+    }
+
+    fn float_recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_recip)
+    }
+
+    fn float_swap_dims<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<Self, D> {
+        swap_dims::<B, Float, D>(tensor, dim1, dim2)
+    }
+
+    fn float_permute<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: [usize; D],
+    ) -> FloatTensor<Self, D> {
+        permute::<B, Float, D>(tensor, axes)
+    }
+
+    fn float_flip<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: &[usize],
+    ) -> FloatTensor<Self, D> {
+        flip::<B, Float, D>(tensor, axes)
+    }
+
+    fn float_reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        reshape::<B, Float, D1, D2>(tensor, shape)
+    }
+
+    fn float_gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let Some(batch_size) = batch_size(&[tensor.batch_size, indices.batch_size]) else {
+            return BatchedTensor::unbatched(B::float_gather(
+                dim,
+                tensor.primitive,
+                indices.primitive,
+            ));
+        };
+
+        let tensor = examples::<B, Float, D>(tensor, batch_size);
+        let indices = examples::<B, Int, D>(indices, batch_size);
+
+        if dim > 0 {
+            return BatchedTensor::new(B::float_gather(dim, tensor, indices), Some(batch_size));
+        }
+
+        let dims = B::int_shape(&indices).dims;
+        let output = B::float_gather(
+            1,
+            view_first_dim::<B, Float, D>(tensor, batch_size),
+            view_first_dim::<B, Int, D>(indices, batch_size),
+        );
+
+        BatchedTensor::new(
+            unview_first_dim::<B, Float, D>(output, dims),
+            Some(batch_size),
+        )
+    }
+
+    fn float_scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let Some(batch_size) =
+            batch_size(&[tensor.batch_size, indices.batch_size, value.batch_size])
+        else {
+            return BatchedTensor::unbatched(B::float_scatter(
+                dim,
+                tensor.primitive,
+                indices.primitive,
+                value.primitive,
+            ));
+        };
+
+        let tensor = examples::<B, Float, D>(tensor, batch_size);
+        let indices = examples::<B, Int, D>(indices, batch_size);
+        let value = examples::<B, Float, D>(value, batch_size);
+
+        if dim > 0 {
+            return BatchedTensor::new(
+                B::float_scatter(dim, tensor, indices, value),
+                Some(batch_size),
+            );
+        }
+
+        let dims = B::float_shape(&tensor).dims;
+        let output = B::float_scatter(
+            1,
+            view_first_dim::<B, Float, D>(tensor, batch_size),
+            view_first_dim::<B, Int, D>(indices, batch_size),
+            view_first_dim::<B, Float, D>(value, batch_size),
+        );
+
+        BatchedTensor::new(
+            unview_first_dim::<B, Float, D>(output, dims),
+            Some(batch_size),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_scatter_reduce<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+        reduction: burn_tensor::ops::ScatterReduction,
+        deterministic: bool,
+    ) -> FloatTensor<Self, D> {
+        let Some(batch_size) =
+            batch_size(&[tensor.batch_size, indices.batch_size, value.batch_size])
+        else {
+            return BatchedTensor::unbatched(B::float_scatter_reduce(
+                dim,
+                tensor.primitive,
+                indices.primitive,
+                value.primitive,
+                reduction,
+                deterministic,
+            ));
+        };
+
+        let tensor = examples::<B, Float, D>(tensor, batch_size);
+        let indices = examples::<B, Int, D>(indices, batch_size);
+        let value = examples::<B, Float, D>(value, batch_size);
+
+        if dim > 0 {
+            return BatchedTensor::new(
+                B::float_scatter_reduce(dim, tensor, indices, value, reduction, deterministic),
+                Some(batch_size),
+            );
+        }
+
+        let dims = B::float_shape(&tensor).dims;
+        let output = B::float_scatter_reduce(
+            1,
+            view_first_dim::<B, Float, D>(tensor, batch_size),
+            view_first_dim::<B, Int, D>(indices, batch_size),
+            view_first_dim::<B, Float, D>(value, batch_size),
+            reduction,
+            deterministic,
+        );
+
+        BatchedTensor::new(
+            unview_first_dim::<B, Float, D>(output, dims),
+            Some(batch_size),
+        )
+    }
+
+    fn float_select<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, D> {
+        if tensor.batch_size.is_none() && dim == 0 {
+            // The elements selected by all the examples are rows of the shared tensor.
+            return indices.map(|indices| B::float_select(tensor.primitive, dim, indices));
+        }
+
+        if indices.batch_size.is_some() {
+            // The examples select different elements, which are gathered.
+            let indices = select_indices::<B, D>(Self::float_shape(&tensor), dim, indices);
+            return Self::float_gather(dim, tensor, indices);
+        }
+
+        let indices_3d = indices.primitive.clone();
+        along_dim::<B, Float, Float, D>(
+            tensor,
+            dim,
+            |tensor, dim| B::float_select(tensor, dim, indices.primitive),
+            |tensor, dim| B::float_select(tensor, dim, indices_3d),
+        )
+    }
+
+    fn float_select_assign<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        if indices.batch_size.is_some() {
+            // The values are summed at the selected elements, as they are by a scatter.
+            let indices = select_indices::<B, D>(Self::float_shape(&value), dim, indices);
+            return Self::float_scatter(dim, tensor, indices, value);
+        }
+
+        let Some(batch_size) = batch_size(&[tensor.batch_size, value.batch_size]) else {
+            return BatchedTensor::unbatched(B::float_select_assign(
+                tensor.primitive,
+                dim,
+                indices.primitive,
+                value.primitive,
+            ));
+        };
+
+        let tensor = examples::<B, Float, D>(tensor, batch_size);
+        let value = examples::<B, Float, D>(value, batch_size);
+
+        if dim > 0 {
+            return BatchedTensor::new(
+                B::float_select_assign(tensor, dim, indices.primitive, value),
+                Some(batch_size),
+            );
+        }
+
+        let dims = B::float_shape(&tensor).dims;
+        let output = B::float_select_assign(
+            view_first_dim::<B, Float, D>(tensor, batch_size),
+            1,
+            indices.primitive,
+            view_first_dim::<B, Float, D>(value, batch_size),
+        );
+
+        BatchedTensor::new(
+            unview_first_dim::<B, Float, D>(output, dims),
+            Some(batch_size),
+        )
+    }
+
+    fn float_slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> FloatTensor<Self, D1> {
+        slice::<B, Float, D1, D2>(tensor, ranges)
+    }
+
+    fn float_slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: FloatTensor<Self, D1>,
+    ) -> FloatTensor<Self, D1> {
+        slice_assign::<B, Float, D1, D2>(tensor, ranges, value)
+    }
+
+    fn float_mask_where<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let Some(batch_size) = batch_size(&[tensor.batch_size, mask.batch_size, value.batch_size])
+        else {
+            return BatchedTensor::unbatched(B::float_mask_where(
+                tensor.primitive,
+                mask.primitive,
+                value.primitive,
+            ));
+        };
+
+        let dim = broadcast_dim(&[
+            shape::<B, Float, D>(&tensor).dims[0],
+            shape::<B, Bool, D>(&mask).dims[0],
+            shape::<B, Float, D>(&value).dims[0],
+        ]);
+        let output = B::float_mask_where(
+            broadcast::<B, Float, D>(tensor, batch_size, dim),
+            broadcast::<B, Bool, D>(mask, batch_size, dim),
+            broadcast::<B, Float, D>(value, batch_size, dim),
+        );
+
+        BatchedTensor::new(output, Some(batch_size))
+    }
+
+    fn float_mask_fill<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let Some(batch_size) = batch_size(&[tensor.batch_size, mask.batch_size]) else {
+            return BatchedTensor::unbatched(B::float_mask_fill(
+                tensor.primitive,
+                mask.primitive,
+                value,
+            ));
+        };
+
+        let dim = broadcast_dim(&[
+            shape::<B, Float, D>(&tensor).dims[0],
+            shape::<B, Bool, D>(&mask).dims[0],
+        ]);
+        let output = B::float_mask_fill(
+            broadcast::<B, Float, D>(tensor, batch_size, dim),
+            broadcast::<B, Bool, D>(mask, batch_size, dim),
+            value,
+        );
+
+        BatchedTensor::new(output, Some(batch_size))
+    }
+
+    fn float_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        binary::<B, Float, D, _>(lhs, rhs, B::float_equal)
+    }
+
+    fn float_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::float_equal_elem(lhs, rhs))
+    }
+
+    fn float_greater<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        binary::<B, Float, D, _>(lhs, rhs, B::float_greater)
+    }
+
+    fn float_greater_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::float_greater_elem(lhs, rhs))
+    }
+
+    fn float_greater_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        binary::<B, Float, D, _>(lhs, rhs, B::float_greater_equal)
+    }
+
+    fn float_greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::float_greater_equal_elem(lhs, rhs))
+    }
+
+    fn float_lower<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        binary::<B, Float, D, _>(lhs, rhs, B::float_lower)
+    }
+
+    fn float_lower_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::float_lower_elem(lhs, rhs))
+    }
+
+    fn float_lower_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        binary::<B, Float, D, _>(lhs, rhs, B::float_lower_equal)
+    }
+
+    fn float_lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        lhs.map(|lhs| B::float_lower_equal_elem(lhs, rhs))
+    }
+
+    fn float_detach<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_detach)
+    }
+
+    fn float_set_require_grad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        require_grad: bool,
+    ) -> FloatTensor<Self, D> {
+        tensor.map(|tensor| B::float_set_require_grad(tensor, require_grad))
+    }
+
+    fn float_is_require_grad<const D: usize>(tensor: &FloatTensor<Self, D>) -> bool {
+        B::float_is_require_grad(&tensor.primitive)
+    }
+
+    fn float_sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        reduce::<B, Float, Float, D>(tensor, B::float_sum, B::float_sum_dim)
+    }
+
+    fn float_sum_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        along_dim::<B, Float, Float, D>(tensor, dim, B::float_sum_dim, B::float_sum_dim)
+    }
+
+    fn float_prod<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        reduce::<B, Float, Float, D>(tensor, B::float_prod, B::float_prod_dim)
+    }
+
+    fn float_prod_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        along_dim::<B, Float, Float, D>(tensor, dim, B::float_prod_dim, B::float_prod_dim)
+    }
+
+    fn float_mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        reduce::<B, Float, Float, D>(tensor, B::float_mean, B::float_mean_dim)
+    }
+
+    fn float_mean_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        along_dim::<B, Float, Float, D>(tensor, dim, B::float_mean_dim, B::float_mean_dim)
+    }
+
+    fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_exp)
+    }
+
+    fn float_log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_log)
+    }
+
+    fn float_log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_log1p)
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary::<B, Float, D, _>(lhs, rhs, B::float_powf)
+    }
+
+    fn float_powf_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        value: f32,
+    ) -> FloatTensor<Self, D> {
+        tensor.map(|tensor| B::float_powf_scalar(tensor, value))
+    }
+
+    fn float_sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_sqrt)
+    }
+
+    fn float_abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_abs)
+    }
+
+    fn float_cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_cos)
+    }
+
+    fn float_sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_sin)
+    }
+
+    fn float_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_tanh)
+    }
+
+    fn float_erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        tensor.map(B::float_erf)
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<Self, D>>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        cat::<B, Float, D>(tensors, dim)
+    }
+
+    fn float_argmax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        along_dim::<B, Float, Int, D>(tensor, dim, B::float_argmax, B::float_argmax)
+    }
+
+    fn float_argmin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        along_dim::<B, Float, Int, D>(tensor, dim, B::float_argmin, B::float_argmin)
+    }
+
+    fn float_expand<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        expand::<B, Float, D1, D2>(tensor, shape)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        along_dim::<B, Float, Float, D>(
+            tensor,
+            dim,
+            |tensor, dim| B::float_sort(tensor, dim, descending),
+            |tensor, dim| B::float_sort(tensor, dim, descending),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        let batch_size = tensor.batch_size;
+        match batch_size {
+            Some(size) if dim == 0 => {
+                let dims = B::float_shape(&tensor.primitive).dims;
+                let view = view_first_dim::<B, Float, D>(tensor.primitive, size);
+                let (values, indices) = B::float_sort_with_indices(view, 1, descending);
+
+                (
+                    BatchedTensor::new(unview_first_dim::<B, Float, D>(values, dims), batch_size),
+                    BatchedTensor::new(unview_first_dim::<B, Int, D>(indices, dims), batch_size),
+                )
+            }
+            _ => {
+                let (values, indices) =
+                    B::float_sort_with_indices(tensor.primitive, dim, descending);
+
+                (
+                    BatchedTensor::new(values, batch_size),
+                    BatchedTensor::new(indices, batch_size),
+                )
+            }
+        }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        along_dim::<B, Float, Int, D>(
+            tensor,
+            dim,
+            |tensor, dim| B::float_argsort(tensor, dim, descending),
+            |tensor, dim| B::float_argsort(tensor, dim, descending),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        if D == 2 && tensor.batch_size.is_some() {
+            return from_matrices::<B, D>(Self::float_cholesky(to_matrices::<B, D>(tensor)));
+        }
+
+        tensor.map(B::float_cholesky)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_solve<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let Some(batch_size) = batch_size(&[lhs.batch_size, rhs.batch_size]) else {
+            return BatchedTensor::unbatched(B::float_solve(lhs.primitive, rhs.primitive));
+        };
+
+        if D == 2 {
+            return from_matrices::<B, D>(Self::float_solve(
+                to_matrices::<B, D>(lhs),
+                to_matrices::<B, D>(rhs),
+            ));
+        }
+
+        let dim = broadcast_dim(&[
+            shape::<B, Float, D>(&lhs).dims[0],
+            shape::<B, Float, D>(&rhs).dims[0],
+        ]);
+        let output = B::float_solve(
+            broadcast::<B, Float, D>(lhs, batch_size, dim),
+            broadcast::<B, Float, D>(rhs, batch_size, dim),
+        );
+
+        BatchedTensor::new(output, Some(batch_size))
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_inverse<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        if D == 2 && tensor.batch_size.is_some() {
+            return from_matrices::<B, D>(Self::float_inverse(to_matrices::<B, D>(tensor)));
+        }
+
+        tensor.map(B::float_inverse)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_det<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        if D == 2 && tensor.batch_size.is_some() {
+            return from_matrices::<B, D>(Self::float_det(to_matrices::<B, D>(tensor)));
+        }
+
+        tensor.map(B::float_det)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_slogdet<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        if D == 2 && tensor.batch_size.is_some() {
+            let (sign, logabsdet) = Self::float_slogdet(to_matrices::<B, D>(tensor));
+            return (
+                from_matrices::<B, D>(sign),
+                from_matrices::<B, D>(logabsdet),
+            );
+        }
+
+        let batch_size = tensor.batch_size;
+        let (sign, logabsdet) = B::float_slogdet(tensor.primitive);
+
+        (
+            BatchedTensor::new(sign, batch_size),
+            BatchedTensor::new(logabsdet, batch_size),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_pinverse<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        rcond: f64,
+    ) -> FloatTensor<Self, D> {
+        if D == 2 && tensor.batch_size.is_some() {
+            return from_matrices::<B, D>(Self::float_pinverse(to_matrices::<B, D>(tensor), rcond));
+        }
+
+        tensor.map(|tensor| B::float_pinverse(tensor, rcond))
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        if D == 2 && tensor.batch_size.is_some() {
+            let (q, r) = Self::float_qr(to_matrices::<B, D>(tensor));
+            return (from_matrices::<B, D>(q), from_matrices::<B, D>(r));
+        }
+
+        let batch_size = tensor.batch_size;
+        let (q, r) = B::float_qr(tensor.primitive);
+
+        (
+            BatchedTensor::new(q, batch_size),
+            BatchedTensor::new(r, batch_size),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        if D == 2 && tensor.batch_size.is_some() {
+            let (u, s, v) = Self::float_svd(to_matrices::<B, D>(tensor));
+            return (
+                from_matrices::<B, D>(u),
+                from_matrices::<B, D>(s),
+                from_matrices::<B, D>(v),
+            );
+        }
+
+        let batch_size = tensor.batch_size;
+        let (u, s, v) = B::float_svd(tensor.primitive);
+
+        (
+            BatchedTensor::new(u, batch_size),
+            BatchedTensor::new(s, batch_size),
+            BatchedTensor::new(v, batch_size),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        if D == 2 && tensor.batch_size.is_some() {
+            let (values, vectors) = Self::float_eigh(to_matrices::<B, D>(tensor));
+            return (
+                from_matrices::<B, D>(values),
+                from_matrices::<B, D>(vectors),
+            );
+        }
+
+        let batch_size = tensor.batch_size;
+        let (values, vectors) = B::float_eigh(tensor.primitive);
+
+        (
+            BatchedTensor::new(values, batch_size),
+            BatchedTensor::new(vectors, batch_size),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn float_bits<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, 2> {
+        // The bytes of the elements of each example are in consecutive rows.
+        tensor.map(B::float_bits)
+    }
+}
+
+/// Reshapes the matrices of the examples to `[1, m, n]`, so the batch is a batch dimension of the
+/// matrices of the inner backend.
+fn to_matrices<B: Backend, const D: usize>(
+    tensor: FloatTensor<Batched<B>, D>,
+) -> FloatTensor<Batched<B>, 3> {
+    let dims = shape::<B, Float, D>(&tensor).dims;
+
+    reshape::<B, Float, D, 3>(tensor, Shape::new([1, dims[D - 2], dims[D - 1]]))
+}
+
+/// Reverts the reshape of the matrices of the examples to [`[1, m, n]`](to_matrices).
+fn from_matrices<B: Backend, const D: usize>(
+    tensor: FloatTensor<Batched<B>, 3>,
+) -> FloatTensor<Batched<B>, D> {
+    let [_, rows, cols] = shape::<B, Float, 3>(&tensor).dims;
+    let mut dims = [1; D];
+    dims[D - 2] = rows;
+    dims[D - 1] = cols;
+
+    reshape::<B, Float, 3, D>(tensor, Shape::new(dims))
+}
+
+/// Returns the indices gathering, from a tensor of the given shape, the elements selected by the
+/// indices along the dimension, so the examples can select different elements.
+pub(crate) fn select_indices<B: Backend, const D: usize>(
+    shape: Shape<D>,
+    dim: usize,
+    indices: IntTensor<Batched<B>, 1>,
+) -> IntTensor<Batched<B>, D> {
+    let [num_indices] = Batched::<B>::int_shape(&indices).dims;
+    let mut dims = [1; D];
+    dims[dim] = num_indices;
+    let indices = Batched::<B>::int_reshape(indices, Shape::new(dims));
+
+    let mut dims = shape.dims;
+    dims[dim] = num_indices;
+    Batched::<B>::int_expand(indices, Shape::new(dims))
+}
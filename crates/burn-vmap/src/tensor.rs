@@ -0,0 +1,465 @@
+use burn_tensor::{backend::Backend, BasicOps, Shape};
+use core::ops::Range;
+
+/// A tensor of the [batched backend](crate::Batched), holding the values of all the examples of a
+/// batch.
+///
+/// The batch dimension is merged with the first dimension of the examples: a batch of `b`
+/// examples of shape `[d0, d1, ...]` is stored in a tensor of shape `[b * d0, d1, ...]` of the
+/// inner backend, so most operations are applied to the whole batch by the inner backend without
+/// moving the values.
+///
+/// The tensors which don't depend on the examples, e.g. the constants created by a batched
+/// function, aren't batched: they are shared by all the examples, and are only broadcast to the
+/// batch by the operations mixing them with batched tensors.
+#[derive(Clone, Debug)]
+pub struct BatchedTensor<P> {
+    pub(crate) primitive: P,
+    pub(crate) batch_size: Option<usize>,
+}
+
+impl<P> BatchedTensor<P> {
+    pub(crate) fn new(primitive: P, batch_size: Option<usize>) -> Self {
+        Self {
+            primitive,
+            batch_size,
+        }
+    }
+
+    pub(crate) fn unbatched(primitive: P) -> Self {
+        Self::new(primitive, None)
+    }
+
+    /// The number of examples of the batch, or `None` if the tensor is shared by all the examples.
+    pub fn batch_size(&self) -> Option<usize> {
+        self.batch_size
+    }
+
+    /// Returns the tensor of the inner backend of a tensor shared by all the examples.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor is batched: its values depend on the examples, so they can't be read inside
+    /// of a batched function.
+    pub(crate) fn shared(&self) -> &P {
+        assert!(
+            self.batch_size.is_none(),
+            "The values of a batched tensor can't be read inside of a batched function."
+        );
+
+        &self.primitive
+    }
+
+    /// Applies an operation which doesn't change the first dimension of the examples, e.g. an
+    /// element-wise operation, to all the examples at once.
+    pub(crate) fn map<O>(self, op: impl FnOnce(P) -> O) -> BatchedTensor<O> {
+        BatchedTensor::new(op(self.primitive), self.batch_size)
+    }
+}
+
+/// Returns the shape of the examples of the tensor.
+pub(crate) fn shape<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: &BatchedTensor<K::Primitive<D>>,
+) -> Shape<D> {
+    let mut shape = K::shape(&tensor.primitive);
+    if let Some(batch_size) = tensor.batch_size {
+        shape.dims[0] /= batch_size;
+    }
+
+    shape
+}
+
+/// Returns the batch size of the batched tensors, or `None` if none of them is batched.
+///
+/// # Panics
+///
+/// If the batched tensors don't have the same batch size.
+pub(crate) fn batch_size(batch_sizes: &[Option<usize>]) -> Option<usize> {
+    let mut output = None;
+    for batch_size in batch_sizes.iter().flatten() {
+        match output {
+            Some(output) => assert_eq!(
+                output, *batch_size,
+                "The batched tensors should have the same batch size."
+            ),
+            None => output = Some(*batch_size),
+        }
+    }
+
+    output
+}
+
+/// Returns the size of a dimension broadcast between tensors, the one different from 1 if any.
+pub(crate) fn broadcast_dim(sizes: &[usize]) -> usize {
+    sizes.iter().copied().find(|size| *size != 1).unwrap_or(1)
+}
+
+/// Returns the values of the tensor for all the examples of a batch of the given size, the first
+/// dimension of the examples having the given size.
+///
+/// The tensor is broadcast if it isn't batched, or if the first dimension of its examples has a
+/// size of 1.
+pub(crate) fn broadcast<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: BatchedTensor<K::Primitive<D>>,
+    batch_size: usize,
+    dim: usize,
+) -> K::Primitive<D> {
+    let dims = shape::<B, K, D>(&tensor).dims;
+    if tensor.batch_size == Some(batch_size) && dims[0] == dim {
+        return tensor.primitive;
+    }
+
+    let rest = dims[1..].iter().product();
+    let values = K::reshape::<D, 3>(
+        tensor.primitive,
+        Shape::new([tensor.batch_size.unwrap_or(1), dims[0], rest]),
+    );
+    let values = K::expand::<3, 3>(values, Shape::new([batch_size, dim, rest]));
+
+    let mut dims = dims;
+    dims[0] = batch_size * dim;
+    K::reshape::<3, D>(values, Shape::new(dims))
+}
+
+/// Returns the values of the tensor for all the examples of a batch of the given size, keeping
+/// the shape of its examples.
+pub(crate) fn examples<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: BatchedTensor<K::Primitive<D>>,
+    batch_size: usize,
+) -> K::Primitive<D> {
+    let dim = shape::<B, K, D>(&tensor).dims[0];
+
+    broadcast::<B, K, D>(tensor, batch_size, dim)
+}
+
+/// Views the values of a batch as a tensor of shape `[batch_size, d0, rest]`, so operations along
+/// the first dimension of the examples are applied along the second dimension of the view.
+pub(crate) fn view_first_dim<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: K::Primitive<D>,
+    batch_size: usize,
+) -> K::Primitive<3> {
+    let dims = K::shape(&tensor).dims;
+    let rest = dims[1..].iter().product();
+
+    K::reshape::<D, 3>(tensor, Shape::new([batch_size, dims[0] / batch_size, rest]))
+}
+
+/// Reverts a [view](view_first_dim) of the values of a batch, whose second dimension may have a
+/// different size than the first dimension of the examples, the other dimensions being the ones
+/// of the given values.
+pub(crate) fn unview_first_dim<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: K::Primitive<3>,
+    mut dims: [usize; D],
+) -> K::Primitive<D> {
+    let [batch_size, dim, _] = K::shape(&tensor).dims;
+    dims[0] = batch_size * dim;
+
+    K::reshape::<3, D>(tensor, Shape::new(dims))
+}
+
+/// Applies an operation broadcasting its operands, e.g. an element-wise operation, to two
+/// tensors.
+pub(crate) fn binary<B: Backend, K: BasicOps<B>, const D: usize, O>(
+    lhs: BatchedTensor<K::Primitive<D>>,
+    rhs: BatchedTensor<K::Primitive<D>>,
+    op: impl FnOnce(K::Primitive<D>, K::Primitive<D>) -> O,
+) -> BatchedTensor<O> {
+    let Some(batch_size) = batch_size(&[lhs.batch_size, rhs.batch_size]) else {
+        return BatchedTensor::unbatched(op(lhs.primitive, rhs.primitive));
+    };
+
+    let dims = [
+        shape::<B, K, D>(&lhs).dims[0],
+        shape::<B, K, D>(&rhs).dims[0],
+    ];
+    let dim = broadcast_dim(&dims);
+
+    // A shared operand whose first dimension has a size of 1 is broadcast by the operation.
+    let operand = |tensor: BatchedTensor<K::Primitive<D>>, size: usize| match tensor.batch_size {
+        None if size == 1 => tensor.primitive,
+        _ => broadcast::<B, K, D>(tensor, batch_size, dim),
+    };
+    let lhs = operand(lhs, dims[0]);
+    let rhs = operand(rhs, dims[1]);
+
+    BatchedTensor::new(op(lhs, rhs), Some(batch_size))
+}
+
+/// Applies a reduction of all the elements of each example, given the reduction of all the
+/// elements of a tensor, and the reduction along a dimension of a tensor of rank 2, applied to
+/// the batched tensors viewed as `[batch_size, num_elements]`.
+pub(crate) fn reduce<B: Backend, K: BasicOps<B>, K2: BasicOps<B>, const D: usize>(
+    tensor: BatchedTensor<K::Primitive<D>>,
+    reduce: impl FnOnce(K::Primitive<D>) -> K2::Primitive<1>,
+    reduce_dim: impl FnOnce(K::Primitive<2>, usize) -> K2::Primitive<2>,
+) -> BatchedTensor<K2::Primitive<1>> {
+    let Some(batch_size) = tensor.batch_size else {
+        return BatchedTensor::unbatched(reduce(tensor.primitive));
+    };
+
+    let num_elements = shape::<B, K, D>(&tensor).num_elements();
+    let values = K::reshape::<D, 2>(tensor.primitive, Shape::new([batch_size, num_elements]));
+    let output = reduce_dim(values, 1);
+
+    BatchedTensor::new(
+        K2::reshape::<2, 1>(output, Shape::new([batch_size])),
+        Some(batch_size),
+    )
+}
+
+/// Applies an operation along a dimension of the examples, given the operation on a tensor, and
+/// on a tensor of rank 3, applied along the second dimension of the [view](view_first_dim) of the
+/// batched tensors when the operation is along the first dimension of the examples.
+pub(crate) fn along_dim<B: Backend, K: BasicOps<B>, K2: BasicOps<B>, const D: usize>(
+    tensor: BatchedTensor<K::Primitive<D>>,
+    dim: usize,
+    op: impl FnOnce(K::Primitive<D>, usize) -> K2::Primitive<D>,
+    op_3d: impl FnOnce(K::Primitive<3>, usize) -> K2::Primitive<3>,
+) -> BatchedTensor<K2::Primitive<D>> {
+    match tensor.batch_size {
+        Some(batch_size) if dim == 0 => {
+            let dims = K::shape(&tensor.primitive).dims;
+            let view = view_first_dim::<B, K, D>(tensor.primitive, batch_size);
+            let output = op_3d(view, 1);
+
+            BatchedTensor::new(unview_first_dim::<B, K2, D>(output, dims), Some(batch_size))
+        }
+        _ => tensor.map(|tensor| op(tensor, dim)),
+    }
+}
+
+/// Reshapes the examples of the tensor.
+pub(crate) fn reshape<B: Backend, K: BasicOps<B>, const D1: usize, const D2: usize>(
+    tensor: BatchedTensor<K::Primitive<D1>>,
+    shape: Shape<D2>,
+) -> BatchedTensor<K::Primitive<D2>> {
+    let mut dims = shape.dims;
+    if let Some(batch_size) = tensor.batch_size {
+        dims[0] *= batch_size;
+    }
+
+    tensor.map(|tensor| K::reshape::<D1, D2>(tensor, Shape::new(dims)))
+}
+
+/// Swaps two dimensions of the examples.
+pub(crate) fn swap_dims<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: BatchedTensor<K::Primitive<D>>,
+    dim1: usize,
+    dim2: usize,
+) -> BatchedTensor<K::Primitive<D>> {
+    let (dim1, dim2) = (dim1.min(dim2), dim1.max(dim2));
+
+    match tensor.batch_size {
+        Some(batch_size) if dim1 == 0 && dim2 != 0 => {
+            // The batch is viewed as `[batch_size, d0, before, d_dim2, after]`, so the first
+            // dimension of the examples is swapped with `dim2` without mixing the examples.
+            let mut dims = shape::<B, K, D>(&tensor).dims;
+            let before = dims[1..dim2].iter().product();
+            let after = dims[dim2 + 1..].iter().product();
+            let view = K::reshape::<D, 5>(
+                tensor.primitive,
+                Shape::new([batch_size, dims[0], before, dims[dim2], after]),
+            );
+            let view = K::swap_dims(view, 1, 3);
+
+            dims.swap(0, dim2);
+            dims[0] *= batch_size;
+            BatchedTensor::new(K::reshape::<5, D>(view, Shape::new(dims)), Some(batch_size))
+        }
+        _ => tensor.map(|tensor| K::swap_dims(tensor, dim1, dim2)),
+    }
+}
+
+/// Permutes the dimensions of the examples.
+pub(crate) fn permute<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: BatchedTensor<K::Primitive<D>>,
+    axes: [usize; D],
+) -> BatchedTensor<K::Primitive<D>> {
+    if tensor.batch_size.is_none() || axes[0] == 0 {
+        return tensor.map(|tensor| K::permute(tensor, axes));
+    }
+
+    // The dimension moved first is swapped with the first dimension of the examples, then the
+    // other dimensions are permuted with the batch, which stays first.
+    let first = axes[0];
+    let tensor = swap_dims::<B, K, D>(tensor, 0, first);
+    let axes = axes.map(|axis| match axis {
+        0 => first,
+        axis if axis == first => 0,
+        axis => axis,
+    });
+
+    tensor.map(|tensor| K::permute(tensor, axes))
+}
+
+/// Reverses the order of the elements of the examples along the given axes.
+pub(crate) fn flip<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: BatchedTensor<K::Primitive<D>>,
+    axes: &[usize],
+) -> BatchedTensor<K::Primitive<D>> {
+    let Some(batch_size) = tensor.batch_size else {
+        return tensor.map(|tensor| K::flip(tensor, axes));
+    };
+
+    let others: Vec<_> = axes.iter().copied().filter(|axis| *axis != 0).collect();
+    let mut output = match others.is_empty() {
+        true => tensor.primitive,
+        false => K::flip(tensor.primitive, &others),
+    };
+
+    if others.len() < axes.len() {
+        let dims = K::shape(&output).dims;
+        let view = K::flip(view_first_dim::<B, K, D>(output, batch_size), &[1]);
+        output = unview_first_dim::<B, K, D>(view, dims);
+    }
+
+    BatchedTensor::new(output, Some(batch_size))
+}
+
+/// Returns a slice of the examples.
+pub(crate) fn slice<B: Backend, K: BasicOps<B>, const D1: usize, const D2: usize>(
+    tensor: BatchedTensor<K::Primitive<D1>>,
+    ranges: [Range<usize>; D2],
+) -> BatchedTensor<K::Primitive<D1>> {
+    let Some(batch_size) = tensor.batch_size else {
+        return tensor.map(|tensor| K::slice(tensor, ranges));
+    };
+
+    // The other dimensions are sliced first, then the first dimension of each example.
+    let dim = shape::<B, K, D1>(&tensor).dims[0];
+    let range = ranges.first().cloned().unwrap_or(0..dim);
+    let mut others = ranges;
+    if let Some(first) = others.first_mut() {
+        *first = 0..batch_size * dim;
+    }
+    let output = K::slice(tensor.primitive, others);
+
+    if range == (0..dim) {
+        return BatchedTensor::new(output, Some(batch_size));
+    }
+
+    let dims = K::shape(&output).dims;
+    let view = view_first_dim::<B, K, D1>(output, batch_size);
+    let rest = K::shape(&view).dims[2];
+    let view = K::slice(view, [0..batch_size, range, 0..rest]);
+
+    BatchedTensor::new(unview_first_dim::<B, K, D1>(view, dims), Some(batch_size))
+}
+
+/// Assigns the value to a slice of the examples.
+pub(crate) fn slice_assign<B: Backend, K: BasicOps<B>, const D1: usize, const D2: usize>(
+    tensor: BatchedTensor<K::Primitive<D1>>,
+    ranges: [Range<usize>; D2],
+    value: BatchedTensor<K::Primitive<D1>>,
+) -> BatchedTensor<K::Primitive<D1>> {
+    let Some(batch_size) = batch_size(&[tensor.batch_size, value.batch_size]) else {
+        return BatchedTensor::unbatched(K::slice_assign(
+            tensor.primitive,
+            ranges,
+            value.primitive,
+        ));
+    };
+
+    let dim = shape::<B, K, D1>(&tensor).dims[0];
+    let tensor = examples::<B, K, D1>(tensor, batch_size);
+    let value = examples::<B, K, D1>(value, batch_size);
+    let range = ranges.first().cloned().unwrap_or(0..dim);
+    let mut others = ranges;
+
+    if range == (0..dim) {
+        if let Some(first) = others.first_mut() {
+            *first = 0..batch_size * dim;
+        }
+
+        return BatchedTensor::new(K::slice_assign(tensor, others, value), Some(batch_size));
+    }
+
+    // The rows of the slice are taken from each example, assigned the value along the other
+    // dimensions, then assigned back to the examples.
+    let dims = K::shape(&tensor).dims;
+    let view = view_first_dim::<B, K, D1>(tensor, batch_size);
+    let rest = K::shape(&view).dims[2];
+    let view_ranges = [0..batch_size, range.clone(), 0..rest];
+    let rows = K::slice(view.clone(), view_ranges.clone());
+
+    let rows = unview_first_dim::<B, K, D1>(rows, dims);
+    others[0] = 0..batch_size * range.len();
+    let rows = K::slice_assign(rows, others, value);
+
+    let view = K::slice_assign(
+        view,
+        view_ranges,
+        view_first_dim::<B, K, D1>(rows, batch_size),
+    );
+
+    BatchedTensor::new(unview_first_dim::<B, K, D1>(view, dims), Some(batch_size))
+}
+
+/// Repeats the examples along the given dimension.
+pub(crate) fn repeat<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: BatchedTensor<K::Primitive<D>>,
+    dim: usize,
+    times: usize,
+) -> BatchedTensor<K::Primitive<D>> {
+    along_dim::<B, K, K, D>(
+        tensor,
+        dim,
+        |tensor, dim| K::repeat(tensor, dim, times),
+        |tensor, dim| K::repeat(tensor, dim, times),
+    )
+}
+
+/// Concatenates the examples of the tensors along the given dimension.
+pub(crate) fn cat<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensors: Vec<BatchedTensor<K::Primitive<D>>>,
+    dim: usize,
+) -> BatchedTensor<K::Primitive<D>> {
+    let batch_sizes: Vec<_> = tensors.iter().map(|tensor| tensor.batch_size).collect();
+    let Some(batch_size) = batch_size(&batch_sizes) else {
+        let tensors = tensors.into_iter().map(|tensor| tensor.primitive).collect();
+        return BatchedTensor::unbatched(K::cat(tensors, dim));
+    };
+
+    let tensors: Vec<_> = tensors
+        .into_iter()
+        .map(|tensor| examples::<B, K, D>(tensor, batch_size))
+        .collect();
+
+    if dim > 0 {
+        return BatchedTensor::new(K::cat(tensors, dim), Some(batch_size));
+    }
+
+    let dims = K::shape(&tensors[0]).dims;
+    let views = tensors
+        .into_iter()
+        .map(|tensor| view_first_dim::<B, K, D>(tensor, batch_size))
+        .collect();
+
+    BatchedTensor::new(
+        unview_first_dim::<B, K, D>(K::cat(views, 1), dims),
+        Some(batch_size),
+    )
+}
+
+/// Broadcasts the examples to the given shape.
+pub(crate) fn expand<B: Backend, K: BasicOps<B>, const D1: usize, const D2: usize>(
+    tensor: BatchedTensor<K::Primitive<D1>>,
+    target: Shape<D2>,
+) -> BatchedTensor<K::Primitive<D2>> {
+    let Some(batch_size) = tensor.batch_size else {
+        return tensor.map(|tensor| K::expand(tensor, target));
+    };
+
+    // The dimensions added by the broadcast are prepended to the examples, not to the batch.
+    let mut dims = [1; D2];
+    dims[D2 - D1..].copy_from_slice(&shape::<B, K, D1>(&tensor).dims);
+    let tensor = reshape::<B, K, D1, D2>(tensor, Shape::new(dims));
+    let tensor = broadcast::<B, K, D2>(tensor, batch_size, target.dims[0]);
+
+    let mut dims = target.dims;
+    dims[0] *= batch_size;
+    BatchedTensor::new(
+        K::expand::<D2, D2>(tensor, Shape::new(dims)),
+        Some(batch_size),
+    )
+}
@@ -0,0 +1,465 @@
+use crate::{tensor::examples, Batched, BatchedTensor};
+use burn_tensor::{backend::Backend, BasicOps, Bool, Float, Int, Shape, Tensor, TensorKind};
+
+/// A tensor kind whose tensors can be [batched](Batched).
+pub trait BatchKind<B: Backend>: BasicOps<B> + BasicOps<Batched<B>> {
+    /// Converts the values of the examples of a batch of the given size, or of a tensor shared by
+    /// all the examples if the size is `None`, to a tensor of the batched backend.
+    fn into_batched<const D: usize>(
+        tensor: <Self as TensorKind<B>>::Primitive<D>,
+        batch_size: Option<usize>,
+    ) -> <Self as TensorKind<Batched<B>>>::Primitive<D>;
+
+    /// Converts a tensor of the batched backend to the values of its examples.
+    fn from_batched<const D: usize>(
+        tensor: <Self as TensorKind<Batched<B>>>::Primitive<D>,
+    ) -> BatchedTensor<<Self as TensorKind<B>>::Primitive<D>>;
+}
+
+macro_rules! batch_kind {
+    ($kind:ident) => {
+        impl<B: Backend> BatchKind<B> for $kind {
+            fn into_batched<const D: usize>(
+                tensor: <Self as TensorKind<B>>::Primitive<D>,
+                batch_size: Option<usize>,
+            ) -> <Self as TensorKind<Batched<B>>>::Primitive<D> {
+                BatchedTensor::new(tensor, batch_size)
+            }
+
+            fn from_batched<const D: usize>(
+                tensor: <Self as TensorKind<Batched<B>>>::Primitive<D>,
+            ) -> BatchedTensor<<Self as TensorKind<B>>::Primitive<D>> {
+                tensor
+            }
+        }
+    };
+}
+
+batch_kind!(Float);
+batch_kind!(Int);
+batch_kind!(Bool);
+
+/// Lifts a function of single examples to a function of batches of examples, the first dimension
+/// of the batched inputs and outputs being the batch dimension.
+///
+/// The function is called once, with a tensor of the [batched backend](Batched) holding all the
+/// examples, so each of its operations is applied to the whole batch at once by the inner
+/// backend. The examples never interact, e.g. for per-sample computations that would otherwise mix
+/// the examples of a batch, and the tensors created by the function, e.g. constants or the
+/// parameters of a module of the batched backend, are shared by all the examples.
+///
+/// The values of the examples can't be read inside of the function, e.g. with
+/// [into_data](Tensor::into_data), since they depend on the example. The random tensors created by
+/// the function are sampled once, and shared by all the examples.
+///
+/// # Panics
+///
+/// When the function is lifted, if `DB` isn't equal to `D + 1` or `OB` isn't equal to `O + 1`, and
+/// when the batched function is called with an empty batch.
+///
+/// # Examples
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::Tensor;
+/// use burn_vmap::vmap;
+///
+/// fn example<B: Backend>(batch: Tensor<B, 3>) -> Tensor<B, 3> {
+///     // The outer product of each row vector with itself, summed over its rows.
+///     let batched = vmap(|vector: Tensor<_, 2>| {
+///         let outer = vector.clone().transpose().matmul(vector);
+///         outer.sum_dim(0)
+///     });
+///
+///     batched(batch)
+/// }
+/// ```
+pub fn vmap<B, F, K, K2, const D: usize, const DB: usize, const O: usize, const OB: usize>(
+    function: F,
+) -> impl Fn(Tensor<B, DB, K>) -> Tensor<B, OB, K2>
+where
+    B: Backend,
+    K: BatchKind<B>,
+    K2: BatchKind<B>,
+    F: Fn(Tensor<Batched<B>, D, K>) -> Tensor<Batched<B>, O, K2>,
+{
+    assert_eq!(
+        DB,
+        D + 1,
+        "The batched inputs should have one more dimension than the examples."
+    );
+    assert_eq!(
+        OB,
+        O + 1,
+        "The batched outputs should have one more dimension than the outputs of the examples."
+    );
+
+    move |batch: Tensor<B, DB, K>| {
+        let batch_size = batch.dims()[0];
+        assert!(batch_size > 0, "The batch should contain examples.");
+
+        unbatch(function(self::batch(batch)), batch_size)
+    }
+}
+
+/// Converts a batch of examples, whose first dimension is the batch dimension, to a tensor of the
+/// [batched backend](Batched) holding the examples.
+///
+/// # Panics
+///
+/// If `DB` isn't equal to `D + 1`.
+pub fn batch<B, K, const D: usize, const DB: usize>(
+    tensor: Tensor<B, DB, K>,
+) -> Tensor<Batched<B>, D, K>
+where
+    B: Backend,
+    K: BatchKind<B>,
+{
+    assert_eq!(
+        DB,
+        D + 1,
+        "The batched inputs should have one more dimension than the examples."
+    );
+
+    // The batch dimension is merged with the first dimension of the examples.
+    let dims = tensor.dims();
+    let mut shape = [0; D];
+    shape.copy_from_slice(&dims[1..]);
+    shape[0] *= dims[0];
+    let primitive =
+        <K as BasicOps<B>>::reshape::<DB, D>(tensor.into_primitive(), Shape::new(shape));
+
+    Tensor::from_primitive(K::into_batched(primitive, Some(dims[0])))
+}
+
+/// Converts a tensor of the [batched backend](Batched) to a batch of the given size, whose first
+/// dimension is the batch dimension.
+///
+/// A tensor shared by all the examples, e.g. an output which doesn't depend on the inputs, is
+/// repeated for each example.
+///
+/// # Panics
+///
+/// If `OB` isn't equal to `O + 1`, or if the tensor is a batch of a different size.
+pub fn unbatch<B, K, const O: usize, const OB: usize>(
+    tensor: Tensor<Batched<B>, O, K>,
+    batch_size: usize,
+) -> Tensor<B, OB, K>
+where
+    B: Backend,
+    K: BatchKind<B>,
+{
+    assert_eq!(
+        OB,
+        O + 1,
+        "The batched outputs should have one more dimension than the outputs of the examples."
+    );
+
+    let values = values_of_examples::<B, K, O>(tensor, batch_size);
+    let dims = <K as BasicOps<B>>::shape(&values).dims;
+    let mut shape = [batch_size; OB];
+    shape[1..].copy_from_slice(&dims);
+    shape[1] /= batch_size;
+
+    Tensor::from_primitive(<K as BasicOps<B>>::reshape::<O, OB>(
+        values,
+        Shape::new(shape),
+    ))
+}
+
+/// Splits a tensor of the [batched backend](Batched) into the values of each example of a batch of
+/// the given size, e.g. to read the per-sample gradients of a parameter shared by the examples.
+///
+/// A tensor shared by all the examples is repeated for each example.
+///
+/// # Panics
+///
+/// If the tensor is a batch of a different size.
+pub fn split_examples<B, K, const D: usize>(
+    tensor: Tensor<Batched<B>, D, K>,
+    batch_size: usize,
+) -> Vec<Tensor<B, D, K>>
+where
+    B: Backend,
+    K: BatchKind<B>,
+{
+    let values = values_of_examples::<B, K, D>(tensor, batch_size);
+
+    Tensor::from_primitive(values).chunk(batch_size, 0)
+}
+
+/// Returns the values of the examples of a batch of the given size, stacked along their first
+/// dimension.
+fn values_of_examples<B, K, const D: usize>(
+    tensor: Tensor<Batched<B>, D, K>,
+    batch_size: usize,
+) -> <K as TensorKind<B>>::Primitive<D>
+where
+    B: Backend,
+    K: BatchKind<B>,
+{
+    let tensor = K::from_batched(tensor.into_primitive());
+    if let Some(size) = tensor.batch_size {
+        assert_eq!(
+            size, batch_size,
+            "The tensor is a batch of a different size."
+        );
+    }
+
+    examples::<B, K, D>(tensor, batch_size)
+}
+
+/// Converts a tensor to a tensor of the [batched backend](Batched) shared by all the examples,
+/// e.g. to use a tensor created outside of a [batched function](vmap).
+pub fn shared<B, K, const D: usize>(tensor: Tensor<B, D, K>) -> Tensor<Batched<B>, D, K>
+where
+    B: Backend,
+    K: BatchKind<B>,
+{
+    Tensor::from_primitive(K::into_batched(tensor.into_primitive(), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_autodiff::Autodiff;
+    use burn_ndarray::NdArray;
+    use burn_tensor::{module::conv2d, ops::ConvOptions, Data, Distribution};
+
+    type TestBackend = NdArray<f32>;
+
+    /// Applies the function to each example of the batch and stacks the outputs.
+    fn per_example<const D: usize, const DB: usize, const O: usize, const OB: usize>(
+        batch: Tensor<TestBackend, DB>,
+        function: impl Fn(Tensor<TestBackend, D>) -> Tensor<TestBackend, O>,
+    ) -> Tensor<TestBackend, OB> {
+        let outputs = (0..batch.dims()[0])
+            .map(|index| function(batch.clone().narrow(0, index, 1).squeeze::<D>(0)).unsqueeze())
+            .collect();
+
+        Tensor::cat(outputs, 0)
+    }
+
+    fn random<const D: usize>(shape: [usize; D]) -> Tensor<TestBackend, D> {
+        Tensor::random(shape, Distribution::Default, &Default::default())
+    }
+
+    #[test]
+    fn should_apply_element_wise_operations_with_constants() {
+        let batch = random([3, 2, 4]);
+        let function = |x: Tensor<Batched<TestBackend>, 2>| {
+            let constant = Tensor::from_floats([[1.0, 2.0, 3.0, 4.0]], &x.device());
+            x.clone().exp().mul(constant).add(x).div_scalar(2.0)
+        };
+
+        let output: Tensor<TestBackend, 3> = vmap(function)(batch.clone());
+
+        let expected = per_example(batch, |x: Tensor<TestBackend, 2>| {
+            let constant = Tensor::from_floats([[1.0, 2.0, 3.0, 4.0]], &x.device());
+            x.clone().exp().mul(constant).add(x).div_scalar(2.0)
+        });
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn should_reduce_each_example() {
+        let batch = random([4, 3, 2]);
+
+        let sum: Tensor<TestBackend, 2> =
+            vmap(|x: Tensor<Batched<TestBackend>, 2>| x.sum())(batch.clone());
+        let max: Tensor<TestBackend, 2> =
+            vmap(|x: Tensor<Batched<TestBackend>, 2>| x.max())(batch.clone());
+        let sum_dim: Tensor<TestBackend, 3> =
+            vmap(|x: Tensor<Batched<TestBackend>, 2>| x.sum_dim(0))(batch.clone());
+
+        let expected_sum = per_example(batch.clone(), |x: Tensor<TestBackend, 2>| x.sum());
+        let expected_max = per_example(batch.clone(), |x: Tensor<TestBackend, 2>| x.max());
+        let expected_sum_dim = per_example(batch, |x: Tensor<TestBackend, 2>| x.sum_dim(0));
+        assert_eq!(sum.dims(), [4, 1]);
+        sum.into_data()
+            .assert_approx_eq(&expected_sum.into_data(), 4);
+        max.into_data()
+            .assert_approx_eq(&expected_max.into_data(), 4);
+        sum_dim
+            .into_data()
+            .assert_approx_eq(&expected_sum_dim.into_data(), 4);
+    }
+
+    #[test]
+    fn should_apply_operations_along_the_first_dimension_of_the_examples() {
+        let batch = random([2, 4, 3]);
+        let function = |x: Tensor<Batched<TestBackend>, 2>| {
+            let flipped = x.clone().flip([0]).swap_dims(0, 1);
+            let sliced = x.clone().slice([1..3]).transpose();
+            let repeated = x.clone().repeat(0, 2).slice([3..6]).transpose();
+            let assigned = x.slice_assign([0..2, 1..2], Tensor::ones([2, 1], &Default::default()));
+
+            Tensor::cat(vec![flipped, sliced, repeated, assigned.transpose()], 1)
+        };
+
+        let output: Tensor<TestBackend, 3> = vmap(function)(batch.clone());
+
+        let expected = per_example(batch, |x: Tensor<TestBackend, 2>| {
+            let flipped = x.clone().flip([0]).swap_dims(0, 1);
+            let sliced = x.clone().slice([1..3]).transpose();
+            let repeated = x.clone().repeat(0, 2).slice([3..6]).transpose();
+            let assigned = x.slice_assign([0..2, 1..2], Tensor::ones([2, 1], &Default::default()));
+
+            Tensor::cat(vec![flipped, sliced, repeated, assigned.transpose()], 1)
+        });
+        assert_eq!(output.dims(), [2, 3, 4 + 2 + 3 + 4]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn should_gather_and_select_with_the_indices_of_each_example() {
+        let batch = random([3, 4, 2]);
+        let function = |x: Tensor<Batched<TestBackend>, 2>| {
+            let indices = x.clone().sum_dim(1).squeeze::<1>(1).argsort(0);
+            let selected = x.clone().select(0, indices.clone());
+            let gathered = x.gather(0, indices.unsqueeze_dim::<2>(1).repeat(1, 2));
+
+            selected.add(gathered.mul_scalar(2.0))
+        };
+
+        let output: Tensor<TestBackend, 3> = vmap(function)(batch.clone());
+
+        let expected = per_example(batch, |x: Tensor<TestBackend, 2>| {
+            let indices = x.clone().sum_dim(1).squeeze::<1>(1).argsort(0);
+            let selected = x.clone().select(0, indices.clone());
+            let gathered = x.gather(0, indices.unsqueeze_dim::<2>(1).repeat(1, 2));
+
+            selected.add(gathered.mul_scalar(2.0))
+        });
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn should_multiply_with_shared_and_batched_matrices() {
+        let batch = random([3, 2, 4]);
+        let weight = random([4, 5]);
+        let weight_batched = shared(weight.clone());
+        let function = |x: Tensor<Batched<TestBackend>, 2>| {
+            let projected = x.clone().matmul(weight_batched.clone());
+            let gram = x.clone().matmul(x.transpose());
+
+            gram.matmul(projected)
+        };
+
+        let output: Tensor<TestBackend, 3> = vmap(function)(batch.clone());
+
+        let expected = per_example(batch, |x: Tensor<TestBackend, 2>| {
+            let projected = x.clone().matmul(weight.clone());
+            let gram = x.clone().matmul(x.transpose());
+
+            gram.matmul(projected)
+        });
+        assert_eq!(output.dims(), [3, 2, 5]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_convolve_each_example_with_its_own_weights() {
+        let inputs = random([2, 3, 2, 5, 5]);
+        let weights = random([2, 4, 2, 3, 3]);
+        let bias = random([4]);
+        let options = ConvOptions::new([1, 1], [1, 1], [1, 1], 1);
+
+        let output: Tensor<TestBackend, 5> = unbatch(
+            conv2d(
+                batch::<_, _, 4, 5>(inputs.clone()),
+                batch::<_, _, 4, 5>(weights.clone()),
+                Some(shared(bias.clone())),
+                options.clone(),
+            ),
+            2,
+        );
+
+        let expected: Tensor<TestBackend, 5> = Tensor::stack(
+            (0..2)
+                .map(|index| {
+                    let input = inputs.clone().narrow(0, index, 1).squeeze::<4>(0);
+                    let weight = weights.clone().narrow(0, index, 1).squeeze::<4>(0);
+                    conv2d(input, weight, Some(bias.clone()), options.clone())
+                })
+                .collect(),
+            0,
+        );
+        assert_eq!(output.dims(), [2, 3, 4, 5, 5]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_repeat_the_outputs_shared_by_the_examples() {
+        let batch = random([3, 2]);
+
+        let output: Tensor<TestBackend, 2> = vmap(|x: Tensor<Batched<TestBackend>, 1>| {
+            Tensor::<Batched<TestBackend>, 1>::ones([2], &x.device())
+        })(batch);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0], [1.0, 1.0], [1.0, 1.0]]), 4);
+    }
+
+    #[test]
+    fn should_split_the_examples_of_a_batch() {
+        let inputs = random([3, 2, 2]);
+
+        let examples = split_examples(batch::<_, Float, 2, 3>(inputs.clone()), 3);
+
+        assert_eq!(examples.len(), 3);
+        for (index, example) in examples.into_iter().enumerate() {
+            let expected = inputs.clone().narrow(0, index, 1).squeeze::<2>(0);
+            example
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 4);
+        }
+    }
+
+    #[test]
+    fn should_compute_per_sample_gradients_in_a_single_backward_pass() {
+        type AutodiffBackend = Autodiff<Batched<TestBackend>>;
+        let inputs = random([3, 4]);
+        let device = Default::default();
+        let weight =
+            Tensor::<AutodiffBackend, 2>::from_floats([[1.0], [2.0], [3.0], [4.0]], &device)
+                .require_grad();
+
+        let x = Tensor::<AutodiffBackend, 1>::from_inner(batch::<_, _, 1, 2>(inputs.clone()));
+        let loss = x
+            .unsqueeze::<2>()
+            .matmul(weight.clone())
+            .powf_scalar(2.0)
+            .sum();
+        let grads = loss.backward();
+        let grad: Tensor<TestBackend, 3> = unbatch(weight.grad(&grads).unwrap(), 3);
+
+        // The gradient of `(x w)^2` is `2 (x w) x`.
+        let expected = per_example(inputs, |x: Tensor<TestBackend, 1>| {
+            let weight = Tensor::from_floats([[1.0], [2.0], [3.0], [4.0]], &x.device());
+            let product = x.clone().unsqueeze::<2>().matmul(weight).sum();
+            x.mul(product.mul_scalar(2.0)).unsqueeze_dim::<2>(1)
+        });
+        grad.into_data().assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic = "The values of a batched tensor can't be read inside of a batched function."]
+    fn should_panic_when_reading_the_values_of_a_batched_tensor() {
+        let _: Tensor<TestBackend, 2> = vmap(|x: Tensor<Batched<TestBackend>, 1>| {
+            let _ = x.to_data();
+            x
+        })(random([2, 3]));
+    }
+}
@@ -0,0 +1,74 @@
+use super::Variable;
+use std::fmt::Display;
+
+#[derive(Debug, Clone)]
+#[allow(dead_code, missing_docs)] // Some variants might not be used with different flags
+pub enum Atomic {
+    Load {
+        array: Variable,
+        index: Variable,
+        out: Variable,
+    },
+    Store {
+        array: Variable,
+        index: Variable,
+        value: Variable,
+    },
+    Binary {
+        function: &'static str,
+        array: Variable,
+        index: Variable,
+        value: Variable,
+        out: Variable,
+    },
+    CompareAndSwap {
+        array: Variable,
+        index: Variable,
+        cmp: Variable,
+        value: Variable,
+        out: Variable,
+    },
+}
+
+impl Display for Atomic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Atomic::Load { array, index, out } => {
+                f.write_fmt(format_args!("{out} = atomicLoad(&{array}[{index}]);\n"))
+            }
+            Atomic::Store {
+                array,
+                index,
+                value,
+            } => f.write_fmt(format_args!("atomicStore(&{array}[{index}], {value});\n")),
+            Atomic::Binary {
+                function,
+                array,
+                index,
+                value,
+                out,
+            } => f.write_fmt(format_args!(
+                "{out} = {function}(&{array}[{index}], {value});\n"
+            )),
+            // The weak exchange can fail even when the values are equal, so it is retried until
+            // it succeeds or the values differ.
+            Atomic::CompareAndSwap {
+                array,
+                index,
+                cmp,
+                value,
+                out,
+            } => f.write_fmt(format_args!(
+                "
+loop {{
+    let result = atomicCompareExchangeWeak(&{array}[{index}], {cmp}, {value});
+    if result.exchanged || result.old_value != {cmp} {{
+        {out} = result.old_value;
+        break;
+    }}
+}}
+"
+            )),
+        }
+    }
+}
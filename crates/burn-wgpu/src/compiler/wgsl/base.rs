@@ -49,6 +49,8 @@ pub enum Elem {
     I32,
     U32,
     Bool,
+    AtomicI32,
+    AtomicU32,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
@@ -180,6 +182,8 @@ impl Elem {
             Self::I32 => core::mem::size_of::<i32>(),
             Self::U32 => core::mem::size_of::<u32>(),
             Self::Bool => core::mem::size_of::<bool>(),
+            Self::AtomicI32 => core::mem::size_of::<i32>(),
+            Self::AtomicU32 => core::mem::size_of::<u32>(),
         }
     }
 }
@@ -191,6 +195,8 @@ impl Display for Elem {
             Self::I32 => f.write_str("i32"),
             Self::U32 => f.write_str("u32"),
             Self::Bool => f.write_str("bool"),
+            Self::AtomicI32 => f.write_str("atomic<i32>"),
+            Self::AtomicU32 => f.write_str("atomic<u32>"),
         }
     }
 }
@@ -230,8 +236,8 @@ impl Display for Variable {
             }
             Variable::ConstantScalar(number, elem) => match elem {
                 Elem::F32 => f.write_fmt(format_args!("{number}f")),
-                Elem::I32 => f.write_fmt(format_args!("{number}i")),
-                Elem::U32 => f.write_fmt(format_args!("{number}u")),
+                Elem::I32 | Elem::AtomicI32 => f.write_fmt(format_args!("{number}i")),
+                Elem::U32 | Elem::AtomicU32 => f.write_fmt(format_args!("{number}u")),
                 Elem::Bool => f.write_fmt(format_args!("bool({number})")),
             },
             Variable::SharedMemory(number, _, _) => {
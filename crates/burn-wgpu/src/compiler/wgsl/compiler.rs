@@ -122,6 +122,11 @@ impl WgslCompiler {
             },
             cube::Elem::UInt => wgsl::Elem::U32,
             cube::Elem::Bool => wgsl::Elem::Bool,
+            cube::Elem::AtomicInt(i) => match i {
+                cube::IntKind::I32 => wgsl::Elem::AtomicI32,
+                cube::IntKind::I64 => panic!("atomic<i64> is not a valid WgpuElement"),
+            },
+            cube::Elem::AtomicUInt => wgsl::Elem::AtomicU32,
         }
     }
 
@@ -277,9 +282,51 @@ impl WgslCompiler {
                 self.compile_synchronization(instructions, val)
             }
             cube::Operation::Subcube(op) => self.compile_subgroup(instructions, op),
+            cube::Operation::Atomic(op) => self.compile_atomic(instructions, op),
         }
     }
 
+    fn compile_atomic(&mut self, instructions: &mut Vec<wgsl::Instruction>, atomic: cube::Atomic) {
+        let mut binary =
+            |function: &'static str, op: cube::AtomicBinaryOperator| wgsl::Atomic::Binary {
+                function,
+                array: self.compile_variable(op.array),
+                index: self.compile_variable(op.index),
+                value: self.compile_variable(op.value),
+                out: self.compile_variable(op.out),
+            };
+
+        let op = match atomic {
+            cube::Atomic::Swap(op) => binary("atomicExchange", op),
+            cube::Atomic::Add(op) => binary("atomicAdd", op),
+            cube::Atomic::Sub(op) => binary("atomicSub", op),
+            cube::Atomic::Max(op) => binary("atomicMax", op),
+            cube::Atomic::Min(op) => binary("atomicMin", op),
+            cube::Atomic::And(op) => binary("atomicAnd", op),
+            cube::Atomic::Or(op) => binary("atomicOr", op),
+            cube::Atomic::Xor(op) => binary("atomicXor", op),
+            cube::Atomic::Load(op) => wgsl::Atomic::Load {
+                array: self.compile_variable(op.array),
+                index: self.compile_variable(op.index),
+                out: self.compile_variable(op.out),
+            },
+            cube::Atomic::Store(op) => wgsl::Atomic::Store {
+                array: self.compile_variable(op.array),
+                index: self.compile_variable(op.index),
+                value: self.compile_variable(op.value),
+            },
+            cube::Atomic::CompareAndSwap(op) => wgsl::Atomic::CompareAndSwap {
+                array: self.compile_variable(op.array),
+                index: self.compile_variable(op.index),
+                cmp: self.compile_variable(op.cmp),
+                value: self.compile_variable(op.value),
+                out: self.compile_variable(op.out),
+            },
+        };
+
+        instructions.push(wgsl::Instruction::Atomic(op));
+    }
+
     fn compile_subgroup(
         &mut self,
         instructions: &mut Vec<wgsl::Instruction>,
@@ -640,6 +687,10 @@ impl WgslCompiler {
                 rhs: self.compile_variable(op.rhs),
                 out: self.compile_variable(op.out),
             },
+            cube::Operator::Bitcast(op) => wgsl::Instruction::Bitcast {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
         }
     }
 
@@ -1,6 +1,6 @@
 use super::{
     base::{Item, Variable},
-    Subgroup,
+    Atomic, Subgroup,
 };
 use std::fmt::Display;
 
@@ -226,7 +226,12 @@ pub enum Instruction {
         rhs: Variable,
         out: Variable,
     },
+    Bitcast {
+        input: Variable,
+        out: Variable,
+    },
     Subgroup(Subgroup),
+    Atomic(Atomic),
 }
 
 impl Display for Instruction {
@@ -487,7 +492,12 @@ for (var {i}: u32 = {start}; {i} < {end}; {i}++) {{
             Instruction::Ceil { input, out } => {
                 f.write_fmt(format_args!("{out} = ceil({input});\n"))
             }
+            Instruction::Bitcast { input, out } => {
+                let item = out.item();
+                f.write_fmt(format_args!("{out} = bitcast<{item}>({input});\n"))
+            }
             Instruction::Subgroup(op) => f.write_fmt(format_args!("{op}")),
+            Instruction::Atomic(op) => f.write_fmt(format_args!("{op}")),
         }
     }
 }
@@ -1,3 +1,4 @@
+mod atomic;
 mod base;
 mod body;
 mod compiler;
@@ -6,6 +7,7 @@ mod instructions;
 mod shader;
 mod subgroup;
 
+pub(crate) use atomic::*;
 pub(crate) use base::*;
 pub(crate) use body::*;
 pub use compiler::*;
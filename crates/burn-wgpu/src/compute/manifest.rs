@@ -0,0 +1,102 @@
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+/// The kernels compiled by a [wgpu server](super::WgpuServer), to compile them again ahead of time.
+///
+/// The first execution of a kernel compiles its shader, which can stall the first frames of a
+/// game or a GUI app. Exporting the manifest of the kernels used by a model, e.g. after running
+/// it once at build time, lets the shaders be [precompiled](crate::precompile) at startup.
+///
+/// The shaders are specialized for the inputs they were compiled for, so the model should be run
+/// with the same shapes and element types at build time and in the application.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct KernelManifest {
+    /// The kernels, sorted by id.
+    pub kernels: Vec<ManifestKernel>,
+}
+
+/// A kernel of a [manifest](KernelManifest).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ManifestKernel {
+    /// The id of the kernel, including its specialization.
+    pub id: String,
+    /// The WGSL source of the shader.
+    pub source: String,
+}
+
+impl KernelManifest {
+    /// Serialize the manifest to JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("The manifest should be serialized to JSON")
+    }
+
+    /// Deserialize a manifest from JSON, e.g. the content of a manifest embedded with
+    /// [include_str].
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Add the kernels of the other manifest that aren't already in this one, e.g. to precompile
+    /// the kernels of multiple models.
+    pub fn merge(&mut self, other: KernelManifest) {
+        for kernel in other.kernels {
+            if let Err(index) = self
+                .kernels
+                .binary_search_by(|existing| existing.id.cmp(&kernel.id))
+            {
+                self.kernels.insert(index, kernel);
+            }
+        }
+    }
+
+    /// The number of kernels in the manifest.
+    pub fn len(&self) -> usize {
+        self.kernels.len()
+    }
+
+    /// If the manifest has no kernels.
+    pub fn is_empty(&self) -> bool {
+        self.kernels.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kernel(id: &str) -> ManifestKernel {
+        ManifestKernel {
+            id: id.into(),
+            source: "@compute @workgroup_size(1) fn main() {}".into(),
+        }
+    }
+
+    #[test]
+    fn manifests_should_merge_sorted_kernels() {
+        let mut manifest = KernelManifest {
+            kernels: vec![kernel("a"), kernel("c")],
+        };
+
+        manifest.merge(KernelManifest {
+            kernels: vec![kernel("b"), kernel("c")],
+        });
+
+        let ids = manifest
+            .kernels
+            .iter()
+            .map(|kernel| kernel.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn manifest_should_be_serialized_to_json() {
+        let manifest = KernelManifest {
+            kernels: vec![kernel("a")],
+        };
+
+        let json = manifest.to_json();
+
+        assert_eq!(KernelManifest::from_json(&json).unwrap(), manifest);
+    }
+}
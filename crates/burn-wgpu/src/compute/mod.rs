@@ -1,5 +1,7 @@
+mod manifest;
 mod server;
 mod storage;
 
+pub use manifest::*;
 pub use server::*;
 pub use storage::*;
@@ -1,15 +1,24 @@
 use std::num::NonZeroU64;
 
-use super::WgpuStorage;
+use super::{KernelManifest, ManifestKernel, WgpuResource, WgpuStorage};
 use alloc::{borrow::Cow, sync::Arc};
 use burn_compute::{
     memory_management::MemoryManagement,
-    server::{self, ComputeServer},
+    server::{self, ComputeServer, ServerError},
+    storage::StorageHint,
 };
+use burn_cube::compute::KernelProfiler;
 use burn_cube::prelude::*;
 use burn_jit::JitAutotuneKey;
-use burn_tensor::{backend::SyncType, Reader};
+use burn_tensor::{
+    backend::{MemoryUsage, SyncType},
+    Reader,
+};
 use hashbrown::HashMap;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
 use wgpu::{
     util::{BufferInitDescriptor, DeviceExt, StagingBelt},
     BindGroup, CommandEncoder, ComputePipeline, ShaderModuleDescriptor,
@@ -29,12 +38,59 @@ pub struct WgpuServer<MM: MemoryManagement<WgpuStorage>> {
     encoder: CommandEncoder,
     staging_belt: StagingBelt,
     pipelines: HashMap<String, Arc<ComputePipeline>>,
+    sources: HashMap<String, String>,
     tasks_max: usize,
     tasks_count: usize,
+    staging: StagingPolicy,
+    staging_pending: usize,
+    staging_in_flight: Arc<AtomicUsize>,
+    lost: Arc<Mutex<Option<String>>>,
+    recovery: Option<DeviceRecovery>,
+    persistent: Vec<(server::Handle<Self>, Vec<u8>)>,
 }
 
 const SMALL_ALLOC_SIZE: usize = 256;
 
+// Uploads up to this size are kept on the host when the device can be recovered, since the client
+// pools them as the info and scalars of kernels, which are never uploaded again.
+const MAX_RECOVERED_UPLOAD_SIZE: usize = 256;
+
+/// Bounds the host memory of the staging buffers holding uploads until the device copies them.
+///
+/// Uploads are only submitted to the device with the next batch of tasks, so uploading a large
+/// dataset without launching kernels would keep every staging buffer alive. Instead, the uploads
+/// are submitted once they reach the [flush size](StagingPolicy::flush_size), and the server
+/// waits for the device to copy them once the staging buffers reach the
+/// [budget](StagingPolicy::budget), throttling the uploads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StagingPolicy {
+    /// The size in bytes of the pending uploads that are submitted to the device without waiting
+    /// for the next batch of tasks.
+    pub flush_size: usize,
+    /// The maximum size in bytes of the uploads that are not yet copied by the device.
+    pub budget: usize,
+}
+
+impl Default for StagingPolicy {
+    fn default() -> Self {
+        Self {
+            flush_size: 64 * 1024 * 1024,
+            budget: 512 * 1024 * 1024,
+        }
+    }
+}
+
+/// Creates the device and the queue replacing a lost device.
+struct DeviceRecovery {
+    create_device: Box<dyn Fn() -> Result<(Arc<wgpu::Device>, Arc<wgpu::Queue>), String> + Send>,
+}
+
+impl core::fmt::Debug for DeviceRecovery {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("DeviceRecovery")
+    }
+}
+
 impl<MM> WgpuServer<MM>
 where
     MM: MemoryManagement<WgpuStorage>,
@@ -45,10 +101,13 @@ where
         device: Arc<wgpu::Device>,
         queue: Arc<wgpu::Queue>,
         tasks_max: usize,
+        staging: StagingPolicy,
     ) -> Self {
         let encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("Command Encoder"),
         });
+        let lost = Arc::new(Mutex::new(None));
+        watch_device_lost(&device, lost.clone());
 
         Self {
             memory_management,
@@ -57,11 +116,108 @@ where
             encoder,
             staging_belt: StagingBelt::new(SMALL_ALLOC_SIZE as u64),
             pipelines: HashMap::new(),
+            sources: HashMap::new(),
             tasks_max,
             tasks_count: 0,
+            staging,
+            staging_pending: 0,
+            staging_in_flight: Arc::new(AtomicUsize::new(0)),
+            lost,
+            recovery: None,
+            persistent: Vec::new(),
         }
     }
 
+    /// Recover the device once it is lost, by replacing it with the one returned by
+    /// `create_device`.
+    ///
+    /// The device is recovered before the next task. The buffers of the existing handles are
+    /// created again on the new device, so the handles stay valid, and the data of the
+    /// [persistent](Self::register_persistent) handles is uploaded again. The content of the
+    /// other buffers and the tasks submitted since the last synchronization are lost.
+    ///
+    /// When no device can be created, the device stays lost and the recovery is tried again
+    /// before the next task.
+    pub fn enable_recovery<F>(&mut self, create_device: F)
+    where
+        F: Fn() -> Result<(Arc<wgpu::Device>, Arc<wgpu::Queue>), String> + Send + 'static,
+    {
+        self.recovery = Some(DeviceRecovery {
+            create_device: Box::new(create_device),
+        });
+    }
+
+    /// Keep a host copy of the data of a handle, uploaded again when the device is recovered.
+    ///
+    /// The copy replaces the previous one of the same handle. It keeps a reference to the handle,
+    /// so its memory isn't reused in place, until the handle is no longer used elsewhere and the
+    /// copy is released. Nothing is kept when the [recovery](Self::enable_recovery) isn't
+    /// enabled.
+    pub fn register_persistent(&mut self, handle: server::Handle<Self>, data: Vec<u8>) {
+        if self.recovery.is_none() {
+            return;
+        }
+
+        let resource = self.memory_management.get(handle.clone().binding().memory);
+        let memory_management = &mut self.memory_management;
+
+        self.persistent.retain(|(existing, _)| {
+            let existing = memory_management.get(existing.clone().binding().memory);
+            !Arc::ptr_eq(&existing.buffer, &resource.buffer)
+                || existing.offset() != resource.offset()
+        });
+        self.persistent.push((handle, data));
+    }
+
+    fn recover_if_lost(&mut self) {
+        if self.recovery.is_some() && self.lost.lock().unwrap().is_some() {
+            self.recover();
+        }
+    }
+
+    fn recover(&mut self) {
+        let reason = self.lost.lock().unwrap().clone().unwrap_or_default();
+        log::warn!("Recovering the lost wgpu device: {reason}");
+
+        let recovery = self.recovery.as_ref().unwrap();
+        let (device, queue) = match (recovery.create_device)() {
+            Ok(created) => created,
+            Err(err) => {
+                log::error!("Unable to recover the lost wgpu device: {err}");
+                return;
+            }
+        };
+        self.lost.lock().unwrap().take();
+        watch_device_lost(&device, self.lost.clone());
+
+        self.memory_management.storage().recreate(device.clone());
+        self.encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Command Encoder"),
+        });
+        self.staging_belt = StagingBelt::new(SMALL_ALLOC_SIZE as u64);
+        // The sources are kept, the kernels being compiled again on the new device when used.
+        self.pipelines.clear();
+        self.tasks_count = 0;
+        // The uploads of the lost device are never completed.
+        self.staging_pending = 0;
+        self.staging_in_flight = Arc::new(AtomicUsize::new(0));
+        self.device = device;
+        self.queue = queue;
+
+        self.release_persistent();
+        for (handle, data) in self.persistent.iter() {
+            let resource = self.memory_management.get(handle.clone().binding().memory);
+            self.queue
+                .write_buffer(&resource.buffer, resource.offset(), data);
+        }
+    }
+
+    /// Release the host copies of the handles that are no longer used elsewhere.
+    fn release_persistent(&mut self) {
+        // The copy holds the only reference besides the memory management.
+        self.persistent.retain(|(handle, _)| !handle.can_mut());
+    }
+
     fn register_compute(
         &mut self,
         label: Option<&str>,
@@ -83,6 +239,55 @@ where
         self.tasks_count += 1;
     }
 
+    fn register_compute_indirect(
+        &mut self,
+        label: Option<&str>,
+        pipeline: Arc<ComputePipeline>,
+        bind_group: BindGroup,
+        cube_count: WgpuResource,
+    ) {
+        let mut compute = self
+            .encoder
+            .begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label,
+                timestamp_writes: None,
+            });
+
+        compute.set_pipeline(&pipeline);
+        compute.set_bind_group(0, &bind_group, &[]);
+        compute.dispatch_workgroups_indirect(&cube_count.buffer, cube_count.offset());
+
+        self.tasks_count += 1;
+    }
+
+    fn bind_group(
+        &mut self,
+        pipeline: &ComputePipeline,
+        bindings: Vec<server::Binding<Self>>,
+    ) -> BindGroup {
+        let group_layout = pipeline.get_bind_group_layout(0);
+
+        let memory_handles = bindings
+            .into_iter()
+            .map(|binding| self.memory_management.get(binding.memory))
+            .collect::<Vec<_>>();
+
+        let entries = memory_handles
+            .iter()
+            .enumerate()
+            .map(|(i, buffer)| wgpu::BindGroupEntry {
+                binding: i as u32,
+                resource: buffer.as_binding(),
+            })
+            .collect::<Vec<_>>();
+
+        self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &group_layout,
+            entries: &entries,
+        })
+    }
+
     fn pipeline(&mut self, kernel: Box<dyn CubeTask>) -> Arc<ComputePipeline> {
         let kernel_id = kernel.id();
 
@@ -94,10 +299,41 @@ where
         let pipeline = self.compile_source(&compile.source);
 
         self.pipelines.insert(kernel_id.clone(), pipeline.clone());
+        self.sources.insert(kernel_id, compile.source);
 
         pipeline
     }
 
+    /// The [manifest](KernelManifest) of the kernels compiled so far.
+    pub fn kernel_manifest(&self) -> KernelManifest {
+        let mut kernels = self
+            .sources
+            .iter()
+            .map(|(id, source)| ManifestKernel {
+                id: id.clone(),
+                source: source.clone(),
+            })
+            .collect::<Vec<_>>();
+        kernels.sort_by(|a, b| a.id.cmp(&b.id));
+
+        KernelManifest { kernels }
+    }
+
+    /// Compile the kernels of the manifest that aren't compiled yet, so their first execution
+    /// doesn't wait for their shader to be compiled.
+    pub fn precompile(&mut self, manifest: &KernelManifest) {
+        for kernel in manifest.kernels.iter() {
+            if self.pipelines.contains_key(&kernel.id) {
+                continue;
+            }
+
+            let pipeline = self.compile_source(&kernel.source);
+            self.pipelines.insert(kernel.id.clone(), pipeline);
+            self.sources
+                .insert(kernel.id.clone(), kernel.source.clone());
+        }
+    }
+
     fn compile_source(&self, source: &str) -> Arc<ComputePipeline> {
         let module = self.device.create_shader_module(ShaderModuleDescriptor {
             label: None,
@@ -116,10 +352,35 @@ where
         )
     }
 
+    /// Submit or wait for the uploads, following the [staging policy](StagingPolicy).
+    fn throttle_staging(&mut self) {
+        if self.staging_pending >= self.staging.flush_size {
+            self.sync(SyncType::Flush);
+        }
+
+        let staging_size = self.staging_pending + self.staging_in_flight.load(Ordering::Relaxed);
+
+        if staging_size > self.staging.budget {
+            self.sync(SyncType::Wait);
+        }
+    }
+
     fn buffer_reader(&mut self, handle: server::Binding<Self>) -> BufferReader {
         let resource = self.memory_management.get(handle.memory);
 
         let size = resource.size();
+
+        // Host-mapped buffers are read directly, without a copy to a staging buffer.
+        if resource
+            .buffer
+            .usage()
+            .contains(wgpu::BufferUsages::MAP_READ)
+        {
+            self.sync(SyncType::Flush);
+
+            return BufferReader::new(resource.buffer, resource.offset(), size);
+        }
+
         let buffer_dest = self.device.create_buffer(&wgpu::BufferDescriptor {
             label: None,
             size,
@@ -138,7 +399,21 @@ where
 
         self.sync(SyncType::Flush);
 
-        BufferReader::new(buffer_dest)
+        BufferReader::new(Arc::new(buffer_dest), 0, size)
+    }
+
+    /// Register an existing [buffer](wgpu::Buffer) of the device, of which the first `size` bytes
+    /// are used, without copying its content.
+    ///
+    /// The buffer is never reused for other allocations, and is released once the returned handle
+    /// is no longer referenced.
+    pub fn register_buffer(
+        &mut self,
+        buffer: Arc<wgpu::Buffer>,
+        size: usize,
+    ) -> server::Handle<Self> {
+        let storage = self.memory_management.storage().register(buffer, size);
+        server::Handle::new(self.memory_management.register(storage))
     }
 
     pub fn get_resource_binding(&mut self, binding: server::Binding<Self>) -> WgpuResource {
@@ -154,9 +429,26 @@ where
     }
 }
 
+/// Record the reason of the loss of the device.
+fn watch_device_lost(device: &wgpu::Device, lost: Arc<Mutex<Option<String>>>) {
+    device.set_device_lost_callback(move |reason, message| {
+        // The device isn't lost when it is dropped, or when the callback is replaced.
+        if matches!(
+            reason,
+            wgpu::DeviceLostReason::Dropped | wgpu::DeviceLostReason::ReplacedCallback
+        ) {
+            return;
+        }
+
+        *lost.lock().unwrap() = Some(format!("{reason:?}, {message}"));
+    });
+}
+
 #[derive(new)]
 struct BufferReader {
-    buffer: wgpu::Buffer,
+    buffer: Arc<wgpu::Buffer>,
+    offset: u64,
+    size: u64,
 }
 
 impl BufferReader {
@@ -171,7 +463,7 @@ impl BufferReader {
     }
 
     async fn read_async(&self, device: &wgpu::Device) -> Vec<u8> {
-        let buffer_slice = self.buffer.slice(..);
+        let buffer_slice = self.buffer.slice(self.offset..self.offset + self.size);
         let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
         buffer_slice.map_async(wgpu::MapMode::Read, move |v| {
             sender
@@ -206,6 +498,8 @@ where
     type AutotuneKey = JitAutotuneKey;
 
     fn read(&mut self, binding: server::Binding<Self>) -> Reader<Vec<u8>> {
+        self.recover_if_lost();
+
         #[cfg(target_family = "wasm")]
         {
             let future = self.buffer_reader(binding).read(self.device.clone());
@@ -229,6 +523,8 @@ where
     /// This is important, otherwise the compute passes are going to be too small and we won't be able to
     /// fully utilize the GPU.
     fn create(&mut self, data: &[u8]) -> server::Handle<Self> {
+        self.recover_if_lost();
+
         let handle = server::Handle::new(self.memory_management.reserve(data.len()));
         let non_zero_len = NonZeroU64::new(data.len() as u64);
 
@@ -263,10 +559,17 @@ where
                     resource.offset(),
                     buffer_src.size(),
                 );
+                self.staging_pending += data.len();
             }
             self.tasks_count += 1;
         }
 
+        if self.recovery.is_some() && data.len() <= MAX_RECOVERED_UPLOAD_SIZE {
+            self.persistent.push((handle.clone(), data.to_vec()));
+        }
+
+        self.throttle_staging();
+
         handle
     }
 
@@ -274,34 +577,42 @@ where
         server::Handle::new(self.memory_management.reserve(size))
     }
 
+    fn empty_with_hint(&mut self, size: usize, hint: StorageHint) -> server::Handle<Self> {
+        server::Handle::new(self.memory_management.reserve_with_hint(size, hint))
+    }
+
     fn execute(&mut self, kernel: Self::Kernel, bindings: Vec<server::Binding<Self>>) {
+        self.recover_if_lost();
+        KernelProfiler::record(&kernel);
+
         let work_group = kernel.launch_settings().cube_count;
         let label = kernel.label();
 
         let pipeline = self.pipeline(kernel);
-        let group_layout = pipeline.get_bind_group_layout(0);
+        let bind_group = self.bind_group(&pipeline, bindings);
 
-        let memory_handles = bindings
-            .into_iter()
-            .map(|binding| self.memory_management.get(binding.memory))
-            .collect::<Vec<_>>();
+        self.register_compute(label, pipeline, bind_group, work_group);
 
-        let entries = memory_handles
-            .iter()
-            .enumerate()
-            .map(|(i, buffer)| wgpu::BindGroupEntry {
-                binding: i as u32,
-                resource: buffer.as_binding(),
-            })
-            .collect::<Vec<_>>();
+        if self.tasks_count >= self.tasks_max {
+            self.sync(SyncType::Flush);
+        }
+    }
 
-        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &group_layout,
-            entries: &entries,
-        });
+    /// The cube count is read from the binding by the dispatch, so no data goes back to the host.
+    fn execute_indirect(
+        &mut self,
+        kernel: Self::Kernel,
+        cube_count: server::Binding<Self>,
+        bindings: Vec<server::Binding<Self>>,
+    ) {
+        self.recover_if_lost();
+        let label = kernel.label();
 
-        self.register_compute(label, pipeline, bind_group, work_group);
+        let pipeline = self.pipeline(kernel);
+        let bind_group = self.bind_group(&pipeline, bindings);
+        let cube_count = self.memory_management.get(cube_count.memory);
+
+        self.register_compute_indirect(label, pipeline, bind_group, cube_count);
 
         if self.tasks_count >= self.tasks_max {
             self.sync(SyncType::Flush);
@@ -309,6 +620,8 @@ where
     }
 
     fn sync(&mut self, sync_type: SyncType) {
+        self.recover_if_lost();
+
         // Flush commands to the queue.
         self.staging_belt.finish();
 
@@ -320,8 +633,19 @@ where
         self.queue.submit(Some(new_encoder.finish()));
         self.tasks_count = 0;
 
+        // The staging buffers of the submitted uploads are released once they are copied.
+        let submitted = core::mem::take(&mut self.staging_pending);
+        if submitted > 0 {
+            let in_flight = self.staging_in_flight.clone();
+            in_flight.fetch_add(submitted, Ordering::Relaxed);
+            self.queue.on_submitted_work_done(move || {
+                in_flight.fetch_sub(submitted, Ordering::Relaxed);
+            });
+        }
+
         // Cleanup allocations and deallocations.
         self.memory_management.storage().perform_deallocations();
+        self.release_persistent();
 
         self.staging_belt.recall();
 
@@ -329,4 +653,137 @@ where
             self.device.poll(wgpu::Maintain::Wait);
         }
     }
+
+    fn memory_usage(&mut self) -> MemoryUsage {
+        self.memory_management.memory_usage()
+    }
+
+    fn run_custom_command(&mut self, f: impl Fn(&mut Self) + Send) {
+        f(self);
+    }
+
+    fn status(&mut self) -> Result<(), ServerError> {
+        match self.lost.lock().unwrap().as_ref() {
+            Some(reason) => Err(ServerError::DeviceLost(reason.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{select_device, AutoGraphicsApi, WgpuDevice};
+    use burn_compute::memory_management::simple::{
+        DeallocStrategy, SimpleMemoryManagement, SliceStrategy,
+    };
+
+    fn create_device() -> (Arc<wgpu::Device>, Arc<wgpu::Queue>) {
+        let (device, queue, _) =
+            pollster::block_on(select_device::<AutoGraphicsApi>(&WgpuDevice::default()));
+        (Arc::new(device), Arc::new(queue))
+    }
+
+    fn try_create_device() -> Result<(Arc<wgpu::Device>, Arc<wgpu::Queue>), String> {
+        Ok(create_device())
+    }
+
+    fn create_server(staging: StagingPolicy) -> WgpuServer<SimpleMemoryManagement<WgpuStorage>> {
+        let (device, queue) = create_device();
+        let storage = WgpuStorage::new(device.clone());
+        let memory_management =
+            SimpleMemoryManagement::new(storage, DeallocStrategy::Never, SliceStrategy::Never);
+
+        WgpuServer::new(memory_management, device, queue, 16, staging)
+    }
+
+    #[test]
+    fn lost_device_should_be_recovered_with_persistent_data() {
+        let mut server = create_server(StagingPolicy::default());
+        server.enable_recovery(try_create_device);
+
+        // Larger than the uploads kept automatically.
+        let data = bytemuck::cast_slice::<f32, u8>(&[1.0; 128]).to_vec();
+        let handle = server.create(&data);
+        server.register_persistent(handle.clone(), data.clone());
+        server.sync(SyncType::Wait);
+
+        server.device.destroy();
+        server.device.poll(wgpu::Maintain::Poll);
+        assert!(matches!(server.status(), Err(ServerError::DeviceLost(_))));
+
+        let recovered = server.read(handle.binding()).read();
+
+        assert_eq!(recovered, data);
+        assert_eq!(server.status(), Ok(()));
+    }
+
+    #[test]
+    fn lost_device_should_stay_lost_when_it_cannot_be_recovered() {
+        let mut server = create_server(StagingPolicy::default());
+        server.enable_recovery(|| Err("No adapter".to_string()));
+
+        server.device.destroy();
+        server.device.poll(wgpu::Maintain::Poll);
+        server.sync(SyncType::Wait);
+
+        assert!(matches!(server.status(), Err(ServerError::DeviceLost(_))));
+    }
+
+    #[test]
+    fn uploads_should_stay_within_the_staging_budget() {
+        let staging = StagingPolicy {
+            flush_size: 2048,
+            budget: 4096,
+        };
+        let mut server = create_server(staging.clone());
+        let data = vec![1; 1024];
+
+        for _ in 0..16 {
+            server.create(&data);
+
+            assert!(server.staging_pending < staging.flush_size);
+            assert!(
+                server.staging_pending + server.staging_in_flight.load(Ordering::Relaxed)
+                    <= staging.budget
+            );
+        }
+    }
+
+    #[test]
+    fn precompiled_kernels_should_be_in_the_manifest() {
+        let mut server = create_server(StagingPolicy::default());
+        let manifest = KernelManifest {
+            kernels: vec![ManifestKernel {
+                id: "empty".into(),
+                source: "@compute @workgroup_size(1) fn main() {}".into(),
+            }],
+        };
+
+        server.precompile(&manifest);
+
+        assert!(server.pipelines.contains_key("empty"));
+        assert_eq!(server.kernel_manifest(), manifest);
+    }
+
+    #[test]
+    fn host_mapped_buffers_should_be_read_back() {
+        let mut server = create_server(StagingPolicy::default());
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let source = server.create(&data);
+        let mapped = server.empty_with_hint(data.len(), StorageHint::HostMapped);
+
+        let source = server.get_resource(source.binding());
+        let target = server.get_resource(mapped.clone().binding());
+        server.encoder.copy_buffer_to_buffer(
+            &source.buffer,
+            source.offset(),
+            &target.buffer,
+            target.offset(),
+            data.len() as u64,
+        );
+        server.tasks_count += 1;
+
+        assert_eq!(server.read(mapped.binding()).read(), data);
+    }
 }
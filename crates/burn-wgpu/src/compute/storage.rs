@@ -1,10 +1,13 @@
-use burn_compute::storage::{ComputeStorage, StorageHandle, StorageId, StorageUtilization};
-use hashbrown::HashMap;
+use burn_compute::storage::{
+    ComputeStorage, StorageHandle, StorageHint, StorageId, StorageUtilization,
+};
+use hashbrown::{HashMap, HashSet};
 use std::{num::NonZeroU64, sync::Arc};
 
 /// Buffer storage for wgpu.
 pub struct WgpuStorage {
     memory: HashMap<StorageId, Arc<wgpu::Buffer>>,
+    external: HashSet<StorageId>,
     deallocations: Vec<StorageId>,
     device: Arc<wgpu::Device>,
 }
@@ -70,6 +73,7 @@ impl WgpuStorage {
     pub fn new(device: Arc<wgpu::Device>) -> Self {
         Self {
             memory: HashMap::new(),
+            external: HashSet::new(),
             deallocations: Vec::new(),
             device,
         }
@@ -79,10 +83,65 @@ impl WgpuStorage {
     pub fn perform_deallocations(&mut self) {
         for id in self.deallocations.drain(..) {
             if let Some(buffer) = self.memory.remove(&id) {
-                buffer.destroy()
+                // Registered buffers are owned by the caller, so they are only released.
+                if !self.external.remove(&id) {
+                    buffer.destroy()
+                }
             }
         }
     }
+
+    /// Register an existing [buffer](wgpu::Buffer) of the device, of which the first `size` bytes
+    /// are used.
+    ///
+    /// # Panics
+    ///
+    /// When the buffer can't be used as a storage buffer, or is smaller than `size`.
+    pub fn register(&mut self, buffer: Arc<wgpu::Buffer>, size: usize) -> StorageHandle {
+        assert!(
+            buffer.usage().contains(wgpu::BufferUsages::STORAGE),
+            "The buffer should have the storage usage to be used by kernels"
+        );
+        assert!(
+            size as u64 <= buffer.size(),
+            "The buffer of {} bytes is too small to hold {size} bytes",
+            buffer.size()
+        );
+
+        let id = StorageId::new();
+        let utilization = if size as u64 == buffer.size() {
+            StorageUtilization::Full(size)
+        } else {
+            StorageUtilization::Slice { offset: 0, size }
+        };
+
+        self.memory.insert(id.clone(), buffer);
+        self.external.insert(id.clone());
+
+        StorageHandle::new(id, utilization)
+    }
+
+    /// Move the storage to a new [device](wgpu::Device), creating a buffer of the same size for
+    /// every allocated buffer, so the existing handles stay valid.
+    ///
+    /// The content of the buffers is lost. Registered buffers belong to the previous device and
+    /// are kept as is, so they can't be used by kernels anymore.
+    pub fn recreate(&mut self, device: Arc<wgpu::Device>) {
+        self.device = device;
+
+        for (id, buffer) in self.memory.iter_mut() {
+            if self.external.contains(id) {
+                continue;
+            }
+
+            *buffer = Arc::new(self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: None,
+                size: buffer.size(),
+                usage: buffer.usage(),
+                mapped_at_creation: false,
+            }));
+        }
+    }
 }
 
 impl ComputeStorage for WgpuStorage {
@@ -103,13 +162,32 @@ impl ComputeStorage for WgpuStorage {
     }
 
     fn alloc(&mut self, size: usize) -> StorageHandle {
+        self.alloc_with_hint(size, StorageHint::Device)
+    }
+
+    /// Host-mapped buffers can be mapped directly when the device supports the
+    /// [mappable primary buffers](wgpu::Features::MAPPABLE_PRIMARY_BUFFERS), usually integrated
+    /// GPUs. Sparse buffers aren't supported by wgpu, so they are regular buffers.
+    fn alloc_with_hint(&mut self, size: usize, hint: StorageHint) -> StorageHandle {
+        let mut usage = wgpu::BufferUsages::COPY_DST
+            | wgpu::BufferUsages::STORAGE
+            | wgpu::BufferUsages::COPY_SRC
+            | wgpu::BufferUsages::INDIRECT;
+
+        if hint == StorageHint::HostMapped
+            && self
+                .device
+                .features()
+                .contains(wgpu::Features::MAPPABLE_PRIMARY_BUFFERS)
+        {
+            usage |= wgpu::BufferUsages::MAP_READ;
+        }
+
         let id = StorageId::new();
         let buffer = Arc::new(self.device.create_buffer(&wgpu::BufferDescriptor {
             label: None,
             size: size as u64,
-            usage: wgpu::BufferUsages::COPY_DST
-                | wgpu::BufferUsages::STORAGE
-                | wgpu::BufferUsages::COPY_SRC,
+            usage,
             mapped_at_creation: false,
         }));
 
@@ -0,0 +1,90 @@
+use crate::{compute::WgpuResource, GraphicsApi, WgpuDevice, WgpuRuntime};
+use alloc::sync::Arc;
+use burn_cube::Runtime;
+use burn_jit::{element::JitElement, tensor::JitTensor};
+use burn_tensor::{backend::SyncType, Shape};
+use std::sync::Mutex;
+
+/// Wrap an existing [buffer](wgpu::Buffer) as a tensor of the given shape, without copying its
+/// content.
+///
+/// The buffer must have been created on the [device](wgpu::Device) registered with
+/// [init_existing_device](crate::init_existing_device), with the
+/// [storage](wgpu::BufferUsages::STORAGE) usage, and the
+/// [copy source](wgpu::BufferUsages::COPY_SRC) usage to read the tensor back. Its first bytes
+/// hold the elements of the tensor in a contiguous layout.
+///
+/// The tensor uses the buffer as is, so the operations that write inplace modify its content. The
+/// buffer is kept alive by the tensor, and is never destroyed by burn.
+///
+/// # Panics
+///
+/// When the buffer doesn't have the storage usage, or is too small to hold the tensor.
+pub fn tensor_from_buffer<G: GraphicsApi, E: JitElement, const D: usize>(
+    device: &WgpuDevice,
+    buffer: Arc<wgpu::Buffer>,
+    shape: Shape<D>,
+) -> JitTensor<WgpuRuntime<G>, E, D> {
+    let client = WgpuRuntime::<G>::client(device);
+    let size = shape.num_elements() * core::mem::size_of::<E>();
+    let handle = Mutex::new(None);
+
+    client.run_custom_command(|server| {
+        let registered = server.register_buffer(buffer.clone(), size);
+        *handle.lock().unwrap() = Some(registered);
+    });
+
+    let handle = handle
+        .into_inner()
+        .unwrap()
+        .expect("The buffer should be registered");
+
+    JitTensor::new(client, device.clone(), shape, handle)
+}
+
+/// Returns the [buffer](wgpu::Buffer) holding the elements of a tensor, along with the
+/// [offset](WgpuResource::offset) and the [size](WgpuResource::size) of the range of bytes used
+/// by the tensor.
+///
+/// The elements follow the strides of the tensor, which can be made contiguous with
+/// [into_contiguous](burn_jit::kernel::into_contiguous) beforehand. The pending operations of the
+/// device are submitted to the queue, so the work submitted afterward on the same queue sees their
+/// results.
+///
+/// The tensor must be kept alive while the buffer is used, otherwise its memory may be reused by
+/// other tensors.
+pub fn tensor_buffer<G: GraphicsApi, E: JitElement, const D: usize>(
+    tensor: &JitTensor<WgpuRuntime<G>, E, D>,
+) -> WgpuResource {
+    let resource = tensor.client.get_resource(tensor.handle.clone().binding());
+    tensor.client.sync(SyncType::Flush);
+
+    resource
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AutoGraphicsApi, JitBackend};
+    use burn_tensor::Tensor;
+
+    type TestBackend = JitBackend<WgpuRuntime<AutoGraphicsApi>, f32, i32>;
+
+    #[test]
+    fn tensor_from_buffer_should_read_the_content_of_the_buffer() {
+        let device = WgpuDevice::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let resource = tensor_buffer(&tensor.clone().into_primitive());
+        assert_eq!(resource.offset(), 0);
+
+        let wrapped = tensor_from_buffer::<AutoGraphicsApi, f32, 2>(
+            &device,
+            resource.buffer.clone(),
+            Shape::new([2, 2]),
+        );
+
+        Tensor::<TestBackend, 2>::from_primitive(wrapped)
+            .into_data()
+            .assert_eq(&tensor.into_data());
+    }
+}
@@ -8,6 +8,7 @@ mod compute;
 mod device;
 mod element;
 mod graphics;
+mod interop;
 mod runtime;
 
 #[cfg(feature = "template")]
@@ -22,10 +23,12 @@ pub use burn_jit::{
 pub use device::*;
 pub use element::*;
 pub use graphics::*;
+pub use interop::*;
 pub use runtime::*;
 
 pub use burn_cube::prelude::CubeCount;
 pub use burn_jit::{tensor::JitTensor, JitBackend};
+pub use compute::{KernelManifest, ManifestKernel, StagingPolicy, WgpuResource, WgpuResourceKind};
 
 #[cfg(feature = "fusion")]
 /// Tensor backend that uses the [wgpu] crate for executing GPU compute shaders.
@@ -1,12 +1,11 @@
 use crate::{
     compiler::wgsl,
-    compute::{WgpuServer, WgpuStorage},
+    compute::{KernelManifest, StagingPolicy, WgpuServer, WgpuStorage},
     GraphicsApi, WgpuDevice,
 };
 use alloc::sync::Arc;
 use burn_common::stub::RwLock;
 use burn_compute::{
-    channel::MutexComputeChannel,
     client::ComputeClient,
     memory_management::simple::{DeallocStrategy, SimpleMemoryManagement, SliceStrategy},
     tune::Tuner,
@@ -14,10 +13,16 @@ use burn_compute::{
 };
 use burn_cube::Runtime;
 use burn_jit::JitRuntime;
-use burn_tensor::backend::{DeviceId, DeviceOps};
+#[cfg(not(target_family = "wasm"))]
+use burn_jit::{element::JitElement, tensor::JitTensor};
+use burn_tensor::backend::{DeviceCapabilities, DeviceId, DeviceInfo, DeviceOps};
+use hashbrown::HashMap;
 use std::{
     marker::PhantomData,
-    sync::atomic::{AtomicBool, Ordering},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
 };
 use wgpu::{AdapterInfo, DeviceDescriptor};
 
@@ -32,27 +37,43 @@ pub struct WgpuRuntime<G: GraphicsApi> {
 impl<G: GraphicsApi> JitRuntime for WgpuRuntime<G> {
     type JitDevice = WgpuDevice;
     type JitServer = WgpuServer<SimpleMemoryManagement<WgpuStorage>>;
+
+    #[cfg(not(target_family = "wasm"))]
+    fn devices() -> Vec<DeviceInfo<WgpuDevice>> {
+        enumerate_devices::<G>()
+    }
 }
 
 /// The compute instance is shared across all [wgpu runtimes](WgpuRuntime).
-static RUNTIME: ComputeRuntime<WgpuDevice, Server, MutexComputeChannel<Server>> =
-    ComputeRuntime::new();
+static RUNTIME: ComputeRuntime<WgpuDevice, Server, Channel> = ComputeRuntime::new();
 
 type Server = WgpuServer<SimpleMemoryManagement<WgpuStorage>>;
 
+#[cfg(not(feature = "channel-mpsc"))]
+type Channel = burn_compute::channel::MutexComputeChannel<Server>;
+/// Each device has its own server thread, so the threads issuing tasks don't wait for each other.
+#[cfg(feature = "channel-mpsc")]
+type Channel = burn_compute::channel::MpscComputeChannel<Server>;
+
+/// The options each device is initialized with.
+static OPTIONS: Mutex<Option<HashMap<WgpuDevice, RuntimeOptions>>> = Mutex::new(None);
+
 static SUBGROUP: AtomicBool = AtomicBool::new(false);
 
 impl<G: GraphicsApi> Runtime for WgpuRuntime<G> {
     type Compiler = wgsl::WgslCompiler;
     type Server = WgpuServer<SimpleMemoryManagement<WgpuStorage>>;
 
-    type Channel = MutexComputeChannel<WgpuServer<SimpleMemoryManagement<WgpuStorage>>>;
+    type Channel = Channel;
     type Device = WgpuDevice;
 
     fn client(device: &Self::Device) -> ComputeClient<Self::Server, Self::Channel> {
         RUNTIME.client(device, move || {
-            let (adapter, device_wgpu, queue) = pollster::block_on(create_wgpu_setup::<G>(device));
-            create_client(adapter, device_wgpu, queue, RuntimeOptions::default())
+            let options = device_options(device);
+            let (adapter, device_wgpu, queue) =
+                pollster::block_on(create_wgpu_setup::<G>(device, &options.fallback_devices));
+            let recovery = device_recovery::<G>(device, &options);
+            create_client(adapter, device_wgpu, queue, options, recovery)
         })
     }
 
@@ -85,6 +106,7 @@ impl DeviceOps for WgpuDevice {
 }
 
 /// The values that control how a WGPU Runtime will perform its calculations.
+#[derive(Debug, Clone)]
 pub struct RuntimeOptions {
     /// How the buffers are deallocated.
     pub dealloc_strategy: DeallocStrategy,
@@ -92,12 +114,28 @@ pub struct RuntimeOptions {
     pub slice_strategy: SliceStrategy,
     /// Control the amount of compute tasks to be aggregated into a single GPU command.
     pub tasks_max: usize,
+    /// The devices tried in order when the device can't be created, e.g. when no adapter of its
+    /// type is available, and when a lost device is [recovered](Self::device_recovery).
+    /// [Cpu](WgpuDevice::Cpu) falls back to a software adapter when the platform provides one.
+    ///
+    /// To fall back to another backend when no device can be created, initialize the device
+    /// with a [builder](RuntimeOptionsBuilder::init), which returns an error instead of
+    /// panicking, e.g. as done by the device selection of `burn-dyn`.
+    pub fallback_devices: Vec<WgpuDevice>,
+    /// Create the device again when it is lost, e.g. after a driver reset, instead of failing.
+    ///
+    /// The tensors stay valid, but only the ones [registered as persistent](register_persistent)
+    /// keep their data. Recovery isn't available for [existing](WgpuDevice::Existing) devices,
+    /// nor on wasm, where the device can't be created synchronously.
+    pub device_recovery: bool,
+    /// Bound the host memory of the uploads waiting to be copied by the device.
+    pub staging: StagingPolicy,
 }
 
+const DEFAULT_MAX_TASKS: usize = 16;
+
 impl Default for RuntimeOptions {
     fn default() -> Self {
-        const DEFAULT_MAX_TASKS: usize = 16;
-
         let tasks_max = match std::env::var("BURN_WGPU_MAX_TASKS") {
             Ok(value) => value
                 .parse::<usize>()
@@ -105,14 +143,223 @@ impl Default for RuntimeOptions {
             Err(_) => DEFAULT_MAX_TASKS,
         };
 
+        Self::with_tasks_max(tasks_max)
+    }
+}
+
+impl RuntimeOptions {
+    /// Create a [builder](RuntimeOptionsBuilder) to initialize a device with its own options.
+    ///
+    /// Unlike the [default](RuntimeOptions::default) options, the builder doesn't read the
+    /// `BURN_WGPU_MAX_TASKS` environment variable.
+    pub fn builder() -> RuntimeOptionsBuilder {
+        RuntimeOptionsBuilder {
+            options: Self::with_tasks_max(DEFAULT_MAX_TASKS),
+        }
+    }
+
+    fn with_tasks_max(tasks_max: usize) -> Self {
         Self {
             dealloc_strategy: DeallocStrategy::new_period_tick(tasks_max * 2),
             slice_strategy: SliceStrategy::Ratio(0.8),
             tasks_max,
+            fallback_devices: Vec::new(),
+            device_recovery: false,
+            staging: StagingPolicy::default(),
+        }
+    }
+
+    fn is_same(&self, other: &Self) -> bool {
+        let same_dealloc_strategy = match (&self.dealloc_strategy, &other.dealloc_strategy) {
+            (
+                DeallocStrategy::PeriodTick { period, .. },
+                DeallocStrategy::PeriodTick { period: other, .. },
+            ) => period == other,
+            (
+                DeallocStrategy::PeriodTime { period, .. },
+                DeallocStrategy::PeriodTime { period: other, .. },
+            ) => period == other,
+            (DeallocStrategy::Never, DeallocStrategy::Never) => true,
+            _ => false,
+        };
+
+        same_dealloc_strategy
+            && self.slice_strategy == other.slice_strategy
+            && self.tasks_max == other.tasks_max
+            && self.fallback_devices == other.fallback_devices
+            && self.device_recovery == other.device_recovery
+            && self.staging == other.staging
+    }
+}
+
+/// Builder of the [runtime options](RuntimeOptions) of a device, which initializes the device
+/// with them.
+///
+/// # Example
+///
+/// ```no_run
+/// use burn_wgpu::{AutoGraphicsApi, RuntimeOptions, WgpuDevice};
+///
+/// let device = WgpuDevice::DiscreteGpu(0);
+///
+/// RuntimeOptions::builder()
+///     .tasks_max(64)
+///     .init::<AutoGraphicsApi>(&device)
+///     .expect("The device should not be initialized with other options");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuntimeOptionsBuilder {
+    options: RuntimeOptions,
+}
+
+/// Error returned when initializing a device with [runtime options](RuntimeOptions).
+#[derive(Debug)]
+pub enum RuntimeOptionsError {
+    /// The device is already initialized with different options.
+    AlreadyInitialized(WgpuDevice),
+    /// Neither the device nor its fallback devices can be created, for the given reasons.
+    Unavailable(String),
+}
+
+impl core::fmt::Display for RuntimeOptionsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AlreadyInitialized(device) => f.write_fmt(format_args!(
+                "The device {device:?} is already initialized with different runtime options"
+            )),
+            Self::Unavailable(reason) => f.write_str(reason),
+        }
+    }
+}
+
+impl std::error::Error for RuntimeOptionsError {}
+
+impl RuntimeOptionsBuilder {
+    /// Set the amount of compute tasks aggregated into a single GPU command.
+    ///
+    /// The deallocation period is updated to twice the amount of tasks, unless a
+    /// [deallocation strategy](RuntimeOptionsBuilder::dealloc_strategy) is set afterward.
+    pub fn tasks_max(mut self, tasks_max: usize) -> Self {
+        self.options.tasks_max = tasks_max;
+        self.options.dealloc_strategy = DeallocStrategy::new_period_tick(tasks_max * 2);
+        self
+    }
+
+    /// Set how the buffers are deallocated.
+    pub fn dealloc_strategy(mut self, dealloc_strategy: DeallocStrategy) -> Self {
+        self.options.dealloc_strategy = dealloc_strategy;
+        self
+    }
+
+    /// Set the slicing strategy.
+    pub fn slice_strategy(mut self, slice_strategy: SliceStrategy) -> Self {
+        self.options.slice_strategy = slice_strategy;
+        self
+    }
+
+    /// Set the devices tried in order when the device can't be created.
+    pub fn fallback_devices(mut self, devices: Vec<WgpuDevice>) -> Self {
+        self.options.fallback_devices = devices;
+        self
+    }
+
+    /// Set whether the device is created again when it is lost.
+    pub fn device_recovery(mut self, device_recovery: bool) -> Self {
+        self.options.device_recovery = device_recovery;
+        self
+    }
+
+    /// Set how the host memory of the uploads is bounded.
+    pub fn staging(mut self, staging: StagingPolicy) -> Self {
+        self.options.staging = staging;
+        self
+    }
+
+    /// Returns the options.
+    pub fn build(self) -> RuntimeOptions {
+        self.options
+    }
+
+    /// Initialize the device with the options.
+    ///
+    /// Initializing a device again with the same options does nothing, which lets each part of a
+    /// program initialize the devices it uses.
+    ///
+    /// # Errors
+    ///
+    /// When the device is already initialized with different options, including the
+    /// [default](RuntimeOptions::default) ones used when a device is used before being
+    /// initialized, or when neither the device nor its
+    /// [fallback devices](RuntimeOptionsBuilder::fallback_devices) can be created, e.g. to fall
+    /// back to another backend.
+    pub fn init<G: GraphicsApi>(self, device: &WgpuDevice) -> Result<(), RuntimeOptionsError> {
+        pollster::block_on(self.init_async::<G>(device))
+    }
+
+    /// Initialize the device with the options asynchronously, necessary for wasm.
+    ///
+    /// See [init](RuntimeOptionsBuilder::init).
+    pub async fn init_async<G: GraphicsApi>(
+        self,
+        device: &WgpuDevice,
+    ) -> Result<(), RuntimeOptionsError> {
+        if !self.record(device)? {
+            return Ok(());
+        }
+
+        let setup = try_create_wgpu_setup::<G>(device, &self.options.fallback_devices).await;
+        let (adapter, device_wgpu, queue) = match setup {
+            Ok(setup) => setup,
+            Err(err) => {
+                if let Some(options) = OPTIONS.lock().unwrap().as_mut() {
+                    options.remove(device);
+                }
+                return Err(RuntimeOptionsError::Unavailable(err));
+            }
+        };
+
+        let recovery = device_recovery::<G>(device, &self.options);
+        let client = create_client(adapter, device_wgpu, queue, self.options, recovery);
+        RUNTIME.register(device, client);
+
+        Ok(())
+    }
+
+    /// Record the options of the device, and returns whether the device must be initialized.
+    fn record(&self, device: &WgpuDevice) -> Result<bool, RuntimeOptionsError> {
+        let mut options = OPTIONS.lock().unwrap();
+        let options = options.get_or_insert_with(HashMap::new);
+
+        match options.get(device) {
+            Some(existing) if existing.is_same(&self.options) => Ok(false),
+            Some(_) => Err(RuntimeOptionsError::AlreadyInitialized(device.clone())),
+            None => {
+                options.insert(device.clone(), self.options.clone());
+                Ok(true)
+            }
         }
     }
 }
 
+/// The options of the device, recording the default ones when it isn't initialized yet.
+fn device_options(device: &WgpuDevice) -> RuntimeOptions {
+    let mut options = OPTIONS.lock().unwrap();
+
+    options
+        .get_or_insert_with(HashMap::new)
+        .entry(device.clone())
+        .or_default()
+        .clone()
+}
+
+fn record_options(device: &WgpuDevice, options: &RuntimeOptions) {
+    let mut recorded = OPTIONS.lock().unwrap();
+
+    recorded
+        .get_or_insert_with(HashMap::new)
+        .insert(device.clone(), options.clone());
+}
+
 pub fn init_existing_device(
     adapter: Arc<wgpu::Adapter>,
     device: Arc<wgpu::Device>,
@@ -120,36 +367,162 @@ pub fn init_existing_device(
     options: RuntimeOptions,
 ) -> WgpuDevice {
     let device_id = WgpuDevice::Existing(device.as_ref().global_id());
-    let client = create_client(adapter, device, queue, options);
+    record_options(&device_id, &options);
+    if options.device_recovery {
+        log::warn!("The recovery of existing devices isn't supported");
+    }
+    let client = create_client(adapter, device, queue, options, None);
     RUNTIME.register(&device_id, client);
     device_id
 }
 
 /// Init the client sync, useful to configure the runtime options.
 pub fn init_sync<G: GraphicsApi>(device: &WgpuDevice, options: RuntimeOptions) {
-    let (adapter, device_wgpu, queue) = pollster::block_on(create_wgpu_setup::<G>(device));
-    let client = create_client(adapter, device_wgpu, queue, options);
+    record_options(device, &options);
+    let (adapter, device_wgpu, queue) =
+        pollster::block_on(create_wgpu_setup::<G>(device, &options.fallback_devices));
+    let recovery = device_recovery::<G>(device, &options);
+    let client = create_client(adapter, device_wgpu, queue, options, recovery);
     RUNTIME.register(device, client)
 }
 
 /// Init the client async, necessary for wasm.
 pub async fn init_async<G: GraphicsApi>(device: &WgpuDevice, options: RuntimeOptions) {
-    let (adapter, device_wgpu, queue) = create_wgpu_setup::<G>(device).await;
-    let client = create_client(adapter, device_wgpu, queue, options);
+    record_options(device, &options);
+    let (adapter, device_wgpu, queue) =
+        create_wgpu_setup::<G>(device, &options.fallback_devices).await;
+    let recovery = device_recovery::<G>(device, &options);
+    let client = create_client(adapter, device_wgpu, queue, options, recovery);
     RUNTIME.register(device, client)
 }
 
+/// Release the client of the device, after waiting for the completion of its tasks, and returns
+/// whether the device was initialized.
+///
+/// With the `fusion` feature, the fusion clients of the device are released first, executing
+/// their queued operations, since they hold the handles of the tensors.
+///
+/// The wgpu device and its memory are freed once every tensor allocated on it is dropped. The
+/// device can then be initialized again, e.g. with [init_sync] or [init_existing_device], or is
+/// lazily initialized with the default options when used.
+pub fn release(device: &WgpuDevice) -> bool {
+    #[cfg(feature = "fusion")]
+    burn_fusion::release_clients(device);
+
+    if let Some(options) = OPTIONS.lock().unwrap().as_mut() {
+        options.remove(device);
+    }
+
+    RUNTIME.release(device)
+}
+
+/// Release the clients of every initialized device.
+///
+/// See [release].
+pub fn shutdown_all() {
+    #[cfg(feature = "fusion")]
+    burn_fusion::release_all_clients::<WgpuDevice>();
+
+    OPTIONS.lock().unwrap().take();
+    RUNTIME.release_all()
+}
+
+/// Keep a host copy of the tensor, uploaded again when its device is recovered after being lost.
+///
+/// The copy is taken now, so tensors updated afterward, such as the weights of a model during
+/// training, should be registered again after each update. The copy is released once the tensor
+/// is dropped, and the tensor isn't updated in place until then. Nothing is kept when the
+/// [recovery](RuntimeOptions::device_recovery) of the device isn't enabled.
+#[cfg(not(target_family = "wasm"))]
+pub fn register_persistent<G: GraphicsApi, E: JitElement, const D: usize>(
+    tensor: &JitTensor<WgpuRuntime<G>, E, D>,
+) {
+    let data = tensor.client.read(tensor.handle.clone().binding()).read();
+    let handle = tensor.handle.clone();
+
+    tensor
+        .client
+        .run_custom_command(|server| server.register_persistent(handle.clone(), data.clone()));
+}
+
+/// The [manifest](KernelManifest) of the kernels compiled so far on the device, to
+/// [precompile] them when the application starts.
+pub fn kernel_manifest<G: GraphicsApi>(device: &WgpuDevice) -> KernelManifest {
+    let manifest = Mutex::new(KernelManifest::default());
+
+    WgpuRuntime::<G>::client(device)
+        .run_custom_command(|server| *manifest.lock().unwrap() = server.kernel_manifest());
+
+    manifest.into_inner().unwrap()
+}
+
+/// Compile the kernels of the [manifest](KernelManifest) on the device, e.g. while the
+/// application starts, so that running the model doesn't wait for its shaders to be compiled.
+pub fn precompile<G: GraphicsApi>(device: &WgpuDevice, manifest: &KernelManifest) {
+    WgpuRuntime::<G>::client(device).run_custom_command(|server| server.precompile(manifest));
+}
+
 async fn create_wgpu_setup<G: GraphicsApi>(
     device: &WgpuDevice,
+    fallback_devices: &[WgpuDevice],
 ) -> (Arc<wgpu::Adapter>, Arc<wgpu::Device>, Arc<wgpu::Queue>) {
-    let (device_wgpu, queue, adapter) = select_device::<G>(device).await;
+    try_create_wgpu_setup::<G>(device, fallback_devices)
+        .await
+        .unwrap_or_else(|err| panic!("{err}"))
+}
 
-    log::info!(
-        "Created wgpu compute server on device {:?} => {:?}",
-        device,
-        adapter.get_info()
-    );
-    (Arc::new(adapter), Arc::new(device_wgpu), Arc::new(queue))
+/// Create the device, or the first of the fallback devices that can be created, returning the
+/// reason each device couldn't be created when none can.
+async fn try_create_wgpu_setup<G: GraphicsApi>(
+    device: &WgpuDevice,
+    fallback_devices: &[WgpuDevice],
+) -> Result<(Arc<wgpu::Adapter>, Arc<wgpu::Device>, Arc<wgpu::Queue>), String> {
+    let mut errors = Vec::new();
+
+    for candidate in core::iter::once(device).chain(fallback_devices) {
+        match try_select_device::<G>(candidate).await {
+            Ok((device_wgpu, queue, adapter)) => {
+                log::info!(
+                    "Created wgpu compute server on device {:?} with {:?} => {:?}",
+                    device,
+                    candidate,
+                    adapter.get_info()
+                );
+                return Ok((Arc::new(adapter), Arc::new(device_wgpu), Arc::new(queue)));
+            }
+            Err(err) => {
+                log::warn!("Unable to create the wgpu device {candidate:?}: {err}");
+                errors.push(format!("{candidate:?}: {err}"));
+            }
+        }
+    }
+
+    Err(format!(
+        "Unable to create the wgpu device {device:?} or its fallback devices:\n{}",
+        errors.join("\n")
+    ))
+}
+
+/// Creates the device and the queue replacing a lost device.
+type CreateDevice = Box<dyn Fn() -> Result<(Arc<wgpu::Device>, Arc<wgpu::Queue>), String> + Send>;
+
+/// How the device is created again when it is lost, when enabled by the options.
+fn device_recovery<G: GraphicsApi>(
+    device: &WgpuDevice,
+    options: &RuntimeOptions,
+) -> Option<CreateDevice> {
+    if !options.device_recovery || cfg!(target_family = "wasm") {
+        return None;
+    }
+
+    let device = device.clone();
+    let fallback_devices = options.fallback_devices.clone();
+
+    Some(Box::new(move || {
+        let (_, device_wgpu, queue) =
+            pollster::block_on(try_create_wgpu_setup::<G>(&device, &fallback_devices))?;
+        Ok((device_wgpu, queue))
+    }))
 }
 
 fn create_client(
@@ -157,15 +530,22 @@ fn create_client(
     device_wgpu: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     options: RuntimeOptions,
-) -> ComputeClient<
-    WgpuServer<SimpleMemoryManagement<WgpuStorage>>,
-    MutexComputeChannel<WgpuServer<SimpleMemoryManagement<WgpuStorage>>>,
-> {
+    recovery: Option<CreateDevice>,
+) -> ComputeClient<WgpuServer<SimpleMemoryManagement<WgpuStorage>>, Channel> {
     let storage = WgpuStorage::new(device_wgpu.clone());
     let memory_management =
         SimpleMemoryManagement::new(storage, options.dealloc_strategy, options.slice_strategy);
-    let server = WgpuServer::new(memory_management, device_wgpu, queue, options.tasks_max);
-    let channel = MutexComputeChannel::new(server);
+    let mut server = WgpuServer::new(
+        memory_management,
+        device_wgpu,
+        queue,
+        options.tasks_max,
+        options.staging,
+    );
+    if let Some(recovery) = recovery {
+        server.enable_recovery(recovery);
+    }
+    let channel = Channel::new(server);
     let tuner_device_id = tuner_device_id(adapter.get_info());
 
     ComputeClient::new(
@@ -175,23 +555,30 @@ fn create_client(
 }
 
 /// Select the wgpu device and queue based on the provided [device](WgpuDevice).
+///
+/// # Panics
+///
+/// When no adapter matches the device, or when the device can't be requested.
 pub async fn select_device<G: GraphicsApi>(
     device: &WgpuDevice,
 ) -> (wgpu::Device, wgpu::Queue, wgpu::Adapter) {
+    try_select_device::<G>(device)
+        .await
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+async fn try_select_device<G: GraphicsApi>(
+    device: &WgpuDevice,
+) -> Result<(wgpu::Device, wgpu::Queue, wgpu::Adapter), String> {
     #[cfg(target_family = "wasm")]
-    let adapter = select_adapter::<G>(device).await;
+    let adapter = select_adapter::<G>(device).await?;
 
     #[cfg(not(target_family = "wasm"))]
-    let adapter = select_adapter::<G>(device);
+    let adapter = select_adapter::<G>(device)?;
 
     let limits = adapter.limits();
     let features = adapter.features();
 
-    SUBGROUP.store(
-        features.contains(wgpu::Features::SUBGROUP),
-        Ordering::Relaxed,
-    );
-
     let (device, queue) = adapter
         .request_device(
             &DeviceDescriptor {
@@ -208,28 +595,85 @@ pub async fn select_device<G: GraphicsApi>(
                 adapter.get_info(),
                 err
             )
-        })
-        .unwrap();
+        })?;
+
+    SUBGROUP.store(
+        features.contains(wgpu::Features::SUBGROUP),
+        Ordering::Relaxed,
+    );
 
-    (device, queue, adapter)
+    Ok((device, queue, adapter))
 }
 
 fn tuner_device_id(info: AdapterInfo) -> String {
     format!("wgpu-{}-{}", info.device, info.backend.to_str())
 }
 
+/// The devices of the adapters of the graphics API, indexed by type like [select_device] does.
+///
+/// Adapters of an unknown type are skipped, they are only selected as a fallback.
+#[cfg(not(target_family = "wasm"))]
+fn enumerate_devices<G: GraphicsApi>() -> Vec<DeviceInfo<WgpuDevice>> {
+    use wgpu::DeviceType;
+
+    let instance = wgpu::Instance::default();
+    let mut num_discrete = 0;
+    let mut num_integrated = 0;
+    let mut num_virtual = 0;
+    let mut has_cpu = false;
+    let mut devices = Vec::new();
+
+    for adapter in instance.enumerate_adapters(G::backend().into()) {
+        let info = adapter.get_info();
+        let (device, unified_memory) = match info.device_type {
+            DeviceType::DiscreteGpu => {
+                num_discrete += 1;
+                (WgpuDevice::DiscreteGpu(num_discrete - 1), false)
+            }
+            DeviceType::IntegratedGpu => {
+                num_integrated += 1;
+                (WgpuDevice::IntegratedGpu(num_integrated - 1), true)
+            }
+            DeviceType::VirtualGpu => {
+                num_virtual += 1;
+                (WgpuDevice::VirtualGpu(num_virtual - 1), false)
+            }
+            // Only the first CPU adapter can be selected.
+            DeviceType::Cpu if !has_cpu => {
+                has_cpu = true;
+                (WgpuDevice::Cpu, true)
+            }
+            DeviceType::Cpu | DeviceType::Other => continue,
+        };
+
+        let capabilities = DeviceCapabilities {
+            name: info.name,
+            // wgpu doesn't report the memory size of the adapters.
+            memory_size: None,
+            // The WGSL compiler doesn't support half precision floats yet.
+            f16: false,
+            bf16: false,
+            max_workgroup_size: Some(adapter.limits().max_compute_invocations_per_workgroup),
+            unified_memory,
+        };
+        devices.push(DeviceInfo::new(device, capabilities));
+    }
+
+    devices
+}
+
 #[cfg(target_family = "wasm")]
-async fn select_adapter<G: GraphicsApi>(_device: &WgpuDevice) -> wgpu::Adapter {
+async fn select_adapter<G: GraphicsApi>(_device: &WgpuDevice) -> Result<wgpu::Adapter, String> {
     let instance = wgpu::Instance::default();
 
     instance
         .request_adapter(&wgpu::RequestAdapterOptionsBase::default())
         .await
-        .unwrap()
+        .ok_or_else(|| "No adapter found".to_string())
 }
 
 #[cfg(not(target_family = "wasm"))]
-fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> wgpu::Adapter {
+fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> Result<wgpu::Adapter, String> {
     use wgpu::DeviceType;
 
     let instance = wgpu::Instance::default();
@@ -237,7 +681,7 @@ fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> wgpu::Adapter {
     let mut adapters = Vec::new();
 
     if matches!(device, WgpuDevice::Existing(_)) {
-        panic!("Cannot automatically create a client for an existing device! Please use init_existing_device instead.")
+        return Err("Cannot automatically create a client for an existing device! Please use init_existing_device instead.".to_string());
     }
 
     instance
@@ -272,10 +716,10 @@ fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> wgpu::Adapter {
         error: &str,
         mut adapters: Vec<wgpu::Adapter>,
         mut adapters_other: Vec<wgpu::Adapter>,
-    ) -> wgpu::Adapter {
+    ) -> Result<wgpu::Adapter, String> {
         if adapters.len() <= num {
             if adapters_other.len() <= num {
-                panic!(
+                return Err(format!(
                     "{}, adapters {:?}, other adapters {:?}",
                     error,
                     adapters
@@ -286,13 +730,13 @@ fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> wgpu::Adapter {
                         .into_iter()
                         .map(|adapter| adapter.get_info())
                         .collect::<Vec<_>>(),
-                );
+                ));
             }
 
-            return adapters_other.remove(num);
+            return Ok(adapters_other.remove(num));
         }
 
-        adapters.remove(num)
+        Ok(adapters.remove(num))
     }
 
     let adapter = match device {
@@ -301,20 +745,20 @@ fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> wgpu::Adapter {
             "No Discrete GPU device found",
             adapters,
             adapters_other,
-        ),
+        )?,
         WgpuDevice::IntegratedGpu(num) => select(
             *num,
             "No Integrated GPU device found",
             adapters,
             adapters_other,
-        ),
+        )?,
         WgpuDevice::VirtualGpu(num) => select(
             *num,
             "No Virtual GPU device found",
             adapters,
             adapters_other,
-        ),
-        WgpuDevice::Cpu => select(0, "No CPU device found", adapters, adapters_other),
+        )?,
+        WgpuDevice::Cpu => select(0, "No CPU device found", adapters, adapters_other)?,
         WgpuDevice::BestAvailable => {
             let mut most_performant_adapter = None;
             let mut current_score = -1;
@@ -339,10 +783,14 @@ fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> wgpu::Adapter {
                     }
                 });
 
-            if let Some(adapter) = most_performant_adapter {
-                adapter
-            } else {
-                panic!("No adapter found for graphics API {:?}", G::default());
+            match most_performant_adapter {
+                Some(adapter) => adapter,
+                None => {
+                    return Err(format!(
+                        "No adapter found for graphics API {:?}",
+                        G::default()
+                    ))
+                }
             }
         }
         WgpuDevice::Existing(_) => unreachable!("Cannot select an adapter for an existing device."),
@@ -350,5 +798,105 @@ fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> wgpu::Adapter {
 
     log::info!("Using adapter {:?}", adapter.get_info());
 
-    adapter
+    Ok(adapter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn device_should_not_be_initialized_with_different_options() {
+        // A device that is never used, so that no client is created.
+        let device = WgpuDevice::VirtualGpu(usize::MAX);
+        let builder = RuntimeOptions::builder().tasks_max(4);
+
+        assert!(builder.record(&device).unwrap());
+        assert!(!builder.record(&device).unwrap());
+        assert!(matches!(
+            RuntimeOptions::builder().tasks_max(8).record(&device),
+            Err(RuntimeOptionsError::AlreadyInitialized(_))
+        ));
+
+        release(&device);
+
+        assert!(RuntimeOptions::builder()
+            .tasks_max(8)
+            .record(&device)
+            .unwrap());
+    }
+
+    #[cfg(all(feature = "fusion", not(target_family = "wasm")))]
+    #[test]
+    fn release_should_release_the_fusion_clients() {
+        use burn_tensor::Tensor;
+
+        // A device of its own, created from the best adapter, so that releasing it doesn't
+        // affect the other tests.
+        let device = WgpuDevice::VirtualGpu(usize::MAX - 1);
+        RuntimeOptions::builder()
+            .fallback_devices(vec![WgpuDevice::BestAvailable])
+            .init::<crate::AutoGraphicsApi>(&device)
+            .unwrap();
+
+        let tensor = Tensor::<crate::Wgpu, 1>::ones([64], &device);
+        tensor.into_data();
+
+        assert!(release(&device));
+        // The fusion client holding the handles of the tensors was released with the device.
+        assert!(!burn_fusion::release_clients(&device));
+        assert!(!release(&device));
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn unavailable_devices_should_be_tried_in_order() {
+        let device = WgpuDevice::VirtualGpu(usize::MAX - 2);
+        let fallback_devices = vec![
+            WgpuDevice::VirtualGpu(usize::MAX - 3),
+            WgpuDevice::VirtualGpu(usize::MAX - 4),
+        ];
+
+        let result = RuntimeOptions::builder()
+            .fallback_devices(fallback_devices.clone())
+            .init::<crate::AutoGraphicsApi>(&device);
+
+        let reason = match result {
+            Err(RuntimeOptionsError::Unavailable(reason)) => reason,
+            _ => panic!("The device should be unavailable"),
+        };
+        let positions = core::iter::once(&device)
+            .chain(fallback_devices.iter())
+            .map(|device| reason.find(&format!("\n{device:?}:")).unwrap())
+            .collect::<Vec<_>>();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // The options of the unavailable device aren't kept.
+        assert!(RuntimeOptions::builder().record(&device).unwrap());
+        release(&device);
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn unavailable_device_should_fall_back_to_the_next_device() {
+        let device = WgpuDevice::VirtualGpu(usize::MAX - 5);
+
+        RuntimeOptions::builder()
+            .fallback_devices(vec![
+                WgpuDevice::VirtualGpu(usize::MAX - 6),
+                WgpuDevice::BestAvailable,
+            ])
+            .init::<crate::AutoGraphicsApi>(&device)
+            .unwrap();
+
+        assert!(release(&device));
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn missing_adapter_should_return_an_error() {
+        let device = WgpuDevice::VirtualGpu(usize::MAX);
+
+        assert!(select_adapter::<crate::AutoGraphicsApi>(&device).is_err());
+    }
 }
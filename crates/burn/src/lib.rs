@@ -56,6 +56,8 @@
 //!   - `train`: Enables features `dataset` and `autodiff` and provides a training environment
 //!   - `tui`: Includes Text UI with progress bar and plots
 //!   - `metrics`: Includes system info metrics (CPU/GPU usage, etc.)
+//!   - `tune`: Enables feature `train` and provides a hyperparameter search
+//!   - `graph`: Graph neural network layers and graph batching
 //! - Dataset
 //!   - `dataset`: Includes a datasets library
 //!   - `audio`: Enables audio datasets (SpeechCommandsDataset)
@@ -64,9 +66,11 @@
 //!   - `vision`: Enables vision datasets (MnistDataset)
 //! - Backends
 //!   - `wgpu`: Makes available the WGPU backend
+//!   - `opencl`: Makes available the OpenCL backend
 //!   - `candle`: Makes available the Candle backend
 //!   - `tch`: Makes available the LibTorch backend
 //!   - `ndarray`: Makes available the NdArray backend
+//!   - `dynamic`: Makes available a backend selected at runtime among the enabled backends
 //! - Backend specifications
 //!   - `cuda`: If supported, CUDA will be used
 //!   - `accelerate`: If supported, Accelerate will be used
@@ -90,3 +94,15 @@ pub use burn_core::*;
 pub mod train {
     pub use burn_train::*;
 }
+
+/// Tune module
+#[cfg(feature = "tune")]
+pub mod tune {
+    pub use burn_tune::*;
+}
+
+/// Graph module
+#[cfg(feature = "graph")]
+pub mod graph {
+    pub use burn_graph::*;
+}
@@ -41,8 +41,17 @@ pub fn run<B: Backend>(device: &B::Device) {
 
     let permut = output.clone().swap_dims::<_, 1, 2>();
 
+    // Dimensions can also be referred to by name, panicking with the names of the tensor
+    // dimensions when it doesn't exist.
+    let summed = output.clone().sum_dim("DModel");
+
+    // The reduced dimension can then be removed from the type.
+    let reduced: NamedTensor<B, (Batch, SeqLength)> = summed.clone().squeeze::<_, 2>();
+
     println!("Weights => {weights}");
     println!("Input   => {input}");
     println!("Output  => {output}");
     println!("Permut  => {permut}");
+    println!("Summed  => {summed}");
+    println!("Reduced => {reduced}");
 }
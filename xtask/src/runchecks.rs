@@ -257,6 +257,11 @@ fn burn_core_std() {
     );
     endgroup!();
 
+    // Run cargo test --features vmap
+    group!("Test: burn-core (vmap)");
+    cargo_test(["-p", "burn-core", "--features", "vmap"].into());
+    endgroup!();
+
     // Run cargo test --features test-wgpu
     if std::env::var("DISABLE_WGPU").is_err() {
         group!("Test: burn-core (wgpu)");